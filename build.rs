@@ -0,0 +1,146 @@
+//! Datos de compilación para `docsguard version --verbose`: commit de git,
+//! target y versiones de las gramáticas de tree-sitter (leídas de Cargo.lock).
+//! También el índice del corpus de `docsguard conformance`.
+//!
+//! Sin `.git` (p. ej. en la imagen Docker) el commit se toma de
+//! `DOCSGUARD_GIT_COMMIT` o queda como "desconocido".
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    println!("cargo:rerun-if-env-changed=DOCSGUARD_GIT_COMMIT");
+    watch_git_head();
+
+    let commit = std::env::var("DOCSGUARD_GIT_COMMIT")
+        .ok()
+        .filter(|c| !c.trim().is_empty())
+        .or_else(git_commit)
+        .unwrap_or_else(|| "desconocido".to_string());
+    println!("cargo:rustc-env=DOCSGUARD_GIT_COMMIT={}", commit.trim());
+
+    let target = std::env::var("TARGET").unwrap_or_default();
+    println!("cargo:rustc-env=DOCSGUARD_TARGET={}", target);
+    let profile = std::env::var("PROFILE").unwrap_or_default();
+    println!("cargo:rustc-env=DOCSGUARD_PROFILE={}", profile);
+
+    let lock = std::fs::read_to_string("Cargo.lock").unwrap_or_default();
+    println!(
+        "cargo:rustc-env=DOCSGUARD_GRAMMARS={}",
+        grammar_versions(&lock).join(",")
+    );
+
+    conformance_corpus();
+}
+
+/// Corpus de `fixtures/conformance/<lenguaje>/`: escribe en `OUT_DIR` la
+/// lista de especímenes con su contenido (`include_str!`), que
+/// `src/conformance.rs` embebe para que `docsguard conformance` no dependa
+/// del repositorio. Un espécimen es un escenario (el nombre sin extensión)
+/// con su archivo de código y su `.yaml` de expectativas; cualquiera de los
+/// dos puede faltar.
+fn conformance_corpus() {
+    let root = Path::new("fixtures/conformance");
+    println!("cargo:rerun-if-changed={}", root.display());
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let include = |path: &Path| {
+        format!(
+            "Some(include_str!({:?}))",
+            Path::new(&manifest_dir).join(path).display().to_string()
+        )
+    };
+
+    let mut specimens = String::from("&[\n");
+    for language in sorted_entries(root) {
+        let mut scenarios: std::collections::BTreeMap<String, (Option<PathBuf>, Option<PathBuf>)> =
+            Default::default();
+        for file in sorted_entries(&language) {
+            let Some(stem) = file.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let entry = scenarios.entry(stem.to_string()).or_default();
+            if file.extension().is_some_and(|ext| ext == "yaml") {
+                entry.1 = Some(file.clone());
+            } else {
+                entry.0 = Some(file.clone());
+            }
+        }
+        let language_name = language.file_name().unwrap_or_default().to_string_lossy();
+        for (scenario, (code, expectation)) in scenarios {
+            let file_name = code
+                .as_ref()
+                .and_then(|c| c.file_name())
+                .map(|n| format!("Some({:?})", n.to_string_lossy()))
+                .unwrap_or_else(|| "None".into());
+            specimens.push_str(&format!(
+                "    RawSpecimen {{ language: {:?}, scenario: {:?}, file_name: {}, source: {}, expectation: {} }},\n",
+                language_name,
+                scenario,
+                file_name,
+                code.as_deref().map(include).unwrap_or_else(|| "None".into()),
+                expectation.as_deref().map(include).unwrap_or_else(|| "None".into()),
+            ));
+        }
+    }
+    specimens.push(']');
+
+    let out = Path::new(&std::env::var("OUT_DIR").unwrap_or_default()).join("conformance.rs");
+    let _ = std::fs::write(out, specimens);
+}
+
+/// Entradas de `dir` en orden estable (ninguna si no existe).
+fn sorted_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| entries.filter_map(|e| e.ok().map(|e| e.path())).collect())
+        .unwrap_or_default();
+    entries.sort();
+    entries
+}
+
+/// Commit corto de `HEAD`.
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short=12", "HEAD"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())?;
+    String::from_utf8(output.stdout).ok()
+}
+
+/// Recompila cuando cambia la rama o su último commit.
+fn watch_git_head() {
+    let head = Path::new(".git/HEAD");
+    if !head.exists() {
+        return;
+    }
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    if let Ok(content) = std::fs::read_to_string(head) {
+        if let Some(reference) = content.trim().strip_prefix("ref: ") {
+            println!("cargo:rerun-if-changed=.git/{}", reference);
+        }
+    }
+}
+
+/// `nombre versión` de `tree-sitter` y cada `tree-sitter-*` de Cargo.lock.
+fn grammar_versions(lock: &str) -> Vec<String> {
+    let mut versions = Vec::new();
+    let mut name: Option<&str> = None;
+    for line in lock.lines() {
+        if line == "[[package]]" {
+            name = None;
+        } else if let Some(value) = line.strip_prefix("name = ") {
+            name = Some(value.trim_matches('"'));
+        } else if let Some(value) = line.strip_prefix("version = ") {
+            if let Some(package) =
+                name.filter(|n| *n == "tree-sitter" || n.starts_with("tree-sitter-"))
+            {
+                // `tree-sitter-language` es el ABI compartido, no una gramática
+                if package != "tree-sitter-language" {
+                    versions.push(format!("{} {}", package, value.trim_matches('"')));
+                }
+            }
+        }
+    }
+    versions
+}
@@ -0,0 +1,159 @@
+//! Página HTML mínima a partir de `docsguard export-model`.
+//!
+//! Lo que haría un generador de sitios: leer el modelo y pintar cada sección
+//! con su marca de "verificado contra el código" y la tabla de argumentos,
+//! enlazando cada ubicación a su archivo. Solo depende del formato JSON
+//! (`schema_version` 1), no de docsguard.
+//!
+//! ```bash
+//! docsguard export-model docs/api.md src/ > model.json
+//! cargo run --example render_model -- model.json > api.html
+//! ```
+
+use serde::Deserialize;
+use std::fmt::Write;
+
+/// Versión del formato que entiende este ejemplo.
+const SUPPORTED_SCHEMA: u32 = 1;
+
+#[derive(Deserialize)]
+struct Model {
+    schema_version: u32,
+    generator: String,
+    sections: Vec<Section>,
+}
+
+#[derive(Deserialize)]
+struct Location {
+    file: String,
+    line: usize,
+}
+
+#[derive(Deserialize)]
+struct Section {
+    id: String,
+    title: Option<String>,
+    location: Location,
+    status: String,
+    functions: Vec<Function>,
+}
+
+#[derive(Deserialize)]
+struct Function {
+    name: String,
+    location: Location,
+    status: String,
+    args: Vec<Arg>,
+    findings: Vec<Finding>,
+}
+
+#[derive(Deserialize)]
+struct Arg {
+    name: String,
+    status: String,
+    code_type: Option<String>,
+    doc_type: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Finding {
+    message: String,
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Enlace `archivo#Llínea`, la forma de GitHub.
+fn source_link(location: &Location) -> String {
+    format!(
+        r#"<a href="{0}#L{1}">{0}:{1}</a>"#,
+        escape(&location.file),
+        location.line
+    )
+}
+
+fn badge(status: &str) -> &'static str {
+    match status {
+        "verified" => "✔ verificado contra el código",
+        "warning" => "⚠ con advertencias",
+        "error" => "✖ desincronizado",
+        _ => "sin enlace desde el código",
+    }
+}
+
+fn render(model: &Model) -> String {
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>API</title></head><body>\n",
+    );
+    for section in &model.sections {
+        let title = section.title.as_deref().unwrap_or(&section.id);
+        let _ = writeln!(
+            html,
+            r#"<section id="{}"><h2>{}</h2><p class="{}">{} · {}</p>"#,
+            escape(&section.id),
+            escape(title),
+            section.status,
+            badge(&section.status),
+            source_link(&section.location)
+        );
+        for function in &section.functions {
+            let _ = writeln!(
+                html,
+                r#"<h3><code>{}</code> <small class="{}">{}</small></h3>"#,
+                escape(&function.name),
+                function.status,
+                source_link(&function.location)
+            );
+            if !function.args.is_empty() {
+                html.push_str(
+                    "<table><tr><th>Argumento</th><th>Código</th><th>Docs</th><th>Estado</th></tr>\n",
+                );
+                for arg in &function.args {
+                    let _ = writeln!(
+                        html,
+                        "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                        escape(&arg.name),
+                        escape(arg.code_type.as_deref().unwrap_or("—")),
+                        escape(arg.doc_type.as_deref().unwrap_or("—")),
+                        arg.status
+                    );
+                }
+                html.push_str("</table>\n");
+            }
+            for finding in &function.findings {
+                let _ = writeln!(
+                    html,
+                    "<p class=\"finding\">{}</p>",
+                    escape(&finding.message)
+                );
+            }
+        }
+        html.push_str("</section>\n");
+    }
+    let _ = writeln!(
+        html,
+        "<footer>{}</footer></body></html>",
+        escape(&model.generator)
+    );
+    html
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::args()
+        .nth(1)
+        .ok_or("uso: render_model <model.json>")?;
+    let model: Model = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    if model.schema_version > SUPPORTED_SCHEMA {
+        return Err(format!(
+            "modelo con schema_version {}; este ejemplo entiende hasta la {}",
+            model.schema_version, SUPPORTED_SCHEMA
+        )
+        .into());
+    }
+    print!("{}", render(&model));
+    Ok(())
+}
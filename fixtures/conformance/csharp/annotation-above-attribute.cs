@@ -0,0 +1,9 @@
+public class Auth
+{
+    // @docs: [auth-login]
+    [HttpPost("login")]
+    public bool Login(string username)
+    {
+        return username.Length > 0;
+    }
+}
@@ -0,0 +1,8 @@
+public class Auth
+{
+    /* @docs: [auth-login] */
+    public bool Login(string username)
+    {
+        return username.Length > 0;
+    }
+}
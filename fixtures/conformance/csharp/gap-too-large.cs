@@ -0,0 +1,10 @@
+public class Auth
+{
+    // @docs: [auth-login]
+
+
+    public bool Login(string username)
+    {
+        return username.Length > 0;
+    }
+}
@@ -0,0 +1,9 @@
+public class Auth
+{
+    // Inicia sesión con usuario y contraseña.
+    // @docs: [auth-login]
+    public bool Login(string username, string password)
+    {
+        return username.Length > 0 && password.Length > 0;
+    }
+}
@@ -0,0 +1,8 @@
+public class Auth
+{
+    public bool Login(string username)
+    {
+        return username.Length > 0;
+    } // @docs: [auth-login]
+    public void Logout(string token) {}
+}
@@ -0,0 +1,6 @@
+package auth
+
+/* @docs: [auth-login] */
+func Login(username string) bool {
+	return username != ""
+}
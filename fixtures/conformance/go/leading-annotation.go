@@ -0,0 +1,7 @@
+package auth
+
+// Login inicia sesión con usuario y contraseña.
+// @docs: [auth-login]
+func Login(username string, password string) bool {
+	return username != "" && password != ""
+}
@@ -0,0 +1,6 @@
+package auth
+
+func Login(username string) bool {
+	return username != ""
+} // @docs: [auth-login]
+func Logout(token string) {}
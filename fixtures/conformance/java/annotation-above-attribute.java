@@ -0,0 +1,7 @@
+public class Auth {
+    // @docs: [auth-login]
+    @PostMapping("/login")
+    public boolean login(String username) {
+        return !username.isEmpty();
+    }
+}
@@ -0,0 +1,9 @@
+public class Auth {
+    /**
+     * Inicia sesión.
+     * @docs: [auth-login]
+     */
+    public boolean login(String username) {
+        return !username.isEmpty();
+    }
+}
@@ -0,0 +1,8 @@
+public class Auth {
+    // @docs: [auth-login]
+
+
+    public boolean login(String username) {
+        return !username.isEmpty();
+    }
+}
@@ -0,0 +1,7 @@
+public class Auth {
+    // Inicia sesión con usuario y contraseña.
+    // @docs: [auth-login]
+    public boolean login(String username, String password) {
+        return !username.isEmpty() && !password.isEmpty();
+    }
+}
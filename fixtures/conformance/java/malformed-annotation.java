@@ -0,0 +1,6 @@
+public class Auth {
+    // @docs [auth-login]
+    public boolean login(String username) {
+        return !username.isEmpty();
+    }
+}
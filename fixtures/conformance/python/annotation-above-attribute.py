@@ -0,0 +1,4 @@
+# @docs: [auth-login]
+@app.post("/login")
+def login(username: str) -> bool:
+    return bool(username)
@@ -0,0 +1,5 @@
+# @docs: [auth-login]
+
+
+def login(username: str) -> bool:
+    return bool(username)
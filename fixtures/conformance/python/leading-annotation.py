@@ -0,0 +1,4 @@
+# Inicia sesión con usuario y contraseña.
+# @docs: [auth-login]
+def login(username: str, password: str) -> bool:
+    return bool(username and password)
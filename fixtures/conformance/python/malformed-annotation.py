@@ -0,0 +1,3 @@
+# @docs [auth-login]
+def login(username: str) -> bool:
+    return bool(username)
@@ -0,0 +1,4 @@
+def login(username: str) -> bool:
+    return bool(username)  # @docs: [auth-login]
+def logout(token: str) -> None:
+    pass
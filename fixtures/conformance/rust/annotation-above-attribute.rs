@@ -0,0 +1,5 @@
+/// @docs: [auth-login]
+#[inline]
+pub fn login(username: &str) -> bool {
+    !username.is_empty()
+}
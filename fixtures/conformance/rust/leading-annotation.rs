@@ -0,0 +1,5 @@
+/// Inicia sesión con usuario y contraseña.
+/// @docs: [auth-login]
+pub fn login(username: &str, password: &str) -> bool {
+    !username.is_empty() && !password.is_empty()
+}
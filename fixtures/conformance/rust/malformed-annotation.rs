@@ -0,0 +1,4 @@
+/// @docs [auth-login]
+pub fn login(username: &str) -> bool {
+    !username.is_empty()
+}
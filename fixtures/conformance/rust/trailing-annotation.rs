@@ -0,0 +1,4 @@
+pub fn login(username: &str) -> bool {
+    !username.is_empty()
+} // @docs: [auth-login]
+pub fn logout(token: &str) {}
@@ -0,0 +1,12 @@
+interface Auth {
+  // @docs: [auth]
+  login(username: string): boolean;
+}
+
+export class AuthController implements Auth {
+  // @docs: [auth-login]
+  @Post("/login")
+  login(username: string): boolean {
+    return username.length > 0;
+  }
+}
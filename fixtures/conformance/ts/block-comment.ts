@@ -0,0 +1,7 @@
+/**
+ * Inicia sesión.
+ * @docs: [auth-login]
+ */
+export function login(username: string): boolean {
+  return username.length > 0;
+}
@@ -0,0 +1,6 @@
+// @docs: [auth-login]
+
+
+export function login(username: string): boolean {
+  return username.length > 0;
+}
@@ -0,0 +1,5 @@
+// Inicia sesión con usuario y contraseña.
+// @docs: [auth-login]
+export function login(username: string, password: string): boolean {
+  return username.length > 0 && password.length > 0;
+}
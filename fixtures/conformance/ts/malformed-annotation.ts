@@ -0,0 +1,4 @@
+// @docs [auth-login]
+export function login(username: string): boolean {
+  return username.length > 0;
+}
@@ -0,0 +1,4 @@
+export function login(username: string): boolean {
+  return username.length > 0;
+} // @docs: [auth-login]
+export function logout(token: string): void {}
@@ -0,0 +1,23 @@
+pub trait Storage {
+    /// @docs: [storage-put]
+    fn put(&self, key: &str, data: &[u8]) -> Result<(), Error>;
+
+    fn flush(&self) {}
+}
+
+pub struct S3Storage;
+
+impl Storage for S3Storage {
+    fn put(&self, key: &str, data: &[u8]) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+pub struct DiskStorage;
+
+impl Storage for DiskStorage {
+    // Deriva: `data` pasó a ser `contents` y apareció `sync`
+    fn put(&self, key: &str, contents: &[u8], sync: bool) -> Result<(), Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+export interface Storage {
+  // @docs: [storage-put]
+  put(key: string, data: Uint8Array): Promise<void>;
+}
+
+export class S3Storage implements Storage {
+  async put(key: string, data: Uint8Array): Promise<void> {}
+
+  private sign(key: string): string {
+    return key;
+  }
+}
+
+export class DiskStorage implements Storage {
+  // Deriva: `data` pasó a ser `contents` y apareció `sync`
+  async put(key: string, contents: Uint8Array, sync: boolean): Promise<void> {}
+}
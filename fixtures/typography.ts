@@ -0,0 +1,12 @@
+/// @docs: [profile-update]
+export function actualizarPerfil(
+  userId: string,
+  nombre: string,
+  email: string,
+  avatarUrl: string,
+  idioma: string,
+  zonaHoraria: string,
+  notificar: boolean,
+  prioridad: number,
+  alias: string,
+) {}
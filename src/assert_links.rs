@@ -0,0 +1,339 @@
+//! Comando `assert`: primitiva de CI para checklists de release.
+//!
+//! Comprueba que una lista de IDs críticos tiene sección en la documentación,
+//! está enlazada desde el código y, con `--require-clean`, no arrastra errores.
+//! Es una vista filtrada del `Report` de `check` indexada por ID.
+//!
+//! Sale con 0 si todos los IDs pasan y con 1 si alguno falla; los errores de
+//! ejecución siguen el esquema común de `crate::exit`.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use crate::core::types::Severity;
+use crate::exit::{Failure, Outcome};
+use crate::messages::{self, print_tr};
+use crate::parser::code_parser::{self, is_valid_id, safe_display};
+use crate::report::{self, Report};
+use crate::vfs::{self, Bounded};
+
+/// Tamaño máximo del archivo de `--ids-from` (VUL-04).
+const MAX_IDS_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Estado de un ID afirmado.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IdStatus {
+    Ok,
+    /// Ni sección ni enlace desde el código.
+    Missing,
+    /// Enlazado desde el código, pero sin sección.
+    NoSection,
+    /// Con sección, pero ninguna función la enlaza.
+    Unlinked,
+    /// Con `--require-clean`: hay hallazgos de severidad Error.
+    Errors,
+}
+
+impl IdStatus {
+    fn label(self) -> &'static str {
+        match self {
+            IdStatus::Ok => "ok",
+            IdStatus::Missing => "no existe",
+            IdStatus::NoSection => "sin sección",
+            IdStatus::Unlinked => "sin enlace",
+            IdStatus::Errors => "con errores",
+        }
+    }
+}
+
+/// Resultado de la comprobación de un ID.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IdAssertion {
+    pub id: String,
+    pub status: IdStatus,
+    pub has_section: bool,
+    pub linked: bool,
+    /// Hallazgos de severidad Error vinculados al ID (tras el baseline).
+    pub errors: usize,
+}
+
+impl IdAssertion {
+    pub fn passed(&self) -> bool {
+        self.status == IdStatus::Ok
+    }
+}
+
+/// Salida JSON para bots de release.
+#[derive(Debug, Serialize)]
+struct AssertOutput<'a> {
+    passed: bool,
+    require_clean: bool,
+    ids: &'a [IdAssertion],
+}
+
+/// Evalúa cada ID sobre el informe.
+pub fn evaluate(report: &Report, ids: &[String], require_clean: bool) -> Vec<IdAssertion> {
+    ids.iter()
+        .map(|id| {
+            let has_section = report.section_ids.contains(id);
+            let linked = report.linked_ids.contains(id);
+            let errors = report
+                .for_doc_id(id)
+                .filter(|r| r.severity == Severity::Error)
+                .count();
+            let status = match (has_section, linked) {
+                (false, false) => IdStatus::Missing,
+                (false, true) => IdStatus::NoSection,
+                (true, false) => IdStatus::Unlinked,
+                (true, true) if require_clean && errors > 0 => IdStatus::Errors,
+                (true, true) => IdStatus::Ok,
+            };
+            IdAssertion {
+                id: id.clone(),
+                status,
+                has_section,
+                linked,
+                errors,
+            }
+        })
+        .collect()
+}
+
+/// Une los IDs de `--id` y `--ids-from`, sin duplicados y en orden.
+pub fn collect_ids(ids: &[String], ids_from: Option<&Path>) -> Result<Vec<String>> {
+    let mut all: Vec<String> = ids.to_vec();
+    if let Some(path) = ids_from {
+        all.extend(read_ids_file(path)?);
+    }
+
+    let mut unique: Vec<String> = Vec::with_capacity(all.len());
+    for id in all {
+        if !is_valid_id(&id) {
+            anyhow::bail!(Failure::usage(format!("ID inválido: '{}'", id)));
+        }
+        if !unique.contains(&id) {
+            unique.push(id);
+        }
+    }
+    if unique.is_empty() {
+        anyhow::bail!(Failure::usage(
+            "No hay IDs que comprobar: usa --id o --ids-from"
+        ));
+    }
+    Ok(unique)
+}
+
+/// Lee un ID por línea; ignora líneas vacías y comentarios `#`.
+fn read_ids_file(path: &Path) -> Result<Vec<String>> {
+    code_parser::require_file_exists(path, "IDs")?;
+    match vfs::read_bounded(path, MAX_IDS_FILE_SIZE)
+        .with_context(|| format!("No se pudo leer: {}", safe_display(path)))?
+    {
+        Bounded::Contents(content) => Ok(parse_ids(&content)),
+        Bounded::TooLarge(size) => anyhow::bail!(Failure::input(format!(
+            "Archivo de IDs demasiado grande ({} KB, máximo: {} KB): {}",
+            size / 1024,
+            MAX_IDS_FILE_SIZE / 1024,
+            safe_display(path)
+        ))
+        .with_path(path)),
+    }
+}
+
+fn parse_ids(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect()
+}
+
+/// Opciones de `assert` que no son entradas.
+pub struct AssertOptions<'a> {
+    pub ids: &'a [String],
+    pub ids_from: Option<&'a Path>,
+    pub require_clean: bool,
+    pub json: bool,
+}
+
+/// Ejecuta `docsguard assert`. `Outcome::Findings` si algún ID no pasa.
+pub fn run_assert(
+    code_files: &[PathBuf],
+    doc_file: &Path,
+    project_root: &Path,
+    options: AssertOptions,
+) -> Result<Outcome> {
+    let ids = collect_ids(options.ids, options.ids_from)?;
+    for code_file in code_files {
+        code_parser::require_file_exists(code_file, "código")?;
+    }
+    code_parser::require_file_exists(doc_file, "documentación")?;
+
+    let report = report::build_report(code_files, doc_file, project_root)?;
+    let assertions = evaluate(&report, &ids, options.require_clean);
+    let passed = assertions.iter().all(IdAssertion::passed);
+
+    if options.json {
+        let output = AssertOutput {
+            passed,
+            require_clean: options.require_clean,
+            ids: &assertions,
+        };
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&output).context("Error al serializar el resultado")?
+        );
+    } else {
+        print_tr!("{}", render_table(&assertions));
+    }
+
+    Ok(Outcome::failed_if(!passed))
+}
+
+/// Tabla de estado por ID.
+fn render_table(assertions: &[IdAssertion]) -> String {
+    let width = assertions
+        .iter()
+        .map(|a| a.id.len())
+        .max()
+        .unwrap_or(0)
+        .max("ID".len());
+
+    let mut out = String::from("DocsGuard Assert — IDs críticos\n\n");
+    out.push_str(&format!(
+        "  {:<width$}  Sección  Enlace  Errores  Estado\n",
+        "ID"
+    ));
+    for a in assertions {
+        out.push_str(&format!(
+            "  {:<width$}  {:<7}  {:<6}  {:>7}  {} {}\n",
+            a.id,
+            messages::tr(if a.has_section { "sí" } else { "no" }),
+            messages::tr(if a.linked { "sí" } else { "no" }),
+            a.errors,
+            if a.passed() { "✓" } else { "✗" },
+            messages::tr(a.status.label())
+        ));
+    }
+
+    let failed = assertions.iter().filter(|a| !a.passed()).count();
+    out.push_str("---\n");
+    if failed == 0 {
+        out.push_str(&format!("Todos los IDs pasan ({}).\n", assertions.len()));
+    } else {
+        out.push_str(&format!(
+            "{} de {} IDs no pasan.\n",
+            failed,
+            assertions.len()
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{Rule, ValidationResult};
+    use crate::vfs::MemFs;
+
+    fn report() -> Report {
+        let error = ValidationResult {
+            severity: Severity::Error,
+            rule: Rule::GhostArg,
+            message: "Argumento fantasma".into(),
+            function_name: Some("refund".into()),
+            code_location: Some("src/payments.ts:9".into()),
+            doc_id: Some("payments-refund".into()),
+            doc_location: Some("docs/api.md:20".into()),
+            hint: None,
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        };
+        Report {
+            results: vec![error],
+            section_ids: ["payments-charge", "payments-refund", "payments-legacy"]
+                .map(String::from)
+                .into(),
+            linked_ids: ["payments-charge", "payments-refund", "payments-v2"]
+                .map(String::from)
+                .into(),
+            ..Report::default()
+        }
+    }
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn each_id_gets_its_status() {
+        let ids = ids(&[
+            "payments-charge",
+            "payments-legacy",
+            "payments-v2",
+            "payments-gone",
+        ]);
+        let statuses: Vec<IdStatus> = evaluate(&report(), &ids, false)
+            .iter()
+            .map(|a| a.status)
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                IdStatus::Ok,
+                IdStatus::Unlinked,
+                IdStatus::NoSection,
+                IdStatus::Missing
+            ]
+        );
+    }
+
+    #[test]
+    fn errors_only_fail_with_require_clean() {
+        let ids = ids(&["payments-refund"]);
+        let lenient = evaluate(&report(), &ids, false);
+        assert!(lenient[0].passed());
+        assert_eq!(lenient[0].errors, 1);
+
+        let strict = evaluate(&report(), &ids, true);
+        assert_eq!(strict[0].status, IdStatus::Errors);
+        assert!(!strict[0].passed());
+    }
+
+    #[test]
+    fn ids_from_file_are_merged_and_deduplicated() {
+        let dir = MemFs::project();
+        let file = dir.path().join("release-critical.txt");
+        dir.write(
+            &file,
+            "# críticos\npayments-charge\n\n  payments-refund  \n",
+        );
+
+        let all = collect_ids(&ids(&["payments-charge"]), Some(&file)).unwrap();
+        assert_eq!(all, ids(&["payments-charge", "payments-refund"]));
+
+        assert!(collect_ids(&[], None).is_err());
+        assert!(collect_ids(&ids(&["bad id"]), None).is_err());
+    }
+
+    #[test]
+    fn json_output_uses_stable_status_names() {
+        let assertions = evaluate(&report(), &ids(&["payments-v2"]), false);
+        let output = AssertOutput {
+            passed: false,
+            require_clean: false,
+            ids: &assertions,
+        };
+        let json = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["ids"][0]["status"], "no-section");
+        assert_eq!(json["ids"][0]["linked"], true);
+        assert_eq!(json["passed"], false);
+    }
+}
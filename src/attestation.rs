@@ -0,0 +1,492 @@
+//! Atestaciones de secciones (`docsguard attest`, `check --check-attestations`).
+//!
+//! Para las funciones en las que "la sección existe y los argumentos cuadran"
+//! no basta, `attest` guarda en `.docsguard/attestations.yaml` quién revisó
+//! una sección, cuándo y el hash de su contenido: del marcador `@docs-id` al
+//! siguiente, con título, prosa y argumentos. Con `--check-attestations`, una
+//! sección enlazada cuyo hash ya no coincide es `changed-since-attestation`
+//! (Warning) y una de `attestation_required` sin atestación,
+//! `missing-attestation` (Error).
+//!
+//! El hash se calcula sobre el texto normalizado (`normalize`): finales de
+//! línea, espacios y líneas en blanco no lo cambian, así que un checkout en
+//! Windows da el mismo que uno en Linux.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::baseline::DOCSGUARD_DIR;
+use crate::config::Config;
+use crate::core::types::{CodeEntity, DocSection, Rule, Severity, ValidationResult};
+use crate::dates::today;
+use crate::exit::Failure;
+use crate::last_run::content_hash;
+use crate::messages::println_tr;
+use crate::parser::code_parser::{self, atomic_write, is_valid_id, safe_display};
+use crate::parser::doc_parser;
+use crate::schema::Artifact;
+use crate::vfs;
+use crate::yaml;
+
+const ATTESTATIONS_FILE: &str = "attestations.yaml";
+
+/// Tamaño máximo de attestations.yaml para prevenir DoS (VUL-04).
+const MAX_ATTESTATIONS_SIZE: u64 = 1024 * 1024;
+
+/// Revisión de una sección por una persona.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attestation {
+    pub doc_id: String,
+    /// `fnv1a64:<hex>` del contenido normalizado de la sección (`section_hash`).
+    pub hash: String,
+    /// Quién atestó (`--by`).
+    pub by: String,
+    /// Día de la atestación en UTC (`AAAA-MM-DD`).
+    pub date: String,
+}
+
+/// Contenido de `.docsguard/attestations.yaml`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Attestations {
+    #[serde(default)]
+    pub attestations: Vec<Attestation>,
+}
+
+/// Ruta del archivo de atestaciones de un proyecto.
+pub fn attestations_path(project_root: &Path) -> PathBuf {
+    project_root.join(DOCSGUARD_DIR).join(ATTESTATIONS_FILE)
+}
+
+impl Attestations {
+    /// Carga las atestaciones de `project_root`; ninguna si no existe el archivo.
+    pub fn load(project_root: &Path) -> Result<Self> {
+        let path = attestations_path(project_root);
+        if !vfs::exists(&path) {
+            return Ok(Attestations::default());
+        }
+
+        let content = yaml::read(&path, MAX_ATTESTATIONS_SIZE, Failure::config)?;
+        Self::from_yaml(&content, &path).with_context(|| {
+            Failure::config(format!(
+                "Error al parsear las atestaciones: {}",
+                path.display()
+            ))
+            .with_path(&path)
+        })
+    }
+
+    /// Parsea las atestaciones leídas de `path`. Un documento vacío no tiene ninguna.
+    fn from_yaml(content: &str, path: &Path) -> Result<Self> {
+        if content.trim().is_empty() {
+            return Ok(Attestations::default());
+        }
+        let attestations: Attestations =
+            yaml::from_str_checked(content, Artifact::Attestations).map_err(|e| e.in_file(path))?;
+        if let Some(a) = attestations
+            .attestations
+            .iter()
+            .find(|a| !is_valid_id(&a.doc_id))
+        {
+            anyhow::bail!("ID inválido '{}' en la atestación de {}", a.doc_id, a.by);
+        }
+        Ok(attestations)
+    }
+
+    /// Guarda las atestaciones con escritura atómica (VUL-02).
+    pub fn save(&self, project_root: &Path) -> Result<PathBuf> {
+        let dir = project_root.join(DOCSGUARD_DIR);
+        if !vfs::exists(&dir) {
+            vfs::create_dir_all(&dir)
+                .with_context(|| format!("No se pudo crear: {}", dir.display()))?;
+        }
+        let path = attestations_path(project_root);
+        let content = serde_yml::to_string(self).context("Error al serializar las atestaciones")?;
+        atomic_write(&path, content.as_bytes())?;
+        Ok(path)
+    }
+
+    /// Atestación vigente de `doc_id`.
+    pub fn get(&self, doc_id: &str) -> Option<&Attestation> {
+        self.attestations.iter().find(|a| a.doc_id == doc_id)
+    }
+
+    /// Añade la atestación o reemplaza la anterior de la misma sección.
+    pub fn upsert(&mut self, attestation: Attestation) {
+        match self
+            .attestations
+            .iter_mut()
+            .find(|a| a.doc_id == attestation.doc_id)
+        {
+            Some(existing) => *existing = attestation,
+            None => self.attestations.push(attestation),
+        }
+    }
+}
+
+/// Texto normalizado: finales de línea `\n`, cada línea sin espacios en los
+/// extremos y con los interiores reducidos a uno, y como mucho una línea en
+/// blanco seguida (ninguna al principio ni al final).
+pub fn normalize(text: &str) -> String {
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    let mut out = String::with_capacity(text.len());
+    let mut pending_blank = false;
+    for line in text.lines() {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        if words.is_empty() {
+            pending_blank = !out.is_empty();
+            continue;
+        }
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        if pending_blank {
+            out.push('\n');
+            pending_blank = false;
+        }
+        out.push_str(&words.join(" "));
+    }
+    out
+}
+
+/// Texto de las secciones `id` de `source`: cada una, desde su marcador hasta
+/// el marcador siguiente o el final. `None` si no hay ninguna.
+fn section_text(source: &str, sections: &[DocSection], id: &str) -> Option<String> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut starts: Vec<usize> = sections.iter().map(|s| s.line).collect();
+    starts.sort_unstable();
+    starts.dedup();
+    let mut text: Option<String> = None;
+    for section in sections.iter().filter(|s| s.id == id) {
+        let end = starts
+            .iter()
+            .find(|&&line| line > section.line)
+            .map_or(lines.len(), |&line| line - 1);
+        let start = section.line.saturating_sub(1).min(end);
+        let text = text.get_or_insert_with(String::new);
+        for line in &lines[start..end] {
+            text.push_str(line);
+            text.push('\n');
+        }
+    }
+    text
+}
+
+/// Hash del contenido normalizado de las secciones `id` (`fnv1a64:<hex>`).
+pub fn section_hash(source: &str, sections: &[DocSection], id: &str) -> Option<String> {
+    section_text(source, sections, id)
+        .map(|text| format!("fnv1a64:{:016x}", content_hash(normalize(&text).as_bytes())))
+}
+
+/// Hallazgos de `check --check-attestations` sobre las secciones de `source`.
+///
+/// Solo se comparan las secciones enlazadas desde el código; las de
+/// `required` sin atestación se reportan estén enlazadas o no.
+pub fn validate_attestations(
+    entities: &[CodeEntity],
+    sections: &[DocSection],
+    source: &str,
+    attestations: &Attestations,
+    required: &[String],
+) -> Vec<ValidationResult> {
+    let mut results = Vec::new();
+    let mut seen: Vec<&str> = Vec::new();
+    for section in sections {
+        if seen.contains(&section.id.as_str()) {
+            continue;
+        }
+        seen.push(&section.id);
+        let linked = entities
+            .iter()
+            .find(|e| e.doc_id.as_deref() == Some(section.id.as_str()));
+        let (severity, rule, message, hint) = match attestations.get(&section.id) {
+            Some(attestation) => {
+                if linked.is_none()
+                    || section_hash(source, sections, &section.id).as_deref()
+                        == Some(attestation.hash.as_str())
+                {
+                    continue;
+                }
+                (
+                    Severity::Warning,
+                    Rule::ChangedSinceAttestation,
+                    format!(
+                        "Sección '{}' modificada desde la última atestación de {} el {}.",
+                        section.id, attestation.by, attestation.date
+                    ),
+                    format!(
+                        "Revisa el cambio y vuelve a atestarla: `docsguard attest {} --docs {} --by …`.",
+                        section.id,
+                        safe_display(&section.file_path)
+                    ),
+                )
+            }
+            None if required.contains(&section.id) => (
+                Severity::Error,
+                Rule::MissingAttestation,
+                format!(
+                    "La sección '{}' requiere atestación (`attestation_required`) y no tiene ninguna.",
+                    section.id
+                ),
+                format!(
+                    "Atéstala tras revisarla: `docsguard attest {} --docs {} --by …`.",
+                    section.id,
+                    safe_display(&section.file_path)
+                ),
+            ),
+            None => continue,
+        };
+        results.push(ValidationResult {
+            severity,
+            rule,
+            message,
+            function_name: linked.map(|e| e.name.clone()),
+            code_location: linked.map(|e| format!("{}:{}", e.file_path.display(), e.line)),
+            doc_id: Some(section.id.clone()),
+            doc_location: Some(format!("{}:{}", section.file_path.display(), section.line)),
+            hint: Some(hint),
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        });
+    }
+    results
+}
+
+/// Ejecuta `attest`: registra el hash actual de la sección `doc_id` de
+/// `doc_file` atestado por `by`.
+pub fn run_attest(doc_file: &Path, doc_id: &str, by: &str, project_root: &Path) -> Result<()> {
+    code_parser::require_file_exists(doc_file, "documentación")?;
+    if by.trim().is_empty() || by.contains('\n') {
+        anyhow::bail!(
+            Failure::usage("--by necesita quién atesta, en una sola línea.")
+                .with_hint("Por ejemplo: `--by @maria`.")
+        );
+    }
+
+    let config = Config::load(project_root)?;
+    let sections = doc_parser::parse_docs(doc_file, &config)
+        .context("Error al parsear el archivo de documentación")?;
+    let source = doc_parser::read_markdown_file(doc_file)?;
+    let Some(hash) = section_hash(&source, &sections, doc_id) else {
+        anyhow::bail!(Failure::usage(format!(
+            "No hay ninguna sección '{}' en {}.",
+            doc_id,
+            safe_display(doc_file)
+        ))
+        .with_hint("`docsguard parse <doc_file>` lista los IDs de las secciones."));
+    };
+
+    let date = today();
+    let mut attestations = Attestations::load(project_root)?;
+    attestations.upsert(Attestation {
+        doc_id: doc_id.to_string(),
+        hash: hash.clone(),
+        by: by.trim().to_string(),
+        date: date.clone(),
+    });
+    let path = attestations.save(project_root)?;
+    println_tr!(
+        "  [attest] Sección '{}' atestada por {} el {} ({}).",
+        doc_id,
+        by.trim(),
+        date,
+        hash
+    );
+    println_tr!("  -> {}", safe_display(&path));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::diagnostics::ParseDiagnostics;
+    use crate::parser::code_parser::parse_code_source;
+    use crate::parser::doc_parser::parse_markdown_source;
+    use crate::vfs::MemFs;
+
+    const DOCS: &str = "# API\n\
+        \n\
+        <!-- @docs-id: auth-login -->\n\
+        ## Login\n\
+        \n\
+        Autentica al usuario.\n\
+        \n\
+        - `username` (string): nombre\n\
+        \n\
+        <!-- @docs-id: auth-logout -->\n\
+        ## Logout\n\
+        \n\
+        Cierra la sesión.\n";
+
+    fn sections(source: &str) -> Vec<DocSection> {
+        parse_markdown_source(
+            source,
+            Path::new("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap()
+    }
+
+    fn hash(source: &str, id: &str) -> String {
+        section_hash(source, &sections(source), id).unwrap()
+    }
+
+    #[test]
+    fn hashes_ignore_line_endings_and_whitespace_but_not_content() {
+        let login = hash(DOCS, "auth-login");
+        let crlf = DOCS.replace('\n', "\r\n");
+        assert_eq!(hash(&crlf, "auth-login"), login);
+        let spaced = DOCS
+            .replace("Autentica al usuario.", "  Autentica   al\tusuario.   ")
+            .replace("## Login\n", "## Login\n\n\n");
+        assert_eq!(hash(&spaced, "auth-login"), login);
+        // La sección siguiente no forma parte del contenido
+        assert_eq!(
+            hash(
+                &DOCS.replace("Cierra la sesión.", "Cierra todo."),
+                "auth-login"
+            ),
+            login
+        );
+        assert_ne!(
+            hash(
+                &DOCS.replace("(string): nombre", "(string): alias"),
+                "auth-login"
+            ),
+            login
+        );
+        assert_ne!(
+            hash(&DOCS.replace("## Login", "## Entrar"), "auth-login"),
+            login
+        );
+        // Valor fijo: el hash no depende de la plataforma ni de la ejecución
+        assert_eq!(normalize("  a  b \r\n\r\n\r\n c\r"), "a b\n\nc");
+        assert_eq!(
+            section_hash("  a  b \r\n\r\n\r\n c\r", &[section_at(1)], "x").unwrap(),
+            "fnv1a64:987bf87aa6e47f3f"
+        );
+    }
+
+    fn section_at(line: usize) -> DocSection {
+        DocSection {
+            id: "x".into(),
+            title: None,
+            parent: None,
+            args: Vec::new(),
+            file_path: PathBuf::from("docs/api.md"),
+            line,
+            expectations: Vec::new(),
+            examples: Vec::new(),
+            since: None,
+            expected_function: None,
+            skips: Vec::new(),
+            dropped_args: 0,
+            title_inferred: false,
+            no_link: false,
+            mentions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn changed_linked_sections_warn_and_required_ones_without_attestation_fail() {
+        let code = "/// @docs: [auth-login]\nexport function login(username: string) {}\n\
+                    /// @docs: [auth-logout]\nexport function logout() {}\n";
+        let entities = parse_code_source(
+            code,
+            code_parser::Language::TypeScript,
+            Path::new("src/auth.ts"),
+            Default::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let attested = |id: &str, source: &str| Attestation {
+            doc_id: id.into(),
+            hash: hash(source, id),
+            by: "@maria".into(),
+            date: "2025-02-01".into(),
+        };
+        let attestations = Attestations {
+            attestations: vec![attested("auth-login", DOCS)],
+        };
+        let required = vec!["auth-logout".to_string()];
+
+        let edited = DOCS.replace("Autentica al usuario.", "Autentica al usuario con 2FA.");
+        let results = validate_attestations(
+            &entities,
+            &sections(&edited),
+            &edited,
+            &attestations,
+            &required,
+        );
+        let summary: Vec<(Severity, Rule, Option<&str>)> = results
+            .iter()
+            .map(|r| (r.severity, r.rule, r.doc_id.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    Severity::Warning,
+                    Rule::ChangedSinceAttestation,
+                    Some("auth-login")
+                ),
+                (
+                    Severity::Error,
+                    Rule::MissingAttestation,
+                    Some("auth-logout")
+                ),
+            ]
+        );
+        assert!(results[0]
+            .message
+            .contains("modificada desde la última atestación de @maria el 2025-02-01"));
+        assert_eq!(results[0].function_name.as_deref(), Some("login"));
+        assert_eq!(results[0].doc_location.as_deref(), Some("docs/api.md:3"));
+
+        // Sin cambios, nada que reportar; sin enlace, el cambio no se compara
+        assert_eq!(
+            validate_attestations(&entities, &sections(DOCS), DOCS, &attestations, &required).len(),
+            1
+        );
+        assert!(
+            validate_attestations(&[], &sections(&edited), &edited, &attestations, &[]).is_empty()
+        );
+    }
+
+    #[test]
+    fn attestations_round_trip_and_reject_invalid_ids_and_duplicate_keys() {
+        let dir = MemFs::project();
+        let mut attestations = Attestations::default();
+        attestations.upsert(Attestation {
+            doc_id: "auth-login".into(),
+            hash: "fnv1a64:0000000000000001".into(),
+            by: "@maria".into(),
+            date: "2025-02-01".into(),
+        });
+        attestations.upsert(Attestation {
+            doc_id: "auth-login".into(),
+            hash: "fnv1a64:0000000000000002".into(),
+            by: "@luis".into(),
+            date: "2025-03-01".into(),
+        });
+        attestations.save(dir.path()).unwrap();
+        let loaded = Attestations::load(dir.path()).unwrap();
+        assert_eq!(loaded, attestations);
+        assert_eq!(loaded.attestations.len(), 1);
+        assert_eq!(loaded.get("auth-login").unwrap().by, "@luis");
+
+        let path = attestations_path(dir.path());
+        dir.write(
+            &path,
+            "attestations:\n- doc_id: \"bad id\"\n  hash: x\n  by: a\n  date: b\n",
+        );
+        assert!(Attestations::load(dir.path()).is_err());
+        dir.write(&path, "attestations: []\nattestations: []\n");
+        let error = format!("{:#}", Attestations::load(dir.path()).unwrap_err());
+        assert!(error.contains("attestations"), "{error}");
+    }
+}
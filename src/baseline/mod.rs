@@ -2,17 +2,41 @@
 //!
 //! Permite volcar todos los errores existentes a `.docsguard/baseline.yaml`.
 //! El comando `check` resta los errores del baseline del resultado,
-//! bloqueando solo regresiones nuevas.
+//! bloqueando solo regresiones nuevas. Con `check --explain-baseline`, cada
+//! hallazgo que no se filtró se muestra junto a la entrada más parecida y los
+//! campos en los que difiere (ver `explain_misses`).
+//!
+//! Cada entrada lleva el día en que entró (`created_at`), que se conserva al
+//! regenerar el baseline para los hallazgos que siguen ahí, y opcionalmente
+//! un `expires`. `check --debt-age` resume con ellos la antigüedad de la
+//! deuda aceptada (ver `debt_age`).
+//!
+//! Las entradas de funciones guardan también su firma normalizada
+//! (`signature`). Si un hallazgo casa con una entrada salvo por el archivo y
+//! la firma es la misma, la función se movió de archivo: el hallazgo se filtra
+//! igual y se informa del movimiento con un Info `moved-baseline-entry`.
+//! `baseline --prune --rewrite-moves` actualiza las rutas guardadas.
+//!
+//! Con `--baseline-name v2` se usa `.docsguard/baseline-v2.yaml`: uno por
+//! combinación de docs y revisión del código (`check --code-ref`).
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
-use crate::core::types::{Severity, ValidationResult};
+use crate::core::types::{parse_location, CodeEntity, Rule, Severity, ValidationResult};
+use crate::dates;
+use crate::exit::Failure;
+use crate::messages;
+use crate::messages::println_tr;
+use crate::paths;
+use crate::schema::Artifact;
+use crate::vfs;
+use crate::yaml;
 
 /// Nombre del directorio de configuración.
-const DOCSGUARD_DIR: &str = ".docsguard";
+pub(crate) const DOCSGUARD_DIR: &str = ".docsguard";
 /// Nombre del archivo de baseline.
 const BASELINE_FILE: &str = "baseline.yaml";
 /// Tamaño máximo del baseline para prevenir DoS (VUL-04) — consistente con parsers.
@@ -32,6 +56,26 @@ pub struct BaselineEntry {
     pub doc_id: Option<String>,
     /// Fingerprint del mensaje (primeras palabras para estabilidad).
     pub message_fingerprint: String,
+    /// Motivo por el que se aceptó el hallazgo (informativo, no afecta al matching).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Archivo del hallazgo (código o docs), relativo a la raíz del proyecto.
+    /// Los baselines antiguos no lo tienen o lo guardan absoluto (ver `same_file`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Día (`AAAA-MM-DD`) en que el hallazgo entró al baseline. Las entradas
+    /// anteriores a este campo no lo tienen: su antigüedad es desconocida.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// Día (`AAAA-MM-DD`) en que la deuda debería estar saldada (informativo,
+    /// se escribe a mano; no deja de filtrar al pasar).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+    /// Firma normalizada de la función (`(id:u64)->bool`), para seguirla si
+    /// se mueve de archivo. Las entradas anteriores a este campo no la tienen
+    /// y solo casan en su archivo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
 }
 
 impl BaselineEntry {
@@ -45,10 +89,147 @@ impl BaselineEntry {
             function_name: r.function_name.clone(),
             doc_id: r.doc_id.clone(),
             message_fingerprint: make_fingerprint(&r.message),
+            reason: None,
+            file: r
+                .code_location
+                .as_deref()
+                .or(r.doc_location.as_deref())
+                .and_then(parse_location)
+                .map(|(path, _)| path.display().to_string()),
+            created_at: None,
+            expires: None,
+            signature: None,
+        }
+    }
+
+    /// Como `from_result`, con la firma de la función del hallazgo si está
+    /// entre `code_entities`.
+    pub fn from_finding(r: &ValidationResult, code_entities: &[CodeEntity]) -> Self {
+        BaselineEntry {
+            signature: entity_signature(r, code_entities),
+            ..BaselineEntry::from_result(r)
+        }
+    }
+
+    /// Copia de la entrada sin metadatos informativos ni archivo, usada como
+    /// clave de matching. El archivo se compara aparte con `same_file`.
+    fn matching_key(&self) -> Self {
+        BaselineEntry {
+            reason: None,
+            file: None,
+            created_at: None,
+            expires: None,
+            signature: None,
+            ..self.clone()
+        }
+    }
+
+    /// Describe la entrada en una línea: `Error en fn login (src/auth.ts): "…"`.
+    fn describe(&self) -> String {
+        let target = match (&self.function_name, &self.doc_id) {
+            (Some(function), _) => format!(" en fn {}", function),
+            (None, Some(doc_id)) => format!(" de '{}'", doc_id),
+            (None, None) => String::new(),
+        };
+        let file = self
+            .file
+            .as_ref()
+            .map(|file| format!(" ({})", file))
+            .unwrap_or_default();
+        format!(
+            "{}{}{}: \"{}\"",
+            self.severity, target, file, self.message_fingerprint
+        )
+    }
+}
+
+/// Firma de la función de un hallazgo: la entidad con su nombre en su
+/// ubicación del código.
+fn entity_signature(r: &ValidationResult, code_entities: &[CodeEntity]) -> Option<String> {
+    let name = r.function_name.as_deref()?;
+    let location = r.code_location.as_deref()?;
+    let entity = code_entities
+        .iter()
+        .find(|e| e.name == name && e.location() == location)?;
+    let args: Vec<String> = entity
+        .args
+        .iter()
+        .map(|a| format!("{}:{}", a.name, a.type_name.as_deref().unwrap_or("_")))
+        .collect();
+    let mut signature = format!("({})", args.join(","));
+    if let Some(return_type) = &entity.return_type {
+        signature.push_str("->");
+        signature.push_str(return_type);
+    }
+    signature.retain(|c| !c.is_whitespace());
+    Some(signature)
+}
+
+/// Una entrada registrada, tal como la indexa `KnownEntries`.
+struct Known {
+    index: usize,
+    file: Option<String>,
+    signature: Option<String>,
+}
+
+/// Cómo casa un hallazgo con el baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Match {
+    /// En el archivo de la entrada (o en cualquiera, si no lo guarda).
+    Same(usize),
+    /// La misma función (nombre, firma e ID de docs) en otro archivo.
+    Moved(usize),
+}
+
+impl Match {
+    fn index(self) -> usize {
+        match self {
+            Match::Same(index) | Match::Moved(index) => index,
         }
     }
 }
 
+/// Índice de entradas conocidas: clave de matching → índice, archivo y firma
+/// de cada entrada registrada.
+struct KnownEntries(HashMap<BaselineEntry, Vec<Known>>);
+
+impl KnownEntries {
+    fn contains(&self, entry: &BaselineEntry) -> bool {
+        self.find(entry).is_some()
+    }
+
+    /// Índice en el baseline de la entrada que casa con `entry`.
+    fn position(&self, entry: &BaselineEntry) -> Option<usize> {
+        self.find(entry).map(Match::index)
+    }
+
+    /// Entrada que casa con `entry`: la de su archivo y, si no hay, la de la
+    /// misma función movida de archivo.
+    fn find(&self, entry: &BaselineEntry) -> Option<Match> {
+        let known = self.0.get(&entry.matching_key())?;
+        known
+            .iter()
+            .find(|k| same_file(k.file.as_deref(), entry.file.as_deref()))
+            .map(|k| Match::Same(k.index))
+            .or_else(|| {
+                let signature = entry.signature.as_deref()?;
+                known
+                    .iter()
+                    .find(|k| k.signature.as_deref() == Some(signature))
+                    .map(|k| Match::Moved(k.index))
+            })
+    }
+}
+
+/// Migración: una entrada sin archivo casa con cualquiera, y una ruta absoluta
+/// de un baseline antiguo casa con la relativa actual si es su sufijo.
+fn same_file(known: Option<&str>, file: Option<&str>) -> bool {
+    match (known, file) {
+        (Some(known), Some(file)) => paths::paths_match(Path::new(known), Path::new(file)),
+        _ => true,
+    }
+}
+
 /// Contenido del archivo baseline.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Baseline {
@@ -61,13 +242,18 @@ pub struct Baseline {
 }
 
 impl Baseline {
-    /// Crea un baseline nuevo desde una lista de resultados de validación.
-    pub fn from_results(results: &[ValidationResult]) -> Self {
-        // Refactorizado: usa BaselineEntry::from_result en lugar de duplicar la struct literal
+    /// Crea un baseline nuevo desde una lista de resultados de validación,
+    /// con las entradas fechadas hoy y las firmas de `code_entities`.
+    pub fn from_results(results: &[ValidationResult], code_entities: &[CodeEntity]) -> Self {
+        // Refactorizado: usa BaselineEntry::from_finding en lugar de duplicar la struct literal
+        let today = dates::today();
         let entries: Vec<BaselineEntry> = results
             .iter()
             .filter(|r| r.severity != Severity::Info)
-            .map(BaselineEntry::from_result)
+            .map(|r| BaselineEntry {
+                created_at: Some(today.clone()),
+                ..BaselineEntry::from_finding(r, code_entities)
+            })
             .collect();
 
         Baseline {
@@ -79,95 +265,817 @@ impl Baseline {
 
     /// Carga un baseline desde el directorio del proyecto.
     pub fn load(project_root: &Path) -> Result<Option<Self>> {
-        let path = baseline_path(project_root);
-        if !path.exists() {
-            return Ok(None);
-        }
+        Self::load_named(project_root, None)
+    }
 
-        // VUL-04: limitar tamaño antes de deserializar para prevenir DoS via YAML grande.
-        let file_size = std::fs::metadata(&path)
-            .with_context(|| format!("No se pudo leer metadata: {}", path.display()))?
-            .len();
-        if file_size > MAX_BASELINE_SIZE {
-            anyhow::bail!(
-                "baseline.yaml demasiado grande ({:.1} MB, máximo: {} MB). Regenera el baseline con `docsguard baseline`.\n    -> Archivo: {}",
-                file_size as f64 / (1024.0 * 1024.0),
-                MAX_BASELINE_SIZE / (1024 * 1024),
-                path.display()
-            );
+    /// Carga el baseline `name` (`baseline-<name>.yaml`) o, sin nombre, el de
+    /// siempre.
+    pub fn load_named(project_root: &Path, name: Option<&str>) -> Result<Option<Self>> {
+        let path = baseline_path(project_root, name);
+        if !vfs::exists(&path) {
+            return Ok(None);
         }
 
-        let content = std::fs::read_to_string(&path)
-            .with_context(|| format!("No se pudo leer el baseline: {}", path.display()))?;
-
-        let baseline: Baseline = serde_yml::from_str(&content)
-            .with_context(|| format!("Error al parsear el baseline: {}", path.display()))?;
+        let content = yaml::read(&path, MAX_BASELINE_SIZE, Failure::baseline)?;
+        let baseline: Baseline = yaml::from_str_checked(&content, Artifact::Baseline)
+            .map_err(|e| e.in_file(&path))
+            .with_context(|| {
+                Failure::baseline(format!("Error al parsear el baseline: {}", path.display()))
+                    .with_path(&path)
+                    .with_hint("Corrígelo a mano o regenera el baseline con `docsguard baseline`.")
+            })?;
 
         if baseline.version != "1" {
-            anyhow::bail!(
+            anyhow::bail!(Failure::baseline(format!(
                 "Versión de baseline no soportada: '{}' (esperada: '1')\n    -> Archivo: {}",
                 baseline.version,
                 path.display()
-            );
+            ))
+            .with_path(&path));
+        }
+
+        let lines = entry_lines(&content);
+        for (index, entry) in baseline.entries.iter().enumerate() {
+            for (field, value) in [
+                ("created_at", &entry.created_at),
+                ("expires", &entry.expires),
+            ] {
+                let Some(value) = value else { continue };
+                if dates::parse_date(value).is_none() {
+                    let location = match lines.get(index) {
+                        Some(line) => format!("{}:{}", path.display(), line),
+                        None => path.display().to_string(),
+                    };
+                    anyhow::bail!(Failure::baseline(format!(
+                        "Fecha inválida en la entrada #{} del baseline: {}: '{}'\n    -> Archivo: {}",
+                        index + 1,
+                        field,
+                        value,
+                        location
+                    ))
+                    .with_path(&path)
+                    .with_hint("Usa el formato AAAA-MM-DD (p. ej. 2025-03-31)."));
+                }
+            }
         }
 
         Ok(Some(baseline))
     }
 
+    /// Conserva los metadatos (`created_at`, `reason`, `expires`) de las
+    /// entradas de `previous` que siguen en este baseline: regenerarlo no
+    /// rejuvenece la deuda, tampoco la de una función que cambió de archivo.
+    /// Las entradas nuevas quedan como estaban.
+    pub fn carry_over(&mut self, previous: &Baseline) {
+        let known = previous.known_entries();
+        for entry in &mut self.entries {
+            if let Some(old) = known.position(entry).map(|i| &previous.entries[i]) {
+                entry.created_at = old.created_at.clone();
+                entry.reason = old.reason.clone();
+                entry.expires = old.expires.clone();
+            }
+        }
+    }
+
     /// Guarda el baseline al disco usando escritura atómica (VUL-02).
     pub fn save(&self, project_root: &Path) -> Result<PathBuf> {
+        self.save_named(project_root, None)
+    }
+
+    /// Como `save`, en el baseline `name`.
+    pub fn save_named(&self, project_root: &Path, name: Option<&str>) -> Result<PathBuf> {
         let dir = project_root.join(DOCSGUARD_DIR);
-        if !dir.exists() {
-            std::fs::create_dir_all(&dir)
+        if !vfs::exists(&dir) {
+            vfs::create_dir_all(&dir)
                 .with_context(|| format!("No se pudo crear: {}", dir.display()))?;
         }
 
-        let path = dir.join(BASELINE_FILE);
+        let path = baseline_path(project_root, name);
         let content = serde_yml::to_string(self).context("Error al serializar el baseline")?;
 
-        // VUL-02: escritura atómica — consistente con apply_changes en interactive.
+        // VUL-02: escritura atómica — misma vía (`Transaction`) que scaffold y --fix.
         // Previene corrupción del baseline si el proceso muere durante la escritura.
         crate::parser::code_parser::atomic_write(&path, content.as_bytes())?;
 
         Ok(path)
     }
 
-    /// Convierte las entradas a un HashSet para comparación rápida.
-    fn entry_set(&self) -> HashSet<BaselineEntry> {
-        self.entries.iter().cloned().collect()
+    /// Añade un hallazgo al baseline, fechado hoy, con un motivo opcional.
+    /// Retorna `false` si el hallazgo ya estaba en el baseline.
+    pub fn add(
+        &mut self,
+        result: &ValidationResult,
+        code_entities: &[CodeEntity],
+        reason: Option<String>,
+    ) -> bool {
+        let entry = BaselineEntry::from_finding(result, code_entities);
+        if self.known_entries().contains(&entry) {
+            return false;
+        }
+        self.entries.push(BaselineEntry {
+            reason,
+            created_at: Some(dates::today()),
+            ..entry
+        });
+        true
+    }
+
+    /// Actualiza el archivo de las entradas cuyas funciones se movieron según
+    /// `results`. Retorna cuántas cambió.
+    pub fn rewrite_moves(
+        &mut self,
+        results: &[ValidationResult],
+        code_entities: &[CodeEntity],
+    ) -> usize {
+        let known = self.known_entries();
+        let mut moved: Vec<(usize, BaselineEntry)> = Vec::new();
+        for result in results.iter().filter(|r| r.severity != Severity::Info) {
+            let entry = BaselineEntry::from_finding(result, code_entities);
+            if let Some(Match::Moved(index)) = known.find(&entry) {
+                if !moved.iter().any(|(i, _)| *i == index) {
+                    moved.push((index, entry));
+                }
+            }
+        }
+        for (index, current) in &moved {
+            self.entries[*index].file = current.file.clone();
+        }
+        moved.len()
     }
+
+    /// Quita las entradas que no casan con ningún hallazgo de `results` (ver
+    /// `unused_entries`). Retorna cuántas quitó.
+    pub fn prune(
+        &mut self,
+        results: &[ValidationResult],
+        code_entities: &[CodeEntity],
+        checked_files: &[PathBuf],
+    ) -> usize {
+        let unused: Vec<usize> = unused_entries(results, self, code_entities, checked_files)
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect();
+        let mut index = 0;
+        self.entries.retain(|_| {
+            index += 1;
+            !unused.contains(&(index - 1))
+        });
+        unused.len()
+    }
+
+    /// Indexa las entradas por clave de matching para comparación rápida.
+    fn known_entries(&self) -> KnownEntries {
+        let mut known: HashMap<BaselineEntry, Vec<Known>> = HashMap::new();
+        for (index, entry) in self.entries.iter().enumerate() {
+            known.entry(entry.matching_key()).or_default().push(Known {
+                index,
+                file: entry.file.clone(),
+                signature: entry.signature.clone(),
+            });
+        }
+        KnownEntries(known)
+    }
+}
+
+/// Añade un único hallazgo al baseline del proyecto (creándolo si no existe).
+pub fn append_to_baseline(
+    project_root: &Path,
+    result: &ValidationResult,
+    code_entities: &[CodeEntity],
+    reason: Option<String>,
+) -> Result<PathBuf> {
+    let mut baseline =
+        Baseline::load(project_root)?.unwrap_or_else(|| Baseline::from_results(&[], &[]));
+    baseline.add(result, code_entities, reason);
+    baseline.save(project_root)
 }
 
 /// Filtra los resultados de validación, eliminando los que están en el baseline.
+/// Cada entrada que casó con una función movida de archivo añade un Info
+/// `moved-baseline-entry` a los resultados nuevos.
 /// Retorna: (resultados_nuevos, total_filtrados)
 pub fn filter_baseline(
     results: &[ValidationResult],
     baseline: &Baseline,
+    code_entities: &[CodeEntity],
 ) -> (Vec<ValidationResult>, usize) {
-    let known = baseline.entry_set();
-    let mut filtered = 0;
+    let matches = find_matches(results, baseline, code_entities);
+    let filtered = matches.iter().filter(|m| m.is_some()).count();
+    let mut new_results: Vec<ValidationResult> = results
+        .iter()
+        .zip(&matches)
+        .filter(|(_, matched)| matched.is_none())
+        .map(|(r, _)| r.clone())
+        .collect();
 
-    let new_results: Vec<ValidationResult> = results
+    let mut followed: Vec<usize> = Vec::new();
+    for (result, matched) in results.iter().zip(&matches) {
+        if let Some(Match::Moved(index)) = matched {
+            if !followed.contains(index) {
+                followed.push(*index);
+                new_results.push(moved_entry_result(result, &baseline.entries[*index]));
+            }
+        }
+    }
+
+    (new_results, filtered)
+}
+
+/// Indica, para cada resultado, si el baseline lo filtra. Info siempre pasa.
+pub fn baseline_matches(
+    results: &[ValidationResult],
+    baseline: &Baseline,
+    code_entities: &[CodeEntity],
+) -> Vec<bool> {
+    find_matches(results, baseline, code_entities)
         .iter()
-        .filter(|r| {
+        .map(Option::is_some)
+        .collect()
+}
+
+/// Hallazgos que filtra cada entrada del baseline, en su orden.
+pub fn entry_hits(
+    results: &[ValidationResult],
+    baseline: &Baseline,
+    code_entities: &[CodeEntity],
+) -> Vec<usize> {
+    let mut hits = vec![0; baseline.entries.len()];
+    for matched in find_matches(results, baseline, code_entities)
+        .into_iter()
+        .flatten()
+    {
+        hits[matched.index()] += 1;
+    }
+    hits
+}
+
+fn find_matches(
+    results: &[ValidationResult],
+    baseline: &Baseline,
+    code_entities: &[CodeEntity],
+) -> Vec<Option<Match>> {
+    let known = baseline.known_entries();
+    results
+        .iter()
+        .map(|r| {
             if r.severity == Severity::Info {
-                return true; // Info siempre pasa
+                return None;
             }
+            known.find(&BaselineEntry::from_finding(r, code_entities))
+        })
+        .collect()
+}
 
-            // Refactorizado: usa BaselineEntry::from_result en lugar de duplicar la struct literal
-            let entry = BaselineEntry::from_result(r);
+/// Info de una entrada seguida hasta el nuevo archivo de su función.
+fn moved_entry_result(result: &ValidationResult, entry: &BaselineEntry) -> ValidationResult {
+    let to = BaselineEntry::from_result(result).file.unwrap_or_default();
+    ValidationResult {
+        severity: Severity::Info,
+        rule: Rule::MovedBaselineEntry,
+        message: format!(
+            "Entrada de baseline seguida tras mover fn {} de {} a {}.",
+            result.function_name.as_deref().unwrap_or_default(),
+            entry.file.as_deref().unwrap_or_default(),
+            to
+        ),
+        function_name: result.function_name.clone(),
+        code_location: result.code_location.clone(),
+        doc_id: result.doc_id.clone(),
+        doc_location: None,
+        hint: Some(
+            "Actualiza las rutas guardadas con `docsguard baseline --prune --rewrite-moves`."
+                .into(),
+        ),
+        provenance: None,
+        related: Vec::new(),
+        target: None,
+        summary: None,
+        cause: None,
+        grace: None,
+    }
+}
+
+/// Campo de `BaselineEntry` que decide si un hallazgo está en el baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryField {
+    Severity,
+    Function,
+    DocId,
+    Fingerprint,
+    File,
+}
+
+impl EntryField {
+    const ALL: [EntryField; 5] = [
+        EntryField::Severity,
+        EntryField::Function,
+        EntryField::DocId,
+        EntryField::Fingerprint,
+        EntryField::File,
+    ];
 
-            if known.contains(&entry) {
-                filtered += 1;
-                false
-            } else {
-                true
+    /// Nombre de la clave en `baseline.yaml`.
+    pub fn name(self) -> &'static str {
+        match self {
+            EntryField::Severity => "severity",
+            EntryField::Function => "function_name",
+            EntryField::DocId => "doc_id",
+            EntryField::Fingerprint => "message_fingerprint",
+            EntryField::File => "file",
+        }
+    }
+
+    fn value(self, entry: &BaselineEntry) -> Option<&str> {
+        match self {
+            EntryField::Severity => Some(&entry.severity),
+            EntryField::Function => entry.function_name.as_deref(),
+            EntryField::DocId => entry.doc_id.as_deref(),
+            EntryField::Fingerprint => Some(&entry.message_fingerprint),
+            EntryField::File => entry.file.as_deref(),
+        }
+    }
+
+    fn matches(self, known: &BaselineEntry, entry: &BaselineEntry) -> bool {
+        match self {
+            EntryField::File => same_file(known.file.as_deref(), entry.file.as_deref()),
+            field => field.value(known) == field.value(entry),
+        }
+    }
+}
+
+/// Hallazgo que el baseline no filtró, con la entrada que más se le parece.
+#[derive(Debug, Clone)]
+pub struct BaselineMiss<'a> {
+    pub result: &'a ValidationResult,
+    /// Entrada calculada para el hallazgo.
+    pub computed: BaselineEntry,
+    /// Entrada más parecida y su índice, si el baseline tiene alguna.
+    pub nearest: Option<(usize, &'a BaselineEntry)>,
+    /// Campos en los que la más parecida difiere de la calculada.
+    pub differing: Vec<EntryField>,
+}
+
+/// Explica los hallazgos de `results` que el baseline no filtra: para cada uno,
+/// la entrada con más campos iguales (a igualdad, la que comparte más
+/// palabras iniciales del fingerprint; luego, la primera) y qué campos fallan.
+pub fn explain_misses<'a>(
+    results: &'a [ValidationResult],
+    baseline: &'a Baseline,
+) -> Vec<BaselineMiss<'a>> {
+    let known = baseline.known_entries();
+    results
+        .iter()
+        .filter(|r| r.severity != Severity::Info)
+        .map(|r| (r, BaselineEntry::from_result(r)))
+        .filter(|(_, entry)| !known.contains(entry))
+        .map(|(result, computed)| {
+            let nearest = baseline
+                .entries
+                .iter()
+                .enumerate()
+                .max_by_key(|(index, known)| {
+                    let same = EntryField::ALL
+                        .iter()
+                        .filter(|f| f.matches(known, &computed))
+                        .count();
+                    let shared_words = known
+                        .message_fingerprint
+                        .split_whitespace()
+                        .zip(computed.message_fingerprint.split_whitespace())
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    (same, shared_words, std::cmp::Reverse(*index))
+                });
+            let differing = nearest
+                .map(|(_, known)| {
+                    EntryField::ALL
+                        .into_iter()
+                        .filter(|f| !f.matches(known, &computed))
+                        .collect()
+                })
+                .unwrap_or_default();
+            BaselineMiss {
+                result,
+                computed,
+                nearest,
+                differing,
             }
         })
-        .cloned()
+        .collect()
+}
+
+/// Antigüedad de la deuda aceptada en el baseline (`check --debt-age`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebtAge<'a> {
+    /// Entradas del baseline.
+    pub size: usize,
+    /// Entradas sin `created_at` (anteriores al campo).
+    pub undated: usize,
+    /// Antigüedad mediana y máxima en días de las entradas fechadas.
+    pub median_days: Option<i64>,
+    pub max_days: Option<i64>,
+    /// Las (como mucho) cinco entradas más antiguas: días, índice y entrada.
+    pub oldest: Vec<(i64, usize, &'a BaselineEntry)>,
+    /// Entradas cuyo `expires` cae dentro de la ventana (o ya pasó): días
+    /// que faltan (negativos si caducó), índice y entrada.
+    pub expiring: Vec<(i64, usize, &'a BaselineEntry)>,
+}
+
+/// Entradas más antiguas que muestra `check --debt-age`.
+const OLDEST_SHOWN: usize = 5;
+
+/// Resume la antigüedad del baseline a día `today` (días desde 1970-01-01);
+/// `window` es cuántos días por delante se buscan caducidades. A igual
+/// antigüedad o caducidad, va primero la entrada anterior en el archivo.
+pub fn debt_age(baseline: &Baseline, today: i64, window: u64) -> DebtAge<'_> {
+    let day = |value: &Option<String>| value.as_deref().and_then(dates::parse_date);
+    let mut dated: Vec<(i64, usize, &BaselineEntry)> = baseline
+        .entries
+        .iter()
+        .enumerate()
+        .filter_map(|(index, entry)| day(&entry.created_at).map(|d| (today - d, index, entry)))
         .collect();
+    dated.sort_by_key(|&(age, index, _)| (std::cmp::Reverse(age), index));
 
-    (new_results, filtered)
+    let mut ages: Vec<i64> = dated.iter().map(|&(age, _, _)| age).collect();
+    ages.sort_unstable();
+    let median_days = match ages.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(ages[n / 2]),
+        n => Some((ages[n / 2 - 1] + ages[n / 2]) / 2),
+    };
+
+    let mut expiring: Vec<(i64, usize, &BaselineEntry)> = baseline
+        .entries
+        .iter()
+        .enumerate()
+        .filter_map(|(index, entry)| day(&entry.expires).map(|d| (d - today, index, entry)))
+        .filter(|&(left, _, _)| left <= window as i64)
+        .collect();
+    expiring.sort_by_key(|&(left, index, _)| (left, index));
+
+    DebtAge {
+        size: baseline.entries.len(),
+        undated: baseline.entries.len() - dated.len(),
+        median_days,
+        max_days: ages.last().copied(),
+        oldest: dated.into_iter().take(OLDEST_SHOWN).collect(),
+        expiring,
+    }
+}
+
+/// Bloque de `check --debt-age`, tras el resumen normal.
+pub fn render_debt_age(debt: &DebtAge, window: u64) -> String {
+    let days = |n: i64| messages::DAYS.count(n.unsigned_abs() as usize);
+    let mut out = format!(
+        "\n  [debt-age] Baseline: {}",
+        messages::BASELINE_ENTRIES.count(debt.size)
+    );
+    if let (Some(median), Some(max)) = (debt.median_days, debt.max_days) {
+        out.push_str(&format!(
+            "; antigüedad mediana {}, máxima {}",
+            days(median),
+            days(max)
+        ));
+    }
+    if debt.undated > 0 {
+        out.push_str(&format!(
+            " ({} sin fecha)",
+            messages::BASELINE_ENTRIES.count(debt.undated)
+        ));
+    }
+    out.push_str(".\n");
+
+    if !debt.oldest.is_empty() {
+        out.push_str("    Más antiguas:\n");
+        for (age, index, entry) in &debt.oldest {
+            out.push_str(&format!(
+                "      {:<10} #{} {}",
+                days(*age),
+                index + 1,
+                entry.describe()
+            ));
+            if let Some(reason) = &entry.reason {
+                out.push_str(&format!(" — {}", reason));
+            }
+            out.push('\n');
+        }
+    }
+    if !debt.expiring.is_empty() {
+        out.push_str(&format!(
+            "    Caducan en {} o menos:\n",
+            days(window as i64)
+        ));
+        for (left, index, entry) in &debt.expiring {
+            let date = entry.expires.as_deref().unwrap_or_default();
+            let when = match left {
+                0 => "hoy".to_string(),
+                left if *left > 0 => format!("en {}", days(*left)),
+                left => format!("caducada hace {}", days(*left)),
+            };
+            out.push_str(&format!(
+                "      {} ({}) #{} {}\n",
+                date,
+                when,
+                index + 1,
+                entry.describe()
+            ));
+        }
+    }
+    out
+}
+
+/// Bloque `debt_age` del informe JSON de `check --debt-age`: lo mismo que
+/// muestra `render_debt_age`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DebtAgeRecord {
+    /// Entradas del baseline.
+    pub size: usize,
+    /// Entradas sin `created_at`.
+    pub undated: usize,
+    pub median_days: Option<i64>,
+    pub max_days: Option<i64>,
+    /// Días por delante en los que se buscan caducidades.
+    pub window_days: u64,
+    /// `days`: antigüedad de la entrada.
+    pub oldest: Vec<DebtEntryRecord>,
+    /// `days`: los que faltan para `expires` (negativos si caducó).
+    pub expiring: Vec<DebtEntryRecord>,
+}
+
+/// Entrada de `DebtAgeRecord`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DebtEntryRecord {
+    /// Posición en `entries`, desde 1.
+    pub index: usize,
+    pub days: i64,
+    pub entry: BaselineEntry,
+}
+
+/// Bloque `debt_age` de `debt`.
+pub fn debt_age_record(debt: &DebtAge, window: u64) -> DebtAgeRecord {
+    let entries = |list: &[(i64, usize, &BaselineEntry)]| {
+        list.iter()
+            .map(|&(days, index, entry)| DebtEntryRecord {
+                index: index + 1,
+                days,
+                entry: entry.clone(),
+            })
+            .collect()
+    };
+    DebtAgeRecord {
+        size: debt.size,
+        undated: debt.undated,
+        median_days: debt.median_days,
+        max_days: debt.max_days,
+        window_days: window,
+        oldest: entries(&debt.oldest),
+        expiring: entries(&debt.expiring),
+    }
+}
+
+/// Texto de `check --explain-baseline`: la entrada calculada de cada hallazgo
+/// no filtrado, la más parecida (con su línea en `baseline.yaml`) y los campos
+/// que difieren.
+pub fn render_misses(project_root: &Path, name: Option<&str>, misses: &[BaselineMiss]) -> String {
+    let locate = entry_locator(project_root, name);
+    let show = |value: Option<&str>| match value {
+        Some(value) => format!("\"{}\"", value),
+        None => "(vacío)".to_string(),
+    };
+
+    let mut out = format!(
+        "  [explain-baseline] {} por el baseline:\n",
+        messages::UNFILTERED_FINDINGS.count(misses.len())
+    );
+    for miss in misses {
+        let result = miss.result;
+        out.push_str(&format!("\n  {} ({})", result.severity, result.rule.id()));
+        if let Some(function) = &result.function_name {
+            out.push_str(&format!(" en fn {}", function));
+        }
+        if let Some(location) = result
+            .code_location
+            .as_ref()
+            .or(result.doc_location.as_ref())
+        {
+            out.push_str(&format!(" ({})", location));
+        }
+        out.push_str("\n    Entrada calculada:\n");
+        for field in EntryField::ALL {
+            out.push_str(&format!(
+                "      {:<20} {}\n",
+                format!("{}:", field.name()),
+                show(field.value(&miss.computed))
+            ));
+        }
+        let Some((index, _)) = miss.nearest else {
+            out.push_str("    El baseline no tiene entradas.\n");
+            continue;
+        };
+        out.push_str(&format!(
+            "    Más parecida: entrada #{} ({})\n",
+            index + 1,
+            locate(index)
+        ));
+        let known = miss.nearest.map(|(_, known)| known);
+        for field in &miss.differing {
+            out.push_str(&format!(
+                "      difiere {}:\n        baseline: {}\n        actual:   {}\n",
+                field.name(),
+                show(known.and_then(|k| field.value(k))),
+                show(field.value(&miss.computed))
+            ));
+        }
+    }
+    out
+}
+
+/// Registro de `baseline_debug` en el informe JSON de
+/// `check --explain-baseline`: lo mismo que muestra `render_misses`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MissRecord {
+    /// Regla del hallazgo (`missing-arg`).
+    pub rule: &'static str,
+    /// `archivo:línea` del hallazgo, en el código o, si no tiene, en la docs.
+    pub location: Option<String>,
+    /// Entrada calculada para el hallazgo.
+    pub computed: BaselineEntry,
+    /// Entrada más parecida; `null` si el baseline no tiene entradas.
+    pub nearest: Option<NearestRecord>,
+}
+
+/// Entrada más parecida de un `MissRecord`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NearestRecord {
+    /// Posición en `entries`, desde 1.
+    pub index: usize,
+    /// `archivo:línea` de la entrada (solo el archivo si no se encuentra).
+    pub location: String,
+    pub entry: BaselineEntry,
+    /// Claves en las que difiere de `computed` (`message_fingerprint`…).
+    pub differing: Vec<&'static str>,
+}
+
+/// Registros de `baseline_debug` de `misses`.
+pub fn miss_records(
+    project_root: &Path,
+    name: Option<&str>,
+    misses: &[BaselineMiss],
+) -> Vec<MissRecord> {
+    let locate = entry_locator(project_root, name);
+    misses
+        .iter()
+        .map(|miss| MissRecord {
+            rule: miss.result.rule.id(),
+            location: miss
+                .result
+                .code_location
+                .clone()
+                .or_else(|| miss.result.doc_location.clone()),
+            computed: miss.computed.clone(),
+            nearest: miss.nearest.map(|(index, entry)| NearestRecord {
+                index: index + 1,
+                location: locate(index),
+                entry: entry.clone(),
+                differing: miss.differing.iter().map(|f| f.name()).collect(),
+            }),
+        })
+        .collect()
+}
+
+/// `archivo:línea` en el baseline de la entrada con cada índice, leyendo el
+/// archivo una sola vez; solo el archivo si la línea no se encuentra.
+fn entry_locator(project_root: &Path, name: Option<&str>) -> impl Fn(usize) -> String {
+    let lines = vfs::read_to_string(&baseline_path(project_root, name))
+        .map(|content| entry_lines(&content))
+        .unwrap_or_default();
+    let file = baseline_file(name);
+    move |index| match lines.get(index) {
+        Some(line) => format!("{}:{}", file.display(), line),
+        None => file.display().to_string(),
+    }
+}
+
+/// Entradas del baseline que no casan con ningún hallazgo actual, con su índice.
+///
+/// Solo se juzgan las entradas de los archivos verificados (`checked_files`,
+/// rutas normalizadas) y las antiguas sin archivo: un `check` parcial no
+/// declara sin uso las entradas del resto del proyecto.
+pub fn unused_entries<'a>(
+    results: &[ValidationResult],
+    baseline: &'a Baseline,
+    code_entities: &[CodeEntity],
+    checked_files: &[PathBuf],
+) -> Vec<(usize, &'a BaselineEntry)> {
+    let current: Vec<BaselineEntry> = results
+        .iter()
+        .filter(|r| r.severity != Severity::Info)
+        .map(|r| BaselineEntry::from_finding(r, code_entities))
+        .collect();
+    baseline
+        .entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| {
+            entry.file.as_deref().is_none_or(|file| {
+                checked_files
+                    .iter()
+                    .any(|checked| paths::paths_match(checked, Path::new(file)))
+            })
+        })
+        .filter(|(_, entry)| {
+            !current.iter().any(|c| {
+                c.matching_key() == entry.matching_key()
+                    && (same_file(entry.file.as_deref(), c.file.as_deref())
+                        || (c.signature.is_some() && c.signature == entry.signature))
+            })
+        })
+        .collect()
+}
+
+/// Hallazgos `unused-suppression` para entradas sin uso, ubicados en su
+/// línea de `.docsguard/baseline.yaml`.
+pub fn unused_entry_results(
+    project_root: &Path,
+    name: Option<&str>,
+    unused: &[(usize, &BaselineEntry)],
+    severity: Severity,
+) -> Vec<ValidationResult> {
+    let locations = entry_locations(project_root, name);
+    unused
+        .iter()
+        .map(|(index, entry)| {
+            let target = match (&entry.function_name, &entry.doc_id) {
+                (Some(function), _) => format!(" en fn {}", function),
+                (None, Some(doc_id)) => format!(" de '{}'", doc_id),
+                (None, None) => String::new(),
+            };
+            ValidationResult {
+                severity,
+                rule: Rule::UnusedSuppression,
+                message: format!(
+                    "La entrada #{} del baseline ({}{}: \"{}\") no silenció ningún hallazgo.",
+                    index + 1,
+                    entry.severity,
+                    target,
+                    entry.message_fingerprint
+                ),
+                function_name: None,
+                code_location: None,
+                doc_id: None,
+                doc_location: Some(
+                    locations
+                        .get(*index)
+                        .cloned()
+                        .unwrap_or_else(|| baseline_file(name).display().to_string()),
+                ),
+                hint: Some("Elimina la entrada del baseline: el hallazgo ya no se produce.".into()),
+                provenance: None,
+                related: Vec::new(),
+                target: None,
+                summary: None,
+                cause: None,
+                grace: None,
+            }
+        })
+        .collect()
+}
+
+/// `.docsguard/baseline.yaml:<línea>` de cada entrada del baseline `name`;
+/// vacío si no se puede leer.
+pub fn entry_locations(project_root: &Path, name: Option<&str>) -> Vec<String> {
+    let file = baseline_file(name);
+    vfs::read_to_string(&baseline_path(project_root, name))
+        .map(|content| entry_lines(&content))
+        .unwrap_or_default()
+        .iter()
+        .map(|line| format!("{}:{}", file.display(), line))
+        .collect()
+}
+
+/// Línea (1-based) de cada elemento de `entries:` en el YAML del baseline.
+fn entry_lines(content: &str) -> Vec<usize> {
+    let mut lines = Vec::new();
+    let mut in_entries = false;
+    let mut indent: Option<usize> = None;
+    for (i, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if !line.starts_with([' ', '-']) {
+            in_entries = line.trim_end() == "entries:";
+            continue;
+        }
+        if in_entries && (trimmed.starts_with("- ") || trimmed == "-") {
+            let level = line.len() - trimmed.len();
+            if *indent.get_or_insert(level) == level {
+                lines.push(i + 1);
+            }
+        }
+    }
+    lines
 }
 
 /// Genera una huella del mensaje para comparación estable.
@@ -181,9 +1089,37 @@ fn make_fingerprint(message: &str) -> String {
         .join(" ")
 }
 
-/// Obtiene la ruta al archivo baseline.
-fn baseline_path(project_root: &Path) -> PathBuf {
-    project_root.join(DOCSGUARD_DIR).join(BASELINE_FILE)
+/// Obtiene la ruta al archivo baseline (`name` para `--baseline-name`).
+pub(crate) fn baseline_path(project_root: &Path, name: Option<&str>) -> PathBuf {
+    project_root.join(baseline_file(name))
+}
+
+/// `.docsguard/baseline.yaml`, o `.docsguard/baseline-<name>.yaml`.
+fn baseline_file(name: Option<&str>) -> PathBuf {
+    let file = match name {
+        Some(name) => format!("baseline-{}.yaml", name),
+        None => BASELINE_FILE.to_string(),
+    };
+    Path::new(DOCSGUARD_DIR).join(file)
+}
+
+/// Comprueba que `name` sirve como nombre de baseline: letras, dígitos, `.`,
+/// `_` y `-`, sin empezar por punto.
+pub fn check_name(name: &str) -> std::result::Result<(), String> {
+    if name.is_empty() {
+        return Err("el nombre no puede estar vacío".into());
+    }
+    if name.starts_with('.')
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+    {
+        return Err(format!(
+            "'{}' no sirve como nombre de baseline (solo letras, dígitos, '.', '_' y '-', sin empezar por punto)",
+            name
+        ));
+    }
+    Ok(())
 }
 
 /// Timestamp simple sin dependencia de chrono.
@@ -196,30 +1132,101 @@ fn chrono_now() -> String {
 }
 
 /// Ejecuta el comando baseline: vuelca errores actuales al archivo.
-pub fn run_baseline(code_file: &Path, doc_file: &Path, project_root: &Path) -> Result<()> {
+///
+/// Con `prune` no se añade nada: se quitan las entradas de estos archivos que
+/// ya no casan con ningún hallazgo y, con `rewrite_moves`, se actualiza el
+/// archivo de las que casan con una función movida.
+pub fn run_baseline(
+    code_file: &Path,
+    doc_file: &Path,
+    project_root: &Path,
+    prune: bool,
+    rewrite_moves: bool,
+    name: Option<&str>,
+) -> Result<()> {
     // Refactorizado: usa require_file_exists para eliminar comprobaciones duplicadas entre comandos
     crate::parser::code_parser::require_file_exists(code_file, "código")?;
     crate::parser::code_parser::require_file_exists(doc_file, "documentación")?;
 
-    println!("DocsGuard Baseline — Volcando errores existentes\n");
+    if prune {
+        println_tr!("DocsGuard Baseline — Podando entradas sin uso\n");
+    } else {
+        println_tr!("DocsGuard Baseline — Volcando errores existentes\n");
+    }
 
-    let code_entities = crate::parser::code_parser::parse_code_file(code_file)
-        .context("Error al parsear el archivo de código")?;
-    let doc_sections = crate::parser::doc_parser::parse_markdown_file(doc_file)
-        .context("Error al parsear el archivo de documentación")?;
+    let config = crate::config::Config::load(project_root)?;
+    let mut diagnostics = crate::core::diagnostics::ParseDiagnostics::default();
+    let code_entities = crate::parser::code_parser::parse_project_code(
+        &[code_file.to_path_buf()],
+        &config,
+        &mut diagnostics,
+    )?;
+    let doc_sections =
+        crate::parser::doc_parser::parse_docs_with_diagnostics(doc_file, &config, &mut diagnostics)
+            .context("Error al parsear el archivo de documentación")?;
 
-    let results = crate::core::validator::validate_links(&code_entities, &doc_sections);
-    let baseline = Baseline::from_results(&results);
+    let doc_source = crate::parser::doc_parser::read_markdown_file(doc_file)?;
+    let results = crate::core::pipeline::run_validation(
+        crate::core::pipeline::Inputs {
+            code_entities: &code_entities,
+            doc_sections: &doc_sections,
+            diagnostics: &diagnostics,
+            docs: &[crate::core::pipeline::DocSource {
+                file: doc_file,
+                source: &doc_source,
+            }],
+            config: &config,
+        },
+        crate::core::pipeline::Passes::default(),
+    )
+    .results;
+
+    if prune {
+        let Some(mut baseline) = Baseline::load_named(project_root, name)? else {
+            let path = baseline_path(project_root, name);
+            anyhow::bail!(Failure::baseline(format!(
+                "No hay baseline que podar: {}",
+                path.display()
+            ))
+            .with_path(&path)
+            .with_hint("Genera uno con `docsguard baseline` sin --prune."));
+        };
+        let checked = [
+            config.paths.normalize(code_file),
+            config.paths.normalize(doc_file),
+        ];
+        let rewritten = if rewrite_moves {
+            baseline.rewrite_moves(&results, &code_entities)
+        } else {
+            0
+        };
+        let pruned = baseline.prune(&results, &code_entities, &checked);
+        let path = baseline.save_named(project_root, name)?;
+        println_tr!("  {} del baseline.", messages::PRUNED_ENTRIES.count(pruned));
+        if rewrite_moves {
+            println_tr!(
+                "  {} tras mover funciones de archivo.",
+                messages::REWRITTEN_PATHS.count(rewritten)
+            );
+        }
+        println_tr!("  Archivo: {}", path.display());
+        return Ok(());
+    }
+
+    let mut baseline = Baseline::from_results(&results, &code_entities);
+    if let Some(previous) = Baseline::load_named(project_root, name)? {
+        baseline.carry_over(&previous);
+    }
 
     let entry_count = baseline.entries.len();
-    let path = baseline.save(project_root)?;
+    let path = baseline.save_named(project_root, name)?;
 
-    println!(
-        "  {} errores/advertencias volcados al baseline.",
-        entry_count
+    println_tr!(
+        "  {} al baseline.",
+        messages::DUMPED_FINDINGS.count(entry_count)
     );
-    println!("  Archivo: {}", path.display());
-    println!("\n  El CI ahora pasará en verde. Solo se bloquearán regresiones nuevas.");
+    println_tr!("  Archivo: {}", path.display());
+    println_tr!("\n  El CI ahora pasará en verde. Solo se bloquearán regresiones nuevas.");
 
     Ok(())
 }
@@ -227,7 +1234,7 @@ pub fn run_baseline(code_file: &Path, doc_file: &Path, project_root: &Path) -> R
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::types::ValidationResult;
+    use crate::vfs::MemFs;
 
     fn make_result(
         severity: Severity,
@@ -237,11 +1244,19 @@ mod tests {
     ) -> ValidationResult {
         ValidationResult {
             severity,
+            rule: Rule::MissingDocSection,
             message: msg.into(),
             function_name: func.map(String::from),
             code_location: None,
             doc_id: doc_id.map(String::from),
+            doc_location: None,
             hint: None,
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
         }
     }
 
@@ -269,11 +1284,11 @@ mod tests {
         ];
 
         // Crear baseline con los primeros 2
-        let baseline = Baseline::from_results(&results[..2]);
+        let baseline = Baseline::from_results(&results[..2], &[]);
         assert_eq!(baseline.entries.len(), 2);
 
         // Filtrar: solo el tercer error debería sobrevivir
-        let (new_results, filtered) = filter_baseline(&results, &baseline);
+        let (new_results, filtered) = filter_baseline(&results, &baseline, &[]);
         assert_eq!(filtered, 2);
         let new_errors: Vec<_> = new_results
             .iter()
@@ -293,7 +1308,7 @@ mod tests {
             entries: vec![],
         };
 
-        let (new_results, filtered) = filter_baseline(&results, &baseline);
+        let (new_results, filtered) = filter_baseline(&results, &baseline, &[]);
         assert_eq!(filtered, 0);
         assert_eq!(new_results.len(), 1);
     }
@@ -307,11 +1322,547 @@ mod tests {
             Some("test-id"),
         )];
 
-        let baseline = Baseline::from_results(&results);
+        let baseline = Baseline::from_results(&results, &[]);
         let yaml = serde_yml::to_string(&baseline).unwrap();
         let loaded: Baseline = serde_yml::from_str(&yaml).unwrap();
 
         assert_eq!(loaded.entries.len(), 1);
         assert_eq!(loaded.entries[0].function_name.as_deref(), Some("test_fn"));
     }
+
+    #[test]
+    fn reason_does_not_affect_matching() {
+        let result = make_result(Severity::Error, "Argumento fantasma: 'x'", Some("f"), None);
+        let mut baseline = Baseline::from_results(&[], &[]);
+        assert!(baseline.add(&result, &[], Some("migración pendiente".into())));
+        assert!(!baseline.add(&result, &[], None));
+
+        let (new_results, filtered) =
+            filter_baseline(std::slice::from_ref(&result), &baseline, &[]);
+        assert_eq!(filtered, 1);
+        assert!(new_results.is_empty());
+        assert_eq!(
+            baseline.entries[0].reason.as_deref(),
+            Some("migración pendiente")
+        );
+    }
+
+    #[test]
+    fn append_creates_baseline_file() {
+        let dir = MemFs::project();
+        let result = make_result(Severity::Warning, "Un aviso", Some("f"), Some("id"));
+        append_to_baseline(dir.path(), &result, &[], Some("motivo".into())).unwrap();
+        append_to_baseline(dir.path(), &result, &[], None).unwrap();
+
+        let loaded = Baseline::load(dir.path()).unwrap().unwrap();
+        assert_eq!(loaded.entries.len(), 1);
+        assert_eq!(loaded.entries[0].reason.as_deref(), Some("motivo"));
+    }
+
+    #[test]
+    fn named_baselines_live_beside_the_default_one() {
+        let dir = MemFs::project();
+        let result = make_result(Severity::Warning, "Un aviso", Some("f"), Some("id"));
+        let path = Baseline::from_results(&[result], &[])
+            .save_named(dir.path(), Some("v2"))
+            .unwrap();
+        assert_eq!(path, dir.path().join(".docsguard/baseline-v2.yaml"));
+        assert!(Baseline::load(dir.path()).unwrap().is_none());
+        let loaded = Baseline::load_named(dir.path(), Some("v2"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.entries.len(), 1);
+
+        assert!(check_name("release-2.x").is_ok());
+        for bad in ["", "../v2", ".v2", "v 2"] {
+            assert!(check_name(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn hand_merged_baselines_with_a_repeated_key_fail_instead_of_dropping_entries() {
+        let dir = MemFs::project();
+        let first = Baseline::from_results(&[located("src/auth.ts:4")], &[]);
+        let yaml = serde_yml::to_string(&first).unwrap();
+        let entries = &yaml[yaml.find("entries:").unwrap()..];
+        dir.mkdir(dir.path().join(DOCSGUARD_DIR));
+        dir.write(baseline_path(dir.path(), None), format!("{yaml}{entries}"));
+
+        let err = Baseline::load(dir.path()).unwrap_err();
+        assert_eq!(crate::exit::exit_code_for(&err), crate::exit::USAGE);
+        let cause = err.chain().nth(1).unwrap().to_string();
+        assert!(cause.contains("baseline.yaml:"), "{cause}");
+        assert!(cause.contains("clave `entries` repetida"), "{cause}");
+    }
+
+    fn located(code_location: &str) -> ValidationResult {
+        ValidationResult {
+            code_location: Some(code_location.into()),
+            ..make_result(
+                Severity::Error,
+                "ID no encontrado",
+                Some("login"),
+                Some("auth-login"),
+            )
+        }
+    }
+
+    #[test]
+    fn entries_record_the_relative_file() {
+        let baseline = Baseline::from_results(&[located("src/auth.ts:4")], &[]);
+        assert_eq!(baseline.entries[0].file.as_deref(), Some("src/auth.ts"));
+
+        // Mismo hallazgo en otro archivo: no es el conocido
+        let (new_results, filtered) = filter_baseline(&[located("lib/auth.ts:4")], &baseline, &[]);
+        assert_eq!(filtered, 0);
+        assert_eq!(new_results.len(), 1);
+    }
+
+    #[test]
+    fn legacy_baseline_with_absolute_paths_still_matches() {
+        let yaml = r#"
+version: '1'
+generated_at: unix:0
+entries:
+- severity: Error
+  function_name: login
+  doc_id: auth-login
+  message_fingerprint: ID no encontrado
+  file: /home/ci/repo/src/auth.ts
+- severity: Error
+  function_name: logout
+  doc_id: auth-logout
+  message_fingerprint: ID no encontrado
+"#;
+        let baseline: Baseline = serde_yml::from_str(yaml).unwrap();
+
+        let logout = ValidationResult {
+            function_name: Some("logout".into()),
+            doc_id: Some("auth-logout".into()),
+            ..located("src/session.ts:9")
+        };
+        let results = [located("src/auth.ts:4"), logout];
+        let (new_results, filtered) = filter_baseline(&results, &baseline, &[]);
+        // Sufijo de la ruta absoluta y entrada sin archivo (formato anterior)
+        assert_eq!(filtered, 2);
+        assert!(new_results.is_empty());
+    }
+
+    #[test]
+    fn entries_that_match_nothing_are_reported_at_their_line() {
+        let dir = MemFs::project();
+        let fixed = ValidationResult {
+            code_location: Some("src/auth.ts:3".into()),
+            ..make_result(
+                Severity::Error,
+                "ID de documentación 'auth-login' no encontrado en el archivo de docs.",
+                Some("login"),
+                Some("auth-login"),
+            )
+        };
+        let still_there = ValidationResult {
+            code_location: Some("src/auth.ts:9".into()),
+            ..make_result(
+                Severity::Error,
+                "ID de documentación 'auth-logout' no encontrado en el archivo de docs.",
+                Some("logout"),
+                Some("auth-logout"),
+            )
+        };
+        let elsewhere = ValidationResult {
+            code_location: Some("src/billing.ts:1".into()),
+            ..fixed.clone()
+        };
+        Baseline::from_results(&[fixed, still_there.clone(), elsewhere], &[])
+            .save(dir.path())
+            .unwrap();
+        let baseline = Baseline::load(dir.path()).unwrap().unwrap();
+
+        // Solo se verificó src/auth.ts: la entrada de billing no se juzga
+        let unused = unused_entries(
+            &[still_there],
+            &baseline,
+            &[],
+            &[PathBuf::from("src/auth.ts"), PathBuf::from("docs/api.md")],
+        );
+        assert_eq!(unused.len(), 1);
+        assert_eq!(unused[0].0, 0);
+
+        let results = unused_entry_results(dir.path(), None, &unused, Severity::Info);
+        assert_eq!(results[0].rule, Rule::UnusedSuppression);
+        assert!(results[0].message.contains("#1"));
+        assert!(results[0].message.contains("fn login"));
+        assert_eq!(
+            results[0].doc_location.as_deref(),
+            Some(".docsguard/baseline.yaml:4")
+        );
+    }
+
+    #[test]
+    fn entry_lines_follow_the_entries_list() {
+        let yaml = "version: '1'\ngenerated_at: unix:0\nentries:\n  # comentario\n  - severity: Error\n    message_fingerprint: a\n  - severity: Warning\n    message_fingerprint: b\n";
+        assert_eq!(entry_lines(yaml), vec![5, 7]);
+    }
+
+    #[test]
+    fn misses_are_paired_with_the_entry_sharing_most_fields() {
+        let known = [
+            located("src/auth.ts:4"),
+            ValidationResult {
+                function_name: Some("logout".into()),
+                ..located("src/auth.ts:9")
+            },
+        ];
+        let baseline = Baseline::from_results(&known, &[]);
+        let renamed = ValidationResult {
+            function_name: Some("sign_in".into()),
+            ..located("src/auth.ts:4")
+        };
+        let reworded = ValidationResult {
+            message: "ID no localizado".into(),
+            ..located("src/auth.ts:4")
+        };
+        let moved = located("lib/auth.ts:4");
+        let results = [known[0].clone(), renamed, reworded, moved];
+
+        let misses = explain_misses(&results, &baseline);
+        let explained: Vec<(Option<usize>, &[EntryField])> = misses
+            .iter()
+            .map(|m| (m.nearest.map(|(i, _)| i), m.differing.as_slice()))
+            .collect();
+        // El hallazgo filtrado no aparece; cada fallo señala su campo
+        assert_eq!(
+            explained,
+            [
+                (Some(0), &[EntryField::Function][..]),
+                (Some(0), &[EntryField::Fingerprint][..]),
+                (Some(0), &[EntryField::File][..]),
+            ]
+        );
+        assert_eq!(misses[1].computed.message_fingerprint, "ID no localizado");
+    }
+
+    #[test]
+    fn fingerprint_prefix_breaks_ties_and_an_empty_baseline_has_no_nearest() {
+        let warning = |message: &str| make_result(Severity::Warning, message, Some("f"), None);
+        let baseline = Baseline::from_results(
+            &[
+                warning("Sección 'Pagos' sin enlace"),
+                warning("El argumento 'token' existe en código"),
+            ],
+            &[],
+        );
+        let results = [warning("El argumento 'tokens' existe en código")];
+        let misses = explain_misses(&results, &baseline);
+        assert_eq!(misses[0].nearest.map(|(i, _)| i), Some(1));
+
+        let empty = Baseline::from_results(&[], &[]);
+        let misses = explain_misses(&results, &empty);
+        assert_eq!(misses[0].nearest, None);
+        assert!(misses[0].differing.is_empty());
+        assert!(render_misses(Path::new("/nonexistent"), None, &misses)
+            .contains("El baseline no tiene entradas."));
+    }
+
+    #[test]
+    fn rendered_misses_name_the_entry_line_and_both_values() {
+        let dir = MemFs::project();
+        Baseline::from_results(&[located("src/auth.ts:4")], &[])
+            .save(dir.path())
+            .unwrap();
+        let baseline = Baseline::load(dir.path()).unwrap().unwrap();
+        let results = [ValidationResult {
+            function_name: Some("sign_in".into()),
+            ..located("src/auth.ts:4")
+        }];
+        let text = render_misses(dir.path(), None, &explain_misses(&results, &baseline));
+        assert!(text.contains("Error (missing-doc-section) en fn sign_in (src/auth.ts:4)"));
+        assert!(text.contains("Más parecida: entrada #1 (.docsguard/baseline.yaml:4)"));
+        assert!(text.contains(
+            "difiere function_name:\n        baseline: \"login\"\n        actual:   \"sign_in\""
+        ));
+        assert!(!text.contains("difiere doc_id"));
+    }
+
+    #[test]
+    fn miss_records_carry_the_same_detail_as_the_text() {
+        let dir = MemFs::project();
+        Baseline::from_results(&[located("src/auth.ts:4")], &[])
+            .save(dir.path())
+            .unwrap();
+        let baseline = Baseline::load(dir.path()).unwrap().unwrap();
+        let results = [ValidationResult {
+            function_name: Some("sign_in".into()),
+            ..located("src/auth.ts:4")
+        }];
+        let records = miss_records(dir.path(), None, &explain_misses(&results, &baseline));
+        assert_eq!(records[0].rule, "missing-doc-section");
+        assert_eq!(records[0].location.as_deref(), Some("src/auth.ts:4"));
+        assert_eq!(
+            records[0].computed.function_name.as_deref(),
+            Some("sign_in")
+        );
+        let nearest = records[0].nearest.as_ref().unwrap();
+        assert_eq!(nearest.index, 1);
+        assert_eq!(nearest.location, ".docsguard/baseline.yaml:4");
+        assert_eq!(nearest.entry.function_name.as_deref(), Some("login"));
+        assert_eq!(nearest.differing, ["function_name"]);
+
+        let empty = Baseline::from_results(&[], &[]);
+        let records = miss_records(dir.path(), None, &explain_misses(&results, &empty));
+        assert_eq!(records[0].nearest, None);
+    }
+
+    fn dated(
+        result: ValidationResult,
+        created_at: Option<&str>,
+        expires: Option<&str>,
+    ) -> BaselineEntry {
+        BaselineEntry {
+            created_at: created_at.map(String::from),
+            expires: expires.map(String::from),
+            ..BaselineEntry::from_result(&result)
+        }
+    }
+
+    #[test]
+    fn regenerating_keeps_the_metadata_of_surviving_entries_and_dates_new_ones() {
+        let kept = located("src/auth.ts:4");
+        let gone = ValidationResult {
+            function_name: Some("logout".into()),
+            ..located("src/auth.ts:9")
+        };
+        let previous = Baseline {
+            version: "1".into(),
+            generated_at: "unix:0".into(),
+            entries: vec![
+                BaselineEntry {
+                    reason: Some("migración pendiente".into()),
+                    ..dated(kept.clone(), Some("2025-01-10"), Some("2025-06-30"))
+                },
+                dated(gone, Some("2024-05-01"), None),
+            ],
+        };
+        let moved = located("lib/auth.ts:4");
+
+        let mut baseline = Baseline::from_results(&[kept, moved], &[]);
+        baseline.carry_over(&previous);
+
+        assert_eq!(baseline.entries.len(), 2);
+        assert_eq!(
+            baseline.entries[0].created_at.as_deref(),
+            Some("2025-01-10")
+        );
+        assert_eq!(
+            baseline.entries[0].reason.as_deref(),
+            Some("migración pendiente")
+        );
+        assert_eq!(baseline.entries[0].expires.as_deref(), Some("2025-06-30"));
+        // Mismo hallazgo en otro archivo: entrada nueva, fechada hoy
+        assert_eq!(baseline.entries[1].created_at, Some(dates::today()));
+        assert_eq!(baseline.entries[1].reason, None);
+
+        // Una entrada anterior al campo sigue sin fecha: no rejuvenece
+        let legacy = Baseline {
+            entries: vec![dated(located("src/auth.ts:4"), None, None)],
+            ..previous
+        };
+        let mut baseline = Baseline::from_results(&[located("src/auth.ts:4")], &[]);
+        baseline.carry_over(&legacy);
+        assert_eq!(baseline.entries[0].created_at, None);
+    }
+
+    #[test]
+    fn debt_age_summarizes_ages_oldest_entries_and_upcoming_expiries() {
+        let today = dates::parse_date("2025-03-01").unwrap();
+        let warning = |message: &str| make_result(Severity::Warning, message, Some("f"), None);
+        let baseline = Baseline {
+            version: "1".into(),
+            generated_at: "unix:0".into(),
+            entries: vec![
+                dated(warning("a"), Some("2025-02-19"), Some("2025-03-20")),
+                dated(warning("b"), Some("2024-03-01"), Some("2025-02-20")),
+                dated(warning("c"), None, Some("2025-06-01")),
+                dated(warning("d"), Some("2025-02-27"), None),
+                dated(warning("e"), Some("2025-02-19"), Some("2025-03-01")),
+            ],
+        };
+
+        let debt = debt_age(&baseline, today, 30);
+        assert_eq!(debt.size, 5);
+        assert_eq!(debt.undated, 1);
+        // Edades 365, 10, 10, 2: mediana entre las dos centrales
+        assert_eq!((debt.median_days, debt.max_days), (Some(10), Some(365)));
+        let oldest: Vec<(i64, usize)> = debt.oldest.iter().map(|&(a, i, _)| (a, i)).collect();
+        assert_eq!(oldest, [(365, 1), (10, 0), (10, 4), (2, 3)]);
+        // La de junio queda fuera de la ventana; la caducada va primero
+        let expiring: Vec<(i64, usize)> = debt.expiring.iter().map(|&(d, i, _)| (d, i)).collect();
+        assert_eq!(expiring, [(-9, 1), (0, 4), (19, 0)]);
+
+        let text = render_debt_age(&debt, 30);
+        assert!(text.contains(
+            "[debt-age] Baseline: 5 entradas; antigüedad mediana 10 días, máxima 365 días (1 entrada sin fecha)."
+        ), "{text}");
+        assert!(
+            text.contains("365 días   #2 Warning en fn f: \"b\"\n"),
+            "{text}"
+        );
+        assert!(text.contains("Caducan en 30 días o menos:"), "{text}");
+        assert!(
+            text.contains("2025-02-20 (caducada hace 9 días) #2"),
+            "{text}"
+        );
+        assert!(text.contains("2025-03-01 (hoy) #5"), "{text}");
+
+        let empty = render_debt_age(&debt_age(&Baseline::from_results(&[], &[]), today, 30), 30);
+        assert_eq!(empty, "\n  [debt-age] Baseline: 0 entradas.\n");
+
+        let record = debt_age_record(&debt, 30);
+        assert_eq!(
+            (record.size, record.undated, record.window_days),
+            (5, 1, 30)
+        );
+        assert_eq!(record.oldest[0].index, 2);
+        assert_eq!(record.oldest[0].days, 365);
+        assert_eq!(record.expiring[0].days, -9);
+        assert_eq!(
+            record.expiring[0].entry.expires.as_deref(),
+            Some("2025-02-20")
+        );
+    }
+
+    #[test]
+    fn invalid_dates_fail_to_load_at_their_entry_line() {
+        let dir = MemFs::project();
+        dir.mkdir(dir.path().join(DOCSGUARD_DIR));
+        dir.write(
+            baseline_path(dir.path(), None),
+            "version: '1'\ngenerated_at: unix:0\nentries:\n- severity: Error\n  message_fingerprint: a\n  expires: 31/03/2025\n",
+        );
+
+        let err = Baseline::load(dir.path()).unwrap_err();
+        assert_eq!(crate::exit::exit_code_for(&err), crate::exit::USAGE);
+        let message = format!("{:#}", err);
+        assert!(
+            message.contains("entrada #1 del baseline: expires: '31/03/2025'"),
+            "{message}"
+        );
+        assert!(message.contains("baseline.yaml:4"), "{message}");
+    }
+
+    /// `login(username: string)` en `file`, línea 4.
+    fn login_in(file: &str, arg_type: &str) -> CodeEntity {
+        CodeEntity {
+            name: "login".into(),
+            args: vec![crate::core::types::Arg {
+                name: "username".into(),
+                type_name: Some(arg_type.into()),
+                description: None,
+                source: crate::core::types::ArgSource::Code,
+                line: None,
+                unit: None,
+                range: None,
+            }],
+            return_type: Some("Promise< Session >".into()),
+            return_fields: None,
+            doc_id: Some("auth-login".into()),
+            file_path: PathBuf::from(file),
+            line: 4,
+            end_line: 4,
+            is_public: true,
+            suppressions: vec![],
+            file_link: None,
+            examples: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn a_function_moved_to_another_file_keeps_its_entry_and_age() {
+        let before = [login_in("src/auth.ts", "string")];
+        let mut baseline = Baseline::from_results(&[located("src/auth.ts:4")], &before);
+        baseline.entries[0].created_at = Some("2025-01-10".into());
+        assert_eq!(
+            baseline.entries[0].signature.as_deref(),
+            Some("(username:string)->Promise<Session>")
+        );
+
+        let after = [login_in("src/session.ts", "string")];
+        let moved = [located("src/session.ts:4")];
+        let (new_results, filtered) = filter_baseline(&moved, &baseline, &after);
+        assert_eq!(filtered, 1);
+        assert_eq!(new_results.len(), 1, "{new_results:?}");
+        assert_eq!(new_results[0].rule, Rule::MovedBaselineEntry);
+        assert_eq!(new_results[0].severity, Severity::Info);
+        assert_eq!(
+            new_results[0].message,
+            "Entrada de baseline seguida tras mover fn login de src/auth.ts a src/session.ts."
+        );
+        let checked = [
+            PathBuf::from("src/auth.ts"),
+            PathBuf::from("src/session.ts"),
+        ];
+        assert!(unused_entries(&moved, &baseline, &after, &checked).is_empty());
+
+        // Regenerar conserva la fecha; reescribir deja la entrada en su archivo nuevo
+        let mut regenerated = Baseline::from_results(&moved, &after);
+        regenerated.carry_over(&baseline);
+        assert_eq!(
+            regenerated.entries[0].created_at.as_deref(),
+            Some("2025-01-10")
+        );
+        assert_eq!(baseline.rewrite_moves(&moved, &after), 1);
+        assert_eq!(baseline.entries[0].file.as_deref(), Some("src/session.ts"));
+        let (new_results, filtered) = filter_baseline(&moved, &baseline, &after);
+        assert_eq!((new_results.len(), filtered), (0, 1));
+    }
+
+    #[test]
+    fn other_signatures_and_entries_without_one_are_not_followed() {
+        let baseline = Baseline::from_results(
+            &[located("src/auth.ts:4")],
+            &[login_in("src/auth.ts", "string")],
+        );
+        let moved = [located("src/session.ts:4")];
+        let (new_results, filtered) =
+            filter_baseline(&moved, &baseline, &[login_in("src/session.ts", "number")]);
+        assert_eq!((new_results.len(), filtered), (1, 0));
+        assert_eq!(new_results[0].rule, Rule::MissingDocSection);
+
+        let legacy = Baseline::from_results(&[located("src/auth.ts:4")], &[]);
+        let (_, filtered) =
+            filter_baseline(&moved, &legacy, &[login_in("src/session.ts", "string")]);
+        assert_eq!(filtered, 0);
+    }
+
+    #[test]
+    fn pruning_drops_only_the_entries_of_checked_files_that_match_nothing() {
+        let mut baseline = Baseline::from_results(
+            &[
+                located("src/auth.ts:4"),
+                located("src/other.ts:4"),
+                ValidationResult {
+                    function_name: Some("logout".into()),
+                    ..located("src/auth.ts:9")
+                },
+            ],
+            &[],
+        );
+        let checked = [PathBuf::from("src/auth.ts")];
+        assert_eq!(
+            baseline.prune(&[located("src/auth.ts:4")], &[], &checked),
+            1
+        );
+        let files: Vec<_> = baseline
+            .entries
+            .iter()
+            .map(|e| (e.function_name.as_deref(), e.file.as_deref()))
+            .collect();
+        assert_eq!(
+            files,
+            vec![
+                (Some("login"), Some("src/auth.ts")),
+                (Some("login"), Some("src/other.ts"))
+            ]
+        );
+    }
 }
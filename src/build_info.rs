@@ -0,0 +1,128 @@
+//! Qué binario se está ejecutando (`docsguard version --verbose`) y qué
+//! subsistemas opcionales trae.
+//!
+//! `interactive` (`scaffold`, `triage`) y `watch` son features de cargo
+//! activas por defecto. Un binario mínimo (`--no-default-features`) conserva
+//! los subcomandos en la CLI, pero al invocarlos responde con un error de uso
+//! en lugar de dejar de compilar a quien los llama. `verify-site` (`check
+//! --verify-site`, el único acceso a la red fuera de CI) es opt-in.
+
+use crate::exit::Failure;
+
+/// Feature de cargo opcional.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature {
+    Interactive,
+    Watch,
+    VerifySite,
+}
+
+impl Feature {
+    pub const ALL: [Feature; 3] = [Feature::Interactive, Feature::Watch, Feature::VerifySite];
+
+    /// Nombre de la feature en Cargo.toml.
+    pub fn name(self) -> &'static str {
+        match self {
+            Feature::Interactive => "interactive",
+            Feature::Watch => "watch",
+            Feature::VerifySite => "verify-site",
+        }
+    }
+
+    pub fn enabled(self) -> bool {
+        match self {
+            Feature::Interactive => cfg!(feature = "interactive"),
+            Feature::Watch => cfg!(feature = "watch"),
+            Feature::VerifySite => cfg!(feature = "verify-site"),
+        }
+    }
+
+    /// Error de un subcomando cuya feature no se compiló.
+    #[cfg_attr(
+        all(feature = "interactive", feature = "watch", feature = "verify-site"),
+        allow(dead_code)
+    )]
+    pub fn unavailable(self) -> anyhow::Error {
+        Failure::usage(format!(
+            "Este binario se compiló sin soporte de {}.",
+            self.name()
+        ))
+        .with_hint(format!(
+            "Usa un binario completo o recompila con `cargo build --features {}`.",
+            self.name()
+        ))
+        .into()
+    }
+}
+
+/// Línea de `docsguard --version`.
+pub fn short_version() -> String {
+    format!("docsguard {}", env!("CARGO_PKG_VERSION"))
+}
+
+/// Datos de compilación, una clave por línea.
+pub fn verbose_version() -> String {
+    let enabled: Vec<&str> = Feature::ALL
+        .iter()
+        .filter(|f| f.enabled())
+        .map(|f| f.name())
+        .collect();
+    let mut out = short_version();
+    out.push('\n');
+    out.push_str(&format!("commit:    {}\n", env!("DOCSGUARD_GIT_COMMIT")));
+    out.push_str(&format!("target:    {}\n", env!("DOCSGUARD_TARGET")));
+    out.push_str(&format!("profile:   {}\n", env!("DOCSGUARD_PROFILE")));
+    out.push_str(&format!(
+        "features:  {}\n",
+        if enabled.is_empty() {
+            "(ninguna)".to_string()
+        } else {
+            enabled.join(", ")
+        }
+    ));
+    out.push_str(&format!("esquemas:  {}\n", crate::schema::versions()));
+    out.push_str("gramáticas:\n");
+    for grammar in env!("DOCSGUARD_GRAMMARS")
+        .split(',')
+        .filter(|g| !g.is_empty())
+    {
+        out.push_str(&format!("  {}\n", grammar));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exit::{exit_code_for, USAGE};
+
+    #[test]
+    fn verbose_version_lists_build_data_and_every_embedded_grammar() {
+        let out = verbose_version();
+        assert!(out.starts_with(&format!("docsguard {}\n", env!("CARGO_PKG_VERSION"))));
+        for key in ["commit:", "target:", "profile:", "features:", "esquemas:"] {
+            assert!(out.contains(key), "falta '{}' en:\n{}", key, out);
+        }
+        for grammar in [
+            "tree-sitter ",
+            "tree-sitter-typescript ",
+            "tree-sitter-rust ",
+            "tree-sitter-python ",
+            "tree-sitter-go ",
+            "tree-sitter-java ",
+            "tree-sitter-c-sharp ",
+        ] {
+            assert!(out.contains(&format!("  {}", grammar)), "falta {grammar}");
+        }
+        assert!(!out.contains("tree-sitter-language"));
+    }
+
+    #[test]
+    fn missing_features_are_usage_errors_naming_the_subsystem() {
+        let error = Feature::Watch.unavailable();
+        assert_eq!(exit_code_for(&error), USAGE);
+        assert!(error
+            .to_string()
+            .starts_with("Este binario se compiló sin soporte de watch."));
+    }
+}
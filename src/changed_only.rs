@@ -0,0 +1,181 @@
+//! `--changed-only [BASE]`: solo lo que toca el diff.
+//!
+//! Las líneas cambiadas salen de `git diff --unified=0` contra el merge-base
+//! de `BASE` (por defecto `HEAD`: lo que está sin commitear), y los archivos
+//! sin seguimiento cuentan enteros. Una función se valida si algún cambio cae
+//! entre su bloque de comentarios y atributos (donde vive el `@docs`) y el
+//! cierre de su cuerpo; una sección, si cae entre su ancla y la siguiente
+//! sección del archivo, o si la enlaza una función que se valida. La vista es
+//! parcial: las secciones enlazadas desde código sin cambios parecerían
+//! huérfanas, así que `orphan-section` no se informa.
+
+use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::core::types::{CodeEntity, DocSection, Rule, ValidationResult};
+use crate::exit::Failure;
+use crate::git::{self, ChangedLines};
+use crate::parser::code_parser::safe_display;
+use crate::paths::ProjectPaths;
+use crate::vfs;
+
+/// Comienzos de línea del bloque que precede a una declaración: comentarios,
+/// atributos (`#[…]`, `[…]`) y decoradores (`@…`).
+const LEADING_MARKERS: &[&str] = &["//", "/*", "*", "#", "@", "["];
+
+/// Líneas cambiadas desde `base`, por ruta canónica.
+pub struct ChangedView {
+    pub base: String,
+    lines: HashMap<PathBuf, ChangedLines>,
+}
+
+impl ChangedView {
+    /// Diff de `project_root` contra `base`; fuera de git es un error de uso.
+    pub fn load(project_root: &Path, base: &str) -> Result<Self> {
+        if !git::is_repository(project_root) {
+            anyhow::bail!(Failure::usage(format!(
+                "--changed-only necesita un repositorio git y {} no está en ninguno.",
+                safe_display(project_root)
+            ))
+            .with_hint("Ejecuta desde un checkout de git o quita --changed-only."));
+        }
+        Ok(ChangedView {
+            base: base.to_string(),
+            lines: git::changed_lines(project_root, base)?,
+        })
+    }
+
+    fn lines_of(&self, file: &Path) -> Option<&ChangedLines> {
+        self.lines.get(&vfs::canonicalize(file).ok()?)
+    }
+}
+
+/// Primera línea del bloque de comentarios y atributos pegado a la línea
+/// `line` (desde 1), sin bajar de `floor`.
+fn leading_block_start(source: &[&str], line: usize, floor: usize) -> usize {
+    let mut start = line;
+    while start > floor.max(1) {
+        let previous = source.get(start - 2).map_or("", |l| l.trim_start());
+        if !LEADING_MARKERS.iter().any(|m| previous.starts_with(m)) {
+            break;
+        }
+        start -= 1;
+    }
+    start
+}
+
+/// Funciones y secciones que deja la vista, en su orden.
+pub fn filter(
+    view: &ChangedView,
+    code_entities: Vec<CodeEntity>,
+    doc_sections: Vec<DocSection>,
+    paths: &ProjectPaths,
+) -> (Vec<CodeEntity>, Vec<DocSection>) {
+    let mut sources: HashMap<PathBuf, Option<String>> = HashMap::new();
+    // Cierre de la función anterior de cada archivo: el bloque no la invade
+    let mut previous_end: HashMap<PathBuf, usize> = HashMap::new();
+    let mut by_file: Vec<&CodeEntity> = code_entities.iter().collect();
+    by_file.sort_by(|a, b| (&a.file_path, a.line).cmp(&(&b.file_path, b.line)));
+    let mut touched = HashSet::new();
+    for entity in by_file {
+        let file = paths.resolve(&entity.file_path);
+        let end = entity.end_line.max(entity.line);
+        let floor = previous_end
+            .insert(entity.file_path.clone(), end)
+            .map_or(1, |end| end + 1);
+        let Some(lines) = view.lines_of(&file) else {
+            continue;
+        };
+        let source = sources
+            .entry(file.clone())
+            .or_insert_with(|| vfs::read_to_string(&file).ok());
+        let start = match source {
+            Some(source) => {
+                let source: Vec<&str> = source.lines().collect();
+                leading_block_start(&source, entity.line, floor)
+            }
+            None => entity.line,
+        };
+        if lines.touches(start, end) {
+            touched.insert((entity.file_path.clone(), entity.line));
+        }
+    }
+    let code_entities: Vec<CodeEntity> = code_entities
+        .into_iter()
+        .filter(|e| touched.contains(&(e.file_path.clone(), e.line)))
+        .collect();
+
+    let linked: HashSet<&str> = code_entities
+        .iter()
+        .flat_map(|e| {
+            [
+                e.doc_id.as_deref(),
+                e.file_link.as_ref().map(|l| l.doc_id.as_str()),
+            ]
+        })
+        .flatten()
+        .collect();
+    // Cada sección llega hasta la siguiente de su archivo
+    let mut starts: HashMap<&Path, Vec<usize>> = HashMap::new();
+    for section in &doc_sections {
+        starts
+            .entry(section.file_path.as_path())
+            .or_default()
+            .push(section.line);
+    }
+    for lines in starts.values_mut() {
+        lines.sort_unstable();
+    }
+    let kept: Vec<bool> = doc_sections
+        .iter()
+        .map(|section| {
+            if linked.contains(section.id.as_str()) {
+                return true;
+            }
+            let Some(lines) = view.lines_of(&paths.resolve(&section.file_path)) else {
+                return false;
+            };
+            let end = starts[section.file_path.as_path()]
+                .iter()
+                .find(|&&start| start > section.line)
+                .map_or(usize::MAX, |next| next - 1);
+            lines.touches(section.line, end)
+        })
+        .collect();
+    let doc_sections = doc_sections
+        .into_iter()
+        .zip(kept)
+        .filter_map(|(section, kept)| kept.then_some(section))
+        .collect();
+    (code_entities, doc_sections)
+}
+
+/// Quita los hallazgos que solo tienen sentido con la vista completa.
+pub fn drop_partial_view_findings(results: &mut Vec<ValidationResult>) {
+    results.retain(|r| r.rule != Rule::OrphanSection);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_leading_block_covers_comments_and_attributes_down_to_the_floor() {
+        let source = [
+            "fn before() {}",
+            "",
+            "/// @docs: [login]",
+            "#[inline]",
+            "pub fn login() {",
+            "}",
+            "// suelto",
+            "fn after() {}",
+        ];
+        assert_eq!(leading_block_start(&source, 5, 1), 3);
+        // Sin línea en blanco, el comentario pegado a `after` ya es suyo
+        assert_eq!(leading_block_start(&source, 8, 7), 7);
+        assert_eq!(leading_block_start(&source, 8, 8), 8);
+        assert_eq!(leading_block_start(&source, 1, 1), 1);
+    }
+}
@@ -0,0 +1,454 @@
+//! `docsguard check-all`: todo el repositorio en una ejecución.
+//!
+//! Recorre la raíz (sin directorios ocultos, `node_modules` ni `target`),
+//! toma como código cada archivo con lenguaje soportado y como docs cada
+//! `.md`, y los valida juntos como `check --doc`: una sola validación, el
+//! baseline de la raíz y el resumen de siempre. Cada archivo se lee una vez;
+//! el mismo contenido sirve para parsear y para las anclas y atestaciones
+//! de las docs. Un archivo que no se puede leer o parsear se informa y se
+//! cuenta, pero no aborta el resto: la ejecución termina y sale con 1.
+//! Con `--changed-only`, solo lo que toca el diff (ver `changed_only`). Con
+//! `pairing: colocated-readme`, cada archivo de docs se valida con el código
+//! emparejado con él, y el resto junto (ver `pairing`).
+
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::baseline;
+use crate::changed_only;
+use crate::config::{Config, InfoAggregation, Pairing};
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::pipeline;
+use crate::core::types::{CodeEntity, DocSection, Severity};
+use crate::exit::{FailOn, Failure, Outcome};
+use crate::layout::{Layout, Style};
+use crate::messages::{self, println_tr};
+use crate::pairing;
+use crate::parser::code_parser::{self, safe_display, Language};
+use crate::parser::doc_parser;
+use crate::paths;
+use crate::report::{Report, Verbosity};
+
+/// Opciones de `check-all`.
+pub struct CheckAllOptions<'a> {
+    pub verbosity: Verbosity,
+    pub fail_on: FailOn,
+    pub layout: Layout,
+    /// `--changed-only`: la referencia base del diff.
+    pub changed_only: Option<&'a str>,
+}
+
+/// Archivos que encontró `discover`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Discovered {
+    pub code: Vec<(PathBuf, Language)>,
+    pub docs: Vec<PathBuf>,
+}
+
+/// Código y docs bajo `root`, en orden estable.
+pub fn discover(root: &Path) -> Result<Discovered> {
+    let mut found = Discovered::default();
+    for path in paths::walk_repository(root)? {
+        if path.extension().is_some_and(|ext| ext == "md") {
+            found.docs.push(path);
+        } else if let Ok(language) = Language::from_extension(&path) {
+            found.code.push((path, language));
+        }
+    }
+    Ok(found)
+}
+
+/// Archivo de código parseado, con sus notas.
+struct ParsedCode<'a> {
+    file: &'a PathBuf,
+    entities: Vec<CodeEntity>,
+    diagnostics: ParseDiagnostics,
+}
+
+/// Archivo de docs parseado; el contenido sirve para las anclas.
+struct ParsedDoc<'a> {
+    file: &'a Path,
+    source: String,
+    sections: Vec<DocSection>,
+    diagnostics: ParseDiagnostics,
+}
+
+/// Lo que se valida junto: con `pairing`, un archivo de docs y el código
+/// emparejado con él, o lo que queda sin par (`doc: None`).
+struct Group<'p, 'a> {
+    doc: Option<&'a Path>,
+    /// Código sin par validado contra docs ajenas: sus huérfanas las
+    /// informan los grupos de esas docs.
+    borrowed_docs: bool,
+    code: Vec<&'p ParsedCode<'a>>,
+    docs: Vec<&'p ParsedDoc<'a>>,
+}
+
+impl Group<'_, '_> {
+    /// Cabecera del grupo en la salida, con el código del que sale cada
+    /// hallazgo.
+    fn header(&self, config: &Config) -> String {
+        let list = |files: &mut dyn Iterator<Item = &Path>, none: &str| {
+            let files: Vec<String> = files
+                .map(|f| safe_display(&config.paths.normalize(f)).to_string())
+                .collect();
+            match files.is_empty() {
+                true => none.to_string(),
+                false => files.join(", "),
+            }
+        };
+        let code = list(&mut self.code.iter().map(|c| c.file.as_path()), "ninguno");
+        let line = match self.doc {
+            Some(doc) => format!(
+                "  [par] {} ← {}",
+                safe_display(&config.paths.normalize(doc)),
+                code
+            ),
+            None => format!(
+                "  [sin par] código: {}; docs: {}",
+                code,
+                list(&mut self.docs.iter().map(|d| d.file), "ninguna")
+            ),
+        };
+        format!("{}\n", messages::tr(&line))
+    }
+}
+
+/// Un grupo por archivo de docs emparejado, por ruta, y al final el código
+/// sin par con las docs que no son par de nadie (o con todas, si no queda
+/// ninguna).
+fn group_by_pair<'p, 'a>(
+    pairs: &[pairing::Pair],
+    parsed_code: &'p [ParsedCode<'a>],
+    parsed_docs: &'p [ParsedDoc<'a>],
+) -> Vec<Group<'p, 'a>> {
+    let mut paired: BTreeMap<&Path, Vec<&ParsedCode>> = BTreeMap::new();
+    let mut rest = Group {
+        doc: None,
+        borrowed_docs: false,
+        code: Vec::new(),
+        docs: Vec::new(),
+    };
+    for (pair, code) in pairs.iter().zip(parsed_code) {
+        let doc = pair
+            .doc
+            .as_ref()
+            .and_then(|(doc, _)| parsed_docs.iter().find(|d| d.file == doc));
+        match doc {
+            Some(doc) => paired.entry(doc.file).or_default().push(code),
+            None => rest.code.push(code),
+        }
+    }
+    rest.docs = parsed_docs
+        .iter()
+        .filter(|d| !paired.contains_key(d.file))
+        .collect();
+    // Sin docs que no sean par de nadie, el código sin par usa todas
+    if rest.docs.is_empty() && !rest.code.is_empty() {
+        rest.docs = parsed_docs.iter().collect();
+        rest.borrowed_docs = true;
+    }
+    let mut groups: Vec<Group> = paired
+        .into_iter()
+        .map(|(doc, code)| Group {
+            doc: Some(doc),
+            borrowed_docs: false,
+            code,
+            docs: parsed_docs.iter().filter(|d| d.file == doc).collect(),
+        })
+        .collect();
+    if !rest.code.is_empty() || !rest.docs.is_empty() {
+        groups.push(rest);
+    }
+    groups
+}
+
+/// Archivo que no se pudo leer o parsear, con el motivo.
+struct ParseFailure {
+    file: String,
+    reason: String,
+}
+
+/// Ejecuta `check-all` sobre `root`, que también es la raíz del proyecto
+/// (configuración y baseline).
+pub fn run_check_all(root: &Path, options: CheckAllOptions) -> Result<Outcome> {
+    let CheckAllOptions {
+        verbosity,
+        fail_on,
+        layout,
+        changed_only,
+    } = options;
+    let config = Config::load(root)?;
+    let changed = changed_only
+        .map(|base| changed_only::ChangedView::load(root, base))
+        .transpose()?;
+    let found = discover(root)?;
+    if found.docs.is_empty() {
+        anyhow::bail!(Failure::input(format!(
+            "No hay archivos Markdown bajo {}.",
+            safe_display(root)
+        ))
+        .with_hint("check-all toma como docs cada `.md` fuera de los directorios ocultos, `node_modules` y `target`."));
+    }
+
+    if verbosity != Verbosity::Quiet {
+        println_tr!(
+            "DocsGuard — Verificando el repositorio {}\n",
+            safe_display(root)
+        );
+        println_tr!(
+            "  Código: {}; docs: {}\n",
+            messages::FILES.count(found.code.len()),
+            messages::FILES.count(found.docs.len())
+        );
+    }
+
+    let mut failures = Vec::new();
+    let mut fail = |file: &Path, error: anyhow::Error| {
+        failures.push(ParseFailure {
+            file: safe_display(&config.paths.normalize(file)).to_string(),
+            reason: format!("{:#}", error),
+        })
+    };
+    // Cada archivo con sus notas, para poder validarlos por grupos
+    let mut parsed_code = Vec::new();
+    for (file, language) in &found.code {
+        let mut diagnostics = ParseDiagnostics::default();
+        match code_parser::read_source_file(file).and_then(|source| {
+            code_parser::parse_project_source(&source, *language, file, &config, &mut diagnostics)
+        }) {
+            Ok(entities) => parsed_code.push(ParsedCode {
+                file,
+                entities,
+                diagnostics,
+            }),
+            Err(error) => fail(file, error),
+        }
+    }
+    let pairs = match config.pairing {
+        Pairing::ColocatedReadme => {
+            let files: Vec<PathBuf> = parsed_code.iter().map(|c| c.file.clone()).collect();
+            pairing::resolve(root, &files, &config)?
+        }
+        Pairing::Off => Vec::new(),
+    };
+    // Un par fuera del recorrido (p. ej. el `doc_file` de una combinación)
+    // también se parsea
+    let mut doc_files = found.docs.clone();
+    for (doc, _) in pairs.iter().filter_map(|pair| pair.doc.as_ref()) {
+        if !doc_files.contains(doc) {
+            doc_files.push(doc.clone());
+        }
+    }
+    // El contenido se guarda para las anclas y las atestaciones
+    let mut parsed_docs = Vec::new();
+    for file in &doc_files {
+        let mut diagnostics = ParseDiagnostics::default();
+        match doc_parser::read_markdown_file(file).and_then(|source| {
+            let sections = doc_parser::parse_docs_source(&source, file, &config, &mut diagnostics)?;
+            Ok((source, sections))
+        }) {
+            Ok((source, sections)) => parsed_docs.push(ParsedDoc {
+                file,
+                source,
+                sections,
+                diagnostics,
+            }),
+            Err(error) => fail(file, error),
+        }
+    }
+    for failure in &failures {
+        println!(
+            "{}",
+            layout.paint(
+                Style::Error,
+                &messages::tr(&format!(
+                    "  [X] No se pudo parsear {}: {}",
+                    failure.file, failure.reason
+                ))
+            )
+        );
+    }
+    if !failures.is_empty() {
+        println!();
+    }
+    if parsed_docs.is_empty() {
+        println_tr!("  Ningún archivo de docs se pudo parsear: no hay nada que validar.");
+        return Ok(Outcome::Findings);
+    }
+
+    let groups = match config.pairing {
+        Pairing::Off => vec![Group {
+            doc: None,
+            borrowed_docs: false,
+            code: parsed_code.iter().collect(),
+            docs: parsed_docs.iter().collect(),
+        }],
+        Pairing::ColocatedReadme => group_by_pair(&pairs, &parsed_code, &parsed_docs),
+    };
+    let mut validated = Vec::new();
+    let mut totals = (0, 0, 0, 0);
+    for group in &groups {
+        let mut code_entities: Vec<CodeEntity> = group
+            .code
+            .iter()
+            .flat_map(|c| c.entities.iter().cloned())
+            .collect();
+        let mut doc_sections: Vec<DocSection> = group
+            .docs
+            .iter()
+            .flat_map(|d| d.sections.iter().cloned())
+            .collect();
+        let mut diagnostics = ParseDiagnostics::default();
+        for parsed in group.code.iter().map(|c| &c.diagnostics) {
+            diagnostics.merge(parsed.clone());
+        }
+        for parsed in group.docs.iter().map(|d| &d.diagnostics) {
+            diagnostics.merge(parsed.clone());
+        }
+        if let Some(view) = &changed {
+            totals.1 += code_entities.len();
+            totals.3 += doc_sections.len();
+            (code_entities, doc_sections) =
+                changed_only::filter(view, code_entities, doc_sections, &config.paths);
+            totals.0 += code_entities.len();
+            totals.2 += doc_sections.len();
+        }
+        let mut docs: Vec<pipeline::DocSource> = group
+            .docs
+            .iter()
+            .map(|d| pipeline::DocSource {
+                file: d.file,
+                source: &d.source,
+            })
+            .collect();
+        // Las secciones que faltan van al final del primero que ya tiene alguna
+        if let Some(first) = group.docs.iter().position(|d| !d.sections.is_empty()) {
+            docs[..=first].rotate_right(1);
+        }
+        let mut validation = pipeline::run_validation(
+            pipeline::Inputs {
+                code_entities: &code_entities,
+                doc_sections: &doc_sections,
+                diagnostics: &diagnostics,
+                docs: &docs,
+                config: &config,
+            },
+            pipeline::Passes::default(),
+        );
+        if changed.is_some() || group.borrowed_docs {
+            changed_only::drop_partial_view_findings(&mut validation.results);
+        }
+        validated.push((validation.results, code_entities, doc_sections));
+    }
+    if let Some(view) = &changed {
+        if verbosity != Verbosity::Quiet {
+            println_tr!(
+                "  [changed-only] Solo lo tocado desde '{}': {} de {}, {} de {}.\n",
+                view.base,
+                messages::FUNCTIONS.count(totals.0),
+                messages::Locale::CANONICAL.number(totals.1),
+                messages::SECTIONS.count(totals.2),
+                messages::Locale::CANONICAL.number(totals.3)
+            );
+        }
+    }
+
+    let baseline = baseline::Baseline::load(root)?;
+    let reports: Vec<Report> = validated
+        .into_iter()
+        .map(|(results, code_entities, doc_sections)| {
+            Report::filtered(
+                results,
+                baseline.as_ref(),
+                InfoAggregation::Off,
+                &code_entities,
+                &doc_sections,
+            )
+        })
+        .collect();
+    let baseline_filtered: usize = reports.iter().map(|r| r.baseline_filtered).sum();
+    if baseline_filtered > 0 {
+        println_tr!(
+            "  [baseline] {}.\n",
+            messages::KNOWN_FINDINGS_FILTERED.count(baseline_filtered)
+        );
+    }
+
+    let verbose = verbosity == Verbosity::Verbose;
+    for (group, report) in groups.iter().zip(&reports) {
+        if config.pairing == Pairing::ColocatedReadme && verbosity != Verbosity::Quiet {
+            print!("{}", group.header(&config));
+        }
+        for result in report.results.iter().filter(|r| verbosity.shows(r)) {
+            print!("{}", result.render(&layout, verbose));
+        }
+    }
+    let results: Vec<_> = reports.iter().flat_map(|r| &r.results).collect();
+    let errors = results
+        .iter()
+        .filter(|r| r.severity == Severity::Error)
+        .count();
+    let warnings = results
+        .iter()
+        .filter(|r| r.severity == Severity::Warning)
+        .count();
+    println!("---");
+    println_tr!(
+        "Resumen: {}, {} total · umbral: {}",
+        messages::summary(errors, warnings),
+        messages::Locale::CANONICAL.number(results.len()),
+        fail_on.name()
+    );
+    if !failures.is_empty() {
+        println_tr!("  {} sin parsear.", messages::FILES.count(failures.len()));
+    }
+    Ok(Outcome::failed_if(
+        fail_on.exceeded(errors, warnings) || !failures.is_empty(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::MemFs;
+
+    #[test]
+    fn discovery_skips_hidden_vendored_and_unsupported_files() {
+        let dir = MemFs::project();
+        let root = dir.path();
+        for file in [
+            "README.md",
+            "docs/api.md",
+            "src/api.ts",
+            "src/view.tsx",
+            "src/lib.rs",
+            "src/notes.txt",
+            "node_modules/pkg/index.ts",
+            "node_modules/pkg/README.md",
+            "target/debug/build.rs",
+            ".github/CONTRIBUTING.md",
+        ] {
+            dir.write(root.join(file), "");
+        }
+        let found = discover(root).unwrap();
+        let relative = |path: &Path| path.strip_prefix(root).unwrap().to_path_buf();
+        assert_eq!(
+            found.docs.iter().map(|p| relative(p)).collect::<Vec<_>>(),
+            [PathBuf::from("README.md"), PathBuf::from("docs/api.md")]
+        );
+        assert_eq!(
+            found
+                .code
+                .iter()
+                .map(|(p, _)| relative(p))
+                .collect::<Vec<_>>(),
+            [
+                PathBuf::from("src/api.ts"),
+                PathBuf::from("src/lib.rs"),
+                PathBuf::from("src/view.tsx")
+            ]
+        );
+    }
+}
@@ -0,0 +1,453 @@
+//! Modo GitHub Actions (`docsguard ci github`).
+//!
+//! - Limita la verificación a los archivos cambiados respecto a la rama base de la PR.
+//! - Emite anotaciones `::error` / `::warning` sobre el diff.
+//! - Escribe el resumen Markdown en `$GITHUB_STEP_SUMMARY`.
+//! - Con `--pr-comment`, crea o actualiza un único comentario fijo en la PR.
+//!
+//! Los fallos de red nunca rompen el job: se degradan a una advertencia y
+//! el resumen del paso sigue escribiéndose.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::config::{Config, InfoAggregation};
+use crate::core::types::{parse_location, Severity, ValidationResult};
+use crate::exit::Outcome;
+use crate::git;
+use crate::messages;
+use crate::messages::{eprintln_tr, println_tr};
+use crate::parser::code_parser::{self, safe_display};
+use crate::report::{self, markdown, redact::Redaction};
+use crate::vfs;
+
+/// Marcador oculto que identifica el comentario fijo de DocsGuard en la PR.
+pub const COMMENT_MARKER: &str = "<!-- docsguard:pr-comment -->";
+
+/// Tiempo máximo por petición a la API de GitHub.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(15);
+/// Páginas de comentarios (100 por página) revisadas buscando el marcador.
+const MAX_COMMENT_PAGES: u32 = 10;
+
+/// Variables de entorno de GitHub Actions relevantes para DocsGuard.
+#[derive(Debug, Clone, Default)]
+pub struct GithubEnv {
+    /// `GITHUB_ACTIONS=true`.
+    pub in_actions: bool,
+    /// `GITHUB_STEP_SUMMARY`: archivo donde se añade el resumen del paso.
+    pub step_summary: Option<PathBuf>,
+    /// `GITHUB_TOKEN`: necesario solo para el comentario en la PR.
+    pub token: Option<String>,
+    /// `GITHUB_REPOSITORY` (`org/repo`).
+    pub repository: Option<String>,
+    /// `GITHUB_SERVER_URL`.
+    pub server_url: String,
+    /// `GITHUB_API_URL`.
+    pub api_url: String,
+    /// `GITHUB_SHA`: revisión usada en los enlaces del resumen.
+    pub sha: Option<String>,
+    /// `GITHUB_BASE_REF`: rama base de la PR (vacía fuera de `pull_request`).
+    pub base_ref: Option<String>,
+    /// Número de PR, extraído de `GITHUB_REF` (`refs/pull/<n>/merge`).
+    pub pr_number: Option<u64>,
+    /// `GITHUB_WORKSPACE`: raíz del checkout.
+    pub workspace: Option<PathBuf>,
+}
+
+impl GithubEnv {
+    /// Lee el entorno del proceso.
+    pub fn from_env() -> Self {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    /// Construye el entorno a partir de una función de consulta (testeable).
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let get = |key: &str| lookup(key).filter(|v| !v.is_empty());
+        GithubEnv {
+            in_actions: get("GITHUB_ACTIONS").as_deref() == Some("true"),
+            step_summary: get("GITHUB_STEP_SUMMARY").map(PathBuf::from),
+            token: get("GITHUB_TOKEN"),
+            repository: get("GITHUB_REPOSITORY"),
+            server_url: get("GITHUB_SERVER_URL").unwrap_or_else(|| "https://github.com".into()),
+            api_url: get("GITHUB_API_URL").unwrap_or_else(|| "https://api.github.com".into()),
+            sha: get("GITHUB_SHA"),
+            base_ref: get("GITHUB_BASE_REF"),
+            pr_number: get("GITHUB_REF").as_deref().and_then(parse_pr_number),
+            workspace: get("GITHUB_WORKSPACE").map(PathBuf::from),
+        }
+    }
+
+    /// Enlaces al código en la revisión actual, si el entorno lo permite.
+    fn source_links(&self) -> Option<markdown::SourceLinks> {
+        let repository = self.repository.as_ref()?;
+        let sha = self.sha.as_ref()?;
+        Some(markdown::SourceLinks {
+            base_url: format!(
+                "{}/{}/blob/{}",
+                self.server_url.trim_end_matches('/'),
+                repository,
+                sha
+            ),
+            root: self.workspace_root(),
+        })
+    }
+
+    fn workspace_root(&self) -> PathBuf {
+        self.workspace
+            .clone()
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Extrae el número de PR de `refs/pull/<n>/merge` (o `/head`).
+fn parse_pr_number(git_ref: &str) -> Option<u64> {
+    git_ref
+        .strip_prefix("refs/pull/")?
+        .split('/')
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// Ejecuta la verificación en modo GitHub Actions.
+///
+/// `base_ref` sobrescribe la rama base detectada (`origin/$GITHUB_BASE_REF`).
+/// `Outcome::Findings` si quedan errores.
+pub fn run_github(
+    code_files: &[PathBuf],
+    doc_file: &Path,
+    project_root: &Path,
+    base_ref: Option<&str>,
+    pr_comment: bool,
+    redact_descriptions: bool,
+    no_aggregate: bool,
+) -> Result<Outcome> {
+    for code_file in code_files {
+        code_parser::require_file_exists(code_file, "código")?;
+    }
+    code_parser::require_file_exists(doc_file, "documentación")?;
+
+    let env = GithubEnv::from_env();
+    if !env.in_actions {
+        eprintln_tr!("  [ci] GITHUB_ACTIONS no detectado: se emiten anotaciones solo por stdout.");
+    }
+
+    let mut report = report::build_report_with(
+        code_files,
+        doc_file,
+        project_root,
+        no_aggregate.then_some(InfoAggregation::Off),
+    )?;
+    println_tr!(
+        "  [ci] En código: {}{}; en docs: {}{}.",
+        messages::FUNCTIONS.count(report.entity_count),
+        report::ignored_note(report.ignored_functions),
+        messages::SECTIONS.count(report.section_count),
+        report::prose_only_note(report.prose_only_links)
+    );
+
+    let base = base_ref
+        .map(String::from)
+        .or_else(|| env.base_ref.as_ref().map(|b| format!("origin/{}", b)));
+    if let Some(ref base) = base {
+        match git::changed_files(project_root, base) {
+            Ok(changed) => report.retain_changed(&changed, project_root),
+            Err(e) => println_tr!(
+                "::warning::{}",
+                escape_data(&format!(
+                    "DocsGuard: no se pudo calcular el diff contra '{}', se reporta todo: {:#}",
+                    base, e
+                ))
+            ),
+        }
+    }
+
+    // Todo lo que sigue sale de la máquina: anotaciones, resumen y comentario
+    let redaction = Redaction::new(&Config::load(project_root)?.redaction, redact_descriptions);
+    let report = redaction.apply(&report).localized();
+
+    let root = env.workspace_root();
+    for finding in &report.results {
+        if let Some(annotation) = annotation(finding, &root) {
+            println!("{}", annotation);
+        }
+    }
+
+    let summary =
+        messages::tr(&markdown::render(&report, env.source_links().as_ref())).into_owned();
+    match env.step_summary {
+        Some(ref path) => append_step_summary(path, &summary)?,
+        None => println_tr!("\n{}", summary),
+    }
+
+    if pr_comment {
+        match upsert_pr_comment(&env, &summary) {
+            Ok(action) => println_tr!("  [ci] Comentario de la PR {}.", action),
+            Err(e) => println_tr!(
+                "::warning::{}",
+                escape_data(&format!(
+                    "DocsGuard: no se pudo publicar el comentario en la PR (solo resumen): {:#}",
+                    e
+                ))
+            ),
+        }
+    }
+
+    let errors = report.count(Severity::Error);
+    println_tr!(
+        "Resumen: {}",
+        messages::summary(errors, report.count(Severity::Warning))
+    );
+    Ok(Outcome::failed_if(errors > 0))
+}
+
+/// Comando de workflow `::error` / `::warning` para un hallazgo.
+/// Los hallazgos informativos no generan anotación.
+fn annotation(finding: &ValidationResult, root: &Path) -> Option<String> {
+    let command = match finding.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => return None,
+    };
+
+    let mut message = finding.message.clone();
+    for related in &finding.related {
+        message.push_str(&format!("\n· {}: {}", related.location, related.message));
+    }
+    if let Some(ref target) = finding.target {
+        message.push_str(&format!("\nInsertar en: {}", target));
+    }
+    if let Some(ref hint) = finding.hint {
+        message.push_str("\nSugerencia: ");
+        message.push_str(hint);
+    }
+
+    let title = format!("DocsGuard ({})", finding.rule);
+    let location = finding
+        .code_location
+        .as_deref()
+        .or(finding.doc_location.as_deref())
+        .and_then(parse_location);
+
+    Some(match location {
+        Some((path, line)) => format!(
+            "::{} file={},line={},title={}::{}",
+            command,
+            escape_property(&workspace_relative(path, root)),
+            line,
+            escape_property(&title),
+            escape_data(&messages::tr(&message))
+        ),
+        None => format!(
+            "::{} title={}::{}",
+            command,
+            escape_property(&title),
+            escape_data(&messages::tr(&message))
+        ),
+    })
+}
+
+/// Ruta relativa al workspace, como la esperan las anotaciones.
+fn workspace_relative(path: &Path, root: &Path) -> String {
+    let relative = path
+        .strip_prefix(root)
+        .or_else(|_| path.strip_prefix("./"))
+        .unwrap_or(path);
+    relative.to_string_lossy().replace('\\', "/")
+}
+
+/// Escapa el mensaje de un comando de workflow.
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapa el valor de una propiedad de un comando de workflow.
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Añade el resumen al archivo de `$GITHUB_STEP_SUMMARY`.
+fn append_step_summary(path: &Path, summary: &str) -> Result<()> {
+    vfs::append(path, format!("{}\n", summary).as_bytes()).with_context(|| {
+        format!(
+            "No se pudo escribir el resumen del paso: {}",
+            safe_display(path)
+        )
+    })
+}
+
+/// Crea o actualiza el comentario fijo de la PR. Retorna la acción realizada.
+fn upsert_pr_comment(env: &GithubEnv, summary: &str) -> Result<&'static str> {
+    let token = env.token.as_deref().context("GITHUB_TOKEN no definido")?;
+    let repository = env
+        .repository
+        .as_deref()
+        .context("GITHUB_REPOSITORY no definido")?;
+    let pr = env
+        .pr_number
+        .context("GITHUB_REF no apunta a una pull request")?;
+
+    let api = env.api_url.trim_end_matches('/');
+    let agent = ureq::AgentBuilder::new().timeout(HTTP_TIMEOUT).build();
+    let request = |method: &str, url: &str| {
+        agent
+            .request(method, url)
+            .set("Authorization", &format!("Bearer {}", token))
+            .set("Accept", "application/vnd.github+json")
+            .set("X-GitHub-Api-Version", "2022-11-28")
+            .set("User-Agent", "docsguard")
+    };
+
+    let body = serde_json::json!({ "body": comment_body(summary) });
+
+    let mut existing = None;
+    for page in 1..=MAX_COMMENT_PAGES {
+        let url = format!(
+            "{}/repos/{}/issues/{}/comments?per_page=100&page={}",
+            api, repository, pr, page
+        );
+        let comments: Value = request("GET", &url)
+            .call()
+            .context("No se pudieron listar los comentarios")?
+            .into_json()
+            .context("Respuesta inválida al listar comentarios")?;
+        let comments = comments.as_array().cloned().unwrap_or_default();
+        if comments.is_empty() {
+            break;
+        }
+        if let Some(id) = find_marked_comment(&comments) {
+            existing = Some(id);
+            break;
+        }
+    }
+
+    match existing {
+        Some(id) => {
+            let url = format!("{}/repos/{}/issues/comments/{}", api, repository, id);
+            request("PATCH", &url)
+                .send_json(body)
+                .context("No se pudo actualizar el comentario")?;
+            Ok("actualizado")
+        }
+        None => {
+            let url = format!("{}/repos/{}/issues/{}/comments", api, repository, pr);
+            request("POST", &url)
+                .send_json(body)
+                .context("No se pudo crear el comentario")?;
+            Ok("creado")
+        }
+    }
+}
+
+/// Cuerpo del comentario: marcador oculto seguido del resumen.
+fn comment_body(summary: &str) -> String {
+    format!("{}\n{}", COMMENT_MARKER, summary)
+}
+
+/// ID del primer comentario que contiene el marcador de DocsGuard.
+fn find_marked_comment(comments: &[Value]) -> Option<u64> {
+    comments.iter().find_map(|c| {
+        let body = c.get("body")?.as_str()?;
+        if body.contains(COMMENT_MARKER) {
+            c.get("id")?.as_u64()
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Rule;
+    use std::collections::HashMap;
+
+    fn finding(severity: Severity, location: Option<&str>) -> ValidationResult {
+        ValidationResult {
+            severity,
+            rule: Rule::MissingArg,
+            message: "Falta 'id': 100%".into(),
+            function_name: Some("login".into()),
+            code_location: location.map(String::from),
+            doc_id: None,
+            doc_location: None,
+            hint: Some("Añade 'id'".into()),
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+
+    #[test]
+    fn env_is_read_from_lookup() {
+        let vars: HashMap<&str, &str> = [
+            ("GITHUB_ACTIONS", "true"),
+            ("GITHUB_REPOSITORY", "org/repo"),
+            ("GITHUB_SHA", "abc123"),
+            ("GITHUB_REF", "refs/pull/42/merge"),
+            ("GITHUB_BASE_REF", "main"),
+            ("GITHUB_WORKSPACE", "/work/repo"),
+            ("GITHUB_TOKEN", ""),
+        ]
+        .into_iter()
+        .collect();
+        let env = GithubEnv::from_lookup(|k| vars.get(k).map(|v| v.to_string()));
+
+        assert!(env.in_actions);
+        assert_eq!(env.pr_number, Some(42));
+        assert_eq!(env.base_ref.as_deref(), Some("main"));
+        assert_eq!(env.token, None, "un token vacío equivale a ausente");
+        assert_eq!(env.api_url, "https://api.github.com");
+        assert_eq!(
+            env.source_links().unwrap().base_url,
+            "https://github.com/org/repo/blob/abc123"
+        );
+    }
+
+    #[test]
+    fn push_events_have_no_pr_number() {
+        assert_eq!(parse_pr_number("refs/heads/main"), None);
+        assert_eq!(parse_pr_number("refs/pull/7/head"), Some(7));
+    }
+
+    #[test]
+    fn annotation_is_relative_and_escaped() {
+        let root = Path::new("/work/repo");
+        let error = finding(Severity::Error, Some("/work/repo/src/auth.ts:3"));
+        assert_eq!(
+            annotation(&error, root).unwrap(),
+            "::error file=src/auth.ts,line=3,title=DocsGuard (missing-arg)::Falta 'id': 100%25%0ASugerencia: Añade 'id'"
+        );
+
+        let warning = finding(Severity::Warning, None);
+        assert!(annotation(&warning, root)
+            .unwrap()
+            .starts_with("::warning title=DocsGuard (missing-arg)::"));
+
+        assert_eq!(annotation(&finding(Severity::Info, None), root), None);
+    }
+
+    #[test]
+    fn marked_comment_is_found_among_others() {
+        let comments: Vec<Value> = serde_json::from_str(&format!(
+            r#"[{{"id": 1, "body": "LGTM"}}, {{"id": 2, "body": "{}\n### DocsGuard"}}]"#,
+            COMMENT_MARKER
+        ))
+        .unwrap();
+        assert_eq!(find_marked_comment(&comments), Some(2));
+        assert_eq!(find_marked_comment(&comments[..1]), None);
+    }
+
+    #[test]
+    fn comment_body_starts_with_marker() {
+        assert!(comment_body("### DocsGuard").starts_with(COMMENT_MARKER));
+    }
+}
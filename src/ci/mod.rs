@@ -0,0 +1,8 @@
+//! Integraciones listas para usar con proveedores de CI.
+//!
+//! Cada proveedor ejecuta la misma verificación que `check`, la limita a los
+//! archivos cambiados en la PR y publica el `Report` en las superficies
+//! nativas del proveedor. El renderizado vive en `report`, no aquí.
+
+pub mod github;
+pub mod profile;
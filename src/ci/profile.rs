@@ -0,0 +1,425 @@
+//! Perfiles de CI (`profiles:` de `config.yaml`): `ci simulate` y `ci run`.
+//!
+//! Un perfil guarda la invocación de CI entera (docs, código, umbral,
+//! `--changed-since`, baseline, presupuesto, máximo de advertencias,
+//! cobertura mínima y formato), así que el job ejecuta `ci run --profile ci`
+//! y en local `ci simulate --profile ci` predice su resultado con la misma
+//! evaluación: el veredicto dice qué política haría fallar y con qué código
+//! de salida. La simulación escribe los artefactos en un directorio temporal
+//! y siempre termina con 0; `ci run` los escribe donde dice el perfil y sale
+//! con el código predicho.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::baseline;
+use crate::config::{CiProfile, Config};
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::pipeline;
+use crate::core::types::Severity;
+use crate::coverage;
+use crate::exit::Outcome;
+use crate::git;
+use crate::layout::{Layout, Style};
+use crate::messages::{self, println_tr};
+use crate::parser::code_parser::{self, safe_display};
+use crate::parser::doc_parser;
+use crate::paths;
+use crate::report::redact::Redaction;
+use crate::report::timing::{format_duration, Timings};
+use crate::report::{self, CheckFormat, Report, Verbosity};
+use crate::transaction;
+use crate::vfs;
+
+/// Artefacto de `format: sarif` sin `output:`.
+pub const DEFAULT_SARIF_OUTPUT: &str = "docsguard.sarif";
+/// Artefacto de `format: json` sin `output:`.
+pub const DEFAULT_JSON_OUTPUT: &str = "docsguard.json";
+/// Artefacto de `format: markdown` sin `output:`.
+pub const DEFAULT_MARKDOWN_OUTPUT: &str = "docsguard.md";
+
+/// Política que puede hacer fallar el job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    FailOn,
+    Budget,
+    MaxWarnings,
+    MinCoverage,
+}
+
+impl Policy {
+    /// Nombre en el veredicto, el de la clave del perfil con guiones.
+    pub fn name(self) -> &'static str {
+        match self {
+            Policy::FailOn => "fail-on",
+            Policy::Budget => "budget",
+            Policy::MaxWarnings => "max-warnings",
+            Policy::MinCoverage => "min-coverage",
+        }
+    }
+}
+
+/// Una política con lo medido frente a su límite; `None` si el perfil no la
+/// configura.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyCheck {
+    pub policy: Policy,
+    pub status: Option<PolicyStatus>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyStatus {
+    /// `0 errores`, `12 ms de 30 s`…
+    pub detail: String,
+    pub tripped: bool,
+}
+
+/// Resultado de ejecutar un perfil.
+pub struct Evaluation {
+    pub report: Report,
+    pub errors: usize,
+    pub warnings: usize,
+    pub elapsed: Duration,
+    pub policies: Vec<PolicyCheck>,
+    /// Artefacto del perfil, relativo a la raíz del proyecto.
+    pub artifact: Option<PathBuf>,
+    pub format: CheckFormat,
+    /// `redaction:` de la configuración: el artefacto sale de la máquina.
+    pub redaction: Redaction,
+}
+
+impl Evaluation {
+    /// Políticas que harían fallar el job.
+    pub fn tripped(&self) -> impl Iterator<Item = Policy> + '_ {
+        self.policies
+            .iter()
+            .filter(|check| check.status.as_ref().is_some_and(|s| s.tripped))
+            .map(|check| check.policy)
+    }
+
+    /// Código de salida del job.
+    pub fn exit_code(&self) -> u8 {
+        u8::from(self.tripped().next().is_some())
+    }
+}
+
+/// Ejecuta el perfil sobre `project_root`: validación, baseline,
+/// `changed_since`, presupuesto y cobertura, sin imprimir nada. Con
+/// `redact_descriptions`, el artefacto sale sin las descripciones documentadas.
+pub fn evaluate(
+    project_root: &Path,
+    profile: &CiProfile,
+    redact_descriptions: bool,
+) -> Result<Evaluation> {
+    let config = Config::load(project_root)?;
+    let doc_file = project_root.join(&profile.doc_file);
+    code_parser::require_file_exists(&doc_file, "documentación")?;
+    let inputs: Vec<PathBuf> = profile.code.iter().map(|p| project_root.join(p)).collect();
+    let (code_files, _) =
+        code_parser::split_supported(paths::expand_inputs(&inputs)?, &config.paths);
+    for code_file in &code_files {
+        code_parser::require_file_exists(code_file, "código")?;
+    }
+
+    let started = Instant::now();
+    let mut timings = Timings::default();
+    let mut diagnostics = ParseDiagnostics::default();
+    let code_entities = report::parse_timed(&code_files, &config, &mut diagnostics, &mut timings)?;
+    let doc_sections = timings
+        .time_parse(&config.paths.normalize(&doc_file), || {
+            doc_parser::parse_docs_with_diagnostics(&doc_file, &config, &mut diagnostics)
+        })
+        .context("Error al parsear el archivo de documentación")?;
+    let doc_source = doc_parser::read_markdown_file(&doc_file)?;
+    let docs = [pipeline::DocSource {
+        file: &doc_file,
+        source: &doc_source,
+    }];
+    let validation = timings.time_validate(|| {
+        pipeline::run_validation(
+            pipeline::Inputs {
+                code_entities: &code_entities,
+                doc_sections: &doc_sections,
+                diagnostics: &diagnostics,
+                docs: &docs,
+                config: &config,
+            },
+            pipeline::Passes::default(),
+        )
+    });
+    let baseline = baseline::Baseline::load_named(project_root, profile.baseline_name.as_deref())?;
+    let mut report = Report::filtered(
+        validation.results,
+        baseline.as_ref(),
+        config.info_aggregation,
+        &code_entities,
+        &doc_sections,
+    );
+    if let Some(since) = &profile.changed_since {
+        report.retain_changed(&git::changed_files(project_root, since)?, project_root);
+    }
+    timings.total = started.elapsed();
+
+    let mut policies = Vec::new();
+    let budget = profile.time_budget.map(Duration::from_secs_f64);
+    let slow = budget.and_then(|b| timings.budget_finding(b));
+    let budget_status = budget.map(|b| PolicyStatus {
+        detail: format!(
+            "{} de {}",
+            format_duration(timings.total),
+            format_duration(b)
+        ),
+        tripped: slow.is_some(),
+    });
+    // Como en `check`, el hallazgo del presupuesto también cuenta para fail-on
+    report.results.extend(slow);
+    let errors = report.count(Severity::Error);
+    let warnings = report.count(Severity::Warning);
+
+    policies.push(PolicyCheck {
+        policy: Policy::FailOn,
+        status: Some(PolicyStatus {
+            detail: format!(
+                "{} · umbral: {}",
+                messages::summary(errors, warnings),
+                profile.fail_on.name()
+            ),
+            tripped: profile.fail_on.exceeded(errors, warnings),
+        }),
+    });
+    policies.push(PolicyCheck {
+        policy: Policy::Budget,
+        status: budget_status,
+    });
+    policies.push(PolicyCheck {
+        policy: Policy::MaxWarnings,
+        status: profile.max_warnings.map(|max| PolicyStatus {
+            detail: format!(
+                "{} (máximo {})",
+                messages::WARNINGS.count(warnings),
+                messages::Locale::CANONICAL.number(max)
+            ),
+            tripped: warnings > max,
+        }),
+    });
+    let min_coverage = match profile.min_coverage {
+        Some(min) => {
+            let pct = coverage::build_report(&code_files, Some(&doc_file))?.percentage();
+            Some(PolicyStatus {
+                detail: format!("{:.1}% (mínimo {}%)", pct, min),
+                tripped: pct < f64::from(min),
+            })
+        }
+        None => None,
+    };
+    policies.push(PolicyCheck {
+        policy: Policy::MinCoverage,
+        status: min_coverage,
+    });
+
+    let default_output = match profile.format {
+        CheckFormat::Sarif => Some(DEFAULT_SARIF_OUTPUT),
+        CheckFormat::Json => Some(DEFAULT_JSON_OUTPUT),
+        CheckFormat::Markdown => Some(DEFAULT_MARKDOWN_OUTPUT),
+        CheckFormat::Text => None,
+    };
+    let artifact = default_output.map(|default| {
+        profile
+            .output
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(default))
+    });
+    if profile.format == CheckFormat::Json {
+        report.links = report::links::build(&code_entities, &doc_sections, &config);
+    }
+    Ok(Evaluation {
+        report,
+        errors,
+        warnings,
+        elapsed: timings.total,
+        policies,
+        artifact,
+        format: profile.format,
+        redaction: Redaction::new(&config.redaction, redact_descriptions),
+    })
+}
+
+/// Escribe el artefacto del perfil en `path`.
+fn write_artifact(path: &Path, evaluation: &Evaluation) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        vfs::create_dir_all(parent)
+            .with_context(|| format!("No se pudo crear el directorio: {}", safe_display(parent)))?;
+    }
+    let report = evaluation.redaction.apply(&evaluation.report.localized());
+    let log = match evaluation.format {
+        CheckFormat::Json => serde_json::to_string_pretty(&report::json::render(&report))?,
+        CheckFormat::Markdown => report::markdown::render_document(&report, Verbosity::Normal)
+            .trim_end()
+            .to_string(),
+        _ => serde_json::to_string_pretty(&report::sarif::render(&report.results))?,
+    };
+    transaction::write_streamed(path, |out| {
+        writeln!(out, "{}", log)?;
+        Ok(())
+    })
+}
+
+/// Bloque del veredicto. `written` es dónde se escribió el artefacto, si no
+/// es la ruta del perfil (la simulación).
+pub fn render_verdict(
+    name: &str,
+    evaluation: &Evaluation,
+    written: Option<&Path>,
+    simulated: bool,
+    layout: &Layout,
+) -> String {
+    let mut out = String::new();
+    let line = |text: String| format!("{}\n", messages::tr(&text));
+    out.push_str(&line(format!(
+        "  Hallazgos: {}, {} total · tiempo: {}",
+        messages::summary(evaluation.errors, evaluation.warnings),
+        messages::Locale::CANONICAL.number(evaluation.report.results.len()),
+        format_duration(evaluation.elapsed)
+    )));
+    out.push_str(&line(format!("  Políticas del perfil '{}':", name)));
+    for check in &evaluation.policies {
+        let text = match &check.status {
+            Some(status) => {
+                let text = line(format!(
+                    "    [{}] {}: {}",
+                    if status.tripped { "✗" } else { "✓" },
+                    check.policy.name(),
+                    status.detail
+                ));
+                let style = if status.tripped {
+                    Style::Error
+                } else {
+                    Style::Success
+                };
+                layout.paint(style, &text).into_owned()
+            }
+            None => line(format!("    [-] {}: sin configurar", check.policy.name())),
+        };
+        out.push_str(&text);
+    }
+    match (&evaluation.artifact, written) {
+        (Some(artifact), Some(written)) => out.push_str(&line(format!(
+            "  Artefactos: {} (simulado en {})",
+            safe_display(artifact),
+            safe_display(written)
+        ))),
+        (Some(artifact), None) => {
+            out.push_str(&line(format!("  Artefactos: {}", safe_display(artifact))))
+        }
+        (None, _) => out.push_str(&line("  Artefactos: ninguno (format: text)".to_string())),
+    }
+    let tripped: Vec<&str> = evaluation.tripped().map(Policy::name).collect();
+    let verdict = match (tripped.is_empty(), simulated) {
+        (true, true) => "pasaría".to_string(),
+        (true, false) => "pasa".to_string(),
+        (false, true) => format!("fallaría por {}", tripped.join(", ")),
+        (false, false) => format!("falla por {}", tripped.join(", ")),
+    };
+    out.push_str(&line(format!(
+        "  Veredicto: {} (código de salida {})",
+        verdict,
+        evaluation.exit_code()
+    )));
+    out
+}
+
+/// Ejecuta `ci simulate --profile <name>`. Sale con 0 si la simulación
+/// termina: el código del job va en el veredicto.
+pub fn run_simulate(
+    project_root: &Path,
+    name: &str,
+    redact_descriptions: bool,
+    layout: &Layout,
+) -> Result<Outcome> {
+    let config = Config::load(project_root)?;
+    let profile = config.profile(name)?;
+    println_tr!("DocsGuard — Simulando el perfil de CI '{}'\n", name);
+    let evaluation = evaluate(project_root, profile, redact_descriptions)?;
+    let written = match &evaluation.artifact {
+        Some(artifact) => {
+            let dir =
+                std::env::temp_dir().join(format!("docsguard-simulate-{}", std::process::id()));
+            let path = dir.join(artifact.file_name().unwrap_or(artifact.as_os_str()));
+            write_artifact(&path, &evaluation)?;
+            Some(path)
+        }
+        None => None,
+    };
+    print!(
+        "{}",
+        render_verdict(name, &evaluation, written.as_deref(), true, layout)
+    );
+    Ok(Outcome::Clean)
+}
+
+/// Ejecuta `ci run --profile <name>`: los hallazgos, el artefacto en su ruta
+/// y el veredicto; `Outcome::Findings` si alguna política falla.
+pub fn run_profile(
+    project_root: &Path,
+    name: &str,
+    redact_descriptions: bool,
+    layout: &Layout,
+) -> Result<Outcome> {
+    let config = Config::load(project_root)?;
+    let profile = config.profile(name)?;
+    println_tr!("DocsGuard — Perfil de CI '{}'\n", name);
+    let evaluation = evaluate(project_root, profile, redact_descriptions)?;
+    for result in evaluation
+        .report
+        .results
+        .iter()
+        .filter(|r| Verbosity::Normal.shows(r))
+    {
+        print!("{}", result.render(layout, false));
+    }
+    if let Some(artifact) = &evaluation.artifact {
+        write_artifact(&project_root.join(artifact), &evaluation)?;
+    }
+    println!("---");
+    print!("{}", render_verdict(name, &evaluation, None, false, layout));
+    Ok(Outcome::failed_if(evaluation.exit_code() != 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(policy: Policy, tripped: Option<bool>) -> PolicyCheck {
+        PolicyCheck {
+            policy,
+            status: tripped.map(|tripped| PolicyStatus {
+                detail: String::new(),
+                tripped,
+            }),
+        }
+    }
+
+    #[test]
+    fn the_exit_code_follows_any_tripped_policy() {
+        let mut evaluation = Evaluation {
+            report: Report::new(Vec::new(), 0, &[], &[]),
+            errors: 0,
+            warnings: 0,
+            elapsed: Duration::ZERO,
+            policies: vec![
+                check(Policy::FailOn, Some(false)),
+                check(Policy::Budget, Some(true)),
+                check(Policy::MaxWarnings, None),
+                check(Policy::MinCoverage, Some(false)),
+            ],
+            artifact: None,
+            format: CheckFormat::Text,
+            redaction: Redaction::default(),
+        };
+        assert_eq!(evaluation.tripped().collect::<Vec<_>>(), [Policy::Budget]);
+        assert_eq!(evaluation.exit_code(), 1);
+        evaluation.policies[1] = check(Policy::Budget, Some(false));
+        assert_eq!(evaluation.exit_code(), 0);
+    }
+}
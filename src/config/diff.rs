@@ -0,0 +1,547 @@
+//! `config diff`: qué cambiaría en `check` con otra configuración.
+//!
+//! Se parsea y valida una sola vez; después cada hallazgo se resuelve con los
+//! niveles de las dos configuraciones (`RuleLevels::resolve`) y con el
+//! baseline, y se comparan los estados. Las claves que afectan al parseo
+//! (`doc_arg_sources`, `annotation_max_gap`…) no se re-evalúan: se usan las de
+//! la configuración actual y, si difieren, se avisa.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use super::{config_path, Config, Preset, RuleLevels, CONFIG_FILE, MAX_CONFIG_SIZE};
+use crate::baseline::{self, Baseline, DOCSGUARD_DIR};
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::pipeline;
+use crate::core::types::{CodeEntity, Rule, Severity, ValidationResult};
+use crate::exit::{Failure, Outcome};
+use crate::messages::print_tr;
+use crate::parser::code_parser::{self, safe_display};
+use crate::parser::doc_parser;
+use crate::vfs;
+use crate::{git, yaml};
+
+/// Configuración con la que se compara la actual.
+#[derive(Debug, Clone, Copy)]
+pub enum Against<'a> {
+    /// Otro archivo (`--against`).
+    File(&'a Path),
+    /// `.docsguard/config.yaml` en una revisión de git (`--against-git`).
+    Git(&'a str),
+}
+
+/// Estado de un hallazgo bajo una configuración.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Regla en `off` (o opt-in sin activar).
+    Off,
+    /// Lo filtra el baseline.
+    Baselined,
+    Reported(Severity),
+}
+
+impl Status {
+    fn name(self) -> &'static str {
+        match self {
+            Status::Off => "off",
+            Status::Baselined => "baseline",
+            Status::Reported(Severity::Error) => "error",
+            Status::Reported(Severity::Warning) => "warning",
+            Status::Reported(Severity::Info) => "info",
+        }
+    }
+
+    fn reported(self) -> bool {
+        matches!(self, Status::Reported(_))
+    }
+}
+
+/// Cómo cambia un hallazgo al pasar de la otra configuración a la actual.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChangeKind {
+    /// Se reporta en las dos, con otra severidad.
+    Severity,
+    /// Solo se reporta con la actual.
+    Surfaced,
+    /// Solo se reporta con la otra.
+    Suppressed,
+}
+
+impl ChangeKind {
+    const ALL: [ChangeKind; 3] = [
+        ChangeKind::Severity,
+        ChangeKind::Surfaced,
+        ChangeKind::Suppressed,
+    ];
+
+    fn title(self) -> &'static str {
+        match self {
+            ChangeKind::Severity => "Cambian de severidad",
+            ChangeKind::Surfaced => "Aparecen",
+            ChangeKind::Suppressed => "Dejan de reportarse",
+        }
+    }
+}
+
+/// Hallazgo cuyo estado cambia.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub rule: Rule,
+    pub function: Option<String>,
+    pub location: Option<String>,
+    pub message: String,
+    /// Estado con la otra configuración (`off`, `baseline`, `error`…).
+    pub before: &'static str,
+    /// Estado con la actual.
+    pub after: &'static str,
+}
+
+/// Código de salida de `check` con cada configuración.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ExitCodes {
+    pub before: u8,
+    pub after: u8,
+}
+
+/// Salida de `config diff` (también en JSON).
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigDiff {
+    /// Configuración actual.
+    pub current: String,
+    /// Configuración con la que se compara.
+    pub against: String,
+    pub exit_code: ExitCodes,
+    pub changes: Vec<Change>,
+    /// Claves distintas entre las dos que `config diff` no evalúa (afectan al parseo).
+    pub unevaluated_keys: Vec<String>,
+}
+
+/// Estado de cada hallazgo de `raw` con `rules` y `baseline`, en orden.
+/// `code_entities` da las firmas para seguir funciones movidas de archivo.
+pub fn statuses(
+    raw: &[ValidationResult],
+    rules: &RuleLevels,
+    baseline: Option<&Baseline>,
+    code_entities: &[CodeEntity],
+) -> Vec<Status> {
+    let resolved: Vec<Option<ValidationResult>> = raw
+        .iter()
+        .map(|r| {
+            rules.resolve(r).map(|severity| ValidationResult {
+                severity,
+                ..r.clone()
+            })
+        })
+        .collect();
+    // El baseline guarda la severidad: se compara la ya resuelta
+    let reported: Vec<ValidationResult> = resolved.iter().flatten().cloned().collect();
+    let mut matches = match baseline {
+        Some(bl) => baseline::baseline_matches(&reported, bl, code_entities),
+        None => vec![false; reported.len()],
+    }
+    .into_iter();
+    resolved
+        .iter()
+        .map(|r| match r {
+            None => Status::Off,
+            Some(r) => {
+                if matches.next() == Some(true) {
+                    Status::Baselined
+                } else {
+                    Status::Reported(r.severity)
+                }
+            }
+        })
+        .collect()
+}
+
+/// Código de salida de `check` con estos estados.
+fn exit_code(statuses: &[Status]) -> u8 {
+    Outcome::failed_if(statuses.contains(&Status::Reported(Severity::Error))).code()
+}
+
+/// Cambios de estado de cada hallazgo de `raw` entre `before` y `after`.
+pub fn changes(raw: &[ValidationResult], before: &[Status], after: &[Status]) -> Vec<Change> {
+    raw.iter()
+        .zip(before.iter().zip(after))
+        .filter_map(|(r, (&before, &after))| {
+            let kind = match (before.reported(), after.reported()) {
+                (true, true) if before != after => ChangeKind::Severity,
+                (false, true) => ChangeKind::Surfaced,
+                (true, false) => ChangeKind::Suppressed,
+                _ => return None,
+            };
+            Some(Change {
+                kind,
+                rule: r.rule,
+                function: r.function_name.clone(),
+                location: r.code_location.clone().or_else(|| r.doc_location.clone()),
+                message: r.message.clone(),
+                before: before.name(),
+                after: after.name(),
+            })
+        })
+        .collect()
+}
+
+/// Claves de primer nivel, salvo `rules`, con valor distinto en los dos YAML.
+pub fn unevaluated_keys(current: &str, other: &str) -> Vec<String> {
+    let mapping = |content: &str| match serde_yml::from_str(content) {
+        Ok(serde_yml::Value::Mapping(mapping)) => mapping,
+        _ => serde_yml::Mapping::new(),
+    };
+    let (current, other) = (mapping(current), mapping(other));
+    let mut keys: Vec<String> = current
+        .keys()
+        .chain(other.keys())
+        .filter_map(|k| k.as_str())
+        .filter(|k| *k != "rules")
+        .filter(|k| current.get(*k) != other.get(*k))
+        .map(String::from)
+        .collect();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+/// Compara el efecto de `current` y `other` sobre los mismos hallazgos.
+pub fn compare(
+    raw: &[ValidationResult],
+    current: &RuleLevels,
+    other: &RuleLevels,
+    baseline: Option<&Baseline>,
+    code_entities: &[CodeEntity],
+) -> (Vec<Change>, ExitCodes) {
+    let before = statuses(raw, other, baseline, code_entities);
+    let after = statuses(raw, current, baseline, code_entities);
+    let exit_code = ExitCodes {
+        before: exit_code(&before),
+        after: exit_code(&after),
+    };
+    (changes(raw, &before, &after), exit_code)
+}
+
+/// Contenido y nombre de la configuración de `against`. Una revisión sin
+/// `config.yaml` equivale a la configuración por defecto.
+fn read_against(against: Against, project_root: &Path) -> Result<(String, String)> {
+    match against {
+        Against::File(path) => {
+            if !vfs::exists(path) {
+                anyhow::bail!(Failure::usage(format!(
+                    "No existe la configuración con la que comparar: {}",
+                    safe_display(path)
+                ))
+                .with_path(path));
+            }
+            let content = yaml::read(path, MAX_CONFIG_SIZE, Failure::config)?;
+            Ok((content, safe_display(path)))
+        }
+        Against::Git(rev) => {
+            let relative = PathBuf::from(DOCSGUARD_DIR).join(CONFIG_FILE);
+            let label = format!("{}:{}", rev, relative.display());
+            let content = git::show_file(project_root, rev, &relative)?.unwrap_or_default();
+            if content.len() as u64 > MAX_CONFIG_SIZE {
+                anyhow::bail!(Failure::config(format!(
+                    "{} demasiado grande (máximo: {} KB)",
+                    label,
+                    MAX_CONFIG_SIZE / 1024
+                )));
+            }
+            Ok((content, label))
+        }
+    }
+}
+
+/// Texto de `config diff`.
+pub fn render(diff: &ConfigDiff) -> String {
+    let mut out = String::from("DocsGuard — Impacto de la configuración\n\n");
+    out.push_str(&format!("  Configuración: {}\n", diff.current));
+    out.push_str(&format!("  Comparada con: {}\n\n", diff.against));
+    if diff.changes.is_empty() {
+        out.push_str("  Sin cambios: los mismos hallazgos con la misma severidad.\n");
+    }
+    for kind in ChangeKind::ALL {
+        let changes: Vec<&Change> = diff.changes.iter().filter(|c| c.kind == kind).collect();
+        if changes.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("  {} ({}):\n", kind.title(), changes.len()));
+        for c in changes {
+            out.push_str(&format!("    {}", c.rule.id()));
+            if let Some(function) = &c.function {
+                out.push_str(&format!(" en fn {}", function));
+            }
+            if let Some(location) = &c.location {
+                out.push_str(&format!(" ({})", location));
+            }
+            out.push_str(&format!(": {} → {}\n", c.before, c.after));
+        }
+    }
+    if !diff.unevaluated_keys.is_empty() {
+        let keys: Vec<String> = diff
+            .unevaluated_keys
+            .iter()
+            .map(|k| format!("`{}`", k))
+            .collect();
+        out.push_str(&format!(
+            "\n  Nota: también cambia {}; afecta al parseo y se evalúa con el valor actual.\n",
+            keys.join(", ")
+        ));
+    }
+    let ExitCodes { before, after } = diff.exit_code;
+    out.push_str(&format!(
+        "\n  Código de salida: {}\n",
+        match (before, after) {
+            _ if before == after => format!("{} (sin cambios)", after),
+            (_, 0) => format!("{} → {} (la CI dejaría de fallar)", before, after),
+            _ => format!("{} → {} (la CI pasaría a fallar)", before, after),
+        }
+    ));
+    out
+}
+
+/// Ejecuta `config diff`: valida una vez y compara la configuración actual
+/// con la de `against`.
+pub fn run_diff(
+    code_files: &[PathBuf],
+    doc_file: &Path,
+    project_root: &Path,
+    against: Against,
+    preset: Option<Preset>,
+    json: bool,
+) -> Result<Outcome> {
+    for code_file in code_files {
+        code_parser::require_file_exists(code_file, "código")?;
+    }
+    code_parser::require_file_exists(doc_file, "documentación")?;
+
+    let config = Config::load(project_root)?.with_preset(preset);
+    let current_path = config_path(project_root);
+    let current_content = if vfs::exists(&current_path) {
+        yaml::read(&current_path, MAX_CONFIG_SIZE, Failure::config)?
+    } else {
+        String::new()
+    };
+    let (other_content, against_label) = read_against(against, project_root)?;
+    let other = Config::parse_file(&other_content, Path::new(&against_label))?.with_preset(preset);
+
+    let mut diagnostics = ParseDiagnostics::default();
+    let code_entities = code_parser::parse_project_code(code_files, &config, &mut diagnostics)?;
+    let doc_sections = doc_parser::parse_docs_with_diagnostics(doc_file, &config, &mut diagnostics)
+        .context("Error al parsear el archivo de documentación")?;
+    let doc_source = doc_parser::read_markdown_file(doc_file)?;
+    // Con las reglas opt-in siempre: cualquiera de las dos puede activarlas
+    let raw = pipeline::run_validation(
+        pipeline::Inputs {
+            code_entities: &code_entities,
+            doc_sections: &doc_sections,
+            diagnostics: &diagnostics,
+            docs: &[pipeline::DocSource {
+                file: doc_file,
+                source: &doc_source,
+            }],
+            config: &config,
+        },
+        pipeline::Passes {
+            raw: true,
+            ..pipeline::Passes::default()
+        },
+    )
+    .results;
+    let baseline = Baseline::load(project_root)?;
+
+    let (changes, exit_code) = compare(
+        &raw,
+        &config.rules,
+        &other.rules,
+        baseline.as_ref(),
+        &code_entities,
+    );
+    let diff = ConfigDiff {
+        current: safe_display(&current_path),
+        against: against_label,
+        exit_code,
+        changes,
+        unevaluated_keys: unevaluated_keys(&current_content, &other_content),
+    };
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&diff).context("Error al serializar el resultado")?
+        );
+    } else {
+        print_tr!("{}", render(&diff));
+    }
+    Ok(Outcome::Clean)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(rule: Rule, severity: Severity, line: usize) -> ValidationResult {
+        ValidationResult {
+            severity,
+            rule,
+            message: format!("{} en la línea {}", rule.id(), line),
+            function_name: Some("login".into()),
+            code_location: Some(format!("src/auth.ts:{}", line)),
+            doc_id: Some("auth-login".into()),
+            doc_location: Some("docs/api.md:1".into()),
+            hint: None,
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+
+    fn rules(yaml: &str) -> RuleLevels {
+        Config::from_yaml(yaml).unwrap().rules
+    }
+
+    #[test]
+    fn severity_switches_surfaced_and_suppressed_findings_are_reported() {
+        let raw = vec![
+            finding(Rule::MissingArg, Severity::Warning, 3),
+            finding(Rule::OrphanSection, Severity::Warning, 7),
+            finding(Rule::PlaceholderDescription, Severity::Warning, 9),
+            finding(Rule::GhostArg, Severity::Error, 12),
+        ];
+        let current = rules(
+            "rules:\n  missing-arg: error\n  orphan-section: off\n  placeholder-description: warning\n",
+        );
+        let (changes, exit_code) = compare(&raw, &current, &RuleLevels::default(), None, &[]);
+        let summary: Vec<(ChangeKind, Rule, &str, &str)> = changes
+            .iter()
+            .map(|c| (c.kind, c.rule, c.before, c.after))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (ChangeKind::Severity, Rule::MissingArg, "warning", "error"),
+                (
+                    ChangeKind::Suppressed,
+                    Rule::OrphanSection,
+                    "warning",
+                    "off"
+                ),
+                (
+                    ChangeKind::Surfaced,
+                    Rule::PlaceholderDescription,
+                    "off",
+                    "warning"
+                ),
+            ]
+        );
+        assert_eq!(changes[0].location.as_deref(), Some("src/auth.ts:3"));
+        assert_eq!(
+            exit_code,
+            ExitCodes {
+                before: 1,
+                after: 1
+            }
+        );
+
+        // Sin el ghost-arg, el cambio de severidad es el que hace fallar la CI
+        let (_, exit_code) = compare(&raw[..3], &current, &RuleLevels::default(), None, &[]);
+        assert_eq!(
+            exit_code,
+            ExitCodes {
+                before: 0,
+                after: 1
+            }
+        );
+        let rendered = render(&ConfigDiff {
+            current: ".docsguard/config.yaml".into(),
+            against: "HEAD:.docsguard/config.yaml".into(),
+            exit_code,
+            changes,
+            unevaluated_keys: vec!["annotation_max_gap".into()],
+        });
+        assert_eq!(
+            rendered,
+            "DocsGuard — Impacto de la configuración\n\n  \
+             Configuración: .docsguard/config.yaml\n  \
+             Comparada con: HEAD:.docsguard/config.yaml\n\n  \
+             Cambian de severidad (1):\n    \
+             missing-arg en fn login (src/auth.ts:3): warning → error\n  \
+             Aparecen (1):\n    \
+             placeholder-description en fn login (src/auth.ts:9): off → warning\n  \
+             Dejan de reportarse (1):\n    \
+             orphan-section en fn login (src/auth.ts:7): warning → off\n\n  \
+             Nota: también cambia `annotation_max_gap`; afecta al parseo y se evalúa con el valor actual.\n\n  \
+             Código de salida: 0 → 1 (la CI pasaría a fallar)\n"
+        );
+    }
+
+    #[test]
+    fn the_baseline_is_matched_with_the_resolved_severity() {
+        let raw = vec![finding(Rule::MissingArg, Severity::Warning, 3)];
+        let baseline = Baseline::from_results(&raw, &[]);
+        let current = rules("rules:\n  missing-arg: error\n");
+        let before = statuses(&raw, &RuleLevels::default(), Some(&baseline), &[]);
+        let after = statuses(&raw, &current, Some(&baseline), &[]);
+        assert_eq!(before, vec![Status::Baselined]);
+        assert_eq!(after, vec![Status::Reported(Severity::Error)]);
+        // Subir la severidad saca el hallazgo del baseline: aparece
+        let changes = changes(&raw, &before, &after);
+        assert_eq!(changes[0].kind, ChangeKind::Surfaced);
+        assert_eq!(changes[0].before, "baseline");
+
+        // Del baseline a off no se ve en la salida de check
+        let off = statuses(
+            &raw,
+            &rules("rules:\n  missing-arg: off\n"),
+            Some(&baseline),
+            &[],
+        );
+        assert!(super::changes(&raw, &before, &off).is_empty());
+    }
+
+    #[test]
+    fn keys_other_than_rules_are_flagged_as_unevaluated() {
+        assert_eq!(
+            unevaluated_keys(
+                "rules:\n  missing-arg: error\nannotation_max_gap: 2\nunits: {px: [pixels]}\n",
+                "annotation_max_gap: 1\nunits: {px: [pixels]}\ndoc_arg_sources: [table]\n",
+            ),
+            vec!["annotation_max_gap", "doc_arg_sources"]
+        );
+        assert!(unevaluated_keys("", "rules:\n  ghost-arg: off\n").is_empty());
+    }
+
+    #[test]
+    fn comparing_against_a_git_revision_reads_the_committed_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .arg("-C")
+                .arg(root)
+                .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?}", args);
+        };
+        git(&["init", "-q"]);
+        std::fs::create_dir(root.join(DOCSGUARD_DIR)).unwrap();
+        std::fs::write(config_path(root), "rules:\n  missing-arg: info\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "base"]);
+
+        let (content, label) = read_against(Against::Git("HEAD"), root).unwrap();
+        assert_eq!(content, "rules:\n  missing-arg: info\n");
+        assert_eq!(label, "HEAD:.docsguard/config.yaml");
+
+        let err = read_against(Against::File(&root.join("otra.yaml")), root).unwrap_err();
+        assert_eq!(crate::exit::exit_code_for(&err), crate::exit::USAGE);
+    }
+}
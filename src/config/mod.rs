@@ -0,0 +1,753 @@
+//! Configuración por proyecto en `.docsguard/config.yaml`.
+//!
+//! El archivo es opcional: sin él se usan los valores por defecto. Vive junto
+//! al baseline y comparte sus límites de tamaño (VUL-04). Los niveles por
+//! regla y los presets viven en `rules`; `diff` compara el efecto de dos
+//! configuraciones sobre los mismos hallazgos; `overrides` elige otro archivo
+//! (`--config`) y fija opciones sueltas (`--set`) desde la CLI.
+
+pub mod diff;
+pub mod overrides;
+pub mod rules;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::baseline::DOCSGUARD_DIR;
+use crate::core::anchors::{CustomSlugRules, SlugStyle};
+use crate::core::heuristic::canonical_name;
+use crate::core::ids::IdStyle;
+use crate::core::types::{ArgSource, DocSection, Severity};
+use crate::exit::{FailOn, Failure};
+use crate::mapping::LinkMapping;
+use crate::parser::code_parser::{AnnotationOptions, DEFAULT_ANNOTATION_MAX_GAP};
+use crate::parser::doc_parser::{
+    DocParseOptions, LeadIns, MarkdownExtension, TitleOptions, DEFAULT_MARKDOWN_EXTENSIONS,
+    DEFAULT_TITLE_MAX_DISTANCE,
+};
+use crate::paths::ProjectPaths;
+use crate::report::redact::RedactionConfig;
+use crate::report::CheckFormat;
+use crate::schema::Artifact;
+use crate::vfs;
+use crate::yaml::{self, YamlError};
+pub use rules::{Preset, RuleLevels};
+
+/// Nombre del archivo de configuración.
+pub(crate) const CONFIG_FILE: &str = "config.yaml";
+/// Tamaño máximo de la configuración (VUL-04).
+const MAX_CONFIG_SIZE: u64 = 1024 * 1024;
+
+/// Configuración del proyecto.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Estrategias de extracción de argumentos de docs habilitadas
+    /// (`list`, `table`, `definition`, `heading`). Sin valor: todas.
+    #[serde(default)]
+    pub doc_arg_sources: Option<Vec<ArgSource>>,
+    /// Referencias a símbolos del proyecto desde las docs.
+    #[serde(default)]
+    pub references: ReferencesConfig,
+    /// Fuente de la versión del proyecto (`check --check-versions`).
+    #[serde(default)]
+    pub versions: VersionsConfig,
+    /// Palabras clave extra por unidad para `arg-constraint` (`px: [pixels, píxeles]`);
+    /// se suman a las incorporadas (`ms`, `s`, `bytes`, `%`).
+    #[serde(default)]
+    pub units: BTreeMap<String, Vec<String>>,
+    /// Líneas en blanco que se toleran entre una anotación `@docs` (o su bloque
+    /// de comentarios) y la función. Sin valor: 1.
+    #[serde(default)]
+    pub annotation_max_gap: Option<usize>,
+    /// Líneas como máximo entre un encabezado y un marcador `@docs-id` sin
+    /// encabezado propio para tomar ese encabezado como título. Sin valor: 10;
+    /// 0 lo desactiva.
+    #[serde(default)]
+    pub title_max_distance: Option<usize>,
+    /// Extensiones de Markdown de las docs (`tables`, `strikethrough`,
+    /// `footnotes`, `tasklists`, `heading_attributes`, `smart_punctuation`).
+    /// Sin valor: `tables`, `strikethrough` y `tasklists`.
+    #[serde(default)]
+    pub markdown_extensions: Option<Vec<MarkdownExtension>>,
+    /// Una sección sin entradilla de argumentos (`Parameters:`, `### Args`…)
+    /// no documenta ninguno: sus listas y definiciones no son argumentos.
+    #[serde(default)]
+    pub require_args_delimiter: bool,
+    /// Entradillas extra: `args` abre el bloque de argumentos de una sección
+    /// y `structural` lo cierra, como `Returns:` o `Errors:`.
+    #[serde(default)]
+    pub lead_ins: LeadIns,
+    /// Cómo deriva `scaffold --stubs` los IDs nuevos (`kebab-from-function`,
+    /// `snake`, `keep` o una plantilla como `{module}-{function_kebab}`).
+    #[serde(default)]
+    pub id_style: IdStyle,
+    /// Módulo de `{module}` por directorio (`src/payments: billing`); sin
+    /// entrada, el nombre del directorio del archivo.
+    #[serde(default)]
+    pub id_modules: BTreeMap<String, String>,
+    /// Textos que se redactan en lo que sale de la máquina (ver `report::redact`).
+    #[serde(default)]
+    pub redaction: RedactionConfig,
+    /// `summarize` agrupa en los informes los hallazgos Info de una regla en
+    /// un archivo en uno solo (`report --html`, `ci`); por defecto `off`.
+    #[serde(default)]
+    pub info_aggregation: InfoAggregation,
+    /// Nivel por regla (`error`, `warning`, `info`, `off`); gana sobre `--preset`.
+    #[serde(default)]
+    pub rules: RuleLevels,
+    /// Topes de argumentos por sección y de secciones por archivo de docs.
+    #[serde(default)]
+    pub limits: LimitsConfig,
+    /// Sitio donde se publican las docs: algoritmo de anclas y URL base.
+    #[serde(default)]
+    pub site: SiteConfig,
+    /// IDs de sección que deben tener atestación (`check --check-attestations`).
+    #[serde(default)]
+    pub attestation_required: Vec<String>,
+    /// Parejas de argumentos que se declaran equivalentes: la pareja
+    /// ghost-arg + missing-arg que producirían pasa a ser un Info.
+    #[serde(default)]
+    pub arg_exceptions: Vec<ArgException>,
+    /// Fronteras de módulo entre código y docs: un enlace desde un archivo de
+    /// `code` a una sección fuera de `docs` es `link-boundary`.
+    #[serde(default)]
+    pub link_boundaries: Vec<LinkBoundary>,
+    /// Cómo se comparan los nombres de argumento de la firma y de las docs.
+    #[serde(default)]
+    pub arg_names: ArgNamesConfig,
+    /// Argumentos fantasma de una sección a partir de los cuales se busca la
+    /// función de la que se copió (`copied-section`). Sin valor: 3.
+    #[serde(default)]
+    pub copied_section_min_args: Option<usize>,
+    /// Globs sobre el nombre de la función (`test_*`, `*_internal`): las que
+    /// encajan se descartan al parsear y no se validan ni se proponen.
+    #[serde(default)]
+    pub ignore_functions: Vec<String>,
+    /// Periodo de gracia de los archivos de código nuevos: sus Error bajan a
+    /// Warning durante `days` días desde su primer commit (ver `grace`).
+    #[serde(default)]
+    pub grace_period: Option<GracePeriodConfig>,
+    /// Combinaciones con nombre de docs y revisiones para `check --combo`.
+    #[serde(default)]
+    pub combos: BTreeMap<String, Combo>,
+    /// Invocaciones de CI con nombre para `ci simulate` y `ci run --profile`.
+    #[serde(default)]
+    pub profiles: BTreeMap<String, CiProfile>,
+    /// `colocated-readme`: `check-all` valida cada archivo de código con el
+    /// `README.md` de su directorio o del ancestro más cercano (ver
+    /// `pairing`); por defecto `off`.
+    #[serde(default)]
+    pub pairing: Pairing,
+    /// Directorios por encima del del archivo en los que se busca el
+    /// `README.md` de `pairing`; sin valor, hasta la raíz del proyecto.
+    #[serde(default)]
+    pub readme_ancestors: Option<usize>,
+    /// Normalización de rutas anclada en `--project-root` (no viene del YAML).
+    #[serde(skip)]
+    pub paths: ProjectPaths,
+    /// Enlaces externos de `.docsguard/links.yaml` (archivo aparte).
+    #[serde(skip)]
+    pub links: LinkMapping,
+}
+
+/// Argumentos por sección tras los que se descarta el resto (`limits.max_section_args`).
+pub const DEFAULT_MAX_SECTION_ARGS: usize = 200;
+/// Secciones por archivo de docs tras las que se descarta el resto (`limits.max_sections`).
+pub const DEFAULT_MAX_SECTIONS: usize = 5000;
+
+/// Topes frente a Markdown patológico: un `@docs-id` perdido sobre un
+/// changelog generado no debe producir una sección de miles de argumentos.
+/// Lo recortado se reporta como `DG006`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct LimitsConfig {
+    pub max_section_args: usize,
+    pub max_sections: usize,
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        LimitsConfig {
+            max_section_args: DEFAULT_MAX_SECTION_ARGS,
+            max_sections: DEFAULT_MAX_SECTIONS,
+        }
+    }
+}
+
+/// `grace_period`: días desde el primer commit de un archivo de código en los
+/// que sus hallazgos no pasan de Warning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GracePeriodConfig {
+    pub days: u32,
+}
+
+/// Sitio publicado de las docs. `slugger` decide cómo se calculan las anclas
+/// de los encabezados al validar los enlaces `#ancla` (`broken-anchor`); el
+/// resto solo lo usa `check --verify-site`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SiteConfig {
+    /// URL base del sitio (`https://docs.example.com/`).
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// `github` (por defecto), `docusaurus` o `custom`.
+    #[serde(default)]
+    pub slugger: SlugStyle,
+    /// Reglas de `slugger: custom`.
+    #[serde(default)]
+    pub custom: CustomSlugRules,
+    /// Directorio de docs que el sitio publica en su raíz (`docs`).
+    #[serde(default)]
+    pub docs_dir: Option<PathBuf>,
+    /// Ruta de cada sección bajo `base_url`; `{page}#{anchor}` por defecto,
+    /// admite también `{id}`.
+    #[serde(default)]
+    pub url_template: Option<String>,
+    /// Segundos de espera por petición (5 por defecto).
+    #[cfg_attr(not(feature = "verify-site"), allow(dead_code))]
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Peticiones simultáneas (4 por defecto, como mucho 16).
+    #[cfg_attr(not(feature = "verify-site"), allow(dead_code))]
+    #[serde(default)]
+    pub concurrency: Option<usize>,
+}
+
+/// Argumento documentado que se declara equivalente a uno del código, para
+/// cuando las docs describen a propósito algo más amplio que la firma
+/// (`filters` como concepto frente a `filter_expr: string`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ArgException {
+    /// Sección donde se documenta `doc_arg`.
+    pub doc_id: String,
+    pub doc_arg: String,
+    /// Argumento del código de las funciones enlazadas a `doc_id`.
+    pub code_arg: String,
+    /// Por qué difieren a propósito; se muestra en el Info.
+    pub reason: String,
+}
+
+/// Frontera de módulo: las funciones de los archivos que encajan con `code`
+/// solo enlazan secciones de archivos que encajan con `docs` (globs sobre
+/// rutas relativas a `--project-root`, `**` para cualquier subdirectorio).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LinkBoundary {
+    pub code: String,
+    pub docs: String,
+}
+
+/// Combinación de `check --combo`: unas docs validadas contra el código de
+/// una revisión (o al revés), con su propio baseline. Las rutas son relativas
+/// a `--project-root`; las opciones de la línea de comandos ganan.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Combo {
+    /// Archivo de documentación.
+    pub doc_file: PathBuf,
+    /// Archivos, directorios o globs de código.
+    pub code: Vec<PathBuf>,
+    /// Revisión de la que se lee el código (`--code-ref`).
+    #[serde(default)]
+    pub code_ref: Option<String>,
+    /// Revisión de la que se leen las docs (`--docs-ref`).
+    #[serde(default)]
+    pub docs_ref: Option<String>,
+    /// `--baseline-name`; sin valor, el nombre de la combinación.
+    #[serde(default)]
+    pub baseline_name: Option<String>,
+}
+
+/// Perfil de `ci simulate` y `ci run`: la invocación de CI entera, para que
+/// la ejecución local y la del CI no difieran en flags. Las rutas son
+/// relativas a `--project-root`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CiProfile {
+    /// Archivo de documentación.
+    pub doc_file: PathBuf,
+    /// Archivos, directorios o globs de código.
+    pub code: Vec<PathBuf>,
+    /// `--fail-on`; por defecto `error`.
+    #[serde(default)]
+    pub fail_on: FailOn,
+    /// `--changed-since`.
+    #[serde(default)]
+    pub changed_since: Option<String>,
+    /// `--baseline-name`.
+    #[serde(default)]
+    pub baseline_name: Option<String>,
+    /// Segundos de `--time-budget`; pasarse hace fallar, como `--fail-on-slow`.
+    #[serde(default)]
+    pub time_budget: Option<f64>,
+    /// Advertencias que se toleran como mucho.
+    #[serde(default)]
+    pub max_warnings: Option<usize>,
+    /// Cobertura mínima de la API pública, en % (`coverage --min-coverage`).
+    #[serde(default)]
+    pub min_coverage: Option<u8>,
+    /// `--format`: con `sarif` se escribe el log en `output`.
+    #[serde(default)]
+    pub format: CheckFormat,
+    /// Artefacto del formato; sin valor, `docsguard.sarif`.
+    #[serde(default)]
+    pub output: Option<PathBuf>,
+}
+
+/// Comparación de los nombres de argumento (`arg_names:`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ArgNamesConfig {
+    /// `exact` (por defecto), `case-insensitive` o `convention-tolerant`.
+    #[serde(default)]
+    pub matching: ArgNameMatching,
+    /// Info `arg-name-style` por cada argumento que solo casa tras normalizar
+    /// el nombre.
+    #[serde(default)]
+    pub report_differences: bool,
+}
+
+/// Modo de comparación de nombres de argumento entre código y docs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ArgNameMatching {
+    /// Nombres idénticos.
+    #[default]
+    Exact,
+    /// Sin distinguir mayúsculas (`userId` = `userid`).
+    CaseInsensitive,
+    /// Además, camelCase, snake_case y kebab-case equivalen (`tenantId` =
+    /// `tenant_id` = `tenant-id`).
+    ConventionTolerant,
+}
+
+impl ArgNameMatching {
+    /// Indica si `doc` y `code` nombran el mismo argumento con este modo.
+    pub fn matches(self, doc: &str, code: &str) -> bool {
+        if doc == code {
+            return true;
+        }
+        let same_letters = || doc.to_lowercase() == code.to_lowercase();
+        match self {
+            ArgNameMatching::Exact => false,
+            ArgNameMatching::CaseInsensitive => same_letters(),
+            ArgNameMatching::ConventionTolerant => {
+                same_letters() || canonical_name(doc) == canonical_name(code)
+            }
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ArgNameMatching::Exact => "exact",
+            ArgNameMatching::CaseInsensitive => "case-insensitive",
+            ArgNameMatching::ConventionTolerant => "convention-tolerant",
+        }
+    }
+}
+
+/// Reglas que comprueban que las docs nombran símbolos existentes
+/// (`check --check-symbols`). Comparten paquetes y lista de permitidos.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReferencesConfig {
+    /// Paquetes propios, además del `name` de `package.json` / `Cargo.toml`.
+    #[serde(default)]
+    pub packages: Vec<String>,
+    /// Identificadores que nunca se reportan.
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub severity: ReferenceSeverity,
+}
+
+/// De dónde sale la versión actual del proyecto para validar las menciones
+/// "desde vX" de las docs. Basta con uno de los dos campos.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VersionsConfig {
+    /// `package.json` o `Cargo.toml`, relativo a `--project-root`; su `version` es la actual.
+    #[serde(default)]
+    pub manifest: Option<PathBuf>,
+    /// Versiones publicadas. Sin `manifest`, la mayor es la actual.
+    #[serde(default)]
+    pub known: Vec<String>,
+}
+
+/// Agrupación de los hallazgos Info en los informes (ver `report::aggregate`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InfoAggregation {
+    /// Cada hallazgo por separado.
+    #[default]
+    Off,
+    /// Un hallazgo resumen por regla y archivo.
+    Summarize,
+}
+
+/// Cómo empareja `check-all` el código con las docs (ver `pairing`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Pairing {
+    /// Todo el código con todas las docs.
+    #[default]
+    Off,
+    /// Cada archivo con el `README.md` más cercano.
+    ColocatedReadme,
+}
+
+/// Severidad de los hallazgos de referencias (`info` por defecto).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReferenceSeverity {
+    #[default]
+    Info,
+    Warning,
+}
+
+impl ReferenceSeverity {
+    pub fn severity(self) -> Severity {
+        match self {
+            ReferenceSeverity::Info => Severity::Info,
+            ReferenceSeverity::Warning => Severity::Warning,
+        }
+    }
+}
+
+impl Config {
+    /// Carga la configuración de `project_root`, o la por defecto si no existe.
+    pub fn load(project_root: &Path) -> Result<Self> {
+        let mut config = Self::load_file(project_root)?;
+        config.paths = ProjectPaths::new(project_root);
+        config.links = LinkMapping::load(project_root)?;
+        Ok(config)
+    }
+
+    fn load_file(project_root: &Path) -> Result<Self> {
+        let path = config_path(project_root);
+        let overrides = overrides::active();
+        let content = match overrides {
+            _ if vfs::exists(&path) => yaml::read(&path, MAX_CONFIG_SIZE, Failure::config)?,
+            // Un archivo elegido a mano tiene que existir; el del proyecto es opcional
+            Some(chosen) if chosen.path().is_some() => {
+                return Err(overrides::missing_file(chosen, &path))
+            }
+            _ => String::new(),
+        };
+        let config = Self::parse_file(&content, &path)?;
+        let Some(chosen) = overrides else {
+            return Ok(config);
+        };
+        let mut config = if chosen.settings.is_empty() {
+            config
+        } else {
+            overrides::apply(&content, &chosen.settings)?
+        };
+        config
+            .ignore_functions
+            .extend(chosen.ignore_functions.iter().cloned());
+        Ok(config)
+    }
+
+    /// Parsea el contenido de `path`, con los errores referidos al archivo.
+    pub(crate) fn parse_file(content: &str, path: &Path) -> Result<Self> {
+        Self::from_yaml(content)
+            .map_err(|e| e.in_file(path))
+            .with_context(|| {
+                Failure::config(format!(
+                    "Error al parsear la configuración: {}",
+                    path.display()
+                ))
+                .with_path(path)
+            })
+    }
+
+    /// Parsea la configuración desde YAML. Un documento vacío es la configuración por defecto.
+    pub fn from_yaml(content: &str) -> Result<Self, YamlError> {
+        if content.trim().is_empty() {
+            return Ok(Config::default());
+        }
+        yaml::from_str_checked(content, Artifact::Config)
+    }
+
+    /// Indica si la estrategia está habilitada. Los argumentos de código siempre lo están.
+    pub fn arg_source_enabled(&self, source: ArgSource) -> bool {
+        source == ArgSource::Code
+            || self
+                .doc_arg_sources
+                .as_ref()
+                .is_none_or(|enabled| enabled.contains(&source))
+    }
+
+    /// Opciones de búsqueda de anotaciones en el código (`annotation_max_gap`).
+    pub fn annotation_options(&self) -> AnnotationOptions {
+        AnnotationOptions {
+            max_gap: self
+                .annotation_max_gap
+                .unwrap_or(DEFAULT_ANNOTATION_MAX_GAP),
+        }
+    }
+
+    /// Opciones del parseo de las docs: asociación de títulos
+    /// (`title_max_distance`), extensiones (`markdown_extensions`) y bloque
+    /// de argumentos (`require_args_delimiter`, `lead_ins`).
+    pub fn doc_parse_options(&self) -> DocParseOptions {
+        let extensions = self
+            .markdown_extensions
+            .as_deref()
+            .unwrap_or(&DEFAULT_MARKDOWN_EXTENSIONS);
+        DocParseOptions {
+            title: TitleOptions {
+                max_distance: self
+                    .title_max_distance
+                    .unwrap_or(DEFAULT_TITLE_MAX_DISTANCE),
+            },
+            lead_ins: self.lead_ins.clone(),
+            require_args_delimiter: self.require_args_delimiter,
+            ..DocParseOptions::with_extensions(extensions)
+        }
+    }
+
+    /// Elimina de las secciones los argumentos de estrategias deshabilitadas.
+    ///
+    /// Se aplica al cargar las docs (`doc_parser::parse_docs`), de modo que
+    /// validación, triage, baseline y correcciones ven los mismos argumentos.
+    pub fn apply_to_sections(&self, sections: &mut [DocSection]) {
+        if self.doc_arg_sources.is_none() {
+            return;
+        }
+        for section in sections {
+            section.args.retain(|a| self.arg_source_enabled(a.source));
+        }
+    }
+
+    /// Combinación `name` de `combos:`, con su nombre de baseline ya resuelto.
+    pub fn combo(&self, name: &str) -> Result<(&Combo, String)> {
+        let Some(combo) = self.combos.get(name) else {
+            let hint = if self.combos.is_empty() {
+                "Decláralas en `combos:` de .docsguard/config.yaml.".to_string()
+            } else {
+                format!(
+                    "Combinaciones declaradas: {}.",
+                    self.combos.keys().cloned().collect::<Vec<_>>().join(", ")
+                )
+            };
+            anyhow::bail!(
+                Failure::config(format!("No hay ninguna combinación '{}'.", name)).with_hint(hint)
+            );
+        };
+        let baseline_name = combo.baseline_name.as_deref().unwrap_or(name);
+        crate::baseline::check_name(baseline_name)
+            .map_err(|e| Failure::config(format!("combos.{}.baseline_name: {}", name, e)))?;
+        Ok((combo, baseline_name.to_string()))
+    }
+
+    /// Perfil `name` de `profiles:`, con el presupuesto ya comprobado.
+    pub fn profile(&self, name: &str) -> Result<&CiProfile> {
+        let Some(profile) = self.profiles.get(name) else {
+            let hint = if self.profiles.is_empty() {
+                "Decláralos en `profiles:` de .docsguard/config.yaml.".to_string()
+            } else {
+                format!(
+                    "Perfiles declarados: {}.",
+                    self.profiles.keys().cloned().collect::<Vec<_>>().join(", ")
+                )
+            };
+            anyhow::bail!(
+                Failure::config(format!("No hay ningún perfil '{}'.", name)).with_hint(hint)
+            );
+        };
+        if profile
+            .time_budget
+            .is_some_and(|secs| !(secs > 0.0 && secs.is_finite()))
+        {
+            anyhow::bail!(Failure::config(format!(
+                "profiles.{}.time_budget: el presupuesto debe ser un número de segundos mayor que 0.",
+                name
+            )));
+        }
+        if let Some(baseline_name) = &profile.baseline_name {
+            crate::baseline::check_name(baseline_name)
+                .map_err(|e| Failure::config(format!("profiles.{}.baseline_name: {}", name, e)))?;
+        }
+        Ok(profile)
+    }
+}
+
+/// `.docsguard/config.yaml` de `project_root`, o el archivo de `--config` /
+/// `DOCSGUARD_CONFIG` si se eligió uno.
+pub(crate) fn config_path(project_root: &Path) -> PathBuf {
+    match overrides::active().and_then(|o| o.path()) {
+        Some(path) => path.to_path_buf(),
+        None => project_root.join(DOCSGUARD_DIR).join(CONFIG_FILE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::diagnostics::ParseDiagnostics;
+    use crate::parser::doc_parser::parse_markdown_source;
+    use crate::vfs::MemFs;
+
+    const MIXED_DOC: &str = r#"<!-- @docs-id: user-create -->
+## Create User
+
+- nickname: Alias opcional
+
+| Param | Type | Description |
+|-------|------|-------------|
+| name | string | Nombre |
+"#;
+
+    #[test]
+    fn missing_or_empty_config_enables_everything() {
+        let dir = MemFs::project();
+        let config = Config::load(dir.path()).unwrap();
+        assert!(config.arg_source_enabled(ArgSource::List));
+        assert!(Config::from_yaml("").unwrap().doc_arg_sources.is_none());
+    }
+
+    #[test]
+    fn disabling_list_keeps_table_args() {
+        let config = Config::from_yaml("doc_arg_sources: [table]").unwrap();
+        let mut sections = parse_markdown_source(
+            MIXED_DOC,
+            Path::new("api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        assert_eq!(sections[0].args.len(), 2);
+
+        config.apply_to_sections(&mut sections);
+        assert_eq!(sections[0].args.len(), 1);
+        assert_eq!(sections[0].args[0].name, "name");
+        assert_eq!(sections[0].args[0].source, ArgSource::Table);
+    }
+
+    #[test]
+    fn unknown_keys_and_sources_are_rejected() {
+        assert!(Config::from_yaml("doc_arg_source: [table]").is_err());
+        assert!(Config::from_yaml("doc_arg_sources: [tabla]").is_err());
+    }
+
+    #[test]
+    fn arg_exceptions_need_all_four_fields() {
+        let config = Config::from_yaml(
+            "arg_exceptions:\n  - {doc_id: search-api, doc_arg: filters, code_arg: filter_expr, reason: \"conceptual grouping\"}\n",
+        )
+        .unwrap();
+        assert_eq!(config.arg_exceptions[0].code_arg, "filter_expr");
+        assert_eq!(config.arg_exceptions[0].reason, "conceptual grouping");
+        assert!(Config::from_yaml(
+            "arg_exceptions:\n  - {doc_id: search-api, doc_arg: filters, code_arg: filter_expr}\n"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn markdown_extensions_default_to_tables_strikethrough_and_tasklists() {
+        let default = Config::default().doc_parse_options();
+        assert_eq!(default, DocParseOptions::default());
+        let config = Config::from_yaml("markdown_extensions: [footnotes]\ntitle_max_distance: 0\n")
+            .unwrap()
+            .doc_parse_options();
+        assert_eq!(
+            config.extensions,
+            DocParseOptions::with_extensions(&[MarkdownExtension::Footnotes]).extensions
+        );
+        assert_eq!(config.title.max_distance, 0);
+        assert!(Config::from_yaml("markdown_extensions: [task_lists]").is_err());
+    }
+
+    #[test]
+    fn args_lead_ins_reach_the_doc_parser() {
+        let config = Config::from_yaml(
+            "require_args_delimiter: true
+lead_ins: {args: [Inputs], structural: [callbacks]}
+",
+        )
+        .unwrap()
+        .doc_parse_options();
+        assert!(config.require_args_delimiter);
+        assert_eq!(config.lead_ins.args, ["Inputs"]);
+        assert_eq!(config.lead_ins.structural, ["callbacks"]);
+        assert!(Config::from_yaml("lead_ins: {returns: [out]}").is_err());
+    }
+
+    #[test]
+    fn link_boundaries_need_both_globs() {
+        let config = Config::from_yaml(
+            "link_boundaries:\n  - {code: 'src/billing/**', docs: 'docs/billing/**'}\n",
+        )
+        .unwrap();
+        assert_eq!(config.link_boundaries[0].docs, "docs/billing/**");
+        assert!(Config::from_yaml("link_boundaries:\n  - {code: 'src/billing/**'}\n").is_err());
+    }
+
+    #[test]
+    fn annotation_max_gap_defaults_to_one_blank_line() {
+        let config = Config::from_yaml("").unwrap();
+        assert_eq!(config.annotation_options().max_gap, 1);
+        let config = Config::from_yaml("annotation_max_gap: 0").unwrap();
+        assert_eq!(config.annotation_options().max_gap, 0);
+    }
+
+    #[test]
+    fn references_default_to_info_and_accept_warning() {
+        let config = Config::from_yaml("doc_arg_sources: [table]").unwrap();
+        assert_eq!(config.references.severity, ReferenceSeverity::Info);
+
+        let config = Config::from_yaml(
+            "references:\n  packages: ['@acme/sdk']\n  allow: [fetchJson]\n  severity: warning\n",
+        )
+        .unwrap();
+        assert_eq!(config.references.severity.severity(), Severity::Warning);
+        assert_eq!(config.references.allow, vec!["fetchJson".to_string()]);
+        assert!(Config::from_yaml("references:\n  severity: error\n").is_err());
+    }
+
+    #[test]
+    fn limits_default_and_accept_partial_overrides() {
+        let config = Config::from_yaml("doc_arg_sources: [table]").unwrap();
+        assert_eq!(config.limits, LimitsConfig::default());
+        let config = Config::from_yaml("limits:\n  max_section_args: 500\n").unwrap();
+        assert_eq!(config.limits.max_section_args, 500);
+        assert_eq!(config.limits.max_sections, DEFAULT_MAX_SECTIONS);
+        assert!(Config::from_yaml("limits:\n  max_args: 500\n").is_err());
+    }
+
+    #[test]
+    fn combos_resolve_their_baseline_name() {
+        let config = Config::from_yaml(
+            "combos:\n  v2: {doc_file: docs/v2/api.md, code: [src], code_ref: release/2.x}\n  \
+             v3: {doc_file: docs/api.md, code: [src], baseline_name: main}\n  \
+             bad: {doc_file: a.md, code: [src], baseline_name: ../x}\n",
+        )
+        .unwrap();
+        let (combo, baseline) = config.combo("v2").unwrap();
+        assert_eq!(combo.code_ref.as_deref(), Some("release/2.x"));
+        assert_eq!(baseline, "v2");
+        assert_eq!(config.combo("v3").unwrap().1, "main");
+        assert!(config.combo("bad").is_err());
+        let err = config.combo("v4").unwrap_err();
+        assert!(format!("{:#}", err).contains("No hay ninguna combinación 'v4'"));
+    }
+
+    #[test]
+    fn config_is_loaded_from_docsguard_dir() {
+        let dir = MemFs::project();
+        dir.write(
+            config_path(dir.path()),
+            "doc_arg_sources: [list, definition]\n",
+        );
+        let config = Config::load(dir.path()).unwrap();
+        assert!(!config.arg_source_enabled(ArgSource::Table));
+        assert!(config.arg_source_enabled(ArgSource::Code));
+    }
+}
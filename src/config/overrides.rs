@@ -0,0 +1,345 @@
+//! Configuración elegida desde la CLI: `--config <archivo>` (o la variable
+//! `DOCSGUARD_CONFIG`) en lugar de `.docsguard/config.yaml`, y `--set
+//! clave=valor` encima.
+//!
+//! De menor a mayor prioridad: valores por defecto → `--preset` → archivo
+//! (el de `--config`, el de `DOCSGUARD_CONFIG` o el del proyecto) → `--set`.
+//! Los `--ignore-fn` no sustituyen a `ignore_functions`: se suman a su lista.
+//! `main` fija las opciones una vez al arrancar (`install`) y las lee
+//! `Config::load`, así que todos los subcomandos las respetan sin pasarlas
+//! por cada firma.
+//!
+//! Un `--set` se aplica sobre el YAML del archivo antes de deserializar: la
+//! ruta con puntos crea los mapeos que falten y el esquema de `Config`
+//! comprueba la clave y el tipo del valor.
+
+use anyhow::Result;
+use serde_yml::{Mapping, Value};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use super::{Config, Preset};
+use crate::core::validator::DEFAULT_COPIED_SECTION_MIN_ARGS;
+use crate::exit::Failure;
+use crate::messages::println_tr;
+use crate::parser::code_parser::{safe_display, DEFAULT_ANNOTATION_MAX_GAP};
+use crate::parser::doc_parser::{DEFAULT_MARKDOWN_EXTENSIONS, DEFAULT_TITLE_MAX_DISTANCE};
+use crate::vfs;
+
+/// Variable de entorno con la ruta de la configuración; `--config` gana.
+pub const CONFIG_ENV: &str = "DOCSGUARD_CONFIG";
+
+const SHOW_HINT: &str =
+    "`docsguard config show --resolved` muestra las claves y los valores efectivos.";
+
+/// Un `--set clave=valor`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setting {
+    /// Ruta con puntos (`rules.type-mismatch`).
+    pub key: String,
+    /// Valor escalar en YAML (`off`, `0.9`, `true`).
+    pub value: String,
+}
+
+impl FromStr for Setting {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((key, value)) = s.split_once('=') else {
+            return Err(format!("se esperaba clave=valor: '{}'", s));
+        };
+        let key = key.trim();
+        if key.split('.').any(|segment| segment.trim().is_empty()) {
+            return Err(format!("clave vacía en '{}'", s));
+        }
+        Ok(Setting {
+            key: key.to_string(),
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+/// De dónde sale el archivo de configuración.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ConfigFile {
+    /// `.docsguard/config.yaml` del proyecto, si existe.
+    #[default]
+    Discovered,
+    /// `--config <archivo>`.
+    Flag(PathBuf),
+    /// `DOCSGUARD_CONFIG`.
+    Env(PathBuf),
+}
+
+/// Opciones de configuración de la línea de comandos.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigOverrides {
+    pub file: ConfigFile,
+    pub settings: Vec<Setting>,
+    /// `--ignore-fn`, tras los `ignore_functions` del archivo.
+    pub ignore_functions: Vec<String>,
+}
+
+impl ConfigOverrides {
+    /// `--config` si se dio; si no, `DOCSGUARD_CONFIG` (`env`) si no está vacía.
+    pub fn new(flag: Option<PathBuf>, env: Option<PathBuf>, settings: Vec<Setting>) -> Self {
+        let file = match (flag, env) {
+            (Some(path), _) => ConfigFile::Flag(path),
+            (None, Some(path)) if !path.as_os_str().is_empty() => ConfigFile::Env(path),
+            (None, _) => ConfigFile::Discovered,
+        };
+        ConfigOverrides {
+            file,
+            settings,
+            ignore_functions: Vec::new(),
+        }
+    }
+
+    /// Archivo elegido en la línea de comandos o el entorno.
+    pub fn path(&self) -> Option<&Path> {
+        match &self.file {
+            ConfigFile::Discovered => None,
+            ConfigFile::Flag(path) | ConfigFile::Env(path) => Some(path),
+        }
+    }
+}
+
+static ACTIVE: OnceLock<ConfigOverrides> = OnceLock::new();
+
+/// Fija las opciones del proceso. Solo cuenta la primera llamada.
+pub fn install(overrides: ConfigOverrides) {
+    let _ = ACTIVE.set(overrides);
+}
+
+/// Opciones fijadas con `install`; sin ellas (tests), ninguna.
+pub(crate) fn active() -> Option<&'static ConfigOverrides> {
+    ACTIVE.get()
+}
+
+/// Configuración de `content` (ya validado) con `settings` encima, en orden.
+pub(crate) fn apply(content: &str, settings: &[Setting]) -> Result<Config> {
+    let mut document = if content.trim().is_empty() {
+        Value::Mapping(Mapping::new())
+    } else {
+        serde_yml::from_str(content)?
+    };
+    let mut config = None;
+    for setting in settings {
+        set_path(&mut document, setting)?;
+        let parsed = serde_yml::from_value(document.clone())
+            .map_err(|e| setting_error(setting, &crate::yaml::strip_marks(&e.to_string())))?;
+        config = Some(parsed);
+    }
+    Ok(config.unwrap_or_default())
+}
+
+/// Fija `setting.value` en la ruta `setting.key` de `document`.
+fn set_path(document: &mut Value, setting: &Setting) -> Result<()> {
+    let value: Value = serde_yml::from_str(&setting.value)
+        .map_err(|e| setting_error(setting, &crate::yaml::strip_marks(&e.to_string())))?;
+    if matches!(
+        value,
+        Value::Sequence(_) | Value::Mapping(_) | Value::Tagged(_)
+    ) {
+        anyhow::bail!(setting_error(setting, "solo admite valores escalares"));
+    }
+
+    let segments: Vec<&str> = setting.key.split('.').map(str::trim).collect();
+    let (last, parents) = segments.split_last().expect("la clave tiene un segmento");
+    let mut section = as_section(document, setting, "")?;
+    for (depth, segment) in parents.iter().enumerate() {
+        let node = section
+            .entry(Value::String(segment.to_string()))
+            .or_insert(Value::Null);
+        section = as_section(node, setting, &segments[..=depth].join("."))?;
+    }
+    section.insert(Value::String(last.to_string()), value);
+    Ok(())
+}
+
+/// El mapeo de `node`, creándolo si está vacío; `prefix` lo nombra en el error.
+fn as_section<'a>(node: &'a mut Value, setting: &Setting, prefix: &str) -> Result<&'a mut Mapping> {
+    if node.is_null() {
+        *node = Value::Mapping(Mapping::new());
+    }
+    match node {
+        Value::Mapping(mapping) => Ok(mapping),
+        _ => anyhow::bail!(setting_error(
+            setting,
+            &format!("`{}` no es una sección", prefix)
+        )),
+    }
+}
+
+fn setting_error(setting: &Setting, reason: &str) -> Failure {
+    Failure::usage(format!(
+        "No se pudo aplicar --set {}={}: {}",
+        setting.key, setting.value, reason
+    ))
+    .with_hint(SHOW_HINT)
+}
+
+/// Error de un archivo elegido con `--config` o `DOCSGUARD_CONFIG` que no existe.
+pub(crate) fn missing_file(overrides: &ConfigOverrides, path: &Path) -> anyhow::Error {
+    let origin = match overrides.file {
+        ConfigFile::Env(_) => CONFIG_ENV,
+        _ => "--config",
+    };
+    Failure::usage(format!(
+        "No existe la configuración de {}: {}",
+        origin,
+        safe_display(path)
+    ))
+    .with_path(path)
+    .into()
+}
+
+impl Config {
+    /// `self` con los valores por defecto explícitos: niveles de todas las
+    /// reglas (por defecto → `preset` → archivo), distancias y extensiones de
+    /// Markdown sin valor.
+    pub fn resolved(self, preset: Option<Preset>) -> Self {
+        let mut config = self.with_preset(preset).with_preset(Some(Preset::Standard));
+        config.annotation_max_gap = Some(
+            config
+                .annotation_max_gap
+                .unwrap_or(DEFAULT_ANNOTATION_MAX_GAP),
+        );
+        config.title_max_distance = Some(
+            config
+                .title_max_distance
+                .unwrap_or(DEFAULT_TITLE_MAX_DISTANCE),
+        );
+        config.copied_section_min_args = Some(
+            config
+                .copied_section_min_args
+                .unwrap_or(DEFAULT_COPIED_SECTION_MIN_ARGS),
+        );
+        config
+            .markdown_extensions
+            .get_or_insert_with(|| DEFAULT_MARKDOWN_EXTENSIONS.to_vec());
+        config
+    }
+}
+
+/// Ejecuta `config show`: la configuración elegida o, con `resolved`, la
+/// efectiva con los valores por defecto, como YAML que se puede volver a cargar.
+pub fn run_show(project_root: &Path, preset: Option<Preset>, resolved: bool) -> Result<()> {
+    let config = Config::load(project_root)?;
+    let path = super::config_path(project_root);
+    let overrides = active().cloned().unwrap_or_default();
+    let origin = match overrides.file {
+        ConfigFile::Flag(_) => "--config",
+        ConfigFile::Env(_) => CONFIG_ENV,
+        ConfigFile::Discovered if vfs::exists(&path) => "del proyecto",
+        ConfigFile::Discovered => "no existe: valores por defecto",
+    };
+    println_tr!("# Configuración: {} ({})", safe_display(&path), origin);
+    for setting in &overrides.settings {
+        println_tr!("# --set {}={}", setting.key, setting.value);
+    }
+
+    if resolved {
+        println_tr!("# Preset: {}", preset.map_or("ninguno", Preset::name));
+        print!("{}", serde_yml::to_string(&config.resolved(preset))?);
+    } else if vfs::exists(&path) {
+        let content = crate::yaml::read(&path, super::MAX_CONFIG_SIZE, Failure::config)?;
+        print!("{}", content);
+        if !overrides.settings.is_empty() {
+            println_tr!("# Los --set no se muestran aquí; --resolved los incluye.");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::rules::RuleLevel;
+    use crate::core::types::Rule;
+
+    fn set(pair: &str) -> Setting {
+        pair.parse().unwrap()
+    }
+
+    fn error(content: &str, pair: &str) -> String {
+        format!("{:#}", apply(content, &[set(pair)]).unwrap_err())
+    }
+
+    #[test]
+    fn settings_need_a_key_and_an_equals_sign() {
+        assert_eq!(
+            set("rules.type-mismatch=off"),
+            Setting {
+                key: "rules.type-mismatch".into(),
+                value: "off".into()
+            }
+        );
+        assert!("rules.type-mismatch".parse::<Setting>().is_err());
+        assert!("rules..x=off".parse::<Setting>().is_err());
+        assert!("=off".parse::<Setting>().is_err());
+    }
+
+    #[test]
+    fn settings_go_on_top_of_the_file_and_create_missing_sections() {
+        let config = apply(
+            "annotation_max_gap: 3\nrules:\n  missing-arg: error\n",
+            &[
+                set("rules.type-mismatch=off"),
+                set("limits.max_sections=10"),
+                set("annotation_max_gap=0"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(config.rules.level(Rule::MissingArg), Some(RuleLevel::Error));
+        assert_eq!(config.rules.level(Rule::TypeMismatch), Some(RuleLevel::Off));
+        assert_eq!(config.limits.max_sections, 10);
+        assert_eq!(config.annotation_max_gap, Some(0));
+        assert_eq!(apply("", &[]).unwrap().annotation_max_gap, None);
+    }
+
+    #[test]
+    fn unknown_keys_wrong_types_and_non_scalars_are_rejected_with_the_setting() {
+        let unknown = error("", "checks.type_mismatch=off");
+        assert!(
+            unknown.contains("--set checks.type_mismatch=off"),
+            "{unknown}"
+        );
+        assert!(unknown.contains("checks"), "{unknown}");
+        assert!(error("", "limits.max_sections=many").contains("max_sections"));
+        assert!(error("", "rules.type-mismatch=fatal").contains("fatal"));
+        assert!(error("", "units=[px]").contains("escalares"));
+        assert!(error("annotation_max_gap: 1\n", "annotation_max_gap.x=1")
+            .contains("`annotation_max_gap` no es una sección"));
+    }
+
+    #[test]
+    fn the_flag_wins_over_the_environment_and_an_empty_variable_is_ignored() {
+        let flag = ConfigOverrides::new(Some("a.yaml".into()), Some("b.yaml".into()), vec![]);
+        assert_eq!(flag.path(), Some(Path::new("a.yaml")));
+        let env = ConfigOverrides::new(None, Some("b.yaml".into()), vec![]);
+        assert_eq!(env.file, ConfigFile::Env("b.yaml".into()));
+        let empty = ConfigOverrides::new(None, Some("".into()), vec![]);
+        assert_eq!(empty.file, ConfigFile::Discovered);
+    }
+
+    #[test]
+    fn the_resolved_configuration_lists_every_rule_and_loads_again() {
+        let config = Config::from_yaml("rules:\n  missing-arg: info\n")
+            .unwrap()
+            .resolved(Some(Preset::Strict));
+        assert_eq!(config.rules.level(Rule::MissingArg), Some(RuleLevel::Info));
+        assert_eq!(config.rules.level(Rule::GhostArg), Some(RuleLevel::Error));
+        assert_eq!(
+            config.rules.level(Rule::LinkVerified),
+            Some(RuleLevel::Info)
+        );
+        assert_eq!(config.annotation_max_gap, Some(DEFAULT_ANNOTATION_MAX_GAP));
+
+        let yaml = serde_yml::to_string(&config).unwrap();
+        let reloaded = Config::from_yaml(&yaml).unwrap();
+        assert_eq!(reloaded.rules, config.rules);
+        assert_eq!(reloaded.title_max_distance, config.title_max_distance);
+    }
+}
@@ -0,0 +1,535 @@
+//! Nivel por regla (`rules:` en `config.yaml`) y presets de validación.
+//!
+//! Un preset no es más que un `RuleLevels` incluido en el binario: se resuelve
+//! con la misma mezcla que la configuración del usuario. El orden es
+//! valores por defecto de cada validador → `--preset` → `rules:` del archivo,
+//! de modo que lo escrito a mano en `config.yaml` siempre gana.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use super::{config_path, Config};
+use crate::core::types::{Rule, Severity, ValidationResult};
+use crate::exit::Failure;
+use crate::messages::{self, print_tr, println_tr, tr};
+use crate::parser::code_parser::{self, safe_display};
+use crate::vfs;
+
+/// Nivel configurado de una regla. `off` descarta sus hallazgos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    Error,
+    Warning,
+    Info,
+    Off,
+}
+
+impl RuleLevel {
+    fn severity(self) -> Option<Severity> {
+        match self {
+            RuleLevel::Error => Some(Severity::Error),
+            RuleLevel::Warning => Some(Severity::Warning),
+            RuleLevel::Info => Some(Severity::Info),
+            RuleLevel::Off => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            RuleLevel::Error => "error",
+            RuleLevel::Warning => "warning",
+            RuleLevel::Info => "info",
+            RuleLevel::Off => "off",
+        }
+    }
+}
+
+/// Presets de validación incluidos en el binario (`--preset`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Preset {
+    /// Solo los enlaces rotos son Error; sin comprobación de argumentos.
+    Lenient,
+    /// Los valores por defecto de cada regla, explícitos.
+    Standard,
+    /// Tipos, argumentos y secciones huérfanas como Error; detecta descripciones de relleno.
+    Strict,
+}
+
+impl Preset {
+    pub fn name(self) -> &'static str {
+        match self {
+            Preset::Lenient => "lenient",
+            Preset::Standard => "standard",
+            Preset::Strict => "strict",
+        }
+    }
+
+    /// Niveles que fija el preset. Las reglas que dependen de un flag
+    /// (`--strict`, `--check-symbols`, `--check-versions`, `--time-budget`, `--verify-site`,
+    /// `--require-docs-for-new`, `--check-attestations`, supresiones sin uso) quedan
+    /// fuera: su severidad la decide el flag.
+    pub fn levels(self) -> RuleLevels {
+        use RuleLevel::*;
+        let arg_checks =
+            |level| [Rule::GhostArg, Rule::MissingArg, Rule::TypeMismatch].map(|r| (r, level));
+        let levels: Vec<(Rule, RuleLevel)> = match self {
+            Preset::Lenient => [
+                (Rule::UnlinkedFunction, Info),
+                (Rule::LinkVerified, Info),
+                (Rule::MissingDocSection, Error),
+                (Rule::OrphanSection, Info),
+                (Rule::ForbiddenLink, Error),
+                (Rule::ExampleMismatch, Info),
+                (Rule::ExampleArgMismatch, Info),
+                (Rule::MalformedAnnotation, Info),
+                (Rule::StaleMapping, Info),
+                (Rule::SkippedFile, Info),
+                (Rule::PlaceholderDescription, Off),
+                (Rule::ArgConstraint, Info),
+                (Rule::ConflictingArgDocs, Info),
+                (Rule::BrokenAnchor, Info),
+                (Rule::CrossLanguageDrift, Off),
+                (Rule::InternalSymbolReference, Off),
+                (Rule::CopiedSection, Info),
+                (Rule::DuplicateDocId, Error),
+                (Rule::VcsConflict, Warning),
+                (Rule::ConflictingDocsIds, Error),
+                (Rule::TruncatedDocs, Info),
+            ]
+            .into_iter()
+            .chain(arg_checks(Off))
+            .collect(),
+            Preset::Standard => DEFAULTS.to_vec(),
+            Preset::Strict => DEFAULTS
+                .iter()
+                .copied()
+                .chain([
+                    (Rule::OrphanSection, Error),
+                    (Rule::PlaceholderDescription, Warning),
+                ])
+                .chain(arg_checks(Error))
+                .collect(),
+        };
+        RuleLevels(levels.into_iter().collect())
+    }
+}
+
+/// Severidad con que cada validador emite sus hallazgos; `placeholder-description`,
+/// `cross-language-drift` e `internal-symbol-reference` son opt-in. Es también
+/// el contenido del preset `standard`.
+const DEFAULTS: [(Rule, RuleLevel); 24] = [
+    (Rule::UnlinkedFunction, RuleLevel::Info),
+    (Rule::LinkVerified, RuleLevel::Info),
+    (Rule::MissingDocSection, RuleLevel::Error),
+    (Rule::OrphanSection, RuleLevel::Warning),
+    (Rule::ForbiddenLink, RuleLevel::Error),
+    (Rule::GhostArg, RuleLevel::Error),
+    (Rule::MissingArg, RuleLevel::Warning),
+    (Rule::TypeMismatch, RuleLevel::Warning),
+    (Rule::ExampleMismatch, RuleLevel::Info),
+    (Rule::ExampleArgMismatch, RuleLevel::Warning),
+    (Rule::MalformedAnnotation, RuleLevel::Warning),
+    (Rule::StaleMapping, RuleLevel::Warning),
+    (Rule::SkippedFile, RuleLevel::Info),
+    (Rule::PlaceholderDescription, RuleLevel::Off),
+    (Rule::ArgConstraint, RuleLevel::Info),
+    (Rule::ConflictingArgDocs, RuleLevel::Warning),
+    (Rule::BrokenAnchor, RuleLevel::Warning),
+    (Rule::CrossLanguageDrift, RuleLevel::Off),
+    (Rule::InternalSymbolReference, RuleLevel::Off),
+    (Rule::CopiedSection, RuleLevel::Warning),
+    (Rule::DuplicateDocId, RuleLevel::Error),
+    (Rule::VcsConflict, RuleLevel::Warning),
+    (Rule::ConflictingDocsIds, RuleLevel::Error),
+    (Rule::TruncatedDocs, RuleLevel::Warning),
+];
+
+/// Reglas que solo se reportan con un nivel explícito (preset o `rules:`).
+const OPT_IN: [Rule; 3] = [
+    Rule::PlaceholderDescription,
+    Rule::CrossLanguageDrift,
+    Rule::InternalSymbolReference,
+];
+
+/// Reglas de estilo que, activas con cualquier nivel, se reportan como Info.
+const ALWAYS_INFO: [Rule; 1] = [Rule::InternalSymbolReference];
+
+/// Flag que activa una regla sin nivel por defecto.
+fn enabling_flag(rule: Rule) -> &'static str {
+    match rule {
+        Rule::SlowRun => "--time-budget",
+        Rule::UnknownExampleSymbol => "--check-symbols",
+        Rule::UnusedSuppression => "--report-unused-suppressions",
+        Rule::MovedBaselineEntry => ".docsguard/baseline.yaml",
+        Rule::SinceVersion => "--check-versions",
+        Rule::ArgTypo => "--check-arg-typos",
+        Rule::ArgException => "arg_exceptions",
+        Rule::LinkBoundary => "link_boundaries",
+        Rule::ArgNameStyle => "arg_names.report_differences",
+        Rule::ExpectedFunction => "@expects",
+        Rule::UnpublishedSection => "--verify-site",
+        Rule::UndocumentedNewFunction => "--require-docs-for-new",
+        Rule::ChangedSinceAttestation | Rule::MissingAttestation => "--check-attestations",
+        _ => "--strict",
+    }
+}
+
+/// Niveles por regla. Las reglas ausentes conservan la severidad del validador.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RuleLevels(BTreeMap<Rule, RuleLevel>);
+
+impl RuleLevels {
+    /// `self` con los niveles de `overrides` encima.
+    pub fn overridden_by(mut self, overrides: &RuleLevels) -> RuleLevels {
+        self.0.extend(overrides.0.iter().map(|(r, l)| (*r, *l)));
+        self
+    }
+
+    pub fn level(&self, rule: Rule) -> Option<RuleLevel> {
+        self.0.get(&rule).copied()
+    }
+
+    /// Indica si la regla opt-in está activa (con cualquier nivel distinto de `off`).
+    pub fn enabled(&self, rule: Rule) -> bool {
+        self.level(rule).is_some_and(|l| l != RuleLevel::Off)
+    }
+
+    /// Severidad de `result` con estos niveles; `None` si su regla está en
+    /// `off` o es opt-in (`placeholder-description`, `cross-language-drift`,
+    /// `internal-symbol-reference`) y no se activó. Las de `ALWAYS_INFO` son
+    /// Info con cualquier nivel activo.
+    ///
+    /// Depende solo del hallazgo y de los niveles: `config diff` evalúa con
+    /// ella los mismos hallazgos bajo dos configuraciones sin re-parsear.
+    pub fn resolve(&self, result: &ValidationResult) -> Option<Severity> {
+        match self.level(result.rule) {
+            Some(level) if ALWAYS_INFO.contains(&result.rule) => {
+                level.severity().map(|_| Severity::Info)
+            }
+            Some(level) => level.severity(),
+            None if OPT_IN.contains(&result.rule) => None,
+            None => Some(result.severity),
+        }
+    }
+
+    /// Descarta los hallazgos de reglas en `off` y fija la severidad del resto.
+    ///
+    /// Se aplica justo tras validar y antes del baseline, que guarda la severidad.
+    pub fn apply(&self, results: &mut Vec<ValidationResult>) {
+        results.retain_mut(|r| match self.resolve(r) {
+            Some(severity) => {
+                r.severity = severity;
+                true
+            }
+            None => false,
+        });
+    }
+
+    /// Forma expandida para `config.yaml`, una regla por línea.
+    pub fn to_yaml(&self) -> String {
+        let mut out = String::from("rules:\n");
+        for (rule, level) in &self.0 {
+            out.push_str(&format!("  {}: {}\n", rule.id(), level.name()));
+        }
+        out
+    }
+}
+
+impl Config {
+    /// Pone `preset` debajo de los `rules:` cargados del archivo.
+    pub fn with_preset(mut self, preset: Option<Preset>) -> Self {
+        if let Some(preset) = preset {
+            self.rules = preset.levels().overridden_by(&self.rules);
+        }
+        self
+    }
+}
+
+/// Tabla de niveles efectivos: regla, nivel y de dónde sale.
+pub fn explain_table(preset: Option<Preset>, file_rules: &RuleLevels) -> String {
+    let preset_rules = preset.map(Preset::levels).unwrap_or_default();
+    let defaults: RuleLevels = RuleLevels(DEFAULTS.into_iter().collect());
+    let mut out = format!(
+        "  {:<26} {:<8} {}\n",
+        tr("Regla"),
+        tr("Nivel"),
+        tr("Origen")
+    );
+    for rule in Rule::ALL {
+        let (level, origin) = if let Some(level) = file_rules.level(rule) {
+            (level.name(), "config.yaml".to_string())
+        } else if let Some(level) = preset_rules.level(rule) {
+            (
+                level.name(),
+                format!("preset {}", preset.map_or("", Preset::name)),
+            )
+        } else if let Some(level) = defaults.level(rule) {
+            (level.name(), tr("por defecto").into_owned())
+        } else {
+            (
+                "-",
+                tr(&format!("según {}", enabling_flag(rule))).into_owned(),
+            )
+        };
+        out.push_str(&format!("  {:<26} {:<8} {}\n", rule.id(), level, origin));
+    }
+    out
+}
+
+/// Ejecuta `explain`: imprime los niveles efectivos de cada regla.
+pub fn run_explain(preset: Option<Preset>, project_root: &Path) -> Result<()> {
+    let config = Config::load(project_root)?;
+    println_tr!("DocsGuard — Niveles efectivos por regla\n");
+    println_tr!(
+        "  Preset: {}",
+        preset.map_or("ninguno (valores por defecto)", Preset::name)
+    );
+    println_tr!(
+        "  Configuración: {}\n",
+        safe_display(&config_path(project_root))
+    );
+    print_tr!("{}", explain_table(preset, &config.rules));
+    if let Some(grace) = config.grace_period {
+        println_tr!(
+            "\n  Periodo de gracia: los Error de archivos de código con menos de {} desde su primer commit bajan a Warning.",
+            messages::DAYS.count(grace.days as usize)
+        );
+    }
+    Ok(())
+}
+
+/// Contenido de `config.yaml` que genera `init`.
+pub fn init_config(preset: Preset) -> String {
+    format!(
+        "# Configuración de DocsGuard generada con `docsguard init --preset {}`.\n\
+         # Niveles: error, warning, info u off. `docsguard explain` muestra los efectivos.\n\
+         {}",
+        preset.name(),
+        preset.levels().to_yaml()
+    )
+}
+
+/// Ejecuta `init`: escribe `.docsguard/config.yaml` con el preset expandido.
+pub fn run_init(preset: Preset, project_root: &Path, force: bool) -> Result<()> {
+    let path = config_path(project_root);
+    if vfs::exists(&path) && !force {
+        anyhow::bail!(Failure::usage(format!(
+            "Ya existe la configuración: {}",
+            safe_display(&path)
+        ))
+        .with_path(&path)
+        .with_hint("Usa --force para sobrescribirla."));
+    }
+    if let Some(dir) = path.parent() {
+        vfs::create_dir_all(dir).map_err(|e| {
+            anyhow::Error::new(e).context(format!("No se pudo crear: {}", dir.display()))
+        })?;
+    }
+    code_parser::atomic_write(&path, init_config(preset).as_bytes())?;
+    println_tr!(
+        "  Configuración escrita (preset {}): {}",
+        preset.name(),
+        safe_display(&path)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(rule: Rule, severity: Severity) -> ValidationResult {
+        ValidationResult {
+            severity,
+            rule,
+            message: String::new(),
+            function_name: None,
+            code_location: None,
+            doc_id: None,
+            doc_location: None,
+            hint: None,
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+
+    #[test]
+    fn preset_expansions_match_snapshot() {
+        let snapshot: String = [Preset::Lenient, Preset::Standard, Preset::Strict]
+            .map(|p| format!("# {}\n{}", p.name(), p.levels().to_yaml()))
+            .concat();
+        assert_eq!(
+            snapshot,
+            "\
+# lenient
+rules:
+  unlinked-function: info
+  link-verified: info
+  missing-doc-section: error
+  orphan-section: info
+  forbidden-link: error
+  ghost-arg: off
+  missing-arg: off
+  type-mismatch: off
+  example-mismatch: info
+  example-arg-mismatch: info
+  malformed-annotation: info
+  stale-mapping: info
+  skipped-file: info
+  placeholder-description: off
+  arg-constraint: info
+  conflicting-arg-docs: info
+  broken-anchor: info
+  cross-language-drift: off
+  internal-symbol-reference: off
+  copied-section: info
+  duplicate-doc-id: error
+  vcs-conflict: warning
+  DG001: error
+  DG006: info
+# standard
+rules:
+  unlinked-function: info
+  link-verified: info
+  missing-doc-section: error
+  orphan-section: warning
+  forbidden-link: error
+  ghost-arg: error
+  missing-arg: warning
+  type-mismatch: warning
+  example-mismatch: info
+  example-arg-mismatch: warning
+  malformed-annotation: warning
+  stale-mapping: warning
+  skipped-file: info
+  placeholder-description: off
+  arg-constraint: info
+  conflicting-arg-docs: warning
+  broken-anchor: warning
+  cross-language-drift: off
+  internal-symbol-reference: off
+  copied-section: warning
+  duplicate-doc-id: error
+  vcs-conflict: warning
+  DG001: error
+  DG006: warning
+# strict
+rules:
+  unlinked-function: info
+  link-verified: info
+  missing-doc-section: error
+  orphan-section: error
+  forbidden-link: error
+  ghost-arg: error
+  missing-arg: error
+  type-mismatch: error
+  example-mismatch: info
+  example-arg-mismatch: warning
+  malformed-annotation: warning
+  stale-mapping: warning
+  skipped-file: info
+  placeholder-description: warning
+  arg-constraint: info
+  conflicting-arg-docs: warning
+  broken-anchor: warning
+  cross-language-drift: off
+  internal-symbol-reference: off
+  copied-section: warning
+  duplicate-doc-id: error
+  vcs-conflict: warning
+  DG001: error
+  DG006: warning
+"
+        );
+    }
+
+    #[test]
+    fn init_output_parses_back_to_the_same_levels() {
+        for preset in [Preset::Lenient, Preset::Standard, Preset::Strict] {
+            let config = Config::from_yaml(&init_config(preset)).unwrap();
+            assert_eq!(config.rules, preset.levels(), "{}", preset.name());
+        }
+    }
+
+    #[test]
+    fn explicit_config_wins_over_the_preset() {
+        let config = Config::from_yaml("rules:\n  missing-arg: info\n")
+            .unwrap()
+            .with_preset(Some(Preset::Strict));
+        assert_eq!(config.rules.level(Rule::MissingArg), Some(RuleLevel::Info));
+        assert_eq!(
+            config.rules.level(Rule::TypeMismatch),
+            Some(RuleLevel::Error)
+        );
+        assert!(config.rules.enabled(Rule::PlaceholderDescription));
+
+        let table = explain_table(
+            Some(Preset::Strict),
+            &config_file_rules("missing-arg: info"),
+        );
+        assert!(
+            table.contains("  missing-arg                info     config.yaml\n"),
+            "{table}"
+        );
+        assert!(
+            table.contains("  type-mismatch              error    preset strict\n"),
+            "{table}"
+        );
+        assert!(
+            table.contains("  DG002                      -        según --strict\n"),
+            "{table}"
+        );
+    }
+
+    fn config_file_rules(yaml: &str) -> RuleLevels {
+        Config::from_yaml(&format!("rules:\n  {yaml}\n"))
+            .unwrap()
+            .rules
+    }
+
+    #[test]
+    fn apply_sets_severity_and_drops_rules_that_are_off() {
+        let mut results = vec![
+            result(Rule::GhostArg, Severity::Error),
+            result(Rule::OrphanSection, Severity::Warning),
+            result(Rule::SlowRun, Severity::Warning),
+        ];
+        Preset::Lenient.levels().apply(&mut results);
+        let kept: Vec<(Rule, Severity)> = results.iter().map(|r| (r.rule, r.severity)).collect();
+        assert_eq!(
+            kept,
+            vec![
+                (Rule::OrphanSection, Severity::Info),
+                (Rule::SlowRun, Severity::Warning)
+            ]
+        );
+    }
+
+    #[test]
+    fn internal_symbol_references_are_opt_in_and_always_info() {
+        let finding = result(Rule::InternalSymbolReference, Severity::Info);
+        assert_eq!(RuleLevels::default().resolve(&finding), None);
+        assert_eq!(Preset::Strict.levels().resolve(&finding), None);
+        for level in ["info", "warning", "error"] {
+            let rules = config_file_rules(&format!("internal-symbol-reference: {level}"));
+            assert_eq!(rules.resolve(&finding), Some(Severity::Info), "{level}");
+        }
+        let rules = config_file_rules("internal-symbol-reference: off");
+        assert_eq!(rules.resolve(&finding), None);
+    }
+
+    #[test]
+    fn unknown_rules_and_levels_are_rejected() {
+        assert!(Config::from_yaml("rules:\n  ghost-args: off\n").is_err());
+        assert!(Config::from_yaml("rules:\n  ghost-arg: fatal\n").is_err());
+        assert!(Config::from_yaml("rules:\n  DG004: warning\n").is_ok());
+    }
+}
@@ -0,0 +1,447 @@
+//! `conformance`: el corpus de anotaciones común a todos los lenguajes.
+//!
+//! Cada parser de lenguaje detecta las anotaciones a su manera (huecos,
+//! comentarios de bloque, atributos y decoradores…) y una regresión en uno
+//! solo pasaba entre sus tests unitarios. `fixtures/conformance/<lenguaje>/`
+//! tiene un archivo pequeño por escenario de `SCENARIOS` y, al lado, un YAML
+//! con lo que hay que extraer de él: cada función con su línea, sus
+//! argumentos y el ID enlazado (`doc_id: null` si la anotación no debe
+//! encontrarse), y las reglas de las notas del parser. El directorio de cada
+//! lenguaje se llama como su valor de `--code-language`.
+//!
+//! El corpus va embebido en el binario (`build.rs`): `docsguard conformance`
+//! comprueba el ejecutable instalado y el test de este módulo ejecuta lo
+//! mismo en `cargo test`. Un lenguaje debe traer todos los escenarios de
+//! `SCENARIOS`; uno que falte es un fallo. Un escenario que no existe en el
+//! lenguaje (Go no tiene atributos) se declara en su YAML con
+//! `not_applicable` y el motivo, que necesita su entrada en `messages::en`.
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Deserialize;
+use std::path::Path;
+
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::exit::Outcome;
+use crate::layout::{Layout, Style};
+use crate::messages::{println_tr, tr};
+use crate::parser::code_parser::{self, AnnotationOptions, Language};
+
+/// Escenarios que todo lenguaje debe cubrir.
+pub const SCENARIOS: [&str; 6] = [
+    "leading-annotation",
+    "trailing-annotation",
+    "gap-too-large",
+    "malformed-annotation",
+    "annotation-above-attribute",
+    "block-comment",
+];
+
+/// Directorio del corpus, relativo al repositorio.
+const CORPUS_DIR: &str = "fixtures/conformance";
+
+/// Un escenario de un lenguaje tal como lo embebe `build.rs`.
+struct RawSpecimen {
+    /// Directorio del lenguaje (`ts`, `rust`…).
+    language: &'static str,
+    scenario: &'static str,
+    file_name: Option<&'static str>,
+    source: Option<&'static str>,
+    /// El YAML de expectativas.
+    expectation: Option<&'static str>,
+}
+
+const CORPUS: &[RawSpecimen] = include!(concat!(env!("OUT_DIR"), "/conformance.rs"));
+
+/// Expectativas de un espécimen.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expectation {
+    /// Todas las funciones que deben extraerse, en ningún orden concreto.
+    #[serde(default)]
+    entities: Vec<ExpectedEntity>,
+    /// Reglas de las notas del parser (`malformed-annotation`, `DG002`…).
+    #[serde(default)]
+    notes: Vec<String>,
+    /// Motivo por el que el escenario no existe en el lenguaje.
+    #[serde(default)]
+    not_applicable: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExpectedEntity {
+    name: String,
+    line: usize,
+    #[serde(default)]
+    args: Vec<String>,
+    /// ID que debe enlazarse; `null` si no debe encontrarse anotación.
+    doc_id: Option<String>,
+}
+
+/// Resultado de un escenario en un lenguaje.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    /// Diferencias con las expectativas, una por línea.
+    Fail(Vec<String>),
+    /// El lenguaje no tiene el escenario; el motivo.
+    NotApplicable(String),
+    /// Falta el espécimen de un escenario obligatorio.
+    Missing,
+}
+
+/// Un escenario comprobado en un lenguaje.
+#[derive(Debug, Clone)]
+pub struct Case {
+    pub language: Language,
+    pub scenario: &'static str,
+    /// Espécimen relativo al corpus (`ts/gap-too-large.ts`).
+    pub file: String,
+    pub verdict: Verdict,
+}
+
+/// Directorio de `language` en el corpus: su valor de `--code-language`.
+fn language_dir(language: Language) -> String {
+    language
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default()
+}
+
+/// Comprueba el corpus embebido en cada lenguaje soportado: primero los
+/// escenarios de `SCENARIOS` y después los que un lenguaje añada por su cuenta.
+pub fn run_corpus() -> Vec<Case> {
+    let mut cases = Vec::new();
+    for &language in Language::value_variants() {
+        let dir = language_dir(language);
+        let own: Vec<&RawSpecimen> = CORPUS.iter().filter(|s| s.language == dir).collect();
+        for scenario in SCENARIOS {
+            let case = match own.iter().find(|s| s.scenario == scenario) {
+                Some(specimen) => check_specimen(language, specimen),
+                None => Case {
+                    language,
+                    scenario,
+                    file: format!("{}/", dir),
+                    verdict: Verdict::Missing,
+                },
+            };
+            cases.push(case);
+        }
+        for specimen in own.iter().filter(|s| !SCENARIOS.contains(&s.scenario)) {
+            cases.push(check_specimen(language, specimen));
+        }
+    }
+    cases
+}
+
+fn check_specimen(language: Language, specimen: &RawSpecimen) -> Case {
+    let file = format!(
+        "{}/{}",
+        specimen.language,
+        specimen.file_name.unwrap_or(specimen.scenario)
+    );
+    Case {
+        language,
+        scenario: specimen.scenario,
+        verdict: verdict(language, specimen, &file),
+        file,
+    }
+}
+
+fn verdict(language: Language, specimen: &RawSpecimen, file: &str) -> Verdict {
+    let Some(expectation) = specimen.expectation else {
+        return Verdict::Fail(vec![format!("Falta {}.yaml", specimen.scenario)]);
+    };
+    let expectation: Expectation = match serde_yml::from_str(expectation) {
+        Ok(expectation) => expectation,
+        Err(e) => {
+            return Verdict::Fail(vec![format!(
+                "{}.yaml no es válido: {}",
+                specimen.scenario, e
+            )])
+        }
+    };
+    if let Some(reason) = expectation.not_applicable {
+        return Verdict::NotApplicable(reason);
+    }
+    let (Some(file_name), Some(source)) = (specimen.file_name, specimen.source) else {
+        return Verdict::Fail(vec![format!(
+            "Falta el archivo de código de {}",
+            specimen.scenario
+        )]);
+    };
+    match Language::from_extension(Path::new(file_name)) {
+        Ok(detected) if detected == language => {}
+        _ => {
+            return Verdict::Fail(vec![format!(
+                "La extensión de {} no es de {}",
+                file_name,
+                language.name()
+            )])
+        }
+    }
+    let differences = compare(language, source, file, &expectation);
+    if differences.is_empty() {
+        Verdict::Pass
+    } else {
+        Verdict::Fail(differences)
+    }
+}
+
+/// Diferencias entre lo que extrae el parser de `source` y `expectation`.
+fn compare(language: Language, source: &str, file: &str, expectation: &Expectation) -> Vec<String> {
+    let mut diagnostics = ParseDiagnostics::default();
+    let entities = match code_parser::parse_code_source(
+        source,
+        language,
+        Path::new(file),
+        AnnotationOptions::default(),
+        &mut diagnostics,
+    ) {
+        Ok(entities) => entities,
+        Err(e) => return vec![format!("No se pudo parsear: {:#}", e)],
+    };
+
+    let mut differences = Vec::new();
+    for expected in &expectation.entities {
+        let Some(entity) = entities.iter().find(|e| e.name == expected.name) else {
+            differences.push(format!("No se extrajo la función '{}'", expected.name));
+            continue;
+        };
+        if entity.line != expected.line {
+            differences.push(format!(
+                "'{}': línea {} (se esperaba {})",
+                expected.name, entity.line, expected.line
+            ));
+        }
+        let args: Vec<&str> = entity.args.iter().map(|a| a.name.as_str()).collect();
+        if args != expected.args {
+            differences.push(format!(
+                "'{}': argumentos [{}] (se esperaba [{}])",
+                expected.name,
+                args.join(", "),
+                expected.args.join(", ")
+            ));
+        }
+        if entity.doc_id != expected.doc_id {
+            differences.push(format!(
+                "'{}': {} (se esperaba {})",
+                expected.name,
+                describe_link(entity.doc_id.as_deref()),
+                describe_link(expected.doc_id.as_deref())
+            ));
+        }
+    }
+    for entity in &entities {
+        if !expectation.entities.iter().any(|e| e.name == entity.name) {
+            differences.push(format!(
+                "Función no esperada '{}' (línea {})",
+                entity.name, entity.line
+            ));
+        }
+    }
+
+    let mut notes: Vec<&str> = diagnostics.notes.iter().map(|n| n.rule.id()).collect();
+    notes.sort_unstable();
+    let mut expected_notes: Vec<&str> = expectation.notes.iter().map(String::as_str).collect();
+    expected_notes.sort_unstable();
+    if notes != expected_notes {
+        differences.push(format!(
+            "Notas del parser [{}] (se esperaba [{}])",
+            notes.join(", "),
+            expected_notes.join(", ")
+        ));
+    }
+    differences
+}
+
+fn describe_link(doc_id: Option<&str>) -> String {
+    match doc_id {
+        Some(id) => format!("enlazada a '{}'", id),
+        None => "sin enlazar".to_string(),
+    }
+}
+
+impl Verdict {
+    /// Texto de la celda de la matriz, sin traducir.
+    fn cell(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "ok",
+            Verdict::Fail(_) => "falla",
+            Verdict::NotApplicable(_) => "n/a",
+            Verdict::Missing => "falta",
+        }
+    }
+
+    fn style(&self) -> Style {
+        match self {
+            Verdict::Pass => Style::Success,
+            Verdict::Fail(_) | Verdict::Missing => Style::Error,
+            Verdict::NotApplicable(_) => Style::Dim,
+        }
+    }
+
+    fn conforms(&self) -> bool {
+        matches!(self, Verdict::Pass | Verdict::NotApplicable(_))
+    }
+}
+
+/// Matriz escenario × lenguaje; `-` donde un lenguaje no tiene un escenario
+/// propio de otro.
+fn matrix_lines(cases: &[Case], layout: &Layout) -> Vec<String> {
+    let languages = Language::value_variants();
+    let mut scenarios: Vec<&str> = SCENARIOS.to_vec();
+    for case in cases {
+        if !scenarios.contains(&case.scenario) {
+            scenarios.push(case.scenario);
+        }
+    }
+    let header = tr("Escenario");
+    let first = scenarios
+        .iter()
+        .map(|s| s.len())
+        .chain([header.chars().count()])
+        .max()
+        .unwrap_or_default();
+    let widths: Vec<usize> = languages
+        .iter()
+        .map(|l| {
+            l.name().len().max(
+                ["falla", "falta"]
+                    .map(|c| tr(c).chars().count())
+                    .into_iter()
+                    .max()
+                    .unwrap_or(0),
+            )
+        })
+        .collect();
+
+    let mut lines = Vec::new();
+    let mut line = format!("  {:<first$}", header);
+    for (language, width) in languages.iter().zip(&widths) {
+        line.push_str(&format!("  {:<width$}", language.name()));
+    }
+    lines.push(layout.paint(Style::Heading, line.trim_end()).into_owned());
+    for scenario in scenarios {
+        let mut line = format!("  {:<first$}", scenario);
+        for (language, width) in languages.iter().zip(&widths) {
+            let case = cases
+                .iter()
+                .find(|c| c.language == *language && c.scenario == scenario);
+            let cell = match case {
+                Some(case) => {
+                    let text = format!("{:<width$}", tr(case.verdict.cell()));
+                    layout.paint(case.verdict.style(), &text).into_owned()
+                }
+                None => format!("{:<width$}", "-"),
+            };
+            line.push_str("  ");
+            line.push_str(&cell);
+        }
+        lines.push(line.trim_end().to_string());
+    }
+    lines
+}
+
+/// Comprueba los parsers con el corpus embebido y muestra la matriz de
+/// conformidad; falla si algún escenario falla o falta.
+pub fn run_conformance(layout: &Layout) -> Result<Outcome> {
+    let cases = run_corpus();
+    println_tr!("DocsGuard — Conformidad de los parsers con el corpus de anotaciones\n");
+    for line in matrix_lines(&cases, layout) {
+        println!("{}", line);
+    }
+
+    let mut failed = 0;
+    for case in &cases {
+        let details = match &case.verdict {
+            Verdict::Fail(details) => details.clone(),
+            Verdict::Missing => vec![tr("Falta el espécimen del escenario").into_owned()],
+            _ => continue,
+        };
+        failed += 1;
+        println!();
+        println!(
+            "  {} {} · {} ({}/{})",
+            layout.paint(Style::Error, "[x]"),
+            case.language.name(),
+            case.scenario,
+            CORPUS_DIR,
+            case.file
+        );
+        for detail in details {
+            println!("      {}", tr(&detail));
+        }
+    }
+
+    let mut not_applicable = 0;
+    for case in &cases {
+        if let Verdict::NotApplicable(reason) = &case.verdict {
+            if not_applicable == 0 {
+                println!();
+            }
+            not_applicable += 1;
+            let line = format!(
+                "  [-] {} · {}: {}",
+                case.language.name(),
+                case.scenario,
+                tr(reason)
+            );
+            println!("{}", layout.paint(Style::Dim, &line));
+        }
+    }
+    println!("---");
+    println_tr!(
+        "Resumen: {} de {} casos conformes ({} no aplicables)",
+        cases.iter().filter(|c| c.verdict.conforms()).count(),
+        cases.len(),
+        not_applicable
+    );
+    Ok(Outcome::failed_if(failed > 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_language_conforms_to_the_corpus() {
+        let failures: Vec<String> = run_corpus()
+            .into_iter()
+            .filter(|case| !case.verdict.conforms())
+            .map(|case| {
+                format!(
+                    "{} · {}: {:?}",
+                    case.language.name(),
+                    case.scenario,
+                    case.verdict
+                )
+            })
+            .collect();
+        assert!(failures.is_empty(), "{:#?}", failures);
+    }
+
+    #[test]
+    fn differences_name_the_function_and_what_was_expected() {
+        let expectation: Expectation = serde_yml::from_str(
+            "entities:\n  - name: login\n    line: 1\n    args: [user]\n    doc_id: auth-login\n",
+        )
+        .unwrap();
+        let differences = compare(
+            Language::TypeScript,
+            "// @docs [auth-login]\nexport function login(username: string) {}\n",
+            "ts/x.ts",
+            &expectation,
+        );
+        assert_eq!(
+            differences,
+            [
+                "'login': línea 2 (se esperaba 1)",
+                "'login': argumentos [username] (se esperaba [user])",
+                "'login': sin enlazar (se esperaba enlazada a 'auth-login')",
+                "Notas del parser [malformed-annotation] (se esperaba [])",
+            ]
+        );
+    }
+}
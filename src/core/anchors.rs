@@ -0,0 +1,584 @@
+//! Anclas de los encabezados y enlaces `#ancla` de las docs (regla `broken-anchor`).
+//!
+//! GitHub y los generadores de sitios no derivan igual el `id` de un
+//! encabezado: un enlace que funciona en el Markdown del repositorio puede dar
+//! 404 en el sitio publicado. `site.slugger` elige el algoritmo con que se
+//! calculan las anclas:
+//!
+//! - `github`: el de github-slugger. Minúsculas, fuera la puntuación salvo `-`
+//!   y `_`, cada espacio es un `-`; los títulos repetidos llevan `-1`, `-2`…
+//! - `docusaurus`: el mismo, salvo que un `{#id}` al final del título fija el
+//!   ancla (en GitHub ese `{#id}` es texto y entra en el ancla).
+//! - `custom`: uno de los dos como base, quitando antes del título los sufijos
+//!   de `strip_suffixes` y con otro separador para los repetidos.
+//!
+//! Las funciones de anclas son puras; `--verify-site` (feature `verify-site`)
+//! solo consulta por red las URLs que se derivan de aquí.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+
+use crate::config::SiteConfig;
+use crate::core::types::{DocSection, Rule, Severity, ValidationResult};
+use crate::parser::doc_parser::{build_line_offsets, offset_to_line, read_markdown_file};
+
+/// Algoritmo con que el sitio deriva el ancla de un encabezado.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SlugStyle {
+    #[default]
+    Github,
+    Docusaurus,
+    Custom,
+}
+
+impl SlugStyle {
+    pub fn name(self) -> &'static str {
+        match self {
+            SlugStyle::Github => "github",
+            SlugStyle::Docusaurus => "docusaurus",
+            SlugStyle::Custom => "custom",
+        }
+    }
+}
+
+/// Algoritmo de partida de `slugger: custom`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SlugBase {
+    #[default]
+    Github,
+    Docusaurus,
+}
+
+/// Reglas de `slugger: custom`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomSlugRules {
+    #[serde(default)]
+    pub base: SlugBase,
+    /// Sufijos del título que el sitio no lleva al ancla (`()`, ` (deprecated)`).
+    #[serde(default)]
+    pub strip_suffixes: Vec<String>,
+    /// Separador ante el número de un título repetido; sin valor, `-`.
+    #[serde(default)]
+    pub duplicate_separator: Option<String>,
+}
+
+/// Ancla de `text` con el algoritmo de github-slugger, sin contar repetidos.
+pub fn github_slug(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter(|&c| c.is_alphanumeric() || matches!(c, '-' | '_' | ' ') || is_combining_mark(c))
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+/// `{#id}` al final de un título: el título sin él y el id.
+fn explicit_id(text: &str) -> Option<(&str, &str)> {
+    let trimmed = text.trim_end();
+    let inner = trimmed.strip_suffix('}')?;
+    let open = inner.rfind("{#")?;
+    let id = &inner[open + 2..];
+    (!id.is_empty() && !id.contains(char::is_whitespace)).then(|| (inner[..open].trim_end(), id))
+}
+
+/// Marcas combinadas (acentos sueltos), que github-slugger conserva.
+fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{300}'..='\u{36F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+/// Anclas de los encabezados de una página, en orden: recuerda las ya dadas
+/// para numerar los títulos repetidos.
+pub struct Slugger<'a> {
+    style: SlugStyle,
+    custom: &'a CustomSlugRules,
+    occurrences: HashMap<String, usize>,
+}
+
+impl<'a> Slugger<'a> {
+    pub fn new(style: SlugStyle, custom: &'a CustomSlugRules) -> Self {
+        Slugger {
+            style,
+            custom,
+            occurrences: HashMap::new(),
+        }
+    }
+
+    /// Ancla del siguiente encabezado de la página.
+    pub fn slug(&mut self, title: &str) -> String {
+        let mut title = title.trim();
+        let (base, separator) = match self.style {
+            SlugStyle::Github => (SlugBase::Github, "-"),
+            SlugStyle::Docusaurus => (SlugBase::Docusaurus, "-"),
+            SlugStyle::Custom => {
+                for suffix in &self.custom.strip_suffixes {
+                    if let Some(rest) = title.strip_suffix(suffix.as_str()) {
+                        title = rest.trim_end();
+                    }
+                }
+                (
+                    self.custom.base,
+                    self.custom.duplicate_separator.as_deref().unwrap_or("-"),
+                )
+            }
+        };
+        if base == SlugBase::Docusaurus {
+            if let Some((_, id)) = explicit_id(title) {
+                self.occurrences.insert(id.to_string(), 0);
+                return id.to_string();
+            }
+        }
+        // Igual que github-slugger: si `uso-1` ya existe, `uso-1-1`
+        let original = github_slug(title);
+        let mut result = original.clone();
+        while self.occurrences.contains_key(&result) {
+            let count = self.occurrences.entry(original.clone()).or_insert(0);
+            *count += 1;
+            result = format!("{}{}{}", original, separator, count);
+        }
+        self.occurrences.insert(result.clone(), 0);
+        result
+    }
+}
+
+/// Encabezado de una página con su ancla.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heading {
+    pub line: usize,
+    pub title: String,
+    pub anchor: String,
+}
+
+/// Anclas de una página: las de sus encabezados y las HTML (`<a id>`, `<a name>`).
+#[derive(Debug, Clone, Default)]
+pub struct PageAnchors {
+    pub headings: Vec<Heading>,
+    html: HashSet<String>,
+}
+
+impl PageAnchors {
+    /// Anclas de `source` con el algoritmo de `site`.
+    pub fn parse(source: &str, site: &SiteConfig) -> Self {
+        Self::parse_with(source, site.slugger, &site.custom)
+    }
+
+    fn parse_with(source: &str, style: SlugStyle, custom: &CustomSlugRules) -> Self {
+        let line_offsets = build_line_offsets(source);
+        let mut slugger = Slugger::new(style, custom);
+        let mut anchors = PageAnchors::default();
+        let mut heading: Option<(usize, String)> = None;
+        for (event, range) in Parser::new_ext(source, markdown_options()).into_offset_iter() {
+            match event {
+                Event::Start(Tag::Heading { .. }) => {
+                    heading = Some((offset_to_line(&line_offsets, range.start), String::new()));
+                }
+                Event::Text(text) | Event::Code(text) => {
+                    if let Some((_, title)) = heading.as_mut() {
+                        title.push_str(&text);
+                    }
+                }
+                Event::End(TagEnd::Heading(_)) => {
+                    if let Some((line, title)) = heading.take() {
+                        let anchor = slugger.slug(&title);
+                        anchors.headings.push(Heading {
+                            line,
+                            title,
+                            anchor,
+                        });
+                    }
+                }
+                Event::Html(html) | Event::InlineHtml(html) => {
+                    anchors.html.extend(html_ids(&html));
+                }
+                _ => {}
+            }
+        }
+        anchors
+    }
+
+    pub fn contains(&self, anchor: &str) -> bool {
+        self.html.contains(anchor) || self.headings.iter().any(|h| h.anchor == anchor)
+    }
+
+    /// Ancla más parecida a `anchor`, si se parece lo bastante.
+    fn closest(&self, anchor: &str) -> Option<&str> {
+        self.headings
+            .iter()
+            .map(|h| h.anchor.as_str())
+            .chain(self.html.iter().map(String::as_str))
+            .map(|candidate| (strsim::normalized_levenshtein(anchor, candidate), candidate))
+            .filter(|(score, _)| *score >= 0.6)
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, candidate)| candidate)
+    }
+}
+
+fn markdown_options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH
+}
+
+/// Valores de los `id="…"` y `name="…"` de un fragmento HTML.
+fn html_ids(html: &str) -> Vec<String> {
+    let mut ids = Vec::new();
+    for attribute in ["id=", "name="] {
+        let mut rest = html;
+        while let Some(start) = rest.find(attribute) {
+            let preceded_by_space = rest[..start]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_whitespace);
+            rest = &rest[start + attribute.len()..];
+            let Some(quote) = rest.chars().next().filter(|c| matches!(c, '"' | '\'')) else {
+                continue;
+            };
+            let Some(end) = rest[1..].find(quote) else {
+                break;
+            };
+            if preceded_by_space {
+                ids.push(rest[1..1 + end].to_string());
+            }
+            rest = &rest[1 + end..];
+        }
+    }
+    ids
+}
+
+/// Hallazgos `broken-anchor` de los enlaces con `#ancla` de `source` (el
+/// contenido de `doc_file`), hacia la propia página o hacia otro Markdown
+/// local (`guia.md#uso`). Los enlaces a archivos que no existen se ignoran.
+pub fn validate_anchors(
+    source: &str,
+    doc_file: &Path,
+    doc_path: &Path,
+    sections: &[DocSection],
+    site: &SiteConfig,
+) -> Vec<ValidationResult> {
+    let own = PageAnchors::parse(source, site);
+    let mut others: HashMap<PathBuf, Option<PageAnchors>> = HashMap::new();
+    let mut results = Vec::new();
+    for (line, destination) in fragment_links(source) {
+        let Some((file, fragment)) = destination.split_once('#') else {
+            continue;
+        };
+        let fragment = percent_decode(fragment);
+        if fragment.is_empty() {
+            continue;
+        }
+        let (anchors, page, linked) = if file.is_empty() {
+            (Some(&own), source.to_string(), None)
+        } else {
+            let path = doc_file.parent().unwrap_or(Path::new("")).join(file);
+            let anchors = others.entry(path.clone()).or_insert_with(|| {
+                read_markdown_file(&path)
+                    .ok()
+                    .map(|content| PageAnchors::parse(&content, site))
+            });
+            (anchors.as_ref(), String::new(), Some(path))
+        };
+        let Some(anchors) = anchors else { continue };
+        if anchors.contains(&fragment) {
+            continue;
+        }
+        let shown = match &linked {
+            Some(_) => file.to_string(),
+            None => doc_path.display().to_string(),
+        };
+        let hint = github_hint(&page, linked.as_deref(), anchors, &fragment, site)
+            .or_else(|| {
+                anchors
+                    .closest(&fragment)
+                    .map(|closest| format!("¿Quisiste decir '#{}'?", closest))
+            })
+            .unwrap_or_else(|| {
+                "Corrige el enlace o el encabezado, o revisa `site.slugger` en config.yaml."
+                    .to_string()
+            });
+        results.push(ValidationResult {
+            severity: Severity::Warning,
+            rule: Rule::BrokenAnchor,
+            message: format!(
+                "El enlace '{}' apunta a un ancla que no existe en {} (anclas de {}).",
+                destination,
+                shown,
+                site.slugger.name()
+            ),
+            function_name: None,
+            code_location: None,
+            doc_id: enclosing_section(sections, line).map(|s| s.id.clone()),
+            doc_location: Some(format!("{}:{}", doc_path.display(), line)),
+            hint: Some(hint),
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        });
+    }
+    results
+}
+
+/// Si el ancla rota es la que daría GitHub, la que genera el sitio para el
+/// mismo encabezado: el caso de un enlace que funciona en el repositorio.
+fn github_hint(
+    own_source: &str,
+    linked: Option<&Path>,
+    anchors: &PageAnchors,
+    fragment: &str,
+    site: &SiteConfig,
+) -> Option<String> {
+    if site.slugger == SlugStyle::Github {
+        return None;
+    }
+    let source = match linked {
+        Some(path) => read_markdown_file(path).ok()?,
+        None => own_source.to_string(),
+    };
+    let github = PageAnchors::parse_with(&source, SlugStyle::Github, &CustomSlugRules::default());
+    let index = github.headings.iter().position(|h| h.anchor == fragment)?;
+    let heading = anchors.headings.get(index)?;
+    Some(format!(
+        "'#{}' es el ancla de GitHub; con `slugger: {}` el encabezado \"{}\" es '#{}'.",
+        fragment,
+        site.slugger.name(),
+        heading.title,
+        heading.anchor
+    ))
+}
+
+/// Enlaces de `source` con `#` y su línea, salvo los absolutos (`https:`, `/…`).
+fn fragment_links(source: &str) -> Vec<(usize, String)> {
+    let line_offsets = build_line_offsets(source);
+    Parser::new_ext(source, markdown_options())
+        .into_offset_iter()
+        .filter_map(|(event, range)| match event {
+            Event::Start(Tag::Link { dest_url, .. }) => Some((range.start, dest_url)),
+            _ => None,
+        })
+        .filter(|(_, destination)| {
+            destination.contains('#') && !destination.starts_with('/') && !has_scheme(destination)
+        })
+        .map(|(offset, destination)| {
+            (
+                offset_to_line(&line_offsets, offset),
+                destination.to_string(),
+            )
+        })
+        .collect()
+}
+
+fn has_scheme(destination: &str) -> bool {
+    destination.split_once(':').is_some_and(|(scheme, _)| {
+        !scheme.is_empty()
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
+
+/// Deshace los `%XX` de un fragmento (`caf%C3%A9` → `café`).
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                out.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                out.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Sección en la que cae `line`: la última que empieza antes.
+fn enclosing_section(sections: &[DocSection], line: usize) -> Option<&DocSection> {
+    sections
+        .iter()
+        .filter(|s| s.line <= line)
+        .max_by_key(|s| s.line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::diagnostics::ParseDiagnostics;
+    use crate::parser::doc_parser::parse_markdown_source;
+    use crate::vfs::MemFs;
+
+    fn site(slugger: SlugStyle) -> SiteConfig {
+        SiteConfig {
+            slugger,
+            ..SiteConfig::default()
+        }
+    }
+
+    fn slugs(style: SlugStyle, custom: &CustomSlugRules, titles: &[&str]) -> Vec<String> {
+        let mut slugger = Slugger::new(style, custom);
+        titles.iter().map(|t| slugger.slug(t)).collect()
+    }
+
+    /// Vectores contrastados con github-slugger 2.0 (el de GitHub y Docusaurus).
+    #[test]
+    fn github_slugs_match_github_slugger() {
+        for (title, slug) in [
+            ("Hello World", "hello-world"),
+            ("createUser(name, email)", "createusername-email"),
+            ("C++ & Rust", "c--rust"),
+            ("API v2.0", "api-v20"),
+            ("snake_case and kebab-case", "snake_case-and-kebab-case"),
+            ("Emoji 🎉 party", "emoji--party"),
+            ("Café crème", "café-crème"),
+            ("Cafe\u{301}", "cafe\u{301}"),
+            ("名前を入力", "名前を入力"),
+            ("Login {#auth-login}", "login-auth-login"),
+            ("¿Qué es?", "qué-es"),
+        ] {
+            assert_eq!(github_slug(title), slug, "{title}");
+        }
+        assert_eq!(
+            slugs(
+                SlugStyle::Github,
+                &CustomSlugRules::default(),
+                &["Usage", "Usage", "Usage-1", "Usage"]
+            ),
+            ["usage", "usage-1", "usage-1-1", "usage-2"]
+        );
+    }
+
+    #[test]
+    fn docusaurus_honours_explicit_ids_and_custom_rules_reshape_slugs() {
+        let none = CustomSlugRules::default();
+        assert_eq!(
+            slugs(
+                SlugStyle::Docusaurus,
+                &none,
+                &["Login {#auth-login}", "Set {a} {b}", "Hello World"]
+            ),
+            ["auth-login", "set-a-b", "hello-world"]
+        );
+        assert_eq!(
+            slugs(SlugStyle::Docusaurus, &none, &["Login {#login}", "Login"]),
+            ["login", "login-1"]
+        );
+        let custom = CustomSlugRules {
+            base: SlugBase::Docusaurus,
+            strip_suffixes: vec!["()".into(), " (deprecated)".into()],
+            duplicate_separator: Some("_".into()),
+        };
+        assert_eq!(
+            slugs(
+                SlugStyle::Custom,
+                &custom,
+                &["createUser()", "createUser (deprecated)", "Old {#old-api}"]
+            ),
+            ["createuser", "createuser_1", "old-api"]
+        );
+    }
+
+    const DOCS: &str = "\
+<!-- @docs-id: auth-login -->
+## Login {#auth-login}
+
+See [logout](#auth-logout), [the table](#parameters) and [top](#).
+
+### Parameters
+
+<!-- @docs-id: auth-logout -->
+## Logout {#auth-logout}
+
+<a id=\"legacy\"></a>
+Bad: [old](#legacy) [typo](#paramters) [guide](guide.md#setup) [gone](missing.md#x)
+[web](https://example.com/#nope)
+";
+
+    fn sections() -> Vec<DocSection> {
+        parse_markdown_source(
+            DOCS,
+            Path::new("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn anchors_are_checked_with_the_configured_slugger() {
+        let dir = MemFs::project();
+        let doc_file = dir.path().join("api.md");
+        dir.write(dir.path().join("guide.md"), "# Setup (deprecated)\n");
+        let sections = sections();
+        let run = |site: &SiteConfig| {
+            validate_anchors(DOCS, &doc_file, Path::new("docs/api.md"), &sections, site)
+        };
+
+        // En GitHub el `{#…}` forma parte del ancla: '#auth-logout' no existe
+        let github = run(&site(SlugStyle::Github));
+        let broken: Vec<(&str, &str)> = github
+            .iter()
+            .map(|r| (r.doc_location.as_deref().unwrap(), r.message.as_str()))
+            .collect();
+        assert_eq!(broken.len(), 3, "{broken:#?}");
+        assert!(broken[0].1.contains("'#auth-logout'"));
+        assert_eq!(broken[0].0, "docs/api.md:4");
+        assert_eq!(github[0].doc_id.as_deref(), Some("auth-login"));
+        assert!(broken[1].1.contains("'#paramters'"));
+        assert_eq!(
+            github[1].hint.as_deref(),
+            Some("¿Quisiste decir '#parameters'?")
+        );
+        assert_eq!(github[1].doc_id.as_deref(), Some("auth-logout"));
+        assert!(broken[2].1.contains("'guide.md#setup'") && broken[2].1.contains("en guide.md"));
+
+        // En Docusaurus sí; el sufijo de la guía se quita con `custom`
+        let docusaurus = run(&site(SlugStyle::Docusaurus));
+        assert_eq!(docusaurus.len(), 2);
+        let custom = SiteConfig {
+            slugger: SlugStyle::Custom,
+            custom: CustomSlugRules {
+                base: SlugBase::Docusaurus,
+                strip_suffixes: vec![" (deprecated)".into()],
+                duplicate_separator: None,
+            },
+            ..SiteConfig::default()
+        };
+        assert_eq!(run(&custom).len(), 1);
+    }
+
+    #[test]
+    fn links_that_work_on_github_get_the_site_anchor_as_hint() {
+        let source = "## Login {#auth-login}\n\nSee [login](#login-auth-login).\n";
+        let results = validate_anchors(
+            source,
+            Path::new("api.md"),
+            Path::new("api.md"),
+            &[],
+            &site(SlugStyle::Docusaurus),
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].hint.as_deref(),
+            Some("'#login-auth-login' es el ancla de GitHub; con `slugger: docusaurus` el encabezado \"Login {#auth-login}\" es '#auth-login'.")
+        );
+        assert_eq!(percent_decode("caf%C3%A9%2"), "café%2");
+    }
+}
@@ -0,0 +1,174 @@
+//! Conflictos de git sin resolver en los archivos que se parsean.
+//!
+//! Un archivo a medio merge o rebase tiene las dos versiones a la vez: lo
+//! que se valida en él no es fiable y lo que se escriba encima se mezcla con
+//! los marcadores. `check`, `check-all` y `watch` lo reportan como
+//! `vcs-conflict` (Warning); `scaffold`, `fix` y `check --fix` se niegan a
+//! escribir si algún archivo leído lo tiene, y `triage` y
+//! `watch --interactive-fixes` no ofrecen correcciones que lo editen.
+//!
+//! Solo cuenta la estructura completa, en orden y al inicio de línea:
+//! `<<<<<<<`, opcionalmente `|||||||` (estilo `diff3`), `=======` y
+//! `>>>>>>>`, cada marcador con exactamente siete caracteres seguidos de un
+//! espacio o del fin de línea. Un `<<<<<<<` suelto en un bloque de código, o
+//! el subrayado `=======` de un encabezado setext, no son conflictos.
+//!
+//! La búsqueda corre en cada parseo, así que es una sola pasada hacia
+//! delante con búsquedas de subcadena (sin recorrer las líneas) que para en
+//! el primer conflicto completo, o en cuanto falta la siguiente pieza: sin
+//! un `=======` tras la apertura, ni un `>>>>>>>` tras el separador, no
+//! puede haber ninguno más adelante. La línea solo se cuenta si lo hay.
+
+use anyhow::Result;
+use std::path::Path;
+
+use super::diagnostics::{ParseDiagnostics, ParseNote};
+use super::types::{parse_location, Rule};
+use crate::exit::Failure;
+use crate::paths::paths_match;
+
+const OPEN: &str = "<<<<<<<";
+const SEPARATOR: &str = "=======";
+const CLOSE: &str = ">>>>>>>";
+
+/// Línea (desde 1) del `<<<<<<<` del primer conflicto completo de `source`.
+pub fn find(source: &str) -> Option<usize> {
+    let mut open = next_marker(source, 0, source.len(), OPEN)?;
+    loop {
+        let separator = next_marker(source, open, source.len(), SEPARATOR)?;
+        // Una apertura suelta antes (un ejemplo en un bloque de código) no es la del conflicto
+        while let Some(later) = next_marker(source, open + OPEN.len(), separator, OPEN) {
+            open = later;
+        }
+        let close = next_marker(source, separator, source.len(), CLOSE)?;
+        match next_marker(source, separator, close, OPEN) {
+            // Otra apertura antes del cierre: el conflicto empieza en ella
+            Some(reopened) => open = reopened,
+            None => return Some(source[..open].matches('\n').count() + 1),
+        }
+    }
+}
+
+/// Inicio del primer marcador de `source[from..to]` al inicio de línea:
+/// exactamente `marker`, seguido de fin de línea o, salvo el separador, de
+/// un espacio.
+fn next_marker(source: &str, from: usize, to: usize, marker: &str) -> Option<usize> {
+    let bytes = source.as_bytes();
+    source[from..to]
+        .match_indices(marker)
+        .map(|(i, _)| from + i)
+        .find(|&i| {
+            let after = bytes.get(i + marker.len());
+            (i == 0 || bytes[i - 1] == b'\n')
+                && match after {
+                    None | Some(b'\n') | Some(b'\r') => true,
+                    Some(b' ') => marker != SEPARATOR,
+                    _ => false,
+                }
+        })
+}
+
+/// Anota un `vcs-conflict` si `source` tiene un conflicto; la ubicación es
+/// de docs si `in_docs`, de código si no.
+pub fn note(source: &str, display_path: &Path, in_docs: bool, diagnostics: &mut ParseDiagnostics) {
+    let Some(line) = find(source) else {
+        return;
+    };
+    let location = format!("{}:{}", display_path.display(), line);
+    diagnostics.push(ParseNote {
+        rule: Rule::VcsConflict,
+        message: format!(
+            "Conflicto de git sin resolver (`<<<<<<<` en la línea {}): lo validado en este archivo puede no ser fiable.",
+            line
+        ),
+        function_name: None,
+        code_location: (!in_docs).then(|| location.clone()),
+        doc_id: None,
+        doc_location: in_docs.then_some(location),
+        hint: None,
+    });
+}
+
+/// Error de entrada si algún archivo parseado tiene un conflicto: los
+/// comandos que escriben no tocan nada hasta que se resuelva.
+pub fn refuse_writes(diagnostics: &ParseDiagnostics) -> Result<()> {
+    refusal(conflict_locations(diagnostics).next())
+}
+
+/// Como `refuse_writes`, solo con el conflicto de `file`: para quien escribe
+/// en un archivo concreto (`triage`, `watch --interactive-fixes`).
+pub fn refuse_writes_to(diagnostics: &ParseDiagnostics, file: &Path) -> Result<()> {
+    refusal(conflict_locations(diagnostics).find(|location| {
+        parse_location(location).is_some_and(|(conflicted, _)| paths_match(conflicted, file))
+    }))
+}
+
+/// Ubicaciones `archivo:línea` de los conflictos anotados.
+fn conflict_locations(diagnostics: &ParseDiagnostics) -> impl Iterator<Item = &String> {
+    diagnostics
+        .notes
+        .iter()
+        .filter(|n| n.rule == Rule::VcsConflict)
+        .filter_map(|n| n.code_location.as_ref().or(n.doc_location.as_ref()))
+}
+
+fn refusal(location: Option<&String>) -> Result<()> {
+    let Some(location) = location else {
+        return Ok(());
+    };
+    Err(Failure::input(format!(
+        "{}: conflicto de git sin resolver (`<<<<<<<`); no se modifica ningún archivo.",
+        location
+    ))
+    .with_hint("Resuelve el conflicto (o termina el merge o el rebase) y vuelve a ejecutar.")
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_complete_conflict_is_found_at_its_opening_marker() {
+        let source = "# API\n\n<<<<<<< HEAD\n| user | string |\n=======\n| login | string |\n>>>>>>> feature\n";
+        assert_eq!(find(source), Some(3));
+        let diff3 = "a\r\n<<<<<<< ours\r\nb\r\n||||||| base\r\n=======\r\nc\r\n>>>>>>>\r\n";
+        assert_eq!(find(diff3), Some(2));
+        // Una apertura entre el separador y el cierre reinicia el conflicto
+        let reopened = "<<<<<<< a\n=======\n<<<<<<< b\nx\n=======\n>>>>>>> b\n";
+        assert_eq!(find(reopened), Some(3));
+    }
+
+    #[test]
+    fn lone_markers_and_setext_headings_are_not_conflicts() {
+        let fenced = "```text\n<<<<<<< HEAD\n```\n\nTítulo\n=======\n\nTexto.\n";
+        assert_eq!(find(fenced), None);
+        // El cierre antes de la apertura no cuenta
+        assert_eq!(find(">>>>>>> a\n=======\n<<<<<<< b\n"), None);
+        // Ocho caracteres o texto pegado al marcador no son marcadores
+        assert_eq!(find("<<<<<<<<\n=======\n>>>>>>>\n"), None);
+        assert_eq!(find("x <<<<<<< a\n=======\n>>>>>>>\n"), None);
+        assert_eq!(find("<<<<<<<a\n=======\n>>>>>>>\n"), None);
+    }
+
+    #[test]
+    fn only_conflicts_block_writes() {
+        let mut diagnostics = ParseDiagnostics::default();
+        refuse_writes(&diagnostics).unwrap();
+        note(
+            "<<<<<<< a\n=======\n>>>>>>> b\n",
+            Path::new("docs/api.md"),
+            true,
+            &mut diagnostics,
+        );
+        assert_eq!(
+            diagnostics.notes[0].doc_location.as_deref(),
+            Some("docs/api.md:1")
+        );
+        let error = refuse_writes(&diagnostics).unwrap_err().to_string();
+        assert!(error.starts_with("docs/api.md:1: conflicto"), "{error}");
+        // Solo el archivo en conflicto se niega a escribir
+        assert!(refuse_writes_to(&diagnostics, Path::new("/repo/docs/api.md")).is_err());
+        refuse_writes_to(&diagnostics, Path::new("src/lib.rs")).unwrap();
+    }
+}
@@ -0,0 +1,271 @@
+//! Unidades y rangos declarados en las descripciones de los argumentos.
+//!
+//! Minería de texto conservadora con listas explícitas de palabras clave, no
+//! NLP: `timeout (number): en milisegundos, 100–30000` da la unidad `ms` y el
+//! rango `100–30000`. Una descripción que nombra dos unidades distintas no
+//! tiene unidad; lo que parece una fecha o una versión no es un rango.
+
+use std::collections::BTreeMap;
+
+use crate::core::types::{Arg, CodeEntity, DocSection, ValueRange};
+
+/// Palabras clave incorporadas por unidad canónica, en inglés y en español.
+/// `units:` en `.docsguard/config.yaml` añade más.
+const BUILTIN_UNITS: &[(&str, &[&str])] = &[
+    (
+        "ms",
+        &[
+            "ms",
+            "msec",
+            "millisecond",
+            "milliseconds",
+            "milisegundo",
+            "milisegundos",
+        ],
+    ),
+    (
+        "s",
+        &[
+            "s", "sec", "secs", "second", "seconds", "segundo", "segundos",
+        ],
+    ),
+    ("bytes", &["byte", "bytes", "octeto", "octetos"]),
+    ("%", &["%", "percent", "percentage", "porcentaje"]),
+];
+
+/// Tabla palabra clave → unidad canónica.
+#[derive(Debug, Clone)]
+pub struct UnitKeywords(BTreeMap<String, String>);
+
+impl UnitKeywords {
+    /// Las palabras incorporadas más las de `units:` (unidad → palabras).
+    pub fn new(extra: &BTreeMap<String, Vec<String>>) -> Self {
+        let mut keywords = BTreeMap::new();
+        for (unit, words) in BUILTIN_UNITS {
+            for word in *words {
+                keywords.insert(word.to_string(), unit.to_string());
+            }
+        }
+        for (unit, words) in extra {
+            keywords.insert(unit.to_lowercase(), unit.clone());
+            for word in words {
+                keywords.insert(word.to_lowercase(), unit.clone());
+            }
+        }
+        UnitKeywords(keywords)
+    }
+
+    /// Unidad que menciona la descripción; `None` si no hay o si hay varias.
+    pub fn find_unit(&self, description: &str) -> Option<String> {
+        let mut found: Option<&String> = None;
+        for token in description.split_whitespace() {
+            let token = token
+                .trim_matches(|c: char| !c.is_alphanumeric() && c != '%')
+                .to_lowercase();
+            // `100ms`, `50%`: la unidad va pegada al número
+            let word = token.trim_start_matches(|c: char| c.is_ascii_digit() || ".,–-".contains(c));
+            let Some(unit) = self.0.get(word) else {
+                continue;
+            };
+            match found {
+                Some(previous) if previous != unit => return None,
+                _ => found = Some(unit),
+            }
+        }
+        found.cloned()
+    }
+}
+
+impl Default for UnitKeywords {
+    fn default() -> Self {
+        Self::new(&BTreeMap::new())
+    }
+}
+
+/// Primer rango `mínimo–máximo` de la descripción (`100-30000`, `1 – 10`,
+/// `0..100`, `between 1 and 5`, `entre 1 y 5`). Los números pegados a otro
+/// número o a una versión (`2024-01-15`, `v1.2-3`) no cuentan.
+pub fn find_range(description: &str) -> Option<ValueRange> {
+    let text = description.to_lowercase();
+    let mut rest = text.as_str();
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+        let preceded = rest[..start].chars().next_back();
+        let (min, after) = split_number(&rest[start..]);
+        rest = after;
+        if preceded.is_some_and(|c| c.is_alphanumeric() || c == '.' || c == '-') {
+            continue;
+        }
+        let Some(after_separator) = strip_separator(rest) else {
+            continue;
+        };
+        if !after_separator.starts_with(|c: char| c.is_ascii_digit()) {
+            continue;
+        }
+        let (max, after) = split_number(after_separator);
+        let continues = after.starts_with(['-', '–', '—'])
+            || after
+                .strip_prefix(['.', ','])
+                .is_some_and(|a| a.starts_with(|c: char| c.is_ascii_digit()));
+        if continues {
+            rest = after;
+            continue;
+        }
+        if let (Ok(min), Ok(max)) = (min.parse(), max.parse()) {
+            return Some(ValueRange { min, max });
+        }
+    }
+    between_range(&text)
+}
+
+/// `between 1 and 5` / `entre 1 y 5`.
+fn between_range(text: &str) -> Option<ValueRange> {
+    let words: Vec<&str> = text
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric() && c != '.'))
+        .collect();
+    words.windows(4).find_map(|w| match w {
+        ["between", min, "and", max] | ["entre", min, "y", max] => Some(ValueRange {
+            min: min.parse().ok()?,
+            max: max.parse().ok()?,
+        }),
+        _ => None,
+    })
+}
+
+/// Separa el número inicial (`30000`, `0.5`) del resto del texto.
+fn split_number(text: &str) -> (&str, &str) {
+    let mut end = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    if let Some(decimals) = text[end..].strip_prefix('.') {
+        let digits = decimals
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(decimals.len());
+        if digits > 0 {
+            end += 1 + digits;
+        }
+    }
+    text.split_at(end)
+}
+
+/// Consume un separador de rango con espacios opcionales alrededor.
+fn strip_separator(text: &str) -> Option<&str> {
+    let trimmed = text.trim_start();
+    ["...", "..", "–", "—", "-"]
+        .iter()
+        .find_map(|separator| trimmed.strip_prefix(separator))
+        .map(str::trim_start)
+}
+
+/// Rellena `unit` y `range` de los argumentos que tienen descripción.
+pub fn annotate_args(args: &mut [Arg], units: &UnitKeywords) {
+    for arg in args {
+        let Some(description) = arg.description.as_deref() else {
+            continue;
+        };
+        arg.unit = units.find_unit(description);
+        arg.range = find_range(description);
+    }
+}
+
+/// `annotate_args` sobre los argumentos de la firma de cada entidad.
+pub fn annotate_entities(entities: &mut [CodeEntity], units: &UnitKeywords) {
+    for entity in entities {
+        annotate_args(&mut entity.args, units);
+    }
+}
+
+/// `annotate_args` sobre los argumentos documentados de cada sección.
+pub fn annotate_sections(sections: &mut [DocSection], units: &UnitKeywords) {
+    for section in sections {
+        annotate_args(&mut section.args, units);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit(description: &str) -> Option<String> {
+        UnitKeywords::default().find_unit(description)
+    }
+
+    fn range(min: f64, max: f64) -> Option<ValueRange> {
+        Some(ValueRange { min, max })
+    }
+
+    #[test]
+    fn units_are_read_from_english_and_spanish_words() {
+        assert_eq!(unit("Timeout in milliseconds"), Some("ms".into()));
+        assert_eq!(unit("Tiempo de espera en milisegundos."), Some("ms".into()));
+        assert_eq!(unit("timeout in seconds"), Some("s".into()));
+        assert_eq!(unit("Espera máxima (segundos)"), Some("s".into()));
+        assert_eq!(unit("Tamaño máximo en bytes"), Some("bytes".into()));
+        assert_eq!(unit("Tamaño en octetos"), Some("bytes".into()));
+        assert_eq!(unit("Porcentaje de muestreo"), Some("%".into()));
+        assert_eq!(unit("Sampling rate, 0-100%"), Some("%".into()));
+        assert_eq!(unit("Retraso, p. ej. 250ms"), Some("ms".into()));
+    }
+
+    #[test]
+    fn ambiguous_or_incidental_words_have_no_unit() {
+        assert_eq!(unit("The user's name"), None);
+        assert_eq!(unit("Nombre del usuario"), None);
+        // Dos unidades distintas: no se adivina cuál es la del argumento
+        assert_eq!(unit("Timeout in ms (max 30 seconds)"), None);
+        assert_eq!(unit("ms, in milliseconds"), Some("ms".into()));
+    }
+
+    #[test]
+    fn configured_keywords_extend_the_builtin_list() {
+        let extra = BTreeMap::from([("px".to_string(), vec!["pixels".into(), "píxeles".into()])]);
+        let units = UnitKeywords::new(&extra);
+        assert_eq!(units.find_unit("Ancho en píxeles"), Some("px".into()));
+        assert_eq!(units.find_unit("Width, 640px"), Some("px".into()));
+        assert_eq!(units.find_unit("in seconds"), Some("s".into()));
+    }
+
+    #[test]
+    fn ranges_accept_dashes_dots_and_words() {
+        assert_eq!(
+            find_range("in milliseconds, 100–30000"),
+            range(100.0, 30000.0)
+        );
+        assert_eq!(find_range("Reintentos (1 - 10)"), range(1.0, 10.0));
+        assert_eq!(find_range("ratio 0.5..1.5"), range(0.5, 1.5));
+        assert_eq!(find_range("between 1 and 5 retries"), range(1.0, 5.0));
+        assert_eq!(find_range("entre 10 y 20 segundos"), range(10.0, 20.0));
+        assert_eq!(find_range("Rango 30000-100"), range(30000.0, 100.0));
+        assert!(find_range("Rango 30000-100").unwrap().is_inverted());
+    }
+
+    #[test]
+    fn dates_versions_and_single_numbers_are_not_ranges() {
+        assert_eq!(find_range("Fecha de alta, p. ej. 2024-01-15"), None);
+        assert_eq!(find_range("Requiere v1.2-3 o superior"), None);
+        assert_eq!(find_range("Máximo 30.000 filas"), None);
+        assert_eq!(find_range("Hasta 100 elementos"), None);
+        assert_eq!(find_range("ISO-8601 date"), None);
+    }
+
+    #[test]
+    fn annotation_only_touches_described_args() {
+        let described = |description: Option<&str>| Arg {
+            name: "timeout".into(),
+            type_name: Some("number".into()),
+            description: description.map(String::from),
+            source: Default::default(),
+            line: None,
+            unit: None,
+            range: None,
+        };
+        let mut args = vec![
+            described(Some("in milliseconds, 100–30000")),
+            described(None),
+        ];
+        annotate_args(&mut args, &UnitKeywords::default());
+        assert_eq!(args[0].unit.as_deref(), Some("ms"));
+        assert_eq!(args[0].range, range(100.0, 30000.0));
+        assert_eq!((args[1].unit.as_deref(), args[1].range), (None, None));
+    }
+}
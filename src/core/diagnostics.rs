@@ -0,0 +1,183 @@
+//! Notas del parser sobre situaciones ambiguas (modo `--strict`).
+//!
+//! Los parsers resuelven las ambigüedades de forma optimista y en silencio:
+//! ignoran anotaciones separadas por un hueco grande, descartan tablas que no
+//! parecen de argumentos… `ParseDiagnostics` recoge esas decisiones junto a
+//! las entidades y secciones, y el validador las convierte en hallazgos
+//! `DGxxx` cuando se pide `--strict`.
+//!
+//! Las anotaciones mal formadas (`@docs [id]`, `<!-- @docs-id: id`…) y los
+//! enlaces obsoletos de `links.yaml` viajan por el mismo canal pero se reportan
+//! siempre: son un error del usuario, no una ambigüedad. Igual los archivos
+//! sin lenguaje soportado de un directorio o glob de `check` (Info). También los `@docs`
+//! apilados con IDs distintos (DG001): ninguno se enlaza y se reportan como Error;
+//! y las secciones recortadas por `limits:` (DG006), como Warning.
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use crate::core::types::{Rule, Severity, ValidationResult};
+
+/// Decisión silenciosa tomada por un parser.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseNote {
+    /// Regla `DGxxx` que identifica la situación.
+    pub rule: Rule,
+    pub message: String,
+    /// Función afectada, si la nota es del código.
+    pub function_name: Option<String>,
+    /// Ubicación en el código (`archivo:línea`).
+    pub code_location: Option<String>,
+    /// ID de la sección afectada, si la nota es de la documentación.
+    pub doc_id: Option<String>,
+    /// Ubicación en la documentación (`archivo:línea`).
+    pub doc_location: Option<String>,
+    /// Sugerencia propia de la nota; sin ella se usa la de la regla.
+    pub hint: Option<String>,
+}
+
+/// Canal lateral de notas que acompaña a entidades y secciones parseadas.
+#[derive(Debug, Clone, Default)]
+pub struct ParseDiagnostics {
+    pub notes: Vec<ParseNote>,
+    /// Archivo de cada función descartada por `ignore_functions`.
+    pub ignored_functions: Vec<PathBuf>,
+    /// Patrones de `ignore_functions` que descartaron alguna función.
+    pub ignore_hits: BTreeSet<String>,
+}
+
+impl ParseDiagnostics {
+    pub fn push(&mut self, note: ParseNote) {
+        self.notes.push(note);
+    }
+
+    /// Añade las notas de otro parseo (p. ej. código + documentación).
+    pub fn merge(&mut self, other: ParseDiagnostics) {
+        self.notes.extend(other.notes);
+        self.ignored_functions.extend(other.ignored_functions);
+        self.ignore_hits.extend(other.ignore_hits);
+    }
+}
+
+/// Nivel de exigencia del modo estricto.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strictness {
+    /// `--strict`: las notas se reportan como Info.
+    Strict,
+    /// `--strict --pedantic`: las notas se reportan como Warning.
+    Pedantic,
+}
+
+impl Strictness {
+    pub fn severity(self) -> Severity {
+        match self {
+            Strictness::Strict => Severity::Info,
+            Strictness::Pedantic => Severity::Warning,
+        }
+    }
+}
+
+impl ParseNote {
+    /// Las notas `DGxxx` solo se reportan con `--strict`; el resto (anotaciones
+    /// mal formadas o en conflicto, enlaces obsoletos de `links.yaml`,
+    /// conflictos de git sin resolver) se reporta siempre.
+    pub fn is_strict_only(&self) -> bool {
+        !matches!(
+            self.rule,
+            Rule::MalformedAnnotation
+                | Rule::StaleMapping
+                | Rule::SkippedFile
+                | Rule::ConflictingDocsIds
+                | Rule::TruncatedDocs
+                | Rule::VcsConflict
+        )
+    }
+
+    /// Severidad de las notas que se reportan siempre: Error si la función
+    /// se queda sin enlazar por el conflicto, Info para un archivo omitido,
+    /// Warning en el resto.
+    pub fn default_severity(&self) -> Severity {
+        match self.rule {
+            Rule::ConflictingDocsIds => Severity::Error,
+            Rule::SkippedFile => Severity::Info,
+            _ => Severity::Warning,
+        }
+    }
+
+    /// Convierte la nota en un hallazgo con la severidad indicada.
+    pub fn to_result(&self, severity: Severity) -> ValidationResult {
+        ValidationResult {
+            severity,
+            rule: self.rule,
+            message: self.message.clone(),
+            function_name: self.function_name.clone(),
+            code_location: self.code_location.clone(),
+            doc_id: self.doc_id.clone(),
+            doc_location: self.doc_location.clone(),
+            hint: self
+                .hint
+                .clone()
+                .or_else(|| hint_for(self.rule).map(String::from)),
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+}
+
+fn hint_for(rule: Rule) -> Option<&'static str> {
+    match rule {
+        Rule::ConflictingDocsIds => {
+            Some("Borra las anotaciones obsoletas y deja un solo `@docs` por función.")
+        }
+        Rule::DetachedAnnotation => {
+            Some("Elimina las líneas vacías entre la anotación y la función para enlazarla.")
+        }
+        Rule::SkippedArgTable => Some(
+            "Añade una cabecera `Param`/`Name` a la tabla si documenta argumentos.",
+        ),
+        Rule::UnnormalizedType => Some(
+            "El tipo genérico se comparó como texto literal: el resultado puede ser un falso positivo.",
+        ),
+        Rule::SyntaxErrorInEntity => {
+            Some("Corrige el error de sintaxis: los argumentos extraídos pueden estar incompletos.")
+        }
+        Rule::InferredTitle => Some(
+            "Pon el marcador `@docs-id` justo encima del encabezado de la sección.",
+        ),
+        Rule::TruncatedDocs => Some(
+            "Comprueba que el marcador `@docs-id` no quedó sobre contenido generado; si el tamaño es real, sube el límite en `limits:` de config.yaml.",
+        ),
+        Rule::VcsConflict => Some(
+            "Resuelve el conflicto (o termina el merge o el rebase) y vuelve a ejecutar.",
+        ),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pedantic_raises_notes_to_warnings() {
+        let note = ParseNote {
+            rule: Rule::DetachedAnnotation,
+            message: "Anotación separada".into(),
+            function_name: Some("login".into()),
+            code_location: Some("a.ts:5".into()),
+            doc_id: None,
+            doc_location: None,
+            hint: None,
+        };
+        let strict = note.to_result(Strictness::Strict.severity());
+        let pedantic = note.to_result(Strictness::Pedantic.severity());
+        assert_eq!(strict.severity, Severity::Info);
+        assert_eq!(pedantic.severity, Severity::Warning);
+        assert_eq!(pedantic.rule.id(), "DG002");
+        assert!(pedantic.hint.is_some());
+    }
+}
@@ -0,0 +1,726 @@
+//! Validación de ejemplos documentados contra el tipo de retorno (opt-in).
+//!
+//! Compara la forma del resultado esperado de un ejemplo
+//! (`` `createUser("ana")` → `{ id: string, name: "ana" }` ``) con el tipo de
+//! retorno de la función vinculada, solo cuando ambos lados son determinables:
+//! claves de objetos literales frente a `CodeEntity::return_fields`, y tipos
+//! escalares adivinados frente al tipo de retorno normalizado.
+//!
+//! Los bloques `@example` del JSDoc de una función (`CodeEntity::examples`)
+//! pasan por la misma validación, apuntando a la línea del comentario: sus
+//! llamadas a la propia función con más argumentos de los que acepta y a
+//! funciones renombradas del mismo archivo (`symbols`), siempre; sus
+//! resultados esperados, con `--check-examples` como los de las docs.
+//!
+//! Es una heurística de mejor esfuerzo: estos hallazgos son siempre `Info`.
+//!
+//! `validate_example_args` mira solo las docs: un ejemplo de la sección que
+//! llama a su función con `createUser(42, "ana")` cuando la tabla de esa misma
+//! sección dice `userId (string)` es `example-arg-mismatch`, un Warning. Los
+//! argumentos se alinean por posición con los documentados y solo se juzgan
+//! los literales (cadena, número, booleano, objeto) frente a tipos que se
+//! reconocen; un spread, una variable o un tipo con nombre propio no dan
+//! hallazgo.
+
+use std::collections::BTreeSet;
+
+use crate::core::types::{
+    Arg, CodeEntity, DocSection, Expectation, Related, Rule, Severity, ValidationResult,
+};
+use crate::core::validator::normalize_type;
+use crate::core::{suppression, symbols};
+use crate::messages;
+use crate::parser::doc_parser;
+
+/// Forma del resultado esperado de un ejemplo.
+#[derive(Debug, Clone, PartialEq)]
+enum Shape {
+    /// Objeto literal: claves con el tipo escalar de su valor, si se puede adivinar.
+    Object(Vec<(String, Option<&'static str>)>),
+    /// Literal escalar (`string`, `number`, `boolean`).
+    Scalar(&'static str),
+}
+
+/// Valida los ejemplos de cada sección contra la función vinculada.
+pub fn validate_examples(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+) -> Vec<ValidationResult> {
+    let mut results = Vec::new();
+
+    for section in doc_sections.iter().filter(|s| !s.skips_return()) {
+        for entity in code_entities
+            .iter()
+            .filter(|e| e.doc_id.as_deref() == Some(section.id.as_str()))
+        {
+            for expectation in section
+                .expectations
+                .iter()
+                .filter(|x| x.function == entity.name)
+            {
+                for (message, hint) in expectation_mismatches(entity, expectation) {
+                    results.push(example_mismatch(
+                        entity,
+                        message,
+                        hint,
+                        entity.location(),
+                        Some((section, expectation.line)),
+                    ));
+                }
+            }
+        }
+    }
+
+    suppression::apply_inline_suppressions(code_entities, &mut results);
+    results
+}
+
+/// Valida los ejemplos `@example` del JSDoc de cada función: llamadas a la
+/// propia función con demasiados argumentos, a funciones renombradas del
+/// mismo archivo y, con `expectations`, los resultados esperados
+/// (`login("ana") // => { token }`).
+pub fn validate_code_examples(
+    code_entities: &[CodeEntity],
+    expectations: bool,
+) -> Vec<ValidationResult> {
+    let mut results = symbols::validate_renamed_siblings(code_entities);
+
+    for entity in code_entities.iter().filter(|e| !e.examples.is_empty()) {
+        let location = |line: usize| format!("{}:{}", entity.file_path.display(), line);
+        let variadic = entity.args.iter().any(|a| a.name.starts_with("..."));
+        for example in &entity.examples {
+            let calls = symbols::ts_calls(&example.code);
+            for call in calls.iter().filter(|c| c.name == entity.name && !variadic) {
+                let Some(given) = call
+                    .args
+                    .as_ref()
+                    .map(Vec::len)
+                    .filter(|&n| n > entity.args.len())
+                else {
+                    continue;
+                };
+                results.push(example_mismatch(
+                    entity,
+                    format!(
+                        "El ejemplo llama a fn {} con {}, pero acepta {}.",
+                        entity.name,
+                        messages::ARGS.count(given),
+                        entity.args.len()
+                    ),
+                    "Actualiza la llamada del ejemplo a la firma actual.".into(),
+                    location(example.line + call.line_offset),
+                    None,
+                ));
+            }
+
+            if !expectations {
+                continue;
+            }
+            let mut found = Vec::new();
+            doc_parser::collect_expectations(&example.code, example.line, &mut found);
+            for expectation in found.iter().filter(|x| x.function == entity.name) {
+                for (message, hint) in expectation_mismatches(entity, expectation) {
+                    results.push(example_mismatch(
+                        entity,
+                        message,
+                        hint,
+                        location(expectation.line),
+                        None,
+                    ));
+                }
+            }
+        }
+    }
+
+    suppression::apply_inline_suppressions(code_entities, &mut results);
+    results
+}
+
+/// Ejemplos de las secciones que llaman a una función enlazada con literales
+/// de otro tipo que el que la propia sección documenta en esa posición.
+pub fn validate_example_args(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+) -> Vec<ValidationResult> {
+    let mut results = Vec::new();
+    for section in doc_sections.iter().filter(|s| !s.is_prose_only()) {
+        // Con campos anidados (`options.retries`) la posición ya no es la de la tabla
+        if section.args.is_empty() || section.args.iter().any(|a| a.name.contains('.')) {
+            continue;
+        }
+        let mut seen = BTreeSet::new();
+        for entity in code_entities.iter().filter(|e| {
+            e.doc_id.as_deref() == Some(section.id.as_str()) && seen.insert(e.name.as_str())
+        }) {
+            for example in section
+                .examples
+                .iter()
+                .filter(|x| symbols::is_typescript(x))
+            {
+                for call in symbols::ts_calls(&example.code) {
+                    let Some(args) = call.args.filter(|_| call.name == entity.name) else {
+                        continue;
+                    };
+                    let line = example.line + call.line_offset;
+                    for (given, documented) in args.iter().zip(&section.args) {
+                        if documented.name.starts_with("...") {
+                            break;
+                        }
+                        let (Some(literal), Some(kinds)) = (
+                            literal_kind(given),
+                            documented.type_name.as_deref().and_then(documented_kinds),
+                        ) else {
+                            continue;
+                        };
+                        if !kinds.contains(&literal) {
+                            results.push(example_arg_mismatch(
+                                entity, section, line, given, literal, documented,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    results
+}
+
+fn example_arg_mismatch(
+    entity: &CodeEntity,
+    section: &DocSection,
+    line: usize,
+    given: &str,
+    literal: &str,
+    documented: &Arg,
+) -> ValidationResult {
+    let type_name = documented.type_name.as_deref().unwrap_or_default();
+    let doc_file = section.file_path.display();
+    ValidationResult {
+        severity: Severity::Warning,
+        rule: Rule::ExampleArgMismatch,
+        message: format!(
+            "El ejemplo pasa {} ({}) como '{}', que la sección documenta como '{}'.",
+            given, literal, documented.name, type_name
+        ),
+        function_name: Some(entity.name.clone()),
+        code_location: Some(entity.location()),
+        doc_id: Some(section.id.clone()),
+        doc_location: Some(format!("{}:{}", doc_file, line)),
+        hint: Some(
+            "Corrige el ejemplo o el tipo documentado: la sección se contradice a sí misma.".into(),
+        ),
+        provenance: None,
+        related: documented
+            .line
+            .map(|row| Related {
+                location: format!("{}:{}", doc_file, row),
+                message: format!("'{}' documentado como '{}'", documented.name, type_name),
+            })
+            .into_iter()
+            .collect(),
+        target: None,
+        summary: None,
+        cause: None,
+        grace: None,
+    }
+}
+
+/// Tipo de un argumento literal de una llamada; `None` si no es un literal
+/// (una variable, una llamada, un array…).
+fn literal_kind(text: &str) -> Option<&'static str> {
+    let text = text.trim();
+    let quoted = |q: char| text.len() >= 2 && text.starts_with(q) && text.ends_with(q);
+    if quoted('"') || quoted('\'') || quoted('`') {
+        return Some("string");
+    }
+    if text == "true" || text == "false" {
+        return Some("boolean");
+    }
+    if text.starts_with('{') && text.ends_with('}') {
+        return Some("object");
+    }
+    let number = text.trim_start_matches('-').replace('_', "");
+    let number = number.strip_suffix('n').unwrap_or(&number);
+    let hex = number
+        .strip_prefix("0x")
+        .or_else(|| number.strip_prefix("0X"));
+    if number.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+        && (number.parse::<f64>().is_ok()
+            || hex.is_some_and(|h| !h.is_empty() && h.chars().all(|c| c.is_ascii_hexdigit())))
+    {
+        return Some("number");
+    }
+    None
+}
+
+/// Tipos de literal que admite un tipo documentado (`string | number`);
+/// `None` si alguna alternativa no se reconoce (`User`, `any`, `string[]`).
+fn documented_kinds(type_name: &str) -> Option<BTreeSet<&'static str>> {
+    let mut kinds = BTreeSet::new();
+    for member in split_top_level(type_name, &['|']) {
+        let member = member.trim().trim_end_matches('?').trim();
+        let lower = member.to_lowercase();
+        if matches!(lower.as_str(), "null" | "undefined" | "none" | "") {
+            continue;
+        }
+        let kind = match normalize_type(member).as_str() {
+            "string" => "string",
+            "number" => "number",
+            "boolean" => "boolean",
+            _ if lower == "object" || lower.starts_with("record<") || member.starts_with('{') => {
+                "object"
+            }
+            // Tipos literales: `'admin' | 'user'`, `1 | 2`
+            _ => literal_kind(member)?,
+        };
+        kinds.insert(kind);
+    }
+    (!kinds.is_empty()).then_some(kinds)
+}
+
+/// Hallazgo `example-mismatch` de `entity`; `doc` es la sección y la línea
+/// del ejemplo cuando está en las docs.
+fn example_mismatch(
+    entity: &CodeEntity,
+    message: String,
+    hint: String,
+    code_location: String,
+    doc: Option<(&DocSection, usize)>,
+) -> ValidationResult {
+    ValidationResult {
+        severity: Severity::Info,
+        rule: Rule::ExampleMismatch,
+        message,
+        function_name: Some(entity.name.clone()),
+        code_location: Some(code_location),
+        doc_id: doc.map_or(entity.doc_id.clone(), |(s, _)| Some(s.id.clone())),
+        doc_location: doc.map(|(s, line)| format!("{}:{}", s.file_path.display(), line)),
+        hint: Some(hint),
+        provenance: None,
+        related: Vec::new(),
+        target: None,
+        summary: None,
+        cause: None,
+        grace: None,
+    }
+}
+
+/// Diferencias (mensaje y sugerencia) entre el resultado esperado de un
+/// ejemplo y el tipo de retorno de `entity`.
+fn expectation_mismatches(entity: &CodeEntity, expectation: &Expectation) -> Vec<(String, String)> {
+    let mut mismatches = Vec::new();
+    let Some(shape) = parse_shape(&expectation.expected) else {
+        return mismatches;
+    };
+    let return_scalar = entity
+        .return_type
+        .as_deref()
+        .map(unwrap_wrappers)
+        .map(normalize_type)
+        .filter(|t| is_scalar(t));
+
+    let mut push = |message: String, hint: String| mismatches.push((message, hint));
+
+    match (&shape, &entity.return_fields, &return_scalar) {
+        (Shape::Object(keys), Some(fields), _) => {
+            for (key, guessed) in keys {
+                match fields.iter().find(|f| &f.name == key) {
+                    None => push(
+                        format!(
+                            "El ejemplo muestra el campo '{}', que no existe en el tipo de retorno de fn {}.",
+                            key, entity.name
+                        ),
+                        format!("Elimina '{}' del ejemplo o revisa si el campo fue renombrado.", key),
+                    ),
+                    Some(field) => {
+                        let field_type = field.type_name.as_deref().map(normalize_type);
+                        if let (Some(guessed), Some(field_type)) = (guessed, field_type) {
+                            if is_scalar(&field_type) && *guessed != field_type {
+                                push(
+                                    format!(
+                                        "El campo '{}' del ejemplo parece {}, pero en código es '{}'.",
+                                        key,
+                                        guessed,
+                                        field.type_name.as_deref().unwrap_or_default()
+                                    ),
+                                    format!("Actualiza el valor de '{}' en el ejemplo.", key),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        (Shape::Object(_), None, Some(scalar)) => push(
+            format!(
+                "El ejemplo muestra un objeto, pero fn {} retorna {}.",
+                entity.name, scalar
+            ),
+            "Actualiza el resultado esperado del ejemplo.".into(),
+        ),
+        (Shape::Scalar(found), Some(_), _) => push(
+            format!(
+                "El ejemplo muestra un {}, pero fn {} retorna un objeto.",
+                found, entity.name
+            ),
+            "Actualiza el resultado esperado del ejemplo.".into(),
+        ),
+        (Shape::Scalar(found), None, Some(scalar)) if found != scalar => push(
+            format!(
+                "El ejemplo muestra un {}, pero fn {} retorna {}.",
+                found, entity.name, scalar
+            ),
+            "Actualiza el resultado esperado del ejemplo.".into(),
+        ),
+        _ => {}
+    }
+    mismatches
+}
+
+/// Quita envoltorios habituales (`Result<T>`, `Option<T>`, `Promise<T>`) del tipo.
+///
+/// Compartida con el parser de Rust para resolver el struct de retorno.
+pub(crate) fn unwrap_wrappers(type_str: &str) -> &str {
+    let mut current = type_str.trim();
+    loop {
+        let Some((outer, rest)) = current.split_once('<') else {
+            return current;
+        };
+        let outer = outer.rsplit("::").next().unwrap_or(outer).trim();
+        if !matches!(outer, "Result" | "Option" | "Promise") {
+            return current;
+        }
+        let Some(inner) = rest.strip_suffix('>') else {
+            return current;
+        };
+        current = split_top_level(inner, &[','])
+            .into_iter()
+            .next()
+            .unwrap_or(inner)
+            .trim();
+    }
+}
+
+fn is_scalar(normalized: &str) -> bool {
+    matches!(normalized, "string" | "number" | "boolean")
+}
+
+/// Interpreta el resultado esperado. `None` si la forma no es determinable.
+fn parse_shape(expected: &str) -> Option<Shape> {
+    let expected = expected.trim();
+    if let Some(body) = expected.strip_prefix('{').and_then(|b| b.strip_suffix('}')) {
+        let mut keys = Vec::new();
+        for entry in split_top_level(body, &[',', ';']) {
+            let entry = entry.trim();
+            if entry.is_empty() {
+                continue;
+            }
+            if entry.starts_with("...") {
+                // Spread: el resto del objeto es desconocido
+                return None;
+            }
+            let (key, value) = match entry.split_once(':') {
+                Some((k, v)) => (k.trim(), Some(v.trim())),
+                None => (entry, None),
+            };
+            let key = key.trim_matches(|c| c == '"' || c == '\'');
+            if key.is_empty() || !key.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                return None;
+            }
+            keys.push((key.to_string(), value.and_then(guess_scalar)));
+        }
+        return Some(Shape::Object(keys));
+    }
+    guess_scalar(expected).map(Shape::Scalar)
+}
+
+/// Adivina el tipo normalizado de un literal o de un nombre de tipo escalar.
+fn guess_scalar(value: &str) -> Option<&'static str> {
+    let value = value.trim();
+    let quoted = |q: char| value.len() >= 2 && value.starts_with(q) && value.ends_with(q);
+    if quoted('"') || quoted('\'') {
+        return Some("string");
+    }
+    if value == "true" || value == "false" {
+        return Some("boolean");
+    }
+    if value.parse::<f64>().is_ok() {
+        return Some("number");
+    }
+    match normalize_type(value).as_str() {
+        "string" => Some("string"),
+        "number" => Some("number"),
+        "boolean" => Some("boolean"),
+        _ => None,
+    }
+}
+
+/// Divide por los separadores dados ignorando los anidados en `{}`, `[]`,
+/// `()`, `<>` y en cadenas.
+fn split_top_level<'a>(text: &'a str, separators: &[char]) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+
+    for (i, c) in text.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '{' | '[' | '(' | '<' => depth += 1,
+                '}' | ']' | ')' | '>' => depth -= 1,
+                c if depth == 0 && separators.contains(&c) => {
+                    parts.push(&text[start..i]);
+                    start = i + c.len_utf8();
+                }
+                _ => {}
+            },
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{Arg, ArgSource, CodeExample};
+    use std::path::PathBuf;
+
+    fn field(name: &str, type_name: &str) -> Arg {
+        Arg {
+            name: name.into(),
+            type_name: Some(type_name.into()),
+            description: None,
+            source: ArgSource::Code,
+            line: None,
+            unit: None,
+            range: None,
+        }
+    }
+
+    fn entity(return_type: Option<&str>, return_fields: Option<Vec<Arg>>) -> CodeEntity {
+        CodeEntity {
+            name: "createUser".into(),
+            args: vec![],
+            return_type: return_type.map(String::from),
+            return_fields,
+            doc_id: Some("create-user".into()),
+            file_path: PathBuf::from("src/users.ts"),
+            line: 3,
+            end_line: 3,
+            is_public: true,
+            suppressions: vec![],
+            file_link: None,
+            examples: Vec::new(),
+        }
+    }
+
+    fn section(expected: &[&str]) -> DocSection {
+        DocSection {
+            id: "create-user".into(),
+            title: None,
+            parent: None,
+            args: vec![],
+            file_path: PathBuf::from("docs/api.md"),
+            line: 1,
+            expectations: expected
+                .iter()
+                .enumerate()
+                .map(|(i, e)| Expectation {
+                    function: "createUser".into(),
+                    expected: e.to_string(),
+                    line: 10 + i,
+                })
+                .collect(),
+            examples: vec![],
+            since: None,
+            expected_function: None,
+            skips: Vec::new(),
+            dropped_args: 0,
+            title_inferred: false,
+            no_link: false,
+            mentions: Vec::new(),
+        }
+    }
+
+    fn user_fields() -> Option<Vec<Arg>> {
+        Some(vec![field("id", "string"), field("name", "string")])
+    }
+
+    #[test]
+    fn matching_example_has_no_findings() {
+        let results = validate_examples(
+            &[entity(None, user_fields())],
+            &[section(&[r#"{ id: string, name: "ana" }"#])],
+        );
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn vanished_field_is_reported_as_info() {
+        let results = validate_examples(
+            &[entity(None, user_fields())],
+            &[section(&[r#"{ id: "1", email: "a@b.c" }"#])],
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].severity, Severity::Info);
+        assert_eq!(results[0].rule, Rule::ExampleMismatch);
+        assert!(results[0].message.contains("'email'"));
+        assert_eq!(results[0].doc_location.as_deref(), Some("docs/api.md:10"));
+    }
+
+    #[test]
+    fn field_value_type_is_compared() {
+        let results =
+            validate_examples(&[entity(None, user_fields())], &[section(&["{ id: 42 }"])]);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].message.contains("parece number"));
+    }
+
+    #[test]
+    fn scalar_examples_compare_against_unwrapped_return_type() {
+        let entities = [entity(Some("Result<bool, Error>"), None)];
+        assert!(validate_examples(&entities, &[section(&["true"])]).is_empty());
+        assert_eq!(
+            validate_examples(&entities, &[section(&[r#""ok""#])]).len(),
+            1
+        );
+        assert_eq!(
+            validate_examples(&entities, &[section(&["{ ok: true }"])]).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn undeterminable_shapes_are_ignored() {
+        // Tipo de retorno opaco, spread, o expresión arbitraria
+        let results = validate_examples(
+            &[entity(Some("User"), None)],
+            &[section(&["{ id: 1 }", "user", "[1, 2]"])],
+        );
+        assert!(results.is_empty());
+
+        let results = validate_examples(
+            &[entity(None, user_fields())],
+            &[section(&["{ ...base, extra: 1 }"])],
+        );
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn examples_of_other_functions_are_ignored() {
+        let mut doc = section(&["{ missing: 1 }"]);
+        doc.expectations[0].function = "map".into();
+        assert!(validate_examples(&[entity(None, user_fields())], &[doc]).is_empty());
+    }
+
+    #[test]
+    fn jsdoc_examples_check_arity_renamed_siblings_and_expectations() {
+        let mut owner = entity(None, user_fields());
+        owner.args = vec![field("name", "string")];
+        owner.examples = vec![
+            CodeExample {
+                lang: Some("ts".into()),
+                code: "createUser(\"ana\")\ncreateUser(\"ana\", { admin: true, x: 1 })".into(),
+                line: 5,
+            },
+            CodeExample {
+                lang: None,
+                code: "createUsr(\"ana\")\ncreateUser(...args)\ncreateUser(\"ana\") // => { email: \"a\" }"
+                    .into(),
+                line: 9,
+            },
+        ];
+        let sibling = CodeEntity {
+            name: "deleteUser".into(),
+            ..entity(None, None)
+        };
+        let entities = [owner, sibling];
+
+        let found = |expectations| -> Vec<_> {
+            validate_code_examples(&entities, expectations)
+                .into_iter()
+                .map(|r| (r.rule, r.code_location.unwrap()))
+                .collect()
+        };
+        let always = [
+            (Rule::UnknownExampleSymbol, "src/users.ts:9".to_string()),
+            (Rule::ExampleMismatch, "src/users.ts:6".to_string()),
+        ];
+        assert_eq!(found(false), always);
+        let mut with_expectations = always.to_vec();
+        with_expectations.push((Rule::ExampleMismatch, "src/users.ts:11".into()));
+        assert_eq!(found(true), with_expectations);
+    }
+
+    /// Hallazgos `example-arg-mismatch` de una sección de `createUser(userId, name, admin)`.
+    fn arg_mismatches(example: &str) -> Vec<ValidationResult> {
+        let docs = format!(
+            "<!-- @docs-id: create-user -->\n## createUser\n\n\
+             | Param | Type | Description |\n|---|---|---|\n\
+             | userId | string | Identificador |\n\
+             | name | string \\| null | Nombre |\n\
+             | admin | boolean | Permisos |\n\n```ts\n{example}\n```\n"
+        );
+        let sections = crate::parser::doc_parser::parse_markdown_source(
+            &docs,
+            &PathBuf::from("docs/api.md"),
+            &mut Default::default(),
+        )
+        .unwrap();
+        validate_example_args(&[entity(None, None)], &sections)
+    }
+
+    #[test]
+    fn literals_that_match_the_documented_types_are_fine() {
+        assert!(arg_mismatches("createUser(\"42\", 'ana', true)").is_empty());
+        assert!(arg_mismatches("createUser(`u-${n}`, null)").is_empty());
+    }
+
+    #[test]
+    fn a_literal_of_another_type_points_at_the_example_and_the_row() {
+        let results = arg_mismatches("const user = await createUser(42, \"ana\", { root: true });");
+        assert_eq!(results.len(), 2, "{results:?}");
+        let first = &results[0];
+        assert_eq!(first.severity, Severity::Warning);
+        assert_eq!(first.rule, Rule::ExampleArgMismatch);
+        assert_eq!(
+            first.message,
+            "El ejemplo pasa 42 (number) como 'userId', que la sección documenta como 'string'."
+        );
+        assert_eq!(first.doc_location.as_deref(), Some("docs/api.md:11"));
+        assert_eq!(first.related[0].location, "docs/api.md:6");
+        assert!(results[1]
+            .message
+            .contains("{ root: true } (object) como 'admin'"));
+    }
+
+    #[test]
+    fn non_literals_spreads_and_unknown_types_are_not_judged() {
+        // Variables, llamadas, spread y otras funciones
+        assert!(arg_mismatches("createUser(id, getName(), flag)").is_empty());
+        assert!(arg_mismatches("createUser(...args)").is_empty());
+        assert!(arg_mismatches("deleteUser(42)").is_empty());
+        assert!(arg_mismatches("createUser(\"1\", \"ana\", false, 99)").is_empty());
+
+        assert_eq!(documented_kinds("User"), None);
+        assert_eq!(documented_kinds("string[]"), None);
+        assert_eq!(
+            documented_kinds("'admin' | 'user' | number"),
+            Some(BTreeSet::from(["string", "number"]))
+        );
+        assert_eq!(literal_kind("Infinity"), None);
+        assert_eq!(literal_kind("-1_000n"), Some("number"));
+    }
+
+    #[test]
+    fn split_respects_nesting_and_quotes() {
+        assert_eq!(
+            split_top_level(r#"a: { b, c }, d: "x, y", e"#, &[',']),
+            vec!["a: { b, c }", r#" d: "x, y""#, " e"]
+        );
+    }
+}
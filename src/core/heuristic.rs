@@ -1,8 +1,14 @@
 //! Heurística de matching entre funciones de código y secciones de documentación.
 //!
 //! Usa distancia de Levenshtein normalizada para sugerir enlaces
-//! candidatos entre funciones sin `@docs` y secciones sin enlace.
+//! candidatos entre funciones sin `@docs` y secciones sin enlace, y un enlace
+//! de archivo (`@docs-file`) cuando todas coinciden bajo un mismo heading padre.
+//! Con miles de funciones y secciones, un índice de bigramas evita puntuar los
+//! pares que no pueden llegar al umbral (`Strategy::Indexed`).
 
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use strsim::normalized_levenshtein;
 
 use crate::core::types::{CodeEntity, DocSection};
@@ -25,91 +31,327 @@ pub struct CandidateLink {
 }
 
 /// Umbral mínimo de confianza para sugerir un enlace (Blueprint §3.2: >80%).
-const MIN_CONFIDENCE: f64 = 0.80;
+pub const MIN_CONFIDENCE: f64 = 0.80;
 
-/// Genera candidatos de enlace entre funciones sin `@docs` y secciones sin enlace.
+/// Confianza mínima por defecto de `docsguard fix`: más alta que la de las
+/// sugerencias, porque nadie revisa cada enlace antes de escribirlo.
+pub const AUTO_APPLY_CONFIDENCE: f64 = 0.90;
+
+/// Cómo se recorren los pares función × sección (`scaffold --heuristic`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Strategy {
+    /// Índice de bigramas y cotas de longitud: solo se puntúan los pares que
+    /// pueden llegar al umbral. Mismo resultado que `exhaustive`.
+    #[default]
+    Indexed,
+    /// Levenshtein completo para cada par (referencia para depurar).
+    Exhaustive,
+}
+
+/// Genera candidatos de enlace entre funciones sin `@docs` y secciones sin enlace
+/// (salvo las `@docs-no-link`).
 pub fn find_candidates(
     code_entities: &[CodeEntity],
     doc_sections: &[DocSection],
+    strategy: Strategy,
 ) -> Vec<CandidateLink> {
     let unlinked_entities: Vec<(usize, &CodeEntity)> = code_entities
         .iter()
         .enumerate()
-        .filter(|(_, e)| e.doc_id.is_none())
+        .filter(|(_, e)| e.doc_id.is_none() && e.file_link.is_none())
         .collect();
 
-    let unlinked_sections: Vec<(usize, &DocSection)> = doc_sections
+    let linked: HashSet<&str> = code_entities
         .iter()
-        .enumerate()
-        .filter(|&(_, s)| {
-            !code_entities
-                .iter()
-                .any(|e| e.doc_id.as_ref() == Some(&s.id))
+        .flat_map(|e| {
+            e.doc_id
+                .as_deref()
+                .into_iter()
+                .chain(e.file_link.as_ref().map(|l| l.doc_id.as_str()))
         })
         .collect();
+    let unlinked_sections: Vec<&DocSection> = doc_sections
+        .iter()
+        .filter(|s| !s.no_link && !linked.contains(s.id.as_str()))
+        .collect();
+
+    // Las secciones con `@expects` declaran su función: candidato seguro, sin
+    // pasar por el umbral, y ambas quedan fuera de la heurística.
+    let mut declared = Vec::new();
+    for section in &unlinked_sections {
+        let Some(expected) = section.expected_function.as_deref() else {
+            continue;
+        };
+        if let Some((ei, entity)) = unlinked_entities.iter().find(|(ei, e)| {
+            e.name == expected
+                && declared
+                    .iter()
+                    .all(|c: &CandidateLink| c.entity_index != *ei)
+        }) {
+            declared.push(candidate(*ei, entity, section, 1.0));
+        }
+    }
+    let unlinked_entities: Vec<(usize, &CodeEntity)> = unlinked_entities
+        .into_iter()
+        .filter(|(ei, _)| declared.iter().all(|c| c.entity_index != *ei))
+        .collect();
+    let unlinked_sections: Vec<&DocSection> = unlinked_sections
+        .into_iter()
+        .filter(|s| declared.iter().all(|c| c.section_id != s.id))
+        .collect();
+
+    let mut candidates = match strategy {
+        Strategy::Indexed => indexed_candidates(&unlinked_entities, &unlinked_sections),
+        Strategy::Exhaustive => exhaustive_candidates(&unlinked_entities, &unlinked_sections),
+    };
+    // Primero: el orden estable los mantiene delante de los empates a 1.0
+    candidates.splice(0..0, declared);
+
+    // Ordenar por confianza descendente (estable: empates en orden de código)
+    candidates.sort_by(|a, b| {
+        b.confidence
+            .partial_cmp(&a.confidence)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    candidates
+}
 
+/// Puntúa cada función contra todas las secciones y se queda con la mejor.
+fn exhaustive_candidates(
+    entities: &[(usize, &CodeEntity)],
+    sections: &[&DocSection],
+) -> Vec<CandidateLink> {
     let mut candidates = Vec::new();
 
-    for (ei, entity) in &unlinked_entities {
-        let mut best_match: Option<CandidateLink> = None;
+    for (ei, entity) in entities {
+        let mut best_match: Option<(f64, &DocSection)> = None;
 
-        for (_si, section) in &unlinked_sections {
+        for section in sections {
             let confidence = compute_confidence(&entity.name, section);
 
-            if confidence >= MIN_CONFIDENCE {
-                let candidate = CandidateLink {
-                    entity_index: *ei,
-                    function_name: entity.name.clone(),
-                    code_location: format!("{}:{}", entity.file_path.display(), entity.line),
-                    section_id: section.id.clone(),
-                    section_title: section.title.clone().unwrap_or_else(|| section.id.clone()),
-                    confidence,
+            if confidence >= MIN_CONFIDENCE && best_match.is_none_or(|(best, _)| confidence > best)
+            {
+                best_match = Some((confidence, section));
+            }
+        }
+
+        if let Some((confidence, section)) = best_match {
+            candidates.push(candidate(*ei, entity, section, confidence));
+        }
+    }
+    candidates
+}
+
+/// Nombre normalizado con su longitud en caracteres (la de `strsim`).
+struct Normalized {
+    text: String,
+    len: usize,
+}
+
+impl Normalized {
+    fn new(name: &str) -> Self {
+        let text = normalize_name(name);
+        let len = text.chars().count();
+        Normalized { text, len }
+    }
+}
+
+/// Mismo resultado que `exhaustive_candidates` sin puntuar todos los pares.
+///
+/// Si `normalized_levenshtein(a, b) >= 0.80`, la distancia `k` cumple
+/// `k <= 0.2 · m` (con `m` la longitud mayor), así que:
+/// - la diferencia de longitudes no puede pasar de `k`, y
+/// - por el lema de q-gramas, `a` y `b` comparten al menos `m - 1 - 2k`
+///   bigramas de caracteres (contando repeticiones), que es ≥ 1 si `m ≥ 2`.
+///
+/// Los pares que no cumplen alguna de las dos cotas no pueden llegar al umbral
+/// y no se puntúan. Se indexan bigramas y no palabras: `getuser` ≈ `get user`
+/// no comparte ninguna palabra. Cada función se procesa en paralelo y el
+/// orden de salida es el de entrada.
+fn indexed_candidates(
+    entities: &[(usize, &CodeEntity)],
+    sections: &[&DocSection],
+) -> Vec<CandidateLink> {
+    // Dos textos por sección, en orden: id (2·i) y título (2·i + 1)
+    let texts: Vec<Option<Normalized>> = sections
+        .iter()
+        .flat_map(|s| {
+            [
+                Some(Normalized::new(&s.id)),
+                s.title.as_deref().map(Normalized::new),
+            ]
+        })
+        .collect();
+    let mut postings: HashMap<(char, char), Vec<(usize, u32)>> = HashMap::new();
+    for (slot, text) in texts.iter().enumerate() {
+        if let Some(text) = text {
+            for (bigram, count) in bigram_counts(&text.text) {
+                postings.entry(bigram).or_default().push((slot, count));
+            }
+        }
+    }
+    let names: Vec<Normalized> = entities
+        .iter()
+        .map(|(_, e)| Normalized::new(&e.name))
+        .collect();
+    let longest = texts
+        .iter()
+        .flatten()
+        .chain(&names)
+        .map(|t| t.len)
+        .max()
+        .unwrap_or(0);
+    let max_distance: Vec<usize> = (0..=longest).map(max_distance_for).collect();
+
+    entities
+        .par_iter()
+        .zip(&names)
+        .filter_map(|((ei, entity), name)| {
+            let mut shared = vec![0u32; texts.len()];
+            for (bigram, count) in bigram_counts(&name.text) {
+                for &(slot, other) in postings.get(&bigram).into_iter().flatten() {
+                    shared[slot] += count.min(other);
+                }
+            }
+            let similarity = |slot: usize| -> f64 {
+                let Some(text) = &texts[slot] else {
+                    return 0.0;
                 };
+                let m = name.len.max(text.len);
+                let k = max_distance[m] as i64;
+                let reachable = m <= 1
+                    || (name.len.abs_diff(text.len) as i64 <= k
+                        && i64::from(shared[slot]) >= m as i64 - 1 - 2 * k);
+                if reachable {
+                    normalized_levenshtein(&name.text, &text.text)
+                } else {
+                    0.0
+                }
+            };
 
-                if best_match
-                    .as_ref()
-                    .is_none_or(|b| confidence > b.confidence)
+            let mut best_match: Option<(f64, &DocSection)> = None;
+            for (i, section) in sections.iter().enumerate() {
+                let confidence = similarity(2 * i).max(similarity(2 * i + 1));
+                if confidence >= MIN_CONFIDENCE
+                    && best_match.is_none_or(|(best, _)| confidence > best)
                 {
-                    best_match = Some(candidate);
+                    best_match = Some((confidence, section));
                 }
             }
-        }
+            best_match.map(|(confidence, section)| candidate(*ei, entity, section, confidence))
+        })
+        .collect()
+}
 
-        if let Some(candidate) = best_match {
-            candidates.push(candidate);
-        }
+/// Mayor distancia de edición con la que dos textos de longitud mayor `m`
+/// aún alcanzan `MIN_CONFIDENCE`, calculada con la misma aritmética que
+/// `normalized_levenshtein` para no descartar empates por redondeo.
+fn max_distance_for(m: usize) -> usize {
+    (0..=m)
+        .take_while(|&k| m == 0 || 1.0 - k as f64 / m as f64 >= MIN_CONFIDENCE)
+        .last()
+        .unwrap_or(0)
+}
+
+/// Bigramas de caracteres de un texto con su número de apariciones.
+fn bigram_counts(text: &str) -> HashMap<(char, char), u32> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut counts = HashMap::new();
+    for pair in chars.windows(2) {
+        *counts.entry((pair[0], pair[1])).or_insert(0) += 1;
     }
+    counts
+}
 
-    // Ordenar por confianza descendente
-    candidates.sort_by(|a, b| {
-        b.confidence
-            .partial_cmp(&a.confidence)
-            .unwrap_or(std::cmp::Ordering::Equal)
-    });
-    candidates
+fn candidate(
+    entity_index: usize,
+    entity: &CodeEntity,
+    section: &DocSection,
+    confidence: f64,
+) -> CandidateLink {
+    CandidateLink {
+        entity_index,
+        function_name: entity.name.clone(),
+        code_location: format!("{}:{}", entity.file_path.display(), entity.line),
+        section_id: section.id.clone(),
+        section_title: section.title.clone().unwrap_or_else(|| section.id.clone()),
+        confidence,
+    }
 }
 
-/// Calcula la confianza de un match entre un nombre de función y una sección de docs.
-/// Compara contra el título y el ID de la sección.
-fn compute_confidence(function_name: &str, section: &DocSection) -> f64 {
-    let fn_normalized = normalize_name(function_name);
+/// Sección candidata para enlazar un archivo completo con `@docs-file`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileCandidate {
+    /// ID de la sección padre.
+    pub section_id: String,
+    /// Título de la sección padre.
+    pub section_title: String,
+}
+
+/// Propone un enlace de archivo si **todas** las funciones del archivo tienen
+/// candidato y todos los candidatos cuelgan del mismo heading padre, que a su
+/// vez es una sección con `@docs-id`.
+///
+/// Se exigen al menos dos funciones: con una sola, el enlace por función basta.
+pub fn find_file_candidate(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+    candidates: &[CandidateLink],
+) -> Option<FileCandidate> {
+    if code_entities.len() < 2
+        || candidates.len() != code_entities.len()
+        || code_entities
+            .iter()
+            .any(|e| e.doc_id.is_some() || e.file_link.is_some())
+    {
+        return None;
+    }
 
-    // Comparar contra el ID de la sección
-    let id_normalized = normalize_name(&section.id);
-    let id_similarity = normalized_levenshtein(&fn_normalized, &id_normalized);
-
-    // Comparar contra el título si existe
-    let title_similarity = section
-        .title
-        .as_ref()
-        .map(|t| {
-            let title_normalized = normalize_name(t);
-            normalized_levenshtein(&fn_normalized, &title_normalized)
+    let mut parents = candidates.iter().map(|c| {
+        doc_sections
+            .iter()
+            .find(|s| s.id == c.section_id)
+            .and_then(|s| s.parent.as_deref())
+    });
+    let parent = parents.next()??;
+    if !parents.all(|p| p == Some(parent)) {
+        return None;
+    }
+
+    doc_sections
+        .iter()
+        .find(|s| s.title.as_deref() == Some(parent))
+        .map(|s| FileCandidate {
+            section_id: s.id.clone(),
+            section_title: parent.to_string(),
         })
-        .unwrap_or(0.0);
+}
+
+/// Similitudes de un nombre de función con el ID y el título de una sección.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MatchScores {
+    pub id: f64,
+    /// `None` si la sección no tiene título.
+    pub title: Option<f64>,
+}
+
+/// Puntúa un nombre de función contra el ID y el título de una sección.
+pub fn match_scores(function_name: &str, section: &DocSection) -> MatchScores {
+    let fn_normalized = normalize_name(function_name);
+    MatchScores {
+        id: normalized_levenshtein(&fn_normalized, &normalize_name(&section.id)),
+        title: section
+            .title
+            .as_ref()
+            .map(|t| normalized_levenshtein(&fn_normalized, &normalize_name(t))),
+    }
+}
 
-    // Tomar la mayor similitud
-    id_similarity.max(title_similarity)
+/// Calcula la confianza de un match entre un nombre de función y una sección de docs:
+/// la mayor similitud con el ID o el título.
+fn compute_confidence(function_name: &str, section: &DocSection) -> f64 {
+    let scores = match_scores(function_name, section);
+    scores.id.max(scores.title.unwrap_or(0.0))
 }
 
 /// Normaliza un nombre para comparación: lowercase, reemplaza separadores por espacios.
@@ -121,6 +363,30 @@ fn normalize_name(name: &str) -> String {
         .join(" ")
 }
 
+/// `normalize_name` partiendo también en las fronteras de camelCase:
+/// `tenantId`, `tenant_id` y `tenant-id` dan `tenant id`, y `parseHTTPHeader`,
+/// `parse http header`. La heurística no lo usa: `getuser` frente a `get-user`
+/// ya puntúa alto y cambiarlo movería las confianzas de `scaffold`.
+pub(crate) fn canonical_name(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut spaced = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        let prev = i.checked_sub(1).map(|p| chars[p]);
+        let next = chars.get(i + 1);
+        let starts_word = c.is_uppercase()
+            && prev.is_some_and(|p| {
+                p.is_lowercase()
+                    || p.is_ascii_digit()
+                    || (p.is_uppercase() && next.is_some_and(|n| n.is_lowercase()))
+            });
+        if starts_word {
+            spaced.push(' ');
+        }
+        spaced.push(c);
+    }
+    normalize_name(&spaced)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,10 +397,15 @@ mod tests {
             name: name.to_string(),
             args: vec![],
             return_type: None,
+            return_fields: None,
             doc_id: None,
             file_path: PathBuf::from(file),
             line,
+            end_line: line,
             is_public: true,
+            suppressions: vec![],
+            file_link: None,
+            examples: Vec::new(),
         }
     }
 
@@ -142,9 +413,19 @@ mod tests {
         DocSection {
             id: id.into(),
             title: Some(title.into()),
+            parent: None,
             args: vec![],
             file_path: PathBuf::from("test.md"),
             line: 1,
+            expectations: vec![],
+            examples: vec![],
+            since: None,
+            expected_function: None,
+            skips: Vec::new(),
+            dropped_args: 0,
+            title_inferred: false,
+            no_link: false,
+            mentions: Vec::new(),
         }
     }
 
@@ -177,8 +458,209 @@ mod tests {
             section("user-create", "Create User"),
         ];
 
-        let candidates = find_candidates(&entities, &sections);
+        let candidates = find_candidates(&entities, &sections, Strategy::Indexed);
         // Al menos login debería matchear con auth-login
         assert!(!candidates.is_empty());
     }
+
+    #[test]
+    fn expected_function_is_a_sure_candidate_below_the_threshold() {
+        let entities = vec![
+            get_test_entity("authenticate", "auth.ts", 3),
+            get_test_entity("login", "auth.ts", 9),
+        ];
+        let sections = vec![
+            section("auth-login", "Login"),
+            DocSection {
+                expected_function: Some("authenticate".into()),
+                ..section("session-start", "Start a session")
+            },
+        ];
+        for strategy in [Strategy::Indexed, Strategy::Exhaustive] {
+            let candidates = find_candidates(&entities, &sections, strategy);
+            let pairs: Vec<_> = candidates
+                .iter()
+                .map(|c| {
+                    (
+                        c.function_name.as_str(),
+                        c.section_id.as_str(),
+                        c.confidence,
+                    )
+                })
+                .collect();
+            assert_eq!(
+                pairs[0],
+                ("authenticate", "session-start", 1.0),
+                "{strategy:?}"
+            );
+            assert_eq!(pairs[1].0, "login");
+        }
+    }
+
+    #[test]
+    fn no_link_sections_are_not_candidates() {
+        let entities = vec![get_test_entity("login", "auth.ts", 3)];
+        let sections = vec![DocSection {
+            no_link: true,
+            mentions: Vec::new(),
+            ..section("auth-login", "Login")
+        }];
+        for strategy in [Strategy::Indexed, Strategy::Exhaustive] {
+            assert!(find_candidates(&entities, &sections, strategy).is_empty());
+        }
+    }
+
+    fn child_section(id: &str, title: &str, parent: &str) -> DocSection {
+        DocSection {
+            parent: Some(parent.into()),
+            ..section(id, title)
+        }
+    }
+
+    #[test]
+    fn file_candidate_when_all_functions_match_under_one_parent() {
+        let entities = vec![
+            get_test_entity("charge", "payments.ts", 3),
+            get_test_entity("refund", "payments.ts", 9),
+        ];
+        let sections = vec![
+            section("payments-overview", "Payments"),
+            child_section("payments-charge", "charge", "Payments"),
+            child_section("payments-refund", "refund", "Payments"),
+        ];
+        let candidates = find_candidates(&entities, &sections, Strategy::Indexed);
+        assert_eq!(candidates.len(), 2);
+
+        let file = find_file_candidate(&entities, &sections, &candidates).unwrap();
+        assert_eq!(file.section_id, "payments-overview");
+    }
+
+    #[test]
+    fn no_file_candidate_when_parents_differ() {
+        let entities = vec![
+            get_test_entity("charge", "payments.ts", 3),
+            get_test_entity("refund", "payments.ts", 9),
+        ];
+        let sections = vec![
+            section("payments-overview", "Payments"),
+            child_section("payments-charge", "charge", "Payments"),
+            child_section("payments-refund", "refund", "Refunds"),
+        ];
+        let candidates = find_candidates(&entities, &sections, Strategy::Indexed);
+        assert_eq!(find_file_candidate(&entities, &sections, &candidates), None);
+    }
+
+    /// Nombres con mucho solapamiento: palabras de un vocabulario corto,
+    /// separadores variados (o ninguno: `getuser`), erratas y casos límite.
+    #[test]
+    fn canonical_names_split_camel_case_and_acronyms() {
+        for name in [
+            "tenantId",
+            "tenant_id",
+            "tenant-id",
+            "TenantId",
+            "TENANT_ID",
+        ] {
+            assert_eq!(canonical_name(name), "tenant id", "{name}");
+        }
+        assert_eq!(canonical_name("parseHTTPHeader"), "parse http header");
+        assert_eq!(canonical_name("page2Size"), "page2 size");
+        // La heurística no parte camelCase
+        assert_eq!(normalize_name("tenantId"), "tenantid");
+    }
+
+    fn random_name(rng: &mut fastrand::Rng, words: &[&str]) -> String {
+        let mut name = String::new();
+        for i in 0..rng.usize(1..=3) {
+            if i > 0 {
+                name.extend(rng.choice(["_", "-", ".", " ", ""]));
+            }
+            let mut word: Vec<char> = rng.choice(words).unwrap().chars().collect();
+            match rng.u8(..6) {
+                0 => word.insert(rng.usize(..=word.len()), rng.lowercase()),
+                1 if word.len() > 1 => {
+                    word.remove(rng.usize(..word.len()));
+                }
+                2 => word.iter_mut().for_each(|c| *c = c.to_ascii_uppercase()),
+                _ => {}
+            }
+            name.extend(word);
+        }
+        name
+    }
+
+    fn random_fixture(
+        seed: u64,
+        entity_count: usize,
+        section_count: usize,
+        words: &[&str],
+    ) -> (Vec<CodeEntity>, Vec<DocSection>) {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let sections: Vec<DocSection> = (0..section_count)
+            .map(|i| DocSection {
+                title: rng.bool().then(|| random_name(&mut rng, words)),
+                line: i + 1,
+                ..section(&random_name(&mut rng, words), "")
+            })
+            .collect();
+        let entities = (0..entity_count)
+            .map(|i| CodeEntity {
+                doc_id: (rng.u8(..10) == 0)
+                    .then(|| sections[rng.usize(..sections.len())].id.clone()),
+                ..get_test_entity(&random_name(&mut rng, words), "gen.ts", i + 1)
+            })
+            .collect();
+        (entities, sections)
+    }
+
+    #[test]
+    fn indexed_candidates_match_the_exhaustive_scan() {
+        const WORDS: [&str; 13] = [
+            "get", "user", "users", "create", "order", "id", "list", "auth", "login", "a", "x",
+            "_", "payment",
+        ];
+        let mut total = 0;
+        for seed in 0..60 {
+            let (entities, sections) = random_fixture(seed, 80, 50, &WORDS);
+            let indexed = find_candidates(&entities, &sections, Strategy::Indexed);
+            let exhaustive = find_candidates(&entities, &sections, Strategy::Exhaustive);
+            assert_eq!(
+                format!("{indexed:?}"),
+                format!("{exhaustive:?}"),
+                "semilla {seed}"
+            );
+            total += indexed.len();
+        }
+        assert!(
+            total > 500,
+            "el generador apenas produce candidatos: {total}"
+        );
+    }
+
+    /// `cargo test --release -- --ignored large_unlinked_set`
+    #[test]
+    #[ignore = "benchmark: ejecutar en release"]
+    fn large_unlinked_set_is_ten_times_faster_indexed() {
+        const WORDS: [&str; 24] = [
+            "get", "set", "user", "account", "create", "delete", "update", "order", "invoice",
+            "payment", "refund", "list", "search", "auth", "login", "logout", "session", "token",
+            "profile", "settings", "report", "export", "import", "webhook",
+        ];
+        let (entities, sections) = random_fixture(7, 3500, 900, &WORDS);
+
+        let started = std::time::Instant::now();
+        let exhaustive = find_candidates(&entities, &sections, Strategy::Exhaustive);
+        let exhaustive_time = started.elapsed();
+        let started = std::time::Instant::now();
+        let indexed = find_candidates(&entities, &sections, Strategy::Indexed);
+        let indexed_time = started.elapsed();
+
+        assert_eq!(format!("{indexed:?}"), format!("{exhaustive:?}"));
+        let speedup = exhaustive_time.as_secs_f64() / indexed_time.as_secs_f64();
+        eprintln!(
+            "exhaustive {exhaustive_time:?}, indexed {indexed_time:?} (x{speedup:.1}), {} candidatos",
+            indexed.len()
+        );
+        assert!(speedup >= 10.0, "solo x{speedup:.1}");
+    }
 }
@@ -0,0 +1,372 @@
+//! IDs de documentación generados (`scaffold --stubs`).
+//!
+//! `id_style` en `.docsguard/config.yaml` fija cómo se deriva el ID a partir
+//! de la función: `kebab-from-function` (por defecto, `createUser` →
+//! `create-user`), `snake` (`create_user`), `keep` (el nombre tal cual) o una
+//! plantilla como `{module}-{function_kebab}`. El módulo es el directorio del
+//! archivo, salvo que `id_modules` asigne otro a ese directorio.
+//!
+//! Todo ID generado pasa `is_valid_id` y no coincide con ninguno existente: si
+//! ya está tomado se le añade `-2`, `-3`… (`_2` en los estilos snake).
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use crate::parser::code_parser::is_valid_id;
+
+/// Marcadores de las plantillas de `id_style`.
+const PLACEHOLDERS: &[&str] = &["module", "function", "function_kebab", "function_snake"];
+
+/// Cómo se deriva un ID nuevo del nombre de la función.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum IdStyle {
+    /// `createUser` → `create-user`.
+    #[default]
+    KebabFromFunction,
+    /// `createUser` → `create_user`.
+    Snake,
+    /// El nombre de la función sin cambios.
+    Keep,
+    /// Texto con marcadores `{module}`, `{function}`, `{function_kebab}` y
+    /// `{function_snake}`.
+    Template(String),
+}
+
+impl TryFrom<String> for IdStyle {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "kebab-from-function" => return Ok(IdStyle::KebabFromFunction),
+            "snake" => return Ok(IdStyle::Snake),
+            "keep" => return Ok(IdStyle::Keep),
+            _ => {}
+        }
+        if !value.contains('{') {
+            return Err(format!(
+                "id_style '{}' no reconocido: usa kebab-from-function, snake, keep o una plantilla con {{function_kebab}}",
+                value
+            ));
+        }
+        let mut names_function = false;
+        let mut rest = value.as_str();
+        while let Some(start) = rest.find('{') {
+            let Some(len) = rest[start..].find('}') else {
+                return Err(format!("Llave sin cerrar en id_style '{}'", value));
+            };
+            let name = &rest[start + 1..start + len];
+            if !PLACEHOLDERS.contains(&name) {
+                return Err(format!(
+                    "Marcador '{{{}}}' desconocido en id_style (disponibles: {})",
+                    name,
+                    PLACEHOLDERS
+                        .iter()
+                        .map(|p| format!("{{{}}}", p))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+            names_function |= name != "module";
+            rest = &rest[start + len + 1..];
+        }
+        if !names_function {
+            return Err(format!(
+                "id_style '{}' no incluye la función: todos los IDs serían iguales",
+                value
+            ));
+        }
+        Ok(IdStyle::Template(value))
+    }
+}
+
+impl From<IdStyle> for String {
+    fn from(style: IdStyle) -> Self {
+        match style {
+            IdStyle::KebabFromFunction => "kebab-from-function".into(),
+            IdStyle::Snake => "snake".into(),
+            IdStyle::Keep => "keep".into(),
+            IdStyle::Template(template) => template,
+        }
+    }
+}
+
+impl IdStyle {
+    /// Separador de palabras y del sufijo numérico.
+    fn separator(&self, function: &str) -> char {
+        match self {
+            IdStyle::Snake => '_',
+            IdStyle::Template(template) if template.contains("{function_snake}") => '_',
+            IdStyle::Keep if function.contains('_') && !function.contains('-') => '_',
+            _ => '-',
+        }
+    }
+}
+
+/// Genera IDs nuevos sin repetir ninguno existente ni generado antes.
+pub struct IdGenerator<'a> {
+    style: &'a IdStyle,
+    /// Directorio relativo al proyecto → módulo (`id_modules`).
+    modules: &'a BTreeMap<String, String>,
+    taken: HashSet<String>,
+}
+
+impl<'a> IdGenerator<'a> {
+    pub fn new(
+        style: &'a IdStyle,
+        modules: &'a BTreeMap<String, String>,
+        existing: impl IntoIterator<Item = String>,
+    ) -> Self {
+        IdGenerator {
+            style,
+            modules,
+            taken: existing.into_iter().collect(),
+        }
+    }
+
+    /// ID para `function`, declarada en `file` (relativo al proyecto).
+    pub fn next(&mut self, function: &str, file: &Path) -> String {
+        let separator = self.style.separator(function);
+        let base = sanitize(&self.derive(function, file), separator);
+        let mut id = base.clone();
+        let mut suffix = 2;
+        while self.taken.contains(&id) {
+            id = format!("{}{}{}", base, separator, suffix);
+            suffix += 1;
+        }
+        self.taken.insert(id.clone());
+        id
+    }
+
+    fn derive(&self, function: &str, file: &Path) -> String {
+        match self.style {
+            IdStyle::KebabFromFunction => join_words(function, '-'),
+            IdStyle::Snake => join_words(function, '_'),
+            IdStyle::Keep => function.to_string(),
+            IdStyle::Template(template) => template
+                .replace("{module}", &self.module(file))
+                .replace("{function_kebab}", &join_words(function, '-'))
+                .replace("{function_snake}", &join_words(function, '_'))
+                .replace("{function}", function),
+        }
+    }
+
+    /// Módulo de `file`: el de `id_modules` con el directorio más largo que
+    /// lo contiene o, si no hay, el nombre de su directorio.
+    fn module(&self, file: &Path) -> String {
+        let dir = file.parent().unwrap_or(Path::new(""));
+        let configured = self
+            .modules
+            .iter()
+            .filter(|(prefix, _)| dir.starts_with(Path::new(prefix.trim_end_matches('/'))))
+            .max_by_key(|(prefix, _)| Path::new(prefix.as_str()).components().count());
+        match configured {
+            Some((_, module)) => module.clone(),
+            None => dir
+                .file_name()
+                .map(|name| join_words(&name.to_string_lossy(), '-'))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Palabras de un identificador en minúsculas: parte en `_`, `-`, `.` y en
+/// los cambios de mayúsculas (`parseHTTPRequest` → `parse http request`).
+fn join_words(name: &str, separator: char) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        let previous = i.checked_sub(1).map(|p| chars[p]);
+        let next = chars.get(i + 1);
+        let boundary = c.is_uppercase()
+            && previous.is_some_and(|p| {
+                p.is_lowercase()
+                    || p.is_ascii_digit()
+                    || (p.is_uppercase() && next.is_some_and(|n| n.is_lowercase()))
+            });
+        if boundary && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        current.extend(c.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words.join(&separator.to_string())
+}
+
+/// Fuerza `is_valid_id`: lo que no es alfanumérico, `-` ni `_` pasa a ser
+/// `separator`, sin separadores repetidos ni en los extremos.
+fn sanitize(id: &str, separator: char) -> String {
+    let mut out = String::with_capacity(id.len());
+    for c in id.chars() {
+        let c = if c.is_alphanumeric() || c == '-' || c == '_' {
+            c
+        } else {
+            separator
+        };
+        let is_separator = c == '-' || c == '_';
+        if is_separator && (out.is_empty() || out.ends_with(['-', '_'])) {
+            continue;
+        }
+        out.push(c);
+    }
+    let out = out.trim_end_matches(['-', '_']).to_string();
+    if is_valid_id(&out) {
+        out
+    } else {
+        "id".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn style(text: &str) -> IdStyle {
+        IdStyle::try_from(text.to_string()).unwrap()
+    }
+
+    fn generate(style: &IdStyle, functions: &[(&str, &str)], existing: &[&str]) -> Vec<String> {
+        let modules = BTreeMap::from([("src/payments".to_string(), "billing".to_string())]);
+        let mut ids = IdGenerator::new(style, &modules, existing.iter().map(|s| s.to_string()));
+        functions
+            .iter()
+            .map(|(function, file)| ids.next(function, Path::new(file)))
+            .collect()
+    }
+
+    #[test]
+    fn builtin_styles_split_camel_case_and_acronyms() {
+        let functions = [
+            ("createUser", "src/users/api.ts"),
+            ("parseHTTPRequest", "src/http.ts"),
+            ("send_email", "src/mail.py"),
+            ("UserService.findById", "src/users/Service.java"),
+        ];
+        assert_eq!(
+            generate(&IdStyle::default(), &functions, &[]),
+            [
+                "create-user",
+                "parse-http-request",
+                "send-email",
+                "user-service-find-by-id"
+            ]
+        );
+        assert_eq!(
+            generate(&style("snake"), &functions, &[]),
+            [
+                "create_user",
+                "parse_http_request",
+                "send_email",
+                "user_service_find_by_id"
+            ]
+        );
+        // `keep` solo sustituye lo que un ID no admite
+        assert_eq!(
+            generate(&style("keep"), &functions, &[]),
+            [
+                "createUser",
+                "parseHTTPRequest",
+                "send_email",
+                "UserService-findById"
+            ]
+        );
+    }
+
+    #[test]
+    fn templates_take_the_module_from_the_directory_or_the_map() {
+        let template = style("{module}-{function_kebab}");
+        assert_eq!(
+            generate(
+                &template,
+                &[
+                    ("createInvoice", "src/payments/invoice.ts"),
+                    ("refund", "src/payments/stripe/refund.ts"),
+                    ("createUser", "src/user_accounts/api.ts"),
+                    ("main", "main.ts"),
+                ],
+                &[],
+            ),
+            [
+                "billing-create-invoice",
+                "billing-refund",
+                "user-accounts-create-user",
+                "main"
+            ]
+        );
+        assert_eq!(
+            generate(&style("api.{function}"), &[("getUser", "src/a.ts")], &[]),
+            ["api-getUser"]
+        );
+    }
+
+    #[test]
+    fn collisions_get_a_numeric_suffix_in_the_style_separator() {
+        assert_eq!(
+            generate(
+                &IdStyle::default(),
+                &[
+                    ("createUser", "a.ts"),
+                    ("create_user", "b.py"),
+                    ("CreateUser", "c.cs")
+                ],
+                &["create-user"],
+            ),
+            ["create-user-2", "create-user-3", "create-user-4"]
+        );
+        assert_eq!(
+            generate(
+                &style("snake"),
+                &[("createUser", "a.ts"), ("createUser", "b.ts")],
+                &["create_user_2"]
+            ),
+            ["create_user", "create_user_3"]
+        );
+    }
+
+    #[test]
+    fn generated_ids_are_always_valid() {
+        let odd = [
+            ("$init", "a.js"),
+            ("__proto__", "b.js"),
+            ("λ", "c.ts"),
+            ("???", "d.ts"),
+        ];
+        for style in [
+            IdStyle::default(),
+            style("snake"),
+            style("keep"),
+            style("{module}--{function}"),
+        ] {
+            for id in generate(&style, &odd, &[]) {
+                assert!(is_valid_id(&id), "{:?}: '{}'", style, id);
+                assert!(!id.starts_with(['-', '_']) && !id.ends_with(['-', '_']));
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_styles_and_placeholders_are_rejected() {
+        assert!(IdStyle::try_from("camel".to_string()).is_err());
+        assert!(IdStyle::try_from("{module}-{name}".to_string())
+            .unwrap_err()
+            .contains("{name}"));
+        assert!(IdStyle::try_from("{module}-api".to_string()).is_err());
+        assert!(IdStyle::try_from("{function".to_string()).is_err());
+        let config: BTreeMap<String, IdStyle> =
+            serde_yml::from_str("id_style: '{module}-{function_snake}'").unwrap();
+        assert_eq!(
+            config["id_style"],
+            IdStyle::Template("{module}-{function_snake}".into())
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! Secciones enlazadas que nombran detalles internos del código (opt-in).
+//!
+//! Una sección que menciona `` `retryWithJitter` `` o `` `connPool` `` —un
+//! helper o una variable que el archivo de su función no exporta— describe la
+//! implementación en lugar del comportamiento, y el lector no puede usar esos
+//! nombres. Se recogen los símbolos internos de cada archivo enlazado
+//! (`code_parser::parse_internal_symbols` más sus funciones no públicas) y se
+//! cruzan con el código en línea de la sección (`DocSection::mentions`). Es
+//! la condición inversa de `unknown-example-symbol`, que reporta los nombres
+//! que no existen, y comparte con ella `references.allow`.
+//!
+//! Es un aviso de estilo: `internal-symbol-reference` se activa en `rules:` y
+//! se reporta siempre como Info.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::Path;
+
+use crate::core::types::{CodeEntity, DocSection, Related, Rule, Severity, ValidationResult};
+use crate::parser::code_parser;
+
+/// Un hallazgo por sección y archivo de código enlazado, con cada símbolo
+/// interno mencionado en `related`.
+pub fn validate_internal_references(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+    allow: &[String],
+) -> Vec<ValidationResult> {
+    let public: HashSet<&str> = code_entities
+        .iter()
+        .filter(|e| e.is_public)
+        .map(|e| e.name.as_str())
+        .collect();
+    let mut internals: HashMap<&Path, BTreeSet<String>> = HashMap::new();
+    let mut checked: HashSet<(&Path, String)> = HashSet::new();
+    let mut results = Vec::new();
+
+    for entity in code_entities {
+        let Some(doc_id) = &entity.doc_id else {
+            continue;
+        };
+        let symbols = internals
+            .entry(&entity.file_path)
+            .or_insert_with(|| internal_symbols(&entity.file_path, code_entities));
+        for section in doc_sections
+            .iter()
+            .filter(|s| s.id == *doc_id && !s.no_link)
+        {
+            if !checked.insert((&entity.file_path, section.location())) {
+                continue;
+            }
+            let is_arg = |name: &str| {
+                entity.args.iter().any(|a| a.name == name)
+                    || section.args.iter().any(|a| a.name == name)
+            };
+            let mut mentioned: Vec<&(String, usize)> = Vec::new();
+            for mention in &section.mentions {
+                let name = mention.0.as_str();
+                if symbols.contains(name)
+                    && name != entity.name
+                    && !public.contains(name)
+                    && !is_arg(name)
+                    && !allow.iter().any(|a| a == name)
+                    && !mentioned.iter().any(|(m, _)| m == name)
+                {
+                    mentioned.push(mention);
+                }
+            }
+            if !mentioned.is_empty() {
+                results.push(internal_reference(entity, section, &mentioned));
+            }
+        }
+    }
+    results
+}
+
+/// Símbolos que `file` declara sin exportar. El archivo ya se parseó; si
+/// ahora no se puede leer, quedan solo sus funciones no públicas.
+fn internal_symbols(file: &Path, code_entities: &[CodeEntity]) -> BTreeSet<String> {
+    let mut symbols: BTreeSet<String> = code_parser::parse_internal_symbols(file)
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+    symbols.extend(
+        code_entities
+            .iter()
+            .filter(|e| !e.is_public && e.file_path == file)
+            .map(|e| e.name.clone()),
+    );
+    symbols
+}
+
+fn internal_reference(
+    entity: &CodeEntity,
+    section: &DocSection,
+    mentioned: &[&(String, usize)],
+) -> ValidationResult {
+    let names: Vec<String> = mentioned
+        .iter()
+        .map(|(name, _)| format!("`{}`", name))
+        .collect();
+    ValidationResult {
+        severity: Severity::Info,
+        rule: Rule::InternalSymbolReference,
+        message: format!(
+            "La sección pública referencia símbolos internos: {}.",
+            names.join(", ")
+        ),
+        function_name: Some(entity.name.clone()),
+        code_location: Some(entity.location()),
+        doc_id: Some(section.id.clone()),
+        doc_location: Some(section.location()),
+        hint: Some(
+            "Describe el comportamiento en lugar de la implementación, o añade el nombre a `references.allow` en .docsguard/config.yaml."
+                .into(),
+        ),
+        provenance: None,
+        related: mentioned
+            .iter()
+            .map(|(name, line)| Related {
+                location: format!("{}:{}", section.file_path.display(), line),
+                message: format!(
+                    "`{}` no se exporta desde {}",
+                    name,
+                    entity.file_path.display()
+                ),
+            })
+            .collect(),
+        target: None,
+        summary: None,
+        cause: None,
+        grace: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::diagnostics::ParseDiagnostics;
+    use crate::parser::doc_parser::parse_markdown_source;
+    use crate::vfs::{MemFs, MemProject};
+    use std::path::PathBuf;
+
+    const TS: &str = "\
+const connPool = createPool();
+
+function retryWithJitter(attempt: number) {
+  return attempt * 2;
+}
+
+export const DEFAULT_TIMEOUT = 30;
+
+export function connect(url: string) {
+  const backoff = retryWithJitter(1);
+  return connPool.open(url, backoff);
+}
+";
+
+    const DOCS: &str = "\
+<!-- @docs-id: db-connect -->
+## connect
+
+Abre una conexión a `url` con `DEFAULT_TIMEOUT` segundos de espera.
+Reintenta con `retryWithJitter()` y reutiliza `connPool`; ver `connect`.
+
+| Param | Type | Description |
+|---|---|---|
+| url | string | Dirección del servidor |
+";
+
+    /// Entidades y secciones del ejemplo, con el código escrito en `dir`.
+    fn project(dir: &MemProject, docs: &str) -> (Vec<CodeEntity>, Vec<DocSection>) {
+        let code_file = dir.path().join("db.ts");
+        dir.write(
+            &code_file,
+            TS.replace(
+                "\nexport function",
+                "\n// @docs: [db-connect]\nexport function",
+            ),
+        );
+        let entities = code_parser::parse_project_code(
+            &[code_file],
+            &Default::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let sections = parse_markdown_source(
+            docs,
+            &PathBuf::from("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        (entities, sections)
+    }
+
+    #[test]
+    fn sections_naming_private_symbols_of_the_linked_file_get_one_info() {
+        let dir = MemFs::project();
+        let (entities, sections) = project(&dir, DOCS);
+        let results = validate_internal_references(&entities, &sections, &[]);
+        assert_eq!(results.len(), 1, "{results:?}");
+        let finding = &results[0];
+        assert_eq!(finding.severity, Severity::Info);
+        assert_eq!(finding.function_name.as_deref(), Some("connect"));
+        // Ni el argumento, ni lo exportado, ni la propia función
+        assert_eq!(
+            finding.message,
+            "La sección pública referencia símbolos internos: `retryWithJitter`, `connPool`."
+        );
+        assert_eq!(finding.related.len(), 2);
+        assert_eq!(finding.related[0].location, "docs/api.md:5");
+    }
+
+    #[test]
+    fn allowed_names_and_unknown_ones_are_not_reported() {
+        let dir = MemFs::project();
+        let (entities, sections) = project(&dir, DOCS);
+        let allow = ["retryWithJitter".to_string(), "connPool".to_string()];
+        assert!(validate_internal_references(&entities, &sections, &allow).is_empty());
+
+        // `backoff` es una variable local: también es interna
+        let (entities, sections) = project(
+            &dir,
+            "<!-- @docs-id: db-connect -->\n## connect\n\nUsa `backoff` y `createPool`.\n",
+        );
+        let results = validate_internal_references(&entities, &sections, &[]);
+        assert_eq!(
+            results[0].message,
+            "La sección pública referencia símbolos internos: `backoff`."
+        );
+    }
+}
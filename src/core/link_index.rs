@@ -0,0 +1,385 @@
+//! Índice incremental de enlaces para el modo watch.
+//!
+//! Con muchas secciones, el pase de huérfanas de `validate_links` (cada
+//! sección contra todas las entidades) domina cada revalidación aunque solo
+//! haya cambiado un archivo. El índice guarda entidades y secciones por
+//! archivo junto a mapas de ID → secciones, ID → entidades que lo enlazan,
+//! título → secciones y nombre → entidades; `set_code_file`/`set_doc_file`
+//! solo tocan las entradas del archivo que cambió, y
+//! `validator::validate_links_indexed` resuelve sus consultas con ellos.
+//!
+//! El orden es el de llegada de los archivos (uno que se reemplaza conserva
+//! su puesto): con las listas concatenadas en ese orden, `validate_links` da
+//! exactamente los mismos hallazgos.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::core::types::{CodeEntity, DocSection};
+use crate::core::validator::LinkLookup;
+
+/// Posición de una entidad o sección: (orden del archivo, índice en el archivo).
+type Slot = (u64, usize);
+
+#[derive(Debug, Default)]
+pub struct LinkIndex {
+    next_order: u64,
+    code_order: HashMap<PathBuf, u64>,
+    doc_order: HashMap<PathBuf, u64>,
+    code: BTreeMap<u64, Vec<CodeEntity>>,
+    docs: BTreeMap<u64, Vec<DocSection>>,
+    section_count: usize,
+    sections_by_id: HashMap<String, BTreeSet<Slot>>,
+    sections_by_title: HashMap<String, BTreeSet<Slot>>,
+    /// ID → entidades que lo enlazan con `@docs`.
+    linkers: HashMap<String, BTreeSet<Slot>>,
+    entities_by_name: HashMap<String, BTreeSet<Slot>>,
+    /// ID → entidades cubiertas por un `@docs-file` a él.
+    file_links: HashMap<String, BTreeSet<Slot>>,
+}
+
+fn insert(map: &mut HashMap<String, BTreeSet<Slot>>, key: &str, slot: Slot) {
+    map.entry(key.to_string()).or_default().insert(slot);
+}
+
+fn remove(map: &mut HashMap<String, BTreeSet<Slot>>, key: &str, slot: Slot) {
+    if let Some(slots) = map.get_mut(key) {
+        slots.remove(&slot);
+        if slots.is_empty() {
+            map.remove(key);
+        }
+    }
+}
+
+impl LinkIndex {
+    /// Orden del archivo `path` en `orders`, asignando el siguiente si es nuevo.
+    fn order_of(next: &mut u64, orders: &mut HashMap<PathBuf, u64>, path: &Path) -> u64 {
+        *orders.entry(path.to_path_buf()).or_insert_with(|| {
+            *next += 1;
+            *next
+        })
+    }
+
+    /// Reemplaza las entidades de `path`. Retorna `false` si no cambiaron.
+    pub fn set_code_file(&mut self, path: &Path, entities: Vec<CodeEntity>) -> bool {
+        let order = Self::order_of(&mut self.next_order, &mut self.code_order, path);
+        if self.code.get(&order) == Some(&entities) {
+            return false;
+        }
+        self.unindex_code(order);
+        for (i, entity) in entities.iter().enumerate() {
+            let slot = (order, i);
+            if let Some(id) = &entity.doc_id {
+                insert(&mut self.linkers, id, slot);
+            }
+            if let Some(link) = &entity.file_link {
+                insert(&mut self.file_links, &link.doc_id, slot);
+            }
+            insert(&mut self.entities_by_name, &entity.name, slot);
+        }
+        self.code.insert(order, entities);
+        true
+    }
+
+    /// Olvida las entidades de `path`.
+    pub fn remove_code_file(&mut self, path: &Path) {
+        if let Some(order) = self.code_order.remove(path) {
+            self.unindex_code(order);
+        }
+    }
+
+    fn unindex_code(&mut self, order: u64) {
+        for (i, entity) in self
+            .code
+            .remove(&order)
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+        {
+            let slot = (order, i);
+            if let Some(id) = &entity.doc_id {
+                remove(&mut self.linkers, id, slot);
+            }
+            if let Some(link) = &entity.file_link {
+                remove(&mut self.file_links, &link.doc_id, slot);
+            }
+            remove(&mut self.entities_by_name, &entity.name, slot);
+        }
+    }
+
+    /// Reemplaza las secciones de `path`. Retorna `false` si no cambiaron.
+    pub fn set_doc_file(&mut self, path: &Path, sections: Vec<DocSection>) -> bool {
+        let order = Self::order_of(&mut self.next_order, &mut self.doc_order, path);
+        if self.docs.get(&order) == Some(&sections) {
+            return false;
+        }
+        self.unindex_docs(order);
+        for (i, section) in sections.iter().enumerate() {
+            let slot = (order, i);
+            insert(&mut self.sections_by_id, &section.id, slot);
+            if let Some(title) = &section.title {
+                insert(&mut self.sections_by_title, title, slot);
+            }
+        }
+        self.section_count += sections.len();
+        self.docs.insert(order, sections);
+        true
+    }
+
+    /// Olvida las secciones de `path`.
+    pub fn remove_doc_file(&mut self, path: &Path) {
+        if let Some(order) = self.doc_order.remove(path) {
+            self.unindex_docs(order);
+        }
+    }
+
+    fn unindex_docs(&mut self, order: u64) {
+        let sections = self.docs.remove(&order).unwrap_or_default();
+        self.section_count -= sections.len();
+        for (i, section) in sections.iter().enumerate() {
+            let slot = (order, i);
+            remove(&mut self.sections_by_id, &section.id, slot);
+            if let Some(title) = &section.title {
+                remove(&mut self.sections_by_title, title, slot);
+            }
+        }
+    }
+
+    fn entity(&self, (order, i): Slot) -> &CodeEntity {
+        &self.code[&order][i]
+    }
+
+    fn section(&self, (order, i): Slot) -> &DocSection {
+        &self.docs[&order][i]
+    }
+
+    /// Entidades de todos los archivos, en orden.
+    pub fn code_entities(&self) -> Vec<CodeEntity> {
+        self.entities().cloned().collect()
+    }
+
+    /// Secciones de todos los archivos, en orden.
+    pub fn doc_sections(&self) -> Vec<DocSection> {
+        self.sections().cloned().collect()
+    }
+}
+
+impl LinkLookup for LinkIndex {
+    fn entities(&self) -> impl Iterator<Item = &CodeEntity> {
+        self.code.values().flatten()
+    }
+
+    fn sections(&self) -> impl Iterator<Item = &DocSection> {
+        self.docs.values().flatten()
+    }
+
+    fn section_count(&self) -> usize {
+        self.section_count
+    }
+
+    fn sections_with_id(&self, id: &str) -> Vec<&DocSection> {
+        self.sections_by_id.get(id).map_or_else(Vec::new, |slots| {
+            slots.iter().map(|&slot| self.section(slot)).collect()
+        })
+    }
+
+    fn section_titled(&self, title: &str) -> Option<&DocSection> {
+        let slot = self.sections_by_title.get(title)?.first()?;
+        Some(self.section(*slot))
+    }
+
+    fn is_linked(&self, id: &str) -> bool {
+        self.linkers.contains_key(id)
+    }
+
+    fn entities_named(&self, name: &str) -> Vec<&CodeEntity> {
+        self.entities_by_name
+            .get(name)
+            .map_or_else(Vec::new, |slots| {
+                slots.iter().map(|&slot| self.entity(slot)).collect()
+            })
+    }
+
+    fn file_link_ids(&self) -> HashSet<&str> {
+        self.file_links.keys().map(String::as_str).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::suppression;
+    use crate::core::types::{Arg, ArgSource, FileLink, Suppression};
+    use crate::core::validator::{validate_links, validate_links_indexed};
+
+    const IDS: [&str; 6] = ["auth", "auth-login", "billing", "refund", "search", "x"];
+    const NAMES: [&str; 6] = ["login", "logout", "charge", "refund", "find", "x"];
+    const TITLES: [&str; 4] = ["Auth", "Billing", "Login", "Refund"];
+
+    fn arg(rng: &mut fastrand::Rng) -> Arg {
+        Arg {
+            name: rng.choice(["id", "user", "amount"]).unwrap().into(),
+            type_name: rng
+                .bool()
+                .then(|| rng.choice(["string", "number"]).unwrap().into()),
+            description: None,
+            source: ArgSource::Code,
+            line: None,
+            unit: None,
+            range: None,
+        }
+    }
+
+    fn pick<'a>(rng: &mut fastrand::Rng, pool: &[&'a str]) -> &'a str {
+        pool[rng.usize(..pool.len())]
+    }
+
+    fn random_code(rng: &mut fastrand::Rng, path: &str) -> Vec<CodeEntity> {
+        let file_link = (rng.u8(..5) == 0).then(|| FileLink {
+            doc_id: pick(rng, &IDS).into(),
+            line: 1,
+        });
+        (0..rng.usize(..6))
+            .map(|i| CodeEntity {
+                name: pick(rng, &NAMES).into(),
+                args: (0..rng.usize(..3)).map(|_| arg(rng)).collect(),
+                return_type: None,
+                return_fields: None,
+                doc_id: rng.bool().then(|| pick(rng, &IDS).into()),
+                file_path: PathBuf::from(path),
+                line: i + 2,
+                end_line: i + 2,
+                is_public: true,
+                suppressions: if rng.u8(..6) == 0 {
+                    vec![Suppression {
+                        rule: rng.choice(["*", "missing-doc-section"]).unwrap().into(),
+                        line: i + 1,
+                    }]
+                } else {
+                    Vec::new()
+                },
+                file_link: file_link.clone(),
+                examples: Vec::new(),
+            })
+            .collect()
+    }
+
+    fn random_docs(rng: &mut fastrand::Rng, path: &str) -> Vec<DocSection> {
+        (0..rng.usize(..6))
+            .map(|i| DocSection {
+                id: pick(rng, &IDS).into(),
+                title: (rng.u8(..4) != 0).then(|| pick(rng, &TITLES).into()),
+                parent: rng.bool().then(|| pick(rng, &TITLES).into()),
+                args: (0..rng.usize(..3)).map(|_| arg(rng)).collect(),
+                file_path: PathBuf::from(path),
+                line: i * 5 + 1,
+                expectations: Vec::new(),
+                examples: Vec::new(),
+                since: None,
+                expected_function: (rng.u8(..5) == 0).then(|| pick(rng, &NAMES).into()),
+                skips: if rng.u8(..5) == 0 {
+                    vec!["args".into()]
+                } else {
+                    Vec::new()
+                },
+                dropped_args: 0,
+                title_inferred: false,
+                no_link: rng.u8(..8) == 0,
+                mentions: Vec::new(),
+            })
+            .collect()
+    }
+
+    /// Lista de archivos en orden de llegada, como la mantiene el índice.
+    fn put<T>(files: &mut Vec<(String, Vec<T>)>, path: &str, items: Vec<T>) {
+        match files.iter_mut().find(|(p, _)| p == path) {
+            Some((_, existing)) => *existing = items,
+            None => files.push((path.to_string(), items)),
+        }
+    }
+
+    #[test]
+    fn incremental_results_match_a_full_validation_after_every_mutation() {
+        const CODE: [&str; 4] = ["src/a.ts", "src/b.ts", "src/c.ts", "src/d.ts"];
+        const DOCS: [&str; 3] = ["docs/a.md", "docs/b.md", "docs/c.md"];
+        let mut findings = 0;
+        for seed in 0..20 {
+            let mut rng = fastrand::Rng::with_seed(seed);
+            let mut index = LinkIndex::default();
+            let mut code: Vec<(String, Vec<CodeEntity>)> = Vec::new();
+            let mut docs: Vec<(String, Vec<DocSection>)> = Vec::new();
+            for step in 0..60 {
+                match rng.u8(..10) {
+                    0 => {
+                        let path = pick(&mut rng, &CODE);
+                        index.remove_code_file(Path::new(path));
+                        code.retain(|(p, _)| p != path);
+                    }
+                    1 => {
+                        let path = pick(&mut rng, &DOCS);
+                        index.remove_doc_file(Path::new(path));
+                        docs.retain(|(p, _)| p != path);
+                    }
+                    2..=5 => {
+                        let path = pick(&mut rng, &CODE);
+                        let entities = random_code(&mut rng, path);
+                        index.set_code_file(Path::new(path), entities.clone());
+                        put(&mut code, path, entities);
+                    }
+                    _ => {
+                        let path = pick(&mut rng, &DOCS);
+                        let sections = random_docs(&mut rng, path);
+                        index.set_doc_file(Path::new(path), sections.clone());
+                        put(&mut docs, path, sections);
+                    }
+                }
+                let entities: Vec<CodeEntity> = code.iter().flat_map(|(_, e)| e.clone()).collect();
+                let sections: Vec<DocSection> = docs.iter().flat_map(|(_, s)| s.clone()).collect();
+                let expected = validate_links(&entities, &sections, &Default::default());
+                let mut indexed = validate_links_indexed(&index, &Default::default());
+                suppression::apply_inline_suppressions(&entities, &mut indexed);
+                assert_eq!(
+                    format!("{:?}", indexed),
+                    format!("{:?}", expected),
+                    "semilla {seed}, paso {step}"
+                );
+                assert_eq!(index.section_count(), sections.len());
+                findings += expected.len();
+            }
+        }
+        assert!(
+            findings > 2000,
+            "el generador apenas produce hallazgos: {findings}"
+        );
+    }
+
+    #[test]
+    fn unchanged_files_leave_the_index_untouched() {
+        let mut rng = fastrand::Rng::with_seed(3);
+        let mut index = LinkIndex::default();
+        let sections = random_docs(&mut rng, "docs/a.md");
+        assert!(index.set_doc_file(Path::new("docs/a.md"), sections.clone()));
+        assert!(!index.set_doc_file(Path::new("docs/a.md"), sections));
+
+        let entities = vec![CodeEntity {
+            name: "login".into(),
+            args: Vec::new(),
+            return_type: None,
+            return_fields: None,
+            doc_id: Some("auth".into()),
+            file_path: PathBuf::from("src/a.ts"),
+            line: 2,
+            end_line: 2,
+            is_public: true,
+            suppressions: Vec::new(),
+            file_link: None,
+            examples: Vec::new(),
+        }];
+        assert!(index.set_code_file(Path::new("src/a.ts"), entities.clone()));
+        assert!(!index.set_code_file(Path::new("src/a.ts"), entities));
+        assert!(index.is_linked("auth"));
+        index.remove_code_file(Path::new("src/a.ts"));
+        assert!(!index.is_linked("auth"));
+        assert!(index.linkers.is_empty() && index.entities_by_name.is_empty());
+    }
+}
@@ -1,3 +1,18 @@
+pub mod anchors;
+pub mod conflicts;
+pub mod constraints;
+pub mod diagnostics;
+pub mod examples;
 pub mod heuristic;
+pub mod ids;
+pub mod internals;
+#[cfg(feature = "watch")]
+pub mod link_index;
+pub mod pipeline;
+pub mod site_urls;
+pub mod suppression;
+pub mod symbols;
+pub mod targets;
 pub mod types;
 pub mod validator;
+pub mod version_source;
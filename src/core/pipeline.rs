@@ -0,0 +1,245 @@
+//! Pipeline de validación compartido.
+//!
+//! `run_validation` ejecuta los pases sobre lo ya parseado y después, una
+//! sola vez, las supresiones en línea, los niveles de `rules:`, el periodo de
+//! gracia y los destinos de inserción. Lo usan `check`, `watch`, `baseline`, `triage`,
+//! `config diff` y `report::build_report` (`report --html`, `ci github`,
+//! `assert`, `self-check`): entre ellos solo cambian los pases opcionales de
+//! `Passes` y lo que hacen con el resultado (baseline, agrupación de Info,
+//! formato), nunca la validación. `watch` aporta su índice incremental para
+//! los pases de enlace, que dan lo mismo que `validate_links`; los tests de
+//! `watch` comprueban que su informe es el de `build_report`.
+
+use std::borrow::Cow;
+use std::path::Path;
+
+use crate::attestation::{self, Attestations};
+use crate::config::rules::RuleLevel;
+use crate::config::Config;
+use crate::core::diagnostics::{ParseDiagnostics, Strictness};
+#[cfg(feature = "watch")]
+use crate::core::link_index::LinkIndex;
+use crate::core::symbols::{self, KnownSymbols};
+use crate::core::types::{CodeEntity, DocSection, Rule, Suppression, ValidationResult};
+use crate::core::validator::{self, StaleArgException};
+use crate::core::version_source::ProjectVersions;
+use crate::core::{anchors, examples, internals, suppression, targets};
+use crate::dates;
+use crate::grace::{self, FileCreationDates};
+use crate::new_functions::{self, NewFunctions};
+
+/// Un archivo de docs de la ejecución con su contenido.
+#[derive(Clone, Copy)]
+pub struct DocSource<'a> {
+    /// Archivo tal como se indicó; los hallazgos usan su ruta relativa
+    /// (`config.paths`).
+    pub file: &'a Path,
+    /// Contenido de `file`, para las anclas y las atestaciones.
+    pub source: &'a str,
+}
+
+/// Lo parseado de una ejecución.
+#[derive(Clone, Copy)]
+pub struct Inputs<'a> {
+    pub code_entities: &'a [CodeEntity],
+    pub doc_sections: &'a [DocSection],
+    /// Notas del parseo del código y de las docs.
+    pub diagnostics: &'a ParseDiagnostics,
+    /// Archivos de docs de los que salen `doc_sections` (al menos uno); al
+    /// final del primero van las secciones que faltan.
+    pub docs: &'a [DocSource<'a>],
+    pub config: &'a Config,
+}
+
+/// Pases opcionales; `Passes::default()` es `check` sin flags.
+#[derive(Default)]
+pub struct Passes<'a> {
+    /// Reclasifica erratas de argumentos (`--check-arg-typos`).
+    pub arg_typos: bool,
+    /// Compara los ejemplos con los tipos de retorno (`--check-examples`).
+    pub examples: bool,
+    /// `--check-symbols`.
+    pub symbols: Option<&'a KnownSymbols>,
+    /// `--check-versions`.
+    pub versions: Option<&'a ProjectVersions>,
+    /// `--strict` / `--pedantic`.
+    pub strictness: Option<Strictness>,
+    /// `--require-docs-for-new`.
+    pub new_functions: Option<&'a NewFunctions>,
+    /// `--check-attestations`.
+    pub attestations: Option<&'a Attestations>,
+    /// Hallazgos de pases que viven fuera de `core` (`--verify-site`); pasan
+    /// por las supresiones y los niveles como los demás.
+    pub extra: Vec<ValidationResult>,
+    /// Todas las reglas opt-in y sin niveles ni destinos: `config diff`
+    /// compara los niveles de dos configuraciones sobre estos hallazgos.
+    pub raw: bool,
+    /// Índice incremental de `watch` para los pases de enlace.
+    #[cfg(feature = "watch")]
+    pub index: Option<&'a LinkIndex>,
+}
+
+/// Hallazgos de una validación, antes del baseline.
+pub struct Validation<'a> {
+    pub results: Vec<ValidationResult>,
+    /// Supresiones en línea que no silenciaron nada, con su entidad.
+    pub unused_directives: Vec<(&'a CodeEntity, &'a Suppression)>,
+    /// Entradas de `arg_exceptions` que no sustituyeron nada.
+    pub stale_exceptions: Vec<StaleArgException<'a>>,
+}
+
+/// Ejecuta todos los pases de `passes` sobre `inputs`. Se valida sin
+/// supresiones y se aplican al final, anotando cuáles silenciaron algo.
+pub fn run_validation<'a>(inputs: Inputs<'a>, passes: Passes) -> Validation<'a> {
+    let Inputs {
+        code_entities,
+        doc_sections,
+        diagnostics,
+        docs,
+        config,
+    } = inputs;
+    let primary = docs[0].file;
+    let doc_path = config.paths.normalize(primary);
+    // Las secciones de cada archivo, para los pases que leen su texto
+    let sections_of = |doc: &DocSource| -> Cow<[DocSection]> {
+        match docs {
+            [_] => Cow::Borrowed(doc_sections),
+            _ => {
+                let file = config.paths.normalize(doc.file);
+                doc_sections
+                    .iter()
+                    .filter(|s| s.file_path == file)
+                    .cloned()
+                    .collect()
+            }
+        }
+    };
+    let enabled = |rule: Rule| passes.raw || config.rules.enabled(rule);
+    let entities = suppression::without_suppressions(code_entities);
+
+    #[cfg(feature = "watch")]
+    let mut results = match passes.index {
+        Some(index) => validator::validate_links_indexed(index, &config.arg_names),
+        None => validator::validate_links(&entities, doc_sections, &config.arg_names),
+    };
+    #[cfg(not(feature = "watch"))]
+    let mut results = validator::validate_links(&entities, doc_sections, &config.arg_names);
+    if passes.raw || config.rules.level(Rule::DuplicateDocId) != Some(RuleLevel::Off) {
+        results.extend(validator::validate_duplicate_ids(doc_sections));
+    }
+    let stale_exceptions = validator::apply_arg_exceptions(
+        &entities,
+        doc_sections,
+        &config.arg_exceptions,
+        &mut results,
+    );
+    results.extend(validator::validate_link_boundaries(
+        &entities,
+        doc_sections,
+        &config.link_boundaries,
+    ));
+    if passes.arg_typos {
+        validator::reclassify_arg_typos(&entities, doc_sections, &mut results);
+    }
+    if passes.raw || config.rules.level(Rule::CopiedSection) != Some(RuleLevel::Off) {
+        validator::merge_copied_sections(
+            &entities,
+            doc_sections,
+            config
+                .copied_section_min_args
+                .unwrap_or(validator::DEFAULT_COPIED_SECTION_MIN_ARGS),
+            &mut results,
+        );
+    }
+    if passes.examples {
+        results.extend(examples::validate_examples(&entities, doc_sections));
+    }
+    results.extend(examples::validate_code_examples(&entities, passes.examples));
+    results.extend(examples::validate_example_args(&entities, doc_sections));
+    if let Some(known) = passes.symbols {
+        results.extend(symbols::validate_example_symbols(
+            doc_sections,
+            known,
+            &config.references,
+        ));
+    }
+    if let Some(versions) = passes.versions {
+        results.extend(validator::validate_since_versions(
+            &entities,
+            doc_sections,
+            versions,
+        ));
+    }
+    results.extend(validator::validate_parse_notes(&entities, diagnostics));
+    if let Some(strictness) = passes.strictness {
+        results.extend(validator::validate_strict(
+            &entities,
+            doc_sections,
+            diagnostics,
+            strictness,
+        ));
+    }
+    if enabled(Rule::PlaceholderDescription) {
+        results.extend(validator::validate_placeholders(&entities, doc_sections));
+    }
+    if enabled(Rule::CrossLanguageDrift) {
+        results.extend(validator::validate_cross_language(&entities));
+    }
+    if enabled(Rule::InternalSymbolReference) {
+        results.extend(internals::validate_internal_references(
+            &entities,
+            doc_sections,
+            &config.references.allow,
+        ));
+    }
+    if let Some(new) = passes.new_functions {
+        new_functions::validate_new_functions(&entities, doc_sections, new, config, &mut results);
+    }
+    if let Some(attestations) = passes.attestations {
+        for doc in docs {
+            results.extend(attestation::validate_attestations(
+                &entities,
+                &sections_of(doc),
+                doc.source,
+                attestations,
+                &config.attestation_required,
+            ));
+        }
+    }
+    for doc in docs {
+        results.extend(anchors::validate_anchors(
+            doc.source,
+            doc.file,
+            &config.paths.normalize(doc.file),
+            &sections_of(doc),
+            &config.site,
+        ));
+    }
+    results.extend(passes.extra);
+
+    let unused_directives = suppression::apply_and_record(code_entities, &mut results);
+    if !passes.raw {
+        config.rules.apply(&mut results);
+        if let Some(policy) = config.grace_period {
+            grace::apply(
+                &mut results,
+                policy,
+                &config.paths,
+                &FileCreationDates::default(),
+                dates::today_days(),
+            );
+        }
+        targets::attach_targets(
+            &mut results,
+            code_entities,
+            doc_sections,
+            &doc_path,
+            targets::end_line(primary),
+        );
+    }
+    Validation {
+        results,
+        unused_directives,
+        stale_exceptions,
+    }
+}
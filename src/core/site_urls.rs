@@ -0,0 +1,194 @@
+//! URLs publicadas de las secciones de docs (`check --verify-site`).
+//!
+//! La página sale de la ruta del archivo de docs y el ancla, del primer
+//! encabezado de la sección con `site.slugger` (ver `core::anchors`). Todo es
+//! puro: las peticiones las hace `site`, solo con la feature `verify-site`.
+#![cfg_attr(not(feature = "verify-site"), allow(dead_code))]
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::config::SiteConfig;
+use crate::core::anchors::PageAnchors;
+use crate::core::types::DocSection;
+
+/// URL publicada de una sección (`check --verify-site`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectionUrl {
+    pub id: String,
+    /// Ubicación de la sección en las docs (`archivo:línea`).
+    pub location: String,
+    pub url: String,
+}
+
+/// Ruta de la página que publica `doc_path` (relativa a la raíz del
+/// proyecto): sin `site.docs_dir` delante ni extensión; `index` y `README`
+/// son el directorio.
+pub fn page_path(doc_path: &Path, docs_dir: Option<&Path>) -> String {
+    let relative = docs_dir
+        .and_then(|dir| doc_path.strip_prefix(dir).ok())
+        .unwrap_or(doc_path);
+    let mut parts: Vec<String> = relative
+        .with_extension("")
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().replace(' ', "%20"))
+        .collect();
+    if parts.last().is_some_and(|last| {
+        last.eq_ignore_ascii_case("index") || last.eq_ignore_ascii_case("readme")
+    }) {
+        parts.pop();
+        return parts.iter().map(|p| format!("{}/", p)).collect();
+    }
+    parts.join("/")
+}
+
+/// URLs de las secciones de `source` bajo `base_url`, con `site.url_template`
+/// (`{page}#{anchor}` por defecto; admite también `{id}`). El ancla es la
+/// del primer encabezado de la sección; las que no tienen se omiten.
+pub fn section_urls(
+    source: &str,
+    sections: &[DocSection],
+    doc_path: &Path,
+    site: &SiteConfig,
+    base_url: &str,
+) -> Vec<SectionUrl> {
+    let anchors = PageAnchors::parse(source, site);
+    let page = page_path(doc_path, site.docs_dir.as_deref());
+    let template = site.url_template.as_deref().unwrap_or("{page}#{anchor}");
+    let mut starts: Vec<usize> = sections.iter().map(|s| s.line).collect();
+    starts.sort_unstable();
+    sections
+        .iter()
+        .filter_map(|section| {
+            let next = starts.iter().copied().find(|&line| line > section.line);
+            let heading = anchors
+                .headings
+                .iter()
+                .find(|h| h.line >= section.line && next.is_none_or(|next| h.line < next))?;
+            let path = template
+                .replace("{page}", &page)
+                .replace("{anchor}", &heading.anchor)
+                .replace("{id}", &section.id);
+            Some(SectionUrl {
+                id: section.id.clone(),
+                location: format!("{}:{}", doc_path.display(), section.line),
+                url: format!(
+                    "{}/{}",
+                    base_url.trim_end_matches('/'),
+                    path.trim_start_matches('/')
+                ),
+            })
+        })
+        .collect()
+}
+
+/// `count` URLs repartidas por toda la lista (todas si no hay más).
+pub fn sample(urls: Vec<SectionUrl>, count: usize) -> Vec<SectionUrl> {
+    if count >= urls.len() {
+        return urls;
+    }
+    let len = urls.len();
+    let picked: HashSet<usize> = (0..count).map(|i| i * len / count).collect();
+    urls.into_iter()
+        .enumerate()
+        .filter(|(i, _)| picked.contains(i))
+        .map(|(_, url)| url)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::anchors::SlugStyle;
+    use crate::core::diagnostics::ParseDiagnostics;
+    use crate::parser::doc_parser::parse_markdown_source;
+    use std::path::PathBuf;
+
+    const DOCS: &str = "\
+<!-- @docs-id: auth-login -->
+## Login {#auth-login}
+
+See [logout](#auth-logout).
+
+### Parameters
+
+<!-- @docs-id: auth-logout -->
+## Logout {#auth-logout}
+";
+
+    fn sections() -> Vec<DocSection> {
+        parse_markdown_source(
+            DOCS,
+            Path::new("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn section_urls_follow_the_page_path_and_template() {
+        assert_eq!(
+            page_path(Path::new("docs/api/users.md"), Some(Path::new("docs"))),
+            "api/users"
+        );
+        assert_eq!(
+            page_path(Path::new("docs/index.md"), Some(Path::new("docs"))),
+            ""
+        );
+        assert_eq!(page_path(Path::new("guide/README.md"), None), "guide/");
+        assert_eq!(page_path(Path::new("my guide.mdx"), None), "my%20guide");
+
+        let sections = sections();
+        let site = SiteConfig {
+            slugger: SlugStyle::Docusaurus,
+            docs_dir: Some(PathBuf::from("docs")),
+            ..SiteConfig::default()
+        };
+        let urls = section_urls(
+            DOCS,
+            &sections,
+            Path::new("docs/api.md"),
+            &site,
+            "https://docs.example.com/",
+        );
+        let urls: Vec<(&str, &str)> = urls
+            .iter()
+            .map(|u| (u.id.as_str(), u.url.as_str()))
+            .collect();
+        assert_eq!(
+            urls,
+            [
+                ("auth-login", "https://docs.example.com/api#auth-login"),
+                ("auth-logout", "https://docs.example.com/api#auth-logout"),
+            ]
+        );
+        let per_id = SiteConfig {
+            url_template: Some("/reference/{id}".into()),
+            ..site
+        };
+        let urls = section_urls(
+            DOCS,
+            &sections,
+            Path::new("docs/api.md"),
+            &per_id,
+            "https://d.io",
+        );
+        assert_eq!(urls[1].url, "https://d.io/reference/auth-logout");
+        assert_eq!(urls[1].location, "docs/api.md:8");
+    }
+
+    #[test]
+    fn samples_spread_over_the_whole_list() {
+        let urls: Vec<SectionUrl> = (0..10)
+            .map(|i| SectionUrl {
+                id: i.to_string(),
+                location: String::new(),
+                url: String::new(),
+            })
+            .collect();
+        let ids = |urls: Vec<SectionUrl>| urls.into_iter().map(|u| u.id).collect::<Vec<_>>();
+        assert_eq!(ids(sample(urls.clone(), 3)), ["0", "3", "6"]);
+        assert_eq!(ids(sample(urls.clone(), 20)).len(), 10);
+        assert!(sample(urls, 0).is_empty());
+    }
+}
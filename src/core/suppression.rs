@@ -0,0 +1,281 @@
+//! Supresiones en línea de hallazgos.
+//!
+//! Un comentario `// docsguard-ignore: ghost-arg, missing-arg` en el bloque de
+//! comentarios previo a una función silencia las reglas indicadas para esa
+//! función. Sin lista de reglas (`// docsguard-ignore`) silencia todas.
+//!
+//! `check` aplica las supresiones una sola vez tras validar y anota cuáles
+//! silenciaron algo: las que no silenciaron nada se reportan como
+//! `unused-suppression` con `--report-unused-suppressions`.
+
+use std::collections::HashSet;
+
+use crate::core::types::{CodeEntity, Rule, Severity, Suppression, ValidationResult};
+
+/// Directiva que marca una supresión en línea.
+pub const IGNORE_DIRECTIVE: &str = "docsguard-ignore";
+
+/// Comodín que representa "todas las reglas".
+pub const ALL_RULES: &str = "*";
+
+/// Extrae las reglas de una directiva `docsguard-ignore` dentro de un comentario.
+///
+/// Devuelve `None` si el comentario no contiene la directiva.
+pub fn parse_ignore_directive(comment_body: &str) -> Option<Vec<String>> {
+    let rest = comment_body.trim().strip_prefix(IGNORE_DIRECTIVE)?;
+    let rest = rest.trim();
+
+    if rest.is_empty() {
+        return Some(vec![ALL_RULES.to_string()]);
+    }
+
+    let rules = rest.strip_prefix(':')?;
+    let rules: Vec<String> = rules
+        .split(',')
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty())
+        .collect();
+
+    if rules.is_empty() {
+        Some(vec![ALL_RULES.to_string()])
+    } else {
+        Some(rules)
+    }
+}
+
+/// Elimina los hallazgos silenciados por supresiones en línea de su función.
+///
+/// El hallazgo se asocia a la entidad por ubicación exacta (`archivo:línea`).
+pub fn apply_inline_suppressions(
+    code_entities: &[CodeEntity],
+    results: &mut Vec<ValidationResult>,
+) {
+    apply_and_record(code_entities, results);
+}
+
+/// Como `apply_inline_suppressions`, pero anotando qué directivas silenciaron
+/// algún hallazgo. Retorna las que no silenciaron ninguno, con su entidad.
+pub fn apply_and_record<'a>(
+    code_entities: &'a [CodeEntity],
+    results: &mut Vec<ValidationResult>,
+) -> Vec<(&'a CodeEntity, &'a Suppression)> {
+    if code_entities.iter().all(|e| e.suppressions.is_empty()) {
+        return Vec::new();
+    }
+
+    // (índice de entidad, índice de directiva) que silenciaron algo
+    let mut hits: HashSet<(usize, usize)> = HashSet::new();
+    results.retain(|r| {
+        let Some(ref location) = r.code_location else {
+            return true;
+        };
+        let mut suppressed = false;
+        for (i, entity) in code_entities.iter().enumerate() {
+            if entity.location() != *location {
+                continue;
+            }
+            for (j, suppression) in entity.suppressions.iter().enumerate() {
+                if silences(suppression, &r.rule) {
+                    hits.insert((i, j));
+                    suppressed = true;
+                }
+            }
+        }
+        !suppressed
+    });
+
+    let hits = &hits;
+    code_entities
+        .iter()
+        .enumerate()
+        .flat_map(|(i, entity)| {
+            entity
+                .suppressions
+                .iter()
+                .enumerate()
+                .filter(move |(j, _)| !hits.contains(&(i, *j)))
+                .map(move |(_, suppression)| (entity, suppression))
+        })
+        .collect()
+}
+
+/// Copia de las entidades sin supresiones, para validar sin filtrar y
+/// aplicar después `apply_and_record`.
+pub fn without_suppressions(code_entities: &[CodeEntity]) -> Vec<CodeEntity> {
+    code_entities
+        .iter()
+        .map(|e| CodeEntity {
+            suppressions: Vec::new(),
+            ..e.clone()
+        })
+        .collect()
+}
+
+fn silences(suppression: &Suppression, rule: &Rule) -> bool {
+    suppression.rule == ALL_RULES || suppression.rule == rule.id()
+}
+
+/// Hallazgos `unused-suppression` para directivas que no silenciaron nada.
+pub fn unused_suppression_results(
+    unused: &[(&CodeEntity, &Suppression)],
+    severity: Severity,
+) -> Vec<ValidationResult> {
+    unused
+        .iter()
+        .map(|(entity, suppression)| {
+            let directive = if suppression.rule == ALL_RULES {
+                IGNORE_DIRECTIVE.to_string()
+            } else {
+                format!("{}: {}", IGNORE_DIRECTIVE, suppression.rule)
+            };
+            ValidationResult {
+                severity,
+                rule: Rule::UnusedSuppression,
+                message: format!(
+                    "La supresión `{}` de fn {} no silenció ningún hallazgo.",
+                    directive, entity.name
+                ),
+                function_name: Some(entity.name.clone()),
+                code_location: Some(format!(
+                    "{}:{}",
+                    entity.file_path.display(),
+                    suppression.line
+                )),
+                doc_id: None,
+                doc_location: None,
+                hint: Some(format!(
+                    "Quita `{}` del comentario: el hallazgo ya no se produce.",
+                    suppression.rule
+                )),
+                provenance: None,
+                related: Vec::new(),
+                target: None,
+                summary: None,
+                cause: None,
+                grace: None,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{Rule, Severity};
+    use std::path::PathBuf;
+
+    fn entity(suppressions: &[&str]) -> CodeEntity {
+        CodeEntity {
+            name: "login".into(),
+            args: vec![],
+            return_type: None,
+            return_fields: None,
+            doc_id: None,
+            file_path: PathBuf::from("src/auth.ts"),
+            line: 3,
+            end_line: 3,
+            is_public: true,
+            suppressions: suppressions
+                .iter()
+                .map(|s| Suppression {
+                    rule: s.to_string(),
+                    line: 2,
+                })
+                .collect(),
+            file_link: None,
+            examples: Vec::new(),
+        }
+    }
+
+    fn result(rule: Rule, location: &str) -> ValidationResult {
+        ValidationResult {
+            severity: Severity::Warning,
+            rule,
+            message: "msg".into(),
+            function_name: Some("login".into()),
+            code_location: Some(location.into()),
+            doc_id: None,
+            doc_location: None,
+            hint: None,
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+
+    #[test]
+    fn parses_rule_list() {
+        assert_eq!(
+            parse_ignore_directive("docsguard-ignore: ghost-arg, missing-arg"),
+            Some(vec!["ghost-arg".to_string(), "missing-arg".to_string()])
+        );
+    }
+
+    #[test]
+    fn bare_directive_means_all_rules() {
+        assert_eq!(
+            parse_ignore_directive("docsguard-ignore"),
+            Some(vec![ALL_RULES.to_string()])
+        );
+    }
+
+    #[test]
+    fn unrelated_comment_is_not_a_directive() {
+        assert_eq!(parse_ignore_directive("@docs: [auth-login]"), None);
+        assert_eq!(parse_ignore_directive("docsguard-ignored-by-typo"), None);
+    }
+
+    #[test]
+    fn suppression_removes_only_named_rule() {
+        let entities = vec![entity(&["missing-arg"])];
+        let mut results = vec![
+            result(Rule::MissingArg, "src/auth.ts:3"),
+            result(Rule::TypeMismatch, "src/auth.ts:3"),
+            result(Rule::MissingArg, "src/other.ts:3"),
+        ];
+        apply_inline_suppressions(&entities, &mut results);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].rule, Rule::TypeMismatch);
+        assert_eq!(results[1].code_location.as_deref(), Some("src/other.ts:3"));
+    }
+
+    #[test]
+    fn wildcard_suppresses_everything_on_entity() {
+        let entities = vec![entity(&[ALL_RULES])];
+        let mut results = vec![
+            result(Rule::UnlinkedFunction, "src/auth.ts:3"),
+            result(Rule::GhostArg, "src/auth.ts:3"),
+        ];
+        apply_inline_suppressions(&entities, &mut results);
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn directives_that_silenced_nothing_are_reported_at_their_line() {
+        let entities = vec![entity(&["missing-arg", "ghost-arg"]), {
+            let mut other = entity(&[ALL_RULES]);
+            other.name = "logout".into();
+            other.line = 9;
+            other.suppressions[0].line = 8;
+            other
+        }];
+        let mut results = vec![result(Rule::MissingArg, "src/auth.ts:3")];
+        let unused = apply_and_record(&entities, &mut results);
+        assert!(results.is_empty());
+
+        let findings = unused_suppression_results(&unused, Severity::Info);
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].rule, Rule::UnusedSuppression);
+        assert!(findings[0]
+            .message
+            .contains("`docsguard-ignore: ghost-arg`"));
+        assert_eq!(findings[0].code_location.as_deref(), Some("src/auth.ts:2"));
+        assert!(findings[1]
+            .message
+            .contains("`docsguard-ignore` de fn logout"));
+        assert_eq!(findings[1].code_location.as_deref(), Some("src/auth.ts:8"));
+    }
+}
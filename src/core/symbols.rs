@@ -0,0 +1,843 @@
+//! Símbolos usados en los ejemplos de código frente a los exportados del proyecto (opt-in).
+//!
+//! Tras un renombrado, un ejemplo `import { createUser } from '@acme/sdk'` sigue
+//! en las docs aunque `createUser` ya no exista. Con `check --check-symbols` se
+//! recogen los nombres exportados de los archivos verificados (`export` en
+//! TypeScript, `pub` en Rust, funciones públicas del resto) y en cada bloque
+//! `ts`/`js`/`rust` se comprueban solo:
+//!
+//! - los nombres importados de un paquete propio: `references.packages`, el
+//!   `name` de `package.json` / `Cargo.toml`, rutas relativas y `crate::`;
+//! - las llamadas directas `nombre(...)` que el ejemplo no define ni importa
+//!   de otro paquete.
+//!
+//! Métodos (`obj.metodo()`), rutas (`Tipo::new()`), macros y globales del
+//! lenguaje no se comprueban. Severidad `Info` salvo `references.severity`.
+
+use anyhow::Result;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use strsim::normalized_levenshtein;
+
+use crate::config::ReferencesConfig;
+use crate::core::types::{CodeEntity, CodeExample, DocSection, Rule, Severity, ValidationResult};
+use crate::parser::code_parser;
+use crate::vfs;
+
+/// Similitud mínima para sugerir un símbolo parecido.
+const SUGGESTION_THRESHOLD: f64 = 0.75;
+
+/// Llamadas a keywords y globales de TypeScript/JavaScript que no se comprueban.
+const TS_BUILTINS: &[&str] = &[
+    "if",
+    "for",
+    "while",
+    "switch",
+    "catch",
+    "return",
+    "typeof",
+    "function",
+    "await",
+    "async",
+    "super",
+    "import",
+    "require",
+    "void",
+    "delete",
+    "yield",
+    "constructor",
+    "fetch",
+    "setTimeout",
+    "setInterval",
+    "clearTimeout",
+    "clearInterval",
+    "queueMicrotask",
+    "parseInt",
+    "parseFloat",
+    "isNaN",
+    "isFinite",
+    "String",
+    "Number",
+    "Boolean",
+    "BigInt",
+    "Array",
+    "Object",
+    "Symbol",
+    "Promise",
+    "Date",
+    "Error",
+    "TypeError",
+    "Map",
+    "Set",
+    "WeakMap",
+    "WeakSet",
+    "RegExp",
+    "URL",
+    "encodeURIComponent",
+    "decodeURIComponent",
+    "structuredClone",
+    "alert",
+    "describe",
+    "it",
+    "test",
+    "expect",
+    "beforeEach",
+    "afterEach",
+    "beforeAll",
+    "afterAll",
+];
+
+/// Llamadas a keywords y constructores del prelude de Rust que no se comprueban.
+const RUST_BUILTINS: &[&str] = &[
+    "if", "while", "for", "match", "return", "loop", "fn", "move", "in", "as", "Some", "Ok", "Err",
+    "Box", "Vec", "String", "drop",
+];
+
+/// Keywords tras las que el identificador es una definición local del ejemplo.
+const DEFINITION_KEYWORDS: &[&str] = &[
+    "function",
+    "class",
+    "const",
+    "let",
+    "var",
+    "interface",
+    "type",
+    "enum",
+    "fn",
+    "struct",
+    "trait",
+    "mod",
+    "static",
+];
+
+/// Lenguajes de ejemplo comprobados.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExampleLang {
+    TypeScript,
+    Rust,
+}
+
+impl ExampleLang {
+    fn from_tag(tag: &str) -> Option<Self> {
+        match tag.to_lowercase().as_str() {
+            "ts" | "tsx" | "typescript" | "js" | "jsx" | "javascript" | "mjs" | "cjs" => {
+                Some(ExampleLang::TypeScript)
+            }
+            "rust" | "rs" => Some(ExampleLang::Rust),
+            _ => None,
+        }
+    }
+
+    fn builtins(self) -> &'static [&'static str] {
+        match self {
+            ExampleLang::TypeScript => TS_BUILTINS,
+            ExampleLang::Rust => RUST_BUILTINS,
+        }
+    }
+}
+
+/// Nombres exportados del proyecto y paquetes propios.
+#[derive(Debug, Clone, Default)]
+pub struct KnownSymbols {
+    names: BTreeSet<String>,
+    packages: Vec<String>,
+}
+
+impl KnownSymbols {
+    /// Recoge los símbolos de `code_files` y los paquetes declarados en
+    /// `project_root` (`package.json`, `Cargo.toml`) y en la configuración.
+    pub fn collect(
+        code_files: &[PathBuf],
+        code_entities: &[CodeEntity],
+        project_root: &Path,
+        config: &ReferencesConfig,
+    ) -> Result<Self> {
+        let mut names: BTreeSet<String> = code_entities
+            .iter()
+            .filter(|e| e.is_public)
+            .map(|e| e.name.clone())
+            .collect();
+        for file in code_files {
+            names.extend(code_parser::parse_exported_symbols(file)?);
+        }
+
+        let mut packages = config.packages.clone();
+        packages.extend(project_package_names(project_root));
+        Ok(KnownSymbols { names, packages })
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.names.contains(name)
+    }
+
+    /// Módulo propio: paquete configurado (o un subpath), ruta relativa o `crate`.
+    fn is_own_module(&self, module: &str) -> bool {
+        if module.starts_with("./") || module.starts_with("../") {
+            return true;
+        }
+        if matches!(module, "crate" | "self" | "super") {
+            return true;
+        }
+        self.packages.iter().any(|p| {
+            let rust_name = p.replace('-', "_");
+            module == p
+                || module == rust_name
+                || module
+                    .strip_prefix(p.as_str())
+                    .is_some_and(|rest| rest.starts_with('/'))
+        })
+    }
+
+    /// Símbolo exportado más parecido, si lo bastante parecido.
+    fn suggestion(&self, name: &str) -> Option<&str> {
+        self.names
+            .iter()
+            .map(|known| (known, normalized_levenshtein(name, known)))
+            .filter(|(_, score)| *score >= SUGGESTION_THRESHOLD)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(known, _)| known.as_str())
+    }
+}
+
+/// `name` de `package.json` y de `[package]` en `Cargo.toml`, si existen.
+fn project_package_names(project_root: &Path) -> Vec<String> {
+    let mut names = Vec::new();
+    if let Some(name) = vfs::read_to_string(&project_root.join("package.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|json| json["name"].as_str().map(String::from))
+    {
+        names.push(name);
+    }
+    if let Ok(content) = vfs::read_to_string(&project_root.join("Cargo.toml")) {
+        let mut in_package = false;
+        for line in content.lines().map(str::trim) {
+            if line.starts_with('[') {
+                in_package = line == "[package]";
+            } else if let Some(value) = line
+                .strip_prefix("name")
+                .map(str::trim_start)
+                .and_then(|rest| rest.strip_prefix('='))
+                .filter(|_| in_package)
+            {
+                names.push(value.trim().trim_matches('"').to_string());
+            }
+        }
+    }
+    names
+}
+
+/// Identificador usado en un ejemplo, con su línea relativa al bloque.
+#[derive(Debug, Clone, PartialEq)]
+struct Reference {
+    name: String,
+    line_offset: usize,
+}
+
+/// Valida los símbolos de los ejemplos de cada sección.
+pub fn validate_example_symbols(
+    doc_sections: &[DocSection],
+    known: &KnownSymbols,
+    config: &ReferencesConfig,
+) -> Vec<ValidationResult> {
+    let mut results = Vec::new();
+    for section in doc_sections {
+        for example in &section.examples {
+            let Some(lang) = example.lang.as_deref().and_then(ExampleLang::from_tag) else {
+                continue;
+            };
+            for reference in references(&example.code, lang, known) {
+                if known.contains(&reference.name) || config.allow.contains(&reference.name) {
+                    continue;
+                }
+                results.push(unknown_symbol(section, example, &reference, known, config));
+            }
+        }
+    }
+    results
+}
+
+fn unknown_symbol(
+    section: &DocSection,
+    example: &CodeExample,
+    reference: &Reference,
+    known: &KnownSymbols,
+    config: &ReferencesConfig,
+) -> ValidationResult {
+    let hint = match known.suggestion(&reference.name) {
+        Some(similar) => format!("¿Quisiste decir `{}`?", similar),
+        None => {
+            "Actualiza el ejemplo o añade el nombre a `references.allow` en .docsguard/config.yaml."
+                .into()
+        }
+    };
+    ValidationResult {
+        severity: config.severity.severity(),
+        rule: Rule::UnknownExampleSymbol,
+        message: format!(
+            "El ejemplo de la sección '{}' usa `{}`, que no está entre los símbolos exportados del proyecto.",
+            section.id, reference.name
+        ),
+        function_name: None,
+        code_location: None,
+        doc_id: Some(section.id.clone()),
+        doc_location: Some(format!(
+            "{}:{}",
+            section.file_path.display(),
+            example.line + reference.line_offset
+        )),
+        hint: Some(hint),
+        provenance: None,
+        related: Vec::new(),
+        target: None,
+        summary: None,
+        cause: None,
+        grace: None,
+    }
+}
+
+/// Identificadores a comprobar en un bloque: importados de un módulo propio y
+/// llamadas directas no definidas ni importadas de otro paquete. Sin duplicados.
+fn references(code: &str, lang: ExampleLang, known: &KnownSymbols) -> Vec<Reference> {
+    let clean = blank_strings_and_comments(code, lang);
+    let mut references: Vec<Reference> = Vec::new();
+    let mut foreign: BTreeSet<String> = BTreeSet::new();
+
+    let imports = match lang {
+        ExampleLang::TypeScript => ts_imports(code, &clean),
+        ExampleLang::Rust => rust_imports(&clean),
+    };
+    for (module, name, offset) in imports {
+        if known.is_own_module(&module) {
+            push_unique(&mut references, name, line_of(&clean, offset));
+        } else {
+            foreign.insert(name);
+        }
+    }
+
+    for (start, name) in direct_calls(&clean, lang) {
+        if !foreign.contains(name) {
+            push_unique(&mut references, name.to_string(), line_of(&clean, start));
+        }
+    }
+    references
+}
+
+/// Llamadas directas `nombre(` de un bloque ya blanqueado, con su posición:
+/// sin métodos, rutas, definiciones locales ni globales del lenguaje.
+fn direct_calls(clean: &str, lang: ExampleLang) -> Vec<(usize, &str)> {
+    let tokens = identifiers(clean);
+    let locals: BTreeSet<&str> = tokens
+        .windows(2)
+        .filter(|w| DEFINITION_KEYWORDS.contains(&w[0].1))
+        .map(|w| w[1].1)
+        .collect();
+
+    let mut calls = Vec::new();
+    for (i, &(start, name)) in tokens.iter().enumerate() {
+        let end = start + name.len();
+        let next = clean[end..].trim_start().chars().next();
+        let prev = clean[..start].trim_end().chars().last();
+        let prev_token = i.checked_sub(1).map(|j| tokens[j].1);
+        let is_call = next == Some('(')
+            && !matches!(prev, Some('.' | ':'))
+            && !matches!(prev_token, Some("function" | "fn"));
+        if is_call && !lang.builtins().contains(&name) && !locals.contains(name) {
+            calls.push((start, name));
+        }
+    }
+    calls
+}
+
+/// Indica si `example` es TypeScript o JavaScript; sin etiqueta, se supone que sí.
+pub fn is_typescript(example: &CodeExample) -> bool {
+    example
+        .lang
+        .as_deref()
+        .is_none_or(|tag| ExampleLang::from_tag(tag) == Some(ExampleLang::TypeScript))
+}
+
+/// Llamada directa de un ejemplo TypeScript.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExampleCall {
+    pub name: String,
+    /// Línea relativa al bloque.
+    pub line_offset: usize,
+    /// Texto de cada argumento de la llamada, recortado; `None` si alguno es
+    /// un spread (`...args`).
+    pub args: Option<Vec<String>>,
+}
+
+/// Llamadas directas de un ejemplo TypeScript con sus argumentos, una por
+/// aparición.
+pub fn ts_calls(code: &str) -> Vec<ExampleCall> {
+    let clean = blank_strings_and_comments(code, ExampleLang::TypeScript);
+    direct_calls(&clean, ExampleLang::TypeScript)
+        .into_iter()
+        .map(|(start, name)| {
+            let open = start + name.len() + clean[start + name.len()..].find('(').unwrap_or(0);
+            ExampleCall {
+                name: name.to_string(),
+                line_offset: line_of(&clean, start),
+                args: call_arguments(code, &clean, open + 1),
+            }
+        })
+        .collect()
+}
+
+/// Argumentos de primer nivel de la llamada cuyo `(` acaba en `from`: se
+/// separan sobre `clean` (sin comas de cadenas) y se copian de `code`.
+fn call_arguments(code: &str, clean: &str, from: usize) -> Option<Vec<String>> {
+    let mut depth = 0usize;
+    let mut args = Vec::new();
+    let mut start = from;
+    let mut end = clean.len();
+    for (i, c) in clean[from..].char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' if depth > 0 => depth -= 1,
+            ')' => {
+                end = from + i;
+                break;
+            }
+            ',' if depth == 0 => {
+                args.push(code[start..from + i].trim().to_string());
+                start = from + i + 1;
+            }
+            _ => {}
+        }
+    }
+    // Coma final (`f(a, b,)`) o llamada sin argumentos
+    let last = code[start..end].trim();
+    if !last.is_empty() {
+        args.push(last.to_string());
+    }
+    if args.iter().any(|a| a.starts_with("...")) {
+        return None;
+    }
+    Some(args)
+}
+
+/// Llamadas de los ejemplos `@example` del JSDoc a funciones que no existen
+/// en el proyecto pero se parecen a una exportada del mismo archivo: el
+/// rastro habitual de un renombrado. Siempre Info.
+pub fn validate_renamed_siblings(code_entities: &[CodeEntity]) -> Vec<ValidationResult> {
+    let all_names: BTreeSet<&str> = code_entities.iter().map(|e| e.name.as_str()).collect();
+    let mut results = Vec::new();
+    for entity in code_entities.iter().filter(|e| !e.examples.is_empty()) {
+        let siblings = KnownSymbols {
+            names: code_entities
+                .iter()
+                .filter(|e| e.is_public && e.file_path == entity.file_path)
+                .map(|e| e.name.clone())
+                .collect(),
+            packages: Vec::new(),
+        };
+        let examples = entity.examples.iter().filter(|x| is_typescript(x));
+        for example in examples {
+            for reference in references(&example.code, ExampleLang::TypeScript, &siblings) {
+                if all_names.contains(reference.name.as_str()) {
+                    continue;
+                }
+                let Some(similar) = siblings.suggestion(&reference.name) else {
+                    continue;
+                };
+                results.push(ValidationResult {
+                    severity: Severity::Info,
+                    rule: Rule::UnknownExampleSymbol,
+                    message: format!(
+                        "El ejemplo de fn {} usa `{}`, que no existe en el proyecto; {} exporta `{}`.",
+                        entity.name,
+                        reference.name,
+                        entity.file_path.display(),
+                        similar
+                    ),
+                    function_name: Some(entity.name.clone()),
+                    code_location: Some(format!(
+                        "{}:{}",
+                        entity.file_path.display(),
+                        example.line + reference.line_offset
+                    )),
+                    doc_id: entity.doc_id.clone(),
+                    doc_location: None,
+                    hint: Some(format!(
+                        "¿Se renombró a `{}`? Actualiza el ejemplo del JSDoc.",
+                        similar
+                    )),
+                    provenance: None,
+                    related: Vec::new(),
+                    target: None,
+                    summary: None,
+                    cause: None,
+                    grace: None,
+                });
+            }
+        }
+    }
+    results
+}
+
+fn push_unique(references: &mut Vec<Reference>, name: String, line_offset: usize) {
+    if !references.iter().any(|r| r.name == name) {
+        references.push(Reference { name, line_offset });
+    }
+}
+
+fn line_of(text: &str, offset: usize) -> usize {
+    text[..offset].matches('\n').count()
+}
+
+/// `import { a, b as c } from 'mod'` → `(mod, a)`, `(mod, b)`. Los imports por
+/// defecto y de namespace no se comprueban: su nombre local es arbitrario.
+fn ts_imports(code: &str, clean: &str) -> Vec<(String, String, usize)> {
+    let mut imports = Vec::new();
+    for (start, token) in identifiers(clean) {
+        if token != "import" || !starts_statement(clean, start) {
+            continue;
+        }
+        let rest = &clean[start + token.len()..];
+        let Some(from) = find_keyword(rest, "from") else {
+            continue;
+        };
+        let module_start = start + token.len() + from + "from".len();
+        let Some(module) = quoted(&code[module_start..]) else {
+            continue;
+        };
+        let clause = &rest[..from];
+        let (Some(open), Some(close)) = (clause.find('{'), clause.rfind('}')) else {
+            continue;
+        };
+        for item in clause[open + 1..close].split(',') {
+            let item = item.trim();
+            let item = item.strip_prefix("type ").unwrap_or(item).trim();
+            let name = item.split_whitespace().next().unwrap_or_default();
+            if is_identifier(name) {
+                imports.push((module.clone(), name.to_string(), start));
+            }
+        }
+    }
+    imports
+}
+
+/// `use acme_sdk::{create_user, Client as C};` → `(acme_sdk, create_user)`, `(acme_sdk, Client)`.
+fn rust_imports(clean: &str) -> Vec<(String, String, usize)> {
+    let mut imports = Vec::new();
+    for (start, token) in identifiers(clean) {
+        if token != "use" || !starts_statement(clean, start) {
+            continue;
+        }
+        let rest = &clean[start + token.len()..];
+        let Some(end) = rest.find(';') else {
+            continue;
+        };
+        let path: String = rest[..end].split_whitespace().collect::<Vec<_>>().join(" ");
+        let root = path
+            .split("::")
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        let items: Vec<&str> = match (path.find('{'), path.rfind('}')) {
+            (Some(open), Some(close)) if open < close => path[open + 1..close].split(',').collect(),
+            _ => vec![path.rsplit("::").next().unwrap_or_default()],
+        };
+        for item in items {
+            let item = item.rsplit("::").next().unwrap_or_default().trim();
+            let name = item.split_whitespace().next().unwrap_or_default();
+            if is_identifier(name) && name != "self" && name != root {
+                imports.push((root.clone(), name.to_string(), start));
+            }
+        }
+    }
+    imports
+}
+
+/// La palabra en `offset` empieza una sentencia (inicio de línea o tras `;`).
+fn starts_statement(text: &str, offset: usize) -> bool {
+    matches!(
+        text[..offset].trim_end_matches([' ', '\t']).chars().last(),
+        None | Some('\n' | ';' | '{' | '}')
+    )
+}
+
+/// Posición de `keyword` como palabra completa.
+fn find_keyword(text: &str, keyword: &str) -> Option<usize> {
+    identifiers(text)
+        .into_iter()
+        .find(|(_, token)| *token == keyword)
+        .map(|(start, _)| start)
+}
+
+/// Contenido del primer literal `'…'` / `"…"` de `text`.
+fn quoted(text: &str) -> Option<String> {
+    let text = text.trim_start();
+    let quote = text.chars().next().filter(|c| matches!(c, '\'' | '"'))?;
+    let body = &text[1..];
+    Some(body[..body.find(quote)?].to_string())
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Identificadores de `text` con su posición en bytes.
+fn identifiers(text: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start: Option<usize> = None;
+    for (i, c) in text.char_indices() {
+        let is_ident = c.is_alphanumeric() || c == '_' || c == '$';
+        match (start, is_ident) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                tokens.push((s, &text[s..i]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        tokens.push((s, &text[s..]));
+    }
+    // Los "identificadores" que empiezan por dígito son números
+    tokens.retain(|(_, t)| !t.starts_with(|c: char| c.is_ascii_digit()));
+    tokens
+}
+
+/// Sustituye literales de texto y comentarios por espacios, conservando
+/// longitudes y saltos de línea para que las posiciones sigan valiendo.
+/// En Rust las comillas simples no se blanquean (lifetimes y `char`).
+fn blank_strings_and_comments(code: &str, lang: ExampleLang) -> String {
+    let bytes = code.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut i = 0;
+    let blank = |out: &mut Vec<u8>, from: usize, to: usize| {
+        for b in &mut out[from..to] {
+            if *b != b'\n' {
+                *b = b' ';
+            }
+        }
+    };
+    while i < bytes.len() {
+        let rest = &bytes[i..];
+        if rest.starts_with(b"//") {
+            let end = rest
+                .iter()
+                .position(|&b| b == b'\n')
+                .map_or(bytes.len(), |p| i + p);
+            blank(&mut out, i, end);
+            i = end;
+        } else if rest.starts_with(b"/*") {
+            let end = rest
+                .windows(2)
+                .position(|w| w == b"*/")
+                .map_or(bytes.len(), |p| i + p + 2);
+            blank(&mut out, i, end);
+            i = end;
+        } else if matches!(rest[0], b'"' | b'`')
+            || (rest[0] == b'\'' && lang == ExampleLang::TypeScript)
+        {
+            let quote = rest[0];
+            let mut j = i + 1;
+            while j < bytes.len() && bytes[j] != quote {
+                j += if bytes[j] == b'\\' { 2 } else { 1 };
+            }
+            let end = (j + 1).min(bytes.len());
+            blank(&mut out, i + 1, end.saturating_sub(1).max(i + 1));
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    // Solo se sustituyen bytes completos de literales por ASCII: sigue siendo UTF-8
+    String::from_utf8(out).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::MemFs;
+
+    #[test]
+    fn calls_count_their_top_level_arguments() {
+        let calls: Vec<_> = ts_calls(
+            "login()\nlogin(\"a, b\", { x: 1, y: [2, 3] }, f(4, 5),)\nlogin(...args)\nclient.login(1)",
+        )
+        .into_iter()
+        .map(|c| (c.name, c.line_offset, c.args.map(|a| a.len())))
+        .collect();
+        assert_eq!(
+            calls,
+            [
+                ("login".to_string(), 0, Some(0)),
+                ("login".to_string(), 1, Some(3)),
+                ("f".to_string(), 1, Some(2)),
+                ("login".to_string(), 2, None),
+            ]
+        );
+        let texts = ts_calls("login(\"a, b\", { x: 1 }, 42)")[0].args.clone();
+        assert_eq!(
+            texts,
+            Some(vec!["\"a, b\"".into(), "{ x: 1 }".into(), "42".into()])
+        );
+    }
+    use crate::config::ReferenceSeverity;
+    use crate::core::types::Severity;
+
+    fn known(names: &[&str]) -> KnownSymbols {
+        KnownSymbols {
+            names: names.iter().map(|n| n.to_string()).collect(),
+            packages: vec!["@acme/sdk".into(), "acme-sdk".into()],
+        }
+    }
+
+    fn section(lang: &str, code: &str) -> DocSection {
+        DocSection {
+            id: "user-create".into(),
+            title: None,
+            parent: None,
+            args: vec![],
+            file_path: PathBuf::from("docs/api.md"),
+            line: 1,
+            expectations: vec![],
+            examples: vec![CodeExample {
+                lang: Some(lang.into()),
+                code: code.into(),
+                line: 10,
+            }],
+            since: None,
+            expected_function: None,
+            skips: Vec::new(),
+            dropped_args: 0,
+            title_inferred: false,
+            no_link: false,
+            mentions: Vec::new(),
+        }
+    }
+
+    fn unknown(section: DocSection, known: &KnownSymbols) -> Vec<(String, usize, String)> {
+        validate_example_symbols(&[section], known, &ReferencesConfig::default())
+            .into_iter()
+            .map(|r| {
+                let name = r.message.split('`').nth(1).unwrap().to_string();
+                let line = r
+                    .doc_location
+                    .unwrap()
+                    .rsplit(':')
+                    .next()
+                    .unwrap()
+                    .parse()
+                    .unwrap();
+                (name, line, r.hint.unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn renamed_import_is_flagged_with_a_suggestion() {
+        let code = "import { createUsers, Client } from '@acme/sdk';\nimport { useState } from 'react';\n\nconst user = createUsers('ana');\n";
+        let found = unknown(section("ts", code), &known(&["createUser", "Client"]));
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, "createUsers");
+        assert_eq!(found[0].1, 10);
+        assert_eq!(found[0].2, "¿Quisiste decir `createUser`?");
+    }
+
+    #[test]
+    fn only_imports_and_direct_calls_are_checked() {
+        let code = r#"
+import React from 'react';
+import { render } from 'react-dom';
+// deleteUser(id) en un comentario
+function helper(x) { return x; }
+const client = new Client({ retries: 3 });
+const user = await client.fetchUser("ana");
+console.log(JSON.stringify(user), helper(user), render(user));
+setTimeout(() => archiveUser(user.id), 10);
+"#;
+        let found = unknown(section("typescript", code), &known(&["Client"]));
+        let names: Vec<&str> = found.iter().map(|f| f.0.as_str()).collect();
+        assert_eq!(names, vec!["archiveUser"]);
+        assert_eq!(found[0].1, 18);
+    }
+
+    #[test]
+    fn rust_use_statements_and_calls() {
+        let code = r#"use acme_sdk::{create_user, Client as Api};
+use serde_json::json;
+
+let client = Api::new();
+let user = create_user("ana")?;
+println!("{}", json!(user));
+let admin = make_admin(&user);
+"#;
+        let found = unknown(section("rust", code), &known(&["create_user", "Client"]));
+        let names: Vec<&str> = found.iter().map(|f| f.0.as_str()).collect();
+        assert_eq!(names, vec!["make_admin"]);
+    }
+
+    #[test]
+    fn untagged_blocks_allowlist_and_severity() {
+        let untagged = DocSection {
+            examples: vec![CodeExample {
+                lang: None,
+                code: "goneFn()".into(),
+                line: 3,
+            }],
+            ..section("ts", "")
+        };
+        assert!(unknown(untagged, &known(&[])).is_empty());
+
+        let config = ReferencesConfig {
+            allow: vec!["legacyFn".into()],
+            severity: ReferenceSeverity::Warning,
+            ..ReferencesConfig::default()
+        };
+        let results = validate_example_symbols(
+            &[section("js", "legacyFn();\ngoneFn();\n")],
+            &known(&[]),
+            &config,
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].severity, Severity::Warning);
+        assert_eq!(results[0].rule, Rule::UnknownExampleSymbol);
+        assert!(results[0].message.contains("`goneFn`"));
+    }
+
+    #[test]
+    fn symbols_are_collected_from_ts_and_rust_exports() {
+        let dir = MemFs::project();
+        let ts = dir.path().join("sdk.ts");
+        dir.write(
+            &ts,
+            "export class Client {}\nexport const VERSION = '1';\nexport interface Options {}\nfunction internal() {}\nexport { internal as publicName };\n",
+        );
+        let rs = dir.path().join("lib.rs");
+        dir.write(
+            &rs,
+            "pub struct User;\nstruct Hidden;\npub mod api { pub fn create_user() {} fn private() {} }\n",
+        );
+        dir.write(dir.path().join("package.json"), r#"{"name": "@acme/web"}"#);
+
+        let known = KnownSymbols::collect(&[ts, rs], &[], dir.path(), &ReferencesConfig::default())
+            .unwrap();
+        for name in [
+            "Client",
+            "VERSION",
+            "Options",
+            "publicName",
+            "User",
+            "api",
+            "create_user",
+        ] {
+            assert!(known.contains(name), "{name}");
+        }
+        for name in ["internal", "Hidden", "private"] {
+            assert!(!known.contains(name), "{name}");
+        }
+        assert!(known.is_own_module("@acme/web/users"));
+        assert!(!known.is_own_module("@acme/webby"));
+    }
+}
@@ -0,0 +1,228 @@
+//! Destino de inserción de los hallazgos (`ValidationResult::target`).
+//!
+//! Para las reglas en las que se sabe dónde hay que escribir, el hallazgo
+//! lleva un `archivo:línea` que la salida de texto muestra como `Insertar en:`,
+//! las anotaciones de CI incluyen y `triage` usa al abrir el editor:
+//!
+//! - `missing-doc-section`: tras la última sección del mismo espacio de
+//!   nombres (`auth` en `auth-login`), o al final del archivo de docs.
+//! - `unlinked-function` y `undocumented-new-function`: la línea de la
+//!   función, donde va el `@docs`.
+//! - `orphan-section`: la función sin enlazar más parecida, con la heurística
+//!   de `scaffold`; sin candidata por encima del umbral no hay destino.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::core::heuristic::{self, Strategy};
+use crate::core::types::{CodeEntity, DocSection, Rule, ValidationResult};
+use crate::vfs;
+
+/// Espacio de nombres de un ID: lo anterior al primer `-` o `_`.
+pub fn namespace(id: &str) -> Option<&str> {
+    id.split_once(['-', '_'])
+        .map(|(namespace, _)| namespace)
+        .filter(|namespace| !namespace.is_empty())
+}
+
+/// Secciones de un archivo de docs por espacio de nombres.
+pub struct SectionIndex<'a> {
+    /// Línea de inicio de cada sección, en orden.
+    starts: Vec<usize>,
+    /// Espacio de nombres → línea de su última sección.
+    last_by_namespace: HashMap<&'a str, usize>,
+}
+
+impl<'a> SectionIndex<'a> {
+    pub fn new(sections: &'a [DocSection]) -> Self {
+        let mut starts: Vec<usize> = sections.iter().map(|s| s.line).collect();
+        starts.sort_unstable();
+        let mut last_by_namespace: HashMap<&'a str, usize> = HashMap::new();
+        for section in sections {
+            if let Some(namespace) = namespace(&section.id) {
+                let last = last_by_namespace.entry(namespace).or_insert(section.line);
+                *last = (*last).max(section.line);
+            }
+        }
+        SectionIndex {
+            starts,
+            last_by_namespace,
+        }
+    }
+
+    /// Línea donde insertar una sección nueva con `id`: la de la sección que
+    /// sigue a la última de su espacio de nombres. `None` si va al final del
+    /// archivo (no hay ninguna de su espacio, o la última cierra el archivo).
+    pub fn insertion_line(&self, id: &str) -> Option<usize> {
+        let last = *self.last_by_namespace.get(namespace(id)?)?;
+        self.starts.iter().copied().find(|&line| line > last)
+    }
+}
+
+/// Línea siguiente a la última de `doc_file` (1 si no se puede leer).
+pub fn end_line(doc_file: &Path) -> usize {
+    vfs::read_to_string(doc_file).map_or(1, |content| content.lines().count() + 1)
+}
+
+/// Rellena `target` en los hallazgos con un destino calculable. `doc_path`
+/// es la ruta mostrada del archivo de docs y `doc_end`, su `end_line`.
+pub fn attach_targets(
+    results: &mut [ValidationResult],
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+    doc_path: &Path,
+    doc_end: usize,
+) {
+    let index = SectionIndex::new(doc_sections);
+    // La heurística solo se ejecuta si hay secciones huérfanas
+    let mut candidates: Option<HashMap<String, (f64, String)>> = None;
+    for result in results.iter_mut().filter(|r| r.target.is_none()) {
+        result.target = match result.rule {
+            Rule::MissingDocSection => result.doc_id.as_deref().map(|id| {
+                let line = index.insertion_line(id).unwrap_or(doc_end);
+                format!("{}:{}", doc_path.display(), line)
+            }),
+            Rule::UnlinkedFunction | Rule::UndocumentedNewFunction => result.code_location.clone(),
+            Rule::OrphanSection => {
+                let best =
+                    candidates.get_or_insert_with(|| best_candidates(code_entities, doc_sections));
+                result
+                    .doc_id
+                    .as_ref()
+                    .and_then(|id| best.get(id))
+                    .map(|(_, location)| location.clone())
+            }
+            _ => None,
+        };
+    }
+}
+
+/// Ubicación de la función candidata más parecida a cada sección sin enlace.
+fn best_candidates(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+) -> HashMap<String, (f64, String)> {
+    let mut best: HashMap<String, (f64, String)> = HashMap::new();
+    for candidate in heuristic::find_candidates(code_entities, doc_sections, Strategy::Indexed) {
+        let entry = best
+            .entry(candidate.section_id)
+            .or_insert((f64::MIN, String::new()));
+        if candidate.confidence > entry.0 {
+            *entry = (candidate.confidence, candidate.code_location);
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::diagnostics::ParseDiagnostics;
+    use crate::core::validator::validate_links;
+    use crate::parser::code_parser::AnnotationOptions;
+    use crate::parser::doc_parser::parse_markdown_source;
+    use crate::parser::lang::typescript::parse_typescript_source;
+    use std::path::PathBuf;
+
+    const DOCS: &str = "\
+<!-- @docs-id: auth-login -->
+## Login
+
+<!-- @docs-id: auth-logout -->
+## Logout
+
+<!-- @docs-id: billing-charge -->
+## Charge
+
+<!-- @docs-id: users-create -->
+## Create user
+";
+
+    fn sections() -> Vec<DocSection> {
+        parse_markdown_source(
+            DOCS,
+            Path::new("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new_sections_go_after_the_last_one_of_their_namespace() {
+        let sections = sections();
+        let index = SectionIndex::new(&sections);
+        // Tras auth-logout, antes de billing-charge
+        assert_eq!(index.insertion_line("auth-refresh"), Some(7));
+        assert_eq!(index.insertion_line("billing_refund"), Some(10));
+        // La última sección del archivo, un espacio nuevo o un ID sin espacio: al final
+        assert_eq!(index.insertion_line("users-delete"), None);
+        assert_eq!(index.insertion_line("orders-list"), None);
+        assert_eq!(index.insertion_line("login"), None);
+        assert_eq!(namespace("-login"), None);
+    }
+
+    #[test]
+    fn findings_get_a_target_where_one_is_computable() {
+        let sections = sections();
+        let code = "\
+/// @docs: [auth-refresh]
+export function refresh(token: string) {}
+
+/// @docs: [orders-list]
+export function listOrders() {}
+
+export function createUser(name: string) {}
+
+export function unrelatedHelper() {}
+";
+        let entities = parse_typescript_source(
+            code,
+            &PathBuf::from("src/api.ts"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let mut results = validate_links(&entities, &sections, &Default::default());
+        attach_targets(
+            &mut results,
+            &entities,
+            &sections,
+            Path::new("docs/api.md"),
+            14,
+        );
+        let target = |rule: Rule, key: &str| {
+            results
+                .iter()
+                .find(|r| {
+                    r.rule == rule
+                        && (r.doc_id.as_deref() == Some(key)
+                            || r.function_name.as_deref() == Some(key))
+                })
+                .unwrap_or_else(|| panic!("sin {} para {}: {:#?}", rule, key, results))
+                .target
+                .clone()
+        };
+        assert_eq!(
+            target(Rule::MissingDocSection, "auth-refresh").as_deref(),
+            Some("docs/api.md:7")
+        );
+        assert_eq!(
+            target(Rule::MissingDocSection, "orders-list").as_deref(),
+            Some("docs/api.md:14")
+        );
+        assert_eq!(
+            target(Rule::UnlinkedFunction, "unrelatedHelper").as_deref(),
+            Some("src/api.ts:9")
+        );
+        // La huérfana users-create tiene candidata; billing-charge no
+        assert_eq!(
+            target(Rule::OrphanSection, "users-create").as_deref(),
+            Some("src/api.ts:7")
+        );
+        assert_eq!(target(Rule::OrphanSection, "billing-charge"), None);
+        assert!(results
+            .iter()
+            .filter(|r| r.rule == Rule::LinkVerified)
+            .all(|r| r.target.is_none()));
+    }
+}
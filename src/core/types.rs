@@ -4,7 +4,10 @@
 //! así como los resultados de validación.
 
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use crate::layout::{Layout, Style};
+use crate::messages::tr;
 
 /// Representa un argumento extraído, ya sea del código fuente o de la documentación.
 /// Estructura normalizada común para ambas fuentes (Blueprint §4.2).
@@ -13,11 +16,89 @@ pub struct Arg {
     pub name: String,
     pub type_name: Option<String>,
     pub description: Option<String>,
+    /// Estrategia de extracción que produjo el argumento.
+    #[serde(default)]
+    pub source: ArgSource,
+    /// Línea del archivo donde se documentó (solo argumentos de docs).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// Unidad canónica mencionada en la descripción (`ms`, `s`, `bytes`, `%`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// Rango numérico de la descripción (`100–30000`), tal cual se escribió.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<ValueRange>,
+}
+
+/// Rango `mínimo–máximo` escrito en una descripción (ver `core::constraints`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ValueRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl ValueRange {
+    /// `true` si el mínimo supera al máximo (`30000–100`).
+    pub fn is_inverted(&self) -> bool {
+        self.min > self.max
+    }
+}
+
+impl std::fmt::Display for ValueRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}–{}", self.min, self.max)
+    }
+}
+
+impl Arg {
+    /// Procedencia legible de un argumento documentado
+    /// (p. ej. "documentado vía tabla, línea 84").
+    pub fn provenance(&self) -> Option<String> {
+        if self.source == ArgSource::Code {
+            return None;
+        }
+        Some(match self.line {
+            Some(line) => format!("documentado vía {}, línea {}", self.source.label(), line),
+            None => format!("documentado vía {}", self.source.label()),
+        })
+    }
+}
+
+/// Estrategia que extrajo un argumento (Strategy Pattern del doc_parser).
+///
+/// Los identificadores en minúsculas se usan en `doc_arg_sources` de la config.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ArgSource {
+    /// Firma de la función en el código (AST).
+    #[default]
+    Code,
+    /// Ítem de lista: `- name (type): description`.
+    List,
+    /// Fila de tabla `| Param | Type | Description |`.
+    Table,
+    /// Definición en párrafo: `` `name` (type): description ``.
+    Definition,
+    /// Encabezado propio bajo la sección: `#### name` + párrafo + `Type: type`.
+    Heading,
+}
+
+impl ArgSource {
+    /// Nombre legible (en español) de la estrategia.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ArgSource::Code => "código",
+            ArgSource::List => "lista",
+            ArgSource::Table => "tabla",
+            ArgSource::Definition => "definición",
+            ArgSource::Heading => "encabezado",
+        }
+    }
 }
 
 /// Entidad de código extraída por tree-sitter.
 /// Representa una función/método con su anotación `@docs` vinculada.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CodeEntity {
     /// Nombre de la función o método.
     pub name: String,
@@ -25,14 +106,55 @@ pub struct CodeEntity {
     pub args: Vec<Arg>,
     /// Tipo de retorno, si existe.
     pub return_type: Option<String>,
+    /// Campos del tipo de retorno cuando son determinables
+    /// (objeto TS en línea, struct Rust definido en el mismo archivo).
+    pub return_fields: Option<Vec<Arg>>,
     /// ID de documentación vinculado (extraído de `/// @docs: [id]`).
     pub doc_id: Option<String>,
     /// Ruta del archivo fuente.
     pub file_path: PathBuf,
     /// Línea donde se declaró la función.
     pub line: usize,
+    /// Última línea de la función (el cierre del cuerpo); `--changed-only`
+    /// la usa para saber qué líneas son suyas.
+    #[serde(default)]
+    pub end_line: usize,
     /// Indica si la función es pública/exportada (relevante para coverage).
     pub is_public: bool,
+    /// Reglas suprimidas en línea con `// docsguard-ignore: regla` (`*` = todas).
+    pub suppressions: Vec<Suppression>,
+    /// Enlace a nivel de archivo (`@docs-file: id`) del archivo que contiene la función.
+    pub file_link: Option<FileLink>,
+    /// Bloques `@example` de su JSDoc (TypeScript); la línea de cada uno es
+    /// la del archivo, dentro del comentario.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<CodeExample>,
+}
+
+/// Regla silenciada por una directiva `docsguard-ignore`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Suppression {
+    /// Id de la regla (`*` = todas).
+    pub rule: String,
+    /// Línea del comentario con la directiva.
+    pub line: usize,
+}
+
+/// Anotación `@docs-file: id` en la cabecera de un archivo: enlaza el archivo
+/// completo con una sección y cubre todas sus funciones.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileLink {
+    /// ID de la sección de documentación.
+    pub doc_id: String,
+    /// Línea de la anotación.
+    pub line: usize,
+}
+
+impl CodeEntity {
+    /// Ubicación `archivo:línea` de la función, formato usado en los hallazgos.
+    pub fn location(&self) -> String {
+        format!("{}:{}", self.file_path.display(), self.line)
+    }
 }
 
 /// Sección de documentación extraída por pulldown-cmark.
@@ -43,12 +165,87 @@ pub struct DocSection {
     pub id: String,
     /// Título de la sección (heading más cercano).
     pub title: Option<String>,
+    /// Heading de nivel superior bajo el que está la sección, si existe.
+    pub parent: Option<String>,
     /// Argumentos documentados en la sección.
     pub args: Vec<Arg>,
     /// Ruta del archivo de documentación.
     pub file_path: PathBuf,
     /// Línea donde se encontró el marcador de ID.
     pub line: usize,
+    /// Ejemplos con resultado esperado (`` `f(x)` → `{ id }` ``) dentro de la sección.
+    pub expectations: Vec<Expectation>,
+    /// Bloques de código con fence de la sección.
+    pub examples: Vec<CodeExample>,
+    /// Código en línea con forma de identificador (`` `connPool` ``,
+    /// `` `retry()` `` sin los paréntesis) y su línea, para
+    /// `internal-symbol-reference`.
+    pub mentions: Vec<(String, usize)>,
+    /// Versión "disponible desde" tal cual aparece (`v2.3`), de una línea
+    /// `*Desde: v2.3*` o del marcador `<!-- @docs-since: v2.3 -->`.
+    pub since: Option<String>,
+    /// Función que la sección declara documentar (`<!-- @docs-id: x @expects: login -->`),
+    /// para validar el enlace desde las docs.
+    pub expected_function: Option<String>,
+    /// Comprobaciones desactivadas para la sección
+    /// (`<!-- @docs-id: x @docs-skip: args,return -->`), de `SKIP_TOKENS`.
+    pub skips: Vec<String>,
+    /// Argumentos descartados por superar `limits.max_section_args`; con
+    /// alguno, los argumentos no se validan uno a uno (ver `validate_args`).
+    pub dropped_args: usize,
+    /// El título no sigue al marcador: es el encabezado anterior más cercano
+    /// (ver `doc_parser::TitleOptions`), y `--strict` lo reporta (DG007).
+    pub title_inferred: bool,
+    /// Sección que ninguna función debe enlazar (`<!-- @docs-id: x @docs-no-link -->`),
+    /// como una plantilla para copiar: no es huérfana ni candidata de la
+    /// heurística, y un `@docs` que la enlaza es `forbidden-link`.
+    pub no_link: bool,
+}
+
+/// Valores admitidos por `@docs-skip`: `args` (argumentos) y `return`
+/// (ejemplos con resultado esperado).
+pub const SKIP_TOKENS: &[&str] = &["args", "return"];
+
+impl DocSection {
+    /// Sección conceptual: no documenta argumentos (`@docs-skip: args`).
+    /// Su enlace se valida y cuenta en la cobertura, pero como prose-only.
+    pub fn is_prose_only(&self) -> bool {
+        self.skips.iter().any(|s| s == "args")
+    }
+
+    /// Los ejemplos con resultado esperado no se comparan (`@docs-skip: return`).
+    pub fn skips_return(&self) -> bool {
+        self.skips.iter().any(|s| s == "return")
+    }
+}
+
+/// Llamada de ejemplo documentada junto a su resultado esperado.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expectation {
+    /// Nombre de la función invocada en el ejemplo.
+    pub function: String,
+    /// Lado derecho de la flecha, tal cual aparece en la documentación.
+    pub expected: String,
+    /// Línea del ejemplo en el archivo de documentación.
+    pub line: usize,
+}
+
+/// Bloque de código con fence dentro de una sección, o bloque `@example` del
+/// JSDoc de una función.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CodeExample {
+    /// Lenguaje del fence (`ts`, `rust`…), si lo hay.
+    pub lang: Option<String>,
+    pub code: String,
+    /// Línea de la primera línea de código.
+    pub line: usize,
+}
+
+impl DocSection {
+    /// Ubicación `archivo:línea` del marcador, formato usado en los hallazgos.
+    pub fn location(&self) -> String {
+        format!("{}:{}", self.file_path.display(), self.line)
+    }
 }
 
 /// Severidad de un hallazgo de validación.
@@ -69,12 +266,245 @@ impl std::fmt::Display for Severity {
     }
 }
 
+impl Severity {
+    /// Color de la severidad en el terminal.
+    pub fn style(self) -> Style {
+        match self {
+            Severity::Error => Style::Error,
+            Severity::Warning => Style::Warning,
+            Severity::Info => Style::Info,
+        }
+    }
+}
+
+/// Regla de validación que produjo un hallazgo.
+///
+/// El identificador kebab-case es estable: se usa en supresiones en línea
+/// (`// docsguard-ignore: ghost-arg`) y en las salidas estructuradas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Rule {
+    UnlinkedFunction,
+    LinkVerified,
+    MissingDocSection,
+    OrphanSection,
+    /// Función enlazada con una sección marcada `@docs-no-link`.
+    ForbiddenLink,
+    GhostArg,
+    MissingArg,
+    TypeMismatch,
+    ExampleMismatch,
+    /// Ejemplo de una sección que pasa a su función un literal de otro tipo
+    /// que el documentado en la propia sección para esa posición.
+    ExampleArgMismatch,
+    /// Comentario con aspecto de anotación que no cumple la sintaxis.
+    MalformedAnnotation,
+    /// La ejecución superó `--time-budget`.
+    SlowRun,
+    /// Entrada de `.docsguard/links.yaml` cuya función ya no existe.
+    StaleMapping,
+    /// Archivo de un directorio o glob de `check` sin lenguaje soportado.
+    SkippedFile,
+    /// Ejemplo que importa o llama a un símbolo inexistente (`check --check-symbols`).
+    UnknownExampleSymbol,
+    /// Supresión en línea, entrada del baseline o de `arg_exceptions` que no
+    /// silenció nada.
+    UnusedSuppression,
+    /// Entrada del baseline que filtró un hallazgo de su función tras
+    /// moverse de archivo (Info).
+    MovedBaselineEntry,
+    /// Argumento documentado con una descripción de relleno (`TODO`, `...`).
+    PlaceholderDescription,
+    /// Versión "desde" posterior a la del proyecto, inexistente o mal formada
+    /// (`check --check-versions`).
+    SinceVersion,
+    /// Argumento documentado que parece una errata de uno del código
+    /// (`check --check-arg-typos`).
+    ArgTypo,
+    /// Pareja de argumentos declarada equivalente en `arg_exceptions` (Info),
+    /// o excepción cuya sección no existe (Warning).
+    ArgException,
+    /// Enlace que cruza una frontera de módulo de `link_boundaries`.
+    LinkBoundary,
+    /// Argumento que solo casa con el de la firma tras normalizar el nombre
+    /// (`arg_names.report_differences`).
+    ArgNameStyle,
+    /// Unidad distinta en la descripción de docs y en la del código, o rango
+    /// con el mínimo mayor que el máximo (ver `core::constraints`).
+    ArgConstraint,
+    /// Sección con `@expects: función` cuya función no existe, no está
+    /// anotada o está anotada con otro id.
+    ExpectedFunction,
+    /// Argumento que dos secciones con el mismo ID documentan con tipos
+    /// distintos, u opcional en una y obligatorio en otra.
+    ConflictingArgDocs,
+    /// Enlace `#ancla` a un encabezado que no existe con el algoritmo de
+    /// anclas del sitio (`site.slugger`, ver `core::anchors`).
+    BrokenAnchor,
+    /// Sección cuya URL publicada no existe (`check --verify-site`).
+    UnpublishedSection,
+    /// Función pública añadida desde una referencia git sin enlace resoluble
+    /// a docs (`check --require-docs-for-new`).
+    UndocumentedNewFunction,
+    /// Sección enlazada cuyo contenido cambió desde su atestación en
+    /// `.docsguard/attestations.yaml` (`check --check-attestations`).
+    ChangedSinceAttestation,
+    /// Sección de `attestation_required` sin atestación (`check --check-attestations`).
+    MissingAttestation,
+    /// Funciones de lenguajes distintos enlazadas a la misma sección cuyas
+    /// firmas no coinciden (opt-in).
+    CrossLanguageDrift,
+    /// Sección enlazada que nombra símbolos internos del código de su
+    /// función (opt-in, siempre Info).
+    InternalSymbolReference,
+    /// Sección con varios argumentos fantasma que son todos de la firma de
+    /// otra función: probablemente copiada de la suya sin actualizar.
+    CopiedSection,
+    /// Mismo `@docs-id` en dos archivos de docs de la misma ejecución
+    /// (`check --doc a.md,b.md`).
+    DuplicateDocId,
+    /// Archivo con un conflicto de git sin resolver (`<<<<<<<`, `=======`,
+    /// `>>>>>>>`); se reporta siempre, como Warning (ver `core::conflicts`).
+    VcsConflict,
+    /// Ambigüedades del parser, solo con `--strict` (ver `core::diagnostics`).
+    #[serde(rename = "DG001")]
+    ConflictingDocsIds,
+    #[serde(rename = "DG002")]
+    DetachedAnnotation,
+    #[serde(rename = "DG003")]
+    SkippedArgTable,
+    #[serde(rename = "DG004")]
+    UnnormalizedType,
+    #[serde(rename = "DG005")]
+    SyntaxErrorInEntity,
+    /// Sección o archivo de docs recortado por los límites de `limits:`; se
+    /// reporta siempre, como Warning.
+    #[serde(rename = "DG006")]
+    TruncatedDocs,
+    #[serde(rename = "DG007")]
+    InferredTitle,
+}
+
+impl Rule {
+    /// Todas las reglas, en el orden en que se listan (`docsguard explain`).
+    pub const ALL: [Rule; 43] = [
+        Rule::UnlinkedFunction,
+        Rule::LinkVerified,
+        Rule::MissingDocSection,
+        Rule::OrphanSection,
+        Rule::ForbiddenLink,
+        Rule::GhostArg,
+        Rule::MissingArg,
+        Rule::TypeMismatch,
+        Rule::ExampleMismatch,
+        Rule::ExampleArgMismatch,
+        Rule::MalformedAnnotation,
+        Rule::SlowRun,
+        Rule::StaleMapping,
+        Rule::SkippedFile,
+        Rule::UnknownExampleSymbol,
+        Rule::UnusedSuppression,
+        Rule::MovedBaselineEntry,
+        Rule::PlaceholderDescription,
+        Rule::SinceVersion,
+        Rule::ArgTypo,
+        Rule::ArgException,
+        Rule::LinkBoundary,
+        Rule::ArgNameStyle,
+        Rule::ArgConstraint,
+        Rule::ExpectedFunction,
+        Rule::ConflictingArgDocs,
+        Rule::BrokenAnchor,
+        Rule::UnpublishedSection,
+        Rule::UndocumentedNewFunction,
+        Rule::ChangedSinceAttestation,
+        Rule::MissingAttestation,
+        Rule::CrossLanguageDrift,
+        Rule::InternalSymbolReference,
+        Rule::CopiedSection,
+        Rule::DuplicateDocId,
+        Rule::VcsConflict,
+        Rule::ConflictingDocsIds,
+        Rule::DetachedAnnotation,
+        Rule::SkippedArgTable,
+        Rule::UnnormalizedType,
+        Rule::SyntaxErrorInEntity,
+        Rule::TruncatedDocs,
+        Rule::InferredTitle,
+    ];
+
+    /// Identificador estable kebab-case de la regla.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Rule::UnlinkedFunction => "unlinked-function",
+            Rule::LinkVerified => "link-verified",
+            Rule::MissingDocSection => "missing-doc-section",
+            Rule::OrphanSection => "orphan-section",
+            Rule::ForbiddenLink => "forbidden-link",
+            Rule::GhostArg => "ghost-arg",
+            Rule::MissingArg => "missing-arg",
+            Rule::TypeMismatch => "type-mismatch",
+            Rule::ExampleMismatch => "example-mismatch",
+            Rule::ExampleArgMismatch => "example-arg-mismatch",
+            Rule::MalformedAnnotation => "malformed-annotation",
+            Rule::SlowRun => "slow-run",
+            Rule::StaleMapping => "stale-mapping",
+            Rule::SkippedFile => "skipped-file",
+            Rule::UnknownExampleSymbol => "unknown-example-symbol",
+            Rule::UnusedSuppression => "unused-suppression",
+            Rule::MovedBaselineEntry => "moved-baseline-entry",
+            Rule::PlaceholderDescription => "placeholder-description",
+            Rule::SinceVersion => "since-version",
+            Rule::ArgTypo => "arg-typo",
+            Rule::ArgException => "arg-exception",
+            Rule::LinkBoundary => "link-boundary",
+            Rule::ArgNameStyle => "arg-name-style",
+            Rule::ArgConstraint => "arg-constraint",
+            Rule::ExpectedFunction => "expected-function",
+            Rule::ConflictingArgDocs => "conflicting-arg-docs",
+            Rule::BrokenAnchor => "broken-anchor",
+            Rule::UnpublishedSection => "unpublished-section",
+            Rule::UndocumentedNewFunction => "undocumented-new-function",
+            Rule::ChangedSinceAttestation => "changed-since-attestation",
+            Rule::MissingAttestation => "missing-attestation",
+            Rule::CrossLanguageDrift => "cross-language-drift",
+            Rule::InternalSymbolReference => "internal-symbol-reference",
+            Rule::CopiedSection => "copied-section",
+            Rule::DuplicateDocId => "duplicate-doc-id",
+            Rule::VcsConflict => "vcs-conflict",
+            Rule::ConflictingDocsIds => "DG001",
+            Rule::DetachedAnnotation => "DG002",
+            Rule::SkippedArgTable => "DG003",
+            Rule::UnnormalizedType => "DG004",
+            Rule::SyntaxErrorInEntity => "DG005",
+            Rule::TruncatedDocs => "DG006",
+            Rule::InferredTitle => "DG007",
+        }
+    }
+}
+
+impl std::fmt::Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+/// Separa una ubicación `archivo:línea` en sus componentes.
+pub fn parse_location(location: &str) -> Option<(&Path, usize)> {
+    let (file, line) = location.rsplit_once(':')?;
+    let line = line.parse().ok()?;
+    Some((Path::new(file), line))
+}
+
 /// Resultado de validación individual.
 /// Sigue el principio "El Error es el Producto" (Blueprint §7):
-/// cada resultado incluye contexto accionable.
-#[derive(Debug, Clone)]
+/// cada resultado incluye contexto accionable. El formato alternativo
+/// (`{:#}`) añade detalles de depuración como la procedencia del argumento.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationResult {
     pub severity: Severity,
+    /// Regla que produjo el hallazgo.
+    pub rule: Rule,
     /// Mensaje principal del hallazgo.
     pub message: String,
     /// Nombre de la función afectada.
@@ -83,39 +513,254 @@ pub struct ValidationResult {
     pub code_location: Option<String>,
     /// ID de documentación vinculado.
     pub doc_id: Option<String>,
+    /// Ubicación de la sección de documentación (`archivo:línea`), si existe.
+    pub doc_location: Option<String>,
     /// Consejo accionable para el desarrollador.
     pub hint: Option<String>,
+    /// Procedencia del argumento documentado implicado, si aplica.
+    /// Solo se muestra en el renderizado detallado (`{:#}`).
+    pub provenance: Option<String>,
+    /// Detalles en otras ubicaciones (p. ej. lo que dice cada sección de un
+    /// argumento documentado de forma contradictoria).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<Related>,
+    /// Dónde escribir para resolver el hallazgo (`archivo:línea`): la sección
+    /// que falta, la anotación de una función o la de la mejor candidata de
+    /// una sección huérfana (ver `core::targets`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    /// Hallazgos Info que representa este, si es un resumen de
+    /// `info_aggregation: summarize` (ver `report::aggregate`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<FindingSummary>,
+    /// Commit que probablemente rompió un enlace que pasaba (ver
+    /// `report::blame`). Fuera del baseline y, sin `--with-blame`, de SARIF.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cause: Option<Cause>,
+    /// Periodo de gracia que rebajó la severidad, si el código es de un
+    /// archivo nuevo (ver `grace`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grace: Option<Grace>,
 }
 
-impl std::fmt::Display for ValidationResult {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// Hallazgos Info de una regla en un archivo agrupados en uno.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FindingSummary {
+    /// Hallazgos agrupados.
+    pub count: usize,
+    /// Funciones afectadas, en orden de aparición y sin repetir.
+    pub functions: Vec<String>,
+}
+
+/// Detalle de un hallazgo en otra ubicación.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Related {
+    /// Ubicación `archivo:línea`.
+    pub location: String,
+    pub message: String,
+}
+
+/// Último commit que tocó la firma de la función de un hallazgo.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cause {
+    pub commit: String,
+    /// `@usuario` del correo del autor, o su nombre si no tiene.
+    pub author: String,
+    /// Fecha del autor, en segundos desde 1970-01-01.
+    pub timestamp: i64,
+    pub subject: String,
+    /// Commit posterior que tocó el archivo de docs, si lo hay.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docs_commit: Option<String>,
+}
+
+impl Cause {
+    /// `3f2a1c4 'refactor auth params' por @diego hace 2 días; …`, con la
+    /// antigüedad contada hasta `now`.
+    pub fn describe(&self, now: i64) -> String {
+        let commit = short_hash(&self.commit);
+        let ago = crate::dates::ago(now - self.timestamp);
+        match &self.docs_commit {
+            Some(docs) => format!(
+                "Probable causa: {} '{}' por {} {}; las docs cambiaron después, en {}",
+                commit,
+                self.subject,
+                self.author,
+                ago,
+                short_hash(docs)
+            ),
+            None => format!(
+                "Probable causa: {} '{}' por {} {}; las docs no cambiaron desde entonces",
+                commit, self.subject, self.author, ago
+            ),
+        }
+    }
+}
+
+/// De dónde sale la fecha de creación de un archivo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CreationSource {
+    /// Primer commit que añadió el archivo (o hoy, si aún no se commiteó).
+    Git,
+    /// Fecha de creación del sistema de archivos, fuera de git: una copia o
+    /// un checkout la reinician.
+    Filesystem,
+}
+
+/// Severidad rebajada por `grace_period`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Grace {
+    /// Último día del periodo (`AAAA-MM-DD`).
+    pub until: String,
+    pub source: CreationSource,
+    /// Severidad que tendría sin el periodo de gracia.
+    pub original: Severity,
+}
+
+impl Grace {
+    /// `archivo nuevo — periodo de gracia hasta 2026-10-28`, avisando si la
+    /// fecha no sale de git.
+    pub fn describe(&self) -> String {
+        match self.source {
+            CreationSource::Git => {
+                format!("Archivo nuevo — periodo de gracia hasta {}", self.until)
+            }
+            CreationSource::Filesystem => format!(
+                "Archivo nuevo — periodo de gracia hasta {} (fecha del sistema de archivos, menos fiable que git)",
+                self.until
+            ),
+        }
+    }
+}
+
+/// Los 7 primeros caracteres de un hash de git.
+fn short_hash(hash: &str) -> &str {
+    &hash[..hash.len().min(7)]
+}
+
+impl ValidationResult {
+    /// Hallazgos que cuenta este: los de su resumen, o 1.
+    pub fn occurrences(&self) -> usize {
+        self.summary.as_ref().map_or(1, |s| s.count)
+    }
+
+    /// Copia con el mensaje, la pista y los detalles en el idioma de la
+    /// salida, para las salidas estructuradas. El original sigue en el texto
+    /// canónico, que es el que guarda el baseline.
+    pub fn localized(&self) -> ValidationResult {
+        let mut result = self.clone();
+        result.message = tr(&self.message).into_owned();
+        result.hint = self.hint.as_deref().map(|h| tr(h).into_owned());
+        result.provenance = self.provenance.as_deref().map(|p| tr(p).into_owned());
+        for related in &mut result.related {
+            related.message = tr(&related.message).into_owned();
+        }
+        result
+    }
+
+    /// Texto de terminal con la maquetación dada; `verbose` añade detalles de
+    /// depuración como la procedencia del argumento.
+    pub fn render(&self, layout: &Layout, verbose: bool) -> String {
         let icon = match self.severity {
             Severity::Error => "[X]",
             Severity::Warning => "[!]",
             Severity::Info => "[i]",
         };
 
-        write!(f, "{} {}", icon, self.severity)?;
-
+        let mut header = format!("{} {} ({})", icon, self.severity, self.rule);
         if let Some(ref func) = self.function_name {
             if let Some(ref loc) = self.code_location {
-                write!(f, " en fn {} ({})", func, loc)?;
+                header.push_str(&format!(" en fn {} ({})", func, layout.location(loc)));
             } else {
-                write!(f, " en fn {}", func)?;
+                header.push_str(&format!(" en fn {}", func));
             }
+        } else if let (None, Some(ref loc)) = (&self.doc_id, &self.doc_location) {
+            // Sin función ni ID (p. ej. un marcador mal formado): la ubicación de docs
+            header.push_str(&format!(" en {}", layout.location(loc)));
         }
+        // Se traduce antes de partir las líneas: `tr` compara líneas enteras
+        let label = format!("{} {}", icon, self.severity);
+        let header = tr(&header).replacen(&label, &layout.paint(self.severity.style(), &label), 1);
+        let mut out = format!("{}\n", header);
 
-        writeln!(f)?;
-        writeln!(f, "    -> {}", self.message)?;
+        out.push_str(&layout.wrap("    -> ", &tr(&self.message)));
+        for related in &self.related {
+            let prefix = "       · ";
+            let wrapped = layout.wrap(
+                prefix,
+                &format!("{}: {}", related.location, tr(&related.message)),
+            );
+            out.push_str(&wrapped.replacen(
+                &format!("{}{}", prefix, related.location),
+                &format!("{}{}", prefix, layout.location(&related.location)),
+                1,
+            ));
+        }
 
         if let Some(ref doc_id) = self.doc_id {
-            writeln!(f, "    -> ID vinculado: '{}'", doc_id)?;
+            match self.doc_location {
+                Some(ref doc_loc) => out.push_str(&tr(&format!(
+                    "    -> ID vinculado: '{}' ({})\n",
+                    doc_id,
+                    layout.location(doc_loc)
+                ))),
+                None => out.push_str(&tr(&format!("    -> ID vinculado: '{}'\n", doc_id))),
+            }
+        }
+
+        if let Some(ref target) = self.target {
+            out.push_str(&tr(&format!(
+                "    -> Insertar en: {}\n",
+                layout.location(target)
+            )));
+        }
+
+        if verbose {
+            if let Some(ref provenance) = self.provenance {
+                out.push_str(&layout.wrap("    -> ", &tr(&format!("Origen: {}", provenance))));
+            }
+        }
+
+        if let Some(ref grace) = self.grace {
+            out.push_str(&layout.wrap("    -> ", &tr(&grace.describe())));
+            if verbose {
+                out.push_str(&tr(&format!(
+                    "    -> Severidad sin periodo de gracia: {}\n",
+                    grace.original
+                )));
+            }
+        }
+
+        if let Some(ref cause) = self.cause {
+            let cause = cause.describe(crate::dates::now());
+            out.push_str(&layout.wrap("    -> ", &tr(&cause)));
         }
 
         if let Some(ref hint) = self.hint {
-            writeln!(f, "    -> Sugerencia: {}", hint)?;
+            let wrapped = layout.wrap("    -> ", &tr(&format!("Sugerencia: {}", hint)));
+            // La etiqueta (`Sugerencia:`, `Suggestion:`) con su propio estilo
+            match wrapped["    -> ".len()..].split_once(':') {
+                Some((label, rest)) => {
+                    let label = format!("{}:", label);
+                    out.push_str(&format!(
+                        "    -> {}{}",
+                        layout.paint(Style::Hint, &label),
+                        rest
+                    ));
+                }
+                None => out.push_str(&wrapped),
+            }
         }
 
-        Ok(())
+        out
+    }
+}
+
+impl std::fmt::Display for ValidationResult {
+    /// `render` con la maquetación por defecto (`layout::DEFAULT_WIDTH`
+    /// columnas, sin hipervínculos).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(&Layout::default(), f.alternate()))
     }
 }
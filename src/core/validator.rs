@@ -5,165 +5,1860 @@
 //! 2. Argumentos fantasma — ¿hay args en docs que no existen en código?
 //! 3. Argumentos faltantes — ¿hay args en código que no están documentados?
 //! 4. Type mismatch — ¿el tipo documentado coincide con el del código?
+//! 5. Enlaces de archivo — ¿la sección de un `@docs-file` existe?
+//! 6. Unidades y rangos — ¿docs y comentario del código dicen la misma unidad?
+//!    ¿los rangos `mínimo–máximo` están bien escritos?
+//! 7. Enlaces declarados desde las docs — ¿la función de `@expects` existe y
+//!    está anotada con el id de la sección?
+//! 8. Varias secciones con el mismo ID (referencia y guía) — cada una se
+//!    valida por separado, y un argumento que documentan con tipos distintos,
+//!    o opcional en una y obligatorio en otra, es un hallazgo de la función.
+//! 9. Fronteras de módulo — ¿el enlace se queda dentro de la pareja de
+//!    `link_boundaries` de su archivo de código? (`validate_link_boundaries`)
+//! 10. Mismo API en varios lenguajes — ¿las funciones de lenguajes distintos
+//!     enlazadas a la misma sección tienen la misma firma? (opt-in,
+//!     `validate_cross_language`)
+//! 11. Secciones copiadas — ¿los argumentos fantasma de una sección son los
+//!     de la firma de otra función? (`merge_copied_sections`)
+//! 12. Varios archivos de docs — ¿algún ID está en más de uno?
+//!     (`validate_duplicate_ids`)
+//!
+//! Las secciones con `@docs-skip: args` (prose-only) validan el enlace pero no
+//! los argumentos, y las de `@docs-skip: return`, no los ejemplos. Las de
+//! `@docs-no-link` (plantillas) no son huérfanas, y enlazarlas es un Error.
+//!
+//! Los ejemplos con resultado esperado se validan aparte (opt-in) en `core::examples`,
+//! las anotaciones mal formadas en `validate_parse_notes` y las ambigüedades
+//! del parser en `validate_strict` (`--strict`). Las descripciones de relleno
+//! (`validate_placeholders`) solo se comprueban si la regla está activa en la
+//! configuración (preset `strict` o `rules:`), y las versiones "desde" de las
+//! secciones en `validate_since_versions` (`--check-versions`).
+//!
+//! Los pases de enlace consultan un `LinkLookup`: `validate_links` recorre las
+//! listas (check de una pasada) y `validate_links_indexed` usa el índice
+//! incremental del modo watch (`core::link_index`), con el mismo resultado.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::Path;
+
+use crate::baseline::DOCSGUARD_DIR;
+use crate::config::{ArgException, ArgNamesConfig, LinkBoundary, CONFIG_FILE};
+use crate::core::diagnostics::{ParseDiagnostics, ParseNote, Strictness};
+use crate::core::heuristic::canonical_name;
+#[cfg(feature = "watch")]
+use crate::core::link_index::LinkIndex;
+use crate::core::suppression;
+use crate::core::types::{Arg, CodeEntity, DocSection, Related, Rule, Severity, ValidationResult};
+use crate::core::version_source::{self, ProjectVersions};
+use crate::messages;
+use crate::parser::code_parser::Language;
+use crate::paths::glob_matches;
+
+/// Consultas de los pases de enlace (sin enlace, secciones por ID, huérfanas,
+/// `@expects`) sobre entidades y secciones, siempre en su orden.
+///
+/// `validate_links` las responde recorriendo las listas; el índice
+/// incremental del modo watch (`core::link_index`), con mapas que solo se
+/// actualizan para los archivos que cambian.
+pub trait LinkLookup {
+    fn entities(&self) -> impl Iterator<Item = &CodeEntity>;
+    fn sections(&self) -> impl Iterator<Item = &DocSection>;
+    fn section_count(&self) -> usize;
+    /// Secciones con `id`, en orden.
+    fn sections_with_id(&self, id: &str) -> Vec<&DocSection>;
+    /// Primera sección con ese título.
+    fn section_titled(&self, title: &str) -> Option<&DocSection>;
+    /// Indica si alguna entidad enlaza `id` con `@docs`.
+    fn is_linked(&self, id: &str) -> bool;
+    /// Entidades llamadas `name`, en orden.
+    fn entities_named(&self, name: &str) -> Vec<&CodeEntity>;
+    /// IDs enlazados con `@docs-file`.
+    fn file_link_ids(&self) -> HashSet<&str>;
+}
+
+/// Consultas por recorrido completo, para una ejecución única.
+struct FullScan<'a> {
+    code_entities: &'a [CodeEntity],
+    doc_sections: &'a [DocSection],
+}
+
+impl LinkLookup for FullScan<'_> {
+    fn entities(&self) -> impl Iterator<Item = &CodeEntity> {
+        self.code_entities.iter()
+    }
+
+    fn sections(&self) -> impl Iterator<Item = &DocSection> {
+        self.doc_sections.iter()
+    }
+
+    fn section_count(&self) -> usize {
+        self.doc_sections.len()
+    }
 
-use crate::core::types::{Arg, CodeEntity, DocSection, Severity, ValidationResult};
+    fn sections_with_id(&self, id: &str) -> Vec<&DocSection> {
+        self.doc_sections.iter().filter(|s| s.id == id).collect()
+    }
+
+    fn section_titled(&self, title: &str) -> Option<&DocSection> {
+        self.doc_sections
+            .iter()
+            .find(|s| s.title.as_deref() == Some(title))
+    }
+
+    fn is_linked(&self, id: &str) -> bool {
+        self.code_entities
+            .iter()
+            .any(|e| e.doc_id.as_deref() == Some(id))
+    }
+
+    fn entities_named(&self, name: &str) -> Vec<&CodeEntity> {
+        self.code_entities
+            .iter()
+            .filter(|e| e.name == name)
+            .collect()
+    }
+
+    fn file_link_ids(&self) -> HashSet<&str> {
+        self.code_entities
+            .iter()
+            .filter_map(|e| e.file_link.as_ref().map(|l| l.doc_id.as_str()))
+            .collect()
+    }
+}
 
 /// @docs: [validate-links]
 /// Valida que cada `CodeEntity` con un `doc_id` tenga una sección correspondiente
 /// en la documentación, y compara argumentos y tipos cuando el enlace existe.
+/// Los nombres de argumento se comparan según `arg_names` (`exact` por defecto).
 pub fn validate_links(
     code_entities: &[CodeEntity],
     doc_sections: &[DocSection],
+    arg_names: &ArgNamesConfig,
 ) -> Vec<ValidationResult> {
+    let mut results = link_passes(
+        &FullScan {
+            code_entities,
+            doc_sections,
+        },
+        arg_names,
+    );
+    suppression::apply_inline_suppressions(code_entities, &mut results);
+    results
+}
+
+/// Como `validate_links`, con las consultas resueltas en el índice del modo
+/// watch y sin aplicar las supresiones en línea, que el pipeline aplica una
+/// sola vez al final (`pipeline::run_validation`): tras aplicarlas, el
+/// resultado es idéntico al de `validate_links` sobre sus entidades y
+/// secciones.
+#[cfg(feature = "watch")]
+pub fn validate_links_indexed(
+    index: &LinkIndex,
+    arg_names: &ArgNamesConfig,
+) -> Vec<ValidationResult> {
+    link_passes(index, arg_names)
+}
+
+fn link_passes(links: &impl LinkLookup, arg_names: &ArgNamesConfig) -> Vec<ValidationResult> {
     let mut results = Vec::new();
 
-    // Entidades de código sin anotación @docs
-    for entity in code_entities.iter().filter(|e| e.doc_id.is_none()) {
+    // Entidades de código sin anotación @docs (las cubiertas por `@docs-file` no generan ruido)
+    for entity in links
+        .entities()
+        .filter(|e| e.doc_id.is_none() && e.file_link.is_none())
+    {
         results.push(ValidationResult {
             severity: Severity::Info,
+            rule: Rule::UnlinkedFunction,
             message: "Función sin anotación @docs — no está vinculada a documentación.".into(),
             function_name: Some(entity.name.clone()),
-            code_location: Some(format!("{}:{}", entity.file_path.display(), entity.line)),
+            code_location: Some(entity.location()),
             doc_id: None,
+            doc_location: None,
             hint: Some("Añade `/// @docs: [id]` antes de la función para vincularla.".into()),
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
         });
     }
 
     // Entidades de código con anotación @docs: validar enlace + argumentos
-    for entity in code_entities.iter().filter(|e| e.doc_id.is_some()) {
+    for entity in links.entities().filter(|e| e.doc_id.is_some()) {
         let doc_id = match entity.doc_id.as_ref() {
             Some(id) => id,
             None => continue, // Defensivo: no debería ocurrir tras el filtro
         };
-        let location = format!("{}:{}", entity.file_path.display(), entity.line);
+        let location = entity.location();
 
-        let matching_section = doc_sections.iter().find(|s| &s.id == doc_id);
-
-        match matching_section {
-            Some(section) => {
-                results.push(ValidationResult {
-                    severity: Severity::Info,
-                    message: format!(
-                        "Enlace verificado: fn {} <-> sección '{}'",
-                        entity.name,
-                        section.title.as_deref().unwrap_or(&section.id)
-                    ),
-                    function_name: Some(entity.name.clone()),
-                    code_location: Some(location.clone()),
-                    doc_id: Some(doc_id.clone()),
-                    hint: None,
-                });
+        let matching_sections = links.sections_with_id(doc_id);
+        if matching_sections.is_empty() {
+            results.push(ValidationResult {
+                severity: Severity::Error,
+                rule: Rule::MissingDocSection,
+                message: format!(
+                    "ID de documentación '{}' no encontrado en el archivo de docs.",
+                    doc_id
+                ),
+                function_name: Some(entity.name.clone()),
+                code_location: Some(location),
+                doc_id: Some(doc_id.clone()),
+                doc_location: None,
+                hint: Some(format!(
+                    "Añade `<!-- @docs-id: {} -->` en el archivo de documentación.",
+                    doc_id
+                )),
+                provenance: None,
+                related: Vec::new(),
+                target: None,
+                summary: None,
+                cause: None,
+                grace: None,
+            });
+            continue;
+        }
 
-                // Validar argumentos si la sección tiene args documentados
-                if !section.args.is_empty() || !entity.args.is_empty() {
-                    validate_args(entity, section, &location, &mut results);
-                }
+        for section in &matching_sections {
+            if section.no_link {
+                results.push(forbidden_link(entity, section, &location));
+                continue;
             }
-            None => {
-                results.push(ValidationResult {
-                    severity: Severity::Error,
-                    message: format!(
-                        "ID de documentación '{}' no encontrado en el archivo de docs.",
-                        doc_id
-                    ),
-                    function_name: Some(entity.name.clone()),
-                    code_location: Some(location),
-                    doc_id: Some(doc_id.clone()),
-                    hint: Some(format!(
-                        "Añade `<!-- @docs-id: {} -->` en el archivo de documentación.",
-                        doc_id
-                    )),
-                });
+            results.push(ValidationResult {
+                severity: Severity::Info,
+                rule: Rule::LinkVerified,
+                message: format!(
+                    "Enlace verificado: fn {} <-> sección '{}'",
+                    entity.name,
+                    section.title.as_deref().unwrap_or(&section.id)
+                ),
+                function_name: Some(entity.name.clone()),
+                code_location: Some(location.clone()),
+                doc_id: Some(doc_id.clone()),
+                doc_location: Some(section.location()),
+                hint: None,
+                provenance: None,
+                related: Vec::new(),
+                target: None,
+                summary: None,
+                cause: None,
+                grace: None,
+            });
+
+            // Validar argumentos si la sección tiene args documentados (y no los omite)
+            if (!section.args.is_empty() || !entity.args.is_empty()) && !section.is_prose_only() {
+                validate_args(entity, section, &location, arg_names, &mut results);
             }
         }
+        let documenting_args: Vec<&DocSection> = matching_sections
+            .into_iter()
+            .filter(|s| !s.is_prose_only() && !s.no_link)
+            .collect();
+        if documenting_args.len() > 1 {
+            check_conflicting_arg_docs(entity, &documenting_args, &location, &mut results);
+        }
     }
 
-    // Secciones de docs sin enlace desde el código
-    for section in doc_sections {
-        let has_link = code_entities
-            .iter()
-            .any(|e| e.doc_id.as_ref() == Some(&section.id));
+    validate_file_links(links, &mut results);
+
+    // Secciones de docs sin enlace desde el código (por función o por archivo).
+    // Un `@docs-file` cubre también las subsecciones de su sección, y las
+    // `@docs-no-link` no deben tener enlace.
+    let file_link_ids = links.file_link_ids();
+    // Con varios archivos de docs, el mensaje dice en cuál está la sección
+    let several_files = links
+        .sections()
+        .map(|s| &s.file_path)
+        .collect::<HashSet<_>>()
+        .len()
+        > 1;
+    for section in links.sections().filter(|s| !s.no_link) {
+        // `@expects` da un hallazgo preciso en lugar del genérico de sección huérfana
+        if let Some(result) = check_expected_function(section, links) {
+            results.push(result);
+            continue;
+        }
+
+        let has_link =
+            links.is_linked(&section.id) || covered_by_file_link(section, links, &file_link_ids);
 
         if !has_link {
             results.push(ValidationResult {
                 severity: Severity::Warning,
-                message: format!(
-                    "Sección de documentación '{}' no está vinculada desde ninguna función.",
-                    section.title.as_deref().unwrap_or(&section.id)
-                ),
+                rule: Rule::OrphanSection,
+                message: if several_files {
+                    format!(
+                        "Sección de documentación '{}' de {} no está vinculada desde ninguna función.",
+                        section.title.as_deref().unwrap_or(&section.id),
+                        section.file_path.display()
+                    )
+                } else {
+                    format!(
+                        "Sección de documentación '{}' no está vinculada desde ninguna función.",
+                        section.title.as_deref().unwrap_or(&section.id)
+                    )
+                },
                 function_name: None,
                 code_location: None,
                 doc_id: Some(section.id.clone()),
+                doc_location: Some(section.location()),
                 hint: Some(format!(
                     "Añade `/// @docs: [{}]` antes de la función correspondiente en el código.",
                     section.id
                 )),
+                provenance: None,
+                related: Vec::new(),
+                target: None,
+                summary: None,
+                cause: None,
+                grace: None,
+            });
+        }
+    }
+
+    results
+}
+
+/// Error de una función anotada con el id de una sección `@docs-no-link`.
+fn forbidden_link(entity: &CodeEntity, section: &DocSection, location: &str) -> ValidationResult {
+    ValidationResult {
+        severity: Severity::Error,
+        rule: Rule::ForbiddenLink,
+        message: format!(
+            "La sección '{}' está marcada como no-vinculable (@docs-no-link), pero fn {} la enlaza.",
+            section.id, entity.name
+        ),
+        function_name: Some(entity.name.clone()),
+        code_location: Some(location.to_string()),
+        doc_id: Some(section.id.clone()),
+        doc_location: Some(section.location()),
+        hint: Some(
+            "Enlaza la función con su propia sección; la marcada es una plantilla, no documentación."
+                .into(),
+        ),
+        provenance: None,
+        related: Vec::new(),
+        target: None,
+        summary: None,
+        cause: None,
+        grace: None,
+    }
+}
+
+/// Contrasta el `@expects: nombre` de una sección con el código:
+/// - la función existe sin anotación → Warning con la anotación lista para pegar;
+/// - existe pero anotada con otro id → Warning con el conflicto;
+/// - no existe en el código analizado → Error con el nombre más parecido.
+///
+/// `None` si la sección no declara función o si esta ya la enlaza.
+fn check_expected_function(
+    section: &DocSection,
+    links: &impl LinkLookup,
+) -> Option<ValidationResult> {
+    let expected = section.expected_function.as_deref()?;
+    let named = links.entities_named(expected);
+    let links_section = |e: &&CodeEntity| {
+        e.doc_id.as_ref() == Some(&section.id)
+            || e.file_link.as_ref().is_some_and(|l| l.doc_id == section.id)
+    };
+    if named.iter().any(links_section) {
+        return None;
+    }
+
+    let title = section.title.as_deref().unwrap_or(&section.id);
+    let finding = |severity, message, entity: Option<&CodeEntity>, hint| ValidationResult {
+        severity,
+        rule: Rule::ExpectedFunction,
+        message,
+        function_name: entity.map(|e| e.name.clone()),
+        code_location: entity.map(CodeEntity::location),
+        doc_id: Some(section.id.clone()),
+        doc_location: Some(section.location()),
+        hint: Some(hint),
+        provenance: None,
+        related: Vec::new(),
+        target: None,
+        summary: None,
+        cause: None,
+        grace: None,
+    };
+
+    if let Some(entity) = named.iter().find(|e| e.doc_id.is_none()) {
+        return Some(finding(
+            Severity::Warning,
+            format!(
+                "La sección '{}' declara `@expects: {}`, pero fn {} no tiene anotación @docs.",
+                title, expected, expected
+            ),
+            Some(entity),
+            format!(
+                "Añade `{}` antes de la función (o acéptalo en `docsguard scaffold`).",
+                annotation_for(entity, &section.id)
+            ),
+        ));
+    }
+    if let Some(entity) = named.first() {
+        return Some(finding(
+            Severity::Warning,
+            format!(
+                "La sección '{}' declara `@expects: {}`, pero fn {} está anotada con '{}'.",
+                title,
+                expected,
+                expected,
+                entity.doc_id.as_deref().unwrap_or_default()
+            ),
+            Some(entity),
+            format!(
+                "Cambia la anotación a `@docs: [{}]` o corrige `@expects` en la sección.",
+                section.id
+            ),
+        ));
+    }
+
+    let closest = links
+        .entities()
+        .map(|e| (strsim::normalized_levenshtein(expected, &e.name), e))
+        .fold(
+            None,
+            |best: Option<(f64, &CodeEntity)>, (score, e)| match best {
+                Some((best_score, _)) if best_score >= score => best,
+                _ => Some((score, e)),
+            },
+        );
+    let (message, hint) = match closest {
+        Some((_, entity)) => (
+            format!(
+                "La sección '{}' declara `@expects: {}`, pero esa función no existe en el código analizado; la más parecida es '{}'.",
+                title, expected, entity.name
+            ),
+            format!(
+                "Corrige `@expects` (¿`@expects: {}`, en {}?) o el nombre de la función.",
+                entity.name,
+                entity.location()
+            ),
+        ),
+        None => (
+            format!(
+                "La sección '{}' declara `@expects: {}`, pero esa función no existe en el código analizado.",
+                title, expected
+            ),
+            "Corrige `@expects` o incluye el archivo de la función en el análisis.".to_string(),
+        ),
+    };
+    Some(finding(Severity::Error, message, None, hint))
+}
+
+/// Anotación `@docs` lista para pegar, en la sintaxis de comentario del lenguaje.
+fn annotation_for(entity: &CodeEntity, doc_id: &str) -> String {
+    let prefix = match Language::from_extension(&entity.file_path) {
+        Ok(Language::Python) => "#",
+        _ => "///",
+    };
+    format!("{} @docs: [{}]", prefix, doc_id)
+}
+
+/// Indica si la sección, o alguno de sus headings ancestros, está enlazada por `@docs-file`.
+/// Indica si `section` tiene enlace desde el código tal como lo decide
+/// `validate_links`: un `@docs` con su ID o un `@docs-file` de ella o de
+/// una sección que la contiene.
+pub fn section_has_link(
+    section: &DocSection,
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+) -> bool {
+    let links = FullScan {
+        code_entities,
+        doc_sections,
+    };
+    links.is_linked(&section.id) || covered_by_file_link(section, &links, &links.file_link_ids())
+}
+
+fn covered_by_file_link(
+    section: &DocSection,
+    links: &impl LinkLookup,
+    file_link_ids: &HashSet<&str>,
+) -> bool {
+    if file_link_ids.is_empty() {
+        return false;
+    }
+    let mut current = section;
+    // Acotado por el número de secciones: títulos repetidos no pueden ciclar
+    for _ in 0..=links.section_count() {
+        if file_link_ids.contains(current.id.as_str()) {
+            return true;
+        }
+        let Some(parent) = current
+            .parent
+            .as_deref()
+            .and_then(|title| links.section_titled(title))
+        else {
+            return false;
+        };
+        current = parent;
+    }
+    false
+}
+
+/// Valida los enlaces `@docs-file`: uno por archivo, contra la sección indicada.
+fn validate_file_links(links: &impl LinkLookup, results: &mut Vec<ValidationResult>) {
+    let mut seen_files = HashSet::new();
+    for entity in links.entities() {
+        let Some(link) = entity.file_link.as_ref() else {
+            continue;
+        };
+        if !seen_files.insert(&entity.file_path) {
+            continue;
+        }
+        let location = format!("{}:{}", entity.file_path.display(), link.line);
+
+        match links.sections_with_id(&link.doc_id).first() {
+            Some(section) => results.push(ValidationResult {
+                severity: Severity::Info,
+                rule: Rule::LinkVerified,
+                message: format!(
+                    "Enlace de archivo verificado: {} <-> sección '{}'",
+                    entity.file_path.display(),
+                    section.title.as_deref().unwrap_or(&section.id)
+                ),
+                function_name: None,
+                code_location: Some(location),
+                doc_id: Some(link.doc_id.clone()),
+                doc_location: Some(section.location()),
+                hint: None,
+                provenance: None,
+                related: Vec::new(),
+                target: None,
+                summary: None,
+                cause: None,
+                grace: None,
+            }),
+            None => results.push(ValidationResult {
+                severity: Severity::Error,
+                rule: Rule::MissingDocSection,
+                message: format!(
+                    "ID de documentación '{}' (enlace de archivo) no encontrado en el archivo de docs.",
+                    link.doc_id
+                ),
+                function_name: None,
+                code_location: Some(location),
+                doc_id: Some(link.doc_id.clone()),
+                doc_location: None,
+                hint: Some(format!(
+                    "Añade `<!-- @docs-id: {} -->` en el archivo de documentación.",
+                    link.doc_id
+                )),
+                provenance: None,
+                related: Vec::new(),
+                target: None,
+                summary: None,
+                cause: None,
+                grace: None,
+            }),
+        }
+    }
+}
+
+/// Convierte en hallazgos las notas del parser que se reportan siempre
+/// (anotaciones mal formadas en código y docs como Warning, `@docs` en
+/// conflicto como Error), aplicando supresiones en línea.
+pub fn validate_parse_notes(
+    code_entities: &[CodeEntity],
+    diagnostics: &ParseDiagnostics,
+) -> Vec<ValidationResult> {
+    let mut results: Vec<ValidationResult> = diagnostics
+        .notes
+        .iter()
+        .filter(|note| !note.is_strict_only())
+        .map(|note| note.to_result(note.default_severity()))
+        .collect();
+    suppression::apply_inline_suppressions(code_entities, &mut results);
+    results
+}
+
+/// Convierte las notas del parser en hallazgos `DGxxx` y añade las
+/// comparaciones de tipos que cayeron en el fallback de texto literal (DG004).
+///
+/// Las supresiones en línea se aplican igual que en `validate_links`.
+pub fn validate_strict(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+    diagnostics: &ParseDiagnostics,
+    strictness: Strictness,
+) -> Vec<ValidationResult> {
+    let severity = strictness.severity();
+    let mut results: Vec<ValidationResult> = diagnostics
+        .notes
+        .iter()
+        .filter(|note| note.is_strict_only())
+        .map(|note| note.to_result(severity))
+        .collect();
+
+    for entity in code_entities {
+        let Some(section) = doc_sections
+            .iter()
+            .find(|s| Some(&s.id) == entity.doc_id.as_ref() && !s.is_prose_only())
+        else {
+            continue;
+        };
+        for doc_arg in &section.args {
+            let Some(code_arg) = entity.args.iter().find(|a| a.name == doc_arg.name) else {
+                continue;
+            };
+            let (Some(code_type), Some(doc_type)) = (&code_arg.type_name, &doc_arg.type_name)
+            else {
+                continue;
+            };
+            let raw_generic = [code_type, doc_type]
+                .into_iter()
+                .any(|t| known_alias(t).is_none() && is_generic(t));
+            if raw_generic && normalize_type(code_type) != normalize_type(doc_type) {
+                let note = ParseNote {
+                    rule: Rule::UnnormalizedType,
+                    message: format!(
+                        "Tipos de '{}' comparados como texto literal: código '{}', docs '{}'.",
+                        code_arg.name, code_type, doc_type
+                    ),
+                    function_name: Some(entity.name.clone()),
+                    code_location: Some(entity.location()),
+                    doc_id: Some(section.id.clone()),
+                    doc_location: Some(section.location()),
+                    hint: None,
+                };
+                results.push(note.to_result(severity));
+            }
+        }
+    }
+
+    suppression::apply_inline_suppressions(code_entities, &mut results);
+    results
+}
+
+/// Descripciones que solo reservan el sitio (comparadas sin mayúsculas ni puntuación final).
+const PLACEHOLDERS: &[&str] = &["todo", "tbd", "fixme", "xxx", "...", "…", "-", "—", "?"];
+
+/// Detecta argumentos documentados cuya descripción es de relleno
+/// (`TODO`, `TBD: ...`, `...`). La regla está desactivada por defecto.
+///
+/// Las supresiones en línea se aplican igual que en `validate_links`.
+pub fn validate_placeholders(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+) -> Vec<ValidationResult> {
+    let mut results = Vec::new();
+    for section in doc_sections.iter().filter(|s| !s.is_prose_only()) {
+        let entity = code_entities
+            .iter()
+            .find(|e| e.doc_id.as_ref() == Some(&section.id));
+        for arg in &section.args {
+            let Some(description) = arg.description.as_deref() else {
+                continue;
+            };
+            if !is_placeholder(description) {
+                continue;
+            }
+            results.push(ValidationResult {
+                severity: Severity::Warning,
+                rule: Rule::PlaceholderDescription,
+                message: format!(
+                    "La descripción de '{}' es de relleno: \"{}\".",
+                    arg.name,
+                    description.trim()
+                ),
+                function_name: entity.map(|e| e.name.clone()),
+                code_location: entity.map(CodeEntity::location),
+                doc_id: Some(section.id.clone()),
+                doc_location: Some(arg_location(section, arg)),
+                hint: Some(format!("Describe qué espera '{}'.", arg.name)),
+                provenance: arg.provenance(),
+                related: Vec::new(),
+                target: None,
+                summary: None,
+                cause: None,
+                grace: None,
             });
         }
     }
+    suppression::apply_inline_suppressions(code_entities, &mut results);
+    results
+}
 
+/// Compara la versión "desde" de cada sección con la del proyecto
+/// (`check --check-versions`): posterior a la actual o ausente de
+/// `versions.known` es Warning; una versión que no es semver, Info.
+///
+/// Las supresiones en línea se aplican igual que en `validate_links`.
+pub fn validate_since_versions(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+    versions: &ProjectVersions,
+) -> Vec<ValidationResult> {
+    let mut results = Vec::new();
+    for section in doc_sections {
+        let Some(since) = section.since.as_deref() else {
+            continue;
+        };
+        let (severity, message, hint) = match version_source::parse_version(since) {
+            None => (
+                Severity::Info,
+                format!(
+                    "La versión '{}' de la sección '{}' no es semver válido.",
+                    since, section.id
+                ),
+                "Usa `MAJOR.MINOR[.PATCH]`, p. ej. `v2.3`.".to_string(),
+            ),
+            Some(version) if version > versions.current => (
+                Severity::Warning,
+                format!(
+                    "La sección '{}' documenta {} pero el proyecto está en {}.",
+                    section.id, since, versions.current_label
+                ),
+                "Corrige la versión o actualiza la fuente de `versions` tras publicar.".to_string(),
+            ),
+            Some(version) if !versions.known.is_empty() && !versions.known.contains(&version) => (
+                Severity::Warning,
+                format!(
+                    "La sección '{}' documenta {}, que no figura entre las versiones publicadas.",
+                    section.id, since
+                ),
+                "Corrige la versión o añádela a `versions.known`.".to_string(),
+            ),
+            Some(_) => continue,
+        };
+        let entity = code_entities
+            .iter()
+            .find(|e| e.doc_id.as_ref() == Some(&section.id));
+        results.push(ValidationResult {
+            severity,
+            rule: Rule::SinceVersion,
+            message,
+            function_name: entity.map(|e| e.name.clone()),
+            code_location: entity.map(CodeEntity::location),
+            doc_id: Some(section.id.clone()),
+            doc_location: Some(section.location()),
+            hint: Some(hint),
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        });
+    }
+    suppression::apply_inline_suppressions(code_entities, &mut results);
     results
 }
 
+/// `true` si la descripción es un marcador (`TODO`, `...`) o empieza por uno
+/// seguido de puntuación (`TODO: documentar`, `TBD (ver #12)`). "Todo list"
+/// es texto normal.
+fn is_placeholder(description: &str) -> bool {
+    let text = description.trim().to_lowercase();
+    let bare = text.trim_end_matches(['.', ':', '!']);
+    if PLACEHOLDERS.contains(&text.as_str()) || PLACEHOLDERS.contains(&bare) {
+        return true;
+    }
+    ["todo", "tbd", "fixme"].iter().any(|marker| {
+        text.strip_prefix(marker)
+            .is_some_and(|rest| rest.trim_start().starts_with([':', '(', '-', '—']))
+    })
+}
+
 /// Compara los argumentos del código con los documentados.
 /// Detecta: args fantasma, args faltantes, y type mismatches.
 fn validate_args(
     entity: &CodeEntity,
     section: &DocSection,
     location: &str,
+    arg_names: &ArgNamesConfig,
     results: &mut Vec<ValidationResult>,
 ) {
     let doc_id = entity.doc_id.as_deref().unwrap_or("?");
+    let doc_location = section.location();
+
+    // Sección recortada por `limits.max_section_args` (DG006): un resumen de
+    // aridad en lugar de un hallazgo por argumento
+    if section.dropped_args > 0 {
+        let documented = section.args.len() + section.dropped_args;
+        if documented > entity.args.len() {
+            results.push(ValidationResult {
+                severity: Severity::Error,
+                rule: Rule::GhostArg,
+                message: format!(
+                    "La sección '{}' documenta {} y fn {} tiene {}: no se comparan uno a uno.",
+                    doc_id,
+                    messages::ARGS.count(documented),
+                    entity.name,
+                    messages::Locale::CANONICAL.number(entity.args.len())
+                ),
+                function_name: Some(entity.name.clone()),
+                code_location: Some(location.to_string()),
+                doc_id: Some(doc_id.to_string()),
+                doc_location: Some(doc_location),
+                hint: Some(
+                    "Comprueba que el marcador `@docs-id` no quedó sobre una lista o tabla que no documenta la función.".into(),
+                ),
+                provenance: None,
+                related: Vec::new(),
+                target: None,
+                summary: None,
+                cause: None,
+                grace: None,
+            });
+        }
+        return;
+    }
+
+    let code_match_for = |doc_arg: &Arg| code_arg_for(entity, section, doc_arg, arg_names);
 
     // Argumentos en docs que no existen en código (fantasma)
     for doc_arg in &section.args {
-        let code_match = entity.args.iter().find(|a| a.name == doc_arg.name);
+        let code_match = code_match_for(doc_arg);
 
         match code_match {
             None => {
                 results.push(ValidationResult {
                     severity: Severity::Error,
-                    message: format!(
-                        "Argumento fantasma: '{}' está documentado pero no existe en fn {}.",
-                        doc_arg.name, entity.name
-                    ),
+                    rule: Rule::GhostArg,
+                    message: ghost_arg_message(&doc_arg.name, &entity.name),
                     function_name: Some(entity.name.clone()),
                     code_location: Some(location.to_string()),
                     doc_id: Some(doc_id.to_string()),
+                    doc_location: Some(doc_location.clone()),
                     hint: Some(format!(
                         "Elimina '{}' de la documentación o añádelo a la firma de la función.",
                         doc_arg.name
                     )),
+                    provenance: doc_arg.provenance(),
+                    related: Vec::new(),
+                    target: None,
+                    summary: None,
+                    cause: None,
+                    grace: None,
                 });
             }
             Some(code_arg) => {
+                if code_arg.name != doc_arg.name && arg_names.report_differences {
+                    results.push(arg_name_style(
+                        entity, code_arg, doc_arg, location, section, arg_names,
+                    ));
+                }
                 // Verificar type mismatch si ambos tienen tipo
-                check_type_mismatch(entity, code_arg, doc_arg, location, doc_id, results);
+                check_type_mismatch(entity, code_arg, doc_arg, location, section, results);
+                check_unit_mismatch(entity, code_arg, doc_arg, location, section, results);
             }
         }
+        if let Some(range) = doc_arg.range.filter(|r| r.is_inverted()) {
+            results.push(ValidationResult {
+                severity: Severity::Info,
+                rule: Rule::ArgConstraint,
+                message: format!(
+                    "Rango mal formado en la descripción de '{}': {} (el mínimo supera al máximo).",
+                    doc_arg.name, range
+                ),
+                function_name: Some(entity.name.clone()),
+                code_location: Some(location.to_string()),
+                doc_id: Some(doc_id.to_string()),
+                doc_location: Some(arg_location(section, doc_arg)),
+                hint: Some("Escribe el rango como mínimo–máximo.".into()),
+                provenance: doc_arg.provenance(),
+                related: Vec::new(),
+                target: None,
+                summary: None,
+                cause: None,
+                grace: None,
+            });
+        }
+    }
+
+    // Rangos invertidos en los comentarios del código
+    for code_arg in entity.args.iter() {
+        let Some(range) = code_arg.range.filter(|r| r.is_inverted()) else {
+            continue;
+        };
+        results.push(ValidationResult {
+            severity: Severity::Info,
+            rule: Rule::ArgConstraint,
+            message: format!(
+                "Rango mal formado en el comentario de '{}' en fn {}: {} (el mínimo supera al máximo).",
+                code_arg.name, entity.name, range
+            ),
+            function_name: Some(entity.name.clone()),
+            code_location: Some(location.to_string()),
+            doc_id: Some(doc_id.to_string()),
+            doc_location: Some(doc_location.clone()),
+            hint: Some("Escribe el rango como mínimo–máximo.".into()),
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        });
     }
 
     // Argumentos en código que no están documentados (faltantes)
     for code_arg in &entity.args {
-        let is_documented = section.args.iter().any(|a| a.name == code_arg.name);
+        let is_documented = section
+            .args
+            .iter()
+            .any(|d| code_match_for(d).is_some_and(|a| a.name == code_arg.name));
 
         if !is_documented {
             results.push(ValidationResult {
                 severity: Severity::Warning,
-                message: format!(
-                    "El argumento '{}' existe en código pero falta en la documentación.",
-                    code_arg.name
-                ),
+                rule: Rule::MissingArg,
+                message: missing_arg_message(&code_arg.name),
                 function_name: Some(entity.name.clone()),
                 code_location: Some(location.to_string()),
                 doc_id: Some(doc_id.to_string()),
+                doc_location: Some(doc_location.clone()),
                 hint: Some(format!(
                     "Documenta el argumento '{}' en la sección '{}'.",
                     code_arg.name, doc_id
                 )),
+                provenance: None,
+                related: Vec::new(),
+                target: None,
+                summary: None,
+                cause: None,
+                grace: None,
+            });
+        }
+    }
+}
+
+/// Argumento de la firma de `entity` que documenta `doc_arg`: el de nombre
+/// idéntico o, si no hay, uno que casa tras normalizar y que ningún otro de
+/// `section` documenta con su nombre exacto.
+pub(crate) fn code_arg_for<'a>(
+    entity: &'a CodeEntity,
+    section: &DocSection,
+    doc_arg: &Arg,
+    arg_names: &ArgNamesConfig,
+) -> Option<&'a Arg> {
+    entity
+        .args
+        .iter()
+        .find(|a| a.name == doc_arg.name)
+        .or_else(|| {
+            entity.args.iter().find(|a| {
+                arg_names.matching.matches(&doc_arg.name, &a.name)
+                    && !section.args.iter().any(|d| d.name == a.name)
+            })
+        })
+}
+
+/// Info de un argumento que las docs nombran con otra convención que la
+/// firma y solo casa con `arg_names.matching`.
+fn arg_name_style(
+    entity: &CodeEntity,
+    code_arg: &Arg,
+    doc_arg: &Arg,
+    location: &str,
+    section: &DocSection,
+    arg_names: &ArgNamesConfig,
+) -> ValidationResult {
+    ValidationResult {
+        severity: Severity::Info,
+        rule: Rule::ArgNameStyle,
+        message: format!(
+            "'{}' (docs) se toma por '{}' (fn {}) con arg_names.matching: {}.",
+            doc_arg.name,
+            code_arg.name,
+            entity.name,
+            arg_names.matching.name()
+        ),
+        function_name: Some(entity.name.clone()),
+        code_location: Some(location.to_string()),
+        doc_id: Some(section.id.clone()),
+        doc_location: Some(arg_location(section, doc_arg)),
+        hint: Some(format!(
+            "Para una sola convención, documenta el argumento como '{}'.",
+            code_arg.name
+        )),
+        provenance: doc_arg.provenance(),
+        related: Vec::new(),
+        target: None,
+        summary: None,
+        cause: None,
+        grace: None,
+    }
+}
+
+/// Compara la unidad de la descripción de docs con la del doc-comment del
+/// código. Solo si ambas fuentes nombran una unidad.
+fn check_unit_mismatch(
+    entity: &CodeEntity,
+    code_arg: &Arg,
+    doc_arg: &Arg,
+    location: &str,
+    section: &DocSection,
+    results: &mut Vec<ValidationResult>,
+) {
+    let (Some(code_unit), Some(doc_unit)) = (&code_arg.unit, &doc_arg.unit) else {
+        return;
+    };
+    if code_unit == doc_unit {
+        return;
+    }
+    results.push(ValidationResult {
+        severity: Severity::Info,
+        rule: Rule::ArgConstraint,
+        message: format!(
+            "Unidades distintas para '{}': la documentación dice {} y el comentario del código {}.",
+            doc_arg.name, doc_unit, code_unit
+        ),
+        function_name: Some(entity.name.clone()),
+        code_location: Some(location.to_string()),
+        doc_id: Some(section.id.clone()),
+        doc_location: Some(arg_location(section, doc_arg)),
+        hint: Some(format!(
+            "Unifica la unidad de '{}' en las docs o en el comentario de la función.",
+            doc_arg.name
+        )),
+        provenance: doc_arg.provenance(),
+        related: Vec::new(),
+        target: None,
+        summary: None,
+        cause: None,
+        grace: None,
+    });
+}
+
+/// Argumentos que varias secciones del mismo ID documentan de forma
+/// contradictoria: tipos normalizados distintos, u opcional en una y
+/// obligatorio en otra. Diferir solo en la descripción no cuenta. Un
+/// hallazgo por argumento, en la función, con lo que dice cada sección.
+fn check_conflicting_arg_docs(
+    entity: &CodeEntity,
+    sections: &[&DocSection],
+    location: &str,
+    results: &mut Vec<ValidationResult>,
+) {
+    let mut names: Vec<&str> = Vec::new();
+    for arg in sections.iter().flat_map(|s| &s.args) {
+        if !names.contains(&arg.name.as_str()) {
+            names.push(&arg.name);
+        }
+    }
+
+    for name in names {
+        let claims: Vec<(&DocSection, &Arg)> = sections
+            .iter()
+            .filter_map(|s| s.args.iter().find(|a| a.name == name).map(|a| (*s, a)))
+            .collect();
+        if claims.len() < 2 {
+            continue;
+        }
+        let types: HashSet<String> = claims
+            .iter()
+            .filter_map(|(_, arg)| documented_type(arg))
+            .map(normalize_type)
+            .collect();
+        let optionality: HashSet<bool> = claims
+            .iter()
+            .filter_map(|(_, arg)| documented_optionality(arg))
+            .collect();
+        let conflicts: Vec<&str> = [
+            (types.len() > 1).then_some("el tipo"),
+            (optionality.len() > 1).then_some("si es opcional"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if conflicts.is_empty() {
+            continue;
+        }
+
+        let code_type = entity
+            .args
+            .iter()
+            .find(|a| a.name == name)
+            .and_then(|a| a.type_name.as_deref());
+        results.push(ValidationResult {
+            severity: Severity::Warning,
+            rule: Rule::ConflictingArgDocs,
+            message: format!(
+                "Las secciones de '{}' se contradicen en {} del argumento '{}'.",
+                entity.doc_id.as_deref().unwrap_or("?"),
+                conflicts.join(" y "),
+                name
+            ),
+            function_name: Some(entity.name.clone()),
+            code_location: Some(location.to_string()),
+            doc_id: entity.doc_id.clone(),
+            doc_location: None,
+            hint: Some(match code_type {
+                Some(code_type) => format!(
+                    "Unifica '{}' en todas las secciones (el código tiene '{}').",
+                    name, code_type
+                ),
+                None => format!("Unifica '{}' en todas las secciones.", name),
+            }),
+            provenance: None,
+            related: claims
+                .iter()
+                .map(|(section, arg)| Related {
+                    location: arg_location(section, arg),
+                    message: describe_claim(section, arg),
+                })
+                .collect(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        });
+    }
+}
+
+/// Tipo documentado sin calificadores (`string, optional` → `string`).
+fn documented_type(arg: &Arg) -> Option<&str> {
+    let type_name = arg.type_name.as_deref()?.split(',').next()?.trim();
+    (!type_name.is_empty()).then_some(type_name)
+}
+
+/// `Some(true)` si las docs marcan el argumento como opcional, `Some(false)`
+/// si como obligatorio: tras el tipo (`string, optional`) o al principio de
+/// la descripción (`Opcional. …`).
+pub(crate) fn documented_optionality(arg: &Arg) -> Option<bool> {
+    let qualifiers = arg.type_name.iter().flat_map(|t| t.split(',').skip(1));
+    let lead = arg
+        .description
+        .as_deref()
+        .and_then(|d| d.split_whitespace().next());
+    qualifiers.chain(lead).find_map(|word| {
+        match word
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase()
+            .as_str()
+        {
+            "optional" | "opcional" => Some(true),
+            "required" | "obligatorio" | "requerido" => Some(false),
+            _ => None,
+        }
+    })
+}
+
+/// Lo que una sección dice de un argumento: `'Guía': tipo 'number', opcional`.
+fn describe_claim(section: &DocSection, arg: &Arg) -> String {
+    let mut claim = format!(
+        "'{}': {}",
+        section.title.as_deref().unwrap_or(&section.id),
+        match documented_type(arg) {
+            Some(type_name) => format!("tipo '{}'", type_name),
+            None => "sin tipo".to_string(),
+        }
+    );
+    match documented_optionality(arg) {
+        Some(true) => claim.push_str(", opcional"),
+        Some(false) => claim.push_str(", obligatorio"),
+        None => {}
+    }
+    claim
+}
+
+/// Ubicación de un argumento documentado: su línea, o la de la sección.
+fn arg_location(section: &DocSection, arg: &Arg) -> String {
+    match arg.line {
+        Some(line) => format!("{}:{}", section.file_path.display(), line),
+        None => section.location(),
+    }
+}
+
+fn ghost_arg_message(arg: &str, function: &str) -> String {
+    format!(
+        "Argumento fantasma: '{}' está documentado pero no existe en fn {}.",
+        arg, function
+    )
+}
+
+fn missing_arg_message(arg: &str) -> String {
+    format!(
+        "El argumento '{}' existe en código pero falta en la documentación.",
+        arg
+    )
+}
+
+const TYPO_PREFIX: &str = "Posible typo en docs: '";
+const TYPO_SEPARATOR: &str = "' ≈ '";
+
+/// Nombres (documentado, de código) de un hallazgo `arg-typo`, para `--fix`.
+pub fn typo_names(message: &str) -> Option<(&str, &str)> {
+    let rest = message.strip_prefix(TYPO_PREFIX)?;
+    let (from, rest) = rest.split_once(TYPO_SEPARATOR)?;
+    let (to, _) = rest.split_once('\'')?;
+    Some((from, to))
+}
+
+const MISMATCH_PREFIX: &str = "Type mismatch en argumento '";
+const MISMATCH_CODE: &str = "': código tiene '";
+const MISMATCH_DOCS: &str = "', docs dice '";
+
+/// Argumento y tipos (de código, documentado) de un hallazgo `type-mismatch`,
+/// para las correcciones de `watch --interactive-fixes`.
+pub fn mismatch_types(message: &str) -> Option<(&str, &str, &str)> {
+    let rest = message.strip_prefix(MISMATCH_PREFIX)?;
+    let (arg, rest) = rest.split_once(MISMATCH_CODE)?;
+    let (code_type, rest) = rest.split_once(MISMATCH_DOCS)?;
+    let doc_type = rest.strip_suffix("'.")?;
+    Some((arg, code_type, doc_type))
+}
+
+/// Reclasifica como `arg-typo` los argumentos fantasma que son una errata de
+/// un argumento de código sin documentar (`usrename` ≈ `username`): la pareja
+/// ghost-arg + missing-arg se sustituye por un único Warning con la edición
+/// sugerida. Se ejecuta sobre los resultados de `validate_links`
+/// (`--check-arg-typos`).
+pub fn reclassify_arg_typos(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+    results: &mut Vec<ValidationResult>,
+) {
+    for entity in code_entities {
+        let Some(section) = entity
+            .doc_id
+            .as_ref()
+            .and_then(|id| doc_sections.iter().find(|s| &s.id == id))
+        else {
+            continue;
+        };
+        let location = entity.location();
+        let is_finding = |r: &ValidationResult, rule: Rule, message: &str| {
+            r.rule == rule
+                && r.function_name.as_deref() == Some(entity.name.as_str())
+                && r.code_location.as_deref() == Some(location.as_str())
+                && r.doc_id.as_ref() == Some(&section.id)
+                && r.message == message
+        };
+        for (doc_arg, code_arg) in arg_typos(entity, section) {
+            let ghost = ghost_arg_message(&doc_arg.name, &entity.name);
+            let Some(index) = results
+                .iter()
+                .position(|r| is_finding(r, Rule::GhostArg, &ghost))
+            else {
+                continue;
+            };
+            let ghost = results.remove(index);
+            let missing = missing_arg_message(&code_arg.name);
+            results.retain(|r| !is_finding(r, Rule::MissingArg, &missing));
+            let index = index.min(results.len());
+            results.insert(
+                index,
+                ValidationResult {
+                    severity: Severity::Warning,
+                    rule: Rule::ArgTypo,
+                    message: format!(
+                        "{}{}{}{}' (fn {}).",
+                        TYPO_PREFIX, doc_arg.name, TYPO_SEPARATOR, code_arg.name, entity.name
+                    ),
+                    doc_location: Some(arg_location(section, doc_arg)),
+                    hint: Some(format!(
+                        "Renombra '{}' a '{}' en la documentación (`check --fix` lo corrige).",
+                        doc_arg.name, code_arg.name
+                    )),
+                    ..ghost
+                },
+            );
+        }
+    }
+}
+
+/// Parejas (arg documentado, arg de código) que parecen una errata: el de
+/// docs no existe en código y está a distancia 1–2 de un único argumento sin
+/// documentar. Si dos fantasmas apuntan al mismo argumento no se adivina.
+fn arg_typos<'a>(entity: &'a CodeEntity, section: &'a DocSection) -> Vec<(&'a Arg, &'a Arg)> {
+    let undocumented: Vec<&Arg> = entity
+        .args
+        .iter()
+        .filter(|c| !section.args.iter().any(|d| d.name == c.name))
+        .collect();
+    let mut pairs: Vec<(&Arg, &Arg)> = Vec::new();
+    for doc_arg in section
+        .args
+        .iter()
+        .filter(|d| !entity.args.iter().any(|c| c.name == d.name))
+    {
+        let mut candidates: Vec<(usize, &Arg)> = undocumented
+            .iter()
+            .filter_map(|code_arg| {
+                typo_distance(&doc_arg.name, &code_arg.name).map(|d| (d, *code_arg))
+            })
+            .collect();
+        candidates.sort_by_key(|(distance, _)| *distance);
+        match candidates.as_slice() {
+            [(_, only)] => pairs.push((doc_arg, only)),
+            [(best, first), (next, _), ..] if best < next => pairs.push((doc_arg, first)),
+            _ => {}
+        }
+    }
+    let claimed = |code: &Arg| pairs.iter().filter(|(_, c)| c.name == code.name).count();
+    pairs
+        .iter()
+        .filter(|(_, code)| claimed(code) == 1)
+        .copied()
+        .collect()
+}
+
+/// Distancia de edición (con transposiciones) entre dos nombres, si está en
+/// el rango de una errata: 1–2, o solo 1 en nombres de hasta 4 caracteres,
+/// donde dos cambios ya suelen dar otro nombre legítimo.
+fn typo_distance(documented: &str, code: &str) -> Option<usize> {
+    let shortest = documented.chars().count().min(code.chars().count());
+    let max = if shortest <= 4 { 1 } else { 2 };
+    if documented.chars().count().abs_diff(code.chars().count()) > max {
+        return None;
+    }
+    let distance = strsim::osa_distance(documented, code);
+    (1..=max).contains(&distance).then_some(distance)
+}
+
+/// Argumentos fantasma de una sección a partir de los cuales se busca la
+/// función de la que se copió (`copied_section_min_args`).
+pub const DEFAULT_COPIED_SECTION_MIN_ARGS: usize = 3;
+
+/// Un Error `duplicate-doc-id` por cada archivo de docs que repite un ID ya
+/// definido en otro, con la primera definición en `related`. Dentro de un
+/// mismo archivo repetir el ID es legítimo (referencia y guía); entre
+/// archivos, la función enlazada no dice cuál de los dos documenta.
+pub fn validate_duplicate_ids(sections: &[DocSection]) -> Vec<ValidationResult> {
+    let mut first: HashMap<&str, &DocSection> = HashMap::new();
+    let mut reported: HashSet<(&str, &Path)> = HashSet::new();
+    let mut results = Vec::new();
+    for section in sections {
+        let original = *first.entry(&section.id).or_insert(section);
+        if original.file_path == section.file_path
+            || !reported.insert((&section.id, &section.file_path))
+        {
+            continue;
+        }
+        results.push(ValidationResult {
+            severity: Severity::Error,
+            rule: Rule::DuplicateDocId,
+            message: format!(
+                "ID de documentación '{}' definido en dos archivos: {} y {}.",
+                section.id,
+                original.location(),
+                section.location()
+            ),
+            function_name: None,
+            code_location: None,
+            doc_id: Some(section.id.clone()),
+            doc_location: Some(section.location()),
+            hint: Some(
+                "Renombra uno de los dos `@docs-id`: cada ID debe vivir en un solo archivo de docs."
+                    .into(),
+            ),
+            provenance: None,
+            related: vec![Related {
+                location: original.location(),
+                message: format!("Primera definición de '{}'", section.id),
+            }],
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        });
+    }
+    results
+}
+
+/// Funde en un Warning `copied-section` los `ghost-arg` de una sección que
+/// parece copiada de otra sin actualizar: al menos `min_ghost_args`
+/// fantasmas cuyos nombres están todos en la firma de una única función
+/// distinta, la que se nombra. Cada fantasma queda en `related`.
+///
+/// Los nombres se buscan en un índice nombre de argumento → funciones de
+/// todo el código. Si varias funciones tienen todos los fantasmas (`page`,
+/// `limit` y `sort` en cada listado) es una convención compartida y no una
+/// copia, y los `ghost-arg` se quedan como están. Va tras
+/// `reclassify_arg_typos`: una errata no es un fantasma.
+pub fn merge_copied_sections(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+    min_ghost_args: usize,
+    results: &mut Vec<ValidationResult>,
+) {
+    let mut functions_by_arg: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+    for entity in code_entities {
+        for arg in &entity.args {
+            functions_by_arg
+                .entry(arg.name.as_str())
+                .or_default()
+                .insert(entity.name.as_str());
+        }
+    }
+
+    for entity in code_entities {
+        let Some(section) = entity
+            .doc_id
+            .as_ref()
+            .and_then(|id| doc_sections.iter().find(|s| &s.id == id))
+        else {
+            continue;
+        };
+        let location = entity.location();
+        let is_ghost = |r: &ValidationResult, arg: &Arg| {
+            r.rule == Rule::GhostArg
+                && r.function_name.as_deref() == Some(entity.name.as_str())
+                && r.code_location.as_deref() == Some(location.as_str())
+                && r.doc_id.as_ref() == Some(&section.id)
+                && r.message == ghost_arg_message(&arg.name, &entity.name)
+        };
+        let ghosts: Vec<&Arg> = section
+            .args
+            .iter()
+            .filter(|arg| results.iter().any(|r| is_ghost(r, arg)))
+            .collect();
+        if ghosts.is_empty() || ghosts.len() < min_ghost_args {
+            continue;
+        }
+
+        let mut donors = functions_by_arg
+            .get(ghosts[0].name.as_str())
+            .cloned()
+            .unwrap_or_default();
+        for ghost in &ghosts[1..] {
+            let with_arg = functions_by_arg.get(ghost.name.as_str());
+            donors.retain(|name| with_arg.is_some_and(|names| names.contains(name)));
+        }
+        donors.remove(entity.name.as_str());
+        let [donor] = donors.into_iter().collect::<Vec<_>>()[..] else {
+            continue;
+        };
+
+        let index = results
+            .iter()
+            .position(|r| ghosts.iter().any(|arg| is_ghost(r, arg)))
+            .unwrap_or(results.len());
+        let mut related = Vec::new();
+        results.retain(|r| match ghosts.iter().find(|arg| is_ghost(r, arg)) {
+            Some(arg) => {
+                related.push(Related {
+                    location: arg_location(section, arg),
+                    message: r.message.clone(),
+                });
+                false
+            }
+            None => true,
+        });
+        results.insert(
+            index.min(results.len()),
+            ValidationResult {
+                severity: Severity::Warning,
+                rule: Rule::CopiedSection,
+                message: format!(
+                    "Los argumentos documentados coinciden con fn {} — posible sección copiada sin actualizar.",
+                    donor
+                ),
+                function_name: Some(entity.name.clone()),
+                code_location: Some(location.clone()),
+                doc_id: Some(section.id.clone()),
+                doc_location: Some(section.location()),
+                hint: Some(format!(
+                    "Documenta en '{}' los argumentos de fn {} en lugar de los de fn {}.",
+                    section.id, entity.name, donor
+                )),
+                provenance: None,
+                related,
+                target: None,
+                summary: None,
+                cause: None,
+                grace: None,
+            },
+        );
+    }
+}
+
+/// Excepción de `arg_exceptions` que no sustituyó ninguna pareja.
+pub struct StaleArgException<'a> {
+    pub exception: &'a ArgException,
+    /// Qué mitad de la pareja ya no se produce.
+    pub detail: String,
+}
+
+/// Sustituye por un Info `arg-exception` cada pareja ghost-arg + missing-arg
+/// que una entrada de `arg_exceptions` declara equivalente. Una excepción
+/// cuya sección no está en las docs es un Warning; las que no sustituyeron
+/// nada (falta una mitad de la pareja, o las dos) se devuelven para
+/// reportarlas como `unused-suppression`. Va antes de `reclassify_arg_typos`:
+/// la equivalencia declarada gana a la errata adivinada.
+pub fn apply_arg_exceptions<'a>(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+    exceptions: &'a [ArgException],
+    results: &mut Vec<ValidationResult>,
+) -> Vec<StaleArgException<'a>> {
+    let mut stale = Vec::new();
+    for exception in exceptions {
+        let sections: Vec<&DocSection> = doc_sections
+            .iter()
+            .filter(|s| s.id == exception.doc_id)
+            .collect();
+        if sections.is_empty() {
+            results.push(unknown_exception_section(exception));
+            continue;
+        }
+        let (mut ghosts, mut missing_args, mut applied) = (false, false, false);
+        let linked = code_entities
+            .iter()
+            .filter(|e| e.doc_id.as_deref() == Some(exception.doc_id.as_str()));
+        for entity in linked {
+            let location = entity.location();
+            let ghost = ghost_arg_message(&exception.doc_arg, &entity.name);
+            let missing = missing_arg_message(&exception.code_arg);
+            for section in &sections {
+                let doc_location = section.location();
+                let is_finding = |r: &ValidationResult, rule: Rule, message: &str| {
+                    r.rule == rule
+                        && r.code_location.as_deref() == Some(location.as_str())
+                        && r.doc_id.as_ref() == Some(&exception.doc_id)
+                        && r.doc_location.as_deref() == Some(doc_location.as_str())
+                        && r.message == message
+                };
+                let ghost_at = results
+                    .iter()
+                    .position(|r| is_finding(r, Rule::GhostArg, &ghost));
+                let has_missing = results
+                    .iter()
+                    .any(|r| is_finding(r, Rule::MissingArg, &missing));
+                ghosts |= ghost_at.is_some();
+                missing_args |= has_missing;
+                let (Some(index), true) = (ghost_at, has_missing) else {
+                    continue;
+                };
+                applied = true;
+                let ghost = results.remove(index);
+                results.retain(|r| !is_finding(r, Rule::MissingArg, &missing));
+                let doc_arg = section.args.iter().find(|a| a.name == exception.doc_arg);
+                let index = index.min(results.len());
+                results.insert(
+                    index,
+                    ValidationResult {
+                        severity: Severity::Info,
+                        rule: Rule::ArgException,
+                        message: format!(
+                            "'{}' (docs) equivale a '{}' (fn {}) según arg_exceptions: {}.",
+                            exception.doc_arg,
+                            exception.code_arg,
+                            entity.name,
+                            exception.reason.trim_end_matches('.')
+                        ),
+                        doc_location: doc_arg
+                            .map(|arg| arg_location(section, arg))
+                            .or(ghost.doc_location.clone()),
+                        hint: None,
+                        ..ghost
+                    },
+                );
+            }
+        }
+        if !applied {
+            let detail = match (ghosts, missing_args) {
+                (true, _) => format!(
+                    "solo queda el argumento fantasma '{}'; '{}' no falta en las docs",
+                    exception.doc_arg, exception.code_arg
+                ),
+                (false, true) => format!(
+                    "solo falta '{}' en las docs; '{}' no es un argumento fantasma",
+                    exception.code_arg, exception.doc_arg
+                ),
+                (false, false) => "ninguna función enlazada produce la pareja".to_string(),
+            };
+            stale.push(StaleArgException { exception, detail });
+        }
+    }
+    stale
+}
+
+/// `.docsguard/config.yaml`, donde viven las excepciones.
+fn exceptions_file() -> String {
+    Path::new(DOCSGUARD_DIR)
+        .join(CONFIG_FILE)
+        .display()
+        .to_string()
+}
+
+fn describe_exception(exception: &ArgException) -> String {
+    format!("`{}` ↔ `{}`", exception.doc_arg, exception.code_arg)
+}
+
+fn unknown_exception_section(exception: &ArgException) -> ValidationResult {
+    ValidationResult {
+        severity: Severity::Warning,
+        rule: Rule::ArgException,
+        message: format!(
+            "La excepción {} de arg_exceptions nombra la sección '{}', que no está en las docs.",
+            describe_exception(exception),
+            exception.doc_id
+        ),
+        function_name: None,
+        code_location: None,
+        doc_id: Some(exception.doc_id.clone()),
+        doc_location: Some(exceptions_file()),
+        hint: Some("Corrige el `doc_id` o quita la excepción de `arg_exceptions`.".into()),
+        provenance: None,
+        related: Vec::new(),
+        target: None,
+        summary: None,
+        cause: None,
+        grace: None,
+    }
+}
+
+/// Hallazgos `unused-suppression` para las excepciones que no sustituyeron nada.
+pub fn stale_arg_exception_results(
+    stale: &[StaleArgException],
+    severity: Severity,
+) -> Vec<ValidationResult> {
+    stale
+        .iter()
+        .map(|StaleArgException { exception, detail }| ValidationResult {
+            severity,
+            rule: Rule::UnusedSuppression,
+            message: format!(
+                "La excepción {} de '{}' (arg_exceptions) no silenció ningún hallazgo: {}.",
+                describe_exception(exception),
+                exception.doc_id,
+                detail
+            ),
+            function_name: None,
+            code_location: None,
+            doc_id: Some(exception.doc_id.clone()),
+            doc_location: Some(exceptions_file()),
+            hint: Some(
+                "Quita la excepción de `arg_exceptions`: la pareja ya no se produce.".into(),
+            ),
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        })
+        .collect()
+}
+
+/// Warning por cada enlace verificado que cruza una frontera de
+/// `link_boundaries`: el archivo de la función encaja con el `code` de una
+/// frontera y el de la sección no encaja con su `docs`. Gana la primera
+/// frontera cuyo `code` encaja; los archivos fuera de toda frontera no se
+/// comprueban.
+pub fn validate_link_boundaries(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+    boundaries: &[LinkBoundary],
+) -> Vec<ValidationResult> {
+    let mut results = Vec::new();
+    if boundaries.is_empty() {
+        return results;
+    }
+    for entity in code_entities {
+        let Some(doc_id) = &entity.doc_id else {
+            continue;
+        };
+        let Some(boundary) = boundaries
+            .iter()
+            .find(|b| glob_matches(&b.code, &entity.file_path))
+        else {
+            continue;
+        };
+        let crossing = doc_sections
+            .iter()
+            .filter(|s| &s.id == doc_id && !s.no_link)
+            .filter(|s| !glob_matches(&boundary.docs, &s.file_path));
+        for section in crossing {
+            results.push(ValidationResult {
+                severity: Severity::Warning,
+                rule: Rule::LinkBoundary,
+                message: format!(
+                    "fn {} ({}) enlaza la sección '{}' de {}, fuera de la frontera `{}` → `{}`.",
+                    entity.name,
+                    entity.file_path.display(),
+                    section.id,
+                    section.file_path.display(),
+                    boundary.code,
+                    boundary.docs
+                ),
+                function_name: Some(entity.name.clone()),
+                code_location: Some(entity.location()),
+                doc_id: Some(doc_id.clone()),
+                doc_location: Some(section.location()),
+                hint: Some(format!(
+                    "Enlaza una sección de `{}`; si el enlace es intencionado, añade `// docsguard-ignore: link-boundary`.",
+                    boundary.docs
+                )),
+                provenance: None,
+                related: Vec::new(),
+                target: None,
+                summary: None,
+                cause: None,
+                grace: None,
             });
         }
     }
+    results
+}
+
+/// Compara las funciones de lenguajes distintos enlazadas a la misma sección
+/// (el mismo API en dos SDK): argumentos (por nombre canónico, así que
+/// `tenantId` y `tenant_id` son el mismo), sus tipos y el de retorno, con la
+/// normalización de `normalize_type`. Un Warning por sección con las
+/// diferencias, en la primera implementación y con todas en `related`. Los
+/// tipos genéricos y los que falten no se comparan; de cada lenguaje cuenta
+/// la primera función. La regla está desactivada por defecto.
+///
+/// Las supresiones en línea se aplican igual que en `validate_links`.
+pub fn validate_cross_language(code_entities: &[CodeEntity]) -> Vec<ValidationResult> {
+    let mut groups: Vec<(&str, Vec<(Language, &CodeEntity)>)> = Vec::new();
+    for entity in code_entities {
+        let (Some(doc_id), Ok(language)) = (
+            entity.doc_id.as_deref(),
+            Language::from_extension(&entity.file_path),
+        ) else {
+            continue;
+        };
+        match groups.iter_mut().find(|(id, _)| *id == doc_id) {
+            Some((_, impls)) => {
+                if !impls.iter().any(|(l, _)| *l == language) {
+                    impls.push((language, entity));
+                }
+            }
+            None => groups.push((doc_id, vec![(language, entity)])),
+        }
+    }
+
+    let mut results = Vec::new();
+    for (doc_id, impls) in groups.iter().filter(|(_, impls)| impls.len() > 1) {
+        let differences = signature_differences(impls);
+        if differences.is_empty() {
+            continue;
+        }
+        let (_, first) = impls[0];
+        let languages: Vec<&str> = impls.iter().map(|(l, _)| l.name()).collect();
+        results.push(ValidationResult {
+            severity: Severity::Warning,
+            rule: Rule::CrossLanguageDrift,
+            message: format!(
+                "Las implementaciones de '{}' en {} no coinciden: {}.",
+                doc_id,
+                join_languages(&languages),
+                differences.join("; ")
+            ),
+            function_name: Some(first.name.clone()),
+            code_location: Some(first.location()),
+            doc_id: Some(doc_id.to_string()),
+            doc_location: None,
+            hint: Some(
+                "Alinea las firmas: las docs compartidas no pueden ser correctas para todas."
+                    .to_string(),
+            ),
+            provenance: None,
+            related: impls
+                .iter()
+                .map(|(language, entity)| Related {
+                    location: entity.location(),
+                    message: format!("{}: {}", language.name(), signature(entity)),
+                })
+                .collect(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        });
+    }
+    suppression::apply_inline_suppressions(code_entities, &mut results);
+    results
+}
+
+/// Diferencias entre las firmas de `impls`, en el orden de los argumentos.
+fn signature_differences(impls: &[(Language, &CodeEntity)]) -> Vec<String> {
+    let mut names: Vec<(String, &str)> = Vec::new();
+    for arg in impls.iter().flat_map(|(_, e)| &e.args) {
+        let canonical = canonical_name(&arg.name);
+        if !names.iter().any(|(c, _)| *c == canonical) {
+            names.push((canonical, &arg.name));
+        }
+    }
+
+    let mut differences = Vec::new();
+    for (canonical, name) in names {
+        let args: Vec<(Language, Option<&Arg>)> = impls
+            .iter()
+            .map(|(l, e)| {
+                let arg = e.args.iter().find(|a| canonical_name(&a.name) == canonical);
+                (*l, arg)
+            })
+            .collect();
+        if args.iter().any(|(_, a)| a.is_none()) {
+            let present: Vec<&str> = args
+                .iter()
+                .filter(|(_, a)| a.is_some())
+                .map(|(l, _)| l.name())
+                .collect();
+            differences.push(format!("'{}' solo en {}", name, join_languages(&present)));
+            continue;
+        }
+        let types = args
+            .iter()
+            .map(|(l, a)| (*l, a.and_then(|a| a.type_name.as_deref())));
+        if let Some(types) = diverging_types(types) {
+            differences.push(format!("'{}': {}", name, types));
+        }
+    }
+
+    let returns = impls.iter().map(|(l, e)| (*l, e.return_type.as_deref()));
+    if let Some(types) = diverging_types(returns) {
+        differences.push(format!("retorno: {}", types));
+    }
+    differences
+}
+
+/// `TypeScript string, Rust bool` si los tipos comparables no coinciden tras
+/// normalizarlos; `void` y `()` son el mismo.
+fn diverging_types<'a>(types: impl Iterator<Item = (Language, Option<&'a str>)>) -> Option<String> {
+    let comparable: Vec<(Language, &str)> = types
+        .filter_map(|(l, t)| t.filter(|t| !is_generic(t)).map(|t| (l, t)))
+        .collect();
+    let normalized: HashSet<String> = comparable
+        .iter()
+        .map(|(_, t)| match t.trim() {
+            "()" => "void".to_string(),
+            t => normalize_type(t),
+        })
+        .collect();
+    (normalized.len() > 1).then(|| {
+        comparable
+            .iter()
+            .map(|(l, t)| format!("{} {}", l.name(), t))
+            .collect::<Vec<_>>()
+            .join(", ")
+    })
+}
+
+/// `TypeScript, Rust y Go`.
+fn join_languages(names: &[&str]) -> String {
+    match names {
+        [] => String::new(),
+        [only] => only.to_string(),
+        [rest @ .., last] => format!("{} y {}", rest.join(", "), last),
+    }
+}
+
+/// `createUser(email: string, tenantId: string): User`, para `related`.
+fn signature(entity: &CodeEntity) -> String {
+    let args: Vec<String> = entity
+        .args
+        .iter()
+        .map(|a| match &a.type_name {
+            Some(t) => format!("{}: {}", a.name, t),
+            None => a.name.clone(),
+        })
+        .collect();
+    match &entity.return_type {
+        Some(t) => format!("{}({}): {}", entity.name, args.join(", "), t),
+        None => format!("{}({})", entity.name, args.join(", ")),
+    }
 }
 
 /// Verifica si el tipo documentado coincide con el del código.
@@ -173,9 +1868,10 @@ fn check_type_mismatch(
     code_arg: &Arg,
     doc_arg: &Arg,
     location: &str,
-    doc_id: &str,
+    section: &DocSection,
     results: &mut Vec<ValidationResult>,
 ) {
+    let doc_id = entity.doc_id.as_deref().unwrap_or("?");
     let code_type = match &code_arg.type_name {
         Some(t) => t,
         None => return, // Sin tipo en código, no se puede comparar
@@ -191,47 +1887,84 @@ fn check_type_mismatch(
     if code_normalized != doc_normalized {
         results.push(ValidationResult {
             severity: Severity::Warning,
+            rule: Rule::TypeMismatch,
             message: format!(
-                "Type mismatch en argumento '{}': código tiene '{}', docs dice '{}'.",
-                code_arg.name, code_type, doc_type
+                "{}{}{}{}{}{}'.",
+                MISMATCH_PREFIX, code_arg.name, MISMATCH_CODE, code_type, MISMATCH_DOCS, doc_type
             ),
             function_name: Some(entity.name.clone()),
             code_location: Some(location.to_string()),
             doc_id: Some(doc_id.to_string()),
+            doc_location: Some(section.location()),
             hint: Some(format!(
                 "Actualiza el tipo de '{}' en la documentación a '{}' (o verifica si es un alias válido).",
                 code_arg.name, code_type
             )),
+            provenance: doc_arg.provenance(),
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
         });
     }
 }
 
+/// Enlaces función → sección prose-only (`@docs-skip: args`), para que la
+/// exclusión no pase inadvertida en `check`, `ci github` y `coverage`.
+pub fn count_prose_only_links<'a>(
+    code_entities: impl IntoIterator<Item = &'a CodeEntity>,
+    doc_sections: &[DocSection],
+) -> usize {
+    code_entities
+        .into_iter()
+        .filter(|e| {
+            doc_sections
+                .iter()
+                .any(|s| s.is_prose_only() && e.doc_id.as_ref() == Some(&s.id))
+        })
+        .count()
+}
+
 /// Normaliza un tipo para comparación, manejando alias comunes.
 /// Blueprint §4.3: String/str -> string, i32/u64 -> number, bool -> boolean.
-fn normalize_type(type_str: &str) -> String {
+pub(crate) fn normalize_type(type_str: &str) -> String {
+    known_alias(type_str)
+        .map(String::from)
+        // Cualquier otro tipo: comparar tal cual (normalizado a lowercase)
+        .unwrap_or_else(|| type_str.trim().to_lowercase())
+}
+
+/// Forma canónica de un alias conocido, o `None` si el tipo cae en el fallback literal.
+fn known_alias(type_str: &str) -> Option<&'static str> {
     let cleaned = type_str.trim().to_lowercase();
 
     match cleaned.as_str() {
         // Texto
-        "string" | "str" | "&str" | "text" | "&string" => "string".to_string(),
+        "string" | "str" | "&str" | "text" | "&string" => Some("string"),
         // Números
         "number" | "integer" | "int" | "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8"
         | "u16" | "u32" | "u64" | "u128" | "usize" | "f32" | "f64" | "float" | "double"
-        | "long" | "short" | "byte" | "decimal" => "number".to_string(),
+        | "long" | "short" | "byte" | "decimal" => Some("number"),
         // Booleanos
-        "boolean" | "bool" => "boolean".to_string(),
+        "boolean" | "bool" => Some("boolean"),
         // UUID
-        "uuid" => "string".to_string(),
+        "uuid" => Some("string"),
         // Arrays / Lists
-        "array" | "list" | "vec" | "[]" => "array".to_string(),
-        // Cualquier otro tipo: comparar tal cual (normalizado a lowercase)
-        _ => cleaned,
+        "array" | "list" | "vec" | "[]" => Some("array"),
+        _ => None,
     }
 }
 
+/// Un tipo genérico no trivial: `Vec<String>`, `string[]`, `Map<K, V>`…
+fn is_generic(type_str: &str) -> bool {
+    type_str.contains('<') || type_str.contains('[')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::types::{ArgSource, FileLink};
     use std::path::PathBuf;
 
     fn make_entity(name: &str, doc_id: Option<&str>) -> CodeEntity {
@@ -239,10 +1972,15 @@ mod tests {
             name: name.into(),
             args: vec![],
             return_type: None,
+            return_fields: None,
             doc_id: doc_id.map(String::from),
             file_path: PathBuf::from("test.ts"),
             line: 1,
+            end_line: 1,
             is_public: true,
+            suppressions: vec![],
+            file_link: None,
+            examples: Vec::new(),
         }
     }
 
@@ -251,10 +1989,15 @@ mod tests {
             name: name.into(),
             args,
             return_type: None,
+            return_fields: None,
             doc_id: Some(doc_id.into()),
             file_path: PathBuf::from("test.ts"),
             line: 1,
+            end_line: 1,
             is_public: true,
+            suppressions: vec![],
+            file_link: None,
+            examples: Vec::new(),
         }
     }
 
@@ -262,9 +2005,19 @@ mod tests {
         DocSection {
             id: id.into(),
             title: title.map(String::from),
+            parent: None,
             args: vec![],
             file_path: PathBuf::from("test.md"),
             line: 1,
+            expectations: vec![],
+            examples: vec![],
+            since: None,
+            expected_function: None,
+            skips: Vec::new(),
+            dropped_args: 0,
+            title_inferred: false,
+            no_link: false,
+            mentions: Vec::new(),
         }
     }
 
@@ -272,9 +2025,19 @@ mod tests {
         DocSection {
             id: id.into(),
             title: Some(title.into()),
+            parent: None,
             args,
             file_path: PathBuf::from("test.md"),
             line: 1,
+            expectations: vec![],
+            examples: vec![],
+            since: None,
+            expected_function: None,
+            skips: Vec::new(),
+            dropped_args: 0,
+            title_inferred: false,
+            no_link: false,
+            mentions: Vec::new(),
         }
     }
 
@@ -283,6 +2046,10 @@ mod tests {
             name: name.into(),
             type_name: type_name.map(String::from),
             description: None,
+            source: ArgSource::Code,
+            line: None,
+            unit: None,
+            range: None,
         }
     }
 
@@ -290,7 +2057,7 @@ mod tests {
     fn matching_link_produces_info() {
         let entities = vec![make_entity("login", Some("auth-login"))];
         let sections = vec![make_section("auth-login", Some("Login"))];
-        let results = validate_links(&entities, &sections);
+        let results = validate_links(&entities, &sections, &Default::default());
 
         let infos: Vec<_> = results
             .iter()
@@ -304,7 +2071,7 @@ mod tests {
     fn missing_doc_section_produces_error() {
         let entities = vec![make_entity("login", Some("auth-login"))];
         let sections = vec![];
-        let results = validate_links(&entities, &sections);
+        let results = validate_links(&entities, &sections, &Default::default());
 
         let errors: Vec<_> = results
             .iter()
@@ -314,11 +2081,54 @@ mod tests {
         assert!(errors[0].message.contains("no encontrado"));
     }
 
+    #[test]
+    fn expects_turns_the_orphan_warning_into_a_precise_finding() {
+        let expecting = DocSection {
+            expected_function: Some("login".into()),
+            ..make_section("auth-login", Some("Login"))
+        };
+        let expected = |entities: &[CodeEntity]| {
+            let results = validate_links(
+                entities,
+                std::slice::from_ref(&expecting),
+                &Default::default(),
+            );
+            assert!(results.iter().all(|r| r.rule != Rule::OrphanSection));
+            results
+                .into_iter()
+                .filter(|r| r.rule == Rule::ExpectedFunction)
+                .collect::<Vec<_>>()
+        };
+
+        // Sin anotación: Warning con la anotación lista para pegar
+        let results = expected(&[make_entity("login", None)]);
+        assert_eq!(results[0].severity, Severity::Warning);
+        assert_eq!(results[0].code_location.as_deref(), Some("test.ts:1"));
+        assert!(results[0]
+            .hint
+            .as_deref()
+            .unwrap()
+            .contains("`/// @docs: [auth-login]`"));
+
+        // Anotada con otro id: conflicto
+        let results = expected(&[make_entity("login", Some("old-login"))]);
+        assert_eq!(results[0].severity, Severity::Warning);
+        assert!(results[0].message.contains("anotada con 'old-login'"));
+
+        // No existe: Error con el nombre más parecido
+        let results = expected(&[make_entity("logIn", None), make_entity("logout", None)]);
+        assert_eq!(results[0].severity, Severity::Error);
+        assert!(results[0].message.contains("la más parecida es 'logIn'"));
+
+        // Enlazada como se declaró: nada que reportar
+        assert!(expected(&[make_entity("login", Some("auth-login"))]).is_empty());
+    }
+
     #[test]
     fn orphan_doc_section_produces_warning() {
         let entities = vec![];
         let sections = vec![make_section("auth-login", Some("Login"))];
-        let results = validate_links(&entities, &sections);
+        let results = validate_links(&entities, &sections, &Default::default());
 
         let warnings: Vec<_> = results
             .iter()
@@ -328,6 +2138,49 @@ mod tests {
         assert!(warnings[0].message.contains("no está vinculada"));
     }
 
+    #[test]
+    fn no_link_sections_are_never_orphans() {
+        let template = DocSection {
+            no_link: true,
+            mentions: Vec::new(),
+            ..make_section("template-endpoint", Some("Copia esta sección"))
+        };
+        let results = validate_links(
+            &[make_entity("login", None)],
+            &[template],
+            &Default::default(),
+        );
+        assert!(
+            results.iter().all(|r| r.severity == Severity::Info),
+            "{results:?}"
+        );
+    }
+
+    #[test]
+    fn linking_a_no_link_section_is_an_error() {
+        let template = DocSection {
+            no_link: true,
+            mentions: Vec::new(),
+            args: vec![arg("id", Some("string"))],
+            ..make_section("template-endpoint", Some("Copia esta sección"))
+        };
+        let entities = vec![make_entity_with_args(
+            "get_order",
+            "template-endpoint",
+            vec![arg("order_id", Some("string"))],
+        )];
+        let results = validate_links(&entities, &[template], &Default::default());
+
+        // Solo el enlace prohibido: ni "verificado" ni argumentos de la plantilla
+        assert_eq!(results.len(), 1, "{results:?}");
+        assert_eq!(results[0].rule, Rule::ForbiddenLink);
+        assert_eq!(results[0].severity, Severity::Error);
+        assert!(results[0]
+            .message
+            .contains("sección 'template-endpoint' está marcada como no-vinculable"));
+        assert_eq!(results[0].code_location.as_deref(), Some("test.ts:1"));
+    }
+
     #[test]
     fn ghost_arg_produces_error() {
         let entities = vec![make_entity_with_args(
@@ -344,7 +2197,7 @@ mod tests {
             ],
         )];
 
-        let results = validate_links(&entities, &sections);
+        let results = validate_links(&entities, &sections, &Default::default());
         let errors: Vec<_> = results
             .iter()
             .filter(|r| r.severity == Severity::Error && r.message.contains("fantasma"))
@@ -370,7 +2223,7 @@ mod tests {
             // password falta en docs
         )];
 
-        let results = validate_links(&entities, &sections);
+        let results = validate_links(&entities, &sections, &Default::default());
         let warnings: Vec<_> = results
             .iter()
             .filter(|r| r.severity == Severity::Warning && r.message.contains("falta"))
@@ -392,7 +2245,7 @@ mod tests {
             vec![arg("tenant_id", Some("Integer"))], // docs dice Integer
         )];
 
-        let results = validate_links(&entities, &sections);
+        let results = validate_links(&entities, &sections, &Default::default());
         let mismatches: Vec<_> = results
             .iter()
             .filter(|r| r.message.contains("Type mismatch"))
@@ -415,7 +2268,7 @@ mod tests {
             vec![arg("name", Some("String"))],
         )];
 
-        let results = validate_links(&entities, &sections);
+        let results = validate_links(&entities, &sections, &Default::default());
         let mismatches: Vec<_> = results
             .iter()
             .filter(|r| r.message.contains("Type mismatch"))
@@ -434,4 +2287,1140 @@ mod tests {
         assert_eq!(normalize_type("UUID"), "string");
         assert_eq!(normalize_type("CustomType"), "customtype");
     }
+
+    #[test]
+    fn ghost_arg_provenance_only_in_verbose_rendering() {
+        let entities = vec![make_entity_with_args("login", "auth-login", vec![])];
+        let doc_arg = Arg {
+            source: ArgSource::Table,
+            line: Some(84),
+            unit: None,
+            range: None,
+            ..arg("tenant_id", Some("string"))
+        };
+        let sections = vec![make_section_with_args("auth-login", "Login", vec![doc_arg])];
+
+        let results = validate_links(&entities, &sections, &Default::default());
+        let ghost = results.iter().find(|r| r.rule == Rule::GhostArg).unwrap();
+        assert_eq!(
+            ghost.provenance.as_deref(),
+            Some("documentado vía tabla, línea 84")
+        );
+        assert!(!format!("{ghost}").contains("Origen"));
+        assert!(format!("{ghost:#}").contains("-> Origen: documentado vía tabla, línea 84"));
+    }
+
+    #[test]
+    fn strict_reports_notes_and_literal_generic_comparisons() {
+        let entities = vec![make_entity_with_args(
+            "list",
+            "users-list",
+            vec![arg("ids", Some("Vec<String>")), arg("limit", Some("u32"))],
+        )];
+        let sections = vec![make_section_with_args(
+            "users-list",
+            "List",
+            vec![arg("ids", Some("string[]")), arg("limit", Some("number"))],
+        )];
+        let mut diagnostics = ParseDiagnostics::default();
+        diagnostics.push(ParseNote {
+            rule: Rule::SkippedArgTable,
+            message: "Tabla ignorada".into(),
+            function_name: None,
+            code_location: None,
+            doc_id: Some("users-list".into()),
+            doc_location: Some("test.md:4".into()),
+            hint: None,
+        });
+
+        let results = validate_strict(&entities, &sections, &diagnostics, Strictness::Strict);
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.severity == Severity::Info));
+        let literal = results
+            .iter()
+            .find(|r| r.rule == Rule::UnnormalizedType)
+            .unwrap();
+        assert!(literal.message.contains("'ids'"));
+
+        let pedantic = validate_strict(&entities, &sections, &diagnostics, Strictness::Pedantic);
+        assert!(pedantic.iter().all(|r| r.severity == Severity::Warning));
+    }
+
+    fn file_linked(name: &str, line: usize, doc_id: &str) -> CodeEntity {
+        CodeEntity {
+            line,
+            end_line: line,
+            file_link: Some(FileLink {
+                doc_id: doc_id.into(),
+                line: 1,
+            }),
+            ..make_entity(name, None)
+        }
+    }
+
+    #[test]
+    fn file_link_covers_functions_and_section() {
+        let entities = vec![
+            file_linked("charge", 3, "payments-overview"),
+            file_linked("refund", 8, "payments-overview"),
+        ];
+        let sections = vec![
+            make_section("payments-overview", Some("Payments")),
+            DocSection {
+                parent: Some("Payments".into()),
+                ..make_section("payments-charge", Some("charge"))
+            },
+        ];
+
+        let results = validate_links(&entities, &sections, &Default::default());
+        assert!(results.iter().all(|r| r.rule != Rule::UnlinkedFunction));
+        assert!(results.iter().all(|r| r.rule != Rule::OrphanSection));
+        let verified: Vec<_> = results
+            .iter()
+            .filter(|r| r.rule == Rule::LinkVerified)
+            .collect();
+        assert_eq!(verified.len(), 1);
+        assert_eq!(verified[0].code_location.as_deref(), Some("test.ts:1"));
+    }
+
+    #[test]
+    fn file_link_to_missing_section_is_an_error() {
+        let entities = vec![file_linked("charge", 3, "payments-overview")];
+        let results = validate_links(&entities, &[], &Default::default());
+        let missing = results
+            .iter()
+            .find(|r| r.rule == Rule::MissingDocSection)
+            .unwrap();
+        assert_eq!(missing.severity, Severity::Error);
+        assert_eq!(missing.function_name, None);
+        assert!(missing.message.contains("enlace de archivo"));
+    }
+
+    fn typo_findings(code: &[&str], documented: &[&str]) -> Vec<ValidationResult> {
+        let code_args = code.iter().map(|name| arg(name, None)).collect();
+        let entities = vec![make_entity_with_args("login", "auth-login", code_args)];
+        let doc_args = documented
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Arg {
+                line: Some(i + 3),
+                ..arg(name, None)
+            })
+            .collect();
+        let sections = vec![make_section_with_args("auth-login", "Login", doc_args)];
+        let mut results = validate_links(&entities, &sections, &Default::default());
+        reclassify_arg_typos(&entities, &sections, &mut results);
+        results
+            .into_iter()
+            .filter(|r| r.rule != Rule::LinkVerified)
+            .collect()
+    }
+
+    #[test]
+    fn one_edit_away_doc_arg_is_a_typo_instead_of_a_ghost() {
+        let results = typo_findings(&["username", "password"], &["usrename", "password"]);
+        assert_eq!(results.len(), 1, "{results:?}");
+        assert_eq!(results[0].rule, Rule::ArgTypo);
+        assert_eq!(results[0].severity, Severity::Warning);
+        assert_eq!(
+            results[0].message,
+            "Posible typo en docs: 'usrename' ≈ 'username' (fn login)."
+        );
+        assert_eq!(results[0].doc_location.as_deref(), Some("test.md:3"));
+        assert_eq!(
+            typo_names(&results[0].message),
+            Some(("usrename", "username"))
+        );
+    }
+
+    #[test]
+    fn two_edits_away_is_a_typo_only_for_longer_names() {
+        let results = typo_findings(&["password"], &["paswrd"]);
+        assert_eq!(results.len(), 1, "{results:?}");
+        assert_eq!(results[0].rule, Rule::ArgTypo);
+
+        // En nombres cortos solo cuenta un cambio: dos ya son otro argumento
+        let rules: Vec<_> = typo_findings(&["port"], &["prt"])
+            .iter()
+            .map(|r| r.rule)
+            .collect();
+        assert_eq!(rules, vec![Rule::ArgTypo]);
+        let rules: Vec<_> = typo_findings(&["port"], &["pst"])
+            .iter()
+            .map(|r| r.rule)
+            .collect();
+        assert_eq!(rules, vec![Rule::GhostArg, Rule::MissingArg]);
+    }
+
+    #[test]
+    fn typo_pass_does_not_steal_a_documented_or_contested_arg() {
+        // `username` ya está documentado: `usernames` es un fantasma de verdad
+        let rules: Vec<_> = typo_findings(&["username"], &["username", "usernames"])
+            .iter()
+            .map(|r| r.rule)
+            .collect();
+        assert_eq!(rules, vec![Rule::GhostArg]);
+
+        // Dos fantasmas igual de cerca del mismo argumento: no se adivina
+        let rules: Vec<_> = typo_findings(&["username"], &["usrename", "usernam"])
+            .iter()
+            .map(|r| r.rule)
+            .collect();
+        assert_eq!(
+            rules,
+            vec![Rule::GhostArg, Rule::GhostArg, Rule::MissingArg]
+        );
+
+        // Un fantasma a la misma distancia de dos argumentos sin documentar tampoco
+        let rules: Vec<_> = typo_findings(&["from_id", "form_ids"], &["from_ids"])
+            .iter()
+            .map(|r| r.rule)
+            .collect();
+        assert_eq!(rules.iter().filter(|r| **r == Rule::ArgTypo).count(), 0);
+    }
+
+    /// Hallazgos de `entities` y `sections` tras fundir las secciones copiadas.
+    fn copied_findings(
+        entities: &[CodeEntity],
+        sections: &[DocSection],
+        min_ghost_args: usize,
+    ) -> Vec<ValidationResult> {
+        let mut results = validate_links(entities, sections, &Default::default());
+        merge_copied_sections(entities, sections, min_ghost_args, &mut results);
+        results
+            .into_iter()
+            .filter(|r| matches!(r.rule, Rule::GhostArg | Rule::CopiedSection))
+            .collect()
+    }
+
+    fn args(names: &[&str]) -> Vec<Arg> {
+        names.iter().map(|name| arg(name, None)).collect()
+    }
+
+    #[test]
+    fn ghost_args_from_another_signature_are_one_copied_section_finding() {
+        let create = ["name", "email", "password", "sendWelcomeEmail", "role"];
+        let entities = vec![
+            make_entity_with_args("createUser", "create-user", args(&create)),
+            CodeEntity {
+                line: 9,
+                end_line: 9,
+                ..make_entity_with_args("updateUser", "update-user", args(&["id", "name", "email"]))
+            },
+        ];
+        let sections = vec![
+            make_section_with_args("create-user", "Create User", args(&create)),
+            make_section_with_args("update-user", "Update User", args(&create)),
+        ];
+        let results = copied_findings(&entities, &sections, DEFAULT_COPIED_SECTION_MIN_ARGS);
+        assert_eq!(results.len(), 1, "{results:?}");
+        let copied = &results[0];
+        assert_eq!(copied.rule, Rule::CopiedSection);
+        assert_eq!(copied.severity, Severity::Warning);
+        assert_eq!(
+            copied.message,
+            "Los argumentos documentados coinciden con fn createUser — posible sección copiada sin actualizar."
+        );
+        assert_eq!(copied.function_name.as_deref(), Some("updateUser"));
+        assert_eq!(copied.code_location.as_deref(), Some("test.ts:9"));
+        let related: Vec<&str> = copied.related.iter().map(|r| r.message.as_str()).collect();
+        assert_eq!(
+            related,
+            [
+                ghost_arg_message("password", "updateUser"),
+                ghost_arg_message("sendWelcomeEmail", "updateUser"),
+                ghost_arg_message("role", "updateUser"),
+            ]
+        );
+
+        // Por debajo del mínimo los fantasmas se quedan sueltos
+        let results = copied_findings(&entities, &sections, 4);
+        assert_eq!(results.len(), 3, "{results:?}");
+        assert!(results.iter().all(|r| r.rule == Rule::GhostArg));
+    }
+
+    #[test]
+    fn ghost_args_shared_by_several_signatures_are_not_a_copy() {
+        let paging = ["page", "limit", "sort"];
+        let entities = vec![
+            make_entity_with_args("listUsers", "list-users", args(&paging)),
+            make_entity_with_args("listOrders", "list-orders", args(&paging)),
+            make_entity_with_args("search", "search", args(&["query"])),
+        ];
+        let sections = vec![
+            make_section_with_args("list-users", "List users", args(&paging)),
+            make_section_with_args("list-orders", "List orders", args(&paging)),
+            make_section_with_args(
+                "search",
+                "Search",
+                args(&["query", "page", "limit", "sort"]),
+            ),
+        ];
+        let results = copied_findings(&entities, &sections, DEFAULT_COPIED_SECTION_MIN_ARGS);
+        assert_eq!(results.len(), 3, "{results:?}");
+        assert!(results.iter().all(|r| r.rule == Rule::GhostArg));
+    }
+
+    fn exception(doc_id: &str, doc_arg: &str, code_arg: &str) -> ArgException {
+        ArgException {
+            doc_id: doc_id.into(),
+            doc_arg: doc_arg.into(),
+            code_arg: code_arg.into(),
+            reason: "agrupación conceptual".into(),
+        }
+    }
+
+    /// Hallazgos (sin `link-verified`) y excepciones sin uso de validar
+    /// `search(code)` contra una sección `search-api` con `documented`.
+    fn exception_findings(
+        code: &[&str],
+        documented: &[&str],
+        exceptions: &[ArgException],
+    ) -> (Vec<ValidationResult>, Vec<String>) {
+        let code_args = code.iter().map(|name| arg(name, None)).collect();
+        let entities = vec![make_entity_with_args("search", "search-api", code_args)];
+        let doc_args = documented
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Arg {
+                line: Some(i + 3),
+                ..arg(name, None)
+            })
+            .collect();
+        let sections = vec![make_section_with_args("search-api", "Search", doc_args)];
+        let mut results = validate_links(&entities, &sections, &Default::default());
+        let stale = apply_arg_exceptions(&entities, &sections, exceptions, &mut results);
+        let stale = stale_arg_exception_results(&stale, Severity::Warning)
+            .into_iter()
+            .map(|r| r.message)
+            .collect();
+        results.retain(|r| r.rule != Rule::LinkVerified);
+        (results, stale)
+    }
+
+    #[test]
+    fn declared_arg_equivalence_replaces_the_ghost_and_missing_pair() {
+        let exceptions = [exception("search-api", "filters", "filter_expr")];
+        let (results, stale) = exception_findings(
+            &["query", "filter_expr"],
+            &["query", "filters"],
+            &exceptions,
+        );
+        assert_eq!(results.len(), 1, "{results:?}");
+        assert_eq!(results[0].rule, Rule::ArgException);
+        assert_eq!(results[0].severity, Severity::Info);
+        assert_eq!(
+            results[0].message,
+            "'filters' (docs) equivale a 'filter_expr' (fn search) según arg_exceptions: agrupación conceptual."
+        );
+        assert_eq!(results[0].doc_location.as_deref(), Some("test.md:4"));
+        assert!(stale.is_empty(), "{stale:?}");
+    }
+
+    #[test]
+    fn exceptions_without_a_current_pair_are_stale() {
+        let exceptions = [exception("search-api", "filters", "filter_expr")];
+
+        // Las docs ya usan el nombre del código: no queda nada que sustituir
+        let (results, stale) = exception_findings(&["filter_expr"], &["filter_expr"], &exceptions);
+        assert!(results.is_empty(), "{results:?}");
+        assert_eq!(
+            stale,
+            ["La excepción `filters` ↔ `filter_expr` de 'search-api' (arg_exceptions) no silenció ningún hallazgo: ninguna función enlazada produce la pareja."]
+        );
+
+        // Otro argumento en la pareja: el fantasma se reporta y la excepción, también
+        let (results, stale) = exception_findings(&["filter_expr"], &["filter"], &exceptions);
+        let rules: Vec<_> = results.iter().map(|r| r.rule).collect();
+        assert_eq!(rules, [Rule::GhostArg, Rule::MissingArg]);
+        assert_eq!(stale.len(), 1);
+    }
+
+    #[test]
+    fn a_half_matching_exception_keeps_the_other_finding_and_says_which_half_is_gone() {
+        let exceptions = [exception("search-api", "filters", "filter_expr")];
+
+        // `filter_expr` se documentó: el fantasma `filters` vuelve a ser un error
+        let (results, stale) =
+            exception_findings(&["filter_expr"], &["filter_expr", "filters"], &exceptions);
+        let rules: Vec<_> = results.iter().map(|r| r.rule).collect();
+        assert_eq!(rules, [Rule::GhostArg]);
+        assert!(
+            stale[0].ends_with(
+                "solo queda el argumento fantasma 'filters'; 'filter_expr' no falta en las docs."
+            ),
+            "{stale:?}"
+        );
+
+        // `filters` pasó al código: solo falta `filter_expr`
+        let (results, stale) =
+            exception_findings(&["filters", "filter_expr"], &["filters"], &exceptions);
+        let rules: Vec<_> = results.iter().map(|r| r.rule).collect();
+        assert_eq!(rules, [Rule::MissingArg]);
+        assert!(
+            stale[0].ends_with(
+                "solo falta 'filter_expr' en las docs; 'filters' no es un argumento fantasma."
+            ),
+            "{stale:?}"
+        );
+    }
+
+    #[test]
+    fn an_exception_for_an_unknown_section_is_a_warning_not_a_stale_entry() {
+        let exceptions = [exception("serch-api", "filters", "filter_expr")];
+        let (results, stale) = exception_findings(&["filter_expr"], &["filters"], &exceptions);
+        let unknown = results
+            .iter()
+            .find(|r| r.rule == Rule::ArgException)
+            .unwrap();
+        assert_eq!(unknown.severity, Severity::Warning);
+        assert!(
+            unknown.message.contains("'serch-api'"),
+            "{}",
+            unknown.message
+        );
+        assert_eq!(
+            unknown.doc_location.as_deref(),
+            Some(
+                Path::new(".docsguard")
+                    .join("config.yaml")
+                    .to_str()
+                    .unwrap()
+            )
+        );
+        assert!(stale.is_empty());
+        assert!(results.iter().any(|r| r.rule == Rule::GhostArg));
+    }
+
+    /// Reglas de los hallazgos de argumentos de `fn create(code)` contra una
+    /// sección que documenta `documented`, con `arg_names`.
+    fn arg_name_findings(
+        code: &[&str],
+        documented: &[&str],
+        arg_names: ArgNamesConfig,
+    ) -> Vec<(Rule, String)> {
+        let entity = make_entity_with_args(
+            "create",
+            "create",
+            code.iter().map(|name| arg(name, None)).collect(),
+        );
+        let section = make_section_with_args(
+            "create",
+            "Create",
+            documented.iter().map(|name| arg(name, None)).collect(),
+        );
+        validate_links(&[entity], &[section], &arg_names)
+            .into_iter()
+            .filter(|r| r.rule != Rule::LinkVerified)
+            .map(|r| (r.rule, r.message))
+            .collect()
+    }
+
+    #[test]
+    fn arg_names_match_exactly_ignoring_case_or_tolerating_conventions() {
+        use crate::config::ArgNameMatching::*;
+        // camelCase en el código frente a snake_case, mayúsculas y kebab-case en las docs
+        let code = ["tenantId", "userName", "limit"];
+        let documented = ["tenant_id", "username", "page-size"];
+        let rules = |matching| -> Vec<Rule> {
+            let arg_names = ArgNamesConfig {
+                matching,
+                report_differences: false,
+            };
+            let mut rules: Vec<Rule> = arg_name_findings(&code, &documented, arg_names)
+                .into_iter()
+                .map(|(rule, _)| rule)
+                .collect();
+            rules.sort();
+            rules
+        };
+        use Rule::{GhostArg as Ghost, MissingArg as Missing};
+        assert_eq!(
+            rules(Exact),
+            [Ghost, Ghost, Ghost, Missing, Missing, Missing]
+        );
+        assert_eq!(rules(CaseInsensitive), [Ghost, Ghost, Missing, Missing]);
+        assert_eq!(rules(ConventionTolerant), [Ghost, Missing]);
+        assert!(arg_name_findings(&code, &code, ArgNamesConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn a_match_only_through_normalization_can_be_reported_as_info() {
+        let arg_names = ArgNamesConfig {
+            matching: crate::config::ArgNameMatching::ConventionTolerant,
+            report_differences: true,
+        };
+        let findings =
+            arg_name_findings(&["tenantId", "limit"], &["tenant_id", "limit"], arg_names);
+        assert_eq!(
+            findings,
+            [(
+                Rule::ArgNameStyle,
+                "'tenant_id' (docs) se toma por 'tenantId' (fn create) con arg_names.matching: convention-tolerant.".to_string()
+            )]
+        );
+        // Un nombre idéntico gana a uno que solo casa tras normalizar
+        let findings = arg_name_findings(&["tenant_id", "tenantId"], &["tenantId"], arg_names);
+        assert_eq!(findings.len(), 1, "{findings:?}");
+        assert_eq!(findings[0].0, Rule::MissingArg);
+    }
+
+    fn boundary(code: &str, docs: &str) -> LinkBoundary {
+        LinkBoundary {
+            code: code.into(),
+            docs: docs.into(),
+        }
+    }
+
+    /// Hallazgos de fronteras de funciones `(archivo, id)` contra secciones `(archivo, id)`.
+    fn boundary_findings(
+        entities: &[(&str, &str)],
+        sections: &[(&str, &str)],
+        boundaries: &[LinkBoundary],
+    ) -> Vec<ValidationResult> {
+        let entities: Vec<CodeEntity> = entities
+            .iter()
+            .map(|(file, id)| CodeEntity {
+                file_path: PathBuf::from(file),
+                ..make_entity(&id.replace('-', "_"), Some(id))
+            })
+            .collect();
+        let sections: Vec<DocSection> = sections
+            .iter()
+            .map(|(file, id)| DocSection {
+                file_path: PathBuf::from(file),
+                ..make_section(id, None)
+            })
+            .collect();
+        validate_link_boundaries(&entities, &sections, boundaries)
+    }
+
+    #[test]
+    fn a_link_across_a_module_boundary_is_a_warning_naming_both_files() {
+        let boundaries = [boundary("src/billing/**", "docs/billing/**")];
+        let results = boundary_findings(
+            &[
+                ("src/billing/invoice.ts", "auth-login"),
+                ("src/billing/tax/vat.ts", "billing-vat"),
+            ],
+            &[
+                ("docs/auth/api.md", "auth-login"),
+                ("docs/billing/api.md", "billing-vat"),
+            ],
+            &boundaries,
+        );
+        assert_eq!(results.len(), 1, "{results:?}");
+        assert_eq!(results[0].rule, Rule::LinkBoundary);
+        assert_eq!(results[0].severity, Severity::Warning);
+        assert_eq!(
+            results[0].message,
+            "fn auth_login (src/billing/invoice.ts) enlaza la sección 'auth-login' de docs/auth/api.md, fuera de la frontera `src/billing/**` → `docs/billing/**`."
+        );
+        assert_eq!(
+            results[0].doc_location.as_deref(),
+            Some("docs/auth/api.md:1")
+        );
+    }
+
+    #[test]
+    fn each_boundary_checks_its_own_code_and_files_outside_all_of_them_are_free() {
+        let boundaries = [
+            boundary("src/billing/**", "docs/billing/**"),
+            boundary("src/auth/**", "docs/auth/**"),
+        ];
+        let results = boundary_findings(
+            &[
+                ("src/auth/login.ts", "billing-vat"),
+                ("src/shared/util.ts", "billing-vat"),
+                ("src/billing/vat.ts", "billing-vat"),
+            ],
+            &[("docs/billing/api.md", "billing-vat")],
+            &boundaries,
+        );
+        assert_eq!(results.len(), 1, "{results:?}");
+        assert_eq!(
+            results[0].code_location.as_deref(),
+            Some("src/auth/login.ts:1")
+        );
+        assert!(results[0]
+            .message
+            .contains("`src/auth/**` → `docs/auth/**`"));
+        assert!(boundary_findings(
+            &[("src/auth/login.ts", "billing-vat")],
+            &[("docs/billing/api.md", "billing-vat")],
+            &[]
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn overlapping_boundaries_use_the_first_whose_code_glob_matches() {
+        // `src/billing/legacy` enlaza a propósito las docs antiguas
+        let boundaries = [
+            boundary("src/billing/legacy/**", "docs/legacy/**"),
+            boundary("src/billing/**", "docs/billing/**"),
+        ];
+        let sections = [
+            ("docs/legacy/billing.md", "billing-old"),
+            ("docs/billing/api.md", "billing-new"),
+        ];
+        let legacy = boundary_findings(
+            &[("src/billing/legacy/old.ts", "billing-old")],
+            &sections,
+            &boundaries,
+        );
+        assert!(legacy.is_empty(), "{legacy:?}");
+        let crossing = boundary_findings(
+            &[("src/billing/legacy/old.ts", "billing-new")],
+            &sections,
+            &boundaries,
+        );
+        assert_eq!(crossing.len(), 1);
+        assert!(crossing[0].message.contains("`src/billing/legacy/**`"));
+        let reversed: Vec<_> = boundaries.iter().rev().cloned().collect();
+        let shadowed = boundary_findings(
+            &[("src/billing/legacy/old.ts", "billing-old")],
+            &sections,
+            &reversed,
+        );
+        assert_eq!(shadowed.len(), 1, "el orden decide: {shadowed:?}");
+    }
+
+    #[test]
+    fn unit_disagreements_and_inverted_ranges_are_info() {
+        let described = |name: &str, description: &str, line: Option<usize>| Arg {
+            description: Some(description.into()),
+            line,
+            ..arg(name, Some("number"))
+        };
+        let mut code_args = vec![
+            described("timeout", "timeout in seconds", None),
+            described("size", "tamaño en bytes, 4096-1024", None),
+            described("ratio", "Porcentaje", None),
+        ];
+        let mut doc_args = vec![
+            described("timeout", "en milisegundos, 100–30000", Some(4)),
+            described("size", "Tamaño máximo en bytes", Some(5)),
+            described("ratio", "sin unidad", Some(6)),
+        ];
+        let units = crate::core::constraints::UnitKeywords::default();
+        crate::core::constraints::annotate_args(&mut code_args, &units);
+        crate::core::constraints::annotate_args(&mut doc_args, &units);
+        let entities = vec![make_entity_with_args("fetch", "net-fetch", code_args)];
+        let sections = vec![make_section_with_args("net-fetch", "Fetch", doc_args)];
+
+        let results: Vec<_> = validate_links(&entities, &sections, &Default::default())
+            .into_iter()
+            .filter(|r| r.rule == Rule::ArgConstraint)
+            .collect();
+        assert_eq!(results.len(), 2, "{results:?}");
+        assert!(results.iter().all(|r| r.severity == Severity::Info));
+        assert_eq!(
+            results[0].message,
+            "Unidades distintas para 'timeout': la documentación dice ms y el comentario del código s."
+        );
+        assert_eq!(results[0].doc_location.as_deref(), Some("test.md:4"));
+        assert!(results[1].message.contains("'size' en fn fetch: 4096–1024"));
+    }
+
+    #[test]
+    fn placeholder_descriptions_are_reported_at_the_arg_line() {
+        let described = |name: &str, description: &str, line: usize| Arg {
+            description: Some(description.into()),
+            source: ArgSource::List,
+            line: Some(line),
+            unit: None,
+            range: None,
+            ..arg(name, None)
+        };
+        let entities = vec![make_entity("createUser", Some("user-create"))];
+        let sections = vec![make_section_with_args(
+            "user-create",
+            "Create User",
+            vec![
+                described("name", "TODO", 4),
+                described("email", "TBD: validar formato", 5),
+                described("role", "...", 6),
+                described("todoList", "Todo list of the user", 7),
+                described("age", "Edad en años.", 8),
+            ],
+        )];
+
+        let results = validate_placeholders(&entities, &sections);
+        let located: Vec<_> = results
+            .iter()
+            .map(|r| r.doc_location.as_deref().unwrap())
+            .collect();
+        assert_eq!(located, vec!["test.md:4", "test.md:5", "test.md:6"]);
+        assert!(results
+            .iter()
+            .all(|r| r.rule == Rule::PlaceholderDescription
+                && r.function_name.as_deref() == Some("createUser")));
+    }
+
+    #[test]
+    fn since_versions_are_compared_with_the_project_version() {
+        let versions = ProjectVersions {
+            current_label: "2.7".into(),
+            current: semver::Version::new(2, 7, 0),
+            known: vec![],
+        };
+        let since = |id: &str, version: &str| DocSection {
+            since: Some(version.into()),
+            expected_function: None,
+            skips: Vec::new(),
+            dropped_args: 0,
+            title_inferred: false,
+            no_link: false,
+            mentions: Vec::new(),
+            ..make_section(id, None)
+        };
+        let sections = vec![
+            since("released", "v2.3"),
+            since("future", "v3.0"),
+            since("typo", "v2.x"),
+            make_section("undated", None),
+        ];
+
+        let results = validate_since_versions(&[], &sections, &versions);
+        let found: Vec<_> = results
+            .iter()
+            .map(|r| (r.doc_id.as_deref().unwrap(), r.severity))
+            .collect();
+        assert_eq!(
+            found,
+            vec![("future", Severity::Warning), ("typo", Severity::Info)]
+        );
+        assert!(results[0]
+            .message
+            .contains("documenta v3.0 pero el proyecto está en 2.7"));
+
+        let listed = ProjectVersions {
+            known: vec![semver::Version::new(2, 0, 0), semver::Version::new(2, 7, 0)],
+            ..versions
+        };
+        let results = validate_since_versions(&[], &sections[..1], &listed);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].message.contains("no figura"));
+    }
+
+    fn reference_and_guide(guide_args: &str) -> Vec<DocSection> {
+        let source = format!(
+            "<!-- @docs-id: create-user -->\n## Referencia\n\n\
+             - `name` (string): Nombre visible\n\
+             - `age` (number, optional): Edad en años\n\n\
+             <!-- @docs-id: create-user -->\n## Guía\n\n{}",
+            guide_args
+        );
+        crate::parser::doc_parser::parse_markdown_source(
+            &source,
+            std::path::Path::new("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn sections_sharing_an_id_that_disagree_on_an_arg_are_one_finding() {
+        let entity = make_entity_with_args(
+            "createUser",
+            "create-user",
+            vec![arg("name", Some("string")), arg("age", Some("number"))],
+        );
+        let sections = reference_and_guide(
+            "- `name` (number): El nombre\n- `age` (integer, required): Edad\n",
+        );
+        let results = validate_links(&[entity], &sections, &Default::default());
+
+        // Cada sección se valida: el tipo de `name` en la guía también es un mismatch
+        assert_eq!(
+            results
+                .iter()
+                .filter(|r| r.rule == Rule::LinkVerified)
+                .count(),
+            2
+        );
+        let conflicts: Vec<&ValidationResult> = results
+            .iter()
+            .filter(|r| r.rule == Rule::ConflictingArgDocs)
+            .collect();
+        assert_eq!(conflicts.len(), 2, "{:#?}", conflicts);
+
+        let name = conflicts[0];
+        assert_eq!(name.severity, Severity::Warning);
+        assert!(name.message.contains("el tipo del argumento 'name'"));
+        assert_eq!(name.code_location.as_deref(), Some("test.ts:1"));
+        assert_eq!(name.doc_location, None);
+        assert_eq!(
+            name.related,
+            [
+                Related {
+                    location: "docs/api.md:4".into(),
+                    message: "'Referencia': tipo 'string'".into()
+                },
+                Related {
+                    location: "docs/api.md:10".into(),
+                    message: "'Guía': tipo 'number'".into()
+                },
+            ]
+        );
+        assert!(name.hint.as_deref().unwrap().contains("'string'"));
+
+        // `number` e `integer` son el mismo tipo: solo choca la opcionalidad
+        let age = conflicts[1];
+        assert!(age
+            .message
+            .contains("en si es opcional del argumento 'age'"));
+        assert_eq!(
+            age.related[1].message,
+            "'Guía': tipo 'integer', obligatorio"
+        );
+        assert!(
+            format!("{}", age).contains("· docs/api.md:5: 'Referencia': tipo 'number', opcional")
+        );
+    }
+
+    #[test]
+    fn sections_differing_only_in_descriptions_do_not_conflict() {
+        let entity = make_entity_with_args(
+            "createUser",
+            "create-user",
+            vec![arg("name", Some("string")), arg("age", Some("number"))],
+        );
+        let sections = reference_and_guide(
+            "- `name` (str): Cómo se muestra el usuario\n- `age`: Opcional. Años cumplidos\n",
+        );
+        let results = validate_links(&[entity], &sections, &Default::default());
+        assert!(
+            !results.iter().any(|r| r.rule == Rule::ConflictingArgDocs),
+            "{:#?}",
+            results
+        );
+    }
+
+    #[test]
+    fn implementations_are_validated_against_the_trait_or_interface_section() {
+        let sections = crate::parser::doc_parser::parse_markdown_source(
+            include_str!("../../fixtures/storage.md"),
+            &PathBuf::from("fixtures/storage.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let options = crate::parser::code_parser::AnnotationOptions::default();
+        let mut entities = crate::parser::lang::rust::parse_rust_source(
+            include_str!("../../fixtures/storage.rs"),
+            &PathBuf::from("fixtures/storage.rs"),
+            options,
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        entities.extend(
+            crate::parser::lang::typescript::parse_typescript_source(
+                include_str!("../../fixtures/storage.ts"),
+                &PathBuf::from("fixtures/storage.ts"),
+                options,
+                &mut ParseDiagnostics::default(),
+            )
+            .unwrap(),
+        );
+
+        let results = validate_links(&entities, &sections, &Default::default());
+        let verified: Vec<_> = results
+            .iter()
+            .filter(|r| r.rule == Rule::LinkVerified)
+            .filter_map(|r| r.code_location.as_deref())
+            .collect();
+        assert_eq!(
+            verified,
+            [
+                "fixtures/storage.rs:11",
+                "fixtures/storage.rs:20",
+                "fixtures/storage.ts:7",
+                "fixtures/storage.ts:16"
+            ]
+        );
+        // Solo la implementación que derivó, en su propia ubicación
+        let drift: Vec<_> = results
+            .iter()
+            .filter(|r| matches!(r.rule, Rule::GhostArg | Rule::MissingArg))
+            .map(|r| (r.rule, r.code_location.as_deref().unwrap()))
+            .collect();
+        assert_eq!(
+            drift,
+            [
+                (Rule::GhostArg, "fixtures/storage.rs:20"),
+                (Rule::MissingArg, "fixtures/storage.rs:20"),
+                (Rule::MissingArg, "fixtures/storage.rs:20"),
+                (Rule::GhostArg, "fixtures/storage.ts:16"),
+                (Rule::MissingArg, "fixtures/storage.ts:16"),
+                (Rule::MissingArg, "fixtures/storage.ts:16"),
+            ]
+        );
+        assert!(!results.iter().any(|r| r.rule == Rule::OrphanSection));
+    }
+
+    #[test]
+    fn prose_only_sections_validate_the_link_but_not_the_args() {
+        let entity = make_entity_with_args(
+            "dispatch",
+            "payments-overview",
+            vec![arg("kind", Some("string")), arg("payload", Some("object"))],
+        );
+        let prose = DocSection {
+            skips: vec!["args".into()],
+            ..make_section_with_args(
+                "payments-overview",
+                "Pagos",
+                vec![arg("kind", Some("number"))],
+            )
+        };
+        let results = validate_links(
+            std::slice::from_ref(&entity),
+            std::slice::from_ref(&prose),
+            &Default::default(),
+        );
+        assert!(results.iter().any(|r| r.rule == Rule::LinkVerified));
+        assert!(
+            results.iter().all(|r| !matches!(
+                r.rule,
+                Rule::MissingArg | Rule::GhostArg | Rule::TypeMismatch
+            )),
+            "{:#?}",
+            results
+        );
+        assert_eq!(
+            count_prose_only_links(std::slice::from_ref(&entity), std::slice::from_ref(&prose)),
+            1
+        );
+
+        // Junto a una sección de referencia, la prose-only no entra en conflicto
+        let mut sections = reference_and_guide("- `name` (number): El nombre\n");
+        sections[1].skips = vec!["args".into()];
+        let entity = make_entity_with_args(
+            "createUser",
+            "create-user",
+            vec![arg("name", Some("string")), arg("age", Some("number"))],
+        );
+        let results = validate_links(
+            std::slice::from_ref(&entity),
+            &sections,
+            &Default::default(),
+        );
+        assert!(
+            results.iter().all(|r| r.rule != Rule::ConflictingArgDocs),
+            "{:#?}",
+            results
+        );
+        assert_eq!(count_prose_only_links(&[entity], &sections), 1);
+    }
+
+    #[test]
+    fn truncated_sections_get_one_arity_summary_and_real_tables_are_validated() {
+        use crate::config::LimitsConfig;
+        use crate::core::diagnostics::ParseDiagnostics;
+        use crate::parser::doc_parser::{apply_limits, parse_markdown_source};
+
+        // Un changelog generado bajo un `@docs-id` perdido
+        let mut changelog = String::from("<!-- @docs-id: release -->\n## Release\n\n");
+        for i in 0..12_000 {
+            changelog.push_str(&format!("- change{}: Cambio {}\n", i, i));
+        }
+        // Una tabla grande pero real
+        changelog.push_str("\n<!-- @docs-id: configure -->\n## Configure\n\n| Param | Type | Description |\n|---|---|---|\n");
+        for i in 0..50 {
+            let type_name = if i == 7 { "number" } else { "string" };
+            changelog.push_str(&format!("| opt{} | {} | Opción {} |\n", i, type_name, i));
+        }
+        let mut diagnostics = ParseDiagnostics::default();
+        let mut sections = parse_markdown_source(
+            &changelog,
+            std::path::Path::new("CHANGELOG.md"),
+            &mut diagnostics,
+        )
+        .unwrap();
+        apply_limits(&mut sections, &LimitsConfig::default(), &mut diagnostics);
+        assert_eq!(sections[0].args.len(), 200);
+        assert_eq!(sections[0].dropped_args, 11_800);
+        assert_eq!(sections[1].args.len(), 50);
+
+        let release = make_entity_with_args(
+            "release",
+            "release",
+            vec![arg("version", Some("string")), arg("notes", Some("string"))],
+        );
+        let configure = make_entity_with_args(
+            "configure",
+            "configure",
+            (0..51)
+                .map(|i| arg(&format!("opt{}", i), Some("string")))
+                .collect(),
+        );
+        let mut results = validate_links(&[release, configure], &sections, &Default::default());
+        results.extend(validate_parse_notes(&[], &diagnostics));
+
+        let release: Vec<_> = results
+            .iter()
+            .filter(|r| r.doc_id.as_deref() == Some("release"))
+            .map(|r| (r.rule, r.severity))
+            .collect();
+        assert_eq!(
+            release,
+            [
+                (Rule::LinkVerified, Severity::Info),
+                (Rule::GhostArg, Severity::Error),
+                (Rule::TruncatedDocs, Severity::Warning),
+            ]
+        );
+        assert!(results.iter().any(|r| r
+            .message
+            .contains("documenta 12.000 argumentos y fn release tiene 2")));
+
+        let configure: Vec<_> = results
+            .iter()
+            .filter(|r| r.doc_id.as_deref() == Some("configure"))
+            .filter(|r| r.rule != Rule::LinkVerified)
+            .map(|r| r.rule)
+            .collect();
+        assert_eq!(configure, [Rule::TypeMismatch, Rule::MissingArg]);
+    }
+
+    fn sdk_entity(name: &str, file: &str, args: &[(&str, &str)], ret: &str) -> CodeEntity {
+        CodeEntity {
+            file_path: PathBuf::from(file),
+            return_type: Some(ret.into()),
+            line: 3,
+            end_line: 3,
+            ..make_entity_with_args(
+                name,
+                "user-create",
+                args.iter().map(|(n, t)| arg(n, Some(t))).collect(),
+            )
+        }
+    }
+
+    #[test]
+    fn implementations_in_two_languages_with_the_same_signature_agree() {
+        let entities = [
+            sdk_entity(
+                "createUser",
+                "sdk-ts/users.ts",
+                &[("email", "string"), ("tenantId", "string")],
+                "User",
+            ),
+            sdk_entity(
+                "create_user",
+                "sdk-rs/users.rs",
+                &[("email", "String"), ("tenant_id", "&str")],
+                "User",
+            ),
+            // Otra función del mismo lenguaje no se compara
+            sdk_entity("createUserLegacy", "sdk-ts/legacy.ts", &[], "void"),
+        ];
+        assert!(validate_cross_language(&entities).is_empty());
+    }
+
+    #[test]
+    fn an_argument_only_one_sdk_has_is_reported_on_both_locations() {
+        let entities = [
+            sdk_entity(
+                "createUser",
+                "sdk-ts/users.ts",
+                &[("email", "string"), ("tenantId", "string")],
+                "User",
+            ),
+            sdk_entity(
+                "create_user",
+                "sdk-rs/users.rs",
+                &[("email", "String")],
+                "User",
+            ),
+        ];
+        let results = validate_cross_language(&entities);
+        assert_eq!(results.len(), 1);
+        let drift = &results[0];
+        assert_eq!(drift.rule, Rule::CrossLanguageDrift);
+        assert_eq!(drift.severity, Severity::Warning);
+        assert_eq!(
+            drift.message,
+            "Las implementaciones de 'user-create' en TypeScript y Rust no coinciden: 'tenantId' solo en TypeScript."
+        );
+        assert_eq!(drift.code_location.as_deref(), Some("sdk-ts/users.ts:3"));
+        let related: Vec<(&str, &str)> = drift
+            .related
+            .iter()
+            .map(|r| (r.location.as_str(), r.message.as_str()))
+            .collect();
+        assert_eq!(
+            related,
+            [
+                (
+                    "sdk-ts/users.ts:3",
+                    "TypeScript: createUser(email: string, tenantId: string): User"
+                ),
+                (
+                    "sdk-rs/users.rs:3",
+                    "Rust: create_user(email: String): User"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn diverging_return_and_argument_types_are_listed() {
+        let entities = [
+            sdk_entity(
+                "createUser",
+                "sdk-ts/users.ts",
+                &[("id", "number")],
+                "string",
+            ),
+            sdk_entity("create_user", "sdk-rs/users.rs", &[("id", "u64")], "bool"),
+            sdk_entity("create_user", "sdk-py/users.py", &[("id", "str")], "bool"),
+        ];
+        let results = validate_cross_language(&entities);
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].message,
+            "Las implementaciones de 'user-create' en TypeScript, Rust y Python no coinciden: \
+             'id': TypeScript number, Rust u64, Python str; \
+             retorno: TypeScript string, Rust bool, Python bool."
+        );
+        assert_eq!(results[0].related.len(), 3);
+    }
+
+    #[test]
+    fn an_id_repeated_across_doc_files_is_an_error_naming_both() {
+        let in_file = |id: &str, file: &str, line: usize| DocSection {
+            file_path: PathBuf::from(file),
+            line,
+            ..make_section(id, None)
+        };
+        let sections = [
+            in_file("auth-login", "docs/auth.md", 3),
+            // Referencia y guía en el mismo archivo: no es un duplicado
+            in_file("auth-login", "docs/auth.md", 40),
+            in_file("users-get", "docs/users.md", 1),
+            in_file("auth-login", "docs/users.md", 12),
+            in_file("auth-login", "docs/users.md", 30),
+        ];
+        let results = validate_duplicate_ids(&sections);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].rule, Rule::DuplicateDocId);
+        assert_eq!(
+            results[0].message,
+            "ID de documentación 'auth-login' definido en dos archivos: \
+             docs/auth.md:3 y docs/users.md:12."
+        );
+        assert_eq!(results[0].related[0].location, "docs/auth.md:3");
+
+        let entities = [make_entity("login", Some("auth-login"))];
+        let orphan = validate_links(&entities, &sections, &Default::default())
+            .into_iter()
+            .find(|r| r.rule == Rule::OrphanSection)
+            .unwrap();
+        assert_eq!(
+            orphan.message,
+            "Sección de documentación 'users-get' de docs/users.md no está vinculada desde ninguna función."
+        );
+    }
 }
@@ -0,0 +1,206 @@
+//! Versión actual del proyecto para `check --check-versions`.
+//!
+//! La fuente se declara en `versions:` de `.docsguard/config.yaml`: el campo
+//! `version` de un `package.json` o `Cargo.toml`, o una lista estática de
+//! versiones publicadas. Las versiones de las docs se escriben sin rigor
+//! (`v2.3`, `2`), así que se completan a `MAJOR.MINOR.PATCH` antes de parsearlas.
+
+use anyhow::{Context, Result};
+use semver::Version;
+use std::path::Path;
+
+use crate::config::VersionsConfig;
+use crate::exit::Failure;
+use crate::parser::code_parser::safe_display;
+use crate::vfs;
+
+/// Versiones contra las que se comparan las menciones de las docs.
+#[derive(Debug, Clone)]
+pub struct ProjectVersions {
+    /// Versión actual, tal cual aparece en la fuente (para los mensajes).
+    pub current_label: String,
+    pub current: Version,
+    /// Versiones publicadas (`versions.known`); vacío si no se declararon.
+    pub known: Vec<Version>,
+}
+
+impl ProjectVersions {
+    pub fn load(config: &VersionsConfig, project_root: &Path) -> Result<Self> {
+        let mut known = Vec::with_capacity(config.known.len());
+        for label in &config.known {
+            known.push(parse_version(label).ok_or_else(|| {
+                Failure::config(format!(
+                    "Versión no válida en `versions.known` de la configuración: '{}'",
+                    label
+                ))
+            })?);
+        }
+
+        let (current_label, current) = match &config.manifest {
+            Some(manifest) => {
+                let path = project_root.join(manifest);
+                let label = manifest_version(&path)?;
+                let version = parse_version(&label).ok_or_else(|| {
+                    Failure::config(format!(
+                        "La versión '{}' de {} no es semver válido.",
+                        label,
+                        safe_display(&path)
+                    ))
+                    .with_path(&path)
+                })?;
+                (label, version)
+            }
+            None => {
+                let Some((label, version)) =
+                    config.known.iter().zip(&known).max_by_key(|(_, v)| *v)
+                else {
+                    anyhow::bail!(Failure::config(
+                        "--check-versions necesita `versions:` en .docsguard/config.yaml"
+                    )
+                    .with_hint(
+                        "Indica `manifest: package.json` (o `Cargo.toml`) o una lista `known`."
+                    ));
+                };
+                (label.clone(), version.clone())
+            }
+        };
+
+        Ok(ProjectVersions {
+            current_label,
+            current,
+            known,
+        })
+    }
+}
+
+/// Parsea una versión escrita a mano: admite `v` inicial y completa los
+/// componentes que falten (`v2.3` → `2.3.0`). `None` si no es semver.
+pub fn parse_version(text: &str) -> Option<Version> {
+    let text = text.trim();
+    let text = text.strip_prefix(['v', 'V']).unwrap_or(text);
+    let core_end = text.find(['-', '+']).unwrap_or(text.len());
+    let (core, suffix) = text.split_at(core_end);
+    let padded = match core.split('.').count() {
+        1 => format!("{core}.0.0{suffix}"),
+        2 => format!("{core}.0{suffix}"),
+        _ => text.to_string(),
+    };
+    Version::parse(&padded).ok()
+}
+
+/// Campo `version` de un `package.json` o de `[package]` en un `Cargo.toml`.
+fn manifest_version(path: &Path) -> Result<String> {
+    let content = vfs::read_to_string(path)
+        .with_context(|| format!("No se pudo leer el manifiesto: {}", safe_display(path)))?;
+    let file_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default();
+    let version = match file_name {
+        "package.json" => {
+            serde_json::from_str::<serde_json::Value>(&content).with_context(|| {
+                Failure::parse(format!("JSON no válido: {}", safe_display(path))).with_path(path)
+            })?["version"]
+                .as_str()
+                .map(String::from)
+        }
+        "Cargo.toml" => cargo_package_version(&content),
+        _ => anyhow::bail!(Failure::config(format!(
+            "Manifiesto no soportado en `versions.manifest`: {} (usa package.json o Cargo.toml)",
+            safe_display(path)
+        ))
+        .with_path(path)),
+    };
+    version.ok_or_else(|| {
+        Failure::config(format!(
+            "{} no tiene un campo `version` de texto.",
+            safe_display(path)
+        ))
+        .with_path(path)
+        .into()
+    })
+}
+
+/// `version = "x"` dentro de `[package]`. `version.workspace = true` no cuenta.
+fn cargo_package_version(content: &str) -> Option<String> {
+    let mut in_package = false;
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_package = line == "[package]";
+        } else if let Some(value) = line
+            .strip_prefix("version")
+            .map(str::trim_start)
+            .and_then(|rest| rest.strip_prefix('='))
+            .filter(|_| in_package)
+        {
+            let value = value.trim();
+            return value
+                .strip_prefix('"')
+                .and_then(|v| v.split_once('"'))
+                .map(|(version, _)| version.to_string());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::{MemFs, MemProject};
+
+    fn manifest(file: &str, content: &str) -> (MemProject, VersionsConfig) {
+        let dir = MemFs::project();
+        dir.write(dir.path().join(file), content);
+        let config = VersionsConfig {
+            manifest: Some(file.into()),
+            known: vec![],
+        };
+        (dir, config)
+    }
+
+    #[test]
+    fn current_version_comes_from_package_json() {
+        let (dir, config) = manifest("package.json", r#"{"name": "web", "version": "2.7.1"}"#);
+        let versions = ProjectVersions::load(&config, dir.path()).unwrap();
+        assert_eq!(versions.current, Version::new(2, 7, 1));
+        assert_eq!(versions.current_label, "2.7.1");
+    }
+
+    #[test]
+    fn current_version_comes_from_the_cargo_package_table() {
+        let (dir, config) = manifest(
+            "Cargo.toml",
+            "[workspace]\nversion = \"9.0.0\"\n\n[package]\nname = \"docsguard\"\nversion = \"0.2.0\" # release\n",
+        );
+        let versions = ProjectVersions::load(&config, dir.path()).unwrap();
+        assert_eq!(versions.current, Version::new(0, 2, 0));
+
+        let (dir, config) = manifest("Cargo.toml", "[package]\nversion.workspace = true\n");
+        let error = ProjectVersions::load(&config, dir.path()).unwrap_err();
+        assert_eq!(crate::exit::exit_code_for(&error), crate::exit::USAGE);
+    }
+
+    #[test]
+    fn static_list_uses_its_highest_version() {
+        let config = VersionsConfig {
+            manifest: None,
+            known: vec!["v2.0".into(), "2.10".into(), "2.3".into()],
+        };
+        let versions = ProjectVersions::load(&config, Path::new(".")).unwrap();
+        assert_eq!(versions.current_label, "2.10");
+        assert_eq!(versions.known.len(), 3);
+        assert!(ProjectVersions::load(&VersionsConfig::default(), Path::new(".")).is_err());
+    }
+
+    #[test]
+    fn handwritten_versions_are_padded() {
+        assert_eq!(parse_version("v2.3"), Some(Version::new(2, 3, 0)));
+        assert_eq!(parse_version("3"), Some(Version::new(3, 0, 0)));
+        assert_eq!(
+            parse_version("1.4-beta.1"),
+            Some(Version::parse("1.4.0-beta.1").unwrap())
+        );
+        assert_eq!(parse_version("2.x"), None);
+        assert_eq!(parse_version("1.2.3.4"), None);
+    }
+}
@@ -0,0 +1,352 @@
+//! Diferencia de cobertura entre dos ejecuciones (`coverage --compare-to`).
+//!
+//! CI guarda en la rama principal la instantánea de `coverage --json` y cada
+//! PR la compara con la suya: cobertura del API público (la de `coverage`),
+//! de todas las funciones y por espacio de nombres (directorio). La
+//! comparación es pura sobre dos `CoverageSnapshot`.
+//!
+//! Las métricas que la instantánea antigua no tiene (generada por una versión
+//! con un `schema_version` anterior) se muestran como `n/a` en lugar de
+//! fallar; un espacio de nombres que solo existe en una de las dos, también.
+//! Solo se rechaza una instantánea de un esquema más nuevo que este binario.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Versión del formato de `coverage --json`. Súbela al añadir una métrica.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Funciones cubiertas sobre el total.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ratio {
+    pub covered: usize,
+    pub total: usize,
+}
+
+impl Ratio {
+    /// Porcentaje cubierto; 100 si no hay funciones, como `CoverageReport`.
+    pub fn percentage(&self) -> f64 {
+        if self.total == 0 {
+            return 100.0;
+        }
+        self.covered as f64 / self.total as f64 * 100.0
+    }
+}
+
+/// Cobertura de una ejecución, tal como la escribe `coverage --json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoverageSnapshot {
+    pub schema_version: u32,
+    /// Funciones públicas con `@docs` o `@docs-file`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_api: Option<Ratio>,
+    /// Todas las funciones enlazadas, públicas o no.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub functions: Option<Ratio>,
+    /// API público por directorio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespaces: Option<BTreeMap<String, Ratio>>,
+}
+
+impl CoverageSnapshot {
+    /// Lee una instantánea; el error es el mensaje para el usuario.
+    pub fn parse(json: &str) -> Result<CoverageSnapshot, String> {
+        let snapshot: CoverageSnapshot = serde_json::from_str(json)
+            .map_err(|e| format!("no es una cobertura de `coverage --json` ({})", e))?;
+        if snapshot.schema_version > SCHEMA_VERSION {
+            return Err(format!(
+                "esquema {} de una versión más nueva de docsguard (esta lee hasta el {})",
+                snapshot.schema_version, SCHEMA_VERSION
+            ));
+        }
+        Ok(snapshot)
+    }
+}
+
+/// Qué mide cada fila de la diferencia.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "name", rename_all = "kebab-case")]
+pub enum MetricKind {
+    PublicApi,
+    Functions,
+    Namespace(String),
+}
+
+impl MetricKind {
+    pub fn label(&self) -> String {
+        match self {
+            MetricKind::PublicApi => "API público".to_string(),
+            MetricKind::Functions => "Todas las funciones".to_string(),
+            MetricKind::Namespace(name) => name.clone(),
+        }
+    }
+}
+
+/// Una métrica antes y ahora, en porcentaje; `None` es `n/a`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetricDelta {
+    pub metric: MetricKind,
+    pub before: Option<f64>,
+    pub after: Option<f64>,
+    /// Puntos porcentuales ganados (negativo si baja), con una cifra decimal.
+    pub delta: Option<f64>,
+}
+
+/// Resultado de `compare`: primero el API público, luego todas las
+/// funciones y, en orden, los espacios de nombres de las dos ejecuciones.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CoverageDelta {
+    pub metrics: Vec<MetricDelta>,
+}
+
+impl CoverageDelta {
+    /// La cobertura del API público, la del titular.
+    pub fn headline(&self) -> &MetricDelta {
+        &self.metrics[0]
+    }
+
+    fn namespaces(&self) -> impl Iterator<Item = &MetricDelta> {
+        self.metrics
+            .iter()
+            .filter(|m| matches!(m.metric, MetricKind::Namespace(_)))
+    }
+}
+
+/// Compara la cobertura de `before` (la guardada) con la de `after`.
+pub fn compare(before: &CoverageSnapshot, after: &CoverageSnapshot) -> CoverageDelta {
+    let metric = |metric: MetricKind, before: Option<&Ratio>, after: Option<&Ratio>| {
+        let before = before.map(|r| round(r.percentage()));
+        let after = after.map(|r| round(r.percentage()));
+        MetricDelta {
+            metric,
+            before,
+            after,
+            delta: before.zip(after).map(|(b, a)| round(a - b)),
+        }
+    };
+    let mut metrics = vec![
+        metric(
+            MetricKind::PublicApi,
+            before.public_api.as_ref(),
+            after.public_api.as_ref(),
+        ),
+        metric(
+            MetricKind::Functions,
+            before.functions.as_ref(),
+            after.functions.as_ref(),
+        ),
+    ];
+    let empty = BTreeMap::new();
+    let old = before.namespaces.as_ref().unwrap_or(&empty);
+    let new = after.namespaces.as_ref().unwrap_or(&empty);
+    let mut names: Vec<&String> = old.keys().chain(new.keys()).collect();
+    names.sort();
+    names.dedup();
+    for name in names {
+        metrics.push(metric(
+            MetricKind::Namespace(name.clone()),
+            old.get(name),
+            new.get(name),
+        ));
+    }
+    CoverageDelta { metrics }
+}
+
+/// Redondeo a una cifra decimal, la que se muestra.
+fn round(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+fn percentage(value: Option<f64>) -> String {
+    value.map_or_else(|| "n/a".to_string(), |v| format!("{:.1}%", v))
+}
+
+fn signed(delta: Option<f64>) -> String {
+    match delta {
+        None => "n/a".to_string(),
+        Some(d) if d > 0.0 => format!("+{:.1}", d),
+        // Sin `-0.0`
+        Some(0.0) => "±0.0".to_string(),
+        Some(d) => format!("{:.1}", d),
+    }
+}
+
+/// `71.2% → 72.0% (+0.8)`.
+fn change(metric: &MetricDelta) -> String {
+    format!(
+        "{} → {} ({})",
+        percentage(metric.before),
+        percentage(metric.after),
+        signed(metric.delta)
+    )
+}
+
+/// Bloque de texto para el terminal, tras el informe de cobertura.
+pub fn render_text(delta: &CoverageDelta) -> String {
+    let mut out = format!(
+        "  Cobertura de documentación: {}\n",
+        change(delta.headline())
+    );
+    for metric in delta.metrics.iter().skip(1) {
+        out.push_str(&format!(
+            "    {:<28} {}\n",
+            metric.metric.label(),
+            change(metric)
+        ));
+    }
+    out.push('\n');
+    out
+}
+
+/// Fragmento Markdown para el comentario de la PR: el titular y, plegadas,
+/// las métricas con cambios (los espacios de nombres sin cambios se omiten).
+pub fn render_markdown(delta: &CoverageDelta) -> String {
+    let mut out = format!(
+        "**Cobertura de documentación:** {}\n",
+        change(delta.headline())
+    );
+    let rows: Vec<&MetricDelta> = delta
+        .metrics
+        .iter()
+        .skip(1)
+        .filter(|m| !matches!(m.metric, MetricKind::Namespace(_)) || m.delta != Some(0.0))
+        .collect();
+    if rows.is_empty() {
+        return out;
+    }
+    let unchanged = delta.namespaces().filter(|m| m.delta == Some(0.0)).count();
+    out.push_str("\n<details><summary>Detalle</summary>\n\n");
+    out.push_str("| Métrica | Antes | Ahora | Δ |\n|---|---:|---:|---:|\n");
+    for metric in rows {
+        let label = match &metric.metric {
+            MetricKind::Namespace(name) => format!("`{}`", name),
+            other => other.label(),
+        };
+        out.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            label,
+            percentage(metric.before),
+            percentage(metric.after),
+            signed(metric.delta)
+        ));
+    }
+    if unchanged > 0 {
+        out.push_str(&format!(
+            "\n_{} sin cambios._\n",
+            crate::messages::NAMESPACES.count(unchanged)
+        ));
+    }
+    out.push_str("\n</details>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ratio(covered: usize, total: usize) -> Ratio {
+        Ratio { covered, total }
+    }
+
+    fn snapshot(public: Ratio, functions: Ratio, namespaces: &[(&str, Ratio)]) -> CoverageSnapshot {
+        CoverageSnapshot {
+            schema_version: SCHEMA_VERSION,
+            public_api: Some(public),
+            functions: Some(functions),
+            namespaces: Some(
+                namespaces
+                    .iter()
+                    .map(|(name, r)| (name.to_string(), *r))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn row<'a>(delta: &'a CoverageDelta, name: &str) -> &'a MetricDelta {
+        delta
+            .metrics
+            .iter()
+            .find(|m| m.metric == MetricKind::Namespace(name.into()))
+            .unwrap()
+    }
+
+    #[test]
+    fn improvements_and_regressions_are_signed_per_metric() {
+        let main = snapshot(
+            ratio(712, 1000),
+            ratio(50, 100),
+            &[("src/auth", ratio(8, 10)), ("src/billing", ratio(5, 10))],
+        );
+        let pr = snapshot(
+            ratio(720, 1000),
+            ratio(48, 100),
+            &[("src/auth", ratio(8, 10)), ("src/billing", ratio(6, 10))],
+        );
+        let delta = compare(&main, &pr);
+        assert_eq!(change(delta.headline()), "71.2% → 72.0% (+0.8)");
+        assert_eq!(delta.metrics[1].delta, Some(-2.0));
+        assert_eq!(row(&delta, "src/billing").delta, Some(10.0));
+        assert_eq!(
+            render_text(&delta),
+            "  Cobertura de documentación: 71.2% → 72.0% (+0.8)\n\
+             \x20   Todas las funciones          50.0% → 48.0% (-2.0)\n\
+             \x20   src/auth                     80.0% → 80.0% (±0.0)\n\
+             \x20   src/billing                  50.0% → 60.0% (+10.0)\n\n"
+        );
+        assert_eq!(
+            render_markdown(&delta),
+            "**Cobertura de documentación:** 71.2% → 72.0% (+0.8)\n\
+             \n<details><summary>Detalle</summary>\n\n\
+             | Métrica | Antes | Ahora | Δ |\n|---|---:|---:|---:|\n\
+             | Todas las funciones | 50.0% | 48.0% | -2.0 |\n\
+             | `src/billing` | 50.0% | 60.0% | +10.0 |\n\
+             \n_1 espacio de nombres sin cambios._\n\
+             \n</details>\n"
+        );
+    }
+
+    #[test]
+    fn renamed_namespaces_show_as_removed_and_added() {
+        let main = snapshot(ratio(3, 4), ratio(3, 4), &[("src/auth", ratio(3, 4))]);
+        let pr = snapshot(ratio(3, 4), ratio(3, 4), &[("src/identity", ratio(3, 4))]);
+        let delta = compare(&main, &pr);
+        let auth = row(&delta, "src/auth");
+        assert_eq!(
+            (auth.before, auth.after, auth.delta),
+            (Some(75.0), None, None)
+        );
+        let identity = row(&delta, "src/identity");
+        assert_eq!(
+            (identity.before, identity.after, identity.delta),
+            (None, Some(75.0), None)
+        );
+        assert!(render_markdown(&delta).contains("| `src/auth` | 75.0% | n/a | n/a |"));
+        assert_eq!(delta.headline().delta, Some(0.0));
+    }
+
+    #[test]
+    fn metrics_missing_from_an_older_schema_are_not_available() {
+        let old = CoverageSnapshot::parse(
+            r#"{"schema_version": 1, "public_api": {"covered": 1, "total": 2}}"#,
+        )
+        .unwrap();
+        let pr = snapshot(ratio(2, 2), ratio(2, 3), &[("src", ratio(2, 2))]);
+        let delta = compare(&old, &pr);
+        assert_eq!(change(delta.headline()), "50.0% → 100.0% (+50.0)");
+        assert_eq!(change(&delta.metrics[1]), "n/a → 66.7% (n/a)");
+        assert_eq!(change(row(&delta, "src")), "n/a → 100.0% (n/a)");
+
+        let json = serde_json::to_value(&delta).unwrap();
+        assert_eq!(json["metrics"][1]["metric"]["kind"], "functions");
+        assert!(json["metrics"][1]["before"].is_null());
+        assert_eq!(json["metrics"][2]["metric"]["name"], "src");
+
+        assert!(CoverageSnapshot::parse(r#"{"schema_version": 99}"#)
+            .unwrap_err()
+            .contains("esquema 99"));
+        assert!(CoverageSnapshot::parse("[]").is_err());
+        // La instantánea de `--json` se vuelve a leer igual
+        let text = serde_json::to_string(&pr).unwrap();
+        assert_eq!(CoverageSnapshot::parse(&text).unwrap(), pr);
+    }
+}
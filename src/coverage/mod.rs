@@ -0,0 +1,528 @@
+//! Módulo de Document Coverage Report.
+//!
+//! Analiza qué porcentaje de las funciones/métodos públicos exportados
+//! tiene una anotación `@docs` vinculada a su sección de documentación.
+//! Las funciones cubiertas solo por un `@docs-file` se cuentan aparte
+//! (cobertura a nivel de archivo). Con `--docs`, también las documentadas
+//! que enlazan con una sección prose-only (`@docs-skip: args`): cuentan como
+//! cubiertas, pero el recuento queda a la vista.
+//!
+//! `--json` emite la instantánea de `delta` que se guarda en CI, y
+//! `--compare-to` compara la cobertura actual con una guardada.
+
+pub mod delta;
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::validator;
+use crate::exit::{Failure, Outcome};
+use crate::layout::{Layout, Style};
+use crate::messages::{print_tr, tr};
+use crate::parser::{code_parser, doc_parser};
+use crate::vfs;
+
+// ── Barra ─────────────────────────────────────────────────────────────────────
+
+const BAR_WIDTH: usize = 24;
+const BAR_FILLED: char = '█';
+const BAR_EMPTY: char = '░';
+
+// ── Tipos ──────────────────────────────────────────────────────────────────────
+
+/// Resultado de cobertura de un único archivo.
+pub struct FileCoverage {
+    pub file: PathBuf,
+    pub total_public: usize,
+    /// Funciones con su propio `@docs`.
+    pub documented: usize,
+    /// Funciones sin `@docs` propio cubiertas por el `@docs-file` del archivo.
+    pub file_level: usize,
+    /// ID del enlace de archivo, si existe.
+    pub file_doc_id: Option<String>,
+}
+
+impl FileCoverage {
+    pub fn percentage(&self) -> f64 {
+        if self.total_public == 0 {
+            return 100.0;
+        }
+        ((self.documented + self.file_level) as f64 / self.total_public as f64) * 100.0
+    }
+}
+
+/// Resultado agregado de todos los archivos analizados.
+pub struct CoverageReport {
+    pub files: Vec<FileCoverage>,
+    pub total_public: usize,
+    pub total_documented: usize,
+    pub total_file_level: usize,
+    /// Todas las funciones, públicas o no.
+    pub total_functions: usize,
+    /// Funciones con `@docs` propio o `@docs-file`, públicas o no.
+    pub total_linked: usize,
+    /// Documentadas que enlazan con secciones prose-only (solo con `--docs`).
+    pub total_prose_only: Option<usize>,
+}
+
+impl CoverageReport {
+    pub fn percentage(&self) -> f64 {
+        if self.total_public == 0 {
+            return 100.0;
+        }
+        ((self.total_documented + self.total_file_level) as f64 / self.total_public as f64) * 100.0
+    }
+
+    /// Instantánea serializable para `--json` y `--compare-to`. El espacio
+    /// de nombres de un archivo es su directorio.
+    pub fn snapshot(&self) -> delta::CoverageSnapshot {
+        let mut namespaces: BTreeMap<String, delta::Ratio> = BTreeMap::new();
+        for fc in &self.files {
+            let namespace = Path::new(&display_path(&fc.file))
+                .parent()
+                .map(|dir| dir.to_string_lossy().replace('\\', "/"))
+                .filter(|dir| !dir.is_empty())
+                .unwrap_or_else(|| ".".to_string());
+            let ratio = namespaces.entry(namespace).or_default();
+            ratio.covered += fc.documented + fc.file_level;
+            ratio.total += fc.total_public;
+        }
+        delta::CoverageSnapshot {
+            schema_version: delta::SCHEMA_VERSION,
+            public_api: Some(delta::Ratio {
+                covered: self.total_documented + self.total_file_level,
+                total: self.total_public,
+            }),
+            functions: Some(delta::Ratio {
+                covered: self.total_linked,
+                total: self.total_functions,
+            }),
+            namespaces: Some(namespaces),
+        }
+    }
+}
+
+/// Salida de `coverage`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverageOutput {
+    Text,
+    /// `--json`.
+    Json,
+    /// `--markdown` (solo con `--compare-to`).
+    Markdown,
+}
+
+// ── Lógica principal ───────────────────────────────────────────────────────────
+
+/// Ejecuta el análisis de cobertura de documentación.
+///
+/// `Outcome::Findings` si la cobertura total está por debajo de `min_coverage`.
+/// Con `compare_to`, muestra además la diferencia con la cobertura guardada.
+pub fn run_coverage(
+    code_files: &[PathBuf],
+    doc_file: Option<&Path>,
+    min_coverage: u8,
+    compare_to: Option<&Path>,
+    output: CoverageOutput,
+    layout: &Layout,
+) -> Result<Outcome> {
+    // Refactorizado: usa require_file_exists para eliminar comprobaciones duplicadas entre comandos
+    for file in code_files {
+        code_parser::require_file_exists(file, "código")?;
+    }
+    if let Some(doc_file) = doc_file {
+        code_parser::require_file_exists(doc_file, "documentación")?;
+    }
+
+    let previous = compare_to.map(load_snapshot).transpose()?;
+    let report = build_report(code_files, doc_file)?;
+    let snapshot = report.snapshot();
+    match (&previous, output) {
+        (None, CoverageOutput::Json) => println!(
+            "{}",
+            serde_json::to_string_pretty(&snapshot).context("Error al serializar la cobertura")?
+        ),
+        (None, _) => print_report(&report, min_coverage, layout),
+        (Some(previous), output) => {
+            let delta = delta::compare(previous, &snapshot);
+            match output {
+                CoverageOutput::Text => {
+                    print_report(&report, min_coverage, layout);
+                    print_tr!("{}", delta::render_text(&delta));
+                }
+                CoverageOutput::Json => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&delta)
+                        .context("Error al serializar la diferencia de cobertura")?
+                ),
+                CoverageOutput::Markdown => print_tr!("{}", delta::render_markdown(&delta)),
+            }
+        }
+    }
+
+    Ok(Outcome::failed_if(
+        report.percentage() < f64::from(min_coverage),
+    ))
+}
+
+/// Instantánea guardada por `coverage --json`.
+fn load_snapshot(path: &Path) -> Result<delta::CoverageSnapshot> {
+    code_parser::require_file_exists(path, "cobertura")?;
+    let content = vfs::read_to_string(path)
+        .with_context(|| format!("No se pudo leer: {}", path.display()))?;
+    delta::CoverageSnapshot::parse(&content).map_err(|message| {
+        Failure::input(format!("{}: {}", code_parser::safe_display(path), message))
+            .with_path(path)
+            .with_hint("Genera el archivo con `docsguard coverage --json`.")
+            .into()
+    })
+}
+
+/// Cobertura de `code_files`; con `doc_file`, también los enlaces prose-only.
+pub fn build_report(code_files: &[PathBuf], doc_file: Option<&Path>) -> Result<CoverageReport> {
+    let mut file_coverages = Vec::new();
+    let mut total_public = 0;
+    let mut total_documented = 0;
+    let mut total_file_level = 0;
+    let mut total_functions = 0;
+    let mut total_linked = 0;
+    let doc_sections = doc_file
+        .map(|doc_file| {
+            doc_parser::parse_markdown_file(doc_file, &mut ParseDiagnostics::default())
+                .context("Error al parsear el archivo de documentación")
+        })
+        .transpose()?;
+    let mut total_prose_only = doc_sections.as_ref().map(|_| 0);
+
+    for file in code_files {
+        let entities = code_parser::parse_code_file(file)
+            .with_context(|| format!("Error al parsear {}", file.display()))?;
+
+        let public: Vec<_> = entities.iter().filter(|e| e.is_public).collect();
+        let documented = public.iter().filter(|e| e.doc_id.is_some()).count();
+        let file_level = public
+            .iter()
+            .filter(|e| e.doc_id.is_none() && e.file_link.is_some())
+            .count();
+
+        total_public += public.len();
+        total_functions += entities.len();
+        total_linked += entities
+            .iter()
+            .filter(|e| e.doc_id.is_some() || e.file_link.is_some())
+            .count();
+        total_documented += documented;
+        total_file_level += file_level;
+        if let (Some(total), Some(sections)) = (total_prose_only.as_mut(), &doc_sections) {
+            *total += validator::count_prose_only_links(public.iter().copied(), sections);
+        }
+
+        file_coverages.push(FileCoverage {
+            file: file.clone(),
+            total_public: public.len(),
+            documented,
+            file_level,
+            file_doc_id: entities
+                .first()
+                .and_then(|e| e.file_link.as_ref())
+                .map(|l| l.doc_id.clone()),
+        });
+    }
+
+    Ok(CoverageReport {
+        files: file_coverages,
+        total_public,
+        total_documented,
+        total_file_level,
+        total_functions,
+        total_linked,
+        total_prose_only,
+    })
+}
+
+// ── Presentación ──────────────────────────────────────────────────────────────
+
+fn print_report(report: &CoverageReport, min_coverage: u8, layout: &Layout) {
+    let separator = "─".repeat(60);
+    let separator = layout.paint(Style::Dim, &separator);
+
+    println!(
+        "\n{}\n",
+        layout.paint(Style::Heading, "DocsGuard — Document Coverage Report")
+    );
+    println!("{separator}");
+
+    // Calcular ancho máximo de rutas para alinear columnas
+    let max_path_len = report
+        .files
+        .iter()
+        .map(|f| display_path(&f.file).len())
+        .max()
+        .unwrap_or(20)
+        .max(20);
+
+    for fc in &report.files {
+        print_file_row(fc, max_path_len, layout);
+    }
+
+    println!("{separator}");
+    print_total_row(report, max_path_len, layout);
+    println!();
+    print_verdict(report.percentage(), min_coverage, layout);
+    println!();
+}
+
+/// Ruta, barra, porcentaje y recuento de una fila de la tabla; el color de la
+/// barra y del porcentaje depende de la cifra.
+fn row(
+    layout: &Layout,
+    label: (Style, &str),
+    pct: f64,
+    covered: usize,
+    total: usize,
+    width: usize,
+) -> String {
+    let style = pct_style(pct);
+    format!(
+        "  {}  {}  {}  {}",
+        layout.paint(label.0, &format!("{:<width$}", label.1)),
+        layout.paint(style, &make_bar(pct)),
+        layout.paint(style, &format!("{:>3.0}%", pct)),
+        layout.paint(Style::Dim, &format!("({}/{})", covered, total)),
+    )
+}
+
+fn print_file_row(fc: &FileCoverage, path_col_width: usize, layout: &Layout) {
+    let pct = fc.percentage();
+    let path = display_path(&fc.file);
+    let file_tag = fc
+        .file_doc_id
+        .as_ref()
+        .map(|id| {
+            let tag = tr(&format!("[archivo: {id}]")).into_owned();
+            format!("  {}", layout.paint(Style::Hint, &tag))
+        })
+        .unwrap_or_default();
+
+    println!(
+        "{}{}",
+        row(
+            layout,
+            (Style::Dim, &path),
+            pct,
+            fc.documented + fc.file_level,
+            fc.total_public,
+            path_col_width,
+        ),
+        file_tag
+    );
+}
+
+fn print_total_row(report: &CoverageReport, path_col_width: usize, layout: &Layout) {
+    println!(
+        "{}",
+        row(
+            layout,
+            (Style::Heading, "TOTAL"),
+            report.percentage(),
+            report.total_documented + report.total_file_level,
+            report.total_public,
+            path_col_width,
+        )
+    );
+    let prose_only = report
+        .total_prose_only
+        .map(|n| format!("  ·  prose-only: {}", n))
+        .unwrap_or_default();
+    let levels = tr(&format!(
+        "nivel función: {}  ·  nivel archivo: {}{}",
+        report.total_documented, report.total_file_level, prose_only,
+    ))
+    .into_owned();
+    println!(
+        "  {:<width$}  {}",
+        "",
+        layout.paint(Style::Dim, &levels),
+        width = path_col_width,
+    );
+}
+
+fn print_verdict(pct: f64, min_coverage: u8, layout: &Layout) {
+    if pct >= f64::from(min_coverage) {
+        let verdict = tr(&format!(
+            "✓ {:.0}% — Cobertura por encima del umbral mínimo ({}%).",
+            pct, min_coverage
+        ))
+        .into_owned();
+        println!("  {}", layout.paint(Style::Success, &verdict));
+    } else {
+        let verdict = tr(&format!(
+            "✗ {:.0}% — Por debajo del umbral mínimo ({}%).",
+            pct, min_coverage
+        ))
+        .into_owned();
+        println!("  {}", layout.paint(Style::Error, &verdict));
+        let advice =
+            tr("Añade anotaciones /// @docs: [id] a las funciones públicas sin documentar.");
+        println!("    {}", layout.paint(Style::Dim, &advice));
+    }
+}
+
+// ── Utilidades ────────────────────────────────────────────────────────────────
+
+fn make_bar(pct: f64) -> String {
+    let filled = ((pct / 100.0) * BAR_WIDTH as f64).round() as usize;
+    let filled = filled.min(BAR_WIDTH);
+    let empty = BAR_WIDTH - filled;
+    format!(
+        "{}{}",
+        BAR_FILLED.to_string().repeat(filled),
+        BAR_EMPTY.to_string().repeat(empty)
+    )
+}
+
+fn pct_style(pct: f64) -> Style {
+    if pct >= 80.0 {
+        Style::Success
+    } else if pct >= 50.0 {
+        Style::Warning
+    } else {
+        Style::Error
+    }
+}
+
+/// Muestra la ruta relativa si es posible, o el nombre del archivo como fallback.
+fn display_path(path: &Path) -> String {
+    // Intentar ruta relativa al directorio actual
+    if let Ok(cwd) = std::env::current_dir() {
+        if let Ok(rel) = path.strip_prefix(&cwd) {
+            return rel.display().to_string();
+        }
+    }
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::MemFs;
+
+    #[test]
+    fn make_bar_full() {
+        let bar = make_bar(100.0);
+        assert_eq!(bar.chars().filter(|&c| c == BAR_FILLED).count(), BAR_WIDTH);
+        assert_eq!(bar.chars().filter(|&c| c == BAR_EMPTY).count(), 0);
+    }
+
+    #[test]
+    fn make_bar_empty() {
+        let bar = make_bar(0.0);
+        assert_eq!(bar.chars().filter(|&c| c == BAR_FILLED).count(), 0);
+        assert_eq!(bar.chars().filter(|&c| c == BAR_EMPTY).count(), BAR_WIDTH);
+    }
+
+    #[test]
+    fn make_bar_half() {
+        let bar = make_bar(50.0);
+        let filled = bar.chars().filter(|&c| c == BAR_FILLED).count();
+        assert_eq!(filled, BAR_WIDTH / 2);
+    }
+
+    #[test]
+    fn pct_style_thresholds() {
+        assert_eq!(pct_style(100.0), Style::Success);
+        assert_eq!(pct_style(80.0), Style::Success);
+        assert_eq!(pct_style(79.9), Style::Warning);
+        assert_eq!(pct_style(50.0), Style::Warning);
+        assert_eq!(pct_style(49.9), Style::Error);
+        assert_eq!(pct_style(0.0), Style::Error);
+    }
+
+    #[test]
+    fn file_coverage_percentage() {
+        let fc = FileCoverage {
+            file: PathBuf::from("test.rs"),
+            total_public: 10,
+            documented: 8,
+            file_level: 0,
+            file_doc_id: None,
+        };
+        assert!((fc.percentage() - 80.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn file_coverage_zero_public() {
+        let fc = FileCoverage {
+            file: PathBuf::from("test.rs"),
+            total_public: 0,
+            documented: 0,
+            file_level: 0,
+            file_doc_id: None,
+        };
+        assert_eq!(fc.percentage(), 100.0);
+    }
+
+    #[test]
+    fn report_percentage_aggregates_correctly() {
+        let report = CoverageReport {
+            files: vec![],
+            total_public: 20,
+            total_documented: 15,
+            total_file_level: 0,
+            total_functions: 20,
+            total_linked: 15,
+            total_prose_only: None,
+        };
+        assert!((report.percentage() - 75.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn file_level_coverage_is_counted_separately() {
+        let dir = MemFs::project();
+        let linked = dir.path().join("payments.ts");
+        dir.write(
+            &linked,
+            "// @docs-file: payments-overview\n\n// @docs: [charge]\nexport function charge() {}\nexport function refund() {}\n",
+        );
+        let plain = dir.path().join("users.ts");
+        dir.write(&plain, "export function create() {}\n");
+
+        let report = build_report(&[linked, plain], None).unwrap();
+        assert_eq!(report.total_public, 3);
+        assert_eq!(report.total_documented, 1);
+        assert_eq!(report.total_file_level, 1);
+        assert_eq!(
+            report.files[0].file_doc_id.as_deref(),
+            Some("payments-overview")
+        );
+        assert_eq!(report.files[0].percentage(), 100.0);
+        assert_eq!(report.files[1].percentage(), 0.0);
+    }
+
+    #[test]
+    fn prose_only_links_are_covered_but_counted_with_docs() {
+        let dir = MemFs::project();
+        let code = dir.path().join("payments.ts");
+        dir.write(
+            &code,
+            "// @docs: [payments-overview]\nexport function dispatch(kind: string) {}\n// @docs: [charge]\nexport function charge(amount: number) {}\n",
+        );
+        let docs = dir.path().join("api.md");
+        dir.write(
+            &docs,
+            "<!-- @docs-id: payments-overview @docs-skip: args -->\n## Pagos\n\nVisión general.\n\n<!-- @docs-id: charge -->\n## charge\n",
+        );
+
+        let report = build_report(std::slice::from_ref(&code), Some(&docs)).unwrap();
+        assert_eq!(report.total_documented, 2);
+        assert_eq!(report.total_prose_only, Some(1));
+        assert_eq!(report.percentage(), 100.0);
+        assert_eq!(build_report(&[code], None).unwrap().total_prose_only, None);
+    }
+}
@@ -0,0 +1,132 @@
+//! Fechas civiles UTC (`AAAA-MM-DD`) sin dependencia de chrono.
+//!
+//! Las usan las atestaciones (`date`) y las entradas del baseline
+//! (`created_at`, `expires`). Un día se representa como los días
+//! transcurridos desde 1970-01-01; la conversión en ambos sentidos sigue los
+//! algoritmos `civil_from_days` y `days_from_civil` de Howard Hinnant.
+
+use crate::messages;
+
+/// Segundos desde 1970-01-01.
+pub fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+/// Día actual en UTC, en días desde 1970-01-01.
+pub fn today_days() -> i64 {
+    now() / 86_400
+}
+
+/// `hace 2 días` para una antigüedad de `seconds`: en la unidad más grande
+/// que cabe al menos una vez, redondeando hacia abajo.
+pub fn ago(seconds: i64) -> String {
+    let seconds = seconds.max(0) as usize;
+    let unit = [
+        (365 * 86_400, messages::YEARS),
+        (30 * 86_400, messages::MONTHS),
+        (86_400, messages::DAYS),
+        (3_600, messages::HOURS),
+        (60, messages::MINUTES),
+    ]
+    .into_iter()
+    .find(|(unit, _)| seconds >= *unit);
+    match unit {
+        Some((unit, noun)) => format!("hace {}", noun.count(seconds / unit)),
+        None => "hace un momento".to_string(),
+    }
+}
+
+/// Día actual en UTC (`AAAA-MM-DD`).
+pub fn today() -> String {
+    civil_date(today_days())
+}
+
+/// Fecha `AAAA-MM-DD` del día `days` contado desde 1970-01-01.
+pub fn civil_date(days: i64) -> String {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Días desde 1970-01-01 de una fecha `AAAA-MM-DD`; `None` si no tiene ese
+/// formato o el día no existe (`2025-02-29`).
+pub fn parse_date(date: &str) -> Option<i64> {
+    let mut parts = date.split('-');
+    let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() || year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return None;
+    }
+    let digits = |s: &str| {
+        s.bytes()
+            .all(|b| b.is_ascii_digit())
+            .then(|| s.parse::<i64>())
+    };
+    let (year, month, day) = (
+        digits(year)?.ok()?,
+        digits(month)?.ok()?,
+        digits(day)?.ok()?,
+    );
+    if !(1..=12).contains(&month) || day < 1 {
+        return None;
+    }
+
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+    // Un día fuera de rango (31 de abril) cae en otro mes: no da la misma fecha
+    (civil_date(days) == date).then_some(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_are_civil_utc_days() {
+        assert_eq!(civil_date(0), "1970-01-01");
+        assert_eq!(civil_date(19_782), "2024-02-29");
+        assert_eq!(civil_date(20_120), "2025-02-01");
+    }
+
+    #[test]
+    fn parsing_inverts_civil_date_and_rejects_impossible_days() {
+        for days in [0, 19_782, 20_120, -1] {
+            assert_eq!(parse_date(&civil_date(days)), Some(days));
+        }
+        for bad in [
+            "2025-02-29",
+            "2025-04-31",
+            "2025-13-01",
+            "2025-1-01",
+            "hoy",
+            "+025-01-01",
+        ] {
+            assert_eq!(parse_date(bad), None, "{bad}");
+        }
+    }
+
+    #[test]
+    fn ages_use_the_largest_whole_unit() {
+        assert_eq!(ago(-5), "hace un momento");
+        assert_eq!(ago(59), "hace un momento");
+        assert_eq!(ago(60), "hace 1 minuto");
+        assert_eq!(ago(2 * 3_600 + 59 * 60), "hace 2 horas");
+        assert_eq!(ago(2 * 86_400 + 1), "hace 2 días");
+        assert_eq!(ago(45 * 86_400), "hace 1 mes");
+        assert_eq!(ago(800 * 86_400), "hace 2 años");
+    }
+}
@@ -0,0 +1,309 @@
+//! Comando `demo`: un proyecto de ejemplo para probar DocsGuard sin
+//! integrarlo en un repositorio.
+//!
+//! `write_sample` escribe un archivo TypeScript, uno Rust y sus docs con una
+//! mezcla a propósito: enlaces correctos, un ID roto, un argumento fantasma,
+//! un tipo que no coincide, una función sin enlazar y una sección huérfana.
+//! `EXPECTED` describe cada hallazgo que provoca y `commentary` lo explica
+//! con las líneas de los archivos generados. El test de este módulo valida el
+//! mismo ejemplo y exige exactamente esos hallazgos, así que la demo no puede
+//! desviarse de lo que hace `check`.
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+use crate::core::types::Rule;
+use crate::exit::Failure;
+use crate::parser::code_parser::{self, safe_display};
+use crate::vfs;
+
+/// Docs del ejemplo, relativas a su directorio.
+pub const DOC_FILE: &str = "docs/api.md";
+/// Código del ejemplo, relativo a su directorio.
+pub const CODE_FILES: [&str; 2] = ["src/auth.ts", "src/search.rs"];
+
+const AUTH_TS: &str = "\
+// Inicia sesión con usuario y contraseña.
+// @docs: [auth-login]
+export function login(username: string, password: string): Session {
+  return openSession(username, password);
+}
+
+// Cierra la sesión.
+// @docs: [auth-logut]
+export function logout(token: string): void {
+  closeSession(token);
+}
+
+export function refreshToken(token: string): string {
+  return renew(token);
+}
+";
+
+const SEARCH_RS: &str = "\
+/// Busca documentos por texto.
+/// @docs: [search]
+pub fn search(query: &str, limit: u32) -> Vec<Hit> {
+    index().find(query, limit)
+}
+
+/// Borra un documento del índice.
+/// @docs: [search-delete]
+pub fn delete(id: u64) -> bool {
+    index().remove(id)
+}
+";
+
+const API_MD: &str = "\
+# API de ejemplo
+
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Nombre de usuario |
+| password | string | Contraseña |
+
+<!-- @docs-id: auth-logout -->
+## logout
+
+| Param | Type | Description |
+|-------|------|-------------|
+| token | string | Token de la sesión |
+
+<!-- @docs-id: search -->
+## search
+
+| Param | Type | Description |
+|-------|------|-------------|
+| query | string | Texto que se busca |
+| limit | number | Resultados como máximo |
+| offset | number | Resultados que se saltan |
+
+<!-- @docs-id: search-delete -->
+## delete
+
+| Param | Type | Description |
+|-------|------|-------------|
+| id | string | Identificador del documento |
+
+<!-- @docs-id: search-reindex -->
+## reindex
+
+Reconstruye el índice completo.
+";
+
+/// Archivos del ejemplo: ruta relativa y contenido.
+const FILES: [(&str, &str); 3] = [
+    (CODE_FILES[0], AUTH_TS),
+    (CODE_FILES[1], SEARCH_RS),
+    (DOC_FILE, API_MD),
+];
+
+/// Un hallazgo que el ejemplo provoca a propósito.
+pub struct Expected {
+    pub rule: Rule,
+    /// Ubicación del hallazgo: archivo y texto de la línea.
+    pub at: (&'static str, &'static str),
+    /// La otra mitad que conviene mirar, si la hay.
+    pub see: Option<(&'static str, &'static str)>,
+    pub comment: &'static str,
+}
+
+/// Hallazgos de `check` sobre el ejemplo, en el orden en que se comentan.
+pub const EXPECTED: &[Expected] = &[
+    Expected {
+        rule: Rule::LinkVerified,
+        at: (CODE_FILES[0], "export function login"),
+        see: Some((DOC_FILE, "@docs-id: auth-login")),
+        comment: "`@docs: [auth-login]` enlaza la función con su sección; los dos argumentos coinciden en nombre y tipo.",
+    },
+    Expected {
+        rule: Rule::MissingDocSection,
+        at: (CODE_FILES[0], "export function logout"),
+        see: Some((CODE_FILES[0], "@docs: [auth-logut]")),
+        comment: "Enlace roto: la anotación dice `auth-logut` y la sección es `auth-logout`.",
+    },
+    Expected {
+        rule: Rule::OrphanSection,
+        at: (DOC_FILE, "@docs-id: auth-logout"),
+        see: None,
+        comment: "Consecuencia del enlace roto: ninguna función enlaza `auth-logout`. Al corregir la errata desaparecen los dos hallazgos.",
+    },
+    Expected {
+        rule: Rule::UnlinkedFunction,
+        at: (CODE_FILES[0], "export function refreshToken"),
+        see: None,
+        comment: "Función pública sin `@docs`. Es solo Info; `scaffold` propone el enlace o una sección nueva.",
+    },
+    Expected {
+        rule: Rule::LinkVerified,
+        at: (CODE_FILES[1], "pub fn search"),
+        see: Some((DOC_FILE, "@docs-id: search")),
+        comment: "El enlace de Rust funciona igual que el de TypeScript, con `/// @docs: [search]`.",
+    },
+    Expected {
+        rule: Rule::GhostArg,
+        at: (CODE_FILES[1], "pub fn search"),
+        see: Some((DOC_FILE, "| offset |")),
+        comment: "Argumento fantasma: la tabla documenta `offset`, que la firma ya no tiene.",
+    },
+    Expected {
+        rule: Rule::LinkVerified,
+        at: (CODE_FILES[1], "pub fn delete"),
+        see: Some((DOC_FILE, "@docs-id: search-delete")),
+        comment: "Enlace correcto, aunque un argumento no coincide (el siguiente hallazgo).",
+    },
+    Expected {
+        rule: Rule::TypeMismatch,
+        at: (CODE_FILES[1], "pub fn delete"),
+        see: Some((DOC_FILE, "| id | string")),
+        comment: "El código declara `id: u64` y las docs dicen `string`.",
+    },
+    Expected {
+        rule: Rule::OrphanSection,
+        at: (DOC_FILE, "@docs-id: search-reindex"),
+        see: None,
+        comment: "Sección huérfana: documenta una función que no existe en el código.",
+    },
+];
+
+/// Directorio de la demo: el indicado o uno nuevo en el temporal del sistema.
+pub fn sample_dir(dir: Option<&Path>) -> PathBuf {
+    match dir {
+        Some(dir) => dir.to_path_buf(),
+        None => std::env::temp_dir().join(format!("docsguard-demo-{}", std::process::id())),
+    }
+}
+
+/// Escribe el proyecto de ejemplo en `dir`, que puede no existir; no
+/// sobrescribe ningún archivo.
+pub fn write_sample(dir: &Path) -> Result<()> {
+    for (path, _) in FILES {
+        let path = dir.join(path);
+        if vfs::exists(&path) {
+            anyhow::bail!(Failure::usage(format!(
+                "Ya existe un archivo del ejemplo: {}",
+                safe_display(&path)
+            ))
+            .with_path(&path)
+            .with_hint("Indica con --dir un directorio vacío o sin el proyecto de ejemplo."));
+        }
+    }
+    for (path, contents) in FILES {
+        let path = dir.join(path);
+        if let Some(parent) = path.parent() {
+            vfs::create_dir_all(parent).map_err(|e| {
+                anyhow::Error::new(e).context(format!("No se pudo crear: {}", parent.display()))
+            })?;
+        }
+        code_parser::atomic_write(&path, contents.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Línea (desde 1) de la primera línea de `file` que contiene `needle`.
+fn line_of(file: &str, needle: &str) -> usize {
+    let contents = FILES
+        .iter()
+        .find(|(path, _)| *path == file)
+        .map_or("", |(_, contents)| contents);
+    contents
+        .lines()
+        .position(|line| line.contains(needle))
+        .map_or(0, |i| i + 1)
+}
+
+fn location((file, needle): (&str, &str)) -> String {
+    format!("{}:{}", file, line_of(file, needle))
+}
+
+/// Comentario de la salida de `check` y siguientes pasos, para el ejemplo
+/// escrito en `dir`.
+pub fn commentary(dir: &Path) -> String {
+    let mut out = String::from("\nQué muestra cada hallazgo:\n\n");
+    for expected in EXPECTED {
+        out.push_str(&format!("  {} en {}", expected.rule, location(expected.at)));
+        if let Some(see) = expected.see {
+            out.push_str(&format!(" (ver {})", location(see)));
+        }
+        out.push_str(&format!("\n      {}\n", expected.comment));
+    }
+
+    let [auth, search] = CODE_FILES;
+    let steps = [
+        (
+            format!("docsguard scaffold {auth} {DOC_FILE} --dry-run"),
+            "propone el enlace de refreshToken",
+        ),
+        (
+            format!("docsguard baseline {search} {DOC_FILE}"),
+            "acepta los hallazgos de hoy como deuda",
+        ),
+        (
+            format!("docsguard watch {search} {DOC_FILE}"),
+            "revalida cada vez que guardas",
+        ),
+    ];
+    let width = steps
+        .iter()
+        .map(|(command, _)| command.len())
+        .max()
+        .unwrap_or(0);
+    out.push_str(&format!(
+        "\nSiguientes pasos:\n\n  cd {}\n",
+        safe_display(dir)
+    ));
+    for (command, what) in steps {
+        out.push_str(&format!("  {:<width$}   # {}\n", command, what));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::parse_location;
+    use crate::report;
+    use crate::vfs::MemFs;
+
+    #[test]
+    fn check_finds_exactly_the_commented_findings() {
+        let dir = MemFs::project();
+        write_sample(dir.path()).unwrap();
+        let code_files: Vec<PathBuf> = CODE_FILES.iter().map(|f| dir.path().join(f)).collect();
+        let report =
+            report::build_report(&code_files, &dir.path().join(DOC_FILE), dir.path()).unwrap();
+
+        let mut found: Vec<(Rule, String)> = report
+            .results
+            .iter()
+            .map(|r| {
+                let at = r.code_location.as_deref().or(r.doc_location.as_deref());
+                let (file, line) = parse_location(at.unwrap()).unwrap();
+                (r.rule, format!("{}:{}", file.display(), line))
+            })
+            .collect();
+        let mut expected: Vec<(Rule, String)> =
+            EXPECTED.iter().map(|e| (e.rule, location(e.at))).collect();
+        found.sort_by_key(|(rule, at)| (rule.id(), at.clone()));
+        expected.sort_by_key(|(rule, at)| (rule.id(), at.clone()));
+        assert_eq!(found, expected);
+
+        // Los textos de `see` existen en los archivos
+        for see in EXPECTED.iter().filter_map(|e| e.see) {
+            assert_ne!(line_of(see.0, see.1), 0, "{:?}", see);
+        }
+    }
+
+    #[test]
+    fn the_sample_is_never_written_over_existing_files() {
+        let dir = MemFs::project();
+        write_sample(dir.path()).unwrap();
+        dir.write(dir.path().join(DOC_FILE), "# Mías\n");
+        let err = write_sample(dir.path()).unwrap_err();
+        assert!(err.to_string().contains("Ya existe"), "{err}");
+        assert_eq!(dir.contents(dir.path().join(DOC_FILE)).unwrap(), "# Mías\n");
+    }
+}
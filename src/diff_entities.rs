@@ -0,0 +1,647 @@
+//! `diff-entities`: qué superficie de API y de docs cambió entre dos revisiones.
+//!
+//! Los archivos de código y de docs indicados se leen en `--from` y en `--to`
+//! (por defecto, el árbol de trabajo) con `git show`, como las revisiones
+//! base de `--require-docs-for-new`, y se parsean con la configuración
+//! actual. Las funciones se emparejan por archivo y nombre, y las secciones
+//! por ID; las repeticiones (sobrecargas, IDs en varias secciones), por
+//! orden. Una función movida de archivo aparece como eliminada y añadida.
+//!
+//! Los tipos se comparan normalizados, como en `type-mismatch`: `int` →
+//! `integer` no es un cambio. La salida es el apartado "impacto en docs" de
+//! las notas de release: texto, JSON o Markdown (`--format`).
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::types::{Arg, CodeEntity, DocSection};
+use crate::core::validator::normalize_type;
+use crate::exit::Failure;
+use crate::git;
+use crate::messages;
+use crate::messages::{print_tr, println_tr};
+use crate::new_functions;
+use crate::parser::code_parser::{safe_display, Language};
+use crate::parser::doc_parser;
+use crate::paths::ProjectPaths;
+use crate::vfs;
+
+/// Formato de salida de `diff-entities`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum DiffFormat {
+    /// Informe agrupado para el terminal.
+    #[default]
+    Text,
+    /// Los cambios como JSON.
+    Json,
+    /// Apartado Markdown para las notas de release.
+    Md,
+}
+
+/// Revisión de la que se leen los archivos.
+#[derive(Debug, Clone, Copy)]
+pub enum Revision<'a> {
+    Git(&'a str),
+    WorkingTree,
+}
+
+impl Revision<'_> {
+    fn label(self) -> String {
+        match self {
+            Revision::Git(rev) => rev.to_string(),
+            Revision::WorkingTree => "árbol de trabajo".into(),
+        }
+    }
+
+    /// Contenido de `path` (relativa al proyecto), o `None` si no existe.
+    fn read(self, project_root: &Path, path: &Path) -> Result<Option<String>> {
+        match self {
+            Revision::Git(rev) => git::show_file(project_root, rev, path),
+            Revision::WorkingTree => {
+                let file = project_root.join(path);
+                if !vfs::is_file(&file) {
+                    return Ok(None);
+                }
+                vfs::read_to_string(&file)
+                    .with_context(|| format!("No se pudo leer {}", safe_display(&file)))
+                    .map(Some)
+            }
+        }
+    }
+}
+
+/// Función o sección añadida o eliminada.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Item {
+    /// Nombre de la función o ID de la sección.
+    pub name: String,
+    /// `archivo:línea` en la revisión donde existe.
+    pub location: String,
+}
+
+/// Valor antes y después.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Change<T> {
+    pub before: T,
+    pub after: T,
+}
+
+/// Argumento con otro tipo normalizado.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Retyped {
+    pub name: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// Cambios de una lista de argumentos, por nombre.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ArgChanges {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub retyped: Vec<Retyped>,
+}
+
+impl ArgChanges {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.retyped.is_empty()
+    }
+}
+
+/// Función que existe en las dos revisiones con otra firma o anotación.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FunctionChange {
+    pub name: String,
+    /// `archivo:línea` en `--to`.
+    pub location: String,
+    pub args: ArgChanges,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<Change<Option<String>>>,
+    /// `@docs` de la función.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotation: Option<Change<Option<String>>>,
+}
+
+/// Sección que existe en las dos revisiones con otros argumentos documentados.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SectionChange {
+    pub id: String,
+    /// `archivo:línea` en `--to`.
+    pub location: String,
+    pub args: ArgChanges,
+}
+
+/// Añadidas, eliminadas y cambiadas.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Changes<T> {
+    pub added: Vec<Item>,
+    pub removed: Vec<Item>,
+    pub changed: Vec<T>,
+}
+
+impl<T> Changes<T> {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Salida de `diff-entities` (también en JSON).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SurfaceDiff {
+    pub from: String,
+    pub to: String,
+    pub functions: Changes<FunctionChange>,
+    pub sections: Changes<SectionChange>,
+}
+
+/// Compara las funciones y secciones de dos revisiones.
+pub fn compare(
+    before: (&[CodeEntity], &[DocSection]),
+    after: (&[CodeEntity], &[DocSection]),
+) -> (Changes<FunctionChange>, Changes<SectionChange>) {
+    let mut functions = Changes {
+        added: Vec::new(),
+        removed: Vec::new(),
+        changed: Vec::new(),
+    };
+    for pair in pair_by_key(before.0, after.0, |e| (e.file_path.clone(), e.name.clone())) {
+        match pair {
+            (None, Some(added)) => functions.added.push(entity_item(added)),
+            (Some(removed), None) => functions.removed.push(entity_item(removed)),
+            (Some(old), Some(new)) => functions.changed.extend(function_change(old, new)),
+            (None, None) => {}
+        }
+    }
+
+    let mut sections = Changes {
+        added: Vec::new(),
+        removed: Vec::new(),
+        changed: Vec::new(),
+    };
+    for pair in pair_by_key(before.1, after.1, |s| s.id.clone()) {
+        match pair {
+            (None, Some(added)) => sections.added.push(section_item(added)),
+            (Some(removed), None) => sections.removed.push(section_item(removed)),
+            (Some(old), Some(new)) => {
+                let args = arg_changes(&old.args, &new.args);
+                if !args.is_empty() {
+                    sections.changed.push(SectionChange {
+                        id: new.id.clone(),
+                        location: new.location(),
+                        args,
+                    });
+                }
+            }
+            (None, None) => {}
+        }
+    }
+    (functions, sections)
+}
+
+/// Empareja los elementos con la misma clave (ordenadas); los repetidos, por
+/// posición, y los que sobran quedan sin pareja.
+fn pair_by_key<'a, T, K: Ord>(
+    before: &'a [T],
+    after: &'a [T],
+    key: impl Fn(&T) -> K,
+) -> Vec<(Option<&'a T>, Option<&'a T>)> {
+    let mut groups: BTreeMap<K, (Vec<&T>, Vec<&T>)> = BTreeMap::new();
+    for item in before {
+        groups.entry(key(item)).or_default().0.push(item);
+    }
+    for item in after {
+        groups.entry(key(item)).or_default().1.push(item);
+    }
+    groups
+        .into_values()
+        .flat_map(|(old, new)| {
+            (0..old.len().max(new.len()))
+                .map(|i| (old.get(i).copied(), new.get(i).copied()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn entity_item(entity: &CodeEntity) -> Item {
+    Item {
+        name: entity.name.clone(),
+        location: entity.location(),
+    }
+}
+
+fn section_item(section: &DocSection) -> Item {
+    Item {
+        name: section.id.clone(),
+        location: section.location(),
+    }
+}
+
+/// Cambios de firma y de anotación; `None` si no hay ninguno.
+fn function_change(old: &CodeEntity, new: &CodeEntity) -> Option<FunctionChange> {
+    let args = arg_changes(&old.args, &new.args);
+    let return_type =
+        (normalized(&old.return_type) != normalized(&new.return_type)).then(|| Change {
+            before: old.return_type.clone(),
+            after: new.return_type.clone(),
+        });
+    let annotation = (old.doc_id != new.doc_id).then(|| Change {
+        before: old.doc_id.clone(),
+        after: new.doc_id.clone(),
+    });
+    if args.is_empty() && return_type.is_none() && annotation.is_none() {
+        return None;
+    }
+    Some(FunctionChange {
+        name: new.name.clone(),
+        location: new.location(),
+        args,
+        return_type,
+        annotation,
+    })
+}
+
+/// Argumentos añadidos, eliminados y con otro tipo normalizado, en el orden
+/// de la lista en que aparecen.
+fn arg_changes(before: &[Arg], after: &[Arg]) -> ArgChanges {
+    let find = |args: &'_ [Arg], name: &str| args.iter().find(|a| a.name == name).cloned();
+    let mut changes = ArgChanges::default();
+    for arg in after {
+        match find(before, &arg.name) {
+            None => changes.added.push(arg.name.clone()),
+            Some(old) if normalized(&old.type_name) != normalized(&arg.type_name) => {
+                changes.retyped.push(Retyped {
+                    name: arg.name.clone(),
+                    before: old.type_name,
+                    after: arg.type_name.clone(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    changes.removed = before
+        .iter()
+        .filter(|a| find(after, &a.name).is_none())
+        .map(|a| a.name.clone())
+        .collect();
+    changes
+}
+
+fn normalized(type_name: &Option<String>) -> Option<String> {
+    type_name.as_deref().map(normalize_type)
+}
+
+/// Funciones y secciones de `code_files` y `doc_file` en `revision`.
+fn parse_at(
+    revision: Revision,
+    code_files: &[(PathBuf, PathBuf)],
+    doc_file: &(PathBuf, PathBuf),
+    project_root: &Path,
+    config: &Config,
+) -> Result<(Vec<CodeEntity>, Vec<DocSection>)> {
+    let mut entities = Vec::new();
+    for (path, display) in code_files {
+        if let Some(source) = revision.read(project_root, path)? {
+            entities.extend(new_functions::parse_source(
+                &source,
+                path,
+                display,
+                config.annotation_options(),
+            ));
+        }
+    }
+    let (path, display) = doc_file;
+    let mut sections = match revision.read(project_root, path)? {
+        Some(source) => doc_parser::parse_markdown_source_with(
+            &source,
+            display,
+            config.doc_parse_options(),
+            &mut ParseDiagnostics::default(),
+        )
+        .with_context(|| {
+            format!(
+                "Error al parsear {} en {}",
+                display.display(),
+                revision.label()
+            )
+        })?,
+        None => Vec::new(),
+    };
+    config.apply_to_sections(&mut sections);
+    Ok((entities, sections))
+}
+
+/// Ruta de `file` relativa a `project_root` (para `git show`) y su forma en
+/// la salida.
+fn project_path(
+    relative: &ProjectPaths,
+    config: &Config,
+    file: &Path,
+) -> Result<(PathBuf, PathBuf)> {
+    let path = relative.normalize(file);
+    if path.is_absolute() {
+        anyhow::bail!(Failure::usage(format!(
+            "{} está fuera de --project-root: no tiene versiones en git.",
+            safe_display(file)
+        ))
+        .with_path(file));
+    }
+    Ok((path, config.paths.normalize(file)))
+}
+
+/// Texto agrupado para el terminal.
+pub fn render_text(diff: &SurfaceDiff) -> String {
+    let mut out = String::new();
+    for (title, items) in [
+        ("Funciones", function_lines(&diff.functions)),
+        ("Secciones", section_lines(&diff.sections)),
+    ] {
+        out.push_str(&format!("{}\n", title));
+        if items.is_empty() {
+            out.push_str("  (sin cambios)\n");
+        }
+        for (mark, head, details) in items {
+            out.push_str(&format!("  {} {}\n", mark, head));
+            for detail in details {
+                out.push_str(&format!("      {}\n", detail));
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Apartado Markdown "impacto en docs" para las notas de release.
+pub fn render_markdown(diff: &SurfaceDiff) -> String {
+    let mut out = format!(
+        "## Impacto en la documentación ({} → {})\n",
+        diff.from, diff.to
+    );
+    for (title, items) in [
+        ("Funciones", function_lines(&diff.functions)),
+        ("Secciones", section_lines(&diff.sections)),
+    ] {
+        out.push_str(&format!("\n### {}\n\n", title));
+        if items.is_empty() {
+            out.push_str("_Sin cambios._\n");
+        }
+        for (mark, head, details) in items {
+            let kind = match mark {
+                '+' => "Añadida",
+                '-' => "Eliminada",
+                _ => "Cambiada",
+            };
+            // Todo en una línea: cada trozo se traduce por separado
+            out.push_str(&format!("- {}: {}", messages::tr(kind), head));
+            if !details.is_empty() {
+                let details: Vec<_> = details.iter().map(|d| messages::tr(d)).collect();
+                out.push_str(&format!(" — {}", details.join("; ")));
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Línea de cabecera y detalles de cada función, con la marca `+`, `-` o `~`.
+fn function_lines(changes: &Changes<FunctionChange>) -> Vec<(char, String, Vec<String>)> {
+    let mut lines = items(changes, "fn ");
+    for change in &changes.changed {
+        let mut details = arg_details(&change.args);
+        if let Some(ret) = &change.return_type {
+            details.push(format!("retorno: {}", type_change(&ret.before, &ret.after)));
+        }
+        if let Some(annotation) = &change.annotation {
+            let id = |id: &Option<String>| {
+                id.as_deref()
+                    .map_or("(sin anotación)".to_string(), |id| format!("`{}`", id))
+            };
+            details.push(format!(
+                "@docs: {} → {}",
+                id(&annotation.before),
+                id(&annotation.after)
+            ));
+        }
+        lines.push((
+            '~',
+            format!("`fn {}` ({})", change.name, change.location),
+            details,
+        ));
+    }
+    lines
+}
+
+fn section_lines(changes: &Changes<SectionChange>) -> Vec<(char, String, Vec<String>)> {
+    let mut lines = items(changes, "");
+    for change in &changes.changed {
+        lines.push((
+            '~',
+            format!("`{}` ({})", change.id, change.location),
+            arg_details(&change.args),
+        ));
+    }
+    lines
+}
+
+fn items<T>(changes: &Changes<T>, prefix: &str) -> Vec<(char, String, Vec<String>)> {
+    let line = |mark, item: &Item| {
+        (
+            mark,
+            format!("`{}{}` ({})", prefix, item.name, item.location),
+            Vec::new(),
+        )
+    };
+    changes
+        .added
+        .iter()
+        .map(|item| line('+', item))
+        .chain(changes.removed.iter().map(|item| line('-', item)))
+        .collect()
+}
+
+fn arg_details(args: &ArgChanges) -> Vec<String> {
+    args.added
+        .iter()
+        .map(|name| format!("arg `{}` añadido", name))
+        .chain(
+            args.removed
+                .iter()
+                .map(|name| format!("arg `{}` eliminado", name)),
+        )
+        .chain(args.retyped.iter().map(|arg| {
+            format!(
+                "arg `{}`: {}",
+                arg.name,
+                type_change(&arg.before, &arg.after)
+            )
+        }))
+        .collect()
+}
+
+fn type_change(before: &Option<String>, after: &Option<String>) -> String {
+    let shown = |t: &Option<String>| {
+        t.as_deref()
+            .map_or("(sin tipo)".to_string(), |t| format!("`{}`", t))
+    };
+    format!("{} → {}", shown(before), shown(after))
+}
+
+/// Ejecuta `docsguard diff-entities`. Informa y sale siempre con 0.
+pub fn run_diff_entities(
+    code_files: &[PathBuf],
+    doc_file: &Path,
+    project_root: &Path,
+    from: &str,
+    to: Option<&str>,
+    format: DiffFormat,
+) -> Result<()> {
+    let config = Config::load(project_root)?;
+    let relative = ProjectPaths::new(project_root);
+    let code_paths = code_files
+        .iter()
+        .map(|file| {
+            Language::from_extension(file)?;
+            project_path(&relative, &config, file)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let doc_path = project_path(&relative, &config, doc_file)?;
+
+    let from = Revision::Git(from);
+    let to = to.map_or(Revision::WorkingTree, Revision::Git);
+    let before = parse_at(from, &code_paths, &doc_path, project_root, &config)?;
+    let after = parse_at(to, &code_paths, &doc_path, project_root, &config)?;
+    let (functions, sections) = compare((&before.0, &before.1), (&after.0, &after.1));
+    let diff = SurfaceDiff {
+        from: from.label(),
+        to: to.label(),
+        functions,
+        sections,
+    };
+
+    match format {
+        DiffFormat::Json => println!("{}", serde_json::to_string_pretty(&diff)?),
+        DiffFormat::Md => print_tr!("{}", render_markdown(&diff)),
+        DiffFormat::Text => {
+            println_tr!(
+                "DocsGuard — Cambios de API y docs: {} → {}\n",
+                diff.from,
+                diff.to
+            );
+            println_tr!("  Docs: {}", doc_path.1.display());
+            println_tr!("  Código: {}\n", messages::FILES.count(code_files.len()));
+            print_tr!("{}", render_text(&diff));
+            println!("---");
+            println_tr!(
+                "Resumen: funciones {}; secciones {}",
+                counts(&diff.functions),
+                counts(&diff.sections)
+            );
+        }
+    }
+    Ok(())
+}
+
+/// `+1 −0 ~2`
+fn counts<T>(changes: &Changes<T>) -> String {
+    if changes.is_empty() {
+        return "sin cambios".into();
+    }
+    format!(
+        "+{} −{} ~{}",
+        changes.added.len(),
+        changes.removed.len(),
+        changes.changed.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::ArgSource;
+
+    fn entity(name: &str, line: usize, args: &[(&str, &str)]) -> CodeEntity {
+        CodeEntity {
+            name: name.into(),
+            args: args
+                .iter()
+                .map(|(name, type_name)| Arg {
+                    name: (*name).into(),
+                    type_name: Some((*type_name).into()),
+                    description: None,
+                    source: ArgSource::Code,
+                    line: None,
+                    unit: None,
+                    range: None,
+                })
+                .collect(),
+            return_type: None,
+            return_fields: None,
+            doc_id: None,
+            file_path: PathBuf::from("src/a.ts"),
+            line,
+            end_line: line,
+            is_public: true,
+            suppressions: vec![],
+            file_link: None,
+            examples: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn repeated_names_pair_up_in_order_and_aliases_are_not_changes() {
+        let before = [
+            entity("get", 1, &[("id", "string")]),
+            entity("get", 5, &[("id", "i32")]),
+        ];
+        let after = [
+            entity("get", 2, &[("id", "str")]),
+            entity("get", 6, &[("id", "number")]),
+            entity("get", 9, &[]),
+        ];
+        let (functions, sections) = compare((&before, &[]), (&after, &[]));
+        assert!(functions.changed.is_empty(), "{functions:?}");
+        assert!(functions.removed.is_empty());
+        assert_eq!(
+            functions.added,
+            [Item {
+                name: "get".into(),
+                location: "src/a.ts:9".into()
+            }]
+        );
+        assert!(sections.is_empty());
+    }
+
+    #[test]
+    fn arg_changes_keep_the_order_of_each_signature() {
+        let before = [entity(
+            "f",
+            1,
+            &[("a", "string"), ("b", "string"), ("c", "bool")],
+        )];
+        let after = [entity(
+            "f",
+            1,
+            &[("c", "string"), ("d", "number"), ("a", "string")],
+        )];
+        let (functions, _) = compare((&before, &[]), (&after, &[]));
+        let args = &functions.changed[0].args;
+        assert_eq!(args.added, ["d"]);
+        assert_eq!(args.removed, ["b"]);
+        assert_eq!(
+            args.retyped,
+            [Retyped {
+                name: "c".into(),
+                before: Some("bool".into()),
+                after: Some("string".into()),
+            }]
+        );
+        // Reordenar argumentos no es un cambio de superficie
+        assert_eq!(functions.changed.len(), 1);
+    }
+}
@@ -0,0 +1,366 @@
+//! Códigos de salida estables de todos los subcomandos.
+//!
+//! | Código | Significado                                                        |
+//! |--------|--------------------------------------------------------------------|
+//! | 0      | Sin hallazgos que hagan fallar la ejecución                         |
+//! | 1      | Hallazgos por encima del umbral (errores, cobertura, IDs, slow-run) |
+//! | 2      | Uso, argumentos o configuración inválidos                           |
+//! | 3      | Archivo no encontrado, sin lenguaje, ilegible o imposible de parsear |
+//! | 4      | Error interno                                                      |
+//!
+//! Los comandos no llaman a `std::process::exit`: devuelven un `Outcome` o un
+//! error, y `main` traduce el error con `exit_code_for`. Los errores se
+//! clasifican donde nacen envolviéndolos (o añadiendo como contexto) un
+//! `Failure`; un `std::io::Error` en la cadena cuenta como entrada ilegible
+//! y cualquier otro error como interno.
+//!
+//! Con `--json-errors` (implícito en `assert --json`) el error se escribe en
+//! stdout como `{"error": {"kind", "message", "path"?, "hint"?}}` en lugar
+//! del texto de stderr; el código de salida no cambia.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+use crate::messages;
+use crate::parser::code_parser::safe_display;
+
+/// Sin hallazgos que hagan fallar la ejecución.
+pub const SUCCESS: u8 = 0;
+/// Hallazgos por encima del umbral del comando.
+pub const FINDINGS: u8 = 1;
+/// Uso, argumentos o configuración inválidos (también los de clap).
+pub const USAGE: u8 = 2;
+/// Archivo no encontrado, sin lenguaje soportado, ilegible o imposible de
+/// parsear.
+pub const INPUT: u8 = 3;
+/// Error interno.
+pub const INTERNAL: u8 = 4;
+
+/// Texto de `--help` con el esquema de códigos.
+pub const EXIT_CODES_HELP: &str = "\
+Códigos de salida:
+  0  Sin hallazgos que hagan fallar la ejecución
+  1  Hallazgos por encima del umbral (errores o --fail-on, cobertura mínima, IDs de assert, --fail-on-slow)
+  2  Uso, argumentos o configuración inválidos
+  3  Archivo no encontrado, sin lenguaje soportado, ilegible o imposible de parsear
+  4  Error interno";
+
+/// Severidad a partir de la cual `check` sale con código 1 (`--fail-on`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailOn {
+    /// Solo los errores.
+    #[default]
+    Error,
+    /// Errores y advertencias.
+    Warning,
+    /// Nunca: ejecuciones que solo informan.
+    Never,
+}
+
+impl FailOn {
+    /// Indica si los hallazgos superan el umbral.
+    pub fn exceeded(self, errors: usize, warnings: usize) -> bool {
+        match self {
+            FailOn::Error => errors > 0,
+            FailOn::Warning => errors + warnings > 0,
+            FailOn::Never => false,
+        }
+    }
+
+    /// Nombre en la CLI (`warning`), para el resumen.
+    pub fn name(self) -> &'static str {
+        match self {
+            FailOn::Error => "error",
+            FailOn::Warning => "warning",
+            FailOn::Never => "never",
+        }
+    }
+}
+
+/// Resultado de un comando que terminó sin errores de ejecución.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Clean,
+    /// Hay hallazgos por encima del umbral del comando.
+    Findings,
+}
+
+impl Outcome {
+    pub fn failed_if(findings: bool) -> Self {
+        if findings {
+            Outcome::Findings
+        } else {
+            Outcome::Clean
+        }
+    }
+
+    pub fn code(self) -> u8 {
+        match self {
+            Outcome::Clean => SUCCESS,
+            Outcome::Findings => FINDINGS,
+        }
+    }
+}
+
+/// Categoría de un error de ejecución. Se asigna donde nace el error y da
+/// tanto el código de salida como el `kind` de `--json-errors`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    /// Argumentos o uso inválidos que no encajan en otra categoría.
+    Usage,
+    /// Extensión de código sin parser.
+    UnsupportedLanguage,
+    /// `.docsguard/config.yaml` (o `links.yaml`, `attestations.yaml`) inválido.
+    ConfigInvalid,
+    /// `.docsguard/baseline.yaml` inválido.
+    BaselineInvalid,
+    /// El archivo indicado no existe.
+    FileNotFound,
+    /// Un archivo existe pero no se pudo parsear.
+    ParseFatal,
+    /// Archivo ilegible, demasiado grande o no escribible.
+    Io,
+}
+
+impl FailureKind {
+    pub fn code(self) -> u8 {
+        match self {
+            FailureKind::Usage | FailureKind::ConfigInvalid | FailureKind::BaselineInvalid => USAGE,
+            // Como un archivo que no se puede parsear: el problema está en la entrada
+            FailureKind::UnsupportedLanguage
+            | FailureKind::FileNotFound
+            | FailureKind::ParseFatal
+            | FailureKind::Io => INPUT,
+        }
+    }
+
+    /// Nombre estable en `--json-errors`.
+    pub fn name(self) -> &'static str {
+        match self {
+            FailureKind::Usage => "usage",
+            FailureKind::UnsupportedLanguage => "unsupported-language",
+            FailureKind::ConfigInvalid => "config-invalid",
+            FailureKind::BaselineInvalid => "baseline-invalid",
+            FailureKind::FileNotFound => "file-not-found",
+            FailureKind::ParseFatal => "parse-fatal",
+            FailureKind::Io => "io",
+        }
+    }
+}
+
+/// Error clasificado. Se muestra su mensaje y, si la hay, la pista en una
+/// línea `-> ` debajo.
+#[derive(Debug)]
+pub struct Failure {
+    pub kind: FailureKind,
+    message: String,
+    path: Option<String>,
+    hint: Option<String>,
+}
+
+impl Failure {
+    pub fn new(kind: FailureKind, message: impl Into<String>) -> Self {
+        Failure {
+            kind,
+            message: message.into(),
+            path: None,
+            hint: None,
+        }
+    }
+
+    pub fn usage(message: impl Into<String>) -> Self {
+        Self::new(FailureKind::Usage, message)
+    }
+
+    /// Entrada ilegible, demasiado grande o no escribible.
+    pub fn input(message: impl Into<String>) -> Self {
+        Self::new(FailureKind::Io, message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(FailureKind::FileNotFound, message)
+    }
+
+    pub fn unsupported_language(message: impl Into<String>) -> Self {
+        Self::new(FailureKind::UnsupportedLanguage, message)
+    }
+
+    pub fn config(message: impl Into<String>) -> Self {
+        Self::new(FailureKind::ConfigInvalid, message)
+    }
+
+    pub fn baseline(message: impl Into<String>) -> Self {
+        Self::new(FailureKind::BaselineInvalid, message)
+    }
+
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::new(FailureKind::ParseFatal, message)
+    }
+
+    /// Archivo al que se refiere el error.
+    pub fn with_path(mut self, path: &Path) -> Self {
+        self.path = Some(safe_display(path));
+        self
+    }
+
+    /// Qué puede hacer el usuario para resolverlo.
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+        if let Some(hint) = &self.hint {
+            write!(f, "\n    -> {}", hint)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Failure {}
+
+/// Código de salida de un error: el `Failure` más externo de la cadena,
+/// `INPUT` si el origen es de E/S, `INTERNAL` en otro caso.
+pub fn exit_code_for(error: &anyhow::Error) -> u8 {
+    if let Some(failure) = error.downcast_ref::<Failure>() {
+        return failure.kind.code();
+    }
+    if error.downcast_ref::<std::io::Error>().is_some() {
+        return INPUT;
+    }
+    INTERNAL
+}
+
+/// Forma de un error en `--json-errors`.
+#[derive(Debug, Serialize)]
+pub struct JsonError {
+    pub error: JsonErrorBody,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonErrorBody {
+    /// `FailureKind::name`, `io` para errores de E/S sin clasificar o
+    /// `internal`.
+    pub kind: &'static str,
+    /// La cadena de contextos unida con `: `, sin la pista.
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+}
+
+impl JsonError {
+    pub fn from_error(error: &anyhow::Error) -> Self {
+        let failure = error.downcast_ref::<Failure>();
+        let kind = match failure {
+            Some(failure) => failure.kind.name(),
+            None if error.downcast_ref::<std::io::Error>().is_some() => FailureKind::Io.name(),
+            None => "internal",
+        };
+        let message = error
+            .chain()
+            .map(|cause| match cause.downcast_ref::<Failure>() {
+                Some(failure) => messages::tr(&failure.message).into_owned(),
+                None => messages::tr(&cause.to_string()).into_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join(": ");
+        JsonError {
+            error: JsonErrorBody {
+                kind,
+                message,
+                path: failure.and_then(|f| f.path.clone()),
+                hint: failure.and_then(|f| f.hint.as_deref().map(|h| messages::tr(h).into_owned())),
+            },
+        }
+    }
+
+    /// Error de clap (argumentos inválidos) con `kind: usage`.
+    pub fn usage(message: String) -> Self {
+        JsonError {
+            error: JsonErrorBody {
+                kind: FailureKind::Usage.name(),
+                message,
+                path: None,
+                hint: None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::{anyhow, Context};
+
+    #[test]
+    fn classified_errors_keep_their_code_through_context() {
+        let error = anyhow::Error::new(Failure::input("Archivo no encontrado"))
+            .context("Error al parsear src/auth.ts");
+        assert_eq!(exit_code_for(&error), INPUT);
+
+        let error = Err::<(), _>(anyhow!("did not find expected key"))
+            .context(Failure::usage("Error al parsear la configuración"))
+            .unwrap_err();
+        assert_eq!(exit_code_for(&error), USAGE);
+        assert_eq!(error.to_string(), "Error al parsear la configuración");
+    }
+
+    #[test]
+    fn fail_on_thresholds() {
+        for (fail_on, expected) in [
+            (FailOn::Error, [false, false, true]),
+            (FailOn::Warning, [false, true, true]),
+            (FailOn::Never, [false, false, false]),
+        ] {
+            let exceeded = [(0, 0), (0, 2), (1, 0)].map(|(e, w)| fail_on.exceeded(e, w));
+            assert_eq!(exceeded, expected, "{fail_on:?}");
+        }
+    }
+
+    #[test]
+    fn io_errors_are_input_and_the_rest_internal() {
+        let io = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied");
+        let error = anyhow::Error::new(io).context("No se pudo leer: docs/api.md");
+        assert_eq!(exit_code_for(&error), INPUT);
+
+        assert_eq!(exit_code_for(&anyhow!("Error al serializar")), INTERNAL);
+    }
+
+    #[test]
+    fn json_errors_report_the_failure_kind_and_the_whole_chain() {
+        let error = anyhow::Error::new(
+            Failure::unsupported_language("Extensión '.rb' no soportada.")
+                .with_path(Path::new("src/auth.rb"))
+                .with_hint("Lenguajes soportados: TypeScript"),
+        )
+        .context("Error al parsear src/auth.rb");
+        assert_eq!(
+            error.chain().nth(1).unwrap().to_string(),
+            "Extensión '.rb' no soportada.\n    -> Lenguajes soportados: TypeScript"
+        );
+        let json = serde_json::to_value(JsonError::from_error(&error)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"error": {
+                "kind": "unsupported-language",
+                "message": "Error al parsear src/auth.rb: Extensión '.rb' no soportada.",
+                "path": "src/auth.rb",
+                "hint": "Lenguajes soportados: TypeScript",
+            }})
+        );
+
+        let json =
+            serde_json::to_value(JsonError::from_error(&anyhow!("Error al serializar"))).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"error": {"kind": "internal", "message": "Error al serializar"}})
+        );
+    }
+}
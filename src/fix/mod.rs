@@ -0,0 +1,684 @@
+//! Correcciones automáticas para hallazgos con una solución mecánica.
+//!
+//! Cada corrección es una edición determinista sobre un archivo concreto;
+//! las del archivo de docs son reemplazos de rangos (`parser::doc_edit`), así
+//! que el resto del Markdown no cambia ni un byte.
+//! Las correcciones se preparan en una `Transaction`: o se escriben todas o
+//! ninguna (VUL-02).
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+use crate::core::heuristic::canonical_name;
+use crate::core::types::{parse_location, CodeEntity, DocSection, Rule, ValidationResult};
+use crate::core::validator;
+use crate::parser::code_parser::{self, safe_display};
+use crate::parser::doc_edit::{self, DocEdits};
+use crate::paths::ProjectPaths;
+use crate::transaction::Transaction;
+
+/// Proyecto parseado sobre el que se proponen las correcciones.
+pub struct FixContext<'a> {
+    pub code_entities: &'a [CodeEntity],
+    pub doc_sections: &'a [DocSection],
+    /// Archivo de docs donde se añaden secciones o se renombran argumentos.
+    pub doc_file: &'a Path,
+    /// Resuelve las rutas de los hallazgos para editar el código.
+    pub paths: &'a ProjectPaths,
+}
+
+/// Corrección aplicable a un hallazgo.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fix {
+    /// Añade al final del archivo de docs una sección esqueleto con el marcador.
+    AppendDocSection {
+        doc_file: PathBuf,
+        doc_id: String,
+        /// Contenido Markdown de la sección (incluye el marcador).
+        content: String,
+    },
+    /// Renombra un argumento documentado con una errata (`arg-typo`).
+    RenameDocArg {
+        doc_file: PathBuf,
+        line: usize,
+        from: String,
+        to: String,
+    },
+    /// Cambia el tipo documentado de un argumento por el del código
+    /// (`type-mismatch`).
+    SetDocArgType {
+        doc_file: PathBuf,
+        line: usize,
+        arg: String,
+        from: String,
+        to: String,
+    },
+    /// Borra las anotaciones `@docs` obsoletas de un bloque en conflicto
+    /// (DG001) y conserva la única cuyo ID tiene sección.
+    RemoveAnnotations {
+        code_file: PathBuf,
+        keep: String,
+        /// Anotaciones que se borran: ID y línea.
+        remove: Vec<(String, usize)>,
+    },
+}
+
+impl Fix {
+    /// Descripción corta de la corrección para mostrar al usuario.
+    pub fn describe(&self) -> String {
+        match self {
+            Fix::AppendDocSection {
+                doc_file, doc_id, ..
+            } => format!(
+                "añadir la sección '{}' al final de {}",
+                doc_id,
+                safe_display(doc_file)
+            ),
+            Fix::RenameDocArg {
+                doc_file,
+                line,
+                from,
+                to,
+            } => format!(
+                "renombrar '{}' a '{}' en {}:{}",
+                from,
+                to,
+                safe_display(doc_file),
+                line
+            ),
+            Fix::SetDocArgType {
+                doc_file,
+                line,
+                arg,
+                from,
+                to,
+            } => format!(
+                "cambiar el tipo de '{}' de '{}' a '{}' en {}:{}",
+                arg,
+                from,
+                to,
+                safe_display(doc_file),
+                line
+            ),
+            Fix::RemoveAnnotations {
+                code_file,
+                keep,
+                remove,
+            } => format!(
+                "conservar '@docs: [{}]' y borrar {} en {}",
+                keep,
+                remove
+                    .iter()
+                    .map(|(id, line)| format!("'{}' (línea {})", id, line))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                safe_display(code_file)
+            ),
+        }
+    }
+
+    /// Indica si la corrección edita código (y no solo el archivo de docs).
+    pub fn edits_code(&self) -> bool {
+        matches!(self, Fix::RemoveAnnotations { .. })
+    }
+
+    /// Archivo que edita la corrección.
+    pub fn target(&self) -> &Path {
+        match self {
+            Fix::AppendDocSection { doc_file, .. }
+            | Fix::RenameDocArg { doc_file, .. }
+            | Fix::SetDocArgType { doc_file, .. } => doc_file,
+            Fix::RemoveAnnotations { code_file, .. } => code_file,
+        }
+    }
+
+    /// Aplica la corrección al disco.
+    pub fn apply(&self) -> Result<()> {
+        let mut transaction = Transaction::new();
+        self.stage(&mut transaction)?;
+        transaction.commit().map(|_| ())
+    }
+
+    /// Prepara la corrección sobre el contenido actual de la transacción.
+    pub fn stage(&self, transaction: &mut Transaction) -> Result<()> {
+        match self {
+            Fix::AppendDocSection {
+                doc_file, content, ..
+            } => {
+                let source = transaction.read_to_string(doc_file)?;
+                let mut edits = DocEdits::new(&source);
+                edits.append_section(content)?;
+                transaction.stage(doc_file, edits.apply());
+                Ok(())
+            }
+            Fix::RenameDocArg {
+                doc_file,
+                line,
+                from,
+                to,
+            } => {
+                let rename = |text: &str| {
+                    identifier_position(text, from).map(|start| {
+                        format!("{}{}{}", &text[..start], to, &text[start + from.len()..])
+                    })
+                };
+                edit_arg_line(transaction, doc_file, *line, from, rename)
+            }
+            Fix::SetDocArgType {
+                doc_file,
+                line,
+                arg,
+                from,
+                to,
+            } => {
+                // El tipo va después del nombre (`- id (string)`) o en otra celda
+                let retype = |text: &str| {
+                    let after = identifier_position(text, arg).map_or(0, |i| i + arg.len());
+                    let start = after + identifier_position(&text[after..], from)?;
+                    Some(format!(
+                        "{}{}{}",
+                        &text[..start],
+                        to,
+                        &text[start + from.len()..]
+                    ))
+                };
+                edit_arg_line(transaction, doc_file, *line, from, retype)
+            }
+            Fix::RemoveAnnotations {
+                code_file, remove, ..
+            } => {
+                let source = transaction.read_to_string(code_file)?;
+                let lines: Vec<&str> = source.split_inclusive('\n').collect();
+                for (id, line) in remove {
+                    let annotated = lines.get(line.wrapping_sub(1)).is_some_and(|text| {
+                        code_parser::extract_docs_id_from_comment(text).as_deref() == Some(id)
+                    });
+                    if !annotated {
+                        anyhow::bail!(
+                            "'@docs: [{}]' ya no está en {}:{}; vuelve a ejecutar `check`.",
+                            id,
+                            safe_display(code_file),
+                            line
+                        );
+                    }
+                }
+                let kept: String = lines
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| !remove.iter().any(|(_, line)| *line == index + 1))
+                    .map(|(_, text)| *text)
+                    .collect();
+                transaction.stage(code_file, kept);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Reescribe con `edit` la línea `line` del archivo de docs donde se documenta
+/// un argumento: en una fila de tabla, la primera celda que `edit` cambia.
+/// Si ya no contiene `expected`, falla sin tocar nada.
+fn edit_arg_line(
+    transaction: &mut Transaction,
+    doc_file: &Path,
+    line: usize,
+    expected: &str,
+    edit: impl Fn(&str) -> Option<String>,
+) -> Result<()> {
+    let source = transaction.read_to_string(doc_file)?;
+    let mut edits = DocEdits::new(&source);
+    let edited = match doc_edit::table_cells(&source).remove(&line) {
+        Some(cells) => cells
+            .iter()
+            .enumerate()
+            .find_map(|(column, cell)| Some((column, edit(&source[cell.clone()])?)))
+            .map(|(column, text)| edits.replace_table_cell(line, column, &text)),
+        None => doc_edit::line_offset(&source, line).and_then(|start| {
+            let text = source[start..].split_inclusive('\n').next()?;
+            let end = start + text.trim_end_matches(['\r', '\n']).len();
+            Some(edits.replace(start..end, edit(&source[start..end])?))
+        }),
+    };
+    match edited {
+        Some(result) => result?,
+        None => anyhow::bail!(
+            "'{}' ya no está en {}:{}; vuelve a ejecutar `check`.",
+            expected,
+            safe_display(doc_file),
+            line
+        ),
+    }
+    transaction.stage(doc_file, edits.apply());
+    Ok(())
+}
+
+/// Posición de la primera aparición de `name` como identificador completo
+/// (`user` no casa dentro de `username`).
+fn identifier_position(text: &str, name: &str) -> Option<usize> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    text.match_indices(name).map(|(i, _)| i).find(|&i| {
+        !text[..i].chars().next_back().is_some_and(is_ident)
+            && !text[i + name.len()..].chars().next().is_some_and(is_ident)
+    })
+}
+
+/// Sugiere una corrección para un hallazgo, si existe una mecánica.
+pub fn suggest_fix(result: &ValidationResult, context: &FixContext) -> Option<Fix> {
+    let doc_file = context.doc_file;
+    match result.rule {
+        Rule::MissingDocSection => {
+            let doc_id = result.doc_id.as_ref()?;
+            let entity = context.code_entities.iter().find(|e| {
+                e.doc_id.as_ref() == Some(doc_id)
+                    && result.code_location.as_deref() == Some(e.location().as_str())
+            })?;
+            Some(Fix::AppendDocSection {
+                doc_file: doc_file.to_path_buf(),
+                doc_id: doc_id.clone(),
+                content: render_section_stub(doc_id, entity),
+            })
+        }
+        Rule::ArgTypo => {
+            let (from, to) = validator::typo_names(&result.message)?;
+            let (_, line) = parse_location(result.doc_location.as_deref()?)?;
+            Some(Fix::RenameDocArg {
+                doc_file: doc_file.to_path_buf(),
+                line,
+                from: from.to_string(),
+                to: to.to_string(),
+            })
+        }
+        Rule::TypeMismatch => {
+            let (arg, code_type, doc_type) = validator::mismatch_types(&result.message)?;
+            // El hallazgo apunta a la sección; la línea es la del argumento
+            let section = context.doc_sections.iter().find(|s| {
+                result.doc_id.as_ref() == Some(&s.id)
+                    && result.doc_location.as_deref() == Some(s.location().as_str())
+            })?;
+            // Las docs pueden escribirlo en otro estilo (`user_id` ↔ `userId`)
+            let doc_arg = section.args.iter().find(|a| {
+                canonical_name(&a.name) == canonical_name(arg)
+                    && a.type_name.as_deref() == Some(doc_type)
+            })?;
+            Some(Fix::SetDocArgType {
+                doc_file: doc_file.to_path_buf(),
+                line: doc_arg.line?,
+                arg: doc_arg.name.clone(),
+                from: doc_type.to_string(),
+                to: code_type.to_string(),
+            })
+        }
+        Rule::ConflictingDocsIds => {
+            let annotations = code_parser::conflicting_annotations(&result.message)?;
+            // Solo hay corrección mecánica si exactamente un ID tiene sección
+            let mut resolving = annotations
+                .iter()
+                .map(|(id, _)| *id)
+                .filter(|id| context.doc_sections.iter().any(|s| s.id == *id));
+            let keep = resolving.next()?;
+            if resolving.any(|id| id != keep) {
+                return None;
+            }
+            let (path, _) = parse_location(result.code_location.as_deref()?)?;
+            Some(Fix::RemoveAnnotations {
+                code_file: context.paths.resolve(path),
+                keep: keep.to_string(),
+                remove: annotations
+                    .iter()
+                    .filter(|(id, _)| *id != keep)
+                    .map(|(id, line)| (id.to_string(), *line))
+                    .collect(),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Aplica todas las correcciones disponibles para los hallazgos dados, en
+/// una sola transacción. Retorna las correcciones aplicadas.
+pub fn apply_all(results: &[ValidationResult], context: &FixContext) -> Result<Vec<Fix>> {
+    let mut applied: Vec<Fix> = Vec::new();
+    let mut transaction = Transaction::new();
+    for result in results {
+        if let Some(fix) = suggest_fix(result, context) {
+            // Dos funciones con el mismo ID producen la misma corrección
+            if applied.contains(&fix) {
+                continue;
+            }
+            fix.stage(&mut transaction)?;
+            applied.push(fix);
+        }
+    }
+    transaction.commit()?;
+    Ok(applied)
+}
+
+/// Genera el esqueleto Markdown de una sección para una función.
+pub(crate) fn render_section_stub(doc_id: &str, entity: &CodeEntity) -> String {
+    let mut out = format!("<!-- @docs-id: {} -->\n## {}\n\n", doc_id, entity.name);
+    out.push_str(&format!("TODO: documentar `{}`.\n", entity.name));
+
+    if !entity.args.is_empty() {
+        out.push_str("\n| Param | Type | Description |\n|-------|------|-------------|\n");
+        for arg in &entity.args {
+            out.push_str(&format!(
+                "| {} | {} | TODO |\n",
+                arg.name,
+                arg.type_name.as_deref().unwrap_or("")
+            ));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::diagnostics::ParseDiagnostics;
+    use crate::core::types::{Arg, ArgSource, Severity};
+    use crate::core::validator::validate_links;
+    use crate::parser::doc_parser::parse_markdown_source;
+    use crate::vfs::{MemFs, MemProject};
+    use std::sync::LazyLock;
+
+    /// Contexto sin raíz de proyecto: las rutas se usan tal cual.
+    fn context<'a>(
+        code_entities: &'a [CodeEntity],
+        doc_sections: &'a [DocSection],
+        doc_file: &'a Path,
+    ) -> FixContext<'a> {
+        static PATHS: LazyLock<ProjectPaths> = LazyLock::new(ProjectPaths::default);
+        FixContext {
+            code_entities,
+            doc_sections,
+            doc_file,
+            paths: &PATHS,
+        }
+    }
+
+    fn entity() -> CodeEntity {
+        CodeEntity {
+            name: "login".into(),
+            args: vec![Arg {
+                name: "username".into(),
+                type_name: Some("string".into()),
+                description: None,
+                source: ArgSource::Code,
+                line: None,
+                unit: None,
+                range: None,
+            }],
+            return_type: None,
+            return_fields: None,
+            doc_id: Some("auth-login".into()),
+            file_path: PathBuf::from("src/auth.ts"),
+            line: 2,
+            end_line: 2,
+            is_public: true,
+            suppressions: vec![],
+            file_link: None,
+            examples: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn missing_section_gets_append_fix() {
+        let entities = vec![entity()];
+        let results = validate_links(&entities, &[], &Default::default());
+        let error = results
+            .iter()
+            .find(|r| r.severity == Severity::Error)
+            .unwrap();
+
+        let fix = suggest_fix(error, &context(&entities, &[], Path::new("docs/api.md"))).unwrap();
+        assert!(fix.describe().contains("auth-login"));
+        let Fix::AppendDocSection { content, .. } = fix else {
+            panic!("se esperaba AppendDocSection: {fix:?}");
+        };
+        assert!(content.starts_with("<!-- @docs-id: auth-login -->"));
+        assert!(content.contains("| username | string | TODO |"));
+        // Ni siquiera sin argumentos se marca el stub como prose-only
+        let bare = CodeEntity {
+            args: vec![],
+            ..entity()
+        };
+        assert!(!render_section_stub("auth-login", &bare).contains("@docs-skip"));
+    }
+
+    #[test]
+    fn other_rules_have_no_fix() {
+        let entities = vec![CodeEntity {
+            doc_id: None,
+            ..entity()
+        }];
+        let results = validate_links(&entities, &[], &Default::default());
+        assert!(results.iter().all(|r| suggest_fix(
+            r,
+            &context(&entities, &[], Path::new("docs/api.md"))
+        )
+        .is_none()));
+    }
+
+    #[test]
+    fn applied_fix_resolves_the_link() {
+        let dir = MemFs::project();
+        let doc_file = dir.path().join("api.md");
+        dir.write(&doc_file, "# API");
+
+        let entities = vec![entity()];
+        let results = validate_links(&entities, &[], &Default::default());
+        let applied = apply_all(&results, &context(&entities, &[], &doc_file)).unwrap();
+        assert_eq!(applied.len(), 1);
+
+        let source = dir.contents(&doc_file).unwrap();
+        assert!(source.starts_with("# API\n\n<!-- @docs-id: auth-login -->"));
+        let sections =
+            parse_markdown_source(&source, &doc_file, &mut ParseDiagnostics::default()).unwrap();
+        let results = validate_links(&entities, &sections, &Default::default());
+        assert!(results.iter().all(|r| r.severity != Severity::Error));
+    }
+
+    #[test]
+    fn typo_fix_renames_the_documented_arg_on_its_line() {
+        let dir = MemFs::project();
+        let doc_file = dir.path().join("api.md");
+        let source = "<!-- @docs-id: auth-login -->\n## login\n\nEl usrename se valida.\n\n\
+                      | Param | Type | Description |\n|---|---|---|\n\
+                      | usrename | string | Nombre (usrename_hint aparte) |\n";
+        dir.write(&doc_file, source);
+
+        let entities = vec![entity()];
+        let sections =
+            parse_markdown_source(source, &doc_file, &mut ParseDiagnostics::default()).unwrap();
+        let mut results = validate_links(&entities, &sections, &Default::default());
+        crate::core::validator::reclassify_arg_typos(&entities, &sections, &mut results);
+        let applied = apply_all(&results, &context(&entities, &[], &doc_file)).unwrap();
+        assert_eq!(applied.len(), 1, "{applied:?}");
+        assert!(applied[0]
+            .describe()
+            .starts_with("renombrar 'usrename' a 'username'"));
+
+        let fixed = dir.contents(&doc_file).unwrap();
+        assert!(fixed.contains("El usrename se valida."));
+        assert!(fixed.contains("| username | string | Nombre (usrename_hint aparte) |\n"));
+        let sections =
+            parse_markdown_source(&fixed, &doc_file, &mut ParseDiagnostics::default()).unwrap();
+        let results = validate_links(&entities, &sections, &Default::default());
+        assert!(results.iter().all(|r| r.severity == Severity::Info));
+    }
+
+    #[test]
+    fn type_fix_changes_only_the_documented_type_of_the_arg() {
+        let dir = MemFs::project();
+        let doc_file = dir.path().join("api.md");
+        let source = "<!-- @docs-id: auth-login -->\n## login\n\n\
+                      - `username` (number): el number de la cuenta\n";
+        dir.write(&doc_file, source);
+
+        let entities = vec![entity()];
+        let sections =
+            parse_markdown_source(source, &doc_file, &mut ParseDiagnostics::default()).unwrap();
+        let results = validate_links(&entities, &sections, &Default::default());
+        let applied = apply_all(&results, &context(&entities, &sections, &doc_file)).unwrap();
+        assert_eq!(
+            applied,
+            [Fix::SetDocArgType {
+                doc_file: doc_file.clone(),
+                line: 4,
+                arg: "username".into(),
+                from: "number".into(),
+                to: "string".into(),
+            }]
+        );
+        assert_eq!(
+            dir.contents(&doc_file).unwrap(),
+            source.replace("(number)", "(string)")
+        );
+    }
+
+    #[test]
+    fn doc_fixes_leave_everything_outside_the_edit_byte_identical() {
+        let dir = MemFs::project();
+        let doc_file = dir.path().join("api.md");
+        let source = "<!-- @docs-id: auth-login -->\r\n## Login  \r\n\r\n\
+                      Ver [la guía][guia].\r\n\r\n\
+                      |   Param    |  Type   |\r\n\
+                      |:-----------|--------:|\r\n\
+                      |  usrename  |  string |\r\n\r\n\
+                      [guia]: https://example.com  \"Guía\"\r\n";
+        dir.write(&doc_file, source);
+        let entities = vec![entity()];
+        let sections =
+            parse_markdown_source(source, &doc_file, &mut ParseDiagnostics::default()).unwrap();
+        let mut results = validate_links(&entities, &sections, &Default::default());
+        crate::core::validator::reclassify_arg_typos(&entities, &sections, &mut results);
+        let logout = CodeEntity {
+            name: "logout".into(),
+            doc_id: Some("auth-logout".into()),
+            line: 8,
+            end_line: 8,
+            ..entity()
+        };
+        results.extend(validate_links(
+            std::slice::from_ref(&logout),
+            &[],
+            &Default::default(),
+        ));
+        let entities = [entities, vec![logout]].concat();
+        let applied = apply_all(&results, &context(&entities, &[], &doc_file)).unwrap();
+        assert_eq!(applied.len(), 2, "{applied:?}");
+
+        let fixed = dir.contents(&doc_file).unwrap();
+        let (head, appended) = fixed.split_at(source.len() + 2);
+        assert_eq!(head, source.replace("usrename", "username") + "\r\n");
+        assert!(appended.starts_with("<!-- @docs-id: auth-logout -->\r\n## logout\r\n"));
+        assert!(!appended.replace("\r\n", "").contains('\n'));
+    }
+
+    #[test]
+    fn fixes_are_not_applied_when_the_doc_file_cannot_be_written() {
+        let dir = MemFs::project();
+        let doc_file = dir.path().join("api.md");
+        dir.write(&doc_file, "# API");
+        dir.set_readonly(&doc_file);
+
+        let entities = vec![
+            entity(),
+            CodeEntity {
+                name: "logout".into(),
+                doc_id: Some("auth-logout".into()),
+                line: 8,
+                end_line: 8,
+                ..entity()
+            },
+        ];
+        let results = validate_links(&entities, &[], &Default::default());
+        assert!(apply_all(&results, &context(&entities, &[], &doc_file)).is_err());
+        assert_eq!(dir.contents(&doc_file).unwrap(), "# API");
+    }
+
+    /// Parsea `source` como `auth.ts` en `dir` y devuelve el hallazgo DG001.
+    fn conflict_in(dir: &MemProject, source: &str) -> (PathBuf, Vec<CodeEntity>, ValidationResult) {
+        let code_file = dir.path().join("auth.ts");
+        dir.write(&code_file, source);
+        let mut diagnostics = ParseDiagnostics::default();
+        let entities = crate::parser::lang::typescript::parse_typescript_source(
+            source,
+            &code_file,
+            Default::default(),
+            &mut diagnostics,
+        )
+        .unwrap();
+        let finding = validator::validate_parse_notes(&entities, &diagnostics)
+            .into_iter()
+            .find(|r| r.rule == Rule::ConflictingDocsIds)
+            .unwrap();
+        assert_eq!(finding.severity, Severity::Error);
+        (code_file, entities, finding)
+    }
+
+    fn section(id: &str) -> DocSection {
+        let source = format!("<!-- @docs-id: {id} -->\n## {id}\n");
+        parse_markdown_source(
+            &source,
+            Path::new("api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap()
+        .remove(0)
+    }
+
+    #[test]
+    fn conflicting_annotations_keep_the_only_id_with_a_section() {
+        let dir = MemFs::project();
+        let source = "// @docs: [auth-login]\n// @docs: [login-v2]\n// @docs: [login-v3]\n\
+                      export function login() {}\n";
+        let (code_file, entities, finding) = conflict_in(&dir, source);
+        let sections = [section("login-v2")];
+
+        let applied = apply_all(
+            &[finding],
+            &context(&entities, &sections, Path::new("docs/api.md")),
+        )
+        .unwrap();
+        assert_eq!(applied.len(), 1);
+        assert!(applied[0].edits_code());
+        assert!(applied[0].describe().starts_with(
+            "conservar '@docs: [login-v2]' y borrar 'auth-login' (línea 1), 'login-v3' (línea 3)"
+        ));
+        assert_eq!(
+            dir.contents(&code_file).unwrap(),
+            "// @docs: [login-v2]\nexport function login() {}\n"
+        );
+    }
+
+    #[test]
+    fn conflicting_annotations_have_no_fix_unless_exactly_one_id_resolves() {
+        let dir = MemFs::project();
+        let source = "// @docs: [auth-login]\n// @docs: [login-v2]\nexport function login() {}\n";
+        let (_, entities, finding) = conflict_in(&dir, source);
+        let doc_file = Path::new("docs/api.md");
+
+        assert!(suggest_fix(&finding, &context(&entities, &[], doc_file)).is_none());
+        let both = [section("auth-login"), section("login-v2")];
+        assert!(suggest_fix(&finding, &context(&entities, &both, doc_file)).is_none());
+    }
+
+    #[test]
+    fn conflict_fix_refuses_a_file_that_changed_since_the_check() {
+        let dir = MemFs::project();
+        let source = "// @docs: [auth-login]\n// @docs: [login-v2]\nexport function login() {}\n";
+        let (code_file, entities, finding) = conflict_in(&dir, source);
+        let edited = "// Nota nueva\n// @docs: [auth-login]\n// @docs: [login-v2]\nexport function login() {}\n";
+        dir.write(&code_file, edited);
+
+        let sections = [section("login-v2")];
+        let result = apply_all(
+            &[finding],
+            &context(&entities, &sections, Path::new("docs/api.md")),
+        );
+        assert!(result.is_err());
+        assert_eq!(dir.contents(&code_file).unwrap(), edited);
+    }
+}
@@ -0,0 +1,365 @@
+//! Consultas mínimas a git: archivos cambiados (`--changed-since`),
+//! contenido de un archivo en otra revisión (`config diff --against-git`,
+//! `--require-docs-for-new`, `diff-entities`, `check --code-ref`), autoría
+//! de sus líneas (la probable causa de `check`), primer commit de un archivo
+//! (`grace_period`) y líneas cambiadas (`--changed-only`).
+
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::exit::Failure;
+use crate::vfs;
+
+/// Archivos modificados desde el merge-base entre `since` y `HEAD`,
+/// incluyendo cambios sin commitear. Devuelve rutas canónicas; los archivos
+/// eliminados se omiten.
+pub fn changed_files(project_root: &Path, since: &str) -> Result<HashSet<PathBuf>> {
+    let toplevel = git(project_root, &["rev-parse", "--show-toplevel"])?;
+    let toplevel = PathBuf::from(toplevel.trim());
+
+    let merge_base = merge_base(project_root, since)?;
+    let diff = git(
+        project_root,
+        &["diff", "--name-only", "--no-renames", &merge_base],
+    )?;
+
+    Ok(diff
+        .lines()
+        .filter(|l| !l.is_empty())
+        .filter_map(|l| vfs::canonicalize(&toplevel.join(l)).ok())
+        .collect())
+}
+
+/// Líneas cambiadas de un archivo: todas si es nuevo y sin seguimiento, o
+/// los tramos (primera y última línea, desde 1) de los hunks del diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangedLines {
+    All,
+    Ranges(Vec<(usize, usize)>),
+}
+
+impl ChangedLines {
+    /// Si algún cambio cae entre `start` y `end`, ambas incluidas.
+    pub fn touches(&self, start: usize, end: usize) -> bool {
+        match self {
+            ChangedLines::All => true,
+            ChangedLines::Ranges(ranges) => ranges.iter().any(|&(a, b)| a <= end && start <= b),
+        }
+    }
+}
+
+/// Líneas cambiadas desde el merge-base entre `since` y `HEAD`, incluidos los
+/// cambios sin commitear y los archivos sin seguimiento, por ruta canónica.
+pub fn changed_lines(project_root: &Path, since: &str) -> Result<HashMap<PathBuf, ChangedLines>> {
+    let toplevel = git(project_root, &["rev-parse", "--show-toplevel"])?;
+    let toplevel = PathBuf::from(toplevel.trim());
+    let merge_base = merge_base(project_root, since)?;
+    let diff = git(
+        project_root,
+        &[
+            "-c",
+            "core.quotePath=false",
+            "diff",
+            "--unified=0",
+            "--no-color",
+            "--no-renames",
+            "--no-ext-diff",
+            &merge_base,
+        ],
+    )?;
+    let untracked = git(
+        project_root,
+        &["ls-files", "--others", "--exclude-standard", "--full-name"],
+    )?;
+    let hunks = parse_hunks(&diff)
+        .into_iter()
+        .map(|(file, ranges)| (file, ChangedLines::Ranges(ranges)));
+    let untracked = untracked
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| (PathBuf::from(l), ChangedLines::All));
+    Ok(hunks
+        .chain(untracked)
+        .filter_map(|(file, lines)| Some((vfs::canonicalize(&toplevel.join(file)).ok()?, lines)))
+        .collect())
+}
+
+/// Tramos de las cabeceras `@@ -a,b +c,d @@` de un `git diff --unified=0`,
+/// por archivo (relativo a la raíz del repositorio). Una eliminación pura
+/// (`d` = 0) toca las dos líneas que la rodean.
+fn parse_hunks(diff: &str) -> HashMap<PathBuf, Vec<(usize, usize)>> {
+    let mut files: HashMap<PathBuf, Vec<(usize, usize)>> = HashMap::new();
+    let mut current = None;
+    for line in diff.lines() {
+        if let Some(path) = line.strip_prefix("+++ ") {
+            current = path.strip_prefix("b/").map(PathBuf::from);
+            continue;
+        }
+        let (Some(file), Some(header)) = (&current, line.strip_prefix("@@ ")) else {
+            continue;
+        };
+        let Some(new) = header.split(' ').find_map(|part| part.strip_prefix('+')) else {
+            continue;
+        };
+        let (start, count): (Option<usize>, Option<usize>) = match new.split_once(',') {
+            Some((start, count)) => (start.parse().ok(), count.parse().ok()),
+            None => (new.parse().ok(), Some(1)),
+        };
+        let (Some(start), Some(count)) = (start, count) else {
+            continue;
+        };
+        let range: (usize, usize) = match count {
+            0 => (start.max(1), start + 1),
+            n => (start, start + n - 1),
+        };
+        files.entry(file.clone()).or_default().push(range);
+    }
+    files
+}
+
+/// Commit del merge-base entre `since` y `HEAD`.
+pub fn merge_base(project_root: &Path, since: &str) -> Result<String> {
+    let merge_base = git(project_root, &["merge-base", since, "HEAD"]).with_context(|| {
+        Failure::usage(format!("No se pudo resolver la referencia '{}'", since))
+    })?;
+    Ok(merge_base.trim().to_string())
+}
+
+/// Archivos bajo `project_root` que cambiaron desde el commit `base`,
+/// incluidos los eliminados, relativos a `project_root`.
+pub fn changed_paths(project_root: &Path, base: &str) -> Result<Vec<PathBuf>> {
+    let diff = git(
+        project_root,
+        &["diff", "--name-only", "--no-renames", "--relative", base],
+    )?;
+    Ok(diff
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(PathBuf::from)
+        .collect())
+}
+
+/// Contenido de `path` (relativo a `project_root`) en la revisión `rev`, o
+/// `None` si el archivo no existía en ella.
+pub fn show_file(project_root: &Path, rev: &str, path: &Path) -> Result<Option<String>> {
+    let commit = format!("{}^{{commit}}", rev);
+    git(project_root, &["rev-parse", "--verify", "--quiet", &commit])
+        .with_context(|| Failure::usage(format!("No se pudo resolver la referencia '{}'", rev)))?;
+    let object = format!("{}:./{}", rev, path.to_string_lossy().replace('\\', "/"));
+    if git(project_root, &["cat-file", "-e", &object]).is_err() {
+        return Ok(None);
+    }
+    git(project_root, &["show", &object]).map(Some)
+}
+
+/// Archivos bajo `project_root` en la revisión `rev`, relativos a `project_root`.
+pub fn tree_files(project_root: &Path, rev: &str) -> Result<Vec<PathBuf>> {
+    let commit = format!("{}^{{commit}}", rev);
+    git(project_root, &["rev-parse", "--verify", "--quiet", &commit])
+        .with_context(|| Failure::usage(format!("No se pudo resolver la referencia '{}'", rev)))?;
+    let tree = git(project_root, &["ls-tree", "-r", "-z", "--name-only", rev])?;
+    Ok(tree
+        .split('\0')
+        .filter(|l| !l.is_empty())
+        .map(PathBuf::from)
+        .collect())
+}
+
+/// Si `project_root` está dentro de un árbol de trabajo de git.
+pub fn is_repository(project_root: &Path) -> bool {
+    git(project_root, &["rev-parse", "--is-inside-work-tree"]).is_ok_and(|out| out.trim() == "true")
+}
+
+/// Directorio de los hooks del repositorio de `project_root` (respeta
+/// `core.hooksPath` y los worktrees).
+pub fn hooks_dir(project_root: &Path) -> Result<PathBuf> {
+    let dir = git(project_root, &["rev-parse", "--git-path", "hooks"])?;
+    Ok(project_root.join(dir.trim()))
+}
+
+/// `index.lock` del repositorio de `project_root` si existe: otra operación
+/// de git está en curso (o terminó mal) y los commits fallarán hasta que
+/// desaparezca. `None` fuera de git.
+pub fn index_lock(project_root: &Path) -> Option<PathBuf> {
+    let lock = git(project_root, &["rev-parse", "--git-path", "index.lock"]).ok()?;
+    let lock = project_root.join(lock.trim());
+    lock.exists().then_some(lock)
+}
+
+/// Ruta de `project_root` relativa a la raíz del árbol de trabajo, con `/`
+/// final (vacía en la raíz).
+pub fn worktree_prefix(project_root: &Path) -> Result<String> {
+    Ok(git(project_root, &["rev-parse", "--show-prefix"])?
+        .trim()
+        .to_string())
+}
+
+/// `git blame --porcelain` de `file` en el árbol de trabajo.
+pub fn blame(project_root: &Path, file: &Path) -> Result<String> {
+    let file = file.to_string_lossy();
+    git(project_root, &["blame", "--porcelain", "--", &file])
+}
+
+/// Último commit que tocó `file` y su fecha de commit (segundos desde
+/// 1970-01-01), o `None` si nunca se commiteó.
+pub fn last_commit(project_root: &Path, file: &Path) -> Result<Option<(String, i64)>> {
+    let file = file.to_string_lossy();
+    let log = git(project_root, &["log", "-1", "--format=%H %ct", "--", &file])?;
+    Ok(log
+        .trim()
+        .split_once(' ')
+        .and_then(|(hash, time)| time.parse().ok().map(|time| (hash.to_string(), time))))
+}
+
+/// Fecha de commit (segundos desde 1970-01-01) del primer commit que añadió
+/// `file`, siguiendo los renombrados, o `None` si nunca se commiteó.
+pub fn first_commit_time(dir: &Path, file: &Path) -> Result<Option<i64>> {
+    let file = file.to_string_lossy();
+    let log = git(
+        dir,
+        &[
+            "log",
+            "--follow",
+            "--diff-filter=A",
+            "--format=%ct",
+            "--",
+            &file,
+        ],
+    )?;
+    Ok(log.lines().filter_map(|l| l.trim().parse().ok()).min())
+}
+
+/// Ejecuta git en `dir` y devuelve su stdout.
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .context("No se pudo ejecutar git")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} falló: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?}", args);
+    }
+
+    #[test]
+    fn changed_files_includes_committed_and_uncommitted_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        run(root, &["init", "-q"]);
+        for name in ["a.ts", "b.ts", "c.ts"] {
+            std::fs::write(root.join(name), "").unwrap();
+        }
+        run(root, &["add", "."]);
+        run(root, &["commit", "-q", "-m", "base"]);
+        run(root, &["tag", "base"]);
+
+        std::fs::write(root.join("a.ts"), "// cambio").unwrap();
+        run(root, &["commit", "-q", "-am", "change a"]);
+        std::fs::write(root.join("b.ts"), "// sin commitear").unwrap();
+
+        let changed = changed_files(root, "base").unwrap();
+        let expected: HashSet<PathBuf> = ["a.ts", "b.ts"]
+            .iter()
+            .map(|n| root.join(n).canonicalize().unwrap())
+            .collect();
+        assert_eq!(changed, expected);
+    }
+
+    #[test]
+    fn changed_lines_maps_hunks_and_untracked_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        run(root, &["init", "-q"]);
+        std::fs::write(root.join("a.ts"), "1\n2\n3\n4\n5\n").unwrap();
+        std::fs::write(root.join("b.ts"), "1\n2\n3\n").unwrap();
+        std::fs::write(root.join("c.ts"), "1\n").unwrap();
+        run(root, &["add", "."]);
+        run(root, &["commit", "-q", "-m", "base"]);
+
+        std::fs::write(root.join("a.ts"), "1\n2\ntres\n4\n5\n6\n7\n").unwrap();
+        std::fs::write(root.join("b.ts"), "1\n3\n").unwrap();
+        std::fs::write(root.join("d.ts"), "nuevo\n").unwrap();
+
+        let changed = changed_lines(root, "HEAD").unwrap();
+        let at = |name: &str| changed.get(&root.join(name).canonicalize().unwrap());
+        assert_eq!(
+            at("a.ts"),
+            Some(&ChangedLines::Ranges(vec![(3, 3), (6, 7)]))
+        );
+        // La línea 2 se eliminó: toca la 1 y la que ahora es la 2
+        assert_eq!(at("b.ts"), Some(&ChangedLines::Ranges(vec![(1, 2)])));
+        assert_eq!(at("c.ts"), None);
+        assert_eq!(at("d.ts"), Some(&ChangedLines::All));
+        assert!(at("a.ts").unwrap().touches(5, 6));
+        assert!(!at("a.ts").unwrap().touches(4, 5));
+    }
+
+    #[test]
+    fn show_file_reads_an_older_revision_and_none_when_absent() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        run(root, &["init", "-q"]);
+        std::fs::create_dir(root.join("cfg")).unwrap();
+        std::fs::write(root.join("cfg/a.yaml"), "v: 1\n").unwrap();
+        run(root, &["add", "."]);
+        run(root, &["commit", "-q", "-m", "base"]);
+        std::fs::write(root.join("cfg/a.yaml"), "v: 2\n").unwrap();
+
+        let show = |path: &str| show_file(root, "HEAD", Path::new(path)).unwrap();
+        assert_eq!(show("cfg/a.yaml").as_deref(), Some("v: 1\n"));
+        assert_eq!(show("cfg/b.yaml"), None);
+        let err = show_file(root, "no-existe", Path::new("cfg/a.yaml")).unwrap_err();
+        assert_eq!(crate::exit::exit_code_for(&err), crate::exit::USAGE);
+    }
+
+    #[test]
+    fn first_commit_time_follows_renames_and_is_none_when_uncommitted() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        run(root, &["init", "-q"]);
+        std::fs::write(root.join("a.ts"), "export function a() {}\n").unwrap();
+        run(root, &["add", "."]);
+        run(root, &["commit", "-q", "-m", "add a"]);
+        let (_, added) = last_commit(root, Path::new("a.ts")).unwrap().unwrap();
+        run(root, &["mv", "a.ts", "b.ts"]);
+        run(root, &["commit", "-q", "-m", "rename"]);
+        std::fs::write(root.join("c.ts"), "").unwrap();
+
+        assert_eq!(
+            first_commit_time(root, Path::new("b.ts")).unwrap(),
+            Some(added)
+        );
+        assert_eq!(first_commit_time(root, Path::new("c.ts")).unwrap(), None);
+    }
+
+    #[test]
+    fn unknown_reference_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        run(dir.path(), &["init", "-q"]);
+        assert!(changed_files(dir.path(), "no-existe").is_err());
+    }
+}
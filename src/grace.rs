@@ -0,0 +1,236 @@
+//! Periodo de gracia de los archivos de código nuevos (`grace_period`).
+//!
+//! Un Error en un archivo cuyo primer commit tiene menos de `days` días baja
+//! a Warning y queda anotado con el último día del periodo; pasado ese día se
+//! aplica la severidad normal sin tocar nada. La severidad se resuelve en
+//! este orden, cada paso sobre el resultado del anterior:
+//!
+//! 1. supresiones en línea;
+//! 2. niveles de regla: por defecto, `--preset`, `rules:` de `config.yaml` y
+//!    `--set rules.…` (`docsguard explain` los muestra);
+//! 3. periodo de gracia: solo rebaja Error a Warning, nunca sube ni quita un
+//!    hallazgo, así que un `off` sigue en `off`;
+//! 4. baseline, que guarda la severidad ya rebajada;
+//! 5. `--fail-on`, que decide el código de salida.
+//!
+//! La fecha de creación sale del primer commit que añadió el archivo
+//! (siguiendo renombrados); un archivo aún sin commitear es de hoy. Fuera de
+//! git se usa la fecha de creación del sistema de archivos, que una copia o
+//! un checkout reinician: la anotación lo dice.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::config::GracePeriodConfig;
+use crate::core::types::{parse_location, CreationSource, Grace, Severity, ValidationResult};
+use crate::dates;
+use crate::git;
+use crate::paths::ProjectPaths;
+use crate::vfs;
+
+/// Día de creación de un archivo (días desde 1970-01-01) y de dónde sale.
+pub type Created = (i64, CreationSource);
+
+/// Fechas de creación de los archivos de código.
+pub trait CreationDates {
+    /// Creación de `file` (ruta utilizable para leer), o `None` si no se sabe.
+    fn created(&self, file: &Path) -> Option<Created>;
+}
+
+/// Fechas de git, o del sistema de archivos fuera de git, con caché por
+/// ejecución: cada archivo se consulta una vez.
+#[derive(Default)]
+pub struct FileCreationDates {
+    files: RefCell<HashMap<PathBuf, Option<Created>>>,
+    /// Si cada directorio está dentro de un repositorio.
+    repositories: RefCell<HashMap<PathBuf, bool>>,
+}
+
+impl FileCreationDates {
+    fn lookup(&self, file: &Path) -> Option<Created> {
+        let dir = match file.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let in_git = *self
+            .repositories
+            .borrow_mut()
+            .entry(dir.to_path_buf())
+            .or_insert_with(|| git::is_repository(dir));
+        if in_git {
+            let name = file.file_name()?;
+            return match git::first_commit_time(dir, Path::new(name)).ok()? {
+                Some(time) => Some((time.div_euclid(86_400), CreationSource::Git)),
+                None => Some((dates::today_days(), CreationSource::Git)),
+            };
+        }
+        let created = vfs::metadata(file).ok()?.created?;
+        let seconds = created
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()?
+            .as_secs();
+        Some((seconds as i64 / 86_400, CreationSource::Filesystem))
+    }
+}
+
+impl CreationDates for FileCreationDates {
+    fn created(&self, file: &Path) -> Option<Created> {
+        if let Some(created) = self.files.borrow().get(file) {
+            return *created;
+        }
+        let created = self.lookup(file);
+        self.files.borrow_mut().insert(file.to_path_buf(), created);
+        created
+    }
+}
+
+/// Rebaja a Warning los Error de archivos de código creados hace menos de
+/// `policy.days` días contando hasta `today`.
+pub fn apply(
+    results: &mut [ValidationResult],
+    policy: GracePeriodConfig,
+    paths: &ProjectPaths,
+    dates: &dyn CreationDates,
+    today: i64,
+) {
+    for result in results {
+        if result.severity != Severity::Error {
+            continue;
+        }
+        let Some((file, _)) = result.code_location.as_deref().and_then(parse_location) else {
+            continue;
+        };
+        let Some((created, source)) = dates.created(&paths.resolve(file)) else {
+            continue;
+        };
+        let last_day = created + i64::from(policy.days) - 1;
+        if today <= last_day {
+            result.grace = Some(Grace {
+                until: dates::civil_date(last_day),
+                source,
+                original: result.severity,
+            });
+            result.severity = Severity::Warning;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Rule;
+
+    /// Fechas fijas por archivo, en vez de git.
+    struct FakeDates(HashMap<PathBuf, Created>);
+
+    impl CreationDates for FakeDates {
+        fn created(&self, file: &Path) -> Option<Created> {
+            self.0.get(file).copied()
+        }
+    }
+
+    fn finding(severity: Severity, location: Option<&str>) -> ValidationResult {
+        ValidationResult {
+            severity,
+            rule: Rule::MissingArg,
+            message: "Argumento 'token' de la firma no está documentado.".into(),
+            function_name: Some("login".into()),
+            code_location: location.map(Into::into),
+            doc_id: None,
+            doc_location: None,
+            hint: None,
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+
+    #[test]
+    fn errors_of_recent_files_are_capped_until_the_last_day() {
+        let today = dates::parse_date("2026-10-14").unwrap();
+        let dates = FakeDates(HashMap::from([
+            (
+                PathBuf::from("src/new.ts"),
+                (today - 3, CreationSource::Git),
+            ),
+            (
+                PathBuf::from("src/old.ts"),
+                (today - 30, CreationSource::Git),
+            ),
+            (
+                PathBuf::from("src/copied.ts"),
+                (today - 13, CreationSource::Filesystem),
+            ),
+        ]));
+        let mut results = vec![
+            finding(Severity::Error, Some("src/new.ts:3")),
+            finding(Severity::Info, Some("src/new.ts:8")),
+            finding(Severity::Error, Some("src/old.ts:3")),
+            finding(Severity::Error, Some("src/copied.ts:1")),
+            finding(Severity::Error, None),
+        ];
+        let policy = GracePeriodConfig { days: 14 };
+        apply(
+            &mut results,
+            policy,
+            &ProjectPaths::default(),
+            &dates,
+            today,
+        );
+
+        let severities: Vec<Severity> = results.iter().map(|r| r.severity).collect();
+        assert_eq!(
+            severities,
+            [
+                Severity::Warning,
+                Severity::Info,
+                Severity::Error,
+                Severity::Warning,
+                Severity::Error
+            ]
+        );
+        assert_eq!(
+            results[0].grace.as_ref().unwrap().describe(),
+            "Archivo nuevo — periodo de gracia hasta 2026-10-24"
+        );
+        assert!(results[1].grace.is_none());
+        assert_eq!(
+            results[3].grace.as_ref().unwrap().describe(),
+            "Archivo nuevo — periodo de gracia hasta 2026-10-14 \
+             (fecha del sistema de archivos, menos fiable que git)"
+        );
+
+        // El día siguiente al último vuelve la severidad normal
+        let mut results = vec![finding(Severity::Error, Some("src/copied.ts:1"))];
+        apply(
+            &mut results,
+            policy,
+            &ProjectPaths::default(),
+            &dates,
+            today + 1,
+        );
+        assert_eq!(results[0].severity, Severity::Error);
+        assert!(results[0].grace.is_none());
+    }
+
+    #[test]
+    fn outside_git_the_date_comes_from_the_vfs() {
+        let fs = vfs::MemFs::new();
+        let file = Path::new("/sin-git/src/new.ts");
+        fs.write(file, "export function login() {}\n");
+        fs.write("/sin-git/src/unknown.ts", "");
+        let day = dates::parse_date("2026-10-11").unwrap();
+        let created = std::time::UNIX_EPOCH + std::time::Duration::from_secs(day as u64 * 86_400);
+        fs.set_created(file, created);
+
+        let dates = FileCreationDates::default();
+        vfs::scoped(fs, || {
+            assert_eq!(dates.created(file), Some((day, CreationSource::Filesystem)));
+            assert_eq!(dates.created(Path::new("/sin-git/src/unknown.ts")), None);
+        });
+    }
+}
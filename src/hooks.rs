@@ -0,0 +1,328 @@
+//! `docsguard install-hooks`: el hook `pre-commit` de git que valida lo que
+//! toca cada commit.
+//!
+//! DocsGuard solo gestiona su bloque, entre `BEGIN` y `END`: ejecutar dos
+//! veces lo deja igual, `--uninstall` quita el bloque y nada más, y un hook
+//! que ya existía sin el bloque no se toca salvo con `--force`, que añade el
+//! bloque al final. El bloque ejecuta `docsguard check --combo <nombre>
+//! --changed-only` por cada combinación de `combos:` que lee el árbol de
+//! trabajo, o `docsguard check-all --changed-only` si no hay ninguna; en el
+//! primer commit, sin `--changed-only`.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::exit::Failure;
+use crate::git;
+use crate::messages::{eprintln_tr, println_tr};
+use crate::parser::code_parser::safe_display;
+use crate::transaction::Transaction;
+use crate::vfs;
+
+/// Primera línea del bloque gestionado.
+pub const BEGIN: &str = "# >>> docsguard >>>";
+/// Última línea del bloque gestionado.
+pub const END: &str = "# <<< docsguard <<<";
+
+const SHEBANG: &str = "#!/bin/sh";
+
+/// `--changed-only` salvo en el primer commit, que no tiene `HEAD` con el
+/// que comparar: entonces se valida todo.
+const CHANGED: &str =
+    "changed=--changed-only; git rev-parse --verify --quiet HEAD >/dev/null || changed=";
+
+/// Qué hizo `install`.
+#[derive(Debug, PartialEq, Eq)]
+enum Installed {
+    Created,
+    Updated,
+    UpToDate,
+    Appended,
+}
+
+/// Contenido del hook con `block` instalado, o `None` si el hook ya existe
+/// sin bloque y no hay `force`.
+fn with_block(
+    existing: Option<&str>,
+    block: &str,
+    force: bool,
+) -> Result<Option<(String, Installed)>> {
+    let Some(existing) = existing else {
+        return Ok(Some((
+            format!("{}\n{}", SHEBANG, block),
+            Installed::Created,
+        )));
+    };
+    match block_span(existing)? {
+        Some((start, end)) => {
+            let content = format!("{}{}{}", &existing[..start], block, &existing[end..]);
+            let installed = match content == existing {
+                true => Installed::UpToDate,
+                false => Installed::Updated,
+            };
+            Ok(Some((content, installed)))
+        }
+        None if force => {
+            let separator = match existing.ends_with('\n') || existing.is_empty() {
+                true => "",
+                false => "\n",
+            };
+            Ok(Some((
+                format!("{}{}{}", existing, separator, block),
+                Installed::Appended,
+            )))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Contenido del hook sin el bloque; `None` si no lo tiene.
+fn without_block(existing: &str) -> Result<Option<String>> {
+    Ok(block_span(existing)?
+        .map(|(start, end)| format!("{}{}", &existing[..start], &existing[end..])))
+}
+
+/// Bytes del bloque en `content`, de `BEGIN` al salto de línea tras `END`.
+fn block_span(content: &str) -> Result<Option<(usize, usize)>> {
+    let mut start = None;
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let next = offset + line.len();
+        match (line.trim_end(), start) {
+            (BEGIN, None) => start = Some(offset),
+            (END, Some(start)) => return Ok(Some((start, next))),
+            _ => {}
+        }
+        offset = next;
+    }
+    match start {
+        Some(_) => Err(Failure::input(format!(
+            "El hook tiene la marca `{}` sin la de cierre `{}`.",
+            BEGIN, END
+        ))
+        .with_hint("Corrige el hook a mano o bórralo y vuelve a instalarlo.")
+        .into()),
+        None => Ok(None),
+    }
+}
+
+/// Argumento de `sh`, entre comillas simples solo si hace falta.
+fn shell_quote(arg: &str) -> String {
+    let plain = |c: char| c.is_ascii_alphanumeric() || "._/-=".contains(c);
+    match !arg.is_empty() && arg.chars().all(plain) {
+        true => arg.to_string(),
+        false => format!("'{}'", arg.replace('\'', r"'\''")),
+    }
+}
+
+/// Órdenes del bloque para el proyecto en `prefix` (relativo a la raíz del
+/// árbol de trabajo, donde git ejecuta el hook).
+fn commands(config: &Config, prefix: &str) -> Vec<String> {
+    let prefix = prefix.trim_end_matches('/');
+    let root = |flag: &str| match prefix.is_empty() {
+        true => String::new(),
+        false => format!(" {} {}", flag, shell_quote(prefix)),
+    };
+    let combos: Vec<String> = config
+        .combos
+        .iter()
+        .filter(|(_, combo)| combo.code_ref.is_none() && combo.docs_ref.is_none())
+        .map(|(name, _)| {
+            format!(
+                "docsguard check --combo {} $changed{}",
+                shell_quote(name),
+                root("--project-root")
+            )
+        })
+        .collect();
+    match combos.is_empty() {
+        true => vec![format!("docsguard check-all $changed{}", root("--root"))],
+        false => combos,
+    }
+}
+
+fn block(commands: &[String]) -> String {
+    let mut block = format!(
+        "{}\n# Gestionado por `docsguard install-hooks`; `--uninstall` quita este bloque.\n{}\n",
+        BEGIN, CHANGED
+    );
+    for command in commands {
+        block.push_str(&format!("{} || exit 1\n", command));
+    }
+    block.push_str(END);
+    block.push('\n');
+    block
+}
+
+/// Hook `pre-commit` del repositorio de `project_root`; fuera de git es un
+/// error de uso.
+fn hook_path(project_root: &Path) -> Result<PathBuf> {
+    if !git::is_repository(project_root) {
+        anyhow::bail!(Failure::usage(format!(
+            "install-hooks necesita un repositorio git y {} no está en ninguno.",
+            safe_display(project_root)
+        ))
+        .with_hint("Ejecuta desde un checkout de git."));
+    }
+    Ok(git::hooks_dir(project_root)?.join("pre-commit"))
+}
+
+fn read_hook(path: &Path) -> Result<Option<String>> {
+    if !vfs::exists(path) {
+        return Ok(None);
+    }
+    vfs::read_to_string(path)
+        .map(Some)
+        .with_context(|| Failure::input(format!("No se pudo leer el hook {}", safe_display(path))))
+}
+
+fn write_hook(path: &Path, content: String) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        vfs::create_dir_all(dir)
+            .with_context(|| format!("No se pudo crear el directorio: {}", safe_display(dir)))?;
+    }
+    let mut transaction = Transaction::new();
+    transaction.stage(path, content);
+    transaction.commit()?;
+    vfs::set_executable(path)
+        .with_context(|| format!("No se pudo hacer ejecutable: {}", safe_display(path)))
+}
+
+/// Avisa si hay un `index.lock`: el hook queda instalado, pero el próximo
+/// commit fallará antes de ejecutarlo.
+fn warn_index_lock(project_root: &Path) {
+    if let Some(lock) = git::index_lock(project_root) {
+        eprintln_tr!(
+            "Aviso: {} existe: hay otra operación de git en curso; los commits fallarán hasta que termine.",
+            safe_display(&lock)
+        );
+    }
+}
+
+/// `docsguard install-hooks [--force]`.
+pub fn run_install(project_root: &Path, force: bool) -> Result<()> {
+    let config = Config::load(project_root)?;
+    let path = hook_path(project_root)?;
+    let commands = commands(&config, &git::worktree_prefix(project_root)?);
+    let existing = read_hook(&path)?;
+    let Some((content, installed)) = with_block(existing.as_deref(), &block(&commands), force)?
+    else {
+        anyhow::bail!(Failure::usage(format!(
+            "{} ya existe y no lo creó DocsGuard.",
+            safe_display(&path)
+        ))
+        .with_hint("Revísalo y repite con --force para añadir el bloque de DocsGuard al final."));
+    };
+    let shown = safe_display(&path);
+    match installed {
+        Installed::UpToDate => {
+            println_tr!("El hook pre-commit de {} ya está al día.", shown);
+            return Ok(());
+        }
+        Installed::Created => println_tr!("Instalado el hook pre-commit en {}.", shown),
+        Installed::Updated => println_tr!("Actualizado el bloque de DocsGuard en {}.", shown),
+        Installed::Appended => println_tr!(
+            "Añadido el bloque de DocsGuard al final del hook existente {}.",
+            shown
+        ),
+    }
+    write_hook(&path, content)?;
+    warn_index_lock(project_root);
+    println_tr!("Antes de cada commit:");
+    for command in &commands {
+        println!("  {}", command.replace("$changed", "--changed-only"));
+    }
+    Ok(())
+}
+
+/// `docsguard install-hooks --uninstall`: quita el bloque y, si no queda
+/// nada más, el hook.
+pub fn run_uninstall(project_root: &Path) -> Result<()> {
+    let path = hook_path(project_root)?;
+    let shown = safe_display(&path);
+    let Some(rest) = read_hook(&path)?
+        .as_deref()
+        .map(without_block)
+        .transpose()?
+        .flatten()
+    else {
+        println_tr!("No hay bloque de DocsGuard en {}: nada que quitar.", shown);
+        return Ok(());
+    };
+    if rest.trim().is_empty() || rest.trim() == SHEBANG {
+        vfs::remove_file(&path)
+            .with_context(|| format!("No se pudo borrar: {}", safe_display(&path)))?;
+        println_tr!("Borrado {}: solo tenía el bloque de DocsGuard.", shown);
+    } else {
+        write_hook(&path, rest)?;
+        println_tr!("Quitado el bloque de DocsGuard de {}.", shown);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Combo;
+
+    #[test]
+    fn the_block_is_created_replaced_in_place_and_removed_alone() {
+        let block_v1 = block(&["docsguard check-all --changed-only".into()]);
+        let (created, installed) = with_block(None, &block_v1, false).unwrap().unwrap();
+        assert_eq!(installed, Installed::Created);
+        assert!(created.starts_with("#!/bin/sh\n# >>> docsguard >>>\n"));
+        assert_eq!(
+            with_block(Some(&created), &block_v1, false).unwrap(),
+            Some((created.clone(), Installed::UpToDate))
+        );
+
+        let foreign = "#!/bin/sh\nnpm run lint";
+        assert_eq!(with_block(Some(foreign), &block_v1, false).unwrap(), None);
+        let (appended, installed) = with_block(Some(foreign), &block_v1, true).unwrap().unwrap();
+        assert_eq!(installed, Installed::Appended);
+        assert_eq!(appended, format!("{}\n{}", foreign, block_v1));
+
+        // Se sustituye en su sitio: lo que hay antes y después se queda
+        let surrounded = format!("{}echo fin\n", appended);
+        let block_v2 = block(&["docsguard check --combo api --changed-only".into()]);
+        let (updated, installed) = with_block(Some(&surrounded), &block_v2, false)
+            .unwrap()
+            .unwrap();
+        assert_eq!(installed, Installed::Updated);
+        assert_eq!(updated, format!("{}\n{}echo fin\n", foreign, block_v2));
+
+        assert_eq!(
+            without_block(&updated).unwrap().unwrap(),
+            format!("{}\necho fin\n", foreign)
+        );
+        assert_eq!(without_block(foreign).unwrap(), None);
+        assert!(without_block(&format!("{}\n", BEGIN)).is_err());
+    }
+
+    #[test]
+    fn the_commands_follow_the_combos_that_read_the_working_tree() {
+        let mut config = Config::default();
+        assert_eq!(commands(&config, ""), ["docsguard check-all $changed"]);
+        assert_eq!(
+            commands(&config, "tools/my app/"),
+            ["docsguard check-all $changed --root 'tools/my app'"]
+        );
+        for (name, code_ref) in [("web", None), ("api", None), ("legacy", Some("v1"))] {
+            config.combos.insert(
+                name.into(),
+                Combo {
+                    code_ref: code_ref.map(String::from),
+                    ..Combo::default()
+                },
+            );
+        }
+        assert_eq!(
+            commands(&config, "svc/"),
+            [
+                "docsguard check --combo api $changed --project-root svc",
+                "docsguard check --combo web $changed --project-root svc",
+            ]
+        );
+    }
+}
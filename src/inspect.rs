@@ -0,0 +1,454 @@
+//! Comandos `parse` y `list`: muestran lo que DocsGuard extrae.
+//!
+//! Herramientas de depuración. `parse` mira un archivo: para docs lista
+//! secciones y argumentos con la estrategia que los produjo; para código,
+//! funciones con su anotación. `list` cruza el código con las docs y marca
+//! qué función resolvió a qué sección, con la misma regla que `check`
+//! (`validator::section_has_link`), para ver por qué un enlace no se detecta.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::core::constraints::{self, UnitKeywords};
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::types::{Arg, ArgSource, CodeEntity, DocSection};
+use crate::core::validator;
+use crate::messages;
+use crate::messages::{print_tr, println_tr};
+use crate::parser::code_parser::{self, safe_display};
+use crate::parser::doc_parser;
+
+/// Ejecuta `docsguard parse <file>`.
+pub fn run_parse(file: &Path, project_root: &Path) -> Result<()> {
+    code_parser::require_file_exists(file, "entrada")?;
+    println_tr!("DocsGuard Parse — {}\n", safe_display(file));
+
+    if doc_parser::is_markdown(file) {
+        print_doc_file(file, project_root)
+    } else {
+        print_code_file(file, project_root)
+    }
+}
+
+fn print_doc_file(file: &Path, project_root: &Path) -> Result<()> {
+    let config = Config::load(project_root)?;
+    let sections = doc_parser::parse_docs(file, &config)
+        .context("Error al parsear el archivo de documentación")?;
+
+    if let Some(ref enabled) = config.doc_arg_sources {
+        let labels: Vec<&str> = enabled.iter().map(ArgSource::label).collect();
+        println_tr!(
+            "  [config] Estrategias habilitadas: {}\n",
+            labels.join(", ")
+        );
+    }
+
+    for section in &sections {
+        println_tr!(
+            "  [{}] '{}'{} ({})",
+            section.id,
+            section.title.as_deref().unwrap_or("sin título"),
+            if section.title_inferred {
+                " (título inferido)"
+            } else {
+                ""
+            },
+            section.location()
+        );
+        if let Some(since) = &section.since {
+            println_tr!("      desde: {}", since);
+        }
+        if section.no_link {
+            println_tr!("      no vinculable (@docs-no-link)");
+        }
+        for arg in &section.args {
+            println_tr!("      {}", render_arg(arg));
+        }
+    }
+    println_tr!("\n  {}.", messages::SECTIONS.count(sections.len()));
+    Ok(())
+}
+
+fn print_code_file(file: &Path, project_root: &Path) -> Result<()> {
+    let config = Config::load(project_root)?;
+    let mut entities = code_parser::parse_code_file(file)
+        .with_context(|| format!("Error al parsear {}", file.display()))?;
+    constraints::annotate_entities(&mut entities, &UnitKeywords::new(&config.units));
+
+    for entity in &entities {
+        let link = entity
+            .doc_id
+            .as_ref()
+            .map(|id| format!(" @docs: [{}]", id))
+            .unwrap_or_default();
+        println_tr!("  fn {} ({}){}", entity.name, entity.location(), link);
+        for arg in &entity.args {
+            println_tr!("      {}", render_arg(arg));
+        }
+        for example in &entity.examples {
+            println_tr!(
+                "      @example{} (línea {})",
+                example
+                    .lang
+                    .as_deref()
+                    .map(|l| format!(" {}", l))
+                    .unwrap_or_default(),
+                example.line
+            );
+        }
+    }
+    println_tr!("\n  {}.", messages::FUNCTIONS.count(entities.len()));
+    Ok(())
+}
+
+/// Ejecuta `docsguard list <doc_file> <code_files>...`.
+pub fn run_list(
+    code_files: &[PathBuf],
+    doc_file: &Path,
+    project_root: &Path,
+    unlinked_only: bool,
+) -> Result<()> {
+    for code_file in code_files {
+        code_parser::require_file_exists(code_file, "código")?;
+    }
+    code_parser::require_file_exists(doc_file, "documentación")?;
+    let config = Config::load(project_root)?;
+    let entities =
+        code_parser::parse_project_code(code_files, &config, &mut ParseDiagnostics::default())?;
+    let sections = doc_parser::parse_docs(doc_file, &config)
+        .context("Error al parsear el archivo de documentación")?;
+    print_tr!("{}", render_list(&entities, &sections, unlinked_only));
+    Ok(())
+}
+
+/// Tablas de funciones y secciones con lo que resolvió cada una; con
+/// `unlinked_only`, solo las que no resolvieron.
+fn render_list(entities: &[CodeEntity], sections: &[DocSection], unlinked_only: bool) -> String {
+    let mut functions = Vec::new();
+    let mut unresolved_functions = 0;
+    for entity in entities {
+        let (annotation, link) = entity_link(entity, sections);
+        let resolved = link.starts_with('→');
+        unresolved_functions += usize::from(!resolved);
+        if !unlinked_only || !resolved {
+            functions.push(vec![
+                entity.name.clone(),
+                entity.location(),
+                annotation,
+                link,
+            ]);
+        }
+    }
+
+    let mut rows = Vec::new();
+    let mut unresolved_sections = 0;
+    for section in sections {
+        let link = section_link(section, entities, sections);
+        let resolved = !link.starts_with('✗');
+        unresolved_sections += usize::from(!resolved);
+        if !unlinked_only || !resolved {
+            rows.push(vec![
+                section.id.clone(),
+                section.title.clone().unwrap_or_else(|| "-".into()),
+                section.location(),
+                section.args.len().to_string(),
+                link,
+            ]);
+        }
+    }
+
+    let mut out = String::from("DocsGuard List\n\n");
+    out.push_str(&format!(
+        "{} ({})\n",
+        if unlinked_only {
+            "Funciones sin resolver"
+        } else {
+            "Funciones"
+        },
+        functions.len()
+    ));
+    out.push_str(&table(
+        &["Función", "Ubicación", "Anotación", "Enlace"],
+        &functions,
+    ));
+    out.push_str(&format!(
+        "\n{} ({})\n",
+        if unlinked_only {
+            "Secciones sin resolver"
+        } else {
+            "Secciones"
+        },
+        rows.len()
+    ));
+    out.push_str(&table(
+        &["ID", "Título", "Ubicación", "Args", "Enlace"],
+        &rows,
+    ));
+    out.push_str(&format!(
+        "\nSin resolver: {} de {} y {} de {}.\n",
+        unresolved_functions,
+        messages::FUNCTIONS.count(entities.len()),
+        unresolved_sections,
+        messages::SECTIONS.count(sections.len())
+    ));
+    out
+}
+
+/// Anotación de `entity` y la sección a la que resolvió (`→ docs/api.md:12`),
+/// o por qué no resolvió (`✗ …`).
+fn entity_link(entity: &CodeEntity, sections: &[DocSection]) -> (String, String) {
+    let (annotation, id) = match (&entity.doc_id, &entity.file_link) {
+        (Some(id), _) => (id.clone(), id),
+        (None, Some(link)) => (format!("@docs-file: {}", link.doc_id), &link.doc_id),
+        (None, None) => return ("-".into(), messages::tr("✗ sin anotación").into()),
+    };
+    let locations: Vec<String> = sections
+        .iter()
+        .filter(|s| s.id == *id)
+        .map(DocSection::location)
+        .collect();
+    let link = if locations.is_empty() {
+        messages::tr("✗ sin sección con ese ID").into()
+    } else {
+        format!("→ {}", locations.join(", "))
+    };
+    (annotation, link)
+}
+
+/// Funciones que resolvieron a `section` (`← login, logout`), o por qué
+/// ninguna (`✗ sin enlace`).
+fn section_link(section: &DocSection, entities: &[CodeEntity], sections: &[DocSection]) -> String {
+    if section.no_link {
+        return "@docs-no-link".into();
+    }
+    let names: Vec<&str> = entities
+        .iter()
+        .filter(|e| e.doc_id.as_deref() == Some(section.id.as_str()))
+        .map(|e| e.name.as_str())
+        .collect();
+    if !names.is_empty() {
+        format!("← {}", names.join(", "))
+    } else if validator::section_has_link(section, entities, sections) {
+        "← @docs-file".into()
+    } else {
+        messages::tr("✗ sin enlace").into()
+    }
+}
+
+/// Filas alineadas por columnas bajo `header`; `(ninguna)` si no hay filas.
+/// La cabecera sale en el idioma de la salida: la alineación es de lo que se
+/// imprime.
+fn table(header: &[&str], rows: &[Vec<String>]) -> String {
+    if rows.is_empty() {
+        return "  (ninguna)\n".into();
+    }
+    let header: Vec<String> = header.iter().map(|h| messages::tr(h).into()).collect();
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let line = |cells: Vec<&str>| {
+        let mut out = String::from(" ");
+        for (cell, width) in cells.iter().zip(&widths) {
+            out.push_str(&format!(" {:<width$} ", cell, width = width));
+        }
+        out.trim_end().to_string() + "\n"
+    };
+    let mut out = line(header.iter().map(String::as_str).collect());
+    for row in rows {
+        out.push_str(&line(row.iter().map(String::as_str).collect()));
+    }
+    out
+}
+
+/// `name: type (ms, 100–30000)  [tabla, línea 84]`
+fn render_arg(arg: &Arg) -> String {
+    let mut out = arg.name.clone();
+    if let Some(ref type_name) = arg.type_name {
+        out.push_str(": ");
+        out.push_str(type_name);
+    }
+    let constraints: Vec<String> = arg
+        .unit
+        .clone()
+        .into_iter()
+        .chain(arg.range.map(|r| r.to_string()))
+        .collect();
+    if !constraints.is_empty() {
+        out.push_str(&format!(" ({})", constraints.join(", ")));
+    }
+    if arg.source != ArgSource::Code {
+        match arg.line {
+            Some(line) => out.push_str(&format!(
+                "  {}",
+                messages::tr(&format!("[{}, línea {}]", arg.source.label(), line))
+            )),
+            None => out.push_str(&format!("  [{}]", messages::tr(arg.source.label()))),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::ValueRange;
+    use crate::vfs::MemFs;
+
+    const AUTH_TS: &str = "\
+// @docs: [auth-login]
+export function login(user: string) {}
+
+// @docs: [auth-logut]
+export function logout(token: string) {}
+
+export function refresh(token: string) {}
+";
+
+    const API_MD: &str = "\
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|---|---|---|
+| user | string | Usuario |
+
+<!-- @docs-id: auth-logout -->
+## logout
+
+<!-- @docs-id: payments -->
+## Pagos
+
+<!-- @docs-id: payments-charge -->
+### charge
+
+<!-- @docs-id: internals @docs-no-link -->
+## Internos
+";
+
+    /// Lista del proyecto de ejemplo, con `payments.ts` enlazado por archivo.
+    fn list(unlinked_only: bool) -> String {
+        let dir = MemFs::project();
+        let root = dir.path();
+        dir.write(root.join("src/auth.ts"), AUTH_TS);
+        dir.write(
+            root.join("src/payments.ts"),
+            "// @docs-file: [payments]\n\nexport function charge(amount: number) {}\n",
+        );
+        dir.write(root.join("docs/api.md"), API_MD);
+        let config = Config {
+            paths: crate::paths::ProjectPaths::new(root),
+            ..Config::default()
+        };
+        let code_files = [root.join("src/auth.ts"), root.join("src/payments.ts")];
+        let entities =
+            code_parser::parse_project_code(&code_files, &config, &mut Default::default()).unwrap();
+        let sections = doc_parser::parse_docs(&root.join("docs/api.md"), &config).unwrap();
+        render_list(&entities, &sections, unlinked_only)
+    }
+
+    #[test]
+    fn list_marks_which_functions_and_sections_resolved_to_each_other() {
+        let out = list(false);
+        let row = |start: &str| {
+            out.lines()
+                .find(|l| l.trim_start().starts_with(start))
+                .unwrap_or_else(|| panic!("sin fila {start}:\n{out}"))
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        assert_eq!(
+            row("login"),
+            "login src/auth.ts:2 auth-login → docs/api.md:1"
+        );
+        assert_eq!(
+            row("logout"),
+            "logout src/auth.ts:5 auth-logut ✗ sin sección con ese ID"
+        );
+        assert_eq!(row("refresh"), "refresh src/auth.ts:7 - ✗ sin anotación");
+        assert_eq!(
+            row("charge"),
+            "charge src/payments.ts:3 @docs-file: payments → docs/api.md:11"
+        );
+        assert_eq!(
+            row("auth-login"),
+            "auth-login login docs/api.md:1 1 ← login"
+        );
+        assert_eq!(
+            row("auth-logout"),
+            "auth-logout logout docs/api.md:8 0 ✗ sin enlace"
+        );
+        // Una subsección queda cubierta por el `@docs-file` de su padre
+        assert_eq!(
+            row("payments-charge"),
+            "payments-charge charge docs/api.md:14 0 ← @docs-file"
+        );
+        assert_eq!(
+            row("internals"),
+            "internals Internos docs/api.md:17 0 @docs-no-link"
+        );
+        assert!(
+            out.ends_with("Sin resolver: 2 de 4 funciones y 1 de 5 secciones.\n"),
+            "{out}"
+        );
+    }
+
+    #[test]
+    fn unlinked_only_keeps_just_the_unmatched_items_on_both_sides() {
+        let out = list(true);
+        assert!(out.contains("Funciones sin resolver (2)"), "{out}");
+        assert!(out.contains("Secciones sin resolver (1)"), "{out}");
+        for shown in ["logout", "refresh", "auth-logout"] {
+            assert!(out.contains(shown), "{shown}:\n{out}");
+        }
+        for hidden in ["auth-login ", "charge", "payments", "internals"] {
+            assert!(!out.contains(hidden), "{hidden}:\n{out}");
+        }
+        assert!(table(&["ID"], &[]).contains("(ninguna)"));
+    }
+
+    #[test]
+    fn doc_args_show_their_source() {
+        let arg = Arg {
+            name: "name".into(),
+            type_name: Some("string".into()),
+            description: None,
+            source: ArgSource::Table,
+            line: Some(84),
+            unit: None,
+            range: None,
+        };
+        assert_eq!(render_arg(&arg), "name: string  [tabla, línea 84]");
+
+        let arg = Arg {
+            unit: Some("ms".into()),
+            range: Some(ValueRange {
+                min: 100.0,
+                max: 30000.0,
+            }),
+            ..arg
+        };
+        assert_eq!(
+            render_arg(&arg),
+            "name: string (ms, 100–30000)  [tabla, línea 84]"
+        );
+    }
+
+    #[test]
+    fn code_args_have_no_source_tag() {
+        let arg = Arg {
+            name: "name".into(),
+            type_name: None,
+            description: None,
+            source: ArgSource::Code,
+            line: None,
+            unit: None,
+            range: None,
+        };
+        assert_eq!(render_arg(&arg), "name");
+    }
+}
@@ -0,0 +1,209 @@
+//! `docsguard fix`: aplica sin preguntar los enlaces de alta confianza.
+//!
+//! Es la mitad no interactiva de `scaffold`: los mismos candidatos
+//! (`heuristic::find_candidates`) y las mismas ediciones (`stage_changes`),
+//! pero solo los que llegan a `--min-confidence` y sin prompts, para que
+//! pueda correr en CI. Dos funciones que apuntan a la misma sección no se
+//! aplican: no hay forma de elegir sin preguntar, así que se listan y el
+//! comando sale con código 1. Con `--dry-run` muestra el diff unificado de lo
+//! que escribiría.
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::patch::unified_diff;
+use super::{say, stage_changes};
+use crate::config::Config;
+use crate::core::conflicts;
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::heuristic::{self, CandidateLink};
+use crate::exit::Outcome;
+use crate::layout::{Layout, Style};
+use crate::messages;
+use crate::messages::println_tr;
+use crate::parser::{code_parser, doc_parser};
+use crate::transaction::Transaction;
+use crate::vfs;
+
+/// Opciones de `fix`.
+pub struct FixOptions {
+    /// Confianza mínima de los enlaces que se aplican.
+    pub min_confidence: f64,
+    /// Mostrar el diff sin escribir.
+    pub dry_run: bool,
+    /// Recorrido de pares de la heurística.
+    pub heuristic: heuristic::Strategy,
+    /// Colores del título y del resultado.
+    pub layout: Layout,
+}
+
+/// Enlaces que se aplican y los que quedan en conflicto.
+struct Plan<'a> {
+    applied: Vec<&'a CandidateLink>,
+    /// Candidatos que comparten sección con otro.
+    conflicts: Vec<&'a CandidateLink>,
+}
+
+/// Separa los candidatos por encima del umbral en aplicables y en conflicto.
+fn plan(candidates: &[CandidateLink], min_confidence: f64) -> Plan<'_> {
+    let links: Vec<&CandidateLink> = candidates
+        .iter()
+        .filter(|c| c.confidence >= min_confidence)
+        .collect();
+    let mut claims: HashMap<&str, usize> = HashMap::new();
+    for link in &links {
+        *claims.entry(link.section_id.as_str()).or_default() += 1;
+    }
+    let (applied, conflicts) = links
+        .into_iter()
+        .partition(|link| claims[link.section_id.as_str()] == 1);
+    Plan { applied, conflicts }
+}
+
+/// Ejecuta `fix` sobre `code_file` y `doc_file`.
+pub fn run_fix(
+    code_file: &Path,
+    doc_file: &Path,
+    project_root: &Path,
+    options: FixOptions,
+) -> Result<Outcome> {
+    let FixOptions {
+        min_confidence,
+        dry_run,
+        heuristic: strategy,
+        layout: term,
+    } = options;
+    code_parser::require_file_exists(code_file, "código")?;
+    code_parser::require_file_exists(doc_file, "documentación")?;
+
+    say(
+        &term,
+        Style::Heading,
+        &format!(
+            "DocsGuard Fix — Enlaces con confianza ≥ {:.0}%\n",
+            min_confidence * 100.0
+        ),
+    );
+
+    let config = Config::load(project_root)?;
+    let mut diagnostics = ParseDiagnostics::default();
+    let code_entities =
+        code_parser::parse_project_code(&[code_file.to_path_buf()], &config, &mut diagnostics)
+            .context("Error al parsear el archivo de código")?;
+    let doc_sections = doc_parser::parse_markdown_file(doc_file, &mut diagnostics)
+        .context("Error al parsear el archivo de documentación")?;
+    conflicts::refuse_writes(&diagnostics)?;
+
+    let candidates = heuristic::find_candidates(&code_entities, &doc_sections, strategy);
+    let Plan { applied, conflicts } = plan(&candidates, min_confidence);
+    if applied.is_empty() && conflicts.is_empty() {
+        println_tr!("  Ningún enlace con confianza suficiente: no hay nada que aplicar.");
+        return Ok(Outcome::Clean);
+    }
+
+    let mut transaction = Transaction::new();
+    if !applied.is_empty() {
+        stage_changes(&mut transaction, code_file, &code_entities, &applied)?;
+    }
+    if dry_run {
+        for (path, content) in transaction.staged() {
+            let display = config.paths.normalize(path);
+            print!(
+                "{}",
+                unified_diff(
+                    &display.to_string_lossy().replace('\\', "/"),
+                    &vfs::read_to_string(path)?,
+                    &String::from_utf8_lossy(content),
+                )
+            );
+        }
+    } else {
+        for link in &applied {
+            println_tr!(
+                "  + {}  /// @docs: [{}]  ({} · {:.0}%)",
+                link.code_location,
+                link.section_id,
+                link.function_name,
+                link.confidence * 100.0
+            );
+        }
+    }
+    for link in &conflicts {
+        say(
+            &term,
+            Style::Error,
+            &format!(
+                "  ✗ {}  {} → '{}': otra función apunta a la misma sección",
+                link.code_location, link.function_name, link.section_id
+            ),
+        );
+    }
+
+    println!();
+    if applied.is_empty() {
+        println_tr!("  No se aplicó ningún enlace.");
+    } else if dry_run {
+        println_tr!(
+            "  [dry-run] {} en {}; ejecuta sin --dry-run para aplicarlos.",
+            messages::LINKS.count(applied.len()),
+            code_file.display()
+        );
+    } else {
+        transaction.commit()?;
+        say(
+            &term,
+            Style::Success,
+            &format!(
+                "  {} en {}.",
+                messages::WRITTEN_LINKS.count(applied.len()),
+                code_file.display()
+            ),
+        );
+    }
+    if !conflicts.is_empty() {
+        println_tr!(
+            "  {} sin aplicar: enlázalos con `docsguard scaffold`.",
+            messages::LINKS.count(conflicts.len())
+        );
+    }
+    Ok(Outcome::failed_if(!conflicts.is_empty()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(function_name: &str, section_id: &str, confidence: f64) -> CandidateLink {
+        CandidateLink {
+            entity_index: 0,
+            function_name: function_name.to_string(),
+            code_location: "src/api.ts:1".to_string(),
+            section_id: section_id.to_string(),
+            section_title: section_id.to_string(),
+            confidence,
+        }
+    }
+
+    #[test]
+    fn links_below_the_threshold_are_dropped_and_shared_sections_conflict() {
+        let candidates = [
+            link("search", "search", 1.0),
+            link("logout", "logout", 1.0),
+            link("logOut", "logout", 0.95),
+            link("getUsr", "get-user", 0.85),
+        ];
+        let Plan { applied, conflicts } = plan(&candidates, 0.9);
+        let names = |links: &[&CandidateLink]| {
+            links
+                .iter()
+                .map(|l| l.function_name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&applied), ["search"]);
+        assert_eq!(names(&conflicts), ["logout", "logOut"]);
+
+        let Plan { applied, .. } = plan(&candidates, 0.8);
+        assert_eq!(names(&applied), ["search", "getUsr"]);
+    }
+}
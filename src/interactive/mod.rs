@@ -1,160 +1,609 @@
 //! Scaffold TUI interactivo (Blueprint §4.1).
 //!
 //! Muestra sugerencias de enlaces código-doc una por una.
-//! Nunca toca el disco sin permiso explícito.
+//! Nunca toca el disco sin permiso explícito. Con `--write-to-mapping` los
+//! enlaces aceptados van a `.docsguard/links.yaml` y el código no se modifica.
+//! Con `--stubs` las funciones que quedan sin enlace reciben un ID nuevo
+//! (`id_style`) y una sección esqueleto al final del archivo de docs.
+//! Cada sesión deja un informe de lo decidido (ver `report`). Con
+//! `--emit-patch` no pregunta ni escribe: deja los enlaces como parches para
+//! `git apply` (ver `patch`); `docsguard fix` aplica sin preguntar los de
+//! alta confianza (ver `apply`).
+
+pub mod apply;
+pub mod patch;
+pub mod report;
 
 use anyhow::{Context, Result};
 use dialoguer::{theme::ColorfulTheme, Select};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+use crate::config::Config;
+use crate::core::conflicts;
+use crate::core::diagnostics::ParseDiagnostics;
 use crate::core::heuristic::{self, CandidateLink};
-use crate::core::types::CodeEntity;
+use crate::core::ids::IdGenerator;
+use crate::core::types::{CodeEntity, DocSection};
+use crate::fix::{self, Fix};
+use crate::layout::{self, Layout, Style};
+use crate::mapping::{self, LinkMapping, MappedLink};
+use crate::messages;
+use crate::messages::println_tr;
+use crate::parser::code_parser::Language;
 use crate::parser::{code_parser, doc_parser};
+use crate::transaction::Transaction;
+use report::{Basis, DecidedBy, FileLinkRecord, LinkRecord, ReportFormat, SessionReport};
+
+/// Columnas de un título de sección en las sugerencias; el resto se recorta.
+const MAX_TITLE_WIDTH: usize = 60;
 
 /// Resultado de la decisión del usuario sobre un candidato.
 #[derive(Debug)]
 enum UserDecision {
     Accept,
+    /// Aceptar esta sugerencia y todas las siguientes.
+    AcceptAll,
     Reject,
     Skip,
 }
 
+/// Opciones de `scaffold` que no son entradas.
+pub struct ScaffoldOptions {
+    /// No escribir cambios al disco.
+    pub dry_run: bool,
+    /// Aceptar todas las sugerencias sin preguntar.
+    pub force: bool,
+    /// Registrar los enlaces en `.docsguard/links.yaml` en lugar del código.
+    pub write_to_mapping: bool,
+    /// Recorrido de pares de la heurística (`exhaustive` para depurar).
+    pub heuristic: heuristic::Strategy,
+    /// Ofrecer una sección nueva para cada función que quede sin enlace.
+    pub stubs: bool,
+    /// Ruta del informe de la sesión (`.md` o `.json`); por defecto
+    /// `.docsguard/scaffold-report-<timestamp>.md`.
+    pub report: Option<PathBuf>,
+    /// Escribir parches en lugar de preguntar (`--emit-patch`).
+    pub emit_patch: Option<patch::EmitPatch>,
+    /// Colores de los títulos y las confirmaciones.
+    pub layout: Layout,
+}
+
 /// Ejecuta el scaffold interactivo.
 /// Parsea código y docs, encuentra candidatos heurísticos, y presenta
 /// cada sugerencia al usuario para confirmación.
-pub fn run_scaffold(code_file: &Path, doc_file: &Path, dry_run: bool, force: bool) -> Result<()> {
+pub fn run_scaffold(
+    code_file: &Path,
+    doc_file: &Path,
+    project_root: &Path,
+    options: ScaffoldOptions,
+) -> Result<()> {
+    let ScaffoldOptions {
+        dry_run,
+        force,
+        write_to_mapping,
+        heuristic: strategy,
+        stubs,
+        report: report_path,
+        emit_patch,
+        layout: term,
+    } = options;
+    if let Some(path) = &report_path {
+        ReportFormat::for_path(path)?;
+    }
     // Refactorizado: usa require_file_exists para eliminar comprobaciones duplicadas entre comandos
     code_parser::require_file_exists(code_file, "código")?;
     code_parser::require_file_exists(doc_file, "documentación")?;
 
-    println!("DocsGuard Scaffold — Vinculación interactiva código ↔ documentación\n");
+    say(
+        &term,
+        Style::Heading,
+        "DocsGuard Scaffold — Vinculación interactiva código ↔ documentación\n",
+    );
 
     if dry_run {
-        println!("  [modo dry-run] No se escribirán cambios al disco.\n");
+        say(
+            &term,
+            Style::Hint,
+            "  [modo dry-run] No se escribirán cambios al disco.\n",
+        );
     }
 
+    // Con el mapeo aplicado: las funciones ya enlazadas en links.yaml no se sugieren
+    let config = Config::load(project_root)?;
+    let mut diagnostics = ParseDiagnostics::default();
     let code_entities =
-        code_parser::parse_code_file(code_file).context("Error al parsear el archivo de código")?;
+        code_parser::parse_project_code(&[code_file.to_path_buf()], &config, &mut diagnostics)
+            .context("Error al parsear el archivo de código")?;
 
-    let doc_sections = doc_parser::parse_markdown_file(doc_file)
+    let doc_sections = doc_parser::parse_markdown_file(doc_file, &mut diagnostics)
         .context("Error al parsear el archivo de documentación")?;
+    conflicts::refuse_writes(&diagnostics)?;
 
-    let candidates = heuristic::find_candidates(&code_entities, &doc_sections);
+    let candidates = heuristic::find_candidates(&code_entities, &doc_sections, strategy);
+    if let Some(emit) = &emit_patch {
+        let inputs = patch::Inputs {
+            config: &config,
+            code_file,
+            doc_file,
+            code_entities: &code_entities,
+            doc_sections: &doc_sections,
+        };
+        return patch::emit(emit, &inputs, &candidates, stubs).map(|_| ());
+    }
+    let mut session = SessionReport {
+        applied: !dry_run,
+        code_file: config.paths.normalize(code_file).display().to_string(),
+        doc_file: config.paths.normalize(doc_file).display().to_string(),
+        destination: if write_to_mapping {
+            mapping::links_path(Path::new("")).display().to_string()
+        } else {
+            config.paths.normalize(code_file).display().to_string()
+        },
+        ..SessionReport::default()
+    };
+    let decided_by = |batch: bool| match (force, batch) {
+        (true, _) => DecidedBy::Force,
+        (false, true) => DecidedBy::Batch,
+        (false, false) => DecidedBy::Manual,
+    };
 
     if candidates.is_empty() {
-        println!("  No se encontraron sugerencias de enlace.");
-        println!("  (Todas las funciones ya están vinculadas o no hay matches heurísticos)");
-        return Ok(());
+        println_tr!("  No se encontraron sugerencias de enlace.");
+        println_tr!("  (Todas las funciones ya están vinculadas o no hay matches heurísticos)");
+        if !stubs {
+            return Ok(());
+        }
+        println!();
+    } else {
+        println_tr!(
+            "  {} (confianza ≥ 80%).\n",
+            messages::LINK_SUGGESTIONS.count(candidates.len())
+        );
     }
 
-    println!(
-        "  Encontradas {} sugerencias de enlace (confianza ≥ 80%).\n",
-        candidates.len()
-    );
+    // El mapeo enlaza funciones: el enlace de archivo solo existe como comentario
+    if let Some(file_candidate) =
+        heuristic::find_file_candidate(&code_entities, &doc_sections, &candidates)
+            .filter(|_| !write_to_mapping)
+    {
+        say(
+            &term,
+            Style::Heading,
+            "── Enlace de archivo ─────────────────────────────",
+        );
+        println_tr!(
+            "  Las {} funciones coinciden con secciones bajo '{}' [id: {}].",
+            code_entities.len(),
+            file_candidate.section_title,
+            file_candidate.section_id
+        );
+        println!();
+
+        let accept = force || prompt_file_link()?;
+        if accept {
+            session.file_link = Some(FileLinkRecord {
+                section_id: file_candidate.section_id.clone(),
+                section_title: file_candidate.section_title.clone(),
+                decided_by: decided_by(false),
+            });
+            let annotation = file_link_annotation(code_file, &file_candidate.section_id)?;
+            if dry_run {
+                println_tr!(
+                    "  [dry-run] Se habría añadido al inicio del archivo: {}",
+                    annotation
+                );
+            } else {
+                let mut transaction = Transaction::new();
+                stage_file_link(&mut transaction, code_file, &annotation)?;
+                transaction.commit()?;
+                say(
+                    &term,
+                    Style::Success,
+                    &format!(
+                        "  → Archivo vinculado: {} escrito en {}.",
+                        annotation,
+                        code_file.display()
+                    ),
+                );
+            }
+            return finish_report(&session, report_path, project_root);
+        }
+        println_tr!("  → Se sigue con los enlaces por función.\n");
+    }
 
     let mut accepted: Vec<&CandidateLink> = Vec::new();
-    let mut rejected = 0;
+    let mut batch = false;
 
     for (i, candidate) in candidates.iter().enumerate() {
-        println!(
-            "── Sugerencia {}/{} ──────────────────────────────",
-            i + 1,
-            candidates.len()
+        say(
+            &term,
+            Style::Heading,
+            &format!(
+                "── Sugerencia {}/{} ──────────────────────────────",
+                i + 1,
+                candidates.len()
+            ),
         );
-        println!(
+        println_tr!(
             "  Función:  {} ({})",
-            candidate.function_name, candidate.code_location
+            candidate.function_name,
+            candidate.code_location
         );
-        println!(
+        println_tr!(
             "  Sección:  '{}' [id: {}]",
-            candidate.section_title, candidate.section_id
+            layout::truncate(&candidate.section_title, MAX_TITLE_WIDTH),
+            candidate.section_id
         );
-        println!("  Confianza: {:.0}%", candidate.confidence * 100.0);
+        println_tr!("  Confianza: {:.0}%", candidate.confidence * 100.0);
         println!();
 
-        let decision = if force {
+        let decision = if force || batch {
             UserDecision::Accept
         } else {
             prompt_user()?
         };
 
+        let record = LinkRecord::new(candidate, &doc_sections, Basis::Similarity);
         match decision {
-            UserDecision::Accept => {
+            UserDecision::Accept | UserDecision::AcceptAll => {
+                batch |= matches!(decision, UserDecision::AcceptAll);
                 accepted.push(candidate);
-                println!("  → Aceptado.\n");
+                session.accepted.push(record.decided_by(decided_by(batch)));
+                say_decision(&term, &decision);
             }
             UserDecision::Reject => {
-                rejected += 1;
-                println!("  → Rechazado.\n");
+                session.rejected.push(record);
+                say_decision(&term, &decision);
             }
             UserDecision::Skip => {
-                println!("  → Omitido.\n");
+                session.skipped.push(record);
+                say_decision(&term, &decision);
             }
         }
     }
 
-    println!("── Resumen ──────────────────────────────────────");
-    println!("  Aceptados: {}", accepted.len());
-    println!("  Rechazados: {}", rejected);
-    println!(
-        "  Omitidos: {}",
-        candidates.len() - accepted.len() - rejected
+    let new_sections = if stubs {
+        let links = stub_links(&config, code_file, &code_entities, &doc_sections, &accepted);
+        propose_stubs(links, &doc_sections, force, &term, &mut session)?
+    } else {
+        Vec::new()
+    };
+
+    say(
+        &term,
+        Style::Heading,
+        "── Resumen ──────────────────────────────────────",
     );
+    println_tr!("  Aceptados: {}", accepted.len());
+    println_tr!("  Rechazados: {}", session.rejected.len());
+    println_tr!("  Omitidos: {}", session.skipped.len());
+    if stubs {
+        println_tr!("  Secciones nuevas: {}", new_sections.len());
+    }
 
-    if accepted.is_empty() {
-        println!("\n  No hay cambios que aplicar.");
-        return Ok(());
+    if accepted.is_empty() && new_sections.is_empty() {
+        println_tr!("\n  No hay cambios que aplicar.");
+        return finish_report(&session, report_path, project_root);
     }
+    let stub_fixes: Vec<Fix> = new_sections
+        .iter()
+        .map(|link| Fix::AppendDocSection {
+            doc_file: doc_file.to_path_buf(),
+            doc_id: link.section_id.clone(),
+            content: fix::render_section_stub(&link.section_id, &code_entities[link.entity_index]),
+        })
+        .collect();
+    accepted.extend(&new_sections);
 
-    if dry_run {
-        println!("\n  [dry-run] Cambios que se habrían escrito:");
+    if dry_run && !stub_fixes.is_empty() {
+        println_tr!("\n  [dry-run] Secciones que se habrían añadido:");
+        for stub in &stub_fixes {
+            println_tr!("    • {}", stub.describe());
+        }
+    }
+
+    if write_to_mapping {
+        let file = config.paths.normalize(code_file);
+        if dry_run {
+            println_tr!(
+                "\n  [dry-run] Enlaces que se habrían escrito en {}:",
+                mapping::links_path(project_root).display()
+            );
+            for candidate in &accepted {
+                println_tr!(
+                    "    • {} → {} ({})",
+                    candidate.function_name,
+                    candidate.section_id,
+                    file.display()
+                );
+            }
+            println_tr!("\n  Ejecuta sin --dry-run para aplicar los cambios.");
+        } else {
+            let mut transaction = Transaction::new();
+            for stub in &stub_fixes {
+                stub.stage(&mut transaction)?;
+            }
+            transaction.commit()?;
+            let path = record_in_mapping(project_root, &file, &accepted)?;
+            say(
+                &term,
+                Style::Success,
+                &format!(
+                    "\n  {} en {} (código sin modificar).",
+                    messages::WRITTEN_LINKS.count(accepted.len()),
+                    path.display()
+                ),
+            );
+        }
+    } else if dry_run {
+        println_tr!("\n  [dry-run] Cambios que se habrían escrito:");
         for candidate in &accepted {
-            println!(
+            println_tr!(
                 "    • {} → /// @docs: [{}]",
-                candidate.function_name, candidate.section_id
+                candidate.function_name,
+                candidate.section_id
             );
         }
-        println!("\n  Ejecuta sin --dry-run para aplicar los cambios.");
+        println_tr!("\n  Ejecuta sin --dry-run para aplicar los cambios.");
     } else {
-        apply_changes(code_file, &code_entities, &accepted)?;
-        println!(
-            "\n  {} enlaces escritos en {}.",
-            accepted.len(),
-            code_file.display()
+        let mut transaction = Transaction::new();
+        stage_changes(&mut transaction, code_file, &code_entities, &accepted)?;
+        for stub in &stub_fixes {
+            stub.stage(&mut transaction)?;
+        }
+        transaction.commit()?;
+        say(
+            &term,
+            Style::Success,
+            &format!(
+                "\n  {} en {}.",
+                messages::WRITTEN_LINKS.count(accepted.len()),
+                code_file.display()
+            ),
         );
     }
 
+    finish_report(&session, report_path, project_root)
+}
+
+/// Escribe el informe de la sesión, si hubo algo que decidir, e indica dónde.
+fn finish_report(
+    session: &SessionReport,
+    path: Option<PathBuf>,
+    project_root: &Path,
+) -> Result<()> {
+    if session.is_empty() {
+        return Ok(());
+    }
+    let path = path.unwrap_or_else(|| report::default_path(project_root));
+    report::write(session, &path)?;
+    println_tr!("\n  Informe de la sesión: {}", path.display());
     Ok(())
 }
 
+/// Propone el ID nuevo de cada enlace de `stub_links`.
+/// Los aceptados vuelven como enlaces de confianza 1 a su sección esqueleto.
+fn propose_stubs(
+    links: Vec<CandidateLink>,
+    doc_sections: &[DocSection],
+    force: bool,
+    term: &Layout,
+    session: &mut SessionReport,
+) -> Result<Vec<CandidateLink>> {
+    let mut stubs = Vec::new();
+    for link in links {
+        say(
+            term,
+            Style::Heading,
+            "── Sección nueva ─────────────────────────────────",
+        );
+        println_tr!(
+            "  Función:  {} ({})",
+            link.function_name,
+            link.code_location
+        );
+        println_tr!("  ID nuevo: {}", link.section_id);
+        println!();
+
+        let decision = if force {
+            UserDecision::Accept
+        } else {
+            prompt_stub()?
+        };
+        let record = LinkRecord::new(&link, doc_sections, Basis::Stub);
+        match decision {
+            UserDecision::Accept | UserDecision::AcceptAll => {
+                say_decision(term, &decision);
+                let by = if force {
+                    DecidedBy::Force
+                } else {
+                    DecidedBy::Manual
+                };
+                session.accepted.push(record.decided_by(by));
+                stubs.push(link);
+            }
+            UserDecision::Reject => {
+                say_decision(term, &decision);
+                session.rejected.push(record);
+            }
+            UserDecision::Skip => {
+                say_decision(term, &decision);
+                session.skipped.push(record);
+            }
+        }
+    }
+    Ok(stubs)
+}
+
+/// Enlace de confianza 1 a una sección esqueleto con un ID nuevo por cada
+/// función pública sin enlace que no esté en `accepted`.
+fn stub_links(
+    config: &Config,
+    code_file: &Path,
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+    accepted: &[&CandidateLink],
+) -> Vec<CandidateLink> {
+    let existing = doc_sections
+        .iter()
+        .map(|s| s.id.clone())
+        .chain(code_entities.iter().filter_map(|e| e.doc_id.clone()))
+        .chain(config.links.links.iter().map(|l| l.doc_id.clone()));
+    let mut ids = IdGenerator::new(&config.id_style, &config.id_modules, existing);
+    let file = config.paths.normalize(code_file);
+
+    let mut stubs = Vec::new();
+    for (index, entity) in code_entities.iter().enumerate() {
+        let linked = !entity.is_public
+            || entity.doc_id.is_some()
+            || entity.file_link.is_some()
+            || accepted.iter().any(|c| c.entity_index == index);
+        if linked {
+            continue;
+        }
+        stubs.push(CandidateLink {
+            entity_index: index,
+            function_name: entity.name.clone(),
+            code_location: entity.location(),
+            section_id: ids.next(&entity.name, &file),
+            section_title: entity.name.clone(),
+            confidence: 1.0,
+        });
+    }
+    stubs
+}
+
+/// Pregunta si se crea la sección esqueleto de una función sin enlace.
+fn prompt_stub() -> Result<UserDecision> {
+    let selections = ["Sí — crear la sección", "No — rechazar", "Omitir"].map(messages::tr);
+    let selection = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt(messages::tr("¿Crear una sección nueva para esta función?"))
+        .items(&selections)
+        .default(0)
+        .interact()
+        .context("Error al leer la respuesta del usuario")?;
+
+    Ok(match selection {
+        0 => UserDecision::Accept,
+        1 => UserDecision::Reject,
+        _ => UserDecision::Skip,
+    })
+}
+
+/// Imprime `text` traducido y con el estilo de la paleta.
+fn say(term: &Layout, style: Style, text: &str) {
+    println!("{}", term.paint(style, &messages::tr(text)));
+}
+
+/// Confirma la decisión sobre una sugerencia.
+fn say_decision(term: &Layout, decision: &UserDecision) {
+    match decision {
+        UserDecision::Accept | UserDecision::AcceptAll => {
+            say(term, Style::Success, "  → Aceptado.\n")
+        }
+        UserDecision::Reject => say(term, Style::Warning, "  → Rechazado.\n"),
+        UserDecision::Skip => say(term, Style::Dim, "  → Omitido.\n"),
+    }
+}
+
 /// Presenta la prompt interactiva al usuario.
 fn prompt_user() -> Result<UserDecision> {
-    let selections = &["Sí — vincular", "No — rechazar", "Omitir"];
+    let selections = [
+        "Sí — vincular",
+        "Todas — vincular esta y las siguientes",
+        "No — rechazar",
+        "Omitir",
+    ]
+    .map(messages::tr);
     let selection = Select::with_theme(&ColorfulTheme::default())
-        .with_prompt("¿Vincular esta función con esta sección?")
-        .items(selections)
+        .with_prompt(messages::tr("¿Vincular esta función con esta sección?"))
+        .items(&selections)
         .default(0)
         .interact()
         .context("Error al leer la respuesta del usuario")?;
 
     Ok(match selection {
         0 => UserDecision::Accept,
-        1 => UserDecision::Reject,
+        1 => UserDecision::AcceptAll,
+        2 => UserDecision::Reject,
         _ => UserDecision::Skip,
     })
 }
 
-/// Aplica los cambios aceptados al archivo de código utilizando persistencia Atómica (Protección TOCTOU/Symlink).
-fn apply_changes(
+/// Pregunta si se prefiere un enlace de archivo a los enlaces por función.
+fn prompt_file_link() -> Result<bool> {
+    let selections = [
+        "Sí — enlazar el archivo completo (@docs-file)",
+        "No — enlazar función por función",
+    ]
+    .map(messages::tr);
+    let selection = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt(messages::tr(
+            "¿Vincular el archivo completo con esta sección?",
+        ))
+        .items(&selections)
+        .default(0)
+        .interact()
+        .context("Error al leer la respuesta del usuario")?;
+    Ok(selection == 0)
+}
+
+/// Comentario `@docs-file` en la sintaxis del lenguaje (`//!` en Rust).
+fn file_link_annotation(code_file: &Path, section_id: &str) -> Result<String> {
+    let prefix = match Language::from_extension(code_file)? {
+        Language::Rust => "//!",
+        language => language.line_comment_prefix(),
+    };
+    Ok(format!(
+        "{} {} {}",
+        prefix,
+        code_parser::FILE_LINK_MARKER,
+        section_id
+    ))
+}
+
+/// Inserta la anotación de archivo en la primera línea (tras un shebang, si lo hay).
+fn stage_file_link(
+    transaction: &mut Transaction,
+    code_file: &Path,
+    annotation: &str,
+) -> Result<()> {
+    let source = transaction.read_to_string(code_file)?;
+    let first_line = source.lines().next().unwrap_or_default();
+    let index = usize::from(first_line.starts_with("#!") && !first_line.starts_with("#!["));
+
+    let mut insertions = std::collections::HashMap::new();
+    insertions.insert(index, annotation.to_string());
+    transaction.stage(code_file, insert_lines_above(&source, &insertions));
+    Ok(())
+}
+
+/// Registra los enlaces aceptados en `.docsguard/links.yaml` (`--write-to-mapping`).
+fn record_in_mapping(
+    project_root: &Path,
+    file: &Path,
+    accepted: &[&CandidateLink],
+) -> Result<std::path::PathBuf> {
+    let mut links = LinkMapping::load(project_root)?;
+    for candidate in accepted {
+        links.upsert(MappedLink {
+            file: file.to_string_lossy().into_owned(),
+            function: candidate.function_name.clone(),
+            doc_id: candidate.section_id.clone(),
+        });
+    }
+    links.save(project_root)
+}
+
+/// Prepara los cambios aceptados sobre el archivo de código. Se escriben al
+/// confirmar la transacción (Protección TOCTOU/Symlink, VUL-02).
+fn stage_changes(
+    transaction: &mut Transaction,
     code_file: &Path,
     code_entities: &[CodeEntity],
     accepted: &[&CandidateLink],
 ) -> Result<()> {
-    let source = std::fs::read_to_string(code_file)
-        .with_context(|| format!("No se pudo leer: {}", code_file.display()))?;
-
-    let lines: Vec<&str> = source.lines().collect();
-    let mut output_lines: Vec<String> = Vec::with_capacity(lines.len() + accepted.len());
+    let source = transaction.read_to_string(code_file)?;
 
     let mut annotations: std::collections::HashMap<usize, String> =
         std::collections::HashMap::new();
@@ -169,10 +618,24 @@ fn apply_changes(
         );
     }
 
+    transaction.stage(code_file, insert_lines_above(&source, &annotations));
+    Ok(())
+}
+
+/// Inserta líneas antes de las líneas indicadas (0-indexed), copiando su indentación.
+///
+/// Compartida por `stage_changes` y las supresiones en línea de `triage`.
+pub(crate) fn insert_lines_above(
+    source: &str,
+    insertions: &std::collections::HashMap<usize, String>,
+) -> String {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut output_lines: Vec<String> = Vec::with_capacity(lines.len() + insertions.len());
+
     for (i, line) in lines.iter().enumerate() {
-        if let Some(annotation) = annotations.get(&i) {
+        if let Some(inserted) = insertions.get(&i) {
             let indent: String = line.chars().take_while(|c| c.is_whitespace()).collect();
-            output_lines.push(format!("{}{}", indent, annotation));
+            output_lines.push(format!("{}{}", indent, inserted));
         }
         output_lines.push(line.to_string());
     }
@@ -181,10 +644,5 @@ fn apply_changes(
     if source.ends_with('\n') {
         result.push('\n');
     }
-
-    // Escritura atómica via utilidad compartida (code_parser::atomic_write).
-    // Previene TOCTOU, corrupción parcial y symlink attacks.
-    crate::parser::code_parser::atomic_write(code_file, result.as_bytes())?;
-
-    Ok(())
+    result
 }
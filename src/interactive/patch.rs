@@ -0,0 +1,380 @@
+//! Parches de `scaffold --emit-patch`.
+//!
+//! En lugar de preguntar y escribir, `scaffold` deja los enlaces propuestos
+//! como parches que se revisan y aplican con `git apply`: uno por enlace
+//! (`001-<id>.patch`, …) o uno solo con `--single-patch`. Cada parche lleva
+//! la anotación `@docs` en el código y, si el enlace es una sección nueva
+//! (`--stubs`), la sección esqueleto en las docs; la cabecera indica la
+//! confianza y las similitudes con el ID y el título de la sección.
+//!
+//! Las ediciones son las mismas que escribiría la sesión interactiva: se
+//! preparan en una `Transaction` que no se confirma y se comparan con lo que
+//! hay en disco. Los parches por enlace son independientes entre sí: todos
+//! parten del árbol actual, así que dos que tocan líneas vecinas (o dos
+//! secciones nuevas al final del mismo archivo) no se aplican uno detrás de
+//! otro; para eso está `--single-patch`.
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+use super::{stage_changes, stub_links};
+use crate::config::Config;
+use crate::core::heuristic::{self, CandidateLink};
+use crate::core::types::{CodeEntity, DocSection};
+use crate::fix::{self, Fix};
+use crate::messages;
+use crate::messages::println_tr;
+use crate::transaction::Transaction;
+use crate::vfs;
+
+/// Líneas de contexto alrededor de cada cambio.
+const CONTEXT: usize = 3;
+
+/// Nombre del parche combinado (`--single-patch`).
+pub const SINGLE_PATCH_NAME: &str = "scaffold.patch";
+
+/// Opciones de `--emit-patch`.
+pub struct EmitPatch {
+    /// Directorio donde se escriben los parches.
+    pub dir: PathBuf,
+    /// Un solo parche con todos los enlaces.
+    pub single: bool,
+    /// Confianza mínima de los enlaces que se emiten.
+    pub min_confidence: f64,
+}
+
+/// Entradas ya parseadas de la sesión.
+pub struct Inputs<'a> {
+    pub config: &'a Config,
+    pub code_file: &'a Path,
+    pub doc_file: &'a Path,
+    pub code_entities: &'a [CodeEntity],
+    pub doc_sections: &'a [DocSection],
+}
+
+/// Un enlace propuesto y, si es una sección nueva, su esqueleto.
+struct Proposal<'a> {
+    link: &'a CandidateLink,
+    stub: Option<Fix>,
+}
+
+/// Escribe los parches de `candidates` (y de las secciones nuevas con
+/// `stubs`) en `options.dir`. Retorna sus rutas.
+pub fn emit(
+    options: &EmitPatch,
+    inputs: &Inputs,
+    candidates: &[CandidateLink],
+    stubs: bool,
+) -> Result<Vec<PathBuf>> {
+    let links: Vec<&CandidateLink> = candidates
+        .iter()
+        .filter(|c| c.confidence >= options.min_confidence)
+        .collect();
+    let new_sections = if stubs {
+        stub_links(
+            inputs.config,
+            inputs.code_file,
+            inputs.code_entities,
+            inputs.doc_sections,
+            &links,
+        )
+    } else {
+        Vec::new()
+    };
+    let proposals: Vec<Proposal> = links
+        .into_iter()
+        .map(|link| Proposal { link, stub: None })
+        .chain(new_sections.iter().map(|link| Proposal {
+            link,
+            stub: Some(Fix::AppendDocSection {
+                doc_file: inputs.doc_file.to_path_buf(),
+                doc_id: link.section_id.clone(),
+                content: fix::render_section_stub(
+                    &link.section_id,
+                    &inputs.code_entities[link.entity_index],
+                ),
+            }),
+        }))
+        .collect();
+
+    let mut patches = Vec::new();
+    if options.single {
+        if !proposals.is_empty() {
+            let patch = render_patch(inputs, &proposals)?;
+            patches.push((options.dir.join(SINGLE_PATCH_NAME), patch));
+        }
+    } else {
+        for (i, proposal) in proposals.iter().enumerate() {
+            let name = format!(
+                "{:03}-{}.patch",
+                i + 1,
+                file_stem(&proposal.link.section_id)
+            );
+            let patch = render_patch(inputs, std::slice::from_ref(proposal))?;
+            patches.push((options.dir.join(name), patch));
+        }
+    }
+
+    if patches.is_empty() {
+        println_tr!(
+            "  Ningún enlace con confianza ≥ {:.0}%: no se escribieron parches.",
+            options.min_confidence * 100.0
+        );
+        return Ok(Vec::new());
+    }
+    vfs::create_dir_all(&options.dir)?;
+    let mut transaction = Transaction::new();
+    for (path, patch) in &patches {
+        transaction.stage(path, patch.as_str());
+    }
+    transaction.commit()?;
+    println_tr!(
+        "  {} en {} ({}); se aplican con git apply.",
+        messages::WRITTEN_PATCHES.count(patches.len()),
+        options.dir.display(),
+        messages::LINKS.count(proposals.len())
+    );
+    Ok(patches.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Cabecera y diffs de los archivos que tocan `proposals`.
+fn render_patch(inputs: &Inputs, proposals: &[Proposal]) -> Result<String> {
+    let mut out = String::from("# docsguard scaffold --emit-patch\n");
+    for proposal in proposals {
+        out.push_str(&header_line(inputs, proposal));
+    }
+    out.push('\n');
+
+    let mut transaction = Transaction::new();
+    let links: Vec<&CandidateLink> = proposals.iter().map(|p| p.link).collect();
+    stage_changes(
+        &mut transaction,
+        inputs.code_file,
+        inputs.code_entities,
+        &links,
+    )?;
+    for stub in proposals.iter().filter_map(|p| p.stub.as_ref()) {
+        stub.stage(&mut transaction)?;
+    }
+    for (path, content) in transaction.staged() {
+        let before = vfs::read_to_string(path)?;
+        let after = String::from_utf8_lossy(content);
+        let display = inputs.config.paths.normalize(path);
+        out.push_str(&unified_diff(
+            &display.to_string_lossy().replace('\\', "/"),
+            &before,
+            &after,
+        ));
+    }
+    Ok(out)
+}
+
+/// `# login (src/auth.ts:3) → auth-login · confianza 92% (ID 92%, título 85%)`.
+fn header_line(inputs: &Inputs, proposal: &Proposal) -> String {
+    let link = proposal.link;
+    let scores = match inputs
+        .doc_sections
+        .iter()
+        .find(|s| s.id == link.section_id)
+        .filter(|_| proposal.stub.is_none())
+    {
+        Some(section) => {
+            let scores = heuristic::match_scores(&link.function_name, section);
+            match scores.title {
+                Some(title) => {
+                    format!("ID {:.0}%, título {:.0}%", scores.id * 100.0, title * 100.0)
+                }
+                None => format!("ID {:.0}%, sin título", scores.id * 100.0),
+            }
+        }
+        None => "sección nueva".to_string(),
+    };
+    format!(
+        "# {} ({}) → {} · confianza {:.0}% ({})\n",
+        link.function_name,
+        link.code_location,
+        link.section_id,
+        link.confidence * 100.0,
+        scores
+    )
+}
+
+/// Nombre de archivo seguro para un ID (`api.auth/login` → `api-auth-login`).
+fn file_stem(id: &str) -> String {
+    id.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Operación de una línea en el diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Diff unificado de `before` a `after` para `path` (`--- a/…`, `+++ b/…`);
+/// vacío si son iguales. Una última línea sin salto se marca con
+/// `\ No newline at end of file`, como hace git.
+pub fn unified_diff(path: &str, before: &str, after: &str) -> String {
+    let old: Vec<&str> = before.split_inclusive('\n').collect();
+    let new: Vec<&str> = after.split_inclusive('\n').collect();
+    let ops = diff_lines(&old, &new);
+    let changes: Vec<usize> = (0..ops.len()).filter(|&i| ops[i].0 != Op::Equal).collect();
+    if changes.is_empty() {
+        return String::new();
+    }
+
+    let mut out = format!("--- a/{}\n+++ b/{}\n", path, path);
+    let mut i = 0;
+    while i < changes.len() {
+        let start = changes[i].saturating_sub(CONTEXT);
+        let mut end = changes[i];
+        i += 1;
+        // Dos cambios separados por hasta 2 × CONTEXT líneas comparten hunk
+        while i < changes.len() && changes[i] <= end + 2 * CONTEXT + 1 {
+            end = changes[i];
+            i += 1;
+        }
+        let stop = (end + CONTEXT + 1).min(ops.len());
+
+        let counts = |range: std::ops::Range<usize>, side: Op| {
+            ops[range].iter().filter(|(op, _)| *op != side).count()
+        };
+        let (old_before, new_before) = (counts(0..start, Op::Insert), counts(0..start, Op::Delete));
+        let (old_len, new_len) = (
+            counts(start..stop, Op::Insert),
+            counts(start..stop, Op::Delete),
+        );
+        // Un lado vacío se ancla en la línea anterior, no en la siguiente
+        let first = |before: usize, len: usize| if len == 0 { before } else { before + 1 };
+        out.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            first(old_before, old_len),
+            old_len,
+            first(new_before, new_len),
+            new_len
+        ));
+        for (op, line) in &ops[start..stop] {
+            out.push(match op {
+                Op::Equal => ' ',
+                Op::Delete => '-',
+                Op::Insert => '+',
+            });
+            out.push_str(line);
+            if !line.ends_with('\n') {
+                out.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+    }
+    out
+}
+
+/// Diff mínimo línea a línea (Myers, O((N+M)·D)). Las ediciones de
+/// `scaffold` insertan pocas líneas, así que D es pequeño.
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(Op, &'a str)> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let max = n + m;
+    let offset = max + 1;
+    let mut v = vec![0isize; 2 * offset as usize + 1];
+    // Por cada paso d, los extremos de las diagonales -d..=d antes del paso
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    'search: for d in 0..=max {
+        trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let at = |k: isize| v[(offset + k) as usize];
+            let mut x = if k == -d || (k != d && at(k - 1) < at(k + 1)) {
+                at(k + 1)
+            } else {
+                at(k - 1) + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[(offset + k) as usize] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, snapshot) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let at = |k: isize| snapshot[(k + d) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && at(k - 1) < at(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = if d == 0 { 0 } else { at(prev_k) };
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            ops.push((Op::Equal, old[(x - 1) as usize]));
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            if x == prev_x {
+                ops.push((Op::Insert, new[(y - 1) as usize]));
+            } else {
+                ops.push((Op::Delete, old[(x - 1) as usize]));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    ops.reverse();
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insertions_get_three_lines_of_context_and_nearby_changes_share_a_hunk() {
+        let before = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\nm\nn\n";
+        let after = "X\na\nb\nc\nd\ne\nf\ng\nh\ni\nj\nY\nk\nl\nm\nn\n";
+        assert_eq!(
+            unified_diff("src/x.ts", before, after),
+            "--- a/src/x.ts\n+++ b/src/x.ts\n\
+             @@ -1,3 +1,4 @@\n+X\n a\n b\n c\n\
+             @@ -8,6 +9,7 @@\n h\n i\n j\n+Y\n k\n l\n m\n"
+        );
+
+        let merged = unified_diff("x", "a\nb\nc\nd\n", "a\n+\nb\nc\n+\nd\n");
+        assert_eq!(
+            merged,
+            "--- a/x\n+++ b/x\n@@ -1,4 +1,6 @@\n a\n++\n b\n c\n++\n d\n"
+        );
+        assert_eq!(unified_diff("x", "a\n", "a\n"), "");
+    }
+
+    #[test]
+    fn a_missing_final_newline_is_marked_like_git() {
+        assert_eq!(
+            unified_diff("doc.md", "# A\nfin", "# A\nfin\n\n## B\n"),
+            "--- a/doc.md\n+++ b/doc.md\n@@ -1,2 +1,4 @@\n # A\n\
+             -fin\n\\ No newline at end of file\n+fin\n+\n+## B\n"
+        );
+    }
+
+    #[test]
+    fn ids_become_safe_file_names() {
+        assert_eq!(file_stem("api.auth/login"), "api-auth-login");
+        assert_eq!(file_stem("user_get-1"), "user_get-1");
+    }
+}
@@ -0,0 +1,413 @@
+//! Informe de una sesión de `scaffold`.
+//!
+//! Al terminar, la sesión deja en `.docsguard/scaffold-report-<timestamp>.md`
+//! (o en la ruta de `--report`, en Markdown o JSON según la extensión) qué se
+//! enlazó y con qué confianza: por cada enlace aceptado, la función, la
+//! sección, las similitudes con su ID y su título, y si lo aceptó quien
+//! revisaba, la opción de aceptar todas las siguientes o `--force`. También
+//! lista lo rechazado y lo omitido. Con `--dry-run` el informe se genera
+//! igual, marcado como no aplicado.
+//!
+//! El Markdown no lleva la fecha ni rutas absolutas: dos sesiones con las
+//! mismas decisiones dan el mismo texto y se pueden comparar con `diff`.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use crate::baseline::DOCSGUARD_DIR;
+use crate::core::heuristic::{self, CandidateLink, MatchScores};
+use crate::core::types::DocSection;
+use crate::exit::Failure;
+use crate::parser::code_parser::atomic_write;
+use crate::vfs;
+
+/// Quién aceptó un enlace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DecidedBy {
+    /// Respuesta a la sugerencia concreta.
+    Manual,
+    /// "Todas": aceptada junto con las siguientes de la sesión.
+    Batch,
+    /// `--force`.
+    Force,
+}
+
+impl DecidedBy {
+    fn label(self) -> &'static str {
+        match self {
+            DecidedBy::Manual => "manual",
+            DecidedBy::Batch => "todas",
+            DecidedBy::Force => "--force",
+        }
+    }
+}
+
+/// De dónde sale la confianza de un enlace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Basis {
+    /// Similitud del nombre con el ID o el título.
+    Similarity,
+    /// La sección declara la función con `@expects`.
+    Expects,
+    /// Sección esqueleto nueva (`--stubs`).
+    Stub,
+}
+
+impl Basis {
+    fn label(self) -> &'static str {
+        match self {
+            Basis::Similarity => "similitud",
+            Basis::Expects => "@expects",
+            Basis::Stub => "sección nueva",
+        }
+    }
+}
+
+/// Una sugerencia de la sesión y lo que se decidió.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LinkRecord {
+    pub function: String,
+    /// `archivo:línea` de la función.
+    pub location: String,
+    pub section_id: String,
+    pub section_title: String,
+    pub confidence: f64,
+    pub basis: Basis,
+    /// Similitudes por componente; no hay en las secciones nuevas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scores: Option<MatchScores>,
+    /// Solo en los aceptados.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decided_by: Option<DecidedBy>,
+}
+
+impl LinkRecord {
+    /// Registro de `candidate`; `sections` son las de la sesión (las
+    /// esqueleto aún no están en ellas).
+    pub fn new(candidate: &CandidateLink, sections: &[DocSection], basis: Basis) -> Self {
+        let section = sections.iter().find(|s| s.id == candidate.section_id);
+        let basis = match section {
+            Some(s) if s.expected_function.as_deref() == Some(&candidate.function_name) => {
+                Basis::Expects
+            }
+            _ => basis,
+        };
+        LinkRecord {
+            function: candidate.function_name.clone(),
+            location: candidate.code_location.clone(),
+            section_id: candidate.section_id.clone(),
+            section_title: candidate.section_title.clone(),
+            confidence: candidate.confidence,
+            basis,
+            scores: section.map(|s| heuristic::match_scores(&candidate.function_name, s)),
+            decided_by: None,
+        }
+    }
+
+    pub fn decided_by(self, decided_by: DecidedBy) -> Self {
+        LinkRecord {
+            decided_by: Some(decided_by),
+            ..self
+        }
+    }
+}
+
+/// Enlace de archivo (`@docs-file`) aceptado en lugar de los de función.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FileLinkRecord {
+    pub section_id: String,
+    pub section_title: String,
+    pub decided_by: DecidedBy,
+}
+
+/// Todo lo decidido en una sesión.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SessionReport {
+    /// `false` con `--dry-run`.
+    pub applied: bool,
+    pub code_file: String,
+    pub doc_file: String,
+    /// Dónde se escriben los enlaces: el archivo de código o `links.yaml`.
+    pub destination: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_link: Option<FileLinkRecord>,
+    pub accepted: Vec<LinkRecord>,
+    pub rejected: Vec<LinkRecord>,
+    pub skipped: Vec<LinkRecord>,
+}
+
+impl SessionReport {
+    /// Indica si hubo alguna sugerencia que decidir.
+    pub fn is_empty(&self) -> bool {
+        self.file_link.is_none()
+            && self.accepted.is_empty()
+            && self.rejected.is_empty()
+            && self.skipped.is_empty()
+    }
+}
+
+/// Formato del informe, por la extensión de su ruta.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Markdown,
+    Json,
+}
+
+impl ReportFormat {
+    pub fn for_path(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("md") => Ok(ReportFormat::Markdown),
+            Some("json") => Ok(ReportFormat::Json),
+            _ => Err(Failure::usage(format!(
+                "Formato de informe no reconocido: {}",
+                path.display()
+            ))
+            .with_hint("Usa una ruta terminada en .md o .json.")
+            .into()),
+        }
+    }
+}
+
+/// Ruta por defecto: `.docsguard/scaffold-report-<segundos unix>.md`.
+pub fn default_path(project_root: &Path) -> PathBuf {
+    let seconds = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    project_root
+        .join(DOCSGUARD_DIR)
+        .join(format!("scaffold-report-{}.md", seconds))
+}
+
+/// Escribe el informe en `path`, en el formato de su extensión.
+pub fn write(report: &SessionReport, path: &Path) -> Result<()> {
+    let content = match ReportFormat::for_path(path)? {
+        ReportFormat::Markdown => render_markdown(report),
+        ReportFormat::Json => {
+            serde_json::to_string_pretty(report).context("Error al serializar el informe")? + "\n"
+        }
+    };
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        vfs::create_dir_all(dir).with_context(|| format!("No se pudo crear: {}", dir.display()))?;
+    }
+    atomic_write(path, content.as_bytes())
+}
+
+/// Informe en Markdown, con una tabla por lista.
+pub fn render_markdown(report: &SessionReport) -> String {
+    let mut out = String::from("# Informe de scaffold\n\n");
+    out.push_str(&format!("- Código: `{}`\n", report.code_file));
+    out.push_str(&format!("- Docs: `{}`\n", report.doc_file));
+    out.push_str(&format!("- Destino: `{}`\n", report.destination));
+    out.push_str(if report.applied {
+        "- Estado: aplicado\n"
+    } else {
+        "- Estado: **dry-run — no se aplicó ningún cambio**\n"
+    });
+
+    if let Some(file_link) = &report.file_link {
+        out.push_str(&format!(
+            "\n## Enlace de archivo\n\n`@docs-file {}` — {} ({})\n",
+            file_link.section_id,
+            cell(&file_link.section_title),
+            file_link.decided_by.label()
+        ));
+    }
+    render_table(&mut out, "Aceptados", &report.accepted, true);
+    render_table(&mut out, "Rechazados", &report.rejected, false);
+    render_table(&mut out, "Omitidos", &report.skipped, false);
+    out
+}
+
+fn render_table(out: &mut String, title: &str, links: &[LinkRecord], decided: bool) {
+    out.push_str(&format!("\n## {} ({})\n\n", title, links.len()));
+    if links.is_empty() {
+        out.push_str("_Ninguno._\n");
+        return;
+    }
+    out.push_str(
+        "| Función | Ubicación | Sección | Título | Confianza | Sim. ID | Sim. título | Origen |",
+    );
+    out.push_str(if decided { " Decisión |\n" } else { "\n" });
+    out.push_str("|---|---|---|---|---:|---:|---:|---|");
+    out.push_str(if decided { "---|\n" } else { "\n" });
+    for link in links {
+        let (id, title) = match link.scores {
+            Some(scores) => (percent(scores.id), scores.title.map_or("—".into(), percent)),
+            None => ("—".into(), "—".into()),
+        };
+        out.push_str(&format!(
+            "| `{}` | {} | `{}` | {} | {} | {} | {} | {} |",
+            link.function,
+            cell(&link.location),
+            link.section_id,
+            cell(&link.section_title),
+            percent(link.confidence),
+            id,
+            title,
+            link.basis.label()
+        ));
+        if decided {
+            let by = link.decided_by.map_or("—", DecidedBy::label);
+            out.push_str(&format!(" {} |", by));
+        }
+        out.push('\n');
+    }
+}
+
+fn percent(value: f64) -> String {
+    format!("{:.0}%", value * 100.0)
+}
+
+/// Texto seguro dentro de una celda de tabla.
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::MemFs;
+    use std::path::PathBuf;
+
+    fn section(id: &str, title: &str) -> DocSection {
+        DocSection {
+            id: id.into(),
+            title: Some(title.into()),
+            parent: None,
+            args: Vec::new(),
+            file_path: PathBuf::from("docs/api.md"),
+            line: 1,
+            expectations: Vec::new(),
+            examples: Vec::new(),
+            since: None,
+            expected_function: None,
+            skips: Vec::new(),
+            dropped_args: 0,
+            title_inferred: false,
+            no_link: false,
+            mentions: Vec::new(),
+        }
+    }
+
+    fn candidate(function: &str, section_id: &str, confidence: f64) -> CandidateLink {
+        CandidateLink {
+            entity_index: 0,
+            function_name: function.into(),
+            code_location: "src/auth.ts:3".into(),
+            section_id: section_id.into(),
+            section_title: "Login | Auth".into(),
+            confidence,
+        }
+    }
+
+    fn session() -> SessionReport {
+        let sections = [
+            section("auth-login", "Login | Auth"),
+            DocSection {
+                expected_function: Some("logout".into()),
+                ..section("auth-logout", "Cerrar sesión")
+            },
+        ];
+        SessionReport {
+            applied: false,
+            code_file: "src/auth.ts".into(),
+            doc_file: "docs/api.md".into(),
+            destination: "src/auth.ts".into(),
+            file_link: None,
+            accepted: vec![
+                LinkRecord::new(
+                    &candidate("login", "auth-login", 1.0),
+                    &sections,
+                    Basis::Similarity,
+                )
+                .decided_by(DecidedBy::Manual),
+                LinkRecord::new(
+                    &candidate("logout", "auth-logout", 1.0),
+                    &sections,
+                    Basis::Similarity,
+                )
+                .decided_by(DecidedBy::Batch),
+                LinkRecord::new(
+                    &candidate("refresh", "refresh", 1.0),
+                    &sections,
+                    Basis::Stub,
+                )
+                .decided_by(DecidedBy::Force),
+            ],
+            rejected: vec![LinkRecord::new(
+                &candidate("loginUser", "auth-login", 0.82),
+                &sections,
+                Basis::Similarity,
+            )],
+            skipped: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn markdown_is_deterministic_and_marks_dry_runs() {
+        let report = session();
+        let markdown = render_markdown(&report);
+        assert_eq!(markdown, render_markdown(&report.clone()));
+        assert_eq!(
+            markdown,
+            "# Informe de scaffold\n\n\
+             - Código: `src/auth.ts`\n\
+             - Docs: `docs/api.md`\n\
+             - Destino: `src/auth.ts`\n\
+             - Estado: **dry-run — no se aplicó ningún cambio**\n\
+             \n## Aceptados (3)\n\n\
+             | Función | Ubicación | Sección | Título | Confianza | Sim. ID | Sim. título | Origen | Decisión |\n\
+             |---|---|---|---|---:|---:|---:|---|---|\n\
+             | `login` | src/auth.ts:3 | `auth-login` | Login \\| Auth | 100% | 50% | 42% | similitud | manual |\n\
+             | `logout` | src/auth.ts:3 | `auth-logout` | Login \\| Auth | 100% | 55% | 0% | @expects | todas |\n\
+             | `refresh` | src/auth.ts:3 | `refresh` | Login \\| Auth | 100% | — | — | sección nueva | --force |\n\
+             \n## Rechazados (1)\n\n\
+             | Función | Ubicación | Sección | Título | Confianza | Sim. ID | Sim. título | Origen |\n\
+             |---|---|---|---|---:|---:|---:|---|\n\
+             | `loginUser` | src/auth.ts:3 | `auth-login` | Login \\| Auth | 82% | 10% | 42% | similitud |\n\
+             \n## Omitidos (0)\n\n_Ninguno._\n"
+        );
+        let applied = SessionReport {
+            applied: true,
+            ..report
+        };
+        assert!(render_markdown(&applied).contains("- Estado: aplicado\n"));
+    }
+
+    #[test]
+    fn json_keeps_component_scores_and_who_decided() {
+        let json = serde_json::to_value(session()).unwrap();
+        assert_eq!(json["applied"], false);
+        let accepted = &json["accepted"];
+        assert_eq!(accepted[0]["decided_by"], "manual");
+        assert_eq!(accepted[1]["basis"], "expects");
+        assert_eq!(accepted[2]["basis"], "stub");
+        assert!(accepted[2].get("scores").is_none());
+        assert_eq!(accepted[0]["scores"]["id"], 0.5);
+        assert!(json["rejected"][0].get("decided_by").is_none());
+    }
+
+    #[test]
+    fn the_extension_picks_the_format() {
+        let dir = MemFs::project();
+        let report = session();
+        let json = dir.path().join("out/session.json");
+        write(&report, &json).unwrap();
+        let content = dir.contents(&json).unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&content).unwrap()["code_file"],
+            "src/auth.ts"
+        );
+        let markdown = dir.path().join("session.md");
+        write(&report, &markdown).unwrap();
+        assert_eq!(dir.contents(&markdown).unwrap(), render_markdown(&report));
+        assert!(write(&report, &dir.path().join("session.txt")).is_err());
+        assert!(default_path(dir.path())
+            .to_string_lossy()
+            .contains(".docsguard/scaffold-report-"));
+    }
+}
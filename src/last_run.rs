@@ -0,0 +1,550 @@
+//! Registro de la última ejecución para `check --fast` (`.docsguard/last_run.json`).
+//!
+//! Guarda el hash del contenido de cada archivo, las entidades de cada archivo
+//! de código y los hallazgos previos al baseline. La siguiente ejecución con
+//! `--fast` re-parsea solo los archivos de código cuyo contenido cambió y
+//! empalma sus hallazgos con los conservados de los archivos intactos.
+//!
+//! Un par es (archivo de docs, archivo de código). Un cambio en el archivo de
+//! docs afecta a todos los pares que lo referencian, así que obliga a una
+//! ejecución completa, igual que otra versión de docsguard, otra configuración,
+//! otro baseline u otras opciones de `check`. El registro es desechable: si no
+//! se puede leer, se repite todo.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::baseline::{self, DOCSGUARD_DIR};
+use crate::config::{self, Config};
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::types::{parse_location, CodeEntity, Rule, ValidationResult};
+use crate::mapping;
+use crate::parser::code_parser::atomic_write;
+use crate::report::{self, timing::Timings};
+use crate::vfs::{self, Bounded};
+
+/// Nombre del archivo del registro dentro de `.docsguard/`.
+const LAST_RUN_FILE: &str = "last_run.json";
+/// Tamaño máximo del registro: por encima se ignora y se repite todo (VUL-04).
+const MAX_LAST_RUN_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Entradas de una ejecución que, si cambian, invalidan todos los pares.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunKey {
+    /// Versión de docsguard que escribió el registro.
+    pub version: String,
+    /// Hash de `config.yaml`, `links.yaml` y los `--ignore-fn`.
+    pub config_hash: u64,
+    /// Hash de `baseline.yaml`.
+    pub baseline_hash: u64,
+    /// Opciones de `check` que cambian los hallazgos.
+    pub options: String,
+    /// Archivo de docs, tal como aparece en los hallazgos.
+    pub doc_file: PathBuf,
+    pub doc_hash: u64,
+}
+
+impl RunKey {
+    /// Clave de esta ejecución. `doc_path` es la ruta normalizada de `doc_file`.
+    pub fn new(
+        project_root: &Path,
+        doc_file: &Path,
+        doc_path: &Path,
+        options: String,
+    ) -> Result<Self> {
+        Ok(RunKey {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            config_hash: content_hash(
+                &[
+                    read_optional(&config::config_path(project_root))?,
+                    read_optional(&mapping::links_path(project_root))?,
+                    format!(
+                        "{:?}",
+                        config::overrides::active().map(|o| &o.ignore_functions)
+                    )
+                    .into_bytes(),
+                ]
+                .concat(),
+            ),
+            baseline_hash: content_hash(&read_optional(&baseline::baseline_path(
+                project_root,
+                None,
+            ))?),
+            options,
+            doc_file: doc_path.to_path_buf(),
+            doc_hash: hash_file(doc_file)?,
+        })
+    }
+}
+
+/// Archivo de código de la ejecución anterior con sus entidades.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FileRecord {
+    path: PathBuf,
+    hash: u64,
+    entities: Vec<CodeEntity>,
+    /// Funciones descartadas por `ignore_functions`, para el resumen.
+    #[serde(default)]
+    ignored: usize,
+}
+
+/// Lo que `--fast` necesita de la ejecución anterior.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LastRun {
+    key: RunKey,
+    files: Vec<FileRecord>,
+    /// Hallazgos tras supresiones y niveles por regla, antes del baseline.
+    results: Vec<ValidationResult>,
+}
+
+impl LastRun {
+    /// Registro de una ejecución: `code_files` son las rutas normalizadas con
+    /// el hash de su contenido, y las entidades y las funciones ignoradas
+    /// (`ParseDiagnostics::ignored_functions`) se reparten por archivo.
+    pub fn new(
+        key: RunKey,
+        code_files: &[(PathBuf, u64)],
+        entities: &[CodeEntity],
+        ignored: &[PathBuf],
+        results: Vec<ValidationResult>,
+    ) -> Self {
+        let files = code_files
+            .iter()
+            .map(|(path, hash)| FileRecord {
+                path: path.clone(),
+                hash: *hash,
+                entities: entities
+                    .iter()
+                    .filter(|e| e.file_path == *path)
+                    .cloned()
+                    .collect(),
+                ignored: ignored.iter().filter(|p| *p == path).count(),
+            })
+            .collect();
+        LastRun {
+            key,
+            files,
+            results,
+        }
+    }
+
+    /// Carga el registro de `project_root`. `None` si no existe o no se puede usar.
+    pub fn load(project_root: &Path) -> Result<Option<Self>> {
+        let path = last_run_path(project_root);
+        if !vfs::exists(&path) {
+            return Ok(None);
+        }
+        match vfs::read_bounded(&path, MAX_LAST_RUN_SIZE)
+            .with_context(|| format!("No se pudo leer el registro: {}", path.display()))?
+        {
+            Bounded::Contents(content) => Ok(serde_json::from_str(&content).ok()),
+            Bounded::TooLarge(_) => Ok(None),
+        }
+    }
+
+    /// Guarda el registro con escritura atómica (VUL-02).
+    pub fn save(&self, project_root: &Path) -> Result<()> {
+        let dir = project_root.join(DOCSGUARD_DIR);
+        vfs::create_dir_all(&dir)
+            .with_context(|| format!("No se pudo crear: {}", dir.display()))?;
+        let content = serde_json::to_vec(self).context("Error al serializar el registro")?;
+        atomic_write(&last_run_path(project_root), &content)
+    }
+
+    /// Motivo por el que el registro no sirve para esta ejecución, o `None`.
+    pub fn stale_reason(&self, key: &RunKey) -> Option<&'static str> {
+        if self.key.version != key.version {
+            Some("la ejecución anterior es de otra versión de docsguard")
+        } else if self.key.config_hash != key.config_hash {
+            Some("cambió la configuración de .docsguard/")
+        } else if self.key.baseline_hash != key.baseline_hash {
+            Some("cambió el baseline")
+        } else if self.key.options != key.options {
+            Some("cambiaron las opciones de check")
+        } else if self.key.doc_file != key.doc_file || self.key.doc_hash != key.doc_hash {
+            Some("cambió el archivo de docs, que afecta a todos los pares")
+        } else {
+            None
+        }
+    }
+
+    /// Entidades guardadas del archivo y cuántas funciones se ignoraron en
+    /// él, si su contenido no cambió.
+    pub fn unchanged_entities(&self, path: &Path, hash: u64) -> Option<(&[CodeEntity], usize)> {
+        self.files
+            .iter()
+            .find(|f| f.path == path && f.hash == hash)
+            .map(|f| (f.entities.as_slice(), f.ignored))
+    }
+
+    /// Archivo de docs de la ejecución registrada.
+    pub fn doc_file(&self) -> &Path {
+        &self.key.doc_file
+    }
+
+    pub fn results(&self) -> &[ValidationResult] {
+        &self.results
+    }
+}
+
+/// Entidades de `code_files` con el registro anterior: las guardadas para los
+/// archivos cuyo contenido no cambió y las re-parseadas del resto. Devuelve
+/// también las rutas normalizadas de los archivos intactos.
+pub fn parse_changed(
+    previous: &LastRun,
+    code_files: &[PathBuf],
+    hashes: &[u64],
+    config: &Config,
+    diagnostics: &mut ParseDiagnostics,
+    timings: &mut Timings,
+) -> Result<(Vec<CodeEntity>, HashSet<PathBuf>)> {
+    let mut entities = Vec::new();
+    let mut untouched = HashSet::new();
+    for (code_file, hash) in code_files.iter().zip(hashes) {
+        let display_path = config.paths.normalize(code_file);
+        match previous.unchanged_entities(&display_path, *hash) {
+            Some((cached, ignored)) => {
+                let mut cached = cached.to_vec();
+                diagnostics
+                    .ignored_functions
+                    .extend(std::iter::repeat_n(display_path.clone(), ignored));
+                // Idempotente sobre entidades ya enlazadas; regenera los `stale-mapping`
+                config.links.apply(&display_path, &mut cached, diagnostics);
+                entities.extend(cached);
+                untouched.insert(display_path);
+            }
+            None => entities.extend(report::parse_timed(
+                std::slice::from_ref(code_file),
+                config,
+                diagnostics,
+                timings,
+            )?),
+        }
+    }
+    Ok((entities, untouched))
+}
+
+/// Hallazgos de esta ejecución empalmados con los conservados: de la ejecución
+/// anterior se queda con los de reglas locales ubicados en archivos intactos, y
+/// de `fresh`, con todo lo demás. Los conservados van primero, así los del
+/// archivo que se está editando quedan junto al resumen.
+pub fn splice(
+    previous: &[ValidationResult],
+    fresh: Vec<ValidationResult>,
+    untouched: &HashSet<PathBuf>,
+) -> Vec<ValidationResult> {
+    let retained = |r: &ValidationResult| {
+        is_pair_local(r.rule)
+            && r.code_location
+                .as_deref()
+                .and_then(parse_location)
+                .is_some_and(|(path, _)| untouched.contains(path))
+    };
+    previous
+        .iter()
+        .filter(|r| retained(r))
+        .cloned()
+        .chain(fresh.into_iter().filter(|r| !retained(r)))
+        .collect()
+}
+
+/// Indica si los hallazgos de la regla dependen solo de una función (y su
+/// sección o las notas de su archivo). Los de reglas globales pueden cambiar
+/// por otro archivo y se recalculan siempre.
+fn is_pair_local(rule: Rule) -> bool {
+    match rule {
+        Rule::UnlinkedFunction
+        | Rule::LinkVerified
+        | Rule::MissingDocSection
+        | Rule::ForbiddenLink
+        | Rule::MissingArg
+        | Rule::TypeMismatch
+        | Rule::ExampleMismatch
+        | Rule::ExampleArgMismatch
+        | Rule::MalformedAnnotation
+        | Rule::StaleMapping
+        | Rule::PlaceholderDescription
+        | Rule::InternalSymbolReference
+        | Rule::ArgTypo
+        | Rule::ArgException
+        | Rule::LinkBoundary
+        | Rule::ArgNameStyle
+        | Rule::ArgConstraint
+        | Rule::ConflictingArgDocs
+        | Rule::ConflictingDocsIds
+        | Rule::DetachedAnnotation
+        | Rule::SkippedArgTable
+        | Rule::UnnormalizedType
+        | Rule::SyntaxErrorInEntity
+        | Rule::TruncatedDocs
+        | Rule::VcsConflict
+        | Rule::InferredTitle => true,
+        // Huérfanas, `@expects`, las firmas entre lenguajes y las secciones
+        // copiadas (que absorben los `ghost-arg`) miran todas las funciones;
+        // símbolos y versiones, el proyecto; las anclas, solo las docs; las
+        // funciones nuevas, la revisión base; las atestaciones, su archivo;
+        // los IDs repetidos, todos los archivos de docs; los demás se
+        // calculan tras el empalme.
+        Rule::OrphanSection
+        | Rule::BrokenAnchor
+        | Rule::UnpublishedSection
+        | Rule::UndocumentedNewFunction
+        | Rule::ChangedSinceAttestation
+        | Rule::MissingAttestation
+        | Rule::ExpectedFunction
+        | Rule::CrossLanguageDrift
+        | Rule::GhostArg
+        | Rule::CopiedSection
+        | Rule::DuplicateDocId
+        | Rule::UnknownExampleSymbol
+        | Rule::SinceVersion
+        | Rule::UnusedSuppression
+        | Rule::MovedBaselineEntry
+        | Rule::SkippedFile
+        | Rule::SlowRun => false,
+    }
+}
+
+/// Hash de un archivo (FNV-1a de 64 bits): estable entre ejecuciones y plataformas.
+pub fn hash_file(path: &Path) -> Result<u64> {
+    let content =
+        vfs::read(path).with_context(|| format!("No se pudo leer: {}", path.display()))?;
+    Ok(content_hash(&content))
+}
+
+pub(crate) fn content_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Contenido de un archivo opcional; uno que no existe cuenta como vacío.
+fn read_optional(path: &Path) -> Result<Vec<u8>> {
+    if !vfs::exists(path) {
+        return Ok(Vec::new());
+    }
+    vfs::read(path).with_context(|| format!("No se pudo leer: {}", path.display()))
+}
+
+fn last_run_path(project_root: &Path) -> PathBuf {
+    project_root.join(DOCSGUARD_DIR).join(LAST_RUN_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Severity;
+    use crate::vfs::MemFs;
+
+    fn entity(name: &str, file: &str) -> CodeEntity {
+        CodeEntity {
+            name: name.into(),
+            args: vec![],
+            return_type: None,
+            return_fields: None,
+            doc_id: Some(name.into()),
+            file_path: PathBuf::from(file),
+            line: 3,
+            end_line: 3,
+            is_public: true,
+            suppressions: vec![],
+            file_link: None,
+            examples: Vec::new(),
+        }
+    }
+
+    fn result(rule: Rule, message: &str, location: Option<&str>) -> ValidationResult {
+        ValidationResult {
+            severity: Severity::Warning,
+            rule,
+            message: message.into(),
+            function_name: None,
+            code_location: location.map(String::from),
+            doc_id: None,
+            doc_location: Some("docs/api.md:10".into()),
+            hint: None,
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+
+    fn key(doc_hash: u64) -> RunKey {
+        RunKey {
+            version: "0.2.0".into(),
+            config_hash: 1,
+            baseline_hash: 2,
+            options: "examples=false".into(),
+            doc_file: PathBuf::from("docs/api.md"),
+            doc_hash,
+        }
+    }
+
+    fn messages(results: &[ValidationResult]) -> Vec<&str> {
+        results.iter().map(|r| r.message.as_str()).collect()
+    }
+
+    #[test]
+    fn splice_keeps_local_findings_of_untouched_files_only() {
+        let previous = vec![
+            result(Rule::TypeMismatch, "old a.ts", Some("src/a.ts:3")),
+            result(Rule::TypeMismatch, "old b.ts", Some("src/b.ts:3")),
+            result(
+                Rule::DetachedAnnotation,
+                "old note a.ts",
+                Some("src/a.ts:1"),
+            ),
+        ];
+        let fresh = vec![
+            // Sin las notas del parser de a.ts, que no se re-parseó
+            result(Rule::TypeMismatch, "new a.ts", Some("src/a.ts:3")),
+            result(Rule::MissingArg, "new b.ts", Some("src/b.ts:3")),
+        ];
+        let untouched = HashSet::from([PathBuf::from("src/a.ts")]);
+        assert_eq!(
+            messages(&splice(&previous, fresh, &untouched)),
+            ["old a.ts", "old note a.ts", "new b.ts"]
+        );
+    }
+
+    #[test]
+    fn splice_always_takes_global_and_unlocated_findings_from_this_run() {
+        let previous = vec![
+            result(Rule::OrphanSection, "old orphan", Some("src/a.ts:3")),
+            result(Rule::ExpectedFunction, "old expects", Some("src/a.ts:3")),
+            // Otro archivo puede fundirlo en un `copied-section`
+            result(Rule::GhostArg, "old ghost", Some("src/a.ts:3")),
+            result(Rule::ConflictingDocsIds, "old doc note", None),
+        ];
+        let fresh = vec![
+            result(Rule::ExpectedFunction, "new expects", Some("src/a.ts:3")),
+            result(Rule::ConflictingDocsIds, "new doc note", None),
+        ];
+        let untouched = HashSet::from([PathBuf::from("src/a.ts")]);
+        assert_eq!(
+            messages(&splice(&previous, fresh, &untouched)),
+            ["new expects", "new doc note"]
+        );
+    }
+
+    #[test]
+    fn splice_with_nothing_untouched_is_the_fresh_run() {
+        let previous = vec![result(Rule::GhostArg, "old", Some("src/a.ts:3"))];
+        let fresh = vec![result(Rule::GhostArg, "new", Some("src/a.ts:3"))];
+        assert_eq!(
+            messages(&splice(&previous, fresh, &HashSet::new())),
+            ["new"]
+        );
+    }
+
+    #[test]
+    fn any_change_to_the_run_key_invalidates_the_record() {
+        let run = LastRun::new(key(7), &[], &[], &[], vec![]);
+        assert_eq!(run.stale_reason(&key(7)), None);
+
+        let changes: [fn(&mut RunKey); 5] = [
+            |k| k.version = "0.3.0".into(),
+            |k| k.config_hash += 1,
+            |k| k.baseline_hash += 1,
+            |k| k.options.push_str(" symbols=true"),
+            |k| k.doc_file = PathBuf::from("docs/other.md"),
+        ];
+        for change in changes {
+            let mut changed = key(7);
+            change(&mut changed);
+            assert!(run.stale_reason(&changed).is_some(), "{changed:?}");
+        }
+    }
+
+    #[test]
+    fn a_changed_doc_invalidates_every_pair_even_with_untouched_code() {
+        let files = [
+            (PathBuf::from("src/a.ts"), 10),
+            (PathBuf::from("src/b.rs"), 20),
+        ];
+        let entities = [entity("login", "src/a.ts"), entity("parse", "src/b.rs")];
+        let run = LastRun::new(key(7), &files, &entities, &[], vec![]);
+        // El código no cambió, pero ningún par puede reutilizarse
+        assert!(run.unchanged_entities(Path::new("src/a.ts"), 10).is_some());
+        assert_eq!(
+            run.stale_reason(&key(8)),
+            Some("cambió el archivo de docs, que afecta a todos los pares")
+        );
+    }
+
+    #[test]
+    fn entities_are_reused_only_for_the_same_content() {
+        let files = [
+            (PathBuf::from("src/a.ts"), 10),
+            (PathBuf::from("src/b.rs"), 20),
+        ];
+        let entities = [
+            entity("login", "src/a.ts"),
+            entity("logout", "src/a.ts"),
+            entity("parse", "src/b.rs"),
+        ];
+        let ignored = [PathBuf::from("src/b.rs")];
+        let run = LastRun::new(key(7), &files, &entities, &ignored, vec![]);
+        let names = |path: &str, hash| {
+            run.unchanged_entities(Path::new(path), hash)
+                .map(|(es, _)| es.iter().map(|e| e.name.clone()).collect::<Vec<_>>())
+        };
+        assert_eq!(
+            names("src/a.ts", 10),
+            Some(vec!["login".into(), "logout".into()])
+        );
+        assert_eq!(names("src/b.rs", 20), Some(vec!["parse".into()]));
+        let ignored_in =
+            |path: &str, hash| run.unchanged_entities(Path::new(path), hash).unwrap().1;
+        assert_eq!(
+            (ignored_in("src/a.ts", 10), ignored_in("src/b.rs", 20)),
+            (0, 1)
+        );
+        assert_eq!(names("src/a.ts", 11), None);
+        assert_eq!(names("src/new.ts", 10), None);
+    }
+
+    #[test]
+    fn record_round_trips_and_a_corrupt_one_is_ignored() {
+        let dir = MemFs::project();
+        assert!(LastRun::load(dir.path()).unwrap().is_none());
+
+        let findings = vec![result(Rule::GhostArg, "ghost", Some("src/a.ts:3"))];
+        let files = [(PathBuf::from("src/a.ts"), 10)];
+        LastRun::new(
+            key(7),
+            &files,
+            &[entity("login", "src/a.ts")],
+            &[],
+            findings,
+        )
+        .save(dir.path())
+        .unwrap();
+        let loaded = LastRun::load(dir.path()).unwrap().unwrap();
+        assert_eq!(loaded.stale_reason(&key(7)), None);
+        assert_eq!(messages(loaded.results()), ["ghost"]);
+        assert_eq!(
+            loaded
+                .unchanged_entities(Path::new("src/a.ts"), 10)
+                .unwrap()
+                .0
+                .len(),
+            1
+        );
+
+        dir.write(last_run_path(dir.path()), "{ no es json");
+        assert!(LastRun::load(dir.path()).unwrap().is_none());
+    }
+
+    #[test]
+    fn content_hash_is_stable_and_sensitive() {
+        // Vector de referencia de FNV-1a 64
+        assert_eq!(content_hash(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_ne!(content_hash(b"fn a() {}"), content_hash(b"fn a() { }"));
+    }
+}
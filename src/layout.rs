@@ -0,0 +1,461 @@
+//! Maquetación del texto de terminal: anchura visible, recorte y ajuste de
+//! líneas, enlaces OSC 8 y colores.
+//!
+//! La anchura es la de `unicode-width` (un ideograma CJK o un emoji ocupan
+//! dos columnas, un acento combinado ninguna), nunca la longitud en bytes: los
+//! recortes no parten una secuencia UTF-8 ni separan una letra de su acento.
+//! Mensajes y sugerencias se ajustan a la anchura del terminal (`COLUMNS`, o
+//! la de la TTY; 100 si no se sabe) con sangría francesa bajo el prefijo
+//! `    -> `. Las palabras más largas que la línea se dejan enteras (rutas,
+//! URLs), salvo que lleven caracteres anchos: el texto CJK no usa espacios y
+//! se parte entre caracteres.
+//!
+//! Las ubicaciones `archivo:línea` se envuelven en un hipervínculo OSC 8 al
+//! archivo si stdout es un terminal que no es `dumb`; `--no-hyperlinks` lo
+//! desactiva.
+//!
+//! Los colores siguen una sola paleta (`Style`) en `check`, `watch`,
+//! `scaffold` y `coverage`: rojo para los errores, amarillo para los avisos,
+//! atenuado para los Info y cian para las sugerencias. Se usan si stdout es un
+//! terminal que no es `dumb`; `--no-color` o `NO_COLOR` (con cualquier valor)
+//! los desactivan. Se pintan al final, sobre el texto ya traducido y
+//! ajustado: las secuencias ANSI no cuentan columnas.
+
+use std::borrow::Cow;
+use std::io::IsTerminal;
+use std::path::Path;
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::core::types::parse_location;
+use crate::vfs;
+
+/// Anchura cuando no se puede detectar la del terminal.
+pub const DEFAULT_WIDTH: usize = 100;
+
+/// Columnas mínimas para el texto tras un prefijo: en terminales muy
+/// estrechos se desborda antes que dejar una palabra por línea.
+const MIN_TEXT_WIDTH: usize = 20;
+
+/// Cómo se maqueta la salida de terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    /// Columnas disponibles.
+    pub width: usize,
+    /// Envolver las ubicaciones en hipervínculos OSC 8.
+    pub hyperlinks: bool,
+    /// Pintar con la paleta de `Style`.
+    pub color: bool,
+}
+
+impl Default for Layout {
+    /// `DEFAULT_WIDTH` columnas, sin hipervínculos ni colores: lo que usa `Display`.
+    fn default() -> Self {
+        Layout {
+            width: DEFAULT_WIDTH,
+            hyperlinks: false,
+            color: false,
+        }
+    }
+}
+
+/// Papel de un texto en la paleta de la salida de terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// `[X] Error`, cifras por debajo del umbral.
+    Error,
+    /// `[!] Warning`, cifras intermedias.
+    Warning,
+    /// `[i] Info`.
+    Info,
+    /// `Sugerencia:` y etiquetas secundarias.
+    Hint,
+    /// Lo que salió bien (`✓`, una corrección aplicada).
+    Success,
+    /// Títulos y separadores de bloque.
+    Heading,
+    /// Detalles que se pueden saltar (rutas en tablas, recuentos).
+    Dim,
+}
+
+impl Style {
+    fn ansi(self) -> &'static str {
+        match self {
+            Style::Error => "\x1b[1;31m",
+            Style::Warning => "\x1b[1;33m",
+            Style::Info | Style::Dim => "\x1b[2m",
+            Style::Hint => "\x1b[36m",
+            Style::Success => "\x1b[1;32m",
+            Style::Heading => "\x1b[1m",
+        }
+    }
+}
+
+impl Layout {
+    /// Maquetación para stdout: anchura detectada, e hipervínculos y colores
+    /// si es un terminal que no es `dumb` y no se pasó `--no-hyperlinks` /
+    /// `--no-color` (ni `NO_COLOR`).
+    pub fn detect(no_hyperlinks: bool, no_color: bool) -> Self {
+        let tty = std::io::stdout().is_terminal()
+            && std::env::var("TERM").map_or(true, |term| term != "dumb");
+        Layout {
+            width: terminal_width().unwrap_or(DEFAULT_WIDTH),
+            hyperlinks: tty && !no_hyperlinks,
+            color: tty && !no_color && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty()),
+        }
+    }
+
+    /// `text` con el color de `style`, o tal cual sin colores. La sangría y
+    /// los saltos de línea de los extremos quedan fuera del color.
+    pub fn paint<'a>(&self, style: Style, text: &'a str) -> Cow<'a, str> {
+        let core = text.trim();
+        if !self.color || core.is_empty() {
+            return Cow::Borrowed(text);
+        }
+        let start = text.len() - text.trim_start().len();
+        Cow::Owned(format!(
+            "{}{}{}\x1b[0m{}",
+            &text[..start],
+            style.ansi(),
+            core,
+            &text[start + core.len()..]
+        ))
+    }
+
+    /// `text` tras `prefix`, ajustado a la anchura: las líneas siguientes se
+    /// sangran con tantos espacios como columnas ocupa el prefijo. Cada línea
+    /// termina en `\n`.
+    pub fn wrap(&self, prefix: &str, text: &str) -> String {
+        let indent = " ".repeat(width(prefix));
+        let available = self.width.saturating_sub(indent.len()).max(MIN_TEXT_WIDTH);
+        let mut out = String::new();
+        for (i, line) in text
+            .split('\n')
+            .flat_map(|paragraph| wrap_paragraph(paragraph, available))
+            .enumerate()
+        {
+            out.push_str(if i == 0 { prefix } else { &indent });
+            out.push_str(&line);
+            out.push('\n');
+        }
+        out
+    }
+
+    /// `location` (`archivo:línea`) como hipervínculo al archivo, si procede.
+    pub fn location(&self, location: &str) -> String {
+        if !self.hyperlinks {
+            return location.to_string();
+        }
+        let file = parse_location(location).map_or(Path::new(location), |(file, _)| file);
+        match vfs::canonicalize(file) {
+            Ok(path) => hyperlink(&format!("file://{}", path.display()), location),
+            Err(_) => location.to_string(),
+        }
+    }
+}
+
+/// Columnas que ocupa `text` en un terminal.
+pub fn width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// `text` recortado a `max_width` columnas, con `…` al final si no cabía.
+pub fn truncate(text: &str, max_width: usize) -> Cow<'_, str> {
+    if width(text) <= max_width {
+        return Cow::Borrowed(text);
+    }
+    let budget = max_width.saturating_sub(1);
+    let mut out = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w > budget {
+            break;
+        }
+        used += w;
+        out.push(c);
+    }
+    if max_width > 0 {
+        out.push('…');
+    }
+    Cow::Owned(out)
+}
+
+/// Secuencia OSC 8 con `text` enlazando a `url`.
+fn hyperlink(url: &str, text: &str) -> String {
+    format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+}
+
+/// Parte un párrafo en líneas de como mucho `available` columnas.
+fn wrap_paragraph(paragraph: &str, available: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut used = 0;
+    for word in paragraph.split(' ') {
+        let w = width(word);
+        if used > 0 && used + 1 + w > available {
+            lines.push(std::mem::take(&mut current));
+            used = 0;
+        }
+        if w > available && has_wide_chars(word) {
+            // Texto CJK: se parte entre caracteres
+            let mut rest = word;
+            loop {
+                let fits = available.saturating_sub(used + usize::from(used > 0));
+                let piece = truncate_at(rest, fits);
+                if piece.is_empty() && used == 0 {
+                    // Ni un carácter cabe: se deja desbordar
+                    current.push_str(rest);
+                    used += width(rest);
+                    break;
+                }
+                if !piece.is_empty() {
+                    if used > 0 {
+                        current.push(' ');
+                        used += 1;
+                    }
+                    current.push_str(piece);
+                    used += width(piece);
+                    rest = &rest[piece.len()..];
+                }
+                if rest.is_empty() {
+                    break;
+                }
+                lines.push(std::mem::take(&mut current));
+                used = 0;
+            }
+            continue;
+        }
+        if used > 0 {
+            current.push(' ');
+            used += 1;
+        }
+        current.push_str(word);
+        used += w;
+    }
+    lines.push(current);
+    lines
+}
+
+/// Prefijo más largo de `text` que cabe en `max_width` columnas, sin separar
+/// un carácter de sus marcas combinadas.
+fn truncate_at(text: &str, max_width: usize) -> &str {
+    let mut used = 0;
+    for (i, c) in text.char_indices() {
+        let w = c.width().unwrap_or(0);
+        if w > 0 && used + w > max_width {
+            return &text[..i];
+        }
+        used += w;
+    }
+    text
+}
+
+fn has_wide_chars(word: &str) -> bool {
+    word.chars().any(|c| c.width().unwrap_or(0) > 1)
+}
+
+/// Columnas del terminal: `COLUMNS` o, en Unix, las de la TTY de stdout.
+fn terminal_width() -> Option<usize> {
+    if let Some(columns) = std::env::var("COLUMNS")
+        .ok()
+        .and_then(|c| c.trim().parse().ok())
+        .filter(|&c: &usize| c > 0)
+    {
+        return Some(columns);
+    }
+    tty_width()
+}
+
+#[cfg(unix)]
+fn tty_width() -> Option<usize> {
+    let mut size = libc::winsize {
+        ws_row: 0,
+        ws_col: 0,
+        ws_xpixel: 0,
+        ws_ypixel: 0,
+    };
+    // SAFETY: TIOCGWINSZ solo escribe en `size`, que vive durante la llamada.
+    let ok = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } == 0;
+    (ok && size.ws_col > 0).then_some(usize::from(size.ws_col))
+}
+
+#[cfg(not(unix))]
+fn tty_width() -> Option<usize> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::MemFs;
+
+    fn at(width: usize) -> Layout {
+        Layout {
+            width,
+            ..Layout::default()
+        }
+    }
+
+    #[test]
+    fn width_counts_columns_not_bytes() {
+        assert_eq!(width("abc"), 3);
+        assert_eq!(width("—"), 1);
+        assert_eq!(width("名前"), 4);
+        assert_eq!(width("🎉"), 2);
+        assert_eq!(width("e\u{301}"), 1);
+    }
+
+    #[test]
+    fn truncation_never_splits_a_character() {
+        assert_eq!(truncate("corto", 10), "corto");
+        assert_eq!(truncate("descripción — larga", 14), "descripción —…");
+        assert_eq!(truncate("名前を入力", 6), "名前…");
+        assert_eq!(truncate("名前を入力", 5), "名前…");
+        assert_eq!(truncate("ok 🎉🎉", 5), "ok …");
+        // El acento combinado se queda o se va con su letra
+        assert_eq!(truncate("cafe\u{301} con leche", 5), "cafe\u{301}…");
+        assert_eq!(truncate("tarde\u{301}s", 5), "tard…");
+        assert_eq!(truncate("abc", 0), "");
+    }
+
+    #[test]
+    fn messages_wrap_with_a_hanging_indent_under_the_prefix() {
+        let text = "El argumento 'user' existe en código pero no está documentado — añádelo a la tabla de parámetros.";
+        assert_eq!(
+            at(40).wrap("    -> ", text),
+            "    -> El argumento 'user' existe en\n       \
+             código pero no está documentado —\n       \
+             añádelo a la tabla de parámetros.\n"
+        );
+        // Cabe entero: una sola línea
+        assert_eq!(at(200).wrap("    -> ", text), format!("    -> {}\n", text));
+    }
+
+    #[test]
+    fn cjk_runs_break_between_characters_and_paths_overflow() {
+        assert_eq!(
+            at(20).wrap("  ", "説明: 名前を入力してくださいユーザー名"),
+            "  説明:\n  名前を入力してくださ\n  いユーザー名\n"
+        );
+        // Mínimo de 20 columnas de texto aunque el terminal sea más estrecho
+        assert_eq!(
+            at(10).wrap("-> ", "ver src/very/long/path/to/the/file.ts:12 🎉 listo"),
+            "-> ver\n   src/very/long/path/to/the/file.ts:12\n   🎉 listo\n"
+        );
+    }
+
+    #[test]
+    fn hyperlinks_wrap_existing_files_only() {
+        let dir = MemFs::project();
+        let file = dir.path().join("a.ts");
+        dir.write(&file, "");
+        let location = format!("{}:3", file.display());
+        let linked = Layout {
+            width: 80,
+            hyperlinks: true,
+            color: false,
+        };
+        let rendered = linked.location(&location);
+        assert!(rendered.starts_with("\x1b]8;;file://"), "{rendered:?}");
+        assert!(rendered.ends_with(&format!("\x1b\\{}\x1b]8;;\x1b\\", location)));
+        assert_eq!(linked.location("missing.ts:3"), "missing.ts:3");
+        assert_eq!(at(80).location(&location), location);
+    }
+
+    #[test]
+    fn findings_render_pinned_at_fixed_widths() {
+        use crate::core::types::{Related, Rule, Severity, ValidationResult};
+        let result = ValidationResult {
+            severity: Severity::Warning,
+            rule: Rule::ConflictingArgDocs,
+            message: "Las secciones de 'create-user' documentan 'name' de forma distinta — revisa cuál es la buena 🎉".into(),
+            function_name: Some("createUser".into()),
+            code_location: Some("src/users.ts:3".into()),
+            doc_id: Some("create-user".into()),
+            doc_location: Some("docs/api.md:1".into()),
+            hint: Some("Unifica el tipo: 名前 (string) en la referencia y en la guía.".into()),
+            provenance: None,
+            related: vec![Related {
+                location: "docs/guide.md:7".into(),
+                message: "`name` (number), obligatorio".into(),
+            }],
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        };
+        assert_eq!(
+            result.render(&at(48), false),
+            "[!] Warning (conflicting-arg-docs) en fn createUser (src/users.ts:3)\n\
+             \x20   -> Las secciones de 'create-user' documentan\n\
+             \x20      'name' de forma distinta — revisa cuál es\n\
+             \x20      la buena 🎉\n\
+             \x20      · docs/guide.md:7: `name` (number),\n\
+             \x20        obligatorio\n\
+             \x20   -> ID vinculado: 'create-user' (docs/api.md:1)\n\
+             \x20   -> Sugerencia: Unifica el tipo: 名前\n\
+             \x20      (string) en la referencia y en la guía.\n"
+        );
+        // `Display` es el render a 100 columnas
+        assert_eq!(result.to_string(), result.render(&Layout::default(), false));
+        assert_eq!(
+            result.to_string().lines().nth(1),
+            Some("    -> Las secciones de 'create-user' documentan 'name' de forma distinta — revisa cuál es la buena")
+        );
+    }
+
+    #[test]
+    fn colors_paint_the_severity_and_the_hint_label_only() {
+        use crate::core::types::{Rule, Severity, ValidationResult};
+        let colored = Layout {
+            color: true,
+            ..at(80)
+        };
+        assert_eq!(
+            colored.paint(Style::Success, "  ✓ Listo.\n"),
+            "  \x1b[1;32m✓ Listo.\x1b[0m\n"
+        );
+        assert_eq!(colored.paint(Style::Dim, "  \n"), "  \n");
+        assert_eq!(at(80).paint(Style::Error, "[X] Error"), "[X] Error");
+
+        let result = ValidationResult {
+            severity: Severity::Error,
+            rule: Rule::TypeMismatch,
+            message: "'id' es u64 en el código y string en las docs".into(),
+            function_name: Some("delete".into()),
+            code_location: Some("src/search.rs:2".into()),
+            doc_id: None,
+            doc_location: None,
+            hint: Some("Cambia el tipo: u64".into()),
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        };
+        let rendered = result.render(&colored, false);
+        assert!(
+            rendered.starts_with("\x1b[1;31m[X] Error\x1b[0m (type-mismatch) en fn delete"),
+            "{rendered:?}"
+        );
+        assert!(
+            rendered.ends_with("    -> \x1b[36mSugerencia:\x1b[0m Cambia el tipo: u64\n"),
+            "{rendered:?}"
+        );
+        // Sin colores, el mismo texto sin una sola secuencia ANSI
+        let plain = result.render(&at(80), false);
+        assert!(!plain.contains('\x1b'), "{plain:?}");
+        assert_eq!(plain, strip(&rendered));
+    }
+
+    /// `text` sin los códigos de color.
+    fn strip(text: &str) -> String {
+        let mut out = text.to_string();
+        for code in ["\x1b[1;31m", "\x1b[36m", "\x1b[0m"] {
+            out = out.replace(code, "");
+        }
+        out
+    }
+}
@@ -3,43 +3,380 @@
 //! Elimina la deriva código-doc mediante validación heurística,
 //! soporte multiformato y corrección interactiva.
 
+// Sin `interactive` quedan sin uso partes de scaffold/triage (heurística,
+// fixes, configuración de IDs); el binario completo sigue sin excepciones.
+#![cfg_attr(not(all(feature = "interactive", feature = "watch")), allow(dead_code))]
+
+mod assert_links;
+mod attestation;
 mod baseline;
+mod build_info;
+mod changed_only;
+mod check_all;
+mod ci;
+mod config;
+mod conformance;
 mod core;
 mod coverage;
+mod dates;
+mod demo;
+mod diff_entities;
+mod exit;
+mod fix;
+mod git;
+mod grace;
+mod hooks;
+mod inspect;
+#[cfg(feature = "interactive")]
 mod interactive;
+mod last_run;
+mod layout;
+mod mapping;
+mod messages;
+mod new_functions;
+mod pairing;
 mod parser;
+mod paths;
+mod report;
+mod schema;
+mod self_check;
+#[cfg(feature = "verify-site")]
+mod site;
+mod transaction;
+#[cfg(feature = "interactive")]
+mod triage;
+mod vfs;
 mod watch;
+mod yaml;
 
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
 
-use crate::core::types::Severity;
-use crate::core::validator;
+use crate::config::{Config, InfoAggregation, Preset};
+use crate::core::diagnostics::{ParseDiagnostics, Strictness};
+use crate::core::types::{Severity, ValidationResult};
+use crate::core::{
+    conflicts, heuristic, pipeline, suppression, symbols, validator, version_source,
+};
+use crate::exit::{FailOn, Failure, Outcome};
+use crate::layout::Layout;
+use crate::messages::{eprintln_tr, print_tr, println_tr};
 use crate::parser::code_parser::{self, safe_display};
 use crate::parser::doc_parser;
+use crate::report::timing::Timings;
+use crate::report::Verbosity;
 
 #[derive(Parser)]
 #[command(
     name = "docsguard",
     version,
-    about = "Motor de Integridad de Documentación — elimina la deriva código-doc"
+    about = "Motor de Integridad de Documentación — elimina la deriva código-doc",
+    after_long_help = exit::EXIT_CODES_HELP
 )]
 struct Cli {
+    /// Escribe los errores de ejecución como JSON en stdout (implícito en `assert --json`, `check --format json|sarif`, `config diff --json` y `suppressions list --format json`).
+    #[arg(long, global = true, default_value_t = false)]
+    json_errors: bool,
+    /// No envuelve las ubicaciones `archivo:línea` en hipervínculos del terminal (OSC 8).
+    #[arg(long, global = true, default_value_t = false)]
+    no_hyperlinks: bool,
+    /// Salida sin colores (también con la variable `NO_COLOR` o si stdout no es un terminal).
+    #[arg(long, global = true, default_value_t = false)]
+    no_color: bool,
+    /// Configuración que se usa en lugar de `.docsguard/config.yaml` (también la variable `DOCSGUARD_CONFIG`).
+    #[arg(long, global = true, value_name = "FILE")]
+    config: Option<PathBuf>,
+    /// Fija una opción escalar encima de la configuración (`--set rules.type-mismatch=off`); repetible.
+    #[arg(long = "set", global = true, value_name = "KEY=VALUE")]
+    settings: Vec<config::overrides::Setting>,
+    /// No valida ni propone las funciones cuyo nombre encaja con el glob (`--ignore-fn "test_*"`); se suma a `ignore_functions` y es repetible.
+    #[arg(long = "ignore-fn", global = true, value_name = "GLOB")]
+    ignore_fn: Vec<String>,
+    /// Idioma de la salida (también la variable `DOCSGUARD_LANG`); los archivos que escribe DocsGuard no cambian.
+    #[arg(long, global = true, value_name = "LANG")]
+    lang: Option<messages::Locale>,
+    /// Lenguaje del código leído de stdin cuando el archivo de código es `-`.
+    #[arg(long, global = true, value_name = "LANG")]
+    code_language: Option<code_parser::Language>,
     #[command(subcommand)]
     command: Commands,
 }
 
+// Se construye una vez por ejecución: el tamaño de `Check` no importa
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 enum Commands {
     /// Verifica que los enlaces entre código y documentación sean válidos.
     Check {
+        /// Archivo de documentación (Markdown), directorio o glob (`"docs/**/*.md"`); en estos se omiten los archivos que no son Markdown.
+        #[arg(required_unless_present_any = ["combo", "doc"])]
+        doc_file: Option<PathBuf>,
+        /// Archivos de código fuente, directorios o globs (`"src/**/*.ts"`); en estos se omiten los archivos sin lenguaje soportado.
+        #[arg(required_unless_present_any = ["combo", "doc"])]
+        code_files: Vec<PathBuf>,
+        /// Archivos, directorios o globs de documentación (repetible o separados por comas); con --doc, todos los posicionales son código.
+        #[arg(long, value_name = "FILE", value_delimiter = ',')]
+        doc: Vec<PathBuf>,
+        /// Directorio raíz del proyecto (para buscar baseline).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+        /// Aplica las correcciones automáticas disponibles antes de reportar.
+        #[arg(long, default_value_t = false)]
+        fix: bool,
+        /// Reporta solo hallazgos en archivos cambiados desde esta referencia git.
+        #[arg(long, value_name = "REF")]
+        changed_since: Option<String>,
+        /// Valida solo las funciones y secciones que toca `git diff` desde BASE (por defecto HEAD); sin `orphan-section`.
+        #[arg(
+            long,
+            value_name = "BASE",
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "HEAD",
+            conflicts_with_all = ["fast", "code_ref", "docs_ref", "report_unused_suppressions", "deny_unused_suppressions"]
+        )]
+        changed_only: Option<String>,
+        /// Compara los resultados esperados de los ejemplos con el tipo de retorno (heurístico).
+        #[arg(long, default_value_t = false)]
+        check_examples: bool,
+        /// Comprueba que los ejemplos solo importen y llamen a símbolos exportados del proyecto.
+        #[arg(long, default_value_t = false)]
+        check_symbols: bool,
+        /// Compara las versiones "desde" de las secciones con la del proyecto (`versions:` en config).
+        #[arg(long, default_value_t = false)]
+        check_versions: bool,
+        /// Reporta como posible errata un argumento fantasma casi igual a uno sin documentar.
+        #[arg(long, default_value_t = false)]
+        check_arg_typos: bool,
+        /// Muestra también los enlaces verificados y los detalles de cada hallazgo (p. ej. la estrategia que extrajo el argumento).
+        #[arg(short, long, default_value_t = false)]
+        verbose: bool,
+        /// Imprime solo los errores y el resumen, que sigue contando todos los hallazgos.
+        #[arg(short, long, default_value_t = false, conflicts_with = "verbose")]
+        quiet: bool,
+        /// Reporta como Info (reglas DGxxx) las ambigüedades que el parser resuelve en silencio.
+        #[arg(long, default_value_t = false)]
+        strict: bool,
+        /// Con --strict, reporta las ambigüedades como Warning.
+        #[arg(long, default_value_t = false, requires = "strict")]
+        pedantic: bool,
+        /// Reporta (Info) las supresiones en línea y entradas del baseline que no silenciaron nada.
+        #[arg(long, default_value_t = false)]
+        report_unused_suppressions: bool,
+        /// Como --report-unused-suppressions, pero como Error.
+        #[arg(long, default_value_t = false)]
+        deny_unused_suppressions: bool,
+        /// Preset de niveles por regla; los `rules:` de config.yaml ganan sobre él.
+        #[arg(long, value_enum)]
+        preset: Option<Preset>,
+        /// Muestra rutas absolutas en lugar de relativas a --project-root.
+        #[arg(long, default_value_t = false)]
+        absolute_paths: bool,
+        /// Advierte (regla slow-run) si la ejecución tarda más de estos segundos.
+        #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+        time_budget: Option<Duration>,
+        /// Con --time-budget, sale con código 1 si se supera el presupuesto.
+        #[arg(long, default_value_t = false, requires = "time_budget")]
+        fail_on_slow: bool,
+        /// Severidad a partir de la cual sale con código 1 (`never`: solo informa).
+        #[arg(long, value_enum, default_value_t = FailOn::Error)]
+        fail_on: FailOn,
+        /// Re-parsea solo los archivos de código cambiados desde la última ejecución con --fast.
+        #[arg(long, default_value_t = false, overrides_with = "no_fast")]
+        fast: bool,
+        /// Ejecución completa aunque se indique --fast (gana la última de las dos).
+        #[arg(long, default_value_t = false, overrides_with = "fast")]
+        no_fast: bool,
+        /// Explica por qué el baseline no filtró cada hallazgo: entrada más parecida y campos distintos (en `baseline_debug` con --format json).
+        #[arg(long, visible_alias = "explain-fingerprint", default_value_t = false)]
+        explain_baseline: bool,
+        /// Comprueba con peticiones HEAD que las secciones existen en el sitio publicado (`site:` en config; feature verify-site).
+        #[arg(long, default_value_t = false)]
+        verify_site: bool,
+        /// Con --verify-site, comprueba solo N secciones repartidas por el archivo.
+        #[arg(long, value_name = "N", requires = "verify_site")]
+        site_sample: Option<usize>,
+        /// Error por cada función pública añadida desde REF (por defecto, la de --changed-since) sin enlace a docs.
+        #[arg(long, value_name = "REF", num_args = 0..=1)]
+        require_docs_for_new: Option<Option<String>>,
+        /// Compara las secciones con sus atestaciones de `.docsguard/attestations.yaml` (`attestation_required` en config).
+        #[arg(long, default_value_t = false)]
+        check_attestations: bool,
+        /// Tras el resumen, muestra la antigüedad del baseline y las entradas que caducan en DAYS días (30 por defecto; en `debt_age` con --format json).
+        #[arg(long, value_name = "DAYS", num_args = 0..=1, require_equals = true, default_missing_value = "30")]
+        debt_age: Option<u64>,
+        /// `sarif`: log SARIF 2.1.0 (code scanning); `json`: hallazgos y cobertura por argumento de cada enlace; `markdown`: documento con una tabla por severidad. Van a stdout y el resto de la salida, a stderr.
+        #[arg(long, value_enum, default_value_t = report::CheckFormat::Text)]
+        format: report::CheckFormat,
+        /// Escribe el documento Markdown de `--format markdown` en FILE; sin -v, no imprime nada más.
+        #[arg(long, value_name = "FILE")]
+        report: Option<PathBuf>,
+        /// Lee el código de esta revisión git en lugar del árbol de trabajo (las docs, del árbol).
+        #[arg(long, value_name = "REF", conflicts_with_all = ["fix", "fast", "changed_since"])]
+        code_ref: Option<String>,
+        /// Lee las docs de esta revisión git en lugar del árbol de trabajo.
+        #[arg(long, value_name = "REF", conflicts_with_all = ["fix", "fast", "changed_since"])]
+        docs_ref: Option<String>,
+        /// Usa `.docsguard/baseline-NAME.yaml` en lugar de `baseline.yaml`.
+        #[arg(long, value_name = "NAME", value_parser = parse_baseline_name)]
+        baseline_name: Option<String>,
+        /// Docs, código, revisiones y baseline de la combinación NAME de `combos:` en config.
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["fix", "fast", "changed_since"])]
+        combo: Option<String>,
+        /// No consulta git para indicar la probable causa de los errores nuevos.
+        #[arg(long, default_value_t = false)]
+        no_git: bool,
+        /// Incluye la probable causa (commit, autor) también en SARIF.
+        #[arg(long, default_value_t = false, conflicts_with = "no_git")]
+        with_blame: bool,
+        /// Muestra cada hallazgo Info por separado aunque config.yaml tenga `info_aggregation: summarize`.
+        #[arg(long, default_value_t = false)]
+        no_aggregate: bool,
+        /// Omite de los informes de `--format` y `--report` las descripciones documentadas de los argumentos (se conservan nombres y tipos).
+        #[arg(long, default_value_t = false)]
+        redact_descriptions: bool,
+    },
+
+    /// Verifica todo el repositorio: cada archivo de código soportado y cada `.md` bajo la raíz.
+    CheckAll {
+        /// Raíz del repositorio: se recorre entera y da la configuración y el baseline.
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+        /// Muestra también los enlaces verificados y los detalles de cada hallazgo.
+        #[arg(short, long, default_value_t = false)]
+        verbose: bool,
+        /// Imprime solo los errores y el resumen.
+        #[arg(short, long, default_value_t = false, conflicts_with = "verbose")]
+        quiet: bool,
+        /// Severidad a partir de la cual sale con código 1 (`never`: solo informa).
+        #[arg(long, value_enum, default_value_t = FailOn::Error)]
+        fail_on: FailOn,
+        /// Valida solo las funciones y secciones que toca `git diff` desde BASE (por defecto HEAD); sin `orphan-section`.
+        #[arg(
+            long,
+            value_name = "BASE",
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "HEAD"
+        )]
+        changed_only: Option<String>,
+    },
+
+    /// Instala el hook pre-commit de git que ejecuta `docsguard check --changed-only`.
+    InstallHooks {
+        /// Añade el bloque de DocsGuard al final de un hook pre-commit que no creó DocsGuard.
+        #[arg(long, default_value_t = false)]
+        force: bool,
+        /// Quita solo el bloque de DocsGuard del hook pre-commit.
+        #[arg(long, default_value_t = false, conflicts_with = "force")]
+        uninstall: bool,
+        /// Directorio raíz del proyecto (configuración y combinaciones).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+    },
+
+    /// Muestra lo que DocsGuard extrae de un archivo de docs o de código.
+    Parse {
+        /// Archivo de documentación (Markdown) o de código.
+        file: PathBuf,
+        /// Directorio raíz del proyecto (configuración).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+    },
+
+    /// Lista las funciones y secciones extraídas y qué pares resolvieron entre sí.
+    List {
         /// Archivo de documentación (Markdown).
         doc_file: PathBuf,
-        /// Archivos de código fuente (TypeScript, Rust).
+        /// Archivos de código fuente.
         #[arg(required = true)]
         code_files: Vec<PathBuf>,
-        /// Directorio raíz del proyecto (para buscar baseline).
+        /// Muestra solo las funciones y secciones que no resolvieron.
+        #[arg(long, default_value_t = false)]
+        unlinked_only: bool,
+        /// Directorio raíz del proyecto (configuración).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+    },
+
+    /// Falla si alguno de los IDs indicados no tiene sección o enlace (checklists de release).
+    Assert {
+        /// Archivo de documentación (Markdown).
+        doc_file: PathBuf,
+        /// Archivos de código fuente.
+        #[arg(required = true)]
+        code_files: Vec<PathBuf>,
+        /// ID de documentación que debe existir y estar enlazado (repetible).
+        #[arg(long = "id", value_name = "ID", required_unless_present = "ids_from")]
+        ids: Vec<String>,
+        /// Archivo con un ID por línea (`#` para comentarios).
+        #[arg(long, value_name = "FILE")]
+        ids_from: Option<PathBuf>,
+        /// Falla también si algún ID tiene hallazgos de severidad Error.
+        #[arg(long, default_value_t = false)]
+        require_clean: bool,
+        /// Emite el resultado como JSON.
+        #[arg(long, default_value_t = false)]
+        json: bool,
+        /// Directorio raíz del proyecto (baseline y configuración).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+    },
+
+    /// Escribe un informe HTML autocontenido de los hallazgos (escala a decenas de miles).
+    Report {
+        /// Archivo de documentación (Markdown).
+        doc_file: PathBuf,
+        /// Archivos de código fuente.
+        #[arg(required = true)]
+        code_files: Vec<PathBuf>,
+        /// Archivo HTML de salida.
+        #[arg(long, value_name = "FILE")]
+        html: PathBuf,
+        /// Directorio raíz del proyecto (baseline y configuración).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+        /// Omite las descripciones documentadas de los argumentos (se conservan nombres y tipos).
+        #[arg(long, default_value_t = false)]
+        redact_descriptions: bool,
+        /// Muestra cada hallazgo Info por separado aunque config.yaml tenga `info_aggregation: summarize`.
+        #[arg(long, default_value_t = false)]
+        no_aggregate: bool,
+    },
+
+    /// Exporta el modelo enlazado (secciones, funciones, estado de cada argumento) para generadores de docs.
+    ExportModel {
+        /// Archivo de documentación (Markdown).
+        doc_file: PathBuf,
+        /// Archivos de código fuente.
+        #[arg(required = true)]
+        code_files: Vec<PathBuf>,
+        /// Formato de salida.
+        #[arg(long, value_enum, default_value_t = report::model::ModelFormat::Json)]
+        format: report::model::ModelFormat,
+        /// Directorio raíz del proyecto (configuración).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+    },
+
+    /// Integraciones de CI listas para usar.
+    Ci {
+        #[command(subcommand)]
+        provider: CiProvider,
+    },
+
+    /// Recorre los hallazgos uno a uno: editor, corrección, baseline o supresión.
+    Triage {
+        /// Archivo de documentación (Markdown).
+        doc_file: PathBuf,
+        /// Archivos de código fuente.
+        #[arg(required = true)]
+        code_files: Vec<PathBuf>,
+        /// Directorio raíz del proyecto (baseline).
         #[arg(long, default_value = ".")]
         project_root: PathBuf,
     },
@@ -56,6 +393,65 @@ enum Commands {
         /// Aceptar todas las sugerencias sin preguntar.
         #[arg(long, default_value_t = false)]
         force: bool,
+        /// Registra los enlaces en .docsguard/links.yaml sin modificar el código.
+        #[arg(long, default_value_t = false)]
+        write_to_mapping: bool,
+        /// Directorio raíz del proyecto (configuración y links.yaml).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+        /// Recorrido de la heurística: `indexed` (por defecto) o `exhaustive` (todos los pares, para depurar).
+        #[arg(long, value_enum, default_value_t = heuristic::Strategy::Indexed)]
+        heuristic: heuristic::Strategy,
+        /// Ofrece una sección esqueleto con un ID nuevo (`id_style`) para cada función que quede sin enlace.
+        #[arg(long, default_value_t = false)]
+        stubs: bool,
+        /// Informe de la sesión (`.md` o `.json`); por defecto `.docsguard/scaffold-report-<timestamp>.md`.
+        #[arg(long, value_name = "PATH")]
+        report: Option<PathBuf>,
+        /// Sin preguntar: escribe en DIR un parche para `git apply` por enlace propuesto.
+        #[arg(
+            long,
+            value_name = "DIR",
+            conflicts_with_all = ["dry_run", "force", "write_to_mapping", "report"]
+        )]
+        emit_patch: Option<PathBuf>,
+        /// Con --emit-patch, un solo parche (`scaffold.patch`) con todos los enlaces.
+        #[arg(long, default_value_t = false, requires = "emit_patch")]
+        single_patch: bool,
+        /// Con --emit-patch, confianza mínima (0 a 1) de los enlaces que se emiten.
+        #[arg(
+            long,
+            value_name = "F",
+            requires = "emit_patch",
+            default_value_t = heuristic::MIN_CONFIDENCE,
+            value_parser = parse_confidence
+        )]
+        min_confidence: f64,
+    },
+
+    /// Aplica sin preguntar los enlaces heurísticos de alta confianza (para CI).
+    Fix {
+        /// Archivo de código fuente.
+        code_file: PathBuf,
+        /// Archivo de documentación.
+        doc_file: PathBuf,
+        /// Confianza mínima (0 a 1) de los enlaces que se aplican.
+        #[arg(
+            long,
+            value_name = "F",
+            default_value_t = heuristic::AUTO_APPLY_CONFIDENCE,
+            value_parser = parse_confidence
+        )]
+        min_confidence: f64,
+        /// Muestra los cambios como diff unificado sin escribirlos.
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+        /// Directorio raíz del proyecto (configuración).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+        /// Recorrido de la heurística: `indexed` (por defecto) o `exhaustive` (todos los pares, para depurar).
+        #[arg(long, value_enum, default_value_t = heuristic::Strategy::Indexed)]
+        heuristic: heuristic::Strategy,
     },
 
     /// Observa cambios en archivos y re-valida automáticamente.
@@ -64,6 +460,72 @@ enum Commands {
         code_file: PathBuf,
         /// Archivo de documentación.
         doc_file: PathBuf,
+
+        /// Silencio (ms) que cierra una ráfaga de cambios antes de validar.
+        #[arg(long, default_value_t = watch::DEFAULT_QUIET_MS)]
+        quiet_ms: u64,
+
+        /// Espera máxima (ms) de una ráfaga antes de validar igualmente.
+        #[arg(long, default_value_t = watch::DEFAULT_MAX_WAIT_MS)]
+        max_wait_ms: u64,
+
+        /// Muestra también los hallazgos Info y desglosa el tiempo de cada validación (parseo de código y docs, validación, render).
+        #[arg(short, long, default_value_t = false)]
+        verbose: bool,
+
+        /// Muestra solo los errores; el resumen sigue contando las advertencias.
+        #[arg(short, long, default_value_t = false, conflicts_with = "verbose")]
+        quiet: bool,
+
+        /// Ofrece corregir desde el teclado los hallazgos nuevos con corrección sugerida (`f` aplica, `n`/`p` cambian de hallazgo).
+        #[arg(long, default_value_t = false)]
+        interactive_fixes: bool,
+    },
+
+    /// Registra que alguien revisó una sección: guarda el hash de su contenido en `.docsguard/attestations.yaml`.
+    Attest {
+        /// ID de la sección atestada.
+        doc_id: String,
+        /// Archivo de documentación (Markdown) con la sección.
+        #[arg(long, value_name = "DOC_FILE")]
+        docs: PathBuf,
+        /// Quién atesta (`@maria`).
+        #[arg(long, value_name = "WHO")]
+        by: String,
+        /// Directorio raíz del proyecto.
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+    },
+
+    /// Valida las anotaciones del propio DocsGuard (`src/**/*.rs`) contra `docs/architecture.md`.
+    SelfCheck {
+        /// Raíz del repositorio de DocsGuard.
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+    },
+
+    /// Comprueba los parsers de cada lenguaje con el corpus de anotaciones embebido en el binario.
+    Conformance,
+
+    /// Funciones y secciones añadidas, eliminadas o cambiadas entre dos revisiones git.
+    DiffEntities {
+        /// Archivo de documentación (Markdown).
+        doc_file: PathBuf,
+        /// Archivos de código fuente.
+        #[arg(required = true)]
+        code_files: Vec<PathBuf>,
+        /// Revisión de partida.
+        #[arg(long, value_name = "REF")]
+        from: String,
+        /// Revisión final (por defecto, el árbol de trabajo).
+        #[arg(long, value_name = "REF")]
+        to: Option<String>,
+        /// Formato de salida.
+        #[arg(long, value_enum, default_value_t)]
+        format: diff_entities::DiffFormat,
+        /// Directorio raíz del proyecto (repositorio git y configuración).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
     },
 
     /// Vuelca los errores actuales al baseline para "Green Build Day 1".
@@ -75,6 +537,65 @@ enum Commands {
         /// Directorio raíz del proyecto.
         #[arg(long, default_value = ".")]
         project_root: PathBuf,
+        /// No añade hallazgos: quita las entradas de estos archivos que ya no
+        /// filtran nada.
+        #[arg(long, default_value_t = false)]
+        prune: bool,
+        /// Con --prune, actualiza el archivo de las entradas cuya función se
+        /// movió a otro.
+        #[arg(long, default_value_t = false, requires = "prune")]
+        rewrite_moves: bool,
+        /// Escribe `.docsguard/baseline-NAME.yaml` en lugar de `baseline.yaml`.
+        #[arg(long, value_name = "NAME", value_parser = parse_baseline_name)]
+        baseline_name: Option<String>,
+        /// Lee el código de esta revisión git en lugar del árbol de trabajo.
+        #[arg(long, value_name = "REF")]
+        code_ref: Option<String>,
+        /// Lee las docs de esta revisión git en lugar del árbol de trabajo.
+        #[arg(long, value_name = "REF")]
+        docs_ref: Option<String>,
+    },
+
+    /// Lista todo lo que se está silenciando: supresiones, baseline, excepciones y marcadores.
+    Suppressions {
+        #[command(subcommand)]
+        action: SuppressionsAction,
+    },
+
+    /// Genera `.docsguard/config.yaml` con los niveles de un preset expandidos.
+    Init {
+        /// Preset que se escribe regla a regla.
+        #[arg(long, value_enum, default_value_t = Preset::Standard)]
+        preset: Preset,
+        /// Directorio raíz del proyecto.
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+        /// Sobrescribe una configuración existente.
+        #[arg(long, default_value_t = false)]
+        force: bool,
+    },
+
+    /// Escribe un proyecto de ejemplo, lo verifica con `check` y comenta cada hallazgo.
+    Demo {
+        /// Directorio del ejemplo (por defecto, uno nuevo en el temporal del sistema).
+        #[arg(long, value_name = "PATH")]
+        dir: Option<PathBuf>,
+    },
+
+    /// Muestra el nivel efectivo de cada regla y de dónde sale.
+    Explain {
+        /// Preset que se aplicaría con `check --preset`.
+        #[arg(long, value_enum)]
+        preset: Option<Preset>,
+        /// Directorio raíz del proyecto (configuración).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+    },
+
+    /// Operaciones sobre `.docsguard/config.yaml`.
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
     },
 
     /// Muestra el porcentaje de funciones públicas con anotación @docs.
@@ -85,97 +606,1784 @@ enum Commands {
         /// Cobertura mínima requerida (0-100). Sale con código 1 si no se alcanza.
         #[arg(long, default_value_t = 80)]
         min_coverage: u8,
+        /// Archivo de docs: cuenta los enlaces con secciones prose-only (`@docs-skip: args`).
+        #[arg(long, value_name = "DOC_FILE")]
+        docs: Option<PathBuf>,
+        /// Compara con una cobertura guardada con --json (p. ej. la de la rama principal).
+        #[arg(long, value_name = "FILE")]
+        compare_to: Option<PathBuf>,
+        /// Emite la cobertura (o, con --compare-to, la diferencia) como JSON.
+        #[arg(long, default_value_t = false)]
+        json: bool,
+        /// Con --compare-to, emite la diferencia como fragmento Markdown para la PR.
+        #[arg(
+            long,
+            default_value_t = false,
+            requires = "compare_to",
+            conflicts_with = "json"
+        )]
+        markdown: bool,
+    },
+
+    /// Imprime el esquema JSON de un archivo de `.docsguard/`.
+    Schema {
+        /// Archivo cuyo esquema se imprime.
+        #[arg(value_enum)]
+        artifact: schema::Artifact,
+    },
+
+    /// Muestra la versión; con `--verbose`, commit, target, features y gramáticas.
+    Version {
+        /// Datos de compilación (también `docsguard --version --verbose`).
+        #[arg(long, default_value_t = false)]
+        verbose: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Muestra la configuración en uso; con `--resolved`, la efectiva con los valores por defecto.
+    Show {
+        /// Incluye los `--set`, el preset y los valores por defecto de cada opción.
+        #[arg(long, default_value_t = false)]
+        resolved: bool,
+        /// Lista el archivo de docs con el que `check-all` valida cada archivo de código (`pairing`).
+        #[arg(long, default_value_t = false, conflicts_with_all = ["resolved", "preset"])]
+        resolved_pairs: bool,
+        /// Preset que se aplicaría con `check --preset`.
+        #[arg(long, value_enum)]
+        preset: Option<Preset>,
+        /// Directorio raíz del proyecto (configuración).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+    },
+    /// Muestra qué hallazgos cambiarían (y el código de salida) con otra configuración.
+    Diff {
+        /// Archivo de documentación (Markdown).
+        doc_file: PathBuf,
+        /// Archivos de código fuente.
+        #[arg(required = true)]
+        code_files: Vec<PathBuf>,
+        /// Configuración con la que comparar la actual.
+        #[arg(
+            long,
+            value_name = "FILE",
+            required_unless_present = "against_git",
+            conflicts_with = "against_git"
+        )]
+        against: Option<PathBuf>,
+        /// Compara con `.docsguard/config.yaml` en esta referencia git.
+        #[arg(long, value_name = "REF")]
+        against_git: Option<String>,
+        /// Directorio raíz del proyecto (configuración y baseline).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+        /// Preset que se aplica debajo de las dos configuraciones.
+        #[arg(long, value_enum)]
+        preset: Option<Preset>,
+        /// Emite el resultado como JSON.
+        #[arg(long, default_value_t = false)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum SuppressionsAction {
+    /// Cada supresión activa con su ubicación, motivo, antigüedad y si silenció algo.
+    List {
+        /// Archivo de documentación (Markdown).
+        doc_file: PathBuf,
+        /// Archivos de código fuente.
+        #[arg(required = true)]
+        code_files: Vec<PathBuf>,
+        /// Formato de la salida.
+        #[arg(long, value_enum, default_value_t = report::suppressions::ListFormat::Text)]
+        format: report::suppressions::ListFormat,
+        /// Solo las de este mecanismo (repetible).
+        #[arg(long, value_enum)]
+        mechanism: Vec<report::suppressions::Mechanism>,
+        /// Orden de la lista.
+        #[arg(long, value_enum, default_value_t = report::suppressions::SuppressionSort::Mechanism)]
+        sort: report::suppressions::SuppressionSort,
+        /// No consulta `git blame` para fechar directivas y marcadores.
+        #[arg(long, default_value_t = false)]
+        no_git: bool,
+        /// Directorio raíz del proyecto (baseline, configuración y repositorio git).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum CiProvider {
+    /// GitHub Actions: anotaciones, resumen del paso y comentario fijo en la PR.
+    Github {
+        /// Archivo de documentación (Markdown).
+        doc_file: PathBuf,
+        /// Archivos de código fuente.
+        #[arg(required = true)]
+        code_files: Vec<PathBuf>,
+        /// Directorio raíz del proyecto (baseline y repositorio git).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+        /// Referencia base del diff (por defecto `origin/$GITHUB_BASE_REF`).
+        #[arg(long, value_name = "REF")]
+        base_ref: Option<String>,
+        /// Crea o actualiza un comentario fijo con el resumen en la PR.
+        #[arg(long, default_value_t = false)]
+        pr_comment: bool,
+        /// Omite las descripciones documentadas de los argumentos (se conservan nombres y tipos).
+        #[arg(long, default_value_t = false)]
+        redact_descriptions: bool,
+        /// Muestra cada hallazgo Info por separado aunque config.yaml tenga `info_aggregation: summarize`.
+        #[arg(long, default_value_t = false)]
+        no_aggregate: bool,
     },
+    /// Predice el resultado de un perfil de `profiles:` sin tocar los artefactos reales.
+    Simulate {
+        /// Perfil de `profiles:` en config.yaml.
+        #[arg(long, value_name = "NOMBRE")]
+        profile: String,
+        /// Directorio raíz del proyecto (configuración, baseline y repositorio git).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+        /// Omite las descripciones documentadas de los argumentos (se conservan nombres y tipos).
+        #[arg(long, default_value_t = false)]
+        redact_descriptions: bool,
+    },
+    /// Ejecuta un perfil de `profiles:` como lo haría el job de CI.
+    Run {
+        /// Perfil de `profiles:` en config.yaml.
+        #[arg(long, value_name = "NOMBRE")]
+        profile: String,
+        /// Directorio raíz del proyecto (configuración, baseline y repositorio git).
+        #[arg(long, default_value = ".")]
+        project_root: PathBuf,
+        /// Omite las descripciones documentadas de los argumentos (se conservan nombres y tipos).
+        #[arg(long, default_value_t = false)]
+        redact_descriptions: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    // clap atiende `--version` antes de ver `--verbose`: se reconoce el par a mano
+    let args: Vec<_> = std::env::args_os().skip(1).collect();
+    if args.len() == 2
+        && args.iter().any(|a| a == "--version" || a == "-V")
+        && args.iter().any(|a| a == "--verbose")
+    {
+        print_version(true);
+        return ExitCode::SUCCESS;
+    }
+
+    match messages::Locale::requested(&args) {
+        Ok(locale) => messages::install(locale.unwrap_or(messages::Locale::CANONICAL)),
+        Err(value) => {
+            let error = anyhow::Error::new(
+                Failure::usage(format!(
+                    "Idioma no válido en {}: '{}'",
+                    messages::LANG_ENV,
+                    value
+                ))
+                .with_hint("Los idiomas disponibles son `es` y `en`."),
+            );
+            if args.iter().any(|a| a == "--json-errors") {
+                print_json_error(&exit::JsonError::from_error(&error));
+            } else {
+                eprintln_tr!("Error: {:?}", error);
+            }
+            return ExitCode::from(exit::USAGE);
+        }
+    }
+
+    let cli = match parse_cli() {
+        Ok(cli) => cli,
+        // `--help` y `--version` también llegan como error de clap
+        Err(e) if e.use_stderr() && std::env::args_os().any(|a| a == "--json-errors") => {
+            let rendered = e.render().to_string();
+            let message = rendered.lines().next().unwrap_or_default();
+            print_json_error(&exit::JsonError::usage(
+                message.trim_start_matches("error: ").to_string(),
+            ));
+            return ExitCode::from(exit::USAGE);
+        }
+        Err(e) => e.exit(),
+    };
+    let json_errors = cli.json_errors
+        || matches!(
+            cli.command,
+            Commands::Assert { json: true, .. }
+                | Commands::Check {
+                    format: report::CheckFormat::Json | report::CheckFormat::Sarif,
+                    ..
+                }
+                | Commands::Config {
+                    action: ConfigAction::Diff { json: true, .. }
+                }
+                | Commands::Suppressions {
+                    action: SuppressionsAction::List {
+                        format: report::suppressions::ListFormat::Json,
+                        ..
+                    }
+                }
+        );
+
+    config::overrides::install(config::overrides::ConfigOverrides {
+        ignore_functions: cli.ignore_fn,
+        ..config::overrides::ConfigOverrides::new(
+            cli.config,
+            std::env::var_os(config::overrides::CONFIG_ENV).map(PathBuf::from),
+            cli.settings,
+        )
+    });
+
+    if let Some(language) = cli.code_language {
+        code_parser::set_stdin_language(language);
+    }
+
+    let layout = Layout::detect(cli.no_hyperlinks, cli.no_color);
+    // Los prompts de dialoguer con la misma decisión
+    #[cfg(feature = "interactive")]
+    dialoguer::console::set_colors_enabled(layout.color);
+    let fs = vfs::StdinFs::new(std::sync::Arc::new(vfs::OsFs));
+    match vfs::scoped(fs, || {
+        single_stdin_input(&args)?;
+        run(cli.command, layout)
+    }) {
+        Ok(outcome) => ExitCode::from(outcome.code()),
+        Err(e) => {
+            if json_errors {
+                print_json_error(&exit::JsonError::from_error(&e));
+            } else {
+                eprintln_tr!("Error: {:?}", e);
+            }
+            ExitCode::from(exit::exit_code_for(&e))
+        }
+    }
+}
+
+/// Rechaza dos entradas `-`: stdin solo se lee una vez.
+fn single_stdin_input(args: &[std::ffi::OsString]) -> Result<()> {
+    if args.iter().filter(|a| *a == vfs::STDIN).count() > 1 {
+        anyhow::bail!(
+            Failure::usage("Solo una entrada puede leerse de stdin (`-`).")
+                .with_hint("Pasa el código o la documentación por stdin y el otro como archivo.")
+        );
+    }
+    Ok(())
 }
 
-fn main() -> Result<()> {
-    let cli = Cli::parse();
+/// `Cli::try_parse` con la ayuda en el idioma de la salida.
+fn parse_cli() -> Result<Cli, clap::Error> {
+    let mut command = Cli::command();
+    if messages::Locale::output() != messages::Locale::CANONICAL {
+        command = localized_command(command);
+    }
+    let mut matches = command.try_get_matches_from_mut(std::env::args_os())?;
+    Cli::from_arg_matches_mut(&mut matches).map_err(|e| e.format(&mut command))
+}
+
+/// Textos de ayuda de `command` y sus subcomandos traducidos con `tr`.
+fn localized_command(command: clap::Command) -> clap::Command {
+    let tr = |text: &clap::builder::StyledStr| messages::tr(&text.to_string()).into_owned();
+    let mut command = command
+        .mut_args(|mut arg| {
+            if let Some(help) = arg.get_help().map(tr) {
+                arg = arg.help(help);
+            }
+            if let Some(help) = arg.get_long_help().map(tr) {
+                arg = arg.long_help(help);
+            }
+            arg
+        })
+        .mut_subcommands(localized_command);
+    if let Some(about) = command.get_about().map(tr) {
+        command = command.about(about);
+    }
+    if let Some(about) = command.get_long_about().map(tr) {
+        command = command.long_about(about);
+    }
+    if let Some(help) = command.get_after_long_help().map(tr) {
+        command = command.after_long_help(help);
+    }
+    if let Some(help) = command.get_after_help().map(tr) {
+        command = command.after_help(help);
+    }
+    command
+}
+
+/// Escribe el error de `--json-errors` en stdout.
+fn print_json_error(error: &exit::JsonError) {
+    match serde_json::to_string(error) {
+        Ok(json) => println!("{}", json),
+        Err(e) => eprintln_tr!("Error: {}", e),
+    }
+}
 
-    match cli.command {
+/// Despacha el subcomando. Los que no tienen umbral terminan en `Outcome::Clean`.
+/// `layout` maqueta los hallazgos que se muestran en el terminal.
+fn run(command: Commands, layout: Layout) -> Result<Outcome> {
+    match command {
         Commands::Check {
             code_files,
             doc_file,
+            doc,
             project_root,
-        } => run_check(&code_files, &doc_file, &project_root),
+            fix,
+            changed_since,
+            changed_only,
+            check_examples,
+            check_symbols,
+            check_versions,
+            check_arg_typos,
+            verbose,
+            quiet,
+            strict,
+            pedantic,
+            report_unused_suppressions,
+            deny_unused_suppressions,
+            preset,
+            absolute_paths,
+            time_budget,
+            fail_on_slow,
+            fail_on,
+            fast,
+            no_fast,
+            explain_baseline,
+            verify_site,
+            site_sample,
+            require_docs_for_new,
+            check_attestations,
+            debt_age,
+            format,
+            report,
+            code_ref,
+            docs_ref,
+            baseline_name,
+            combo,
+            no_git,
+            with_blame,
+            no_aggregate,
+            redact_descriptions,
+        } => {
+            // Con --doc, el primer posicional también es código
+            let (doc_files, code_files) = if doc.is_empty() {
+                (doc_file.into_iter().collect(), code_files)
+            } else {
+                (doc, doc_file.into_iter().chain(code_files).collect())
+            };
+            let mut target = CheckTarget {
+                doc_files,
+                code_files,
+                code_ref,
+                docs_ref,
+                baseline_name,
+            };
+            if let Some(name) = &combo {
+                target.apply_combo(&project_root, name)?;
+            }
+            if target.code_files.is_empty() {
+                anyhow::bail!(
+                    Failure::usage("Falta el código que verificar.").with_hint(
+                        "Con --doc, todos los posicionales son código: `docsguard check --doc docs/auth.md,docs/users.md src/`."
+                    )
+                );
+            }
+            run_check(
+                &target.code_files,
+                &target.doc_files,
+                &project_root,
+                CheckOptions {
+                    apply_fixes: fix,
+                    changed_since: changed_since.as_deref(),
+                    changed_only: changed_only.as_deref(),
+                    check_examples,
+                    check_symbols,
+                    check_versions,
+                    check_arg_typos,
+                    verbosity: Verbosity::from_flags(quiet, verbose),
+                    strictness: match (strict, pedantic) {
+                        (_, true) => Some(Strictness::Pedantic),
+                        (true, false) => Some(Strictness::Strict),
+                        (false, false) => None,
+                    },
+                    unused_suppressions: match (
+                        report_unused_suppressions,
+                        deny_unused_suppressions,
+                    ) {
+                        (_, true) => Some(Severity::Error),
+                        (true, false) => Some(Severity::Info),
+                        (false, false) => None,
+                    },
+                    preset,
+                    absolute_paths,
+                    time_budget,
+                    fail_on_slow,
+                    fail_on,
+                    fast: fast && !no_fast,
+                    explain_baseline,
+                    verify_site: verify_site.then_some(site_sample),
+                    require_docs_for_new: require_docs_for_new
+                        .map(|since| since.or_else(|| changed_since.clone())),
+                    check_attestations,
+                    debt_age,
+                    format,
+                    report: report.as_deref(),
+                    layout,
+                    code_ref: target.code_ref.as_deref(),
+                    docs_ref: target.docs_ref.as_deref(),
+                    baseline_name: target.baseline_name.as_deref(),
+                    no_git,
+                    with_blame,
+                    no_aggregate,
+                    redact_descriptions,
+                },
+            )
+        }
+
+        Commands::Parse { file, project_root } => {
+            inspect::run_parse(&file, &project_root).map(|()| Outcome::Clean)
+        }
+
+        Commands::List {
+            doc_file,
+            code_files,
+            unlinked_only,
+            project_root,
+        } => inspect::run_list(&code_files, &doc_file, &project_root, unlinked_only)
+            .map(|()| Outcome::Clean),
+
+        Commands::Assert {
+            doc_file,
+            code_files,
+            ids,
+            ids_from,
+            require_clean,
+            json,
+            project_root,
+        } => {
+            let options = assert_links::AssertOptions {
+                ids: &ids,
+                ids_from: ids_from.as_deref(),
+                require_clean,
+                json,
+            };
+            assert_links::run_assert(&code_files, &doc_file, &project_root, options)
+        }
+
+        Commands::Report {
+            doc_file,
+            code_files,
+            html,
+            project_root,
+            redact_descriptions,
+            no_aggregate,
+        } => report::run_html_report(
+            &code_files,
+            &doc_file,
+            &project_root,
+            &html,
+            redact_descriptions,
+            no_aggregate,
+        )
+        .map(|()| Outcome::Clean),
+
+        Commands::CheckAll {
+            root,
+            verbose,
+            quiet,
+            fail_on,
+            changed_only,
+        } => check_all::run_check_all(
+            &root,
+            check_all::CheckAllOptions {
+                verbosity: Verbosity::from_flags(quiet, verbose),
+                fail_on,
+                layout,
+                changed_only: changed_only.as_deref(),
+            },
+        ),
+
+        Commands::InstallHooks {
+            force,
+            uninstall,
+            project_root,
+        } => match uninstall {
+            true => hooks::run_uninstall(&project_root),
+            false => hooks::run_install(&project_root, force),
+        }
+        .map(|()| Outcome::Clean),
+
+        Commands::ExportModel {
+            doc_file,
+            code_files,
+            format,
+            project_root,
+        } => report::model::run_export_model(&code_files, &doc_file, &project_root, format),
 
+        Commands::Ci {
+            provider:
+                CiProvider::Github {
+                    doc_file,
+                    code_files,
+                    project_root,
+                    base_ref,
+                    pr_comment,
+                    redact_descriptions,
+                    no_aggregate,
+                },
+        } => ci::github::run_github(
+            &code_files,
+            &doc_file,
+            &project_root,
+            base_ref.as_deref(),
+            pr_comment,
+            redact_descriptions,
+            no_aggregate,
+        ),
+        Commands::Ci {
+            provider:
+                CiProvider::Simulate {
+                    profile,
+                    project_root,
+                    redact_descriptions,
+                },
+        } => ci::profile::run_simulate(&project_root, &profile, redact_descriptions, &layout),
+        Commands::Ci {
+            provider:
+                CiProvider::Run {
+                    profile,
+                    project_root,
+                    redact_descriptions,
+                },
+        } => ci::profile::run_profile(&project_root, &profile, redact_descriptions, &layout),
+
+        #[cfg(feature = "interactive")]
+        Commands::Triage {
+            code_files,
+            doc_file,
+            project_root,
+        } => triage::run_triage(&code_files, &doc_file, &project_root).map(|()| Outcome::Clean),
+
+        #[cfg(feature = "interactive")]
         Commands::Scaffold {
             code_file,
             doc_file,
             dry_run,
             force,
-        } => interactive::run_scaffold(&code_file, &doc_file, dry_run, force),
+            write_to_mapping,
+            project_root,
+            heuristic,
+            stubs,
+            report,
+            emit_patch,
+            single_patch,
+            min_confidence,
+        } => interactive::run_scaffold(
+            &code_file,
+            &doc_file,
+            &project_root,
+            interactive::ScaffoldOptions {
+                dry_run,
+                force,
+                write_to_mapping,
+                heuristic,
+                stubs,
+                report,
+                emit_patch: emit_patch.map(|dir| interactive::patch::EmitPatch {
+                    dir,
+                    single: single_patch,
+                    min_confidence,
+                }),
+                layout,
+            },
+        )
+        .map(|()| Outcome::Clean),
+
+        #[cfg(feature = "interactive")]
+        Commands::Fix {
+            code_file,
+            doc_file,
+            min_confidence,
+            dry_run,
+            project_root,
+            heuristic,
+        } => interactive::apply::run_fix(
+            &code_file,
+            &doc_file,
+            &project_root,
+            interactive::apply::FixOptions {
+                min_confidence,
+                dry_run,
+                heuristic,
+                layout,
+            },
+        ),
 
+        #[cfg(not(feature = "interactive"))]
+        Commands::Triage { .. } | Commands::Scaffold { .. } | Commands::Fix { .. } => {
+            Err(build_info::Feature::Interactive.unavailable())
+        }
+
+        #[cfg(feature = "watch")]
         Commands::Watch {
             code_file,
             doc_file,
-        } => watch::run_watch(&code_file, &doc_file),
+            quiet_ms,
+            max_wait_ms,
+            verbose,
+            quiet,
+            interactive_fixes,
+        } => watch::run_watch(
+            &code_file,
+            &doc_file,
+            watch::BurstWindow::from_millis(quiet_ms, max_wait_ms),
+            layout,
+            Verbosity::from_flags(quiet, verbose),
+            interactive_fixes,
+        )
+        .map(|()| Outcome::Clean),
+
+        #[cfg(not(feature = "watch"))]
+        Commands::Watch { .. } => Err(build_info::Feature::Watch.unavailable()),
+
+        Commands::Attest {
+            doc_id,
+            docs,
+            by,
+            project_root,
+        } => attestation::run_attest(&docs, &doc_id, &by, &project_root).map(|()| Outcome::Clean),
+
+        Commands::SelfCheck { project_root } => self_check::run_self_check(&project_root, &layout),
+
+        Commands::Conformance => conformance::run_conformance(&layout),
+
+        Commands::DiffEntities {
+            doc_file,
+            code_files,
+            from,
+            to,
+            format,
+            project_root,
+        } => diff_entities::run_diff_entities(
+            &code_files,
+            &doc_file,
+            &project_root,
+            &from,
+            to.as_deref(),
+            format,
+        )
+        .map(|()| Outcome::Clean),
 
         Commands::Baseline {
             code_file,
             doc_file,
             project_root,
-        } => baseline::run_baseline(&code_file, &doc_file, &project_root),
+            prune,
+            rewrite_moves,
+            baseline_name,
+            code_ref,
+            docs_ref,
+        } => {
+            let run = || {
+                baseline::run_baseline(
+                    &code_file,
+                    &doc_file,
+                    &project_root,
+                    prune,
+                    rewrite_moves,
+                    baseline_name.as_deref(),
+                )
+            };
+            let revisions = revision_fs(
+                &project_root,
+                std::slice::from_ref(&code_file),
+                std::slice::from_ref(&doc_file),
+                code_ref.as_deref(),
+                docs_ref.as_deref(),
+            )?;
+            match revisions {
+                Some(revisions) => vfs::scoped(revisions, run),
+                None => run(),
+            }
+            .map(|()| Outcome::Clean)
+        }
+
+        Commands::Init {
+            preset,
+            project_root,
+            force,
+        } => config::rules::run_init(preset, &project_root, force).map(|()| Outcome::Clean),
+
+        Commands::Demo { dir } => run_demo(&demo::sample_dir(dir.as_deref()), layout),
+
+        Commands::Explain {
+            preset,
+            project_root,
+        } => config::rules::run_explain(preset, &project_root).map(|()| Outcome::Clean),
+
+        Commands::Config {
+            action:
+                ConfigAction::Show {
+                    resolved_pairs: true,
+                    project_root,
+                    ..
+                },
+        } => pairing::run_resolved_pairs(&project_root).map(|()| Outcome::Clean),
+        Commands::Config {
+            action:
+                ConfigAction::Show {
+                    resolved,
+                    preset,
+                    project_root,
+                    resolved_pairs: false,
+                },
+        } => config::overrides::run_show(&project_root, preset, resolved).map(|()| Outcome::Clean),
+
+        Commands::Config {
+            action:
+                ConfigAction::Diff {
+                    doc_file,
+                    code_files,
+                    against,
+                    against_git,
+                    project_root,
+                    preset,
+                    json,
+                },
+        } => {
+            let against = match (&against, &against_git) {
+                (Some(file), _) => config::diff::Against::File(file),
+                (None, Some(rev)) => config::diff::Against::Git(rev),
+                (None, None) => unreachable!("clap exige --against o --against-git"),
+            };
+            config::diff::run_diff(&code_files, &doc_file, &project_root, against, preset, json)
+        }
 
         Commands::Coverage {
             code_files,
             min_coverage,
-        } => coverage::run_coverage(&code_files, min_coverage),
+            docs,
+            compare_to,
+            json,
+            markdown,
+        } => coverage::run_coverage(
+            &code_files,
+            docs.as_deref(),
+            min_coverage,
+            compare_to.as_deref(),
+            match (json, markdown) {
+                (true, _) => coverage::CoverageOutput::Json,
+                (_, true) => coverage::CoverageOutput::Markdown,
+                _ => coverage::CoverageOutput::Text,
+            },
+            &layout,
+        ),
+
+        Commands::Suppressions {
+            action:
+                SuppressionsAction::List {
+                    doc_file,
+                    code_files,
+                    format,
+                    mechanism,
+                    sort,
+                    no_git,
+                    project_root,
+                },
+        } => report::suppressions::run_list(
+            &code_files,
+            &doc_file,
+            &project_root,
+            report::suppressions::ListOptions {
+                format,
+                mechanisms: mechanism,
+                sort,
+                no_git,
+            },
+        )
+        .map(|()| Outcome::Clean),
+
+        Commands::Schema { artifact } => schema::run_schema(artifact),
+
+        Commands::Version { verbose } => {
+            print_version(verbose);
+            Ok(Outcome::Clean)
+        }
+    }
+}
+
+/// Adónde va la salida de `check` que no es el informe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Console {
+    Stdout,
+    /// `--format sarif|json|markdown`: stdout queda para el informe.
+    Stderr,
+    /// `--report` sin `-v`.
+    Silent,
+}
+
+impl Console {
+    fn for_check(options: &CheckOptions) -> Self {
+        match (options.format, options.report) {
+            (_, Some(_)) if options.verbosity != Verbosity::Verbose => Console::Silent,
+            (report::CheckFormat::Text, _) => Console::Stdout,
+            _ => Console::Stderr,
+        }
+    }
+}
+
+/// `println_tr!` de la salida de `check` según la `Console` del primer
+/// argumento.
+macro_rules! say {
+    ($console:expr) => {
+        say!($console, "")
+    };
+    ($console:expr, $($arg:tt)*) => {
+        match $console {
+            Console::Stdout => println_tr!($($arg)*),
+            Console::Stderr => eprintln_tr!($($arg)*),
+            Console::Silent => {}
+        }
+    };
+}
+
+/// Hallazgos de `--verify-site`: las URLs de las secciones (o una muestra de
+/// `sample`) que el sitio de `site.base_url` no publica.
+#[cfg(feature = "verify-site")]
+fn verify_site(
+    sample: Option<usize>,
+    doc_source: &str,
+    doc_sections: &[core::types::DocSection],
+    doc_path: &Path,
+    config: &Config,
+    console: Console,
+) -> Vec<core::types::ValidationResult> {
+    let Some(base_url) = config.site.base_url.as_deref() else {
+        return Vec::new();
+    };
+    let urls =
+        core::site_urls::section_urls(doc_source, doc_sections, doc_path, &config.site, base_url);
+    let total = urls.len();
+    let urls = match sample {
+        Some(count) => core::site_urls::sample(urls, count),
+        None => urls,
+    };
+    say!(
+        console,
+        "  [site] Verificando {} de {} en {}.\n",
+        messages::Locale::CANONICAL.number(urls.len()),
+        messages::SECTIONS.count(total),
+        base_url
+    );
+    site::verify(&urls, &config.site)
+}
+
+/// Sin la feature `verify-site`, `run_check` ya rechazó el flag.
+#[cfg(not(feature = "verify-site"))]
+fn verify_site(
+    _sample: Option<usize>,
+    _doc_source: &str,
+    _doc_sections: &[core::types::DocSection],
+    _doc_path: &Path,
+    _config: &Config,
+    _console: Console,
+) -> Vec<core::types::ValidationResult> {
+    Vec::new()
+}
+
+fn print_version(verbose: bool) {
+    if verbose {
+        print_tr!("{}", build_info::verbose_version());
+    } else {
+        println_tr!("{}", build_info::short_version());
+    }
+}
+
+/// Segundos (admite decimales) para `--time-budget`.
+fn parse_seconds(value: &str) -> Result<Duration, String> {
+    let seconds: f64 = value
+        .parse()
+        .map_err(|_| format!("'{}' no es un número de segundos", value))?;
+    if !seconds.is_finite() || seconds <= 0.0 {
+        return Err("el presupuesto debe ser mayor que 0".into());
+    }
+    Ok(Duration::from_secs_f64(seconds))
+}
+
+/// Ubicaciones de `result` con la revisión de la que salió cada archivo.
+fn locate_in_revisions(result: &mut ValidationResult, revisions: &vfs::RevisionFs) {
+    for location in [
+        &mut result.code_location,
+        &mut result.doc_location,
+        &mut result.target,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        *location = revisions.locate(location);
     }
+    for related in &mut result.related {
+        related.location = revisions.locate(&related.location);
+    }
+}
+
+/// Nombre de `--baseline-name` (`.docsguard/baseline-<nombre>.yaml`).
+fn parse_baseline_name(value: &str) -> Result<String, String> {
+    baseline::check_name(value).map(|()| value.to_string())
 }
 
-fn run_check(code_files: &[PathBuf], doc_file: &Path, project_root: &Path) -> Result<()> {
+/// `RevisionFs` para `--code-ref`/`--docs-ref`, si se indicó alguna: el
+/// código con `code_ref` (menos las docs) y las docs con `docs_ref`.
+fn revision_fs(
+    project_root: &Path,
+    code_inputs: &[PathBuf],
+    doc_files: &[PathBuf],
+    code_ref: Option<&str>,
+    docs_ref: Option<&str>,
+) -> Result<Option<vfs::RevisionFs>> {
+    let mut sides = Vec::new();
+    if let Some(rev) = docs_ref {
+        sides.push(vfs::RevisionSide {
+            rev: rev.to_string(),
+            roots: doc_files.to_vec(),
+            except: Vec::new(),
+        });
+    }
+    if let Some(rev) = code_ref {
+        sides.push(vfs::RevisionSide {
+            rev: rev.to_string(),
+            roots: code_inputs
+                .iter()
+                .map(|input| paths::input_root(input))
+                .collect(),
+            except: doc_files.to_vec(),
+        });
+    }
+    if sides.is_empty() {
+        return Ok(None);
+    }
+    vfs::RevisionFs::new(vfs::current(), project_root, &sides).map(Some)
+}
+
+/// Confianza entre 0 y 1 para `scaffold --min-confidence`.
+fn parse_confidence(value: &str) -> Result<f64, String> {
+    let confidence: f64 = value
+        .parse()
+        .map_err(|_| format!("'{}' no es un número", value))?;
+    if !(0.0..=1.0).contains(&confidence) {
+        return Err("la confianza va de 0 a 1 (0.9 = 90%)".into());
+    }
+    Ok(confidence)
+}
+
+/// Entradas de `check` y de dónde se leen.
+struct CheckTarget {
+    doc_files: Vec<PathBuf>,
+    code_files: Vec<PathBuf>,
+    code_ref: Option<String>,
+    docs_ref: Option<String>,
+    baseline_name: Option<String>,
+}
+
+impl CheckTarget {
+    /// Completa lo que no se indicó en la línea de comandos con la
+    /// combinación `name` de `combos:`; sus rutas son relativas a `project_root`.
+    fn apply_combo(&mut self, project_root: &Path, name: &str) -> Result<()> {
+        let config = Config::load(project_root)?;
+        let (combo, baseline_name) = config.combo(name)?;
+        if self.doc_files.is_empty() {
+            self.doc_files = vec![project_root.join(&combo.doc_file)];
+        }
+        if self.code_files.is_empty() {
+            self.code_files = combo.code.iter().map(|p| project_root.join(p)).collect();
+        }
+        self.code_ref = self.code_ref.take().or_else(|| combo.code_ref.clone());
+        self.docs_ref = self.docs_ref.take().or_else(|| combo.docs_ref.clone());
+        self.baseline_name = self.baseline_name.take().or(Some(baseline_name));
+        Ok(())
+    }
+}
+
+/// Opciones de `check` que no son entradas.
+struct CheckOptions<'a> {
+    apply_fixes: bool,
+    changed_since: Option<&'a str>,
+    /// `--changed-only`: la referencia base del diff.
+    changed_only: Option<&'a str>,
+    check_examples: bool,
+    check_symbols: bool,
+    check_versions: bool,
+    check_arg_typos: bool,
+    verbosity: Verbosity,
+    strictness: Option<Strictness>,
+    /// Severidad de `unused-suppression`, si se reporta.
+    unused_suppressions: Option<Severity>,
+    preset: Option<Preset>,
+    absolute_paths: bool,
+    time_budget: Option<Duration>,
+    fail_on_slow: bool,
+    fail_on: FailOn,
+    /// Reutiliza `.docsguard/last_run.json` para los archivos que no cambiaron.
+    fast: bool,
+    explain_baseline: bool,
+    /// `--verify-site`, con la muestra de `--site-sample` (sin ella, todas).
+    verify_site: Option<Option<usize>>,
+    /// `--require-docs-for-new`, con la referencia ya resuelta (`None` si
+    /// no se indicó ninguna).
+    require_docs_for_new: Option<Option<String>>,
+    check_attestations: bool,
+    /// `--debt-age`, con la ventana de caducidad en días.
+    debt_age: Option<u64>,
+    format: report::CheckFormat,
+    /// `--report`: dónde escribir el documento Markdown.
+    report: Option<&'a Path>,
+    layout: Layout,
+    /// `--code-ref`: revisión de la que se lee el código.
+    code_ref: Option<&'a str>,
+    /// `--docs-ref`: revisión de la que se leen las docs.
+    docs_ref: Option<&'a str>,
+    /// `--baseline-name`: `.docsguard/baseline-<nombre>.yaml`.
+    baseline_name: Option<&'a str>,
+    /// No buscar la probable causa de los errores (`report::blame`).
+    no_git: bool,
+    /// La probable causa también en SARIF.
+    with_blame: bool,
+    /// Ignora `info_aggregation: summarize`.
+    no_aggregate: bool,
+    /// Quita las descripciones documentadas de lo que sale de la máquina.
+    redact_descriptions: bool,
+}
+
+impl CheckOptions<'_> {
+    /// Opciones que cambian los hallazgos guardados por `--fast`.
+    fn fingerprint(&self) -> String {
+        format!(
+            "examples={} symbols={} versions={} arg-typos={} attestations={} strictness={:?} preset={:?} absolute-paths={}",
+            self.check_examples,
+            self.check_symbols,
+            self.check_versions,
+            self.check_arg_typos,
+            self.check_attestations,
+            self.strictness,
+            self.preset,
+            self.absolute_paths,
+        )
+    }
+
+    /// Motivo por el que `--fast` no puede reutilizar nada, aunque el registro sirva.
+    fn full_run_reason(&self) -> Option<&'static str> {
+        if self.apply_fixes {
+            Some("--fix puede modificar los archivos")
+        } else if self.unused_suppressions.is_some() {
+            Some("las supresiones sin usar necesitan todas las directivas")
+        } else {
+            None
+        }
+    }
+}
+
+/// Secciones de todos los archivos de docs de `check`, en orden.
+fn parse_doc_files(
+    doc_files: &[PathBuf],
+    config: &Config,
+    diagnostics: &mut ParseDiagnostics,
+    timings: &mut Timings,
+) -> Result<Vec<core::types::DocSection>> {
+    let mut sections = Vec::new();
+    for doc_file in doc_files {
+        sections.extend(
+            timings
+                .time_parse(&config.paths.normalize(doc_file), || {
+                    doc_parser::parse_docs_with_diagnostics(doc_file, config, diagnostics)
+                })
+                .context("Error al parsear el archivo de documentación")?,
+        );
+    }
+    Ok(sections)
+}
+
+/// Contenido de cada archivo de docs, para `doc_inputs`.
+fn read_doc_sources(doc_files: &[PathBuf]) -> Result<Vec<String>> {
+    doc_files
+        .iter()
+        .map(|f| doc_parser::read_markdown_file(f))
+        .collect()
+}
+
+/// Los archivos de docs con su contenido, para `pipeline::Inputs`.
+fn doc_inputs<'a>(doc_files: &'a [PathBuf], sources: &'a [String]) -> Vec<pipeline::DocSource<'a>> {
+    doc_files
+        .iter()
+        .zip(sources)
+        .map(|(file, source)| pipeline::DocSource { file, source })
+        .collect()
+}
+
+/// Ejecuta `demo`: escribe el ejemplo, lo verifica como `check` con las
+/// opciones por defecto y lo comenta. Los hallazgos son el objetivo: sale
+/// con 0 si `check` termina.
+fn run_demo(dir: &Path, layout: Layout) -> Result<Outcome> {
+    demo::write_sample(dir)?;
+    println_tr!(
+        "DocsGuard demo — proyecto de ejemplo en {}\n",
+        safe_display(dir)
+    );
+    let code_files: Vec<PathBuf> = demo::CODE_FILES.iter().map(|f| dir.join(f)).collect();
+    run_check(
+        &code_files,
+        &[dir.join(demo::DOC_FILE)],
+        dir,
+        CheckOptions {
+            apply_fixes: false,
+            changed_since: None,
+            changed_only: None,
+            check_examples: false,
+            check_symbols: false,
+            check_versions: false,
+            check_arg_typos: false,
+            // Los enlaces verificados también se comentan
+            verbosity: Verbosity::Verbose,
+            strictness: None,
+            unused_suppressions: None,
+            preset: None,
+            absolute_paths: false,
+            time_budget: None,
+            fail_on_slow: false,
+            fail_on: FailOn::Error,
+            fast: false,
+            explain_baseline: false,
+            verify_site: None,
+            require_docs_for_new: None,
+            check_attestations: false,
+            debt_age: None,
+            format: report::CheckFormat::Text,
+            report: None,
+            layout,
+            code_ref: None,
+            docs_ref: None,
+            baseline_name: None,
+            // El ejemplo no está en un repositorio propio
+            no_git: true,
+            with_blame: false,
+            no_aggregate: false,
+            redact_descriptions: false,
+        },
+    )?;
+    print_tr!("{}", demo::commentary(dir));
+    Ok(Outcome::Clean)
+}
+
+fn run_check(
+    code_inputs: &[PathBuf],
+    doc_files: &[PathBuf],
+    project_root: &Path,
+    options: CheckOptions,
+) -> Result<Outcome> {
+    let console = Console::for_check(&options);
+    // --code-ref/--docs-ref: desde aquí, esas rutas se leen de la revisión
+    let revisions = revision_fs(
+        project_root,
+        code_inputs,
+        doc_files,
+        options.code_ref,
+        options.docs_ref,
+    )?;
+    let _revisions = revisions.clone().map(vfs::install);
+    // Directorios y globs se expanden; lo descubierto sin lenguaje soportado se omite
+    let (code_files, skipped_files) = code_parser::split_supported(
+        paths::expand_inputs(code_inputs)?,
+        &paths::ProjectPaths::new(project_root).with_absolute(options.absolute_paths),
+    );
+    let code_files = code_files.as_slice();
+    // Igual en la documentación, omitiendo lo descubierto que no es Markdown
+    let (doc_files, skipped_docs) = doc_parser::split_markdown(
+        paths::expand_inputs(doc_files)?,
+        &paths::ProjectPaths::new(project_root).with_absolute(options.absolute_paths),
+    );
+    if doc_files.is_empty() {
+        anyhow::bail!(
+            Failure::not_found("No hay documentación Markdown que verificar.").with_hint(
+                "Indica un archivo .md, o un directorio o un glob (`\"docs/**/*.md\"`) que los contenga."
+            )
+        );
+    }
+    let doc_files = doc_files.as_slice();
+    // El primer archivo de docs recibe las secciones que añade --fix y da
+    // la clave de --fast y del historial; los demás solo aportan secciones
+    let doc_file = doc_files[0].as_path();
+
     // Refactorizado: usa require_file_exists para eliminar comprobaciones duplicadas entre comandos
     for code_file in code_files {
         code_parser::require_file_exists(code_file, "código")?;
     }
-    code_parser::require_file_exists(doc_file, "documentación")?;
+    for doc_file in doc_files {
+        code_parser::require_file_exists(doc_file, "documentación")?;
+    }
 
-    println!("DocsGuard — Verificando enlaces código ↔ documentación\n");
-    println!("  Docs: {}", safe_display(doc_file));
-    println!("  Código: {} archivos", code_files.len());
+    let changed = options
+        .changed_only
+        .map(|base| changed_only::ChangedView::load(project_root, base))
+        .transpose()?;
 
-    let mut all_code_entities = Vec::new();
-    for code_file in code_files {
-        println!("    -> {}", safe_display(code_file));
-        let mut entities = code_parser::parse_code_file(code_file)
-            .context(format!("Error al parsear {}", code_file.display()))?;
-        all_code_entities.append(&mut entities);
+    // Con -q, de la cabecera no queda nada
+    if options.verbosity != Verbosity::Quiet {
+        say!(
+            console,
+            "DocsGuard — Verificando enlaces código ↔ documentación\n"
+        );
+        let docs = doc_files
+            .iter()
+            .map(|f| safe_display(f).to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        if skipped_docs.is_empty() {
+            say!(console, "  Docs: {}", docs);
+        } else {
+            say!(
+                console,
+                "  Docs: {} ({}: sin extensión Markdown)",
+                docs,
+                messages::SKIPPED_FILES.count(skipped_docs.len())
+            );
+        }
+        if skipped_files.is_empty() {
+            say!(
+                console,
+                "  Código: {}",
+                messages::FILES.count(code_files.len())
+            );
+        } else {
+            say!(
+                console,
+                "  Código: {} ({}: sin lenguaje soportado)",
+                messages::FILES.count(code_files.len()),
+                messages::SKIPPED_FILES.count(skipped_files.len())
+            );
+        }
+
+        for code_file in code_files {
+            say!(console, "    -> {}", safe_display(code_file));
+        }
+        if let Some(rev) = options.code_ref {
+            say!(console, "  Código leído de la revisión '{}'", rev);
+        }
+        if let Some(rev) = options.docs_ref {
+            say!(console, "  Docs leídas de la revisión '{}'", rev);
+        }
+        if let Some(name) = options.baseline_name {
+            say!(
+                console,
+                "  Baseline: {}",
+                safe_display(&baseline::baseline_path(project_root, Some(name)))
+            );
+        }
+        say!(console); // spacer
     }
-    println!(); // spacer
 
-    let doc_sections = doc_parser::parse_markdown_file(doc_file)
-        .context("Error al parsear el archivo de documentación")?;
+    let started = Instant::now();
+    let mut timings = Timings::default();
+    let mut config = Config::load(project_root)?.with_preset(options.preset);
+    config.paths = config.paths.with_absolute(options.absolute_paths);
+    let doc_path = config.paths.normalize(doc_file);
+    let mut diagnostics = ParseDiagnostics::default();
+    if options.verify_site.is_some() {
+        if !build_info::Feature::VerifySite.enabled() {
+            return Err(build_info::Feature::VerifySite.unavailable());
+        }
+        if config.site.base_url.is_none() {
+            anyhow::bail!(
+                Failure::config("--verify-site necesita la URL del sitio publicado.")
+                    .with_hint("Añade `site: {base_url: https://…}` a .docsguard/config.yaml.")
+            );
+        }
+    }
+
+    if options.require_docs_for_new == Some(None) {
+        anyhow::bail!(
+            Failure::usage("--require-docs-for-new necesita una referencia git.").with_hint(
+                "Indícala (`--require-docs-for-new origin/main`) o usa --changed-since."
+            )
+        );
+    }
+
+    // --fast: el registro de la última ejecución, si todavía sirve
+    let mut fast_key = None;
+    let mut code_hashes = Vec::new();
+    let mut previous = None;
+    if options.fast {
+        // Los demás archivos de docs invalidan el registro como las opciones
+        let mut fingerprint = options.fingerprint();
+        for extra in &doc_files[1..] {
+            fingerprint.push_str(&format!(
+                " doc={}:{:x}",
+                config.paths.normalize(extra).display(),
+                last_run::hash_file(extra)?
+            ));
+        }
+        let key = last_run::RunKey::new(project_root, doc_file, &doc_path, fingerprint)?;
+        code_hashes = code_files
+            .iter()
+            .map(|f| last_run::hash_file(f))
+            .collect::<Result<_>>()?;
+        let reason = match last_run::LastRun::load(project_root)? {
+            None => Some("no hay registro de una ejecución anterior"),
+            Some(run) => match options.full_run_reason().or_else(|| run.stale_reason(&key)) {
+                None => {
+                    previous = Some(run);
+                    None
+                }
+                reason => reason,
+            },
+        };
+        if let Some(reason) = reason {
+            say!(console, "  [fast] Ejecución completa: {}.\n", reason);
+        }
+        fast_key = Some(key);
+    }
+    let (mut all_code_entities, untouched) = match &previous {
+        Some(run) => last_run::parse_changed(
+            run,
+            code_files,
+            &code_hashes,
+            &config,
+            &mut diagnostics,
+            &mut timings,
+        )?,
+        None => (
+            report::parse_timed(code_files, &config, &mut diagnostics, &mut timings)?,
+            HashSet::new(),
+        ),
+    };
+    if previous.is_some() {
+        say!(
+            console,
+            "  [fast] {} de {}; se conservan los hallazgos del resto.\n",
+            messages::Locale::CANONICAL.number(code_files.len() - untouched.len()),
+            messages::REPARSED_CODE_FILES.count(code_files.len())
+        );
+    }
+    let mut doc_diagnostics = ParseDiagnostics::default();
+    let mut doc_sections = parse_doc_files(doc_files, &config, &mut doc_diagnostics, &mut timings)?;
+
+    if options.apply_fixes {
+        conflicts::refuse_writes(&diagnostics)?;
+        conflicts::refuse_writes(&doc_diagnostics)?;
+        let doc_sources = read_doc_sources(doc_files)?;
+        let results = pipeline::run_validation(
+            pipeline::Inputs {
+                code_entities: &all_code_entities,
+                doc_sections: &doc_sections,
+                diagnostics: &diagnostics,
+                docs: &doc_inputs(doc_files, &doc_sources),
+                config: &config,
+            },
+            pipeline::Passes {
+                arg_typos: options.check_arg_typos,
+                ..pipeline::Passes::default()
+            },
+        )
+        .results;
+        let applied = fix::apply_all(
+            &results,
+            &fix::FixContext {
+                code_entities: &all_code_entities,
+                doc_sections: &doc_sections,
+                doc_file,
+                paths: &config.paths,
+            },
+        )?;
+        for fix in &applied {
+            say!(console, "  [fix] {}", fix.describe());
+        }
+        if applied.iter().any(fix::Fix::edits_code) {
+            diagnostics = ParseDiagnostics::default();
+            all_code_entities =
+                report::parse_timed(code_files, &config, &mut diagnostics, &mut timings)?;
+        }
+        if !applied.is_empty() {
+            say!(console);
+            doc_diagnostics = ParseDiagnostics::default();
+            doc_sections = parse_doc_files(doc_files, &config, &mut doc_diagnostics, &mut timings)?;
+        }
+    }
 
-    println!(
-        "  Encontradas {} funciones en código (total), {} secciones en docs.\n",
-        all_code_entities.len(),
-        doc_sections.len()
+    say!(
+        console,
+        "  En código: {} (total){}; en docs: {}{}.\n",
+        messages::FUNCTIONS.count(all_code_entities.len()),
+        report::ignored_note(diagnostics.ignored_functions.len()),
+        messages::SECTIONS.count(doc_sections.len()),
+        report::prose_only_note(validator::count_prose_only_links(
+            &all_code_entities,
+            &doc_sections
+        ))
     );
 
-    let results = validator::validate_links(&all_code_entities, &doc_sections);
+    let known_symbols = if options.check_symbols {
+        Some(symbols::KnownSymbols::collect(
+            code_files,
+            &all_code_entities,
+            project_root,
+            &config.references,
+        )?)
+    } else {
+        None
+    };
+    let project_versions = if options.check_versions {
+        Some(version_source::ProjectVersions::load(
+            &config.versions,
+            project_root,
+        )?)
+    } else {
+        None
+    };
+
+    let new_functions = match &options.require_docs_for_new {
+        Some(Some(since)) => Some(new_functions::NewFunctions::find(
+            project_root,
+            since,
+            code_files,
+            &all_code_entities,
+            &config,
+        )?),
+        _ => None,
+    };
+    let attestations = if options.check_attestations {
+        Some(attestation::Attestations::load(project_root)?)
+    } else {
+        None
+    };
+
+    if let Some(view) = &changed {
+        let (entities, sections) = (all_code_entities.len(), doc_sections.len());
+        (all_code_entities, doc_sections) =
+            changed_only::filter(view, all_code_entities, doc_sections, &config.paths);
+        say!(
+            console,
+            "  [changed-only] Solo lo tocado desde '{}': {} de {}, {} de {}.\n",
+            view.base,
+            messages::FUNCTIONS.count(all_code_entities.len()),
+            messages::Locale::CANONICAL.number(entities),
+            messages::SECTIONS.count(doc_sections.len()),
+            messages::Locale::CANONICAL.number(sections)
+        );
+    }
+
+    diagnostics.merge(doc_diagnostics);
+    diagnostics.notes.extend(skipped_files);
+    diagnostics.notes.extend(skipped_docs);
+    let doc_sources = read_doc_sources(doc_files)?;
+    let docs = doc_inputs(doc_files, &doc_sources);
+    let validation = timings.time_validate(|| {
+        let mut site_findings = Vec::new();
+        if let Some(sample) = options.verify_site {
+            for (file, source) in doc_files.iter().zip(&doc_sources) {
+                let display = config.paths.normalize(file);
+                let sections: Vec<_> = doc_sections
+                    .iter()
+                    .filter(|s| s.file_path == display)
+                    .cloned()
+                    .collect();
+                site_findings.extend(verify_site(
+                    sample,
+                    source,
+                    &sections,
+                    &config.paths.normalize(file),
+                    &config,
+                    console,
+                ));
+            }
+        }
+        pipeline::run_validation(
+            pipeline::Inputs {
+                code_entities: &all_code_entities,
+                doc_sections: &doc_sections,
+                diagnostics: &diagnostics,
+                docs: &docs,
+                config: &config,
+            },
+            pipeline::Passes {
+                arg_typos: options.check_arg_typos,
+                examples: options.check_examples,
+                symbols: known_symbols.as_ref(),
+                versions: project_versions.as_ref(),
+                strictness: options.strictness,
+                new_functions: new_functions.as_ref(),
+                attestations: attestations.as_ref(),
+                extra: site_findings,
+                ..pipeline::Passes::default()
+            },
+        )
+    });
+    let pipeline::Validation {
+        results: mut all_results,
+        unused_directives,
+        stale_exceptions,
+    } = validation;
+    if changed.is_some() {
+        changed_only::drop_partial_view_findings(&mut all_results);
+    }
+    let all_results = match &previous {
+        Some(run) => last_run::splice(run.results(), all_results, &untouched),
+        None => all_results,
+    };
+    // El registro anterior, antes de que --fast lo sustituya por este
+    let history = if options.no_git || revisions.is_some() {
+        None
+    } else {
+        last_run::LastRun::load(project_root)
+            .ok()
+            .flatten()
+            .filter(|run| run.doc_file() == doc_path)
+    };
+    if let Some(key) = fast_key {
+        let files: Vec<_> = code_files
+            .iter()
+            .map(|f| config.paths.normalize(f))
+            .zip(code_hashes)
+            .collect();
+        last_run::LastRun::new(
+            key,
+            &files,
+            &all_code_entities,
+            &diagnostics.ignored_functions,
+            all_results.clone(),
+        )
+        .save(project_root)?;
+    }
+
+    let baseline = baseline::Baseline::load_named(project_root, options.baseline_name)?;
+    let unused = match options.unused_suppressions {
+        Some(severity) => {
+            // Las reglas de pasadas que no se ejecutaron no pueden haber silenciado nada
+            let unused_directives: Vec<_> = unused_directives
+                .into_iter()
+                .filter(|(_, directive)| match directive.rule.as_str() {
+                    "example-mismatch" => options.check_examples,
+                    "since-version" => options.check_versions,
+                    "arg-typo" => options.check_arg_typos,
+                    "changed-since-attestation" | "missing-attestation" => {
+                        options.check_attestations
+                    }
+                    rule if rule.starts_with("DG") => options.strictness.is_some(),
+                    _ => true,
+                })
+                .collect();
+            let mut unused = suppression::unused_suppression_results(&unused_directives, severity);
+            unused.extend(validator::stale_arg_exception_results(
+                &stale_exceptions,
+                severity,
+            ));
+            if let Some(bl) = &baseline {
+                let mut checked: Vec<PathBuf> = code_files
+                    .iter()
+                    .map(|f| config.paths.normalize(f))
+                    .collect();
+                checked.extend(doc_files.iter().map(|f| config.paths.normalize(f)));
+                let entries =
+                    baseline::unused_entries(&all_results, bl, &all_code_entities, &checked);
+                unused.extend(baseline::unused_entry_results(
+                    project_root,
+                    options.baseline_name,
+                    &entries,
+                    severity,
+                ));
+            }
+            unused
+        }
+        None => Vec::new(),
+    };
 
     // Aplicar baseline si existe
-    let (results, baseline_filtered) = match baseline::Baseline::load(project_root)? {
+    let debug_json = options.explain_baseline && options.format == report::CheckFormat::Json;
+    let mut baseline_debug = None;
+    let (mut results, baseline_filtered) = match &baseline {
         Some(bl) => {
-            let (filtered_results, count) = baseline::filter_baseline(&results, &bl);
+            let (filtered_results, count) =
+                baseline::filter_baseline(&all_results, bl, &all_code_entities);
             if count > 0 {
-                println!(
-                    "  [baseline] {} errores/advertencias conocidos filtrados.\n",
-                    count
+                say!(
+                    console,
+                    "  [baseline] {}.\n",
+                    messages::KNOWN_FINDINGS_FILTERED.count(count)
                 );
             }
+            if options.explain_baseline {
+                let misses = baseline::explain_misses(&filtered_results, bl);
+                if !misses.is_empty() {
+                    say!(
+                        console,
+                        "{}",
+                        baseline::render_misses(project_root, options.baseline_name, &misses)
+                    );
+                }
+                if debug_json {
+                    baseline_debug = Some(baseline::miss_records(
+                        project_root,
+                        options.baseline_name,
+                        &misses,
+                    ));
+                }
+            }
             (filtered_results, count)
         }
-        None => (results, 0),
+        None => {
+            if options.explain_baseline {
+                say!(
+                    console,
+                    "  [explain-baseline] No hay baseline en {}: no se filtra nada.\n",
+                    safe_display(&baseline::baseline_path(
+                        project_root,
+                        options.baseline_name
+                    ))
+                );
+            }
+            if debug_json {
+                let empty = baseline::Baseline::from_results(&[], &[]);
+                baseline_debug = Some(baseline::miss_records(
+                    project_root,
+                    options.baseline_name,
+                    &baseline::explain_misses(&all_results, &empty),
+                ));
+            }
+            (all_results, 0)
+        }
     };
+    results.extend(unused);
+    // Tras el baseline, que guarda las rutas sin revisión
+    if let Some(revisions) = &revisions {
+        for result in &mut results {
+            locate_in_revisions(result, revisions);
+        }
+    }
+
+    // El baseline ya se filtró arriba; aquí solo se agrupan los Info
+    let mut report = report::Report::filtered(
+        results,
+        None,
+        match options.no_aggregate {
+            true => InfoAggregation::Off,
+            false => config.info_aggregation,
+        },
+        &all_code_entities,
+        &doc_sections,
+    );
+    report.baseline_filtered = baseline_filtered;
+    report.ignored_functions = diagnostics.ignored_functions.len();
+    report.baseline_debug = baseline_debug;
+    if let Some(since) = options.changed_since {
+        let changed = git::changed_files(project_root, since)?;
+        report.retain_changed(&changed, project_root);
+        say!(
+            console,
+            "  [changed-since] {} desde '{}'.\n",
+            messages::CHANGED_FILES.count(changed.len()),
+            since
+        );
+    }
+    // Las revisiones de --code-ref no son las del blame del árbol de trabajo
+    if !options.no_git
+        && revisions.is_none()
+        && (options.format == report::CheckFormat::Text || options.with_blame)
+    {
+        report::blame::explain(
+            &mut report,
+            &all_code_entities,
+            history.as_ref().map(|run| run.results()),
+            &config.paths,
+            project_root,
+        );
+    }
+
+    timings.total = started.elapsed();
+    if options.verbosity == Verbosity::Verbose {
+        say!(console, "{}", timings.render());
+    }
+    // Tras baseline y --changed-since: el hallazgo no tiene ubicación y es de esta ejecución
+    let over_budget = match options.time_budget.and_then(|b| timings.budget_finding(b)) {
+        Some(finding) => {
+            report.results.push(finding);
+            true
+        }
+        None => false,
+    };
+    report.timings = timings;
+    // Lo que sale de la máquina (CI, PR, Slack) va redactado como `report --html`
+    let redaction = report::redact::Redaction::new(&config.redaction, options.redact_descriptions);
+    if options.format == report::CheckFormat::Sarif {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report::sarif::render(
+                &redaction.apply(&report.localized()).results
+            ))?
+        );
+    }
+    if options.format == report::CheckFormat::Json {
+        report.links = report::links::build(&all_code_entities, &doc_sections, &config);
+        if let Some(window) = options.debt_age {
+            let empty = baseline::Baseline::from_results(&[], &[]);
+            let debt = baseline::debt_age(
+                baseline.as_ref().unwrap_or(&empty),
+                dates::today_days(),
+                window,
+            );
+            report.debt_age = Some(baseline::debt_age_record(&debt, window));
+        }
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report::json::render(
+                &redaction.apply(&report.localized())
+            ))?
+        );
+    }
+    if options.format == report::CheckFormat::Markdown || options.report.is_some() {
+        let document = report::markdown::render_document(
+            &redaction.apply(&report.localized()),
+            options.verbosity,
+        );
+        if options.format == report::CheckFormat::Markdown {
+            print!("{}", document);
+        }
+        if let Some(path) = options.report {
+            if let Some(dir) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                vfs::create_dir_all(dir).with_context(|| {
+                    format!("No se pudo crear el directorio: {}", safe_display(dir))
+                })?;
+            }
+            transaction::write_streamed(path, |out| {
+                out.write_all(document.as_bytes())?;
+                Ok(())
+            })?;
+        }
+    }
+    let results = report.results;
+    let debt_age = options.debt_age.map(|window| match &baseline {
+        Some(bl) => {
+            baseline::render_debt_age(&baseline::debt_age(bl, dates::today_days(), window), window)
+        }
+        None => format!(
+            "\n  [debt-age] No hay baseline en {}: no hay deuda aceptada.\n",
+            safe_display(&baseline::baseline_path(
+                project_root,
+                options.baseline_name
+            ))
+        ),
+    });
 
     if results.is_empty() {
         if baseline_filtered > 0 {
-            println!("  Sin errores nuevos (baseline activo).");
+            say!(console, "  Sin errores nuevos (baseline activo).");
         } else {
-            println!("  No se encontraron funciones ni secciones para validar.");
+            say!(
+                console,
+                "  No se encontraron funciones ni secciones para validar."
+            );
+        }
+        if let Some(debt) = &debt_age {
+            say!(console, "{}", debt.trim_end());
         }
-        return Ok(());
+        return Ok(Outcome::Clean);
     }
 
     let error_count = results
@@ -187,21 +2395,39 @@ fn run_check(code_files: &[PathBuf], doc_file: &Path, project_root: &Path) -> Re
         .filter(|r| r.severity == Severity::Warning)
         .count();
 
-    for result in &results {
-        print!("{result}");
+    // Con --format sarif|json|markdown los hallazgos van en el informe
+    let verbose = options.verbosity == Verbosity::Verbose;
+    for result in results
+        .iter()
+        .filter(|r| console == Console::Stdout && options.verbosity.shows(r))
+    {
+        print!("{}", result.render(&options.layout, verbose));
     }
 
-    println!("---");
-    println!(
-        "Resumen: {} errores, {} advertencias, {} total",
-        error_count,
-        warning_count,
-        results.len()
+    say!(console, "---");
+    say!(
+        console,
+        "Resumen{}: {}, {} total · umbral: {}",
+        if previous.is_some() {
+            " (incremental)"
+        } else {
+            ""
+        },
+        messages::summary(error_count, warning_count),
+        messages::Locale::CANONICAL.number(
+            results
+                .iter()
+                .map(ValidationResult::occurrences)
+                .sum::<usize>()
+        ),
+        options.fail_on.name()
     );
-
-    if error_count > 0 {
-        std::process::exit(1);
+    if let Some(debt) = &debt_age {
+        say!(console, "{}", debt.trim_end());
     }
 
-    Ok(())
+    Ok(Outcome::failed_if(
+        options.fail_on.exceeded(error_count, warning_count)
+            || (over_budget && options.fail_on_slow),
+    ))
 }
@@ -0,0 +1,292 @@
+//! Enlaces externos `.docsguard/links.yaml` (sidecar).
+//!
+//! Para código que no admite comentarios (vendorizado, generado…), cada
+//! entrada enlaza una función de un archivo con un ID de documentación como
+//! si tuviera `@docs`. Se aplica al parsear (`code_parser::parse_project_code`),
+//! así que validación, baseline y triage no distinguen un enlace del otro.
+//! Una anotación en el código siempre gana sobre la entrada del mapeo.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::baseline::DOCSGUARD_DIR;
+use crate::core::diagnostics::{ParseDiagnostics, ParseNote};
+use crate::core::types::{CodeEntity, Rule};
+use crate::exit::Failure;
+use crate::parser::code_parser::{atomic_write, is_valid_id};
+use crate::paths::paths_match;
+use crate::schema::Artifact;
+use crate::vfs;
+use crate::yaml;
+
+const LINKS_FILE: &str = "links.yaml";
+
+/// Tamaño máximo de links.yaml para prevenir DoS (VUL-04).
+const MAX_LINKS_SIZE: u64 = 1024 * 1024;
+
+/// Enlace función → ID declarado fuera del código.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MappedLink {
+    /// Archivo relativo a la raíz del proyecto.
+    pub file: String,
+    /// Nombre de la función o método.
+    pub function: String,
+    pub doc_id: String,
+}
+
+/// Contenido de `.docsguard/links.yaml`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LinkMapping {
+    #[serde(default)]
+    pub links: Vec<MappedLink>,
+}
+
+/// Ruta del archivo de enlaces de un proyecto.
+pub fn links_path(project_root: &Path) -> PathBuf {
+    project_root.join(DOCSGUARD_DIR).join(LINKS_FILE)
+}
+
+impl LinkMapping {
+    /// Carga el mapeo de `project_root`; vacío si no existe.
+    pub fn load(project_root: &Path) -> Result<Self> {
+        let path = links_path(project_root);
+        if !vfs::exists(&path) {
+            return Ok(LinkMapping::default());
+        }
+
+        let content = yaml::read(&path, MAX_LINKS_SIZE, Failure::config)?;
+        Self::from_yaml(&content, &path).with_context(|| {
+            Failure::config(format!("Error al parsear el mapeo: {}", path.display()))
+                .with_path(&path)
+        })
+    }
+
+    /// Parsea el mapeo leído de `path`. Un documento vacío es un mapeo vacío.
+    ///
+    /// VUL-01: los IDs siguen las mismas reglas que en las anotaciones.
+    fn from_yaml(content: &str, path: &Path) -> Result<Self> {
+        if content.trim().is_empty() {
+            return Ok(LinkMapping::default());
+        }
+        let mapping: LinkMapping =
+            yaml::from_str_checked(content, Artifact::Links).map_err(|e| e.in_file(path))?;
+        if let Some(link) = mapping.links.iter().find(|l| !is_valid_id(&l.doc_id)) {
+            anyhow::bail!(
+                "ID inválido '{}' para {} en {}",
+                link.doc_id,
+                link.function,
+                link.file
+            );
+        }
+        Ok(mapping)
+    }
+
+    /// Guarda el mapeo con escritura atómica (VUL-02).
+    pub fn save(&self, project_root: &Path) -> Result<PathBuf> {
+        let dir = project_root.join(DOCSGUARD_DIR);
+        if !vfs::exists(&dir) {
+            vfs::create_dir_all(&dir)
+                .with_context(|| format!("No se pudo crear: {}", dir.display()))?;
+        }
+        let path = links_path(project_root);
+        let content = serde_yml::to_string(self).context("Error al serializar el mapeo")?;
+        atomic_write(&path, content.as_bytes())?;
+        Ok(path)
+    }
+
+    /// Añade o reemplaza el enlace de `function` en `file`.
+    /// Retorna `false` si ya existía exactamente igual.
+    pub fn upsert(&mut self, link: MappedLink) -> bool {
+        match self
+            .links
+            .iter_mut()
+            .find(|l| l.file == link.file && l.function == link.function)
+        {
+            Some(existing) if *existing == link => false,
+            Some(existing) => {
+                *existing = link;
+                true
+            }
+            None => {
+                self.links.push(link);
+                true
+            }
+        }
+    }
+
+    /// Aplica el mapeo a las entidades de `file` (ruta normalizada).
+    ///
+    /// Las entidades sin `@docs` propio reciben el ID mapeado; las entradas
+    /// cuya función ya no existe en el archivo se anotan como `stale-mapping`.
+    pub fn apply(
+        &self,
+        file: &Path,
+        entities: &mut [CodeEntity],
+        diagnostics: &mut ParseDiagnostics,
+    ) {
+        for link in self
+            .links
+            .iter()
+            .filter(|l| paths_match(file, Path::new(&l.file)))
+        {
+            let mut found = false;
+            for entity in entities.iter_mut().filter(|e| e.name == link.function) {
+                found = true;
+                if entity.doc_id.is_none() {
+                    entity.doc_id = Some(link.doc_id.clone());
+                }
+            }
+            if !found {
+                diagnostics.push(ParseNote {
+                    rule: Rule::StaleMapping,
+                    message: format!(
+                        "Enlace obsoleto en {}/{}: la función '{}' ya no existe en {}.",
+                        DOCSGUARD_DIR,
+                        LINKS_FILE,
+                        link.function,
+                        file.display()
+                    ),
+                    function_name: Some(link.function.clone()),
+                    code_location: None,
+                    doc_id: Some(link.doc_id.clone()),
+                    doc_location: None,
+                    hint: Some(format!(
+                        "Actualiza o elimina la entrada de '{}' en {}/{}.",
+                        link.function, DOCSGUARD_DIR, LINKS_FILE
+                    )),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::MemFs;
+
+    fn entity(name: &str, doc_id: Option<&str>) -> CodeEntity {
+        CodeEntity {
+            name: name.into(),
+            args: vec![],
+            return_type: None,
+            return_fields: None,
+            doc_id: doc_id.map(String::from),
+            file_path: PathBuf::from("vendor/billing.ts"),
+            line: 1,
+            end_line: 1,
+            is_public: true,
+            suppressions: vec![],
+            file_link: None,
+            examples: Vec::new(),
+        }
+    }
+
+    fn link(function: &str, doc_id: &str) -> MappedLink {
+        MappedLink {
+            file: "vendor/billing.ts".into(),
+            function: function.into(),
+            doc_id: doc_id.into(),
+        }
+    }
+
+    #[test]
+    fn mapping_round_trips_through_yaml() {
+        let dir = MemFs::project();
+        let mapping = LinkMapping {
+            links: vec![
+                link("charge", "billing-charge"),
+                link("refund", "billing-refund"),
+            ],
+        };
+        mapping.save(dir.path()).unwrap();
+        assert_eq!(LinkMapping::load(dir.path()).unwrap(), mapping);
+
+        let yaml = dir.contents(links_path(dir.path())).unwrap();
+        assert!(yaml.contains("function: charge"));
+        assert_eq!(
+            LinkMapping::load(&dir.path().join("missing")).unwrap(),
+            LinkMapping::default()
+        );
+    }
+
+    #[test]
+    fn in_source_annotations_beat_the_mapping() {
+        let mapping = LinkMapping {
+            links: vec![
+                link("charge", "billing-charge"),
+                link("refund", "billing-refund"),
+            ],
+        };
+        let mut entities = vec![
+            entity("charge", None),
+            entity("refund", Some("payments-refund")),
+        ];
+        let mut diagnostics = ParseDiagnostics::default();
+        mapping.apply(
+            Path::new("vendor/billing.ts"),
+            &mut entities,
+            &mut diagnostics,
+        );
+
+        assert_eq!(entities[0].doc_id.as_deref(), Some("billing-charge"));
+        assert_eq!(entities[1].doc_id.as_deref(), Some("payments-refund"));
+        assert!(diagnostics.notes.is_empty());
+    }
+
+    #[test]
+    fn entries_for_missing_functions_are_stale() {
+        let mapping = LinkMapping {
+            links: vec![
+                link("charge", "billing-charge"),
+                MappedLink {
+                    file: "src/other.ts".into(),
+                    ..link("gone", "billing-gone")
+                },
+                link("void_invoice", "billing-void"),
+            ],
+        };
+        let mut entities = vec![entity("charge", None)];
+        let mut diagnostics = ParseDiagnostics::default();
+        mapping.apply(
+            Path::new("vendor/billing.ts"),
+            &mut entities,
+            &mut diagnostics,
+        );
+
+        // Solo el archivo parseado: `src/other.ts` no se juzga
+        assert_eq!(diagnostics.notes.len(), 1);
+        assert_eq!(diagnostics.notes[0].rule, Rule::StaleMapping);
+        assert_eq!(
+            diagnostics.notes[0].function_name.as_deref(),
+            Some("void_invoice")
+        );
+    }
+
+    #[test]
+    fn upsert_replaces_the_entry_of_a_function() {
+        let mut mapping = LinkMapping::default();
+        assert!(mapping.upsert(link("charge", "billing-charge")));
+        assert!(!mapping.upsert(link("charge", "billing-charge")));
+        assert!(mapping.upsert(link("charge", "billing-v2")));
+        assert_eq!(mapping.links, vec![link("charge", "billing-v2")]);
+    }
+
+    #[test]
+    fn invalid_ids_are_rejected() {
+        let yaml = "links:\n  - file: a.ts\n    function: f\n    doc_id: \"bad id\"\n";
+        assert!(LinkMapping::from_yaml(yaml, Path::new("links.yaml")).is_err());
+    }
+
+    #[test]
+    fn repeated_links_keys_are_rejected_with_the_file_position() {
+        let yaml = "links:\n  - file: a.ts\n    function: f\n    doc_id: a\nlinks: []\n";
+        let err = LinkMapping::from_yaml(yaml, Path::new(".docsguard/links.yaml")).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with(".docsguard/links.yaml:5:1: clave `links` repetida"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,2755 @@
+//! Tabla de traducción al inglés.
+//!
+//! Cada entrada es `(formato canónico, traducción)`. El formato es el de
+//! `format!` tal como está en el código: `{…}` es un hueco y `{{`/`}}` una
+//! llave, así que un texto fijo con llaves (una pista con `{base_url: …}`) se
+//! escribe con ellas dobladas. En la traducción `{}` toma el siguiente hueco
+//! y `{1}` uno concreto, para cambiar el orden. Las entradas de varias líneas
+//! se traducen línea a línea y sin sangría: las dos mitades necesitan el
+//! mismo número de líneas. Los tests comprueban que no falte ningún texto de
+//! la ayuda, de la demo ni de los literales que el código pasa a `tr` y a las
+//! macros `*_tr!`.
+
+pub const TABLE: &[(&str, &str)] = &[
+    // Ayuda de la CLI (main.rs)
+    (
+        "Motor de Integridad de Documentación — elimina la deriva código-doc",
+        "Documentation Integrity Engine — eliminates code-doc drift",
+    ),
+    (
+        "Escribe los errores de ejecución como JSON en stdout (implícito en `assert --json`, `check --format json|sarif`, `config diff --json` y `suppressions list --format json`)",
+        "Writes runtime errors as JSON to stdout (implied by `assert --json`, `check --format json|sarif`, `config diff --json` and `suppressions list --format json`)",
+    ),
+    (
+        "No envuelve las ubicaciones `archivo:línea` en hipervínculos del terminal (OSC 8)",
+        "Does not wrap `file:line` locations in terminal hyperlinks (OSC 8)",
+    ),
+    (
+        "Salida sin colores (también con la variable `NO_COLOR` o si stdout no es un terminal)",
+        "Output without colours (also with the `NO_COLOR` variable or when stdout is not a terminal)",
+    ),
+    (
+        "Configuración que se usa en lugar de `.docsguard/config.yaml` (también la variable `DOCSGUARD_CONFIG`)",
+        "Configuration used instead of `.docsguard/config.yaml` (also the `DOCSGUARD_CONFIG` variable)",
+    ),
+    (
+        "Fija una opción escalar encima de la configuración (`--set rules.type-mismatch=off`); repetible",
+        "Sets a scalar option on top of the configuration (`--set rules.type-mismatch=off`); repeatable",
+    ),
+    (
+        "No valida ni propone las funciones cuyo nombre encaja con el glob (`--ignore-fn \"test_*\"`); se suma a `ignore_functions` y es repetible",
+        "Neither validates nor proposes functions whose name matches the glob (`--ignore-fn \"test_*\"`); adds to `ignore_functions` and is repeatable",
+    ),
+    (
+        "Idioma de la salida (también la variable `DOCSGUARD_LANG`); los archivos que escribe DocsGuard no cambian",
+        "Output language (also the `DOCSGUARD_LANG` variable); the files DocsGuard writes do not change",
+    ),
+    (
+        "Lenguaje del código leído de stdin cuando el archivo de código es `-`",
+        "Language of the code read from stdin when the code file is `-`",
+    ),
+    (
+        "Verifica que los enlaces entre código y documentación sean válidos",
+        "Checks that the links between code and documentation are valid",
+    ),
+    ("Archivo de documentación (Markdown)", "Documentation file (Markdown)"),
+    (
+        "Archivo de documentación (Markdown), directorio o glob (`\"docs/**/*.md\"`); en estos se omiten los archivos que no son Markdown",
+        "Documentation file (Markdown), directory or glob (`\"docs/**/*.md\"`); files that are not Markdown are skipped in these",
+    ),
+    (
+        "Archivos de código fuente, directorios o globs (`\"src/**/*.ts\"`); en estos se omiten los archivos sin lenguaje soportado",
+        "Source files, directories or globs (`\"src/**/*.ts\"`); files without a supported language are skipped in these",
+    ),
+    (
+        "Directorio raíz del proyecto (para buscar baseline)",
+        "Project root directory (to look for the baseline)",
+    ),
+    (
+        "Aplica las correcciones automáticas disponibles antes de reportar",
+        "Applies the available automatic fixes before reporting",
+    ),
+    (
+        "Reporta solo hallazgos en archivos cambiados desde esta referencia git",
+        "Reports only findings in files changed since this git reference",
+    ),
+    (
+        "Compara los resultados esperados de los ejemplos con el tipo de retorno (heurístico)",
+        "Compares the expected results of examples with the return type (heuristic)",
+    ),
+    (
+        "Comprueba que los ejemplos solo importen y llamen a símbolos exportados del proyecto",
+        "Checks that examples only import and call symbols exported by the project",
+    ),
+    (
+        "Compara las versiones \"desde\" de las secciones con la del proyecto (`versions:` en config)",
+        "Compares the sections' \"since\" versions with the project's (`versions:` in config)",
+    ),
+    (
+        "Reporta como posible errata un argumento fantasma casi igual a uno sin documentar",
+        "Reports a ghost argument almost equal to an undocumented one as a possible typo",
+    ),
+    (
+        "Muestra también los enlaces verificados y los detalles de cada hallazgo (p. ej. la estrategia que extrajo el argumento)",
+        "Also shows verified links and the details of each finding (e.g. the strategy that extracted the argument)",
+    ),
+    (
+        "Imprime solo los errores y el resumen, que sigue contando todos los hallazgos",
+        "Prints only the errors and the summary, which still counts every finding",
+    ),
+    (
+        "Reporta como Info (reglas DGxxx) las ambigüedades que el parser resuelve en silencio",
+        "Reports the ambiguities the parser resolves silently as Info (DGxxx rules)",
+    ),
+    (
+        "Con --strict, reporta las ambigüedades como Warning",
+        "With --strict, reports the ambiguities as Warning",
+    ),
+    (
+        "Reporta (Info) las supresiones en línea y entradas del baseline que no silenciaron nada",
+        "Reports (Info) the inline suppressions and baseline entries that silenced nothing",
+    ),
+    (
+        "Como --report-unused-suppressions, pero como Error",
+        "Like --report-unused-suppressions, but as Error",
+    ),
+    (
+        "Preset de niveles por regla; los `rules:` de config.yaml ganan sobre él",
+        "Preset of per-rule levels; the `rules:` in config.yaml win over it",
+    ),
+    (
+        "Muestra rutas absolutas en lugar de relativas a --project-root",
+        "Shows absolute paths instead of paths relative to --project-root",
+    ),
+    (
+        "Advierte (regla slow-run) si la ejecución tarda más de estos segundos",
+        "Warns (slow-run rule) if the run takes longer than these seconds",
+    ),
+    (
+        "Con --time-budget, sale con código 1 si se supera el presupuesto",
+        "With --time-budget, exits with code 1 if the budget is exceeded",
+    ),
+    (
+        "Severidad a partir de la cual sale con código 1 (`never`: solo informa)",
+        "Severity from which it exits with code 1 (`never`: report only)",
+    ),
+    (
+        "Re-parsea solo los archivos de código cambiados desde la última ejecución con --fast",
+        "Re-parses only the code files changed since the last run with --fast",
+    ),
+    (
+        "Ejecución completa aunque se indique --fast (gana la última de las dos)",
+        "Full run even if --fast is given (the last of the two wins)",
+    ),
+    (
+        "Explica por qué el baseline no filtró cada hallazgo: entrada más parecida y campos distintos (en `baseline_debug` con --format json)",
+        "Explains why the baseline did not filter each finding: closest entry and differing fields (under `baseline_debug` with --format json)",
+    ),
+    (
+        "Comprueba con peticiones HEAD que las secciones existen en el sitio publicado (`site:` en config; feature verify-site)",
+        "Checks with HEAD requests that the sections exist on the published site (`site:` in config; verify-site feature)",
+    ),
+    (
+        "Con --verify-site, comprueba solo N secciones repartidas por el archivo",
+        "With --verify-site, checks only N sections spread across the file",
+    ),
+    (
+        "Error por cada función pública añadida desde REF (por defecto, la de --changed-since) sin enlace a docs",
+        "Error for every public function added since REF (by default, the --changed-since one) without a docs link",
+    ),
+    (
+        "Compara las secciones con sus atestaciones de `.docsguard/attestations.yaml` (`attestation_required` en config)",
+        "Compares the sections with their attestations in `.docsguard/attestations.yaml` (`attestation_required` in config)",
+    ),
+    (
+        "Tras el resumen, muestra la antigüedad del baseline y las entradas que caducan en DAYS días (30 por defecto; en `debt_age` con --format json)",
+        "After the summary, shows the age of the baseline and the entries that expire within DAYS days (30 by default; under `debt_age` with --format json)",
+    ),
+    (
+        "`sarif`: log SARIF 2.1.0 (code scanning); `json`: hallazgos y cobertura por argumento de cada enlace; `markdown`: documento con una tabla por severidad. Van a stdout y el resto de la salida, a stderr",
+        "`sarif`: SARIF 2.1.0 log (code scanning); `json`: findings and per-argument coverage of each link; `markdown`: document with a table per severity. They go to stdout and the rest of the output to stderr",
+    ),
+    (
+        "Hallazgos y resumen para el terminal",
+        "Findings and summary for the terminal",
+    ),
+    (
+        "Log SARIF 2.1.0 para el code scanning, en stdout",
+        "SARIF 2.1.0 log for code scanning, on stdout",
+    ),
+    (
+        "Informe JSON con los hallazgos y la cobertura por argumento de cada enlace, en stdout",
+        "JSON report with the findings and the per-argument coverage of each link, on stdout",
+    ),
+    (
+        "Documento Markdown con el resumen y una tabla por severidad, en stdout",
+        "Markdown document with the summary and a table per severity, on stdout",
+    ),
+    (
+        "Escribe el documento Markdown de `--format markdown` en FILE; sin -v, no imprime nada más",
+        "Writes the `--format markdown` document to FILE; without -v, prints nothing else",
+    ),
+    (
+        "Lee el código de esta revisión git en lugar del árbol de trabajo (las docs, del árbol)",
+        "Reads the code at this git revision instead of the working tree (the docs, from the tree)",
+    ),
+    (
+        "Lee las docs de esta revisión git en lugar del árbol de trabajo",
+        "Reads the docs at this git revision instead of the working tree",
+    ),
+    (
+        "Usa `.docsguard/baseline-NAME.yaml` en lugar de `baseline.yaml`",
+        "Uses `.docsguard/baseline-NAME.yaml` instead of `baseline.yaml`",
+    ),
+    (
+        "Docs, código, revisiones y baseline de la combinación NAME de `combos:` en config",
+        "Docs, code, revisions and baseline of the NAME combination from `combos:` in config",
+    ),
+    (
+        "No consulta git para indicar la probable causa de los errores nuevos",
+        "Does not query git for the probable cause of new errors",
+    ),
+    (
+        "Incluye la probable causa (commit, autor) también en SARIF",
+        "Also includes the probable cause (commit, author) in SARIF",
+    ),
+    (
+        "Muestra lo que DocsGuard extrae de un archivo de docs o de código",
+        "Shows what DocsGuard extracts from a docs or code file",
+    ),
+    (
+        "Archivo de documentación (Markdown) o de código",
+        "Documentation (Markdown) or code file",
+    ),
+    (
+        "Directorio raíz del proyecto (configuración)",
+        "Project root directory (configuration)",
+    ),
+    (
+        "Lista las funciones y secciones extraídas y qué pares resolvieron entre sí",
+        "Lists the extracted functions and sections and which pairs resolved to each other",
+    ),
+    ("Archivos de código fuente", "Source files"),
+    (
+        "Muestra solo las funciones y secciones que no resolvieron",
+        "Shows only the functions and sections that did not resolve",
+    ),
+    (
+        "Falla si alguno de los IDs indicados no tiene sección o enlace (checklists de release)",
+        "Fails if any of the given IDs has no section or link (release checklists)",
+    ),
+    (
+        "ID de documentación que debe existir y estar enlazado (repetible)",
+        "Documentation ID that must exist and be linked (repeatable)",
+    ),
+    (
+        "Archivo con un ID por línea (`#` para comentarios)",
+        "File with one ID per line (`#` for comments)",
+    ),
+    (
+        "Falla también si algún ID tiene hallazgos de severidad Error",
+        "Also fails if any ID has findings of Error severity",
+    ),
+    ("Emite el resultado como JSON", "Emits the result as JSON"),
+    (
+        "Directorio raíz del proyecto (baseline y configuración)",
+        "Project root directory (baseline and configuration)",
+    ),
+    (
+        "Escribe un informe HTML autocontenido de los hallazgos (escala a decenas de miles)",
+        "Writes a self-contained HTML report of the findings (scales to tens of thousands)",
+    ),
+    ("Archivo HTML de salida", "Output HTML file"),
+    (
+        "Omite las descripciones documentadas de los argumentos (se conservan nombres y tipos)",
+        "Omits the documented argument descriptions (names and types are kept)",
+    ),
+    (
+        "Omite de los informes de `--format` y `--report` las descripciones documentadas de los argumentos (se conservan nombres y tipos)",
+        "Omits the documented argument descriptions from the `--format` and `--report` reports (names and types are kept)",
+    ),
+    (
+        "Muestra cada hallazgo Info por separado aunque config.yaml tenga `info_aggregation: summarize`",
+        "Shows each Info finding separately even if config.yaml has `info_aggregation: summarize`",
+    ),
+    ("Integraciones de CI listas para usar", "Ready-to-use CI integrations"),
+    (
+        "GitHub Actions: anotaciones, resumen del paso y comentario fijo en la PR",
+        "GitHub Actions: annotations, step summary and a sticky PR comment",
+    ),
+    (
+        "Directorio raíz del proyecto (baseline y repositorio git)",
+        "Project root directory (baseline and git repository)",
+    ),
+    (
+        "Referencia base del diff (por defecto `origin/$GITHUB_BASE_REF`)",
+        "Base reference of the diff (by default `origin/$GITHUB_BASE_REF`)",
+    ),
+    (
+        "Crea o actualiza un comentario fijo con el resumen en la PR",
+        "Creates or updates a sticky comment with the summary on the PR",
+    ),
+    (
+        "Recorre los hallazgos uno a uno: editor, corrección, baseline o supresión",
+        "Walks through the findings one by one: editor, fix, baseline or suppression",
+    ),
+    (
+        "Directorio raíz del proyecto (baseline)",
+        "Project root directory (baseline)",
+    ),
+    (
+        "Scaffold interactivo: sugiere enlaces código ↔ docs con confirmación",
+        "Interactive scaffold: suggests code ↔ docs links with confirmation",
+    ),
+    ("Archivo de código fuente", "Source file"),
+    ("Archivo de documentación", "Documentation file"),
+    (
+        "No escribir cambios al disco (solo mostrar sugerencias)",
+        "Do not write changes to disk (only show suggestions)",
+    ),
+    (
+        "Aceptar todas las sugerencias sin preguntar",
+        "Accept every suggestion without asking",
+    ),
+    (
+        "Registra los enlaces en .docsguard/links.yaml sin modificar el código",
+        "Records the links in .docsguard/links.yaml without modifying the code",
+    ),
+    (
+        "Directorio raíz del proyecto (configuración y links.yaml)",
+        "Project root directory (configuration and links.yaml)",
+    ),
+    (
+        "Recorrido de la heurística: `indexed` (por defecto) o `exhaustive` (todos los pares, para depurar)",
+        "Heuristic traversal: `indexed` (default) or `exhaustive` (every pair, for debugging)",
+    ),
+    (
+        "Ofrece una sección esqueleto con un ID nuevo (`id_style`) para cada función que quede sin enlace",
+        "Offers a skeleton section with a new ID (`id_style`) for every function left unlinked",
+    ),
+    (
+        "Informe de la sesión (`.md` o `.json`); por defecto `.docsguard/scaffold-report-<timestamp>.md`",
+        "Session report (`.md` or `.json`); by default `.docsguard/scaffold-report-<timestamp>.md`",
+    ),
+    (
+        "Sin preguntar: escribe en DIR un parche para `git apply` por enlace propuesto",
+        "Without asking: writes one `git apply` patch per proposed link to DIR",
+    ),
+    (
+        "Con --emit-patch, un solo parche (`scaffold.patch`) con todos los enlaces",
+        "With --emit-patch, a single patch (`scaffold.patch`) with every link",
+    ),
+    (
+        "Con --emit-patch, confianza mínima (0 a 1) de los enlaces que se emiten",
+        "With --emit-patch, minimum confidence (0 to 1) of the emitted links",
+    ),
+    (
+        "Aplica sin preguntar los enlaces heurísticos de alta confianza (para CI)",
+        "Applies high-confidence heuristic links without prompting (for CI)",
+    ),
+    (
+        "Confianza mínima (0 a 1) de los enlaces que se aplican",
+        "Minimum confidence (0 to 1) of the links that are applied",
+    ),
+    (
+        "Muestra los cambios como diff unificado sin escribirlos",
+        "Shows the changes as a unified diff without writing them",
+    ),
+    (
+        "Observa cambios en archivos y re-valida automáticamente",
+        "Watches files for changes and re-validates automatically",
+    ),
+    (
+        "Silencio (ms) que cierra una ráfaga de cambios antes de validar",
+        "Quiet time (ms) that closes a burst of changes before validating",
+    ),
+    (
+        "Espera máxima (ms) de una ráfaga antes de validar igualmente",
+        "Maximum wait (ms) for a burst before validating anyway",
+    ),
+    (
+        "Muestra también los hallazgos Info y desglosa el tiempo de cada validación (parseo de código y docs, validación, render)",
+        "Also shows Info findings and breaks down the time of each validation (code and docs parsing, validation, render)",
+    ),
+    (
+        "Muestra solo los errores; el resumen sigue contando las advertencias",
+        "Shows only the errors; the summary still counts the warnings",
+    ),
+    (
+        "Ofrece corregir desde el teclado los hallazgos nuevos con corrección sugerida (`f` aplica, `n`/`p` cambian de hallazgo)",
+        "Offers to fix new findings that have a suggested fix from the keyboard (`f` applies, `n`/`p` switch findings)",
+    ),
+    (
+        "Registra que alguien revisó una sección: guarda el hash de su contenido en `.docsguard/attestations.yaml`",
+        "Records that someone reviewed a section: stores the hash of its content in `.docsguard/attestations.yaml`",
+    ),
+    ("ID de la sección atestada", "ID of the attested section"),
+    (
+        "Archivo de documentación (Markdown) con la sección",
+        "Documentation file (Markdown) with the section",
+    ),
+    ("Quién atesta (`@maria`)", "Who attests (`@maria`)"),
+    ("Directorio raíz del proyecto", "Project root directory"),
+    (
+        "Valida las anotaciones del propio DocsGuard (`src/**/*.rs`) contra `docs/architecture.md`",
+        "Validates DocsGuard's own annotations (`src/**/*.rs`) against `docs/architecture.md`",
+    ),
+    ("Raíz del repositorio de DocsGuard", "Root of the DocsGuard repository"),
+    (
+        "Comprueba los parsers de cada lenguaje con el corpus de anotaciones embebido en el binario",
+        "Checks every language parser against the annotation corpus embedded in the binary",
+    ),
+    (
+        "Funciones y secciones añadidas, eliminadas o cambiadas entre dos revisiones git",
+        "Functions and sections added, removed or changed between two git revisions",
+    ),
+    ("Revisión de partida", "Starting revision"),
+    (
+        "Revisión final (por defecto, el árbol de trabajo)",
+        "Final revision (by default, the working tree)",
+    ),
+    ("Formato de salida", "Output format"),
+    (
+        "Exporta el modelo enlazado (secciones, funciones, estado de cada argumento) para generadores de docs",
+        "Exports the linked model (sections, functions, status of each argument) for docs generators",
+    ),
+    (
+        "Directorio raíz del proyecto (repositorio git y configuración)",
+        "Project root directory (git repository and configuration)",
+    ),
+    (
+        "Vuelca los errores actuales al baseline para \"Green Build Day 1\"",
+        "Dumps the current errors into the baseline for \"Green Build Day 1\"",
+    ),
+    (
+        "No añade hallazgos: quita las entradas de estos archivos que ya no filtran nada",
+        "Adds no findings: removes the entries of these files that no longer filter anything",
+    ),
+    (
+        "Con --prune, actualiza el archivo de las entradas cuya función se movió a otro",
+        "With --prune, updates the file of the entries whose function moved to another one",
+    ),
+    (
+        "Escribe `.docsguard/baseline-NAME.yaml` en lugar de `baseline.yaml`",
+        "Writes `.docsguard/baseline-NAME.yaml` instead of `baseline.yaml`",
+    ),
+    (
+        "Lee el código de esta revisión git en lugar del árbol de trabajo",
+        "Reads the code at this git revision instead of the working tree",
+    ),
+    (
+        "Lista todo lo que se está silenciando: supresiones, baseline, excepciones y marcadores",
+        "Lists everything being silenced: suppressions, baseline, exceptions and markers",
+    ),
+    (
+        "Cada supresión activa con su ubicación, motivo, antigüedad y si silenció algo",
+        "Each active suppression with its location, reason, age and whether it silenced anything",
+    ),
+    ("Formato de la salida", "Output format"),
+    (
+        "Solo las de este mecanismo (repetible)",
+        "Only those of this mechanism (repeatable)",
+    ),
+    ("Orden de la lista", "Order of the list"),
+    (
+        "No consulta `git blame` para fechar directivas y marcadores",
+        "Does not query `git blame` to date directives and markers",
+    ),
+    (
+        "Directorio raíz del proyecto (baseline, configuración y repositorio git)",
+        "Project root directory (baseline, configuration and git repository)",
+    ),
+    (
+        "Genera `.docsguard/config.yaml` con los niveles de un preset expandidos",
+        "Generates `.docsguard/config.yaml` with the levels of a preset expanded",
+    ),
+    ("Preset que se escribe regla a regla", "Preset written rule by rule"),
+    (
+        "Sobrescribe una configuración existente",
+        "Overwrites an existing configuration",
+    ),
+    (
+        "Escribe un proyecto de ejemplo, lo verifica con `check` y comenta cada hallazgo",
+        "Writes a sample project, checks it with `check` and comments on each finding",
+    ),
+    (
+        "Directorio del ejemplo (por defecto, uno nuevo en el temporal del sistema)",
+        "Sample directory (by default, a new one in the system temp directory)",
+    ),
+    (
+        "Muestra el nivel efectivo de cada regla y de dónde sale",
+        "Shows the effective level of each rule and where it comes from",
+    ),
+    (
+        "Preset que se aplicaría con `check --preset`",
+        "Preset that `check --preset` would apply",
+    ),
+    (
+        "Operaciones sobre `.docsguard/config.yaml`",
+        "Operations on `.docsguard/config.yaml`",
+    ),
+    (
+        "Muestra la configuración en uso; con `--resolved`, la efectiva con los valores por defecto",
+        "Shows the configuration in use; with `--resolved`, the effective one with the defaults",
+    ),
+    (
+        "Incluye los `--set`, el preset y los valores por defecto de cada opción",
+        "Includes the `--set` values, the preset and the default of each option",
+    ),
+    (
+        "Muestra qué hallazgos cambiarían (y el código de salida) con otra configuración",
+        "Shows which findings (and exit code) would change with another configuration",
+    ),
+    (
+        "Configuración con la que comparar la actual",
+        "Configuration to compare the current one with",
+    ),
+    (
+        "Compara con `.docsguard/config.yaml` en esta referencia git",
+        "Compares with `.docsguard/config.yaml` at this git reference",
+    ),
+    (
+        "Directorio raíz del proyecto (configuración y baseline)",
+        "Project root directory (configuration and baseline)",
+    ),
+    (
+        "Preset que se aplica debajo de las dos configuraciones",
+        "Preset applied beneath both configurations",
+    ),
+    (
+        "Muestra el porcentaje de funciones públicas con anotación @docs",
+        "Shows the percentage of public functions with a @docs annotation",
+    ),
+    ("Archivos de código fuente a analizar", "Source files to analyze"),
+    (
+        "Cobertura mínima requerida (0-100). Sale con código 1 si no se alcanza",
+        "Minimum required coverage (0-100). Exits with code 1 if it is not reached",
+    ),
+    (
+        "Archivo de docs: cuenta los enlaces con secciones prose-only (`@docs-skip: args`)",
+        "Docs file: counts the links to prose-only sections (`@docs-skip: args`)",
+    ),
+    (
+        "Compara con una cobertura guardada con --json (p. ej. la de la rama principal)",
+        "Compares with a coverage saved with --json (e.g. the main branch's)",
+    ),
+    (
+        "Emite la cobertura (o, con --compare-to, la diferencia) como JSON",
+        "Emits the coverage (or, with --compare-to, the difference) as JSON",
+    ),
+    (
+        "Con --compare-to, emite la diferencia como fragmento Markdown para la PR",
+        "With --compare-to, emits the difference as a Markdown snippet for the PR",
+    ),
+    (
+        "Imprime el esquema JSON de un archivo de `.docsguard/`",
+        "Prints the JSON schema of a `.docsguard/` file",
+    ),
+    ("Archivo cuyo esquema se imprime", "File whose schema is printed"),
+    (
+        "Muestra la versión; con `--verbose`, commit, target, features y gramáticas",
+        "Shows the version; with `--verbose`, commit, target, features and grammars",
+    ),
+    (
+        "Datos de compilación (también `docsguard --version --verbose`)",
+        "Build details (also `docsguard --version --verbose`)",
+    ),
+    // Códigos de salida (exit.rs)
+    (
+        "Códigos de salida:
+  0  Sin hallazgos que hagan fallar la ejecución
+  1  Hallazgos por encima del umbral (errores o --fail-on, cobertura mínima, IDs de assert, --fail-on-slow)
+  2  Uso, argumentos o configuración inválidos
+  3  Archivo no encontrado, sin lenguaje soportado, ilegible o imposible de parsear
+  4  Error interno",
+        "Exit codes:
+  0  No findings that fail the run
+  1  Findings above the threshold (errors or --fail-on, minimum coverage, assert IDs, --fail-on-slow)
+  2  Invalid usage, arguments or configuration
+  3  File not found, unsupported language, unreadable or impossible to parse
+  4  Internal error",
+    ),
+    // Hallazgos (core/types.rs)
+    ("{} {} ({}) en fn {} ({})", "{} {} ({}) in fn {} ({})"),
+    ("{} {} ({}) en fn {}", "{} {} ({}) in fn {}"),
+    ("{} {} ({}) en {}", "{} {} ({}) in {}"),
+    ("-> ID vinculado: '{}' ({})", "-> Linked ID: '{}' ({})"),
+    ("-> ID vinculado: '{}'", "-> Linked ID: '{}'"),
+    ("-> Insertar en: {}", "-> Insert at: {}"),
+    ("Insertar en: {}", "Insert at: {}"),
+    ("Punto de inserción", "Insertion point"),
+    ("Origen: {}", "Source: {}"),
+    ("Sugerencia: {}", "Suggestion: {}"),
+    (
+        "Probable causa: {} '{}' por {} {}; las docs cambiaron después, en {}",
+        "Probable cause: {} '{}' by {} {}; the docs changed afterwards, in {}",
+    ),
+    (
+        "Probable causa: {} '{}' por {} {}; las docs no cambiaron desde entonces",
+        "Probable cause: {} '{}' by {} {}; the docs have not changed since",
+    ),
+    ("hace {}", "{} ago"),
+    ("hace un momento", "a moment ago"),
+    ("documentado vía {}, línea {}", "documented via {}, line {}"),
+    ("documentado vía {}", "documented via {}"),
+    ("tabla", "table"),
+    ("lista", "list"),
+    ("definición", "definition"),
+    ("comentario", "comment"),
+    // core/validator.rs
+    (
+        "Función sin anotación @docs — no está vinculada a documentación.",
+        "Function without a @docs annotation — it is not linked to documentation.",
+    ),
+    (
+        "Añade `/// @docs: [id]` antes de la función para vincularla.",
+        "Add `/// @docs: [id]` before the function to link it.",
+    ),
+    (
+        "ID de documentación '{}' no encontrado en el archivo de docs.",
+        "Documentation ID '{}' not found in the docs file.",
+    ),
+    (
+        "Añade `<!-- @docs-id: {} -->` en el archivo de documentación.",
+        "Add `<!-- @docs-id: {} -->` to the documentation file.",
+    ),
+    (
+        "Enlace verificado: fn {} <-> sección '{}'",
+        "Link verified: fn {} <-> section '{}'",
+    ),
+    (
+        "Sección de documentación '{}' no está vinculada desde ninguna función.",
+        "Documentation section '{}' is not linked from any function.",
+    ),
+    (
+        "Añade `/// @docs: [{}]` antes de la función correspondiente en el código.",
+        "Add `/// @docs: [{}]` before the matching function in the code.",
+    ),
+    (
+        "La sección '{}' está marcada como no-vinculable (@docs-no-link), pero fn {} la enlaza.",
+        "Section '{}' is marked as unlinkable (@docs-no-link), but fn {} links it.",
+    ),
+    (
+        "Enlaza la función con su propia sección; la marcada es una plantilla, no documentación.",
+        "Link the function to its own section; the marked one is a template, not documentation.",
+    ),
+    (
+        "La sección '{}' declara `@expects: {}`, pero fn {} no tiene anotación @docs.",
+        "Section '{}' declares `@expects: {}`, but fn {} has no @docs annotation.",
+    ),
+    (
+        "Añade `{}` antes de la función (o acéptalo en `docsguard scaffold`).",
+        "Add `{}` before the function (or accept it in `docsguard scaffold`).",
+    ),
+    (
+        "La sección '{}' declara `@expects: {}`, pero fn {} está anotada con '{}'.",
+        "Section '{}' declares `@expects: {}`, but fn {} is annotated with '{}'.",
+    ),
+    (
+        "Cambia la anotación a `@docs: [{}]` o corrige `@expects` en la sección.",
+        "Change the annotation to `@docs: [{}]` or fix `@expects` in the section.",
+    ),
+    (
+        "La sección '{}' declara `@expects: {}`, pero esa función no existe en el código analizado; la más parecida es '{}'.",
+        "Section '{}' declares `@expects: {}`, but that function does not exist in the analyzed code; the closest is '{}'.",
+    ),
+    (
+        "Corrige `@expects` (¿`@expects: {}`, en {}?) o el nombre de la función.",
+        "Fix `@expects` (`@expects: {}`, in {}?) or the function name.",
+    ),
+    (
+        "La sección '{}' declara `@expects: {}`, pero esa función no existe en el código analizado.",
+        "Section '{}' declares `@expects: {}`, but that function does not exist in the analyzed code.",
+    ),
+    (
+        "Corrige `@expects` o incluye el archivo de la función en el análisis.",
+        "Fix `@expects` or include the function's file in the analysis.",
+    ),
+    (
+        "Enlace de archivo verificado: {} <-> sección '{}'",
+        "File link verified: {} <-> section '{}'",
+    ),
+    (
+        "ID de documentación '{}' (enlace de archivo) no encontrado en el archivo de docs.",
+        "Documentation ID '{}' (file link) not found in the docs file.",
+    ),
+    (
+        "Tipos de '{}' comparados como texto literal: código '{}', docs '{}'.",
+        "Types of '{}' compared as literal text: code '{}', docs '{}'.",
+    ),
+    (
+        "La descripción de '{}' es de relleno: \"{}\".",
+        "The description of '{}' is a placeholder: \"{}\".",
+    ),
+    ("Describe qué espera '{}'.", "Describe what '{}' expects."),
+    (
+        "La versión '{}' de la sección '{}' no es semver válido.",
+        "Version '{}' of section '{}' is not valid semver.",
+    ),
+    (
+        "Usa `MAJOR.MINOR[.PATCH]`, p. ej. `v2.3`.",
+        "Use `MAJOR.MINOR[.PATCH]`, e.g. `v2.3`.",
+    ),
+    (
+        "La sección '{}' documenta {} pero el proyecto está en {}.",
+        "Section '{}' documents {} but the project is at {}.",
+    ),
+    (
+        "Corrige la versión o actualiza la fuente de `versions` tras publicar.",
+        "Fix the version or update the `versions` source after publishing.",
+    ),
+    (
+        "La sección '{}' documenta {}, que no figura entre las versiones publicadas.",
+        "Section '{}' documents {}, which is not among the published versions.",
+    ),
+    (
+        "Corrige la versión o añádela a `versions.known`.",
+        "Fix the version or add it to `versions.known`.",
+    ),
+    (
+        "La sección '{}' documenta {} y fn {} tiene {}: no se comparan uno a uno.",
+        "Section '{}' documents {} and fn {} has {}: they are not compared one by one.",
+    ),
+    (
+        "Comprueba que el marcador `@docs-id` no quedó sobre una lista o tabla que no documenta la función.",
+        "Check that the `@docs-id` marker did not end up above a list or table that does not document the function.",
+    ),
+    (
+        "Elimina '{}' de la documentación o añádelo a la firma de la función.",
+        "Remove '{}' from the documentation or add it to the function signature.",
+    ),
+    (
+        "Rango mal formado en la descripción de '{}': {} (el mínimo supera al máximo).",
+        "Malformed range in the description of '{}': {} (the minimum exceeds the maximum).",
+    ),
+    (
+        "Escribe el rango como mínimo–máximo.",
+        "Write the range as minimum–maximum.",
+    ),
+    (
+        "Rango mal formado en el comentario de '{}' en fn {}: {} (el mínimo supera al máximo).",
+        "Malformed range in the comment of '{}' in fn {}: {} (the minimum exceeds the maximum).",
+    ),
+    (
+        "Documenta el argumento '{}' en la sección '{}'.",
+        "Document argument '{}' in section '{}'.",
+    ),
+    (
+        "'{}' (docs) se toma por '{}' (fn {}) con arg_names.matching: {}.",
+        "'{}' (docs) is taken as '{}' (fn {}) with arg_names.matching: {}.",
+    ),
+    (
+        "Para una sola convención, documenta el argumento como '{}'.",
+        "For a single convention, document the argument as '{}'.",
+    ),
+    (
+        "Unidades distintas para '{}': la documentación dice {} y el comentario del código {}.",
+        "Different units for '{}': the documentation says {} and the code comment {}.",
+    ),
+    (
+        "Unifica la unidad de '{}' en las docs o en el comentario de la función.",
+        "Align the unit of '{}' in the docs or in the function comment.",
+    ),
+    (
+        "Las secciones de '{}' se contradicen en {} del argumento '{}'.",
+        "The sections of '{}' contradict each other on {} of argument '{}'.",
+    ),
+    ("el tipo", "the type"),
+    ("si es opcional", "whether it is optional"),
+    ("el tipo y si es opcional", "the type and whether it is optional"),
+    (
+        "Unifica '{}' en todas las secciones (el código tiene '{}').",
+        "Align '{}' across every section (the code has '{}').",
+    ),
+    (
+        "Unifica '{}' en todas las secciones.",
+        "Align '{}' across every section.",
+    ),
+    ("'{}': tipo '{}', opcional", "'{}': type '{}', optional"),
+    ("'{}': tipo '{}', obligatorio", "'{}': type '{}', required"),
+    ("'{}': tipo '{}'", "'{}': type '{}'"),
+    ("'{}': sin tipo, opcional", "'{}': untyped, optional"),
+    ("'{}': sin tipo, obligatorio", "'{}': untyped, required"),
+    ("'{}': sin tipo", "'{}': untyped"),
+    ("sin tipo", "untyped"),
+    (
+        "Argumento fantasma: '{}' está documentado pero no existe en fn {}.",
+        "Ghost argument: '{}' is documented but does not exist in fn {}.",
+    ),
+    (
+        "El argumento '{}' existe en código pero falta en la documentación.",
+        "Argument '{}' exists in code but is missing from the documentation.",
+    ),
+    (
+        "Posible typo en docs: '{}' ≈ '{}' (fn {}).",
+        "Possible typo in docs: '{}' ≈ '{}' (fn {}).",
+    ),
+    (
+        "Type mismatch en argumento '{}': código tiene '{}', docs dice '{}'.",
+        "Type mismatch in argument '{}': code has '{}', docs say '{}'.",
+    ),
+    (
+        "Renombra '{}' a '{}' en la documentación (`check --fix` lo corrige).",
+        "Rename '{}' to '{}' in the documentation (`check --fix` fixes it).",
+    ),
+    (
+        "Los argumentos documentados coinciden con fn {} — posible sección copiada sin actualizar.",
+        "The documented arguments match fn {} — possibly a section copied without updating it.",
+    ),
+    (
+        "Documenta en '{}' los argumentos de fn {} en lugar de los de fn {}.",
+        "Document in '{}' the arguments of fn {} instead of those of fn {}.",
+    ),
+    (
+        "ID de documentación '{}' definido en dos archivos: {} y {}.",
+        "Documentation ID '{}' defined in two files: {} and {}.",
+    ),
+    (
+        "Renombra uno de los dos `@docs-id`: cada ID debe vivir en un solo archivo de docs.",
+        "Rename one of the two `@docs-id`s: each ID must live in a single docs file.",
+    ),
+    ("Primera definición de '{}'", "First definition of '{}'"),
+    (
+        "Sección de documentación '{}' de {} no está vinculada desde ninguna función.",
+        "Documentation section '{}' in {} is not linked from any function.",
+    ),
+    ("Falta el código que verificar.", "Missing the code to check."),
+    (
+        "Con --doc, todos los posicionales son código: `docsguard check --doc docs/auth.md,docs/users.md src/`.",
+        "With --doc, every positional is code: `docsguard check --doc docs/auth.md,docs/users.md src/`.",
+    ),
+    (
+        "Archivos, directorios o globs de documentación (repetible o separados por comas); con --doc, todos los posicionales son código",
+        "Documentation files, directories or globs (repeatable or comma-separated); with --doc, every positional is code",
+    ),
+    (
+        "Actualiza el tipo de '{}' en la documentación a '{}' (o verifica si es un alias válido).",
+        "Update the type of '{}' in the documentation to '{}' (or check whether it is a valid alias).",
+    ),
+    (
+        "'{}' (docs) equivale a '{}' (fn {}) según arg_exceptions: {}.",
+        "'{}' (docs) is equivalent to '{}' (fn {}) according to arg_exceptions: {}.",
+    ),
+    (
+        "solo queda el argumento fantasma '{}'; '{}' no falta en las docs",
+        "only the ghost argument '{}' remains; '{}' is not missing from the docs",
+    ),
+    (
+        "solo falta '{}' en las docs; '{}' no es un argumento fantasma",
+        "only '{}' is missing from the docs; '{}' is not a ghost argument",
+    ),
+    (
+        "ninguna función enlazada produce la pareja",
+        "no linked function produces the pair",
+    ),
+    (
+        "La excepción {} de arg_exceptions nombra la sección '{}', que no está en las docs.",
+        "Exception {} in arg_exceptions names section '{}', which is not in the docs.",
+    ),
+    (
+        "Corrige el `doc_id` o quita la excepción de `arg_exceptions`.",
+        "Fix the `doc_id` or remove the exception from `arg_exceptions`.",
+    ),
+    (
+        "La excepción {} de '{}' (arg_exceptions) no silenció ningún hallazgo: {}.",
+        "Exception {} of '{}' (arg_exceptions) silenced no finding: {}.",
+    ),
+    (
+        "Quita la excepción de `arg_exceptions`: la pareja ya no se produce.",
+        "Remove the exception from `arg_exceptions`: the pair no longer occurs.",
+    ),
+    (
+        "fn {} ({}) enlaza la sección '{}' de {}, fuera de la frontera `{}` → `{}`.",
+        "fn {} ({}) links section '{}' of {}, outside the `{}` → `{}` boundary.",
+    ),
+    (
+        "Enlaza una sección de `{}`; si el enlace es intencionado, añade `// docsguard-ignore: link-boundary`.",
+        "Link a section of `{}`; if the link is intended, add `// docsguard-ignore: link-boundary`.",
+    ),
+    (
+        "Las implementaciones de '{}' en {} no coinciden: {}.",
+        "The implementations of '{}' in {} do not match: {}.",
+    ),
+    ("{} y {}", "{} and {}"),
+    ("'{}' solo en {}", "'{}' only in {}"),
+    ("retorno: {}", "return: {}"),
+    (
+        "Alinea las firmas: las docs compartidas no pueden ser correctas para todas.",
+        "Align the signatures: the shared docs cannot be right for all of them.",
+    ),
+    // core/diagnostics.rs
+    (
+        "Borra las anotaciones obsoletas y deja un solo `@docs` por función.",
+        "Delete the stale annotations and leave a single `@docs` per function.",
+    ),
+    (
+        "Elimina las líneas vacías entre la anotación y la función para enlazarla.",
+        "Remove the blank lines between the annotation and the function to link it.",
+    ),
+    (
+        "Añade una cabecera `Param`/`Name` a la tabla si documenta argumentos.",
+        "Add a `Param`/`Name` header to the table if it documents arguments.",
+    ),
+    (
+        "El tipo genérico se comparó como texto literal: el resultado puede ser un falso positivo.",
+        "The generic type was compared as literal text: the result may be a false positive.",
+    ),
+    (
+        "Corrige el error de sintaxis: los argumentos extraídos pueden estar incompletos.",
+        "Fix the syntax error: the extracted arguments may be incomplete.",
+    ),
+    (
+        "Pon el marcador `@docs-id` justo encima del encabezado de la sección.",
+        "Put the `@docs-id` marker right above the section heading.",
+    ),
+    (
+        "Comprueba que el marcador `@docs-id` no quedó sobre contenido generado; si el tamaño es real, sube el límite en `limits:` de config.yaml.",
+        "Check that the `@docs-id` marker did not end up above generated content; if the size is real, raise the limit in `limits:` of config.yaml.",
+    ),
+    // core/examples.rs
+    (
+        "El ejemplo llama a fn {} con {}, pero acepta {}.",
+        "The example calls fn {} with {}, but it accepts {}.",
+    ),
+    (
+        "Actualiza la llamada del ejemplo a la firma actual.",
+        "Update the example's call to the current signature.",
+    ),
+    (
+        "El ejemplo pasa {} ({}) como '{}', que la sección documenta como '{}'.",
+        "The example passes {} ({}) as '{}', which the section documents as '{}'.",
+    ),
+    (
+        "Corrige el ejemplo o el tipo documentado: la sección se contradice a sí misma.",
+        "Fix the example or the documented type: the section contradicts itself.",
+    ),
+    ("'{}' documentado como '{}'", "'{}' documented as '{}'"),
+    ("un texto", "a string"),
+    ("un booleano", "a boolean"),
+    ("un número", "a number"),
+    ("un objeto", "an object"),
+    ("una lista", "a list"),
+    ("texto", "string"),
+    ("booleano", "boolean"),
+    ("número", "number"),
+    ("objeto", "object"),
+    (
+        "El ejemplo muestra el campo '{}', que no existe en el tipo de retorno de fn {}.",
+        "The example shows field '{}', which does not exist in the return type of fn {}.",
+    ),
+    (
+        "Elimina '{}' del ejemplo o revisa si el campo fue renombrado.",
+        "Remove '{}' from the example or check whether the field was renamed.",
+    ),
+    (
+        "El campo '{}' del ejemplo parece {}, pero en código es '{}'.",
+        "Field '{}' of the example looks like {}, but in code it is '{}'.",
+    ),
+    (
+        "Actualiza el valor de '{}' en el ejemplo.",
+        "Update the value of '{}' in the example.",
+    ),
+    (
+        "El ejemplo muestra un objeto, pero fn {} retorna {}.",
+        "The example shows an object, but fn {} returns {}.",
+    ),
+    (
+        "Actualiza el resultado esperado del ejemplo.",
+        "Update the expected result of the example.",
+    ),
+    (
+        "El ejemplo muestra un {}, pero fn {} retorna un objeto.",
+        "The example shows a {}, but fn {} returns an object.",
+    ),
+    (
+        "El ejemplo muestra un {}, pero fn {} retorna {}.",
+        "The example shows a {}, but fn {} returns {}.",
+    ),
+    // core/anchors.rs
+    ("¿Quisiste decir '#{}'?", "Did you mean '#{}'?"),
+    (
+        "Corrige el enlace o el encabezado, o revisa `site.slugger` en config.yaml.",
+        "Fix the link or the heading, or check `site.slugger` in config.yaml.",
+    ),
+    (
+        "El enlace '{}' apunta a un ancla que no existe en {} (anclas de {}).",
+        "Link '{}' points to an anchor that does not exist in {} ({} anchors).",
+    ),
+    (
+        "'#{}' es el ancla de GitHub; con `slugger: {}` el encabezado \"{}\" es '#{}'.",
+        "'#{}' is the GitHub anchor; with `slugger: {}` heading \"{}\" is '#{}'.",
+    ),
+    // core/ids.rs
+    (
+        "id_style '{}' no reconocido: usa kebab-from-function, snake, keep o una plantilla con {{function_kebab}}",
+        "Unrecognized id_style '{}': use kebab-from-function, snake, keep or a template with {{function_kebab}}",
+    ),
+    (
+        "Llave sin cerrar en id_style '{}'",
+        "Unclosed brace in id_style '{}'",
+    ),
+    (
+        "Marcador '{{{}}}' desconocido en id_style (disponibles: {})",
+        "Unknown placeholder '{{{}}}' in id_style (available: {})",
+    ),
+    (
+        "id_style '{}' no incluye la función: todos los IDs serían iguales",
+        "id_style '{}' does not include the function: every ID would be the same",
+    ),
+    // core/internals.rs
+    (
+        "La sección pública referencia símbolos internos: {}.",
+        "The public section references internal symbols: {}.",
+    ),
+    (
+        "Describe el comportamiento en lugar de la implementación, o añade el nombre a `references.allow` en .docsguard/config.yaml.",
+        "Describe the behavior instead of the implementation, or add the name to `references.allow` in .docsguard/config.yaml.",
+    ),
+    ("`{}` no se exporta desde {}", "`{}` is not exported from {}"),
+    // core/suppression.rs
+    (
+        "La supresión `{}` de fn {} no silenció ningún hallazgo.",
+        "Suppression `{}` of fn {} silenced no finding.",
+    ),
+    (
+        "Quita `{}` del comentario: el hallazgo ya no se produce.",
+        "Remove `{}` from the comment: the finding no longer occurs.",
+    ),
+    // core/symbols.rs
+    ("¿Quisiste decir `{}`?", "Did you mean `{}`?"),
+    (
+        "Actualiza el ejemplo o añade el nombre a `references.allow` en .docsguard/config.yaml.",
+        "Update the example or add the name to `references.allow` in .docsguard/config.yaml.",
+    ),
+    (
+        "El ejemplo de la sección '{}' usa `{}`, que no está entre los símbolos exportados del proyecto.",
+        "The example of section '{}' uses `{}`, which is not among the project's exported symbols.",
+    ),
+    (
+        "El ejemplo de fn {} usa `{}`, que no existe en el proyecto; {} exporta `{}`.",
+        "The example of fn {} uses `{}`, which does not exist in the project; {} exports `{}`.",
+    ),
+    (
+        "¿Se renombró a `{}`? Actualiza el ejemplo del JSDoc.",
+        "Was it renamed to `{}`? Update the JSDoc example.",
+    ),
+    // core/version_source.rs
+    (
+        "Versión no válida en `versions.known` de la configuración: '{}'",
+        "Invalid version in `versions.known` of the configuration: '{}'",
+    ),
+    (
+        "La versión '{}' de {} no es semver válido.",
+        "Version '{}' of {} is not valid semver.",
+    ),
+    (
+        "--check-versions necesita `versions:` en .docsguard/config.yaml",
+        "--check-versions needs `versions:` in .docsguard/config.yaml",
+    ),
+    (
+        "Indica `manifest: package.json` (o `Cargo.toml`) o una lista `known`.",
+        "Give `manifest: package.json` (or `Cargo.toml`) or a `known` list.",
+    ),
+    (
+        "No se pudo leer el manifiesto: {}",
+        "Could not read the manifest: {}",
+    ),
+    ("JSON no válido: {}", "Invalid JSON: {}"),
+    (
+        "Manifiesto no soportado en `versions.manifest`: {} (usa package.json o Cargo.toml)",
+        "Unsupported manifest in `versions.manifest`: {} (use package.json or Cargo.toml)",
+    ),
+    (
+        "{} no tiene un campo `version` de texto.",
+        "{} has no string `version` field.",
+    ),
+    // parser/code_parser.rs
+    (
+        "Lenguajes soportados: TypeScript (.ts/.tsx), Rust (.rs), Python (.py), Go (.go), Java (.java), C# (.cs)",
+        "Supported languages: TypeScript (.ts/.tsx), Rust (.rs), Python (.py), Go (.go), Java (.java), C# (.cs)",
+    ),
+    ("Extensión '.{}' no soportada.", "Unsupported extension '.{}'."),
+    (
+        "El archivo '{}' no tiene extensión.",
+        "File '{}' has no extension.",
+    ),
+    (
+        "No se puede determinar el lenguaje.",
+        "The language cannot be determined.",
+    ),
+    (
+        "El código leído de stdin necesita `--code-language`.",
+        "Code read from stdin needs `--code-language`.",
+    ),
+    (
+        "Por ejemplo: `docsguard check docs/api.md - --code-language ts`.",
+        "For example: `docsguard check docs/api.md - --code-language ts`.",
+    ),
+    (
+        "Error al configurar tree-sitter con {}",
+        "Error configuring tree-sitter with {}",
+    ),
+    ("Error al parsear el archivo {}", "Error parsing file {}"),
+    ("Archivo de {} no encontrado: {}", "Missing {} file: {}"),
+    ("código", "code"),
+    ("documentación", "documentation"),
+    (
+        "Verifica que la ruta sea correcta.",
+        "Check that the path is correct.",
+    ),
+    ("extensión '.{}' no soportada", "unsupported extension '.{}'"),
+    ("sin extensión", "no extension"),
+    ("Se omite {}: {}.", "Skipping {}: {}."),
+    ("Se omite {}: no es Markdown.", "Skipping {}: not Markdown."),
+    (
+        "La documentación se lee de los archivos .md y .markdown.",
+        "Documentation is read from .md and .markdown files.",
+    ),
+    ("No se pudo leer el archivo: {}", "Could not read the file: {}"),
+    (
+        "Archivo demasiado grande ({:.1} MB, máximo: {} MB): {}",
+        "File too large ({} MB, maximum: {} MB): {}",
+    ),
+    (
+        "Anotaciones @docs en conflicto: {}; no se enlaza ninguna.",
+        "Conflicting @docs annotations: {}; none is linked.",
+    ),
+    ("'{}' (línea {})", "'{}' (line {})"),
+    ("Sintaxis correcta: `{}`", "Correct syntax: `{}`"),
+    (
+        "Anotación @docs mal formada (línea {}): `{}` — la función no se enlaza.",
+        "Malformed @docs annotation (line {}): `{}` — the function is not linked.",
+    ),
+    (
+        "Anotación @docs '{}' (línea {}) separada de la función por líneas vacías: no se enlaza.",
+        "@docs annotation '{}' (line {}) separated from the function by blank lines: not linked.",
+    ),
+    (
+        "tree-sitter encontró errores de sintaxis dentro de la función.",
+        "tree-sitter found syntax errors inside the function.",
+    ),
+    // parser/doc_edit.rs
+    (
+        "Rango de edición inválido {:?} en un texto de {} bytes.",
+        "Invalid edit range {} in a text of {} bytes.",
+    ),
+    (
+        "La edición {:?} se solapa con otra en {:?}.",
+        "Edit {} overlaps another one at {}.",
+    ),
+    (
+        "La línea {} no es una fila de tabla con {} columnas.",
+        "Line {} is not a table row with {} columns.",
+    ),
+    // parser/doc_parser.rs
+    (
+        "El archivo tiene {}; se conservan las {} primeras (límite `limits.max_sections`).",
+        "The file has {}; the first {} are kept (`limits.max_sections` limit).",
+    ),
+    (
+        "La sección '{}' documenta {}; se conservan los {} primeros (límite `limits.max_section_args`) y no se validan uno a uno.",
+        "Section '{}' documents {}; the first {} are kept (`limits.max_section_args` limit) and they are not validated one by one.",
+    ),
+    (
+        "Valor de @docs-skip desconocido en '{}' (línea {}): `{}` — se ignora.",
+        "Unknown @docs-skip value in '{}' (line {}): `{}` — ignored.",
+    ),
+    (
+        "Valores admitidos: {} (separados por comas, sin espacios).",
+        "Accepted values: {} (comma-separated, no spaces).",
+    ),
+    (
+        "Marcador @docs-id mal formado (línea {}): `{}` — la sección no se registra.",
+        "Malformed @docs-id marker (line {}): `{}` — the section is not registered.",
+    ),
+    (
+        "Tabla ignorada en la sección '{}': sin cabecera de nombre y su primera columna no parece una lista de argumentos.",
+        "Table ignored in section '{}': no name header and its first column does not look like a list of arguments.",
+    ),
+    (
+        "La sección '{}' no tiene encabezado tras su marcador: se usa como título '{}', el anterior (línea {}).",
+        "Section '{}' has no heading after its marker: '{}', the previous one (line {}), is used as its title.",
+    ),
+    // parser/mapping
+    ("Error al parsear el mapeo: {}", "Error parsing the mapping: {}"),
+    ("ID inválido '{}' para {} en {}", "Invalid ID '{}' for {} in {}"),
+    ("Error al serializar el mapeo", "Error serializing the mapping"),
+    (
+        "Enlace obsoleto en {}/{}: la función '{}' ya no existe en {}.",
+        "Stale link in {}/{}: function '{}' no longer exists in {}.",
+    ),
+    (
+        "Actualiza o elimina la entrada de '{}' en {}/{}.",
+        "Update or remove the entry for '{}' in {}/{}.",
+    ),
+    // report/aggregate.rs
+    (
+        "{} de `{}` agrupados en {}: {} y {} más.",
+        "{} of `{}` grouped in {}: {} and {} more.",
+    ),
+    (
+        "{} de `{}` agrupados en {}: {}.",
+        "{} of `{}` grouped in {}: {}.",
+    ),
+    ("{} de `{}` agrupados en {}.", "{} of `{}` grouped in {}."),
+    (
+        "{} de `{}` agrupados sin ubicación: {} y {} más.",
+        "{} of `{}` grouped without a location: {} and {} more.",
+    ),
+    (
+        "{} de `{}` agrupados sin ubicación: {}.",
+        "{} of `{}` grouped without a location: {}.",
+    ),
+    (
+        "{} de `{}` agrupados sin ubicación.",
+        "{} of `{}` grouped without a location.",
+    ),
+    (
+        "`--no-aggregate` los muestra uno a uno.",
+        "`--no-aggregate` shows them one by one.",
+    ),
+    // report/timing.rs
+    (
+        "La verificación tardó {} (presupuesto: {}; parseo {}, validación {}).",
+        "The check took {} (budget: {}; parsing {}, validation {}).",
+    ),
+    (
+        "Archivos más lentos: {}. Excluye los archivos generados o ajusta --time-budget.",
+        "Slowest files: {}. Exclude generated files or adjust --time-budget.",
+    ),
+    (
+        "Tiempos: parseo {}, validación {}, total {}",
+        "Timings: parsing {}, validation {}, total {}",
+    ),
+    // report/sarif.rs
+    ("Sección de la documentación", "Documentation section"),
+    // report/mod.rs
+    ("({}, sin validar argumentos)", "({}, arguments not validated)"),
+    (
+        "Informe HTML escrito ({}): {}",
+        "HTML report written ({}): {}",
+    ),
+    // report/markdown.rs
+    (
+        "### DocsGuard — integridad de la documentación",
+        "### DocsGuard — documentation integrity",
+    ),
+    (
+        "| Errores | Advertencias | Filtrados por baseline |",
+        "| Errors | Warnings | Filtered by baseline |",
+    ),
+    ("✅ Sin errores ni advertencias.", "✅ No errors or warnings."),
+    (
+        "| | Regla | Función | Ubicación | Mensaje |",
+        "| | Rule | Function | Location | Message |",
+    ),
+    ("_… y {} más._", "_… and {} more._"),
+    (
+        "# DocsGuard — informe de verificación",
+        "# DocsGuard — verification report",
+    ),
+    (
+        "| Errores | Advertencias | Info | Filtrados por baseline | Secciones enlazadas |",
+        "| Errors | Warnings | Info | Filtered by baseline | Linked sections |",
+    ),
+    ("0 de 0", "0 of 0"),
+    ("{} de {} ({}%)", "{} of {} ({}%)"),
+    ("Errores", "Errors"),
+    ("Advertencias", "Warnings"),
+    ("Info", "Info"),
+    (
+        "| Función | Ubicación | Doc ID | Mensaje | Sugerencia |",
+        "| Function | Location | Doc ID | Message | Suggestion |",
+    ),
+    ("✅ Sin hallazgos.", "✅ No findings."),
+    // report/suppressions.rs
+    ("{} ({} sin uso en esta ejecución)", "{} ({} unused in this run)"),
+    ("antigüedad desconocida", "unknown age"),
+    ("hoy", "today"),
+    (
+        "{} · silenció hallazgos en esta ejecución",
+        "{} · silenced findings in this run",
+    ),
+    ("{} · sin uso en esta ejecución", "{} · unused in this run"),
+    ("Motivo: {}", "Reason: {}"),
+    ("todas las reglas", "all rules"),
+    ("'{}' documentado como '{}' en '{}'", "'{}' documented as '{}' in '{}'"),
+    ("orphan-section de '{}'", "orphan-section of '{}'"),
+    ("{} en fn {}: \"{}\"", "{} in fn {}: \"{}\""),
+    ("{} de '{}': \"{}\"", "{} in '{}': \"{}\""),
+    // new_functions.rs
+    (
+        "Función pública nueva desde '{}' sin documentar: el API nuevo se documenta en el mismo cambio.",
+        "New public function since '{}' is undocumented: new API is documented in the same change.",
+    ),
+    (
+        "Añade la sección `<!-- @docs-id: {} -->` a la documentación.",
+        "Add the section `<!-- @docs-id: {} -->` to the documentation.",
+    ),
+    (
+        "Añade `/// @docs: [{0}]` antes de la función y la sección `<!-- @docs-id: {0} -->` (o ejecuta `docsguard scaffold`).",
+        "Add `/// @docs: [{}]` before the function and the section `<!-- @docs-id: {} -->` (or run `docsguard scaffold`).",
+    ),
+    // attestation.rs
+    (
+        "Error al parsear las atestaciones: {}",
+        "Error parsing the attestations: {}",
+    ),
+    (
+        "ID inválido '{}' en la atestación de {}",
+        "Invalid ID '{}' in the attestation by {}",
+    ),
+    ("No se pudo crear: {}", "Could not create: {}"),
+    (
+        "Error al serializar las atestaciones",
+        "Error serializing the attestations",
+    ),
+    (
+        "Sección '{}' modificada desde la última atestación de {} el {}.",
+        "Section '{}' modified since the last attestation by {} on {}.",
+    ),
+    (
+        "Revisa el cambio y vuelve a atestarla: `docsguard attest {} --docs {} --by …`.",
+        "Review the change and attest it again: `docsguard attest {} --docs {} --by …`.",
+    ),
+    (
+        "La sección '{}' requiere atestación (`attestation_required`) y no tiene ninguna.",
+        "Section '{}' requires attestation (`attestation_required`) and has none.",
+    ),
+    (
+        "Atéstala tras revisarla: `docsguard attest {} --docs {} --by …`.",
+        "Attest it after reviewing it: `docsguard attest {} --docs {} --by …`.",
+    ),
+    (
+        "--by necesita quién atesta, en una sola línea.",
+        "--by needs who attests, on a single line.",
+    ),
+    ("Por ejemplo: `--by @maria`.", "For example: `--by @maria`."),
+    (
+        "Error al parsear el archivo de documentación",
+        "Error parsing the documentation file",
+    ),
+    (
+        "No hay ninguna sección '{}' en {}.",
+        "There is no section '{}' in {}.",
+    ),
+    (
+        "`docsguard parse <doc_file>` lista los IDs de las secciones.",
+        "`docsguard parse <doc_file>` lists the section IDs.",
+    ),
+    (
+        "[attest] Sección '{}' atestada por {} el {} ({}).",
+        "[attest] Section '{}' attested by {} on {} ({}).",
+    ),
+    // assert_links.rs
+    ("no existe", "missing"),
+    ("sin sección", "no section"),
+    ("sin enlace", "no link"),
+    ("con errores", "with errors"),
+    ("ID inválido: '{}'", "Invalid ID: '{}'"),
+    (
+        "No hay IDs que comprobar: usa --id o --ids-from",
+        "No IDs to check: use --id or --ids-from",
+    ),
+    ("No se pudo leer: {}", "Could not read: {}"),
+    (
+        "Archivo de IDs demasiado grande ({} KB, máximo: {} KB): {}",
+        "IDs file too large ({} KB, maximum: {} KB): {}",
+    ),
+    ("Error al serializar el resultado", "Error serializing the result"),
+    ("DocsGuard Assert — IDs críticos", "DocsGuard Assert — critical IDs"),
+    (
+        "{:<width$}  Sección  Enlace  Errores  Estado",
+        "{}  Section  Link     Errors  Status",
+    ),
+    ("sí", "yes"),
+    ("Todos los IDs pasan ({}).", "Every ID passes ({})."),
+    ("{} de {} IDs no pasan.", "{} of {} IDs do not pass."),
+    // build_info.rs
+    (
+        "Este binario se compiló sin soporte de {}.",
+        "This binary was built without {} support.",
+    ),
+    (
+        "Usa un binario completo o recompila con `cargo build --features {}`.",
+        "Use a full binary or rebuild with `cargo build --features {}`.",
+    ),
+    ("gramáticas:", "grammars:"),
+    // demo.rs
+    (
+        "`@docs: [auth-login]` enlaza la función con su sección; los dos argumentos coinciden en nombre y tipo.",
+        "`@docs: [auth-login]` links the function to its section; both arguments match in name and type.",
+    ),
+    (
+        "Enlace roto: la anotación dice `auth-logut` y la sección es `auth-logout`.",
+        "Broken link: the annotation says `auth-logut` and the section is `auth-logout`.",
+    ),
+    (
+        "Consecuencia del enlace roto: ninguna función enlaza `auth-logout`. Al corregir la errata desaparecen los dos hallazgos.",
+        "A consequence of the broken link: no function links `auth-logout`. Fixing the typo makes both findings go away.",
+    ),
+    (
+        "Función pública sin `@docs`. Es solo Info; `scaffold` propone el enlace o una sección nueva.",
+        "Public function without `@docs`. It is only Info; `scaffold` proposes the link or a new section.",
+    ),
+    (
+        "El enlace de Rust funciona igual que el de TypeScript, con `/// @docs: [search]`.",
+        "The Rust link works just like the TypeScript one, with `/// @docs: [search]`.",
+    ),
+    (
+        "Argumento fantasma: la tabla documenta `offset`, que la firma ya no tiene.",
+        "Ghost argument: the table documents `offset`, which the signature no longer has.",
+    ),
+    (
+        "Enlace correcto, aunque un argumento no coincide (el siguiente hallazgo).",
+        "Correct link, although one argument does not match (the next finding).",
+    ),
+    (
+        "El código declara `id: u64` y las docs dicen `string`.",
+        "The code declares `id: u64` and the docs say `string`.",
+    ),
+    (
+        "Sección huérfana: documenta una función que no existe en el código.",
+        "Orphan section: it documents a function that does not exist in the code.",
+    ),
+    (
+        "Ya existe un archivo del ejemplo: {}",
+        "A sample file already exists: {}",
+    ),
+    (
+        "Indica con --dir un directorio vacío o sin el proyecto de ejemplo.",
+        "Use --dir to give an empty directory or one without the sample project.",
+    ),
+    ("Qué muestra cada hallazgo:", "What each finding shows:"),
+    ("{} en {} (ver {})", "{} at {} (see {})"),
+    (
+        "{}   # propone el enlace de refreshToken",
+        "{}   # proposes the refreshToken link",
+    ),
+    (
+        "{}   # acepta los hallazgos de hoy como deuda",
+        "{}   # accepts today's findings as debt",
+    ),
+    (
+        "{}   # revalida cada vez que guardas",
+        "{}   # revalidates every time you save",
+    ),
+    ("Siguientes pasos:", "Next steps:"),
+    (
+        "DocsGuard demo — proyecto de ejemplo en {}",
+        "DocsGuard demo — sample project in {}",
+    ),
+    // diff_entities.rs
+    ("árbol de trabajo", "working tree"),
+    ("No se pudo leer {}", "Could not read {}"),
+    ("Error al parsear {} en {}", "Error parsing {} at {}"),
+    (
+        "{} está fuera de --project-root: no tiene versiones en git.",
+        "{} is outside --project-root: it has no versions in git.",
+    ),
+    ("(sin cambios)", "(no changes)"),
+    (
+        "## Impacto en la documentación ({} → {})",
+        "## Documentation impact ({} → {})",
+    ),
+    ("_Sin cambios._", "_No changes._"),
+    ("Añadida", "Added"),
+    ("Eliminada", "Removed"),
+    ("Cambiada", "Changed"),
+    ("(sin anotación)", "(no annotation)"),
+    ("arg `{}` añadido", "arg `{}` added"),
+    ("arg `{}` eliminado", "arg `{}` removed"),
+    ("(sin tipo)", "(untyped)"),
+    (
+        "DocsGuard — Cambios de API y docs: {} → {}",
+        "DocsGuard — API and docs changes: {} → {}",
+    ),
+    ("Código: {}", "Code: {}"),
+    ("Docs: {}", "Docs: {}"),
+    (
+        "Resumen: funciones {}; secciones {}",
+        "Summary: functions {}; sections {}",
+    ),
+    ("sin cambios", "no changes"),
+    ("{} añadidas", "{} added"),
+    ("{} eliminadas", "{} removed"),
+    ("{} cambiadas", "{} changed"),
+    // git.rs
+    (
+        "No se pudo resolver la referencia '{}'",
+        "Could not resolve reference '{}'",
+    ),
+    ("No se pudo ejecutar git", "Could not run git"),
+    ("git {} falló: {}", "git {} failed: {}"),
+    // inspect.rs
+    ("DocsGuard Parse — {}", "DocsGuard Parse — {}"),
+    ("desde: {}", "since: {}"),
+    ("fn {} ({}){}", "fn {} ({}){}"),
+    ("{} sección.", "{} section."),
+    ("{} secciones.", "{} sections."),
+    ("{} función.", "{} function."),
+    ("{} funciones.", "{} functions."),
+    ("Funciones ({})", "Functions ({})"),
+    ("Funciones sin resolver ({})", "Unresolved functions ({})"),
+    ("Secciones ({})", "Sections ({})"),
+    ("Secciones sin resolver ({})", "Unresolved sections ({})"),
+    (
+        "[config] Estrategias habilitadas: {}",
+        "[config] Enabled strategies: {}",
+    ),
+    ("sin título", "untitled"),
+    ("(título inferido)", "(inferred title)"),
+    ("[{}] '{}' (título inferido) ({})", "[{}] '{}' (inferred title) ({})"),
+    ("no vinculable (@docs-no-link)", "unlinkable (@docs-no-link)"),
+    ("Error al parsear {}", "Error parsing {}"),
+    ("@example{} (línea {})", "@example{} (line {})"),
+    ("DocsGuard List", "DocsGuard List"),
+    ("Funciones sin resolver", "Unresolved functions"),
+    ("Funciones", "Functions"),
+    ("Función", "Function"),
+    ("Ubicación", "Location"),
+    ("Anotación", "Annotation"),
+    ("Secciones sin resolver", "Unresolved sections"),
+    ("Secciones", "Sections"),
+    ("Sección", "Section"),
+    ("Título", "Title"),
+    ("Enlace", "Link"),
+    ("(ninguna)", "(none)"),
+    ("Vinculada a", "Linked to"),
+    ("Resuelve a", "Resolves to"),
+    (
+        "Sin resolver: {} de {} y {} de {}.",
+        "Unresolved: {} of {} and {} of {}.",
+    ),
+    ("✗ sin anotación", "✗ no annotation"),
+    ("✗ sin sección con ese ID", "✗ no section with that ID"),
+    ("✗ sin enlace", "✗ no link"),
+    ("[{}, línea {}]", "[{}, line {}]"),
+    // baseline/mod.rs
+    ("Archivo: {}", "File: {}"),
+    ("{} al baseline.", "{} to the baseline."),
+    (
+        "el nombre no puede estar vacío",
+        "the name cannot be empty",
+    ),
+    (
+        "'{}' no sirve como nombre de baseline (solo letras, dígitos, '.', '_' y '-', sin empezar por punto)",
+        "'{}' cannot be used as a baseline name (only letters, digits, '.', '_' and '-', not starting with a dot)",
+    ),
+    (
+        "Error al parsear el baseline: {}",
+        "Error parsing the baseline: {}",
+    ),
+    (
+        "Corrígelo a mano o regenera el baseline con `docsguard baseline`.",
+        "Fix it by hand or regenerate the baseline with `docsguard baseline`.",
+    ),
+    (
+        "Versión de baseline no soportada: '{}' (esperada: '1')\n    -> Archivo: {}",
+        "Unsupported baseline version: '{}' (expected: '1')\n    -> File: {}",
+    ),
+    (
+        "Fecha inválida en la entrada #{} del baseline: {}: '{}'\n    -> Archivo: {}",
+        "Invalid date in baseline entry #{}: {}: '{}'\n    -> File: {}",
+    ),
+    ("-> Archivo: {}", "-> File: {}"),
+    (
+        "Usa el formato AAAA-MM-DD (p. ej. 2025-03-31).",
+        "Use the YYYY-MM-DD format (e.g. 2025-03-31).",
+    ),
+    ("Error al serializar el baseline", "Error serializing the baseline"),
+    (
+        "Entrada de baseline seguida tras mover fn {} de {} a {}.",
+        "Baseline entry followed after fn {} moved from {} to {}.",
+    ),
+    (
+        "Actualiza las rutas guardadas con `docsguard baseline --prune --rewrite-moves`.",
+        "Update the stored paths with `docsguard baseline --prune --rewrite-moves`.",
+    ),
+    (
+        "[debt-age] Baseline: {}; antigüedad mediana {}, máxima {} ({} sin fecha)",
+        "[debt-age] Baseline: {}; median age {}, maximum {} ({} undated)",
+    ),
+    (
+        "[debt-age] Baseline: {}; antigüedad mediana {}, máxima {}",
+        "[debt-age] Baseline: {}; median age {}, maximum {}",
+    ),
+    (
+        "[debt-age] Baseline: {} ({} sin fecha)",
+        "[debt-age] Baseline: {} ({} undated)",
+    ),
+    ("[debt-age] Baseline: {}", "[debt-age] Baseline: {}"),
+    ("Más antiguas:", "Oldest:"),
+    ("Caducan en {} o menos:", "Expiring in {} or less:"),
+    ("caducada hace {}", "expired {} ago"),
+    ("caduca en {}", "expires in {}"),
+    ("(vacío)", "(empty)"),
+    (
+        "[explain-baseline] {} por el baseline:",
+        "[explain-baseline] {} by the baseline:",
+    ),
+    ("Entrada calculada:", "Computed entry:"),
+    (
+        "El baseline no tiene entradas.",
+        "The baseline has no entries.",
+    ),
+    ("Más parecida: entrada #{} ({})", "Closest: entry #{} ({})"),
+    (
+        "difiere {}:\n        baseline: {}\n        actual:   {}",
+        "differs in {}:\n        baseline: {}\n        current:  {}",
+    ),
+    (
+        "La entrada #{} del baseline ({}{}: \"{}\") no silenció ningún hallazgo.",
+        "Baseline entry #{} ({}{}: \"{}\") silenced no finding.",
+    ),
+    (
+        "Elimina la entrada del baseline: el hallazgo ya no se produce.",
+        "Remove the entry from the baseline: the finding no longer occurs.",
+    ),
+    (
+        "DocsGuard Baseline — Podando entradas sin uso",
+        "DocsGuard Baseline — Pruning unused entries",
+    ),
+    (
+        "DocsGuard Baseline — Volcando errores existentes",
+        "DocsGuard Baseline — Dumping existing errors",
+    ),
+    ("No hay baseline que podar: {}", "There is no baseline to prune: {}"),
+    (
+        "Genera uno con `docsguard baseline` sin --prune.",
+        "Generate one with `docsguard baseline` without --prune.",
+    ),
+    ("{} del baseline.", "{} from the baseline."),
+    (
+        "{} tras mover funciones de archivo.",
+        "{} after functions moved between files.",
+    ),
+    ("{} en {}", "{} in {}"),
+    ("{} en {}.", "{} in {}."),
+    (
+        "El CI ahora pasará en verde. Solo se bloquearán regresiones nuevas.",
+        "CI will now pass green. Only new regressions will be blocked.",
+    ),
+    // ci/github.rs
+    (
+        "[ci] GITHUB_ACTIONS no detectado: se emiten anotaciones solo por stdout.",
+        "[ci] GITHUB_ACTIONS not detected: annotations are only written to stdout.",
+    ),
+    (
+        "[ci] En código: {}{}; en docs: {}{}.",
+        "[ci] In code: {}{}; in docs: {}{}.",
+    ),
+    ("::warning::{}", "::warning::{}"),
+    (
+        "DocsGuard: no se pudo calcular el diff contra '{}', se reporta todo: {:#}",
+        "DocsGuard: could not compute the diff against '{}', reporting everything: {}",
+    ),
+    (
+        "DocsGuard: no se pudo publicar el comentario en la PR (solo resumen): {:#}",
+        "DocsGuard: could not post the PR comment (summary only): {}",
+    ),
+    ("[ci] Comentario de la PR {}.", "[ci] PR comment {}."),
+    ("creado", "created"),
+    ("actualizado", "updated"),
+    (
+        "No se pudo escribir el resumen del paso: {}",
+        "Could not write the step summary: {}",
+    ),
+    ("GITHUB_TOKEN no definido", "GITHUB_TOKEN not set"),
+    ("GITHUB_REPOSITORY no definido", "GITHUB_REPOSITORY not set"),
+    (
+        "GITHUB_REF no apunta a una pull request",
+        "GITHUB_REF does not point to a pull request",
+    ),
+    (
+        "No se pudieron listar los comentarios",
+        "Could not list the comments",
+    ),
+    (
+        "Respuesta inválida al listar comentarios",
+        "Invalid response when listing comments",
+    ),
+    (
+        "No se pudo actualizar el comentario",
+        "Could not update the comment",
+    ),
+    ("No se pudo crear el comentario", "Could not create the comment"),
+    ("Resumen: {}", "Summary: {}"),
+    ("Resumen: {} · umbral: {}", "Summary: {} · threshold: {}"),
+    // conformance.rs
+    (
+        "DocsGuard — Conformidad de los parsers con el corpus de anotaciones",
+        "DocsGuard — Parser conformance with the annotation corpus",
+    ),
+    ("Escenario", "Scenario"),
+    ("falla", "fail"),
+    ("falta", "missing"),
+    (
+        "Falta el espécimen del escenario",
+        "The scenario's specimen is missing",
+    ),
+    ("Falta {}.yaml", "{}.yaml is missing"),
+    ("{}.yaml no es válido: {}", "{}.yaml is not valid: {}"),
+    (
+        "Falta el archivo de código de {}",
+        "The code file of {} is missing",
+    ),
+    ("La extensión de {} no es de {}", "The extension of {} is not {}"),
+    ("No se pudo parsear: {}", "Could not parse: {}"),
+    ("No se extrajo la función '{}'", "Function '{}' was not extracted"),
+    (
+        "'{}': línea {} (se esperaba {})",
+        "'{}': line {} (expected {})",
+    ),
+    (
+        "'{}': argumentos [{}] (se esperaba [{}])",
+        "'{}': arguments [{}] (expected [{}])",
+    ),
+    ("'{}': {} (se esperaba {})", "'{}': {} (expected {})"),
+    ("enlazada a '{}'", "linked to '{}'"),
+    ("sin enlazar", "unlinked"),
+    (
+        "Función no esperada '{}' (línea {})",
+        "Unexpected function '{}' (line {})",
+    ),
+    (
+        "Notas del parser [{}] (se esperaba [{}])",
+        "Parser notes [{}] (expected [{}])",
+    ),
+    // Motivos `not_applicable` de fixtures/conformance
+    (
+        "Go no tiene atributos ni decoradores sobre las funciones",
+        "Go has no attributes or decorators on functions",
+    ),
+    (
+        "Python no tiene comentarios de bloque (las docstrings van dentro de la función)",
+        "Python has no block comments (docstrings go inside the function)",
+    ),
+    (
+        "Resumen: {} de {} casos conformes ({} no aplicables)",
+        "Summary: {} of {} cases conform ({} not applicable)",
+    ),
+    // config/diff.rs
+    ("Cambian de severidad", "Change severity"),
+    ("Dejan de reportarse", "No longer reported"),
+    ("Pasan a reportarse", "Newly reported"),
+    (
+        "No existe la configuración con la que comparar: {}",
+        "The configuration to compare with does not exist: {}",
+    ),
+    (
+        "{} demasiado grande (máximo: {} KB)",
+        "{} too large (maximum: {} KB)",
+    ),
+    (
+        "DocsGuard — Impacto de la configuración",
+        "DocsGuard — Configuration impact",
+    ),
+    ("Configuración: {}", "Configuration: {}"),
+    ("Comparada con: {}", "Compared with: {}"),
+    (
+        "Sin cambios: los mismos hallazgos con la misma severidad.",
+        "No changes: the same findings with the same severity.",
+    ),
+    (
+        "Nota: también cambia {}; afecta al parseo y se evalúa con el valor actual.",
+        "Note: {} also changes; it affects parsing and is evaluated with the current value.",
+    ),
+    ("Código de salida: {}", "Exit code: {}"),
+    ("{} (sin cambios)", "{} (no change)"),
+    (
+        "{} → {} (la CI dejaría de fallar)",
+        "{} → {} (CI would stop failing)",
+    ),
+    (
+        "{} → {} (la CI pasaría a fallar)",
+        "{} → {} (CI would start failing)",
+    ),
+    // config/mod.rs, config/overrides.rs
+    ("# --set {}={}", "# --set {}={}"),
+    ("# Preset: {}", "# Preset: {}"),
+    ("ninguno", "none"),
+    (
+        "No hay ninguna combinación '{}'.",
+        "There is no combination '{}'.",
+    ),
+    (
+        "Decláralas en `combos:` de .docsguard/config.yaml.",
+        "Declare them under `combos:` in .docsguard/config.yaml.",
+    ),
+    (
+        "Combinaciones declaradas: {}.",
+        "Declared combinations: {}.",
+    ),
+    (
+        "Error al parsear la configuración: {}",
+        "Error parsing the configuration: {}",
+    ),
+    (
+        "`docsguard config show --resolved` muestra las claves y los valores efectivos.",
+        "`docsguard config show --resolved` shows the effective keys and values.",
+    ),
+    ("se esperaba clave=valor: '{}'", "expected key=value: '{}'"),
+    ("clave vacía en '{}'", "empty key in '{}'"),
+    ("solo admite valores escalares", "only scalar values are accepted"),
+    ("la clave tiene un segmento", "the key has a single segment"),
+    ("`{}` no es una sección", "`{}` is not a section"),
+    (
+        "No se pudo aplicar --set {}={}: {}",
+        "Could not apply --set {}={}: {}",
+    ),
+    (
+        "No existe la configuración de {}: {}",
+        "The {} configuration does not exist: {}",
+    ),
+    ("del proyecto", "project"),
+    (
+        "no existe: valores por defecto",
+        "does not exist: default values",
+    ),
+    ("# Configuración: {} ({})", "# Configuration: {} ({})"),
+    (
+        "# Los --set no se muestran aquí; --resolved los incluye.",
+        "# The --set values are not shown here; --resolved includes them.",
+    ),
+    // config/rules.rs
+    ("por defecto", "default"),
+    ("Regla", "Rule"),
+    ("Nivel", "Level"),
+    ("Origen", "Origin"),
+    ("según {}", "per {}"),
+    (
+        "DocsGuard — Niveles efectivos por regla",
+        "DocsGuard — Effective levels per rule",
+    ),
+    (
+        "ninguno (valores por defecto)",
+        "none (default values)",
+    ),
+    ("Preset: {}", "Preset: {}"),
+    (
+        "# Configuración de DocsGuard generada con `docsguard init --preset {}`.",
+        "# DocsGuard configuration generated with `docsguard init --preset {}`.",
+    ),
+    (
+        "# Niveles: error, warning, info u off. `docsguard explain` muestra los efectivos.",
+        "# Levels: error, warning, info or off. `docsguard explain` shows the effective ones.",
+    ),
+    (
+        "Ya existe la configuración: {}",
+        "The configuration already exists: {}",
+    ),
+    (
+        "Usa --force para sobrescribirla.",
+        "Use --force to overwrite it.",
+    ),
+    (
+        "Configuración escrita (preset {}): {}",
+        "Configuration written (preset {}): {}",
+    ),
+    // coverage/
+    (
+        "no es una cobertura de `coverage --json` ({})",
+        "not a `coverage --json` coverage ({})",
+    ),
+    (
+        "esquema {} de una versión más nueva de docsguard (esta lee hasta el {})",
+        "schema {} from a newer docsguard version (this one reads up to {})",
+    ),
+    ("API público", "Public API"),
+    ("Todas las funciones", "All functions"),
+    (
+        "Cobertura de documentación: {}",
+        "Documentation coverage: {}",
+    ),
+    (
+        "**Cobertura de documentación:** {}",
+        "**Documentation coverage:** {}",
+    ),
+    ("<details><summary>Detalle</summary>", "<details><summary>Details</summary>"),
+    (
+        "| Métrica | Antes | Ahora | Δ |",
+        "| Metric | Before | Now | Δ |",
+    ),
+    ("_{} sin cambios._", "_{} unchanged._"),
+    ("Error al serializar la cobertura", "Error serializing the coverage"),
+    (
+        "Error al serializar la diferencia de cobertura",
+        "Error serializing the coverage difference",
+    ),
+    (
+        "Genera el archivo con `docsguard coverage --json`.",
+        "Generate the file with `docsguard coverage --json`.",
+    ),
+    ("[archivo: {}]", "[file: {}]"),
+    (
+        "nivel función: {}  ·  nivel archivo: {}{}",
+        "function level: {}  ·  file level: {}{}",
+    ),
+    (
+        "✓ {:.0}% — Cobertura por encima del umbral mínimo ({}%).",
+        "✓ {}% — Coverage above the minimum threshold ({}%).",
+    ),
+    (
+        "✗ {:.0}% — Por debajo del umbral mínimo ({}%).",
+        "✗ {}% — Below the minimum threshold ({}%).",
+    ),
+    (
+        "Añade anotaciones /// @docs: [id] a las funciones públicas sin documentar.",
+        "Add /// @docs: [id] annotations to the undocumented public functions.",
+    ),
+    // fix/mod.rs
+    (
+        "añadir la sección '{}' al final de {}",
+        "add section '{}' at the end of {}",
+    ),
+    (
+        "cambiar el tipo de '{}' de '{}' a '{}' en {}:{}",
+        "change the type of '{}' from '{}' to '{}' in {}:{}",
+    ),
+    (
+        "conservar '@docs: [{}]' y borrar {} en {}",
+        "keep '@docs: [{}]' and delete {} in {}",
+    ),
+    (
+        "renombrar '{}' a '{}' en {}:{}",
+        "rename '{}' to '{}' in {}:{}",
+    ),
+    (
+        "'@docs: [{}]' ya no está en {}:{}; vuelve a ejecutar `check`.",
+        "'@docs: [{}]' is no longer at {}:{}; run `check` again.",
+    ),
+    (
+        "'{}' ya no está en {}:{}; vuelve a ejecutar `check`.",
+        "'{}' is no longer at {}:{}; run `check` again.",
+    ),
+    // interactive/
+    ("{} (confianza ≥ 80%).", "{} (confidence ≥ 80%)."),
+    ("Confianza: {:.0}%", "Confidence: {:.0}%"),
+    ("Aceptados: {}", "Accepted: {}"),
+    ("Rechazados: {}", "Rejected: {}"),
+    ("Omitidos: {}", "Skipped: {}"),
+    ("• {} → /// @docs: [{}]", "• {} → /// @docs: [{}]"),
+    ("ID nuevo: {}", "New ID: {}"),
+    ("+ {}  /// @docs: [{}]  ({} · {:.0}%)", "+ {}  /// @docs: [{}]  ({} · {:.0}%)"),
+    (
+        "DocsGuard Scaffold — Vinculación interactiva código ↔ documentación",
+        "DocsGuard Scaffold — Interactive code ↔ documentation linking",
+    ),
+    (
+        "[modo dry-run] No se escribirán cambios al disco.",
+        "[dry-run mode] No changes will be written to disk.",
+    ),
+    (
+        "Error al parsear el archivo de código",
+        "Error parsing the code file",
+    ),
+    (
+        "No se encontraron sugerencias de enlace.",
+        "No link suggestions found.",
+    ),
+    (
+        "(Todas las funciones ya están vinculadas o no hay matches heurísticos)",
+        "(Every function is already linked or there are no heuristic matches)",
+    ),
+    (
+        "── Enlace de archivo ─────────────────────────────",
+        "── File link ────────────────────────────────────",
+    ),
+    (
+        "Las {} funciones coinciden con secciones bajo '{}' [id: {}].",
+        "All {} functions match sections under '{}' [id: {}].",
+    ),
+    (
+        "[dry-run] Se habría añadido al inicio del archivo: {}",
+        "[dry-run] Would have been added at the start of the file: {}",
+    ),
+    (
+        "→ Archivo vinculado: {} escrito en {}.",
+        "→ File linked: {} written to {}.",
+    ),
+    (
+        "→ Se sigue con los enlaces por función.",
+        "→ Continuing with per-function links.",
+    ),
+    ("Función:  {} ({})", "Function: {} ({})"),
+    ("Sección:  '{}' [id: {}]", "Section:  '{}' [id: {}]"),
+    (
+        "Confianza: {:.0}% (ID: {:.0}%, título: {:.0}%)",
+        "Confidence: {}% (ID: {}%, title: {}%)",
+    ),
+    ("Secciones nuevas: {}", "New sections: {}"),
+    ("No hay cambios que aplicar.", "There are no changes to apply."),
+    (
+        "[dry-run] Secciones que se habrían añadido:",
+        "[dry-run] Sections that would have been added:",
+    ),
+    (
+        "[dry-run] Enlaces que se habrían escrito en {}:",
+        "[dry-run] Links that would have been written to {}:",
+    ),
+    (
+        "Ejecuta sin --dry-run para aplicar los cambios.",
+        "Run without --dry-run to apply the changes.",
+    ),
+    ("{} en {} (código sin modificar).", "{} in {} (code unchanged)."),
+    (
+        "[dry-run] Cambios que se habrían escrito:",
+        "[dry-run] Changes that would have been written:",
+    ),
+    ("Informe de la sesión: {}", "Session report: {}"),
+    (
+        "── Sección nueva ─────────────────────────────────",
+        "── New section ──────────────────────────────────",
+    ),
+    ("Sí — crear la sección", "Yes — create the section"),
+    ("No — rechazar", "No — reject"),
+    ("Omitir", "Skip"),
+    (
+        "¿Crear una sección nueva para esta función?",
+        "Create a new section for this function?",
+    ),
+    (
+        "Error al leer la respuesta del usuario",
+        "Error reading the user's answer",
+    ),
+    ("Sí — vincular", "Yes — link"),
+    (
+        "Todas — vincular esta y las siguientes",
+        "All — link this one and the following ones",
+    ),
+    (
+        "¿Vincular esta función con esta sección?",
+        "Link this function to this section?",
+    ),
+    (
+        "Sí — enlazar el archivo completo (@docs-file)",
+        "Yes — link the whole file (@docs-file)",
+    ),
+    ("No — enlazar función por función", "No — link function by function"),
+    (
+        "¿Vincular el archivo completo con esta sección?",
+        "Link the whole file to this section?",
+    ),
+    ("Índice de entidad inválido: {}", "Invalid entity index: {}"),
+    (
+        "Ningún enlace con confianza ≥ {:.0}%: no se escribieron parches.",
+        "No link with confidence ≥ {}%: no patches were written.",
+    ),
+    (
+        "{} en {} ({}); se aplican con git apply.",
+        "{} in {} ({}); apply them with git apply.",
+    ),
+    ("ID {:.0}%, título {:.0}%", "ID {}%, title {}%"),
+    ("ID {:.0}%, sin título", "ID {}%, no title"),
+    ("sección nueva", "new section"),
+    (
+        "DocsGuard Fix — Enlaces con confianza ≥ {:.0}%",
+        "DocsGuard Fix — Links with confidence ≥ {}%",
+    ),
+    (
+        "Ningún enlace con confianza suficiente: no hay nada que aplicar.",
+        "No link with enough confidence: nothing to apply.",
+    ),
+    (
+        "{}  {} → '{}': otra función apunta a la misma sección",
+        "{}  {} → '{}': another function points to the same section",
+    ),
+    ("No se aplicó ningún enlace.", "No link was applied."),
+    (
+        "[dry-run] {} en {}; ejecuta sin --dry-run para aplicarlos.",
+        "[dry-run] {} in {}; run without --dry-run to apply them.",
+    ),
+    (
+        "{} sin aplicar: enlázalos con `docsguard scaffold`.",
+        "{} not applied: link them with `docsguard scaffold`.",
+    ),
+    (
+        "Formato de informe no reconocido: {}",
+        "Unrecognized report format: {}",
+    ),
+    (
+        "Usa una ruta terminada en .md o .json.",
+        "Use a path ending in .md or .json.",
+    ),
+    ("Error al serializar el informe", "Error serializing the report"),
+    // last_run.rs
+    ("No se pudo leer el registro: {}", "Could not read the record: {}"),
+    ("Error al serializar el registro", "Error serializing the record"),
+    (
+        "la ejecución anterior es de otra versión de docsguard",
+        "the previous run is from another docsguard version",
+    ),
+    (
+        "cambió la configuración de .docsguard/",
+        "the .docsguard/ configuration changed",
+    ),
+    ("cambió el baseline", "the baseline changed"),
+    ("cambiaron las opciones de check", "the check options changed"),
+    (
+        "cambió el archivo de docs, que afecta a todos los pares",
+        "the docs file changed, which affects every pair",
+    ),
+    (
+        "no hay registro de una ejecución anterior",
+        "there is no record of a previous run",
+    ),
+    // paths.rs
+    (
+        "El patrón '{}' no encaja con ningún archivo.",
+        "Pattern '{}' does not match any file.",
+    ),
+    (
+        "Los patrones son relativos al directorio actual: `**` cruza directorios y `*` no.",
+        "Patterns are relative to the current directory: `**` crosses directories and `*` does not.",
+    ),
+    (
+        "No se pudo leer el directorio {}",
+        "Could not read directory {}",
+    ),
+    // schema.rs
+    ("el mínimo es {}", "the minimum is {}"),
+    (
+        "falta el campo `{}` (¿`{}` es una errata?)",
+        "missing field `{}` (is `{}` a typo?)",
+    ),
+    ("falta el campo `{}`", "missing field `{}`"),
+    (
+        "{} `{}` desconocido; ¿quisiste decir `{}`?",
+        "unknown {} `{}`; did you mean `{}`?",
+    ),
+    (
+        "{} `{}` desconocido (admitidos: {})",
+        "unknown {} `{}` (accepted: {})",
+    ),
+    ("campo", "field"),
+    ("valor", "value"),
+    ("se esperaba {}", "expected {}"),
+    ("un texto", "a string"),
+    ("un mapeo", "a mapping"),
+    (
+        "un enum unitario se serializa como texto",
+        "a unit enum serializes as a string",
+    ),
+    // self_check.rs
+    (
+        "DocsGuard — Auto-verificación de las anotaciones del crate",
+        "DocsGuard — Self-check of the crate's annotations",
+    ),
+    ("Código: {} en {}/", "Code: {} in {}/"),
+    (
+        "Resumen: {}; {} de {} enlazadas desde el código",
+        "Summary: {}; {} of {} linked from the code",
+    ),
+    // site.rs
+    ("sin respuesta", "no response"),
+    (
+        "La sección '{}' no está publicada: {} responde {}.",
+        "Section '{}' is not published: {} responds {}.",
+    ),
+    (
+        "Publica la página o revisa `base_url`, `docs_dir` y `url_template` en `site:` de config.yaml.",
+        "Publish the page or check `base_url`, `docs_dir` and `url_template` in `site:` of config.yaml.",
+    ),
+    (
+        "No se pudo confirmar que la sección '{}' esté publicada: {} responde {}.",
+        "Could not confirm that section '{}' is published: {} responds {}.",
+    ),
+    (
+        "El servidor rechazó la petición; comprueba la URL en un navegador.",
+        "The server rejected the request; check the URL in a browser.",
+    ),
+    (
+        "No se pudo contactar con el sitio ({}): {} de {} sin verificar.",
+        "Could not reach the site ({}): {} of {} unverified.",
+    ),
+    (
+        "Repite con conexión; las anclas se validan igualmente sin red (`broken-anchor`).",
+        "Retry with a connection; anchors are still validated offline (`broken-anchor`).",
+    ),
+    // transaction.rs
+    ("Contenido no UTF-8 para: {}", "Non-UTF-8 content for: {}"),
+    ("No se escribió ningún archivo", "No file was written"),
+    ("No se pudo renombrar a: {}", "Could not rename to: {}"),
+    ("Cambios revertidos", "Changes reverted"),
+    ("No se pudo escribir temporal: {}", "Could not write temporary file: {}"),
+    ("No se pudo leer metadata: {}", "Could not read metadata: {}"),
+    ("Archivo de solo lectura: {}", "Read-only file: {}"),
+    (
+        "-> NO restaurados (revisar a mano): {}",
+        "-> NOT restored (check by hand): {}",
+    ),
+    ("-> Sin tocar: {}", "-> Untouched: {}"),
+    // triage/mod.rs
+    (
+        "── Hallazgo 1/{} ──────────────────────────────",
+        "── Finding 1/{} ───────────────────────────────",
+    ),
+    ("→ Omitido.", "→ Skipped."),
+    (
+        "── Resumen ──────────────────────────────────────",
+        "── Summary ──────────────────────────────────────",
+    ),
+    ("Resueltos: {}", "Resolved: {}"),
+    ("Abrir en el editor", "Open in the editor"),
+    ("Aplicar corrección sugerida", "Apply the suggested fix"),
+    ("Añadir al baseline con motivo", "Add to the baseline with a reason"),
+    ("Suprimir en línea", "Suppress inline"),
+    ("Siguiente", "Next"),
+    ("Salir", "Quit"),
+    (
+        "DocsGuard Triage — Revisión interactiva de hallazgos",
+        "DocsGuard Triage — Interactive review of findings",
+    ),
+    (
+        "No quedan hallazgos por revisar.",
+        "There are no findings left to review.",
+    ),
+    ("→ Corrección aplicada: {}.", "→ Fix applied: {}."),
+    ("→ Añadido al baseline ({}).", "→ Added to the baseline ({})."),
+    ("→ Suprimido en línea en {}.", "→ Suppressed inline in {}."),
+    ("¿Qué hacer con este hallazgo?", "What to do with this finding?"),
+    ("Motivo", "Reason"),
+    ("Editor inválido: '{}'", "Invalid editor: '{}'"),
+    (
+        "No se pudo lanzar el editor '{}'",
+        "Could not launch editor '{}'",
+    ),
+    (
+        "[!] El editor terminó con estado {}",
+        "[!] The editor exited with status {}",
+    ),
+    // vfs/
+    ("permiso denegado: {}", "permission denied: {}"),
+    ("solo lectura: {}", "read-only: {}"),
+    (
+        "{} no existe en la revisión '{}'",
+        "{} does not exist at revision '{}'",
+    ),
+    (
+        "{} se lee de la revisión '{}' y no se puede modificar",
+        "{} is read from revision '{}' and cannot be modified",
+    ),
+    // watch/
+    (
+        "El modo watch no puede leer de stdin (`-`).",
+        "Watch mode cannot read from stdin (`-`).",
+    ),
+    (
+        "Observa archivos en disco, o valida el buffer con `docsguard check` y `-`.",
+        "Watch files on disk, or validate the buffer with `docsguard check` and `-`.",
+    ),
+    (
+        "--interactive-fixes necesita una terminal.",
+        "--interactive-fixes needs a terminal.",
+    ),
+    (
+        "Sin terminal, usa `docsguard check --fix`.",
+        "Without a terminal, use `docsguard check --fix`.",
+    ),
+    ("No se pudo resolver la ruta: {}", "Could not resolve the path: {}"),
+    (
+        "Error al inicializar el watcher de archivos",
+        "Error initializing the file watcher",
+    ),
+    ("Error al observar: {}", "Error watching: {}"),
+    ("[watch] Error del watcher: {}", "[watch] Watcher error: {}"),
+    (
+        "[!] Archivo de código eliminado: {}",
+        "[!] Code file deleted: {}",
+    ),
+    (
+        "[!] Archivo de documentación eliminado: {}",
+        "[!] Documentation file deleted: {}",
+    ),
+    (
+        "\r\x1B[2K  esperando a que terminen los cambios…",
+        "\r\x1B[2K  waiting for the changes to finish…",
+    ),
+    ("en caché", "cached"),
+    (
+        "código {} · docs {} · validación {}ms · render {}ms",
+        "code {} · docs {} · validation {}ms · render {}ms",
+    ),
+    (
+        "\r\x1B[2K  Sin cambios en el resultado ({}). Observando cambios... (Ctrl+C para salir)",
+        "\r\x1B[2K  No changes in the result ({}). Watching for changes... (Ctrl+C to quit)",
+    ),
+    (
+        "\x1B[2J\x1B[1;1HDocsGuard Watch — Validación en tiempo real",
+        "\x1B[2J\x1B[1;1HDocsGuard Watch — Real-time validation",
+    ),
+    (
+        "Observando cambios... (Ctrl+C para salir)",
+        "Watching for changes... (Ctrl+C to quit)",
+    ),
+    ("{}  ({})", "{}  ({})"),
+    (
+        "[!] Error en la configuración: {}",
+        "[!] Configuration error: {}",
+    ),
+    ("✓ Sin errores ni advertencias.", "✓ No errors or warnings."),
+    ("Error al parsear código", "Error parsing code"),
+    ("Error al parsear docs", "Error parsing docs"),
+    (
+        "f: aplicar fix sugerido para {} ({})",
+        "f: apply suggested fix for {} ({})",
+    ),
+    ("{} · {}/{}, n/p: otro", "{} · {}/{}, n/p: another"),
+    ("{} en fn {}", "{} in fn {}"),
+    (
+        "[!] {} cambió desde la validación; no se aplica la corrección.",
+        "[!] {} changed since the validation; the fix is not applied.",
+    ),
+    (
+        "[!] No se pudo aplicar la corrección: {:#}",
+        "[!] Could not apply the fix: {}",
+    ),
+    ("[fix] {}", "[fix] {}"),
+    // yaml.rs
+    (
+        "(la línea se indenta con tabuladores: YAML solo admite espacios)",
+        "(the line is indented with tabs: YAML only allows spaces)",
+    ),
+    ("línea {}, columna {}: {}", "line {}, column {}: {}"),
+    (
+        "{} demasiado grande ({}, máximo: {})\n    -> Archivo: {}",
+        "{} too large ({}, maximum: {})\n    -> File: {}",
+    ),
+    (
+        "clave `{}` repetida (ya aparece en la línea {}): YAML se quedaría solo con la última",
+        "duplicate key `{}` (it already appears on line {}): YAML would keep only the last one",
+    ),
+    // main.rs
+    ("Error: {}", "Error: {}"),
+    (
+        "Solo una entrada puede leerse de stdin (`-`).",
+        "Only one input can be read from stdin (`-`).",
+    ),
+    (
+        "Pasa el código o la documentación por stdin y el otro como archivo.",
+        "Pipe either the code or the documentation through stdin and pass the other as a file.",
+    ),
+    (
+        "Código leído de la revisión '{}'",
+        "Code read from revision '{}'",
+    ),
+    (
+        "Docs leídas de la revisión '{}'",
+        "Docs read from revision '{}'",
+    ),
+    ("Idioma no válido en {}: '{}'", "Invalid language in {}: '{}'"),
+    (
+        "Los idiomas disponibles son `es` y `en`.",
+        "The available languages are `es` and `en`.",
+    ),
+    (
+        "[site] Verificando {} de {} en {}.",
+        "[site] Checking {} of {} at {}.",
+    ),
+    (
+        "'{}' no es un número de segundos",
+        "'{}' is not a number of seconds",
+    ),
+    (
+        "el presupuesto debe ser mayor que 0",
+        "the budget must be greater than 0",
+    ),
+    ("'{}' no es un número", "'{}' is not a number"),
+    (
+        "--fix puede modificar los archivos",
+        "--fix may modify the files",
+    ),
+    (
+        "las supresiones sin usar necesitan todas las directivas",
+        "unused suppressions need every directive",
+    ),
+    (
+        "DocsGuard — Verificando enlaces código ↔ documentación",
+        "DocsGuard — Checking code ↔ documentation links",
+    ),
+    (
+        "Código: {} ({}: sin lenguaje soportado)",
+        "Code: {} ({}: no supported language)",
+    ),
+    ("Docs: {} ({}: sin extensión Markdown)", "Docs: {} ({}: no Markdown extension)"),
+    (
+        "No hay documentación Markdown que verificar.",
+        "There is no Markdown documentation to check.",
+    ),
+    (
+        "Indica un archivo .md, o un directorio o un glob (`\"docs/**/*.md\"`) que los contenga.",
+        "Pass a .md file, or a directory or glob (`\"docs/**/*.md\"`) that contains them.",
+    ),
+    (
+        "--verify-site necesita la URL del sitio publicado.",
+        "--verify-site needs the URL of the published site.",
+    ),
+    (
+        "Añade `site: {{base_url: https://…}}` a .docsguard/config.yaml.",
+        "Add `site: {{base_url: https://…}}` to .docsguard/config.yaml.",
+    ),
+    (
+        "--require-docs-for-new necesita una referencia git.",
+        "--require-docs-for-new needs a git reference.",
+    ),
+    (
+        "Indícala (`--require-docs-for-new origin/main`) o usa --changed-since.",
+        "Give it (`--require-docs-for-new origin/main`) or use --changed-since.",
+    ),
+    ("[fast] Ejecución completa: {}.", "[fast] Full run: {}."),
+    (
+        "[fast] {} de {}; se conservan los hallazgos del resto.",
+        "[fast] {} of {}; the findings of the rest are kept.",
+    ),
+    (
+        "En código: {} (total){}; en docs: {}{}.",
+        "In code: {} (total){}; in docs: {}{}.",
+    ),
+    (
+        "[explain-baseline] No hay baseline en {}: no se filtra nada.",
+        "[explain-baseline] There is no baseline at {}: nothing is filtered.",
+    ),
+    (
+        "[changed-since] {} desde '{}'.",
+        "[changed-since] {} since '{}'.",
+    ),
+    (
+        "[debt-age] No hay baseline en {}: no hay deuda aceptada.",
+        "[debt-age] There is no baseline at {}: there is no accepted debt.",
+    ),
+    (
+        "Sin errores nuevos (baseline activo).",
+        "No new errors (baseline active).",
+    ),
+    (
+        "No se encontraron funciones ni secciones para validar.",
+        "No functions or sections were found to validate.",
+    ),
+    (
+        "Resumen{}: {}, {} total · umbral: {}",
+        "Summary{}: {}, {} total · threshold: {}",
+    ),
+    (" (incremental)", " (incremental)"),
+    (
+        "Archivo nuevo — periodo de gracia hasta {}",
+        "New file — grace period until {}",
+    ),
+    (
+        "Archivo nuevo — periodo de gracia hasta {} (fecha del sistema de archivos, menos fiable que git)",
+        "New file — grace period until {} (filesystem date, less reliable than git)",
+    ),
+    (
+        "-> Severidad sin periodo de gracia: {}",
+        "-> Severity without grace period: {}",
+    ),
+    (
+        "Periodo de gracia: los Error de archivos de código con menos de {} desde su primer commit bajan a Warning.",
+        "Grace period: Errors in code files less than {} past their first commit drop to Warning.",
+    ),
+    (
+        "Verifica todo el repositorio: cada archivo de código soportado y cada `.md` bajo la raíz",
+        "Checks the whole repository: every supported code file and every `.md` under the root",
+    ),
+    (
+        "Raíz del repositorio: se recorre entera y da la configuración y el baseline",
+        "Repository root: walked in full; it also provides the configuration and the baseline",
+    ),
+    (
+        "Muestra también los enlaces verificados y los detalles de cada hallazgo",
+        "Also shows verified links and the details of each finding",
+    ),
+    (
+        "Imprime solo los errores y el resumen",
+        "Prints only the errors and the summary",
+    ),
+    (
+        "DocsGuard — Verificando el repositorio {}",
+        "DocsGuard — Checking the repository {}",
+    ),
+    ("Código: {}; docs: {}", "Code: {}; docs: {}"),
+    ("[X] No se pudo parsear {}: {}", "[X] Could not parse {}: {}"),
+    (
+        "Ningún archivo de docs se pudo parsear: no hay nada que validar.",
+        "No docs file could be parsed: there is nothing to check.",
+    ),
+    ("{} sin parsear.", "{} not parsed."),
+    ("[baseline] {}.", "[baseline] {}."),
+    ("No hay archivos Markdown bajo {}.", "There are no Markdown files under {}."),
+    (
+        "check-all toma como docs cada `.md` fuera de los directorios ocultos, `node_modules` y `target`.",
+        "check-all takes as docs every `.md` outside hidden directories, `node_modules` and `target`.",
+    ),
+    ("Predice el resultado de un perfil de `profiles:` sin tocar los artefactos reales", "Predicts the outcome of a `profiles:` profile without touching the real artifacts"),
+    ("Ejecuta un perfil de `profiles:` como lo haría el job de CI", "Runs a `profiles:` profile the way the CI job would"),
+    ("Perfil de `profiles:` en config.yaml", "Profile from `profiles:` in config.yaml"),
+    ("Directorio raíz del proyecto (configuración, baseline y repositorio git)", "Project root directory (configuration, baseline and git repository)"),
+    ("No hay ningún perfil '{}'.", "There is no profile '{}'."),
+    ("Decláralos en `profiles:` de .docsguard/config.yaml.", "Declare them under `profiles:` in .docsguard/config.yaml."),
+    ("Perfiles declarados: {}.", "Declared profiles: {}."),
+    (
+        "profiles.{}.time_budget: el presupuesto debe ser un número de segundos mayor que 0.",
+        "profiles.{}.time_budget: the budget must be a number of seconds greater than 0.",
+    ),
+    ("profiles.{}.baseline_name: {}", "profiles.{}.baseline_name: {}"),
+    ("DocsGuard — Simulando el perfil de CI '{}'", "DocsGuard — Simulating CI profile '{}'"),
+    ("DocsGuard — Perfil de CI '{}'", "DocsGuard — CI profile '{}'"),
+    ("Hallazgos: {}, {} total · tiempo: {}", "Findings: {}, {} total · time: {}"),
+    ("Políticas del perfil '{}':", "Policies of profile '{}':"),
+    ("fail-on: {} · umbral: {}", "fail-on: {} · threshold: {}"),
+    ("budget: {} de {}", "budget: {} of {}"),
+    ("max-warnings: {} (máximo {})", "max-warnings: {} (at most {})"),
+    ("min-coverage: {}% (mínimo {}%)", "min-coverage: {}% (minimum {}%)"),
+    ("{}: sin configurar", "{}: not configured"),
+    ("Artefactos: {} (simulado en {})", "Artifacts: {} (simulated at {})"),
+    ("Artefactos: {}", "Artifacts: {}"),
+    ("Artefactos: ninguno (format: text)", "Artifacts: none (format: text)"),
+    ("Veredicto: {} (código de salida {})", "Verdict: {} (exit code {})"),
+    ("pasaría", "would pass"),
+    ("pasa", "passes"),
+    ("fallaría por {}", "would fail on {}"),
+    ("falla por {}", "fails on {}"),
+    (
+        "Valida solo las funciones y secciones que toca `git diff` desde BASE (por defecto HEAD); sin `orphan-section`",
+        "Validates only the functions and sections touched by `git diff` since BASE (HEAD by default); no `orphan-section`",
+    ),
+    (
+        "--changed-only necesita un repositorio git y {} no está en ninguno.",
+        "--changed-only needs a git repository and {} is not in one.",
+    ),
+    ("Ejecuta desde un checkout de git o quita --changed-only.", "Run from a git checkout or drop --changed-only."),
+    ("[changed-only] Solo lo tocado desde '{}': {} de {}, {} de {}.", "[changed-only] Only what changed since '{}': {} of {}, {} of {}."),
+    ("[par] {} ← {}", "[pair] {} ← {}"),
+    ("[sin par] código: {}; docs: {}", "[unpaired] code: {}; docs: {}"),
+    ("ninguno", "none"),
+    ("ninguna", "none"),
+    ("# pairing: off — check-all valida todo junto; así se emparejaría:", "# pairing: off — check-all validates everything together; this is how it would pair:"),
+    ("{} -> {} (mismo directorio)", "{} -> {} (same directory)"),
+    ("{} -> {} ({} más arriba)", "{} -> {} ({} up)"),
+    ("{} -> sin par: se valida con el resto de docs", "{} -> unpaired: validated with the remaining docs"),
+    ("Lista el archivo de docs con el que `check-all` valida cada archivo de código (`pairing`)", "List the docs file `check-all` validates each code file against (`pairing`)"),
+    ("Instala el hook pre-commit de git que ejecuta `docsguard check --changed-only`", "Install the git pre-commit hook that runs `docsguard check --changed-only`"),
+    ("Añade el bloque de DocsGuard al final de un hook pre-commit que no creó DocsGuard", "Append the DocsGuard block to a pre-commit hook DocsGuard didn't create"),
+    ("Quita solo el bloque de DocsGuard del hook pre-commit", "Remove only the DocsGuard block from the pre-commit hook"),
+    ("Directorio raíz del proyecto (configuración y combinaciones)", "Project root directory (configuration and combos)"),
+    ("El hook tiene la marca `{}` sin la de cierre `{}`.", "The hook has the `{}` marker without the closing `{}`."),
+    ("Corrige el hook a mano o bórralo y vuelve a instalarlo.", "Fix the hook by hand or delete it and install it again."),
+    ("install-hooks necesita un repositorio git y {} no está en ninguno.", "install-hooks needs a git repository and {} is not in one."),
+    ("Ejecuta desde un checkout de git.", "Run it from a git checkout."),
+    ("No se pudo leer el hook {}", "Could not read the hook {}"),
+    ("No se pudo hacer ejecutable: {}", "Could not make executable: {}"),
+    ("{} ya existe y no lo creó DocsGuard.", "{} already exists and wasn't created by DocsGuard."),
+    ("Revísalo y repite con --force para añadir el bloque de DocsGuard al final.", "Review it and run again with --force to append the DocsGuard block."),
+    ("El hook pre-commit de {} ya está al día.", "The pre-commit hook at {} is already up to date."),
+    ("Instalado el hook pre-commit en {}.", "Installed the pre-commit hook at {}."),
+    ("Actualizado el bloque de DocsGuard en {}.", "Updated the DocsGuard block in {}."),
+    ("Añadido el bloque de DocsGuard al final del hook existente {}.", "Appended the DocsGuard block to the existing hook {}."),
+    ("Antes de cada commit:", "Before each commit:"),
+    ("No hay bloque de DocsGuard en {}: nada que quitar.", "No DocsGuard block in {}: nothing to remove."),
+    ("Borrado {}: solo tenía el bloque de DocsGuard.", "Deleted {}: it only had the DocsGuard block."),
+    ("Quitado el bloque de DocsGuard de {}.", "Removed the DocsGuard block from {}."),
+    ("No se pudo crear el directorio: {}", "Could not create the directory: {}"),
+    ("No se pudo borrar: {}", "Could not delete: {}"),
+    (
+        "Aviso: {} existe: hay otra operación de git en curso; los commits fallarán hasta que termine.",
+        "Warning: {} exists: another git operation is in progress; commits will fail until it finishes.",
+    ),
+    // core/conflicts.rs
+    (
+        "Conflicto de git sin resolver (`<<<<<<<` en la línea {}): lo validado en este archivo puede no ser fiable.",
+        "Unresolved git conflict (`<<<<<<<` on line {}): what is validated in this file may not be reliable.",
+    ),
+    (
+        "{}: conflicto de git sin resolver (`<<<<<<<`); no se modifica ningún archivo.",
+        "{}: unresolved git conflict (`<<<<<<<`); no file is modified.",
+    ),
+    (
+        "Resuelve el conflicto (o termina el merge o el rebase) y vuelve a ejecutar.",
+        "Resolve the conflict (or finish the merge or rebase) and run again.",
+    ),
+    (
+        "[!] {} existe: hay otra operación de git en curso; el baseline no se podrá commitear hasta que termine.",
+        "[!] {} exists: another git operation is in progress; the baseline can't be committed until it finishes.",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::super::translate::{consistent, lines, translate};
+    use super::TABLE;
+    use clap::CommandFactory;
+
+    fn help_texts(command: &clap::Command, out: &mut Vec<String>) {
+        let mut push = |text: Option<&clap::builder::StyledStr>| {
+            if let Some(text) = text {
+                out.push(text.to_string());
+            }
+        };
+        push(command.get_about());
+        push(command.get_long_about());
+        push(command.get_after_help());
+        push(command.get_after_long_help());
+        for arg in command.get_arguments() {
+            push(arg.get_help());
+            push(arg.get_long_help());
+        }
+        for sub in command.get_subcommands() {
+            help_texts(sub, out);
+        }
+    }
+
+    #[test]
+    fn every_help_text_has_a_translation() {
+        let mut texts = Vec::new();
+        help_texts(&crate::Cli::command(), &mut texts);
+        let missing: Vec<&String> = texts
+            .iter()
+            .filter(|text| translate(text).as_ref() == text.as_str())
+            .collect();
+        assert!(missing.is_empty(), "{missing:#?}");
+    }
+
+    /// Primer literal de cada llamada a `tr`, `tr_in` o las macros `*_tr!`
+    /// de `source`, también dentro de un `&format!(…)`.
+    fn tr_literals(source: &str) -> Vec<String> {
+        let mut found = Vec::new();
+        for call in ["tr(", "tr_in(", "_tr!("] {
+            for (at, _) in source.match_indices(call) {
+                let before = source[..at].chars().next_back();
+                if call != "_tr!(" && before.is_some_and(|c| c.is_alphanumeric() || c == '_') {
+                    continue;
+                }
+                let mut rest = source[at + call.len()..].trim_start();
+                if call == "tr_in(" {
+                    rest = rest.split_once(',').map_or("", |(_, r)| r).trim_start();
+                }
+                if let Some(format) = rest.strip_prefix("&format!(") {
+                    rest = format.trim_start();
+                }
+                if let Some(literal) = rest.strip_prefix('"') {
+                    found.push(unescape(literal));
+                }
+            }
+        }
+        found
+    }
+
+    /// Contenido de un literal de Rust hasta su comilla de cierre.
+    fn unescape(literal: &str) -> String {
+        let mut out = String::new();
+        let mut chars = literal.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => break,
+                '\\' => match chars.next() {
+                    Some('n') => out.push('\n'),
+                    Some('r') => out.push('\r'),
+                    Some('t') => out.push('\t'),
+                    Some('x') => {
+                        let code: String = chars.by_ref().take(2).collect();
+                        out.push(char::from(u8::from_str_radix(&code, 16).unwrap()));
+                    }
+                    Some('\n') => {
+                        while chars.clone().next().is_some_and(char::is_whitespace) {
+                            chars.next();
+                        }
+                    }
+                    Some(other) => out.push(other),
+                    None => break,
+                },
+                c => out.push(c),
+            }
+        }
+        out
+    }
+
+    /// `line` sin secuencias de control del terminal (`\x1B[2K`).
+    fn without_escapes(line: &str) -> String {
+        let mut out = String::new();
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+            if c != '\x1B' {
+                out.push(c);
+                continue;
+            }
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        }
+        out
+    }
+
+    fn rust_sources(dir: &std::path::Path, out: &mut Vec<std::path::PathBuf>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                rust_sources(&path, out);
+            } else if path.extension().is_some_and(|e| e == "rs") {
+                out.push(path);
+            }
+        }
+    }
+
+    #[test]
+    fn every_text_passed_to_tr_has_a_translation() {
+        let known: std::collections::HashSet<String> = TABLE
+            .iter()
+            .filter_map(lines)
+            .flatten()
+            .map(|(es, _)| es)
+            .collect();
+        let mut files = Vec::new();
+        rust_sources(
+            &std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &mut files,
+        );
+        // Cada hueco como `{}`: `{:?}` y `{:.0}` usan la entrada de `{}`
+        let holes = |line: &str| super::super::translate::literals(line).join("{}");
+        let known: std::collections::HashSet<String> = known.iter().map(|l| holes(l)).collect();
+        let mut missing = Vec::new();
+        for file in files {
+            let source = std::fs::read_to_string(&file).unwrap();
+            // Los tests pasan textos a `tr` a propósito
+            let code = source.split("#[cfg(test)]").next().unwrap();
+            for literal in tr_literals(code) {
+                for line in literal.split('\n').map(str::trim) {
+                    let line = holes(line);
+                    // Sin letras propias (`{}`, `  [!] {}`, `\x1B[2K{}`) el
+                    // texto viene de otro sitio
+                    if !without_escapes(&line)
+                        .replace("{}", "")
+                        .chars()
+                        .any(char::is_alphabetic)
+                        || known.contains(&line)
+                        || translate(&line).as_ref() != line
+                    {
+                        continue;
+                    }
+                    missing.push(format!("{}: {}", file.display(), line));
+                }
+            }
+        }
+        // Los motivos `not_applicable` del corpus salen por `tr` sin estar
+        // en el código
+        for case in crate::conformance::run_corpus() {
+            if let crate::conformance::Verdict::NotApplicable(reason) = case.verdict {
+                if translate(&reason).as_ref() == reason {
+                    missing.push(format!("{}: {}", case.file, reason));
+                }
+            }
+        }
+        assert!(missing.is_empty(), "{missing:#?}");
+    }
+
+    #[test]
+    fn entries_keep_their_lines_and_holes() {
+        for entry in TABLE {
+            let lines = lines(entry).unwrap_or_else(|| panic!("líneas distintas: {entry:?}"));
+            for (es, en) in lines {
+                assert!(consistent(&es, &en), "huecos: {es:?} → {en:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,432 @@
+//! Mensajes al usuario: cantidades e idioma de la salida.
+//!
+//! Toda cifra que acompaña a un sustantivo se escribe con `Noun::count`:
+//! elige singular o plural, separa los miles (`1.234` en castellano, `1,234`
+//! en inglés) y, con `count_or_zero`, usa la frase del cero (`sin errores`)
+//! si el sustantivo la tiene. Las formas viven aquí, una constante por
+//! sustantivo con los dos idiomas, para que ningún mensaje concatene un plural
+//! a mano.
+//!
+//! Los mensajes se generan siempre en castellano (`Locale::CANONICAL`): es el
+//! texto que guardan el baseline, la caché de `--fast` y los hallazgos, así
+//! que nada de eso depende del idioma. `--lang en` (o `DOCSGUARD_LANG=en`)
+//! instala otro idioma de salida y `tr` traduce el texto al escribirlo, con la
+//! tabla de plantillas de `en.rs`: cada entrada es un formato canónico tal
+//! como aparece en el código, con sus huecos `{}`, y su traducción. Lo que no
+//! está en la tabla sale en castellano.
+
+mod en;
+mod translate;
+
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+/// Variable de entorno con el idioma de la salida; `--lang` tiene prioridad.
+pub const LANG_ENV: &str = "DOCSGUARD_LANG";
+
+/// Idioma de los mensajes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Locale {
+    /// Castellano (por defecto).
+    Es,
+    /// Inglés.
+    En,
+}
+
+static OUTPUT: OnceLock<Locale> = OnceLock::new();
+
+/// Fija el idioma de la salida; solo cuenta la primera llamada.
+pub fn install(locale: Locale) {
+    let _ = OUTPUT.set(locale);
+}
+
+/// `text` en el idioma de la salida.
+pub fn tr(text: &str) -> Cow<'_, str> {
+    tr_in(Locale::output(), text)
+}
+
+/// `text` en `locale`, para quien renderiza en un idioma concreto.
+pub fn tr_in(locale: Locale, text: &str) -> Cow<'_, str> {
+    match locale {
+        Locale::Es => Cow::Borrowed(text),
+        Locale::En => translate::translate(text),
+    }
+}
+
+/// `println!` en el idioma de la salida.
+macro_rules! println_tr {
+    () => {
+        ::std::println!()
+    };
+    ($($arg:tt)*) => {
+        ::std::println!("{}", $crate::messages::tr(&::std::format!($($arg)*)))
+    };
+}
+
+/// `print!` en el idioma de la salida.
+macro_rules! print_tr {
+    ($($arg:tt)*) => {
+        ::std::print!("{}", $crate::messages::tr(&::std::format!($($arg)*)))
+    };
+}
+
+/// `eprintln!` en el idioma de la salida.
+macro_rules! eprintln_tr {
+    () => {
+        ::std::eprintln!()
+    };
+    ($($arg:tt)*) => {
+        ::std::eprintln!("{}", $crate::messages::tr(&::std::format!($($arg)*)))
+    };
+}
+
+pub(crate) use {eprintln_tr, print_tr, println_tr};
+
+impl Locale {
+    /// Idioma en que se generan los mensajes, sea cual sea el de la salida.
+    pub const CANONICAL: Locale = Locale::Es;
+
+    /// Idioma de la salida: el de `install` o, sin instalar, el canónico.
+    pub fn output() -> Locale {
+        OUTPUT.get().copied().unwrap_or(Locale::CANONICAL)
+    }
+
+    /// `es`, `en` (sin distinguir mayúsculas); `None` para cualquier otro.
+    pub fn parse(code: &str) -> Option<Locale> {
+        match code.trim().to_ascii_lowercase().as_str() {
+            "es" => Some(Locale::Es),
+            "en" => Some(Locale::En),
+            _ => None,
+        }
+    }
+
+    /// Idioma pedido en `args` (`--lang en`, `--lang=en`) o en
+    /// `DOCSGUARD_LANG`. Un valor de `--lang` no válido se deja a clap; uno
+    /// de la variable vuelve como error.
+    pub fn requested(args: &[std::ffi::OsString]) -> Result<Option<Locale>, String> {
+        let mut args = args.iter().map(|a| a.to_string_lossy());
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                break;
+            }
+            let value = match arg.strip_prefix("--lang") {
+                Some("") => args.next().map(|v| v.into_owned()),
+                Some(rest) if rest.starts_with('=') => Some(rest[1..].to_string()),
+                _ => continue,
+            };
+            return Ok(value.as_deref().and_then(Locale::parse));
+        }
+        match std::env::var(LANG_ENV) {
+            Ok(value) if value.trim().is_empty() => Ok(None),
+            Ok(value) => Locale::parse(&value).map(Some).ok_or(value),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// `n` con separador de miles: `1.234.567` (es), `1,234,567` (en).
+    pub fn number(self, n: usize) -> String {
+        let separator = match self {
+            Locale::Es => '.',
+            Locale::En => ',',
+        };
+        let digits = n.to_string();
+        let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                out.push(separator);
+            }
+            out.push(digit);
+        }
+        out
+    }
+}
+
+/// Formas de un sustantivo (o sintagma) contable en un idioma.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Forms {
+    pub one: &'static str,
+    pub other: &'static str,
+    /// Frase para cero en lugar de `0 <plural>` (`sin errores`).
+    pub zero: Option<&'static str>,
+}
+
+/// Sustantivo contable con sus formas en cada idioma.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Noun {
+    pub es: Forms,
+    pub en: Forms,
+}
+
+impl Noun {
+    const fn new(es: [&'static str; 2], en: [&'static str; 2]) -> Noun {
+        Noun {
+            es: Forms {
+                one: es[0],
+                other: es[1],
+                zero: None,
+            },
+            en: Forms {
+                one: en[0],
+                other: en[1],
+                zero: None,
+            },
+        }
+    }
+
+    /// El mismo sustantivo con frase para el cero.
+    const fn zero(mut self, es: &'static str, en: &'static str) -> Noun {
+        self.es.zero = Some(es);
+        self.en.zero = Some(en);
+        self
+    }
+
+    pub fn forms(&self, locale: Locale) -> &Forms {
+        match locale {
+            Locale::Es => &self.es,
+            Locale::En => &self.en,
+        }
+    }
+
+    /// `n` con la forma que le toca: `1 error`, `1.234 errores`.
+    pub fn count(&self, n: usize) -> String {
+        self.count_in(Locale::CANONICAL, n)
+    }
+
+    pub fn count_in(&self, locale: Locale, n: usize) -> String {
+        let forms = self.forms(locale);
+        let noun = if n == 1 { forms.one } else { forms.other };
+        format!("{} {}", locale.number(n), noun)
+    }
+
+    /// Como `count`, pero `0` es la frase del cero si la hay (`sin errores`).
+    pub fn count_or_zero(&self, n: usize) -> String {
+        self.count_or_zero_in(Locale::CANONICAL, n)
+    }
+
+    pub fn count_or_zero_in(&self, locale: Locale, n: usize) -> String {
+        match self.forms(locale).zero {
+            Some(zero) if n == 0 => zero.to_string(),
+            _ => self.count_in(locale, n),
+        }
+    }
+}
+
+pub const ERRORS: Noun =
+    Noun::new(["error", "errores"], ["error", "errors"]).zero("sin errores", "no errors");
+pub const WARNINGS: Noun = Noun::new(["advertencia", "advertencias"], ["warning", "warnings"])
+    .zero("sin advertencias", "no warnings");
+pub const INFOS: Noun = Noun::new(["info", "info"], ["info", "info"]);
+pub const FINDINGS: Noun = Noun::new(["hallazgo", "hallazgos"], ["finding", "findings"])
+    .zero("sin hallazgos", "no findings");
+pub const FILES: Noun = Noun::new(["archivo", "archivos"], ["file", "files"]);
+pub const FUNCTIONS: Noun = Noun::new(["función", "funciones"], ["function", "functions"]);
+pub const SECTIONS: Noun = Noun::new(["sección", "secciones"], ["section", "sections"]);
+pub const ARGS: Noun = Noun::new(["argumento", "argumentos"], ["argument", "arguments"]);
+pub const NAMESPACES: Noun = Noun::new(
+    ["espacio de nombres", "espacios de nombres"],
+    ["namespace", "namespaces"],
+);
+pub const CHANGED_FILES: Noun = Noun::new(
+    ["archivo cambiado", "archivos cambiados"],
+    ["changed file", "changed files"],
+);
+pub const DIRECTORIES: Noun =
+    Noun::new(["directorio", "directorios"], ["directory", "directories"]);
+pub const SKIPPED_FILES: Noun = Noun::new(
+    ["archivo omitido", "archivos omitidos"],
+    ["skipped file", "skipped files"],
+);
+pub const IGNORED_FUNCTIONS: Noun = Noun::new(
+    ["función ignorada", "funciones ignoradas"],
+    ["ignored function", "ignored functions"],
+);
+pub const REPARSED_CODE_FILES: Noun = Noun::new(
+    [
+        "archivo de código re-parseado",
+        "archivos de código re-parseados",
+    ],
+    ["code file re-parsed", "code files re-parsed"],
+);
+pub const KNOWN_FINDINGS_FILTERED: Noun = Noun::new(
+    [
+        "hallazgo conocido filtrado",
+        "hallazgos conocidos filtrados",
+    ],
+    ["known finding filtered", "known findings filtered"],
+);
+pub const FILTERED_BY_BASELINE: Noun = Noun::new(
+    ["filtrado por baseline", "filtrados por baseline"],
+    ["filtered by baseline", "filtered by baseline"],
+);
+pub const UNFILTERED_FINDINGS: Noun = Noun::new(
+    ["hallazgo no filtrado", "hallazgos no filtrados"],
+    ["unfiltered finding", "unfiltered findings"],
+);
+pub const BASELINE_ENTRIES: Noun = Noun::new(["entrada", "entradas"], ["entry", "entries"]);
+pub const DAYS: Noun = Noun::new(["día", "días"], ["day", "days"]);
+pub const MINUTES: Noun = Noun::new(["minuto", "minutos"], ["minute", "minutes"]);
+pub const HOURS: Noun = Noun::new(["hora", "horas"], ["hour", "hours"]);
+pub const MONTHS: Noun = Noun::new(["mes", "meses"], ["month", "months"]);
+pub const YEARS: Noun = Noun::new(["año", "años"], ["year", "years"]);
+pub const DUMPED_FINDINGS: Noun = Noun::new(
+    ["hallazgo volcado", "hallazgos volcados"],
+    ["finding dumped", "findings dumped"],
+);
+pub const PRUNED_ENTRIES: Noun = Noun::new(
+    ["entrada eliminada", "entradas eliminadas"],
+    ["entry removed", "entries removed"],
+);
+pub const REWRITTEN_PATHS: Noun = Noun::new(
+    ["ruta actualizada", "rutas actualizadas"],
+    ["path rewritten", "paths rewritten"],
+);
+pub const PROSE_ONLY_LINKS: Noun = Noun::new(
+    ["enlace prose-only", "enlaces prose-only"],
+    ["prose-only link", "prose-only links"],
+);
+pub const WRITTEN_LINKS: Noun = Noun::new(
+    ["enlace escrito", "enlaces escritos"],
+    ["link written", "links written"],
+);
+pub const LINKS: Noun = Noun::new(["enlace", "enlaces"], ["link", "links"]);
+pub const WRITTEN_PATCHES: Noun = Noun::new(
+    ["parche escrito", "parches escritos"],
+    ["patch written", "patches written"],
+);
+pub const SUPPRESSIONS: Noun = Noun::new(
+    ["supresión activa", "supresiones activas"],
+    ["active suppression", "active suppressions"],
+)
+.zero("sin supresiones activas", "no active suppressions");
+pub const LINK_SUGGESTIONS: Noun = Noun::new(
+    [
+        "sugerencia de enlace encontrada",
+        "sugerencias de enlace encontradas",
+    ],
+    ["link suggestion found", "link suggestions found"],
+);
+
+/// Todos los sustantivos, para traducir las cantidades ya escritas. Un
+/// sustantivo nuevo se añade también aquí.
+const NOUNS: &[Noun] = &[
+    ERRORS,
+    WARNINGS,
+    INFOS,
+    FINDINGS,
+    FILES,
+    FUNCTIONS,
+    SECTIONS,
+    ARGS,
+    NAMESPACES,
+    CHANGED_FILES,
+    SKIPPED_FILES,
+    IGNORED_FUNCTIONS,
+    REPARSED_CODE_FILES,
+    KNOWN_FINDINGS_FILTERED,
+    FILTERED_BY_BASELINE,
+    UNFILTERED_FINDINGS,
+    BASELINE_ENTRIES,
+    DAYS,
+    DIRECTORIES,
+    MINUTES,
+    HOURS,
+    MONTHS,
+    YEARS,
+    DUMPED_FINDINGS,
+    PRUNED_ENTRIES,
+    REWRITTEN_PATHS,
+    PROSE_ONLY_LINKS,
+    WRITTEN_LINKS,
+    LINKS,
+    WRITTEN_PATCHES,
+    LINK_SUGGESTIONS,
+    SUPPRESSIONS,
+];
+
+/// `1 error, 2 advertencias`: la cabeza de las líneas de resumen.
+pub fn summary(errors: usize, warnings: usize) -> String {
+    summary_in(Locale::CANONICAL, errors, warnings)
+}
+
+pub fn summary_in(locale: Locale, errors: usize, warnings: usize) -> String {
+    format!(
+        "{}, {}",
+        ERRORS.count_in(locale, errors),
+        WARNINGS.count_in(locale, warnings)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COUNTS: [usize; 4] = [0, 1, 2, 1234];
+
+    fn table(render: impl Fn(usize) -> String) -> Vec<String> {
+        COUNTS.iter().map(|&n| render(n)).collect()
+    }
+
+    #[test]
+    fn summaries_match_snapshot_in_both_locales() {
+        assert_eq!(
+            table(|n| summary_in(Locale::Es, n, n)),
+            [
+                "0 errores, 0 advertencias",
+                "1 error, 1 advertencia",
+                "2 errores, 2 advertencias",
+                "1.234 errores, 1.234 advertencias",
+            ]
+        );
+        assert_eq!(
+            table(|n| summary_in(Locale::En, n, n)),
+            [
+                "0 errors, 0 warnings",
+                "1 error, 1 warning",
+                "2 errors, 2 warnings",
+                "1,234 errors, 1,234 warnings",
+            ]
+        );
+        assert_eq!(summary(1, 2), "1 error, 2 advertencias");
+    }
+
+    #[test]
+    fn zero_phrases_and_phrase_nouns_agree_with_the_count() {
+        assert_eq!(
+            table(|n| FINDINGS.count_or_zero_in(Locale::Es, n)),
+            [
+                "sin hallazgos",
+                "1 hallazgo",
+                "2 hallazgos",
+                "1.234 hallazgos"
+            ]
+        );
+        assert_eq!(
+            table(|n| FINDINGS.count_or_zero_in(Locale::En, n)),
+            ["no findings", "1 finding", "2 findings", "1,234 findings"]
+        );
+        // Sin frase del cero, la cifra
+        assert_eq!(SECTIONS.count_or_zero(0), "0 secciones");
+        assert_eq!(
+            table(|n| KNOWN_FINDINGS_FILTERED.count_in(Locale::Es, n)),
+            [
+                "0 hallazgos conocidos filtrados",
+                "1 hallazgo conocido filtrado",
+                "2 hallazgos conocidos filtrados",
+                "1.234 hallazgos conocidos filtrados",
+            ]
+        );
+        assert_eq!(
+            REPARSED_CODE_FILES.count(1),
+            "1 archivo de código re-parseado"
+        );
+    }
+
+    #[test]
+    fn thousands_are_grouped_per_locale() {
+        assert_eq!(Locale::Es.number(999), "999");
+        assert_eq!(Locale::Es.number(1000), "1.000");
+        assert_eq!(Locale::Es.number(1_234_567), "1.234.567");
+        assert_eq!(Locale::En.number(12_000), "12,000");
+        assert_eq!(Locale::En.number(0), "0");
+    }
+}
@@ -0,0 +1,440 @@
+//! Traducción del texto canónico con la tabla de plantillas.
+//!
+//! Se traduce línea a línea: la sangría y los marcadores del principio
+//! (`->`, `·`, `[!]`, `0:`) se conservan y el resto tiene que coincidir
+//! entero con una plantilla. Cada plantilla es una secuencia de literales
+//! separados por huecos; los valores de los huecos se traducen a su vez si
+//! coinciden enteros con otra plantilla, son una cantidad (`3 archivos`,
+//! `sin errores`) o una lista de ellas separadas por comas. Se prueban
+//! primero las plantillas con más texto literal; las que tienen huecos y
+//! menos de `MIN_LETTERS` letras propias (`{} y {}`) solo se prueban dentro
+//! de un hueco o si ninguna otra traduce la línea, para no comerse líneas
+//! que no son suyas.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use super::{en, Locale, NOUNS};
+
+/// Letras literales mínimas de una plantilla con huecos que traduce una
+/// línea entera.
+const MIN_LETTERS: usize = 3;
+/// Profundidad máxima de huecos dentro de huecos.
+const MAX_DEPTH: usize = 4;
+/// Marcadores que se saltan al principio de una línea.
+const MAX_MARKERS: usize = 3;
+
+#[derive(Debug)]
+enum Piece {
+    Text(String),
+    Hole(usize),
+}
+
+#[derive(Debug)]
+struct Template {
+    /// Literales del formato canónico: uno más que huecos.
+    literals: Vec<String>,
+    translation: Vec<Piece>,
+    /// Letras de los literales.
+    letters: usize,
+}
+
+struct Table {
+    /// Plantillas sin huecos.
+    exact: HashMap<String, String>,
+    /// Con huecos, de más a menos texto literal.
+    templates: Vec<Template>,
+}
+
+fn table() -> &'static Table {
+    static TABLE: OnceLock<Table> = OnceLock::new();
+    TABLE.get_or_init(|| build(en::TABLE))
+}
+
+/// Literales de un formato de `format!`: cada `{…}` es un hueco y `{{`/`}}`
+/// son llaves.
+pub(super) fn literals(format: &str) -> Vec<String> {
+    let mut literals = vec![String::new()];
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literals.last_mut().unwrap().push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literals.last_mut().unwrap().push('}');
+            }
+            '{' => {
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        break;
+                    }
+                }
+                literals.push(String::new());
+            }
+            c => literals.last_mut().unwrap().push(c),
+        }
+    }
+    literals
+}
+
+/// La traducción: `{}` es el siguiente hueco y `{N}` el N-ésimo (desde 0).
+fn pieces(format: &str) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let mut next = 0;
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                text.push('}');
+            }
+            '{' => {
+                let mut index = String::new();
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        break;
+                    }
+                    index.push(c);
+                }
+                if !text.is_empty() {
+                    pieces.push(Piece::Text(std::mem::take(&mut text)));
+                }
+                let hole = match index.parse() {
+                    Ok(i) => i,
+                    Err(_) => {
+                        next += 1;
+                        next - 1
+                    }
+                };
+                pieces.push(Piece::Hole(hole));
+            }
+            c => text.push(c),
+        }
+    }
+    if !text.is_empty() {
+        pieces.push(Piece::Text(text));
+    }
+    pieces
+}
+
+/// Huecos de un formato de la traducción.
+#[cfg(test)]
+fn holes(pieces: &[Piece]) -> Vec<usize> {
+    pieces
+        .iter()
+        .filter_map(|p| match p {
+            Piece::Hole(i) => Some(*i),
+            Piece::Text(_) => None,
+        })
+        .collect()
+}
+
+/// Pares de líneas de una entrada: la del formato canónico y la de la
+/// traducción, sin sangría. `None` si no tienen las mismas líneas.
+pub(super) fn lines(entry: &(&'static str, &'static str)) -> Option<Vec<(String, String)>> {
+    let split = |text: &str| -> Vec<String> {
+        text.split('\n')
+            .map(|line| line.trim().to_string())
+            .collect()
+    };
+    let (es, en) = (split(entry.0), split(entry.1));
+    if es.len() != en.len() {
+        return None;
+    }
+    Some(
+        es.into_iter()
+            .zip(en)
+            .filter(|(es, _)| !es.is_empty())
+            .collect(),
+    )
+}
+
+/// Comprueba que la traducción use los huecos del formato canónico, cada uno
+/// una vez como mucho.
+#[cfg(test)]
+pub(super) fn consistent(es: &str, en: &str) -> bool {
+    let count = literals(es).len() - 1;
+    let mut used = holes(&pieces(en));
+    used.sort_unstable();
+    let unique = used.len();
+    used.dedup();
+    used.len() == unique && used.iter().all(|&i| i < count)
+}
+
+fn build(entries: &[(&'static str, &'static str)]) -> Table {
+    let mut exact = HashMap::new();
+    let mut templates = Vec::new();
+    for entry in entries {
+        for (es, en) in lines(entry).unwrap_or_default() {
+            let literals = literals(&es);
+            if literals.len() == 1 {
+                exact.entry(literals[0].clone()).or_insert(en);
+                continue;
+            }
+            let letters = literals
+                .iter()
+                .flat_map(|l| l.chars())
+                .filter(|c| c.is_alphabetic())
+                .count();
+            if letters > 0 {
+                templates.push(Template {
+                    literals,
+                    translation: pieces(&en),
+                    letters,
+                });
+            }
+        }
+    }
+    templates.sort_by_key(|t| {
+        let literal: usize = t.literals.iter().map(|l| l.chars().count()).sum();
+        (std::cmp::Reverse(literal), t.literals.len())
+    });
+    Table { exact, templates }
+}
+
+/// Valores de los huecos de `template` si `text` coincide entero con él.
+fn capture<'a>(template: &Template, text: &'a str) -> Option<Vec<&'a str>> {
+    let rest = text.strip_prefix(template.literals[0].as_str())?;
+    let mut values = Vec::with_capacity(template.literals.len() - 1);
+    fill(&template.literals[1..], rest, &mut values).then_some(values)
+}
+
+fn fill<'a>(literals: &[String], text: &'a str, values: &mut Vec<&'a str>) -> bool {
+    let Some((literal, rest)) = literals.split_first() else {
+        return text.is_empty();
+    };
+    if rest.is_empty() {
+        return match text.strip_suffix(literal.as_str()) {
+            Some(value) => {
+                values.push(value);
+                true
+            }
+            None => false,
+        };
+    }
+    if literal.is_empty() {
+        // Dos huecos seguidos: el primero, vacío
+        values.push("");
+        return fill(rest, text, values) || {
+            values.pop();
+            false
+        };
+    }
+    for (at, _) in text.match_indices(literal.as_str()) {
+        values.push(&text[..at]);
+        if fill(rest, &text[at + literal.len()..], values) {
+            return true;
+        }
+        values.pop();
+    }
+    false
+}
+
+fn render(template: &Template, values: &[&str], depth: usize) -> String {
+    let mut out = String::new();
+    for piece in &template.translation {
+        match piece {
+            Piece::Text(text) => out.push_str(text),
+            Piece::Hole(i) => {
+                let value = values.get(*i).copied().unwrap_or_default();
+                out.push_str(&fragment(value, depth + 1).unwrap_or(Cow::Borrowed(value)));
+            }
+        }
+    }
+    out
+}
+
+/// Traducción de un trozo completo, si la tiene.
+fn fragment(text: &str, depth: usize) -> Option<Cow<'_, str>> {
+    if text.trim().is_empty() || depth > MAX_DEPTH {
+        return None;
+    }
+    let table = table();
+    if let Some(en) = table.exact.get(text) {
+        return Some(Cow::Owned(en.clone()));
+    }
+    if let Some(en) = quantity(text) {
+        return Some(Cow::Owned(en));
+    }
+    for template in &table.templates {
+        if depth == 0 && template.letters < MIN_LETTERS {
+            continue;
+        }
+        if let Some(values) = capture(template, text) {
+            return Some(Cow::Owned(render(template, &values, depth)));
+        }
+    }
+    list(text, depth).map(Cow::Owned)
+}
+
+/// `3 archivos`, `1.234 errores`, `sin errores`; o una cifra sola con
+/// separador de miles.
+fn quantity(text: &str) -> Option<String> {
+    if let Some(noun) = NOUNS.iter().find(|noun| noun.es.zero == Some(text)) {
+        return noun.en.zero.map(String::from);
+    }
+    let number = |digits: &str| -> Option<usize> {
+        let groups: Vec<&str> = digits.split('.').collect();
+        let grouped =
+            groups.len() == 1 || (groups[0].len() <= 3 && groups[1..].iter().all(|g| g.len() == 3));
+        let digits_only = groups
+            .iter()
+            .all(|g| !g.is_empty() && g.bytes().all(|b| b.is_ascii_digit()));
+        (grouped && digits_only)
+            .then(|| groups.concat().parse().ok())
+            .flatten()
+    };
+    match text.split_once(' ') {
+        Some((digits, phrase)) => {
+            let n = number(digits)?;
+            NOUNS.iter().find_map(|noun| {
+                let es = noun.forms(Locale::Es);
+                (phrase == es.one || phrase == es.other).then(|| noun.count_in(Locale::En, n))
+            })
+        }
+        None if text.contains('.') => number(text).map(|n| Locale::En.number(n)),
+        None => None,
+    }
+}
+
+/// `1 error, 2 advertencias`: cada elemento de la lista por separado; si
+/// alguno con letras no se traduce, nada.
+fn list(text: &str, depth: usize) -> Option<String> {
+    if !text.contains(", ") {
+        return None;
+    }
+    let mut out = Vec::new();
+    for item in text.split(", ") {
+        if !item.chars().any(char::is_alphabetic) {
+            out.push(Cow::Borrowed(item));
+            continue;
+        }
+        out.push(fragment(item, depth + 1)?);
+    }
+    Some(out.join(", "))
+}
+
+/// Marcador del principio de una línea que se conserva: `->`, `·`, `[!]`,
+/// `0:`, `-`… Sin letras, o una palabra entre corchetes.
+fn marker(text: &str) -> Option<usize> {
+    let end = text.find(' ')?;
+    let token = &text[..end];
+    let bracketed = token.starts_with('[') && token.ends_with(']');
+    (bracketed || !token.chars().any(char::is_alphabetic)).then_some(end + 1)
+}
+
+fn line(text: &str) -> Option<String> {
+    line_from(text, 0).or_else(|| line_from(text, 1))
+}
+
+/// `line` con las plantillas de la profundidad `depth`.
+fn line_from(text: &str, depth: usize) -> Option<String> {
+    let indent = text.len() - text.trim_start().len();
+    let mut start = indent;
+    for _ in 0..=MAX_MARKERS {
+        if let Some(en) = fragment(&text[start..], depth) {
+            return Some(format!("{}{}", &text[..start], en));
+        }
+        start += marker(&text[start..])?;
+        start += text[start..].len() - text[start..].trim_start().len();
+    }
+    None
+}
+
+/// `text` en inglés, línea a línea; lo que no está en la tabla se deja.
+pub(super) fn translate(text: &str) -> Cow<'_, str> {
+    let mut out = String::with_capacity(text.len());
+    let mut changed = false;
+    for (i, source) in text.split('\n').enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let (body, cr) = match source.strip_suffix('\r') {
+            Some(body) => (body, "\r"),
+            None => (source, ""),
+        };
+        match line(body) {
+            Some(en) => {
+                changed = true;
+                out.push_str(&en);
+            }
+            None => out.push_str(body),
+        }
+        out.push_str(cr);
+    }
+    match changed {
+        true => Cow::Owned(out),
+        false => Cow::Borrowed(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_keep_indentation_markers_and_unknown_text() {
+        let text = "  [X] Error (ghost-arg) en fn search (src/search.rs:3)\n    -> Sugerencia: algo que no conoce la tabla\r\nTexto libre";
+        assert_eq!(
+            translate(text),
+            "  [X] Error (ghost-arg) in fn search (src/search.rs:3)\n    -> Suggestion: algo que no conoce la tabla\r\nTexto libre"
+        );
+        // Sin nada que traducir no se copia
+        assert!(matches!(translate("fn search"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn holes_are_translated_when_they_match_whole() {
+        // Cantidades, listas de cantidades y plantillas dentro de huecos
+        assert_eq!(
+            translate("Resumen: 1 error, 1.234 advertencias · umbral: error"),
+            "Summary: 1 error, 1,234 warnings · threshold: error"
+        );
+        assert_eq!(
+            translate("Las secciones de 'id' se contradicen en el tipo y si es opcional del argumento 'id'."),
+            "The sections of 'id' contradict each other on the type and whether it is optional of argument 'id'."
+        );
+        // Un valor de usuario que casualmente es una entrada no se toca si
+        // no llena el hueco entero
+        assert_eq!(
+            translate("Origen: tabla de precios"),
+            "Source: tabla de precios"
+        );
+    }
+
+    #[test]
+    fn explicit_holes_reorder_and_braces_are_literal() {
+        let template = Template {
+            literals: literals("{} de {}"),
+            translation: pieces("{1}'s {0}"),
+            letters: 2,
+        };
+        let values = capture(&template, "ruta de DocsGuard").unwrap();
+        assert_eq!(render(&template, &values, 0), "DocsGuard's ruta");
+        assert_eq!(literals("`{{a}}` {:.0}%"), ["`{a}` ", "%"]);
+        assert!(consistent("{} y {}", "{1} and {0}"));
+        assert!(!consistent("{}", "{} {}"));
+        assert!(!consistent("{}", "{1}"));
+    }
+
+    #[test]
+    fn short_templates_only_fill_holes() {
+        assert_eq!(
+            translate(
+                "Las implementaciones de 'f' en TypeScript y Rust no coinciden: 'id' solo en Rust."
+            ),
+            "The implementations of 'f' in TypeScript and Rust do not match: 'id' only in Rust."
+        );
+        // Si ninguna otra plantilla traduce la línea, también la traducen
+        assert_eq!(translate("  3 entradas en a.ts"), "  3 entries in a.ts");
+    }
+}
@@ -0,0 +1,435 @@
+//! Funciones públicas nuevas sin documentar (`check --require-docs-for-new`).
+//!
+//! Una función es nueva si su archivo no la tenía en el merge-base de la
+//! referencia con `HEAD` (se compara con el árbol de trabajo, así que cuentan
+//! los cambios sin commitear). No son nuevas:
+//!
+//! - las movidas: el mismo nombre desaparece de otro archivo cambiado;
+//! - las renombradas: desaparece una función con la misma firma (tipos de los
+//!   argumentos y retorno iguales, como mucho un nombre de argumento distinto;
+//!   sin argumentos, además, un nombre parecido).
+//!
+//! Cada función pública nueva sin un `@docs` que resuelva a una sección es un
+//! Error `undocumented-new-function`, con un ID sugerido por `id_style`. Las
+//! funciones que ya existían siguen como `unlinked-function` (Info).
+
+use anyhow::Result;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::ids::IdGenerator;
+use crate::core::types::{CodeEntity, DocSection, Rule, Severity, ValidationResult};
+use crate::git;
+use crate::parser::code_parser::{self, Language};
+use crate::paths::ProjectPaths;
+use crate::vfs;
+
+/// Similitud mínima de nombres para emparejar funciones sin argumentos.
+const MIN_RENAME_SIMILARITY: f64 = 0.5;
+
+/// Funciones públicas añadidas desde una referencia.
+#[derive(Debug, Default)]
+pub struct NewFunctions {
+    /// Referencia tal como se indicó.
+    pub since: String,
+    /// `archivo:línea` de las funciones nuevas, en la forma de `CodeEntity::location`.
+    locations: HashSet<String>,
+}
+
+impl NewFunctions {
+    /// Compara `code_files`, ya parseados en `current`, con su versión en el
+    /// merge-base de `since`.
+    pub fn find(
+        project_root: &Path,
+        since: &str,
+        code_files: &[PathBuf],
+        current: &[CodeEntity],
+        config: &Config,
+    ) -> Result<NewFunctions> {
+        let base = git::merge_base(project_root, since)?;
+        let relative = ProjectPaths::new(project_root);
+        let options = config.annotation_options();
+        let mut before = Vec::new();
+        let mut checked: HashSet<PathBuf> = HashSet::new();
+        let mut seen: HashSet<PathBuf> = HashSet::new();
+        for code_file in code_files {
+            let path = relative.normalize(code_file);
+            if path.is_absolute() {
+                // Fuera del proyecto no hay revisión base con la que comparar
+                continue;
+            }
+            let display = config.paths.normalize(code_file);
+            before.extend(parse_at(project_root, &base, &path, &display, options)?);
+            checked.insert(display);
+            seen.insert(path);
+        }
+        let mut after: Vec<CodeEntity> = current
+            .iter()
+            .filter(|e| checked.contains(&e.file_path))
+            .cloned()
+            .collect();
+        // Los demás archivos cambiados solo aportan lo que se movió o renombró desde ellos
+        for path in git::changed_paths(project_root, &base)? {
+            if seen.contains(&path) || Language::from_extension(&path).is_err() {
+                continue;
+            }
+            before.extend(parse_at(project_root, &base, &path, &path, options)?);
+            let current = project_root.join(&path);
+            if vfs::is_file(&current) {
+                let source = vfs::read_to_string(&current).unwrap_or_default();
+                after.extend(parse_source(&source, &path, &path, options));
+            }
+        }
+        let locations = added(&before, &after)
+            .into_iter()
+            .filter(|e| e.is_public && checked.contains(&e.file_path))
+            .map(CodeEntity::location)
+            .collect();
+        Ok(NewFunctions {
+            since: since.to_string(),
+            locations,
+        })
+    }
+
+    pub fn contains(&self, entity: &CodeEntity) -> bool {
+        self.locations.contains(&entity.location())
+    }
+}
+
+/// Entidades de `path` (relativa al proyecto) en el commit `base`, bajo
+/// `display`; ninguna si el archivo no existía.
+fn parse_at(
+    project_root: &Path,
+    base: &str,
+    path: &Path,
+    display: &Path,
+    options: code_parser::AnnotationOptions,
+) -> Result<Vec<CodeEntity>> {
+    Ok(match git::show_file(project_root, base, path)? {
+        Some(source) => parse_source(&source, path, display, options),
+        None => Vec::new(),
+    })
+}
+
+/// Una versión que no se puede parsear no aporta funciones: el `check`
+/// del árbol de trabajo ya reporta los errores de sintaxis.
+pub fn parse_source(
+    source: &str,
+    path: &Path,
+    display: &Path,
+    options: code_parser::AnnotationOptions,
+) -> Vec<CodeEntity> {
+    Language::from_extension(path)
+        .and_then(|language| {
+            code_parser::parse_code_source(
+                source,
+                language,
+                display,
+                options,
+                &mut ParseDiagnostics::default(),
+            )
+        })
+        .unwrap_or_default()
+}
+
+/// Funciones de `after` sin equivalente en `before`: ni en su archivo, ni
+/// movidas desde otro, ni renombradas.
+fn added<'a>(before: &[CodeEntity], after: &'a [CodeEntity]) -> Vec<&'a CodeEntity> {
+    let same = |a: &CodeEntity, b: &CodeEntity| a.file_path == b.file_path && a.name == b.name;
+    let mut candidates: Vec<&CodeEntity> = after
+        .iter()
+        .filter(|a| !before.iter().any(|b| same(a, b)))
+        .collect();
+    let mut removed: Vec<&CodeEntity> = before
+        .iter()
+        .filter(|b| !after.iter().any(|a| same(a, b)))
+        .collect();
+    // Movidas: mismo nombre en otro archivo
+    candidates.retain(
+        |candidate| match removed.iter().position(|r| r.name == candidate.name) {
+            Some(i) => {
+                removed.swap_remove(i);
+                false
+            }
+            None => true,
+        },
+    );
+    // Renombradas: la de firma compatible más parecida, antes en el mismo archivo
+    candidates.retain(|candidate| {
+        let best = removed
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| same_signature(candidate, r))
+            .max_by(|(_, a), (_, b)| {
+                rename_score(candidate, a)
+                    .partial_cmp(&rename_score(candidate, b))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(i, _)| i);
+        match best {
+            Some(i) => {
+                removed.swap_remove(i);
+                false
+            }
+            None => true,
+        }
+    });
+    candidates
+}
+
+/// Firmas casi idénticas: mismo retorno, mismos tipos de argumentos en el
+/// mismo orden y como mucho un nombre de argumento distinto. Sin argumentos
+/// la firma no distingue nada y hace falta un nombre parecido.
+fn same_signature(a: &CodeEntity, b: &CodeEntity) -> bool {
+    if a.return_type != b.return_type || a.args.len() != b.args.len() {
+        return false;
+    }
+    if a.args.is_empty() {
+        return strsim::normalized_levenshtein(&a.name, &b.name) >= MIN_RENAME_SIMILARITY;
+    }
+    let types_match = a
+        .args
+        .iter()
+        .zip(&b.args)
+        .all(|(x, y)| x.type_name == y.type_name);
+    let renamed_args = a
+        .args
+        .iter()
+        .zip(&b.args)
+        .filter(|(x, y)| x.name != y.name)
+        .count();
+    types_match && renamed_args <= 1
+}
+
+/// Preferencia entre candidatas a renombrado: el mismo archivo primero y,
+/// dentro de él, el nombre más parecido.
+fn rename_score(candidate: &CodeEntity, removed: &CodeEntity) -> f64 {
+    let same_file = if candidate.file_path == removed.file_path {
+        1.0
+    } else {
+        0.0
+    };
+    same_file + strsim::normalized_levenshtein(&candidate.name, &removed.name)
+}
+
+/// Un Error por cada función nueva de `new` sin un enlace que resuelva a
+/// una sección. Sustituye al `unlinked-function` de esas funciones.
+pub fn validate_new_functions(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+    new: &NewFunctions,
+    config: &Config,
+    results: &mut Vec<ValidationResult>,
+) {
+    let existing = doc_sections
+        .iter()
+        .map(|s| s.id.clone())
+        .chain(code_entities.iter().filter_map(|e| e.doc_id.clone()))
+        .chain(config.links.links.iter().map(|l| l.doc_id.clone()));
+    let mut ids = IdGenerator::new(&config.id_style, &config.id_modules, existing);
+    let resolves = |id: &str| doc_sections.iter().any(|s| s.id == id);
+    let undocumented: Vec<&CodeEntity> = code_entities
+        .iter()
+        .filter(|e| new.contains(e))
+        .filter(|e| {
+            let linked = e.doc_id.as_deref().is_some_and(resolves)
+                || e.file_link.as_ref().is_some_and(|l| resolves(&l.doc_id));
+            !linked
+        })
+        .collect();
+    results.retain(|r| {
+        r.rule != Rule::UnlinkedFunction
+            || !undocumented
+                .iter()
+                .any(|e| r.code_location.as_deref() == Some(e.location().as_str()))
+    });
+    for entity in undocumented {
+        let id = entity
+            .doc_id
+            .clone()
+            .unwrap_or_else(|| ids.next(&entity.name, &entity.file_path));
+        results.push(ValidationResult {
+            severity: Severity::Error,
+            rule: Rule::UndocumentedNewFunction,
+            message: format!(
+                "Función pública nueva desde '{}' sin documentar: el API nuevo se documenta en el mismo cambio.",
+                new.since
+            ),
+            function_name: Some(entity.name.clone()),
+            code_location: Some(entity.location()),
+            doc_id: entity.doc_id.clone(),
+            doc_location: None,
+            hint: Some(match &entity.doc_id {
+                Some(id) => format!(
+                    "Añade la sección `<!-- @docs-id: {} -->` a la documentación.",
+                    id
+                ),
+                None => format!(
+                    "Añade `/// @docs: [{0}]` antes de la función y la sección `<!-- @docs-id: {0} -->` (o ejecuta `docsguard scaffold`).",
+                    id
+                ),
+            }),
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::code_parser::AnnotationOptions;
+    use crate::parser::lang::typescript::parse_typescript_source;
+
+    fn entities(path: &str, code: &str) -> Vec<CodeEntity> {
+        parse_typescript_source(
+            code,
+            Path::new(path),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap()
+    }
+
+    fn names(added: Vec<&CodeEntity>) -> Vec<&str> {
+        added.iter().map(|e| e.name.as_str()).collect()
+    }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?}", args);
+    }
+
+    #[test]
+    fn moved_and_renamed_functions_are_not_new() {
+        let before = [
+            entities(
+                "src/a.ts",
+                "export function login(user: string, password: string): boolean {}\n\
+                 export function legacy(id: number) {}\n\
+                 export function ping() {}\n",
+            ),
+            entities("src/b.ts", "export function helper(x: number) {}\n"),
+        ]
+        .concat();
+        let after = [
+            entities(
+                "src/a.ts",
+                // login renombrada con un argumento también renombrado; legacy y ping, eliminadas
+                "export function signIn(user: string, secret: string): boolean {}\n\
+                 export function pong() {}\n\
+                 export function refresh(token: string) {}\n",
+            ),
+            // helper movida de b.ts a c.ts
+            entities("src/c.ts", "export function helper(x: number) {}\n"),
+        ]
+        .concat();
+        assert_eq!(names(added(&before, &after)), ["refresh"]);
+    }
+
+    #[test]
+    fn different_signatures_and_unrelated_names_stay_new() {
+        let before = entities(
+            "src/a.ts",
+            "export function remove(id: number) {}\nexport function reset() {}\n",
+        );
+        let after = entities(
+            "src/a.ts",
+            "export function create(name: string) {}\nexport function fetchAll() {}\n",
+        );
+        assert_eq!(names(added(&before, &after)), ["create", "fetchAll"]);
+    }
+
+    #[test]
+    fn only_new_public_functions_of_the_checked_files_need_docs() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let write = |path: &str, content: &str| std::fs::write(root.join(path), content).unwrap();
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        git(root, &["init", "-q"]);
+        write(
+            "src/auth.ts",
+            "export function login(user: string) {}\nexport function legacy() {}\n",
+        );
+        write("src/util.ts", "export function slugify(text: string) {}\n");
+        git(root, &["add", "."]);
+        git(root, &["commit", "-q", "-m", "base"]);
+        git(root, &["tag", "base"]);
+
+        // Segunda revisión: login renombrada, slugify movida desde util.ts,
+        // dos públicas nuevas (una documentada) y una privada
+        write(
+            "src/auth.ts",
+            "export function signIn(user: string) {}\n\
+             export function legacy() {}\n\
+             export function slugify(text: string) {}\n\
+             /// @docs: [auth-logout]\n\
+             export function logout(token: string) {}\n\
+             export function refresh(token: string, ttl: number) {}\n\
+             function internal(x: number) {}\n",
+        );
+        std::fs::remove_file(root.join("src/util.ts")).unwrap();
+        git(root, &["commit", "-q", "-am", "head"]);
+        write(
+            "docs/api.md",
+            "<!-- @docs-id: auth-logout -->\n## Logout\n\n| Param | Type |\n|---|---|\n| token | string |\n",
+        );
+
+        let config = Config::load(root).unwrap();
+        let code_files = [root.join("src/auth.ts")];
+        let entities =
+            code_parser::parse_project_code(&code_files, &config, &mut ParseDiagnostics::default())
+                .unwrap();
+        let new = NewFunctions::find(root, "base", &code_files, &entities, &config).unwrap();
+        let mut is_new: Vec<&str> = entities
+            .iter()
+            .filter(|e| new.contains(e))
+            .map(|e| e.name.as_str())
+            .collect();
+        is_new.sort_unstable();
+        assert_eq!(is_new, ["logout", "refresh"]);
+
+        let sections = crate::parser::doc_parser::parse_docs_with_diagnostics(
+            &root.join("docs/api.md"),
+            &config,
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let mut results =
+            crate::core::validator::validate_links(&entities, &sections, &Default::default());
+        validate_new_functions(&entities, &sections, &new, &config, &mut results);
+        let errors: Vec<&ValidationResult> = results
+            .iter()
+            .filter(|r| r.rule == Rule::UndocumentedNewFunction)
+            .collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].function_name.as_deref(), Some("refresh"));
+        assert_eq!(errors[0].severity, Severity::Error);
+        assert!(errors[0]
+            .hint
+            .as_deref()
+            .unwrap()
+            .contains("`/// @docs: [refresh]`"));
+        // Las que ya existían siguen siendo Info; refresh deja de serlo
+        let unlinked: Vec<&str> = results
+            .iter()
+            .filter(|r| r.rule == Rule::UnlinkedFunction)
+            .filter_map(|r| r.function_name.as_deref())
+            .collect();
+        assert!(unlinked.contains(&"legacy") && !unlinked.contains(&"refresh"));
+    }
+}
@@ -0,0 +1,226 @@
+//! Emparejado de cada archivo de código con su archivo de docs
+//! (`pairing: colocated-readme`).
+//!
+//! Los módulos pequeños suelen tener sus docs en un `README.md` junto al
+//! código. Con `colocated-readme`, `check-all` valida cada archivo de código
+//! con su par, y los archivos que comparten par se validan juntos: una
+//! sección del README enlazada desde cualquiera de ellos no es huérfana. El
+//! par se decide así, en este orden y sin depender del orden de recorrido:
+//!
+//! 1. Las combinaciones de `combos:` que incluyen el archivo: gana la
+//!    primera por orden alfabético de nombre, con su `doc_file`.
+//! 2. `README.md` (el nombre exacto) en el directorio del archivo.
+//! 3. `README.md` en el directorio padre, y así hacia arriba hasta la raíz
+//!    del proyecto incluida, o hasta `readme_ancestors` niveles por encima
+//!    del directorio del archivo.
+//!
+//! Sin par, el archivo se valida con el resto de docs del repositorio, como
+//! sin `pairing`. `docsguard config show --resolved-pairs` lista los pares.
+
+use anyhow::Result;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::check_all;
+use crate::config::{Config, Pairing};
+use crate::messages;
+use crate::parser::code_parser::safe_display;
+use crate::paths;
+use crate::vfs;
+
+/// Nombre del archivo de docs colocado.
+pub const README: &str = "README.md";
+
+/// De dónde sale el par de un archivo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PairSource {
+    /// `combos.<nombre>`.
+    Combo(String),
+    /// README encontrado `levels` directorios por encima del del archivo
+    /// (0: el suyo).
+    Readme { levels: usize },
+}
+
+/// Archivo de código con su archivo de docs, si tiene.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pair {
+    pub code: PathBuf,
+    pub doc: Option<(PathBuf, PairSource)>,
+}
+
+/// Pares de `code_files` (rutas bajo `root`), en el mismo orden.
+pub fn resolve(root: &Path, code_files: &[PathBuf], config: &Config) -> Result<Vec<Pair>> {
+    // Archivo canónico → combinación que lo incluye, la primera por nombre
+    let mut combos: HashMap<PathBuf, (&str, PathBuf)> = HashMap::new();
+    for (name, combo) in &config.combos {
+        let inputs: Vec<PathBuf> = combo.code.iter().map(|p| root.join(p)).collect();
+        for file in paths::expand_inputs(&inputs)? {
+            if let Ok(canonical) = vfs::canonicalize(&file.path) {
+                combos
+                    .entry(canonical)
+                    .or_insert_with(|| (name.as_str(), root.join(&combo.doc_file)));
+            }
+        }
+    }
+    Ok(code_files
+        .iter()
+        .map(|code| {
+            let combo = vfs::canonicalize(code)
+                .ok()
+                .and_then(|canonical| combos.get(&canonical));
+            let doc = match combo {
+                Some((name, doc)) => Some((doc.clone(), PairSource::Combo(name.to_string()))),
+                None => colocated_readme(root, code, config.readme_ancestors),
+            };
+            Pair {
+                code: code.clone(),
+                doc,
+            }
+        })
+        .collect())
+}
+
+/// `README.md` más cercano a `code`, subiendo como mucho `max_levels`
+/// directorios y nunca por encima de `root`.
+fn colocated_readme(
+    root: &Path,
+    code: &Path,
+    max_levels: Option<usize>,
+) -> Option<(PathBuf, PairSource)> {
+    let root_canonical = vfs::canonicalize(root).ok();
+    let mut dir = code.parent()?;
+    for levels in 0.. {
+        let readme = dir.join(README);
+        if vfs::is_file(&readme) {
+            return Some((readme, PairSource::Readme { levels }));
+        }
+        let at_root = dir == root || vfs::canonicalize(dir).ok() == root_canonical;
+        if at_root || max_levels.is_some_and(|max| levels >= max) {
+            return None;
+        }
+        dir = dir.parent().filter(|p| !p.as_os_str().is_empty())?;
+    }
+    None
+}
+
+/// `docsguard config show --resolved-pairs`: los pares del código que
+/// recorrería `check-all`.
+pub fn run_resolved_pairs(project_root: &Path) -> Result<()> {
+    let config = Config::load(project_root)?;
+    let code: Vec<PathBuf> = check_all::discover(project_root)?
+        .code
+        .into_iter()
+        .map(|(file, _)| file)
+        .collect();
+    print!(
+        "{}",
+        render(&resolve(project_root, &code, &config)?, &config)
+    );
+    Ok(())
+}
+
+/// Texto de `config show --resolved-pairs`, con rutas relativas a la raíz.
+pub fn render(pairs: &[Pair], config: &Config) -> String {
+    let mut out = match config.pairing {
+        Pairing::ColocatedReadme => String::from("# pairing: colocated-readme\n"),
+        Pairing::Off => {
+            String::from("# pairing: off — check-all valida todo junto; así se emparejaría:\n")
+        }
+    };
+    for pair in pairs {
+        let code = safe_display(&config.paths.normalize(&pair.code)).to_string();
+        let line = match &pair.doc {
+            Some((doc, source)) => {
+                let doc = safe_display(&config.paths.normalize(doc)).to_string();
+                match source {
+                    PairSource::Combo(name) => format!("{} -> {} (combos.{})", code, doc, name),
+                    PairSource::Readme { levels: 0 } => {
+                        format!("{} -> {} (mismo directorio)", code, doc)
+                    }
+                    PairSource::Readme { levels } => format!(
+                        "{} -> {} ({} más arriba)",
+                        code,
+                        doc,
+                        messages::DIRECTORIES.count(*levels)
+                    ),
+                }
+            }
+            None => format!("{} -> sin par: se valida con el resto de docs", code),
+        };
+        out.push_str(&messages::tr(&line));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Combo;
+    use crate::vfs::MemFs;
+
+    #[test]
+    fn combos_win_then_the_nearest_readme_up_to_the_root_or_the_limit() {
+        let dir = MemFs::project();
+        let root = dir.path();
+        for file in [
+            "README.md",
+            "src/auth/README.md",
+            "src/auth/login.ts",
+            "src/auth/oauth/google.ts",
+            "src/billing/charge.ts",
+            "src/billing/refund.ts",
+            "docs/billing.md",
+        ] {
+            dir.write(root.join(file), "");
+        }
+        let mut config = Config {
+            pairing: Pairing::ColocatedReadme,
+            ..Config::default()
+        };
+        config.combos.insert(
+            "billing".into(),
+            Combo {
+                doc_file: "docs/billing.md".into(),
+                code: vec!["src/billing/refund.ts".into()],
+                ..Combo::default()
+            },
+        );
+        let code: Vec<PathBuf> = [
+            "src/auth/login.ts",
+            "src/auth/oauth/google.ts",
+            "src/billing/charge.ts",
+            "src/billing/refund.ts",
+        ]
+        .iter()
+        .map(|f| root.join(f))
+        .collect();
+
+        let docs = |config: &Config| -> Vec<Option<(PathBuf, PairSource)>> {
+            resolve(root, &code, config)
+                .unwrap()
+                .into_iter()
+                .map(|pair| pair.doc)
+                .collect()
+        };
+        let readme = |file: &str, levels| Some((root.join(file), PairSource::Readme { levels }));
+        assert_eq!(
+            docs(&config),
+            [
+                readme("src/auth/README.md", 0),
+                readme("src/auth/README.md", 1),
+                readme("README.md", 2),
+                Some((
+                    root.join("docs/billing.md"),
+                    PairSource::Combo("billing".into())
+                )),
+            ]
+        );
+
+        config.readme_ancestors = Some(1);
+        assert_eq!(docs(&config)[2], None);
+        config.readme_ancestors = Some(0);
+        assert_eq!(docs(&config)[1], None);
+        assert_eq!(docs(&config)[0], readme("src/auth/README.md", 0));
+    }
+}
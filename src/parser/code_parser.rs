@@ -4,25 +4,78 @@
 //! compartidas para la extracción de anotaciones `@docs`.
 
 use anyhow::{bail, Context, Result};
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
-use crate::core::types::CodeEntity;
+use crate::config::Config;
+use crate::core::conflicts;
+use crate::core::constraints::{self, UnitKeywords};
+use crate::core::diagnostics::{ParseDiagnostics, ParseNote};
+use crate::core::suppression::parse_ignore_directive;
+use crate::core::types::{Arg, CodeEntity, CodeExample, FileLink, Rule, Suppression};
+use crate::exit::Failure;
 use crate::parser::lang;
+use crate::paths::{InputFile, ProjectPaths};
+use crate::transaction::Transaction;
+use crate::vfs::{self, Bounded};
 
-/// Lenguajes soportados por el code parser.
+/// Líneas en blanco que se toleran, por defecto, entre una anotación y la función.
+pub const DEFAULT_ANNOTATION_MAX_GAP: usize = 1;
+
+/// Cómo se busca el bloque de comentarios previo a una función.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnnotationOptions {
+    /// Máximo de líneas en blanco entre dos comentarios del bloque, o entre
+    /// el último y la función (`annotation_max_gap`). Pasado ese hueco no
+    /// se enlaza nada.
+    pub max_gap: usize,
+}
+
+impl Default for AnnotationOptions {
+    fn default() -> Self {
+        AnnotationOptions {
+            max_gap: DEFAULT_ANNOTATION_MAX_GAP,
+        }
+    }
+}
+
+/// Pista de los errores de un archivo sin lenguaje soportado.
+const SUPPORTED_LANGUAGES: &str =
+    "Lenguajes soportados: TypeScript (.ts/.tsx), Rust (.rs), Python (.py), Go (.go), Java (.java), C# (.cs)";
+
+/// Lenguajes soportados por el code parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum Language {
+    #[value(name = "ts", alias = "typescript")]
     TypeScript,
     Rust,
     Python,
     Go,
     Java,
+    #[value(name = "csharp", alias = "cs")]
     CSharp,
 }
 
+/// Lenguaje del código leído de stdin (`--code-language`).
+static STDIN_LANGUAGE: OnceLock<Language> = OnceLock::new();
+
+/// Fija el lenguaje con el que se parsea `-`. Solo cuenta la primera llamada.
+pub fn set_stdin_language(language: Language) {
+    let _ = STDIN_LANGUAGE.set(language);
+}
+
 impl Language {
-    /// Detecta el lenguaje a partir de la extensión del archivo.
+    /// Detecta el lenguaje a partir de la extensión del archivo. `-` (stdin)
+    /// no tiene extensión: su lenguaje es el de `--code-language`.
     pub fn from_extension(path: &Path) -> Result<Self> {
+        if vfs::is_stdin(path) {
+            return STDIN_LANGUAGE.get().copied().ok_or_else(|| {
+                Failure::usage("El código leído de stdin necesita `--code-language`.")
+                    .with_hint("Por ejemplo: `docsguard check docs/api.md - --code-language ts`.")
+                    .into()
+            });
+        }
         match path.extension().and_then(|e| e.to_str()) {
             Some("ts" | "tsx") => Ok(Language::TypeScript),
             Some("js" | "jsx") => Ok(Language::TypeScript), // tree-sitter-typescript parsea JS
@@ -31,14 +84,38 @@ impl Language {
             Some("go") => Ok(Language::Go),
             Some("java") => Ok(Language::Java),
             Some("cs") => Ok(Language::CSharp),
-            Some(ext) => bail!(
-                "Extensión '.{}' no soportada.\n    -> Lenguajes soportados: TypeScript (.ts/.tsx), Rust (.rs), Python (.py), Go (.go), Java (.java), C# (.cs)",
+            Some(ext) => bail!(Failure::unsupported_language(format!(
+                "Extensión '.{}' no soportada.",
                 ext
-            ),
-            None => bail!(
-                "El archivo '{}' no tiene extensión.\n    -> No se puede determinar el lenguaje.",
+            ))
+            .with_path(path)
+            .with_hint(SUPPORTED_LANGUAGES)),
+            None => bail!(Failure::unsupported_language(format!(
+                "El archivo '{}' no tiene extensión.",
                 path.display()
-            ),
+            ))
+            .with_path(path)
+            .with_hint("No se puede determinar el lenguaje.")),
+        }
+    }
+
+    /// Nombre del lenguaje en los mensajes.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::TypeScript => "TypeScript",
+            Language::Rust => "Rust",
+            Language::Python => "Python",
+            Language::Go => "Go",
+            Language::Java => "Java",
+            Language::CSharp => "C#",
+        }
+    }
+
+    /// Prefijo de comentario de línea usado al insertar anotaciones o directivas.
+    pub fn line_comment_prefix(&self) -> &'static str {
+        match self {
+            Language::Python => "#",
+            _ => "//",
         }
     }
 }
@@ -61,7 +138,7 @@ pub fn create_tree(
         .with_context(|| format!("Error al configurar tree-sitter con {}", lang_name))?;
     parser
         .parse(source, None)
-        .ok_or_else(|| anyhow::anyhow!("Error al parsear el archivo {}", lang_name))
+        .ok_or_else(|| Failure::parse(format!("Error al parsear el archivo {}", lang_name)).into())
 }
 
 /// Verifica que un archivo existe y retorna un error educativo si no.
@@ -69,102 +146,749 @@ pub fn create_tree(
 /// Refactorizado: función DRY compartida por todos los comandos CLI —
 /// elimina el patrón repetido `if !path.exists() { bail!(...) }`.
 pub fn require_file_exists(path: &Path, kind: &str) -> Result<()> {
-    if !path.exists() {
-        anyhow::bail!(
-            "Archivo de {} no encontrado: {}\n    -> Verifica que la ruta sea correcta.",
+    if !vfs::exists(path) {
+        bail!(Failure::not_found(format!(
+            "Archivo de {} no encontrado: {}",
             kind,
             path.display()
-        );
+        ))
+        .with_path(path)
+        .with_hint("Verifica que la ruta sea correcta."));
     }
     Ok(())
 }
 
+/// Separa de las entradas expandidas (`paths::expand_inputs`) los archivos
+/// que se descubrieron en un directorio o glob y no tienen lenguaje soportado:
+/// se omiten con una nota `skipped-file` en lugar de abortar. Un archivo
+/// nombrado a mano sigue fallando al parsearlo.
+pub fn split_supported(
+    inputs: Vec<InputFile>,
+    paths: &ProjectPaths,
+) -> (Vec<PathBuf>, Vec<ParseNote>) {
+    let mut files = Vec::new();
+    let mut skipped = Vec::new();
+    for input in inputs {
+        if !input.discovered || Language::from_extension(&input.path).is_ok() {
+            files.push(input.path);
+            continue;
+        }
+        let reason = match input.path.extension() {
+            Some(ext) => format!("extensión '.{}' no soportada", ext.to_string_lossy()),
+            None => "sin extensión".to_string(),
+        };
+        let location = safe_display(&paths.normalize(&input.path));
+        skipped.push(ParseNote {
+            rule: Rule::SkippedFile,
+            message: format!("Se omite {}: {}.", location, reason),
+            function_name: None,
+            code_location: Some(location),
+            doc_id: None,
+            doc_location: None,
+            hint: Some(SUPPORTED_LANGUAGES.to_string()),
+        });
+    }
+    (files, skipped)
+}
+
 /// @docs: [parse-code-file]
 /// Parsea un archivo de código auto-detectando el lenguaje por extensión.
 pub fn parse_code_file(file_path: &Path) -> Result<Vec<CodeEntity>> {
-    use std::io::Read;
-    // VUL-03: abrir una sola vez — el check de tamaño y la lectura comparten el mismo fd,
-    // eliminando la ventana TOCTOU entre metadata() y read_to_string().
-    let mut file = std::fs::File::open(file_path)
-        .with_context(|| format!("No se pudo abrir: {}", file_path.display()))?;
-    let metadata = file
-        .metadata()
-        .with_context(|| format!("No se pudo leer metadata: {}", file_path.display()))?;
-    if metadata.len() > MAX_FILE_SIZE {
-        anyhow::bail!(
+    parse_code_file_with_diagnostics(file_path, &mut ParseDiagnostics::default())
+}
+
+/// Como `parse_code_file`, registrando en `diagnostics` las ambigüedades encontradas.
+pub fn parse_code_file_with_diagnostics(
+    file_path: &Path,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<Vec<CodeEntity>> {
+    parse_code_file_as(
+        file_path,
+        file_path,
+        AnnotationOptions::default(),
+        diagnostics,
+    )
+}
+
+/// Parsea los archivos de código del proyecto con rutas relativas a su raíz
+/// (`config.paths`), sin las funciones de `ignore_functions` y aplicando los
+/// enlaces de `.docsguard/links.yaml`. Punto de entrada de los comandos que
+/// emiten hallazgos.
+pub fn parse_project_code(
+    code_files: &[PathBuf],
+    config: &Config,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<Vec<CodeEntity>> {
+    let mut entities = Vec::new();
+    for code_file in code_files {
+        let file_entities = read_source_file(code_file)
+            .and_then(|source| {
+                let language = Language::from_extension(code_file)?;
+                parse_project_source(&source, language, code_file, config, diagnostics)
+            })
+            .with_context(|| format!("Error al parsear {}", code_file.display()))?;
+        entities.extend(file_entities);
+    }
+    Ok(entities)
+}
+
+/// Como `parse_project_code` para un archivo ya leído (`check-all`, que lee
+/// cada archivo una sola vez).
+pub fn parse_project_source(
+    source: &str,
+    language: Language,
+    code_file: &Path,
+    config: &Config,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<Vec<CodeEntity>> {
+    let display_path = config.paths.normalize(code_file);
+    let mut entities = parse_code_source(
+        source,
+        language,
+        &display_path,
+        config.annotation_options(),
+        diagnostics,
+    )?;
+    ignore_functions(&mut entities, &config.ignore_functions, diagnostics);
+    config
+        .links
+        .apply(&display_path, &mut entities, diagnostics);
+    constraints::annotate_entities(&mut entities, &UnitKeywords::new(&config.units));
+    Ok(entities)
+}
+
+/// Descarta de `entities` las funciones cuyo nombre encaja con algún glob de
+/// `patterns` y lo anota en `diagnostics`. Va antes de `links.yaml`: las
+/// entidades que `--fast` guarda ya están filtradas, y un enlace externo a una
+/// función ignorada se reporta igual con o sin registro.
+fn ignore_functions(
+    entities: &mut Vec<CodeEntity>,
+    patterns: &[String],
+    diagnostics: &mut ParseDiagnostics,
+) {
+    if patterns.is_empty() {
+        return;
+    }
+    entities.retain(|entity| {
+        let ignored_by = patterns
+            .iter()
+            .find(|pattern| crate::paths::glob_matches(pattern, Path::new(&entity.name)));
+        if let Some(pattern) = ignored_by {
+            diagnostics.ignored_functions.push(entity.file_path.clone());
+            diagnostics.ignore_hits.insert(pattern.clone());
+        }
+        ignored_by.is_none()
+    });
+}
+
+/// Parsea `file_path` registrando las entidades bajo `display_path`.
+fn parse_code_file_as(
+    file_path: &Path,
+    display_path: &Path,
+    options: AnnotationOptions,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<Vec<CodeEntity>> {
+    let source = read_source_file(file_path)?;
+    let language = Language::from_extension(file_path)?;
+    parse_code_source(&source, language, display_path, options, diagnostics)
+}
+
+/// Parsea `source` como código de `language`, con las entidades bajo
+/// `display_path` (contenido de otra revisión, `check --require-docs-for-new`).
+pub fn parse_code_source(
+    source: &str,
+    language: Language,
+    display_path: &Path,
+    options: AnnotationOptions,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<Vec<CodeEntity>> {
+    conflicts::note(source, display_path, false, diagnostics);
+    let mut entities = match language {
+        Language::TypeScript => {
+            lang::typescript::parse_typescript_source(source, display_path, options, diagnostics)
+        }
+        Language::Rust => lang::rust::parse_rust_source(source, display_path, options, diagnostics),
+        Language::Python => {
+            lang::python::parse_python_source(source, display_path, options, diagnostics)
+        }
+        Language::Go => lang::go::parse_go_source(source, display_path, options, diagnostics),
+        Language::Java => lang::java::parse_java_source(source, display_path, options, diagnostics),
+        Language::CSharp => {
+            lang::c_sharp::parse_c_sharp_source(source, display_path, options, diagnostics)
+        }
+    }?;
+
+    if let Some(link) = find_file_link(source) {
+        for entity in &mut entities {
+            entity.file_link = Some(link.clone());
+        }
+    }
+    Ok(entities)
+}
+
+/// Nombres exportados de un archivo (funciones, clases, tipos, constantes…).
+///
+/// Solo TypeScript/JavaScript (`export`) y Rust (`pub`); para el resto de
+/// lenguajes basta con las entidades públicas.
+pub fn parse_exported_symbols(file_path: &Path) -> Result<Vec<String>> {
+    let language = Language::from_extension(file_path)?;
+    if !matches!(language, Language::TypeScript | Language::Rust) {
+        return Ok(Vec::new());
+    }
+    let source = read_source_file(file_path)?;
+    match language {
+        Language::TypeScript => lang::typescript::exported_symbols(&source),
+        _ => lang::rust::exported_symbols(&source),
+    }
+}
+
+/// Nombres que un archivo declara sin exportar (helpers, variables, métodos
+/// privados), para `internal-symbol-reference`.
+///
+/// Solo TypeScript/JavaScript y Rust, como `parse_exported_symbols`; para el
+/// resto de lenguajes, las funciones no públicas de sus entidades.
+pub fn parse_internal_symbols(file_path: &Path) -> Result<Vec<String>> {
+    let language = Language::from_extension(file_path)?;
+    if !matches!(language, Language::TypeScript | Language::Rust) {
+        return Ok(Vec::new());
+    }
+    let source = read_source_file(file_path)?;
+    match language {
+        Language::TypeScript => lang::typescript::internal_symbols(&source),
+        _ => lang::rust::internal_symbols(&source),
+    }
+}
+
+/// Lee un archivo de código respetando el límite de tamaño.
+pub(crate) fn read_source_file(file_path: &Path) -> Result<String> {
+    // VUL-03: el check de tamaño y la lectura comparten el mismo fd (`vfs::read_bounded`).
+    match vfs::read_bounded(file_path, MAX_FILE_SIZE)
+        .with_context(|| format!("No se pudo leer el archivo: {}", file_path.display()))?
+    {
+        Bounded::Contents(source) => Ok(source),
+        Bounded::TooLarge(len) => bail!(Failure::input(format!(
             "Archivo demasiado grande ({:.1} MB, máximo: {} MB): {}",
-            metadata.len() as f64 / (1024.0 * 1024.0),
+            len as f64 / (1024.0 * 1024.0),
             MAX_FILE_SIZE / (1024 * 1024),
             file_path.display()
-        );
+        ))
+        .with_path(file_path)),
     }
-    let mut source = String::with_capacity(metadata.len() as usize);
-    file.read_to_string(&mut source)
-        .with_context(|| format!("No se pudo leer el archivo: {}", file_path.display()))?;
+}
 
-    let language = Language::from_extension(file_path)?;
+/// Marcador de enlace a nivel de archivo.
+pub const FILE_LINK_MARKER: &str = "@docs-file:";
 
-    match language {
-        Language::TypeScript => lang::typescript::parse_typescript_source(&source, file_path),
-        Language::Rust => lang::rust::parse_rust_source(&source, file_path),
-        Language::Python => lang::python::parse_python_source(&source, file_path),
-        Language::Go => lang::go::parse_go_source(&source, file_path),
-        Language::Java => lang::java::parse_java_source(&source, file_path),
-        Language::CSharp => lang::c_sharp::parse_c_sharp_source(&source, file_path),
+/// Líneas de cabecera inspeccionadas en busca de `@docs-file`.
+const FILE_LINK_MAX_LINES: usize = 40;
+
+/// Pre-pass: busca `@docs-file: id` en la cabecera del archivo.
+///
+/// Solo se recorren líneas vacías, comentarios y boilerplate habitual
+/// (shebang, atributos `#![...]`, `"use strict"`, `package`); la primera línea
+/// de código real termina la búsqueda. Acepta `//! @docs-file: id` (Rust),
+/// `// @docs-file: [id]`, `# @docs-file: id` y líneas de bloques `/* … */`.
+pub fn find_file_link(source: &str) -> Option<FileLink> {
+    for (i, line) in source.lines().take(FILE_LINK_MAX_LINES).enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || is_header_boilerplate(trimmed) {
+            continue;
+        }
+        let is_comment = ["//", "/*", "*"]
+            .iter()
+            .any(|prefix| trimmed.starts_with(prefix))
+            || (trimmed.starts_with('#') && !trimmed.starts_with("#["));
+        if !is_comment {
+            return None;
+        }
+
+        let content = trimmed.trim_start_matches(['/', '!', '*', '#']).trim();
+        if let Some(rest) = content.strip_prefix(FILE_LINK_MARKER) {
+            let id = rest.trim().trim_end_matches("*/").trim();
+            let id = id
+                .strip_prefix('[')
+                .and_then(|id| id.strip_suffix(']'))
+                .unwrap_or(id)
+                .trim();
+            // VUL-01: mismo alfabeto que los IDs de función
+            return is_valid_id(id).then(|| FileLink {
+                doc_id: id.to_string(),
+                line: i + 1,
+            });
+        }
+    }
+    None
+}
+
+/// Líneas de cabecera que no son código significativo.
+fn is_header_boilerplate(line: &str) -> bool {
+    line.starts_with("#!")
+        || line.starts_with("package ")
+        || matches!(
+            line.trim_end_matches(';'),
+            "'use strict'" | "\"use strict\""
+        )
+}
+
+/// Anotaciones encontradas en el bloque de comentarios previo a una función.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Annotations {
+    /// ID de documentación (`@docs: [id]`).
+    pub doc_id: Option<String>,
+    /// Reglas suprimidas con `docsguard-ignore`.
+    pub suppressions: Vec<Suppression>,
+    /// Todas las anotaciones del bloque, con su línea y de arriba abajo, cuando
+    /// nombran más de un ID distinto: no se enlaza ninguno (DG001).
+    pub conflicting_ids: Vec<(String, usize)>,
+    /// Anotación separada de la función por un hueco grande, con su línea
+    /// (no se enlaza; DG002).
+    pub detached_id: Option<(String, usize)>,
+    /// Comentarios que parecen una anotación `@docs` pero no cumplen la sintaxis.
+    pub malformed: Vec<MalformedAnnotation>,
+    /// Descripciones de argumentos del doc-comment (`@param name desc`, `` * `name` - desc ``).
+    pub arg_descriptions: Vec<(String, String)>,
+    /// Bloques `@example` de los comentarios JSDoc del bloque, de arriba abajo.
+    pub examples: Vec<CodeExample>,
+}
+
+impl Annotations {
+    /// Copia a los argumentos de la firma su descripción del doc-comment.
+    pub fn describe_args(&self, args: &mut [Arg]) {
+        for arg in args.iter_mut().filter(|a| a.description.is_none()) {
+            arg.description = self
+                .arg_descriptions
+                .iter()
+                .find(|(name, _)| *name == arg.name)
+                .map(|(_, description)| description.clone());
+        }
+    }
+}
+
+/// IDs anotados en los métodos de traits (Rust) o interfaces (TypeScript) de
+/// un archivo, que heredan los métodos que los implementan sin `@docs` propio.
+#[derive(Debug, Clone, Default)]
+pub struct InheritedLinks {
+    /// Trait o interfaz → método → ID.
+    by_owner: HashMap<String, HashMap<String, String>>,
+}
+
+impl InheritedLinks {
+    pub fn insert(&mut self, owner: &str, method: &str, doc_id: String) {
+        self.by_owner
+            .entry(owner_name(owner).to_string())
+            .or_default()
+            .insert(method.to_string(), doc_id);
+    }
+
+    /// ID del método `method` en el primero de `owners` que lo anota.
+    /// `owners` se escriben como en el código (`io::Storage`, `Repo<User>`).
+    pub fn get<'a>(&self, owners: impl IntoIterator<Item = &'a str>, method: &str) -> Option<&str> {
+        owners.into_iter().find_map(|owner| {
+            self.by_owner
+                .get(owner_name(owner))
+                .and_then(|methods| methods.get(method))
+                .map(String::as_str)
+        })
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_owner.is_empty()
+    }
+}
+
+/// Nombre de un trait o interfaz sin ruta ni argumentos de tipo.
+fn owner_name(owner: &str) -> &str {
+    let owner = owner.split('<').next().unwrap_or(owner).trim();
+    owner.rsplit(['.', ':']).next().unwrap_or(owner)
+}
+
+/// Comentario con aspecto de anotación `@docs` que la extracción estricta rechaza
+/// (`@docs [id]`, `@docs: [id`, `@doc: [id]`…).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MalformedAnnotation {
+    /// Línea del comentario, recortada.
+    pub text: String,
+    /// Línea (1-indexed).
+    pub line: usize,
+    /// Sintaxis corregida sugerida.
+    pub suggestion: String,
 }
 
-/// Busca la anotación `/// @docs: [id]` en los comentarios previos a un nodo.
+/// Atributos y decoradores que quedan entre la anotación y la función como
+/// nodos hermanos (`#[inline]` en Rust, `@Post()` en TypeScript); en Java y
+/// C# forman parte de la declaración.
+const ATTRIBUTE_KINDS: [&str; 2] = ["attribute_item", "decorator"];
+
+/// Recorre el bloque contiguo de comentarios previo a un nodo y extrae
+/// la anotación `@docs` y las directivas `docsguard-ignore`.
 ///
-/// `comment_kind` varía según el lenguaje:
-/// - TypeScript: `"comment"`
-/// - Rust: `"line_comment"`
-pub fn find_docs_annotation(
+/// `comment_kinds` lista los tipos de nodo que cuentan como comentario
+/// (Java y Rust, por ejemplo, usan `line_comment` y `block_comment`).
+/// El bloque se corta en el primer hueco de más de `options.max_gap`
+/// líneas en blanco, y no incluye el comentario al final de la línea de
+/// otro nodo (`} // @docs: [id]` es del código que cierra).
+pub fn find_annotations(
     func_node: &tree_sitter::Node,
     source: &[u8],
     parent_node: &tree_sitter::Node,
-    comment_kind: &str,
-) -> Option<String> {
+    comment_kinds: &[&str],
+    options: AnnotationOptions,
+) -> Annotations {
     let func_start = func_node.start_position().row;
+    let mut annotations = Annotations::default();
 
     let mut cursor = parent_node.walk();
     let siblings: Vec<_> = parent_node.children(&mut cursor).collect();
 
-    // Rastrear la fila del nodo anterior para medir gaps entre comentarios
-    // consecutivos, no desde la función (que puede estar lejos si hay
-    // múltiples líneas de doc-comments).
-    let mut prev_row = func_start;
+    // Medir el hueco contra el nodo anterior (el comentario siguiente o la
+    // función), no siempre desde la función: un bloque de doc-comments
+    // puede ocupar muchas líneas.
+    let mut next_start = func_node.start_byte();
+    // Pasado un hueco grande ya no se enlaza nada: el bloque siguiente solo
+    // se inspecciona para informar de anotaciones desconectadas.
+    let mut detached = false;
+    // IDs del bloque enlazable con su línea, de la función hacia arriba
+    let mut ids: Vec<(String, usize)> = Vec::new();
 
-    for sibling in siblings.iter().rev() {
+    for (index, sibling) in siblings.iter().enumerate().rev() {
         let sibling_start_row = sibling.start_position().row;
 
         // Solo mirar nodos que comiencen antes de la función
         if sibling_start_row >= func_start {
             continue;
         }
+        // El nodo que envuelve a la función (`decorated_definition` en Python)
+        if sibling.start_byte() <= func_node.start_byte()
+            && sibling.end_byte() >= func_node.end_byte()
+        {
+            next_start = sibling.start_byte();
+            continue;
+        }
+
+        if blank_lines_between(source, sibling.end_byte(), next_start) > options.max_gap {
+            if detached || !ids.is_empty() {
+                break;
+            }
+            detached = true;
+        }
+
+        next_start = sibling.start_byte();
 
-        // Si hay más de una línea vacía entre este nodo y el anterior, dejar de buscar
-        if prev_row.saturating_sub(sibling_start_row) > 2 {
+        if ATTRIBUTE_KINDS.contains(&sibling.kind()) {
+            continue;
+        }
+        // Si encontramos algo que no es un comentario, dejar de buscar
+        if !comment_kinds.contains(&sibling.kind()) {
             break;
         }
+        // Un comentario al final de la línea de otro nodo es de ese nodo
+        if index > 0 {
+            let previous = &siblings[index - 1];
+            if previous.end_position().row == sibling_start_row
+                && !comment_kinds.contains(&previous.kind())
+            {
+                break;
+            }
+        }
 
-        prev_row = sibling_start_row;
+        if let Ok(text) = sibling.utf8_text(source) {
+            // El texto de los ejemplos no cuenta como anotación ni como `@param`
+            let (examples, text) = split_jsdoc_examples(text, sibling_start_row + 1);
+            let text = text.as_str();
+            let id = extract_docs_id_from_comment(text);
+            if detached {
+                if annotations.detached_id.is_none() {
+                    annotations.detached_id = id.map(|id| (id, sibling_start_row + 1));
+                }
+                continue;
+            }
+            if id.is_none() {
+                annotations
+                    .malformed
+                    .extend(find_malformed_annotations(text, sibling_start_row + 1));
+            }
+            if let Some(id) = id {
+                ids.push((id, sibling_start_row + 1));
+            }
+            annotations.examples.splice(0..0, examples);
+            annotations
+                .arg_descriptions
+                .extend(text.lines().filter_map(parse_param_description));
+            if let Some(rules) = strip_comment_prefix(text).and_then(parse_ignore_directive) {
+                annotations
+                    .suppressions
+                    .extend(rules.into_iter().map(|rule| Suppression {
+                        rule,
+                        line: sibling_start_row + 1,
+                    }));
+            }
+        }
+    }
 
-        if sibling.kind() == comment_kind {
-            if let Ok(text) = sibling.utf8_text(source) {
-                if let Some(id) = extract_docs_id_from_comment(text) {
-                    return Some(id);
+    // Con IDs distintos no hay uno correcto: el orden de los hermanos no decide
+    ids.reverse();
+    match ids.first() {
+        Some((first, _)) if ids.iter().all(|(id, _)| id == first) => {
+            annotations.doc_id = Some(first.clone());
+        }
+        Some(_) => annotations.conflicting_ids = ids,
+        None => {}
+    }
+
+    annotations
+}
+
+/// Separa los bloques `@example` de un comentario JSDoc (`/** … */`) y
+/// devuelve también el comentario con las líneas de los ejemplos vaciadas.
+/// Un ejemplo llega hasta la siguiente etiqueta JSDoc fuera de un fence; si
+/// tiene fences, cada uno es un ejemplo; si no, lo es todo su texto.
+/// `first_line` es la línea del comentario. Otros comentarios salen intactos.
+pub fn split_jsdoc_examples(comment: &str, first_line: usize) -> (Vec<CodeExample>, String) {
+    if !comment.trim_start().starts_with("/**") {
+        return (Vec::new(), comment.to_string());
+    }
+    let mut examples = Vec::new();
+    let mut kept: Vec<&str> = Vec::new();
+    // Líneas del ejemplo abierto, con su índice en el comentario
+    let mut current: Option<Vec<(usize, &str)>> = None;
+    let mut in_fence = false;
+
+    for (i, line) in comment.lines().enumerate() {
+        let content = jsdoc_content(line);
+        let trimmed = content.trim();
+        let is_tag = trimmed
+            .strip_prefix('@')
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_alphabetic()));
+        if is_tag && !in_fence {
+            if let Some(body) = current.take() {
+                examples.extend(fenced_examples(&body, first_line));
+            }
+            if trimmed == "@example" || trimmed.starts_with("@example ") {
+                current = Some(Vec::new());
+                kept.push("");
+                continue;
+            }
+        }
+        match current {
+            Some(ref mut body) => {
+                if trimmed.starts_with("```") {
+                    in_fence = !in_fence;
                 }
+                body.push((i, content));
+                kept.push("");
             }
+            None => kept.push(line),
         }
+    }
+    if let Some(body) = current {
+        examples.extend(fenced_examples(&body, first_line));
+    }
+    (examples, kept.join("\n"))
+}
 
-        // Si encontramos algo que no es un comentario, dejar de buscar
-        if sibling.kind() != comment_kind {
-            break;
+/// Texto de una línea de JSDoc sin `/**`, `*/` ni el `*` inicial.
+fn jsdoc_content(line: &str) -> &str {
+    let line = line.trim_start();
+    let line = line.strip_prefix("/**").unwrap_or(line);
+    let line = line.trim_end().strip_suffix("*/").unwrap_or(line);
+    let line = line.trim_start();
+    let line = line.strip_prefix('*').unwrap_or(line);
+    line.strip_prefix(' ').unwrap_or(line)
+}
+
+/// Línea de un comentario con su índice dentro de él.
+type CommentLine<'a> = (usize, &'a str);
+
+/// Ejemplos de las líneas de un `@example`: un bloque por fence o, sin
+/// fences, todo el texto.
+fn fenced_examples(body: &[(usize, &str)], first_line: usize) -> Vec<CodeExample> {
+    let example = |lang: Option<String>, lines: &[(usize, &str)]| {
+        let code: Vec<&str> = lines.iter().map(|(_, l)| *l).collect();
+        lines.first().map(|(i, _)| CodeExample {
+            lang,
+            code: code.join("\n"),
+            line: first_line + i,
+        })
+    };
+    if !body.iter().any(|(_, l)| l.trim().starts_with("```")) {
+        let text: Vec<_> = body
+            .iter()
+            .skip_while(|(_, l)| l.trim().is_empty())
+            .copied()
+            .collect();
+        let end = text
+            .iter()
+            .rposition(|(_, l)| !l.trim().is_empty())
+            .map_or(0, |p| p + 1);
+        return example(None, &text[..end]).into_iter().collect();
+    }
+
+    let mut examples = Vec::new();
+    let mut open: Option<(Option<String>, Vec<CommentLine>)> = None;
+    for &(i, line) in body {
+        match (open.take(), line.trim().strip_prefix("```")) {
+            (None, Some(info)) => {
+                let lang = Some(info.trim())
+                    .filter(|l| !l.is_empty())
+                    .map(String::from);
+                open = Some((lang, Vec::new()));
+            }
+            (Some((lang, lines)), Some(_)) => examples.extend(example(lang, &lines)),
+            (Some((lang, mut lines)), None) => {
+                lines.push((i, line));
+                open = Some((lang, lines));
+            }
+            (None, None) => {}
         }
     }
+    if let Some((lang, lines)) = open {
+        examples.extend(example(lang, &lines));
+    }
+    examples
+}
 
-    None
+const CONFLICT_PREFIX: &str = "Anotaciones @docs en conflicto: ";
+const CONFLICT_SUFFIX: &str = "; no se enlaza ninguna.";
+
+/// Mensaje de DG001: `Anotaciones @docs en conflicto: 'a' (línea 1), 'b' (línea 2); …`.
+fn conflict_message(ids: &[(String, usize)]) -> String {
+    let listed: Vec<String> = ids
+        .iter()
+        .map(|(id, line)| format!("'{}' (línea {})", id, line))
+        .collect();
+    format!(
+        "{}{}{}",
+        CONFLICT_PREFIX,
+        listed.join(", "),
+        CONFLICT_SUFFIX
+    )
+}
+
+/// IDs y líneas de un hallazgo DG001, para `--fix`.
+pub fn conflicting_annotations(message: &str) -> Option<Vec<(&str, usize)>> {
+    let listed = message
+        .strip_prefix(CONFLICT_PREFIX)?
+        .strip_suffix(CONFLICT_SUFFIX)?;
+    listed
+        .split(", ")
+        .map(|entry| {
+            let (id, line) = entry.strip_prefix('\'')?.split_once("' (línea ")?;
+            Some((id, line.strip_suffix(')')?.parse().ok()?))
+        })
+        .collect()
+}
+
+/// Líneas en blanco entre el final de un nodo (`end`) y el comienzo del
+/// siguiente (`start`). Solo cuentan las líneas completas vacías: las que
+/// ocupa un `/* ... */` multilínea no son hueco, y da igual que el nodo del
+/// comentario incluya su salto de línea (`line_comment` de Rust) o no.
+fn blank_lines_between(source: &[u8], end: usize, start: usize) -> usize {
+    let Some(between) = source.get(end..start) else {
+        return 0;
+    };
+    let mut lines: Vec<&[u8]> = between.split(|&b| b == b'\n').collect();
+    // El último trozo es el comienzo de la línea del nodo siguiente
+    lines.pop();
+    // El primero es el resto de la línea del comentario, salvo que este
+    // termine en su propio salto de línea
+    if !source[..end].ends_with(b"\n") && !lines.is_empty() {
+        lines.remove(0);
+    }
+    lines
+        .iter()
+        .filter(|line| line.iter().all(u8::is_ascii_whitespace))
+        .count()
+}
+
+/// Descripción de un argumento en una línea de doc-comment: JSDoc/Javadoc
+/// (`@param {number} timeout - en ms`, `@param [retries=3] reintentos`) o la
+/// lista de rustdoc (`` * `timeout` - en ms ``). Texto libre no cuenta.
+fn parse_param_description(line: &str) -> Option<(String, String)> {
+    let line = line
+        .trim()
+        .trim_start_matches(['/', '*', '!', '#'])
+        .trim_end_matches("*/")
+        .trim();
+    let (name, rest) = if let Some(rest) = line.strip_prefix("@param") {
+        let rest = rest.strip_prefix(char::is_whitespace)?.trim_start();
+        let rest = match rest.strip_prefix('{') {
+            Some(typed) => typed.split_once('}')?.1.trim_start(),
+            None => rest,
+        };
+        let (name, rest) = rest.split_once(char::is_whitespace)?;
+        let name = name.trim_start_matches('[').trim_end_matches(']');
+        let name = name.split_once('=').map_or(name, |(name, _)| name);
+        (
+            name,
+            rest.trim_start().strip_prefix(['-', ':']).unwrap_or(rest),
+        )
+    } else {
+        let rest = line
+            .strip_prefix(['-', '*'])?
+            .trim_start()
+            .strip_prefix('`')?;
+        let (name, rest) = rest.split_once('`')?;
+        (name, rest.trim_start().strip_prefix(['-', ':', '–', '—'])?)
+    };
+    let description = rest.trim();
+    (is_valid_id(name) && !description.is_empty())
+        .then(|| (name.to_string(), description.to_string()))
+}
+
+/// Registra las ambigüedades de una entidad recién extraída: anotaciones mal
+/// formadas, IDs en conflicto, anotación desconectada y errores de sintaxis.
+pub fn note_entity_ambiguities(
+    entity: &CodeEntity,
+    annotations: &Annotations,
+    func_node: &tree_sitter::Node,
+    diagnostics: &mut ParseDiagnostics,
+) {
+    let note = |rule: Rule, message: String| ParseNote {
+        rule,
+        message,
+        function_name: Some(entity.name.clone()),
+        code_location: Some(entity.location()),
+        doc_id: entity.doc_id.clone(),
+        doc_location: None,
+        hint: None,
+    };
+
+    for malformed in &annotations.malformed {
+        diagnostics.push(ParseNote {
+            hint: Some(format!("Sintaxis correcta: `{}`", malformed.suggestion)),
+            ..note(
+                Rule::MalformedAnnotation,
+                format!(
+                    "Anotación @docs mal formada (línea {}): `{}` — la función no se enlaza.",
+                    malformed.line, malformed.text
+                ),
+            )
+        });
+    }
+
+    if !annotations.conflicting_ids.is_empty() {
+        diagnostics.push(note(
+            Rule::ConflictingDocsIds,
+            conflict_message(&annotations.conflicting_ids),
+        ));
+    }
+
+    if let Some((id, line)) = &annotations.detached_id {
+        diagnostics.push(note(
+            Rule::DetachedAnnotation,
+            format!(
+                "Anotación @docs '{}' (línea {}) separada de la función por líneas vacías: no se enlaza.",
+                id, line
+            ),
+        ));
+    }
+
+    if func_node.has_error() {
+        diagnostics.push(note(
+            Rule::SyntaxErrorInEntity,
+            "tree-sitter encontró errores de sintaxis dentro de la función.".into(),
+        ));
+    }
 }
 
 /// Valida que un ID de sección solo contiene caracteres seguros.
@@ -179,19 +903,16 @@ pub(crate) fn is_valid_id(id: &str) -> bool {
             .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
 }
 
-/// Escribe `content` en `path` de forma atómica usando temp file + POSIX rename.
+/// Escribe `content` en `path` de forma atómica: una `Transaction` de un archivo
+/// (temp file + fsync + POSIX rename).
 ///
-/// Función compartida usada por `apply_changes` (interactive) y `Baseline::save`.
+/// Función compartida por baseline, mapeo y triage; las escrituras de varios
+/// archivos usan `Transaction` directamente.
 /// Previene TOCTOU, corrupción parcial y symlink attacks (VUL-02).
 pub(crate) fn atomic_write(path: &Path, content: &[u8]) -> Result<()> {
-    let tmp_path = path.with_extension("tmp.docsguardwrite");
-    std::fs::write(&tmp_path, content)
-        .with_context(|| format!("No se pudo escribir temporal: {}", tmp_path.display()))?;
-    std::fs::rename(&tmp_path, path)
-        .inspect_err(|_| {
-            let _ = std::fs::remove_file(&tmp_path);
-        })
-        .with_context(|| format!("No se pudo renombrar a: {}", path.display()))
+    let mut transaction = Transaction::new();
+    transaction.stage(path, content);
+    transaction.commit().map(|_| ())
 }
 
 /// Devuelve el path como string con secuencias ANSI escapadas.
@@ -205,20 +926,18 @@ pub(crate) fn safe_display(path: &Path) -> String {
         .replace('\r', "\\r")
 }
 
-/// Extrae el ID de una anotación `/// @docs: [id]`, `// @docs: [id]`, o `# @docs: [id]`.
-pub fn extract_docs_id_from_comment(comment: &str) -> Option<String> {
+/// Quita el prefijo de comentario de línea (`///`, `//` o `#`).
+fn strip_comment_prefix(comment: &str) -> Option<&str> {
     let trimmed = comment.trim();
-    let content = if let Some(rest) = trimmed.strip_prefix("///") {
-        rest
-    } else if let Some(rest) = trimmed.strip_prefix("//") {
-        rest
-    } else if let Some(rest) = trimmed.strip_prefix("#") {
-        rest
-    } else {
-        return None;
-    };
+    trimmed
+        .strip_prefix("///")
+        .or_else(|| trimmed.strip_prefix("//"))
+        .or_else(|| trimmed.strip_prefix('#'))
+}
 
-    let content = content.trim();
+/// Extrae el ID de una anotación `/// @docs: [id]`, `// @docs: [id]`, o `# @docs: [id]`.
+pub fn extract_docs_id_from_comment(comment: &str) -> Option<String> {
+    let content = strip_comment_prefix(comment)?.trim();
 
     if let Some(after_docs) = content.strip_prefix("@docs:") {
         let id_part = after_docs.trim();
@@ -237,9 +956,58 @@ pub fn extract_docs_id_from_comment(comment: &str) -> Option<String> {
     None
 }
 
+/// Busca, línea a línea, texto de comentario que empieza como una anotación
+/// `@docs` (sin distinguir mayúsculas) pero no se puede extraer.
+///
+/// Solo cuenta el texto al inicio del comentario: la prosa que menciona
+/// `@docs` no es una anotación. `first_line` es la línea del comentario.
+pub fn find_malformed_annotations(comment: &str, first_line: usize) -> Vec<MalformedAnnotation> {
+    comment
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let trimmed = line.trim();
+            let body = trimmed
+                .trim_start_matches(['/', '*', '!', '#'])
+                .trim_end_matches("*/")
+                .trim();
+            let lower = body.to_lowercase();
+            if !lower.starts_with("@doc") || lower.starts_with(FILE_LINK_MARKER) {
+                return None;
+            }
+            if extract_docs_id_from_comment(trimmed).is_some() {
+                return None;
+            }
+            let prefix = ["///", "//", "#"]
+                .into_iter()
+                .find(|p| trimmed.starts_with(p))
+                .unwrap_or("//");
+            Some(MalformedAnnotation {
+                text: trimmed.to_string(),
+                line: first_line + i,
+                suggestion: format!(
+                    "{} @docs: [{}]",
+                    prefix,
+                    suggested_id(body).unwrap_or_else(|| "id".into())
+                ),
+            })
+        })
+        .collect()
+}
+
+/// ID más probable de una anotación mal formada: el último token válido
+/// que no sea la propia clave (`@docs`, `@docs-id`…).
+pub(crate) fn suggested_id(text: &str) -> Option<String> {
+    text.split(|c: char| c.is_whitespace() || matches!(c, ':' | '[' | ']' | '<' | '>' | '!'))
+        .filter(|token| !token.is_empty() && !token.starts_with('@') && *token != "--")
+        .rfind(|token| is_valid_id(token))
+        .map(String::from)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::vfs::MemFs;
     use std::path::PathBuf;
 
     #[test]
@@ -266,6 +1034,353 @@ mod tests {
         );
     }
 
+    #[test]
+    fn extract_docs_id_python_hash() {
+        assert_eq!(
+            extract_docs_id_from_comment("# @docs: [py-fn]"),
+            Some("py-fn".into())
+        );
+    }
+
+    #[test]
+    fn annotations_collect_suppressions_from_comment_block() {
+        let source = r#"
+// @docs: [auth-login]
+// docsguard-ignore: missing-arg
+function login(user: string) {}
+"#;
+        let entities = lang::typescript::parse_typescript_source(
+            source,
+            &PathBuf::from("a.ts"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        assert_eq!(entities[0].doc_id.as_deref(), Some("auth-login"));
+        assert_eq!(
+            entities[0].suppressions,
+            vec![Suppression {
+                rule: "missing-arg".into(),
+                line: 3
+            }]
+        );
+    }
+
+    fn parse_ts_with_notes(source: &str) -> (Vec<CodeEntity>, ParseDiagnostics) {
+        let mut diagnostics = ParseDiagnostics::default();
+        let entities = lang::typescript::parse_typescript_source(
+            source,
+            &PathBuf::from("a.ts"),
+            AnnotationOptions::default(),
+            &mut diagnostics,
+        )
+        .unwrap();
+        (entities, diagnostics)
+    }
+
+    #[test]
+    fn jsdoc_examples_are_extracted_with_their_file_lines() {
+        let (entities, _) = parse_ts_with_notes(
+            "// @docs: [user-create]\n\
+             /**\n \
+             * Crea un usuario.\n \
+             * @example\n \
+             * ```ts\n \
+             * createUser(\"ana\")\n \
+             * ```\n \
+             * Y otro:\n \
+             * ```js\n \
+             * createUser(\"eva\", true)\n \
+             * ```\n \
+             * @example Sin fence\n \
+             *   const user = createUser(\"ana\");\n \
+             *   user.id\n \
+             *\n \
+             * @returns El usuario\n \
+             */\n\
+             export function createUser(name: string, admin: boolean) {}\n",
+        );
+        let examples: Vec<_> = entities[0]
+            .examples
+            .iter()
+            .map(|x| (x.lang.as_deref(), x.code.as_str(), x.line))
+            .collect();
+        assert_eq!(
+            examples,
+            [
+                (Some("ts"), "createUser(\"ana\")", 6),
+                (Some("js"), "createUser(\"eva\", true)", 10),
+                (None, "  const user = createUser(\"ana\");\n  user.id", 13),
+            ]
+        );
+    }
+
+    #[test]
+    fn docs_text_inside_an_example_is_not_an_annotation() {
+        let (entities, diagnostics) = parse_ts_with_notes(
+            "// @docs: [user-create]\n\
+             /**\n \
+             * @param name - Nombre visible\n \
+             * @example\n \
+             * ```ts\n \
+             * // @docs: [user-delete]\n \
+             * @param admin - no es del doc-comment\n \
+             * ```\n \
+             * @example\n \
+             * // @docs: [user-delete]\n \
+             * createUser(\"ana\")\n \
+             */\n\
+             export function createUser(name: string, admin: boolean) {}\n",
+        );
+        assert_eq!(entities[0].doc_id.as_deref(), Some("user-create"));
+        assert!(diagnostics.notes.is_empty(), "{:?}", diagnostics.notes);
+        let descriptions: Vec<_> = entities[0]
+            .args
+            .iter()
+            .map(|a| a.description.as_deref())
+            .collect();
+        assert_eq!(descriptions, [Some("Nombre visible"), None]);
+        assert_eq!(entities[0].examples.len(), 2);
+
+        // Fuera de un JSDoc, `@example` no es nada especial
+        let (examples, text) = split_jsdoc_examples("// @example\n// f()", 1);
+        assert!(examples.is_empty());
+        assert_eq!(text, "// @example\n// f()");
+    }
+
+    #[test]
+    fn jsdoc_and_rustdoc_param_lines_describe_the_signature_args() {
+        let (entities, _) = parse_ts_with_notes(
+            "/**\n * Descarga un recurso.\n * @param {string} url - Dirección del recurso\n \
+             * @param [timeout=5] timeout in seconds, 1-60\n * La url se valida.\n */\n\
+             /// @docs: [net-fetch]\n\
+             export function fetch(url: string, timeout?: number, retries?: number) {}\n",
+        );
+        assert_eq!(entities[0].doc_id.as_deref(), Some("net-fetch"));
+        let described: Vec<_> = entities[0]
+            .args
+            .iter()
+            .map(|a| (a.name.as_str(), a.description.as_deref()))
+            .collect();
+        assert_eq!(
+            described,
+            vec![
+                ("url", Some("Dirección del recurso")),
+                ("timeout", Some("timeout in seconds, 1-60")),
+                ("retries", None),
+            ]
+        );
+
+        let entities = lang::rust::parse_rust_source(
+            "/// @docs: [net-fetch]\n///\n/// # Arguments\n///\n\
+             /// * `timeout` - tiempo de espera en milisegundos\n\
+             /// * `retries` se reintenta\n\
+             pub fn fetch(timeout: u64, retries: u8) {}\n",
+            &PathBuf::from("a.rs"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            entities[0].args[0].description.as_deref(),
+            Some("tiempo de espera en milisegundos")
+        );
+        // Sin separador no es una descripción de argumento
+        assert_eq!(entities[0].args[1].description, None);
+    }
+
+    #[test]
+    fn differing_ids_in_one_block_link_none_and_are_noted() {
+        let (entities, diagnostics) = parse_ts_with_notes(
+            "// @docs: [old-login]\n// @docs: [auth-login]\nfunction login() {}\n",
+        );
+        assert_eq!(entities[0].doc_id, None);
+        assert_eq!(diagnostics.notes.len(), 1);
+        assert_eq!(diagnostics.notes[0].rule, Rule::ConflictingDocsIds);
+        assert_eq!(
+            conflicting_annotations(&diagnostics.notes[0].message),
+            Some(vec![("old-login", 1), ("auth-login", 2)])
+        );
+    }
+
+    #[test]
+    fn repeating_the_same_id_is_not_a_conflict() {
+        let (entities, diagnostics) = parse_ts_with_notes(
+            "// @docs: [auth-login]\n// Inicia sesión.\n// @docs: [auth-login]\nfunction login() {}\n",
+        );
+        assert_eq!(entities[0].doc_id.as_deref(), Some("auth-login"));
+        assert!(diagnostics.notes.is_empty());
+    }
+
+    #[test]
+    fn stacked_annotations_conflict_the_same_way_in_every_language() {
+        // (lenguaje, prefijo de comentario, antes de las anotaciones, función)
+        let languages = [
+            ("typescript", "//", "", "function login() {}\n"),
+            ("rust", "///", "", "fn login() {}\n"),
+            ("python", "#", "", "def login():\n    pass\n"),
+            ("go", "//", "package main\n", "func login() {}\n"),
+            ("java", "//", "class Auth {\n", "void login() {}\n}\n"),
+            ("c_sharp", "//", "class Auth {\n", "void login() {}\n}\n"),
+        ];
+        let parse = |language: &str, source: &str, diagnostics: &mut ParseDiagnostics| {
+            let options = AnnotationOptions::default();
+            match language {
+                "typescript" => lang::typescript::parse_typescript_source(
+                    source,
+                    Path::new("a.ts"),
+                    options,
+                    diagnostics,
+                ),
+                "rust" => {
+                    lang::rust::parse_rust_source(source, Path::new("a.rs"), options, diagnostics)
+                }
+                "python" => lang::python::parse_python_source(
+                    source,
+                    Path::new("a.py"),
+                    options,
+                    diagnostics,
+                ),
+                "go" => lang::go::parse_go_source(source, Path::new("a.go"), options, diagnostics),
+                "java" => {
+                    lang::java::parse_java_source(source, Path::new("A.java"), options, diagnostics)
+                }
+                _ => lang::c_sharp::parse_c_sharp_source(
+                    source,
+                    Path::new("A.cs"),
+                    options,
+                    diagnostics,
+                ),
+            }
+            .unwrap()
+        };
+
+        for (language, prefix, before, function) in languages {
+            let first = before.lines().count() + 1;
+            for ids in [
+                &["auth-login", "login-v2"][..],
+                &["auth-login", "login-v2", "login-v3"],
+            ] {
+                let annotations: String = ids
+                    .iter()
+                    .map(|id| format!("{prefix} @docs: [{id}]\n"))
+                    .collect();
+                let source = format!("{before}{annotations}{function}");
+                let mut diagnostics = ParseDiagnostics::default();
+                let entities = parse(language, &source, &mut diagnostics);
+                assert_eq!(entities[0].doc_id, None, "{language}:\n{source}");
+                let notes: Vec<_> = diagnostics
+                    .notes
+                    .iter()
+                    .filter(|n| n.rule == Rule::ConflictingDocsIds)
+                    .collect();
+                assert_eq!(notes.len(), 1, "{language}:\n{source}");
+                let expected: Vec<(&str, usize)> = ids
+                    .iter()
+                    .enumerate()
+                    .map(|(i, id)| (*id, first + i))
+                    .collect();
+                assert_eq!(
+                    conflicting_annotations(&notes[0].message),
+                    Some(expected),
+                    "{language}:\n{source}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn annotation_beyond_large_gap_is_not_linked_but_noted() {
+        let (entities, diagnostics) =
+            parse_ts_with_notes("// @docs: [auth-login]\n\n\n\nfunction login() {}\n");
+        assert_eq!(entities[0].doc_id, None);
+        assert_eq!(diagnostics.notes[0].rule, Rule::DetachedAnnotation);
+        assert!(diagnostics.notes[0].message.contains("línea 1"));
+    }
+
+    #[test]
+    fn annotation_gap_matrix_is_the_same_for_typescript_and_rust() {
+        // (forma, comentarios antes del hueco, comentarios después del hueco)
+        let shapes = [
+            ("línea", "// @docs: [auth-login]\n", ""),
+            (
+                "bloque entre anotación y función",
+                "// @docs: [auth-login]\n/**\n * Inicia sesión.\n *\n * Devuelve un token.\n */\n",
+                "",
+            ),
+            (
+                "regla decorativa",
+                "// @docs: [auth-login]\n",
+                "// ----------------------------------------\n",
+            ),
+            (
+                "bloque tras el hueco",
+                "// @docs: [auth-login]\n",
+                "/*\n * Inicia sesión.\n */\n",
+            ),
+        ];
+        type Parse = fn(&str, AnnotationOptions) -> Vec<CodeEntity>;
+        let languages: [(&str, Parse); 2] = [
+            ("function login() {}\n", |source, options| {
+                lang::typescript::parse_typescript_source(
+                    source,
+                    &PathBuf::from("a.ts"),
+                    options,
+                    &mut ParseDiagnostics::default(),
+                )
+                .unwrap()
+            }),
+            ("fn login() {}\n", |source, options| {
+                lang::rust::parse_rust_source(
+                    source,
+                    &PathBuf::from("a.rs"),
+                    options,
+                    &mut ParseDiagnostics::default(),
+                )
+                .unwrap()
+            }),
+        ];
+
+        for (function, parse) in languages {
+            for (shape, before, after) in shapes {
+                for gap in 0..=3 {
+                    for max_gap in 0..=2 {
+                        let source = format!("{before}{}{after}{function}", "\n".repeat(gap));
+                        let entities = parse(&source, AnnotationOptions { max_gap });
+                        let attached = entities[0].doc_id.as_deref() == Some("auth-login");
+                        assert_eq!(
+                            attached,
+                            gap <= max_gap,
+                            "{shape}, {gap} líneas en blanco, máximo {max_gap}:\n{source}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn whitespace_only_lines_count_as_blank_and_same_line_comments_as_none() {
+        let (entities, _) =
+            parse_ts_with_notes("// @docs: [auth-login]\n   \n\t\n/* a */ function login() {}\n");
+        assert_eq!(entities[0].doc_id, None);
+        let (entities, _) =
+            parse_ts_with_notes("// @docs: [auth-login]\r\n\r\n/* a */ function login() {}\r\n");
+        assert_eq!(entities[0].doc_id.as_deref(), Some("auth-login"));
+    }
+
+    #[test]
+    fn syntax_errors_inside_function_are_noted() {
+        let (_, diagnostics) = parse_ts_with_notes("function login(user: ) { return }\n");
+        assert!(diagnostics
+            .notes
+            .iter()
+            .any(|n| n.rule == Rule::SyntaxErrorInEntity));
+
+        let (_, clean) = parse_ts_with_notes("// @docs: [a]\nfunction a(x: string) {}\n");
+        assert!(clean.notes.is_empty());
+    }
+
     #[test]
     fn language_detection_typescript() {
         assert_eq!(
@@ -323,4 +1438,135 @@ mod tests {
         assert!(!is_valid_id("id;evil()"));
         assert!(!is_valid_id(""));
     }
+
+    #[test]
+    fn file_link_in_rust_inner_doc_comment() {
+        let link =
+            find_file_link("//! Pagos.\n//! @docs-file: payments-overview\n\nuse std::io;\n");
+        assert_eq!(
+            link,
+            Some(FileLink {
+                doc_id: "payments-overview".into(),
+                line: 2
+            })
+        );
+    }
+
+    #[test]
+    fn file_link_after_license_header_and_use_strict() {
+        let source = "#!/usr/bin/env node\n/*\n * Copyright 2026\n */\n'use strict';\n// @docs-file: [payments-overview]\nexport function charge() {}\n";
+        assert_eq!(
+            find_file_link(source).map(|l| l.doc_id),
+            Some("payments-overview".into())
+        );
+    }
+
+    #[test]
+    fn file_link_is_only_searched_before_code() {
+        assert_eq!(
+            find_file_link("import x from 'y';\n// @docs-file: late\n"),
+            None
+        );
+        assert_eq!(find_file_link("// @docs-file: bad id\n"), None);
+        // La anotación por función no es un enlace de archivo
+        assert_eq!(find_file_link("// @docs: [auth-login]\n"), None);
+    }
+
+    #[test]
+    fn file_link_is_applied_to_every_entity() {
+        let dir = MemFs::project();
+        let path = dir.path().join("payments.py");
+        dir.write(
+            &path,
+            "# @docs-file: payments-overview\n\ndef charge():\n    pass\n\ndef refund():\n    pass\n",
+        );
+        let entities = parse_code_file(&path).unwrap();
+        assert_eq!(entities.len(), 2);
+        assert!(entities
+            .iter()
+            .all(|e| e.file_link.as_ref().map(|l| l.line) == Some(1)));
+    }
+
+    #[test]
+    fn project_code_and_docs_use_paths_relative_to_the_root() {
+        let dir = MemFs::project();
+        dir.mkdir(dir.path().join("src"));
+        let code = dir.path().join("src").join("auth.ts");
+        let doc = dir.path().join("api.md");
+        dir.write(&code, "// @docs: [auth-login]\nfunction login() {}\n");
+        dir.write(&doc, "<!-- @docs-id: auth-login -->\n## Login\n");
+
+        let config = Config::load(dir.path()).unwrap();
+        let mut diagnostics = ParseDiagnostics::default();
+        let entities = parse_project_code(&[code], &config, &mut diagnostics).unwrap();
+        let sections = crate::parser::doc_parser::parse_docs(&doc, &config).unwrap();
+        assert_eq!(entities[0].location(), "src/auth.ts:2");
+        assert_eq!(sections[0].file_path, Path::new("api.md"));
+    }
+
+    #[test]
+    fn ignored_functions_are_dropped_and_counted_per_file() {
+        let dir = MemFs::project();
+        let code = dir.path().join("search.py");
+        dir.write(
+            &code,
+            "def search():\n    pass\n\ndef test_search():\n    pass\n\ndef _cache_internal():\n    pass\n",
+        );
+        let mut config = Config::load(dir.path()).unwrap();
+        config.ignore_functions = vec!["test_*".into(), "*_internal".into()];
+        let mut diagnostics = ParseDiagnostics::default();
+        let entities =
+            parse_project_code(std::slice::from_ref(&code), &config, &mut diagnostics).unwrap();
+        let names: Vec<&str> = entities.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["search"]);
+        assert_eq!(
+            diagnostics.ignored_functions,
+            [PathBuf::from("search.py"), PathBuf::from("search.py")]
+        );
+
+        // Sin patrones no se descarta nada
+        config.ignore_functions.clear();
+        let mut diagnostics = ParseDiagnostics::default();
+        assert_eq!(
+            parse_project_code(&[code], &config, &mut diagnostics)
+                .unwrap()
+                .len(),
+            3
+        );
+        assert!(diagnostics.ignored_functions.is_empty());
+    }
+
+    #[test]
+    fn near_miss_annotations_are_reported_with_a_suggestion() {
+        let cases = [
+            ("/// @docs [auth-login]", "/// @docs: [auth-login]"),
+            ("// @docs: [auth-login", "// @docs: [auth-login]"),
+            ("# @doc: [auth-login]", "# @docs: [auth-login]"),
+            ("// @Docs: auth-login", "// @docs: [auth-login]"),
+            ("/** @docs: [auth-login] */", "// @docs: [auth-login]"),
+        ];
+        for (comment, suggestion) in cases {
+            let found = find_malformed_annotations(comment, 7);
+            assert_eq!(found.len(), 1, "{}", comment);
+            assert_eq!(found[0].line, 7);
+            assert_eq!(found[0].text, comment);
+            assert_eq!(found[0].suggestion, suggestion);
+        }
+    }
+
+    #[test]
+    fn valid_annotations_and_prose_are_not_near_misses() {
+        for comment in [
+            "/// @docs: [auth-login]",
+            "// @docs-file: payments",
+            "/// Extrae la anotación `@docs` del bloque.",
+            "// docsguard-ignore: ghost-arg",
+        ] {
+            assert!(
+                find_malformed_annotations(comment, 1).is_empty(),
+                "{}",
+                comment
+            );
+        }
+    }
 }
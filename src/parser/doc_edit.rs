@@ -0,0 +1,310 @@
+//! Ediciones del Markdown de docs sin re-serializarlo.
+//!
+//! Toda modificación de un archivo de docs (las secciones esqueleto de
+//! `scaffold` y `--fix`, los argumentos renombrados) se expresa como
+//! reemplazos de rangos de bytes sobre el texto original, que se aplican de
+//! atrás hacia delante. Nada se regenera desde los eventos del parser, que
+//! normaliza (pipes alineados, espacios finales, enlaces por referencia):
+//! fuera de los rangos editados el archivo queda idéntico byte a byte.
+//!
+//! Los rangos salen del propio texto o de `into_offset_iter`, como en
+//! `doc_parser` (`table_cells`).
+
+use anyhow::{bail, Result};
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+/// Ediciones pendientes sobre un texto Markdown.
+pub struct DocEdits<'a> {
+    source: &'a str,
+    /// Rango reemplazado y texto nuevo, en el orden en que se pidieron.
+    edits: Vec<(Range<usize>, String)>,
+}
+
+impl<'a> DocEdits<'a> {
+    pub fn new(source: &'a str) -> Self {
+        DocEdits {
+            source,
+            edits: Vec::new(),
+        }
+    }
+
+    /// Reemplaza `range` por `text`. Un rango vacío es una inserción; varias
+    /// en el mismo offset quedan en el orden en que se pidieron.
+    ///
+    /// Falla si el rango se sale del texto, corta un carácter o se solapa con
+    /// otra edición.
+    pub fn replace(&mut self, range: Range<usize>, text: impl Into<String>) -> Result<()> {
+        if range.start > range.end
+            || !self.source.is_char_boundary(range.start)
+            || !self.source.is_char_boundary(range.end)
+        {
+            bail!(
+                "Rango de edición inválido {:?} en un texto de {} bytes.",
+                range,
+                self.source.len()
+            );
+        }
+        if let Some((other, _)) = self
+            .edits
+            .iter()
+            .find(|(other, _)| range.start < other.end && other.start < range.end)
+        {
+            bail!("La edición {:?} se solapa con otra en {:?}.", range, other);
+        }
+        self.edits.push((range, text.into()));
+        Ok(())
+    }
+
+    /// Inserta `line` como línea propia antes de la que contiene `offset`,
+    /// con el mismo fin de línea que esa.
+    ///
+    /// Aún sin uso fuera de las pruebas: es la operación de los marcadores
+    /// `@docs-id` sobre encabezados existentes.
+    #[allow(dead_code)]
+    pub fn insert_line_before(&mut self, offset: usize, line: &str) -> Result<()> {
+        let start = line_start(self.source, offset.min(self.source.len()));
+        let newline = newline_at(self.source, start);
+        self.replace(start..start, format!("{}{}", line, newline))
+    }
+
+    /// Añade `text` al final, tras una línea en blanco. El texto llega con
+    /// `\n` y se escribe con el fin de línea del archivo.
+    pub fn append_section(&mut self, text: &str) -> Result<()> {
+        let newline = newline_at(self.source, 0);
+        let mut appended = String::new();
+        if !self.source.is_empty() && !self.source.ends_with('\n') {
+            appended.push_str(newline);
+        }
+        appended.push_str(newline);
+        appended.push_str(&text.replace('\n', newline));
+        let end = self.source.len();
+        self.replace(end..end, appended)
+    }
+
+    /// Reemplaza el contenido de la celda `column` (0-based) de la fila de
+    /// tabla en `line` (1-based). El relleno de la celda y los pipes se
+    /// conservan.
+    pub fn replace_table_cell(&mut self, line: usize, column: usize, text: &str) -> Result<()> {
+        let Some(cell) = table_cells(self.source)
+            .remove(&line)
+            .and_then(|cells| cells.into_iter().nth(column))
+        else {
+            bail!(
+                "La línea {} no es una fila de tabla con {} columnas.",
+                line,
+                column + 1
+            );
+        };
+        self.replace(cell, text)
+    }
+
+    /// Texto con todas las ediciones aplicadas.
+    pub fn apply(self) -> String {
+        let mut edits: Vec<(usize, Range<usize>, String)> = self
+            .edits
+            .into_iter()
+            .enumerate()
+            .map(|(order, (range, text))| (order, range, text))
+            .collect();
+        // De atrás hacia delante, así los offsets pendientes siguen valiendo.
+        // En el mismo offset va primero la última pedida: queda detrás.
+        edits.sort_by_key(|(order, range, _)| (range.start, *order));
+        let mut out = self.source.to_string();
+        for (_, range, text) in edits.into_iter().rev() {
+            out.replace_range(range, &text);
+        }
+        out
+    }
+}
+
+/// Offset donde empieza la línea que contiene `offset`.
+pub fn line_start(source: &str, offset: usize) -> usize {
+    source[..offset].rfind('\n').map_or(0, |i| i + 1)
+}
+
+/// Offset donde empieza la línea `line` (1-based), si existe.
+pub fn line_offset(source: &str, line: usize) -> Option<usize> {
+    if line == 0 {
+        return None;
+    }
+    if line == 1 {
+        return Some(0);
+    }
+    source
+        .match_indices('\n')
+        .nth(line - 2)
+        .map(|(i, _)| i + 1)
+        .filter(|&start| start < source.len())
+}
+
+/// Fin de línea de la línea que empieza en `start`; sin fin propio (última
+/// línea), el de la primera línea del archivo, y `\n` si no hay ninguno.
+fn newline_at(source: &str, start: usize) -> &'static str {
+    let ending = |text: &str| {
+        text.find('\n').map(|i| {
+            if text[..i].ends_with('\r') {
+                "\r\n"
+            } else {
+                "\n"
+            }
+        })
+    };
+    ending(&source[start..])
+        .or_else(|| ending(source))
+        .unwrap_or("\n")
+}
+
+/// Rango del contenido (sin el relleno de espacios) de cada celda de tabla,
+/// por línea 1-based, en el orden de las columnas.
+pub fn table_cells(source: &str) -> BTreeMap<usize, Vec<Range<usize>>> {
+    let mut cells: BTreeMap<usize, Vec<Range<usize>>> = BTreeMap::new();
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+    let mut in_row = false;
+    for (event, range) in Parser::new_ext(source, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::TableHead | Tag::TableRow) => in_row = true,
+            Event::End(TagEnd::TableHead | TagEnd::TableRow) => in_row = false,
+            Event::Start(Tag::TableCell) if in_row => {
+                let line = source[..range.start].matches('\n').count() + 1;
+                let text = &source[range.clone()];
+                let start = range.start + (text.len() - text.trim_start().len());
+                let end = (range.end - (text.len() - text.trim_end().len())).max(start);
+                cells.entry(line).or_default().push(start..end);
+            }
+            _ => {}
+        }
+    }
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Markdown con formato poco habitual: pipes alineados, espacios finales,
+    /// enlaces por referencia, HTML y CRLF en una línea.
+    const UNUSUAL: &str = "<!-- @docs-id: search-api -->\n\
+## Search   \n\
+\n\
+See [the guide][guide] and <kbd>Ctrl</kbd>.  \n\
+\n\
+|  Param   |  Type    | Description        |\n\
+|:---------|---------:|:------------------:|\n\
+|  query   |  string  | Texto  \\| literal  |\n\
+|  filters |  object  |                    |\n\
+\n\
+* item   \r\n\
+\n\
+[guide]: https://example.com/guide  \"Guía\"\n";
+
+    /// `after` es `before` con `inserted` en `at` (bytes) en lugar de `removed` bytes.
+    fn assert_only_changed(before: &str, after: &str, at: usize, removed: usize, inserted: &str) {
+        assert_eq!(&after[..at], &before[..at], "antes de la edición");
+        assert_eq!(&after[at..at + inserted.len()], inserted);
+        assert_eq!(
+            &after[at + inserted.len()..],
+            &before[at + removed..],
+            "después de la edición"
+        );
+    }
+
+    #[test]
+    fn no_edits_leave_the_source_byte_identical() {
+        assert_eq!(DocEdits::new(UNUSUAL).apply(), UNUSUAL);
+    }
+
+    #[test]
+    fn a_replaced_cell_keeps_the_padding_pipes_and_every_other_byte() {
+        let cells = table_cells(UNUSUAL);
+        assert_eq!(cells[&8].len(), 3, "{cells:?}");
+        let query = cells[&8][0].clone();
+        assert_eq!(&UNUSUAL[query.clone()], "query");
+        // El pipe escapado no parte la celda
+        assert_eq!(&UNUSUAL[cells[&8][2].clone()], "Texto  \\| literal");
+
+        let mut edits = DocEdits::new(UNUSUAL);
+        edits.replace_table_cell(8, 0, "search_query").unwrap();
+        let edited = edits.apply();
+        assert_only_changed(UNUSUAL, &edited, query.start, 5, "search_query");
+        assert!(edited.contains("|  search_query   |  string  |"));
+    }
+
+    #[test]
+    fn an_empty_cell_and_missing_rows_or_columns() {
+        let empty = table_cells(UNUSUAL)[&9][2].clone();
+        assert!(empty.is_empty());
+        let mut edits = DocEdits::new(UNUSUAL);
+        edits.replace_table_cell(9, 2, "Filtros").unwrap();
+        let edited = edits.apply();
+        assert_only_changed(UNUSUAL, &edited, empty.start, 0, "Filtros");
+
+        let mut edits = DocEdits::new(UNUSUAL);
+        assert!(edits.replace_table_cell(4, 0, "x").is_err());
+        assert!(edits.replace_table_cell(8, 3, "x").is_err());
+    }
+
+    #[test]
+    fn inserted_lines_take_the_line_ending_of_their_line() {
+        let item = UNUSUAL.find("* item").unwrap();
+        let mut edits = DocEdits::new(UNUSUAL);
+        edits
+            .insert_line_before(item + 3, "<!-- @docs-id: items -->")
+            .unwrap();
+        let edited = edits.apply();
+        assert_only_changed(UNUSUAL, &edited, item, 0, "<!-- @docs-id: items -->\r\n");
+
+        let heading = UNUSUAL.find("## Search").unwrap();
+        let mut edits = DocEdits::new(UNUSUAL);
+        edits.insert_line_before(heading, "<!-- a -->").unwrap();
+        edits.insert_line_before(heading, "<!-- b -->").unwrap();
+        let edited = edits.apply();
+        assert_only_changed(UNUSUAL, &edited, heading, 0, "<!-- a -->\n<!-- b -->\n");
+    }
+
+    #[test]
+    fn appended_sections_follow_a_blank_line_in_the_file_line_ending() {
+        let mut edits = DocEdits::new(UNUSUAL);
+        edits.append_section("## Nueva\n\nTexto.\n").unwrap();
+        let edited = edits.apply();
+        assert_only_changed(UNUSUAL, &edited, UNUSUAL.len(), 0, "\n## Nueva\n\nTexto.\n");
+
+        // Sin fin de línea final se añade uno; en CRLF, todo en CRLF
+        let mut edits = DocEdits::new("# Docs\r\nFin");
+        edits.append_section("## Nueva\n").unwrap();
+        assert_eq!(edits.apply(), "# Docs\r\nFin\r\n\r\n## Nueva\r\n");
+        let mut edits = DocEdits::new("");
+        edits.append_section("## Nueva\n").unwrap();
+        assert_eq!(edits.apply(), "\n## Nueva\n");
+    }
+
+    #[test]
+    fn edits_apply_in_reverse_offset_order_and_reject_overlaps() {
+        let source = "uno dos tres\n";
+        let mut edits = DocEdits::new(source);
+        edits.replace(8..12, "3").unwrap();
+        edits.replace(0..3, "one").unwrap();
+        edits.replace(4..7, "two").unwrap();
+        assert!(edits.replace(2..5, "x").is_err());
+        assert!(edits.replace(10..10, "x").is_err());
+        // Una inserción justo en el borde de otra edición no se solapa
+        edits.replace(12..12, "!").unwrap();
+        assert_eq!(edits.apply(), "one two 3!\n");
+
+        let mut edits = DocEdits::new("añadir");
+        assert!(edits.replace(0..2, "x").is_err());
+        assert!(edits.replace(0..99, "x").is_err());
+    }
+
+    #[test]
+    fn lines_are_located_by_number() {
+        let source = "a\nbb\r\nccc";
+        assert_eq!(line_offset(source, 1), Some(0));
+        assert_eq!(line_offset(source, 2), Some(2));
+        assert_eq!(line_offset(source, 3), Some(6));
+        assert_eq!(line_offset(source, 4), None);
+        assert_eq!(line_offset(source, 0), None);
+        assert_eq!(line_start(source, 7), 6);
+    }
+}
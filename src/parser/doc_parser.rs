@@ -3,63 +3,385 @@
 //! Extrae secciones marcadas con `<!-- @docs-id: xxx -->` y los argumentos
 //! documentados dentro de cada sección. No usa regex para parsear estructura
 //! Markdown (Blueprint §7: "No Regex Parser").
+//!
+//! Las extensiones de pulldown-cmark activas salen de `markdown_extensions`
+//! (ver `MarkdownExtension`); cada una tiene en el bucle de eventos un
+//! tratamiento explícito, para que activarla no cambie en silencio los
+//! argumentos extraídos.
 
 use anyhow::{Context, Result};
-use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
-use std::path::Path;
-
-use super::code_parser::{is_valid_id, safe_display};
-
-use crate::core::types::{Arg, DocSection};
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use super::code_parser::{is_valid_id, safe_display, suggested_id};
+
+use crate::config::{Config, LimitsConfig};
+use crate::core::conflicts;
+use crate::core::constraints::{self, UnitKeywords};
+use crate::core::diagnostics::{ParseDiagnostics, ParseNote};
+use crate::core::types::{Arg, ArgSource, CodeExample, DocSection, Expectation, Rule, SKIP_TOKENS};
+use crate::exit::Failure;
+use crate::messages;
+use crate::paths::{InputFile, ProjectPaths};
+use crate::vfs::{self, Bounded};
 
 /// Tamaño máximo de archivo para prevenir DoS (10 MB).
 const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
 
 /// @docs: [parse-markdown-file]
 /// Parsea un archivo Markdown y extrae todas las secciones con anotación `@docs-id`.
-pub fn parse_markdown_file(file_path: &Path) -> Result<Vec<DocSection>> {
-    use std::io::Read;
-    // VUL-03: abrir una sola vez — elimina la ventana TOCTOU entre metadata() y la lectura.
-    let mut file = std::fs::File::open(file_path)
-        .with_context(|| format!("No se pudo abrir: {}", safe_display(file_path)))?;
-    let metadata = file
-        .metadata()
-        .with_context(|| format!("No se pudo leer metadata: {}", safe_display(file_path)))?;
-    if metadata.len() > MAX_FILE_SIZE {
-        anyhow::bail!(
+pub fn parse_markdown_file(
+    file_path: &Path,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<Vec<DocSection>> {
+    let source = read_markdown_file(file_path)?;
+    parse_markdown_source(&source, file_path, diagnostics)
+}
+
+/// Si `file` es Markdown por su extensión (`.md`, `.markdown`).
+pub fn is_markdown(file: &Path) -> bool {
+    matches!(
+        file.extension().and_then(|e| e.to_str()),
+        Some("md" | "markdown")
+    )
+}
+
+/// Como `code_parser::split_supported` para la documentación: lo descubierto
+/// en un directorio o glob que no es Markdown se omite con una nota
+/// `skipped-file`. Un archivo nombrado a mano se lee como Markdown.
+pub fn split_markdown(
+    inputs: Vec<InputFile>,
+    paths: &ProjectPaths,
+) -> (Vec<PathBuf>, Vec<ParseNote>) {
+    let mut files = Vec::new();
+    let mut skipped = Vec::new();
+    for input in inputs {
+        if !input.discovered || is_markdown(&input.path) {
+            files.push(input.path);
+            continue;
+        }
+        let location = safe_display(&paths.normalize(&input.path));
+        skipped.push(ParseNote {
+            rule: Rule::SkippedFile,
+            message: format!("Se omite {}: no es Markdown.", location),
+            function_name: None,
+            code_location: None,
+            doc_id: None,
+            doc_location: Some(location),
+            hint: Some("La documentación se lee de los archivos .md y .markdown.".to_string()),
+        });
+    }
+    (files, skipped)
+}
+
+/// Lee un archivo Markdown respetando el límite de tamaño.
+pub(crate) fn read_markdown_file(file_path: &Path) -> Result<String> {
+    // VUL-03: tamaño y lectura del mismo descriptor (`vfs::read_bounded`).
+    match vfs::read_bounded(file_path, MAX_FILE_SIZE)
+        .with_context(|| format!("No se pudo leer el archivo: {}", safe_display(file_path)))?
+    {
+        Bounded::Contents(source) => Ok(source),
+        Bounded::TooLarge(len) => Err(Failure::input(format!(
             "Archivo demasiado grande ({:.1} MB, máximo: {} MB): {}",
-            metadata.len() as f64 / (1024.0 * 1024.0),
+            len as f64 / (1024.0 * 1024.0),
             MAX_FILE_SIZE / (1024 * 1024),
             safe_display(file_path)
-        );
+        ))
+        .with_path(file_path)
+        .into()),
     }
-    let mut source = String::with_capacity(metadata.len() as usize);
-    file.read_to_string(&mut source)
-        .with_context(|| format!("No se pudo leer el archivo: {}", safe_display(file_path)))?;
+}
 
-    parse_markdown_source(&source, file_path)
+/// Parsea un archivo de docs aplicando la configuración del proyecto
+/// (estrategias de argumentos deshabilitadas en `doc_arg_sources`, rutas
+/// relativas a la raíz del proyecto).
+///
+/// Punto de entrada de todos los comandos; `parse_markdown_file` queda sin filtrar.
+pub fn parse_docs(file_path: &Path, config: &Config) -> Result<Vec<DocSection>> {
+    parse_docs_with_diagnostics(file_path, config, &mut ParseDiagnostics::default())
+}
+
+/// Como `parse_docs`, registrando en `diagnostics` las ambigüedades encontradas.
+pub fn parse_docs_with_diagnostics(
+    file_path: &Path,
+    config: &Config,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<Vec<DocSection>> {
+    let source = read_markdown_file(file_path)?;
+    parse_docs_source(&source, file_path, config, diagnostics)
+}
+
+/// Como `parse_docs_with_diagnostics` para un archivo ya leído (`check-all`).
+pub fn parse_docs_source(
+    source: &str,
+    file_path: &Path,
+    config: &Config,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<Vec<DocSection>> {
+    let display_path = config.paths.normalize(file_path);
+    let mut sections = parse_markdown_source_with(
+        source,
+        &display_path,
+        config.doc_parse_options(),
+        diagnostics,
+    )?;
+    config.apply_to_sections(&mut sections);
+    apply_limits(&mut sections, &config.limits, diagnostics);
+    constraints::annotate_sections(&mut sections, &UnitKeywords::new(&config.units));
+    Ok(sections)
+}
+
+/// Recorta las secciones que superan `limits` y deja una nota `DG006` por
+/// cada recorte: argumentos de más en una sección (se conservan los primeros)
+/// y secciones de más en el archivo.
+pub fn apply_limits(
+    sections: &mut Vec<DocSection>,
+    limits: &LimitsConfig,
+    diagnostics: &mut ParseDiagnostics,
+) {
+    if sections.len() > limits.max_sections {
+        let first_dropped = &sections[limits.max_sections];
+        diagnostics.push(ParseNote {
+            rule: Rule::TruncatedDocs,
+            message: format!(
+                "El archivo tiene {}; se conservan las {} primeras (límite `limits.max_sections`).",
+                messages::SECTIONS.count(sections.len()),
+                messages::Locale::CANONICAL.number(limits.max_sections)
+            ),
+            function_name: None,
+            code_location: None,
+            doc_id: Some(first_dropped.id.clone()),
+            doc_location: Some(first_dropped.location()),
+            hint: None,
+        });
+        sections.truncate(limits.max_sections);
+    }
+    for section in sections.iter_mut() {
+        if section.args.len() <= limits.max_section_args {
+            continue;
+        }
+        section.dropped_args = section.args.len() - limits.max_section_args;
+        diagnostics.push(ParseNote {
+            rule: Rule::TruncatedDocs,
+            message: format!(
+                "La sección '{}' documenta {}; se conservan los {} primeros (límite `limits.max_section_args`) y no se validan uno a uno.",
+                section.id,
+                messages::ARGS.count(section.args.len()),
+                messages::Locale::CANONICAL.number(limits.max_section_args)
+            ),
+            function_name: None,
+            code_location: None,
+            doc_id: Some(section.id.clone()),
+            doc_location: Some(section.location()),
+            hint: None,
+        });
+        section.args.truncate(limits.max_section_args);
+    }
+}
+
+/// Líneas como máximo entre el encabezado anterior y un marcador sin
+/// encabezado propio para tomarlo como título (`title_max_distance`).
+pub const DEFAULT_TITLE_MAX_DISTANCE: usize = 10;
+
+/// Cómo se asocia el título de una sección.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TitleOptions {
+    /// Una sección sin encabezado tras su marcador toma el anterior más
+    /// cercano si está a lo sumo a estas líneas del marcador, no es ya el
+    /// título de otra sección y sigue abierto (mismo padre). 0 lo desactiva.
+    pub max_distance: usize,
+}
+
+impl Default for TitleOptions {
+    fn default() -> Self {
+        TitleOptions {
+            max_distance: DEFAULT_TITLE_MAX_DISTANCE,
+        }
+    }
+}
+
+/// Extensión de Markdown que se puede activar (`markdown_extensions`).
+///
+/// Tratamiento de cada una al extraer argumentos:
+///
+/// - `tables`: filas como argumentos (TableStrategy). Sin ella, una tabla es
+///   un párrafo con `|` y no da argumentos.
+/// - `strikethrough`: el texto tachado se lee como texto normal.
+/// - `footnotes`: las llamadas `[^1]` no llegan a nombres ni descripciones y
+///   las definiciones no aportan argumentos ni expectativas. Sin ella, la
+///   llamada queda como texto literal.
+/// - `tasklists`: la casilla `[ ]` / `[x]` se quita antes de leer el ítem y
+///   su estado se ignora. Sin ella, la casilla es parte del nombre.
+/// - `heading_attributes`: `{#id .clase}` al final de un encabezado no entra
+///   en el título ni en el nombre de un argumento con encabezado propio.
+/// - `smart_punctuation`: las comillas tipográficas que genera se devuelven a
+///   rectas, para que no cambien las llamadas de las expectativas
+///   (`login("ana") → true`); guiones y `…` ya se aceptan como separadores y
+///   marcadores de descripción pendiente.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarkdownExtension {
+    Tables,
+    Strikethrough,
+    Footnotes,
+    Tasklists,
+    HeadingAttributes,
+    SmartPunctuation,
+}
+
+impl MarkdownExtension {
+    pub const ALL: [MarkdownExtension; 6] = [
+        MarkdownExtension::Tables,
+        MarkdownExtension::Strikethrough,
+        MarkdownExtension::Footnotes,
+        MarkdownExtension::Tasklists,
+        MarkdownExtension::HeadingAttributes,
+        MarkdownExtension::SmartPunctuation,
+    ];
+
+    fn option(self) -> Options {
+        match self {
+            MarkdownExtension::Tables => Options::ENABLE_TABLES,
+            MarkdownExtension::Strikethrough => Options::ENABLE_STRIKETHROUGH,
+            MarkdownExtension::Footnotes => Options::ENABLE_FOOTNOTES,
+            MarkdownExtension::Tasklists => Options::ENABLE_TASKLISTS,
+            MarkdownExtension::HeadingAttributes => Options::ENABLE_HEADING_ATTRIBUTES,
+            MarkdownExtension::SmartPunctuation => Options::ENABLE_SMART_PUNCTUATION,
+        }
+    }
+}
+
+/// Extensiones activas sin `markdown_extensions` en la configuración.
+pub const DEFAULT_MARKDOWN_EXTENSIONS: [MarkdownExtension; 3] = [
+    MarkdownExtension::Tables,
+    MarkdownExtension::Strikethrough,
+    MarkdownExtension::Tasklists,
+];
+
+/// Opciones del parseo de un archivo de docs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocParseOptions {
+    pub title: TitleOptions,
+    /// Extensiones de pulldown-cmark activas.
+    pub extensions: Options,
+    /// Entradillas añadidas a las de serie (`lead_ins`).
+    pub lead_ins: LeadIns,
+    /// Una sección sin bloque de argumentos no documenta ninguno
+    /// (`require_args_delimiter`).
+    pub require_args_delimiter: bool,
+}
+
+impl DocParseOptions {
+    /// Opciones por defecto con solo las extensiones indicadas.
+    pub fn with_extensions(extensions: &[MarkdownExtension]) -> Self {
+        DocParseOptions {
+            title: TitleOptions::default(),
+            extensions: extensions
+                .iter()
+                .fold(Options::empty(), |options, e| options | e.option()),
+            lead_ins: LeadIns::default(),
+            require_args_delimiter: false,
+        }
+    }
+}
+
+impl Default for DocParseOptions {
+    fn default() -> Self {
+        DocParseOptions::with_extensions(&DEFAULT_MARKDOWN_EXTENSIONS)
+    }
+}
+
+/// Comillas tipográficas de `smart_punctuation`, devueltas a rectas.
+fn straight_quotes(text: &str) -> Cow<'_, str> {
+    if !text.contains(['‘', '’', '“', '”']) {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(
+        text.chars()
+            .map(|c| match c {
+                '‘' | '’' => '\'',
+                '“' | '”' => '"',
+                c => c,
+            })
+            .collect(),
+    )
 }
 
 /// Parsea Markdown desde un string (útil para testing).
-pub fn parse_markdown_source(source: &str, file_path: &Path) -> Result<Vec<DocSection>> {
-    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
-    let parser = Parser::new_ext(source, options);
+pub fn parse_markdown_source(
+    source: &str,
+    file_path: &Path,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<Vec<DocSection>> {
+    parse_markdown_source_with(source, file_path, DocParseOptions::default(), diagnostics)
+}
+
+/// Como `parse_markdown_source`, con las opciones indicadas.
+pub fn parse_markdown_source_with(
+    source: &str,
+    file_path: &Path,
+    options: DocParseOptions,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<Vec<DocSection>> {
+    conflicts::note(source, file_path, true, diagnostics);
+    let title_options = options.title;
+    let lead_ins = &options.lead_ins;
+    let require_delimiter = options.require_args_delimiter;
+    let smart_punctuation = options
+        .extensions
+        .contains(Options::ENABLE_SMART_PUNCTUATION);
+    let parser = Parser::new_ext(source, options.extensions);
 
     let mut sections: Vec<DocSection> = Vec::new();
     let mut current_id: Option<String> = None;
     let mut current_title: Option<String> = None;
     let mut in_heading = false;
     let mut heading_text = String::new();
-    let mut current_args: Vec<Arg> = Vec::new();
+    let mut heading_level: usize = 0;
+    // Headings abiertos (nivel, texto) para conocer el padre de cada sección
+    let mut heading_stack: Vec<(usize, String, usize)> = Vec::new();
+    // Encabezado anterior al marcador abierto (título de reserva) y líneas de
+    // los encabezados que ya son título de una sección
+    let mut preceding_heading: Option<PrecedingHeading> = None;
+    let mut claimed_headings: HashSet<usize> = HashSet::new();
+    let mut current_parent: Option<String> = None;
+    let mut current_args = SectionArgs::default();
+    let mut current_expectations: Vec<Expectation> = Vec::new();
+    let mut current_examples: Vec<CodeExample> = Vec::new();
+    let mut current_since: Option<String> = None;
+    let mut current_expects: Option<String> = None;
+    let mut current_skips: Vec<String> = Vec::new();
+    let mut current_no_link = false;
+    let mut current_mentions: Vec<(String, usize)> = Vec::new();
     let mut current_line: usize = 0;
+    // Nivel del título de la sección abierta
+    let mut current_level: usize = 0;
+
+    // Estado para argumentos con encabezado propio (Strategy Pattern: HeadingStrategy)
+    let mut heading_arg: Option<Arg> = None;
+    let mut heading_arg_paragraphs: usize = 0;
 
     // Estado para parseo de listas (Strategy Pattern: ListStrategy)
     let mut in_list_item = false;
     let mut list_item_text = String::new();
+    let mut list_item_line: usize = 0;
 
     // Estado para parseo de definiciones (Strategy Pattern: DefinitionStrategy)
     let mut in_paragraph = false;
     let mut paragraph_text = String::new();
+    let mut paragraph_line: usize = 0;
+
+    // Dentro de una definición de nota al pie: no aporta argumentos
+    let mut in_footnote = false;
+
+    // Estado para bloques de código (ejemplos con resultado esperado)
+    let mut in_code_block = false;
+    let mut code_block_text = String::new();
+    let mut code_block_line: usize = 0;
+    let mut code_block_lang: Option<String> = None;
 
     // Estado para parseo de tablas (Strategy Pattern: TableStrategy)
     let mut table_row: Vec<String> = Vec::new();
@@ -67,6 +389,13 @@ pub fn parse_markdown_source(source: &str, file_path: &Path) -> Result<Vec<DocSe
     let mut in_table_head = false;
     let mut in_table_cell = false;
     let mut cell_text = String::new();
+    let mut table_row_line: usize = 0;
+    // Las filas se acumulan hasta cerrar la tabla: una tabla sin cabecera de
+    // nombre cuyas celdas no parecen identificadores se descarta entera.
+    let mut table_args: Vec<Arg> = Vec::new();
+    let mut table_line: usize = 0;
+    let mut table_has_name_header = false;
+    let mut table_rejected = false;
 
     // Calcular mapeo de offset a línea
     let line_offsets = build_line_offsets(source);
@@ -77,59 +406,208 @@ pub fn parse_markdown_source(source: &str, file_path: &Path) -> Result<Vec<DocSe
         match event {
             Event::Html(html) => {
                 let html_str = html.trim();
-                if let Some(id) = extract_docs_id_from_html(html_str) {
+                if let Some(marker) = extract_docs_marker(html_str) {
+                    current_args.extend(heading_arg.take());
                     // Si ya teníamos una sección abierta, cerrarla
                     if let Some(prev_id) = current_id.take() {
+                        let title_inferred = fall_back_to_preceding(
+                            &prev_id,
+                            &mut current_title,
+                            &mut current_parent,
+                            preceding_heading.take(),
+                            &mut claimed_headings,
+                            format!("{}:{}", file_path.display(), current_line),
+                            diagnostics,
+                        );
                         sections.push(DocSection {
                             id: prev_id,
                             title: current_title.take(),
-                            args: std::mem::take(&mut current_args),
+                            parent: current_parent.take(),
+                            args: current_args.take(require_delimiter),
                             file_path: file_path.to_path_buf(),
                             line: current_line,
+                            expectations: std::mem::take(&mut current_expectations),
+                            examples: std::mem::take(&mut current_examples),
+                            since: current_since.take(),
+                            expected_function: current_expects.take(),
+                            skips: std::mem::take(&mut current_skips),
+                            dropped_args: 0,
+                            title_inferred,
+                            no_link: std::mem::take(&mut current_no_link),
+                            mentions: std::mem::take(&mut current_mentions),
+                        });
+                    }
+                    for token in &marker.unknown_skips {
+                        diagnostics.push(ParseNote {
+                            rule: Rule::MalformedAnnotation,
+                            message: format!(
+                                "Valor de @docs-skip desconocido en '{}' (línea {}): `{}` — se ignora.",
+                                marker.id, line, token
+                            ),
+                            function_name: None,
+                            code_location: None,
+                            doc_id: Some(marker.id.clone()),
+                            doc_location: Some(format!("{}:{}", file_path.display(), line)),
+                            hint: Some(format!(
+                                "Valores admitidos: {} (separados por comas, sin espacios).",
+                                SKIP_TOKENS.join(", ")
+                            )),
                         });
                     }
-                    current_id = Some(id);
+                    current_id = Some(marker.id);
+                    current_expects = marker.expects;
+                    current_skips = marker.skips;
+                    current_no_link = marker.no_link;
                     current_line = line;
+                    preceding_heading = preceding_heading_for(
+                        &heading_stack,
+                        &claimed_headings,
+                        line,
+                        title_options,
+                    );
+                } else if let Some(version) = extract_docs_since_from_html(html_str) {
+                    if current_id.is_some() {
+                        current_since = Some(version);
+                    }
+                } else if let Some((text, suggestion)) = find_malformed_docs_id(html_str) {
+                    diagnostics.push(ParseNote {
+                        rule: Rule::MalformedAnnotation,
+                        message: format!(
+                            "Marcador @docs-id mal formado (línea {}): `{}` — la sección no se registra.",
+                            line, text
+                        ),
+                        function_name: None,
+                        code_location: None,
+                        doc_id: None,
+                        doc_location: Some(format!("{}:{}", file_path.display(), line)),
+                        hint: Some(format!("Sintaxis correcta: `{}`", suggestion)),
+                    });
                 }
             }
 
             // --- Headings ---
-            Event::Start(Tag::Heading { .. }) => {
+            Event::Start(Tag::Heading { level, .. }) => {
+                // Un encabezado cierra el argumento de encabezado anterior
+                current_args.extend(heading_arg.take());
                 in_heading = true;
                 heading_text.clear();
+                heading_level = level as usize;
             }
             Event::End(TagEnd::Heading(_)) => {
                 in_heading = false;
+                heading_stack.retain(|(level, _, _)| *level < heading_level);
+                let text = heading_text.trim().to_string();
                 if current_id.is_some() && current_title.is_none() {
-                    current_title = Some(heading_text.trim().to_string());
+                    current_title = Some(text.clone());
+                    current_parent = heading_stack.last().map(|(_, t, _)| t.clone());
+                    current_level = heading_level;
+                    claimed_headings.insert(line);
+                } else if let Some(kind) = lead_ins.kind(&text).filter(|_| current_id.is_some()) {
+                    current_args.lead_in(kind, Some(heading_level));
+                } else if current_id.is_some() {
+                    let is_arg = heading_level > current_level && is_arg_heading(&text, lead_ins);
+                    current_args.heading(heading_level, is_arg);
+                    if is_arg {
+                        heading_arg = Some(Arg {
+                            name: clean_name(&text),
+                            type_name: None,
+                            description: None,
+                            source: ArgSource::Heading,
+                            line: Some(line),
+                            unit: None,
+                            range: None,
+                        });
+                        heading_arg_paragraphs = 0;
+                    }
                 }
+                heading_stack.push((heading_level, text, line));
             }
 
             // --- Párrafos (DefinitionStrategy) ---
             Event::Start(Tag::Paragraph) => {
                 in_paragraph = true;
                 paragraph_text.clear();
+                paragraph_line = line;
+            }
+            Event::End(TagEnd::Paragraph) if in_footnote => {
+                in_paragraph = false;
             }
             Event::End(TagEnd::Paragraph) => {
                 in_paragraph = false;
-                if current_id.is_some() && !in_list_item {
-                    for line in paragraph_text.lines() {
-                        if let Some(arg) = parse_definition_as_arg(line) {
+                if current_id.is_some() {
+                    collect_expectations(
+                        &paragraph_text,
+                        paragraph_line,
+                        &mut current_expectations,
+                    );
+                    if current_since.is_none() {
+                        current_since = paragraph_text.lines().find_map(parse_since_line);
+                    }
+                }
+                let opens_lead_in = paragraph_text
+                    .lines()
+                    .next()
+                    .is_some_and(|first| lead_ins.kind(first).is_some());
+                if opens_lead_in && !in_list_item {
+                    // `**Errors:**` tras `### id` cierra el argumento de encabezado
+                    current_args.extend(heading_arg.take());
+                }
+                if let Some(arg) = heading_arg.as_mut().filter(|_| !in_list_item) {
+                    apply_heading_paragraph(arg, &paragraph_text, heading_arg_paragraphs == 0);
+                    heading_arg_paragraphs += 1;
+                } else if current_id.is_some() && !in_list_item {
+                    for (i, line) in paragraph_text.lines().enumerate() {
+                        if let Some(kind) = lead_ins.kind(line) {
+                            current_args.lead_in(kind, None);
+                        } else if let Some(mut arg) = parse_definition_as_arg(line) {
+                            arg.line = Some(paragraph_line + i);
                             current_args.push(arg);
                         }
                     }
                 }
             }
 
+            // --- Bloques de código (ejemplos) ---
+            Event::Start(Tag::CodeBlock(kind)) => {
+                in_code_block = true;
+                code_block_text.clear();
+                // En bloques con fence el contenido empieza en la línea siguiente
+                code_block_line = match kind {
+                    CodeBlockKind::Fenced(_) => line + 1,
+                    CodeBlockKind::Indented => line,
+                };
+                code_block_lang = match kind {
+                    CodeBlockKind::Fenced(info) => info.split_whitespace().next().map(String::from),
+                    CodeBlockKind::Indented => None,
+                };
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                in_code_block = false;
+                if current_id.is_some() {
+                    collect_expectations(
+                        &code_block_text,
+                        code_block_line,
+                        &mut current_expectations,
+                    );
+                    current_examples.push(CodeExample {
+                        lang: code_block_lang.take(),
+                        code: std::mem::take(&mut code_block_text),
+                        line: code_block_line,
+                    });
+                }
+            }
+
             // --- Listas (ListStrategy) ---
             Event::Start(Tag::Item) => {
                 in_list_item = true;
                 list_item_text.clear();
+                list_item_line = line;
             }
             Event::End(TagEnd::Item) => {
                 in_list_item = false;
-                if current_id.is_some() {
-                    if let Some(arg) = parse_list_item_as_arg(&list_item_text) {
+                if current_id.is_some() && !in_footnote {
+                    if let Some(mut arg) = parse_list_item_as_arg(&list_item_text) {
+                        arg.line = Some(list_item_line);
                         current_args.push(arg);
                     }
                 }
@@ -138,8 +616,29 @@ pub fn parse_markdown_source(source: &str, file_path: &Path) -> Result<Vec<DocSe
             // --- Tablas (TableStrategy) ---
             Event::Start(Tag::Table(_)) => {
                 table_headers.clear();
+                table_args.clear();
+                table_line = line;
+                table_rejected = false;
+            }
+            Event::End(TagEnd::Table) => {
+                if let Some(id) = current_id.as_ref().filter(|_| table_rejected) {
+                    diagnostics.push(ParseNote {
+                        rule: Rule::SkippedArgTable,
+                        message: format!(
+                            "Tabla ignorada en la sección '{}': sin cabecera de nombre y su primera columna no parece una lista de argumentos.",
+                            id
+                        ),
+                        function_name: None,
+                        code_location: None,
+                        doc_id: Some(id.clone()),
+                        doc_location: Some(format!("{}:{}", file_path.display(), table_line)),
+                        hint: None,
+                    });
+                } else {
+                    current_args.extend(table_args.drain(..));
+                }
+                table_args.clear();
             }
-            Event::End(TagEnd::Table) => {}
             Event::Start(Tag::TableHead) => {
                 in_table_head = true;
                 table_row.clear();
@@ -147,16 +646,25 @@ pub fn parse_markdown_source(source: &str, file_path: &Path) -> Result<Vec<DocSe
             Event::End(TagEnd::TableHead) => {
                 in_table_head = false;
                 table_headers = table_row.clone();
+                table_has_name_header = find_column(&table_headers, NAME_HEADERS).is_some();
                 table_row.clear();
             }
             Event::Start(Tag::TableRow) => {
                 table_row.clear();
+                table_row_line = line;
             }
-            Event::End(TagEnd::TableRow) => {
-                if !in_table_head && current_id.is_some() && !table_row.is_empty() {
-                    if let Some(arg) = parse_table_row_as_arg(&table_headers, &table_row) {
-                        current_args.push(arg);
+            Event::End(TagEnd::TableRow)
+                if !in_table_head
+                    && current_id.is_some()
+                    && !in_footnote
+                    && !table_row.is_empty() =>
+            {
+                if let Some(mut arg) = parse_table_row_as_arg(&table_headers, &table_row) {
+                    if !table_has_name_header && !looks_like_identifier(&arg.name) {
+                        table_rejected = true;
                     }
+                    arg.line = Some(table_row_line);
+                    table_args.push(arg);
                 }
             }
             Event::Start(Tag::TableCell) => {
@@ -168,15 +676,30 @@ pub fn parse_markdown_source(source: &str, file_path: &Path) -> Result<Vec<DocSe
                 table_row.push(cell_text.trim().to_string());
             }
 
+            // --- Extensiones sin estrategia propia ---
+            Event::Start(Tag::FootnoteDefinition(_)) => in_footnote = true,
+            Event::End(TagEnd::FootnoteDefinition) => in_footnote = false,
+            // La llamada `[^1]` no es parte del nombre ni de la descripción
+            Event::FootnoteReference(_) => {}
+            // pulldown-cmark ya quitó la casilla del texto del ítem; el estado no importa
+            Event::TaskListMarker(_) => {}
+            // `Tag::Heading { id, classes, attrs }`: los atributos no llegan al texto
+            // `Tag::Strikethrough`: su texto llega como `Event::Text`
+
             // --- Texto ---
-            Event::SoftBreak | Event::HardBreak => {
-                if in_paragraph {
-                    paragraph_text.push('\n');
-                }
+            Event::SoftBreak | Event::HardBreak if in_paragraph => {
+                paragraph_text.push('\n');
             }
 
             Event::Text(text) => {
-                if in_heading {
+                let text = if smart_punctuation {
+                    straight_quotes(&text)
+                } else {
+                    Cow::Borrowed(&*text)
+                };
+                if in_code_block {
+                    code_block_text.push_str(&text);
+                } else if in_heading {
                     heading_text.push_str(&text);
                 } else if in_list_item {
                     list_item_text.push_str(&text);
@@ -188,6 +711,9 @@ pub fn parse_markdown_source(source: &str, file_path: &Path) -> Result<Vec<DocSe
             }
 
             Event::Code(code) => {
+                if let Some(name) = mention_name(&code).filter(|_| current_id.is_some()) {
+                    current_mentions.push((name.to_string(), line));
+                }
                 if in_heading {
                     heading_text.push_str(&code);
                 } else if in_list_item {
@@ -208,32 +734,315 @@ pub fn parse_markdown_source(source: &str, file_path: &Path) -> Result<Vec<DocSe
     }
 
     // Cerrar última sección si existe
+    current_args.extend(heading_arg.take());
     if let Some(id) = current_id.take() {
+        let title_inferred = fall_back_to_preceding(
+            &id,
+            &mut current_title,
+            &mut current_parent,
+            preceding_heading.take(),
+            &mut claimed_headings,
+            format!("{}:{}", file_path.display(), current_line),
+            diagnostics,
+        );
         sections.push(DocSection {
             id,
             title: current_title.take(),
-            args: std::mem::take(&mut current_args),
+            parent: current_parent.take(),
+            args: current_args.take(require_delimiter),
             file_path: file_path.to_path_buf(),
             line: current_line,
+            expectations: std::mem::take(&mut current_expectations),
+            examples: std::mem::take(&mut current_examples),
+            since: current_since.take(),
+            expected_function: current_expects.take(),
+            skips: std::mem::take(&mut current_skips),
+            dropped_args: 0,
+            title_inferred,
+            no_link: current_no_link,
+            mentions: std::mem::take(&mut current_mentions),
         });
     }
 
     Ok(sections)
 }
 
-/// Extrae el ID de un comentario HTML `<!-- @docs-id: xxx -->`.
+/// Nombre de un código en línea con forma de identificador (`connPool`,
+/// `retry()`); `None` para expresiones, rutas o tipos compuestos.
+fn mention_name(code: &str) -> Option<&str> {
+    let name = code.trim();
+    let name = name.strip_suffix("()").unwrap_or(name);
+    let mut chars = name.chars();
+    let starts_ident = chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$');
+    (starts_ident && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')).then_some(name)
+}
+
+/// Encabezado anterior a un marcador, con el suyo como padre.
+struct PrecedingHeading {
+    text: String,
+    parent: Option<String>,
+    line: usize,
+}
+
+/// Último encabezado abierto antes del marcador de la línea `marker_line`,
+/// si puede ser su título según `options`.
+fn preceding_heading_for(
+    heading_stack: &[(usize, String, usize)],
+    claimed: &HashSet<usize>,
+    marker_line: usize,
+    options: TitleOptions,
+) -> Option<PrecedingHeading> {
+    let [.., (_, text, line)] = heading_stack else {
+        return None;
+    };
+    if claimed.contains(line) || marker_line.saturating_sub(*line) > options.max_distance {
+        return None;
+    }
+    let parent = heading_stack
+        .len()
+        .checked_sub(2)
+        .map(|i| heading_stack[i].1.clone());
+    Some(PrecedingHeading {
+        text: text.clone(),
+        parent,
+        line: *line,
+    })
+}
+
+/// Si la sección `id` se cierra sin título, toma el encabezado anterior a su
+/// marcador y deja una nota `DG007` en `location`. Devuelve si lo tomó.
+fn fall_back_to_preceding(
+    id: &str,
+    title: &mut Option<String>,
+    parent: &mut Option<String>,
+    preceding: Option<PrecedingHeading>,
+    claimed: &mut HashSet<usize>,
+    location: String,
+    diagnostics: &mut ParseDiagnostics,
+) -> bool {
+    let Some(heading) = preceding.filter(|_| title.is_none()) else {
+        return false;
+    };
+    diagnostics.push(ParseNote {
+        rule: Rule::InferredTitle,
+        message: format!(
+            "La sección '{}' no tiene encabezado tras su marcador: se usa como título '{}', el anterior (línea {}).",
+            id, heading.text, heading.line
+        ),
+        function_name: None,
+        code_location: None,
+        doc_id: Some(id.to_string()),
+        doc_location: Some(location),
+        hint: None,
+    });
+    claimed.insert(heading.line);
+    *title = Some(heading.text);
+    *parent = heading.parent;
+    true
+}
+
+/// Campos `@clave: valor` de un comentario HTML, en orden
+/// (`<!-- @docs-id: auth-login @expects: login -->`). Admite `@clave:valor` y
+/// banderas sin valor (`@docs-no-link`), que llegan con `None`.
+/// `None` si no es un comentario o si algún token no empieza por `@` o no
+/// lleva valor tras los dos puntos.
+fn marker_fields(html: &str) -> Option<Vec<(&str, Option<&str>)>> {
+    let content = html.strip_prefix("<!--")?.strip_suffix("-->")?;
+    let mut tokens = content.split_whitespace();
+    let mut fields = Vec::new();
+    while let Some(token) = tokens.next() {
+        let token = token.strip_prefix('@')?;
+        let Some((key, value)) = token.split_once(':') else {
+            fields.push((token, None));
+            continue;
+        };
+        let value = match value {
+            "" => tokens.next()?,
+            value => value,
+        };
+        fields.push((key, Some(value)));
+    }
+    Some(fields)
+}
+
+/// Marcador de sección `<!-- @docs-id: xxx -->`, con la función que declara
+/// documentar si lleva `@expects: nombre`, las comprobaciones de
+/// `@docs-skip: args,return` y la bandera `@docs-no-link`.
+#[derive(Debug, Clone, PartialEq)]
+struct DocsMarker {
+    id: String,
+    expects: Option<String>,
+    skips: Vec<String>,
+    /// Valores de `@docs-skip` que no están en `SKIP_TOKENS`.
+    unknown_skips: Vec<String>,
+    no_link: bool,
+}
+
+/// Extrae el marcador de un comentario HTML
+/// `<!-- @docs-id: xxx [@expects: f] [@docs-skip: args] [@docs-no-link] -->`.
 ///
-/// VUL-01: solo acepta IDs con caracteres `[a-zA-Z0-9_-]`. Cualquier ID con
-/// newlines, espacios o caracteres especiales se descarta para prevenir que un
-/// doc malicioso inyecte código en archivos fuente vía `scaffold`.
-fn extract_docs_id_from_html(html: &str) -> Option<String> {
+/// VUL-01: solo acepta IDs (y nombres de `@expects`) con caracteres
+/// `[a-zA-Z0-9_-]`. Cualquier valor con newlines, espacios o caracteres
+/// especiales descarta el marcador para prevenir que un doc malicioso inyecte
+/// código en archivos fuente vía `scaffold`.
+fn extract_docs_marker(html: &str) -> Option<DocsMarker> {
+    let fields = marker_fields(html)?;
+    let [("docs-id", Some(id)), rest @ ..] = fields.as_slice() else {
+        return None;
+    };
+    if !is_valid_id(id) {
+        return None;
+    }
+    let mut expects = None;
+    let mut skip = None;
+    let mut no_link = false;
+    for field in rest {
+        match *field {
+            ("expects", Some(value)) if expects.is_none() && is_valid_id(value) => {
+                expects = Some(value.to_string())
+            }
+            ("docs-skip", Some(value)) if skip.is_none() => skip = Some(value),
+            ("docs-no-link", None) if !no_link => no_link = true,
+            _ => return None,
+        }
+    }
+    let mut skips = Vec::new();
+    let mut unknown_skips = Vec::new();
+    for token in skip.into_iter().flat_map(|s| s.split(',')) {
+        let token = token.trim().to_lowercase();
+        if SKIP_TOKENS.contains(&token.as_str()) {
+            if !skips.contains(&token) {
+                skips.push(token);
+            }
+        } else if !token.is_empty() {
+            unknown_skips.push(token);
+        }
+    }
+    Some(DocsMarker {
+        id: id.to_string(),
+        expects,
+        skips,
+        unknown_skips,
+        no_link,
+    })
+}
+
+/// Extrae la versión de un marcador `<!-- @docs-since: v2.3 -->`.
+fn extract_docs_since_from_html(html: &str) -> Option<String> {
     let content = html.strip_prefix("<!--")?.strip_suffix("-->")?;
-    let id = content.trim().strip_prefix("@docs-id:")?.trim();
-    if is_valid_id(id) {
-        Some(id.to_string())
-    } else {
-        None
+    let version = content.trim().strip_prefix("@docs-since:")?.trim();
+    (!version.is_empty() && !version.contains(char::is_whitespace)).then(|| version.to_string())
+}
+
+/// Prefijos de una línea "desde versión", en minúsculas (los largos primero).
+const SINCE_PREFIXES: &[&str] = &[
+    "disponible desde",
+    "available since",
+    "añadido en",
+    "added in",
+    "cambiado en",
+    "changed in",
+    "since",
+    "desde",
+];
+
+/// Reconoce `*Disponible desde: v2.3*`, `Since v2.3` o `Changed in: 1.4.0`.
+///
+/// Conservador: lo que sigue al prefijo debe empezar por un dígito (o `v` y
+/// dígito), así "Desde el panel…" no cuenta. La versión se devuelve tal cual.
+fn parse_since_line(line: &str) -> Option<String> {
+    let line = normalize_spaces(line);
+    let text = line.trim().trim_start_matches(['>', '*', '_', ' ']);
+    let prefix = SINCE_PREFIXES.iter().find(|p| {
+        text.get(..p.len())
+            .is_some_and(|head| head.to_lowercase() == **p)
+    })?;
+    let rest = &text[prefix.len()..];
+    let rest = strip_separator(rest).unwrap_or(rest.trim_start());
+    let version = rest
+        .split_whitespace()
+        .next()?
+        .trim_matches(['`', '*', '_'])
+        .trim_end_matches(['.', ',', ';', ')']);
+    let digits = version.strip_prefix(['v', 'V']).unwrap_or(version);
+    digits
+        .starts_with(|c: char| c.is_ascii_digit())
+        .then(|| version.to_string())
+}
+
+/// Comentario HTML con aspecto de marcador `@docs-id` que no se puede extraer
+/// (sin `-->`, clave mal escrita…). Devuelve su primera línea y la sintaxis sugerida.
+fn find_malformed_docs_id(html: &str) -> Option<(String, String)> {
+    let first_line = html.lines().next()?.trim();
+    let body = first_line
+        .strip_prefix("<!--")?
+        .trim_end_matches("-->")
+        .trim();
+    let lower = body.to_lowercase();
+    if !lower.starts_with("@doc") && !lower.starts_with("docs-id") {
+        return None;
     }
+    let id = suggested_id(body).unwrap_or_else(|| "id".into());
+    Some((first_line.to_string(), format!("<!-- @docs-id: {} -->", id)))
+}
+
+/// Separadores entre una llamada de ejemplo y su resultado esperado.
+const EXPECTATION_ARROWS: &[&str] = &["→", "=>", " returns "];
+
+/// Recoge las expectativas de cada línea de un bloque de texto.
+/// `first_line` es la línea (1-indexed) de la primera línea del texto.
+pub(crate) fn collect_expectations(text: &str, first_line: usize, out: &mut Vec<Expectation>) {
+    for (i, line) in text.lines().enumerate() {
+        if let Some((function, expected)) = parse_expectation(line) {
+            out.push(Expectation {
+                function,
+                expected,
+                line: first_line + i,
+            });
+        }
+    }
+}
+
+/// Reconoce `llamada(args) → resultado` en una línea de ejemplo.
+/// Formatos soportados:
+///   - `` `createUser("ana")` → `{ id: string }` ``
+///   - `createUser("ana") // => { id: "1" }`
+///   - `` `isAdmin(user)` returns `true` ``
+///
+/// Conservador: la llamada debe ser `identificador(` al inicio de una
+/// expresión; funciones flecha como `(x) => x` no cuentan.
+fn parse_expectation(line: &str) -> Option<(String, String)> {
+    let (arrow_pos, arrow) = EXPECTATION_ARROWS
+        .iter()
+        .filter_map(|a| line.find(a).map(|pos| (pos, *a)))
+        .min_by_key(|(pos, _)| *pos)?;
+
+    let lhs = line[..arrow_pos].trim_end();
+    let lhs = lhs
+        .strip_suffix("//")
+        .unwrap_or(lhs)
+        .trim()
+        .trim_matches('`');
+    let rhs = line[arrow_pos + arrow.len()..].trim();
+    let rhs = rhs.trim_matches('`').trim().trim_end_matches(';').trim();
+    if rhs.is_empty() {
+        return None;
+    }
+
+    let open = lhs.find('(')?;
+    let before = &lhs[..open];
+    let name_start = before
+        .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .map(|i| i + before[i..].chars().next().map_or(1, |c| c.len_utf8()))
+        .unwrap_or(0);
+    let name = &before[name_start..];
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((name.to_string(), rhs.to_string()))
 }
 
 /// Parsea un ítem de lista como argumento documentado.
@@ -242,6 +1051,7 @@ fn extract_docs_id_from_html(html: &str) -> Option<String> {
 ///   - `name` (`type`): description
 ///   - `name` (type) — description
 fn parse_list_item_as_arg(text: &str) -> Option<Arg> {
+    let text = normalize_spaces(text);
     let text = text.trim();
     if text.is_empty() {
         return None;
@@ -260,19 +1070,23 @@ fn parse_list_item_as_arg(text: &str) -> Option<Arg> {
         let end = stripped.find('`')?;
         let name = &stripped[..end];
         (name, &stripped[end + 1..])
-    } else if let Some(colon_pos) = text.find(':') {
-        let name = text[..colon_pos].trim();
+    } else if let Some((sep_pos, sep)) = find_separator(text) {
+        let name = text[..sep_pos].trim();
         // Verificar si hay tipo entre paréntesis en el nombre
-        if let Some(paren_pos) = name.find('(') {
-            (&text[..paren_pos], &text[paren_pos..])
-        } else {
-            (name, &text[colon_pos..])
+        let (name, rest) = match name.find('(') {
+            Some(paren_pos) => (&text[..paren_pos], &text[paren_pos..]),
+            None => (name, &text[sep_pos..]),
+        };
+        // Con guion la prosa (`Ver más - abajo`) no es un argumento
+        if DASHES.contains(&sep) && !looks_like_identifier(name.trim()) {
+            return None;
         }
+        (name, rest)
     } else {
         return None;
     };
 
-    let name = name_part.trim().to_string();
+    let name = clean_name(name_part);
     if name.is_empty() {
         return None;
     }
@@ -285,15 +1099,12 @@ fn parse_list_item_as_arg(text: &str) -> Option<Arg> {
             // Limpiar backticks del tipo
             let type_str = type_str.trim_matches('`');
             let desc = rest[close_paren + 1..].trim();
-            let desc = desc.strip_prefix(':').unwrap_or(desc);
-            let desc = desc.strip_prefix('—').unwrap_or(desc);
-            let desc = desc.strip_prefix('-').unwrap_or(desc);
+            let desc = strip_separator(desc).unwrap_or(desc);
             (Some(type_str.to_string()), Some(desc.trim().to_string()))
         } else {
             (None, Some(rest.to_string()))
         }
-    } else if let Some(stripped) = rest.strip_prefix(':') {
-        let desc = stripped.trim();
+    } else if let Some(desc) = strip_separator(rest) {
         (None, Some(desc.to_string()))
     } else {
         (
@@ -310,9 +1121,94 @@ fn parse_list_item_as_arg(text: &str) -> Option<Arg> {
         name,
         type_name,
         description: description.filter(|d| !d.is_empty()),
+        source: ArgSource::List,
+        line: None,
+        unit: None,
+        range: None,
+    })
+}
+
+/// Separadores nombre/descripción con dos puntos (también el de ancho completo
+/// de textos CJK pegados).
+const COLONS: &[char] = &[':', '：'];
+
+/// Guiones separadores (`-`, en dash, em dash). Dentro de una línea solo
+/// cuentan rodeados de espacios, para no partir nombres como `user-id`.
+const DASHES: &[char] = &['-', '–', '—'];
+
+/// Caracteres de ancho cero que llegan al copiar y pegar.
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}'
+    )
+}
+
+/// Sustituye los espacios tipográficos (NBSP, espacio fino…) por espacios
+/// normales y quita los caracteres de ancho cero.
+fn normalize_spaces(text: &str) -> String {
+    text.chars()
+        .filter(|c| !is_invisible(*c))
+        .map(|c| {
+            if c != '\n' && c.is_whitespace() {
+                ' '
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// Nombre de argumento limpio: sin caracteres invisibles ni espacios
+/// tipográficos en los bordes y con los espacios internos colapsados.
+fn clean_name(name: &str) -> String {
+    normalize_spaces(name)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Primer separador nombre/descripción de una línea: dos puntos, o un guion
+/// rodeado de espacios. Devuelve su posición y el carácter.
+fn find_separator(text: &str) -> Option<(usize, char)> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    chars.iter().enumerate().find_map(|(i, &(pos, c))| {
+        let spaced = i > 0
+            && chars[i - 1].1 == ' '
+            && chars.get(i + 1).is_some_and(|&(_, next)| next == ' ');
+        (COLONS.contains(&c) || (DASHES.contains(&c) && spaced)).then_some((pos, c))
+    })
+}
+
+/// Quita el separador inicial (`:`, `：`, `-`, `–`, `—`) de una descripción.
+fn strip_separator(text: &str) -> Option<&str> {
+    let text = text.trim_start();
+    text.strip_prefix(COLONS)
+        .or_else(|| text.strip_prefix(DASHES))
+        .map(str::trim_start)
+}
+
+/// Cabeceras que identifican la columna de nombres en una tabla de argumentos.
+const NAME_HEADERS: &[&str] = &["name", "param", "arg", "nombre"];
+
+/// Índice de la primera cabecera que contiene alguno de `names` (sin mayúsculas).
+fn find_column(headers: &[String], names: &[&str]) -> Option<usize> {
+    headers.iter().position(|h| {
+        let lower = h.to_lowercase();
+        names.iter().any(|n| lower.contains(n))
     })
 }
 
+/// Heurística de tablas sin cabecera de nombre: la primera columna debe
+/// parecer un identificador (`user_id`, `opts.limit`, `...rest`, `name?`).
+fn looks_like_identifier(name: &str) -> bool {
+    let name = name.trim_start_matches("...").trim_end_matches('?');
+    name.starts_with(|c: char| c.is_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '$' | '.' | '-'))
+}
+
 /// Parsea una fila de tabla como argumento documentado.
 /// Espera columnas con headers como: Name/Param, Type, Description.
 fn parse_table_row_as_arg(headers: &[String], row: &[String]) -> Option<Arg> {
@@ -320,18 +1216,11 @@ fn parse_table_row_as_arg(headers: &[String], row: &[String]) -> Option<Arg> {
         return None;
     }
 
-    let find_col = |names: &[&str]| -> Option<usize> {
-        headers.iter().position(|h| {
-            let lower = h.to_lowercase();
-            names.iter().any(|n| lower.contains(n))
-        })
-    };
-
-    let name_col = find_col(&["name", "param", "arg", "nombre"]).unwrap_or(0);
-    let type_col = find_col(&["type", "tipo"]);
-    let desc_col = find_col(&["desc", "descripción", "description"]);
+    let name_col = find_column(headers, NAME_HEADERS).unwrap_or(0);
+    let type_col = find_column(headers, &["type", "tipo"]);
+    let desc_col = find_column(headers, &["desc", "descripción", "description"]);
 
-    let name = row.get(name_col)?.trim().trim_matches('`').to_string();
+    let name = clean_name(row.get(name_col)?.trim().trim_matches('`'));
     if name.is_empty() {
         return None;
     }
@@ -350,6 +1239,10 @@ fn parse_table_row_as_arg(headers: &[String], row: &[String]) -> Option<Arg> {
         name,
         type_name,
         description,
+        source: ArgSource::Table,
+        line: None,
+        unit: None,
+        range: None,
     })
 }
 
@@ -362,6 +1255,7 @@ fn parse_table_row_as_arg(headers: &[String], row: &[String]) -> Option<Arg> {
 /// Solo matchea si la línea empieza con backtick-wrapped name o un identificador
 /// seguido de `:` o `(type):`, para evitar falsos positivos con prosa normal.
 fn parse_definition_as_arg(line: &str) -> Option<Arg> {
+    let line = normalize_spaces(line);
     let line = line.trim();
     if line.is_empty() {
         return None;
@@ -371,7 +1265,7 @@ fn parse_definition_as_arg(line: &str) -> Option<Arg> {
     // falsos positivos con prosa normal que contenga dos puntos.
     if let Some(stripped) = line.strip_prefix('`') {
         let end = stripped.find('`')?;
-        let name = stripped[..end].trim();
+        let name = clean_name(&stripped[..end]);
         if name.is_empty() || name.contains(' ') {
             return None;
         }
@@ -383,31 +1277,235 @@ fn parse_definition_as_arg(line: &str) -> Option<Arg> {
             if let Some(close_paren) = rest.find(')') {
                 let type_str = rest[1..close_paren].trim().trim_matches('`');
                 let desc = rest[close_paren + 1..].trim();
-                let desc = desc.strip_prefix(':').unwrap_or(desc);
-                let desc = desc.strip_prefix('—').unwrap_or(desc);
-                let desc = desc.strip_prefix('-').unwrap_or(desc);
+                let desc = strip_separator(desc).unwrap_or(desc);
                 (Some(type_str.to_string()), Some(desc.trim().to_string()))
             } else {
                 (None, Some(rest.to_string()))
             }
-        } else if let Some(stripped) = rest.strip_prefix(':') {
-            (None, Some(stripped.trim().to_string()))
+        } else if let Some(desc) = strip_separator(rest) {
+            (None, Some(desc.to_string()))
         } else {
             return None; // No separator after name — not a definition
         };
 
         Some(Arg {
-            name: name.to_string(),
+            name,
             type_name,
             description: description.filter(|d| !d.is_empty()),
+            source: ArgSource::Definition,
+            line: None,
+            unit: None,
+            range: None,
         })
     } else {
         None
     }
 }
 
+/// Entradillas que abren el bloque de argumentos de una sección.
+///
+/// Como las de `STRUCTURAL_HEADINGS`, nunca son argumentos aunque parezcan
+/// identificadores.
+const ARGS_LEAD_INS: &[&str] = &[
+    "arguments",
+    "args",
+    "options",
+    "parameters",
+    "params",
+    "argumentos",
+    "opciones",
+    "parámetros",
+];
+
+/// Encabezados estructurales de una sección (ejemplos, errores, retorno…).
+///
+/// Nunca son argumentos aunque parezcan identificadores y cierran el bloque
+/// de argumentos; con `ARGS_LEAD_INS` es la lista común para cualquier
+/// detección de subsecciones dentro de una sección.
+const STRUCTURAL_HEADINGS: &[&str] = &[
+    "description",
+    "error",
+    "errors",
+    "example",
+    "examples",
+    "note",
+    "notes",
+    "raises",
+    "request",
+    "response",
+    "return",
+    "returns",
+    "throws",
+    "type",
+    "usage",
+    "descripción",
+    "devuelve",
+    "ejemplo",
+    "ejemplos",
+    "errores",
+    "excepciones",
+    "nota",
+    "notas",
+    "respuesta",
+    "retorna",
+    "retorno",
+    "tipo",
+    "uso",
+];
+
+/// Qué abre una entradilla: un encabezado o una línea sola (`**Parameters:**`,
+/// `Errores:`) con una de las palabras de `ARGS_LEAD_INS` o
+/// `STRUCTURAL_HEADINGS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LeadIn {
+    Args,
+    Structural,
+}
+
+/// Palabras que se añaden a las entradillas de serie (`lead_ins` en la
+/// configuración), sin distinguir mayúsculas.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LeadIns {
+    /// Abren el bloque de argumentos (`Inputs`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    /// Abren otra parte de la sección y cierran el bloque (`Callbacks`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub structural: Vec<String>,
+}
+
+impl LeadIns {
+    /// Entradilla que es `text` entero (sin los `:` finales), si lo es.
+    fn kind(&self, text: &str) -> Option<LeadIn> {
+        let lower = text.trim().trim_end_matches(':').trim_end().to_lowercase();
+        let listed = |builtin: &[&str], extra: &[String]| {
+            builtin.contains(&lower.as_str()) || extra.iter().any(|w| w.to_lowercase() == lower)
+        };
+        if listed(ARGS_LEAD_INS, &self.args) {
+            Some(LeadIn::Args)
+        } else if listed(STRUCTURAL_HEADINGS, &self.structural) {
+            Some(LeadIn::Structural)
+        } else {
+            None
+        }
+    }
+}
+
+/// Un subencabezado es un argumento si es un único identificador que no es
+/// una entradilla.
+fn is_arg_heading(text: &str, lead_ins: &LeadIns) -> bool {
+    looks_like_identifier(text) && lead_ins.kind(text).is_none()
+}
+
+/// Argumentos de la sección abierta.
+///
+/// Si la sección tiene un bloque de argumentos (desde una entradilla de
+/// `ARGS_LEAD_INS` hasta la siguiente entradilla o encabezado), solo
+/// cuentan los de dentro: las listas de pasos, notas o códigos de error de
+/// fuera no son argumentos. Sin bloque cuentan todos, o ninguno con
+/// `require_args_delimiter`.
+#[derive(Debug, Default)]
+struct SectionArgs {
+    inside: Vec<Arg>,
+    outside: Vec<Arg>,
+    /// Bloque abierto, con el nivel del encabezado que lo abrió (`None` si
+    /// lo abrió una línea).
+    block: Option<Option<usize>>,
+    delimited: bool,
+}
+
+impl SectionArgs {
+    fn push(&mut self, arg: Arg) {
+        match self.block {
+            Some(_) => self.inside.push(arg),
+            None => self.outside.push(arg),
+        }
+    }
+
+    fn extend(&mut self, args: impl IntoIterator<Item = Arg>) {
+        for arg in args {
+            self.push(arg);
+        }
+    }
+
+    /// Una entradilla en la línea o el encabezado de nivel `heading_level`.
+    fn lead_in(&mut self, kind: LeadIn, heading_level: Option<usize>) {
+        match kind {
+            LeadIn::Args => {
+                self.block = Some(heading_level);
+                self.delimited = true;
+            }
+            LeadIn::Structural => self.block = None,
+        }
+    }
+
+    /// Un encabezado que no es entradilla cierra el bloque, salvo que sea un
+    /// argumento por debajo del encabezado que lo abrió.
+    fn heading(&mut self, level: usize, is_arg: bool) {
+        if let Some(opened_at) = self.block {
+            if !is_arg || opened_at.is_some_and(|opened| level <= opened) {
+                self.block = None;
+            }
+        }
+    }
+
+    /// Argumentos de la sección que se cierra; deja el estado para la siguiente.
+    fn take(&mut self, require_delimiter: bool) -> Vec<Arg> {
+        let args = std::mem::take(self);
+        if args.delimited {
+            args.inside
+        } else if require_delimiter {
+            Vec::new()
+        } else {
+            args.outside
+        }
+    }
+}
+
+/// Aplica un párrafo bajo un argumento de encabezado (HeadingStrategy).
+///
+/// Las líneas `Type:`/`Tipo:` fijan el tipo en cualquier párrafo; el primero
+/// aporta la descripción y, si no hay línea de tipo, el primer `` `tipo` ``.
+fn apply_heading_paragraph(arg: &mut Arg, paragraph: &str, first: bool) {
+    let mut description: Vec<&str> = Vec::new();
+    for line in paragraph.lines() {
+        match parse_type_line(line) {
+            Some(type_name) => arg.type_name = Some(type_name),
+            None if first => description.push(line.trim()),
+            None => {}
+        }
+    }
+    if !first {
+        return;
+    }
+    if arg.type_name.is_none() {
+        arg.type_name = first_code_span(paragraph);
+    }
+    arg.description = Some(description.join(" ")).filter(|d| !d.is_empty());
+}
+
+/// `Type: string` / `Tipo: `string`` → `string`.
+fn parse_type_line(line: &str) -> Option<String> {
+    let line = line.trim();
+    let (label, rest) = line.split_once(':')?;
+    if !matches!(label.trim().to_lowercase().as_str(), "type" | "tipo") {
+        return None;
+    }
+    let type_name = rest.trim().trim_matches('`').trim();
+    (!type_name.is_empty()).then(|| type_name.to_string())
+}
+
+/// Contenido del primer `` `span` `` de código de un texto.
+fn first_code_span(text: &str) -> Option<String> {
+    let start = text.find('`')? + 1;
+    let end = start + text[start..].find('`')?;
+    let span = text[start..end].trim();
+    (!span.is_empty()).then(|| span.to_string())
+}
+
 /// Construye un índice de offsets por línea para convertir byte offset → número de línea.
-fn build_line_offsets(source: &str) -> Vec<usize> {
+pub(crate) fn build_line_offsets(source: &str) -> Vec<usize> {
     let mut offsets = vec![0];
     for (i, byte) in source.bytes().enumerate() {
         if byte == b'\n' {
@@ -418,7 +1516,7 @@ fn build_line_offsets(source: &str) -> Vec<usize> {
 }
 
 /// Convierte un byte offset a número de línea (1-based).
-fn offset_to_line(line_offsets: &[usize], offset: usize) -> usize {
+pub(crate) fn offset_to_line(line_offsets: &[usize], offset: usize) -> usize {
     match line_offsets.binary_search(&offset) {
         Ok(line) => line + 1,
         Err(line) => line,
@@ -430,6 +1528,10 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
 
+    fn extract_docs_id_from_html(html: &str) -> Option<String> {
+        extract_docs_marker(html).map(|marker| marker.id)
+    }
+
     #[test]
     fn extract_id_from_html_comment() {
         assert_eq!(
@@ -470,6 +1572,171 @@ mod tests {
         );
     }
 
+    #[test]
+    fn expects_token_declares_the_documented_function() {
+        let marker = extract_docs_marker("<!-- @docs-id: auth-login @expects: login -->").unwrap();
+        assert_eq!(marker.id, "auth-login");
+        assert_eq!(marker.expects.as_deref(), Some("login"));
+        assert_eq!(
+            extract_docs_marker("<!-- @docs-id:auth-login @expects:login -->"),
+            Some(marker)
+        );
+        for html in [
+            "<!-- @docs-id: auth-login @expects: log in -->",
+            "<!-- @docs-id: auth-login @expects: evil()\n -->",
+            "<!-- @docs-id: auth-login @expects: a @expects: b -->",
+            "<!-- @docs-id: auth-login @expect: login -->",
+            "<!-- @expects: login @docs-id: auth-login -->",
+            "<!-- @docs-id: auth-login @expects: -->",
+        ] {
+            assert_eq!(extract_docs_marker(html), None, "{html}");
+        }
+
+        let sections = parse_markdown_source(
+            "<!-- @docs-id: auth-login @expects: login -->\n## Login\n\n\
+             <!-- @docs-id: auth-logout -->\n## Logout\n",
+            &PathBuf::from("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let expected: Vec<_> = sections
+            .iter()
+            .map(|s| s.expected_function.as_deref())
+            .collect();
+        assert_eq!(expected, vec![Some("login"), None]);
+    }
+
+    #[test]
+    fn docs_skip_lists_known_checks_and_notes_unknown_tokens() {
+        let mut diagnostics = ParseDiagnostics::default();
+        let sections = parse_markdown_source(
+            "<!-- @docs-id: payments-overview @docs-skip: args,RETURN,args -->\n## Pagos\n\n\
+             <!-- @docs-id: charge @expects: charge @docs-skip: args,params -->\n## charge\n\n\
+             <!-- @docs-id: refund -->\n## refund\n",
+            &PathBuf::from("docs/api.md"),
+            &mut diagnostics,
+        )
+        .unwrap();
+        let skips: Vec<&[String]> = sections.iter().map(|s| s.skips.as_slice()).collect();
+        assert_eq!(skips, [&["args", "return"][..], &["args"][..], &[][..]]);
+        assert!(sections[0].is_prose_only() && sections[0].skips_return());
+        assert_eq!(sections[1].expected_function.as_deref(), Some("charge"));
+
+        assert_eq!(diagnostics.notes.len(), 1);
+        let note = &diagnostics.notes[0];
+        assert_eq!(note.rule, Rule::MalformedAnnotation);
+        assert!(note.message.contains("`params`"), "{}", note.message);
+        assert_eq!(note.doc_location.as_deref(), Some("docs/api.md:4"));
+    }
+
+    #[test]
+    fn docs_no_link_flag_marks_the_section() {
+        let marker =
+            extract_docs_marker("<!-- @docs-id: template-endpoint @docs-no-link -->").unwrap();
+        assert!(marker.no_link);
+        assert_eq!(
+            extract_docs_marker(
+                "<!-- @docs-id:template-endpoint @docs-skip:args @docs-no-link @expects:get -->"
+            )
+            .map(|m| (m.no_link, m.skips, m.expects)),
+            Some((true, vec!["args".into()], Some("get".into())))
+        );
+        for html in [
+            "<!-- @docs-id: template-endpoint @docs-no-link @docs-no-link -->",
+            "<!-- @docs-id: template-endpoint @docs-no-link: yes -->",
+            "<!-- @docs-no-link @docs-id: template-endpoint -->",
+            "<!-- @docs-id: template-endpoint @docs-nolink -->",
+            "<!-- @docs-id: template-endpoint @expects -->",
+        ] {
+            assert_eq!(extract_docs_marker(html), None, "{html}");
+        }
+
+        let sections = parse_markdown_source(
+            "<!-- @docs-id: orders-get -->\n## Get\n\n\
+             <!-- @docs-id: template-endpoint @docs-no-link -->\n## Copia esta sección\n",
+            &PathBuf::from("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let flags: Vec<_> = sections
+            .iter()
+            .map(|s| (s.id.as_str(), s.no_link))
+            .collect();
+        assert_eq!(flags, [("orders-get", false), ("template-endpoint", true)]);
+    }
+
+    fn titles_with(source: &str, max_distance: usize) -> (Vec<DocSection>, ParseDiagnostics) {
+        let mut diagnostics = ParseDiagnostics::default();
+        let sections = parse_markdown_source_with(
+            source,
+            &PathBuf::from("docs/api.md"),
+            DocParseOptions {
+                title: TitleOptions { max_distance },
+                ..DocParseOptions::default()
+            },
+            &mut diagnostics,
+        )
+        .unwrap();
+        (sections, diagnostics)
+    }
+
+    #[test]
+    fn a_marker_below_its_heading_takes_it_as_inferred_title() {
+        let (sections, diagnostics) = titles_with(
+            "# Auth\n\n## Login\n\nInicia sesión.\n\n<!-- @docs-id: auth-login -->\n\n\
+             | Param | Type | Description |\n|-------|------|-------------|\n\
+             | username | string | Usuario |\n",
+            DEFAULT_TITLE_MAX_DISTANCE,
+        );
+        let section = &sections[0];
+        assert_eq!(section.title.as_deref(), Some("Login"));
+        assert_eq!(section.parent.as_deref(), Some("Auth"));
+        assert!(section.title_inferred);
+        assert_eq!(section.args[0].name, "username");
+
+        assert_eq!(diagnostics.notes.len(), 1);
+        let note = &diagnostics.notes[0];
+        assert_eq!(note.rule, Rule::InferredTitle);
+        assert!(
+            note.message.contains("'Login', el anterior (línea 3)"),
+            "{}",
+            note.message
+        );
+        assert_eq!(note.doc_location.as_deref(), Some("docs/api.md:7"));
+    }
+
+    #[test]
+    fn a_heading_after_the_marker_still_wins() {
+        for source in [
+            "<!-- @docs-id: auth-login -->\n## Login\n",
+            "## Auth\n\n<!-- @docs-id: auth-login -->\n## Login\n",
+        ] {
+            let (sections, diagnostics) = titles_with(source, DEFAULT_TITLE_MAX_DISTANCE);
+            assert_eq!(sections[0].title.as_deref(), Some("Login"), "{source}");
+            assert!(!sections[0].title_inferred, "{source}");
+            assert!(diagnostics.notes.is_empty(), "{source}");
+        }
+    }
+
+    #[test]
+    fn a_claimed_or_distant_heading_is_not_inferred() {
+        // `## Login` ya es el título de auth-login
+        let (sections, _) = titles_with(
+            "<!-- @docs-id: auth-login -->\n## Login\n\n<!-- @docs-id: auth-logout -->\nTexto.\n",
+            DEFAULT_TITLE_MAX_DISTANCE,
+        );
+        assert_eq!(sections[1].title, None);
+        assert!(!sections[1].title_inferred);
+
+        let source = "## Login\n\nUno.\n\nDos.\n\n<!-- @docs-id: auth-login -->\nTexto.\n";
+        assert_eq!(titles_with(source, 6).0[0].title.as_deref(), Some("Login"));
+        for max_distance in [5, 0] {
+            let (sections, diagnostics) = titles_with(source, max_distance);
+            assert_eq!(sections[0].title, None, "{max_distance}");
+            assert!(diagnostics.notes.is_empty(), "{max_distance}");
+        }
+    }
+
     #[test]
     fn parse_section_with_id_and_heading() {
         let source = r#"
@@ -480,7 +1747,12 @@ mod tests {
 
 Authenticates a user.
 "#;
-        let sections = parse_markdown_source(source, &PathBuf::from("docs/api.md")).unwrap();
+        let sections = parse_markdown_source(
+            source,
+            &PathBuf::from("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(sections.len(), 1);
         assert_eq!(sections[0].id, "auth-login");
         assert_eq!(sections[0].title.as_deref(), Some("Login"));
@@ -496,7 +1768,12 @@ Arguments:
 - username: The user's login name
 - password: The user's password
 "#;
-        let sections = parse_markdown_source(source, &PathBuf::from("docs/api.md")).unwrap();
+        let sections = parse_markdown_source(
+            source,
+            &PathBuf::from("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(sections.len(), 1);
         assert_eq!(sections[0].args.len(), 2);
         assert_eq!(sections[0].args[0].name, "username");
@@ -504,6 +1781,126 @@ Arguments:
             sections[0].args[0].description.as_deref(),
             Some("The user's login name")
         );
+        assert_eq!(sections[0].args[0].source, ArgSource::List);
+        assert_eq!(sections[0].args[1].line, Some(7));
+    }
+
+    const DELIMITED_ARGS: &str = r#"
+<!-- @docs-id: upload -->
+## Upload
+
+Steps:
+- Timeout: requests abort after 30s
+- Retry: once, after 1s
+
+**Parameters:**
+- file: The file to upload
+- overwrite: Replace an existing file
+
+**Errors:**
+- E409: the file already exists
+
+### Limits
+- Size: 10 MB at most
+"#;
+
+    fn section_arg_names(source: &str, options: DocParseOptions) -> Vec<Vec<String>> {
+        parse_markdown_source_with(
+            source,
+            &PathBuf::from("docs/api.md"),
+            options,
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap()
+        .iter()
+        .map(|s| s.args.iter().map(|a| a.name.clone()).collect())
+        .collect()
+    }
+
+    #[test]
+    fn only_the_parameters_block_documents_args_when_there_is_one() {
+        // Ni los pasos de antes ni los códigos de error o la subsección de después
+        assert_eq!(
+            section_arg_names(DELIMITED_ARGS, DocParseOptions::default()),
+            [["file", "overwrite"]]
+        );
+
+        // Un encabezado también abre el bloque, y otro encabezado lo cierra
+        let source = "\
+<!-- @docs-id: upload -->
+## Upload
+
+- Timeout: requests abort after 30s
+
+### Parámetros
+
+| Nombre | Tipo | Descripción |
+|--------|------|-------------|
+| file | File | Archivo |
+
+#### overwrite
+Reemplaza el archivo.
+
+### Errores
+
+- E409: ya existe
+";
+        assert_eq!(
+            section_arg_names(source, DocParseOptions::default()),
+            [["file", "overwrite"]]
+        );
+    }
+
+    #[test]
+    fn sections_without_a_parameters_block_stay_permissive_unless_required() {
+        let source = "\
+<!-- @docs-id: a -->
+## A
+
+- id: Identificador
+
+<!-- @docs-id: b -->
+## B
+
+Args:
+- name: Nombre
+";
+        assert_eq!(
+            section_arg_names(source, DocParseOptions::default()),
+            [vec!["id"], vec!["name"]]
+        );
+        let required = DocParseOptions {
+            require_args_delimiter: true,
+            ..DocParseOptions::default()
+        };
+        assert_eq!(section_arg_names(source, required), [vec![], vec!["name"]]);
+    }
+
+    #[test]
+    fn configured_lead_ins_extend_the_builtin_ones() {
+        let source = "\
+<!-- @docs-id: hook -->
+## Hook
+
+Inputs:
+- event: Evento
+- payload: Datos
+
+Callbacks:
+- onDone: Al terminar
+";
+        assert_eq!(
+            section_arg_names(source, DocParseOptions::default()),
+            [["event", "payload", "onDone"]]
+        );
+        let options = DocParseOptions {
+            lead_ins: LeadIns {
+                args: vec!["inputs".into()],
+                structural: vec!["Callbacks".into()],
+            },
+            ..DocParseOptions::default()
+        };
+        assert_eq!(section_arg_names(source, options), [["event", "payload"]]);
     }
 
     #[test]
@@ -517,12 +1914,93 @@ Arguments:
 | name | string | The user's display name |
 | email | string | The user's email address |
 "#;
-        let sections = parse_markdown_source(source, &PathBuf::from("docs/api.md")).unwrap();
+        let sections = parse_markdown_source(
+            source,
+            &PathBuf::from("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(sections.len(), 1);
         assert_eq!(sections[0].args.len(), 2);
         assert_eq!(sections[0].args[0].name, "name");
         assert_eq!(sections[0].args[0].type_name.as_deref(), Some("string"));
         assert_eq!(sections[0].args[1].name, "email");
+        assert_eq!(sections[0].args[0].source, ArgSource::Table);
+        assert_eq!(sections[0].args[0].line, Some(7));
+    }
+
+    #[test]
+    fn table_without_name_header_and_prose_cells_is_skipped_and_noted() {
+        let source = r#"<!-- @docs-id: user-create -->
+## Create User
+
+| Código | Significado |
+|--------|-------------|
+| 404 Not Found | El usuario no existe |
+
+| Campo | Tipo |
+|-------|------|
+| user_id | string |
+"#;
+        let mut diagnostics = ParseDiagnostics::default();
+        let sections =
+            parse_markdown_source(source, &PathBuf::from("api.md"), &mut diagnostics).unwrap();
+        let names: Vec<_> = sections[0].args.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["user_id"]);
+        assert_eq!(diagnostics.notes.len(), 1);
+        assert_eq!(diagnostics.notes[0].rule, Rule::SkippedArgTable);
+        assert_eq!(
+            diagnostics.notes[0].doc_location.as_deref(),
+            Some("api.md:4")
+        );
+    }
+
+    #[test]
+    fn malformed_docs_id_markers_are_noted() {
+        let cases = [
+            ("<!-- @docs-id: auth-login\n## Login\n", 1),
+            ("# API\n\n<!-- @doc-id: auth-login -->\n## Login\n", 3),
+            ("<!-- @docsid auth-login -->\n## Login\n", 1),
+            ("<!-- docs-id: auth-login -->\n## Login\n", 1),
+        ];
+        for (source, line) in cases {
+            let mut diagnostics = ParseDiagnostics::default();
+            let sections =
+                parse_markdown_source(source, &PathBuf::from("api.md"), &mut diagnostics).unwrap();
+            assert!(sections.is_empty(), "{}", source);
+            assert_eq!(diagnostics.notes.len(), 1, "{}", source);
+            let result = diagnostics.notes[0].to_result(crate::core::types::Severity::Warning);
+            assert_eq!(result.rule, Rule::MalformedAnnotation);
+            assert_eq!(result.doc_location, Some(format!("api.md:{}", line)));
+            assert_eq!(
+                result.hint.as_deref(),
+                Some("Sintaxis correcta: `<!-- @docs-id: auth-login -->`")
+            );
+        }
+    }
+
+    #[test]
+    fn sections_remember_their_parent_heading() {
+        let source = r#"<!-- @docs-id: payments-overview -->
+# Payments
+
+<!-- @docs-id: payments-charge -->
+## charge
+
+### Errores
+
+<!-- @docs-id: payments-refund -->
+## refund
+"#;
+        let sections = parse_markdown_source(
+            source,
+            &PathBuf::from("api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        assert_eq!(sections[0].parent, None);
+        assert_eq!(sections[1].parent.as_deref(), Some("Payments"));
+        assert_eq!(sections[2].parent.as_deref(), Some("Payments"));
     }
 
     #[test]
@@ -536,7 +2014,12 @@ Parameters:
 `name` (`String`): The user's display name
 `email` (`String`): The user's email address
 "#;
-        let sections = parse_markdown_source(source, &PathBuf::from("docs/api.md")).unwrap();
+        let sections = parse_markdown_source(
+            source,
+            &PathBuf::from("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(sections.len(), 1);
         assert_eq!(sections[0].args.len(), 2);
         assert_eq!(sections[0].args[0].name, "name");
@@ -546,6 +2029,171 @@ Parameters:
             Some("The user's display name")
         );
         assert_eq!(sections[0].args[1].name, "email");
+        assert_eq!(sections[0].args[0].source, ArgSource::Definition);
+        assert_eq!(sections[0].args[1].line, Some(8));
+    }
+
+    #[test]
+    fn heading_per_arg_subsections_mix_with_tables() {
+        let source = r#"
+<!-- @docs-id: auth-login -->
+## Login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| password | string | The user's password |
+
+#### username
+
+The user's login name.
+
+Type: `string`
+
+#### remember
+
+Keeps the session open, a `boolean`.
+
+#### Examples
+
+`login("ana")` → `{ token }`
+
+#### Errors
+
+Fails with `AuthError`.
+
+<!-- @docs-id: auth-logout -->
+## logout
+
+#### session_id
+
+Tipo: string
+"#;
+        let sections = parse_markdown_source(
+            source,
+            &PathBuf::from("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let args: Vec<(&str, Option<&str>, ArgSource)> = sections[0]
+            .args
+            .iter()
+            .map(|a| (a.name.as_str(), a.type_name.as_deref(), a.source))
+            .collect();
+        assert_eq!(
+            args,
+            vec![
+                ("password", Some("string"), ArgSource::Table),
+                ("username", Some("string"), ArgSource::Heading),
+                ("remember", Some("boolean"), ArgSource::Heading),
+            ]
+        );
+        assert_eq!(
+            sections[0].args[1].description.as_deref(),
+            Some("The user's login name.")
+        );
+        assert_eq!(sections[0].args[1].line, Some(9));
+        assert_eq!(sections[0].expectations.len(), 1);
+
+        assert_eq!(sections[1].args.len(), 1);
+        assert_eq!(sections[1].args[0].name, "session_id");
+        assert_eq!(sections[1].args[0].type_name.as_deref(), Some("string"));
+        assert_eq!(sections[1].args[0].description, None);
+    }
+
+    #[test]
+    fn since_comes_from_a_marker_or_the_first_since_line() {
+        let source = r#"
+<!-- @docs-id: user-create -->
+## createUser
+
+Desde el panel se crean usuarios.
+
+*Disponible desde: v2.3*
+
+Changed in 2.5.
+
+<!-- @docs-id: user-delete -->
+<!-- @docs-since: 3.0.0-beta -->
+## deleteUser
+
+> **Since** `v2.9`
+
+<!-- @docs-id: user-list -->
+## listUsers
+"#;
+        let mut diagnostics = ParseDiagnostics::default();
+        let sections =
+            parse_markdown_source(source, &PathBuf::from("docs/api.md"), &mut diagnostics).unwrap();
+        let since: Vec<_> = sections.iter().map(|s| s.since.as_deref()).collect();
+        assert_eq!(since, vec![Some("v2.3"), Some("3.0.0-beta"), None]);
+        assert!(diagnostics.notes.is_empty());
+    }
+
+    #[test]
+    fn headings_at_the_section_level_or_with_prose_are_not_args() {
+        let source = r#"
+<!-- @docs-id: user-create -->
+### createUser
+
+### username
+
+#### Return value
+
+#### Parámetros
+"#;
+        let sections = parse_markdown_source(
+            source,
+            &PathBuf::from("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        assert!(sections[0].args.is_empty());
+    }
+
+    #[test]
+    fn pasted_typography_still_yields_the_code_arg_names() {
+        let sections = parse_markdown_source(
+            include_str!("../../fixtures/typography.md"),
+            &PathBuf::from("fixtures/typography.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let entities = crate::parser::lang::typescript::parse_typescript_source(
+            include_str!("../../fixtures/typography.ts"),
+            &PathBuf::from("fixtures/typography.ts"),
+            crate::parser::code_parser::AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+
+        let doc_names: Vec<&str> = sections[0].args.iter().map(|a| a.name.as_str()).collect();
+        let code_names: Vec<&str> = entities[0].args.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(doc_names, code_names);
+        for arg in &sections[0].args {
+            let description = arg.description.as_deref().unwrap_or_default();
+            assert!(
+                !description.is_empty() && !description.starts_with([':', '：', '-', '–', '—']),
+                "{}: {:?}",
+                arg.name,
+                arg.description
+            );
+        }
+        let prioridad = sections[0].args.iter().find(|a| a.name == "prioridad");
+        assert_eq!(prioridad.unwrap().type_name.as_deref(), Some("number"));
+    }
+
+    #[test]
+    fn dash_separators_need_spaces_and_an_identifier() {
+        assert_eq!(
+            parse_list_item_as_arg("user-id: el ID").unwrap().name,
+            "user-id"
+        );
+        assert_eq!(
+            parse_list_item_as_arg("user-id — el ID").unwrap().name,
+            "user-id"
+        );
+        assert!(parse_list_item_as_arg("Ver la guía - abajo").is_none());
+        assert!(parse_list_item_as_arg("sin-separador").is_none());
     }
 
     #[test]
@@ -557,7 +2205,12 @@ Parameters:
 `key`: The configuration key
 `value`: The configuration value
 "#;
-        let sections = parse_markdown_source(source, &PathBuf::from("docs/api.md")).unwrap();
+        let sections = parse_markdown_source(
+            source,
+            &PathBuf::from("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(sections.len(), 1);
         assert_eq!(sections[0].args.len(), 2);
         assert_eq!(sections[0].args[0].name, "key");
@@ -577,7 +2230,12 @@ Parameters:
 This function does something: it processes data.
 Note: this is just a description paragraph.
 "#;
-        let sections = parse_markdown_source(source, &PathBuf::from("docs/api.md")).unwrap();
+        let sections = parse_markdown_source(
+            source,
+            &PathBuf::from("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(sections.len(), 1);
         // Prose with colons should NOT be parsed as arguments
         assert!(sections[0].args.is_empty());
@@ -596,9 +2254,223 @@ Login function.
 
 Logout function.
 "#;
-        let sections = parse_markdown_source(source, &PathBuf::from("docs/api.md")).unwrap();
+        let sections = parse_markdown_source(
+            source,
+            &PathBuf::from("docs/api.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(sections.len(), 2);
         assert_eq!(sections[0].id, "auth-login");
         assert_eq!(sections[1].id, "auth-logout");
     }
+
+    #[test]
+    fn expectations_are_collected_from_prose_and_code_blocks() {
+        let md = r#"<!-- @docs-id: create-user -->
+## createUser
+
+`createUser("ana")` → `{ id: string, name: "ana" }`
+
+```ts
+const user = await createUser("ana"); // => { id: "1" }
+items.map((x) => x.id);
+```
+"#;
+        let sections = parse_markdown_source(
+            md,
+            &PathBuf::from("test.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let expectations = &sections[0].expectations;
+        assert_eq!(expectations.len(), 3);
+        assert_eq!(expectations[0].function, "createUser");
+        assert_eq!(expectations[0].expected, r#"{ id: string, name: "ana" }"#);
+        assert_eq!(expectations[0].line, 4);
+        assert_eq!(expectations[1].expected, r#"{ id: "1" }"#);
+        assert_eq!(expectations[1].line, 7);
+        // El validador descarta llamadas a otras funciones
+        assert_eq!(expectations[2].function, "map");
+    }
+
+    #[test]
+    fn code_blocks_are_kept_as_examples_with_their_language() {
+        let md = r#"<!-- @docs-id: create-user -->
+## createUser
+
+```ts title="ejemplo"
+import { createUser } from '@acme/sdk';
+```
+
+    createUser("ana")
+"#;
+        let sections = parse_markdown_source(
+            md,
+            &PathBuf::from("test.md"),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let examples = &sections[0].examples;
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].lang.as_deref(), Some("ts"));
+        assert_eq!(examples[0].line, 5);
+        assert!(examples[0].code.starts_with("import { createUser }"));
+        assert_eq!(examples[1].lang, None);
+    }
+
+    #[test]
+    fn expectation_requires_a_call_before_the_arrow() {
+        assert_eq!(parse_expectation("const f = (x) => x"), None);
+        assert_eq!(parse_expectation("The function returns `true`"), None);
+        assert_eq!(
+            parse_expectation("`isAdmin(user)` returns `true`"),
+            Some(("isAdmin".into(), "true".into()))
+        );
+    }
+
+    #[test]
+    fn limits_cap_args_and_sections_with_one_note_each() {
+        let mut source = String::new();
+        for id in ["a", "b", "c"] {
+            source.push_str(&format!("<!-- @docs-id: {} -->\n## {}\n\n", id, id));
+            for i in 0..6 {
+                source.push_str(&format!("- arg{}: Descripción\n", i));
+            }
+            source.push('\n');
+        }
+        let mut diagnostics = ParseDiagnostics::default();
+        let mut sections =
+            parse_markdown_source(&source, Path::new("api.md"), &mut diagnostics).unwrap();
+        let limits = LimitsConfig {
+            max_section_args: 4,
+            max_sections: 2,
+        };
+        apply_limits(&mut sections, &limits, &mut diagnostics);
+
+        let ids: Vec<_> = sections.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, ["a", "b"]);
+        let kept: Vec<_> = sections[0].args.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(kept, ["arg0", "arg1", "arg2", "arg3"]);
+        assert_eq!(sections[1].dropped_args, 2);
+
+        let notes: Vec<_> = diagnostics
+            .notes
+            .iter()
+            .map(|n| (n.rule, n.doc_location.as_deref().unwrap()))
+            .collect();
+        assert_eq!(
+            notes,
+            [
+                (Rule::TruncatedDocs, "api.md:21"),
+                (Rule::TruncatedDocs, "api.md:1"),
+                (Rule::TruncatedDocs, "api.md:11"),
+            ]
+        );
+        assert!(!diagnostics.notes[0].is_strict_only());
+
+        // Dentro del límite no cambia nada
+        let mut diagnostics = ParseDiagnostics::default();
+        let mut sections =
+            parse_markdown_source(&source, Path::new("api.md"), &mut diagnostics).unwrap();
+        apply_limits(&mut sections, &LimitsConfig::default(), &mut diagnostics);
+        assert_eq!(sections.len(), 3);
+        assert!(diagnostics.notes.is_empty());
+    }
+
+    /// Secciones de `source` con solo las extensiones indicadas.
+    fn parsed_with(source: &str, extensions: &[MarkdownExtension]) -> Vec<DocSection> {
+        parse_markdown_source_with(
+            source,
+            &PathBuf::from("docs/api.md"),
+            DocParseOptions::with_extensions(extensions),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap()
+    }
+
+    fn arg_names(section: &DocSection) -> Vec<&str> {
+        section.args.iter().map(|a| a.name.as_str()).collect()
+    }
+
+    use MarkdownExtension::*;
+
+    #[test]
+    fn task_list_checkboxes_are_stripped_and_their_state_ignored() {
+        let source = "<!-- @docs-id: todo -->\n## todo\n\n- [ ] name: Nombre\n- [x] `done` (boolean): Hecho\n";
+        let sections = parsed_with(source, &DEFAULT_MARKDOWN_EXTENSIONS);
+        assert_eq!(arg_names(&sections[0]), ["name", "done"]);
+        assert_eq!(sections[0].args[1].type_name.as_deref(), Some("boolean"));
+
+        // Sin la extensión la casilla es texto y entra en el nombre
+        let sections = parsed_with(source, &[Tables, Strikethrough]);
+        assert_eq!(arg_names(&sections[0]), ["[ ] name", "[x] `done`"]);
+    }
+
+    #[test]
+    fn footnote_definitions_add_no_args_and_references_stay_out_of_descriptions() {
+        let source = "<!-- @docs-id: login -->\n## login\n\n\
+                      - username: Usuario[^1]\n\n\
+                      [^1]: Nota: ver la guía.\n\n    - token: no es un argumento\n";
+        let sections = parsed_with(source, &[Tables, Footnotes]);
+        assert_eq!(arg_names(&sections[0]), ["username"]);
+        assert_eq!(sections[0].args[0].description.as_deref(), Some("Usuario"));
+
+        // Sin la extensión, la llamada es texto literal
+        let sections = parsed_with(source, &[Tables]);
+        assert_eq!(
+            sections[0].args[0].description.as_deref(),
+            Some("Usuario[^1]")
+        );
+    }
+
+    #[test]
+    fn heading_attributes_stay_out_of_titles_and_arg_names() {
+        let source = "<!-- @docs-id: login -->\n## Login {#sign-in .api}\n\n### username {.required}\n\nUsuario.\n";
+        let sections = parsed_with(source, &[HeadingAttributes]);
+        assert_eq!(sections[0].title.as_deref(), Some("Login"));
+        assert_eq!(arg_names(&sections[0]), ["username"]);
+
+        let sections = parsed_with(source, &[]);
+        assert_eq!(sections[0].title.as_deref(), Some("Login {#sign-in .api}"));
+        assert!(arg_names(&sections[0]).is_empty());
+    }
+
+    #[test]
+    fn smart_quotes_do_not_change_expectation_calls() {
+        let source = "<!-- @docs-id: login -->\n## login\n\nlogin(\"ana\") => \"ok\"\n\n- user -- El usuario...\n";
+        for extensions in [&[SmartPunctuation][..], &[]] {
+            let sections = parsed_with(source, extensions);
+            let expectation = &sections[0].expectations[0];
+            assert_eq!(
+                (expectation.function.as_str(), expectation.expected.as_str()),
+                ("login", "\"ok\"")
+            );
+        }
+        // `--` es un en dash, que ya es separador, y `...` pasa a ser `…`
+        let smart = parsed_with(source, &[SmartPunctuation]);
+        assert_eq!(arg_names(&smart[0]), ["user"]);
+        assert_eq!(smart[0].args[0].description.as_deref(), Some("El usuario…"));
+        assert!(parsed_with(source, &[])[0].args.is_empty());
+    }
+
+    #[test]
+    fn tables_and_strikethrough_can_be_turned_off() {
+        let source = "<!-- @docs-id: login -->\n## login\n\n\
+                      | Param | Type | Description |\n|-------|------|-------------|\n\
+                      | username | string | ~~Login~~ Usuario |\n";
+        let sections = parsed_with(source, &[Tables, Strikethrough]);
+        assert_eq!(arg_names(&sections[0]), ["username"]);
+        assert_eq!(
+            sections[0].args[0].description.as_deref(),
+            Some("Login Usuario")
+        );
+        let sections = parsed_with(source, &[Tables]);
+        assert_eq!(
+            sections[0].args[0].description.as_deref(),
+            Some("~~Login~~ Usuario")
+        );
+
+        assert!(parsed_with(source, &[Strikethrough])[0].args.is_empty());
+    }
 }
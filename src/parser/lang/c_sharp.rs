@@ -6,12 +6,18 @@
 use anyhow::Result;
 use std::path::Path;
 
-use crate::core::types::{Arg, CodeEntity};
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::types::{Arg, ArgSource, CodeEntity};
 use crate::parser::code_parser;
-use crate::parser::code_parser::find_docs_annotation;
+use crate::parser::code_parser::{find_annotations, AnnotationOptions};
 
 /// Parsea código C# desde un string.
-pub fn parse_c_sharp_source(source: &str, file_path: &Path) -> Result<Vec<CodeEntity>> {
+pub fn parse_c_sharp_source(
+    source: &str,
+    file_path: &Path,
+    options: AnnotationOptions,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<Vec<CodeEntity>> {
     // Refactorizado: uso de create_tree para eliminar boilerplate duplicado entre parsers
     let tree = code_parser::create_tree(source, tree_sitter_c_sharp::LANGUAGE.into(), "C#")?;
     let mut entities = Vec::new();
@@ -20,6 +26,8 @@ pub fn parse_c_sharp_source(source: &str, file_path: &Path) -> Result<Vec<CodeEn
         source.as_bytes(),
         file_path,
         &mut entities,
+        options,
+        diagnostics,
     )?;
     Ok(entities)
 }
@@ -30,18 +38,22 @@ fn collect_functions(
     source: &[u8],
     file_path: &Path,
     entities: &mut Vec<CodeEntity>,
+    options: AnnotationOptions,
+    diagnostics: &mut ParseDiagnostics,
 ) -> Result<()> {
     let mut cursor = node.walk();
 
     for child in node.children(&mut cursor) {
         match child.kind() {
             "method_declaration" | "constructor_declaration" | "local_function_statement" => {
-                if let Some(entity) = extract_function(&child, source, file_path, node)? {
+                if let Some(entity) =
+                    extract_function(&child, source, file_path, node, options, diagnostics)?
+                {
                     entities.push(entity);
                 }
             }
             _ => {
-                collect_functions(&child, source, file_path, entities)?;
+                collect_functions(&child, source, file_path, entities, options, diagnostics)?;
             }
         }
     }
@@ -55,6 +67,8 @@ fn extract_function(
     source: &[u8],
     file_path: &Path,
     parent_node: &tree_sitter::Node,
+    options: AnnotationOptions,
+    diagnostics: &mut ParseDiagnostics,
 ) -> Result<Option<CodeEntity>> {
     let name = func_node
         .child_by_field_name("name")
@@ -69,19 +83,26 @@ fn extract_function(
     let args = extract_parameters(func_node, source)?;
     let return_type = extract_return_type(func_node, source);
 
-    let doc_id = find_docs_annotation(func_node, source, parent_node, "comment");
+    let annotations = find_annotations(func_node, source, parent_node, &["comment"], options);
 
     let line = func_node.start_position().row + 1;
 
-    Ok(Some(CodeEntity {
+    let entity = CodeEntity {
         name,
         args,
         return_type,
-        doc_id,
+        return_fields: None,
+        doc_id: annotations.doc_id.clone(),
         file_path: file_path.to_path_buf(),
         line,
+        end_line: func_node.end_position().row + 1,
         is_public: true,
-    }))
+        suppressions: annotations.suppressions.clone(),
+        file_link: None,
+        examples: Vec::new(),
+    };
+    code_parser::note_entity_ambiguities(&entity, &annotations, func_node, diagnostics);
+    Ok(Some(entity))
 }
 
 /// Extrae los parámetros de una función C#.
@@ -112,6 +133,10 @@ fn extract_parameters(func_node: &tree_sitter::Node, source: &[u8]) -> Result<Ve
                     name: param_name,
                     type_name,
                     description: None,
+                    source: ArgSource::Code,
+                    line: None,
+                    unit: None,
+                    range: None,
                 });
             }
         }
@@ -143,7 +168,13 @@ class Test {
     }
 }
         "#;
-        let entities = parse_c_sharp_source(source, &PathBuf::from("test.cs")).unwrap();
+        let entities = parse_c_sharp_source(
+            source,
+            &PathBuf::from("test.cs"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(entities.len(), 1);
         let entity = &entities[0];
         assert_eq!(entity.name, "CSharpTest");
@@ -155,4 +186,30 @@ class Test {
         assert_eq!(entity.args[1].name, "b");
         assert_eq!(entity.args[1].type_name, Some("string".to_string()));
     }
+
+    #[test]
+    fn malformed_annotation_is_noted() {
+        let source = r#"
+class Auth {
+    // @docs [auth-login]
+    public void Login(string user) {}
+}
+"#;
+        let mut diagnostics = ParseDiagnostics::default();
+        let entities = parse_c_sharp_source(
+            source,
+            &PathBuf::from("Test.cs"),
+            AnnotationOptions::default(),
+            &mut diagnostics,
+        )
+        .unwrap();
+        assert_eq!(entities[0].doc_id, None);
+        let note = &diagnostics.notes[0];
+        assert_eq!(note.rule, crate::core::types::Rule::MalformedAnnotation);
+        assert!(note.message.contains("(línea 3): `// @docs [auth-login]`"));
+        assert_eq!(
+            note.hint.as_deref(),
+            Some("Sintaxis correcta: `// @docs: [auth-login]`")
+        );
+    }
 }
@@ -6,12 +6,18 @@
 use anyhow::Result;
 use std::path::Path;
 
-use crate::core::types::{Arg, CodeEntity};
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::types::{Arg, ArgSource, CodeEntity};
 use crate::parser::code_parser;
-use crate::parser::code_parser::find_docs_annotation;
+use crate::parser::code_parser::{find_annotations, AnnotationOptions};
 
 /// Parsea código Python desde un string.
-pub fn parse_python_source(source: &str, file_path: &Path) -> Result<Vec<CodeEntity>> {
+pub fn parse_python_source(
+    source: &str,
+    file_path: &Path,
+    options: AnnotationOptions,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<Vec<CodeEntity>> {
     // Refactorizado: uso de create_tree para eliminar boilerplate duplicado entre parsers
     let tree = code_parser::create_tree(source, tree_sitter_python::LANGUAGE.into(), "Python")?;
     let mut entities = Vec::new();
@@ -20,6 +26,8 @@ pub fn parse_python_source(source: &str, file_path: &Path) -> Result<Vec<CodeEnt
         source.as_bytes(),
         file_path,
         &mut entities,
+        options,
+        diagnostics,
     )?;
     Ok(entities)
 }
@@ -30,40 +38,56 @@ fn collect_functions(
     source: &[u8],
     file_path: &Path,
     entities: &mut Vec<CodeEntity>,
+    options: AnnotationOptions,
+    diagnostics: &mut ParseDiagnostics,
 ) -> Result<()> {
     let mut cursor = node.walk();
 
     for child in node.children(&mut cursor) {
         match child.kind() {
             "function_definition" => {
-                if let Some(entity) = extract_function(&child, source, file_path, node)? {
+                if let Some(entity) =
+                    extract_function(&child, source, file_path, node, options, diagnostics)?
+                {
                     entities.push(entity);
                 }
             }
             "class_definition" => {
                 let body = child.child_by_field_name("body");
                 if let Some(body) = body {
-                    collect_functions(&body, source, file_path, entities)?;
+                    collect_functions(&body, source, file_path, entities, options, diagnostics)?;
                 }
             }
             "decorated_definition" => {
                 if let Some(definition) = child.child_by_field_name("definition") {
                     if definition.kind() == "function_definition" {
-                        if let Some(entity) =
-                            extract_function(&definition, source, file_path, node)?
-                        {
+                        if let Some(entity) = extract_function(
+                            &definition,
+                            source,
+                            file_path,
+                            node,
+                            options,
+                            diagnostics,
+                        )? {
                             entities.push(entity);
                         }
                     } else if definition.kind() == "class_definition" {
                         let body = definition.child_by_field_name("body");
                         if let Some(body) = body {
-                            collect_functions(&body, source, file_path, entities)?;
+                            collect_functions(
+                                &body,
+                                source,
+                                file_path,
+                                entities,
+                                options,
+                                diagnostics,
+                            )?;
                         }
                     }
                 }
             }
             _ => {
-                collect_functions(&child, source, file_path, entities)?;
+                collect_functions(&child, source, file_path, entities, options, diagnostics)?;
             }
         }
     }
@@ -77,6 +101,8 @@ fn extract_function(
     source: &[u8],
     file_path: &Path,
     parent_node: &tree_sitter::Node,
+    options: AnnotationOptions,
+    diagnostics: &mut ParseDiagnostics,
 ) -> Result<Option<CodeEntity>> {
     let name = func_node
         .child_by_field_name("name")
@@ -93,19 +119,26 @@ fn extract_function(
 
     // En Python, los comentarios `#` son nodos `comment` en tree-sitter.
     // Hay que buscarlos como hermanos del `function_definition` o del `decorated_definition`
-    let doc_id = find_docs_annotation(func_node, source, parent_node, "comment");
+    let annotations = find_annotations(func_node, source, parent_node, &["comment"], options);
 
     let line = func_node.start_position().row + 1;
 
-    Ok(Some(CodeEntity {
+    let entity = CodeEntity {
         name,
         args,
         return_type,
-        doc_id,
+        return_fields: None,
+        doc_id: annotations.doc_id.clone(),
         file_path: file_path.to_path_buf(),
         line,
+        end_line: func_node.end_position().row + 1,
         is_public: true,
-    }))
+        suppressions: annotations.suppressions.clone(),
+        file_link: None,
+        examples: Vec::new(),
+    };
+    code_parser::note_entity_ambiguities(&entity, &annotations, func_node, diagnostics);
+    Ok(Some(entity))
 }
 
 /// Extrae nombre y tipo de un nodo `typed_parameter` de Python.
@@ -147,6 +180,10 @@ fn extract_parameters(func_node: &tree_sitter::Node, source: &[u8]) -> Result<Ve
                         name: param_name,
                         type_name: None,
                         description: None,
+                        source: ArgSource::Code,
+                        line: None,
+                        unit: None,
+                        range: None,
                     });
                 }
             }
@@ -158,6 +195,10 @@ fn extract_parameters(func_node: &tree_sitter::Node, source: &[u8]) -> Result<Ve
                         name: param_name,
                         type_name,
                         description: None,
+                        source: ArgSource::Code,
+                        line: None,
+                        unit: None,
+                        range: None,
                     });
                 }
             }
@@ -170,6 +211,10 @@ fn extract_parameters(func_node: &tree_sitter::Node, source: &[u8]) -> Result<Ve
                                 name: param_name,
                                 type_name: None,
                                 description: None,
+                                source: ArgSource::Code,
+                                line: None,
+                                unit: None,
+                                range: None,
                             });
                         }
                     } else if name_n.kind() == "typed_parameter" {
@@ -180,6 +225,10 @@ fn extract_parameters(func_node: &tree_sitter::Node, source: &[u8]) -> Result<Ve
                                 name: param_name,
                                 type_name,
                                 description: None,
+                                source: ArgSource::Code,
+                                line: None,
+                                unit: None,
+                                range: None,
                             });
                         }
                     }
@@ -211,7 +260,13 @@ mod tests {
 def python_test(a: int, b: str) -> bool:
     return True
 "#;
-        let entities = parse_python_source(source, &PathBuf::from("test.py")).unwrap();
+        let entities = parse_python_source(
+            source,
+            &PathBuf::from("test.py"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(entities.len(), 1);
         let entity = &entities[0];
         assert_eq!(entity.name, "python_test");
@@ -221,4 +276,29 @@ def python_test(a: int, b: str) -> bool:
         assert_eq!(entity.args[0].name, "a");
         assert_eq!(entity.args[1].name, "b");
     }
+
+    #[test]
+    fn malformed_annotation_is_noted() {
+        let source = r#"
+# @doc: [auth-login]
+def login(user: str):
+    pass
+"#;
+        let mut diagnostics = ParseDiagnostics::default();
+        let entities = parse_python_source(
+            source,
+            &PathBuf::from("test.py"),
+            AnnotationOptions::default(),
+            &mut diagnostics,
+        )
+        .unwrap();
+        assert_eq!(entities[0].doc_id, None);
+        let note = &diagnostics.notes[0];
+        assert_eq!(note.rule, crate::core::types::Rule::MalformedAnnotation);
+        assert!(note.message.contains("(línea 2): `# @doc: [auth-login]`"));
+        assert_eq!(
+            note.hint.as_deref(),
+            Some("Sintaxis correcta: `# @docs: [auth-login]`")
+        );
+    }
 }
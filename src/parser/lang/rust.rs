@@ -2,52 +2,271 @@
 //!
 //! Extrae funciones de archivos Rust y busca anotaciones `/// @docs: [id]`
 //! en los doc-comments inmediatamente anteriores a la declaración.
+//!
+//! Los métodos de un `impl Trait for Tipo` sin anotación propia heredan la del
+//! método del trait, si el trait está en el mismo archivo: se validan contra
+//! la sección del trait con su propia firma.
 
 use anyhow::Result;
+use std::collections::HashMap;
 use std::path::Path;
 
-use crate::core::types::{Arg, CodeEntity};
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::examples::unwrap_wrappers;
+use crate::core::types::{Arg, ArgSource, CodeEntity};
 use crate::parser::code_parser;
-use crate::parser::code_parser::find_docs_annotation;
+use crate::parser::code_parser::{find_annotations, AnnotationOptions, InheritedLinks};
 
 /// Parsea código Rust desde un string.
-pub fn parse_rust_source(source: &str, file_path: &Path) -> Result<Vec<CodeEntity>> {
+pub fn parse_rust_source(
+    source: &str,
+    file_path: &Path,
+    options: AnnotationOptions,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<Vec<CodeEntity>> {
     // Refactorizado: uso de create_tree para eliminar boilerplate duplicado entre parsers
     let tree = code_parser::create_tree(source, tree_sitter_rust::LANGUAGE.into(), "Rust")?;
+    let mut traits = InheritedLinks::default();
+    collect_trait_links(&tree.root_node(), source.as_bytes(), options, &mut traits);
     let mut entities = Vec::new();
     collect_functions(
         &tree.root_node(),
         source.as_bytes(),
         file_path,
         &mut entities,
+        &Context {
+            options,
+            traits: &traits,
+        },
+        diagnostics,
     )?;
+
+    // Resolver los campos de retorno contra los structs del mismo archivo
+    let mut structs = HashMap::new();
+    collect_structs(&tree.root_node(), source.as_bytes(), &mut structs);
+    for entity in &mut entities {
+        if let Some(return_type) = entity.return_type.as_deref() {
+            entity.return_fields = structs.get(unwrap_wrappers(return_type)).cloned();
+        }
+    }
+
     Ok(entities)
 }
 
+/// Nombres que el archivo declara sin `pub`: ítems privados (también en
+/// módulos e `impl`) y variables `let`. Sin los parámetros.
+pub fn internal_symbols(source: &str) -> Result<Vec<String>> {
+    let tree = code_parser::create_tree(source, tree_sitter_rust::LANGUAGE.into(), "Rust")?;
+    let mut symbols = Vec::new();
+    collect_private_items(&tree.root_node(), source.as_bytes(), &mut symbols);
+    Ok(symbols)
+}
+
+fn collect_private_items(node: &tree_sitter::Node, source: &[u8], symbols: &mut Vec<String>) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        let name = match child.kind() {
+            "function_item" | "struct_item" | "enum_item" | "union_item" | "trait_item"
+            | "type_item" | "const_item" | "static_item" | "mod_item" => {
+                let mut item_cursor = child.walk();
+                let is_pub = child
+                    .children(&mut item_cursor)
+                    .any(|c| c.kind() == "visibility_modifier");
+                child.child_by_field_name("name").filter(|_| !is_pub)
+            }
+            // `let mut x` también es un `identifier` (con `mutable_specifier` aparte)
+            "let_declaration" => child
+                .child_by_field_name("pattern")
+                .filter(|p| p.kind() == "identifier"),
+            _ => None,
+        };
+        if let Some(name) = name.and_then(|n| n.utf8_text(source).ok()) {
+            symbols.push(name.to_string());
+        }
+        collect_private_items(&child, source, symbols);
+    }
+}
+
+/// Nombres de los ítems `pub` del archivo, incluidos los de módulos e `impl`.
+pub fn exported_symbols(source: &str) -> Result<Vec<String>> {
+    let tree = code_parser::create_tree(source, tree_sitter_rust::LANGUAGE.into(), "Rust")?;
+    let mut symbols = Vec::new();
+    collect_pub_items(&tree.root_node(), source.as_bytes(), &mut symbols);
+    Ok(symbols)
+}
+
+fn collect_pub_items(node: &tree_sitter::Node, source: &[u8], symbols: &mut Vec<String>) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        let is_item = matches!(
+            child.kind(),
+            "function_item"
+                | "function_signature_item"
+                | "struct_item"
+                | "enum_item"
+                | "union_item"
+                | "trait_item"
+                | "type_item"
+                | "const_item"
+                | "static_item"
+                | "mod_item"
+        );
+        let mut item_cursor = child.walk();
+        let is_pub = child
+            .children(&mut item_cursor)
+            .any(|c| c.kind() == "visibility_modifier");
+        if is_item && is_pub {
+            if let Some(name) = child
+                .child_by_field_name("name")
+                .and_then(|n| n.utf8_text(source).ok())
+            {
+                symbols.push(name.to_string());
+            }
+        }
+        collect_pub_items(&child, source, symbols);
+    }
+}
+
+/// Recoge los structs con campos nombrados (`struct_item`) del archivo.
+fn collect_structs(
+    node: &tree_sitter::Node,
+    source: &[u8],
+    structs: &mut HashMap<String, Vec<Arg>>,
+) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() != "struct_item" {
+            collect_structs(&child, source, structs);
+            continue;
+        }
+        let name = child
+            .child_by_field_name("name")
+            .and_then(|n| n.utf8_text(source).ok());
+        let body = child
+            .child_by_field_name("body")
+            .filter(|b| b.kind() == "field_declaration_list");
+        let (Some(name), Some(body)) = (name, body) else {
+            continue;
+        };
+
+        let mut fields = Vec::new();
+        let mut field_cursor = body.walk();
+        for field in body.children(&mut field_cursor) {
+            if field.kind() != "field_declaration" {
+                continue;
+            }
+            if let Some(field_name) = field
+                .child_by_field_name("name")
+                .and_then(|n| n.utf8_text(source).ok())
+            {
+                fields.push(Arg {
+                    name: field_name.to_string(),
+                    type_name: field
+                        .child_by_field_name("type")
+                        .and_then(|t| t.utf8_text(source).ok())
+                        .map(String::from),
+                    description: None,
+                    source: ArgSource::Code,
+                    line: None,
+                    unit: None,
+                    range: None,
+                });
+            }
+        }
+        structs.insert(name.to_string(), fields);
+    }
+}
+
+/// Opciones del recorrido de `collect_functions`.
+struct Context<'a> {
+    options: AnnotationOptions,
+    /// Anotaciones de los métodos de los traits del archivo.
+    traits: &'a InheritedLinks,
+}
+
+/// Recoge los IDs anotados en los métodos de cada `trait` del archivo, con
+/// cuerpo por defecto o solo firma.
+fn collect_trait_links(
+    node: &tree_sitter::Node,
+    source: &[u8],
+    options: AnnotationOptions,
+    traits: &mut InheritedLinks,
+) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() != "trait_item" {
+            collect_trait_links(&child, source, options, traits);
+            continue;
+        }
+        let name = child
+            .child_by_field_name("name")
+            .and_then(|n| n.utf8_text(source).ok());
+        let (Some(name), Some(body)) = (name, child.child_by_field_name("body")) else {
+            continue;
+        };
+        let mut body_cursor = body.walk();
+        for method in body
+            .children(&mut body_cursor)
+            .filter(|m| matches!(m.kind(), "function_item" | "function_signature_item"))
+        {
+            let method_name = method
+                .child_by_field_name("name")
+                .and_then(|n| n.utf8_text(source).ok());
+            let doc_id = find_annotations(
+                &method,
+                source,
+                &body,
+                &["line_comment", "block_comment"],
+                options,
+            )
+            .doc_id;
+            if let (Some(method_name), Some(doc_id)) = (method_name, doc_id) {
+                traits.insert(name, method_name, doc_id);
+            }
+        }
+    }
+}
+
 /// Recorre el AST recursivamente buscando `function_item` nodes.
 fn collect_functions(
     node: &tree_sitter::Node,
     source: &[u8],
     file_path: &Path,
     entities: &mut Vec<CodeEntity>,
+    context: &Context,
+    diagnostics: &mut ParseDiagnostics,
 ) -> Result<()> {
     let mut cursor = node.walk();
 
     for child in node.children(&mut cursor) {
         match child.kind() {
             "function_item" => {
-                if let Some(entity) = extract_function(&child, source, file_path, node)? {
+                if let Some(entity) = extract_function(
+                    &child,
+                    source,
+                    file_path,
+                    node,
+                    context.options,
+                    diagnostics,
+                )? {
                     entities.push(entity);
                 }
             }
             // Recurrir en módulos, impl blocks, etc.
             "mod_item" | "impl_item" | "trait_item" => {
                 if let Some(body) = child.child_by_field_name("body") {
-                    collect_functions(&body, source, file_path, entities)?;
+                    let first = entities.len();
+                    collect_functions(&body, source, file_path, entities, context, diagnostics)?;
+                    let implemented = child
+                        .child_by_field_name("trait")
+                        .and_then(|t| t.utf8_text(source).ok());
+                    if let Some(implemented) = implemented {
+                        inherit_trait_links(&mut entities[first..], implemented, context.traits);
+                    }
                 }
             }
             _ => {
-                collect_functions(&child, source, file_path, entities)?;
+                collect_functions(&child, source, file_path, entities, context, diagnostics)?;
             }
         }
     }
@@ -55,12 +274,22 @@ fn collect_functions(
     Ok(())
 }
 
+/// Enlaza los métodos de un `impl Trait for Tipo` sin `@docs` propio con la
+/// sección del método homónimo del trait.
+fn inherit_trait_links(methods: &mut [CodeEntity], implemented: &str, traits: &InheritedLinks) {
+    for method in methods.iter_mut().filter(|m| m.doc_id.is_none()) {
+        method.doc_id = traits.get([implemented], &method.name).map(String::from);
+    }
+}
+
 /// Extrae una CodeEntity de un nodo `function_item` de Rust.
 fn extract_function(
     func_node: &tree_sitter::Node,
     source: &[u8],
     file_path: &Path,
     parent_node: &tree_sitter::Node,
+    options: AnnotationOptions,
+    diagnostics: &mut ParseDiagnostics,
 ) -> Result<Option<CodeEntity>> {
     let name = func_node
         .child_by_field_name("name")
@@ -72,11 +301,18 @@ fn extract_function(
         None => return Ok(None),
     };
 
-    let args = extract_parameters(func_node, source)?;
+    let mut args = extract_parameters(func_node, source)?;
     let return_type = extract_return_type(func_node, source);
 
-    // En Rust, los doc comments `///` son nodos `line_comment` en tree-sitter
-    let doc_id = find_docs_annotation(func_node, source, parent_node, "line_comment");
+    // En Rust, los doc comments `///` son nodos `line_comment` y los `/** */`, `block_comment`
+    let annotations = find_annotations(
+        func_node,
+        source,
+        parent_node,
+        &["line_comment", "block_comment"],
+        options,
+    );
+    annotations.describe_args(&mut args);
 
     let line = func_node.start_position().row + 1;
 
@@ -86,15 +322,22 @@ fn extract_function(
         .children(&mut vis_cursor)
         .any(|child| child.kind() == "visibility_modifier");
 
-    Ok(Some(CodeEntity {
+    let entity = CodeEntity {
         name,
         args,
         return_type,
-        doc_id,
+        return_fields: None,
+        doc_id: annotations.doc_id.clone(),
         file_path: file_path.to_path_buf(),
         line,
+        end_line: func_node.end_position().row + 1,
         is_public,
-    }))
+        suppressions: annotations.suppressions.clone(),
+        file_link: None,
+        examples: Vec::new(),
+    };
+    code_parser::note_entity_ambiguities(&entity, &annotations, func_node, diagnostics);
+    Ok(Some(entity))
 }
 
 /// Extrae los parámetros de una función Rust.
@@ -127,6 +370,10 @@ fn extract_parameters(func_node: &tree_sitter::Node, source: &[u8]) -> Result<Ve
                     name: param_name,
                     type_name,
                     description: None,
+                    source: ArgSource::Code,
+                    line: None,
+                    unit: None,
+                    range: None,
                 });
             }
         } else if child.kind() == "self_parameter" {
@@ -159,7 +406,13 @@ pub fn validate_links(entities: &[CodeEntity], sections: &[DocSection]) -> Vec<V
     vec![]
 }
 "#;
-        let entities = parse_rust_source(source, &PathBuf::from("test.rs")).unwrap();
+        let entities = parse_rust_source(
+            source,
+            &PathBuf::from("test.rs"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(entities.len(), 1);
 
         let entity = &entities[0];
@@ -171,13 +424,50 @@ pub fn validate_links(entities: &[CodeEntity], sections: &[DocSection]) -> Vec<V
         assert_eq!(entity.args[1].name, "sections");
     }
 
+    #[test]
+    fn internal_symbols_are_private_items_and_let_bindings() {
+        let source = "\
+const MAX_RETRIES: u32 = 3;
+pub const TIMEOUT: u32 = 30;
+struct ConnPool;
+pub fn connect(url: &str) -> bool {
+    let mut backoff = retry_with_jitter(1);
+    let (a, b) = (1, 2);
+    open(url, backoff)
+}
+fn retry_with_jitter(attempt: u32) -> u32 { attempt }
+impl ConnPool {
+    pub fn open(&self) {}
+    fn evict(&self) {}
+}
+";
+        let mut symbols = internal_symbols(source).unwrap();
+        symbols.sort();
+        assert_eq!(
+            symbols,
+            [
+                "ConnPool",
+                "MAX_RETRIES",
+                "backoff",
+                "evict",
+                "retry_with_jitter"
+            ]
+        );
+    }
+
     #[test]
     fn parse_rust_function_without_annotation() {
         let source = r#"
 fn helper() {
 }
 "#;
-        let entities = parse_rust_source(source, &PathBuf::from("test.rs")).unwrap();
+        let entities = parse_rust_source(
+            source,
+            &PathBuf::from("test.rs"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(entities.len(), 1);
         assert_eq!(entities[0].name, "helper");
         assert_eq!(entities[0].doc_id, None);
@@ -191,7 +481,13 @@ pub fn parse_file(path: &Path) -> Result<Vec<CodeEntity>> {
     Ok(vec![])
 }
 "#;
-        let entities = parse_rust_source(source, &PathBuf::from("test.rs")).unwrap();
+        let entities = parse_rust_source(
+            source,
+            &PathBuf::from("test.rs"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(entities.len(), 1);
         assert_eq!(
             entity_return_type(&entities[0]),
@@ -209,7 +505,13 @@ impl Validator {
     }
 }
 "#;
-        let entities = parse_rust_source(source, &PathBuf::from("test.rs")).unwrap();
+        let entities = parse_rust_source(
+            source,
+            &PathBuf::from("test.rs"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(entities.len(), 1);
         assert_eq!(entities[0].name, "run");
         assert_eq!(entities[0].doc_id, Some("validator-run".into()));
@@ -229,14 +531,114 @@ fn private_helper() {}
 /// @docs: [fn-b]
 pub fn beta(name: String) -> String { name }
 "#;
-        let entities = parse_rust_source(source, &PathBuf::from("test.rs")).unwrap();
+        let entities = parse_rust_source(
+            source,
+            &PathBuf::from("test.rs"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(entities.len(), 3);
         assert_eq!(entities[0].doc_id, Some("fn-a".into()));
         assert_eq!(entities[1].doc_id, None);
         assert_eq!(entities[2].doc_id, Some("fn-b".into()));
     }
 
+    #[test]
+    fn struct_return_type_in_same_file_exposes_fields() {
+        let source = r#"
+pub struct User {
+    pub id: String,
+    pub age: u32,
+}
+
+pub fn create_user(name: &str) -> Result<User, Error> { todo!() }
+pub fn load(name: &str) -> Remote { todo!() }
+"#;
+        let entities = parse_rust_source(
+            source,
+            &PathBuf::from("test.rs"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let fields = entities[0].return_fields.as_ref().unwrap();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[1].name, "age");
+        assert_eq!(fields[1].type_name.as_deref(), Some("u32"));
+        assert_eq!(entities[1].return_fields, None);
+    }
+
     fn entity_return_type(entity: &CodeEntity) -> Option<&str> {
         entity.return_type.as_deref()
     }
+
+    #[test]
+    fn malformed_annotation_is_noted() {
+        let source = r#"
+/// @docs: [auth-login
+pub fn login(user: &str) {}
+"#;
+        let mut diagnostics = ParseDiagnostics::default();
+        let entities = parse_rust_source(
+            source,
+            &PathBuf::from("test.rs"),
+            AnnotationOptions::default(),
+            &mut diagnostics,
+        )
+        .unwrap();
+        assert_eq!(entities[0].doc_id, None);
+        let note = &diagnostics.notes[0];
+        assert_eq!(note.rule, crate::core::types::Rule::MalformedAnnotation);
+        assert!(note.message.contains("(línea 2): `/// @docs: [auth-login`"));
+        assert_eq!(
+            note.hint.as_deref(),
+            Some("Sintaxis correcta: `/// @docs: [auth-login]`")
+        );
+    }
+
+    #[test]
+    fn trait_impls_inherit_the_trait_method_link_unless_annotated() {
+        let source = r#"
+pub trait Storage {
+    /// @docs: [storage-put]
+    fn put(&self, key: &str);
+}
+
+impl Storage for S3 {
+    fn put(&self, key: &str) {}
+    fn helper(&self) {}
+}
+
+impl io::Storage<u8> for Disk {
+    /// @docs: [disk-put]
+    fn put(&self, key: &str) {}
+}
+
+impl Disk {
+    fn put(&self, key: &str) {}
+}
+"#;
+        let entities = parse_rust_source(
+            source,
+            &PathBuf::from("test.rs"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let links: Vec<(&str, usize, Option<&str>)> = entities
+            .iter()
+            .map(|e| (e.name.as_str(), e.line, e.doc_id.as_deref()))
+            .collect();
+        // La firma del trait no es una entidad; un `impl` propio no hereda nada
+        assert_eq!(
+            links,
+            [
+                ("put", 8, Some("storage-put")),
+                ("helper", 9, None),
+                ("put", 14, Some("disk-put")),
+                ("put", 18, None),
+            ]
+        );
+    }
 }
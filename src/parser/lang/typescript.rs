@@ -1,35 +1,204 @@
 //! Parser de TypeScript usando tree-sitter.
+//!
+//! Los métodos de clase solo se extraen cuando la clase implementa una
+//! interfaz del mismo archivo que anota ese método: heredan su `@docs` (salvo
+//! que tengan uno propio) y se validan con su propia firma.
 
 use anyhow::Result;
+use std::collections::HashSet;
 use std::path::Path;
 
-use crate::core::types::{Arg, CodeEntity};
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::types::{Arg, ArgSource, CodeEntity};
 use crate::parser::code_parser;
-use crate::parser::code_parser::find_docs_annotation;
+use crate::parser::code_parser::{find_annotations, AnnotationOptions, InheritedLinks};
 
 /// Parsea código TypeScript desde un string.
-pub fn parse_typescript_source(source: &str, file_path: &Path) -> Result<Vec<CodeEntity>> {
+pub fn parse_typescript_source(
+    source: &str,
+    file_path: &Path,
+    options: AnnotationOptions,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<Vec<CodeEntity>> {
     // Refactorizado: uso de create_tree para eliminar boilerplate duplicado entre parsers
     let tree = code_parser::create_tree(
         source,
         tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
         "TypeScript",
     )?;
+    let mut interfaces = InheritedLinks::default();
+    collect_interface_links(
+        &tree.root_node(),
+        source.as_bytes(),
+        options,
+        &mut interfaces,
+    );
     let mut entities = Vec::new();
     collect_functions(
         &tree.root_node(),
         source.as_bytes(),
         file_path,
         &mut entities,
+        &Context {
+            options,
+            interfaces: &interfaces,
+        },
+        diagnostics,
     )?;
     Ok(entities)
 }
 
+/// Opciones del recorrido de `collect_functions`.
+struct Context<'a> {
+    options: AnnotationOptions,
+    /// Anotaciones de los métodos de las interfaces del archivo.
+    interfaces: &'a InheritedLinks,
+}
+
+/// Recoge los IDs anotados en las firmas de método de cada `interface`.
+fn collect_interface_links(
+    node: &tree_sitter::Node,
+    source: &[u8],
+    options: AnnotationOptions,
+    interfaces: &mut InheritedLinks,
+) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() != "interface_declaration" {
+            collect_interface_links(&child, source, options, interfaces);
+            continue;
+        }
+        let name = child
+            .child_by_field_name("name")
+            .and_then(|n| n.utf8_text(source).ok());
+        let (Some(name), Some(body)) = (name, child.child_by_field_name("body")) else {
+            continue;
+        };
+        let mut body_cursor = body.walk();
+        for method in body
+            .children(&mut body_cursor)
+            .filter(|m| m.kind() == "method_signature")
+        {
+            let method_name = method
+                .child_by_field_name("name")
+                .and_then(|n| n.utf8_text(source).ok());
+            let doc_id = find_annotations(&method, source, &body, &["comment"], options).doc_id;
+            if let (Some(method_name), Some(doc_id)) = (method_name, doc_id) {
+                interfaces.insert(name, method_name, doc_id);
+            }
+        }
+    }
+}
+
+/// Nombres que el archivo declara y no exporta: funciones, clases, tipos y
+/// variables (también locales), y los métodos `private` o `#privados`. Sin
+/// los parámetros.
+pub fn internal_symbols(source: &str) -> Result<Vec<String>> {
+    let tree = code_parser::create_tree(
+        source,
+        tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+        "TypeScript",
+    )?;
+    let mut symbols = Vec::new();
+    collect_declarations(&tree.root_node(), source.as_bytes(), &mut symbols);
+    let exported: HashSet<String> = exported_symbols(source)?.into_iter().collect();
+    symbols.retain(|name| !exported.contains(name));
+    Ok(symbols)
+}
+
+fn collect_declarations(node: &tree_sitter::Node, source: &[u8], symbols: &mut Vec<String>) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        let name = match child.kind() {
+            "function_declaration"
+            | "generator_function_declaration"
+            | "class_declaration"
+            | "interface_declaration"
+            | "type_alias_declaration"
+            | "enum_declaration" => child.child_by_field_name("name"),
+            "variable_declarator" => child
+                .child_by_field_name("name")
+                .filter(|n| n.kind() == "identifier"),
+            "method_definition" | "public_field_definition" => {
+                let mut member_cursor = child.walk();
+                let is_private = child.children(&mut member_cursor).any(|c| {
+                    c.kind() == "accessibility_modifier"
+                        && c.utf8_text(source).is_ok_and(|t| t == "private")
+                });
+                child
+                    .child_by_field_name("name")
+                    .filter(|n| is_private || n.kind() == "private_property_identifier")
+            }
+            _ => None,
+        };
+        if let Some(name) = name.and_then(|n| n.utf8_text(source).ok()) {
+            symbols.push(name.trim_start_matches('#').to_string());
+        }
+        collect_declarations(&child, source, symbols);
+    }
+}
+
+/// Nombres exportados (`export function`, `export class`, `export { a as b }`…).
+pub fn exported_symbols(source: &str) -> Result<Vec<String>> {
+    let tree = code_parser::create_tree(
+        source,
+        tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+        "TypeScript",
+    )?;
+    let source = source.as_bytes();
+    let name_of = |node: tree_sitter::Node| {
+        node.child_by_field_name("name")
+            .and_then(|n| n.utf8_text(source).ok())
+            .map(String::from)
+    };
+
+    let mut symbols = Vec::new();
+    let root = tree.root_node();
+    let mut cursor = root.walk();
+    for export in root
+        .children(&mut cursor)
+        .filter(|c| c.kind() == "export_statement")
+    {
+        let mut export_cursor = export.walk();
+        for child in export.children(&mut export_cursor) {
+            match child.kind() {
+                "lexical_declaration" | "variable_declaration" => {
+                    let mut decl_cursor = child.walk();
+                    symbols.extend(
+                        child
+                            .children(&mut decl_cursor)
+                            .filter(|d| d.kind() == "variable_declarator")
+                            .filter_map(name_of),
+                    );
+                }
+                "export_clause" => {
+                    let mut clause_cursor = child.walk();
+                    symbols.extend(
+                        child
+                            .children(&mut clause_cursor)
+                            .filter(|s| s.kind() == "export_specifier")
+                            .filter_map(|s| {
+                                s.child_by_field_name("alias")
+                                    .or_else(|| s.child_by_field_name("name"))
+                                    .and_then(|n| n.utf8_text(source).ok())
+                                    .map(String::from)
+                            }),
+                    );
+                }
+                _ => symbols.extend(name_of(child)),
+            }
+        }
+    }
+    Ok(symbols)
+}
+
 fn collect_functions(
     node: &tree_sitter::Node,
     source: &[u8],
     file_path: &Path,
     entities: &mut Vec<CodeEntity>,
+    context: &Context,
+    diagnostics: &mut ParseDiagnostics,
 ) -> Result<()> {
     let mut cursor = node.walk();
 
@@ -44,15 +213,43 @@ fn collect_functions(
                 };
 
                 if let Some(func_node) = func_node {
-                    if let Some(entity) =
-                        extract_function(&func_node, source, file_path, node, is_exported)?
-                    {
+                    if let Some(entity) = extract_function(
+                        &func_node,
+                        source,
+                        file_path,
+                        node,
+                        is_exported,
+                        context.options,
+                        diagnostics,
+                    )? {
                         entities.push(entity);
                     }
+                } else if let Some(class) = find_class_in_export(&child) {
+                    collect_implemented_methods(
+                        &class,
+                        source,
+                        file_path,
+                        entities,
+                        true,
+                        context,
+                        diagnostics,
+                    )?;
                 }
             }
+            "class_declaration" | "abstract_class_declaration" => {
+                collect_implemented_methods(
+                    &child,
+                    source,
+                    file_path,
+                    entities,
+                    false,
+                    context,
+                    diagnostics,
+                )?;
+                collect_functions(&child, source, file_path, entities, context, diagnostics)?;
+            }
             _ => {
-                collect_functions(&child, source, file_path, entities)?;
+                collect_functions(&child, source, file_path, entities, context, diagnostics)?;
             }
         }
     }
@@ -70,12 +267,91 @@ fn find_function_in_export<'a>(
     result
 }
 
+fn find_class_in_export<'a>(export_node: &tree_sitter::Node<'a>) -> Option<tree_sitter::Node<'a>> {
+    let mut cursor = export_node.walk();
+    let result = export_node.children(&mut cursor).find(|child| {
+        matches!(
+            child.kind(),
+            "class_declaration" | "abstract_class_declaration"
+        )
+    });
+    result
+}
+
+/// Extrae los métodos de `class` que implementan un método anotado de una
+/// interfaz del archivo (`class S3 implements Storage`), con el ID heredado
+/// si no tienen `@docs` propio.
+fn collect_implemented_methods(
+    class: &tree_sitter::Node,
+    source: &[u8],
+    file_path: &Path,
+    entities: &mut Vec<CodeEntity>,
+    is_exported: bool,
+    context: &Context,
+    diagnostics: &mut ParseDiagnostics,
+) -> Result<()> {
+    if context.interfaces.is_empty() {
+        return Ok(());
+    }
+    let mut implemented = Vec::new();
+    let mut cursor = class.walk();
+    for heritage in class
+        .children(&mut cursor)
+        .filter(|c| c.kind() == "class_heritage")
+    {
+        let mut heritage_cursor = heritage.walk();
+        for clause in heritage
+            .children(&mut heritage_cursor)
+            .filter(|c| c.kind() == "implements_clause")
+        {
+            let mut clause_cursor = clause.walk();
+            implemented.extend(
+                clause
+                    .named_children(&mut clause_cursor)
+                    .filter_map(|t| t.utf8_text(source).ok()),
+            );
+        }
+    }
+    let Some(body) = class.child_by_field_name("body") else {
+        return Ok(());
+    };
+
+    let mut body_cursor = body.walk();
+    for method in body
+        .children(&mut body_cursor)
+        .filter(|m| m.kind() == "method_definition")
+    {
+        let Some(inherited) = method
+            .child_by_field_name("name")
+            .and_then(|n| n.utf8_text(source).ok())
+            .and_then(|name| context.interfaces.get(implemented.iter().copied(), name))
+        else {
+            continue;
+        };
+        if let Some(mut entity) = extract_function(
+            &method,
+            source,
+            file_path,
+            &body,
+            is_exported,
+            context.options,
+            diagnostics,
+        )? {
+            entity.doc_id.get_or_insert_with(|| inherited.to_string());
+            entities.push(entity);
+        }
+    }
+    Ok(())
+}
+
 fn extract_function(
     func_node: &tree_sitter::Node,
     source: &[u8],
     file_path: &Path,
     parent_node: &tree_sitter::Node,
     is_public: bool,
+    options: AnnotationOptions,
+    diagnostics: &mut ParseDiagnostics,
 ) -> Result<Option<CodeEntity>> {
     let name = func_node
         .child_by_field_name("name")
@@ -87,20 +363,29 @@ fn extract_function(
         None => return Ok(None),
     };
 
-    let args = extract_parameters(func_node, source)?;
+    let mut args = extract_parameters(func_node, source)?;
     let return_type = extract_return_type(func_node, source);
-    let doc_id = find_docs_annotation(func_node, source, parent_node, "comment");
+    let return_fields = extract_return_fields(func_node, source);
+    let annotations = find_annotations(func_node, source, parent_node, &["comment"], options);
+    annotations.describe_args(&mut args);
     let line = func_node.start_position().row + 1;
 
-    Ok(Some(CodeEntity {
+    let entity = CodeEntity {
         name,
         args,
         return_type,
-        doc_id,
+        return_fields,
+        doc_id: annotations.doc_id.clone(),
         file_path: file_path.to_path_buf(),
         line,
+        end_line: func_node.end_position().row + 1,
         is_public,
-    }))
+        suppressions: annotations.suppressions.clone(),
+        file_link: None,
+        examples: annotations.examples.clone(),
+    };
+    code_parser::note_entity_ambiguities(&entity, &annotations, func_node, diagnostics);
+    Ok(Some(entity))
 }
 
 fn extract_parameters(func_node: &tree_sitter::Node, source: &[u8]) -> Result<Vec<Arg>> {
@@ -137,6 +422,10 @@ fn extract_parameters(func_node: &tree_sitter::Node, source: &[u8]) -> Result<Ve
                     name: param_name,
                     type_name,
                     description: None,
+                    source: ArgSource::Code,
+                    line: None,
+                    unit: None,
+                    range: None,
                 });
             }
         }
@@ -159,6 +448,43 @@ fn extract_return_type(func_node: &tree_sitter::Node, source: &[u8]) -> Option<S
         .map(String::from)
 }
 
+/// Campos del tipo de retorno cuando es un objeto en línea (`: { id: string }`).
+fn extract_return_fields(func_node: &tree_sitter::Node, source: &[u8]) -> Option<Vec<Arg>> {
+    let type_ann = func_node.child_by_field_name("return_type")?;
+    let mut cursor = type_ann.walk();
+    let object_type = type_ann
+        .children(&mut cursor)
+        .find(|c| c.kind() == "object_type")?;
+
+    let mut fields = Vec::new();
+    let mut cursor = object_type.walk();
+    for member in object_type.children(&mut cursor) {
+        if member.kind() != "property_signature" {
+            continue;
+        }
+        let Some(name) = member
+            .child_by_field_name("name")
+            .and_then(|n| n.utf8_text(source).ok())
+        else {
+            continue;
+        };
+        let type_name = member
+            .child_by_field_name("type")
+            .and_then(|t| t.utf8_text(source).ok())
+            .map(|t| t.trim_start_matches(':').trim().to_string());
+        fields.push(Arg {
+            name: name.to_string(),
+            type_name,
+            description: None,
+            source: ArgSource::Code,
+            line: None,
+            unit: None,
+            range: None,
+        });
+    }
+    Some(fields)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,7 +498,13 @@ function login(username: string, password: string): boolean {
     return true;
 }
 "#;
-        let entities = parse_typescript_source(source, &PathBuf::from("test.ts")).unwrap();
+        let entities = parse_typescript_source(
+            source,
+            &PathBuf::from("test.ts"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(entities.len(), 1);
 
         let entity = &entities[0];
@@ -190,7 +522,13 @@ function login(username: string, password: string): boolean {
 function helper(): void {
 }
 "#;
-        let entities = parse_typescript_source(source, &PathBuf::from("test.ts")).unwrap();
+        let entities = parse_typescript_source(
+            source,
+            &PathBuf::from("test.ts"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(entities.len(), 1);
         assert_eq!(entities[0].name, "helper");
         assert_eq!(entities[0].doc_id, None);
@@ -204,9 +542,118 @@ export function createUser(name: string): User {
     return new User(name);
 }
 "#;
-        let entities = parse_typescript_source(source, &PathBuf::from("test.ts")).unwrap();
+        let entities = parse_typescript_source(
+            source,
+            &PathBuf::from("test.ts"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
         assert_eq!(entities.len(), 1);
         assert_eq!(entities[0].name, "createUser");
         assert_eq!(entities[0].doc_id, Some("user-create".into()));
     }
+
+    #[test]
+    fn inline_object_return_type_exposes_fields() {
+        let source = r#"
+export function createUser(name: string): { id: string; name: string } {
+    return { id: "1", name };
+}
+"#;
+        let entities = parse_typescript_source(
+            source,
+            &PathBuf::from("test.ts"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let fields = entities[0].return_fields.as_ref().unwrap();
+        let names: Vec<_> = fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["id", "name"]);
+        assert_eq!(fields[0].type_name.as_deref(), Some("string"));
+    }
+
+    #[test]
+    fn named_return_type_has_no_fields() {
+        let source = "function createUser(name: string): User { return new User(name); }";
+        let entities = parse_typescript_source(
+            source,
+            &PathBuf::from("test.ts"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        assert_eq!(entities[0].return_fields, None);
+    }
+
+    #[test]
+    fn malformed_annotation_is_noted() {
+        let source = r#"
+// @docs [auth-login]
+function login(user: string) {}
+"#;
+        let mut diagnostics = ParseDiagnostics::default();
+        let entities = parse_typescript_source(
+            source,
+            &PathBuf::from("test.ts"),
+            AnnotationOptions::default(),
+            &mut diagnostics,
+        )
+        .unwrap();
+        assert_eq!(entities[0].doc_id, None);
+        let note = &diagnostics.notes[0];
+        assert_eq!(note.rule, crate::core::types::Rule::MalformedAnnotation);
+        assert!(note.message.contains("(línea 2): `// @docs [auth-login]`"));
+        assert_eq!(
+            note.hint.as_deref(),
+            Some("Sintaxis correcta: `// @docs: [auth-login]`")
+        );
+    }
+
+    #[test]
+    fn methods_implementing_an_annotated_interface_become_linked_entities() {
+        let source = r#"
+interface Storage {
+  // @docs: [storage-put]
+  put(key: string): void;
+  get(key: string): string;
+}
+
+export class S3 implements Storage {
+  put(key: string, region: string): void {}
+  get(key: string): string { return key; }
+}
+
+class Cache extends Base implements lib.Storage {
+  // @docs: [cache-put]
+  put(key: string): void {}
+}
+
+class Plain {
+  put(key: string): void {}
+}
+"#;
+        let entities = parse_typescript_source(
+            source,
+            &PathBuf::from("test.ts"),
+            AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let links: Vec<(&str, usize, Option<&str>, bool)> = entities
+            .iter()
+            .map(|e| (e.name.as_str(), e.line, e.doc_id.as_deref(), e.is_public))
+            .collect();
+        assert_eq!(
+            links,
+            [
+                ("put", 9, Some("storage-put"), true),
+                ("put", 15, Some("cache-put"), false),
+            ]
+        );
+        // La firma validada es la de la implementación
+        let args: Vec<&str> = entities[0].args.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(args, ["key", "region"]);
+    }
 }
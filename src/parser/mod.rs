@@ -1,3 +1,4 @@
 pub mod code_parser;
+pub mod doc_edit;
 pub mod doc_parser;
 pub mod lang;
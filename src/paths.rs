@@ -0,0 +1,371 @@
+//! Rutas relativas a la raíz del proyecto.
+//!
+//! Los parsers reciben las rutas tal como las pasó el usuario (relativas al
+//! directorio actual, absolutas tras `canonicalize`…). Se normalizan una sola
+//! vez, al crear las entidades y secciones, para que hallazgos, baseline y
+//! salidas de CI no dependan de la máquina ni del directorio de trabajo.
+//! Las rutas fuera de la raíz conservan su forma absoluta.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::exit::Failure;
+use crate::vfs;
+
+/// Normalización de rutas de un proyecto.
+///
+/// Sin raíz (`Default`) las rutas se conservan tal cual.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProjectPaths {
+    /// Raíz canónica del proyecto.
+    root: Option<PathBuf>,
+    /// `--absolute-paths`: mostrar siempre rutas absolutas.
+    absolute: bool,
+}
+
+impl ProjectPaths {
+    /// Ancla las rutas en `project_root` (sin raíz si no se puede resolver).
+    pub fn new(project_root: &Path) -> Self {
+        ProjectPaths {
+            root: vfs::canonicalize(project_root).ok(),
+            absolute: false,
+        }
+    }
+
+    /// Activa o desactiva la forma absoluta (`--absolute-paths`).
+    pub fn with_absolute(self, absolute: bool) -> Self {
+        ProjectPaths { absolute, ..self }
+    }
+
+    /// Forma almacenada y mostrada de una ruta existente: relativa a la raíz,
+    /// o absoluta si está fuera de ella o se pidió `--absolute-paths`.
+    pub fn normalize(&self, path: &Path) -> PathBuf {
+        let Some(root) = &self.root else {
+            return path.to_path_buf();
+        };
+        let Ok(canonical) = vfs::canonicalize(path) else {
+            return path.to_path_buf();
+        };
+        if self.absolute {
+            return canonical;
+        }
+        match canonical.strip_prefix(root) {
+            Ok(relative) => relative.to_path_buf(),
+            Err(_) => canonical,
+        }
+    }
+
+    /// Ruta utilizable para leer o escribir a partir de su forma normalizada.
+    pub fn resolve(&self, path: &Path) -> PathBuf {
+        match &self.root {
+            Some(root) if path.is_relative() => root.join(path),
+            _ => path.to_path_buf(),
+        }
+    }
+}
+
+/// Compara dos rutas tolerando formas antiguas: iguales, o una es sufijo
+/// de la otra por componentes (`/home/u/repo/src/a.ts` ~ `src/a.ts`).
+pub fn paths_match(a: &Path, b: &Path) -> bool {
+    a == b || a.ends_with(b) || b.ends_with(a)
+}
+
+/// Indica si `path`, en su forma normalizada, encaja con el glob `pattern`:
+/// `*` y `?` dentro de un componente, `**` cualquier número de componentes
+/// (`src/billing/**`). Se compara por componentes separados por `/`.
+pub fn glob_matches(pattern: &str, path: &Path) -> bool {
+    let path = path.to_string_lossy().replace('\\', "/");
+    let components: Vec<&str> = path
+        .split('/')
+        .filter(|c| !c.is_empty() && *c != ".")
+        .collect();
+    let pattern: Vec<&str> = pattern
+        .split('/')
+        .filter(|c| !c.is_empty() && *c != ".")
+        .collect();
+    components_match(&pattern, &components)
+}
+
+fn components_match(pattern: &[&str], components: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => components.is_empty(),
+        Some((&"**", rest)) => {
+            (0..=components.len()).any(|skip| components_match(rest, &components[skip..]))
+        }
+        Some((first, rest)) => components.split_first().is_some_and(|(component, tail)| {
+            let pattern: Vec<char> = first.chars().collect();
+            let text: Vec<char> = component.chars().collect();
+            component_matches(&pattern, &text) && components_match(rest, tail)
+        }),
+    }
+}
+
+/// `*` (cero o más caracteres) y `?` (uno) dentro de un componente. Se
+/// compara por caracteres, no por bytes: `caf?.md` encaja con `café.md`.
+fn component_matches(pattern: &[char], text: &[char]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => (0..=text.len()).any(|skip| component_matches(rest, &text[skip..])),
+        Some(('?', rest)) => !text.is_empty() && component_matches(rest, &text[1..]),
+        Some((c, rest)) => text.first() == Some(c) && component_matches(rest, &text[1..]),
+    }
+}
+
+/// Archivo al que se expandió una entrada de la línea de comandos.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputFile {
+    pub path: PathBuf,
+    /// Encontrado al recorrer un directorio o un glob, no nombrado a mano.
+    pub discovered: bool,
+}
+
+/// Expande las entradas de la CLI: un archivo se queda tal cual (exista o
+/// no: lo comprueba quien lo lee), un directorio da todos sus archivos
+/// recursivamente y un glob (`src/**/*.rs`, entre comillas para que no lo
+/// expanda la shell) los que encajan con él. Se saltan los directorios
+/// ocultos (`.git`, `.docsguard`). Cada entrada conserva su orden, con lo
+/// descubierto ordenado y sin repetir un archivo ya incluido.
+pub fn expand_inputs(inputs: &[PathBuf]) -> Result<Vec<InputFile>> {
+    let mut files: Vec<InputFile> = Vec::new();
+    for input in inputs {
+        let pattern = input.to_string_lossy().replace('\\', "/");
+        let found = if is_glob(&pattern) {
+            let found: Vec<PathBuf> = walk_files(&glob_base(&pattern))?
+                .into_iter()
+                .filter(|path| glob_matches(&pattern, path))
+                .collect();
+            if found.is_empty() {
+                anyhow::bail!(Failure::not_found(format!(
+                    "El patrón '{}' no encaja con ningún archivo.",
+                    pattern
+                ))
+                .with_hint("Los patrones son relativos al directorio actual: `**` cruza directorios y `*` no."));
+            }
+            found
+        } else if vfs::is_dir(input) {
+            walk_files(input)?
+        } else {
+            files.push(InputFile {
+                path: input.clone(),
+                discovered: false,
+            });
+            continue;
+        };
+        for path in found {
+            if !files.iter().any(|f| f.path == path) {
+                files.push(InputFile {
+                    path,
+                    discovered: true,
+                });
+            }
+        }
+    }
+    Ok(files)
+}
+
+fn is_glob(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+/// Archivo o directorio que cubre una entrada de `expand_inputs`: la propia
+/// ruta o, si es un glob, el directorio desde el que se recorre.
+pub fn input_root(input: &Path) -> PathBuf {
+    let pattern = input.to_string_lossy().replace('\\', "/");
+    match is_glob(&pattern) {
+        true => glob_base(&pattern),
+        false => input.to_path_buf(),
+    }
+}
+
+/// Directorio desde el que recorrer un glob: sus componentes sin comodines.
+fn glob_base(pattern: &str) -> PathBuf {
+    let literal: Vec<&str> = pattern
+        .split('/')
+        .take_while(|component| !is_glob(component))
+        .collect();
+    match literal.join("/") {
+        base if base.is_empty() && pattern.starts_with('/') => PathBuf::from("/"),
+        base if base.is_empty() => PathBuf::from("."),
+        base => PathBuf::from(base),
+    }
+}
+
+/// Directorios de dependencias y de compilación que `check-all` no recorre.
+const VENDORED_DIRS: [&str; 2] = ["node_modules", "target"];
+
+/// Archivos bajo `root` para `check-all`: como un directorio de la CLI, y
+/// además sin `VENDORED_DIRS`.
+pub fn walk_repository(root: &Path) -> Result<Vec<PathBuf>> {
+    walk(root, &VENDORED_DIRS)
+}
+
+fn walk_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    walk(dir, &[])
+}
+
+/// Archivos bajo `dir`, recursivamente y en orden estable, sin entrar en
+/// directorios ocultos ni en los llamados como `skip`, ni seguir enlaces
+/// simbólicos a directorios.
+fn walk(dir: &Path, skip: &[&str]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    if !vfs::is_dir(dir) {
+        return Ok(files);
+    }
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let entries = vfs::read_dir(&dir)
+            .with_context(|| format!("No se pudo leer el directorio {}", dir.display()))?;
+        for path in entries {
+            let Ok(metadata) = vfs::metadata(&path) else {
+                continue;
+            };
+            let hidden = path.file_name().is_some_and(|name| {
+                let name = name.to_string_lossy();
+                name.starts_with('.') || (metadata.is_dir && skip.contains(&name.as_ref()))
+            });
+            if metadata.is_dir {
+                if !hidden && !metadata.is_symlink {
+                    pending.push(path);
+                }
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::{MemFs, MemProject};
+
+    fn project() -> (MemProject, PathBuf) {
+        let dir = MemFs::project();
+        let file = dir.path().join("src").join("api.ts");
+        dir.write(&file, "");
+        (dir, file)
+    }
+
+    #[test]
+    fn paths_inside_root_become_relative() {
+        let (dir, file) = project();
+        let paths = ProjectPaths::new(dir.path());
+        assert_eq!(paths.normalize(&file), PathBuf::from("src/api.ts"));
+        assert_eq!(
+            paths.resolve(Path::new("src/api.ts")),
+            vfs::canonicalize(dir.path()).unwrap().join("src/api.ts")
+        );
+    }
+
+    #[test]
+    fn paths_outside_root_stay_absolute() {
+        let (dir, _) = project();
+        let doc = dir.path().join("api.md");
+        dir.write(&doc, "");
+
+        let normalized = ProjectPaths::new(&dir.path().join("src")).normalize(&doc);
+        assert!(normalized.is_absolute());
+        assert_eq!(normalized, vfs::canonicalize(&doc).unwrap());
+    }
+
+    #[test]
+    fn absolute_paths_flag_keeps_canonical_form() {
+        let (dir, file) = project();
+        let paths = ProjectPaths::new(dir.path()).with_absolute(true);
+        assert_eq!(paths.normalize(&file), vfs::canonicalize(&file).unwrap());
+    }
+
+    #[test]
+    fn suffix_comparison_matches_legacy_absolute_paths() {
+        assert!(paths_match(
+            Path::new("/home/ci/repo/src/api.ts"),
+            Path::new("src/api.ts")
+        ));
+        assert!(!paths_match(
+            Path::new("/home/ci/repo/src/api.ts"),
+            Path::new("lib/api.ts")
+        ));
+        // Por componentes, no por texto
+        assert!(!paths_match(Path::new("src/myapi.ts"), Path::new("api.ts")));
+    }
+
+    #[test]
+    fn directories_and_globs_expand_to_their_files_in_a_stable_order() {
+        let dir = MemFs::project();
+        let root = dir.path();
+        for file in [
+            "src/b.ts",
+            "src/a.rs",
+            "src/auth/c.ts",
+            "src/.cache/d.ts",
+            "notes.txt",
+        ] {
+            let path = root.join(file);
+            dir.mkdir(path.parent().unwrap());
+            dir.write(path, "");
+        }
+        let expand = |inputs: &[PathBuf]| -> Vec<(PathBuf, bool)> {
+            expand_inputs(inputs)
+                .unwrap()
+                .into_iter()
+                .map(|f| {
+                    (
+                        f.path.strip_prefix(root).unwrap().to_path_buf(),
+                        f.discovered,
+                    )
+                })
+                .collect()
+        };
+
+        assert_eq!(
+            expand(&[root.join("src/b.ts"), root.join("src")]),
+            [
+                (PathBuf::from("src/b.ts"), false),
+                (PathBuf::from("src/a.rs"), true),
+                (PathBuf::from("src/auth/c.ts"), true),
+            ]
+        );
+        assert_eq!(
+            expand(&[root.join("src/**/*.ts")]),
+            [
+                (PathBuf::from("src/auth/c.ts"), true),
+                (PathBuf::from("src/b.ts"), true),
+            ]
+        );
+        assert_eq!(
+            expand(&[root.join("src/*.ts")]),
+            [(PathBuf::from("src/b.ts"), true)]
+        );
+        let err = expand_inputs(&[root.join("lib/**/*.ts")]).unwrap_err();
+        assert_eq!(
+            crate::exit::exit_code_for(&err),
+            crate::exit::exit_code_for(
+                &crate::parser::code_parser::require_file_exists(
+                    &root.join("missing.ts"),
+                    "código"
+                )
+                .unwrap_err()
+            )
+        );
+    }
+
+    #[test]
+    fn globs_match_by_component_and_double_star_spans_directories() {
+        let matches = |pattern: &str, path: &str| glob_matches(pattern, Path::new(path));
+        assert!(matches("src/billing/**", "src/billing/invoice.ts"));
+        assert!(matches("src/billing/**", "src/billing/tax/vat.ts"));
+        assert!(matches("./src/billing/**", "src/billing/invoice.ts"));
+        assert!(!matches("src/billing/**", "src/billing-legacy/invoice.ts"));
+        assert!(matches("docs/**/*.md", "docs/api.md"));
+        assert!(matches("docs/**/*.md", "docs/billing/api.md"));
+        assert!(!matches("docs/*.md", "docs/billing/api.md"));
+        assert!(matches("src/*/api.t?", "src/auth/api.ts"));
+        assert!(!matches("src/*.ts", "lib/api.ts"));
+        // `?` es un carácter, aunque ocupe varios bytes
+        assert!(matches("docs/caf?.md", "docs/café.md"));
+        assert!(!matches("docs/caf??.md", "docs/café.md"));
+        assert!(matches("docs/*é.md", "docs/café.md"));
+    }
+}
@@ -0,0 +1,209 @@
+//! Agrupación de los hallazgos Info (`info_aggregation: summarize`).
+//!
+//! Los informes de un proyecto grande llevan cientos de Info ("Función sin
+//! anotación", "Enlace verificado") que solo inflan los artefactos de CI. Con
+//! `summarize`, los Info de una misma regla en un mismo archivo se sustituyen
+//! por un único hallazgo con su recuento y las funciones afectadas en
+//! `ValidationResult::summary`. Los Errors y Warnings no se tocan y
+//! `Report::count` sigue contando cada hallazgo original, así que el código
+//! de salida no cambia.
+
+use std::collections::HashMap;
+
+use crate::core::types::{parse_location, FindingSummary, Rule, Severity, ValidationResult};
+use crate::messages;
+
+/// Funciones que se nombran en el mensaje de un resumen; el resto, en `summary`.
+const LISTED_FUNCTIONS: usize = 3;
+
+/// Clave de agrupación: regla y archivo (del código o, si no, de las docs).
+type Group = (Rule, Option<String>);
+
+fn group_of(result: &ValidationResult) -> Group {
+    let file = location_of(result).map(|loc| match parse_location(loc) {
+        Some((path, _)) => path.display().to_string(),
+        None => loc.to_string(),
+    });
+    (result.rule, file)
+}
+
+fn location_of(result: &ValidationResult) -> Option<&str> {
+    result
+        .code_location
+        .as_deref()
+        .or(result.doc_location.as_deref())
+}
+
+/// Sustituye cada grupo de dos o más Info de una regla en un archivo por un
+/// resumen, en la posición del primero. El resto conserva su orden.
+pub fn summarize_info(results: Vec<ValidationResult>) -> Vec<ValidationResult> {
+    let mut groups: HashMap<Group, Vec<ValidationResult>> = HashMap::new();
+    let mut order = Vec::new();
+    for result in results {
+        if result.severity != Severity::Info {
+            order.push(Err(result));
+            continue;
+        }
+        let group = group_of(&result);
+        let members = groups.entry(group.clone()).or_default();
+        if members.is_empty() {
+            order.push(Ok(group));
+        }
+        members.push(result);
+    }
+
+    order
+        .into_iter()
+        .map(|entry| match entry {
+            Err(result) => result,
+            Ok(group) => {
+                let mut members = groups.remove(&group).unwrap_or_default();
+                match members.len() {
+                    1 => members.remove(0),
+                    _ => summary(&group, members),
+                }
+            }
+        })
+        .collect()
+}
+
+/// Hallazgo resumen de los `members` de un grupo.
+fn summary((rule, file): &Group, members: Vec<ValidationResult>) -> ValidationResult {
+    let mut functions: Vec<String> = Vec::new();
+    for name in members.iter().filter_map(|r| r.function_name.as_deref()) {
+        if !functions.iter().any(|f| f == name) {
+            functions.push(name.to_string());
+        }
+    }
+    let listed = match functions.len() {
+        0 => String::new(),
+        n if n <= LISTED_FUNCTIONS => format!(": {}", functions.join(", ")),
+        n => format!(
+            ": {} y {} más",
+            functions[..LISTED_FUNCTIONS].join(", "),
+            n - LISTED_FUNCTIONS
+        ),
+    };
+    let place = match file {
+        Some(file) => format!("en {}", file),
+        None => "sin ubicación".to_string(),
+    };
+    let first = &members[0];
+    let (code_location, doc_location) = match first.code_location {
+        Some(ref loc) => (Some(loc.clone()), None),
+        None => (None, first.doc_location.clone()),
+    };
+
+    ValidationResult {
+        severity: Severity::Info,
+        rule: *rule,
+        message: format!(
+            "{} de `{}` agrupados {}{}.",
+            messages::FINDINGS.count(members.len()),
+            rule,
+            place,
+            listed
+        ),
+        function_name: None,
+        code_location,
+        doc_id: None,
+        doc_location,
+        hint: Some("`--no-aggregate` los muestra uno a uno.".into()),
+        provenance: None,
+        related: Vec::new(),
+        target: None,
+        summary: Some(FindingSummary {
+            count: members.len(),
+            functions,
+        }),
+        cause: None,
+        grace: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(severity: Severity, rule: Rule, function: &str, location: &str) -> ValidationResult {
+        ValidationResult {
+            severity,
+            rule,
+            message: format!("fn {}", function),
+            function_name: Some(function.into()),
+            code_location: Some(location.into()),
+            doc_id: None,
+            doc_location: None,
+            hint: None,
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+
+    #[test]
+    fn info_is_grouped_by_rule_and_file_and_the_rest_is_kept() {
+        let results = vec![
+            finding(Severity::Info, Rule::UnlinkedFunction, "a", "src/x.ts:1"),
+            finding(Severity::Error, Rule::GhostArg, "a", "src/x.ts:1"),
+            finding(Severity::Info, Rule::UnlinkedFunction, "b", "src/x.ts:5"),
+            finding(Severity::Info, Rule::UnlinkedFunction, "c", "src/y.ts:1"),
+            finding(Severity::Info, Rule::LinkVerified, "d", "src/x.ts:9"),
+            finding(Severity::Info, Rule::UnlinkedFunction, "a", "src/x.ts:12"),
+            finding(Severity::Warning, Rule::MissingArg, "b", "src/x.ts:5"),
+        ];
+        let total = results.len();
+        let summarized = summarize_info(results);
+
+        let shape: Vec<_> = summarized
+            .iter()
+            .map(|r| (r.rule, r.function_name.as_deref(), r.occurrences()))
+            .collect();
+        assert_eq!(
+            shape,
+            [
+                (Rule::UnlinkedFunction, None, 3),
+                (Rule::GhostArg, Some("a"), 1),
+                (Rule::UnlinkedFunction, Some("c"), 1),
+                (Rule::LinkVerified, Some("d"), 1),
+                (Rule::MissingArg, Some("b"), 1),
+            ]
+        );
+        assert_eq!(
+            summarized.iter().map(|r| r.occurrences()).sum::<usize>(),
+            total
+        );
+
+        let first = &summarized[0];
+        assert_eq!(
+            first.summary.as_ref().unwrap().functions,
+            ["a".to_string(), "b".to_string()]
+        );
+        assert_eq!(first.code_location.as_deref(), Some("src/x.ts:1"));
+        assert_eq!(
+            first.message,
+            "3 hallazgos de `unlinked-function` agrupados en src/x.ts: a, b."
+        );
+    }
+
+    #[test]
+    fn long_function_lists_are_cut_in_the_message() {
+        let results = (0..5)
+            .map(|i| {
+                finding(
+                    Severity::Info,
+                    Rule::LinkVerified,
+                    &format!("f{}", i),
+                    &format!("src/x.ts:{}", i + 1),
+                )
+            })
+            .collect();
+        let summarized = summarize_info(results);
+        assert_eq!(summarized.len(), 1);
+        assert!(summarized[0].message.ends_with(": f0, f1, f2 y 2 más."));
+        assert_eq!(summarized[0].summary.as_ref().unwrap().functions.len(), 5);
+    }
+}
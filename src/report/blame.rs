@@ -0,0 +1,456 @@
+//! Probable causa de los errores nuevos en enlaces que pasaban (`check`).
+//!
+//! Un CI en rojo por unas docs que nadie tocó suele venir de un cambio de
+//! código de otra persona. Tras el baseline, cada Error de un enlace (una
+//! función con `@docs` y su sección) que no tenía errores en la ejecución
+//! anterior recibe en `ValidationResult::cause` el último commit que tocó la
+//! firma de la función, de su línea a la de su último argumento, según
+//! `git blame`, y si el archivo de docs tiene algún commit posterior. La
+//! ejecución anterior es `.docsguard/last_run.json` si es del mismo archivo de
+//! docs; sin registro, cuentan todos los errores de enlaces. Si la firma
+//! tiene cambios sin commitear, la causa es local y no se indica.
+//!
+//! El blame se pide una vez por archivo y un fallo (archivo sin seguimiento,
+//! git que no está) solo deja el hallazgo sin causa. `check --no-git` no
+//! consulta git; en SARIF la causa, que nombra al autor, solo sale con
+//! `--with-blame`. El baseline y el registro de `--fast` no la guardan.
+
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+use crate::core::types::{parse_location, Cause, CodeEntity, Severity, ValidationResult};
+use crate::git;
+use crate::paths::ProjectPaths;
+use crate::report::Report;
+
+/// Hash de `git blame` para las líneas sin commitear.
+const UNCOMMITTED: &str = "0000000000000000000000000000000000000000";
+
+/// Commit de `git blame --porcelain`.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Commit {
+    author: String,
+    mail: String,
+    author_time: i64,
+    committer_time: i64,
+    subject: String,
+}
+
+/// Autoría de las líneas de un archivo.
+#[derive(Debug, Default)]
+struct FileBlame {
+    /// Hash del commit de cada línea, desde la 1.
+    lines: Vec<String>,
+    commits: HashMap<String, Commit>,
+}
+
+/// Consultas a git de una ejecución, una por archivo.
+struct Blames<'a> {
+    project_root: &'a Path,
+    files: HashMap<PathBuf, Option<FileBlame>>,
+    docs: HashMap<PathBuf, Option<(String, i64)>>,
+}
+
+/// Añade la probable causa a los errores de `report` en enlaces que no
+/// tenían errores en `history`. Las rutas de los hallazgos se resuelven con
+/// `paths`; fuera de un repositorio git no hace nada.
+pub fn explain(
+    report: &mut Report,
+    entities: &[CodeEntity],
+    history: Option<&[ValidationResult]>,
+    paths: &ProjectPaths,
+    project_root: &Path,
+) {
+    let failing: HashSet<(&str, &str)> = history
+        .unwrap_or_default()
+        .iter()
+        .filter(|r| r.severity == Severity::Error)
+        .filter_map(link)
+        .collect();
+    let candidates: Vec<&mut ValidationResult> = report
+        .results
+        .iter_mut()
+        .filter(|r| r.severity == Severity::Error && r.doc_location.is_some())
+        .filter(|r| link(r).is_some_and(|key| !failing.contains(&key)))
+        .collect();
+    if candidates.is_empty() || !git::is_repository(project_root) {
+        return;
+    }
+
+    let mut blames = Blames {
+        project_root,
+        files: HashMap::new(),
+        docs: HashMap::new(),
+    };
+    for result in candidates {
+        let Some((file, lines)) = signature(result, entities) else {
+            continue;
+        };
+        let Some((commit, info)) = blames.latest(&paths.resolve(&file), lines) else {
+            continue;
+        };
+        let docs_commit = result
+            .doc_location
+            .as_deref()
+            .and_then(parse_location)
+            .and_then(|(doc_file, _)| blames.last_doc_commit(&paths.resolve(doc_file)))
+            .filter(|(_, time)| *time > info.committer_time)
+            .map(|(hash, _)| hash);
+        result.cause = Some(Cause {
+            commit,
+            author: handle(&info.mail, &info.author),
+            timestamp: info.author_time,
+            subject: info.subject,
+            docs_commit,
+        });
+    }
+}
+
+/// Fecha de autor (segundos desde 1970-01-01) de cada línea de `file`, desde
+/// la 1; `None` en las sin commitear, y en conjunto si git no conoce el archivo.
+pub fn line_times(project_root: &Path, file: &Path) -> Option<Vec<Option<i64>>> {
+    let blame = parse_porcelain(&git::blame(project_root, file).ok()?);
+    Some(
+        blame
+            .lines
+            .iter()
+            .map(|hash| match hash.as_str() {
+                UNCOMMITTED => None,
+                hash => blame.commits.get(hash).map(|c| c.author_time),
+            })
+            .collect(),
+    )
+}
+
+/// Función y sección del enlace de un hallazgo.
+fn link(result: &ValidationResult) -> Option<(&str, &str)> {
+    Some((result.function_name.as_deref()?, result.doc_id.as_deref()?))
+}
+
+/// Archivo y líneas de la firma de la función del hallazgo: de su línea a la
+/// de su último argumento, y la del propio hallazgo.
+fn signature(
+    result: &ValidationResult,
+    entities: &[CodeEntity],
+) -> Option<(PathBuf, RangeInclusive<usize>)> {
+    let (file, line) = parse_location(result.code_location.as_deref()?)?;
+    let name = result.function_name.as_deref()?;
+    let entity = entities
+        .iter()
+        .filter(|e| e.name == name && e.file_path == file)
+        .min_by_key(|e| e.line.abs_diff(line))?;
+    let last_arg = entity.args.iter().filter_map(|a| a.line).max();
+    let start = entity.line.min(line).max(1);
+    let end = last_arg.unwrap_or(entity.line).max(entity.line).max(line);
+    Some((file.to_path_buf(), start..=end))
+}
+
+impl Blames<'_> {
+    /// Commit más reciente (por fecha de commit) de las líneas de `file`;
+    /// `None` si alguna está sin commitear.
+    fn latest(&mut self, file: &Path, lines: RangeInclusive<usize>) -> Option<(String, Commit)> {
+        let project_root = self.project_root;
+        let blame = self
+            .files
+            .entry(file.to_path_buf())
+            .or_insert_with(|| {
+                git::blame(project_root, file)
+                    .ok()
+                    .map(|out| parse_porcelain(&out))
+            })
+            .as_ref()?;
+        let first = lines.start().saturating_sub(1);
+        let last = (*lines.end()).min(blame.lines.len());
+        let lines = blame.lines.get(first..last)?;
+        if lines.iter().any(|hash| hash == UNCOMMITTED) {
+            return None;
+        }
+        lines
+            .iter()
+            .filter_map(|hash| blame.commits.get(hash).map(|info| (hash, info)))
+            .max_by_key(|(_, info)| info.committer_time)
+            .map(|(hash, info)| (hash.clone(), info.clone()))
+    }
+
+    /// Último commit del archivo de docs y su fecha de commit.
+    fn last_doc_commit(&mut self, doc_file: &Path) -> Option<(String, i64)> {
+        let project_root = self.project_root;
+        self.docs
+            .entry(doc_file.to_path_buf())
+            .or_insert_with(|| git::last_commit(project_root, doc_file).ok().flatten())
+            .clone()
+    }
+}
+
+/// Autoría de cada línea según `git blame --porcelain`: cada línea empieza
+/// con `<hash> <línea original> <línea final>`, las cabeceras de un commit
+/// solo van la primera vez que aparece y el contenido va tras un tabulador.
+fn parse_porcelain(output: &str) -> FileBlame {
+    let mut blame = FileBlame::default();
+    let mut current: Option<(String, usize)> = None;
+    for line in output.lines() {
+        if line.starts_with('\t') {
+            if let Some((hash, final_line)) = current.take() {
+                if blame.lines.len() < final_line {
+                    blame.lines.resize(final_line, String::new());
+                }
+                blame.lines[final_line - 1] = hash;
+            }
+            continue;
+        }
+        let Some((hash, _)) = &current else {
+            let mut fields = line.split(' ');
+            let hash = fields.next().unwrap_or_default().to_string();
+            let final_line = fields.nth(1).and_then(|n| n.parse().ok()).unwrap_or(0);
+            if final_line > 0 {
+                current = Some((hash, final_line));
+            }
+            continue;
+        };
+        let commit = blame.commits.entry(hash.clone()).or_default();
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        match key {
+            "author" => commit.author = value.to_string(),
+            "author-mail" => commit.mail = value.to_string(),
+            "author-time" => commit.author_time = value.parse().unwrap_or(0),
+            "committer-time" => commit.committer_time = value.parse().unwrap_or(0),
+            "summary" => commit.subject = value.to_string(),
+            _ => {}
+        }
+    }
+    blame
+}
+
+/// `@diego` de `<diego@example.com>` (o de `<123+diego@users.noreply…>`);
+/// el nombre si el correo no tiene usuario.
+fn handle(mail: &str, name: &str) -> String {
+    let mail = mail.trim_start_matches('<').trim_end_matches('>');
+    let user = match mail.split_once('@') {
+        Some((user, _)) => user,
+        None => return name.to_string(),
+    };
+    let user = match user.split_once('+') {
+        Some((id, user)) if id.bytes().all(|b| b.is_ascii_digit()) => user,
+        _ => user,
+    };
+    if user.is_empty() {
+        name.to_string()
+    } else {
+        format!("@{}", user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{Arg, ArgSource, Rule};
+    use std::process::Command;
+
+    const DOCS: &str = "<!-- @docs-id: auth-login -->\n## login\n";
+    const DAY: i64 = 86_400;
+
+    /// git con ese autor y con la fecha `at` (segundos) para autor y commit.
+    fn git_as(dir: &Path, author: (&str, &str), at: i64, args: &[&str]) {
+        let date = format!("@{} +0000", at);
+        let output = Command::new("git")
+            .current_dir(dir)
+            .env("GIT_AUTHOR_DATE", &date)
+            .env("GIT_COMMITTER_DATE", &date)
+            .args(["-c", &format!("user.name={}", author.0)])
+            .args(["-c", &format!("user.email={}", author.1)])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?}", args);
+    }
+
+    fn head(dir: &Path) -> String {
+        let output = Command::new("git")
+            .current_dir(dir)
+            .args(["rev-parse", "HEAD"])
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    }
+
+    /// `login` documentada por alice y cuya firma luego cambia diego.
+    fn fixture() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let alice = ("Alice", "alice@example.com");
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        std::fs::write(
+            root.join("src/auth.ts"),
+            "// auth\n/// @docs: [auth-login]\nexport function login(\n  user: string,\n) {}\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("docs/api.md"), DOCS).unwrap();
+        git_as(root, alice, DAY, &["init", "-q"]);
+        git_as(root, alice, DAY, &["add", "."]);
+        git_as(root, alice, DAY, &["commit", "-q", "-m", "login"]);
+
+        std::fs::write(
+            root.join("src/auth.ts"),
+            "// auth\n/// @docs: [auth-login]\nexport function login(\n  username: string,\n) {}\n",
+        )
+        .unwrap();
+        let diego = ("Diego", "1234+diego@users.noreply.github.com");
+        git_as(
+            root,
+            diego,
+            2 * DAY,
+            &["commit", "-q", "-am", "refactor auth params"],
+        );
+        dir
+    }
+
+    fn login() -> CodeEntity {
+        CodeEntity {
+            name: "login".into(),
+            args: vec![Arg {
+                name: "username".into(),
+                type_name: Some("string".into()),
+                description: None,
+                source: ArgSource::Code,
+                line: Some(4),
+                unit: None,
+                range: None,
+            }],
+            return_type: None,
+            return_fields: None,
+            doc_id: Some("auth-login".into()),
+            file_path: PathBuf::from("src/auth.ts"),
+            line: 3,
+            end_line: 3,
+            is_public: true,
+            suppressions: vec![],
+            file_link: None,
+            examples: Vec::new(),
+        }
+    }
+
+    fn missing_arg() -> ValidationResult {
+        ValidationResult {
+            severity: Severity::Error,
+            rule: Rule::MissingArg,
+            message: "El argumento 'username' no está documentado".into(),
+            function_name: Some("login".into()),
+            code_location: Some("src/auth.ts:3".into()),
+            doc_id: Some("auth-login".into()),
+            doc_location: Some("docs/api.md:1".into()),
+            hint: None,
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+
+    fn explained(root: &Path, history: Option<&[ValidationResult]>) -> Option<Cause> {
+        let mut report = Report::new(vec![missing_arg()], 0, &[login()], &[]);
+        let paths = ProjectPaths::new(root);
+        explain(&mut report, &[login()], history, &paths, root);
+        report.results.remove(0).cause
+    }
+
+    #[test]
+    fn a_newly_failing_link_names_the_commit_that_touched_the_signature() {
+        let dir = fixture();
+        let cause = explained(dir.path(), None).unwrap();
+        assert_eq!(cause.commit, head(dir.path()));
+        assert_eq!(cause.author, "@diego");
+        assert_eq!(cause.subject, "refactor auth params");
+        assert_eq!(cause.docs_commit, None);
+        assert_eq!(cause.timestamp, 2 * DAY);
+        let text = cause.describe(4 * DAY + 5);
+        assert!(
+            text.starts_with(&format!(
+                "Probable causa: {} 'refactor auth params' por @diego hace 2 días; ",
+                &cause.commit[..7]
+            )),
+            "{text}"
+        );
+        assert!(
+            text.ends_with("las docs no cambiaron desde entonces"),
+            "{text}"
+        );
+
+        // Un commit posterior en las docs se menciona
+        std::fs::write(dir.path().join("docs/api.md"), format!("{DOCS}\nNotas.\n")).unwrap();
+        let alice = ("Alice", "alice@example.com");
+        git_as(
+            dir.path(),
+            alice,
+            3 * DAY,
+            &["commit", "-q", "-am", "notas"],
+        );
+        let cause = explained(dir.path(), None).unwrap();
+        assert_eq!(cause.docs_commit, Some(head(dir.path())));
+    }
+
+    #[test]
+    fn links_that_already_failed_and_uncommitted_lines_get_no_cause() {
+        let dir = fixture();
+        assert_eq!(explained(dir.path(), Some(&[missing_arg()])), None);
+        let mut warning = missing_arg();
+        warning.severity = Severity::Warning;
+        assert!(explained(dir.path(), Some(&[warning])).is_some());
+
+        // Con la firma cambiada sin commitear, la causa es local
+        std::fs::write(
+            dir.path().join("src/auth.ts"),
+            "// auth\n/// @docs: [auth-login]\nexport function login(\n  name: string,\n) {}\n",
+        )
+        .unwrap();
+        assert_eq!(explained(dir.path(), None), None);
+    }
+
+    #[test]
+    fn outside_git_nothing_is_added() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("src/auth.ts"),
+            "export function login() {}\n",
+        )
+        .unwrap();
+        assert_eq!(explained(dir.path(), None), None);
+    }
+
+    #[test]
+    fn porcelain_headers_are_shared_between_lines_of_a_commit() {
+        let output = "\
+aaaa 1 1 2
+author Alice
+author-mail <alice@example.com>
+author-time 100
+committer-time 110
+summary first
+filename a.ts
+\tline 1
+aaaa 2 2
+\tline 2
+bbbb 2 3 1
+author Bob
+author-mail <>
+author-time 200
+committer-time 210
+summary second
+filename a.ts
+\tline 3
+";
+        let blame = parse_porcelain(output);
+        assert_eq!(blame.lines, ["aaaa", "aaaa", "bbbb"]);
+        assert_eq!(blame.commits["aaaa"].mail, "<alice@example.com>");
+        assert_eq!(blame.commits["bbbb"].subject, "second");
+        assert_eq!(blame.commits["bbbb"].committer_time, 210);
+        assert_eq!(handle("<alice@example.com>", "Alice"), "@alice");
+        assert_eq!(handle("<>", "Bob"), "Bob");
+        assert_eq!(handle("<7+dev@users.noreply.github.com>", "Dev"), "@dev");
+        assert_eq!(handle("<dev+ci@example.com>", "Dev"), "@dev+ci");
+    }
+}
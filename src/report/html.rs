@@ -0,0 +1,760 @@
+//! Formateador HTML de un `Report`, pensado para proyectos enormes.
+//!
+//! Un único archivo autocontenido, pero sin una tabla gigante en el DOM:
+//!
+//! - los hallazgos se agrupan por archivo en `<details>` plegables;
+//! - las filas viven en un bloque JSON incrustado y el JS solo construye las
+//!   de un grupo al desplegarlo, por páginas de `PAGE_SIZE`;
+//! - la búsqueda (función, doc-id, mensaje) y los filtros de severidad y regla
+//!   recorren el JSON, no el DOM.
+//!
+//! La pestaña "Supresiones" lista lo que la ejecución silencia (ver
+//! `suppressions`), ordenable por columna y filtrable por mecanismo, y la
+//! pestaña "Argumentos" da un `<details>` por enlace función → sección con
+//! la tabla de sus argumentos coloreada por estado (ver `links`), también
+//! construida al desplegarla.
+//!
+//! La generación escribe grupo a grupo en un `Write`: el HTML completo nunca
+//! está en memoria (ver `transaction::write_streamed`).
+
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+
+use super::links::{ArgCoverage, LinkRecord};
+use super::suppressions::Mechanism;
+use super::Report;
+use crate::core::types::{parse_location, Severity, ValidationResult};
+use crate::dates;
+use crate::messages;
+
+/// Filas que el JS añade cada vez que se despliega un grupo o se pide "más".
+const PAGE_SIZE: usize = 200;
+
+/// Grupo de los hallazgos sin ubicación (p. ej. `slow-run`).
+const NO_LOCATION: &str = "(sin ubicación)";
+
+/// Escribe el informe completo en `out`.
+pub fn write(report: &Report, out: &mut dyn Write) -> Result<()> {
+    let groups = group_by_file(&report.results);
+    let rules: BTreeSet<&str> = report.results.iter().map(|r| r.rule.id()).collect();
+
+    out.write_all(HEAD.as_bytes())?;
+    writeln!(
+        out,
+        "<nav class=\"tabs\"><button data-tab=\"findings\" class=\"active\">Hallazgos</button>\
+         <button data-tab=\"suppressions\">Supresiones ({})</button>\
+         <button data-tab=\"args\">Argumentos ({} enlaces)</button></nav><section id=\"findings\">",
+        report.suppressions.len(),
+        report.links.len()
+    )?;
+    writeln!(
+        out,
+        "<p class=\"totals\">{} · {} · {} · {} · {}</p>",
+        messages::ERRORS.count(report.count(Severity::Error)),
+        messages::WARNINGS.count(report.count(Severity::Warning)),
+        messages::INFOS.count(report.count(Severity::Info)),
+        messages::FILTERED_BY_BASELINE.count(report.baseline_filtered),
+        messages::FILES.count(groups.len())
+    )?;
+    out.write_all(CONTROLS_START.as_bytes())?;
+    for rule in &rules {
+        writeln!(out, "<option value=\"{0}\">{0}</option>", escape(rule))?;
+    }
+    out.write_all(CONTROLS_END.as_bytes())?;
+
+    for (index, (file, indices)) in groups.iter().enumerate() {
+        let count = |severity| {
+            indices
+                .iter()
+                .filter(|&&i| report.results[i].severity == severity)
+                .map(|&i| report.results[i].occurrences())
+                .sum::<usize>()
+        };
+        writeln!(
+            out,
+            "<details class=\"group\" data-g=\"{}\"><summary><span class=\"file\">{}</span> \
+             <span class=\"count\">{}</span> <span class=\"sev\">{} E · {} W · {} I</span></summary>\
+             <table><thead><tr><th></th><th>Regla</th><th>Función</th><th>Ubicación</th><th>Mensaje</th></tr></thead>\
+             <tbody></tbody></table><button class=\"more\" hidden></button></details>",
+            index,
+            escape(file),
+            indices.len(),
+            count(Severity::Error),
+            count(Severity::Warning),
+            count(Severity::Info)
+        )?;
+    }
+
+    // Un array por grupo, en el mismo orden que los <details>
+    out.write_all(b"<script id=\"dg-data\" type=\"application/json\">[")?;
+    let mut json = ScriptSafe(&mut *out);
+    for (index, (_, indices)) in groups.iter().enumerate() {
+        json.write_all(if index == 0 { b"[" } else { b",[" })?;
+        for (n, &i) in indices.iter().enumerate() {
+            if n > 0 {
+                json.write_all(b",")?;
+            }
+            serde_json::to_writer(&mut json, &row(&report.results[i]))?;
+        }
+        json.write_all(b"]")?;
+    }
+    out.write_all(b"]</script>\n</section>\n")?;
+    write_suppressions(report, out)?;
+    write_links(&report.links, out)?;
+    writeln!(
+        out,
+        "<script>const PAGE = {};{}{}{}</script>",
+        PAGE_SIZE, SCRIPT, SUPPRESSIONS_SCRIPT, LINKS_SCRIPT
+    )?;
+    out.write_all(b"</main></body></html>\n")?;
+    Ok(())
+}
+
+/// Pestaña "Supresiones": controles, tabla vacía y las filas como JSON
+/// `[mecanismo, etiqueta, ubicación, silencia, motivo, desde, días, edad, usada]`.
+fn write_suppressions(report: &Report, out: &mut dyn Write) -> Result<()> {
+    let today = dates::today_days();
+    let mechanisms: BTreeSet<Mechanism> = report.suppressions.iter().map(|s| s.mechanism).collect();
+    out.write_all(SUPPRESSIONS_START.as_bytes())?;
+    for mechanism in &mechanisms {
+        writeln!(
+            out,
+            "<option value=\"{}\">{}</option>",
+            escape(
+                serde_json::to_value(mechanism)?
+                    .as_str()
+                    .unwrap_or_default()
+            ),
+            escape(mechanism.label())
+        )?;
+    }
+    out.write_all(SUPPRESSIONS_END.as_bytes())?;
+    out.write_all(b"<script id=\"dg-suppressions\" type=\"application/json\">")?;
+    let rows: Vec<_> = report
+        .suppressions
+        .iter()
+        .map(|s| {
+            (
+                s.mechanism,
+                s.mechanism.label(),
+                &s.location,
+                &s.silences,
+                &s.reason,
+                &s.since,
+                s.age_days(today),
+                messages::tr(&s.age(today)).into_owned(),
+                s.used,
+            )
+        })
+        .collect();
+    serde_json::to_writer(ScriptSafe(&mut *out), &rows)?;
+    out.write_all(b"</script>\n</section>\n")?;
+    Ok(())
+}
+
+/// Pestaña "Argumentos": un `<details>` por enlace y sus filas como JSON
+/// `[estado, etiqueta, argumento, tipo en código, tipo en docs, ubicación en docs]`.
+fn write_links(links: &[LinkRecord], out: &mut dyn Write) -> Result<()> {
+    out.write_all(b"<section id=\"args\" hidden>\n")?;
+    if links.is_empty() {
+        out.write_all("<p class=\"totals\">Sin enlaces función → sección.</p>\n".as_bytes())?;
+    }
+    for (index, link) in links.iter().enumerate() {
+        let coverage = match link.args_compared {
+            true => format!(
+                "{} de {} documentados",
+                link.count(ArgCoverage::Documented),
+                link.args.len()
+            ),
+            false => "argumentos sin comparar".to_string(),
+        };
+        writeln!(
+            out,
+            "<details class=\"link\" data-l=\"{}\"><summary><span class=\"file\">fn {} → {}</span> \
+             <span class=\"count\">{}</span> <span class=\"sev\">{}:{}</span></summary>\
+             <table><thead><tr><th>Estado</th><th>Argumento</th><th>Tipo en código</th>\
+             <th>Tipo en docs</th><th>Ubicación en docs</th></tr></thead><tbody></tbody></table></details>",
+            index,
+            escape(&link.function),
+            escape(&link.doc_id),
+            coverage,
+            escape(&link.location.file.display().to_string()),
+            link.location.line
+        )?;
+    }
+    out.write_all(b"<script id=\"dg-args\" type=\"application/json\">")?;
+    let rows: Vec<Vec<_>> = links
+        .iter()
+        .map(|link| {
+            link.args
+                .iter()
+                .map(|arg| {
+                    (
+                        arg.status,
+                        arg.status.label(),
+                        &arg.name,
+                        &arg.code_type,
+                        &arg.doc_type,
+                        arg.doc_location
+                            .as_ref()
+                            .map(|at| format!("{}:{}", at.file.display(), at.line)),
+                    )
+                })
+                .collect()
+        })
+        .collect();
+    serde_json::to_writer(ScriptSafe(&mut *out), &rows)?;
+    out.write_all(b"</script>\n</section>\n")?;
+    Ok(())
+}
+
+/// Índices de los hallazgos agrupados por archivo (código o, si no, docs).
+fn group_by_file(results: &[ValidationResult]) -> BTreeMap<String, Vec<usize>> {
+    let mut groups: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (i, result) in results.iter().enumerate() {
+        let file = result
+            .code_location
+            .as_deref()
+            .or(result.doc_location.as_deref())
+            .map(|loc| match parse_location(loc) {
+                Some((path, _)) => path.display().to_string(),
+                None => loc.to_string(),
+            })
+            .unwrap_or_else(|| NO_LOCATION.to_string());
+        groups.entry(file).or_default().push(i);
+    }
+    groups
+}
+
+/// Fila compacta del JSON: `[severidad, regla, función, ubicación, doc-id, mensaje, sugerencia]`.
+type Row<'a> = (
+    String,
+    &'a str,
+    Option<&'a str>,
+    Option<&'a str>,
+    Option<&'a str>,
+    &'a str,
+    Option<&'a str>,
+);
+
+fn row(result: &ValidationResult) -> Row<'_> {
+    (
+        result.severity.to_string(),
+        result.rule.id(),
+        result.function_name.as_deref(),
+        result
+            .code_location
+            .as_deref()
+            .or(result.doc_location.as_deref()),
+        result.doc_id.as_deref(),
+        &result.message,
+        result.hint.as_deref(),
+    )
+}
+
+/// Escapa texto para HTML (contenido y atributos).
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// JSON seguro dentro de `<script>`: `<` solo aparece en cadenas, así que
+/// sustituirlo por `\u003c` impide cerrar el bloque con `</script>`.
+struct ScriptSafe<W: Write>(W);
+
+impl<W: Write> Write for ScriptSafe<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        for chunk in buf.split_inclusive(|&b| b == b'<') {
+            match chunk.split_last() {
+                Some((b'<', rest)) => {
+                    self.0.write_all(rest)?;
+                    self.0.write_all(b"\\u003c")?;
+                }
+                _ => self.0.write_all(chunk)?,
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+const HEAD: &str = r#"<!DOCTYPE html>
+<html lang="es"><head><meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>DocsGuard — informe</title>
+<style>
+body{font:14px/1.4 system-ui,sans-serif;margin:0;color:#1f2328;background:#fff}
+main{max-width:1200px;margin:0 auto;padding:16px}
+.controls{position:sticky;top:0;background:#fff;padding:8px 0;display:flex;gap:8px;flex-wrap:wrap;border-bottom:1px solid #d0d7de}
+.controls input{flex:1;min-width:240px;padding:4px 8px}
+.group{border:1px solid #d0d7de;border-radius:6px;margin:6px 0}
+.group summary{cursor:pointer;padding:6px 10px}
+.file{font-family:ui-monospace,monospace}
+.count{font-weight:600}
+.sev{color:#57606a;font-size:12px}
+table{border-collapse:collapse;width:100%}
+th,td{text-align:left;padding:3px 8px;border-top:1px solid #eaeef2;vertical-align:top}
+td.loc{font-family:ui-monospace,monospace;white-space:nowrap}
+tr.Error td:first-child{color:#cf222e}
+tr.Warning td:first-child{color:#9a6700}
+tr.Info td:first-child{color:#0969da}
+.hint{color:#57606a}
+.more{margin:6px 10px}
+.tabs{display:flex;gap:4px;border-bottom:1px solid #d0d7de;margin-bottom:8px}
+.tabs button{border:1px solid transparent;background:none;padding:6px 12px;cursor:pointer;font:inherit}
+.tabs button.active{border-color:#d0d7de;border-bottom-color:#fff;border-radius:6px 6px 0 0;margin-bottom:-1px;font-weight:600}
+th[data-k]{cursor:pointer;user-select:none}
+tr.unused td{color:#9a6700}
+tr.documented td:first-child{color:#1a7f37}
+tr.missing td:first-child{color:#9a6700}
+tr.ghost td:first-child{color:#cf222e}
+tr.type_mismatch td:first-child,tr.optionality_mismatch td:first-child{color:#bc4c00}
+</style></head>
+<body><main>
+<h1>DocsGuard — integridad de la documentación</h1>
+"#;
+
+const CONTROLS_START: &str = r#"<div class="controls">
+<input id="q" type="search" placeholder="Buscar función, doc-id o mensaje">
+<select id="sev">
+<option value="problems">Errores y advertencias</option>
+<option value="Error">Errores</option>
+<option value="Warning">Advertencias</option>
+<option value="Info">Info</option>
+<option value="all">Todas</option>
+</select>
+<select id="rule"><option value="">Todas las reglas</option>
+"#;
+
+const CONTROLS_END: &str = r#"</select>
+<span id="status"></span>
+</div>
+"#;
+
+const SUPPRESSIONS_START: &str = r#"<section id="suppressions" hidden>
+<div class="controls">
+<input id="sq" type="search" placeholder="Buscar ubicación, regla o motivo">
+<select id="smech"><option value="">Todos los mecanismos</option>
+"#;
+
+const SUPPRESSIONS_END: &str = r#"</select>
+<select id="sused">
+<option value="all">Con y sin uso</option>
+<option value="unused">Sin uso en esta ejecución</option>
+<option value="used">Silenciaron algo</option>
+</select>
+<span id="sstatus"></span>
+</div>
+<table><thead><tr><th data-k="1">Mecanismo</th><th data-k="2">Ubicación</th><th data-k="3">Silencia</th>
+<th data-k="4">Motivo</th><th data-k="6">Antigüedad</th><th data-k="8">Uso</th></tr></thead>
+<tbody id="srows"></tbody></table>
+"#;
+
+/// Pestañas y tabla de supresiones: filtra y ordena el JSON y reconstruye
+/// la tabla entera, que es corta.
+const SUPPRESSIONS_SCRIPT: &str = r#"
+for (const button of document.querySelectorAll('.tabs button')) {
+  button.addEventListener('click', () => {
+    for (const other of document.querySelectorAll('.tabs button')) {
+      other.classList.toggle('active', other === button);
+      document.getElementById(other.dataset.tab).hidden = other !== button;
+    }
+  });
+}
+const suppressions = JSON.parse(document.getElementById('dg-suppressions').textContent);
+const sq = document.getElementById('sq'), smech = document.getElementById('smech'),
+  sused = document.getElementById('sused'), srows = document.getElementById('srows');
+let sortKey = 1, sortDir = 1;
+function sortValue(row) {
+  const value = row[sortKey];
+  return value === null ? (sortDir > 0 ? Infinity : -Infinity) : value;
+}
+function renderSuppressions() {
+  const text = sq.value.trim().toLowerCase(), m = smech.value, u = sused.value;
+  const rows = suppressions.filter(row => (!m || row[0] === m)
+    && (u === 'all' || (u === 'used') === row[8])
+    && (!text || [row[2], row[3], row[4]].some(f => f && f.toLowerCase().includes(text))));
+  rows.sort((a, b) => {
+    const x = sortValue(a), y = sortValue(b);
+    return (x < y ? -1 : x > y ? 1 : 0) * sortDir;
+  });
+  const frag = document.createDocumentFragment();
+  for (const row of rows) {
+    const tr = document.createElement('tr');
+    if (!row[8]) tr.className = 'unused';
+    for (const [text, cls] of [[row[1], ''], [row[2], 'loc'], [row[3], ''], [row[4] || '', ''],
+        [row[7], ''], [row[8] ? 'silenció hallazgos' : 'sin uso', '']]) {
+      const td = document.createElement('td');
+      td.textContent = text;
+      if (cls) td.className = cls;
+      tr.appendChild(td);
+    }
+    frag.appendChild(tr);
+  }
+  srows.textContent = '';
+  srows.appendChild(frag);
+  document.getElementById('sstatus').textContent = rows.length + ' de ' + suppressions.length + ' supresiones';
+}
+for (const th of document.querySelectorAll('th[data-k]')) {
+  th.addEventListener('click', () => {
+    const key = +th.dataset.k;
+    sortDir = key === sortKey ? -sortDir : 1;
+    sortKey = key;
+    renderSuppressions();
+  });
+}
+sq.addEventListener('input', renderSuppressions);
+smech.addEventListener('change', renderSuppressions);
+sused.addEventListener('change', renderSuppressions);
+renderSuppressions();
+"#;
+
+/// Tabla de argumentos de cada enlace, al desplegarlo.
+const LINKS_SCRIPT: &str = r#"
+const links = JSON.parse(document.getElementById('dg-args').textContent);
+for (const el of document.querySelectorAll('details.link')) {
+  el.addEventListener('toggle', () => {
+    const body = el.querySelector('tbody');
+    body.textContent = '';
+    if (!el.open) return;
+    for (const [status, label, name, codeType, docType, loc] of links[+el.dataset.l]) {
+      const tr = document.createElement('tr');
+      tr.className = status;
+      for (const [text, cls] of [[label, ''], [name, ''], [codeType || '', ''], [docType || '', ''], [loc || '', 'loc']]) {
+        const td = document.createElement('td');
+        td.textContent = text;
+        if (cls) td.className = cls;
+        tr.appendChild(td);
+      }
+      body.appendChild(tr);
+    }
+  });
+}
+"#;
+
+/// Construye las filas bajo demanda. Todo texto entra por `textContent`.
+const SCRIPT: &str = r#"
+const data = JSON.parse(document.getElementById('dg-data').textContent);
+const q = document.getElementById('q'), sev = document.getElementById('sev'),
+  rule = document.getElementById('rule'), statusLine = document.getElementById('status');
+const groups = Array.from(document.querySelectorAll('details.group'));
+let keep = () => true;
+function compile() {
+  const text = q.value.trim().toLowerCase(), s = sev.value, r = rule.value;
+  keep = row => (s === 'all' || (s === 'problems' ? row[0] !== 'Info' : row[0] === s))
+    && (!r || row[1] === r)
+    && (!text || [row[2], row[4], row[5]].some(f => f && f.toLowerCase().includes(text)));
+}
+function more(el) {
+  const body = el.querySelector('tbody'), rows = el.rows, end = Math.min(el.shown + PAGE, rows.length);
+  const frag = document.createDocumentFragment();
+  for (let i = el.shown; i < end; i++) {
+    const [severity, id, fn, loc, , message, hint] = rows[i];
+    const tr = document.createElement('tr');
+    tr.className = severity;
+    for (const [text, cls] of [[severity, ''], [id, ''], [fn || '', ''], [loc || '', 'loc'], [message, '']]) {
+      const td = document.createElement('td');
+      td.textContent = text;
+      if (cls) td.className = cls;
+      tr.appendChild(td);
+    }
+    if (hint) {
+      const span = document.createElement('div');
+      span.className = 'hint';
+      span.textContent = hint;
+      tr.lastChild.appendChild(span);
+    }
+    frag.appendChild(tr);
+  }
+  body.appendChild(frag);
+  el.shown = end;
+  const button = el.querySelector('.more');
+  button.hidden = end >= rows.length;
+  button.textContent = 'Mostrar más (' + (rows.length - end) + ' restantes)';
+}
+function render(el) {
+  el.rows = data[+el.dataset.g].filter(keep);
+  el.shown = 0;
+  el.querySelector('tbody').textContent = '';
+  more(el);
+}
+function apply() {
+  compile();
+  let total = 0, visible = 0;
+  for (const el of groups) {
+    let n = 0;
+    for (const row of data[+el.dataset.g]) if (keep(row)) n++;
+    el.querySelector('.count').textContent = n;
+    el.hidden = n === 0;
+    total += n;
+    if (n) visible++;
+    if (el.open && n) render(el);
+  }
+  statusLine.textContent = total + ' hallazgos en ' + visible + ' archivos';
+}
+for (const el of groups) {
+  el.addEventListener('toggle', () => {
+    if (el.open) render(el); else el.querySelector('tbody').textContent = '';
+  });
+  el.querySelector('.more').addEventListener('click', () => more(el));
+}
+let timer;
+q.addEventListener('input', () => { clearTimeout(timer); timer = setTimeout(apply, 150); });
+sev.addEventListener('change', apply);
+rule.addEventListener('change', apply);
+apply();
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Rule;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    /// Contabiliza el pico de memoria viva por hilo, para medir la generación
+    /// sin que interfieran los tests que corren en paralelo.
+    struct Meter;
+
+    thread_local! {
+        /// (viva, pico) del hilo actual, en bytes.
+        static USAGE: Cell<(isize, isize)> = const { Cell::new((0, 0)) };
+    }
+
+    fn track(delta: isize) {
+        let _ = USAGE.try_with(|usage| {
+            let (live, peak) = usage.get();
+            let live = live + delta;
+            usage.set((live, peak.max(live)));
+        });
+    }
+
+    unsafe impl GlobalAlloc for Meter {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                track(layout.size() as isize);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            track(-(layout.size() as isize));
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new = System.realloc(ptr, layout, new_size);
+            if !new.is_null() {
+                track(new_size as isize - layout.size() as isize);
+            }
+            new
+        }
+    }
+
+    #[global_allocator]
+    static METER: Meter = Meter;
+
+    /// Pico de memoria (por encima de la viva al empezar) que necesita `f`.
+    fn peak_during(f: impl FnOnce()) -> isize {
+        USAGE.with(|usage| usage.set((0, 0)));
+        f();
+        USAGE.with(|usage| usage.get().1)
+    }
+
+    fn finding(severity: Severity, rule: Rule, location: &str, message: &str) -> ValidationResult {
+        ValidationResult {
+            severity,
+            rule,
+            message: message.into(),
+            function_name: Some("login".into()),
+            code_location: Some(location.into()),
+            doc_id: Some("auth-login".into()),
+            doc_location: None,
+            hint: None,
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+
+    /// Informe sintético de `count` hallazgos repartidos en 500 archivos.
+    fn stress_report(count: usize) -> Report {
+        let rules = [
+            Rule::GhostArg,
+            Rule::MissingArg,
+            Rule::TypeMismatch,
+            Rule::LinkVerified,
+        ];
+        let severities = [Severity::Error, Severity::Warning, Severity::Info];
+        Report {
+            results: (0..count)
+                .map(|i| {
+                    finding(
+                        severities[i % 3],
+                        rules[i % 4],
+                        &format!("src/module_{}/handlers.ts:{}", i % 500, i),
+                        &format!("Argumento fantasma: 'param_{i}' está documentado pero no existe en fn handler_{i}."),
+                    )
+                })
+                .collect(),
+            ..Report::default()
+        }
+    }
+
+    fn render(report: &Report) -> String {
+        let mut out = Vec::new();
+        write(report, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn findings_are_grouped_by_file_and_embedded_as_json() {
+        let report = Report {
+            results: vec![
+                finding(Severity::Error, Rule::GhostArg, "src/b.ts:3", "fantasma"),
+                finding(Severity::Warning, Rule::MissingArg, "src/a.ts:9", "falta"),
+                finding(Severity::Info, Rule::LinkVerified, "src/b.ts:1", "ok"),
+            ],
+            ..Report::default()
+        };
+        let html = render(&report);
+
+        let a = html.find("<span class=\"file\">src/a.ts</span>").unwrap();
+        let b = html.find("<span class=\"file\">src/b.ts</span>").unwrap();
+        assert!(a < b);
+        assert!(html.contains("1 E · 0 W · 1 I"));
+        assert!(html.contains("<option value=\"ghost-arg\">ghost-arg</option>"));
+        // Sin filas en el DOM: solo el bloque JSON, en el orden de los grupos
+        assert!(!html.contains("<td"));
+        assert!(html.contains(
+            r#"[[["Warning","missing-arg","login","src/a.ts:9","auth-login","falta",null]],[["Error","ghost-arg""#
+        ));
+    }
+
+    #[test]
+    fn markup_in_findings_cannot_escape_the_json_or_the_summary() {
+        let report = Report {
+            results: vec![finding(
+                Severity::Error,
+                Rule::GhostArg,
+                "src/<img src=x>.ts:1",
+                "</script><script>alert(1)</script>",
+            )],
+            ..Report::default()
+        };
+        let html = render(&report);
+        // Datos, supresiones, argumentos y código
+        assert_eq!(html.matches("</script>").count(), 4);
+        assert!(html.contains(r#"\u003c/script>\u003cscript>alert(1)"#));
+        assert!(html.contains("src/&lt;img src=x&gt;.ts"));
+    }
+
+    #[test]
+    fn suppressions_have_their_own_tab_filtered_by_mechanism() {
+        use crate::report::suppressions::Record;
+        let record = |mechanism, used| Record {
+            mechanism,
+            location: "src/auth.ts:2".into(),
+            silences: "ghost-arg en fn login".into(),
+            reason: Some("</script><b>motivo</b>".into()),
+            since: None,
+            used,
+        };
+        let report = Report {
+            suppressions: vec![
+                record(Mechanism::Inline, true),
+                record(Mechanism::Baseline, false),
+            ],
+            ..Report::default()
+        };
+        let html = render(&report);
+        assert!(html.contains("<button data-tab=\"suppressions\">Supresiones (2)</button>"));
+        assert!(html.contains("<option value=\"inline\">docsguard-ignore</option>"));
+        assert!(html.contains("<option value=\"baseline\">baseline</option>"));
+        assert!(!html.contains("value=\"no-link\""));
+        assert!(html.contains(
+            r#"[["inline","docsguard-ignore","src/auth.ts:2","ghost-arg en fn login","\u003c/script>\u003cb>motivo\u003c/b>",null,null,"antigüedad desconocida",true]"#
+        ));
+    }
+
+    #[test]
+    fn each_link_gets_an_arg_table_colored_by_status() {
+        use crate::report::links::ArgRecord;
+        use crate::report::model::SourceLocation;
+        let at = |file: &str, line| SourceLocation {
+            file: file.into(),
+            line,
+        };
+        let arg = |name: &str, status| ArgRecord {
+            name: name.into(),
+            code_type: Some("string".into()),
+            doc_type: None,
+            status,
+            doc_location: None,
+        };
+        let report = Report {
+            links: vec![LinkRecord {
+                function: "login".into(),
+                location: at("src/auth.ts", 2),
+                doc_id: "auth-login".into(),
+                doc_location: at("docs/api.md", 5),
+                args_compared: true,
+                args: vec![
+                    arg("user", ArgCoverage::Documented),
+                    arg("device", ArgCoverage::Missing),
+                ],
+            }],
+            ..Report::default()
+        };
+        let html = render(&report);
+        assert!(html.contains("<button data-tab=\"args\">Argumentos (1 enlaces)</button>"));
+        assert!(html.contains(
+            "fn login → auth-login</span> <span class=\"count\">1 de 2 documentados</span>"
+        ));
+        assert!(html.contains(
+            r#"<script id="dg-args" type="application/json">[[["documented","documentado","user","string",null,null],["missing","sin documentar","device","string",null,null]]]</script>"#
+        ));
+    }
+
+    #[test]
+    fn fifty_thousand_findings_stream_under_a_memory_ceiling() {
+        const CEILING: isize = 4 * 1024 * 1024;
+        let report = stress_report(50_000);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("report.html");
+
+        let peak = peak_during(|| {
+            crate::transaction::write_streamed(&path, |out| write(&report, out)).unwrap()
+        });
+
+        let size = std::fs::metadata(&path).unwrap().len() as isize;
+        assert!(
+            size > CEILING,
+            "el informe debería superar el techo: {size}"
+        );
+        assert!(
+            peak < CEILING,
+            "pico de memoria {peak} bytes (techo {CEILING})"
+        );
+        let html = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(html.matches("<details class=\"group\"").count(), 500);
+    }
+}
@@ -0,0 +1,64 @@
+//! Informe JSON de `check --format json`, para paneles e ingesta.
+//!
+//! Los hallazgos tras el baseline, en el idioma de la salida, con los
+//! registros por enlace de `links`: estos salen de lo parseado, así que un
+//! baseline que lo oculta todo deja `findings` vacío y `links` igual. Con
+//! `--explain-baseline`, `baseline_debug` lleva por cada hallazgo no filtrado
+//! la entrada calculada, la más parecida y los campos que difieren, y con
+//! `--debt-age`, `debt_age` lleva el bloque de antigüedad del baseline; sin
+//! la opción, cada clave no aparece. El formato es estable: `schema_version` sube
+//! cuando cambia (una clave opcional nueva no lo cambia).
+
+use serde::Serialize;
+
+use super::links::LinkRecord;
+use super::Report;
+use crate::baseline::{DebtAgeRecord, MissRecord};
+use crate::core::types::{Severity, ValidationResult};
+
+/// Versión del formato; sube cuando cambia la forma del JSON.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize)]
+pub struct Summary {
+    pub errors: usize,
+    pub warnings: usize,
+    pub infos: usize,
+    pub baseline_filtered: usize,
+    pub functions: usize,
+    pub sections: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonReport<'a> {
+    pub schema_version: u32,
+    /// Versión de docsguard que lo generó.
+    pub generator: String,
+    pub summary: Summary,
+    pub findings: &'a [ValidationResult],
+    pub links: &'a [LinkRecord],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline_debug: Option<&'a [MissRecord]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debt_age: Option<&'a DebtAgeRecord>,
+}
+
+/// Informe de `report`, ya localizado y con `links` rellenos.
+pub fn render(report: &Report) -> JsonReport<'_> {
+    JsonReport {
+        schema_version: SCHEMA_VERSION,
+        generator: format!("docsguard {}", env!("CARGO_PKG_VERSION")),
+        summary: Summary {
+            errors: report.count(Severity::Error),
+            warnings: report.count(Severity::Warning),
+            infos: report.count(Severity::Info),
+            baseline_filtered: report.baseline_filtered,
+            functions: report.entity_count,
+            sections: report.section_count,
+        },
+        findings: &report.results,
+        links: &report.links,
+        baseline_debug: report.baseline_debug.as_deref(),
+        debt_age: report.debt_age.as_ref(),
+    }
+}
@@ -0,0 +1,311 @@
+//! Cobertura por argumento de cada enlace función → sección, para los
+//! paneles que siguen la completitud de la documentación de parámetros.
+//!
+//! Cada función con `@docs` da un registro por sección con su ID, con el
+//! estado de cada argumento tal como lo compara `validator::validate_args`:
+//! `documented`, `missing` (en la firma, no en la sección), `ghost` (en la
+//! sección, no en la firma), `type_mismatch` (tipos distintos tras
+//! normalizarlos) y `optionality_mismatch` (la sección lo da por obligatorio
+//! y el tipo de la firma lo envuelve en `Option<…>`, `Optional[…]`,
+//! `… | None`, `… | undefined`, `… | null` o `T?`; un parámetro con valor por
+//! defecto no se distingue de uno obligatorio). Los registros salen de lo
+//! parseado, no de los hallazgos: el baseline y las supresiones no los
+//! alteran. Las secciones `@docs-skip: args` y las recortadas por
+//! `limits.max_section_args` no comparan argumentos (`args_compared: false`).
+//!
+//! Los consumen `check --format json` y la pestaña "Argumentos" de
+//! `report --html`; la salida de texto no.
+
+use serde::{Deserialize, Serialize};
+
+use super::model::SourceLocation;
+use crate::config::Config;
+use crate::core::types::{Arg, CodeEntity, DocSection};
+use crate::core::validator::{self, normalize_type};
+
+/// Estado de un argumento en un enlace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArgCoverage {
+    Documented,
+    Missing,
+    Ghost,
+    TypeMismatch,
+    OptionalityMismatch,
+}
+
+impl ArgCoverage {
+    /// Nombre para la pestaña "Argumentos" del informe HTML.
+    pub fn label(self) -> &'static str {
+        match self {
+            ArgCoverage::Documented => "documentado",
+            ArgCoverage::Missing => "sin documentar",
+            ArgCoverage::Ghost => "fantasma",
+            ArgCoverage::TypeMismatch => "tipo distinto",
+            ArgCoverage::OptionalityMismatch => "opcionalidad distinta",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArgRecord {
+    /// Nombre en la firma (en la sección, si es fantasma).
+    pub name: String,
+    pub code_type: Option<String>,
+    pub doc_type: Option<String>,
+    pub status: ArgCoverage,
+    /// Donde se documenta, si la sección lo documenta.
+    pub doc_location: Option<SourceLocation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinkRecord {
+    pub function: String,
+    pub location: SourceLocation,
+    pub doc_id: String,
+    pub doc_location: SourceLocation,
+    pub args_compared: bool,
+    /// Los de la firma en su orden y después los fantasmas.
+    pub args: Vec<ArgRecord>,
+}
+
+impl LinkRecord {
+    /// Argumentos con `status` entre los comparados.
+    pub fn count(&self, status: ArgCoverage) -> usize {
+        self.args.iter().filter(|a| a.status == status).count()
+    }
+}
+
+/// Si el tipo de la firma dice que el argumento es opcional; `None` si no
+/// lo dice (puede serlo por un valor por defecto).
+fn code_optionality(type_name: &str) -> Option<bool> {
+    let type_name = type_name.trim();
+    let wrapped = ["Option<", "Optional["]
+        .iter()
+        .any(|w| type_name.starts_with(w));
+    let union = type_name
+        .split('|')
+        .skip(1)
+        .any(|t| ["None", "undefined", "null"].contains(&t.trim()));
+    (wrapped || union || type_name.ends_with('?')).then_some(true)
+}
+
+fn compare(code_arg: &Arg, doc_arg: &Arg) -> ArgCoverage {
+    if let (Some(code), Some(doc)) = (&code_arg.type_name, &doc_arg.type_name) {
+        if normalize_type(code) != normalize_type(doc) {
+            return ArgCoverage::TypeMismatch;
+        }
+    }
+    let code = code_arg.type_name.as_deref().and_then(code_optionality);
+    match (code, validator::documented_optionality(doc_arg)) {
+        (Some(code), Some(doc)) if code != doc => ArgCoverage::OptionalityMismatch,
+        _ => ArgCoverage::Documented,
+    }
+}
+
+fn arg_records(
+    entity: &CodeEntity,
+    section: &DocSection,
+    config: &Config,
+    at: impl Fn(usize) -> SourceLocation,
+) -> Vec<ArgRecord> {
+    let documented_by = |code_arg: &Arg| {
+        section.args.iter().find(|d| {
+            validator::code_arg_for(entity, section, d, &config.arg_names)
+                .is_some_and(|a| a.name == code_arg.name)
+        })
+    };
+    let mut args: Vec<ArgRecord> = entity
+        .args
+        .iter()
+        .map(|code_arg| {
+            let doc_arg = documented_by(code_arg);
+            ArgRecord {
+                name: code_arg.name.clone(),
+                code_type: code_arg.type_name.clone(),
+                doc_type: doc_arg.and_then(|d| d.type_name.clone()),
+                status: match doc_arg {
+                    Some(doc_arg) => compare(code_arg, doc_arg),
+                    None => ArgCoverage::Missing,
+                },
+                doc_location: doc_arg.map(|d| at(d.line.unwrap_or(section.line))),
+            }
+        })
+        .collect();
+    args.extend(
+        section
+            .args
+            .iter()
+            .filter(|d| validator::code_arg_for(entity, section, d, &config.arg_names).is_none())
+            .map(|doc_arg| ArgRecord {
+                name: doc_arg.name.clone(),
+                code_type: None,
+                doc_type: doc_arg.type_name.clone(),
+                status: ArgCoverage::Ghost,
+                doc_location: Some(at(doc_arg.line.unwrap_or(section.line))),
+            }),
+    );
+    args
+}
+
+/// Un registro por función con `@docs` y sección con su ID, en el orden del
+/// código.
+pub fn build(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+    config: &Config,
+) -> Vec<LinkRecord> {
+    let mut links = Vec::new();
+    for entity in code_entities {
+        let Some(doc_id) = &entity.doc_id else {
+            continue;
+        };
+        for section in doc_sections.iter().filter(|s| &s.id == doc_id) {
+            let at = |line| SourceLocation {
+                file: config.paths.normalize(&section.file_path),
+                line,
+            };
+            let args_compared = !section.is_prose_only() && section.dropped_args == 0;
+            links.push(LinkRecord {
+                function: entity.name.clone(),
+                location: SourceLocation {
+                    file: config.paths.normalize(&entity.file_path),
+                    line: entity.line,
+                },
+                doc_id: doc_id.clone(),
+                doc_location: at(section.line),
+                args_compared,
+                args: match args_compared {
+                    true => arg_records(entity, section, config, at),
+                    false => Vec::new(),
+                },
+            });
+        }
+    }
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{code_parser, doc_parser};
+    use crate::vfs::MemFs;
+
+    const CODE: &str = "\
+/// @docs: [login]
+pub fn login(user: String, retries: u32, remember: Option<bool>, scope: Option<String>, device: String) {}
+";
+
+    const DOCS: &str = "\
+<!-- @docs-id: login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| user | string | Usuario |
+| retries | string | Reintentos |
+| remember | `Option<bool>` | Obligatorio. Recordar la sesión |
+| scope | `Option<String>` | Opcional. Alcance |
+| token | string | Sobra |
+";
+
+    #[test]
+    fn every_status_is_recorded_with_its_doc_location() {
+        let dir = MemFs::project();
+        let root = dir.path();
+        dir.write(root.join("src/lib.rs"), CODE);
+        dir.write(root.join("docs/api.md"), DOCS);
+        let config = Config::default();
+        let mut diagnostics = Default::default();
+        let entities =
+            code_parser::parse_project_code(&[root.join("src/lib.rs")], &config, &mut diagnostics)
+                .unwrap();
+        let sections = doc_parser::parse_docs_with_diagnostics(
+            &root.join("docs/api.md"),
+            &config,
+            &mut diagnostics,
+        )
+        .unwrap();
+
+        let links = build(&entities, &sections, &config);
+        let json = serde_json::to_string_pretty(&links[0].args).unwrap();
+        let doc_file = config.paths.normalize(&root.join("docs/api.md"));
+        let doc_file = serde_json::to_string(&doc_file).unwrap();
+        let at = |line: usize| {
+            format!(
+                "{{\n      \"file\": {},\n      \"line\": {}\n    }}",
+                doc_file, line
+            )
+        };
+        let expected = format!(
+            r#"[
+  {{
+    "name": "user",
+    "code_type": "String",
+    "doc_type": "string",
+    "status": "documented",
+    "doc_location": {}
+  }},
+  {{
+    "name": "retries",
+    "code_type": "u32",
+    "doc_type": "string",
+    "status": "type_mismatch",
+    "doc_location": {}
+  }},
+  {{
+    "name": "remember",
+    "code_type": "Option<bool>",
+    "doc_type": "Option<bool>",
+    "status": "optionality_mismatch",
+    "doc_location": {}
+  }},
+  {{
+    "name": "scope",
+    "code_type": "Option<String>",
+    "doc_type": "Option<String>",
+    "status": "documented",
+    "doc_location": {}
+  }},
+  {{
+    "name": "device",
+    "code_type": "String",
+    "doc_type": null,
+    "status": "missing",
+    "doc_location": null
+  }},
+  {{
+    "name": "token",
+    "code_type": null,
+    "doc_type": "string",
+    "status": "ghost",
+    "doc_location": {}
+  }}
+]"#,
+            at(6),
+            at(7),
+            at(8),
+            at(9),
+            at(10)
+        );
+        assert_eq!(json, expected);
+        assert_eq!(links[0].count(ArgCoverage::Documented), 2);
+        assert!(links[0].args_compared);
+    }
+
+    #[test]
+    fn the_signature_only_says_optional_with_a_wrapper() {
+        for optional in [
+            "Option<u32>",
+            "Optional[int]",
+            "str | None",
+            "string | undefined",
+            "int?",
+        ] {
+            assert_eq!(code_optionality(optional), Some(true), "{optional}");
+        }
+        for unknown in ["u32", "Vec<Option<u32>>", "string | number"] {
+            assert_eq!(code_optionality(unknown), None, "{unknown}");
+        }
+    }
+}
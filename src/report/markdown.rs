@@ -0,0 +1,405 @@
+//! Formateador Markdown de un `Report`.
+//!
+//! Pensado para superficies de CI (resumen de job, comentario de PR/MR):
+//! contadores, tabla con los hallazgos más relevantes y enlaces al código.
+//! `render_document` es el documento completo de `check --format markdown`
+//! y `check --report`, sin truncar, para pegarlo en una PR o en Slack; sus
+//! textos fijos salen en el idioma de la salida.
+
+use std::path::{Path, PathBuf};
+
+use super::{Report, Verbosity};
+use crate::core::types::{parse_location, Severity, ValidationResult};
+use crate::messages::{self, Locale};
+
+/// Número máximo de hallazgos listados en la tabla.
+pub const MAX_LISTED_FINDINGS: usize = 20;
+
+/// Construye enlaces web a `archivo#Llínea` en el repositorio remoto.
+#[derive(Debug, Clone)]
+pub struct SourceLinks {
+    /// URL base hasta la revisión, sin barra final
+    /// (p. ej. `https://github.com/org/repo/blob/<sha>`).
+    pub base_url: String,
+    /// Raíz local del repositorio, para relativizar rutas absolutas.
+    pub root: PathBuf,
+}
+
+impl SourceLinks {
+    /// URL de una ubicación `archivo:línea`, o `None` si no es relativizable.
+    pub fn url(&self, location: &str) -> Option<String> {
+        let (path, line) = parse_location(location)?;
+        let relative = if path.is_absolute() {
+            path.strip_prefix(&self.root).ok()?
+        } else {
+            path.strip_prefix("./").unwrap_or(path)
+        };
+        Some(format!(
+            "{}/{}#L{}",
+            self.base_url.trim_end_matches('/'),
+            url_path(relative),
+            line
+        ))
+    }
+}
+
+/// Renderiza el informe completo en Markdown.
+pub fn render(report: &Report, links: Option<&SourceLinks>) -> String {
+    let errors = report.count(Severity::Error);
+    let warnings = report.count(Severity::Warning);
+
+    let mut out = String::from("### DocsGuard — integridad de la documentación\n\n");
+    out.push_str("| Errores | Advertencias | Filtrados por baseline |\n");
+    out.push_str("|--------:|-------------:|-----------------------:|\n");
+    out.push_str(&format!(
+        "| {} | {} | {} |\n\n",
+        errors, warnings, report.baseline_filtered
+    ));
+
+    let mut findings: Vec<&ValidationResult> = report
+        .results
+        .iter()
+        .filter(|r| r.severity != Severity::Info)
+        .collect();
+
+    if findings.is_empty() {
+        out.push_str("✅ Sin errores ni advertencias.\n");
+        return out;
+    }
+
+    // Errores primero; el orden relativo del validador se conserva
+    findings.sort_by_key(|r| r.severity != Severity::Error);
+
+    out.push_str("| | Regla | Función | Ubicación | Mensaje |\n");
+    out.push_str("|---|---|---|---|---|\n");
+    for finding in findings.iter().take(MAX_LISTED_FINDINGS) {
+        out.push_str(&format!(
+            "| {} | `{}` | {} | {} | {} |\n",
+            severity_icon(finding.severity),
+            finding.rule,
+            finding
+                .function_name
+                .as_deref()
+                .map(|f| format!("`{}`", escape_cell(f)))
+                .unwrap_or_default(),
+            render_location(finding, links),
+            escape_cell(&finding.message)
+        ));
+    }
+
+    if findings.len() > MAX_LISTED_FINDINGS {
+        out.push_str(&format!(
+            "\n_… y {} más._\n",
+            messages::FINDINGS.count(findings.len() - MAX_LISTED_FINDINGS)
+        ));
+    }
+
+    out
+}
+
+/// Documento completo: tabla de resumen (contadores y secciones enlazadas)
+/// y una sección por severidad con la tabla de sus hallazgos, los que
+/// `verbosity` muestra.
+pub fn render_document(report: &Report, verbosity: Verbosity) -> String {
+    render_document_in(Locale::output(), report, verbosity)
+}
+
+/// Como `render_document`, con los textos fijos en `locale`.
+pub fn render_document_in(locale: Locale, report: &Report, verbosity: Verbosity) -> String {
+    let tr = |text: &str| messages::tr_in(locale, text).into_owned();
+    let linked = report.section_ids.intersection(&report.linked_ids).count();
+    let coverage = match report.section_count {
+        0 => tr("0 de 0"),
+        total => tr(&format!(
+            "{} de {} ({:.1}%)",
+            linked,
+            total,
+            linked as f64 * 100.0 / total as f64
+        )),
+    };
+
+    let mut out = format!("{}\n\n", tr("# DocsGuard — informe de verificación"));
+    out.push_str(&tr(
+        "| Errores | Advertencias | Info | Filtrados por baseline | Secciones enlazadas |",
+    ));
+    out.push('\n');
+    out.push_str(
+        "|--------:|-------------:|-----:|-----------------------:|--------------------:|\n",
+    );
+    out.push_str(&format!(
+        "| {} | {} | {} | {} | {} |\n",
+        report.count(Severity::Error),
+        report.count(Severity::Warning),
+        report.count(Severity::Info),
+        report.baseline_filtered,
+        coverage
+    ));
+
+    let mut listed = 0;
+    for (severity, title) in [
+        (Severity::Error, "Errores"),
+        (Severity::Warning, "Advertencias"),
+        (Severity::Info, "Info"),
+    ] {
+        let findings: Vec<&ValidationResult> = report
+            .results
+            .iter()
+            .filter(|r| r.severity == severity && verbosity.shows(r))
+            .collect();
+        if findings.is_empty() {
+            continue;
+        }
+        listed += findings.len();
+        out.push_str(&format!(
+            "\n## {} {} ({})\n\n",
+            severity_icon(severity),
+            tr(title),
+            findings.len()
+        ));
+        out.push_str(&tr(
+            "| Función | Ubicación | Doc ID | Mensaje | Sugerencia |",
+        ));
+        out.push('\n');
+        out.push_str("|---|---|---|---|---|\n");
+        for finding in findings {
+            let code = |text: Option<&str>| {
+                text.map(|t| format!("`{}`", escape_cell(t)))
+                    .unwrap_or_default()
+            };
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                code(finding.function_name.as_deref()),
+                render_location(finding, None),
+                code(finding.doc_id.as_deref()),
+                escape_cell(&finding.message),
+                finding.hint.as_deref().map(hint_cell).unwrap_or_default()
+            ));
+        }
+    }
+    if listed == 0 {
+        out.push_str(&format!("\n{}\n", tr("✅ Sin hallazgos.")));
+    }
+    out
+}
+
+fn severity_icon(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "❌",
+        Severity::Warning => "⚠️",
+        Severity::Info => "ℹ️",
+    }
+}
+
+/// Ubicación del hallazgo, enlazada si hay `SourceLinks`.
+fn render_location(finding: &ValidationResult, links: Option<&SourceLinks>) -> String {
+    let Some(location) = finding
+        .code_location
+        .as_deref()
+        .or(finding.doc_location.as_deref())
+    else {
+        return String::new();
+    };
+    let label = escape_cell(location);
+    match links.and_then(|l| l.url(location)) {
+        Some(url) => format!("[{}]({})", label, url),
+        None => format!("`{}`", label),
+    }
+}
+
+/// Escapa el contenido de una celda de tabla Markdown.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+/// Sugerencia para una celda: los tramos entre comillas invertidas se
+/// conservan y, fuera de ellos, cada palabra con sintaxis de código
+/// (`<!--`, `@docs:`, `user_id`…) se envuelve en comillas invertidas para
+/// que Markdown no la interprete.
+fn hint_cell(hint: &str) -> String {
+    let looks_like_code = |word: &str| {
+        word.contains(|c| "<>*_#@[]\\".contains(c)) || word.contains("//") || word.contains("::")
+    };
+    let mut out = String::new();
+    for (i, part) in escape_cell(hint).split('`').enumerate() {
+        if i % 2 == 1 {
+            out.push_str(&format!("`{}`", part));
+            continue;
+        }
+        let words: Vec<String> = part
+            .split(' ')
+            .map(|word| {
+                let core = word
+                    .trim_start_matches(['(', '¿', '¡'])
+                    .trim_end_matches(['.', ',', ';', ':', ')', '?', '!']);
+                if core.is_empty() || !looks_like_code(core) {
+                    return word.to_string();
+                }
+                let start = word.len() - word.trim_start_matches(['(', '¿', '¡']).len();
+                let end = start + core.len();
+                format!("{}`{}`{}", &word[..start], core, &word[end..])
+            })
+            .collect();
+        out.push_str(&words.join(" "));
+    }
+    out
+}
+
+/// Ruta con separadores `/` y espacios codificados, apta para una URL.
+fn url_path(path: &Path) -> String {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy().replace(' ', "%20"))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Rule;
+
+    fn finding(severity: Severity, rule: Rule, location: &str) -> ValidationResult {
+        ValidationResult {
+            severity,
+            rule,
+            message: "Argumento fantasma | 'x'".into(),
+            function_name: Some("login".into()),
+            code_location: Some(location.into()),
+            doc_id: Some("auth-login".into()),
+            doc_location: None,
+            hint: None,
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+
+    fn links() -> SourceLinks {
+        SourceLinks {
+            base_url: "https://github.com/org/repo/blob/abc123".into(),
+            root: PathBuf::from("/work/repo"),
+        }
+    }
+
+    #[test]
+    fn clean_report_says_so() {
+        let out = render(&Report::default(), None);
+        assert!(out.contains("| 0 | 0 | 0 |"));
+        assert!(out.contains("Sin errores ni advertencias"));
+    }
+
+    #[test]
+    fn errors_are_listed_first_with_links() {
+        let report = Report {
+            results: vec![
+                finding(Severity::Warning, Rule::GhostArg, "src/a.ts:4"),
+                finding(Severity::Error, Rule::MissingDocSection, "./src/b.ts:9"),
+                finding(Severity::Info, Rule::LinkVerified, "src/c.ts:1"),
+            ],
+            ..Report::default()
+        };
+        let out = render(&report, Some(&links()));
+
+        let error_pos = out.find("missing-doc-section").unwrap();
+        let warning_pos = out.find("ghost-arg").unwrap();
+        assert!(error_pos < warning_pos);
+        assert!(!out.contains("link-verified"));
+        assert!(out.contains("(https://github.com/org/repo/blob/abc123/src/b.ts#L9)"));
+        assert!(out.contains("Argumento fantasma \\| 'x'"));
+    }
+
+    #[test]
+    fn long_reports_are_truncated() {
+        let report = Report {
+            results: (0..MAX_LISTED_FINDINGS + 3)
+                .map(|i| finding(Severity::Warning, Rule::GhostArg, &format!("a.ts:{i}")))
+                .collect(),
+            ..Report::default()
+        };
+        assert!(render(&report, None).contains("… y 3 hallazgos más."));
+    }
+
+    #[test]
+    fn the_document_has_a_table_per_severity_with_hints_as_code() {
+        let mut error = finding(Severity::Error, Rule::MissingDocSection, "src/b.ts:9");
+        error.hint = Some("Añade `<!-- @docs-id: auth-login -->` en el archivo.".into());
+        let mut warning = finding(Severity::Warning, Rule::MissingArg, "src/a.ts:4");
+        warning.hint = Some("Documenta el argumento 'user_id' en la sección <login>.".into());
+        let report = Report {
+            results: vec![
+                warning,
+                error,
+                finding(Severity::Info, Rule::LinkVerified, "src/c.ts:1"),
+            ],
+            section_count: 2,
+            section_ids: ["auth-login".to_string(), "auth-logout".to_string()].into(),
+            linked_ids: ["auth-login".to_string()].into(),
+            ..Report::default()
+        };
+        let out = render_document(&report, Verbosity::Normal);
+        assert!(
+            out.contains("| 1 | 1 | 1 | 0 | 1 de 2 (50.0%) |\n"),
+            "{out}"
+        );
+        let errors = out.find("## ❌ Errores (1)").unwrap();
+        let warnings = out.find("## ⚠️ Advertencias (1)").unwrap();
+        assert!(errors < warnings);
+        // Los enlaces verificados, solo con -v
+        assert!(!out.contains("## ℹ️ Info"), "{out}");
+        assert!(out.contains(
+            "| `login` | `src/b.ts:9` | `auth-login` | Argumento fantasma \\| 'x' | Añade `<!-- @docs-id: auth-login -->` en el archivo. |\n"
+        ), "{out}");
+        assert!(
+            out.contains("Documenta el argumento `'user_id'` en la sección `<login>`."),
+            "{out}"
+        );
+        assert!(render_document(&report, Verbosity::Verbose).contains("## ℹ️ Info (1)"));
+        assert!(
+            render_document(&Report::default(), Verbosity::Normal).contains("✅ Sin hallazgos.")
+        );
+    }
+
+    #[test]
+    fn the_document_follows_the_output_locale() {
+        let report = Report {
+            results: vec![finding(Severity::Warning, Rule::GhostArg, "src/a.ts:4")],
+            section_count: 2,
+            section_ids: ["auth-login".to_string(), "auth-logout".to_string()].into(),
+            linked_ids: ["auth-login".to_string()].into(),
+            ..Report::default()
+        };
+        let out = render_document_in(Locale::En, &report, Verbosity::Normal);
+        assert!(
+            out.starts_with("# DocsGuard — verification report\n"),
+            "{out}"
+        );
+        assert!(
+            out.contains("| Errors | Warnings | Info | Filtered by baseline | Linked sections |\n"),
+            "{out}"
+        );
+        assert!(
+            out.contains("| 0 | 1 | 0 | 0 | 1 of 2 (50.0%) |\n"),
+            "{out}"
+        );
+        assert!(out.contains("## ⚠️ Warnings (1)"), "{out}");
+        assert!(
+            out.contains("| Function | Location | Doc ID | Message | Suggestion |\n"),
+            "{out}"
+        );
+        let clean = render_document_in(Locale::En, &Report::default(), Verbosity::Normal);
+        assert!(clean.contains("| 0 of 0 |"), "{clean}");
+        assert!(clean.contains("✅ No findings."), "{clean}");
+    }
+
+    #[test]
+    fn absolute_paths_are_relativized_to_root() {
+        let links = links();
+        assert_eq!(
+            links.url("/work/repo/src/my file.rs:3").as_deref(),
+            Some("https://github.com/org/repo/blob/abc123/src/my%20file.rs#L3")
+        );
+        assert_eq!(links.url("/elsewhere/x.rs:3"), None);
+    }
+}
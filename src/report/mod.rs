@@ -0,0 +1,430 @@
+//! Informe de una verificación y capa de formateadores.
+//!
+//! `Report` agrupa los hallazgos de una ejecución de `check` ya filtrados por
+//! baseline. Los formateadores (`markdown`, `html`) lo renderizan sin volver a
+//! validar, de modo que cada integración de CI reutiliza la misma salida.
+
+pub mod aggregate;
+pub mod blame;
+pub mod html;
+pub mod json;
+pub mod links;
+pub mod markdown;
+pub mod model;
+pub mod redact;
+pub mod sarif;
+pub mod suppressions;
+pub mod timing;
+
+use anyhow::{Context, Result};
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::baseline;
+use crate::config::{Config, InfoAggregation};
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::types::{
+    parse_location, CodeEntity, DocSection, Rule, Severity, ValidationResult,
+};
+use crate::core::{pipeline, validator};
+use crate::messages;
+use crate::messages::println_tr;
+use crate::parser::{code_parser, doc_parser};
+use crate::vfs;
+use timing::Timings;
+
+/// Formato de salida de `check`.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    clap::ValueEnum,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckFormat {
+    // Los textos son la ayuda de `check --format`, traducida al construirla
+    #[default]
+    #[value(help = messages::tr("Hallazgos y resumen para el terminal").into_owned())]
+    Text,
+    // Ver `sarif`
+    #[value(help = messages::tr("Log SARIF 2.1.0 para el code scanning, en stdout").into_owned())]
+    Sarif,
+    // Ver `json`
+    #[value(help = messages::tr("Informe JSON con los hallazgos y la cobertura por argumento de cada enlace, en stdout").into_owned())]
+    Json,
+    // Ver `markdown::render_document`
+    #[value(help = messages::tr("Documento Markdown con el resumen y una tabla por severidad, en stdout").into_owned())]
+    Markdown,
+}
+
+/// Hallazgos que se imprimen (`-q`/`-v` de `check` y `watch`). Los
+/// resúmenes cuentan siempre todos, se impriman o no.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Verbosity {
+    /// Solo los errores.
+    Quiet,
+    /// Todo salvo los enlaces verificados.
+    #[default]
+    Normal,
+    /// Todo, con los detalles de cada hallazgo.
+    Verbose,
+}
+
+impl Verbosity {
+    /// `-q` y `-v` (clap no deja pasar los dos juntos).
+    pub fn from_flags(quiet: bool, verbose: bool) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, true) => Verbosity::Verbose,
+            (false, false) => Verbosity::Normal,
+        }
+    }
+
+    /// Si el hallazgo se imprime.
+    pub fn shows(self, result: &ValidationResult) -> bool {
+        match self {
+            Verbosity::Quiet => result.severity == Severity::Error,
+            Verbosity::Normal => result.rule != Rule::LinkVerified,
+            Verbosity::Verbose => true,
+        }
+    }
+}
+
+/// Resultado agregado de una verificación.
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    /// Hallazgos tras aplicar el baseline.
+    pub results: Vec<ValidationResult>,
+    /// Hallazgos ocultados por el baseline.
+    pub baseline_filtered: usize,
+    /// Funciones encontradas en el código.
+    pub entity_count: usize,
+    /// Secciones encontradas en la documentación.
+    pub section_count: usize,
+    /// IDs de las secciones de la documentación.
+    pub section_ids: BTreeSet<String>,
+    /// IDs enlazados desde el código (`@docs` o `@docs-file`).
+    pub linked_ids: BTreeSet<String>,
+    /// Enlaces con secciones prose-only (`@docs-skip: args`).
+    pub prose_only_links: usize,
+    /// Funciones descartadas por `ignore_functions`.
+    pub ignored_functions: usize,
+    /// Descripciones documentadas de los argumentos (`--redact-descriptions`).
+    pub descriptions: BTreeSet<String>,
+    /// Tiempos de parseo y validación de la ejecución.
+    pub timings: Timings,
+    /// Supresiones activas de todos los mecanismos (ver `suppressions`).
+    pub suppressions: Vec<suppressions::Record>,
+    /// Argumentos de cada enlace (ver `links`); los rellenan las salidas
+    /// que los usan.
+    pub links: Vec<links::LinkRecord>,
+    /// Por qué el baseline no filtró cada hallazgo (`--explain-baseline`);
+    /// solo lo rellena `check` con `--format json`.
+    pub baseline_debug: Option<Vec<baseline::MissRecord>>,
+    /// Antigüedad de la deuda del baseline (`--debt-age`); solo la rellena
+    /// `check` con `--format json`.
+    pub debt_age: Option<baseline::DebtAgeRecord>,
+}
+
+impl Report {
+    /// Construye el informe de los hallazgos ya filtrados y lo parseado.
+    pub fn new(
+        results: Vec<ValidationResult>,
+        baseline_filtered: usize,
+        code_entities: &[CodeEntity],
+        doc_sections: &[DocSection],
+    ) -> Self {
+        Report {
+            results,
+            baseline_filtered,
+            entity_count: code_entities.len(),
+            section_count: doc_sections.len(),
+            section_ids: doc_sections.iter().map(|s| s.id.clone()).collect(),
+            linked_ids: code_entities
+                .iter()
+                .flat_map(|e| {
+                    let file_link = e.file_link.as_ref().map(|l| &l.doc_id);
+                    e.doc_id.iter().chain(file_link)
+                })
+                .cloned()
+                .collect(),
+            prose_only_links: validator::count_prose_only_links(code_entities, doc_sections),
+            ignored_functions: 0,
+            descriptions: doc_sections
+                .iter()
+                .flat_map(|s| &s.args)
+                .filter_map(|a| a.description.as_deref())
+                .map(|d| d.trim().to_string())
+                .filter(|d| !d.is_empty())
+                .collect(),
+            timings: Timings::default(),
+            suppressions: Vec::new(),
+            links: Vec::new(),
+            baseline_debug: None,
+            debt_age: None,
+        }
+    }
+
+    /// Informe de los hallazgos de `pipeline::run_validation`: sin los del
+    /// baseline y con los Info agrupados según `aggregation`. `check` filtra
+    /// el baseline por su cuenta para explicar lo que no filtró: pasa `None`
+    /// y fija `baseline_filtered` después.
+    pub fn filtered(
+        results: Vec<ValidationResult>,
+        baseline: Option<&baseline::Baseline>,
+        aggregation: InfoAggregation,
+        code_entities: &[CodeEntity],
+        doc_sections: &[DocSection],
+    ) -> Self {
+        let (results, baseline_filtered) = match baseline {
+            Some(bl) => baseline::filter_baseline(&results, bl, code_entities),
+            None => (results, 0),
+        };
+        let results = match aggregation {
+            InfoAggregation::Summarize => aggregate::summarize_info(results),
+            InfoAggregation::Off => results,
+        };
+        Report::new(results, baseline_filtered, code_entities, doc_sections)
+    }
+
+    /// Hallazgos vinculados al ID de documentación indicado.
+    pub fn for_doc_id<'a>(&'a self, doc_id: &'a str) -> impl Iterator<Item = &'a ValidationResult> {
+        self.results
+            .iter()
+            .filter(move |r| r.doc_id.as_deref() == Some(doc_id))
+    }
+
+    /// Copia con los hallazgos en el idioma de la salida
+    /// (`ValidationResult::localized`), para lo que sale de la máquina.
+    pub fn localized(&self) -> Report {
+        let mut report = self.clone();
+        report.results = self.results.iter().map(|r| r.localized()).collect();
+        report.suppressions = self.suppressions.iter().map(|s| s.localized()).collect();
+        report
+    }
+
+    /// Número de hallazgos con la severidad indicada; un resumen de
+    /// `info_aggregation` cuenta todos los que agrupa.
+    pub fn count(&self, severity: Severity) -> usize {
+        self.results
+            .iter()
+            .filter(|r| r.severity == severity)
+            .map(ValidationResult::occurrences)
+            .sum()
+    }
+
+    /// Conserva solo los hallazgos que tocan alguno de los archivos indicados,
+    /// ya sea por su ubicación en el código o por la de su sección de docs.
+    ///
+    /// `changed` debe contener rutas canónicas (ver `git::changed_files`); las
+    /// ubicaciones relativas se resuelven contra `project_root`.
+    pub fn retain_changed(&mut self, changed: &HashSet<PathBuf>, project_root: &Path) {
+        self.results.retain(|r| {
+            [&r.code_location, &r.doc_location]
+                .into_iter()
+                .flatten()
+                .filter_map(|loc| parse_location(loc))
+                .filter_map(|(path, _)| vfs::canonicalize(&project_root.join(path)).ok())
+                .any(|path| changed.contains(&path))
+        });
+    }
+}
+
+/// Coletilla de los recuentos de `check`, `ci github` y `coverage` con los
+/// enlaces prose-only; vacía si no hay ninguno.
+pub fn prose_only_note(links: usize) -> String {
+    match links {
+        0 => String::new(),
+        n => format!(
+            " ({}, sin validar argumentos)",
+            messages::PROSE_ONLY_LINKS.count(n)
+        ),
+    }
+}
+
+/// `, 3 funciones ignoradas` tras el total de funciones, si hay alguna.
+pub fn ignored_note(functions: usize) -> String {
+    match functions {
+        0 => String::new(),
+        n => format!(", {}", messages::IGNORED_FUNCTIONS.count(n)),
+    }
+}
+
+/// @docs: [build-report]
+/// Parsea código y docs, valida y aplica el baseline de `project_root`.
+pub fn build_report(
+    code_files: &[PathBuf],
+    doc_file: &Path,
+    project_root: &Path,
+) -> Result<Report> {
+    build_report_with(code_files, doc_file, project_root, None)
+}
+
+/// `build_report` con `info_aggregation` forzado (`--no-aggregate`); sin
+/// valor, el de la configuración.
+pub fn build_report_with(
+    code_files: &[PathBuf],
+    doc_file: &Path,
+    project_root: &Path,
+    info_aggregation: Option<InfoAggregation>,
+) -> Result<Report> {
+    let started = std::time::Instant::now();
+    let mut timings = Timings::default();
+    let config = Config::load(project_root)?;
+    let mut diagnostics = ParseDiagnostics::default();
+    let code_entities = parse_timed(code_files, &config, &mut diagnostics, &mut timings)?;
+    let doc_sections = timings
+        .time_parse(&config.paths.normalize(doc_file), || {
+            doc_parser::parse_docs_with_diagnostics(doc_file, &config, &mut diagnostics)
+        })
+        .context("Error al parsear el archivo de documentación")?;
+    let doc_source = doc_parser::read_markdown_file(doc_file)?;
+
+    let baseline = baseline::Baseline::load(project_root)?;
+    let (results, suppressions) = timings.time_validate(|| {
+        let inputs = pipeline::Inputs {
+            code_entities: &code_entities,
+            doc_sections: &doc_sections,
+            diagnostics: &diagnostics,
+            docs: &[pipeline::DocSource {
+                file: doc_file,
+                source: &doc_source,
+            }],
+            config: &config,
+        };
+        let validation = pipeline::run_validation(inputs, pipeline::Passes::default());
+        let suppressions = suppressions::collect(suppressions::Sources {
+            validation: &validation,
+            code_entities: &code_entities,
+            doc_sections: &doc_sections,
+            baseline: baseline.as_ref(),
+            config: &config,
+            diagnostics: &diagnostics,
+            project_root,
+        });
+        (validation.results, suppressions)
+    });
+    let mut report = Report::filtered(
+        results,
+        baseline.as_ref(),
+        info_aggregation.unwrap_or(config.info_aggregation),
+        &code_entities,
+        &doc_sections,
+    );
+    timings.total = started.elapsed();
+    report.timings = timings;
+    report.ignored_functions = diagnostics.ignored_functions.len();
+    report.suppressions = suppressions;
+    report.links = links::build(&code_entities, &doc_sections, &config);
+    Ok(report)
+}
+
+/// Ejecuta `report --html`: valida y escribe el informe, redactado (ver
+/// `redact`), en streaming.
+pub fn run_html_report(
+    code_files: &[PathBuf],
+    doc_file: &Path,
+    project_root: &Path,
+    output: &Path,
+    redact_descriptions: bool,
+    no_aggregate: bool,
+) -> Result<()> {
+    for code_file in code_files {
+        code_parser::require_file_exists(code_file, "código")?;
+    }
+    code_parser::require_file_exists(doc_file, "documentación")?;
+
+    let mut report = build_report_with(
+        code_files,
+        doc_file,
+        project_root,
+        no_aggregate.then_some(InfoAggregation::Off),
+    )?;
+    let config = Config::load(project_root)?;
+    suppressions::date_from_git(&mut report.suppressions, &config.paths, project_root);
+    let redaction = redact::Redaction::new(&config.redaction, redact_descriptions);
+    let report = redaction.apply(&report).localized();
+    crate::transaction::write_streamed(output, |out| html::write(&report, out))?;
+    println_tr!(
+        "  Informe HTML escrito ({}): {}",
+        messages::FINDINGS.count_or_zero(report.results.len()),
+        code_parser::safe_display(output)
+    );
+    Ok(())
+}
+
+/// Parsea los archivos de código uno a uno, midiendo cada uno en `timings`.
+pub fn parse_timed(
+    code_files: &[PathBuf],
+    config: &Config,
+    diagnostics: &mut ParseDiagnostics,
+    timings: &mut Timings,
+) -> Result<Vec<CodeEntity>> {
+    let mut entities = Vec::new();
+    for code_file in code_files {
+        entities.extend(timings.time_parse(&config.paths.normalize(code_file), || {
+            code_parser::parse_project_code(std::slice::from_ref(code_file), config, diagnostics)
+        })?);
+    }
+    Ok(entities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Rule;
+    use crate::vfs::MemFs;
+
+    fn finding(code: Option<&str>, doc: Option<&str>) -> ValidationResult {
+        ValidationResult {
+            severity: Severity::Warning,
+            rule: Rule::GhostArg,
+            message: "msg".into(),
+            function_name: Some("login".into()),
+            code_location: code.map(String::from),
+            doc_id: None,
+            doc_location: doc.map(String::from),
+            hint: None,
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+
+    #[test]
+    fn retain_changed_matches_code_or_doc_location() {
+        let dir = MemFs::project();
+        let code = dir.path().join("auth.ts");
+        let doc = dir.path().join("api.md");
+        let other = dir.path().join("other.ts");
+        for path in [&code, &doc, &other] {
+            dir.write(path, "");
+        }
+        let loc = |p: &Path| format!("{}:1", p.display());
+
+        let mut report = Report {
+            results: vec![
+                finding(Some(&loc(&code)), None),
+                finding(Some(&loc(&other)), Some(&loc(&doc))),
+                finding(Some(&loc(&other)), None),
+                finding(Some("auth.ts:1"), None),
+            ],
+            ..Report::default()
+        };
+        let changed: HashSet<PathBuf> = [&code, &doc]
+            .into_iter()
+            .map(|p| vfs::canonicalize(p).unwrap())
+            .collect();
+
+        report.retain_changed(&changed, dir.path());
+        assert_eq!(report.results.len(), 3);
+    }
+}
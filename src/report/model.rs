@@ -0,0 +1,470 @@
+//! `docsguard export-model`: el modelo enlazado de docs y código.
+//!
+//! Para generadores de sitios que quieren pintar la referencia con una marca
+//! de "verificado contra el código" sin re-parsear el Markdown: cada sección
+//! con las funciones que la enlazan, el estado de cada argumento
+//! (`documented`, `ghost`, `missing`, `mismatched`) y los hallazgos de la
+//! validación, todo con archivo y línea para enlazar al código fuente. Los
+//! estados de los argumentos salen de comparar firma y sección como lo hace
+//! `validator::validate_args`; los de funciones y secciones, de los
+//! hallazgos tras supresiones y niveles de `rules:`, sin baseline (una marca
+//! de verificado no debe ocultar lo conocido).
+//!
+//! El formato es estable: `schema_version` sube cuando cambia, y el test de
+//! snapshot de este módulo lo protege. `examples/render_model.rs` pinta una
+//! página HTML a partir de él.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::config::{ArgNamesConfig, Config};
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::pipeline;
+use crate::core::types::{CodeEntity, DocSection, Rule, Severity, ValidationResult};
+use crate::core::validator::{self, normalize_type};
+use crate::exit::Outcome;
+use crate::parser::{code_parser, doc_parser};
+
+/// Versión del formato; sube cuando cambia la forma del JSON.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Formato de `export-model`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ModelFormat {
+    Json,
+}
+
+/// Grafo sección → funciones enlazadas → argumentos.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocsModel {
+    pub schema_version: u32,
+    /// Versión de docsguard que lo generó.
+    pub generator: String,
+    /// Secciones en el orden del archivo de docs.
+    pub sections: Vec<SectionNode>,
+    /// Funciones sin `@docs` (ni `@docs-file`).
+    pub unlinked_functions: Vec<FunctionRef>,
+}
+
+/// Archivo (relativo a la raíz del proyecto) y línea.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceLocation {
+    pub file: PathBuf,
+    pub line: usize,
+}
+
+/// Estado de una sección o de uno de sus enlaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// Sin errores ni advertencias.
+    Verified,
+    Warning,
+    Error,
+    /// Sección que ninguna función enlaza.
+    Orphan,
+}
+
+/// Estado de un argumento en un enlace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArgStatus {
+    /// En la firma y en la sección, con el mismo tipo (o sin tipo en alguna).
+    Documented,
+    /// En la sección pero no en la firma.
+    Ghost,
+    /// En la firma pero no en la sección.
+    Missing,
+    /// En las dos con tipos distintos tras normalizarlos.
+    Mismatched,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SectionNode {
+    pub id: String,
+    pub title: Option<String>,
+    pub location: SourceLocation,
+    pub status: Status,
+    /// `@docs-skip: args`: los argumentos no se comparan y `args` de sus
+    /// funciones queda vacío.
+    pub prose_only: bool,
+    pub functions: Vec<LinkedFunction>,
+    /// Hallazgos de la sección que no son de una función (huérfana, anclas…).
+    pub findings: Vec<Finding>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinkedFunction {
+    pub name: String,
+    pub location: SourceLocation,
+    pub status: Status,
+    pub args: Vec<ArgNode>,
+    /// Hallazgos del enlace, sin el Info `link-verified`.
+    pub findings: Vec<Finding>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArgNode {
+    pub name: String,
+    pub status: ArgStatus,
+    pub code_type: Option<String>,
+    pub doc_type: Option<String>,
+    /// Línea de la sección donde se documenta.
+    pub doc_line: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionRef {
+    pub name: String,
+    pub location: SourceLocation,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Finding {
+    pub rule: Rule,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl From<&ValidationResult> for Finding {
+    fn from(result: &ValidationResult) -> Self {
+        Finding {
+            rule: result.rule,
+            severity: result.severity,
+            message: result.message.clone(),
+        }
+    }
+}
+
+/// Peor estado de unos hallazgos.
+fn status_of<'a>(findings: impl IntoIterator<Item = &'a Finding>) -> Status {
+    findings
+        .into_iter()
+        .fold(Status::Verified, |status, f| match (status, f.severity) {
+            (_, Severity::Error) | (Status::Error, _) => Status::Error,
+            (_, Severity::Warning) | (Status::Warning, _) => Status::Warning,
+            _ => status,
+        })
+}
+
+/// Estado de cada argumento del enlace de `entity` con `section`: los de la
+/// sección en su orden y después los de la firma que no documenta.
+fn arg_statuses(
+    entity: &CodeEntity,
+    section: &DocSection,
+    arg_names: &ArgNamesConfig,
+) -> Vec<ArgNode> {
+    let mut args = Vec::new();
+    let mut documented = Vec::new();
+    for doc_arg in &section.args {
+        // El mismo emparejamiento que `validate_args`
+        let code_arg = validator::code_arg_for(entity, section, doc_arg, arg_names);
+        let status = match code_arg {
+            None => ArgStatus::Ghost,
+            Some(code_arg) => {
+                documented.push(code_arg.name.as_str());
+                match (&code_arg.type_name, &doc_arg.type_name) {
+                    (Some(code), Some(doc)) if normalize_type(code) != normalize_type(doc) => {
+                        ArgStatus::Mismatched
+                    }
+                    _ => ArgStatus::Documented,
+                }
+            }
+        };
+        args.push(ArgNode {
+            name: doc_arg.name.clone(),
+            status,
+            code_type: code_arg.and_then(|a| a.type_name.clone()),
+            doc_type: doc_arg.type_name.clone(),
+            doc_line: doc_arg.line,
+        });
+    }
+    for code_arg in &entity.args {
+        if !documented.contains(&code_arg.name.as_str()) {
+            args.push(ArgNode {
+                name: code_arg.name.clone(),
+                status: ArgStatus::Missing,
+                code_type: code_arg.type_name.clone(),
+                doc_type: None,
+                doc_line: None,
+            });
+        }
+    }
+    args
+}
+
+/// Construye el modelo de lo parseado y los hallazgos ya validados.
+pub fn build_model(
+    code_entities: &[CodeEntity],
+    doc_sections: &[DocSection],
+    results: &[ValidationResult],
+    config: &Config,
+) -> DocsModel {
+    let location = |file: &Path, line: usize| SourceLocation {
+        file: config.paths.normalize(file),
+        line,
+    };
+    let sections = doc_sections
+        .iter()
+        .map(|section| {
+            let section_location = section.location();
+            let functions: Vec<LinkedFunction> = code_entities
+                .iter()
+                .filter(|e| e.doc_id.as_deref() == Some(section.id.as_str()))
+                .map(|entity| {
+                    let entity_location = entity.location();
+                    let findings: Vec<Finding> = results
+                        .iter()
+                        .filter(|r| {
+                            r.rule != Rule::LinkVerified
+                                && r.code_location.as_deref() == Some(entity_location.as_str())
+                                && r.doc_location
+                                    .as_deref()
+                                    .is_none_or(|l| l == section_location)
+                        })
+                        .map(Finding::from)
+                        .collect();
+                    LinkedFunction {
+                        name: entity.name.clone(),
+                        location: location(&entity.file_path, entity.line),
+                        status: status_of(&findings),
+                        args: if section.is_prose_only() {
+                            Vec::new()
+                        } else {
+                            arg_statuses(entity, section, &config.arg_names)
+                        },
+                        findings,
+                    }
+                })
+                .collect();
+            let findings: Vec<Finding> = results
+                .iter()
+                .filter(|r| {
+                    r.code_location.is_none()
+                        && r.doc_location.as_deref() == Some(section_location.as_str())
+                })
+                .map(Finding::from)
+                .collect();
+            let status = if functions.is_empty() {
+                Status::Orphan
+            } else {
+                status_of(functions.iter().flat_map(|f| &f.findings).chain(&findings))
+            };
+            SectionNode {
+                id: section.id.clone(),
+                title: section.title.clone(),
+                location: location(&section.file_path, section.line),
+                status,
+                prose_only: section.is_prose_only(),
+                functions,
+                findings,
+            }
+        })
+        .collect();
+    let unlinked_functions = code_entities
+        .iter()
+        .filter(|e| e.doc_id.is_none() && e.file_link.is_none())
+        .map(|e| FunctionRef {
+            name: e.name.clone(),
+            location: location(&e.file_path, e.line),
+        })
+        .collect();
+    DocsModel {
+        schema_version: SCHEMA_VERSION,
+        generator: format!("docsguard {}", env!("CARGO_PKG_VERSION")),
+        sections,
+        unlinked_functions,
+    }
+}
+
+/// Ejecuta `export-model`: valida como `check` sin flags e imprime el modelo.
+pub fn run_export_model(
+    code_files: &[PathBuf],
+    doc_file: &Path,
+    project_root: &Path,
+    format: ModelFormat,
+) -> Result<Outcome> {
+    for code_file in code_files {
+        code_parser::require_file_exists(code_file, "código")?;
+    }
+    code_parser::require_file_exists(doc_file, "documentación")?;
+
+    let config = Config::load(project_root)?;
+    let mut diagnostics = ParseDiagnostics::default();
+    let code_entities = code_parser::parse_project_code(code_files, &config, &mut diagnostics)?;
+    let doc_sections = doc_parser::parse_docs_with_diagnostics(doc_file, &config, &mut diagnostics)
+        .context("Error al parsear el archivo de documentación")?;
+    let doc_source = doc_parser::read_markdown_file(doc_file)?;
+    let results = pipeline::run_validation(
+        pipeline::Inputs {
+            code_entities: &code_entities,
+            doc_sections: &doc_sections,
+            diagnostics: &diagnostics,
+            docs: &[pipeline::DocSource {
+                file: doc_file,
+                source: &doc_source,
+            }],
+            config: &config,
+        },
+        pipeline::Passes::default(),
+    )
+    .results;
+    let model = build_model(&code_entities, &doc_sections, &results, &config);
+    match format {
+        ModelFormat::Json => println!("{}", serde_json::to_string_pretty(&model)?),
+    }
+    Ok(Outcome::Clean)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::MemFs;
+
+    const CODE: &str = "\
+/// @docs: [auth-login]
+export function login(username: string, remember: boolean, retries: number) {}
+
+/// @docs: [auth-logout]
+export function logout() {}
+
+export function helper() {}
+";
+
+    const DOCS: &str = "\
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Usuario |
+| retries | string | Reintentos |
+| token | string | Ya no existe |
+
+<!-- @docs-id: auth-logout -->
+## logout
+
+<!-- @docs-id: auth-refresh -->
+## refresh
+";
+
+    fn model() -> DocsModel {
+        let fs = MemFs::new();
+        fs.write("src/auth.ts", CODE);
+        fs.write("docs/api.md", DOCS);
+        crate::vfs::scoped(fs, || {
+            let config = Config::default();
+            let mut diagnostics = ParseDiagnostics::default();
+            let entities = code_parser::parse_project_code(
+                &[PathBuf::from("src/auth.ts")],
+                &config,
+                &mut diagnostics,
+            )
+            .unwrap();
+            let doc_file = Path::new("docs/api.md");
+            let sections =
+                doc_parser::parse_docs_with_diagnostics(doc_file, &config, &mut diagnostics)
+                    .unwrap();
+            let results = pipeline::run_validation(
+                pipeline::Inputs {
+                    code_entities: &entities,
+                    doc_sections: &sections,
+                    diagnostics: &diagnostics,
+                    docs: &[pipeline::DocSource {
+                        file: doc_file,
+                        source: DOCS,
+                    }],
+                    config: &config,
+                },
+                pipeline::Passes::default(),
+            )
+            .results;
+            build_model(&entities, &sections, &results, &config)
+        })
+    }
+
+    #[test]
+    fn args_are_classified_per_link_and_statuses_come_from_findings() {
+        let model = model();
+        let statuses: Vec<_> = model.sections[0].functions[0]
+            .args
+            .iter()
+            .map(|a| (a.name.as_str(), a.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            [
+                ("username", ArgStatus::Documented),
+                ("retries", ArgStatus::Mismatched),
+                ("token", ArgStatus::Ghost),
+                ("remember", ArgStatus::Missing),
+            ]
+        );
+        assert_eq!(model.sections[0].status, Status::Error);
+        assert_eq!(model.sections[1].status, Status::Verified);
+        assert_eq!(model.sections[2].status, Status::Orphan);
+        assert_eq!(model.sections[2].findings[0].rule, Rule::OrphanSection);
+        assert_eq!(model.unlinked_functions[0].name, "helper");
+        assert_eq!(
+            model.unlinked_functions[0].location,
+            SourceLocation {
+                file: PathBuf::from("src/auth.ts"),
+                line: 7
+            }
+        );
+    }
+
+    /// Protege el formato: un cambio aquí es un cambio de `SCHEMA_VERSION`.
+    #[test]
+    fn serialized_model_matches_snapshot() {
+        let mut model = model();
+        model.generator = "docsguard X.Y.Z".into();
+        assert_eq!(
+            serde_json::to_string_pretty(&model.sections[1]).unwrap(),
+            r#"{
+  "id": "auth-logout",
+  "title": "logout",
+  "location": {
+    "file": "docs/api.md",
+    "line": 10
+  },
+  "status": "verified",
+  "prose_only": false,
+  "functions": [
+    {
+      "name": "logout",
+      "location": {
+        "file": "src/auth.ts",
+        "line": 5
+      },
+      "status": "verified",
+      "args": [],
+      "findings": []
+    }
+  ],
+  "findings": []
+}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&model.sections[0].functions[0].args[1]).unwrap(),
+            r#"{"name":"retries","status":"mismatched","code_type":"number","doc_type":"string","doc_line":7}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&model.sections[2].findings).unwrap(),
+            r#"[{"rule":"orphan-section","severity":"Warning","message":"Sección de documentación 'refresh' no está vinculada desde ninguna función."}]"#
+        );
+        let json = serde_json::to_string(&DocsModel {
+            sections: Vec::new(),
+            ..model.clone()
+        })
+        .unwrap();
+        assert_eq!(
+            json,
+            r#"{"schema_version":1,"generator":"docsguard X.Y.Z","sections":[],"unlinked_functions":[{"name":"helper","location":{"file":"src/auth.ts","line":7}}]}"#
+        );
+        // Lo exportado se vuelve a leer (lo que hacen los generadores)
+        let json = serde_json::to_string(&model).unwrap();
+        assert_eq!(serde_json::from_str::<DocsModel>(&json).unwrap(), model);
+    }
+}
@@ -0,0 +1,373 @@
+//! Redacción de los informes que salen de la máquina: anotaciones, resumen de
+//! job y comentario de PR de `ci github`, `report --html`, el documento
+//! Markdown de `check --report`/`--format markdown` y los informes de
+//! `check --format sarif` y `--format json`.
+//!
+//! Se aplica en la capa de formateadores sobre una copia del `Report`; la
+//! salida de texto de `check` y quien consuma el `Report` en el proceso ven los
+//! datos completos. Cada coincidencia de `redaction.patterns` en mensajes,
+//! sugerencias, procedencias, nombres de función, IDs de docs y ubicaciones
+//! (también las de los enlaces, de `baseline_debug` y de `debt_age` del
+//! informe JSON) se sustituye por `█`. Con `--redact-descriptions`
+//! las descripciones documentadas de los argumentos desaparecen igual, aunque
+//! no estén en la lista; nombres y tipos se conservan.
+
+use serde::{Deserialize, Serialize};
+
+use super::links::LinkRecord;
+use super::Report;
+use crate::baseline::{BaselineEntry, MissRecord};
+use crate::core::types::ValidationResult;
+
+/// Sustituto de cada coincidencia.
+pub const REDACTED: &str = "█";
+
+/// Bloque `redaction:` de `.docsguard/config.yaml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RedactionConfig {
+    /// Subcadenas literales o globs simples (`*`: tramo sin espacios, `?`: un
+    /// carácter). Sin distinguir mayúsculas.
+    #[serde(default)]
+    pub patterns: Vec<String>,
+}
+
+/// Elemento de un patrón compilado.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Char(char),
+    /// `?`: un carácter que no es espacio.
+    One,
+    /// `*`: cero o más caracteres que no son espacio.
+    Run,
+}
+
+/// Patrones compilados de una redacción.
+#[derive(Debug, Clone, Default)]
+pub struct Redaction {
+    patterns: Vec<Vec<Token>>,
+    descriptions: bool,
+}
+
+impl Redaction {
+    /// Redacción con los patrones de la configuración y, si `descriptions`,
+    /// las descripciones documentadas del informe.
+    pub fn new(config: &RedactionConfig, descriptions: bool) -> Self {
+        let patterns = config
+            .patterns
+            .iter()
+            .filter(|p| !p.trim().is_empty())
+            .map(|p| {
+                p.chars()
+                    .map(|c| match c {
+                        '*' => Token::Run,
+                        '?' => Token::One,
+                        c => Token::Char(c),
+                    })
+                    .collect()
+            })
+            .collect();
+        Redaction {
+            patterns,
+            descriptions,
+        }
+    }
+
+    /// Indica si la redacción no cambia nada.
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty() && !self.descriptions
+    }
+
+    /// Copia del informe con los textos redactados.
+    pub fn apply(&self, report: &Report) -> Report {
+        let mut redacted = report.clone();
+        if self.is_empty() {
+            return redacted;
+        }
+        let mut patterns = self.patterns.clone();
+        if self.descriptions {
+            // Las descripciones son literales: `*` o `?` en ellas no son comodines
+            patterns.extend(
+                report
+                    .descriptions
+                    .iter()
+                    .map(|d| d.chars().map(Token::Char).collect()),
+            );
+        }
+        for result in &mut redacted.results {
+            redact_result(result, &patterns);
+        }
+        for link in &mut redacted.links {
+            redact_link(link, &patterns);
+        }
+        for miss in redacted.baseline_debug.iter_mut().flatten() {
+            redact_miss(miss, &patterns);
+        }
+        if let Some(debt) = &mut redacted.debt_age {
+            for listed in debt.oldest.iter_mut().chain(&mut debt.expiring) {
+                redact_entry(&mut listed.entry, &patterns);
+            }
+        }
+        for suppression in &mut redacted.suppressions {
+            suppression.silences = redact(&suppression.silences, &patterns);
+            if let Some(reason) = &mut suppression.reason {
+                *reason = redact(reason, &patterns);
+            }
+        }
+        redacted
+    }
+}
+
+fn redact_result(result: &mut ValidationResult, patterns: &[Vec<Token>]) {
+    result.message = redact(&result.message, patterns);
+    for text in [
+        &mut result.hint,
+        &mut result.provenance,
+        &mut result.function_name,
+        &mut result.doc_id,
+        &mut result.code_location,
+        &mut result.doc_location,
+        &mut result.target,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        *text = redact(text, patterns);
+    }
+    for related in &mut result.related {
+        related.location = redact(&related.location, patterns);
+        related.message = redact(&related.message, patterns);
+    }
+    if let Some(summary) = &mut result.summary {
+        for function in &mut summary.functions {
+            *function = redact(function, patterns);
+        }
+    }
+    if let Some(cause) = &mut result.cause {
+        cause.subject = redact(&cause.subject, patterns);
+    }
+}
+
+/// Los registros de `links` del informe JSON: nombres, tipos y ubicaciones.
+fn redact_link(link: &mut LinkRecord, patterns: &[Vec<Token>]) {
+    link.function = redact(&link.function, patterns);
+    link.doc_id = redact(&link.doc_id, patterns);
+    let locations = [&mut link.location, &mut link.doc_location]
+        .into_iter()
+        .chain(link.args.iter_mut().filter_map(|a| a.doc_location.as_mut()));
+    for location in locations {
+        location.file = redact(&location.file.to_string_lossy(), patterns).into();
+    }
+    for arg in &mut link.args {
+        arg.name = redact(&arg.name, patterns);
+        for type_name in [&mut arg.code_type, &mut arg.doc_type]
+            .into_iter()
+            .flatten()
+        {
+            *type_name = redact(type_name, patterns);
+        }
+    }
+}
+
+/// Los registros de `baseline_debug`: ubicaciones y las dos entradas.
+fn redact_miss(miss: &mut MissRecord, patterns: &[Vec<Token>]) {
+    if let Some(location) = &mut miss.location {
+        *location = redact(location, patterns);
+    }
+    redact_entry(&mut miss.computed, patterns);
+    if let Some(nearest) = &mut miss.nearest {
+        nearest.location = redact(&nearest.location, patterns);
+        redact_entry(&mut nearest.entry, patterns);
+    }
+}
+
+fn redact_entry(entry: &mut BaselineEntry, patterns: &[Vec<Token>]) {
+    entry.message_fingerprint = redact(&entry.message_fingerprint, patterns);
+    for text in [
+        &mut entry.function_name,
+        &mut entry.doc_id,
+        &mut entry.reason,
+        &mut entry.file,
+        &mut entry.signature,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        *text = redact(text, patterns);
+    }
+}
+
+/// Sustituye por `REDACTED` la coincidencia más larga que empieza en cada
+/// posición, de izquierda a derecha. Las coincidencias vacías no cuentan.
+fn redact(text: &str, patterns: &[Vec<Token>]) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let end = patterns
+            .iter()
+            .filter_map(|p| match_at(p, &chars, i))
+            .filter(|&end| end > i)
+            .max();
+        match end {
+            Some(end) => {
+                out.push_str(REDACTED);
+                i = end;
+            }
+            None => {
+                out.push(chars[i]);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Fin de la coincidencia más larga de `pattern` en `text[start..]`.
+fn match_at(pattern: &[Token], text: &[char], start: usize) -> Option<usize> {
+    let Some((&token, rest)) = pattern.split_first() else {
+        return Some(start);
+    };
+    match token {
+        Token::Run => {
+            let run_end = text[start..]
+                .iter()
+                .position(|c| c.is_whitespace())
+                .map_or(text.len(), |n| start + n);
+            (start..=run_end)
+                .rev()
+                .find_map(|next| match_at(rest, text, next))
+        }
+        Token::One => text
+            .get(start)
+            .filter(|c| !c.is_whitespace())
+            .and_then(|_| match_at(rest, text, start + 1)),
+        Token::Char(expected) => text
+            .get(start)
+            .filter(|&&c| c.to_lowercase().eq(expected.to_lowercase()))
+            .and_then(|_| match_at(rest, text, start + 1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{Rule, Severity};
+    use crate::report::{html, markdown};
+
+    fn redaction(patterns: &[&str], descriptions: bool) -> Redaction {
+        let config = RedactionConfig {
+            patterns: patterns.iter().map(|p| p.to_string()).collect(),
+        };
+        Redaction::new(&config, descriptions)
+    }
+
+    fn redact_text(redaction: &Redaction, text: &str) -> String {
+        redact(text, &redaction.patterns)
+    }
+
+    fn finding(message: &str, hint: &str) -> ValidationResult {
+        ValidationResult {
+            severity: Severity::Error,
+            rule: Rule::PlaceholderDescription,
+            message: message.into(),
+            function_name: Some("login".into()),
+            code_location: Some("src/auth.ts:3".into()),
+            doc_id: Some("auth-login".into()),
+            doc_location: Some("docs/api.md:7".into()),
+            hint: Some(hint.into()),
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+
+    #[test]
+    fn literals_and_globs_are_replaced_case_insensitively() {
+        let redaction = redaction(&["project falcon", "ACME-*-key", "v?.internal"], false);
+        assert_eq!(
+            redact_text(
+                &redaction,
+                "Usa Project Falcon con acme-prod-key en v2.internal."
+            ),
+            "Usa █ con █ en █."
+        );
+        // `*` no cruza espacios: la frase entera no desaparece
+        assert_eq!(
+            redact_text(&redaction, "ACME-a b-key ACME--key"),
+            "ACME-a b-key █"
+        );
+    }
+
+    #[test]
+    fn empty_and_whitespace_patterns_are_ignored() {
+        let redaction = redaction(&["", "  "], false);
+        assert!(redaction.is_empty());
+        assert_eq!(
+            redact_text(&redaction, "Nada que ocultar"),
+            "Nada que ocultar"
+        );
+    }
+
+    #[test]
+    fn descriptions_are_dropped_only_on_request_and_as_literals() {
+        let report = Report {
+            results: vec![finding(
+                "La descripción de 'token' es de relleno: \"TODO: ver *wiki* interna\".",
+                "Describe qué espera 'token'.",
+            )],
+            descriptions: ["TODO: ver *wiki* interna".to_string()].into(),
+            ..Report::default()
+        };
+        let kept = redaction(&[], false).apply(&report);
+        assert_eq!(kept.results[0].message, report.results[0].message);
+
+        let dropped = redaction(&[], true).apply(&report);
+        assert_eq!(
+            dropped.results[0].message,
+            "La descripción de 'token' es de relleno: \"█\"."
+        );
+        assert_eq!(dropped.results[0].function_name.as_deref(), Some("login"));
+    }
+
+    #[test]
+    fn no_configured_pattern_survives_into_machine_formats() {
+        let patterns = ["Project Falcon", "secret-*", "intranet.acme"];
+        let report = Report {
+            results: vec![
+                finding(
+                    "Sección de Project Falcon sin enlace (secret-42).",
+                    "Consulta https://intranet.acme/falcon",
+                ),
+                ValidationResult {
+                    severity: Severity::Warning,
+                    provenance: Some("tabla de project falcon".into()),
+                    ..finding("Ver SECRET-token", "sin pista")
+                },
+            ],
+            ..Report::default()
+        };
+        let original = markdown::render(&report, None);
+        assert!(original.contains("Project Falcon"));
+
+        let redacted = redaction(&patterns, false).apply(&report);
+        let mut page = Vec::new();
+        html::write(&redacted, &mut page).unwrap();
+        let outputs = [
+            markdown::render(&redacted, None),
+            String::from_utf8(page).unwrap(),
+            format!("{:#?}", redacted.results),
+        ];
+        for output in &outputs {
+            let lower = output.to_lowercase();
+            for needle in ["project falcon", "secret-", "intranet.acme"] {
+                assert!(!lower.contains(needle), "'{needle}' en:\n{output}");
+            }
+        }
+        // El informe original no se toca
+        assert!(report.results[0].message.contains("Project Falcon"));
+    }
+}
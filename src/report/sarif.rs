@@ -0,0 +1,280 @@
+//! Log SARIF 2.1.0 de `check --format sarif`.
+//!
+//! Lo ingieren el code scanning de GitHub (`github/codeql-action/upload-sarif`)
+//! y otros sistemas de CI. Cada hallazgo es un `result` con el ID de su regla
+//! (`ghost-arg`, `DG001`…), el nivel según su severidad (`Info` es `note`) y
+//! la ubicación `archivo:línea` del código o, si no la tiene, de la sección.
+//! La otra ubicación, el punto de inserción (`target`) y los detalles de
+//! `related` van en `relatedLocations`, cada uno con su mensaje.
+//! `tool.driver.rules` declara solo las reglas que aparecen, en el orden de
+//! `Rule::ALL`, para que dos ejecuciones con los mismos hallazgos den el
+//! mismo log. Los textos salen en el idioma de la salida. Con
+//! `check --with-blame`, la probable causa (`report::blame`) va en
+//! `properties.probableCause`.
+
+use serde_json::{json, Value};
+
+use crate::core::types::{parse_location, Rule, Severity, ValidationResult};
+use crate::messages::tr;
+
+/// Esquema declarado en `$schema`.
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Log SARIF con los hallazgos de una ejecución.
+pub fn render(results: &[ValidationResult]) -> Value {
+    let rules: Vec<Rule> = Rule::ALL
+        .into_iter()
+        .filter(|rule| results.iter().any(|r| r.rule == *rule))
+        .collect();
+    json!({
+        "$schema": SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "DocsGuard",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "semanticVersion": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_HOMEPAGE"),
+                    "rules": rules.iter().map(|rule| json!({ "id": rule.id() })).collect::<Vec<_>>(),
+                }
+            },
+            "results": results
+                .iter()
+                .map(|r| result(r, rules.iter().position(|rule| *rule == r.rule)))
+                .collect::<Vec<_>>(),
+        }]
+    })
+}
+
+/// `result` de un hallazgo; `rule_index` es su posición en `rules`.
+fn result(finding: &ValidationResult, rule_index: Option<usize>) -> Value {
+    let finding = &finding.localized();
+    let mut text = finding.message.clone();
+    if let Some(hint) = &finding.hint {
+        text.push_str(&format!("\nSugerencia: {}", hint));
+    }
+
+    let mut out = json!({
+        "ruleId": finding.rule.id(),
+        "ruleIndex": rule_index,
+        "level": level(finding.severity),
+        "message": { "text": tr(&text) },
+    });
+    let primary = finding
+        .code_location
+        .as_deref()
+        .or(finding.doc_location.as_deref());
+    if let Some(location) = primary.and_then(|l| location(l, None)) {
+        out["locations"] = json!([location]);
+    }
+
+    let section = finding
+        .doc_location
+        .as_deref()
+        .filter(|_| finding.code_location.is_some())
+        .map(|l| (l, tr("Sección de la documentación").into_owned()));
+    let target = finding
+        .target
+        .as_deref()
+        .map(|l| (l, tr("Punto de inserción").into_owned()));
+    let related: Vec<Value> = section
+        .into_iter()
+        .chain(target)
+        .chain(
+            finding
+                .related
+                .iter()
+                .map(|r| (r.location.as_str(), r.message.clone())),
+        )
+        .filter_map(|(l, message)| location(l, Some(message)))
+        .enumerate()
+        .map(|(id, mut location)| {
+            location["id"] = json!(id);
+            location
+        })
+        .collect();
+    if !related.is_empty() {
+        out["relatedLocations"] = json!(related);
+    }
+    // Solo la tiene con `check --with-blame`
+    if let Some(cause) = &finding.cause {
+        out["properties"] = json!({ "probableCause": cause });
+    }
+    out
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+/// `physicalLocation` de `archivo:línea`; sin línea (o con la 0), solo el
+/// archivo.
+fn location(location: &str, message: Option<String>) -> Option<Value> {
+    let (file, line) = match parse_location(location) {
+        Some((file, line)) => (file.to_string_lossy().into_owned(), Some(line)),
+        None if !location.is_empty() => (location.to_string(), None),
+        None => return None,
+    };
+    let mut physical = json!({ "artifactLocation": { "uri": uri(&file) } });
+    if let Some(line) = line.filter(|&l| l > 0) {
+        physical["region"] = json!({ "startLine": line });
+    }
+    let mut out = json!({ "physicalLocation": physical });
+    if let Some(text) = message {
+        out["message"] = json!({ "text": text });
+    }
+    Some(out)
+}
+
+/// Referencia URI de una ruta: relativa a la raíz (la resuelve quien ingiere
+/// el log) o `file://` si es absoluta, con `/` y los caracteres reservados
+/// escapados.
+fn uri(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let path = path.strip_prefix("./").unwrap_or(&path);
+    let mut out = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~/:".contains(&byte) {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    if out.starts_with('/') {
+        format!("file://{}", out)
+    } else if out.as_bytes().get(1) == Some(&b':') {
+        // Unidad de Windows (`C:/repo/…`)
+        format!("file:///{}", out)
+    } else {
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Related;
+
+    fn finding(rule: Rule, severity: Severity) -> ValidationResult {
+        ValidationResult {
+            severity,
+            rule,
+            message: "Argumento 'id' no documentado".into(),
+            function_name: Some("login".into()),
+            code_location: Some("src/auth.ts:3".into()),
+            doc_id: Some("auth-login".into()),
+            doc_location: Some("docs/api.md:10".into()),
+            hint: Some("Añade 'id' a la tabla".into()),
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+
+    #[test]
+    fn findings_become_results_with_rule_level_and_location() {
+        let mut orphan = finding(Rule::OrphanSection, Severity::Info);
+        orphan.code_location = None;
+        orphan.related = vec![Related {
+            location: "docs/api.md:20".into(),
+            message: "Otra sección".into(),
+        }];
+        let log = render(&[
+            finding(Rule::MissingArg, Severity::Error),
+            orphan,
+            finding(Rule::MissingArg, Severity::Warning),
+        ]);
+
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "DocsGuard");
+        assert_eq!(run["tool"]["driver"]["version"], env!("CARGO_PKG_VERSION"));
+        // Orden de Rule::ALL, sin repetir
+        assert_eq!(
+            run["tool"]["driver"]["rules"],
+            json!([{ "id": "orphan-section" }, { "id": "missing-arg" }])
+        );
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0],
+            json!({
+                "ruleId": "missing-arg",
+                "ruleIndex": 1,
+                "level": "error",
+                "message": { "text": "Argumento 'id' no documentado\nSugerencia: Añade 'id' a la tabla" },
+                "locations": [{ "physicalLocation": {
+                    "artifactLocation": { "uri": "src/auth.ts" },
+                    "region": { "startLine": 3 },
+                } }],
+                "relatedLocations": [{
+                    "id": 0,
+                    "message": { "text": "Sección de la documentación" },
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": "docs/api.md" },
+                        "region": { "startLine": 10 },
+                    },
+                }],
+            })
+        );
+        // Sin código, la sección es la ubicación principal
+        assert_eq!(results[1]["level"], "note");
+        assert_eq!(
+            results[1]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "docs/api.md"
+        );
+        assert_eq!(
+            results[1]["relatedLocations"][0]["message"]["text"],
+            "Otra sección"
+        );
+        assert_eq!(results[2]["level"], "warning");
+    }
+
+    #[test]
+    fn the_insertion_target_is_a_related_location() {
+        let mut missing = finding(Rule::MissingDocSection, Severity::Error);
+        missing.doc_location = None;
+        missing.target = Some("docs/api.md:42".into());
+        let log = render(&[missing]);
+        let result = &log["runs"][0]["results"][0];
+        assert_eq!(
+            result["relatedLocations"],
+            json!([{
+                "id": 0,
+                "message": { "text": "Punto de inserción" },
+                "physicalLocation": {
+                    "artifactLocation": { "uri": "docs/api.md" },
+                    "region": { "startLine": 42 },
+                },
+            }])
+        );
+        // Fuera del mensaje, que se traduce y se redacta como texto
+        assert_eq!(
+            result["message"]["text"],
+            "Argumento 'id' no documentado\nSugerencia: Añade 'id' a la tabla"
+        );
+    }
+
+    #[test]
+    fn findings_without_location_have_none_and_paths_become_uris() {
+        let mut slow = finding(Rule::SlowRun, Severity::Warning);
+        slow.code_location = None;
+        slow.doc_location = None;
+        let log = render(&[slow]);
+        assert!(log["runs"][0]["results"][0].get("locations").is_none());
+
+        assert_eq!(uri("./src/mi api.ts"), "src/mi%20api.ts");
+        assert_eq!(uri("/repo/src/a.ts"), "file:///repo/src/a.ts");
+        assert_eq!(uri("C:\\repo\\a.ts"), "file:///C:/repo/a.ts");
+        assert_eq!(uri("docs/año.md"), "docs/a%C3%B1o.md");
+    }
+}
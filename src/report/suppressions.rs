@@ -0,0 +1,569 @@
+//! Todo lo que una ejecución decide no ver: `suppressions list` y la pestaña
+//! "Supresiones" de `report --html`.
+//!
+//! Cada mecanismo de silenciado filtra por su cuenta: las directivas
+//! `docsguard-ignore`, las entradas del baseline, `arg_exceptions`, las
+//! secciones `@docs-no-link` y los patrones de `ignore_functions`. `collect`
+//! los reúne en una lista con la ubicación de cada uno, su motivo si se
+//! anotó, el día en que se añadió y si silenció algo en esta ejecución.
+//!
+//! Solo el baseline guarda la fecha de alta; la de las directivas y los
+//! marcadores sale de `git blame` (`date_from_git`), y la de lo que vive en
+//! `config.yaml` no se conoce.
+
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::baseline::{self, Baseline};
+use crate::config::{self, Config};
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::pipeline::Validation;
+use crate::core::suppression::ALL_RULES;
+use crate::core::types::{parse_location, CodeEntity, DocSection};
+use crate::dates;
+use crate::messages::{self, println_tr, tr};
+use crate::parser::code_parser;
+use crate::paths::ProjectPaths;
+
+/// Mecanismo de silenciado, en el orden en que se listan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Mechanism {
+    /// Directiva `docsguard-ignore` en el comentario de una función.
+    Inline,
+    /// Entrada de `.docsguard/baseline.yaml`.
+    Baseline,
+    /// Entrada de `arg_exceptions`.
+    ArgException,
+    /// Sección `@docs-no-link`.
+    NoLink,
+    /// Patrón de `ignore_functions` (o `--ignore-fn`).
+    IgnoreFunction,
+}
+
+impl Mechanism {
+    /// Cómo se escribe en el código, las docs o la configuración.
+    pub fn label(self) -> &'static str {
+        match self {
+            Mechanism::Inline => "docsguard-ignore",
+            Mechanism::Baseline => "baseline",
+            Mechanism::ArgException => "arg_exceptions",
+            Mechanism::NoLink => "@docs-no-link",
+            Mechanism::IgnoreFunction => "ignore_functions",
+        }
+    }
+}
+
+/// Orden de `suppressions list`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum SuppressionSort {
+    /// Por mecanismo y, dentro de cada uno, por ubicación.
+    #[default]
+    Mechanism,
+    /// Por archivo y línea.
+    Location,
+    /// Las más antiguas primero; las de fecha desconocida, al final.
+    Age,
+}
+
+/// Formato de `suppressions list`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ListFormat {
+    /// Una supresión por bloque, para el terminal.
+    #[default]
+    Text,
+    /// La lista como JSON, con `age_days`.
+    Json,
+}
+
+/// Una supresión activa.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub mechanism: Mechanism,
+    /// `archivo:línea` de la directiva, entrada o marcador; solo el archivo
+    /// si la línea no se conoce.
+    pub location: String,
+    /// Qué silencia: regla y función, hallazgo aceptado, pareja de
+    /// argumentos, sección o patrón.
+    pub silences: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Día (`AAAA-MM-DD`) en que se añadió, si se sabe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    /// Si silenció algún hallazgo en esta ejecución.
+    pub used: bool,
+}
+
+impl Record {
+    /// Días desde `since` hasta `today` (días desde 1970-01-01).
+    pub fn age_days(&self, today: i64) -> Option<i64> {
+        let since = dates::parse_date(self.since.as_deref()?)?;
+        Some((today - since).max(0))
+    }
+
+    /// `hace 3 días`, `hoy` o `antigüedad desconocida`.
+    pub fn age(&self, today: i64) -> String {
+        match self.age_days(today) {
+            None => "antigüedad desconocida".to_string(),
+            Some(0) => "hoy".to_string(),
+            Some(days) => dates::ago(days * 86_400),
+        }
+    }
+
+    /// Copia con el texto en el idioma de la salida, para lo que sale de la máquina.
+    pub fn localized(&self) -> Record {
+        Record {
+            silences: tr(&self.silences).into_owned(),
+            ..self.clone()
+        }
+    }
+}
+
+/// Lo que `collect` necesita de una validación.
+pub struct Sources<'a> {
+    /// Validación antes del baseline, con las supresiones en línea aplicadas.
+    pub validation: &'a Validation<'a>,
+    pub code_entities: &'a [CodeEntity],
+    pub doc_sections: &'a [DocSection],
+    pub baseline: Option<&'a Baseline>,
+    pub config: &'a Config,
+    pub diagnostics: &'a ParseDiagnostics,
+    pub project_root: &'a Path,
+}
+
+/// Las supresiones activas de todos los mecanismos.
+pub fn collect(sources: Sources) -> Vec<Record> {
+    let Sources {
+        validation,
+        code_entities,
+        doc_sections,
+        baseline,
+        config,
+        diagnostics,
+        project_root,
+    } = sources;
+    let mut records = Vec::new();
+
+    for entity in code_entities {
+        for suppression in &entity.suppressions {
+            let rule = match suppression.rule.as_str() {
+                ALL_RULES => "todas las reglas",
+                rule => rule,
+            };
+            records.push(Record {
+                mechanism: Mechanism::Inline,
+                location: format!("{}:{}", entity.file_path.display(), suppression.line),
+                silences: format!("{} en fn {}", rule, entity.name),
+                reason: None,
+                since: None,
+                used: !validation
+                    .unused_directives
+                    .iter()
+                    .any(|(_, unused)| std::ptr::eq(*unused, suppression)),
+            });
+        }
+    }
+
+    if let Some(baseline) = baseline {
+        let hits = baseline::entry_hits(&validation.results, baseline, code_entities);
+        let locations = baseline::entry_locations(project_root, None);
+        for (index, entry) in baseline.entries.iter().enumerate() {
+            let (severity, fingerprint) = (&entry.severity, &entry.message_fingerprint);
+            let silences = match (&entry.function_name, &entry.doc_id) {
+                (Some(function), _) => {
+                    format!("{} en fn {}: \"{}\"", severity, function, fingerprint)
+                }
+                (None, Some(doc_id)) => {
+                    format!("{} de '{}': \"{}\"", severity, doc_id, fingerprint)
+                }
+                (None, None) => format!("{}: \"{}\"", severity, fingerprint),
+            };
+            records.push(Record {
+                mechanism: Mechanism::Baseline,
+                location: locations.get(index).cloned().unwrap_or_default(),
+                silences,
+                reason: entry.reason.clone(),
+                since: entry.created_at.clone(),
+                used: hits[index] > 0,
+            });
+        }
+    }
+
+    let config_file = config::config_path(project_root);
+    let config_file = config_file
+        .strip_prefix(project_root)
+        .unwrap_or(&config_file)
+        .display()
+        .to_string();
+    for exception in &config.arg_exceptions {
+        records.push(Record {
+            mechanism: Mechanism::ArgException,
+            location: config_file.clone(),
+            silences: format!(
+                "'{}' documentado como '{}' en '{}'",
+                exception.code_arg, exception.doc_arg, exception.doc_id
+            ),
+            reason: Some(exception.reason.clone()),
+            since: None,
+            used: !validation
+                .stale_exceptions
+                .iter()
+                .any(|stale| std::ptr::eq(stale.exception, exception)),
+        });
+    }
+
+    let linked: Vec<&str> = code_entities
+        .iter()
+        .flat_map(|e| {
+            let file_link = e.file_link.as_ref().map(|l| &l.doc_id);
+            e.doc_id.iter().chain(file_link)
+        })
+        .map(String::as_str)
+        .collect();
+    for section in doc_sections.iter().filter(|s| s.no_link) {
+        records.push(Record {
+            mechanism: Mechanism::NoLink,
+            location: section.location(),
+            silences: format!("orphan-section de '{}'", section.id),
+            reason: None,
+            since: None,
+            used: !linked.contains(&section.id.as_str()),
+        });
+    }
+
+    for pattern in &config.ignore_functions {
+        records.push(Record {
+            mechanism: Mechanism::IgnoreFunction,
+            location: config_file.clone(),
+            silences: format!("fn {}", pattern),
+            reason: None,
+            since: None,
+            used: diagnostics.ignore_hits.contains(pattern),
+        });
+    }
+
+    records
+}
+
+/// Fecha de las directivas y los marcadores sin fecha según `git blame` de
+/// su línea; las líneas sin commitear son de hoy. Fuera de un repositorio
+/// git no hace nada.
+pub fn date_from_git(records: &mut [Record], paths: &ProjectPaths, project_root: &Path) {
+    if !crate::git::is_repository(project_root) {
+        return;
+    }
+    let mut files: HashMap<PathBuf, Option<Vec<Option<i64>>>> = HashMap::new();
+    for record in records.iter_mut().filter(|r| r.since.is_none()) {
+        let Some((file, line)) = parse_location(&record.location) else {
+            continue;
+        };
+        let times = files
+            .entry(paths.resolve(file))
+            .or_insert_with_key(|file| super::blame::line_times(project_root, file));
+        let Some(time) = times.as_ref().and_then(|t| t.get(line.checked_sub(1)?)) else {
+            continue;
+        };
+        let day = time.unwrap_or_else(dates::now) / 86_400;
+        record.since = Some(dates::civil_date(day));
+    }
+}
+
+/// Ordena `records` según `sort`; a igualdad, por mecanismo y ubicación.
+pub fn sort(records: &mut [Record], sort: SuppressionSort) {
+    records.sort_by(|a, b| {
+        let by_location = || {
+            let key = |r: &Record| {
+                parse_location(&r.location)
+                    .map(|(file, line)| (file.to_path_buf(), line))
+                    .unwrap_or_else(|| (PathBuf::from(&r.location), 0))
+            };
+            key(a).cmp(&key(b))
+        };
+        match sort {
+            SuppressionSort::Mechanism => a.mechanism.cmp(&b.mechanism).then_with(by_location),
+            SuppressionSort::Location => by_location().then(a.mechanism.cmp(&b.mechanism)),
+            SuppressionSort::Age => {
+                // `AAAA-MM-DD` ordena como texto; sin fecha, al final
+                let since = |r: &Record| (r.since.is_none(), r.since.clone());
+                since(a)
+                    .cmp(&since(b))
+                    .then(a.mechanism.cmp(&b.mechanism))
+                    .then_with(by_location)
+            }
+        }
+    });
+}
+
+/// Líneas de texto de `suppressions list`.
+fn text_lines(records: &[Record], today: i64) -> Vec<String> {
+    let unused = records.iter().filter(|r| !r.used).count();
+    let mut lines = vec![format!(
+        "  {} ({} sin uso en esta ejecución)",
+        messages::SUPPRESSIONS.count_or_zero(records.len()),
+        unused
+    )];
+    for record in records {
+        lines.push(String::new());
+        lines.push(format!(
+            "  [{}] {}",
+            record.mechanism.label(),
+            record.location
+        ));
+        lines.push(format!("      {}", record.silences));
+        lines.push(if record.used {
+            format!(
+                "      {} · silenció hallazgos en esta ejecución",
+                record.age(today)
+            )
+        } else {
+            format!("      {} · sin uso en esta ejecución", record.age(today))
+        });
+        if let Some(reason) = &record.reason {
+            lines.push(format!("      Motivo: {}", reason));
+        }
+    }
+    lines
+}
+
+/// Fila de `suppressions list --format json`.
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    #[serde(flatten)]
+    record: &'a Record,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    age_days: Option<i64>,
+}
+
+/// Salida de `suppressions list --format json`.
+#[derive(Serialize)]
+struct JsonList<'a> {
+    total: usize,
+    unused: usize,
+    suppressions: Vec<JsonRecord<'a>>,
+}
+
+/// Opciones de `suppressions list`.
+pub struct ListOptions {
+    pub format: ListFormat,
+    /// Solo estos mecanismos; vacío, todos.
+    pub mechanisms: Vec<Mechanism>,
+    pub sort: SuppressionSort,
+    /// No consulta `git blame` para fechar directivas y marcadores.
+    pub no_git: bool,
+}
+
+/// Ejecuta `suppressions list`: valida como `check` y lista lo silenciado.
+pub fn run_list(
+    code_files: &[PathBuf],
+    doc_file: &Path,
+    project_root: &Path,
+    options: ListOptions,
+) -> Result<()> {
+    for code_file in code_files {
+        code_parser::require_file_exists(code_file, "código")?;
+    }
+    code_parser::require_file_exists(doc_file, "documentación")?;
+
+    let report = super::build_report(code_files, doc_file, project_root)?;
+    let mut records = report.suppressions;
+    if !options.mechanisms.is_empty() {
+        records.retain(|r| options.mechanisms.contains(&r.mechanism));
+    }
+    if !options.no_git {
+        let paths = Config::load(project_root)?.paths;
+        date_from_git(&mut records, &paths, project_root);
+    }
+    sort(&mut records, options.sort);
+
+    let today = dates::today_days();
+    if options.format == ListFormat::Json {
+        let records: Vec<Record> = records.iter().map(Record::localized).collect();
+        let rows: Vec<JsonRecord> = records
+            .iter()
+            .map(|record| JsonRecord {
+                record,
+                age_days: record.age_days(today),
+            })
+            .collect();
+        let list = JsonList {
+            total: rows.len(),
+            unused: records.iter().filter(|r| !r.used).count(),
+            suppressions: rows,
+        };
+        println!("{}", serde_json::to_string_pretty(&list)?);
+    } else {
+        for line in text_lines(&records, today) {
+            println_tr!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::pipeline::{self, DocSource, Inputs, Passes};
+    use crate::parser::{code_parser, doc_parser};
+    use crate::vfs::MemFs;
+
+    const CODE: &str = "\
+/// @docs: [auth-login]
+// docsguard-ignore: ghost-arg
+export function login(username: string) {}
+
+/// @docs: [auth-logout]
+// docsguard-ignore: missing-arg
+export function logout() {}
+
+export function internalHelper() {}
+";
+
+    const DOCS: &str = "\
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Usuario |
+| remember | boolean | Recordar |
+
+<!-- @docs-id: auth-logout -->
+## logout
+
+<!-- @docs-id: template @docs-no-link -->
+## Plantilla
+";
+
+    const CONFIG: &str = "\
+ignore_functions: [\"internal*\", \"legacy*\"]
+arg_exceptions:
+  - {doc_id: auth-login, doc_arg: filters, code_arg: filter_expr, reason: concepto}
+";
+
+    /// Supresiones de `CODE` y `DOCS` con `CONFIG` y un baseline de dos entradas.
+    fn records() -> Vec<Record> {
+        let dir = MemFs::project();
+        let root = dir.path();
+        dir.write(root.join("src/auth.ts"), CODE);
+        dir.write(root.join("docs/api.md"), DOCS);
+        dir.write(root.join(".docsguard/config.yaml"), CONFIG);
+        dir.write(
+            root.join(".docsguard/baseline.yaml"),
+            "version: '1'\ngenerated_at: '0'\nentries:\n\
+             - severity: Warning\n  doc_id: auth-logout\n  message_fingerprint: nunca\n  \
+             reason: deuda\n  created_at: '2026-01-10'\n",
+        );
+
+        let config = Config::load(root).unwrap();
+        let mut diagnostics = ParseDiagnostics::default();
+        let code_file = root.join("src/auth.ts");
+        let doc_file = root.join("docs/api.md");
+        let entities = code_parser::parse_project_code(
+            std::slice::from_ref(&code_file),
+            &config,
+            &mut diagnostics,
+        )
+        .unwrap();
+        let sections =
+            doc_parser::parse_docs_with_diagnostics(&doc_file, &config, &mut diagnostics).unwrap();
+        let source = doc_parser::read_markdown_file(&doc_file).unwrap();
+        let docs = [DocSource {
+            file: &doc_file,
+            source: &source,
+        }];
+        let validation = pipeline::run_validation(
+            Inputs {
+                code_entities: &entities,
+                doc_sections: &sections,
+                diagnostics: &diagnostics,
+                docs: &docs,
+                config: &config,
+            },
+            Passes::default(),
+        );
+        let baseline = Baseline::load(root).unwrap();
+        collect(Sources {
+            validation: &validation,
+            code_entities: &entities,
+            doc_sections: &sections,
+            baseline: baseline.as_ref(),
+            config: &config,
+            diagnostics: &diagnostics,
+            project_root: root,
+        })
+    }
+
+    #[test]
+    fn every_mechanism_reports_whether_it_silenced_something() {
+        let records = records();
+        let summary: Vec<(Mechanism, &str, bool)> = records
+            .iter()
+            .map(|r| (r.mechanism, r.silences.as_str(), r.used))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (Mechanism::Inline, "ghost-arg en fn login", true),
+                (Mechanism::Inline, "missing-arg en fn logout", false),
+                (
+                    Mechanism::Baseline,
+                    "Warning de 'auth-logout': \"nunca\"",
+                    false
+                ),
+                (
+                    Mechanism::ArgException,
+                    "'filter_expr' documentado como 'filters' en 'auth-login'",
+                    false
+                ),
+                (Mechanism::NoLink, "orphan-section de 'template'", true),
+                (Mechanism::IgnoreFunction, "fn internal*", true),
+                (Mechanism::IgnoreFunction, "fn legacy*", false),
+            ]
+        );
+        let baseline = &records[2];
+        assert_eq!(baseline.location, ".docsguard/baseline.yaml:4");
+        assert_eq!(baseline.reason.as_deref(), Some("deuda"));
+        assert_eq!(records[0].location, "src/auth.ts:2");
+        assert_eq!(records[3].location, ".docsguard/config.yaml");
+        assert_eq!(records[4].location, "docs/api.md:12");
+    }
+
+    #[test]
+    fn records_sort_by_age_and_location() {
+        let mut records = records();
+        sort(&mut records, SuppressionSort::Age);
+        assert_eq!(records[0].mechanism, Mechanism::Baseline);
+
+        sort(&mut records, SuppressionSort::Location);
+        let locations: Vec<&str> = records.iter().map(|r| r.location.as_str()).collect();
+        let mut sorted = locations.clone();
+        sorted.sort();
+        assert_eq!(locations, sorted);
+    }
+
+    #[test]
+    fn text_lists_age_state_and_reason() {
+        let today = dates::parse_date("2026-01-13").unwrap();
+        let records = records();
+        let text = text_lines(&records, today).join("\n");
+        assert!(
+            text.starts_with("  7 supresiones activas (4 sin uso en esta ejecución)"),
+            "{text}"
+        );
+        assert!(
+            text.contains(
+                "  [baseline] .docsguard/baseline.yaml:4\n      Warning de 'auth-logout': \"nunca\"\n      \
+                 hace 3 días · sin uso en esta ejecución\n      Motivo: deuda"
+            ),
+            "{text}"
+        );
+        assert!(text.contains("      antigüedad desconocida · silenció hallazgos"));
+        assert_eq!(
+            text_lines(&[], today),
+            ["  sin supresiones activas (0 sin uso en esta ejecución)"]
+        );
+    }
+}
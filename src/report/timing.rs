@@ -0,0 +1,198 @@
+//! Tiempos de una verificación y guardia de regresiones de rendimiento.
+//!
+//! Los parsers no saben nada de tiempos: el pipeline (`check`, `build_report`)
+//! envuelve cada llamada con `Timings::time_parse` / `time_validate`. Con
+//! `--time-budget`, una ejecución más lenta que el presupuesto produce un
+//! hallazgo `slow-run` que lista los archivos más lentos.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::core::types::{Rule, Severity, ValidationResult};
+
+/// Archivos listados en `--verbose` y en el hallazgo `slow-run`.
+pub const SLOWEST_LISTED: usize = 5;
+
+/// Tiempo de parseo de un archivo (código o docs).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileTiming {
+    pub path: PathBuf,
+    pub parse: Duration,
+}
+
+/// Tiempos de una ejecución.
+#[derive(Debug, Clone, Default)]
+pub struct Timings {
+    /// Parseo por archivo, en orden de ejecución.
+    pub files: Vec<FileTiming>,
+    /// Validación (enlaces, argumentos, notas del parser…).
+    pub validate: Duration,
+    /// Ejecución completa, fijada por el llamador al terminar.
+    pub total: Duration,
+}
+
+impl Timings {
+    /// Ejecuta `parse` y acumula su duración en la entrada de `path`.
+    pub fn time_parse<T>(&mut self, path: &Path, parse: impl FnOnce() -> T) -> T {
+        let started = Instant::now();
+        let result = parse();
+        let elapsed = started.elapsed();
+        match self.files.iter_mut().find(|f| f.path == path) {
+            Some(file) => file.parse += elapsed,
+            None => self.files.push(FileTiming {
+                path: path.to_path_buf(),
+                parse: elapsed,
+            }),
+        }
+        result
+    }
+
+    /// Ejecuta `validate` y acumula su duración.
+    pub fn time_validate<T>(&mut self, validate: impl FnOnce() -> T) -> T {
+        let started = Instant::now();
+        let result = validate();
+        self.validate += started.elapsed();
+        result
+    }
+
+    /// Tiempo total de parseo.
+    pub fn parse_total(&self) -> Duration {
+        self.files.iter().map(|f| f.parse).sum()
+    }
+
+    /// Los `n` archivos más lentos de parsear, del más lento al más rápido.
+    pub fn slowest(&self, n: usize) -> Vec<&FileTiming> {
+        let mut files: Vec<&FileTiming> = self.files.iter().collect();
+        files.sort_by_key(|f| std::cmp::Reverse(f.parse));
+        files.truncate(n);
+        files
+    }
+
+    /// Hallazgo `slow-run` si la ejecución superó el presupuesto.
+    pub fn budget_finding(&self, budget: Duration) -> Option<ValidationResult> {
+        if self.total <= budget {
+            return None;
+        }
+        let offenders: Vec<String> = self
+            .slowest(SLOWEST_LISTED)
+            .iter()
+            .map(|f| format!("{} ({})", f.path.display(), format_duration(f.parse)))
+            .collect();
+        Some(ValidationResult {
+            severity: Severity::Warning,
+            rule: Rule::SlowRun,
+            message: format!(
+                "La verificación tardó {} (presupuesto: {}; parseo {}, validación {}).",
+                format_duration(self.total),
+                format_duration(budget),
+                format_duration(self.parse_total()),
+                format_duration(self.validate)
+            ),
+            function_name: None,
+            code_location: None,
+            doc_id: None,
+            doc_location: None,
+            hint: Some(format!(
+                "Archivos más lentos: {}. Excluye los archivos generados o ajusta --time-budget.",
+                offenders.join(", ")
+            )),
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        })
+    }
+
+    /// Resumen para `--verbose`: totales y archivos más lentos.
+    pub fn render(&self) -> String {
+        let mut out = format!(
+            "  Tiempos: parseo {}, validación {}, total {}\n",
+            format_duration(self.parse_total()),
+            format_duration(self.validate),
+            format_duration(self.total)
+        );
+        for file in self.slowest(SLOWEST_LISTED) {
+            out.push_str(&format!(
+                "    {:>8}  {}\n",
+                format_duration(file.parse),
+                file.path.display()
+            ));
+        }
+        out
+    }
+}
+
+/// `12 ms` por debajo del segundo, `3.4 s` a partir de ahí.
+pub fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_secs(1) {
+        format!("{} ms", duration.as_millis())
+    } else {
+        format!("{:.1} s", duration.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parser inyectado que simula un archivo generado enorme.
+    fn slow_parser(millis: u64) -> impl FnOnce() -> usize {
+        move || {
+            std::thread::sleep(Duration::from_millis(millis));
+            0
+        }
+    }
+
+    fn timed_run() -> Timings {
+        let started = Instant::now();
+        let mut timings = Timings::default();
+        timings.time_parse(Path::new("src/auth.ts"), || 0);
+        timings.time_parse(Path::new("src/generated.ts"), slow_parser(40));
+        timings.time_parse(Path::new("docs/api.md"), || 0);
+        timings.time_validate(|| ());
+        timings.total = started.elapsed();
+        timings
+    }
+
+    #[test]
+    fn slow_file_exceeds_the_budget_and_is_listed_first() {
+        let timings = timed_run();
+        assert_eq!(
+            timings.slowest(1)[0].path,
+            PathBuf::from("src/generated.ts")
+        );
+
+        let finding = timings
+            .budget_finding(Duration::from_millis(10))
+            .expect("la ejecución supera el presupuesto");
+        assert_eq!(finding.rule, Rule::SlowRun);
+        assert_eq!(finding.severity, Severity::Warning);
+        assert!(finding
+            .hint
+            .unwrap()
+            .starts_with("Archivos más lentos: src/generated.ts ("));
+    }
+
+    #[test]
+    fn run_within_budget_has_no_finding() {
+        let timings = timed_run();
+        assert!(timings.budget_finding(Duration::from_secs(60)).is_none());
+    }
+
+    #[test]
+    fn repeated_parses_of_a_file_accumulate() {
+        let mut timings = Timings::default();
+        timings.time_parse(Path::new("docs/api.md"), slow_parser(5));
+        timings.time_parse(Path::new("docs/api.md"), slow_parser(5));
+        assert_eq!(timings.files.len(), 1);
+        assert!(timings.files[0].parse >= Duration::from_millis(10));
+    }
+
+    #[test]
+    fn durations_switch_to_seconds() {
+        assert_eq!(format_duration(Duration::from_millis(12)), "12 ms");
+        assert_eq!(format_duration(Duration::from_millis(3400)), "3.4 s");
+    }
+}
@@ -0,0 +1,824 @@
+//! Esquemas JSON (draft 2020-12) de los archivos de `.docsguard/`.
+//!
+//! `docsguard schema <artefacto>` imprime el esquema para que otras
+//! herramientas validen o generen estos archivos. Se escriben aquí, junto al
+//! vocabulario serde de cada tipo: las listas de valores (reglas, niveles,
+//! estrategias) salen de serializar los propios enums, y los tests comprueban
+//! que lo que escribe cada tipo y los ejemplos YAML del README validan contra
+//! su esquema. Un objeto es cerrado (`additionalProperties: false`) solo si
+//! el tipo usa `deny_unknown_fields`, igual que al cargarlo.
+//!
+//! La carga endurecida (`yaml::from_str_checked`) recurre al esquema cuando
+//! un archivo no deserializa: la primera violación da la ruta del campo y,
+//! ante una clave o un valor desconocidos, el más parecido (`sluger` →
+//! `slugger`).
+
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::config::rules::RuleLevel;
+use crate::config::{ArgNameMatching, InfoAggregation, Pairing, ReferenceSeverity};
+use crate::core::anchors::{SlugBase, SlugStyle};
+use crate::core::types::{ArgSource, Rule};
+use crate::exit::{FailOn, Outcome};
+use crate::parser::doc_parser::MarkdownExtension;
+use crate::report::CheckFormat;
+
+/// Similitud mínima para sugerir una clave o un valor parecidos.
+const MIN_SUGGESTION_SIMILARITY: f64 = 0.6;
+
+/// Archivo de `.docsguard/` con esquema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Artifact {
+    Config,
+    Baseline,
+    Links,
+    Attestations,
+}
+
+impl Artifact {
+    pub const ALL: [Artifact; 4] = [
+        Artifact::Config,
+        Artifact::Baseline,
+        Artifact::Links,
+        Artifact::Attestations,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Artifact::Config => "config",
+            Artifact::Baseline => "baseline",
+            Artifact::Links => "links",
+            Artifact::Attestations => "attestations",
+        }
+    }
+
+    /// Versión del esquema; sube cuando cambia el formato del archivo.
+    pub fn version(self) -> u32 {
+        1
+    }
+
+    /// Esquema JSON del archivo.
+    pub fn schema(self) -> Value {
+        let (file, body) = match self {
+            Artifact::Config => ("config.yaml", config()),
+            Artifact::Baseline => ("baseline.yaml", baseline()),
+            Artifact::Links => ("links.yaml", links()),
+            Artifact::Attestations => ("attestations.yaml", attestations()),
+        };
+        let mut schema = Map::new();
+        schema.insert(
+            "$schema".into(),
+            "https://json-schema.org/draft/2020-12/schema".into(),
+        );
+        schema.insert(
+            "title".into(),
+            format!("DocsGuard .docsguard/{}", file).into(),
+        );
+        schema.insert("x-docsguard-schema-version".into(), self.version().into());
+        if let Value::Object(body) = body {
+            schema.extend(body);
+        }
+        Value::Object(schema)
+    }
+}
+
+/// `docsguard schema <artefacto>`: imprime el esquema JSON.
+pub fn run_schema(artifact: Artifact) -> Result<Outcome> {
+    println!("{}", serde_json::to_string_pretty(&artifact.schema())?);
+    Ok(Outcome::Clean)
+}
+
+/// `config v1, baseline v1, …` para `docsguard --version --verbose`.
+pub fn versions() -> String {
+    Artifact::ALL
+        .iter()
+        .map(|a| format!("{} v{}", a.name(), a.version()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Primera violación del esquema de `artifact` en el YAML `content`, como
+/// texto para el usuario. `None` si el documento no es YAML o lo cumple.
+pub fn explain(artifact: Artifact, content: &str) -> Option<String> {
+    let value: Value = serde_yml::from_str(content).ok()?;
+    validate(&artifact.schema(), &value)
+        .into_iter()
+        .next()
+        .map(|v| v.to_string())
+}
+
+/// Incumplimiento del esquema en un punto del documento.
+#[derive(Debug, Clone, PartialEq)]
+struct Violation {
+    /// Ruta del valor (`site.slugger`, `links[2].doc_id`); vacía en la raíz.
+    path: String,
+    message: String,
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "`{}`: {}", self.path, self.message)
+        }
+    }
+}
+
+/// Valida `value` contra el subconjunto de JSON Schema que usan estos
+/// esquemas: `type`, `enum`, `minimum`, `properties`, `required`,
+/// `additionalProperties`, `propertyNames` (con `enum`) e `items`.
+fn validate(schema: &Value, value: &Value) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    check(schema, value, String::new(), &mut violations);
+    violations
+}
+
+fn check(schema: &Value, value: &Value, path: String, out: &mut Vec<Violation>) {
+    let violation = |message: String| Violation {
+        path: path.clone(),
+        message,
+    };
+    if let Some(expected) = schema.get("type") {
+        let types: Vec<&str> = match expected {
+            Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+            other => other.as_str().into_iter().collect(),
+        };
+        if !types.iter().any(|t| has_type(value, t)) {
+            let expected: Vec<&str> = types.iter().map(|t| type_name(t)).collect();
+            out.push(violation(format!(
+                "se esperaba {}, no {}",
+                expected.join(" o "),
+                type_name(value_type(value))
+            )));
+            return;
+        }
+    }
+    if let Some(Value::Array(allowed)) = schema.get("enum") {
+        if !allowed.contains(value) {
+            let names: Vec<&str> = allowed.iter().filter_map(Value::as_str).collect();
+            let found = value
+                .as_str()
+                .map_or_else(|| value.to_string(), str::to_string);
+            out.push(violation(unknown(&found, "valor", &names)));
+        }
+        return;
+    }
+    if let (Some(minimum), Some(number)) = (
+        schema.get("minimum").and_then(Value::as_f64),
+        value.as_f64(),
+    ) {
+        if number < minimum {
+            out.push(violation(format!("el mínimo es {}", minimum)));
+        }
+    }
+    match value {
+        Value::Object(fields) => check_object(schema, fields, &path, out),
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    check(item_schema, item, format!("{}[{}]", path, i), out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn check_object(schema: &Value, fields: &Map<String, Value>, path: &str, out: &mut Vec<Violation>) {
+    let child = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+    let properties = schema.get("properties").and_then(Value::as_object);
+    let known: Vec<&str> = properties
+        .map(|p| p.keys().map(String::as_str).collect())
+        .unwrap_or_default();
+    let unknown_keys: Vec<&str> = fields
+        .keys()
+        .map(String::as_str)
+        .filter(|k| properties.is_some() && !known.contains(k))
+        .collect();
+
+    if let Some(Value::Array(required)) = schema.get("required") {
+        for name in required.iter().filter_map(Value::as_str) {
+            if !fields.contains_key(name) {
+                let message = match closest(name, &unknown_keys) {
+                    Some(typo) => format!("falta el campo `{}` (¿`{}` es una errata?)", name, typo),
+                    None => format!("falta el campo `{}`", name),
+                };
+                out.push(Violation {
+                    path: path.to_string(),
+                    message,
+                });
+            }
+        }
+    }
+    for (key, value) in fields {
+        if let Some(Value::Array(names)) = schema.pointer("/propertyNames/enum") {
+            if !names.iter().any(|n| n == key) {
+                let names: Vec<&str> = names.iter().filter_map(Value::as_str).collect();
+                out.push(Violation {
+                    path: child(key),
+                    message: unknown(key, "campo", &names),
+                });
+                continue;
+            }
+        }
+        match properties.and_then(|p| p.get(key)) {
+            Some(property) => check(property, value, child(key), out),
+            None => match schema.get("additionalProperties") {
+                Some(Value::Bool(false)) => out.push(Violation {
+                    path: child(key),
+                    message: unknown(key, "campo", &known),
+                }),
+                Some(additional @ Value::Object(_)) => check(additional, value, child(key), out),
+                _ => {}
+            },
+        }
+    }
+}
+
+/// `campo desconocido; ¿quisiste decir `slugger`?`, o la lista de admitidos.
+fn unknown(found: &str, what: &str, allowed: &[&str]) -> String {
+    match closest(found, allowed) {
+        Some(suggestion) => format!(
+            "{} `{}` desconocido; ¿quisiste decir `{}`?",
+            what, found, suggestion
+        ),
+        None => format!(
+            "{} `{}` desconocido (admitidos: {})",
+            what,
+            found,
+            allowed.join(", ")
+        ),
+    }
+}
+
+/// El candidato más parecido a `name`, si se parece lo suficiente.
+fn closest<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|c| (strsim::normalized_levenshtein(name, c), *c))
+        .filter(|(similarity, _)| *similarity >= MIN_SUGGESTION_SIMILARITY)
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, candidate)| candidate)
+}
+
+fn has_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "integer" => value.is_u64() || value.is_i64(),
+        "number" => value.is_number(),
+        other => value_type(value) == other,
+    }
+}
+
+fn value_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn type_name(json_type: &str) -> &'static str {
+    match json_type {
+        "null" => "nulo",
+        "boolean" => "un booleano",
+        "integer" => "un entero",
+        "number" => "un número",
+        "string" => "texto",
+        "array" => "una lista",
+        _ => "un mapeo",
+    }
+}
+
+// --- Constructores ---
+
+fn string() -> Value {
+    json!({"type": "string"})
+}
+
+fn count() -> Value {
+    json!({"type": "integer", "minimum": 0})
+}
+
+fn array(items: Value) -> Value {
+    json!({"type": "array", "items": items})
+}
+
+fn map_of(values: Value) -> Value {
+    json!({"type": "object", "additionalProperties": values})
+}
+
+/// `Option<T>`: el mismo esquema admitiendo `null`.
+fn nullable(mut schema: Value) -> Value {
+    if let Some(kind) = schema.get("type").cloned() {
+        schema["type"] = json!([kind, "null"]);
+    }
+    schema
+}
+
+/// Texto con los valores que serde escribe para `variants`.
+fn one_of<T: Serialize>(variants: &[T]) -> Value {
+    let names: Vec<Value> = variants
+        .iter()
+        .map(|v| serde_json::to_value(v).expect("un enum unitario se serializa como texto"))
+        .collect();
+    json!({"type": "string", "enum": names})
+}
+
+/// Objeto con `properties`; `closed` si el tipo usa `deny_unknown_fields`.
+fn object(properties: Vec<(&str, Value)>, required: &[&str], closed: bool) -> Value {
+    let mut schema = json!({
+        "type": "object",
+        "properties": properties
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect::<Map<_, _>>(),
+    });
+    if !required.is_empty() {
+        schema["required"] = json!(required);
+    }
+    if closed {
+        schema["additionalProperties"] = false.into();
+    }
+    schema
+}
+
+// --- Artefactos ---
+
+fn config() -> Value {
+    let rule_levels = json!({
+        "type": "object",
+        "propertyNames": one_of(&Rule::ALL),
+        "additionalProperties": one_of(&[
+            RuleLevel::Error,
+            RuleLevel::Warning,
+            RuleLevel::Info,
+            RuleLevel::Off,
+        ]),
+    });
+    let strings = || array(string());
+    object(
+        vec![
+            (
+                "doc_arg_sources",
+                nullable(array(one_of(&[
+                    ArgSource::Code,
+                    ArgSource::List,
+                    ArgSource::Table,
+                    ArgSource::Definition,
+                    ArgSource::Heading,
+                ]))),
+            ),
+            (
+                "references",
+                object(
+                    vec![
+                        ("packages", strings()),
+                        ("allow", strings()),
+                        (
+                            "severity",
+                            one_of(&[ReferenceSeverity::Info, ReferenceSeverity::Warning]),
+                        ),
+                    ],
+                    &[],
+                    true,
+                ),
+            ),
+            (
+                "versions",
+                object(
+                    vec![("manifest", nullable(string())), ("known", strings())],
+                    &[],
+                    true,
+                ),
+            ),
+            ("units", map_of(strings())),
+            ("annotation_max_gap", nullable(count())),
+            ("title_max_distance", nullable(count())),
+            (
+                "markdown_extensions",
+                nullable(array(one_of(&MarkdownExtension::ALL))),
+            ),
+            ("require_args_delimiter", json!({"type": "boolean"})),
+            (
+                "lead_ins",
+                object(
+                    vec![("args", strings()), ("structural", strings())],
+                    &[],
+                    true,
+                ),
+            ),
+            // `kebab-from-function`, `snake`, `keep` o una plantilla
+            ("id_style", string()),
+            ("id_modules", map_of(string())),
+            (
+                "redaction",
+                object(vec![("patterns", strings())], &[], true),
+            ),
+            (
+                "info_aggregation",
+                one_of(&[InfoAggregation::Off, InfoAggregation::Summarize]),
+            ),
+            ("rules", rule_levels),
+            (
+                "limits",
+                object(
+                    vec![("max_section_args", count()), ("max_sections", count())],
+                    &[],
+                    true,
+                ),
+            ),
+            (
+                "site",
+                object(
+                    vec![
+                        ("base_url", nullable(string())),
+                        (
+                            "slugger",
+                            one_of(&[SlugStyle::Github, SlugStyle::Docusaurus, SlugStyle::Custom]),
+                        ),
+                        (
+                            "custom",
+                            object(
+                                vec![
+                                    ("base", one_of(&[SlugBase::Github, SlugBase::Docusaurus])),
+                                    ("strip_suffixes", strings()),
+                                    ("duplicate_separator", nullable(string())),
+                                ],
+                                &[],
+                                true,
+                            ),
+                        ),
+                        ("docs_dir", nullable(string())),
+                        ("url_template", nullable(string())),
+                        ("timeout_secs", nullable(count())),
+                        ("concurrency", nullable(count())),
+                    ],
+                    &[],
+                    true,
+                ),
+            ),
+            ("attestation_required", strings()),
+            (
+                "arg_exceptions",
+                array(object(
+                    vec![
+                        ("doc_id", string()),
+                        ("doc_arg", string()),
+                        ("code_arg", string()),
+                        ("reason", string()),
+                    ],
+                    &["doc_id", "doc_arg", "code_arg", "reason"],
+                    true,
+                )),
+            ),
+            (
+                "link_boundaries",
+                array(object(
+                    vec![("code", string()), ("docs", string())],
+                    &["code", "docs"],
+                    true,
+                )),
+            ),
+            ("ignore_functions", strings()),
+            (
+                "combos",
+                map_of(object(
+                    vec![
+                        ("doc_file", string()),
+                        ("code", strings()),
+                        ("code_ref", nullable(string())),
+                        ("docs_ref", nullable(string())),
+                        ("baseline_name", nullable(string())),
+                    ],
+                    &["doc_file", "code"],
+                    true,
+                )),
+            ),
+            (
+                "arg_names",
+                object(
+                    vec![
+                        (
+                            "matching",
+                            one_of(&[
+                                ArgNameMatching::Exact,
+                                ArgNameMatching::CaseInsensitive,
+                                ArgNameMatching::ConventionTolerant,
+                            ]),
+                        ),
+                        ("report_differences", json!({"type": "boolean"})),
+                    ],
+                    &[],
+                    true,
+                ),
+            ),
+            ("copied_section_min_args", nullable(count())),
+            (
+                "grace_period",
+                nullable(object(vec![("days", count())], &["days"], true)),
+            ),
+            ("pairing", one_of(&[Pairing::Off, Pairing::ColocatedReadme])),
+            ("readme_ancestors", nullable(count())),
+            (
+                "profiles",
+                map_of(object(
+                    vec![
+                        ("doc_file", string()),
+                        ("code", strings()),
+                        (
+                            "fail_on",
+                            one_of(&[FailOn::Error, FailOn::Warning, FailOn::Never]),
+                        ),
+                        ("changed_since", nullable(string())),
+                        ("baseline_name", nullable(string())),
+                        ("time_budget", nullable(json!({"type": "number"}))),
+                        ("max_warnings", nullable(count())),
+                        ("min_coverage", nullable(count())),
+                        (
+                            "format",
+                            one_of(&[
+                                CheckFormat::Text,
+                                CheckFormat::Sarif,
+                                CheckFormat::Json,
+                                CheckFormat::Markdown,
+                            ]),
+                        ),
+                        ("output", nullable(string())),
+                    ],
+                    &["doc_file", "code"],
+                    true,
+                )),
+            ),
+        ],
+        &[],
+        true,
+    )
+}
+
+fn baseline() -> Value {
+    let entry = object(
+        vec![
+            ("severity", string()),
+            ("function_name", nullable(string())),
+            ("doc_id", nullable(string())),
+            ("message_fingerprint", string()),
+            ("reason", nullable(string())),
+            ("file", nullable(string())),
+            ("created_at", nullable(string())),
+            ("expires", nullable(string())),
+            ("signature", nullable(string())),
+        ],
+        &["severity", "message_fingerprint"],
+        false,
+    );
+    object(
+        vec![
+            ("version", json!({"type": "string", "enum": ["1"]})),
+            ("generated_at", string()),
+            ("entries", array(entry)),
+        ],
+        &["version", "generated_at", "entries"],
+        false,
+    )
+}
+
+fn links() -> Value {
+    let link = object(
+        vec![
+            ("file", string()),
+            ("function", string()),
+            ("doc_id", string()),
+        ],
+        &["file", "function", "doc_id"],
+        false,
+    );
+    object(vec![("links", array(link))], &[], false)
+}
+
+fn attestations() -> Value {
+    let attestation = object(
+        vec![
+            ("doc_id", string()),
+            ("hash", string()),
+            ("by", string()),
+            ("date", string()),
+        ],
+        &["doc_id", "hash", "by", "date"],
+        false,
+    );
+    object(vec![("attestations", array(attestation))], &[], false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attestation::{Attestation, Attestations};
+    use crate::baseline::Baseline;
+    use crate::config::Config;
+    use crate::core::types::{Severity, ValidationResult};
+    use crate::mapping::{LinkMapping, MappedLink};
+
+    fn violations(artifact: Artifact, value: &Value) -> Vec<String> {
+        validate(&artifact.schema(), value)
+            .iter()
+            .map(|v| v.to_string())
+            .collect()
+    }
+
+    /// Una configuración que usa cada campo, a partir de YAML.
+    const FULL_CONFIG: &str = "\
+doc_arg_sources: [table, list]
+references: {packages: ['@acme/sdk'], allow: [legacyLogin], severity: warning}
+versions: {manifest: package.json, known: ['2.0']}
+units: {px: [pixels]}
+annotation_max_gap: 2
+title_max_distance: 4
+markdown_extensions: [tables, footnotes, heading_attributes]
+require_args_delimiter: true
+lead_ins: {args: [inputs], structural: [callbacks]}
+id_style: snake
+id_modules: {src/billing: billing}
+redaction: {patterns: [ACME-*]}
+info_aggregation: summarize
+rules: {orphan-section: off, DG001: info}
+limits: {max_section_args: 10, max_sections: 20}
+site:
+  base_url: https://docs.example.com/
+  slugger: custom
+  custom: {base: docusaurus, strip_suffixes: ['()'], duplicate_separator: _}
+  docs_dir: docs
+  url_template: '{base_url}{path}'
+  timeout_secs: 5
+  concurrency: 4
+attestation_required: [auth-login]
+arg_exceptions:
+  - {doc_id: search-api, doc_arg: filters, code_arg: filter_expr, reason: grouping}
+link_boundaries:
+  - {code: 'src/billing/**', docs: 'docs/billing/**'}
+arg_names: {matching: convention-tolerant, report_differences: true}
+copied_section_min_args: 4
+ignore_functions: ['test_*']
+grace_period: {days: 14}
+pairing: colocated-readme
+readme_ancestors: 2
+profiles:
+  ci: {doc_file: docs/api.md, code: [src], fail_on: warning, changed_since: origin/main, baseline_name: v2, time_budget: 30, max_warnings: 5, min_coverage: 80, format: sarif, output: out/docsguard.sarif}
+combos:
+  v2: {doc_file: docs/v2/api.md, code: [src], code_ref: release/2.x, baseline_name: v2}
+";
+
+    #[test]
+    fn what_each_type_writes_validates_against_its_schema() {
+        let config = Config::from_yaml(FULL_CONFIG).unwrap();
+        for config in [Config::default(), config] {
+            let value = serde_json::to_value(&config).unwrap();
+            assert_eq!(violations(Artifact::Config, &value), Vec::<String>::new());
+        }
+        let parsed: Value = serde_yml::from_str(FULL_CONFIG).unwrap();
+        assert_eq!(violations(Artifact::Config, &parsed), Vec::<String>::new());
+
+        let result = ValidationResult {
+            severity: Severity::Warning,
+            rule: Rule::MissingArg,
+            message: "Falta 'x'".into(),
+            function_name: Some("login".into()),
+            code_location: Some("src/auth.ts:3".into()),
+            doc_id: Some("auth-login".into()),
+            doc_location: None,
+            hint: None,
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        };
+        let baseline = Baseline::from_results(&[result], &[]);
+        let links = LinkMapping {
+            links: vec![MappedLink {
+                file: "vendor/billing.ts".into(),
+                function: "charge".into(),
+                doc_id: "billing-charge".into(),
+            }],
+        };
+        let attestations = Attestations {
+            attestations: vec![Attestation {
+                doc_id: "auth-login".into(),
+                hash: "fnv1a64:00".into(),
+                by: "@maria".into(),
+                date: "2026-01-01".into(),
+            }],
+        };
+        for (artifact, value) in [
+            (Artifact::Baseline, serde_json::to_value(&baseline).unwrap()),
+            (Artifact::Links, serde_json::to_value(&links).unwrap()),
+            (
+                Artifact::Attestations,
+                serde_json::to_value(&attestations).unwrap(),
+            ),
+        ] {
+            assert_eq!(
+                violations(artifact, &value),
+                Vec::<String>::new(),
+                "{:?}",
+                artifact
+            );
+        }
+    }
+
+    #[test]
+    fn every_config_field_is_in_the_schema() {
+        let written = serde_json::to_value(Config::default()).unwrap();
+        let mut written: Vec<&String> = written.as_object().unwrap().keys().collect();
+        let schema = Artifact::Config.schema();
+        let mut described: Vec<&String> =
+            schema["properties"].as_object().unwrap().keys().collect();
+        written.sort();
+        described.sort();
+        assert_eq!(written, described);
+    }
+
+    #[test]
+    fn readme_yaml_examples_validate_against_their_schema() {
+        for readme in [
+            include_str!("../README.md"),
+            include_str!("../README.es.md"),
+        ] {
+            for block in readme.split("```yaml\n").skip(1) {
+                let block = &block[..block.find("```").unwrap()];
+                // Los ejemplos de GitHub Actions y del corpus de conformidad
+                // no son archivos de `.docsguard/`
+                if block.trim_start().starts_with('-') || block.starts_with("# fixtures/") {
+                    continue;
+                }
+                let artifact = if block.trim_start().starts_with("links:") {
+                    Artifact::Links
+                } else {
+                    Artifact::Config
+                };
+                let value: Value = serde_yml::from_str(block).unwrap();
+                assert_eq!(
+                    violations(artifact, &value),
+                    Vec::<String>::new(),
+                    "{block}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn violations_name_the_path_and_the_closest_key_or_value() {
+        let config_error = |content| explain(Artifact::Config, content).unwrap();
+        assert_eq!(
+            config_error("site:\n  sluger: github\n"),
+            "`site.sluger`: campo `sluger` desconocido; ¿quisiste decir `slugger`?"
+        );
+        assert_eq!(
+            config_error("arg_names: {matching: convention}\n"),
+            "`arg_names.matching`: valor `convention` desconocido (admitidos: exact, case-insensitive, convention-tolerant)"
+        );
+        assert_eq!(
+            config_error("rules: {orphan-sectoin: off}\n"),
+            "`rules.orphan-sectoin`: campo `orphan-sectoin` desconocido; ¿quisiste decir `orphan-section`?"
+        );
+        assert_eq!(
+            config_error("limits: {max_sections: many}\n"),
+            "`limits.max_sections`: se esperaba un entero, no texto"
+        );
+        assert_eq!(
+            explain(
+                Artifact::Baseline,
+                "version: '1'\ngenerated_at: hoy\nentires: []\n"
+            ),
+            Some("falta el campo `entries` (¿`entires` es una errata?)".to_string())
+        );
+        assert_eq!(
+            explain(Artifact::Config, "rules: {ghost-arg: warning}\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn schemas_carry_their_version() {
+        for artifact in Artifact::ALL {
+            assert_eq!(artifact.schema()["x-docsguard-schema-version"], 1);
+        }
+        assert_eq!(
+            versions(),
+            "config v1, baseline v1, links v1, attestations v1"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! `self-check`: DocsGuard sobre sus propias anotaciones.
+//!
+//! Las funciones del crate anotadas con `@docs` enlazan secciones de
+//! `docs/architecture.md`, que es a la vez la documentación de la
+//! arquitectura. El comando recorre `src/**/*.rs` y pasa el mismo pipeline que
+//! `check` (`report::build_report`), en el propio proceso; el test de
+//! integración `tests/self_check.rs` lo ejecuta sobre el repositorio, así que
+//! una anotación o una sección que se desvía rompe la build.
+//!
+//! A diferencia de `check`, una advertencia también falla: un argumento sin
+//! documentar en nuestras propias docs es deriva. Las funciones sin anotar son
+//! Info y no se muestran.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::core::types::Severity;
+use crate::exit::Outcome;
+use crate::layout::Layout;
+use crate::messages;
+use crate::messages::println_tr;
+use crate::parser::code_parser::{self, safe_display};
+use crate::report;
+use crate::vfs;
+
+/// Documentación que enlazan las anotaciones del crate.
+pub const ARCHITECTURE_DOC: &str = "docs/architecture.md";
+/// Directorio con el código del crate.
+const SOURCE_DIR: &str = "src";
+
+/// @docs: [self-check]
+/// Valida las anotaciones `@docs` de `src/**/*.rs` contra `docs/architecture.md`.
+pub fn run_self_check(project_root: &Path, layout: &Layout) -> Result<Outcome> {
+    let doc_file = project_root.join(ARCHITECTURE_DOC);
+    code_parser::require_file_exists(&doc_file, "documentación")?;
+    let code_files = rust_sources(&project_root.join(SOURCE_DIR))?;
+
+    println_tr!("DocsGuard — Auto-verificación de las anotaciones del crate\n");
+    println_tr!("  Docs: {}", safe_display(&doc_file));
+    println_tr!(
+        "  Código: {} en {}/\n",
+        messages::FILES.count(code_files.len()),
+        SOURCE_DIR
+    );
+
+    let report = report::build_report(&code_files, &doc_file, project_root)?;
+    let linked = report.section_ids.intersection(&report.linked_ids).count();
+    let drift: Vec<_> = report
+        .results
+        .iter()
+        .filter(|r| r.severity != Severity::Info)
+        .collect();
+    for result in &drift {
+        print!("{}", result.render(layout, false));
+    }
+
+    let errors = report.count(Severity::Error);
+    let warnings = report.count(Severity::Warning);
+    println!("---");
+    println_tr!(
+        "Resumen: {}; {} de {} enlazadas desde el código",
+        messages::summary(errors, warnings),
+        messages::Locale::CANONICAL.number(linked),
+        messages::SECTIONS.count(report.section_count)
+    );
+    Ok(Outcome::failed_if(!drift.is_empty()))
+}
+
+/// Archivos `.rs` bajo `dir`, recursivamente y en orden estable.
+fn rust_sources(dir: &Path) -> Result<Vec<PathBuf>> {
+    let files = vfs::walk(dir)
+        .with_context(|| format!("No se pudo leer el directorio {}", safe_display(dir)))?;
+    Ok(files
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::MemFs;
+
+    #[test]
+    fn sources_are_found_recursively_in_a_stable_order() {
+        let dir = MemFs::project();
+        dir.mkdir(dir.path().join("b/c"));
+        for file in ["main.rs", "b/c/deep.rs", "b/mod.rs", "b/notes.md"] {
+            dir.write(dir.path().join(file), "");
+        }
+        let files: Vec<_> = rust_sources(dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|f| f.strip_prefix(dir.path()).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            files,
+            [
+                PathBuf::from("b/c/deep.rs"),
+                PathBuf::from("b/mod.rs"),
+                PathBuf::from("main.rs"),
+            ]
+        );
+    }
+}
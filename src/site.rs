@@ -0,0 +1,302 @@
+//! Comprobación del sitio publicado (`check --verify-site`, feature `verify-site`).
+//!
+//! Solo se compila con la feature y solo se ejecuta con el flag: un binario
+//! por defecto nunca abre una conexión. Cada página de las URLs de sección
+//! (`core::site_urls`) se pide una vez con HEAD, con
+//! `site.concurrency` peticiones a la vez y `site.timeout_secs` de espera. El
+//! `#ancla` no viaja al servidor: la petición confirma que la página existe y
+//! el ancla la garantiza `site.slugger`.
+//!
+//! Un 404 o 410 es `unpublished-section` (Warning). Cualquier otra respuesta
+//! de error queda como Info, y sin red se emite una única Info: estar offline
+//! no hace fallar el check.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::config::SiteConfig;
+use crate::core::site_urls::SectionUrl;
+use crate::core::types::{Rule, Severity, ValidationResult};
+use crate::messages;
+
+/// Segundos de espera por petición sin `site.timeout_secs`.
+const DEFAULT_TIMEOUT_SECS: u64 = 5;
+/// Peticiones simultáneas sin `site.concurrency`.
+const DEFAULT_CONCURRENCY: usize = 4;
+const MAX_CONCURRENCY: usize = 16;
+
+/// Resultado de pedir una página.
+#[derive(Debug, Clone, PartialEq)]
+enum PageStatus {
+    Found,
+    /// 404 o 410.
+    Missing(u16),
+    /// Otro error HTTP: no confirma ni descarta la página.
+    Unconfirmed(u16),
+    /// Sin respuesta (DNS, conexión, tiempo agotado).
+    Unreachable(String),
+}
+
+/// Hallazgos de las URLs de `urls` que el sitio no publica.
+pub fn verify(urls: &[SectionUrl], site: &SiteConfig) -> Vec<ValidationResult> {
+    let agent = ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(
+            site.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS),
+        ))
+        .user_agent("docsguard")
+        .build();
+    let mut pages: Vec<&str> = Vec::new();
+    for url in urls {
+        let page = page_of(&url.url);
+        if !pages.contains(&page) {
+            pages.push(page);
+        }
+    }
+    let statuses = fetch_all(
+        &pages,
+        site.concurrency.unwrap_or(DEFAULT_CONCURRENCY),
+        |page| head(&agent, page),
+    );
+    findings(urls, &pages, &statuses)
+}
+
+/// La URL sin su `#ancla`.
+fn page_of(url: &str) -> &str {
+    url.split_once('#').map_or(url, |(page, _)| page)
+}
+
+fn head(agent: &ureq::Agent, page: &str) -> PageStatus {
+    match agent.head(page).call() {
+        // Hay servidores que no aceptan HEAD: se repite con GET
+        Err(ureq::Error::Status(405 | 501, _)) => classify(agent.get(page).call()),
+        response => classify(response),
+    }
+}
+
+fn classify(response: Result<ureq::Response, ureq::Error>) -> PageStatus {
+    match response {
+        Ok(_) => PageStatus::Found,
+        Err(ureq::Error::Status(code @ (404 | 410), _)) => PageStatus::Missing(code),
+        Err(ureq::Error::Status(code, _)) => PageStatus::Unconfirmed(code),
+        Err(ureq::Error::Transport(error)) => PageStatus::Unreachable(error.to_string()),
+    }
+}
+
+/// Estado de cada página, en su orden, con como mucho `concurrency`
+/// peticiones en curso.
+fn fetch_all<F>(pages: &[&str], concurrency: usize, fetch: F) -> Vec<PageStatus>
+where
+    F: Fn(&str) -> PageStatus + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let statuses = Mutex::new(vec![None; pages.len()]);
+    let workers = concurrency.clamp(1, MAX_CONCURRENCY).min(pages.len());
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(page) = pages.get(i) else { break };
+                let status = fetch(page);
+                statuses.lock().unwrap_or_else(|e| e.into_inner())[i] = Some(status);
+            });
+        }
+    });
+    statuses
+        .into_inner()
+        .unwrap_or_else(|e| e.into_inner())
+        .into_iter()
+        .map(|status| status.unwrap_or(PageStatus::Unreachable("sin respuesta".into())))
+        .collect()
+}
+
+fn findings(urls: &[SectionUrl], pages: &[&str], statuses: &[PageStatus]) -> Vec<ValidationResult> {
+    let mut results = Vec::new();
+    let mut unreachable: Vec<(&SectionUrl, &str)> = Vec::new();
+    for url in urls {
+        let Some(i) = pages.iter().position(|&p| p == page_of(&url.url)) else {
+            continue;
+        };
+        let (severity, message, hint) = match &statuses[i] {
+            PageStatus::Found => continue,
+            PageStatus::Unreachable(error) => {
+                unreachable.push((url, error));
+                continue;
+            }
+            PageStatus::Missing(code) => (
+                Severity::Warning,
+                format!(
+                    "La sección '{}' no está publicada: {} responde {}.",
+                    url.id, url.url, code
+                ),
+                "Publica la página o revisa `base_url`, `docs_dir` y `url_template` en `site:` de config.yaml.",
+            ),
+            PageStatus::Unconfirmed(code) => (
+                Severity::Info,
+                format!(
+                    "No se pudo confirmar que la sección '{}' esté publicada: {} responde {}.",
+                    url.id, url.url, code
+                ),
+                "El servidor rechazó la petición; comprueba la URL en un navegador.",
+            ),
+        };
+        results.push(finding(severity, url, message, hint));
+    }
+    if let Some((first, error)) = unreachable.first() {
+        results.push(finding(
+            Severity::Info,
+            first,
+            format!(
+                "No se pudo contactar con el sitio ({}): {} de {} sin verificar.",
+                error,
+                messages::Locale::CANONICAL.number(unreachable.len()),
+                messages::SECTIONS.count(urls.len())
+            ),
+            "Repite con conexión; las anclas se validan igualmente sin red (`broken-anchor`).",
+        ));
+    }
+    results
+}
+
+fn finding(severity: Severity, url: &SectionUrl, message: String, hint: &str) -> ValidationResult {
+    ValidationResult {
+        severity,
+        rule: Rule::UnpublishedSection,
+        message,
+        function_name: None,
+        code_location: None,
+        doc_id: Some(url.id.clone()),
+        doc_location: Some(url.location.clone()),
+        hint: Some(hint.to_string()),
+        provenance: None,
+        related: Vec::new(),
+        target: None,
+        summary: None,
+        cause: None,
+        grace: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    fn url(id: &str, url: &str) -> SectionUrl {
+        SectionUrl {
+            id: id.into(),
+            location: format!("docs/api.md:{}", id.len()),
+            url: url.into(),
+        }
+    }
+
+    #[test]
+    fn requests_stay_within_the_concurrency_bound() {
+        let pages: Vec<String> = (0..20).map(|i| format!("https://d.io/{i}")).collect();
+        let pages: Vec<&str> = pages.iter().map(String::as_str).collect();
+        let (running, peak) = (AtomicUsize::new(0), AtomicUsize::new(0));
+        let statuses = fetch_all(&pages, 3, |page| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(5));
+            running.fetch_sub(1, Ordering::SeqCst);
+            if page.ends_with("/7") {
+                PageStatus::Missing(404)
+            } else {
+                PageStatus::Found
+            }
+        });
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+        assert_eq!(statuses[7], PageStatus::Missing(404));
+        assert_eq!(
+            statuses.iter().filter(|s| **s == PageStatus::Found).count(),
+            19
+        );
+    }
+
+    #[test]
+    fn missing_pages_warn_and_being_offline_is_a_single_info() {
+        let urls = [
+            url("login", "https://d.io/api#login"),
+            url("logout", "https://d.io/api#logout"),
+            url("old", "https://d.io/old#old"),
+            url("team", "https://d.io/team"),
+            url("faq", "https://other.io/faq"),
+            url("help", "https://other.io/help"),
+        ];
+        let pages = [
+            "https://d.io/api",
+            "https://d.io/old",
+            "https://d.io/team",
+            "https://other.io/faq",
+            "https://other.io/help",
+        ];
+        let statuses = [
+            PageStatus::Found,
+            PageStatus::Missing(404),
+            PageStatus::Unconfirmed(403),
+            PageStatus::Unreachable("dns error".into()),
+            PageStatus::Unreachable("dns error".into()),
+        ];
+        let results = findings(&urls, &pages, &statuses);
+        let summary: Vec<(Severity, Option<&str>)> = results
+            .iter()
+            .map(|r| (r.severity, r.doc_id.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (Severity::Warning, Some("old")),
+                (Severity::Info, Some("team")),
+                (Severity::Info, Some("faq")),
+            ]
+        );
+        assert!(results[0]
+            .message
+            .contains("https://d.io/old#old responde 404"));
+        assert!(results[2]
+            .message
+            .contains("(dns error): 2 de 6 secciones sin verificar"));
+    }
+
+    #[test]
+    fn head_requests_hit_the_page_without_the_fragment() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let mut lines = Vec::new();
+            for stream in listener.incoming().take(2) {
+                let mut stream = stream.unwrap();
+                let mut buffer = [0; 1024];
+                let read = stream.read(&mut buffer).unwrap();
+                let request = String::from_utf8_lossy(&buffer[..read]).into_owned();
+                let line = request.lines().next().unwrap_or_default().to_string();
+                let status = if line.contains("/gone") {
+                    "404 Not Found"
+                } else {
+                    "200 OK"
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                )
+                .unwrap();
+                lines.push(line);
+            }
+            lines
+        });
+        let urls = [
+            url("a", &format!("{base}/api#a")),
+            url("b", &format!("{base}/api#b")),
+            url("gone", &format!("{base}/gone#gone")),
+        ];
+        let results = verify(&urls, &SiteConfig::default());
+        let mut lines = server.join().unwrap();
+        lines.sort();
+        assert_eq!(lines, ["HEAD /api HTTP/1.1", "HEAD /gone HTTP/1.1"]);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].doc_id.as_deref(), Some("gone"));
+        assert_eq!(results[0].severity, Severity::Warning);
+    }
+}
@@ -0,0 +1,379 @@
+//! Escritura transaccional de varios archivos (VUL-02).
+//!
+//! Scaffold y `--fix` pueden modificar más de un archivo por ejecución. Si
+//! el segundo falla (disco lleno, permiso denegado) no debe quedar el primero
+//! escrito: una anotación sin su marcador es un enlace roto que el usuario no
+//! creó. `Transaction` acumula el contenido nuevo en memoria y al confirmar:
+//!
+//! 1. comprueba que ningún destino sea de solo lectura;
+//! 2. escribe cada archivo en un temporal hermano y hace `fsync`;
+//! 3. renombra todos los temporales sobre sus destinos.
+//!
+//! Ante cualquier fallo se borran los temporales y se restauran los archivos
+//! ya renombrados desde la copia tomada antes de escribir; el error indica
+//! qué archivos se restauraron y cuáles no llegaron a tocarse.
+//! Es la única vía de escritura: `atomic_write` es una transacción de un archivo
+//! y `write_streamed` la variante de un archivo que no se acumula en memoria.
+
+use anyhow::{Context, Result};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::exit::Failure;
+use crate::parser::code_parser::safe_display;
+use crate::vfs;
+
+/// Sufijo de los temporales hermanos.
+const TMP_SUFFIX: &str = "tmp.docsguardwrite";
+
+/// Conjunto de escrituras pendientes.
+#[derive(Debug, Default)]
+pub struct Transaction {
+    staged: Vec<(PathBuf, Vec<u8>)>,
+}
+
+/// Estado previo de un destino, para restaurarlo.
+struct Backup {
+    /// Contenido original; `None` si el archivo no existía.
+    original: Option<Vec<u8>>,
+}
+
+impl Transaction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prepara `content` para `path`, reemplazando lo preparado antes para él.
+    pub fn stage(&mut self, path: &Path, content: impl Into<Vec<u8>>) {
+        let content = content.into();
+        match self.staged.iter_mut().find(|(p, _)| p == path) {
+            Some((_, existing)) => *existing = content,
+            None => self.staged.push((path.to_path_buf(), content)),
+        }
+    }
+
+    /// Contenido actual de `path` dentro de la transacción: lo preparado o,
+    /// si no hay nada, lo que hay en disco. Permite encadenar ediciones.
+    pub fn read_to_string(&self, path: &Path) -> Result<String> {
+        match self.staged.iter().find(|(p, _)| p == path) {
+            Some((_, content)) => String::from_utf8(content.clone())
+                .with_context(|| format!("Contenido no UTF-8 para: {}", safe_display(path))),
+            None => vfs::read_to_string(path)
+                .with_context(|| format!("No se pudo leer: {}", safe_display(path))),
+        }
+    }
+
+    /// Contenido preparado por archivo, en el orden en que se preparó
+    /// (`scaffold --emit-patch` lo compara con el disco sin escribirlo).
+    pub fn staged(&self) -> impl Iterator<Item = (&Path, &[u8])> {
+        self.staged.iter().map(|(p, c)| (p.as_path(), c.as_slice()))
+    }
+
+    /// Escribe todos los archivos o ninguno. Retorna las rutas escritas.
+    pub fn commit(self) -> Result<Vec<PathBuf>> {
+        self.commit_with(vfs::rename)
+    }
+
+    /// `commit` con el rename inyectable (los tests simulan fallos en él).
+    fn commit_with(
+        self,
+        rename: impl Fn(&Path, &Path) -> std::io::Result<()>,
+    ) -> Result<Vec<PathBuf>> {
+        let targets: Vec<&Path> = self.staged.iter().map(|(p, _)| p.as_path()).collect();
+
+        let mut backups = Vec::with_capacity(targets.len());
+        for path in &targets {
+            backups.push(backup(path).map_err(|e| {
+                e.context(Failure::input(report(
+                    "No se escribió ningún archivo",
+                    &[],
+                    &targets,
+                    &[],
+                )))
+            })?);
+        }
+
+        let mut temps: Vec<PathBuf> = Vec::with_capacity(targets.len());
+        for ((path, content), backup) in self.staged.iter().zip(&backups) {
+            let tmp = tmp_path(path);
+            let written = write_synced(
+                &tmp,
+                content,
+                backup.original.as_ref().map(|_| path.as_path()),
+            );
+            temps.push(tmp);
+            if let Err(e) = written {
+                remove_all(&temps);
+                return Err(e.context(report("No se escribió ningún archivo", &[], &targets, &[])));
+            }
+        }
+
+        for (i, (path, tmp)) in targets.iter().zip(&temps).enumerate() {
+            if let Err(e) = rename(tmp, path) {
+                remove_all(&temps[i..]);
+                let (restored, failed) = rollback(&targets[..i], &backups[..i]);
+                return Err(anyhow::Error::new(e)
+                    .context(format!("No se pudo renombrar a: {}", safe_display(path)))
+                    .context(report(
+                        "Cambios revertidos",
+                        &restored,
+                        &targets[i..],
+                        &failed,
+                    )));
+            }
+        }
+
+        Ok(targets.into_iter().map(Path::to_path_buf).collect())
+    }
+}
+
+/// Escribe `path` desde `write` sin tener el contenido entero en memoria
+/// (informes HTML con decenas de miles de hallazgos). Mismo temporal hermano,
+/// `fsync` y rename que `commit`; si `write` falla, el destino queda intacto.
+pub fn write_streamed(path: &Path, write: impl FnOnce(&mut dyn Write) -> Result<()>) -> Result<()> {
+    let exists = writable(path)?;
+    let tmp = tmp_path(path);
+    let result = (|| {
+        let context = || format!("No se pudo escribir temporal: {}", safe_display(&tmp));
+        let mut file = vfs::create(&tmp, exists.then_some(path)).with_context(context)?;
+        write(&mut file)?;
+        file.sync().with_context(context)?;
+        vfs::rename(&tmp, path)
+            .with_context(|| format!("No se pudo renombrar a: {}", safe_display(path)))
+    })();
+    if result.is_err() {
+        let _ = vfs::remove_file(&tmp);
+    }
+    result
+}
+
+/// Indica si `path` existe. Un destino de solo lectura se rechaza: el rename
+/// lo reemplazaría sin respetar el permiso.
+fn writable(path: &Path) -> Result<bool> {
+    let metadata = match vfs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => {
+            return Err(anyhow::Error::new(e)
+                .context(format!("No se pudo leer metadata: {}", safe_display(path))))
+        }
+    };
+    if metadata.readonly {
+        anyhow::bail!(Failure::input(format!(
+            "Archivo de solo lectura: {}",
+            safe_display(path)
+        )));
+    }
+    Ok(true)
+}
+
+/// Copia previa de `path`. Un destino de solo lectura aborta la transacción.
+fn backup(path: &Path) -> Result<Backup> {
+    if !writable(path)? {
+        return Ok(Backup { original: None });
+    }
+    let content =
+        vfs::read(path).with_context(|| format!("No se pudo leer: {}", safe_display(path)))?;
+    Ok(Backup {
+        original: Some(content),
+    })
+}
+
+/// Temporal hermano de `path` (mismo directorio, así el rename es atómico).
+fn tmp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(TMP_SUFFIX);
+    path.with_file_name(name)
+}
+
+/// Escribe `content` en `tmp` con los permisos de `target`, si existe, y
+/// hace `fsync`.
+fn write_synced(tmp: &Path, content: &[u8], target: Option<&Path>) -> Result<()> {
+    let context = || format!("No se pudo escribir temporal: {}", safe_display(tmp));
+    let mut file = vfs::create(tmp, target).with_context(context)?;
+    file.write_all(content).with_context(context)?;
+    file.sync().with_context(context)
+}
+
+fn remove_all(paths: &[PathBuf]) {
+    for path in paths {
+        let _ = vfs::remove_file(path);
+    }
+}
+
+/// Deshace los renombrados ya hechos. Retorna (restaurados, no restaurados).
+fn rollback<'a>(done: &[&'a Path], backups: &[Backup]) -> (Vec<&'a Path>, Vec<&'a Path>) {
+    let mut restored = Vec::new();
+    let mut failed = Vec::new();
+    for (path, backup) in done.iter().zip(backups) {
+        let result = match &backup.original {
+            Some(content) => {
+                let tmp = tmp_path(path);
+                write_synced(&tmp, content, Some(path))
+                    .and_then(|()| vfs::rename(&tmp, path).map_err(anyhow::Error::from))
+                    .inspect_err(|_| {
+                        let _ = vfs::remove_file(&tmp);
+                    })
+            }
+            None => vfs::remove_file(path).map_err(anyhow::Error::from),
+        };
+        match result {
+            Ok(()) => restored.push(*path),
+            Err(_) => failed.push(*path),
+        }
+    }
+    (restored, failed)
+}
+
+/// Mensaje con el estado en que queda cada archivo.
+fn report(summary: &str, restored: &[&Path], untouched: &[&Path], failed: &[&Path]) -> String {
+    let list = |paths: &[&Path]| {
+        paths
+            .iter()
+            .map(|p| safe_display(p))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let mut out = format!("{summary}:");
+    if !failed.is_empty() {
+        out.push_str(&format!(
+            "\n    -> NO restaurados (revisar a mano): {}",
+            list(failed)
+        ));
+    }
+    if !restored.is_empty() {
+        out.push_str(&format!("\n    -> Restaurados: {}", list(restored)));
+    }
+    if !untouched.is_empty() {
+        out.push_str(&format!("\n    -> Sin tocar: {}", list(untouched)));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::MemFs;
+
+    fn files(fs: &MemFs) -> Vec<String> {
+        vfs::scoped(fs.clone(), || vfs::read_dir(Path::new("/")))
+            .unwrap()
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn commit_writes_every_staged_file() {
+        let fs = MemFs::new();
+        fs.write("auth.ts", "function login() {}\n");
+        let (code, docs) = (Path::new("auth.ts"), Path::new("api.md"));
+
+        let mut tx = Transaction::new();
+        tx.stage(code, "/// @docs: [auth-login]\nfunction login() {}\n");
+        tx.stage(docs, "<!-- @docs-id: auth-login -->\n");
+        let written = vfs::scoped(fs.clone(), || {
+            let staged = tx.read_to_string(docs).unwrap();
+            tx.stage(docs, staged + "## login\n");
+            tx.commit().unwrap()
+        });
+        assert_eq!(written, vec![code.to_path_buf(), docs.to_path_buf()]);
+
+        assert!(fs.contents(code).unwrap().starts_with("/// @docs"));
+        assert_eq!(
+            fs.contents(docs).unwrap(),
+            "<!-- @docs-id: auth-login -->\n## login\n"
+        );
+        assert_eq!(files(&fs), vec!["api.md", "auth.ts"]);
+    }
+
+    #[test]
+    fn a_read_only_target_aborts_before_writing_anything() {
+        let fs = MemFs::new();
+        fs.write("auth.ts", "function login() {}\n");
+        fs.write("api.md", "# API\n");
+        fs.set_readonly("api.md");
+
+        let mut tx = Transaction::new();
+        tx.stage(
+            Path::new("auth.ts"),
+            "/// @docs: [auth-login]\nfunction login() {}\n",
+        );
+        tx.stage(
+            Path::new("api.md"),
+            "# API\n<!-- @docs-id: auth-login -->\n",
+        );
+        let error = vfs::scoped(fs.clone(), || tx.commit()).unwrap_err();
+
+        assert_eq!(crate::exit::exit_code_for(&error), crate::exit::INPUT);
+        let message = format!("{error:#}");
+        assert!(message.contains("solo lectura"), "{message}");
+        assert!(message.contains("Sin tocar:"), "{message}");
+        assert_eq!(fs.contents("auth.ts").unwrap(), "function login() {}\n");
+        assert_eq!(fs.contents("api.md").unwrap(), "# API\n");
+        assert_eq!(files(&fs), vec!["api.md", "auth.ts"]);
+    }
+
+    #[test]
+    fn a_failed_rename_rolls_back_the_files_already_replaced() {
+        let fs = MemFs::new();
+        fs.write("auth.ts", "function login() {}\n");
+        fs.write("api.md", "# API\n");
+
+        let mut tx = Transaction::new();
+        tx.stage(
+            Path::new("auth.ts"),
+            "/// @docs: [auth-login]\nfunction login() {}\n",
+        );
+        tx.stage(Path::new("new.md"), "<!-- @docs-id: auth-login -->\n");
+        tx.stage(
+            Path::new("api.md"),
+            "# API\n<!-- @docs-id: auth-login -->\n",
+        );
+        // Disco lleno al llegar al tercer archivo
+        let error = vfs::scoped(fs.clone(), || {
+            tx.commit_with(|from, to| {
+                if to.ends_with("api.md") {
+                    Err(std::io::Error::other("No space left on device"))
+                } else {
+                    vfs::rename(from, to)
+                }
+            })
+        })
+        .unwrap_err();
+
+        assert_eq!(crate::exit::exit_code_for(&error), crate::exit::INPUT);
+        let message = format!("{error:#}");
+        assert!(message.contains("Cambios revertidos"), "{message}");
+        assert!(message.contains("Restaurados:"), "{message}");
+        assert!(message.contains("auth.ts"), "{message}");
+        assert!(!message.contains("NO restaurados"), "{message}");
+        assert_eq!(fs.contents("auth.ts").unwrap(), "function login() {}\n");
+        assert_eq!(fs.contents("api.md").unwrap(), "# API\n");
+        assert_eq!(fs.contents("new.md"), None);
+        assert_eq!(files(&fs), vec!["api.md", "auth.ts"]);
+    }
+
+    #[test]
+    fn a_temporary_that_cannot_be_written_leaves_every_target_untouched() {
+        let fs = MemFs::new();
+        fs.write("auth.ts", "function login() {}\n");
+        fs.write("api.md", "# API\n");
+        fs.deny(tmp_path(Path::new("api.md")));
+
+        let mut tx = Transaction::new();
+        tx.stage(Path::new("auth.ts"), "// cambiado\n");
+        tx.stage(Path::new("api.md"), "# Cambiado\n");
+        let error = vfs::scoped(fs.clone(), || tx.commit()).unwrap_err();
+
+        let message = format!("{error:#}");
+        assert!(
+            message.contains("No se escribió ningún archivo"),
+            "{message}"
+        );
+        assert!(message.contains("permiso denegado"), "{message}");
+        assert_eq!(fs.contents("auth.ts").unwrap(), "function login() {}\n");
+        assert_eq!(files(&fs), vec!["api.md", "auth.ts"]);
+    }
+}
@@ -0,0 +1,483 @@
+//! Triage interactivo de hallazgos (`docsguard triage`).
+//!
+//! Recorre los errores y advertencias uno a uno y permite despacharlos:
+//! abrir en el editor, aplicar la corrección sugerida, añadirlos al baseline
+//! con un motivo o suprimirlos en línea. Tras cada acción que modifica
+//! archivos se re-valida, de modo que la cola se reduce en vivo.
+//! Cada escritura es atómica: abortar la sesión nunca deja archivos a medias.
+//! Un archivo con un conflicto de git sin resolver no se corrige ni se
+//! suprime en línea (ver `core::conflicts`).
+
+use anyhow::{Context, Result};
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::baseline::{self, BaselineEntry};
+use crate::config::Config;
+use crate::core::conflicts;
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::pipeline;
+use crate::core::types::{parse_location, CodeEntity, DocSection, Severity, ValidationResult};
+use crate::fix::{self, Fix};
+use crate::git;
+use crate::interactive::insert_lines_above;
+use crate::messages::{self, eprintln_tr, print_tr, println_tr};
+use crate::parser::code_parser::{self, atomic_write, safe_display, Language};
+use crate::parser::doc_parser;
+use crate::paths::ProjectPaths;
+use crate::vfs;
+
+/// Acción elegida por el usuario para un hallazgo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TriageAction {
+    OpenInEditor,
+    ApplyFix,
+    Baseline,
+    Suppress,
+    Skip,
+    Quit,
+}
+
+impl TriageAction {
+    fn label(&self) -> &'static str {
+        match self {
+            TriageAction::OpenInEditor => "Abrir en el editor",
+            TriageAction::ApplyFix => "Aplicar corrección sugerida",
+            TriageAction::Baseline => "Añadir al baseline con motivo",
+            TriageAction::Suppress => "Suprimir en línea",
+            TriageAction::Skip => "Omitir",
+            TriageAction::Quit => "Salir",
+        }
+    }
+}
+
+/// Ejecuta el triage interactivo sobre los hallazgos de `check`.
+pub fn run_triage(code_files: &[PathBuf], doc_file: &Path, project_root: &Path) -> Result<()> {
+    for code_file in code_files {
+        code_parser::require_file_exists(code_file, "código")?;
+    }
+    code_parser::require_file_exists(doc_file, "documentación")?;
+
+    println_tr!("DocsGuard Triage — Revisión interactiva de hallazgos\n");
+
+    // Las rutas de los hallazgos son relativas a la raíz: se resuelven para abrir y escribir.
+    let paths = ProjectPaths::new(project_root);
+    let mut skipped: HashSet<BaselineEntry> = HashSet::new();
+    let mut handled = 0;
+
+    loop {
+        let Collected {
+            code_entities,
+            doc_sections,
+            findings,
+            diagnostics,
+        } = collect_findings(code_files, doc_file, project_root)?;
+        let pending: Vec<&ValidationResult> = findings
+            .iter()
+            .filter(|r| !skipped.contains(&BaselineEntry::from_result(r)))
+            .collect();
+
+        let Some(finding) = pending.first().copied() else {
+            println_tr!("  No quedan hallazgos por revisar.");
+            break;
+        };
+
+        println_tr!(
+            "── Hallazgo 1/{} ──────────────────────────────",
+            pending.len()
+        );
+        print_tr!("{finding}");
+        println!();
+
+        let fix = fix::suggest_fix(
+            finding,
+            &fix::FixContext {
+                code_entities: &code_entities,
+                doc_sections: &doc_sections,
+                doc_file,
+                paths: &paths,
+            },
+        );
+        let entity = find_entity(finding, &code_entities);
+        // Nada se escribe en un archivo con un conflicto de git sin resolver
+        let refused = |file: &Path| conflicts::refuse_writes_to(&diagnostics, file).err();
+        let fix_refused = fix.as_ref().and_then(|fix| refused(fix.target()));
+        let entity_refused = entity.and_then(|entity| refused(&entity.file_path));
+        if let Some(error) = fix_refused.as_ref().or(entity_refused.as_ref()) {
+            eprintln!("  [!] {}\n", messages::tr(&error.to_string()));
+        }
+        let actions = available_actions(
+            finding,
+            fix.is_some() && fix_refused.is_none(),
+            entity.is_some() && entity_refused.is_none(),
+        );
+
+        match prompt_action(&actions, fix.as_ref())? {
+            TriageAction::OpenInEditor => {
+                if let Some((file, line)) = editor_target(finding) {
+                    open_in_editor(&paths.resolve(file), line)?;
+                }
+            }
+            TriageAction::ApplyFix => {
+                if let Some(fix) = fix {
+                    fix.apply()?;
+                    println_tr!("  → Corrección aplicada: {}.\n", fix.describe());
+                    handled += 1;
+                }
+            }
+            TriageAction::Baseline => {
+                let reason: String = Input::with_theme(&ColorfulTheme::default())
+                    .with_prompt(messages::tr("Motivo"))
+                    .allow_empty(true)
+                    .interact_text()
+                    .context("Error al leer la respuesta del usuario")?;
+                let reason = Some(reason.trim().to_string()).filter(|r| !r.is_empty());
+                let path =
+                    baseline::append_to_baseline(project_root, finding, &code_entities, reason)?;
+                println_tr!("  → Añadido al baseline ({}).\n", safe_display(&path));
+                if let Some(lock) = git::index_lock(project_root) {
+                    eprintln_tr!(
+                        "  [!] {} existe: hay otra operación de git en curso; el baseline no se podrá commitear hasta que termine.\n",
+                        safe_display(&lock)
+                    );
+                }
+                handled += 1;
+            }
+            TriageAction::Suppress => {
+                if let Some(entity) = entity {
+                    insert_suppression(&paths.resolve(&entity.file_path), entity, finding)?;
+                    println_tr!(
+                        "  → Suprimido en línea en {}.\n",
+                        safe_display(&entity.file_path)
+                    );
+                    handled += 1;
+                }
+            }
+            TriageAction::Skip => {
+                skipped.insert(BaselineEntry::from_result(finding));
+                println_tr!("  → Omitido.\n");
+            }
+            TriageAction::Quit => break,
+        }
+    }
+
+    println_tr!("── Resumen ──────────────────────────────────────");
+    println_tr!("  Resueltos: {}", handled);
+    println_tr!("  Omitidos: {}", skipped.len());
+
+    Ok(())
+}
+
+/// Lo parseado en una vuelta del triage y sus hallazgos pendientes.
+struct Collected {
+    code_entities: Vec<CodeEntity>,
+    doc_sections: Vec<DocSection>,
+    findings: Vec<ValidationResult>,
+    /// Notas del parseo: dicen qué archivos tienen un conflicto de git.
+    diagnostics: ParseDiagnostics,
+}
+
+/// Parsea y valida las entradas, devolviendo solo errores y advertencias
+/// que no estén ya en el baseline.
+fn collect_findings(
+    code_files: &[PathBuf],
+    doc_file: &Path,
+    project_root: &Path,
+) -> Result<Collected> {
+    let config = Config::load(project_root)?;
+    let mut diagnostics = ParseDiagnostics::default();
+    let code_entities = code_parser::parse_project_code(code_files, &config, &mut diagnostics)?;
+    let doc_sections = doc_parser::parse_docs_with_diagnostics(doc_file, &config, &mut diagnostics)
+        .context("Error al parsear el archivo de documentación")?;
+
+    let doc_source = doc_parser::read_markdown_file(doc_file)?;
+    let results = pipeline::run_validation(
+        pipeline::Inputs {
+            code_entities: &code_entities,
+            doc_sections: &doc_sections,
+            diagnostics: &diagnostics,
+            docs: &[pipeline::DocSource {
+                file: doc_file,
+                source: &doc_source,
+            }],
+            config: &config,
+        },
+        pipeline::Passes::default(),
+    )
+    .results;
+    let results = match baseline::Baseline::load(project_root)? {
+        Some(bl) => baseline::filter_baseline(&results, &bl, &code_entities).0,
+        None => results,
+    };
+
+    let findings = results
+        .into_iter()
+        .filter(|r| r.severity != Severity::Info)
+        .collect();
+    Ok(Collected {
+        code_entities,
+        doc_sections,
+        findings,
+        diagnostics,
+    })
+}
+
+/// Acciones disponibles para un hallazgo, según su contexto.
+fn available_actions(
+    finding: &ValidationResult,
+    has_fix: bool,
+    has_entity: bool,
+) -> Vec<TriageAction> {
+    let mut actions = Vec::new();
+    if editor_target(finding).is_some() {
+        actions.push(TriageAction::OpenInEditor);
+    }
+    if has_fix {
+        actions.push(TriageAction::ApplyFix);
+    }
+    actions.push(TriageAction::Baseline);
+    if has_entity {
+        actions.push(TriageAction::Suppress);
+    }
+    actions.push(TriageAction::Skip);
+    actions.push(TriageAction::Quit);
+    actions
+}
+
+/// Presenta el menú de acciones para un hallazgo.
+fn prompt_action(actions: &[TriageAction], fix: Option<&Fix>) -> Result<TriageAction> {
+    let items: Vec<String> = actions
+        .iter()
+        .map(|a| match (a, fix) {
+            (TriageAction::ApplyFix, Some(fix)) => {
+                format!(
+                    "{} ({})",
+                    messages::tr(a.label()),
+                    messages::tr(&fix.describe())
+                )
+            }
+            _ => messages::tr(a.label()).into_owned(),
+        })
+        .collect();
+
+    let selection = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt(messages::tr("¿Qué hacer con este hallazgo?"))
+        .items(&items)
+        .default(0)
+        .interact()
+        .context("Error al leer la respuesta del usuario")?;
+
+    Ok(actions[selection])
+}
+
+/// Ubicación a abrir en el editor: dónde insertar si el hallazgo lo sabe
+/// (`target`), si no la sección de docs y, sin ella, el código.
+fn editor_target(finding: &ValidationResult) -> Option<(&Path, usize)> {
+    finding
+        .target
+        .as_deref()
+        .or(finding.doc_location.as_deref())
+        .or(finding.code_location.as_deref())
+        .and_then(parse_location)
+}
+
+/// Entidad de código a la que pertenece el hallazgo.
+fn find_entity<'a>(
+    finding: &ValidationResult,
+    code_entities: &'a [CodeEntity],
+) -> Option<&'a CodeEntity> {
+    let location = finding.code_location.as_deref()?;
+    code_entities.iter().find(|e| e.location() == location)
+}
+
+/// Abre `$VISUAL`/`$EDITOR` (o `vi`) en el archivo y la línea indicados.
+fn open_in_editor(file: &Path, line: usize) -> Result<()> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let (program, args) = editor_command(&editor, file, line)
+        .with_context(|| format!("Editor inválido: '{}'", editor))?;
+
+    let status = std::process::Command::new(&program)
+        .args(&args)
+        .status()
+        .with_context(|| format!("No se pudo lanzar el editor '{}'", program))?;
+    if !status.success() {
+        eprintln_tr!("  [!] El editor terminó con estado {}", status);
+    }
+    Ok(())
+}
+
+/// Construye la invocación del editor con salto a línea cuando el editor lo soporta.
+fn editor_command(editor: &str, file: &Path, line: usize) -> Option<(String, Vec<String>)> {
+    let mut parts = editor.split_whitespace().map(String::from);
+    let program = parts.next()?;
+    let mut args: Vec<String> = parts.collect();
+
+    let name = Path::new(&program)
+        .file_stem()
+        .and_then(|n| n.to_str())
+        .unwrap_or("");
+    let file = file.display().to_string();
+
+    match name {
+        "vi" | "vim" | "nvim" | "nano" | "emacs" | "emacsclient" | "micro" | "kak" => {
+            args.push(format!("+{}", line));
+            args.push(file);
+        }
+        "code" | "codium" => {
+            args.push("--goto".into());
+            args.push(format!("{}:{}", file, line));
+        }
+        "subl" | "zed" | "hx" => args.push(format!("{}:{}", file, line)),
+        _ => args.push(file),
+    }
+
+    Some((program, args))
+}
+
+/// Inserta `docsguard-ignore: <regla>` justo encima de la función del hallazgo.
+///
+/// `code_file` es la ruta resuelta de `entity.file_path`.
+fn insert_suppression(
+    code_file: &Path,
+    entity: &CodeEntity,
+    finding: &ValidationResult,
+) -> Result<()> {
+    let prefix = Language::from_extension(code_file)?.line_comment_prefix();
+    let source = vfs::read_to_string(code_file)
+        .with_context(|| format!("No se pudo leer: {}", safe_display(code_file)))?;
+
+    let mut insertions = HashMap::new();
+    insertions.insert(
+        entity.line.saturating_sub(1),
+        suppression_comment(prefix, finding),
+    );
+    let updated = insert_lines_above(&source, &insertions);
+
+    atomic_write(code_file, updated.as_bytes())
+}
+
+/// Texto de la directiva de supresión para un hallazgo.
+fn suppression_comment(prefix: &str, finding: &ValidationResult) -> String {
+    format!(
+        "{} {}: {}",
+        prefix,
+        crate::core::suppression::IGNORE_DIRECTIVE,
+        finding.rule
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Rule;
+    use crate::core::validator;
+    use crate::parser::lang::typescript::parse_typescript_source;
+    use crate::vfs::MemFs;
+
+    fn finding(rule: Rule, code: Option<&str>, doc: Option<&str>) -> ValidationResult {
+        ValidationResult {
+            severity: Severity::Warning,
+            rule,
+            message: "msg".into(),
+            function_name: Some("login".into()),
+            code_location: code.map(String::from),
+            doc_id: Some("auth-login".into()),
+            doc_location: doc.map(String::from),
+            hint: None,
+            provenance: None,
+            related: Vec::new(),
+            target: None,
+            summary: None,
+            cause: None,
+            grace: None,
+        }
+    }
+
+    #[test]
+    fn editor_command_uses_plus_line_for_terminal_editors() {
+        let (program, args) = editor_command("nvim", Path::new("docs/api.md"), 12).unwrap();
+        assert_eq!(program, "nvim");
+        assert_eq!(args, vec!["+12", "docs/api.md"]);
+    }
+
+    #[test]
+    fn editor_command_keeps_extra_args_and_uses_goto_for_vscode() {
+        let (program, args) = editor_command("code -w", Path::new("a.md"), 3).unwrap();
+        assert_eq!(program, "code");
+        assert_eq!(args, vec!["-w", "--goto", "a.md:3"]);
+    }
+
+    #[test]
+    fn editor_command_unknown_editor_opens_file_only() {
+        let (_, args) = editor_command("/usr/bin/ed", Path::new("a.md"), 3).unwrap();
+        assert_eq!(args, vec!["a.md"]);
+        assert!(editor_command("   ", Path::new("a.md"), 3).is_none());
+    }
+
+    #[test]
+    fn editor_target_prefers_doc_location() {
+        let f = finding(Rule::MissingArg, Some("src/a.ts:4"), Some("docs/api.md:9"));
+        assert_eq!(editor_target(&f), Some((Path::new("docs/api.md"), 9)));
+        let f = finding(Rule::MissingDocSection, Some("src/a.ts:4"), None);
+        assert_eq!(editor_target(&f), Some((Path::new("src/a.ts"), 4)));
+        let f = ValidationResult {
+            target: Some("docs/api.md:12".into()),
+            ..f
+        };
+        assert_eq!(editor_target(&f), Some((Path::new("docs/api.md"), 12)));
+    }
+
+    #[test]
+    fn actions_depend_on_context() {
+        let orphan = finding(Rule::OrphanSection, None, None);
+        assert_eq!(
+            available_actions(&orphan, false, false),
+            vec![
+                TriageAction::Baseline,
+                TriageAction::Skip,
+                TriageAction::Quit
+            ]
+        );
+        let missing = finding(Rule::MissingDocSection, Some("src/a.ts:4"), None);
+        let actions = available_actions(&missing, true, true);
+        assert!(actions.contains(&TriageAction::ApplyFix));
+        assert!(actions.contains(&TriageAction::Suppress));
+        assert_eq!(actions[0], TriageAction::OpenInEditor);
+    }
+
+    #[test]
+    fn inserted_suppression_silences_the_finding() {
+        let dir = MemFs::project();
+        let code_file = dir.path().join("auth.ts");
+        dir.write(
+            &code_file,
+            "// @docs: [auth-login]\nfunction login(user: string) {}\n",
+        );
+
+        let entities = code_parser::parse_code_file(&code_file).unwrap();
+        let results = validator::validate_links(&entities, &[], &Default::default());
+        let error = results
+            .iter()
+            .find(|r| r.rule == Rule::MissingDocSection)
+            .unwrap();
+        insert_suppression(&code_file, &entities[0], error).unwrap();
+
+        let source = dir.contents(&code_file).unwrap();
+        assert_eq!(
+            source,
+            "// @docs: [auth-login]\n// docsguard-ignore: missing-doc-section\nfunction login(user: string) {}\n"
+        );
+        let entities = parse_typescript_source(
+            &source,
+            &code_file,
+            crate::parser::code_parser::AnnotationOptions::default(),
+            &mut ParseDiagnostics::default(),
+        )
+        .unwrap();
+        let results = validator::validate_links(&entities, &[], &Default::default());
+        assert!(results.iter().all(|r| r.rule != Rule::MissingDocSection));
+    }
+}
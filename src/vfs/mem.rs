@@ -0,0 +1,454 @@
+//! `MemFs`: el sistema de archivos en memoria de los tests.
+//!
+//! Se siembra con `write` y se activa con `vfs::scoped`; `deny` y
+//! `set_readonly` hacen fallar rutas concretas como lo haría el disco y
+//! `set_created` fija la fecha de creación que el disco registraría. Fuera
+//! de los tests aún no lo construye nadie.
+#![cfg_attr(not(test), allow(dead_code))]
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use super::{Metadata, Scope, SyncedFile, Vfs};
+
+/// Nodo de `MemFs`.
+#[derive(Debug, Clone)]
+enum Node {
+    File { content: Vec<u8>, readonly: bool },
+    Dir,
+}
+
+/// Sistema de archivos en memoria. Las rutas relativas cuelgan de `/`
+/// (el directorio de trabajo virtual) y se normalizan sin tocar nada real.
+/// Los clones comparten el mismo árbol: el test conserva uno para sembrar
+/// y mirar el resultado mientras el otro está activo con `scoped`.
+#[derive(Debug, Default, Clone)]
+pub struct MemFs {
+    inner: Arc<MemTree>,
+}
+
+#[derive(Debug, Default)]
+struct MemTree {
+    nodes: Mutex<BTreeMap<PathBuf, Node>>,
+    /// Rutas en las que toda operación falla con `PermissionDenied`.
+    denied: Mutex<Vec<PathBuf>>,
+    /// Fechas de creación de `set_created`; el resto de archivos no la tiene.
+    created: Mutex<BTreeMap<PathBuf, SystemTime>>,
+}
+
+impl MemFs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Escribe `content` en `path`, creando los directorios que falten.
+    pub fn write(&self, path: impl AsRef<Path>, content: impl AsRef<[u8]>) {
+        let path = absolute(path.as_ref());
+        let mut nodes = self.lock();
+        insert_parents(&mut nodes, &path);
+        nodes.insert(
+            path,
+            Node::File {
+                content: content.as_ref().to_vec(),
+                readonly: false,
+            },
+        );
+    }
+
+    /// Contenido de `path` como texto, si es un archivo.
+    pub fn contents(&self, path: impl AsRef<Path>) -> Option<String> {
+        match self.lock().get(&absolute(path.as_ref())) {
+            Some(Node::File { content, .. }) => String::from_utf8(content.clone()).ok(),
+            _ => None,
+        }
+    }
+
+    /// Un `MemFs` vacío con `/project` creado, activo en este hilo mientras
+    /// viva el proyecto: el equivalente en memoria de `tempfile::tempdir()`.
+    pub fn project() -> MemProject {
+        let fs = MemFs::new();
+        fs.mkdir(MemProject::ROOT);
+        MemProject {
+            _scope: super::install(fs.clone()),
+            fs,
+        }
+    }
+
+    /// Crea el directorio `path` y los que falten por encima.
+    pub fn mkdir(&self, path: impl AsRef<Path>) {
+        let path = absolute(path.as_ref());
+        let mut nodes = self.lock();
+        insert_parents(&mut nodes, &path);
+        nodes.insert(path, Node::Dir);
+    }
+
+    /// Marca `path` como de solo lectura.
+    pub fn set_readonly(&self, path: impl AsRef<Path>) {
+        if let Some(Node::File { readonly, .. }) = self.lock().get_mut(&absolute(path.as_ref())) {
+            *readonly = true;
+        }
+    }
+
+    /// Fija la fecha de creación de `path`.
+    pub fn set_created(&self, path: impl AsRef<Path>, created: SystemTime) {
+        self.inner
+            .created
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(absolute(path.as_ref()), created);
+    }
+
+    /// Hace fallar con `PermissionDenied` toda operación sobre `path`.
+    pub fn deny(&self, path: impl AsRef<Path>) {
+        self.inner
+            .denied
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(absolute(path.as_ref()));
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<PathBuf, Node>> {
+        self.inner.nodes.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Ruta absoluta de `path`, o el error si está denegada.
+    fn resolve(&self, path: &Path) -> io::Result<PathBuf> {
+        let path = absolute(path);
+        let denied = self.inner.denied.lock().unwrap_or_else(|e| e.into_inner());
+        if denied.contains(&path) {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("permiso denegado: {}", path.display()),
+            ));
+        }
+        Ok(path)
+    }
+}
+
+/// Proyecto de `MemFs::project`; se usa como el `MemFs` que lo contiene.
+pub struct MemProject {
+    fs: MemFs,
+    _scope: Scope,
+}
+
+impl MemProject {
+    const ROOT: &'static str = "/project";
+
+    /// Raíz del proyecto.
+    pub fn path(&self) -> &Path {
+        Path::new(Self::ROOT)
+    }
+}
+
+impl std::ops::Deref for MemProject {
+    type Target = MemFs;
+
+    fn deref(&self) -> &MemFs {
+        &self.fs
+    }
+}
+
+/// `path` absoluto y sin `.` ni `..`.
+fn absolute(path: &Path) -> PathBuf {
+    let mut out = PathBuf::from("/");
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => out.push(part),
+            Component::ParentDir => {
+                out.pop();
+            }
+            Component::RootDir | Component::Prefix(_) | Component::CurDir => {}
+        }
+    }
+    out
+}
+
+fn insert_parents(nodes: &mut BTreeMap<PathBuf, Node>, path: &Path) {
+    for parent in path.ancestors().skip(1) {
+        nodes.entry(parent.to_path_buf()).or_insert(Node::Dir);
+    }
+}
+
+fn not_found(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("no existe: {}", path.display()),
+    )
+}
+
+/// Archivo de `MemFs` en escritura: se guarda al hacer `sync`.
+struct MemFile {
+    fs: MemFs,
+    path: PathBuf,
+    content: Vec<u8>,
+    readonly: bool,
+}
+
+impl Write for MemFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.content.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl SyncedFile for MemFile {
+    fn sync(self: Box<Self>) -> io::Result<()> {
+        let MemFile {
+            fs,
+            path,
+            content,
+            readonly,
+        } = *self;
+        fs.lock().insert(path, Node::File { content, readonly });
+        Ok(())
+    }
+}
+
+impl Vfs for MemFs {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let path = self.resolve(path)?;
+        match self.lock().get(&path) {
+            Some(Node::File { content, .. }) => Ok(content.clone()),
+            Some(Node::Dir) => Err(io::Error::other(format!(
+                "es un directorio: {}",
+                path.display()
+            ))),
+            None => Err(not_found(&path)),
+        }
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+        let path = self.resolve(path)?;
+        match self.lock().get(&path) {
+            Some(Node::File { content, readonly }) => Ok(Metadata {
+                len: content.len() as u64,
+                is_dir: false,
+                readonly: *readonly,
+                is_symlink: false,
+                created: self
+                    .inner
+                    .created
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .get(&path)
+                    .copied(),
+            }),
+            Some(Node::Dir) => Ok(Metadata {
+                len: 0,
+                is_dir: true,
+                readonly: false,
+                is_symlink: false,
+                created: None,
+            }),
+            None => Err(not_found(&path)),
+        }
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let path = self.resolve(path)?;
+        let nodes = self.lock();
+        match nodes.get(&path) {
+            Some(Node::Dir) => Ok(nodes
+                .keys()
+                .filter(|p| p.parent() == Some(path.as_path()) && **p != path)
+                .cloned()
+                .collect()),
+            Some(Node::File { .. }) => Err(io::Error::other(format!(
+                "no es un directorio: {}",
+                path.display()
+            ))),
+            None => Err(not_found(&path)),
+        }
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        let path = self.resolve(path)?;
+        if self.lock().contains_key(&path) {
+            Ok(path)
+        } else {
+            Err(not_found(&path))
+        }
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        let path = self.resolve(path)?;
+        let mut nodes = self.lock();
+        if let Some(Node::File { .. }) = nodes.get(&path) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("es un archivo: {}", path.display()),
+            ));
+        }
+        insert_parents(&mut nodes, &path);
+        nodes.insert(path, Node::Dir);
+        Ok(())
+    }
+
+    fn create(
+        &self,
+        path: &Path,
+        permissions_of: Option<&Path>,
+    ) -> io::Result<Box<dyn SyncedFile>> {
+        let path = self.resolve(path)?;
+        let readonly = match permissions_of {
+            Some(source) => self.metadata(source)?.readonly,
+            None => false,
+        };
+        let nodes = self.lock();
+        let parent = path.parent().unwrap_or(Path::new("/"));
+        if !matches!(nodes.get(parent), Some(Node::Dir)) {
+            return Err(not_found(parent));
+        }
+        if let Some(Node::File { readonly: true, .. }) = nodes.get(&path) {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("solo lectura: {}", path.display()),
+            ));
+        }
+        drop(nodes);
+        Ok(Box::new(MemFile {
+            fs: self.clone(),
+            path,
+            content: Vec::new(),
+            readonly,
+        }))
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        let from = self.resolve(from)?;
+        let to = self.resolve(to)?;
+        let mut nodes = self.lock();
+        match nodes.remove(&from) {
+            Some(node @ Node::File { .. }) => {
+                nodes.insert(to, node);
+                Ok(())
+            }
+            Some(node) => {
+                nodes.insert(from.clone(), node);
+                Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    format!("MemFs no renombra directorios: {}", from.display()),
+                ))
+            }
+            None => Err(not_found(&from)),
+        }
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        let path = self.resolve(path)?;
+        let mut nodes = self.lock();
+        match nodes.get(&path) {
+            Some(Node::File { .. }) => {
+                nodes.remove(&path);
+                Ok(())
+            }
+            Some(Node::Dir) => Err(io::Error::other(format!(
+                "es un directorio: {}",
+                path.display()
+            ))),
+            None => Err(not_found(&path)),
+        }
+    }
+
+    fn append(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+        let mut existing = match self.read(path) {
+            Ok(existing) => existing,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e),
+        };
+        existing.extend_from_slice(content);
+        let mut file = self.create(path, None)?;
+        file.write_all(&existing)?;
+        file.sync()
+    }
+
+    /// Los permisos en memoria son solo `readonly`: basta con que exista.
+    fn set_executable(&self, path: &Path) -> io::Result<()> {
+        let path = self.resolve(path)?;
+        match self.lock().get(&path) {
+            Some(Node::File { .. }) => Ok(()),
+            Some(Node::Dir) => Err(io::Error::other(format!(
+                "es un directorio: {}",
+                path.display()
+            ))),
+            None => Err(not_found(&path)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::*;
+
+    #[test]
+    fn mem_fs_behaves_like_a_small_disk() {
+        let fs = MemFs::new();
+        fs.write("docs/api.md", "# API\n");
+        fs.write("./src/lib/../auth.ts", "export {}\n");
+        scoped(fs.clone(), || {
+            assert_eq!(read_to_string(Path::new("docs/api.md")).unwrap(), "# API\n");
+            assert!(is_file(Path::new("/src/auth.ts")));
+            assert!(is_dir(Path::new("src")));
+            assert_eq!(
+                canonicalize(Path::new("src/../docs/api.md")).unwrap(),
+                PathBuf::from("/docs/api.md")
+            );
+            assert_eq!(
+                walk(Path::new(".")).unwrap(),
+                vec![PathBuf::from("/docs/api.md"), PathBuf::from("/src/auth.ts")]
+            );
+
+            let mut file = create(Path::new("docs/new.md"), None).unwrap();
+            file.write_all(b"nuevo").unwrap();
+            assert!(!exists(Path::new("docs/new.md")), "antes de sync");
+            file.sync().unwrap();
+            rename(Path::new("docs/new.md"), Path::new("docs/api.md")).unwrap();
+            assert_eq!(read_to_string(Path::new("docs/api.md")).unwrap(), "nuevo");
+            assert_eq!(
+                read(Path::new("docs/new.md")).unwrap_err().kind(),
+                io::ErrorKind::NotFound
+            );
+            assert_eq!(
+                create(Path::new("missing/x.md"), None)
+                    .err()
+                    .unwrap()
+                    .kind(),
+                io::ErrorKind::NotFound
+            );
+        });
+        // Fuera de `scoped` se vuelve al disco real
+        assert!(!exists(Path::new("/docs/api.md")));
+    }
+
+    #[test]
+    fn denied_and_readonly_paths_fail_like_the_disk() {
+        let fs = MemFs::new();
+        fs.write("a.md", "a");
+        fs.write("b.md", "b");
+        fs.deny("a.md");
+        fs.set_readonly("b.md");
+        scoped(fs.clone(), || {
+            assert_eq!(
+                read(Path::new("a.md")).unwrap_err().kind(),
+                io::ErrorKind::PermissionDenied
+            );
+            assert!(metadata(Path::new("b.md")).unwrap().readonly);
+            assert_eq!(
+                create(Path::new("b.md"), None).err().unwrap().kind(),
+                io::ErrorKind::PermissionDenied
+            );
+            // Los permisos se copian del archivo indicado
+            let file = create(Path::new("c.md"), Some(Path::new("b.md"))).unwrap();
+            file.sync().unwrap();
+            assert!(metadata(Path::new("c.md")).unwrap().readonly);
+        });
+    }
+}
@@ -0,0 +1,357 @@
+//! Acceso al sistema de archivos.
+//!
+//! Ningún módulo llama a `std::fs`: leen, escriben y recorren archivos con
+//! las funciones de este módulo, que delegan en el `Vfs` activo. La CLI
+//! ejecuta cada comando sobre `OsFs`, el disco real. `MemFs` es un árbol en
+//! memoria: los tests lo siembran con el contenido que necesitan (sin
+//! directorios temporales) y pueden hacer fallar una ruta concreta
+//! (`MemFs::deny`) para cubrir los caminos de error; es lo que sembraría un
+//! servidor LSP con los buffers abiertos en el editor. `RevisionFs` sirve
+//! parte del árbol desde una revisión git (`check --code-ref`) y `StdinFs`,
+//! la ruta `-` desde la entrada estándar; la CLI envuelve siempre `OsFs` en
+//! este último.
+//!
+//! El `Vfs` activo es por hilo (`scoped`) en lugar de un parámetro más en
+//! cada función: lo que un comando ejecute en otros hilos ve `OsFs`. Solo
+//! `main.rs` y `watch`, que espera eventos del disco real, usan `std::fs`,
+//! además de los tests que necesitan el disco (git, memoria del HTML).
+
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use std::time::SystemTime;
+
+mod mem;
+mod revision;
+mod stdin;
+
+#[cfg(test)]
+pub use mem::{MemFs, MemProject};
+pub use revision::{RevisionFs, RevisionSide};
+pub use stdin::{is_stdin, StdinFs, STDIN};
+
+/// Lo que los módulos necesitan saber de un archivo o directorio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metadata {
+    /// Tamaño en bytes (0 para un directorio).
+    pub len: u64,
+    pub is_dir: bool,
+    pub readonly: bool,
+    /// La ruta es un enlace simbólico (el resto describe su destino).
+    pub is_symlink: bool,
+    /// Creación del archivo, donde el sistema la registra; `None` en una
+    /// revisión git o en stdin.
+    pub created: Option<SystemTime>,
+}
+
+/// Resultado de `Vfs::read_bounded`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Bounded {
+    Contents(String),
+    /// El archivo supera el límite; su tamaño en bytes.
+    TooLarge(u64),
+}
+
+/// Archivo abierto para escribir con `Vfs::create`. El contenido no cuenta
+/// como escrito hasta `sync`.
+pub trait SyncedFile: Write {
+    /// Vuelca el contenido y hace `fsync`.
+    fn sync(self: Box<Self>) -> io::Result<()>;
+}
+
+/// Operaciones sobre archivos que usa DocsGuard.
+///
+/// Los errores son `io::Error` con el `ErrorKind` que daría el disco
+/// (`NotFound`, `PermissionDenied`…): quien llama añade el contexto.
+pub trait Vfs: Send + Sync {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        String::from_utf8(self.read(path)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Como `read_to_string` si `path` no pasa de `limit` bytes; si pasa,
+    /// solo su tamaño.
+    fn read_bounded(&self, path: &Path, limit: u64) -> io::Result<Bounded> {
+        let len = self.metadata(path)?.len;
+        if len > limit {
+            return Ok(Bounded::TooLarge(len));
+        }
+        self.read_to_string(path).map(Bounded::Contents)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<Metadata>;
+
+    fn exists(&self, path: &Path) -> bool {
+        self.metadata(path).is_ok()
+    }
+
+    /// Entradas del directorio, ordenadas.
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+
+    /// Archivos bajo `root`, en todos los niveles, ordenados.
+    fn walk(&self, root: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        let mut pending = vec![root.to_path_buf()];
+        while let Some(dir) = pending.pop() {
+            for path in self.read_dir(&dir)? {
+                if self.metadata(&path)?.is_dir {
+                    pending.push(path);
+                } else {
+                    files.push(path);
+                }
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf>;
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()>;
+
+    /// Crea (o trunca) `path` para escribirlo, con los permisos que tenga
+    /// `permissions_of` si se indica.
+    fn create(&self, path: &Path, permissions_of: Option<&Path>)
+        -> io::Result<Box<dyn SyncedFile>>;
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()>;
+
+    fn remove_file(&self, path: &Path) -> io::Result<()>;
+
+    /// Añade `content` al final de `path`, creándolo si no existe.
+    fn append(&self, path: &Path, content: &[u8]) -> io::Result<()>;
+
+    /// Da permiso de ejecución a `path` a quien puede leerlo (hooks de git);
+    /// sin efecto donde no hay bits de permisos.
+    fn set_executable(&self, path: &Path) -> io::Result<()>;
+}
+
+/// El disco real.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OsFs;
+
+struct OsFile {
+    out: io::BufWriter<std::fs::File>,
+    permissions: Option<std::fs::Permissions>,
+}
+
+impl Write for OsFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.out.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+impl SyncedFile for OsFile {
+    fn sync(self: Box<Self>) -> io::Result<()> {
+        let OsFile { out, permissions } = *self;
+        let file = out.into_inner().map_err(|e| e.into_error())?;
+        if let Some(permissions) = permissions {
+            file.set_permissions(permissions)?;
+        }
+        file.sync_all()
+    }
+}
+
+impl Vfs for OsFs {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        std::fs::read(path)
+    }
+
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    /// VUL-03: el tamaño y la lectura salen del mismo descriptor, sin
+    /// ventana TOCTOU entre `metadata()` y `read_to_string()`.
+    fn read_bounded(&self, path: &Path, limit: u64) -> io::Result<Bounded> {
+        use std::io::Read;
+        let mut file = std::fs::File::open(path)?;
+        let len = file.metadata()?.len();
+        if len > limit {
+            return Ok(Bounded::TooLarge(len));
+        }
+        let mut source = String::with_capacity(len as usize);
+        file.read_to_string(&mut source)?;
+        Ok(Bounded::Contents(source))
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+        let metadata = std::fs::metadata(path)?;
+        Ok(Metadata {
+            len: metadata.len(),
+            is_dir: metadata.is_dir(),
+            readonly: metadata.permissions().readonly(),
+            is_symlink: std::fs::symlink_metadata(path)?.file_type().is_symlink(),
+            created: metadata.created().ok(),
+        })
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut entries = std::fs::read_dir(path)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        entries.sort();
+        Ok(entries)
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        std::fs::canonicalize(path)
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        std::fs::create_dir_all(path)
+    }
+
+    fn create(
+        &self,
+        path: &Path,
+        permissions_of: Option<&Path>,
+    ) -> io::Result<Box<dyn SyncedFile>> {
+        let permissions = match permissions_of {
+            Some(source) => Some(std::fs::metadata(source)?.permissions()),
+            None => None,
+        };
+        let file = std::fs::File::create(path)?;
+        Ok(Box::new(OsFile {
+            out: io::BufWriter::new(file),
+            permissions,
+        }))
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        std::fs::rename(from, to)
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        std::fs::remove_file(path)
+    }
+
+    fn append(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?
+            .write_all(content)
+    }
+
+    #[cfg(unix)]
+    fn set_executable(&self, path: &Path) -> io::Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+        let mut permissions = std::fs::metadata(path)?.permissions();
+        let mode = permissions.mode();
+        permissions.set_mode(mode | (mode & 0o444) >> 2);
+        std::fs::set_permissions(path, permissions)
+    }
+
+    #[cfg(not(unix))]
+    fn set_executable(&self, path: &Path) -> io::Result<()> {
+        std::fs::metadata(path).map(|_| ())
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<dyn Vfs>>> = const { RefCell::new(None) };
+}
+
+/// `Vfs` activo en este hilo: el de `scoped` o, por defecto, `OsFs`.
+pub fn current() -> Arc<dyn Vfs> {
+    static OS: OnceLock<Arc<dyn Vfs>> = OnceLock::new();
+    CURRENT
+        .with(|current| current.borrow().clone())
+        .unwrap_or_else(|| Arc::clone(OS.get_or_init(|| Arc::new(OsFs))))
+}
+
+/// Deja `vfs` activo en este hilo hasta que se suelte el `Scope`, que
+/// restaura el anterior.
+pub fn install(vfs: impl Vfs + 'static) -> Scope {
+    let vfs: Arc<dyn Vfs> = Arc::new(vfs);
+    Scope(CURRENT.with(|current| current.borrow_mut().replace(vfs)))
+}
+
+/// Guarda de `install`.
+#[must_use]
+pub struct Scope(Option<Arc<dyn Vfs>>);
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// Ejecuta `f` con `vfs` como sistema de archivos de este hilo.
+pub fn scoped<T>(vfs: impl Vfs + 'static, f: impl FnOnce() -> T) -> T {
+    let _scope = install(vfs);
+    f()
+}
+
+pub fn read(path: &Path) -> io::Result<Vec<u8>> {
+    current().read(path)
+}
+
+pub fn read_to_string(path: &Path) -> io::Result<String> {
+    current().read_to_string(path)
+}
+
+pub fn read_bounded(path: &Path, limit: u64) -> io::Result<Bounded> {
+    current().read_bounded(path, limit)
+}
+
+pub fn metadata(path: &Path) -> io::Result<Metadata> {
+    current().metadata(path)
+}
+
+pub fn exists(path: &Path) -> bool {
+    current().exists(path)
+}
+
+pub fn is_file(path: &Path) -> bool {
+    metadata(path).is_ok_and(|m| !m.is_dir)
+}
+
+pub fn is_dir(path: &Path) -> bool {
+    metadata(path).is_ok_and(|m| m.is_dir)
+}
+
+pub fn read_dir(path: &Path) -> io::Result<Vec<PathBuf>> {
+    current().read_dir(path)
+}
+
+pub fn walk(root: &Path) -> io::Result<Vec<PathBuf>> {
+    current().walk(root)
+}
+
+pub fn canonicalize(path: &Path) -> io::Result<PathBuf> {
+    current().canonicalize(path)
+}
+
+pub fn create_dir_all(path: &Path) -> io::Result<()> {
+    current().create_dir_all(path)
+}
+
+pub fn create(path: &Path, permissions_of: Option<&Path>) -> io::Result<Box<dyn SyncedFile>> {
+    current().create(path, permissions_of)
+}
+
+pub fn rename(from: &Path, to: &Path) -> io::Result<()> {
+    current().rename(from, to)
+}
+
+pub fn remove_file(path: &Path) -> io::Result<()> {
+    current().remove_file(path)
+}
+
+pub fn append(path: &Path, content: &[u8]) -> io::Result<()> {
+    current().append(path, content)
+}
+
+pub fn set_executable(path: &Path) -> io::Result<()> {
+    current().set_executable(path)
+}
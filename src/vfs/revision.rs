@@ -0,0 +1,427 @@
+//! Archivos de una revisión git (`check --code-ref`/`--docs-ref`).
+//!
+//! `RevisionFs` envuelve otro `Vfs`: las rutas bajo las raíces de una
+//! `RevisionSide` se leen de la revisión (`git show <ref>:./ruta`) y el resto,
+//! del que envuelve. Así un `check` desde main valida `docs/v2` contra el
+//! código de `release/2.x` sin un worktree. Las raíces y las rutas son
+//! relativas a `--project-root`; `.docsguard` (configuración, baseline)
+//! siempre es la del árbol de trabajo.
+//!
+//! Lo que viene de una revisión no se escribe: crear, renombrar o borrar
+//! bajo esas raíces es `PermissionDenied`.
+
+use std::collections::{BTreeSet, HashMap};
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+
+use super::{Bounded, Metadata, SyncedFile, Vfs};
+use crate::baseline::DOCSGUARD_DIR;
+use crate::git;
+
+/// Rutas que se leen de una revisión.
+#[derive(Debug, Clone)]
+pub struct RevisionSide {
+    /// Referencia git tal como se indicó (`release/2.x`).
+    pub rev: String,
+    /// Archivos o directorios, relativos al directorio actual o absolutos.
+    pub roots: Vec<PathBuf>,
+    /// Rutas bajo `roots` que siguen saliendo del `Vfs` envuelto.
+    pub except: Vec<PathBuf>,
+}
+
+/// Una `RevisionSide` con las rutas ya relativas a la raíz del proyecto.
+#[derive(Debug)]
+struct Side {
+    rev: String,
+    roots: Vec<PathBuf>,
+    except: Vec<PathBuf>,
+    /// Archivos de la revisión.
+    files: BTreeSet<PathBuf>,
+    /// Contenido de los ya leídos.
+    contents: Mutex<HashMap<PathBuf, Arc<Vec<u8>>>>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    project_root: PathBuf,
+    canonical_root: PathBuf,
+    sides: Vec<Side>,
+}
+
+/// `Vfs` que sirve unas rutas desde revisiones git. Clonarlo comparte la
+/// caché: `check` instala uno y conserva otro para `locate`.
+#[derive(Clone)]
+pub struct RevisionFs {
+    inner: Arc<dyn Vfs>,
+    shared: Arc<Shared>,
+}
+
+impl RevisionFs {
+    /// Envuelve `inner`. Falla si alguna referencia no existe.
+    pub fn new(inner: Arc<dyn Vfs>, project_root: &Path, sides: &[RevisionSide]) -> Result<Self> {
+        let project_root = lexical_absolute(project_root)?;
+        let canonical_root = inner
+            .canonicalize(&project_root)
+            .unwrap_or_else(|_| project_root.clone());
+        let relative = |path: &Path| -> Result<PathBuf> {
+            let path = lexical_absolute(path)?;
+            Ok(strip_root(&path, &project_root, &canonical_root).unwrap_or(path))
+        };
+        let mut resolved = Vec::new();
+        for side in sides {
+            resolved.push(Side {
+                rev: side.rev.clone(),
+                roots: side
+                    .roots
+                    .iter()
+                    .map(|p| relative(p))
+                    .collect::<Result<_>>()?,
+                except: side
+                    .except
+                    .iter()
+                    .map(|p| relative(p))
+                    .collect::<Result<_>>()?,
+                files: git::tree_files(&project_root, &side.rev)?
+                    .into_iter()
+                    .collect(),
+                contents: Mutex::default(),
+            });
+        }
+        Ok(RevisionFs {
+            inner,
+            shared: Arc::new(Shared {
+                project_root,
+                canonical_root,
+                sides: resolved,
+            }),
+        })
+    }
+
+    /// `location` (`archivo:línea`, relativa a la raíz o absoluta) con la
+    /// revisión tras el archivo si sale de una: `auth.ts@release/2.x:42`.
+    pub fn locate(&self, location: &str) -> String {
+        let (file, line) = match location.rsplit_once(':') {
+            Some((file, line)) if line.parse::<usize>().is_ok() => (file, Some(line)),
+            _ => (location, None),
+        };
+        let path = self.shared.project_root.join(file);
+        match self.side(&path) {
+            Some((index, _)) => {
+                let rev = &self.shared.sides[index].rev;
+                match line {
+                    Some(line) => format!("{}@{}:{}", file, rev, line),
+                    None => format!("{}@{}", file, rev),
+                }
+            }
+            None => location.to_string(),
+        }
+    }
+
+    /// Lado que sirve `path` y la ruta relativa a la raíz.
+    fn side(&self, path: &Path) -> Option<(usize, PathBuf)> {
+        let shared = &self.shared;
+        let path = lexical_absolute(path).ok()?;
+        let relative = strip_root(&path, &shared.project_root, &shared.canonical_root)?;
+        if relative.starts_with(DOCSGUARD_DIR) {
+            return None;
+        }
+        let index = shared.sides.iter().position(|side| {
+            side.roots.iter().any(|root| relative.starts_with(root))
+                && !side
+                    .except
+                    .iter()
+                    .any(|except| relative.starts_with(except))
+        })?;
+        Some((index, relative))
+    }
+
+    fn contents(&self, index: usize, relative: &Path) -> io::Result<Arc<Vec<u8>>> {
+        let side = &self.shared.sides[index];
+        if !side.files.contains(relative) {
+            return Err(not_found(relative, &side.rev));
+        }
+        let mut cache = side.contents.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(contents) = cache.get(relative) {
+            return Ok(Arc::clone(contents));
+        }
+        let contents = git::show_file(&self.shared.project_root, &side.rev, relative)
+            .map_err(|e| io::Error::other(format!("{:#}", e)))?
+            .ok_or_else(|| not_found(relative, &side.rev))?;
+        let contents = Arc::new(contents.into_bytes());
+        cache.insert(relative.to_path_buf(), Arc::clone(&contents));
+        Ok(contents)
+    }
+
+    /// Archivos de la revisión bajo el directorio `relative`.
+    fn children<'a>(&'a self, index: usize, relative: &'a Path) -> impl Iterator<Item = &'a Path> {
+        self.shared.sides[index]
+            .files
+            .iter()
+            .filter_map(move |file| file.strip_prefix(relative).ok())
+            .filter(|rest| !rest.as_os_str().is_empty())
+    }
+
+    /// Error de las escrituras en rutas de una revisión.
+    fn deny_write(&self, path: &Path) -> io::Result<()> {
+        match self.side(path) {
+            Some((index, _)) => Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!(
+                    "{} se lee de la revisión '{}' y no se puede modificar",
+                    path.display(),
+                    self.shared.sides[index].rev
+                ),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Vfs for RevisionFs {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        match self.side(path) {
+            Some((index, relative)) => Ok(self.contents(index, &relative)?.to_vec()),
+            None => self.inner.read(path),
+        }
+    }
+
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        match self.side(path) {
+            Some(_) => String::from_utf8(self.read(path)?)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            None => self.inner.read_to_string(path),
+        }
+    }
+
+    fn read_bounded(&self, path: &Path, limit: u64) -> io::Result<Bounded> {
+        match self.side(path) {
+            Some((index, relative)) => {
+                let contents = self.contents(index, &relative)?;
+                if contents.len() as u64 > limit {
+                    return Ok(Bounded::TooLarge(contents.len() as u64));
+                }
+                String::from_utf8(contents.to_vec())
+                    .map(Bounded::Contents)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            None => self.inner.read_bounded(path, limit),
+        }
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+        let Some((index, relative)) = self.side(path) else {
+            return self.inner.metadata(path);
+        };
+        let is_dir = !self.shared.sides[index].files.contains(&relative);
+        if is_dir && self.children(index, &relative).next().is_none() {
+            return Err(not_found(&relative, &self.shared.sides[index].rev));
+        }
+        let len = match is_dir {
+            true => 0,
+            false => self.contents(index, &relative)?.len() as u64,
+        };
+        Ok(Metadata {
+            len,
+            is_dir,
+            readonly: true,
+            is_symlink: false,
+            created: None,
+        })
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let Some((index, relative)) = self.side(path) else {
+            return self.inner.read_dir(path);
+        };
+        let entries: BTreeSet<PathBuf> = self
+            .children(index, &relative)
+            .filter_map(|rest| rest.components().next())
+            .map(|first| path.join(first))
+            .collect();
+        if entries.is_empty() {
+            return Err(not_found(&relative, &self.shared.sides[index].rev));
+        }
+        Ok(entries.into_iter().collect())
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        match self.side(path) {
+            Some((_, relative)) => {
+                self.metadata(path)?;
+                Ok(self.shared.canonical_root.join(relative))
+            }
+            None => self.inner.canonicalize(path),
+        }
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        self.deny_write(path)?;
+        self.inner.create_dir_all(path)
+    }
+
+    fn create(
+        &self,
+        path: &Path,
+        permissions_of: Option<&Path>,
+    ) -> io::Result<Box<dyn SyncedFile>> {
+        self.deny_write(path)?;
+        self.inner.create(path, permissions_of)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        self.deny_write(from)?;
+        self.deny_write(to)?;
+        self.inner.rename(from, to)
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        self.deny_write(path)?;
+        self.inner.remove_file(path)
+    }
+
+    fn append(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+        self.deny_write(path)?;
+        self.inner.append(path, content)
+    }
+
+    fn set_executable(&self, path: &Path) -> io::Result<()> {
+        self.deny_write(path)?;
+        self.inner.set_executable(path)
+    }
+}
+
+fn not_found(relative: &Path, rev: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("{} no existe en la revisión '{}'", relative.display(), rev),
+    )
+}
+
+/// Ruta absoluta sin `.` ni `..`, sin consultar el disco.
+fn lexical_absolute(path: &Path) -> io::Result<PathBuf> {
+    let mut out = PathBuf::new();
+    for component in std::path::absolute(path)?.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            component => out.push(component),
+        }
+    }
+    Ok(out)
+}
+
+/// `path` relativa a la raíz, en su forma tal cual o canónica.
+fn strip_root(path: &Path, root: &Path, canonical_root: &Path) -> Option<PathBuf> {
+    path.strip_prefix(root)
+        .or_else(|_| path.strip_prefix(canonical_root))
+        .ok()
+        .map(Path::to_path_buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::OsFs;
+    use std::process::Command;
+
+    fn run(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?}", args);
+    }
+
+    /// Repositorio con `src/a.ts` y `docs/api.md` en `v1`, cambiados después.
+    fn repo() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        run(root, &["init", "-q"]);
+        std::fs::create_dir_all(root.join("src/nested")).unwrap();
+        std::fs::create_dir(root.join("docs")).unwrap();
+        std::fs::write(root.join("src/a.ts"), "v1\n").unwrap();
+        std::fs::write(root.join("src/nested/b.ts"), "b\n").unwrap();
+        std::fs::write(root.join("docs/api.md"), "docs v1\n").unwrap();
+        run(root, &["add", "."]);
+        run(root, &["commit", "-q", "-m", "v1"]);
+        run(root, &["tag", "v1"]);
+        std::fs::write(root.join("src/a.ts"), "v2\n").unwrap();
+        std::fs::write(root.join("src/new.ts"), "nuevo\n").unwrap();
+        std::fs::write(root.join("docs/api.md"), "docs v2\n").unwrap();
+        dir
+    }
+
+    fn code_at_v1(root: &Path) -> RevisionFs {
+        let side = RevisionSide {
+            rev: "v1".into(),
+            roots: vec![root.to_path_buf()],
+            except: vec![root.join("docs")],
+        };
+        RevisionFs::new(Arc::new(OsFs), root, &[side]).unwrap()
+    }
+
+    #[test]
+    fn paths_under_a_side_come_from_the_revision() {
+        let dir = repo();
+        let root = dir.path();
+        let fs = code_at_v1(root);
+
+        assert_eq!(fs.read_to_string(&root.join("src/a.ts")).unwrap(), "v1\n");
+        let err = fs.read_to_string(&root.join("src/new.ts")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        // Las excepciones y `.docsguard` salen del árbol de trabajo
+        assert_eq!(
+            fs.read_to_string(&root.join("docs/api.md")).unwrap(),
+            "docs v2\n"
+        );
+        assert_eq!(
+            fs.walk(&root.join("src")).unwrap(),
+            [root.join("src/a.ts"), root.join("src/nested/b.ts")]
+        );
+        assert!(fs.metadata(&root.join("src/a.ts")).unwrap().readonly);
+        assert!(fs.metadata(&root.join("src/nested")).unwrap().is_dir);
+    }
+
+    #[test]
+    fn writes_under_a_side_are_denied() {
+        let dir = repo();
+        let root = dir.path();
+        let fs = code_at_v1(root);
+        let err = fs.append(&root.join("src/a.ts"), b"x").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        fs.create_dir_all(&root.join(".docsguard")).unwrap();
+        fs.append(&root.join(".docsguard/notes"), b"x").unwrap();
+    }
+
+    #[test]
+    fn locations_from_a_revision_carry_the_ref() {
+        let dir = repo();
+        let fs = code_at_v1(dir.path());
+        assert_eq!(fs.locate("src/a.ts:42"), "src/a.ts@v1:42");
+        assert_eq!(fs.locate("src/a.ts"), "src/a.ts@v1");
+        assert_eq!(fs.locate("docs/api.md:3"), "docs/api.md:3");
+    }
+
+    #[test]
+    fn an_unknown_ref_is_a_usage_error() {
+        let dir = repo();
+        let side = RevisionSide {
+            rev: "release/9.x".into(),
+            roots: vec![dir.path().to_path_buf()],
+            except: vec![],
+        };
+        let err = RevisionFs::new(Arc::new(OsFs), dir.path(), &[side])
+            .err()
+            .unwrap();
+        assert_eq!(crate::exit::exit_code_for(&err), crate::exit::USAGE);
+    }
+}
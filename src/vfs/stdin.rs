@@ -0,0 +1,242 @@
+//! La entrada estándar como archivo (`docsguard check - docs/api.md`).
+//!
+//! `StdinFs` envuelve otro `Vfs` y sirve la ruta `-` desde stdin: así un
+//! editor valida un buffer sin guardar pasándolo por una tubería, y el resto
+//! de módulos lo leen como cualquier archivo (tamaño incluido, de modo que
+//! el límite de los parsers también protege de una entrada sin fin). Stdin
+//! se lee la primera vez que alguien pregunta por `-` y una sola vez; de lo
+//! que pase del límite solo se cuentan los bytes.
+//!
+//! `-` no se escribe: crear, renombrar o borrar esa ruta es
+//! `PermissionDenied` (`check --fix` sobre stdin no tiene dónde escribir).
+
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use super::{Bounded, Metadata, SyncedFile, Vfs};
+
+/// Ruta que representa la entrada estándar.
+pub const STDIN: &str = "-";
+
+/// Bytes que se guardan de stdin (el límite de tamaño de los parsers).
+const STDIN_LIMIT: u64 = 10 * 1024 * 1024;
+
+/// `path` es la entrada estándar.
+pub fn is_stdin(path: &Path) -> bool {
+    path == Path::new(STDIN)
+}
+
+/// Lo leído de stdin.
+#[derive(Debug)]
+enum Input {
+    Contents(Vec<u8>),
+    /// Pasaba de `STDIN_LIMIT`; su tamaño en bytes.
+    TooLarge(u64),
+    Failed(io::ErrorKind, String),
+}
+
+/// `Vfs` que sirve `-` desde la entrada estándar.
+pub struct StdinFs {
+    inner: Arc<dyn Vfs>,
+    source: Mutex<Option<Box<dyn Read + Send>>>,
+    input: OnceLock<Input>,
+}
+
+impl StdinFs {
+    /// Envuelve `inner` con la entrada estándar del proceso.
+    pub fn new(inner: Arc<dyn Vfs>) -> Self {
+        Self::with_reader(inner, io::stdin())
+    }
+
+    /// Envuelve `inner` sirviendo `-` desde `reader`.
+    pub fn with_reader(inner: Arc<dyn Vfs>, reader: impl Read + Send + 'static) -> Self {
+        StdinFs {
+            inner,
+            source: Mutex::new(Some(Box::new(reader))),
+            input: OnceLock::new(),
+        }
+    }
+
+    fn input(&self) -> &Input {
+        self.input.get_or_init(|| {
+            let Some(reader) = self.source.lock().unwrap_or_else(|e| e.into_inner()).take() else {
+                return Input::Failed(io::ErrorKind::Other, "stdin ya consumido".to_string());
+            };
+            match read_limited(reader, STDIN_LIMIT) {
+                Ok(input) => input,
+                Err(e) => Input::Failed(e.kind(), e.to_string()),
+            }
+        })
+    }
+
+    fn contents(&self) -> io::Result<&[u8]> {
+        match self.input() {
+            Input::Contents(bytes) => Ok(bytes),
+            Input::TooLarge(len) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("la entrada estándar supera el límite ({} bytes)", len),
+            )),
+            Input::Failed(kind, message) => Err(io::Error::new(*kind, message.clone())),
+        }
+    }
+}
+
+/// Lee hasta `limit` bytes de `reader`; si hay más, los cuenta sin guardarlos.
+fn read_limited(reader: impl Read, limit: u64) -> io::Result<Input> {
+    let mut reader = reader.take(limit + 1);
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    if bytes.len() as u64 <= limit {
+        return Ok(Input::Contents(bytes));
+    }
+    let rest = io::copy(&mut reader.into_inner(), &mut io::sink())?;
+    Ok(Input::TooLarge(bytes.len() as u64 + rest))
+}
+
+fn read_only() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::PermissionDenied,
+        "la entrada estándar es de solo lectura",
+    )
+}
+
+impl Vfs for StdinFs {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        if is_stdin(path) {
+            return self.contents().map(<[u8]>::to_vec);
+        }
+        self.inner.read(path)
+    }
+
+    fn read_bounded(&self, path: &Path, limit: u64) -> io::Result<Bounded> {
+        if !is_stdin(path) {
+            return self.inner.read_bounded(path, limit);
+        }
+        let len = self.metadata(path)?.len;
+        if len > limit {
+            return Ok(Bounded::TooLarge(len));
+        }
+        String::from_utf8(self.contents()?.to_vec())
+            .map(Bounded::Contents)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<Metadata> {
+        if !is_stdin(path) {
+            return self.inner.metadata(path);
+        }
+        let len = match self.input() {
+            Input::Contents(bytes) => bytes.len() as u64,
+            Input::TooLarge(len) => *len,
+            Input::Failed(kind, message) => return Err(io::Error::new(*kind, message.clone())),
+        };
+        Ok(Metadata {
+            len,
+            is_dir: false,
+            readonly: true,
+            is_symlink: false,
+            created: None,
+        })
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        self.inner.read_dir(path)
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        if is_stdin(path) {
+            return Ok(path.to_path_buf());
+        }
+        self.inner.canonicalize(path)
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        self.inner.create_dir_all(path)
+    }
+
+    fn create(
+        &self,
+        path: &Path,
+        permissions_of: Option<&Path>,
+    ) -> io::Result<Box<dyn SyncedFile>> {
+        if is_stdin(path) {
+            return Err(read_only());
+        }
+        self.inner.create(path, permissions_of)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        if is_stdin(from) || is_stdin(to) {
+            return Err(read_only());
+        }
+        self.inner.rename(from, to)
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        if is_stdin(path) {
+            return Err(read_only());
+        }
+        self.inner.remove_file(path)
+    }
+
+    fn append(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+        if is_stdin(path) {
+            return Err(read_only());
+        }
+        self.inner.append(path, content)
+    }
+
+    fn set_executable(&self, path: &Path) -> io::Result<()> {
+        if is_stdin(path) {
+            return Err(read_only());
+        }
+        self.inner.set_executable(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::MemFs;
+
+    fn stdin_fs(input: &'static [u8]) -> StdinFs {
+        let fs = MemFs::new();
+        fs.write("docs/api.md", "# API\n");
+        StdinFs::with_reader(Arc::new(fs), input)
+    }
+
+    #[test]
+    fn dash_is_served_from_the_reader_and_the_rest_from_inner() {
+        let fs = stdin_fs(b"export function f() {}\n");
+        assert_eq!(fs.metadata(Path::new("-")).unwrap().len, 23);
+        // Segunda lectura: el mismo buffer, stdin no se vuelve a leer
+        for _ in 0..2 {
+            assert_eq!(
+                fs.read_bounded(Path::new("-"), 100).unwrap(),
+                Bounded::Contents("export function f() {}\n".to_string())
+            );
+        }
+        assert_eq!(
+            fs.read_bounded(Path::new("-"), 10).unwrap(),
+            Bounded::TooLarge(23)
+        );
+        assert_eq!(fs.canonicalize(Path::new("-")).unwrap(), Path::new("-"));
+        let kind = fs.create(Path::new("-"), None).err().unwrap().kind();
+        assert_eq!(kind, io::ErrorKind::PermissionDenied);
+        assert_eq!(fs.read(Path::new("docs/api.md")).unwrap(), b"# API\n");
+    }
+
+    #[test]
+    fn oversized_input_is_counted_but_not_kept() {
+        let input = [b'x'; 40];
+        match read_limited(&input[..], 16).unwrap() {
+            Input::TooLarge(len) => assert_eq!(len, 40),
+            other => panic!("{:?}", other),
+        }
+        match read_limited(&input[..], 40).unwrap() {
+            Input::Contents(bytes) => assert_eq!(bytes.len(), 40),
+            other => panic!("{:?}", other),
+        }
+    }
+}
@@ -0,0 +1,897 @@
+//! Observador de archivos del modo watch (feature `watch`, con notify).
+
+use anyhow::{Context, Result};
+use notify::{EventKind, Watcher};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::baseline::Baseline;
+use crate::config::{Config, InfoAggregation};
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::link_index::LinkIndex;
+use crate::core::pipeline;
+use crate::core::types::Severity;
+use crate::exit::Failure;
+use crate::fix::FixContext;
+use crate::last_run;
+use crate::layout::{Layout, Style};
+use crate::parser::code_parser::{self, safe_display};
+use crate::parser::doc_parser;
+use crate::report::{Report, Verbosity};
+use crate::vfs;
+
+use super::quick_fix::QuickFixes;
+use super::{DEFAULT_MAX_WAIT_MS, DEFAULT_QUIET_MS};
+use crate::messages::{eprintln_tr, print_tr, tr};
+
+/// Ventana de coalescencia de ráfagas de eventos.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BurstWindow {
+    /// Silencio necesario desde el último evento para cerrar la ráfaga.
+    pub quiet: Duration,
+    /// Tope desde el primer evento: evita esperar indefinidamente.
+    pub max: Duration,
+}
+
+impl BurstWindow {
+    /// Crea la ventana; el silencio nunca supera el tope.
+    pub fn from_millis(quiet_ms: u64, max_ms: u64) -> Self {
+        let max = Duration::from_millis(max_ms);
+        BurstWindow {
+            quiet: Duration::from_millis(quiet_ms).min(max),
+            max,
+        }
+    }
+}
+
+impl Default for BurstWindow {
+    fn default() -> Self {
+        Self::from_millis(DEFAULT_QUIET_MS, DEFAULT_MAX_WAIT_MS)
+    }
+}
+
+/// Ejecuta el modo watch: observa cambios y re-valida automáticamente.
+/// Con `interactive_fixes`, ofrece corregir los hallazgos nuevos desde el
+/// teclado (`quick_fix`).
+pub fn run_watch(
+    code_file: &Path,
+    doc_file: &Path,
+    window: BurstWindow,
+    layout: Layout,
+    verbosity: Verbosity,
+    interactive_fixes: bool,
+) -> Result<()> {
+    if vfs::is_stdin(code_file) || vfs::is_stdin(doc_file) {
+        anyhow::bail!(
+            Failure::usage("El modo watch no puede leer de stdin (`-`).").with_hint(
+                "Observa archivos en disco, o valida el buffer con `docsguard check` y `-`."
+            )
+        );
+    }
+    if interactive_fixes {
+        use std::io::IsTerminal;
+        if !crate::build_info::Feature::Interactive.enabled() {
+            return Err(crate::build_info::Feature::Interactive.unavailable());
+        }
+        if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+            anyhow::bail!(Failure::usage("--interactive-fixes necesita una terminal.")
+                .with_hint("Sin terminal, usa `docsguard check --fix`."));
+        }
+    }
+
+    // Refactorizado: usa require_file_exists para eliminar comprobaciones duplicadas entre comandos
+    code_parser::require_file_exists(code_file, "código")?;
+    code_parser::require_file_exists(doc_file, "documentación")?;
+
+    let code_file = std::fs::canonicalize(code_file)
+        .with_context(|| format!("No se pudo resolver la ruta: {}", code_file.display()))?;
+    let doc_file = std::fs::canonicalize(doc_file)
+        .with_context(|| format!("No se pudo resolver la ruta: {}", doc_file.display()))?;
+
+    let mut screen = Screen {
+        layout,
+        verbosity,
+        fixes: interactive_fixes.then(Arc::default),
+        ..Screen::default()
+    };
+    #[cfg(feature = "interactive")]
+    if let Some(fixes) = &screen.fixes {
+        super::quick_fix::listen(Arc::clone(fixes));
+    }
+
+    // Validación inicial
+    screen.show(&code_file, &doc_file);
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher =
+        notify::recommended_watcher(tx).context("Error al inicializar el watcher de archivos")?;
+
+    // Observar los directorios padre de ambos archivos
+    let watch_paths = collect_watch_paths(&code_file, &doc_file);
+    for path in &watch_paths {
+        watcher
+            .watch(path, notify::RecursiveMode::NonRecursive)
+            .with_context(|| format!("Error al observar: {}", path.display()))?;
+    }
+
+    let targets = [code_file.clone(), doc_file.clone()];
+    let is_relevant = |event: &notify::Result<notify::Event>| match event {
+        Ok(event) => is_relevant_event(event, &targets),
+        Err(e) => {
+            eprintln_tr!("  [watch] Error del watcher: {}", e);
+            false
+        }
+    };
+
+    watch_loop(&rx, is_relevant, window, show_waiting_indicator, || {
+        if !code_file.exists() {
+            eprintln_tr!(
+                "  [!] Archivo de código eliminado: {}",
+                safe_display(&code_file)
+            );
+        } else if !doc_file.exists() {
+            eprintln_tr!(
+                "  [!] Archivo de documentación eliminado: {}",
+                safe_display(&doc_file)
+            );
+        } else {
+            screen.show(&code_file, &doc_file);
+        }
+        Ok(())
+    })
+}
+
+/// Bucle principal: una validación por ráfaga coalescida, hasta que se cierre el canal.
+fn watch_loop<T>(
+    rx: &Receiver<T>,
+    is_relevant: impl Fn(&T) -> bool,
+    window: BurstWindow,
+    mut on_burst_start: impl FnMut(),
+    mut on_burst: impl FnMut() -> Result<()>,
+) -> Result<()> {
+    while next_burst(rx, &is_relevant, window, &mut on_burst_start).is_some() {
+        on_burst()?;
+    }
+    Ok(())
+}
+
+/// Espera el primer evento relevante y absorbe los siguientes hasta que pase
+/// `window.quiet` sin eventos relevantes o se alcance `window.max`.
+///
+/// Retorna el número de eventos relevantes absorbidos, o `None` si el canal se
+/// cerró antes de empezar una ráfaga. Una ráfaga interrumpida por el cierre
+/// del canal se entrega igualmente para validar el estado final.
+fn next_burst<T>(
+    rx: &Receiver<T>,
+    is_relevant: &impl Fn(&T) -> bool,
+    window: BurstWindow,
+    on_start: &mut impl FnMut(),
+) -> Option<usize> {
+    loop {
+        let event = rx.recv().ok()?;
+        if is_relevant(&event) {
+            break;
+        }
+    }
+    on_start();
+
+    let started = Instant::now();
+    let mut last_event = started;
+    let mut count = 1;
+    loop {
+        let now = Instant::now();
+        let quiet_deadline = last_event + window.quiet;
+        let max_deadline = started + window.max;
+        let deadline = quiet_deadline.min(max_deadline);
+        if now >= deadline {
+            return Some(count);
+        }
+        match rx.recv_timeout(deadline - now) {
+            Ok(event) if is_relevant(&event) => {
+                count += 1;
+                last_event = Instant::now();
+            }
+            Ok(_) => {}
+            Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => {
+                return Some(count);
+            }
+        }
+    }
+}
+
+/// Un evento es relevante si modifica, crea o elimina alguno de los archivos observados.
+fn is_relevant_event(event: &notify::Event, targets: &[PathBuf]) -> bool {
+    matches!(
+        event.kind,
+        EventKind::Any | EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    ) && event.paths.iter().any(|p| targets.contains(p))
+}
+
+fn show_waiting_indicator() {
+    print_tr!("\r\x1B[2K  esperando a que terminen los cambios…");
+    let _ = std::io::stdout().flush();
+}
+
+/// Estado de la terminal entre validaciones.
+#[derive(Default)]
+struct Screen {
+    /// Hash del último resultado mostrado.
+    last_hash: Option<u64>,
+    layout: Layout,
+    /// Hallazgos que se muestran; con `-v`, también el desglose de tiempos.
+    verbosity: Verbosity,
+    parsed: Parsed,
+    /// Correcciones ofrecidas (`--interactive-fixes`); el mutex también
+    /// ordena lo que escriben la validación y el hilo del teclado.
+    fixes: Option<Arc<Mutex<QuickFixes>>>,
+}
+
+/// Lo parseado en validaciones anteriores: el índice de enlaces y, por
+/// archivo, el hash de su contenido y las notas de su parseo. Solo se
+/// re-parsea (y se re-indexa) el archivo cuyo contenido cambió.
+#[derive(Default)]
+struct Parsed {
+    /// Configuración con la que se parseó (`{:?}`); si cambia, se parte de cero.
+    config: String,
+    index: LinkIndex,
+    files: HashMap<PathBuf, (u64, ParseDiagnostics)>,
+    /// Parseos ejecutados; los tests comprueban con él que el lado sin
+    /// cambios sale de la caché.
+    parses: AtomicUsize,
+}
+
+/// Lo que dejó parsear un archivo cuyo contenido cambió: su hash, lo
+/// extraído y las notas del parseo.
+type Fresh<T> = Result<(u64, Vec<T>, ParseDiagnostics)>;
+
+impl Parsed {
+    /// Parsea `path` con `parse`, salvo que su contenido sea el de la última
+    /// vez (`None`). Solo lee `self`, así que los dos lados de la pareja se
+    /// parsean a la vez.
+    fn parse_if_changed<T>(
+        &self,
+        path: &Path,
+        parse: impl FnOnce(&mut ParseDiagnostics) -> Result<Vec<T>>,
+    ) -> Option<Fresh<T>> {
+        let hash = last_run::hash_file(path);
+        if let Ok(hash) = &hash {
+            if self.files.get(path).is_some_and(|(known, _)| known == hash) {
+                return None;
+            }
+        }
+        Some(hash.and_then(|hash| {
+            self.parses.fetch_add(1, Ordering::Relaxed);
+            let mut diagnostics = ParseDiagnostics::default();
+            let items = parse(&mut diagnostics)?;
+            Ok((hash, items, diagnostics))
+        }))
+    }
+
+    /// Guarda en el índice con `store` lo que `parse_if_changed` parseó de
+    /// `path`. Si no se pudo leer o parsear, sale del índice (con `unstore`)
+    /// hasta que vuelva a parsear.
+    fn store<T>(
+        &mut self,
+        path: &Path,
+        fresh: Option<Fresh<T>>,
+        store: fn(&mut LinkIndex, &Path, Vec<T>) -> bool,
+        unstore: fn(&mut LinkIndex, &Path),
+    ) -> Result<()> {
+        let Some(fresh) = fresh else {
+            return Ok(());
+        };
+        self.files.remove(path);
+        unstore(&mut self.index, path);
+        let (hash, items, diagnostics) = fresh?;
+        store(&mut self.index, path, items);
+        self.files.insert(path.to_path_buf(), (hash, diagnostics));
+        Ok(())
+    }
+
+    /// Notas del último parseo de `paths`, en ese orden.
+    fn diagnostics(&self, paths: &[&Path]) -> ParseDiagnostics {
+        let mut diagnostics = ParseDiagnostics::default();
+        for path in paths {
+            if let Some((_, notes)) = self.files.get(*path) {
+                diagnostics.merge(notes.clone());
+            }
+        }
+        diagnostics
+    }
+}
+
+/// Desglose del tiempo de una validación, para `watch --verbose`.
+#[derive(Debug, Default)]
+struct Timings {
+    /// Parseo del código; `None` si salió de la caché.
+    parse_code: Option<Duration>,
+    /// Parseo de las docs; `None` si salió de la caché.
+    parse_docs: Option<Duration>,
+    validate: Duration,
+    render: Duration,
+}
+
+impl Timings {
+    /// `código 220ms · docs en caché · validación 60ms · render 2ms`.
+    fn describe(&self) -> String {
+        let parse = |time: Option<Duration>| match time {
+            Some(time) => format!("{}ms", time.as_millis()),
+            None => "en caché".to_string(),
+        };
+        format!(
+            "código {} · docs {} · validación {}ms · render {}ms",
+            parse(self.parse_code),
+            parse(self.parse_docs),
+            self.validate.as_millis(),
+            self.render.as_millis()
+        )
+    }
+}
+
+/// Ejecuta `run` y mide cuánto tardó, si llegó a parsear.
+fn timed<T>(run: impl FnOnce() -> Option<T>) -> (Option<T>, Option<Duration>) {
+    let start = Instant::now();
+    let result = run();
+    let elapsed = result.as_ref().map(|_| start.elapsed());
+    (result, elapsed)
+}
+
+impl Screen {
+    /// Valida y repinta, salvo que el resultado sea idéntico al anterior.
+    ///
+    /// La pantalla se limpia cuando el resultado ya está listo, y el cuadro
+    /// entero se escribe de una vez: la terminal no queda en blanco mientras
+    /// se valida.
+    fn show(&mut self, code_file: &Path, doc_file: &Path) {
+        let mut fixes = self
+            .fixes
+            .as_ref()
+            .map(|fixes| fixes.lock().unwrap_or_else(|e| e.into_inner()));
+        let start = Instant::now();
+        let (body, timings) = render_validation(
+            code_file,
+            doc_file,
+            &self.layout,
+            self.verbosity,
+            &mut self.parsed,
+            fixes.as_deref_mut(),
+        );
+        let elapsed = if self.verbosity == Verbosity::Verbose {
+            format!("{}ms: {}", start.elapsed().as_millis(), timings.describe())
+        } else {
+            format!("{}ms", start.elapsed().as_millis())
+        };
+
+        let hash = hash_output(&body);
+        if self.last_hash == Some(hash) {
+            print_tr!(
+                "\r\x1B[2K  Sin cambios en el resultado ({}). Observando cambios... (Ctrl+C para salir)",
+                elapsed
+            );
+
+            let _ = std::io::stdout().flush();
+            return;
+        }
+        self.last_hash = Some(hash);
+
+        // Limpiar pantalla
+        let frame = format!(
+            "\x1B[2J\x1B[1;1H\
+             DocsGuard Watch — Validación en tiempo real\n\n  \
+             Código: {}\n  \
+             Docs:   {}\n\n\
+             {}  ({})\n\n  \
+             Observando cambios... (Ctrl+C para salir)\n",
+            safe_display(code_file),
+            safe_display(doc_file),
+            body,
+            elapsed
+        );
+        print_tr!("{}", frame);
+        // Sin cambios en el resultado no hay hallazgos nuevos que corregir
+        if let Some(prompt) = fixes.as_ref().and_then(|fixes| fixes.prompt()) {
+            print_tr!("{}", prompt);
+        }
+        let _ = std::io::stdout().flush();
+    }
+}
+
+fn hash_output(output: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    output.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Ejecuta la validación y la renderiza (sin tiempos, para poder compararla).
+/// Con `fixes`, actualiza también las correcciones ofrecidas.
+fn render_validation(
+    code_file: &Path,
+    doc_file: &Path,
+    layout: &Layout,
+    verbosity: Verbosity,
+    parsed: &mut Parsed,
+    fixes: Option<&mut QuickFixes>,
+) -> (String, Timings) {
+    // Config releída en cada ciclo: editarla también se refleja en vivo
+    let config = match Config::load(Path::new(".")) {
+        Ok(c) => c,
+        Err(e) => {
+            let message = format!("  [!] Error en la configuración: {}\n", e);
+            return (message, Timings::default());
+        }
+    };
+    let baseline = match Baseline::load(Path::new(".")) {
+        Ok(baseline) => baseline,
+        Err(e) => return (format!("  [!] {:#}\n", e), Timings::default()),
+    };
+    let arg_typos = fixes.is_some();
+    let (report, mut timings) =
+        match validate_pair(code_file, doc_file, &config, baseline, parsed, arg_typos) {
+            Ok(validated) => validated,
+            Err(e) => return (format!("  [!] {:#}\n", e), Timings::default()),
+        };
+    if let Some(fixes) = fixes {
+        let code_entities = parsed.index.code_entities();
+        let doc_sections = parsed.index.doc_sections();
+        let context = FixContext {
+            code_entities: &code_entities,
+            doc_sections: &doc_sections,
+            doc_file,
+            paths: &config.paths,
+        };
+        fixes.update(&report.results, &context, &parsed.files);
+    }
+
+    let start = Instant::now();
+    let error_count = report.count(Severity::Error);
+    let warning_count = report.count(Severity::Warning);
+
+    let mut out = String::new();
+    // Sin -v, watch no muestra los Info
+    let verbose = verbosity == Verbosity::Verbose;
+    for result in report
+        .results
+        .iter()
+        .filter(|r| verbosity.shows(r) && (verbose || r.severity != Severity::Info))
+    {
+        out.push_str(&result.render(layout, verbose));
+    }
+
+    if error_count == 0 && warning_count == 0 {
+        let clean = tr("✓ Sin errores ni advertencias.");
+        out.push_str(&format!("  {}\n", layout.paint(Style::Success, &clean)));
+    }
+
+    out.push_str(&format!(
+        "\n  Resumen: {}\n",
+        crate::messages::summary(error_count, warning_count)
+    ));
+    timings.render = start.elapsed();
+    (out, timings)
+}
+
+/// Parsea lo que cambió de la pareja y la valida como `check`.
+///
+/// Los dos parseos son independientes y corren a la vez en hilos con ámbito
+/// (`std::thread::scope`); el lado cuyo contenido no cambió sale de la caché
+/// de `parsed` sin re-parsearse. La validación es `pipeline::run_validation`
+/// con los pases de enlace sobre el índice incremental, y el informe se filtra
+/// con `baseline`. Con `arg_typos`, las erratas de argumentos se reclasifican
+/// como en `check --fix`.
+fn validate_pair(
+    code_file: &Path,
+    doc_file: &Path,
+    config: &Config,
+    baseline: Option<Baseline>,
+    parsed: &mut Parsed,
+    arg_typos: bool,
+) -> Result<(Report, Timings)> {
+    let config_key = format!("{:?}", config);
+    if parsed.config != config_key {
+        *parsed = Parsed {
+            config: config_key,
+            ..Parsed::default()
+        };
+    }
+
+    let cache = &*parsed;
+    let ((code, parse_code), (docs, parse_docs)) = std::thread::scope(|scope| {
+        let docs = scope.spawn(|| {
+            timed(|| {
+                cache.parse_if_changed(doc_file, |diagnostics| {
+                    doc_parser::parse_docs_with_diagnostics(doc_file, config, diagnostics)
+                })
+            })
+        });
+        let code = timed(|| {
+            cache.parse_if_changed(code_file, |diagnostics| {
+                code_parser::parse_project_code(&[code_file.to_path_buf()], config, diagnostics)
+            })
+        });
+        let docs = docs
+            .join()
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+        (code, docs)
+    });
+    let mut timings = Timings {
+        parse_code,
+        parse_docs,
+        ..Timings::default()
+    };
+    let code = parsed.store(
+        code_file,
+        code,
+        LinkIndex::set_code_file,
+        LinkIndex::remove_code_file,
+    );
+    let docs = parsed.store(
+        doc_file,
+        docs,
+        LinkIndex::set_doc_file,
+        LinkIndex::remove_doc_file,
+    );
+    code.context("Error al parsear código")?;
+    docs.context("Error al parsear docs")?;
+
+    let start = Instant::now();
+    let doc_source = doc_parser::read_markdown_file(doc_file)?;
+    let diagnostics = parsed.diagnostics(&[code_file, doc_file]);
+    let code_entities = parsed.index.code_entities();
+    let doc_sections = parsed.index.doc_sections();
+    let results = pipeline::run_validation(
+        pipeline::Inputs {
+            code_entities: &code_entities,
+            doc_sections: &doc_sections,
+            diagnostics: &diagnostics,
+            docs: &[pipeline::DocSource {
+                file: doc_file,
+                source: &doc_source,
+            }],
+            config,
+        },
+        pipeline::Passes {
+            arg_typos,
+            index: Some(&parsed.index),
+            ..pipeline::Passes::default()
+        },
+    )
+    .results;
+    let report = Report::filtered(
+        results,
+        baseline.as_ref(),
+        InfoAggregation::Off,
+        &code_entities,
+        &doc_sections,
+    );
+    timings.validate = start.elapsed();
+    Ok((report, timings))
+}
+
+/// Obtiene los directorios a observar.
+fn collect_watch_paths(code_file: &Path, doc_file: &Path) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+
+    if let Some(parent) = code_file.parent() {
+        paths.push(parent.to_path_buf());
+    }
+
+    if let Some(parent) = doc_file.parent() {
+        if !paths.contains(&parent.to_path_buf()) {
+            paths.push(parent.to_path_buf());
+        }
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Rule;
+    use std::sync::mpsc::Sender;
+    use std::thread;
+
+    fn window(quiet_ms: u64, max_ms: u64) -> BurstWindow {
+        BurstWindow::from_millis(quiet_ms, max_ms)
+    }
+
+    /// Ejecuta `watch_loop` sobre un canal alimentado por `producer` y cuenta validaciones.
+    fn count_validations<T: Send + 'static>(
+        window: BurstWindow,
+        is_relevant: impl Fn(&T) -> bool,
+        producer: impl FnOnce(Sender<T>) + Send + 'static,
+    ) -> usize {
+        let (tx, rx) = mpsc::channel();
+        let handle = thread::spawn(move || producer(tx));
+        let mut validations = 0;
+        watch_loop(
+            &rx,
+            is_relevant,
+            window,
+            || {},
+            || {
+                validations += 1;
+                Ok(())
+            },
+        )
+        .unwrap();
+        handle.join().unwrap();
+        validations
+    }
+
+    #[test]
+    fn synthetic_burst_is_coalesced_into_one_validation() {
+        let validations = count_validations(
+            window(100, 2000),
+            |_: &u32| true,
+            |tx| {
+                for i in 0..30 {
+                    tx.send(i).unwrap();
+                    thread::sleep(Duration::from_millis(5));
+                }
+            },
+        );
+        assert_eq!(validations, 1);
+    }
+
+    #[test]
+    fn separate_bursts_validate_separately() {
+        let validations = count_validations(
+            window(50, 2000),
+            |_: &u32| true,
+            |tx| {
+                tx.send(1).unwrap();
+                thread::sleep(Duration::from_millis(200));
+                tx.send(2).unwrap();
+            },
+        );
+        assert_eq!(validations, 2);
+    }
+
+    #[test]
+    fn irrelevant_events_do_not_trigger_validation() {
+        let validations = count_validations(
+            window(50, 2000),
+            |e: &u32| *e == 0,
+            |tx| {
+                for _ in 0..5 {
+                    tx.send(7).unwrap();
+                }
+            },
+        );
+        assert_eq!(validations, 0);
+    }
+
+    #[test]
+    fn endless_burst_is_cut_at_max_wait() {
+        let (tx, rx) = mpsc::channel();
+        let producer = thread::spawn(move || {
+            let start = Instant::now();
+            while start.elapsed() < Duration::from_millis(600) {
+                if tx.send(()).is_err() {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(10));
+            }
+        });
+        let start = Instant::now();
+        let count = next_burst(&rx, &|_| true, window(100, 200), &mut || {});
+        assert!(count.is_some());
+        assert!(start.elapsed() < Duration::from_millis(500));
+        drop(rx);
+        producer.join().unwrap();
+    }
+
+    #[test]
+    fn quiet_period_is_capped_by_max_wait() {
+        assert_eq!(window(5000, 2000).quiet, Duration::from_millis(2000));
+    }
+
+    #[test]
+    fn burst_of_real_writes_triggers_a_single_validation() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir_path = dir.path().canonicalize().unwrap();
+        let target = dir_path.join("api.ts");
+        std::fs::write(&target, "").unwrap();
+
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx).unwrap();
+        watcher
+            .watch(&dir_path, notify::RecursiveMode::NonRecursive)
+            .unwrap();
+
+        let writer_target = target.clone();
+        let writer = thread::spawn(move || {
+            for i in 0..20 {
+                std::fs::write(&writer_target, format!("// {i}")).unwrap();
+                thread::sleep(Duration::from_millis(10));
+            }
+            // Dar tiempo a que cierre la ráfaga antes de terminar el watcher
+            thread::sleep(Duration::from_millis(800));
+            drop(watcher);
+        });
+
+        let targets = [target];
+        let mut validations = 0;
+        watch_loop(
+            &rx,
+            |e: &notify::Result<notify::Event>| {
+                e.as_ref().is_ok_and(|e| is_relevant_event(e, &targets))
+            },
+            window(300, 2000),
+            || {},
+            || {
+                validations += 1;
+                Ok(())
+            },
+        )
+        .unwrap();
+        writer.join().unwrap();
+
+        assert_eq!(validations, 1);
+    }
+
+    #[test]
+    fn the_unchanged_side_of_the_pair_is_not_parsed_again() {
+        let dir = tempfile::tempdir().unwrap();
+        let code_file = dir.path().join("api.ts");
+        let doc_file = dir.path().join("api.md");
+        std::fs::write(
+            &code_file,
+            "/// @docs: [login]\nexport function login(user: string) {}\n",
+        )
+        .unwrap();
+        std::fs::write(&doc_file, "<!-- @docs-id: login -->\n## login\n").unwrap();
+        let config = Config::default();
+        let mut parsed = Parsed::default();
+
+        let (report, first) =
+            validate_pair(&code_file, &doc_file, &config, None, &mut parsed, false).unwrap();
+        assert!(first.parse_code.is_some() && first.parse_docs.is_some());
+        assert!(report.results.iter().any(|r| r.rule == Rule::MissingArg));
+        assert_eq!(parsed.parses.load(Ordering::Relaxed), 2);
+
+        std::fs::write(
+            &doc_file,
+            "<!-- @docs-id: login -->\n## login\n\n\
+             | Param | Type | Description |\n|---|---|---|\n| user | string | Usuario |\n",
+        )
+        .unwrap();
+        let (report, second) =
+            validate_pair(&code_file, &doc_file, &config, None, &mut parsed, false).unwrap();
+        assert!(second.parse_code.is_none(), "{second:?}");
+        assert!(second.parse_docs.is_some());
+        assert_eq!(parsed.parses.load(Ordering::Relaxed), 3);
+        assert!(!report.results.iter().any(|r| r.rule == Rule::MissingArg));
+
+        validate_pair(&code_file, &doc_file, &config, None, &mut parsed, false).unwrap();
+        assert_eq!(parsed.parses.load(Ordering::Relaxed), 3);
+    }
+
+    /// Lo que `watch` y `check` comparan: hallazgos, recuentos y baseline.
+    fn summary(report: &Report) -> String {
+        format!(
+            "{:#?}\nerrores={} advertencias={} info={} baseline={}",
+            report.results,
+            report.count(Severity::Error),
+            report.count(Severity::Warning),
+            report.count(Severity::Info),
+            report.baseline_filtered
+        )
+    }
+
+    /// Informe de la pareja por el camino de `check` (`report::build_report`).
+    fn check_report(dir: &Path, code_file: &Path, doc_file: &Path) -> String {
+        let report =
+            crate::report::build_report(&[code_file.to_path_buf()], doc_file, dir).unwrap();
+        summary(&report)
+    }
+
+    /// Informe de la pareja por el camino de `watch`, con la caché `parsed`.
+    fn watch_report(dir: &Path, code_file: &Path, doc_file: &Path, parsed: &mut Parsed) -> String {
+        let config = Config::load(dir).unwrap();
+        let baseline = Baseline::load(dir).unwrap();
+        let (report, _) =
+            validate_pair(code_file, doc_file, &config, baseline, parsed, false).unwrap();
+        summary(&report)
+    }
+
+    #[test]
+    fn watch_and_check_report_the_same_findings() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        crate::demo::write_sample(dir).unwrap();
+        let doc_file = dir.join(crate::demo::DOC_FILE);
+        // Una supresión, una regla opt-in, una excepción de argumentos y un baseline
+        let auth = dir.join(crate::demo::CODE_FILES[0]);
+        let source = std::fs::read_to_string(&auth).unwrap().replace(
+            "// Cierra la sesión.",
+            "// Cierra la sesión.\n// docsguard-ignore: missing-doc-section",
+        );
+        std::fs::write(&auth, source).unwrap();
+        std::fs::create_dir_all(dir.join(".docsguard")).unwrap();
+        std::fs::write(
+            dir.join(".docsguard/config.yaml"),
+            "rules:\n  placeholder-description: warning\narg_exceptions:\n  - {doc_id: search, doc_arg: offset, code_arg: limit, reason: alias}\n",
+        )
+        .unwrap();
+
+        for (i, code) in crate::demo::CODE_FILES.iter().enumerate() {
+            let code_file = dir.join(code);
+            if i == 1 {
+                let report =
+                    crate::report::build_report(std::slice::from_ref(&code_file), &doc_file, dir)
+                        .unwrap();
+                let accepted: Vec<_> = report
+                    .results
+                    .into_iter()
+                    .filter(|r| r.severity != Severity::Info)
+                    .take(1)
+                    .collect();
+                Baseline::from_results(&accepted, &[]).save(dir).unwrap();
+            }
+            let check = check_report(dir, &code_file, &doc_file);
+            assert!(check.contains("rule: "), "{check}");
+            let watch = watch_report(dir, &code_file, &doc_file, &mut Parsed::default());
+            assert_eq!(watch, check, "{code}");
+        }
+        assert!(
+            check_report(dir, &dir.join(crate::demo::CODE_FILES[1]), &doc_file)
+                .ends_with("baseline=1")
+        );
+    }
+
+    #[test]
+    fn any_sequence_of_saves_ends_in_the_report_of_a_cold_run() {
+        const CODE: [&str; 4] = [
+            "/// @docs: [login]\nexport function login(user: string) {}\n",
+            "/// @docs: [login]\nexport function login(user: number, pass: string) {}\n",
+            "// docsguard-ignore: ghost-arg\n/// @docs: [login]\nexport function login() {}\n\nexport function logout(token: string) {}\n",
+            "/// @docs: [logout]\nexport function logout(token: string) {}\n",
+        ];
+        const DOCS: [&str; 4] = [
+            "<!-- @docs-id: login -->\n## login\n\n| Param | Type | Description |\n|---|---|---|\n| user | string | Usuario |\n",
+            "<!-- @docs-id: login -->\n## login\n\n- `user` (string): Usuario\n- `pass` (string): TODO\n",
+            "<!-- @docs-id: logout -->\n## logout\n\nVer [login](#login).\n",
+            "# API\n\n<!-- @docs-id: login -->\n## login\n\n<!-- @docs-id: logout -->\n## logout\n",
+        ];
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        let code_file = dir.join("api.ts");
+        let doc_file = dir.join("api.md");
+        for seed in 0..20 {
+            let mut rng = fastrand::Rng::with_seed(seed);
+            std::fs::write(&code_file, CODE[0]).unwrap();
+            std::fs::write(&doc_file, DOCS[0]).unwrap();
+            let mut parsed = Parsed::default();
+            for step in 0..12 {
+                if rng.bool() {
+                    std::fs::write(&code_file, rng.choice(CODE).unwrap()).unwrap();
+                } else {
+                    std::fs::write(&doc_file, rng.choice(DOCS).unwrap()).unwrap();
+                }
+                let incremental = watch_report(dir, &code_file, &doc_file, &mut parsed);
+                let cold = watch_report(dir, &code_file, &doc_file, &mut Parsed::default());
+                assert_eq!(incremental, cold, "semilla {seed}, paso {step}");
+                assert_eq!(
+                    incremental,
+                    check_report(dir, &code_file, &doc_file),
+                    "semilla {seed}, paso {step}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn identical_outputs_hash_equal() {
+        assert_eq!(hash_output("a"), hash_output("a"));
+        assert_ne!(hash_output("a"), hash_output("b"));
+    }
+}
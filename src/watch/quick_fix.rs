@@ -0,0 +1,372 @@
+//! Correcciones rápidas de `watch --interactive-fixes`.
+//!
+//! Tras cada validación, los hallazgos que no estaban en la anterior y tienen
+//! una corrección mecánica (`fix::suggest_fix`: sección que falta, tipo de una
+//! fila, errata de un argumento) se ofrecen en una línea bajo el resultado.
+//! `f` aplica la seleccionada en una `Transaction` y el guardado dispara la
+//! revalidación como cualquier otro; `n`/`p` cambian de hallazgo. La primera
+//! validación solo sirve de referencia: al arrancar no se ofrece nada.
+//!
+//! El editor puede tener cambios sin guardar que no vemos, así que antes de
+//! escribir se vuelve a calcular el hash del archivo y, si no es el de la
+//! validación, la corrección se descarta. Las que editarían un archivo con
+//! un conflicto de git sin resolver no se ofrecen.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use crate::core::conflicts;
+use crate::core::diagnostics::ParseDiagnostics;
+use crate::core::types::ValidationResult;
+use crate::fix::{self, Fix, FixContext};
+use crate::last_run;
+use crate::parser::code_parser::safe_display;
+use crate::vfs;
+
+/// Identidad de un hallazgo entre validaciones: sin la línea, que se mueve
+/// al editar por encima.
+type FindingKey = (&'static str, Option<String>, Option<String>, String);
+
+fn key(result: &ValidationResult) -> FindingKey {
+    (
+        result.rule.id(),
+        result.function_name.clone(),
+        result.doc_id.clone(),
+        result.message.clone(),
+    )
+}
+
+/// Corrección ofrecida para un hallazgo nuevo.
+#[derive(Debug)]
+struct Offer {
+    key: FindingKey,
+    /// `type-mismatch en fn delete`.
+    finding: String,
+    fix: Fix,
+    /// Hash del archivo que edita, tal como se validó.
+    hash: Option<u64>,
+}
+
+/// Tecla de la línea de correcciones.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    Apply,
+    Next,
+    Previous,
+}
+
+/// Hallazgos de la última validación y correcciones ofrecidas.
+#[derive(Debug, Default)]
+pub struct QuickFixes {
+    /// `None` hasta la primera validación.
+    previous: Option<HashSet<FindingKey>>,
+    offers: Vec<Offer>,
+    selected: usize,
+}
+
+impl QuickFixes {
+    /// Ofrece correcciones para los hallazgos de `results` que no estaban en
+    /// la validación anterior. `files` son los hashes del parseo.
+    pub fn update(
+        &mut self,
+        results: &[ValidationResult],
+        context: &FixContext,
+        files: &HashMap<PathBuf, (u64, ParseDiagnostics)>,
+    ) {
+        let current: HashSet<FindingKey> = results.iter().map(key).collect();
+        self.offers.clear();
+        self.selected = 0;
+        if let Some(previous) = self.previous.replace(current) {
+            for result in results.iter().filter(|r| !previous.contains(&key(r))) {
+                let Some(fix) = fix::suggest_fix(result, context) else {
+                    continue;
+                };
+                if self.offers.iter().any(|offer| offer.fix == fix) {
+                    continue;
+                }
+                let parsed = vfs::canonicalize(fix.target())
+                    .ok()
+                    .and_then(|path| files.get(&path));
+                // Un archivo con un conflicto de git sin resolver no se edita
+                if parsed.is_some_and(|(_, notes)| conflicts::refuse_writes(notes).is_err()) {
+                    continue;
+                }
+                let hash = parsed
+                    .map(|(hash, _)| *hash)
+                    .or_else(|| last_run::hash_file(fix.target()).ok());
+                let finding = match &result.function_name {
+                    Some(name) => format!("{} en fn {}", result.rule, name),
+                    None => result.rule.to_string(),
+                };
+                self.offers.push(Offer {
+                    key: key(result),
+                    finding,
+                    fix,
+                    hash,
+                });
+            }
+        }
+    }
+
+    /// Línea de la corrección seleccionada, si hay alguna.
+    pub fn prompt(&self) -> Option<String> {
+        let offer = self.offers.get(self.selected)?;
+        let mut line = format!(
+            "  f: aplicar fix sugerido para {} ({})",
+            offer.finding,
+            offer.fix.describe()
+        );
+        if self.offers.len() > 1 {
+            line.push_str(&format!(
+                " · {}/{}, n/p: otro",
+                self.selected + 1,
+                self.offers.len()
+            ));
+        }
+        Some(line)
+    }
+
+    /// Atiende una tecla; retorna la línea que hay que mostrar.
+    pub fn handle(&mut self, command: Command) -> Option<String> {
+        let count = self.offers.len();
+        if count == 0 {
+            return None;
+        }
+        match command {
+            Command::Next => self.selected = (self.selected + 1) % count,
+            Command::Previous => self.selected = (self.selected + count - 1) % count,
+            Command::Apply => {
+                let offers = std::mem::take(&mut self.offers);
+                let line = apply(&offers[self.selected]);
+                self.selected = 0;
+                // Los que sigan tras revalidar se vuelven a ofrecer
+                if let Some(previous) = &mut self.previous {
+                    for offer in &offers {
+                        previous.remove(&offer.key);
+                    }
+                }
+                return Some(line);
+            }
+        }
+        self.prompt()
+    }
+}
+
+/// Aplica la corrección si el archivo sigue como se validó.
+fn apply(offer: &Offer) -> String {
+    let target = offer.fix.target();
+    if last_run::hash_file(target).ok() != offer.hash {
+        return format!(
+            "  [!] {} cambió desde la validación; no se aplica la corrección.",
+            safe_display(target)
+        );
+    }
+    match offer.fix.apply() {
+        Ok(()) => format!("  [fix] {}", offer.fix.describe()),
+        Err(e) => format!("  [!] No se pudo aplicar la corrección: {:#}", e),
+    }
+}
+
+/// Lee las teclas de la terminal en un hilo aparte mientras dure el watch.
+#[cfg(feature = "interactive")]
+pub fn listen(fixes: std::sync::Arc<std::sync::Mutex<QuickFixes>>) {
+    use crate::messages;
+    use dialoguer::console::{Key, Term};
+    use std::io::Write;
+
+    std::thread::spawn(move || {
+        let term = Term::stdout();
+        while let Ok(key) = term.read_key() {
+            let command = match key {
+                Key::Char('f') => Command::Apply,
+                Key::Char('n') => Command::Next,
+                Key::Char('p') => Command::Previous,
+                _ => continue,
+            };
+            let mut fixes = fixes.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(line) = fixes.handle(command) {
+                // El borrado de la línea va fuera: la tabla traduce la línea en sí
+                print!("\r\x1B[2K{}", messages::tr(&line));
+                let _ = std::io::stdout().flush();
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{Arg, ArgSource, CodeEntity, DocSection};
+    use crate::core::validator::validate_links;
+    use crate::parser::doc_parser::parse_markdown_source;
+    use crate::paths::ProjectPaths;
+    use crate::vfs::MemFs;
+    use std::path::Path;
+
+    const DOCS: &str = "<!-- @docs-id: search-delete -->\n## delete\n\n\
+                        | Param | Type | Description |\n|---|---|---|\n\
+                        | id | string | Identificador |\n";
+
+    fn delete(doc_id: &str) -> CodeEntity {
+        CodeEntity {
+            name: "delete".into(),
+            args: vec![Arg {
+                name: "id".into(),
+                type_name: Some("u64".into()),
+                description: None,
+                source: ArgSource::Code,
+                line: None,
+                unit: None,
+                range: None,
+            }],
+            return_type: None,
+            return_fields: None,
+            doc_id: Some(doc_id.into()),
+            file_path: PathBuf::from("src/search.rs"),
+            line: 2,
+            end_line: 2,
+            is_public: true,
+            suppressions: vec![],
+            file_link: None,
+            examples: Vec::new(),
+        }
+    }
+
+    /// Secciones de `doc_file` y, como el watch, su hash y sus notas.
+    fn sections(doc_file: &Path) -> (Vec<DocSection>, HashMap<PathBuf, (u64, ParseDiagnostics)>) {
+        let source = crate::vfs::read_to_string(doc_file).unwrap();
+        let mut diagnostics = ParseDiagnostics::default();
+        let sections = parse_markdown_source(&source, doc_file, &mut diagnostics).unwrap();
+        let hash = last_run::hash_file(doc_file).unwrap();
+        let path = vfs::canonicalize(doc_file).unwrap();
+        (sections, HashMap::from([(path, (hash, diagnostics))]))
+    }
+
+    /// Valida `entities` contra `doc_file` y actualiza `fixes`.
+    fn validate(fixes: &mut QuickFixes, entities: &[CodeEntity], doc_file: &Path) {
+        let (sections, files) = sections(doc_file);
+        let results = validate_links(entities, &sections, &Default::default());
+        let paths = ProjectPaths::default();
+        let context = FixContext {
+            code_entities: entities,
+            doc_sections: &sections,
+            doc_file,
+            paths: &paths,
+        };
+        fixes.update(&results, &context, &files);
+    }
+
+    #[test]
+    fn new_findings_with_a_fix_are_offered_and_applied() {
+        let dir = MemFs::project();
+        let doc_file = dir.path().join("api.md");
+        dir.write(&doc_file, DOCS);
+        let mut fixes = QuickFixes::default();
+
+        // Lo que ya estaba al arrancar no se ofrece
+        validate(&mut fixes, &[delete("search-delete")], &doc_file);
+        assert_eq!(fixes.prompt(), None);
+        assert_eq!(fixes.handle(Command::Apply), None);
+
+        let entities = [delete("search-delete"), delete("search-purge")];
+        validate(&mut fixes, &entities, &doc_file);
+        let prompt = fixes.prompt().unwrap();
+        assert!(
+            prompt.starts_with("  f: aplicar fix sugerido para missing-doc-section en fn delete"),
+            "{prompt}"
+        );
+        assert!(!prompt.contains("n/p"), "{prompt}");
+
+        let line = fixes.handle(Command::Apply).unwrap();
+        assert!(
+            line.contains("[fix] añadir la sección 'search-purge'"),
+            "{line}"
+        );
+        assert_eq!(fixes.prompt(), None);
+        let fixed = dir.contents(&doc_file).unwrap();
+        assert!(fixed.starts_with(DOCS));
+        assert!(fixed.contains("<!-- @docs-id: search-purge -->"));
+    }
+
+    #[test]
+    fn several_offers_cycle_and_the_others_are_offered_again() {
+        let dir = MemFs::project();
+        let doc_file = dir.path().join("api.md");
+        dir.write(&doc_file, DOCS.replace("string", "u64"));
+        let mut fixes = QuickFixes::default();
+        validate(&mut fixes, &[delete("search-delete")], &doc_file);
+
+        // Un guardado introduce un tipo equivocado y un enlace roto
+        dir.write(&doc_file, DOCS);
+        let entities = [delete("search-delete"), delete("search-purge")];
+        validate(&mut fixes, &entities, &doc_file);
+        let first = fixes.prompt().unwrap();
+        assert!(first.contains("· 1/2, n/p: otro"), "{first}");
+        let second = fixes.handle(Command::Next).unwrap();
+        assert!(second.contains("· 2/2"), "{second}");
+        assert_eq!(fixes.handle(Command::Next).unwrap(), first);
+        assert_eq!(fixes.handle(Command::Previous).unwrap(), second);
+
+        // Seleccionar la del tipo y aplicarla
+        while !fixes.prompt().unwrap().contains("type-mismatch") {
+            fixes.handle(Command::Next);
+        }
+        assert!(
+            fixes
+                .prompt()
+                .unwrap()
+                .contains("(cambiar el tipo de 'id' de 'string' a 'u64'"),
+            "{first}"
+        );
+        fixes.handle(Command::Apply).unwrap();
+        assert_eq!(
+            dir.contents(&doc_file).unwrap(),
+            DOCS.replace("| id | string |", "| id | u64 |")
+        );
+
+        // Tras revalidar, el que quedó pendiente se vuelve a ofrecer
+        validate(&mut fixes, &entities, &doc_file);
+        let pending = fixes.prompt().unwrap();
+        assert!(pending.contains("missing-doc-section"), "{pending}");
+        assert!(!pending.contains("n/p"), "{pending}");
+    }
+
+    #[test]
+    fn fixes_to_a_file_with_a_git_conflict_are_not_offered() {
+        let dir = MemFs::project();
+        let doc_file = dir.path().join("api.md");
+        dir.write(&doc_file, DOCS.replace("string", "u64"));
+        let mut fixes = QuickFixes::default();
+        validate(&mut fixes, &[delete("search-delete")], &doc_file);
+
+        // El tipo equivocado llega con un merge a medias
+        let conflicted = format!("{DOCS}\n<<<<<<< HEAD\nUno.\n=======\nOtro.\n>>>>>>> rama\n");
+        dir.write(&doc_file, &conflicted);
+        validate(&mut fixes, &[delete("search-delete")], &doc_file);
+        assert_eq!(fixes.prompt(), None);
+        assert_eq!(fixes.handle(Command::Apply), None);
+        assert_eq!(dir.contents(&doc_file).unwrap(), conflicted);
+    }
+
+    #[test]
+    fn a_file_that_changed_since_the_validation_is_not_written() {
+        let dir = MemFs::project();
+        let doc_file = dir.path().join("api.md");
+        dir.write(&doc_file, DOCS.replace("string", "u64"));
+        let mut fixes = QuickFixes::default();
+        validate(&mut fixes, &[delete("search-delete")], &doc_file);
+        dir.write(&doc_file, DOCS);
+        validate(&mut fixes, &[delete("search-delete")], &doc_file);
+        assert!(fixes.prompt().is_some());
+
+        // Cambios que el watch aún no validó (el editor guardó otra vez)
+        let unsaved = format!("{DOCS}\nNota nueva.\n");
+        dir.write(&doc_file, &unsaved);
+        let line = fixes.handle(Command::Apply).unwrap();
+        assert!(
+            line.contains("cambió desde la validación; no se aplica"),
+            "{line}"
+        );
+        assert_eq!(dir.contents(&doc_file).unwrap(), unsaved);
+    }
+}
@@ -0,0 +1,366 @@
+//! Carga endurecida de los YAML de `.docsguard/` (baseline, configuración y
+//! enlaces).
+//!
+//! serde_yml se queda en silencio con la última aparición de una clave
+//! repetida: un `entries:` duplicado a mano en el baseline perdía la mitad de
+//! las entradas. Antes de deserializar se recorren los eventos del documento y
+//! una clave repetida en el mismo mapeo es un error con su nombre y sus dos
+//! líneas. Todo error de sintaxis o de esquema se muestra como
+//! `archivo:línea:columna: mensaje`, seguido de la línea afectada y un `^`
+//! bajo la columna. Si el archivo tiene esquema (`schema`), la primera
+//! violación del esquema sigue como pista: la ruta del campo y la clave o el
+//! valor admitidos más parecidos.
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use serde_yml::libyml::parser::{Event, Parser};
+
+use crate::exit::Failure;
+use crate::parser::code_parser::safe_display;
+use crate::schema::{self, Artifact};
+use crate::vfs::{self, Bounded};
+
+/// Error de un documento YAML con su posición (1-based).
+#[derive(Debug, Clone, PartialEq)]
+pub struct YamlError {
+    /// Archivo, si se conoce.
+    pub path: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+    /// Línea afectada tal cual aparece en el documento.
+    source_line: String,
+    /// Lo que dice el esquema del archivo (`schema::explain`).
+    hint: Option<String>,
+}
+
+impl YamlError {
+    fn at(content: &str, line: usize, column: usize, message: String) -> Self {
+        let line = line.max(1);
+        let source_line = content.lines().nth(line - 1).unwrap_or_default();
+        let mut message = message;
+        if source_line
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .any(|c| c == '\t')
+        {
+            message.push_str(" (la línea se indenta con tabuladores: YAML solo admite espacios)");
+        }
+        YamlError {
+            path: None,
+            line,
+            column: column.max(1),
+            message,
+            source_line: source_line.to_string(),
+            hint: None,
+        }
+    }
+
+    /// El mismo error referido a `path`.
+    pub fn in_file(mut self, path: &Path) -> Self {
+        self.path = Some(safe_display(path));
+        self
+    }
+}
+
+impl fmt::Display for YamlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{}:{}:{}: ", path, self.line, self.column)?,
+            None => write!(f, "línea {}, columna {}: ", self.line, self.column)?,
+        }
+        f.write_str(&self.message)?;
+        let gutter = " ".repeat(self.line.to_string().len());
+        // Los tabuladores se conservan para que el `^` caiga bajo la columna
+        let caret: String = self
+            .source_line
+            .chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        write!(
+            f,
+            "\n {} | {}\n {} | {}^",
+            self.line, self.source_line, gutter, caret
+        )?;
+        match &self.hint {
+            Some(hint) => write!(f, "\n    -> Esquema: {}", hint),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for YamlError {}
+
+/// Lee `path` si no supera `max_size` bytes. El exceso es un error de la
+/// categoría de `failure` (`Failure::baseline`, `Failure::config`…).
+pub fn read(path: &Path, max_size: u64, failure: fn(String) -> Failure) -> Result<String> {
+    // VUL-04: limitar tamaño antes de deserializar para prevenir DoS via YAML grande.
+    let file_size = match vfs::read_bounded(path, max_size)
+        .with_context(|| format!("No se pudo leer: {}", path.display()))?
+    {
+        Bounded::Contents(content) => return Ok(content),
+        Bounded::TooLarge(file_size) => file_size,
+    };
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| safe_display(path));
+    Err(failure(format!(
+        "{} demasiado grande ({}, máximo: {})\n    -> Archivo: {}",
+        name,
+        format_size(file_size),
+        format_size(max_size),
+        path.display()
+    ))
+    .with_path(path)
+    .into())
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else {
+        format!("{} KB", bytes / 1024)
+    }
+}
+
+/// Deserializa `content` rechazando claves repetidas.
+pub fn from_str<T: DeserializeOwned>(content: &str) -> Result<T, YamlError> {
+    check_duplicate_keys(content)?;
+    serde_yml::from_str(content).map_err(|e| {
+        let (line, column) = e
+            .location()
+            .map_or((1, 1), |location| (location.line(), location.column()));
+        YamlError::at(content, line, column, strip_marks(&e.to_string()))
+    })
+}
+
+/// Como `from_str`, y si falla, añade la primera violación del esquema de
+/// `artifact`, que nombra el campo y sugiere la clave o el valor más parecidos.
+pub fn from_str_checked<T: DeserializeOwned>(
+    content: &str,
+    artifact: Artifact,
+) -> Result<T, YamlError> {
+    from_str(content).map_err(|mut e| {
+        e.hint = schema::explain(artifact, content);
+        e
+    })
+}
+
+/// Estado de un mapeo o secuencia abiertos al recorrer los eventos.
+enum Frame {
+    Sequence,
+    Mapping {
+        /// Clave escalar → línea de su primera aparición.
+        keys: HashMap<Vec<u8>, usize>,
+        expecting_key: bool,
+    },
+}
+
+/// Falla con la primera clave escalar repetida dentro de un mismo mapeo.
+fn check_duplicate_keys(content: &str) -> Result<(), YamlError> {
+    let mut parser = Parser::new(Cow::Borrowed(content.as_bytes()));
+    let mut stack: Vec<Frame> = Vec::new();
+    loop {
+        let (event, mark) = match parser.parse_next_event() {
+            Ok(next) => next,
+            Err(e) => {
+                let mark = e.mark();
+                return Err(YamlError::at(
+                    content,
+                    mark.line() as usize + 1,
+                    mark.column() as usize + 1,
+                    strip_marks(&e.to_string()),
+                ));
+            }
+        };
+        let (line, column) = (mark.line() as usize + 1, mark.column() as usize + 1);
+        // Clave o valor: en un mapeo, los nodos se alternan
+        let is_key = match stack.last_mut() {
+            Some(Frame::Mapping { expecting_key, .. })
+                if matches!(
+                    event,
+                    Event::Scalar(_)
+                        | Event::Alias(_)
+                        | Event::MappingStart(_)
+                        | Event::SequenceStart(_)
+                ) =>
+            {
+                let is_key = *expecting_key;
+                *expecting_key = !is_key;
+                is_key
+            }
+            _ => false,
+        };
+        match event {
+            Event::StreamEnd => return Ok(()),
+            Event::Scalar(scalar) if is_key => {
+                let Some(Frame::Mapping { keys, .. }) = stack.last_mut() else {
+                    unreachable!("una clave siempre está en un mapeo");
+                };
+                let key = scalar.value.to_vec();
+                if let Some(first) = keys.get(&key) {
+                    return Err(YamlError::at(
+                        content,
+                        line,
+                        column,
+                        format!(
+                            "clave `{}` repetida (ya aparece en la línea {}): YAML se quedaría solo con la última",
+                            String::from_utf8_lossy(&key),
+                            first
+                        ),
+                    ));
+                }
+                keys.insert(key, line);
+            }
+            Event::MappingStart(_) => stack.push(Frame::Mapping {
+                keys: HashMap::new(),
+                expecting_key: true,
+            }),
+            Event::SequenceStart(_) => stack.push(Frame::Sequence),
+            Event::MappingEnd | Event::SequenceEnd => {
+                stack.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Quita las posiciones ` at line N column M` que serde_yml y libyml añaden
+/// al texto: la posición ya va delante del mensaje.
+pub(crate) fn strip_marks(message: &str) -> String {
+    let mut out = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(start) = rest.find(" at line ") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + " at line ".len()..];
+        let digits = |s: &str| s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let line_len = digits(after);
+        match after[line_len..].strip_prefix(" column ") {
+            Some(column) if line_len > 0 => rest = &column[digits(column)..],
+            _ => {
+                out.push_str(" at line ");
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vfs::MemFs;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Doc {
+        version: String,
+        #[serde(default)]
+        entries: Vec<Entry>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Entry {
+        rule: String,
+        #[serde(default)]
+        tags: HashMap<String, String>,
+    }
+
+    fn error(content: &str) -> YamlError {
+        from_str::<Doc>(content).unwrap_err()
+    }
+
+    #[test]
+    fn duplicate_keys_fail_with_the_key_and_both_lines() {
+        let err =
+            error("version: '1'\nentries:\n  - rule: ghost-arg\nentries:\n  - rule: missing-arg\n");
+        assert_eq!((err.line, err.column), (4, 1));
+        assert!(err.message.contains("`entries`"), "{}", err.message);
+        assert!(err.message.contains("línea 2"), "{}", err.message);
+
+        // Dentro de un elemento de la lista, y con la clave entre comillas
+        let err = error("version: '1'\nentries:\n  - rule: a\n    \"rule\": b\n");
+        assert_eq!(err.line, 4);
+        assert!(err.message.contains("`rule`"));
+
+        // La misma clave en mapeos distintos no es un duplicado
+        let doc: Doc =
+            from_str("version: '1'\nentries:\n  - rule: a\n    tags: {rule: x}\n  - rule: b\n")
+                .unwrap();
+        assert_eq!(doc.entries.len(), 2);
+    }
+
+    #[test]
+    fn tab_indentation_points_at_the_line_and_says_why() {
+        let err = error("version: '1'\nentries:\n\t- rule: a\n").in_file(Path::new("b.yaml"));
+        assert_eq!(err.line, 3);
+        assert!(err.message.contains("tabuladores"), "{}", err.message);
+        let rendered = err.to_string();
+        assert!(rendered.starts_with("b.yaml:3:"), "{rendered}");
+        assert!(rendered.contains("\n 3 | \t- rule: a\n   | "), "{rendered}");
+        assert!(!rendered.contains(" at line "), "{rendered}");
+    }
+
+    #[test]
+    fn truncated_files_and_schema_errors_carry_a_position_and_snippet() {
+        let err = error("version: '1'\nentries:\n  - rule: \"ghost-a");
+        assert_eq!(err.line, 3);
+        assert!(err
+            .to_string()
+            .contains("\n 3 |   - rule: \"ghost-a\n   | "));
+
+        let err = error("version: '1'\nentries:\n  - rule: [a, b]\n");
+        assert_eq!((err.line, err.column), (3, 11));
+        assert!(
+            err.to_string()
+                .ends_with(" 3 |   - rule: [a, b]\n   |           ^"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn schema_violations_follow_the_serde_error_as_a_hint() {
+        let content = "rules:\n  orphan-section: off\nsite:\n  sluger: github\n";
+        let err = from_str_checked::<crate::config::Config>(content, Artifact::Config).unwrap_err();
+        assert_eq!(err.line, 4);
+        assert!(
+            err.to_string().ends_with(
+                "\n    -> Esquema: `site.sluger`: campo `sluger` desconocido; ¿quisiste decir `slugger`?"
+            ),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn oversized_files_are_rejected_before_parsing() {
+        let dir = MemFs::project();
+        let path = dir.path().join("config.yaml");
+        dir.write(&path, "a: 1\n".repeat(1024));
+        let err = read(&path, 1024, Failure::config).unwrap_err();
+        assert_eq!(crate::exit::exit_code_for(&err), crate::exit::USAGE);
+        assert!(err
+            .to_string()
+            .starts_with("config.yaml demasiado grande (5 KB, máximo: 1 KB)"));
+        assert_eq!(read(&path, 10 * 1024, Failure::config).unwrap().len(), 5120);
+    }
+
+    #[test]
+    fn marks_are_stripped_but_other_text_survives() {
+        assert_eq!(
+            strip_marks("did not find expected key at line 3 column 1, while parsing a block mapping at line 1 column 1"),
+            "did not find expected key, while parsing a block mapping"
+        );
+        assert_eq!(strip_marks("look at line breaks"), "look at line breaks");
+    }
+}
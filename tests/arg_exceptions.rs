@@ -0,0 +1,48 @@
+//! `arg_exceptions`: parejas de argumentos con nombre distinto en código y
+//! docs declaradas a propósito.
+
+mod common;
+
+use common::{auth_project, check, LINKED};
+
+#[test]
+fn declared_arg_exceptions_clear_the_pair_and_stale_ones_are_unused() {
+    let dir =
+        auth_project("/// @docs: [auth-login]\nexport function login(user_name: string) {}\n");
+    check(dir.path()).assert().code(1);
+    std::fs::create_dir(dir.path().join(".docsguard")).unwrap();
+    let exceptions = |code_arg: &str| {
+        std::fs::write(
+            dir.path().join(".docsguard/config.yaml"),
+            format!(
+                "arg_exceptions:\n  - {{doc_id: auth-login, doc_arg: username, code_arg: {}, reason: legacy name}}\n",
+                code_arg
+            ),
+        )
+        .unwrap();
+    };
+    exceptions("user_name");
+    let output = check(dir.path())
+        .assert()
+        .code(0)
+        .get_output()
+        .stdout
+        .clone();
+    assert!(String::from_utf8_lossy(&output).contains(
+        "'username' (docs) equivale a 'user_name' (fn login) según arg_exceptions: legacy name."
+    ));
+    check(dir.path())
+        .arg("--deny-unused-suppressions")
+        .assert()
+        .code(0);
+
+    // Una pareja que ya no se produce es una excepción sin uso
+    exceptions("login_name");
+    check(dir.path()).assert().code(1);
+    std::fs::write(dir.path().join("src/auth.ts"), LINKED).unwrap();
+    check(dir.path()).assert().code(0);
+    check(dir.path())
+        .arg("--deny-unused-suppressions")
+        .assert()
+        .code(1);
+}
@@ -0,0 +1,59 @@
+//! `attest` y `check --check-attestations`: secciones revisadas por alguien
+//! y avisos cuando cambian después.
+
+mod common;
+
+use assert_cmd::Command;
+use common::{auth_project, check, command, AUTH_DOCS, LINKED};
+
+#[test]
+fn attested_sections_warn_when_edited_and_required_ones_exit_1_without_one() {
+    let dir = auth_project(LINKED);
+    std::fs::create_dir(dir.path().join(".docsguard")).unwrap();
+    std::fs::write(
+        dir.path().join(".docsguard/config.yaml"),
+        "attestation_required: [auth-login]\n",
+    )
+    .unwrap();
+    check(dir.path()).assert().code(0);
+    check(dir.path())
+        .arg("--check-attestations")
+        .assert()
+        .code(1);
+
+    let attest = |id: &str| {
+        command(dir.path())
+            .args(["attest", id, "--docs", "docs/api.md", "--by", "@maria"])
+            .assert()
+    };
+    attest("auth-logout").code(2);
+    attest("auth-login").code(0);
+    assert!(
+        std::fs::read_to_string(dir.path().join(".docsguard/attestations.yaml"))
+            .unwrap()
+            .contains("by: '@maria'")
+    );
+    check(dir.path())
+        .arg("--check-attestations")
+        .assert()
+        .code(0);
+
+    // Reformatear no cuenta como cambio; cambiar el texto, sí
+    std::fs::write(
+        dir.path().join("docs/api.md"),
+        AUTH_DOCS.replace('\n', "\r\n"),
+    )
+    .unwrap();
+    let stdout = |cmd: &mut Command| {
+        String::from_utf8_lossy(&cmd.assert().code(0).get_output().stdout).into_owned()
+    };
+    assert!(!stdout(check(dir.path()).arg("--check-attestations"))
+        .contains("changed-since-attestation"));
+    std::fs::write(
+        dir.path().join("docs/api.md"),
+        AUTH_DOCS.replace("Usuario", "Usuario o email"),
+    )
+    .unwrap();
+    assert!(stdout(check(dir.path()).arg("--check-attestations"))
+        .contains("modificada desde la última atestación de @maria el "));
+}
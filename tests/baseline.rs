@@ -0,0 +1,122 @@
+//! El baseline a lo largo del tiempo: funciones movidas de archivo, edades
+//! de las entradas al regenerarlo y `check --debt-age`.
+
+mod common;
+
+use common::{auth_project, check, command, UNDOCUMENTED};
+
+#[test]
+fn functions_moved_to_another_file_stay_in_the_baseline() {
+    let dir = auth_project(UNDOCUMENTED);
+    command(dir.path())
+        .args(["baseline", "src/auth.ts", "docs/api.md"])
+        .assert()
+        .code(0);
+    let path = dir.path().join(".docsguard/baseline.yaml");
+    let yaml = std::fs::read_to_string(&path).unwrap();
+    let stamp = yaml.lines().find(|l| l.contains("created_at:")).unwrap();
+    std::fs::write(&path, yaml.replacen(stamp, "  created_at: '2020-01-01'", 1)).unwrap();
+
+    // Refactor: logout pasa de auth.ts a session.ts
+    std::fs::write(dir.path().join("src/auth.ts"), "").unwrap();
+    std::fs::write(dir.path().join("src/session.ts"), UNDOCUMENTED).unwrap();
+    let moved = || {
+        command(dir.path())
+            .args(["check", "docs/api.md", "src/auth.ts", "src/session.ts"])
+            .output()
+            .unwrap()
+    };
+    let output = moved();
+    assert_eq!(output.status.code(), Some(0), "{:?}", output);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains(
+            "Entrada de baseline seguida tras mover fn logout de src/auth.ts a src/session.ts."
+        ),
+        "{stdout}"
+    );
+    assert!(stdout.contains("0 errores, 0 advertencias"), "{stdout}");
+
+    let pruned = command(dir.path())
+        .args([
+            "baseline",
+            "src/session.ts",
+            "docs/api.md",
+            "--prune",
+            "--rewrite-moves",
+        ])
+        .output()
+        .unwrap();
+    assert_eq!(pruned.status.code(), Some(0), "{:?}", pruned);
+    let stdout = String::from_utf8(pruned.stdout).unwrap();
+    assert!(
+        stdout.contains("  1 ruta actualizada tras mover"),
+        "{stdout}"
+    );
+    let yaml = std::fs::read_to_string(&path).unwrap();
+    assert!(yaml.contains("file: src/session.ts"), "{yaml}");
+    assert!(yaml.contains("created_at: '2020-01-01'"), "{yaml}");
+    let stdout = String::from_utf8(moved().stdout).unwrap();
+    assert!(!stdout.contains("seguida tras mover"), "{stdout}");
+
+    // --rewrite-moves solo tiene sentido al podar
+    command(dir.path())
+        .args([
+            "baseline",
+            "src/session.ts",
+            "docs/api.md",
+            "--rewrite-moves",
+        ])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn regenerated_baselines_keep_entry_ages_and_debt_age_reports_them() {
+    let dir = auth_project(UNDOCUMENTED);
+    let regenerate = || {
+        command(dir.path())
+            .args(["baseline", "src/auth.ts", "docs/api.md"])
+            .assert()
+            .code(0);
+    };
+    regenerate();
+    let path = dir.path().join(".docsguard/baseline.yaml");
+    let yaml = std::fs::read_to_string(&path).unwrap();
+    let stamp = yaml.lines().find(|l| l.contains("created_at:")).unwrap();
+    // La primera entrada pasa a ser deuda antigua con motivo y caducidad
+    let aged = yaml.replacen(
+        stamp,
+        "  created_at: '2020-01-01'\n  reason: migración pendiente\n  expires: '2020-06-30'",
+        1,
+    );
+    std::fs::write(&path, aged).unwrap();
+    regenerate();
+    let yaml = std::fs::read_to_string(&path).unwrap();
+    assert!(yaml.contains("created_at: '2020-01-01'"), "{yaml}");
+    assert!(yaml.contains("reason: migración pendiente"), "{yaml}");
+
+    let output = check(dir.path()).arg("--debt-age").output().unwrap();
+    assert_eq!(output.status.code(), Some(0), "{:?}", output);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let block = &stdout[stdout.find("[debt-age]").expect(&stdout)..];
+    assert!(
+        block.starts_with("[debt-age] Baseline: 2 entradas;"),
+        "{block}"
+    );
+    assert!(
+        block.contains("#1 Error en fn logout (src/auth.ts):"),
+        "{block}"
+    );
+    assert!(block.contains("— migración pendiente\n"), "{block}");
+    assert!(
+        block.contains("Caducan en 30 días o menos:\n      2020-06-30 (caducada hace"),
+        "{block}"
+    );
+
+    // Sin la opción, la salida no cambia
+    let plain = check(dir.path()).output().unwrap();
+    assert!(!String::from_utf8(plain.stdout)
+        .unwrap()
+        .contains("[debt-age]"));
+}
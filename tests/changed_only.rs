@@ -0,0 +1,132 @@
+//! `--changed-only [BASE]`: solo las funciones y secciones que toca el diff.
+
+mod common;
+
+use common::{docsguard, git, project};
+
+const CODE: &str = "\
+/// @docs: [login]
+export function login(user: string) {
+  return user;
+}
+
+/// @docs: [logout]
+export function logout(token: string) {
+  return token;
+}
+";
+
+const DOCS: &str = "\
+<!-- @docs-id: login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| user | string | Usuario |
+
+<!-- @docs-id: logout -->
+## logout
+
+<!-- @docs-id: legacy -->
+## legacy
+
+Sin función.
+";
+
+/// Repositorio con `logout` sin documentar y la sección `legacy` huérfana,
+/// ya commiteados.
+fn repository() -> tempfile::TempDir {
+    let dir = project(&[("src/api.ts", CODE), ("docs/api.md", DOCS)]);
+    let root = dir.path();
+    git(root, &["init", "-q"]);
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "base"]);
+    dir
+}
+
+#[test]
+fn only_the_functions_in_the_diff_are_validated_without_orphan_sections() {
+    let dir = repository();
+    let root = dir.path();
+    // Un cambio en el cuerpo de `login`, que pasa a tener un argumento sin documentar
+    std::fs::write(
+        root.join("src/api.ts"),
+        CODE.replace(
+            "login(user: string)",
+            "login(user: string, remember: boolean)",
+        ),
+    )
+    .unwrap();
+
+    let (code, out, _) = docsguard(root, &["check", "docs/api.md", "src/"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(out.contains("en fn logout"), "{out}");
+    assert!(out.contains("(orphan-section)"), "{out}");
+
+    let (code, out, _) = docsguard(root, &["check", "--changed-only", "docs/api.md", "src/"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(
+        out.contains("[changed-only] Solo lo tocado desde 'HEAD': 1 función de 2, 1 sección de 3."),
+        "{out}"
+    );
+    assert!(
+        out.contains("[!] Warning (missing-arg) en fn login (src/api.ts:2)"),
+        "{out}"
+    );
+    assert!(!out.contains("fn logout"), "{out}");
+    assert!(!out.contains("orphan-section"), "{out}");
+
+    // Con todo commiteado, contra la revisión anterior
+    git(root, &["commit", "-q", "-am", "remember"]);
+    let (_, out, _) = docsguard(root, &["check-all", "--changed-only=HEAD~1"]);
+    assert!(
+        out.contains(
+            "[changed-only] Solo lo tocado desde 'HEAD~1': 1 función de 2, 1 sección de 3."
+        ),
+        "{out}"
+    );
+    assert!(out.contains("en fn login (src/api.ts:2)"), "{out}");
+}
+
+#[test]
+fn a_changed_annotation_and_a_changed_section_count_as_touched() {
+    let dir = repository();
+    let root = dir.path();
+    std::fs::write(
+        root.join("src/api.ts"),
+        CODE.replace("/// @docs: [logout]", "/// @docs: [logout-v2]"),
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("docs/api.md"),
+        DOCS.replace("Sin función.", "Sin función todavía."),
+    )
+    .unwrap();
+
+    let (code, out, _) = docsguard(root, &["check", "--changed-only", "docs/api.md", "src/"]);
+    assert_eq!(code, Some(1), "{out}");
+    assert!(
+        out.contains("[X] Error (missing-doc-section) en fn logout (src/api.ts:7)"),
+        "{out}"
+    );
+    // `legacy` cambió, pero en la vista parcial no se da por huérfana
+    assert!(out.contains("1 función de 2, 1 sección de 3."), "{out}");
+    assert!(
+        !out.contains("orphan-section") && !out.contains("fn login"),
+        "{out}"
+    );
+}
+
+#[test]
+fn outside_a_git_repository_it_is_a_usage_error() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("api.ts"), CODE).unwrap();
+    std::fs::write(dir.path().join("api.md"), DOCS).unwrap();
+    let (code, out, err) = docsguard(dir.path(), &["check", "--changed-only", "api.md", "api.ts"]);
+    assert_eq!(code, Some(2), "{out}{err}");
+    assert!(
+        err.contains("--changed-only necesita un repositorio git y . no está en ninguno."),
+        "{err}"
+    );
+    assert!(out.is_empty(), "{out}");
+}
@@ -0,0 +1,75 @@
+//! `docsguard check-all`: todo el repositorio, con los archivos que no se
+//! pueden parsear contados sin abortar.
+
+mod common;
+
+use common::docsguard;
+
+const CODE: &str = "\
+/// @docs: [login]
+export function login(user: string) {}
+
+/// @docs: [logout]
+export function logout() {}
+";
+
+const DOCS: &str = "\
+<!-- @docs-id: login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| user | string | Usuario |
+";
+
+fn project() -> tempfile::TempDir {
+    common::project(&[
+        ("src/api.ts", CODE),
+        ("docs/api.md", DOCS),
+        ("README.md", "# Proyecto\n"),
+        // Ni dependencias ni directorios ocultos
+        (
+            "node_modules/pkg/index.ts",
+            "/// @docs: [pkg]\nexport function pkg() {}\n",
+        ),
+        (".github/notes.md", "<!-- @docs-id: hidden -->\n## hidden\n"),
+    ])
+}
+
+#[test]
+fn every_code_and_markdown_file_is_checked_together_with_the_baseline() {
+    let dir = project();
+    let (code, out, _) = docsguard(&dir, &["check-all"]);
+    assert_eq!(code, Some(1), "{out}");
+    assert!(out.contains("Código: 1 archivo; docs: 2 archivos"), "{out}");
+    assert!(
+        out.contains("[X] Error (missing-doc-section) en fn logout (src/api.ts:5)"),
+        "{out}"
+    );
+    assert!(out.contains("    -> Insertar en: docs/api.md:7"), "{out}");
+    assert!(!out.contains("pkg") && !out.contains("hidden"), "{out}");
+    assert!(out.contains("Resumen: 1 error, 0 advertencias"), "{out}");
+
+    let (code, _, _) = docsguard(&dir, &["baseline", "src/api.ts", "docs/api.md"]);
+    assert_eq!(code, Some(0));
+    let (code, out, _) = docsguard(&dir, &["check-all", "--root", "."]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(
+        out.contains("[baseline] 1 hallazgo conocido filtrado."),
+        "{out}"
+    );
+}
+
+#[test]
+fn a_file_that_cannot_be_parsed_is_counted_and_the_rest_still_checked() {
+    let dir = project();
+    std::fs::write(dir.path().join("src/broken.ts"), b"\xff\xfe export").unwrap();
+    let (code, out, _) = docsguard(&dir, &["check-all", "--fail-on", "never"]);
+    assert_eq!(code, Some(1), "{out}");
+    assert!(
+        out.contains("[X] No se pudo parsear src/broken.ts: "),
+        "{out}"
+    );
+    assert!(out.contains("en fn logout (src/api.ts:5)"), "{out}");
+    assert!(out.contains("  1 archivo sin parsear."), "{out}");
+}
@@ -0,0 +1,122 @@
+//! `docsguard ci simulate` y `ci run`: el perfil de `profiles:` predice y
+//! ejecuta la misma invocación de CI.
+
+mod common;
+
+use common::docsguard;
+
+const CODE: &str = "\
+/// @docs: [login]
+export function login(user: string) {}
+";
+
+const DOCS: &str = "\
+<!-- @docs-id: login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| user | string | Usuario |
+";
+
+const PROFILES: &str = "\
+profiles:
+  slow: {doc_file: docs/api.md, code: [src], fail_on: error, time_budget: 0.000001}
+  broken:
+    doc_file: docs/api.md
+    code: [src]
+    time_budget: 600
+    max_warnings: 5
+    min_coverage: 50
+    format: sarif
+    output: out/docsguard.sarif
+";
+
+fn project(extra_code: &str) -> tempfile::TempDir {
+    common::project(&[
+        ("src/api.ts", &format!("{CODE}{extra_code}")),
+        ("docs/api.md", DOCS),
+        (".docsguard/config.yaml", PROFILES),
+    ])
+}
+
+#[test]
+fn a_profile_over_budget_fails_even_though_fail_on_passes() {
+    let dir = project("");
+    let (code, out, _) = docsguard(&dir, &["ci", "simulate", "--profile", "slow"]);
+    // La simulación termina bien; el código del job va en el veredicto
+    assert_eq!(code, Some(0), "{out}");
+    assert!(
+        out.contains("[✓] fail-on: 0 errores, 1 advertencia · umbral: error"),
+        "{out}"
+    );
+    assert!(out.contains("[✗] budget: "), "{out}");
+    assert!(out.contains("[-] max-warnings: sin configurar"), "{out}");
+    assert!(out.contains("Artefactos: ninguno (format: text)"), "{out}");
+    assert!(
+        out.contains("Veredicto: fallaría por budget (código de salida 1)"),
+        "{out}"
+    );
+
+    let (code, out, _) = docsguard(&dir, &["ci", "run", "--profile", "slow"]);
+    assert_eq!(code, Some(1), "{out}");
+    assert!(out.contains("[!] Warning (slow-run)"), "{out}");
+    assert!(
+        out.contains("Veredicto: falla por budget (código de salida 1)"),
+        "{out}"
+    );
+}
+
+#[test]
+fn a_profile_with_errors_fails_fail_on_within_budget_and_writes_its_artifact() {
+    let dir = project("\n/// @docs: [logout]\nexport function logout() {}\n");
+    let (code, out, _) = docsguard(&dir, &["ci", "simulate", "--profile", "broken"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(
+        out.contains("[✗] fail-on: 1 error, 0 advertencias · umbral: error"),
+        "{out}"
+    );
+    assert!(out.contains("[✓] budget: "), "{out}");
+    assert!(
+        out.contains("[✓] max-warnings: 0 advertencias (máximo 5)"),
+        "{out}"
+    );
+    assert!(
+        out.contains("[✓] min-coverage: 100.0% (mínimo 50%)"),
+        "{out}"
+    );
+    assert!(
+        out.contains("Artefactos: out/docsguard.sarif (simulado en "),
+        "{out}"
+    );
+    assert!(
+        out.contains("Veredicto: fallaría por fail-on (código de salida 1)"),
+        "{out}"
+    );
+    // Simular no toca los artefactos reales
+    assert!(!dir.path().join("out").exists());
+
+    let (code, out, _) = docsguard(&dir, &["ci", "run", "--profile", "broken"]);
+    assert_eq!(code, Some(1), "{out}");
+    assert!(
+        out.contains("[X] Error (missing-doc-section) en fn logout (src/api.ts:5)"),
+        "{out}"
+    );
+    let sarif = std::fs::read_to_string(dir.path().join("out/docsguard.sarif")).unwrap();
+    assert!(sarif.contains("missing-doc-section"), "{sarif}");
+}
+
+#[test]
+fn an_unknown_profile_lists_the_declared_ones() {
+    let dir = project("");
+    let (code, _, stderr) = docsguard(&dir, &["ci", "run", "--profile", "nightly"]);
+    assert_eq!(code, Some(2), "{stderr}");
+    assert!(
+        stderr.contains("No hay ningún perfil 'nightly'."),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("Perfiles declarados: broken, slow."),
+        "{stderr}"
+    );
+}
@@ -0,0 +1,112 @@
+//! Ayudantes de los tests de integración: cada archivo los trae con
+//! `mod common;` y usa los que necesita.
+#![allow(dead_code)]
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use std::path::Path;
+
+/// Proyecto temporal con `files` (`(ruta relativa, contenido)`), creando
+/// los directorios que hagan falta.
+pub fn project(files: &[(&str, &str)]) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    for (file, content) in files {
+        let path = dir.path().join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+    dir
+}
+
+/// `docsguard` en `dir`, sin backtraces, para añadirle argumentos, stdin o
+/// variables.
+pub fn command(dir: impl AsRef<Path>) -> Command {
+    let mut cmd = cargo_bin_cmd!("docsguard");
+    cmd.current_dir(dir.as_ref()).env("RUST_BACKTRACE", "0");
+    cmd
+}
+
+/// `docs/api.md` del proyecto de autenticación: la sección `auth-login`.
+pub const AUTH_DOCS: &str = "\
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Usuario |
+";
+
+/// `login`, enlazada a su sección de `AUTH_DOCS`.
+pub const LINKED: &str = "\
+/// @docs: [auth-login]
+export function login(username: string) {}
+";
+
+/// `logout`, enlazada a una sección que `AUTH_DOCS` no tiene.
+pub const UNDOCUMENTED: &str = "\
+/// @docs: [auth-logout]
+export function logout(username: string) {}
+";
+
+/// Proyecto temporal con `docs/api.md` (`AUTH_DOCS`) y `src/auth.ts` (`code`).
+pub fn auth_project(code: &str) -> tempfile::TempDir {
+    project(&[("docs/api.md", AUTH_DOCS), ("src/auth.ts", code)])
+}
+
+/// `docsguard check docs/api.md src/auth.ts` en `dir`, para añadirle opciones.
+pub fn check(dir: &Path) -> Command {
+    let mut cmd = command(dir);
+    cmd.args(["check", "docs/api.md", "src/auth.ts"]);
+    cmd
+}
+
+/// Código de salida, stdout y stderr de `docsguard args` en `dir`.
+pub fn docsguard(dir: impl AsRef<Path>, args: &[&str]) -> (Option<i32>, String, String) {
+    let output = command(dir).args(args).output().unwrap();
+    (
+        output.status.code(),
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+/// `git args` en `dir` con una identidad de prueba; falla si git falla.
+pub fn git(dir: &Path, args: &[&str]) {
+    git_with(dir, &[], args);
+}
+
+/// Como `git`, con el autor y el committer fechados en `date` (cualquier
+/// formato de `GIT_AUTHOR_DATE`).
+pub fn git_at(dir: &Path, date: &str, args: &[&str]) {
+    git_with(
+        dir,
+        &[("GIT_AUTHOR_DATE", date), ("GIT_COMMITTER_DATE", date)],
+        args,
+    );
+}
+
+/// Como `git`, con variables de entorno (`GIT_AUTHOR_NAME`…) encima de la
+/// identidad de prueba.
+pub fn git_with(dir: &Path, env: &[(&str, &str)], args: &[&str]) {
+    let output = std::process::Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .envs(env.iter().copied())
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "git {:?}: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Segundos desde 1970 ahora mismo, para fechar commits relativos a hoy.
+pub fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
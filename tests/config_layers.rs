@@ -0,0 +1,94 @@
+//! Capas de configuración: `--config`, `DOCSGUARD_CONFIG` y `--set` sobre
+//! `.docsguard/config.yaml`, y `config show --resolved`.
+
+mod common;
+
+use common::{auth_project, check, command};
+
+#[test]
+fn config_files_and_settings_from_the_cli_layer_over_the_project_config() {
+    // `password` falta en las docs: Warning por defecto
+    let dir = auth_project(
+        "/// @docs: [auth-login]\nexport function login(username: string, password: string) {}\n",
+    );
+    std::fs::create_dir(dir.path().join("ci")).unwrap();
+    std::fs::write(
+        dir.path().join("ci/strict.yaml"),
+        "rules:\n  missing-arg: error\n",
+    )
+    .unwrap();
+    check(dir.path()).assert().code(0);
+    check(dir.path())
+        .args(["--config", "ci/strict.yaml"])
+        .assert()
+        .code(1);
+    check(dir.path())
+        .env("DOCSGUARD_CONFIG", "ci/strict.yaml")
+        .assert()
+        .code(1);
+    check(dir.path())
+        .env("DOCSGUARD_CONFIG", "ci/strict.yaml")
+        .args(["--config", "ci/none.yaml"])
+        .assert()
+        .code(2);
+    check(dir.path())
+        .args([
+            "--config",
+            "ci/strict.yaml",
+            "--set",
+            "rules.missing-arg=info",
+        ])
+        .assert()
+        .code(0);
+    check(dir.path())
+        .args(["--set", "rules.missing-arg=error"])
+        .assert()
+        .code(1);
+
+    let output = check(dir.path())
+        .args(["--set", "checks.type_mismatch=off"])
+        .assert()
+        .code(2)
+        .get_output()
+        .stderr
+        .clone();
+    let stderr = String::from_utf8_lossy(&output);
+    assert!(
+        stderr.contains("--set checks.type_mismatch=off"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("config show --resolved"), "{stderr}");
+
+    // La configuración resuelta se puede volver a cargar con --config
+    let output = command(dir.path())
+        .args([
+            "config",
+            "show",
+            "--resolved",
+            "--config",
+            "ci/strict.yaml",
+            "--set",
+            "limits.max_sections=10",
+        ])
+        .assert()
+        .code(0)
+        .get_output()
+        .stdout
+        .clone();
+    let resolved = String::from_utf8_lossy(&output);
+    assert!(
+        resolved.contains("# Configuración: ci/strict.yaml (--config)"),
+        "{resolved}"
+    );
+    assert!(resolved.contains("  missing-arg: error\n"), "{resolved}");
+    assert!(
+        resolved.contains("  type-mismatch: warning\n"),
+        "{resolved}"
+    );
+    assert!(resolved.contains("  max_sections: 10\n"), "{resolved}");
+    std::fs::write(dir.path().join("ci/resolved.yaml"), resolved.as_bytes()).unwrap();
+    check(dir.path())
+        .args(["--config", "ci/resolved.yaml"])
+        .assert()
+        .code(1);
+}
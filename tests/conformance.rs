@@ -0,0 +1,40 @@
+//! `docsguard conformance` con el corpus embebido en el binario: la matriz
+//! sale completa y todos los escenarios pasan.
+
+use assert_cmd::cargo::cargo_bin_cmd;
+
+#[test]
+fn the_installed_binary_conforms_to_its_corpus() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = cargo_bin_cmd!("docsguard")
+        .current_dir(dir.path())
+        .args(["conformance", "--no-color"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(0), "{stdout}");
+    assert!(
+        stdout.contains("  block-comment               ok          ok     n/a     ok"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("Resumen: 36 de 36 casos conformes (2 no aplicables)"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn the_not_applicable_reasons_are_translated() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = cargo_bin_cmd!("docsguard")
+        .current_dir(dir.path())
+        .args(["--lang", "en", "conformance", "--no-color"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("[-] Go · annotation-above-attribute: Go has no attributes"),
+        "{stdout}"
+    );
+    assert!(!stdout.contains("no tiene"), "{stdout}");
+}
@@ -0,0 +1,37 @@
+//! `docsguard demo`: el proyecto de ejemplo y sus hallazgos.
+
+mod common;
+
+use common::command;
+
+#[test]
+fn the_demo_exits_0_with_its_findings_and_never_overwrites_the_sample() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = command(dir.path())
+        .args(["demo", "--dir", "sample"])
+        .assert()
+        .code(0)
+        .get_output()
+        .stdout
+        .clone();
+    let out = String::from_utf8(output).unwrap();
+    assert!(out.contains("[X] Error (ghost-arg) en fn search"), "{out}");
+    assert!(
+        out.contains("  ghost-arg en src/search.rs:3 (ver docs/api.md:25)\n"),
+        "{out}"
+    );
+    assert!(
+        out.contains("docsguard baseline src/search.rs docs/api.md"),
+        "{out}"
+    );
+
+    // El ejemplo generado es un proyecto normal para `check`
+    command(dir.path().join("sample"))
+        .args(["check", "docs/api.md", "src/auth.ts", "src/search.rs"])
+        .assert()
+        .code(1);
+    command(dir.path())
+        .args(["demo", "--dir", "sample"])
+        .assert()
+        .code(2);
+}
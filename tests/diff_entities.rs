@@ -0,0 +1,137 @@
+//! `docsguard diff-entities` sobre un repositorio git con dos commits.
+
+mod common;
+
+use common::{command, git};
+use std::path::Path;
+
+const CODE_V1: &str = "\
+/// @docs: [auth-login]
+export function login(username: string, remember: boolean) {}
+export function legacy() {}
+";
+
+const DOCS_V1: &str = "\
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Usuario |
+| remember | boolean | Recordar la sesión |
+
+<!-- @docs-id: auth-legacy -->
+## legacy
+";
+
+const CODE_V2: &str = "\
+/// @docs: [auth-signin]
+export function login(username: string, tenant: number) {}
+export function search(query: string) {}
+";
+
+const DOCS_V2: &str = "\
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | str | Usuario |
+| tenant | integer | Organización |
+
+<!-- @docs-id: search -->
+## search
+";
+
+/// Repositorio con `v1` y `v2` etiquetados; el árbol de trabajo queda en `v2`.
+fn two_commit_repo() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("docs")).unwrap();
+    std::fs::create_dir(dir.path().join("src")).unwrap();
+    git(dir.path(), &["init", "-q"]);
+    for (tag, code, docs) in [("v1", CODE_V1, DOCS_V1), ("v2", CODE_V2, DOCS_V2)] {
+        std::fs::write(dir.path().join("src/auth.ts"), code).unwrap();
+        std::fs::write(dir.path().join("docs/api.md"), docs).unwrap();
+        git(dir.path(), &["add", "."]);
+        git(dir.path(), &["commit", "-q", "-m", tag]);
+        git(dir.path(), &["tag", tag]);
+    }
+    dir
+}
+
+fn diff_entities(dir: &Path, args: &[&str]) -> String {
+    let output = command(dir)
+        .args(["diff-entities", "docs/api.md", "src/auth.ts"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn entities_and_sections_are_diffed_between_two_commits() {
+    let dir = two_commit_repo();
+    let json = diff_entities(
+        dir.path(),
+        &["--from", "v1", "--to", "v2", "--format", "json"],
+    );
+    let diff: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    let functions = &diff["functions"];
+    assert_eq!(functions["added"][0]["name"], "search");
+    assert_eq!(functions["removed"][0]["name"], "legacy");
+    let login = &functions["changed"][0];
+    assert_eq!(login["name"], "login");
+    assert_eq!(login["args"]["added"], serde_json::json!(["tenant"]));
+    assert_eq!(login["args"]["removed"], serde_json::json!(["remember"]));
+    assert!(login["args"].get("retyped").is_none(), "{login}");
+    assert_eq!(login["annotation"]["before"], "auth-login");
+    assert_eq!(login["annotation"]["after"], "auth-signin");
+
+    let sections = &diff["sections"];
+    assert_eq!(sections["added"][0]["name"], "search");
+    assert_eq!(sections["removed"][0]["name"], "auth-legacy");
+    // `string` → `str` es el mismo tipo normalizado
+    let section = &sections["changed"][0];
+    assert_eq!(section["id"], "auth-login");
+    assert_eq!(section["args"]["added"], serde_json::json!(["tenant"]));
+    assert!(section["args"].get("retyped").is_none(), "{section}");
+}
+
+#[test]
+fn the_working_tree_is_the_default_target_and_markdown_is_grouped() {
+    let dir = two_commit_repo();
+    let unchanged = diff_entities(dir.path(), &["--from", "v2", "--format", "md"]);
+    assert_eq!(
+        unchanged,
+        "## Impacto en la documentación (v2 → árbol de trabajo)\n\n\
+         ### Funciones\n\n_Sin cambios._\n\n### Secciones\n\n_Sin cambios._\n"
+    );
+
+    std::fs::write(
+        dir.path().join("src/auth.ts"),
+        CODE_V2.replace("tenant: number", "tenant: string"),
+    )
+    .unwrap();
+    let md = diff_entities(dir.path(), &["--from", "v2", "--format", "md"]);
+    assert!(
+        md.contains("- Cambiada: `fn login` (src/auth.ts:2) — arg `tenant`: `number` → `string`\n"),
+        "{md}"
+    );
+}
+
+#[test]
+fn an_unknown_reference_is_a_usage_error() {
+    let dir = two_commit_repo();
+    command(&dir)
+        .args([
+            "diff-entities",
+            "docs/api.md",
+            "src/auth.ts",
+            "--from",
+            "v9",
+        ])
+        .assert()
+        .code(2);
+}
@@ -0,0 +1,102 @@
+//! `--lang en` y `DOCSGUARD_LANG`: la salida en inglés, sin tocar el
+//! baseline ni los códigos de salida.
+
+mod common;
+
+use common::{auth_project, check, command, docsguard, LINKED, UNDOCUMENTED};
+
+#[test]
+fn english_output_leaves_the_baseline_and_the_exit_codes_alone() {
+    let dir = auth_project(UNDOCUMENTED);
+    let output = command(dir.path())
+        .args(["--lang", "en", "check", "docs/api.md", "src/auth.ts"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let out = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        out.contains("[X] Error (missing-doc-section) in fn logout (src/auth.ts:2)"),
+        "{out}"
+    );
+    assert!(
+        out.contains("Documentation ID 'auth-logout' not found in the docs file."),
+        "{out}"
+    );
+    assert!(
+        out.contains("Summary: 1 error, 1 warning, 2 total"),
+        "{out}"
+    );
+
+    // El baseline se escribe igual en los dos idiomas y filtra en ambos
+    command(dir.path())
+        .args(["baseline", "src/auth.ts", "docs/api.md"])
+        .env("DOCSGUARD_LANG", "en")
+        .assert()
+        .code(0);
+    let baseline = std::fs::read_to_string(dir.path().join(".docsguard/baseline.yaml")).unwrap();
+    assert!(baseline.contains("no encontrado"), "{baseline}");
+    check(dir.path()).assert().code(0);
+    let output = check(dir.path())
+        .env("DOCSGUARD_LANG", "en")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let out = String::from_utf8(output.stdout).unwrap();
+    assert!(out.contains("No new errors (baseline active)."), "{out}");
+
+    // La ayuda también
+    let help = command(dir.path())
+        .args(["--lang", "en", "check", "--help"])
+        .output()
+        .unwrap();
+    let help = String::from_utf8(help.stdout).unwrap();
+    assert!(help.contains("Checks that the links between code and documentation are valid"));
+    // Los valores de `--format` también, sin referencias al código
+    assert!(
+        help.contains("- json:     JSON report with the findings"),
+        "{help}"
+    );
+    assert!(!help.contains("(ver `"), "{help}");
+}
+
+#[test]
+fn english_list_and_parse_translate_their_headings_and_counts() {
+    let dir = auth_project(UNDOCUMENTED);
+    let run = |args: &[&str]| docsguard(dir.path(), args).1;
+    let list = run(&["--lang", "en", "list", "docs/api.md", "src/auth.ts"]);
+    assert!(list.contains("Functions (1)\n"), "{list}");
+    assert!(list.contains("Sections (1)\n"), "{list}");
+    let unresolved = run(&[
+        "--lang",
+        "en",
+        "list",
+        "--unlinked-only",
+        "docs/api.md",
+        "src/auth.ts",
+    ]);
+    assert!(
+        unresolved.contains("Unresolved functions (1)"),
+        "{unresolved}"
+    );
+    let parse = run(&["--lang", "en", "parse", "docs/api.md"]);
+    assert!(parse.contains("  1 section.\n"), "{parse}");
+}
+
+#[test]
+fn an_unknown_language_is_a_usage_error() {
+    let dir = auth_project(LINKED);
+    let output = check(dir.path())
+        .env("DOCSGUARD_LANG", "fr")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("Idioma no válido en DOCSGUARD_LANG: 'fr'"),
+        "{stderr}"
+    );
+    command(dir.path())
+        .args(["--lang", "fr", "check", "docs/api.md", "src/auth.ts"])
+        .assert()
+        .code(2);
+}
@@ -0,0 +1,210 @@
+//! Esquema de códigos de salida (`src/exit.rs`) visto desde fuera del binario.
+//!
+//! El código 4 (error interno) no tiene disparador desde la CLI; su
+//! clasificación se cubre en los tests unitarios de `exit`.
+
+mod common;
+
+use common::{auth_project, check, command, AUTH_DOCS, LINKED, UNDOCUMENTED};
+
+#[test]
+fn clean_runs_exit_0() {
+    let dir = auth_project(LINKED);
+    check(dir.path()).assert().code(0);
+    command(dir.path())
+        .args(["coverage", "src/auth.ts"])
+        .assert()
+        .code(0);
+    command(dir.path())
+        .args(["assert", "docs/api.md", "src/auth.ts", "--id", "auth-login"])
+        .assert()
+        .code(0);
+    command(dir.path())
+        .args(["baseline", "src/auth.ts", "docs/api.md"])
+        .assert()
+        .code(0);
+}
+
+#[test]
+fn findings_above_the_threshold_exit_1() {
+    let dir = auth_project(UNDOCUMENTED);
+    check(dir.path()).assert().code(1);
+    command(dir.path())
+        .args(["ci", "github", "docs/api.md", "src/auth.ts"])
+        .env_remove("GITHUB_STEP_SUMMARY")
+        .assert()
+        .code(1);
+    command(dir.path())
+        .args([
+            "assert",
+            "docs/api.md",
+            "src/auth.ts",
+            "--id",
+            "auth-logout",
+        ])
+        .assert()
+        .code(1);
+
+    let dir = auth_project("export function login(username: string) {}\n");
+    command(dir.path())
+        .args(["coverage", "src/auth.ts"])
+        .assert()
+        .code(1);
+}
+
+#[test]
+fn presets_move_the_threshold_and_config_still_wins() {
+    // `password` falta en las docs: Warning por defecto, Error con el preset strict
+    let dir = auth_project(
+        "/// @docs: [auth-login]\nexport function login(username: string, password: string) {}\n",
+    );
+    check(dir.path()).assert().code(0);
+    check(dir.path()).arg("--preset=strict").assert().code(1);
+    check(dir.path()).arg("--preset=lenient").assert().code(0);
+
+    command(dir.path())
+        .args(["init", "--preset", "strict"])
+        .assert()
+        .code(0);
+    let config = std::fs::read_to_string(dir.path().join(".docsguard/config.yaml")).unwrap();
+    assert!(config.contains("  missing-arg: error\n"), "{config}");
+    check(dir.path()).assert().code(1);
+    command(dir.path()).args(["init"]).assert().code(2);
+
+    std::fs::write(
+        dir.path().join(".docsguard/config.yaml"),
+        config.replace("missing-arg: error", "missing-arg: info"),
+    )
+    .unwrap();
+    check(dir.path()).arg("--preset=strict").assert().code(0);
+}
+
+#[test]
+fn fail_on_moves_the_severity_that_exits_1() {
+    // `password` falta en las docs: solo un Warning
+    let warning = auth_project(
+        "/// @docs: [auth-login]\nexport function login(username: string, password: string) {}\n",
+    );
+    let error = auth_project(UNDOCUMENTED);
+    for (dir, default, on_warning) in [(&warning, 0, 1), (&error, 1, 1)] {
+        let output = check(dir.path())
+            .assert()
+            .code(default)
+            .get_output()
+            .stdout
+            .clone();
+        let out = String::from_utf8(output).unwrap();
+        assert!(out.contains(" total · umbral: error\n"), "{out}");
+        check(dir.path())
+            .args(["--fail-on", "error"])
+            .assert()
+            .code(default);
+        let output = check(dir.path())
+            .args(["--fail-on", "warning"])
+            .assert()
+            .code(on_warning)
+            .get_output()
+            .stdout
+            .clone();
+        let out = String::from_utf8(output).unwrap();
+        assert!(out.contains(" total · umbral: warning\n"), "{out}");
+        check(dir.path())
+            .args(["--fail-on", "never"])
+            .assert()
+            .code(0);
+    }
+    check(warning.path())
+        .args(["--fail-on", "info"])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn usage_and_configuration_errors_exit_2() {
+    let dir = auth_project(LINKED);
+    command(dir.path())
+        .args(["check", "--no-such-flag"])
+        .assert()
+        .code(2);
+    command(dir.path())
+        .args(["coverage", "src/auth.ts", "--markdown"])
+        .assert()
+        .code(2);
+    command(dir.path())
+        .args(["assert", "docs/api.md", "src/auth.ts", "--id", "bad id"])
+        .assert()
+        .code(2);
+
+    std::fs::create_dir(dir.path().join(".docsguard")).unwrap();
+    std::fs::write(
+        dir.path().join(".docsguard/config.yaml"),
+        "doc_arg_sources: [",
+    )
+    .unwrap();
+    check(dir.path()).assert().code(2);
+
+    std::fs::write(dir.path().join(".docsguard/config.yaml"), "").unwrap();
+    std::fs::write(
+        dir.path().join(".docsguard/baseline.yaml"),
+        "version: \"99\"\nentries: []\n",
+    )
+    .unwrap();
+    check(dir.path()).assert().code(2);
+}
+
+#[test]
+fn missing_or_unparseable_files_exit_3() {
+    let dir = auth_project(LINKED);
+    command(dir.path())
+        .args(["check", "docs/api.md", "src/missing.ts"])
+        .assert()
+        .code(3);
+    command(dir.path())
+        .args(["coverage", "src/missing.ts"])
+        .assert()
+        .code(3);
+    command(dir.path())
+        .args(["coverage", "src/auth.ts", "--compare-to", "docs/api.md"])
+        .assert()
+        .code(3);
+    command(dir.path())
+        .args(["baseline", "src/auth.ts", "docs/missing.md"])
+        .assert()
+        .code(3);
+    command(dir.path())
+        .args(["scaffold", "src/missing.ts", "docs/api.md", "--force"])
+        .assert()
+        // El binario mínimo rechaza scaffold antes de mirar los archivos
+        .code(if cfg!(feature = "interactive") { 3 } else { 2 });
+
+    std::fs::write(dir.path().join("src/auth.rb"), "def login; end\n").unwrap();
+    command(dir.path())
+        .args(["check", "docs/api.md", "src/auth.rb"])
+        .assert()
+        .code(3);
+    command(dir.path())
+        .args(["coverage", "src/auth.rb"])
+        .assert()
+        .code(3);
+
+    std::fs::write(dir.path().join("docs/api.md"), [0xff, 0xfe, 0x00]).unwrap();
+    check(dir.path()).assert().code(3);
+    std::fs::write(dir.path().join("docs/api.md"), AUTH_DOCS).unwrap();
+    std::fs::write(dir.path().join("src/auth.ts"), [0xff, 0xfe, 0x00]).unwrap();
+    check(dir.path()).assert().code(3);
+}
+
+#[test]
+fn help_documents_the_exit_codes() {
+    let output = command(".")
+        .arg("--help")
+        .assert()
+        .code(0)
+        .get_output()
+        .stdout
+        .clone();
+    let help = String::from_utf8(output).unwrap();
+    assert!(help.contains("Códigos de salida:"));
+    assert!(help.contains("3  Archivo no encontrado, sin lenguaje soportado"));
+    assert!(help.contains("4  Error interno"));
+}
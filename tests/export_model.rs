@@ -0,0 +1,34 @@
+//! `docsguard export-model`: el modelo JSON que leen los generadores de docs.
+
+mod common;
+
+use common::{command, project};
+
+#[test]
+fn exported_model_links_sections_functions_and_args() {
+    let dir = project(&[
+        (
+            "auth.ts",
+            "/// @docs: [auth-login]\nexport function login(username: string, remember: boolean) {}\n",
+        ),
+        (
+            "api.md",
+            "<!-- @docs-id: auth-login -->\n## login\n\n\
+             | Param | Type | Description |\n|-------|------|-------------|\n\
+             | username | string | Usuario |\n",
+        ),
+    ]);
+    let output = command(&dir)
+        .args(["export-model", "api.md", "auth.ts", "--format", "json"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let model: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(model["schema_version"], 1);
+    let login = &model["sections"][0]["functions"][0];
+    assert_eq!(login["location"]["file"], "auth.ts");
+    assert_eq!(login["status"], "warning");
+    assert_eq!(login["args"][0]["status"], "documented");
+    assert_eq!(login["args"][1]["name"], "remember");
+    assert_eq!(login["args"][1]["status"], "missing");
+}
@@ -0,0 +1,55 @@
+//! `check --fast`: re-parsea solo el código que cambió y da los mismos
+//! hallazgos que una ejecución completa.
+
+mod common;
+
+use common::{auth_project, command, AUTH_DOCS, LINKED, UNDOCUMENTED};
+use std::path::Path;
+
+/// Salida de `check` sin las líneas (y huecos) que dependen de `--fast`, ordenada.
+fn findings(dir: &Path, fast: &[&str]) -> (Vec<String>, String) {
+    let mut args = vec!["check", "docs/api.md", "src/auth.ts", "src/session.ts"];
+    args.extend(fast);
+    let output = command(dir).args(&args).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines: Vec<String> = stdout
+        .lines()
+        .filter(|l| !l.is_empty() && !l.contains("[fast]") && !l.starts_with("Resumen"))
+        .map(String::from)
+        .collect();
+    lines.sort();
+    (lines, stdout)
+}
+
+#[test]
+fn fast_runs_reparse_only_changed_code_and_match_a_full_run() {
+    let dir = auth_project(LINKED);
+    std::fs::write(dir.path().join("src/session.ts"), UNDOCUMENTED).unwrap();
+
+    let (_, first) = findings(dir.path(), &["--fast"]);
+    assert!(first.contains("[fast] Ejecución completa: no hay registro"));
+    assert!(dir.path().join(".docsguard/last_run.json").exists());
+
+    let (cached, second) = findings(dir.path(), &["--fast"]);
+    assert!(second.contains("[fast] 0 de 2 archivos de código re-parseados"));
+    assert!(second.contains("Resumen (incremental): 1 error,"));
+    assert_eq!(cached, findings(dir.path(), &[]).0);
+
+    // La función de session.ts pasa a tener sección: sus hallazgos se recalculan
+    std::fs::write(
+        dir.path().join("src/session.ts"),
+        UNDOCUMENTED.replace("auth-logout", "auth-login"),
+    )
+    .unwrap();
+    let (spliced, third) = findings(dir.path(), &["--fast"]);
+    assert!(third.contains("[fast] 1 de 2 archivos de código re-parseados"));
+    assert_eq!(spliced, findings(dir.path(), &[]).0);
+
+    // El archivo de docs es de todos los pares
+    std::fs::write(dir.path().join("docs/api.md"), format!("{AUTH_DOCS}\n")).unwrap();
+    let (_, after_doc_change) = findings(dir.path(), &["--fast"]);
+    assert!(after_doc_change.contains("[fast] Ejecución completa: cambió el archivo de docs"));
+
+    let (_, no_fast) = findings(dir.path(), &["--fast", "--no-fast"]);
+    assert!(!no_fast.contains("[fast]"));
+}
@@ -0,0 +1,114 @@
+//! Combinaciones de features de cargo: el binario completo y el mínimo
+//! (`--no-default-features`) compilan sin avisos, y el mínimo rechaza con un
+//! error de uso los subcomandos que no trae (y el de por defecto,
+//! `--verify-site`).
+//!
+//! Las comprobaciones usan su propio directorio de compilación
+//! (`target/features`) para no esperar al bloqueo del `cargo test` en curso.
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use std::path::Path;
+use std::process::Command;
+
+/// `cargo check --all-targets` con los argumentos de features dados.
+fn cargo_check(features: &[&str]) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(env!("CARGO"))
+        .arg("check")
+        .arg("--all-targets")
+        .arg("--quiet")
+        .args(features)
+        .current_dir(root)
+        .env("CARGO_TARGET_DIR", root.join("target/features"))
+        .env("RUSTFLAGS", "-Dwarnings")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "cargo check {:?} falló:\n{}",
+        features,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn full_and_minimal_feature_sets_build_without_warnings() {
+    cargo_check(&[]);
+    cargo_check(&["--no-default-features"]);
+    cargo_check(&["--no-default-features", "--features", "watch"]);
+    cargo_check(&["--no-default-features", "--features", "interactive"]);
+    cargo_check(&["--features", "verify-site"]);
+}
+
+#[test]
+fn version_verbose_reports_what_was_compiled_in() {
+    for args in [&["version", "--verbose"][..], &["--version", "--verbose"]] {
+        let output = cargo_bin_cmd!("docsguard").args(args).output().unwrap();
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.starts_with(&format!("docsguard {}\n", env!("CARGO_PKG_VERSION"))));
+        if cfg!(all(
+            feature = "interactive",
+            feature = "watch",
+            not(feature = "verify-site")
+        )) {
+            assert!(
+                stdout.contains("features:  interactive, watch\n"),
+                "{stdout}"
+            );
+        }
+        if cfg!(all(
+            feature = "interactive",
+            feature = "watch",
+            feature = "verify-site"
+        )) {
+            assert!(
+                stdout.contains("features:  interactive, watch, verify-site\n"),
+                "{stdout}"
+            );
+        }
+        assert!(stdout.contains("  tree-sitter-rust "), "{stdout}");
+    }
+    // Sin `--verbose`, la línea de siempre
+    cargo_bin_cmd!("docsguard")
+        .arg("--version")
+        .assert()
+        .success()
+        .stdout(format!("docsguard {}\n", env!("CARGO_PKG_VERSION")));
+}
+
+#[cfg(not(feature = "watch"))]
+#[test]
+fn the_minimal_binary_rejects_excluded_subcommands() {
+    let output = cargo_bin_cmd!("docsguard")
+        .args(["watch", "src/a.ts", "docs/api.md"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("Este binario se compiló sin soporte de watch."),
+        "{stderr}"
+    );
+}
+
+/// El binario por defecto no trae acceso a la red: `--verify-site` es un
+/// error de uso antes de parsear nada.
+#[cfg(not(feature = "verify-site"))]
+#[test]
+fn the_default_binary_rejects_verify_site() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("api.md"), "# API\n").unwrap();
+    std::fs::write(dir.path().join("a.ts"), "export function a() {}\n").unwrap();
+    let output = cargo_bin_cmd!("docsguard")
+        .current_dir(dir.path())
+        .args(["check", "api.md", "a.ts", "--verify-site"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("Este binario se compiló sin soporte de verify-site."),
+        "{stderr}"
+    );
+}
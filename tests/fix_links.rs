@@ -0,0 +1,97 @@
+//! `docsguard fix`: enlaces de alta confianza aplicados sin preguntar, su
+//! vista previa con `--dry-run` y los conflictos que hacen fallar el comando.
+
+#![cfg(feature = "interactive")]
+
+mod common;
+
+use common::docsguard;
+
+const DOCS: &str = "\
+<!-- @docs-id: search -->
+## search
+
+<!-- @docs-id: get-user -->
+## Get user
+
+<!-- @docs-id: logout -->
+## logout
+";
+
+const CODE: &str = "\
+export function search(query: string) {}
+
+export function getUser() {}
+
+export function logout() {}
+";
+
+fn project(code: &str) -> tempfile::TempDir {
+    common::project(&[("api.ts", code), ("api.md", DOCS)])
+}
+
+#[test]
+fn high_confidence_links_are_written_without_prompting() {
+    let dir = project(CODE);
+    let (code, out, _) = docsguard(&dir, &["fix", "api.ts", "api.md", "--dry-run"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(
+        out.contains(
+            "--- a/api.ts\n+++ b/api.ts\n@@ -1,5 +1,7 @@\n\
+             +/// @docs: [search]\n export function search(query: string) {}\n \n\
+             \x20export function getUser() {}\n \n\
+             +/// @docs: [logout]\n export function logout() {}\n"
+        ),
+        "{out}"
+    );
+    assert!(out.contains("[dry-run] 2 enlaces en api.ts"), "{out}");
+    // `getUser` frente a `get-user` puntúa un 88%
+    assert!(!out.contains("[get-user]"), "{out}");
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join("api.ts")).unwrap(),
+        CODE
+    );
+
+    let (code, out, _) = docsguard(&dir, &["fix", "api.ts", "api.md"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(
+        out.contains("  + api.ts:1  /// @docs: [search]  (search · 100%)"),
+        "{out}"
+    );
+    assert!(out.contains("2 enlaces escritos en api.ts."), "{out}");
+    let (code, out, _) = docsguard(&dir, &["check", "api.md", "api.ts"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(out.contains("ID vinculado: 'search'"), "{out}");
+
+    // Ya enlazado: no queda nada que aplicar
+    let (code, out, _) = docsguard(&dir, &["fix", "api.ts", "api.md"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(out.contains("no hay nada que aplicar"), "{out}");
+}
+
+#[test]
+fn two_functions_for_one_section_are_left_unapplied() {
+    let code = "\
+export function logout() {}
+
+export function logOut() {}
+
+export function search(query: string) {}
+";
+    let dir = project(code);
+    let (status, out, _) = docsguard(&dir, &["fix", "api.ts", "api.md"]);
+    assert_eq!(status, Some(1), "{out}");
+    assert!(
+        out.contains("  ✗ api.ts:1  logout → 'logout': otra función apunta a la misma sección"),
+        "{out}"
+    );
+    assert!(out.contains("1 enlace escrito en api.ts."), "{out}");
+    assert!(
+        out.contains("2 enlaces sin aplicar: enlázalos con `docsguard scaffold`."),
+        "{out}"
+    );
+
+    let written = std::fs::read_to_string(dir.path().join("api.ts")).unwrap();
+    assert!(written.contains("/// @docs: [search]\nexport function search"));
+    assert!(!written.contains("[logout]"), "{written}");
+}
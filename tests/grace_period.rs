@@ -0,0 +1,91 @@
+//! `grace_period`: los Error de archivos recién commiteados bajan a Warning.
+
+mod common;
+
+use common::{command, git_at};
+use std::path::Path;
+
+const DOCS: &str = "\
+<!-- @docs-id: login -->
+## login
+";
+
+/// `old.ts`, de 2020, y `new.ts`, de hoy; cada uno enlaza una sección que
+/// no existe.
+fn project() -> tempfile::TempDir {
+    let dir = common::project(&[
+        (".docsguard/config.yaml", "grace_period: {days: 14}\n"),
+        ("api.md", DOCS),
+        (
+            "old.ts",
+            "/// @docs: [logout]\nexport function logout() {}\n",
+        ),
+    ]);
+    let root = dir.path();
+    git_at(root, "2020-01-01T00:00:00Z", &["init", "-q"]);
+    git_at(root, "2020-01-01T00:00:00Z", &["add", "."]);
+    git_at(root, "2020-01-01T00:00:00Z", &["commit", "-q", "-m", "old"]);
+    std::fs::write(
+        root.join("new.ts"),
+        "/// @docs: [search]\nexport function search() {}\n",
+    )
+    .unwrap();
+    let now = common::now();
+    git_at(root, &format!("@{now} +0000"), &["add", "."]);
+    git_at(
+        root,
+        &format!("@{now} +0000"),
+        &["commit", "-q", "-m", "new"],
+    );
+    dir
+}
+
+fn check(dir: &Path, args: &[&str]) -> (Option<i32>, String) {
+    let output = command(dir)
+        .args(["check", "api.md", "old.ts", "new.ts", "-v"])
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        output.status.code(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn only_errors_of_files_first_committed_recently_are_capped() {
+    let dir = project();
+    let (code, out) = check(dir.path(), &[]);
+    // `old.ts` sigue en Error y hace fallar
+    assert_eq!(code, Some(1), "{out}");
+    assert!(
+        out.contains("[X] Error (missing-doc-section) en fn logout (old.ts:2)"),
+        "{out}"
+    );
+    assert!(
+        out.contains("[!] Warning (missing-doc-section) en fn search (new.ts:2)"),
+        "{out}"
+    );
+    assert!(
+        out.contains("    -> Archivo nuevo — periodo de gracia hasta 20"),
+        "{out}"
+    );
+    assert!(
+        out.contains("    -> Severidad sin periodo de gracia: Error"),
+        "{out}"
+    );
+    assert_eq!(out.matches("periodo de gracia hasta").count(), 1, "{out}");
+
+    // Sin días de gracia, la severidad normal
+    let (_, out) = check(dir.path(), &["--set", "grace_period.days=0"]);
+    assert!(
+        out.contains("[X] Error (missing-doc-section) en fn search (new.ts:2)"),
+        "{out}"
+    );
+    assert!(!out.contains("periodo de gracia"), "{out}");
+
+    // Los niveles se resuelven antes: lo que `rules:` apaga no vuelve
+    let (code, out) = check(dir.path(), &["--set", "rules.missing-doc-section=off"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(!out.contains("missing-doc-section"), "{out}");
+}
@@ -0,0 +1,46 @@
+//! `--ignore-fn` e `ignore_functions`: funciones que no se validan ni se
+//! proponen, pero se cuentan.
+
+mod common;
+
+use common::{auth_project, check, LINKED};
+
+#[test]
+fn ignored_functions_are_neither_validated_nor_proposed_and_are_counted() {
+    let dir = auth_project(&format!(
+        "{LINKED}\nexport function test_login(user: string) {{}}\n"
+    ));
+    let run = |args: &[&str]| {
+        let mut cmd = check(dir.path());
+        cmd.args(args);
+        String::from_utf8(cmd.output().unwrap().stdout).unwrap()
+    };
+    let plain = run(&[]);
+    assert!(plain.contains("fn test_login"), "{plain}");
+    assert!(!plain.contains("ignorada"), "{plain}");
+
+    let ignored = run(&["--ignore-fn", "test_*"]);
+    assert!(!ignored.contains("fn test_login"), "{ignored}");
+    assert!(
+        ignored.contains("En código: 1 función (total), 1 función ignorada; en docs: 1 sección."),
+        "{ignored}"
+    );
+
+    // La lista de la configuración y la de la CLI se suman; --fast conserva la cuenta
+    std::fs::create_dir(dir.path().join(".docsguard")).unwrap();
+    std::fs::write(
+        dir.path().join(".docsguard/config.yaml"),
+        "ignore_functions: ['test_*']\n",
+    )
+    .unwrap();
+    assert!(run(&["--fast"]).contains("1 función ignorada"));
+    let cached = run(&["--fast"]);
+    assert!(cached.contains("[fast] 0 de 1"), "{cached}");
+    assert!(cached.contains("1 función ignorada"), "{cached}");
+    let more = run(&["--fast", "--ignore-fn", "login"]);
+    assert!(
+        more.contains("Ejecución completa: cambió la configuración"),
+        "{more}"
+    );
+    assert!(more.contains("2 funciones ignoradas"), "{more}");
+}
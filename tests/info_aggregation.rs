@@ -0,0 +1,143 @@
+//! `info_aggregation: summarize`: los Info de una regla en un archivo se
+//! agrupan en los informes sin cambiar los totales ni el código de salida.
+
+mod common;
+
+use common::{auth_project, command, docsguard, LINKED, UNDOCUMENTED};
+use serde_json::Value;
+
+const DOCS: &str = "\
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Usuario |
+";
+
+const CODE: &str = "\
+/// @docs: [auth-login]
+export function login(username: string) {}
+export function helper1(value: string) {}
+export function helper2(value: string) {}
+export function helper3(value: string) {}
+export function helper4(value: string) {}
+";
+
+fn project() -> tempfile::TempDir {
+    common::project(&[
+        ("docs/api.md", DOCS),
+        ("src/auth.ts", CODE),
+        (".docsguard/config.yaml", "info_aggregation: summarize\n"),
+    ])
+}
+
+/// Resultados del log SARIF de `check` con `extra`.
+fn sarif_results(dir: &tempfile::TempDir, extra: &[&str]) -> Vec<Value> {
+    let mut args = vec!["check", "docs/api.md", "src/auth.ts", "--format", "sarif"];
+    args.extend(extra);
+    let (code, out, err) = docsguard(dir, &args);
+    assert_eq!(code, Some(0), "{out}{err}");
+    let log: Value = serde_json::from_str(&out).unwrap();
+    log["runs"][0]["results"].as_array().unwrap().clone()
+}
+
+#[test]
+fn check_sarif_groups_the_info_findings_of_a_file() {
+    let dir = project();
+    let individual = sarif_results(&dir, &["--no-aggregate"]);
+    let unlinked = |results: &[Value]| {
+        results
+            .iter()
+            .filter(|r| r["ruleId"] == "unlinked-function")
+            .count()
+    };
+    assert_eq!(unlinked(&individual), 4);
+
+    let summarized = sarif_results(&dir, &[]);
+    assert_eq!(unlinked(&summarized), 1);
+    assert_eq!(summarized.len(), individual.len() - 3);
+    let message = summarized
+        .iter()
+        .find(|r| r["ruleId"] == "unlinked-function")
+        .unwrap()["message"]["text"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert!(
+        message.contains("4 hallazgos de `unlinked-function` agrupados en src/auth.ts"),
+        "{message}"
+    );
+}
+
+#[test]
+fn check_json_keeps_the_totals_of_the_grouped_findings() {
+    let dir = project();
+    let (code, out, err) = docsguard(
+        &dir,
+        &["check", "docs/api.md", "src/auth.ts", "--format", "json"],
+    );
+    assert_eq!(code, Some(0), "{out}{err}");
+    let report: Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(report["summary"]["infos"], 5, "{out}");
+    let grouped = report["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|f| f["rule"] == "unlinked-function")
+        .count();
+    assert_eq!(grouped, 1, "{out}");
+}
+
+#[test]
+fn info_aggregation_shrinks_reports_without_changing_counts_or_exit_codes() {
+    let mut code = format!("{}{}", LINKED, UNDOCUMENTED);
+    for i in 0..400 {
+        code.push_str(&format!(
+            "export function helper{}(value: string) {{}}\n",
+            i
+        ));
+    }
+    let dir = auth_project(&code);
+    std::fs::create_dir(dir.path().join(".docsguard")).unwrap();
+    std::fs::write(
+        dir.path().join(".docsguard/config.yaml"),
+        "info_aggregation: summarize\n",
+    )
+    .unwrap();
+
+    let html = |extra: &[&str]| {
+        let mut args = vec!["report", "docs/api.md", "src/auth.ts", "--html", "out.html"];
+        args.extend(extra);
+        command(dir.path()).args(&args).assert().code(0);
+        std::fs::read_to_string(dir.path().join("out.html")).unwrap()
+    };
+    let totals = |html: &str| {
+        html.lines()
+            .find(|l| l.starts_with("<p class=\"totals\">"))
+            .unwrap()
+            .to_string()
+    };
+    let summarized = html(&[]);
+    let individual = html(&["--no-aggregate"]);
+    assert!(
+        summarized.len() * 4 < individual.len(),
+        "{} vs {}",
+        summarized.len(),
+        individual.len()
+    );
+    assert_eq!(totals(&summarized), totals(&individual));
+    assert!(totals(&summarized).contains("1 error · 0 advertencias · 401 info"));
+    assert!(summarized.contains("400 hallazgos de `unlinked-function` agrupados en src/auth.ts"));
+
+    for extra in [&[][..], &["--no-aggregate"][..]] {
+        let mut args = vec!["ci", "github", "docs/api.md", "src/auth.ts"];
+        args.extend(extra);
+        command(dir.path())
+            .args(&args)
+            .env_remove("GITHUB_ACTIONS")
+            .env_remove("GITHUB_STEP_SUMMARY")
+            .assert()
+            .code(1);
+    }
+}
@@ -0,0 +1,157 @@
+//! Entradas de `check`: directorios y globs se expanden en el código y en
+//! la documentación, y lo descubierto que no se puede leer se omite con un
+//! hallazgo `skipped-file`.
+
+mod common;
+
+use common::{auth_project, command, docsguard, LINKED, UNDOCUMENTED};
+use serde_json::Value;
+
+const DOCS: &str = "\
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Usuario |
+";
+
+const SESSION: &str = "\
+<!-- @docs-id: auth-logout -->
+## logout
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Usuario |
+";
+
+const CODE: &str = "\
+/// @docs: [auth-login]
+export function login(username: string) {}
+
+/// @docs: [auth-logout]
+export function logout(username: string) {}
+";
+
+fn project() -> tempfile::TempDir {
+    common::project(&[
+        ("docs/api.md", DOCS),
+        ("docs/guides/session.md", SESSION),
+        ("docs/notes.txt", "borrador"),
+        ("src/auth.ts", CODE),
+        ("src/notes.txt", ""),
+    ])
+}
+
+#[test]
+fn doc_directories_and_globs_are_expanded_and_non_markdown_skipped() {
+    let dir = project();
+    let (code, stdout, _) = docsguard(dir.path(), &["check", "docs", "src"]);
+    assert_eq!(code, Some(0), "{stdout}");
+    assert!(
+        stdout.contains(
+            "Docs: docs/api.md, docs/guides/session.md (1 archivo omitido: sin extensión Markdown)"
+        ),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("Se omite docs/notes.txt: no es Markdown."),
+        "{stdout}"
+    );
+
+    // Con --doc, igual; los posicionales son el código
+    let (code, stdout, _) = docsguard(dir.path(), &["check", "--doc", "docs/**/*.md", "src"]);
+    assert_eq!(code, Some(0), "{stdout}");
+    assert!(
+        stdout.contains("Docs: docs/api.md, docs/guides/session.md\n"),
+        "{stdout}"
+    );
+
+    // Sin ningún Markdown no hay nada que verificar
+    let (code, _, stderr) = docsguard(dir.path(), &["check", "src", "src"]);
+    assert_eq!(code, Some(3));
+    assert!(
+        stderr.contains("No hay documentación Markdown que verificar."),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn skipped_files_are_info_findings_in_json_and_sarif() {
+    let dir = project();
+    let (code, stdout, _) = docsguard(dir.path(), &["check", "docs", "src", "--format", "json"]);
+    assert_eq!(code, Some(0), "{stdout}");
+    let report: Value = serde_json::from_str(&stdout).unwrap();
+    let skipped: Vec<(&str, &str)> = report["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|f| f["rule"] == "skipped-file")
+        .map(|f| {
+            let location = f["code_location"].as_str().or(f["doc_location"].as_str());
+            (f["severity"].as_str().unwrap(), location.unwrap())
+        })
+        .collect();
+    assert_eq!(
+        skipped,
+        [("Info", "src/notes.txt"), ("Info", "docs/notes.txt")]
+    );
+
+    let (code, stdout, _) = docsguard(dir.path(), &["check", "docs", "src", "--format", "sarif"]);
+    assert_eq!(code, Some(0), "{stdout}");
+    let log: Value = serde_json::from_str(&stdout).unwrap();
+    let mut skipped: Vec<&str> = log["runs"][0]["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|r| r["ruleId"] == "skipped-file")
+        .map(|r| {
+            assert_eq!(r["level"], "note");
+            r["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]
+                .as_str()
+                .unwrap()
+        })
+        .collect();
+    skipped.sort_unstable();
+    assert_eq!(skipped, ["docs/notes.txt", "src/notes.txt"]);
+}
+
+#[test]
+fn directories_and_globs_are_expanded_and_unsupported_files_skipped() {
+    let dir = auth_project(LINKED);
+    std::fs::create_dir(dir.path().join("src/auth")).unwrap();
+    std::fs::write(dir.path().join("src/auth/logout.ts"), UNDOCUMENTED).unwrap();
+    std::fs::write(dir.path().join("src/notes.txt"), "").unwrap();
+
+    let output = command(dir.path())
+        .args(["check", "docs/api.md", "src"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("Código: 2 archivos (1 archivo omitido: sin lenguaje soportado)"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("Se omite src/notes.txt: extensión '.txt' no soportada."));
+    assert!(stdout.contains("src/auth/logout.ts:2"), "{stdout}");
+
+    let output = command(dir.path())
+        .args(["check", "docs/api.md", "src/*.ts"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .contains("Código: 1 archivo\n    -> src/auth.ts\n"));
+
+    // Nombrado a mano, un archivo sin lenguaje soportado sigue fallando
+    command(dir.path())
+        .args(["check", "docs/api.md", "src/notes.txt"])
+        .assert()
+        .code(3);
+    command(dir.path())
+        .args(["check", "docs/api.md", "lib/**/*.ts"])
+        .assert()
+        .code(3);
+}
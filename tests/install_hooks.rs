@@ -0,0 +1,111 @@
+//! `docsguard install-hooks`: el bloque de DocsGuard en `.git/hooks/pre-commit`.
+
+mod common;
+
+use common::{docsguard, git, project};
+
+fn repository(config: &str) -> tempfile::TempDir {
+    let dir = project(&[(".docsguard/config.yaml", config)]);
+    git(dir.path(), &["init", "-q"]);
+    dir
+}
+
+const COMBOS: &str = "\
+combos:
+  api: {doc_file: docs/api.md, code: [src/api]}
+";
+
+#[test]
+fn installing_twice_leaves_one_executable_block_and_uninstall_removes_it() {
+    let dir = repository(COMBOS);
+    let root = dir.path();
+    let hook = root.join(".git/hooks/pre-commit");
+
+    let (code, out, err) = docsguard(root, &["install-hooks"]);
+    assert_eq!(code, Some(0), "{out}{err}");
+    assert!(out.contains("Instalado el hook pre-commit en "), "{out}");
+    assert!(
+        out.contains("  docsguard check --combo api --changed-only\n"),
+        "{out}"
+    );
+    let installed = std::fs::read_to_string(&hook).unwrap();
+    assert_eq!(
+        installed,
+        "\
+#!/bin/sh
+# >>> docsguard >>>
+# Gestionado por `docsguard install-hooks`; `--uninstall` quita este bloque.
+changed=--changed-only; git rev-parse --verify --quiet HEAD >/dev/null || changed=
+docsguard check --combo api $changed || exit 1
+# <<< docsguard <<<
+"
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&hook).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111, "{mode:o}");
+    }
+
+    let (code, out, _) = docsguard(root, &["install-hooks"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(out.contains("ya está al día."), "{out}");
+    assert_eq!(std::fs::read_to_string(&hook).unwrap(), installed);
+
+    let (code, out, _) = docsguard(root, &["install-hooks", "--uninstall"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(out.contains("solo tenía el bloque de DocsGuard."), "{out}");
+    assert!(!hook.exists());
+}
+
+#[test]
+fn a_foreign_hook_is_kept_unless_forced_and_uninstall_leaves_it_as_it_was() {
+    let dir = repository("");
+    let root = dir.path();
+    let hook = root.join(".git/hooks/pre-commit");
+    let foreign = "#!/bin/sh\nnpm run lint || exit 1\n";
+    std::fs::create_dir_all(hook.parent().unwrap()).unwrap();
+    std::fs::write(&hook, foreign).unwrap();
+
+    let (code, out, err) = docsguard(root, &["install-hooks"]);
+    assert_eq!(code, Some(2), "{out}{err}");
+    assert!(err.contains("ya existe y no lo creó DocsGuard."), "{err}");
+    assert!(err.contains("--force"), "{err}");
+    assert_eq!(std::fs::read_to_string(&hook).unwrap(), foreign);
+
+    let (code, out, _) = docsguard(root, &["install-hooks", "--force"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(out.contains("al final del hook existente"), "{out}");
+    let hooked = std::fs::read_to_string(&hook).unwrap();
+    assert!(hooked.starts_with(foreign), "{hooked}");
+    // Sin combinaciones, todo el repositorio
+    assert!(
+        hooked.contains("docsguard check-all $changed || exit 1\n"),
+        "{hooked}"
+    );
+
+    // Con el bloque ya puesto, repetir no necesita --force
+    let (code, out, _) = docsguard(root, &["install-hooks"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert_eq!(std::fs::read_to_string(&hook).unwrap(), hooked);
+
+    let (code, out, _) = docsguard(root, &["install-hooks", "--uninstall"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(out.contains("Quitado el bloque de DocsGuard de "), "{out}");
+    assert_eq!(std::fs::read_to_string(&hook).unwrap(), foreign);
+
+    let (code, out, _) = docsguard(root, &["install-hooks", "--uninstall"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(out.contains("nada que quitar."), "{out}");
+}
+
+#[test]
+fn outside_a_git_repository_it_is_a_usage_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let (code, out, err) = docsguard(dir.path(), &["install-hooks"]);
+    assert_eq!(code, Some(2), "{out}{err}");
+    assert!(
+        err.contains("install-hooks necesita un repositorio git y . no está en ninguno."),
+        "{err}"
+    );
+}
@@ -0,0 +1,77 @@
+//! `--json-errors`: los errores de ejecución como JSON en stdout, con su
+//! tipo, ruta y sugerencia y el código de salida de siempre.
+
+mod common;
+
+use assert_cmd::Command;
+use common::{auth_project, check, command, LINKED};
+
+/// Error de `--json-errors`: la última línea de stdout, sin nada en stderr.
+fn json_error(cmd: &mut Command, code: i32) -> serde_json::Value {
+    let output = cmd.assert().code(code).get_output().clone();
+    assert!(output.stderr.is_empty(), "{:?}", output.stderr);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    serde_json::from_str(stdout.lines().last().unwrap()).unwrap()
+}
+
+#[test]
+fn json_errors_carry_a_kind_path_and_hint_with_the_usual_code() {
+    let dir = auth_project(LINKED);
+
+    let missing = json_error(
+        command(dir.path()).args(["check", "docs/api.md", "src/missing.ts", "--json-errors"]),
+        3,
+    );
+    assert_eq!(missing["error"]["kind"], "file-not-found");
+    assert_eq!(missing["error"]["path"], "src/missing.ts");
+    assert_eq!(
+        missing["error"]["hint"],
+        "Verifica que la ruta sea correcta."
+    );
+
+    std::fs::write(dir.path().join("src/auth.rb"), "def login; end\n").unwrap();
+    let language = json_error(
+        command(dir.path()).args(["--json-errors", "check", "docs/api.md", "src/auth.rb"]),
+        3,
+    );
+    assert_eq!(language["error"]["kind"], "unsupported-language");
+    assert!(language["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("'.rb'"));
+
+    std::fs::create_dir(dir.path().join(".docsguard")).unwrap();
+    std::fs::write(dir.path().join(".docsguard/config.yaml"), ": : :\n").unwrap();
+    let config = json_error(check(dir.path()).arg("--json-errors"), 2);
+    assert_eq!(config["error"]["kind"], "config-invalid");
+    assert!(config["error"]["path"]
+        .as_str()
+        .unwrap()
+        .ends_with("config.yaml"));
+    assert!(config["error"].get("hint").is_none());
+    std::fs::remove_file(dir.path().join(".docsguard/config.yaml")).unwrap();
+
+    std::fs::write(dir.path().join(".docsguard/baseline.yaml"), "version: [\n").unwrap();
+    let baseline = json_error(check(dir.path()).arg("--json-errors"), 2);
+    assert_eq!(baseline["error"]["kind"], "baseline-invalid");
+
+    // `assert --json` sigue siendo JSON cuando falla antes de evaluar los IDs
+    let assert = json_error(
+        command(dir.path()).args([
+            "assert",
+            "docs/api.md",
+            "src/missing.ts",
+            "--id",
+            "auth-login",
+            "--json",
+        ]),
+        3,
+    );
+    assert_eq!(assert["error"]["kind"], "file-not-found");
+
+    let usage = json_error(
+        command(dir.path()).args(["check", "--bogus", "--json-errors"]),
+        2,
+    );
+    assert_eq!(usage["error"]["kind"], "usage");
+}
@@ -0,0 +1,203 @@
+//! `check --format json`: la cobertura por argumento de cada enlace, que el
+//! baseline no toca.
+
+mod common;
+
+use common::docsguard;
+use serde_json::Value;
+
+fn project() -> tempfile::TempDir {
+    common::project(&[
+        (
+            "src/lib.rs",
+            "/// @docs: [login]\npub fn login(user: String, device: String) {}\n",
+        ),
+        (
+            "docs/api.md",
+            "<!-- @docs-id: login -->\n## login\n\n| Param | Type | Description |\n|-------|------|-------------|\n| user | string | Usuario |\n| token | string | Sobra |\n",
+        ),
+    ])
+}
+
+fn statuses(report: &Value) -> Vec<(String, String)> {
+    report["links"][0]["args"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|arg| {
+            (
+                arg["name"].as_str().unwrap().to_string(),
+                arg["status"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+const CHECK: &[&str] = &["check", "docs/api.md", "src/lib.rs", "--format", "json"];
+
+#[test]
+fn the_report_has_the_findings_and_the_coverage_of_each_link() {
+    let dir = project();
+    let (code, out, err) = docsguard(&dir, CHECK);
+    assert_eq!(code, Some(1), "{out}{err}");
+    // stdout es solo el JSON; el progreso va a stderr
+    let report: Value = serde_json::from_str(&out).unwrap();
+    assert!(err.contains("Resumen: 1 error, 1 advertencia"), "{err}");
+    assert_eq!(report["schema_version"], 1);
+    assert_eq!(report["summary"]["errors"], 1);
+    assert_eq!(report["links"][0]["function"], "login");
+    assert_eq!(report["links"][0]["doc_id"], "login");
+    assert_eq!(
+        statuses(&report),
+        [
+            ("user".into(), "documented".into()),
+            ("device".into(), "missing".into()),
+            ("token".into(), "ghost".into()),
+        ]
+    );
+    assert_eq!(
+        report["links"][0]["args"][2]["doc_location"],
+        serde_json::json!({"file": "docs/api.md", "line": 7})
+    );
+}
+
+#[test]
+fn a_baseline_that_hides_every_finding_keeps_the_links() {
+    let dir = project();
+    let (code, out, err) = docsguard(&dir, &["baseline", "src/lib.rs", "docs/api.md"]);
+    assert_eq!(code, Some(0), "{out}{err}");
+
+    let (code, out, err) = docsguard(&dir, CHECK);
+    assert_eq!(code, Some(0), "{out}{err}");
+    let report: Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(report["summary"]["errors"], 0);
+    assert_eq!(report["summary"]["baseline_filtered"], 2);
+    assert!(report["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|f| f["severity"] == "Info"));
+    assert_eq!(statuses(&report).len(), 3);
+    assert_eq!(statuses(&report)[2].1, "ghost");
+}
+
+#[test]
+fn redaction_patterns_reach_findings_and_links() {
+    let dir = project();
+    std::fs::create_dir(dir.path().join(".docsguard")).unwrap();
+    std::fs::write(
+        dir.path().join(".docsguard/config.yaml"),
+        "redaction:\n  patterns: [login]\n",
+    )
+    .unwrap();
+    let (code, out, err) = docsguard(&dir, CHECK);
+    assert_eq!(code, Some(1), "{out}{err}");
+    let report: Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(report["links"][0]["function"], "█");
+    assert_eq!(report["links"][0]["doc_id"], "█");
+    assert_eq!(statuses(&report)[2], ("token".into(), "ghost".into()));
+    assert!(!out.to_lowercase().contains("login"), "{out}");
+}
+
+#[test]
+fn explain_baseline_adds_the_nearest_entry_of_each_unfiltered_finding() {
+    let dir = project();
+    let (code, out, err) = docsguard(&dir, &["baseline", "src/lib.rs", "docs/api.md"]);
+    assert_eq!(code, Some(0), "{out}{err}");
+    // Sin la opción la clave no aparece
+    let (_, out, _) = docsguard(&dir, CHECK);
+    let report: Value = serde_json::from_str(&out).unwrap();
+    assert!(report.get("baseline_debug").is_none(), "{out}");
+
+    // Renombrar la función deja sus hallazgos fuera del baseline
+    std::fs::write(
+        dir.path().join("src/lib.rs"),
+        "/// @docs: [login]\npub fn sign_in(user: String, device: String) {}\n",
+    )
+    .unwrap();
+    let mut args = CHECK.to_vec();
+    args.push("--explain-baseline");
+    let (code, out, err) = docsguard(&dir, &args);
+    assert_eq!(code, Some(1), "{out}{err}");
+    let report: Value = serde_json::from_str(&out).unwrap();
+    let debug = report["baseline_debug"].as_array().unwrap();
+    assert_eq!(debug.len(), 2, "{out}");
+    let missing = debug.iter().find(|m| m["rule"] == "missing-arg").unwrap();
+    assert_eq!(missing["location"], "src/lib.rs:2");
+    assert_eq!(missing["computed"]["function_name"], "sign_in");
+    assert_eq!(missing["nearest"]["index"], 2);
+    assert_eq!(
+        missing["nearest"]["location"],
+        ".docsguard/baseline.yaml:11"
+    );
+    assert_eq!(missing["nearest"]["entry"]["function_name"], "login");
+    assert_eq!(
+        missing["nearest"]["differing"],
+        serde_json::json!(["function_name"])
+    );
+
+    // La redacción también alcanza a las dos entradas
+    std::fs::write(
+        dir.path().join(".docsguard/config.yaml"),
+        "redaction:\n  patterns: [sign_in, login]\n",
+    )
+    .unwrap();
+    let (_, out, _) = docsguard(&dir, &args);
+    let report: Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(
+        report["baseline_debug"][0]["computed"]["function_name"],
+        "█"
+    );
+    assert!(!out.contains("sign_in") && !out.contains("login"), "{out}");
+}
+
+#[test]
+fn debt_age_adds_the_age_of_the_baseline() {
+    let dir = project();
+    let (code, out, err) = docsguard(&dir, &["baseline", "src/lib.rs", "docs/api.md"]);
+    assert_eq!(code, Some(0), "{out}{err}");
+    // La primera entrada, con años; la segunda, de hoy
+    let path = dir.path().join(".docsguard/baseline.yaml");
+    let yaml = std::fs::read_to_string(&path).unwrap();
+    let today = yaml
+        .lines()
+        .find_map(|l| l.trim().strip_prefix("created_at: "))
+        .unwrap()
+        .to_string();
+    std::fs::write(&path, yaml.replacen(&today, "'2020-01-01'", 1)).unwrap();
+
+    let (_, out, _) = docsguard(&dir, CHECK);
+    let report: Value = serde_json::from_str(&out).unwrap();
+    assert!(report.get("debt_age").is_none(), "{out}");
+
+    let mut args = CHECK.to_vec();
+    args.push("--debt-age=10");
+    let (code, out, err) = docsguard(&dir, &args);
+    assert_eq!(code, Some(0), "{out}{err}");
+    let debt = &serde_json::from_str::<Value>(&out).unwrap()["debt_age"];
+    assert_eq!(debt["size"], 2, "{out}");
+    assert_eq!(debt["undated"], 0);
+    assert_eq!(debt["window_days"], 10);
+    assert!(debt["max_days"].as_i64().unwrap() > 2000, "{out}");
+    assert_eq!(debt["oldest"][0]["index"], 1);
+    assert_eq!(debt["oldest"][0]["entry"]["created_at"], "2020-01-01");
+    assert_eq!(debt["oldest"][1]["days"], 0);
+    assert_eq!(debt["expiring"], serde_json::json!([]));
+}
+
+#[test]
+fn a_missing_file_is_reported_as_a_json_error() {
+    let dir = project();
+    for format in ["json", "sarif"] {
+        let (code, out, err) = docsguard(
+            &dir,
+            &["check", "docs/api.md", "src/missing.rs", "--format", format],
+        );
+        assert_eq!(code, Some(3), "{format}: {out}{err}");
+        // Sin `--json-errors`: el formato ya lo implica y stderr queda sin la cadena de error
+        let error: Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(error["error"]["kind"], "file-not-found", "{out}");
+        assert_eq!(error["error"]["path"], "src/missing.rs", "{out}");
+        assert!(!err.contains("Error:"), "{err}");
+    }
+}
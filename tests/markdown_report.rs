@@ -0,0 +1,87 @@
+//! `check --report FILE` y `--format markdown`: el documento Markdown de los
+//! hallazgos, para pegarlo en una PR o en Slack.
+
+mod common;
+
+use common::docsguard;
+
+fn project() -> tempfile::TempDir {
+    common::project(&[
+        (
+            "src/lib.rs",
+            "/// @docs: [login]\npub fn login(user: String, device: String) {}\n\n/// @docs: [logout]\npub fn logout() {}\n",
+        ),
+        (
+            "docs/api.md",
+            "<!-- @docs-id: login -->\n## login\n\n| Param | Type | Description |\n|-------|------|-------------|\n| user | string | Usuario |\n| token | string | Sobra |\n",
+        ),
+    ])
+}
+
+#[test]
+fn the_report_is_written_and_the_console_stays_quiet_without_verbose() {
+    let dir = project();
+    let (code, out, err) = docsguard(
+        &dir,
+        &[
+            "check",
+            "docs/api.md",
+            "src/lib.rs",
+            "--report",
+            "out/check.md",
+        ],
+    );
+    assert_eq!(code, Some(1), "{out}{err}");
+    assert_eq!(out, "");
+    assert_eq!(err, "");
+
+    let report = std::fs::read_to_string(dir.path().join("out/check.md")).unwrap();
+    assert!(
+        report.starts_with("# DocsGuard — informe de verificación\n"),
+        "{report}"
+    );
+    assert!(
+        report.contains("| 2 | 1 | 1 | 0 | 1 de 1 (100.0%) |\n"),
+        "{report}"
+    );
+    assert!(report.contains("## ❌ Errores (2)"), "{report}");
+    assert!(report.contains("## ⚠️ Advertencias (1)"), "{report}");
+    assert!(
+        report.contains("| `logout` | `src/lib.rs:5` | `logout` | "),
+        "{report}"
+    );
+    // La plantilla de la sugerencia queda como código
+    assert!(report.contains("`<!-- @docs-id: logout -->`"), "{report}");
+    assert!(!dir.path().join("out/check.md.tmp.docsguardwrite").exists());
+
+    // Con -v, la salida de siempre además del archivo
+    let (code, out, _) = docsguard(
+        &dir,
+        &[
+            "check",
+            "docs/api.md",
+            "src/lib.rs",
+            "--report",
+            "out/check.md",
+            "-v",
+        ],
+    );
+    assert_eq!(code, Some(1));
+    assert!(out.contains("Resumen: 2 errores, 1 advertencia"), "{out}");
+}
+
+#[test]
+fn format_markdown_prints_the_document_on_stdout() {
+    let dir = project();
+    let (code, out, err) = docsguard(
+        &dir,
+        &["check", "docs/api.md", "src/lib.rs", "--format", "markdown"],
+    );
+    assert_eq!(code, Some(1), "{out}{err}");
+    assert!(
+        out.starts_with("# DocsGuard — informe de verificación\n"),
+        "{out}"
+    );
+    assert!(!out.contains("Resumen:"), "{out}");
+    assert!(err.contains("Resumen: 2 errores, 1 advertencia"), "{err}");
+}
@@ -0,0 +1,99 @@
+//! `check --doc`: varios archivos de docs en una ejecución, con los IDs
+//! repetidos entre archivos como Error.
+
+mod common;
+
+use common::docsguard;
+
+const AUTH: &str = "\
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Usuario |
+";
+
+const USERS: &str = "\
+<!-- @docs-id: users-get -->
+## getUser
+
+| Param | Type | Description |
+|-------|------|-------------|
+| id | string | Usuario |
+";
+
+const CODE: &str = "\
+/// @docs: [auth-login]
+export function login(username: string) {}
+
+/// @docs: [users-get]
+export function getUser(id: string) {}
+";
+
+fn project(users: &str) -> tempfile::TempDir {
+    common::project(&[
+        ("docs/auth.md", AUTH),
+        ("docs/users.md", users),
+        ("api.ts", CODE),
+    ])
+}
+
+#[test]
+fn sections_of_every_doc_file_are_checked_together() {
+    let dir = project(USERS);
+    // Repetido o separado por comas, y todos los posicionales son código
+    for args in [
+        &[
+            "check",
+            "--doc",
+            "docs/auth.md,docs/users.md",
+            "api.ts",
+            "-v",
+        ][..],
+        &[
+            "check",
+            "api.ts",
+            "--doc",
+            "docs/auth.md",
+            "--doc",
+            "docs/users.md",
+            "-v",
+        ],
+    ] {
+        let (code, out, _) = docsguard(&dir, args);
+        assert_eq!(code, Some(0), "{out}");
+        assert!(out.contains("Docs: docs/auth.md, docs/users.md"), "{out}");
+        assert!(
+            out.contains("ID vinculado: 'users-get' (docs/users.md:1)"),
+            "{out}"
+        );
+        assert!(out.contains("0 errores, 0 advertencias"), "{out}");
+    }
+
+    let (code, _, err) = docsguard(&dir, &["check", "--doc", "docs/auth.md"]);
+    assert_eq!(code, Some(2), "{err}");
+    assert!(err.contains("Falta el código que verificar."), "{err}");
+}
+
+#[test]
+fn an_id_in_two_doc_files_is_an_error_naming_both() {
+    let users = format!("{USERS}\n<!-- @docs-id: auth-login -->\n## login\n\n<!-- @docs-id: users-list -->\n## listUsers\n");
+    let dir = project(&users);
+    let (code, out, _) = docsguard(
+        &dir,
+        &["check", "--doc", "docs/auth.md,docs/users.md", "api.ts"],
+    );
+    assert_eq!(code, Some(1), "{out}");
+    assert!(
+        out.contains(
+            "ID de documentación 'auth-login' definido en dos archivos: \
+             docs/auth.md:1 y docs/users.md:8."
+        ),
+        "{out}"
+    );
+    assert!(
+        out.contains("'listUsers' de docs/users.md no está vinculada"),
+        "{out}"
+    );
+}
@@ -0,0 +1,132 @@
+//! `pairing: colocated-readme`: cada archivo de código con el `README.md`
+//! más cercano, salvo que `combos:` diga otra cosa.
+
+mod common;
+
+use common::docsguard;
+
+const CONFIG: &str = "\
+pairing: colocated-readme
+combos:
+  billing:
+    doc_file: docs/billing.md
+    code: [src/billing/refund.ts]
+";
+
+/// `login.ts` y `google.ts` comparten `src/auth/README.md`; `charge.ts` sube
+/// hasta el README de la raíz y `refund.ts` va con su combinación.
+fn project(config: &str) -> tempfile::TempDir {
+    common::project(&[
+        (".docsguard/config.yaml", config),
+        (
+            "src/auth/login.ts",
+            "/// @docs: [login]\nexport function login(user: string) {}\n",
+        ),
+        (
+            "src/auth/oauth/google.ts",
+            "/// @docs: [oauth]\nexport function google(token: string) {}\n",
+        ),
+        (
+            "src/auth/README.md",
+            "<!-- @docs-id: login -->\n## login\n\n| Param | Type | Description |\n|-------|------|-------------|\n| user | string | Usuario |\n\n<!-- @docs-id: oauth -->\n## oauth\n\n| Param | Type | Description |\n|-------|------|-------------|\n| token | string | Token |\n",
+        ),
+        (
+            "src/billing/charge.ts",
+            "/// @docs: [charge]\nexport function charge(amount: number) {}\n",
+        ),
+        (
+            "src/billing/refund.ts",
+            "/// @docs: [refund]\nexport function refund(id: string) {}\n",
+        ),
+        (
+            "README.md",
+            "<!-- @docs-id: charge -->\n## charge\n\n| Param | Type | Description |\n|-------|------|-------------|\n| amount | number | Importe |\n",
+        ),
+        (
+            "docs/billing.md",
+            "<!-- @docs-id: refund -->\n## refund\n\n| Param | Type | Description |\n|-------|------|-------------|\n| id | string | Pago |\n",
+        ),
+    ])
+}
+
+#[test]
+fn the_resolved_pairs_follow_combos_then_the_nearest_readme() {
+    let dir = project(CONFIG);
+    let (code, out, _) = docsguard(&dir, &["config", "show", "--resolved-pairs"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert_eq!(
+        out,
+        "\
+# pairing: colocated-readme
+src/auth/login.ts -> src/auth/README.md (mismo directorio)
+src/auth/oauth/google.ts -> src/auth/README.md (1 directorio más arriba)
+src/billing/charge.ts -> README.md (2 directorios más arriba)
+src/billing/refund.ts -> docs/billing.md (combos.billing)
+"
+    );
+
+    let dir = project(&format!("{CONFIG}readme_ancestors: 1\n"));
+    let (_, out, _) = docsguard(&dir, &["config", "show", "--resolved-pairs"]);
+    assert!(
+        out.contains("src/billing/charge.ts -> sin par: se valida con el resto de docs"),
+        "{out}"
+    );
+}
+
+#[test]
+fn a_shared_readme_is_linked_by_any_sibling_and_each_group_names_its_code() {
+    let dir = project(CONFIG);
+    let (code, out, _) = docsguard(&dir, &["check-all"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(
+        out.contains("[par] src/auth/README.md ← src/auth/login.ts, src/auth/oauth/google.ts"),
+        "{out}"
+    );
+    assert!(
+        out.contains("[par] docs/billing.md ← src/billing/refund.ts"),
+        "{out}"
+    );
+    // Cada sección la enlaza uno de los hermanos: ninguna es huérfana
+    assert!(!out.contains("orphan-section"), "{out}");
+    assert!(out.contains("Resumen: 0 errores, 0 advertencias"), "{out}");
+
+    // Sin subir hasta la raíz, `charge` se valida con las docs que no son
+    // par de nadie, y su sección sigue ahí
+    let dir = project(&format!("{CONFIG}readme_ancestors: 1\n"));
+    let (code, out, _) = docsguard(&dir, &["check-all"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(
+        out.contains("[sin par] código: src/billing/charge.ts; docs: README.md"),
+        "{out}"
+    );
+    assert!(out.contains("Resumen: 0 errores, 0 advertencias"), "{out}");
+}
+
+#[test]
+fn a_section_only_another_pair_links_is_missing_for_this_one() {
+    let dir = project(CONFIG);
+    // `refund` pasa a enlazar una sección del README de auth, que no es su par
+    std::fs::write(
+        dir.path().join("src/billing/refund.ts"),
+        "/// @docs: [login]\nexport function refund(id: string) {}\n",
+    )
+    .unwrap();
+    let (code, out, _) = docsguard(&dir, &["check-all"]);
+    assert_eq!(code, Some(1), "{out}");
+    assert!(
+        out.contains("[X] Error (missing-doc-section) en fn refund (src/billing/refund.ts:2)"),
+        "{out}"
+    );
+    assert!(out.contains("(orphan-section)"), "{out}");
+
+    // Sin `pairing`, todo se valida junto como antes
+    let dir = project("");
+    let (code, out, _) = docsguard(&dir, &["check-all"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(!out.contains("[par]"), "{out}");
+    let (_, out, _) = docsguard(&dir, &["config", "show", "--resolved-pairs"]);
+    assert!(
+        out.starts_with("# pairing: off — check-all valida todo junto; así se emparejaría:\n"),
+        "{out}"
+    );
+}
@@ -0,0 +1,102 @@
+//! La probable causa de `check`: el commit que rompió un enlace que pasaba.
+
+mod common;
+
+use common::{docsguard, git_with};
+use std::path::Path;
+
+const CODE: &str = "\
+/// @docs: [auth-login]
+export function login(username: string) {}
+";
+
+const DOCS: &str = "\
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Usuario |
+";
+
+/// `git args` con `author` como autor y committer.
+fn git(dir: &Path, author: &str, args: &[&str]) {
+    let email = format!("{author}@example.com");
+    let identity = [
+        ("GIT_AUTHOR_NAME", author),
+        ("GIT_AUTHOR_EMAIL", &email),
+        ("GIT_COMMITTER_NAME", author),
+        ("GIT_COMMITTER_EMAIL", &email),
+    ];
+    git_with(dir, &identity, args);
+}
+
+/// Enlace en verde de alice que un commit de diego rompe.
+fn broken_by_diego() -> tempfile::TempDir {
+    let dir = common::project(&[("src/auth.ts", CODE), ("docs/api.md", DOCS)]);
+    let root = dir.path();
+    git(root, "alice", &["init", "-q"]);
+    git(root, "alice", &["add", "."]);
+    git(root, "alice", &["commit", "-q", "-m", "login"]);
+    std::fs::write(root.join("src/auth.ts"), CODE.replace("username", "user")).unwrap();
+    git(
+        root,
+        "diego",
+        &["commit", "-q", "-am", "refactor auth params"],
+    );
+    dir
+}
+
+#[test]
+fn new_errors_name_the_commit_that_broke_the_link() {
+    let dir = broken_by_diego();
+    let (code, out, _) = docsguard(dir.path(), &["check", "docs/api.md", "src"]);
+    assert_eq!(code, Some(1), "{out}");
+    assert!(
+        out.contains("'refactor auth params' por @diego hace un momento"),
+        "{out}"
+    );
+    // La línea se ajusta al ancho como las demás
+    let unwrapped = out.replace("\n       ", " ");
+    assert!(
+        unwrapped.contains("las docs no cambiaron desde entonces"),
+        "{out}"
+    );
+
+    let (code, out, _) = docsguard(dir.path(), &["check", "docs/api.md", "src", "--no-git"]);
+    assert_eq!(code, Some(1), "{out}");
+    assert!(!out.contains("Probable causa"), "{out}");
+}
+
+#[test]
+fn sarif_only_carries_the_cause_with_blame() {
+    let dir = broken_by_diego();
+    let (_, out, _) = docsguard(
+        dir.path(),
+        &["check", "docs/api.md", "src", "--format", "sarif"],
+    );
+    assert!(!out.contains("probableCause"), "{out}");
+    assert!(!out.contains("diego"), "{out}");
+
+    let (_, out, _) = docsguard(
+        dir.path(),
+        &[
+            "check",
+            "docs/api.md",
+            "src",
+            "--format",
+            "sarif",
+            "--with-blame",
+        ],
+    );
+    let log: serde_json::Value = serde_json::from_str(&out).unwrap();
+    let error = log["runs"][0]["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|r| r["level"] == "error")
+        .unwrap();
+    let cause = &error["properties"]["probableCause"];
+    assert_eq!(cause["author"], "@diego", "{out}");
+    assert_eq!(cause["subject"], "refactor auth params", "{out}");
+}
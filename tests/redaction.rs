@@ -0,0 +1,151 @@
+//! Redacción de lo que sale de la máquina: `redaction.patterns` y
+//! `--redact-descriptions` en los informes estructurados, nunca en el
+//! terminal.
+
+mod common;
+
+use assert_cmd::Command;
+use common::{auth_project, check, command, docsguard, LINKED, UNDOCUMENTED};
+
+/// La descripción de relleno llega al mensaje de `placeholder-description`.
+const DOCS: &str = "\
+<!-- @docs-id: login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| user | string | TODO: migrar a ProyectoHalcon |
+";
+
+const CONFIG: &str = "\
+rules:
+  placeholder-description: warning
+profiles:
+  ci: {doc_file: docs/api.md, code: [src], fail_on: never, format: json, output: out.json}
+";
+
+fn project() -> tempfile::TempDir {
+    common::project(&[
+        (
+            "src/lib.rs",
+            "/// @docs: [login]\npub fn login(user: String) {}\n",
+        ),
+        ("docs/api.md", DOCS),
+        (".docsguard/config.yaml", CONFIG),
+    ])
+}
+
+#[test]
+fn check_reports_drop_documented_descriptions_on_request() {
+    let dir = project();
+    for format in ["sarif", "json", "markdown"] {
+        let args = ["check", "docs/api.md", "src/lib.rs", "--format", format];
+        let (_, out, _) = docsguard(&dir, &args);
+        assert!(out.contains("ProyectoHalcon"), "{format}: {out}");
+
+        let (code, out, err) = docsguard(&dir, &[&args[..], &["--redact-descriptions"]].concat());
+        assert_eq!(code, Some(0), "{format}: {out}{err}");
+        assert!(!out.contains("ProyectoHalcon"), "{format}: {out}");
+        assert!(
+            out.contains("es de relleno") && out.contains("█"),
+            "{format}: {out}"
+        );
+    }
+    // El terminal no se redacta
+    let (_, out, _) = docsguard(
+        &dir,
+        &[
+            "check",
+            "docs/api.md",
+            "src/lib.rs",
+            "--redact-descriptions",
+        ],
+    );
+    assert!(out.contains("ProyectoHalcon"), "{out}");
+}
+
+#[test]
+fn ci_run_artifacts_drop_documented_descriptions_on_request() {
+    let dir = project();
+    let artifact = || std::fs::read_to_string(dir.path().join("out.json")).unwrap();
+    let (code, out, err) = docsguard(&dir, &["ci", "run", "--profile", "ci"]);
+    assert_eq!(code, Some(0), "{out}{err}");
+    assert!(artifact().contains("ProyectoHalcon"));
+
+    let (code, out, err) = docsguard(
+        &dir,
+        &["ci", "run", "--profile", "ci", "--redact-descriptions"],
+    );
+    assert_eq!(code, Some(0), "{out}{err}");
+    assert!(!artifact().contains("ProyectoHalcon"), "{}", artifact());
+    assert!(artifact().contains("placeholder-description"));
+}
+
+#[test]
+fn redaction_applies_to_ci_and_html_but_not_the_terminal() {
+    let dir = auth_project(UNDOCUMENTED);
+    std::fs::create_dir(dir.path().join(".docsguard")).unwrap();
+    std::fs::write(
+        dir.path().join(".docsguard/config.yaml"),
+        "redaction:\n  patterns: [\"auth-log*\"]\n",
+    )
+    .unwrap();
+    let stdout = |cmd: &mut Command| String::from_utf8(cmd.output().unwrap().stdout).unwrap();
+
+    assert!(stdout(&mut check(dir.path())).contains("'auth-logout' no encontrado"));
+
+    let ci = stdout(
+        command(dir.path())
+            .args(["ci", "github", "docs/api.md", "src/auth.ts"])
+            .env_remove("GITHUB_ACTIONS")
+            .env_remove("GITHUB_STEP_SUMMARY"),
+    );
+    assert!(ci.contains("ID de documentación '█ no encontrado"));
+    assert!(!ci.contains("auth-logout' no encontrado"));
+    assert!(!ci.contains("<!-- @docs-id: auth-logout"));
+
+    command(dir.path())
+        .args(["report", "docs/api.md", "src/auth.ts", "--html", "out.html"])
+        .assert()
+        .code(0);
+    let html = std::fs::read_to_string(dir.path().join("out.html")).unwrap();
+    assert!(html.contains("█"));
+    assert!(!html.contains("auth-logout' no encontrado"));
+}
+
+#[test]
+fn sarif_output_is_redacted_in_messages_and_locations() {
+    let dir = auth_project(LINKED);
+    std::fs::write(dir.path().join("src/auth-logout.ts"), UNDOCUMENTED).unwrap();
+    std::fs::create_dir(dir.path().join(".docsguard")).unwrap();
+    std::fs::write(
+        dir.path().join(".docsguard/config.yaml"),
+        "redaction:\n  patterns: [auth-logout]\n",
+    )
+    .unwrap();
+    for lang in ["es", "en"] {
+        let output = command(dir.path())
+            .args([
+                "--lang",
+                lang,
+                "check",
+                "docs/api.md",
+                "src/auth-logout.ts",
+                "--format",
+                "sarif",
+            ])
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(1));
+        let log = String::from_utf8(output.stdout).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&log).unwrap();
+        let error = &parsed["runs"][0]["results"][0];
+        assert_eq!(error["ruleId"], "missing-doc-section");
+        assert!(error["message"]["text"].as_str().unwrap().contains("█"));
+        assert_eq!(
+            error["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "src/%E2%96%88.ts"
+        );
+        assert!(!log.to_lowercase().contains("auth-logout"), "{log}");
+    }
+}
@@ -0,0 +1,48 @@
+//! `check --require-docs-for-new`: las funciones públicas nuevas desde una
+//! referencia git necesitan documentación.
+
+mod common;
+
+use common::{auth_project, check, LINKED};
+
+#[test]
+fn new_public_functions_without_docs_exit_1() {
+    let dir = auth_project(LINKED);
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .current_dir(dir.path())
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .output()
+            .unwrap()
+    };
+    git(&["init", "-q"]);
+    git(&["add", "."]);
+    git(&["commit", "-q", "-m", "base"]);
+    check(dir.path())
+        .arg("--require-docs-for-new=HEAD")
+        .assert()
+        .code(0);
+    // Sin referencia propia ni --changed-since no hay con qué comparar
+    check(dir.path())
+        .arg("--require-docs-for-new")
+        .assert()
+        .code(2);
+
+    std::fs::write(
+        dir.path().join("src/auth.ts"),
+        format!("{}export function refresh(token: string) {{}}\n", LINKED),
+    )
+    .unwrap();
+    check(dir.path()).assert().code(0);
+    let output = check(dir.path())
+        .args(["--changed-since", "HEAD", "--require-docs-for-new"])
+        .assert()
+        .code(1)
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8_lossy(&output);
+    assert!(stdout.contains("(undocumented-new-function) en fn refresh"));
+    assert!(stdout.contains("`/// @docs: [refresh]`"));
+}
@@ -0,0 +1,44 @@
+//! `check --format sarif`: el log es lo único en stdout y el código de
+//! salida no cambia.
+
+mod common;
+
+use common::{auth_project, command, LINKED, UNDOCUMENTED};
+
+#[test]
+fn sarif_output_is_the_only_stdout_and_keeps_the_exit_code() {
+    let sarif = |code: &str| {
+        let dir = auth_project(code);
+        let output = command(dir.path())
+            .args(["check", "docs/api.md", "src/auth.ts", "--format", "sarif"])
+            .output()
+            .unwrap();
+        let log: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        (output.status.code(), log, stderr)
+    };
+
+    let (code, log, stderr) = sarif(UNDOCUMENTED);
+    assert_eq!(code, Some(1));
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "DocsGuard");
+    assert_eq!(run["tool"]["driver"]["version"], env!("CARGO_PKG_VERSION"));
+    let error = run["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|r| r["level"] == "error")
+        .unwrap();
+    assert_eq!(error["ruleId"], "missing-doc-section");
+    let location = &error["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "src/auth.ts");
+    assert_eq!(location["region"]["startLine"], 2);
+    // La salida de siempre sigue en stderr, menos los hallazgos
+    assert!(stderr.contains("Resumen: 1 error"), "{stderr}");
+    assert!(!stderr.contains("auth-logout'"), "{stderr}");
+
+    let (code, log, _) = sarif(LINKED);
+    assert_eq!(code, Some(0));
+    assert_eq!(log["runs"][0]["results"][0]["ruleId"], "link-verified");
+}
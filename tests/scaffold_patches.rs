@@ -0,0 +1,169 @@
+//! `docsguard scaffold --emit-patch`: los parches se aplican con `git apply`
+//! en un repositorio y después `check` resuelve los enlaces.
+
+#![cfg(feature = "interactive")]
+
+mod common;
+
+use common::{command, git};
+use std::path::Path;
+
+const DOCS: &str = "\
+# API
+
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Usuario |
+
+<!-- @docs-id: search -->
+## search
+
+| Param | Type | Description |
+|-------|------|-------------|
+| query | string | Texto |
+
+<!-- @docs-id: user-get -->
+## get user
+";
+
+const CODE: &str = "\
+import { db } from \"./db\";
+
+export function login(username: string) {
+  return db.user(username);
+}
+
+const LIMIT = 10;
+const OFFSET = 0;
+
+export function search(query: string) {
+  return db.find(query, LIMIT, OFFSET);
+}
+
+// Usuarios
+
+export function getUser() {}
+
+// Sesiones
+
+export function logout(token: string) {}
+";
+
+/// Repositorio con `docs/api.md` y `src/api.ts` sin enlazar, ya confirmados.
+fn repo() -> tempfile::TempDir {
+    let dir = common::project(&[("docs/api.md", DOCS), ("src/api.ts", CODE)]);
+    git(dir.path(), &["init", "-q"]);
+    git(dir.path(), &["add", "-A"]);
+    git(dir.path(), &["commit", "-q", "-m", "sin enlaces"]);
+    dir
+}
+
+fn scaffold(dir: &Path, extra: &[&str]) {
+    command(dir)
+        .args([
+            "scaffold",
+            "src/api.ts",
+            "docs/api.md",
+            "--emit-patch",
+            "patches",
+        ])
+        .args(extra)
+        .assert()
+        .code(0);
+}
+
+/// `check` sale con 0 y devuelve la salida.
+fn check(dir: &Path) -> String {
+    let output = command(dir)
+        .args(["check", "docs/api.md", "src/api.ts", "--verbose"])
+        .assert()
+        .code(0)
+        .get_output()
+        .stdout
+        .clone();
+    String::from_utf8(output).unwrap()
+}
+
+fn patch_names(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(dir.join("patches"))
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn one_patch_per_link_applies_with_git_and_check_resolves_the_links() {
+    let dir = repo();
+    scaffold(dir.path(), &["--stubs"]);
+    // Sin preguntar y sin tocar el árbol: solo los parches
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join("src/api.ts")).unwrap(),
+        CODE
+    );
+    let names = patch_names(dir.path());
+    assert_eq!(
+        names,
+        [
+            "001-auth-login.patch",
+            "002-search.patch",
+            "003-user-get.patch",
+            "004-logout.patch"
+        ]
+    );
+
+    let login = std::fs::read_to_string(dir.path().join("patches/001-auth-login.patch")).unwrap();
+    assert!(
+        login.starts_with(
+            "# docsguard scaffold --emit-patch\n\
+             # login (src/api.ts:3) → auth-login · confianza 100% (ID 50%, título 100%)\n"
+        ),
+        "{login}"
+    );
+    assert!(login.contains("+/// @docs: [auth-login]\n"), "{login}");
+    // La sección nueva trae también el marcador en las docs
+    let logout = std::fs::read_to_string(dir.path().join("patches/004-logout.patch")).unwrap();
+    assert!(logout.contains("(sección nueva)"), "{logout}");
+    assert!(logout.contains("+++ b/docs/api.md\n"), "{logout}");
+    assert!(logout.contains("+<!-- @docs-id: logout -->\n"), "{logout}");
+
+    let mut apply = vec!["apply"];
+    let paths: Vec<String> = names.iter().map(|n| format!("patches/{n}")).collect();
+    apply.extend(paths.iter().map(String::as_str));
+    git(dir.path(), &apply);
+
+    let out = check(dir.path());
+    for (function, id) in [
+        ("login", "auth-login"),
+        ("search", "search"),
+        ("getUser", "user-get"),
+        ("logout", "logout"),
+    ] {
+        assert!(
+            out.contains(&format!("Enlace verificado: fn {function} ")),
+            "{function} → {id}:\n{out}"
+        );
+    }
+}
+
+#[test]
+fn single_patch_keeps_only_links_above_the_minimum_confidence() {
+    let dir = repo();
+    scaffold(dir.path(), &["--single-patch", "--min-confidence", "0.9"]);
+    assert_eq!(patch_names(dir.path()), ["scaffold.patch"]);
+
+    let patch = std::fs::read_to_string(dir.path().join("patches/scaffold.patch")).unwrap();
+    // getUser ↔ «get user» se queda en 88%
+    assert!(!patch.contains("user-get"), "{patch}");
+    assert_eq!(patch.matches("+/// @docs: [").count(), 2, "{patch}");
+
+    git(dir.path(), &["apply", "patches/scaffold.patch"]);
+    let out = check(dir.path());
+    assert!(out.contains("Enlace verificado: fn login "), "{out}");
+    assert!(out.contains("Enlace verificado: fn search "), "{out}");
+    assert!(!out.contains("Enlace verificado: fn getUser "), "{out}");
+}
@@ -0,0 +1,36 @@
+//! `schema`: los esquemas impresos son JSON, y las erratas de la
+//! configuración reciben una sugerencia.
+
+mod common;
+
+use common::{auth_project, check, command, LINKED};
+
+#[test]
+fn printed_schemas_are_json_and_config_typos_get_a_suggestion() {
+    let dir = auth_project(LINKED);
+    let output = command(dir.path())
+        .args(["schema", "config"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(schema["properties"]["site"]["additionalProperties"], false);
+    command(dir.path())
+        .args(["schema", "history"])
+        .assert()
+        .code(2);
+
+    std::fs::create_dir(dir.path().join(".docsguard")).unwrap();
+    std::fs::write(
+        dir.path().join(".docsguard/config.yaml"),
+        "info_agregation: summarize\n",
+    )
+    .unwrap();
+    let output = check(dir.path()).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("-> Esquema: `info_agregation`: campo `info_agregation` desconocido; ¿quisiste decir `info_aggregation`?"),
+        "{stderr}"
+    );
+}
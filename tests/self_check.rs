@@ -0,0 +1,43 @@
+//! `docsguard self-check` sobre este repositorio: las anotaciones `@docs` de
+//! `src/` y `docs/architecture.md` no pueden desviarse sin romper la build.
+
+mod common;
+
+use common::{command, project};
+
+#[test]
+fn the_crate_annotations_match_the_architecture_docs() {
+    let output = command(env!("CARGO_MANIFEST_DIR"))
+        .args(["self-check", "--project-root", env!("CARGO_MANIFEST_DIR")])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(0), "{stdout}");
+    assert!(stdout.contains("0 errores, 0 advertencias"), "{stdout}");
+}
+
+#[test]
+fn drift_between_an_annotation_and_its_section_fails() {
+    let dir = project(&[
+        (
+            "docs/architecture.md",
+            "<!-- @docs-id: validate-links -->\n## validate_links\n\n\
+             | Param | Type | Description |\n|-------|------|-------------|\n\
+             | code_entities | &[CodeEntity] | Entidades |\n",
+        ),
+        (
+            "src/core/validator.rs",
+            "/// @docs: [validate-links]\n\
+             pub fn validate_links(code_entities: &[CodeEntity], doc_sections: &[DocSection]) {}\n",
+        ),
+    ]);
+
+    let output = command(&dir)
+        .args(["self-check", "--project-root"])
+        .arg(dir.path())
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(1), "{stdout}");
+    assert!(stdout.contains("doc_sections"), "{stdout}");
+}
@@ -0,0 +1,85 @@
+//! `-` como archivo de código o de documentación: el contenido llega por
+//! stdin, como lo pasaría un editor con un buffer sin guardar.
+
+mod common;
+
+use common::command;
+
+const CODE: &str = "\
+/// @docs: [auth-login]
+export function login(username: string, remember: boolean) {}
+";
+
+const DOCS: &str = "\
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Usuario |
+";
+
+fn project() -> tempfile::TempDir {
+    common::project(&[("auth.ts", CODE), ("api.md", DOCS)])
+}
+
+/// Código de salida y salida completa (stdout y stderr) con `stdin` en la
+/// entrada estándar.
+fn with_stdin(dir: &tempfile::TempDir, args: &[&str], stdin: &str) -> (Option<i32>, String) {
+    let output = command(dir).args(args).write_stdin(stdin).output().unwrap();
+    let mut text = String::from_utf8(output.stdout).unwrap();
+    text.push_str(&String::from_utf8(output.stderr).unwrap());
+    (output.status.code(), text)
+}
+
+#[test]
+fn code_and_docs_can_come_from_stdin() {
+    let dir = project();
+    let (code, out) = with_stdin(
+        &dir,
+        &["check", "api.md", "-", "--code-language", "ts"],
+        CODE,
+    );
+    assert_eq!(code, Some(0), "{out}");
+    assert!(out.contains("en fn login (-:2)"), "{out}");
+
+    let (code, out) = with_stdin(&dir, &["check", "-", "auth.ts"], DOCS);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(out.contains("ID vinculado: 'auth-login' (-:1)"), "{out}");
+}
+
+#[test]
+fn stdin_input_is_checked_like_a_file() {
+    let dir = project();
+    // Sin extensión no hay lenguaje
+    let (code, out) = with_stdin(&dir, &["check", "api.md", "-"], CODE);
+    assert_eq!(code, Some(2), "{out}");
+    assert!(out.contains("necesita `--code-language`"), "{out}");
+
+    let (code, out) = with_stdin(&dir, &["check", "-", "-", "--code-language", "ts"], CODE);
+    assert_eq!(code, Some(2), "{out}");
+    assert!(
+        out.contains("Solo una entrada puede leerse de stdin"),
+        "{out}"
+    );
+
+    let huge = "x".repeat(10 * 1024 * 1024 + 1);
+    let (code, out) = with_stdin(&dir, &["check", "-", "auth.ts"], &huge);
+    assert_eq!(code, Some(3), "{out}");
+    assert!(
+        out.contains("Archivo demasiado grande (10.0 MB, máximo: 10 MB): -"),
+        "{out}"
+    );
+}
+
+#[cfg(feature = "watch")]
+#[test]
+fn watch_rejects_stdin() {
+    let dir = project();
+    let (code, out) = with_stdin(&dir, &["watch", "-", "api.md"], CODE);
+    assert_eq!(code, Some(2), "{out}");
+    assert!(
+        out.contains("El modo watch no puede leer de stdin"),
+        "{out}"
+    );
+}
@@ -0,0 +1,114 @@
+//! `suppressions list` y la pestaña "Supresiones" de `report --html` sobre
+//! un repositorio con una directiva commiteada hace diez días.
+
+mod common;
+
+use common::{docsguard, git, git_at};
+
+const CODE: &str = "\
+/// @docs: [auth-login]
+// docsguard-ignore: ghost-arg
+export function login(username: string) {}
+
+/// @docs: [auth-logout]
+// docsguard-ignore: missing-arg
+export function logout() {}
+";
+
+const DOCS: &str = "\
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Usuario |
+| remember | boolean | Recordar la sesión |
+
+<!-- @docs-id: auth-logout -->
+## logout
+
+<!-- @docs-id: template @docs-no-link -->
+## Plantilla
+";
+
+const DAY: u64 = 86_400;
+
+fn project() -> tempfile::TempDir {
+    let dir = common::project(&[("src/auth.ts", CODE), ("docs/api.md", DOCS)]);
+    let root = dir.path();
+    git(root, &["init", "-q"]);
+    git(root, &["add", "."]);
+    let ten_days_ago = format!("@{} +0000", common::now() - 10 * DAY);
+    git_at(root, &ten_days_ago, &["commit", "-q", "-m", "auth"]);
+    dir
+}
+
+#[test]
+fn suppressions_are_listed_with_their_age_and_use() {
+    let dir = project();
+    let list = ["suppressions", "list", "docs/api.md", "src/auth.ts"];
+    let (code, out, _) = docsguard(dir.path(), &list);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(
+        out.starts_with("  3 supresiones activas (1 sin uso en esta ejecución)"),
+        "{out}"
+    );
+    assert!(
+        out.contains(
+            "  [docsguard-ignore] src/auth.ts:2\n      ghost-arg en fn login\n      \
+             hace 10 días · silenció hallazgos en esta ejecución"
+        ),
+        "{out}"
+    );
+    assert!(
+        out.contains("      missing-arg en fn logout\n      hace 10 días · sin uso"),
+        "{out}"
+    );
+
+    // Sin git no hay fecha; el filtro deja un mecanismo
+    let (_, out, _) = docsguard(
+        dir.path(),
+        &[&list[..], &["--no-git", "--mechanism", "no-link"]].concat(),
+    );
+    assert!(
+        out.contains("orphan-section de 'template'\n      antigüedad desconocida"),
+        "{out}"
+    );
+    assert!(!out.contains("docsguard-ignore"), "{out}");
+
+    let (code, out, _) = docsguard(
+        dir.path(),
+        &[&list[..], &["--format", "json", "--sort", "location"]].concat(),
+    );
+    assert_eq!(code, Some(0), "{out}");
+    let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(json["total"], 3);
+    assert_eq!(json["unused"], 1);
+    let first = &json["suppressions"][0];
+    assert_eq!(first["mechanism"], "no-link");
+    assert_eq!(first["location"], "docs/api.md:12");
+    assert_eq!(first["age_days"], 10);
+    assert_eq!(first["used"], true);
+}
+
+#[test]
+fn the_html_report_has_a_suppressions_tab() {
+    let dir = project();
+    let (code, out, _) = docsguard(
+        dir.path(),
+        &[
+            "report",
+            "docs/api.md",
+            "src/auth.ts",
+            "--html",
+            "report.html",
+        ],
+    );
+    assert_eq!(code, Some(0), "{out}");
+    let html = std::fs::read_to_string(dir.path().join("report.html")).unwrap();
+    assert!(html.contains(">Supresiones (3)</button>"), "{html}");
+    assert!(html.contains(
+        r#"["inline","docsguard-ignore","src/auth.ts:6","missing-arg en fn logout",null,"#
+    ));
+    assert!(html.contains(r#",10,"hace 10 días",false]"#));
+}
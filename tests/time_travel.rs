@@ -0,0 +1,200 @@
+//! `check --code-ref`/`--docs-ref` sobre un repositorio con dos ramas cuyo
+//! código difiere en una firma.
+
+mod common;
+
+use common::{docsguard, git};
+
+/// `release/2.x`.
+const CODE_V2: &str = "\
+/// @docs: [auth-login]
+export function login(username: string, remember: boolean) {}
+";
+
+/// `main`.
+const CODE_V3: &str = "\
+/// @docs: [auth-login]
+export function login(username: string, tenant: number) {}
+";
+
+const DOCS_V2: &str = "\
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Usuario |
+| remember | boolean | Recordar la sesión |
+";
+
+const DOCS_V3: &str = "\
+<!-- @docs-id: auth-login -->
+## login
+
+| Param | Type | Description |
+|-------|------|-------------|
+| username | string | Usuario |
+| tenant | number | Organización |
+";
+
+const COMBOS: &str = "\
+combos:
+  v2: {doc_file: docs/v2/api.md, code: [src], code_ref: release/2.x}
+";
+
+/// `release/2.x` con el código de la v2 y `main`, en el árbol de trabajo,
+/// con el de la v3. Las docs de ambas versiones viven en `main`.
+fn two_branch_repo() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    for sub in ["src", "docs/v2", "docs/v3", ".docsguard"] {
+        std::fs::create_dir_all(root.join(sub)).unwrap();
+    }
+    git(root, &["init", "-q", "-b", "main"]);
+    std::fs::write(root.join("src/auth.ts"), CODE_V2).unwrap();
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "v2"]);
+    git(root, &["branch", "release/2.x"]);
+
+    std::fs::write(root.join("src/auth.ts"), CODE_V3).unwrap();
+    std::fs::write(root.join("docs/v2/api.md"), DOCS_V2).unwrap();
+    std::fs::write(root.join("docs/v3/api.md"), DOCS_V3).unwrap();
+    std::fs::write(root.join(".docsguard/config.yaml"), COMBOS).unwrap();
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "v3"]);
+    dir
+}
+
+#[test]
+fn docs_are_checked_against_the_code_of_another_branch() {
+    let dir = two_branch_repo();
+    let (code, out, _) = docsguard(dir.path(), &["check", "docs/v3/api.md", "src"]);
+    assert_eq!(code, Some(0), "{out}");
+    let (code, out, _) = docsguard(dir.path(), &["check", "docs/v2/api.md", "src"]);
+    assert_eq!(code, Some(1), "{out}");
+
+    let (code, out, _) = docsguard(
+        dir.path(),
+        &[
+            "check",
+            "docs/v2/api.md",
+            "src",
+            "--code-ref",
+            "release/2.x",
+        ],
+    );
+    assert_eq!(code, Some(0), "{out}");
+    assert!(
+        out.contains("Código leído de la revisión 'release/2.x'"),
+        "{out}"
+    );
+
+    // Los hallazgos dicen de qué revisión salió el archivo
+    let (code, out, _) = docsguard(
+        dir.path(),
+        &[
+            "check",
+            "docs/v3/api.md",
+            "src",
+            "--code-ref",
+            "release/2.x",
+        ],
+    );
+    assert_eq!(code, Some(1), "{out}");
+    assert!(out.contains("src/auth.ts@release/2.x:2"), "{out}");
+    assert!(out.contains("docs/v3/api.md:"), "{out}");
+    assert!(!out.contains("docs/v3/api.md@"), "{out}");
+}
+
+#[test]
+fn docs_can_be_read_from_a_revision_too() {
+    let dir = two_branch_repo();
+    std::fs::write(
+        dir.path().join("docs/v3/api.md"),
+        DOCS_V3.replace("tenant", "org"),
+    )
+    .unwrap();
+    let (code, out, _) = docsguard(dir.path(), &["check", "docs/v3/api.md", "src"]);
+    assert_eq!(code, Some(1), "{out}");
+    let (code, out, _) = docsguard(
+        dir.path(),
+        &["check", "docs/v3/api.md", "src", "--docs-ref", "HEAD"],
+    );
+    assert_eq!(code, Some(0), "{out}");
+}
+
+#[test]
+fn a_combo_uses_its_own_baseline() {
+    let dir = two_branch_repo();
+    let (code, out, _) = docsguard(dir.path(), &["check", "--combo", "v2"]);
+    assert_eq!(code, Some(0), "{out}");
+
+    // Un arg fantasma en las docs de la v2, aceptado en el baseline de la combinación
+    std::fs::write(
+        dir.path().join("docs/v2/api.md"),
+        format!("{DOCS_V2}| locale | string | Idioma |\n"),
+    )
+    .unwrap();
+    let (code, out, _) = docsguard(dir.path(), &["check", "--combo", "v2"]);
+    assert_eq!(code, Some(1), "{out}");
+    let (code, out, _) = docsguard(
+        dir.path(),
+        &[
+            "baseline",
+            "src/auth.ts",
+            "docs/v2/api.md",
+            "--code-ref",
+            "release/2.x",
+            "--baseline-name",
+            "v2",
+        ],
+    );
+    assert_eq!(code, Some(0), "{out}");
+    assert!(dir.path().join(".docsguard/baseline-v2.yaml").exists());
+    assert!(!dir.path().join(".docsguard/baseline.yaml").exists());
+
+    let (code, out, _) = docsguard(dir.path(), &["check", "--combo", "v2"]);
+    assert_eq!(code, Some(0), "{out}");
+    assert!(out.contains("[baseline]"), "{out}");
+    let (code, out, _) = docsguard(
+        dir.path(),
+        &[
+            "check",
+            "docs/v2/api.md",
+            "src",
+            "--code-ref",
+            "release/2.x",
+        ],
+    );
+    assert_eq!(code, Some(1), "{out}");
+}
+
+#[test]
+fn unknown_refs_and_combos_are_reported() {
+    let dir = two_branch_repo();
+    let (code, _, _) = docsguard(
+        dir.path(),
+        &[
+            "check",
+            "docs/v2/api.md",
+            "src",
+            "--code-ref",
+            "release/9.x",
+        ],
+    );
+    assert_eq!(code, Some(2));
+    let (code, _, _) = docsguard(dir.path(), &["check", "--combo", "v9"]);
+    assert_eq!(code, Some(2));
+    let (code, _, _) = docsguard(
+        dir.path(),
+        &[
+            "check",
+            "docs/v2/api.md",
+            "src",
+            "--code-ref",
+            "HEAD",
+            "--fix",
+        ],
+    );
+    assert_eq!(code, Some(2));
+}
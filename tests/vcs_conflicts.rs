@@ -0,0 +1,128 @@
+//! Archivos con un conflicto de git sin resolver: `check` los reporta como
+//! `vcs-conflict` y los comandos que escriben se niegan a tocarlos.
+
+mod common;
+
+use common::docsguard;
+
+const CODE: &str = "\
+/// @docs: [login]
+pub fn login(user: String) {}
+
+pub fn logout() {}
+";
+
+/// Lo que deja `git merge` al chocar dos versiones de la tabla.
+const CONFLICTED: &str = "\
+<!-- @docs-id: login -->
+## login
+
+<<<<<<< HEAD
+| Param | Type | Description |
+|-------|------|-------------|
+| user | string | Usuario |
+=======
+| Param | Type | Description |
+|-------|------|-------------|
+| login | string | Usuario |
+>>>>>>> feature
+
+<!-- @docs-id: logout -->
+## logout
+";
+
+/// Un `<<<<<<<` de ejemplo y un encabezado setext: sin la estructura
+/// completa no es un conflicto.
+const FENCED: &str = "\
+<!-- @docs-id: login -->
+Login
+=======
+
+| Param | Type | Description |
+|-------|------|-------------|
+| user | string | Usuario |
+
+Así se ve un conflicto:
+
+```text
+<<<<<<< HEAD
+```
+";
+
+fn project(docs: &str) -> tempfile::TempDir {
+    common::project(&[("src/lib.rs", CODE), ("docs/api.md", docs)])
+}
+
+#[test]
+fn check_reports_the_conflict_as_a_warning() {
+    let dir = project(CONFLICTED);
+    let (code, out, err) = docsguard(&dir, &["check", "docs/api.md", "src/lib.rs"]);
+    assert_eq!(code, Some(1), "{out}{err}");
+    assert!(out.contains("[!] Warning (vcs-conflict)"), "{out}");
+    assert!(out.contains("`<<<<<<<` en la línea 4"), "{out}");
+    assert!(out.contains("docs/api.md:4"), "{out}");
+}
+
+#[test]
+fn a_lone_marker_in_a_fence_is_not_a_conflict() {
+    let dir = project(FENCED);
+    let (code, out, err) = docsguard(&dir, &["check", "docs/api.md", "src/lib.rs"]);
+    assert_eq!(code, Some(0), "{out}{err}");
+    assert!(!out.contains("vcs-conflict"), "{out}");
+}
+
+#[test]
+fn check_fix_refuses_to_write_and_names_the_line() {
+    let dir = project(CONFLICTED);
+    let (code, out, err) = docsguard(&dir, &["check", "docs/api.md", "src/lib.rs", "--fix"]);
+    assert_eq!(code, Some(3), "{out}{err}");
+    assert!(
+        err.contains("docs/api.md:4: conflicto de git sin resolver"),
+        "{err}"
+    );
+    let docs = std::fs::read_to_string(dir.path().join("docs/api.md")).unwrap();
+    assert_eq!(docs, CONFLICTED);
+}
+
+#[cfg(feature = "interactive")]
+#[test]
+fn scaffold_and_fix_leave_conflicted_files_untouched() {
+    let dir = project(CONFLICTED);
+    for args in [
+        &["fix", "src/lib.rs", "docs/api.md"][..],
+        &["scaffold", "src/lib.rs", "docs/api.md", "--emit-patch", "p"],
+    ] {
+        let (code, out, err) = docsguard(&dir, args);
+        assert_eq!(code, Some(3), "{args:?}: {out}{err}");
+        assert!(err.contains("docs/api.md:4: conflicto"), "{err}");
+    }
+    let code = std::fs::read_to_string(dir.path().join("src/lib.rs")).unwrap();
+    assert_eq!(code, CODE);
+    assert!(!dir.path().join("p").exists());
+}
+
+/// `triage` avisa antes del menú y no ofrece suprimir en línea en un
+/// archivo en conflicto. Sin terminal se detiene en el menú: basta para ver
+/// el aviso y que nada se escribió.
+#[cfg(feature = "interactive")]
+#[test]
+fn triage_offers_no_writes_into_a_conflicted_file() {
+    let conflicted_code = "\
+/// @docs: [login]
+pub fn login(user: String, device: String) {}
+<<<<<<< HEAD
+=======
+pub fn logout() {}
+>>>>>>> feature
+";
+    let dir = project(FENCED);
+    std::fs::write(dir.path().join("src/lib.rs"), conflicted_code).unwrap();
+    let (_, out, err) = docsguard(&dir, &["triage", "docs/api.md", "src/lib.rs"]);
+    assert!(out.contains("(missing-arg) en fn login"), "{out}");
+    assert!(
+        err.contains("  [!] src/lib.rs:3: conflicto de git sin resolver"),
+        "{err}"
+    );
+    let code = std::fs::read_to_string(dir.path().join("src/lib.rs")).unwrap();
+    assert_eq!(code, conflicted_code);
+}
@@ -0,0 +1,42 @@
+//! `check -q` y `check -v`: cambian lo que se imprime, no el resumen.
+
+mod common;
+
+use common::{auth_project, check};
+
+#[test]
+fn quiet_and_verbose_change_what_is_printed_but_not_the_summary() {
+    let dir = auth_project(
+        "/// @docs: [auth-login]\nexport function login(username: string, password: string) {}\n",
+    );
+    let run = |flag: Option<&str>| {
+        let mut cmd = check(dir.path());
+        cmd.args(flag);
+        let output = cmd.assert().code(0).get_output().stdout.clone();
+        String::from_utf8(output).unwrap()
+    };
+    let summary = |out: &str| {
+        out.lines()
+            .find(|l| l.starts_with("Resumen"))
+            .unwrap()
+            .to_string()
+    };
+
+    let normal = run(None);
+    assert!(normal.contains("[!] Warning (missing-arg)"), "{normal}");
+    assert!(!normal.contains("Enlace verificado"), "{normal}");
+    let verbose = run(Some("-v"));
+    assert!(
+        verbose.contains("Enlace verificado: fn login "),
+        "{verbose}"
+    );
+    let quiet = run(Some("--quiet"));
+    assert!(!quiet.contains("DocsGuard —"), "{quiet}");
+    assert!(!quiet.contains("missing-arg"), "{quiet}");
+
+    // El resumen cuenta los dos hallazgos, se impriman o no
+    assert!(summary(&normal).contains(", 2 total"), "{normal}");
+    assert_eq!(summary(&quiet), summary(&normal));
+    assert_eq!(summary(&verbose), summary(&normal));
+    check(dir.path()).args(["-q", "-v"]).assert().code(2);
+}
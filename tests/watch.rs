@@ -0,0 +1,22 @@
+//! `docsguard watch --interactive-fixes` sin terminal: un error de uso. Solo
+//! con las features `watch` e `interactive`.
+#![cfg(all(feature = "watch", feature = "interactive"))]
+
+mod common;
+
+use common::{auth_project, command, LINKED};
+
+#[test]
+fn interactive_fixes_need_a_terminal() {
+    let dir = auth_project(LINKED);
+    let output = command(dir.path())
+        .args(["watch", "src/auth.ts", "docs/api.md", "--interactive-fixes"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("--interactive-fixes necesita una terminal."),
+        "{stderr}"
+    );
+}